@@ -0,0 +1,178 @@
+//! Auto-discovery check for views added to `#[dampen_app]` applications.
+//!
+//! `#[dampen_app]` builds its view list from the `.dampen` files under its
+//! `ui_dir`, minus the `exclude` patterns. After scaffolding a view, the `add`
+//! command reads the macro's attributes from `src/main.rs` to tell whether the
+//! new view will be picked up, rather than leaving the user to find out at the
+//! next build.
+
+use std::fs;
+use std::path::Path;
+
+/// Whether `#[dampen_app]` will discover a view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ViewDiscovery {
+    /// The view is under `ui_dir` and matches no `exclude` pattern
+    Discovered,
+    /// The view is outside `ui_dir`
+    OutsideUiDir {
+        /// `ui_dir` of the macro, relative to the project root
+        ui_dir: String,
+    },
+    /// The view matches an `exclude` pattern
+    Excluded {
+        /// The matching pattern
+        pattern: String,
+    },
+}
+
+impl ViewDiscovery {
+    /// Line of the success message describing the result
+    pub fn message(&self, dampen_file: &Path) -> String {
+        match self {
+            ViewDiscovery::Discovered => {
+                "\n  → Auto-discovered by #[dampen_app] in src/main.rs".to_string()
+            }
+            ViewDiscovery::OutsideUiDir { ui_dir } => format!(
+                "\n  ⚠ {} is outside ui_dir \"{}\": #[dampen_app] will not discover it",
+                dampen_file.display(),
+                ui_dir
+            ),
+            ViewDiscovery::Excluded { pattern } => format!(
+                "\n  ⚠ {} matches the exclude pattern \"{}\" of #[dampen_app]",
+                dampen_file.display(),
+                pattern
+            ),
+        }
+    }
+}
+
+/// Check whether the `#[dampen_app]` of `src/main.rs` discovers `dampen_file`.
+///
+/// Returns `None` if the project does not use `#[dampen_app]`, or if its
+/// `ui_dir` cannot be read.
+pub fn check_discovery(project_root: &Path, dampen_file: &Path) -> Option<ViewDiscovery> {
+    let main_rs = fs::read_to_string(project_root.join("src/main.rs")).ok()?;
+    let (ui_dir, exclude) = dampen_app_attributes(&main_rs)?;
+
+    let Ok(relative) = dampen_file.strip_prefix(project_root.join(&ui_dir)) else {
+        return Some(ViewDiscovery::OutsideUiDir { ui_dir });
+    };
+    let relative = relative.to_string_lossy().replace('\\', "/");
+
+    let excluded_by = exclude.into_iter().find(|pattern| {
+        let mut patterns = vec![pattern.clone()];
+        // Same expansion as the macro: `debug` also excludes `debug.dampen`
+        if !pattern.ends_with(".dampen") && !pattern.ends_with("/*") {
+            patterns.push(format!("{}.dampen", pattern));
+        }
+        patterns.iter().any(|p| {
+            glob::Pattern::new(p)
+                .map(|glob| glob.matches(&relative))
+                .unwrap_or(false)
+        })
+    });
+
+    Some(match excluded_by {
+        Some(pattern) => ViewDiscovery::Excluded { pattern },
+        None => ViewDiscovery::Discovered,
+    })
+}
+
+/// `ui_dir` and `exclude` patterns of the `#[dampen_app(...)]` attribute
+fn dampen_app_attributes(content: &str) -> Option<(String, Vec<String>)> {
+    let start = content.find("#[dampen_app(")?;
+    let attribute = &content[start..];
+    let attribute = &attribute[..attribute.find(")]")?];
+
+    let ui_dir = regex::Regex::new(r#"ui_dir\s*=\s*"([^"]*)""#)
+        .ok()?
+        .captures(attribute)?
+        .get(1)?
+        .as_str()
+        .to_string();
+
+    let exclude = regex::Regex::new(r#"exclude\s*=\s*\[([^\]]*)\]"#)
+        .ok()
+        .and_then(|re| re.captures(attribute))
+        .and_then(|captures| captures.get(1))
+        .map(|list| {
+            list.as_str()
+                .split(',')
+                .map(|item| item.trim().trim_matches('"').to_string())
+                .filter(|item| !item.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some((ui_dir, exclude))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn project(main_rs: &str) -> TempDir {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join("src/ui")).unwrap();
+        fs::write(temp.path().join("src/main.rs"), main_rs).unwrap();
+        temp
+    }
+
+    const MAIN_RS: &str = r#"
+#[dampen_app(
+    ui_dir = "src/ui",
+    message_type = "Message",
+    handler_variant = "Handler",
+    exclude = ["debug", "experimental/*"],
+)]
+struct App;
+"#;
+
+    #[test]
+    fn test_view_under_ui_dir_is_discovered() {
+        let temp = project(MAIN_RS);
+        let file = temp.path().join("src/ui/dashboard.dampen");
+        assert_eq!(
+            check_discovery(temp.path(), &file),
+            Some(ViewDiscovery::Discovered)
+        );
+    }
+
+    #[test]
+    fn test_excluded_and_outside_views() {
+        let temp = project(MAIN_RS);
+
+        let debug = temp.path().join("src/ui/debug.dampen");
+        assert_eq!(
+            check_discovery(temp.path(), &debug),
+            Some(ViewDiscovery::Excluded {
+                pattern: "debug".to_string()
+            })
+        );
+
+        let nested = temp.path().join("src/ui/experimental/chart.dampen");
+        assert_eq!(
+            check_discovery(temp.path(), &nested),
+            Some(ViewDiscovery::Excluded {
+                pattern: "experimental/*".to_string()
+            })
+        );
+
+        let outside = temp.path().join("src/views/chart.dampen");
+        assert_eq!(
+            check_discovery(temp.path(), &outside),
+            Some(ViewDiscovery::OutsideUiDir {
+                ui_dir: "src/ui".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_no_dampen_app() {
+        let temp = project("fn main() {}");
+        let file = temp.path().join("src/ui/dashboard.dampen");
+        assert_eq!(check_discovery(temp.path(), &file), None);
+    }
+}
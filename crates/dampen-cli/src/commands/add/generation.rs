@@ -1,5 +1,6 @@
 //! File generation logic for creating window files from templates.
 
+use crate::commands::add::discovery::{ViewDiscovery, check_discovery};
 use crate::commands::add::errors::GenerationError;
 use crate::commands::add::integration::add_module_to_mod_rs;
 use crate::commands::add::templates::{TemplateKind, WindowTemplate};
@@ -28,6 +29,9 @@ pub struct GeneratedFiles {
 
     /// Whether main.rs was modified to enable view switching
     pub view_switching_activated: bool,
+
+    /// Whether `#[dampen_app]` will discover the view (if the project uses it)
+    pub discovery: Option<ViewDiscovery>,
 }
 
 impl GeneratedFiles {
@@ -50,6 +54,10 @@ impl GeneratedFiles {
             message.push_str("\n  → Activated multi-view in src/main.rs");
         }
 
+        if let Some(discovery) = &self.discovery {
+            message.push_str(&discovery.message(&self.dampen_file));
+        }
+
         message.push_str("\n\nNext steps:");
 
         // Only show manual mod.rs step if integration was NOT performed
@@ -95,6 +103,46 @@ pub fn generate_window_files(
     target_path: &TargetPath,
     window_name: &WindowName,
     enable_integration: bool,
+) -> Result<GeneratedFiles, GenerationError> {
+    generate_files(
+        target_path,
+        window_name,
+        enable_integration,
+        (TemplateKind::RustModule, TemplateKind::DampenXml),
+    )
+}
+
+/// Generate view files (.rs and .dampen) for `dampen add view`
+///
+/// Unlike [`generate_window_files`], the module has no sample handler and the
+/// `.dampen` file holds a starter layout. With integration enabled, the result also tells whether the
+/// project's `#[dampen_app]` will discover the view.
+///
+/// # Errors
+///
+/// Same as [`generate_window_files`]
+pub fn generate_view_files(
+    target_path: &TargetPath,
+    view_name: &WindowName,
+    enable_integration: bool,
+) -> Result<GeneratedFiles, GenerationError> {
+    let mut generated = generate_files(
+        target_path,
+        view_name,
+        enable_integration,
+        (TemplateKind::ViewModule, TemplateKind::ViewXml),
+    )?;
+    if enable_integration {
+        generated.discovery = check_discovery(&target_path.project_root, &generated.dampen_file);
+    }
+    Ok(generated)
+}
+
+fn generate_files(
+    target_path: &TargetPath,
+    window_name: &WindowName,
+    enable_integration: bool,
+    (rust_kind, dampen_kind): (TemplateKind, TemplateKind),
 ) -> Result<GeneratedFiles, GenerationError> {
     // 1. Check if files already exist (prevent overwriting)
     let rust_file = target_path.file_path(&window_name.snake, "rs");
@@ -121,8 +169,8 @@ pub fn generate_window_files(
     })?;
 
     // 3. Load and render templates
-    let rust_template = WindowTemplate::load(TemplateKind::RustModule);
-    let dampen_template = WindowTemplate::load(TemplateKind::DampenXml);
+    let rust_template = WindowTemplate::load(rust_kind);
+    let dampen_template = WindowTemplate::load(dampen_kind);
 
     let variants = window_name.to_variants();
     let rust_content = rust_template.render(&variants);
@@ -204,6 +252,7 @@ pub fn generate_window_files(
         target_dir: target_path.absolute.clone(),
         updated_mod_file,
         view_switching_activated,
+        discovery: None,
     })
 }
 
@@ -430,6 +479,7 @@ mod tests {
             target_dir: target_path.absolute.clone(),
             updated_mod_file: None,
             view_switching_activated: false,
+            discovery: None,
         };
 
         let message = generated.success_message();
@@ -455,6 +505,7 @@ mod tests {
             target_dir: target_path.absolute.clone(),
             updated_mod_file: Some(project_root.join("src/ui/mod.rs")),
             view_switching_activated: false,
+            discovery: None,
         };
 
         let message = generated.success_message();
@@ -503,6 +554,37 @@ mod tests {
         assert!(mod_content.contains("pub mod settings;"));
     }
 
+    #[test]
+    fn test_generate_view_files_with_dampen_app() {
+        let temp = TempDir::new().unwrap();
+        let project_root = temp.path();
+        let ui_dir = project_root.join("src/ui");
+        fs::create_dir_all(&ui_dir).unwrap();
+        fs::write(ui_dir.join("mod.rs"), "pub mod window;\n").unwrap();
+        fs::write(
+            project_root.join("src/main.rs"),
+            "#[dampen_app(ui_dir = \"src/ui\", message_type = \"Message\")]\nstruct App;\n",
+        )
+        .unwrap();
+
+        let target_path = TargetPath::resolve(project_root, Some("src/ui")).unwrap();
+        let view_name = WindowName::new("dashboard").unwrap();
+        let generated = generate_view_files(&target_path, &view_name, true).unwrap();
+
+        let rust_content = fs::read_to_string(&generated.rust_file).unwrap();
+        assert!(rust_content.contains("#[dampen_ui(\"dashboard.dampen\")]"));
+        assert!(!rust_content.contains("inventory_handlers!"));
+        assert!(!rust_content.contains("on_action"));
+
+        let dampen_content = fs::read_to_string(&generated.dampen_file).unwrap();
+        assert!(dampen_content.contains("Dashboard"));
+
+        let mod_content = fs::read_to_string(ui_dir.join("mod.rs")).unwrap();
+        assert!(mod_content.contains("pub mod dashboard;"));
+        assert_eq!(generated.discovery, Some(ViewDiscovery::Discovered));
+        assert!(generated.success_message().contains("Auto-discovered"));
+    }
+
     #[test]
     fn test_generate_files_integration_disabled() {
         let temp = TempDir::new().unwrap();
@@ -25,6 +25,20 @@
 //! - `src/ui/settings.rs` - Rust module with Model and handlers
 //! - `src/ui/settings.dampen` - XML UI definition
 //!
+//! ## Views
+//!
+//! `dampen add view` scaffolds a view to fill in, rather than a sample window:
+//!
+//! ```bash
+//! dampen add view dashboard
+//! ```
+//!
+//! The module has a `Model` deriving `UiModel` and no handler, and the
+//! `.dampen` file a starter layout. If
+//! `src/main.rs` uses `#[dampen_app]`, the command also reports whether the
+//! view is under its `ui_dir` and outside its `exclude` patterns, i.e. whether
+//! it will be discovered.
+//!
 //! ## Custom Output Directory
 //!
 //! Specify a custom output directory with `--path`:
@@ -96,8 +110,9 @@
 //! # → Generates: order_form.rs, order_form.dampen
 //! ```
 
-use clap::Args;
+use clap::{Args, Subcommand};
 
+pub mod discovery;
 pub mod errors;
 pub mod generation;
 pub mod integration;
@@ -115,7 +130,8 @@ pub use templates::{TemplateKind, WindowNameVariants, WindowTemplate};
 pub use validation::{ProjectInfo, TargetPath, WindowName};
 
 // Export generation types (Phase 5)
-pub use generation::{GeneratedFiles, generate_window_files};
+pub use discovery::ViewDiscovery;
+pub use generation::{GeneratedFiles, generate_view_files, generate_window_files};
 
 // Types will be exported as they're implemented in later phases
 // pub use validation::{TargetPath};
@@ -134,10 +150,16 @@ pub use generation::{GeneratedFiles, generate_window_files};
 ///
 /// # Fields
 ///
+/// - `command`: What to add, e.g. `view <name>`
 /// - `ui`: Window name (converted to snake_case for filenames)
 /// - `path`: Custom output directory (relative to project root)
 #[derive(Debug, Args)]
+#[command(args_conflicts_with_subcommands = true)]
 pub struct AddArgs {
+    /// What to add
+    #[command(subcommand)]
+    pub command: Option<AddCommand>,
+
     /// Add a new UI window
     ///
     /// The window name will be converted to snake_case for filenames.
@@ -160,7 +182,7 @@ pub struct AddArgs {
     /// Security:
     ///   - Must be relative (absolute paths rejected)
     ///   - Must be within project (cannot escape via ..)
-    #[arg(long)]
+    #[arg(long, global = true)]
     pub path: Option<String>,
 
     /// Disable automatic integration (do not update mod.rs)
@@ -171,10 +193,25 @@ pub struct AddArgs {
     ///
     /// Example:
     ///   dampen add --ui settings --no-integrate
-    #[arg(long)]
+    #[arg(long, global = true)]
     pub no_integrate: bool,
 }
 
+/// Components that `dampen add` can scaffold.
+#[derive(Debug, Subcommand)]
+pub enum AddCommand {
+    /// Add a view: a `.dampen` file with a starter layout and the Rust module
+    /// loading it, with a `UiModel` and no handlers yet
+    ///
+    /// Examples:
+    ///   dampen add view dashboard
+    ///   dampen add view reports --path "src/ui/admin"
+    View {
+        /// View name (converted to snake_case for filenames)
+        name: String,
+    },
+}
+
 /// Execute the add command.
 ///
 /// This generates UI window files based on validated inputs.
@@ -202,6 +239,7 @@ pub struct AddArgs {
 /// use dampen_cli::commands::add::{AddArgs, execute};
 ///
 /// let args = AddArgs {
+///     command: None,
 ///     ui: Some("settings".to_string()),
 ///     path: None,
 ///     no_integrate: false,
@@ -224,10 +262,16 @@ pub fn execute(args: &AddArgs) -> Result<(), String> {
     }
 
     // T074: Validate window name
-    let window_name_str = args
-        .ui
-        .as_ref()
-        .ok_or_else(|| "Error: Missing window name\nhelp: Use --ui <name>".to_string())?;
+    let (window_name_str, is_view) = match (&args.command, &args.ui) {
+        (Some(AddCommand::View { name }), _) => (name, true),
+        (None, Some(ui)) => (ui, false),
+        (None, None) => {
+            return Err(
+                "Error: Missing window name\nhelp: Use --ui <name>, or `dampen add view <name>`"
+                    .to_string(),
+            );
+        }
+    };
 
     let window_name = WindowName::new(window_name_str).map_err(|e| e.to_string())?;
 
@@ -237,8 +281,12 @@ pub fn execute(args: &AddArgs) -> Result<(), String> {
 
     // T076: Generate files
     let enable_integration = !args.no_integrate;
-    let generated = generate_window_files(&target_path, &window_name, enable_integration)
-        .map_err(|e| e.to_string())?;
+    let generated = if is_view {
+        generate_view_files(&target_path, &window_name, enable_integration)
+    } else {
+        generate_window_files(&target_path, &window_name, enable_integration)
+    }
+    .map_err(|e| e.to_string())?;
 
     // T077: Print success message
     println!("{}", generated.success_message());
@@ -7,6 +7,10 @@ pub enum TemplateKind {
     RustModule,
     /// Dampen XML template (.dampen file)
     DampenXml,
+    /// Rust module template of a view, without sample handlers (.rs file)
+    ViewModule,
+    /// Dampen XML template of a view, with a starter layout (.dampen file)
+    ViewXml,
 }
 
 /// A window file template with placeholder replacement.
@@ -51,6 +55,8 @@ impl WindowTemplate {
             TemplateKind::DampenXml => {
                 include_str!("../../../templates/add/window.dampen.template")
             }
+            TemplateKind::ViewModule => include_str!("../../../templates/add/view.rs.template"),
+            TemplateKind::ViewXml => include_str!("../../../templates/add/view.dampen.template"),
         };
 
         Self {
@@ -114,6 +120,22 @@ mod tests {
         assert!(template.content.contains("{{WINDOW_NAME_TITLE}}"));
     }
 
+    #[test]
+    fn test_template_load_view() {
+        let module = WindowTemplate::load(TemplateKind::ViewModule);
+        assert!(module.content.contains("derive(Default, UiModel"));
+        assert!(!module.content.contains("inventory_handlers!"));
+        assert!(
+            module
+                .content
+                .contains("#[dampen_ui(\"{{WINDOW_NAME}}.dampen\")]")
+        );
+
+        let xml = WindowTemplate::load(TemplateKind::ViewXml);
+        assert!(xml.content.contains("{{WINDOW_NAME_TITLE}}"));
+        assert!(xml.content.contains("{status}"));
+    }
+
     #[test]
     fn test_template_render_snake_case() {
         let template = WindowTemplate {
@@ -0,0 +1,307 @@
+#![allow(clippy::print_stderr, clippy::print_stdout)]
+
+//! Bench command - runs benchmarks and reports regressions
+//!
+//! This command wraps `cargo bench` for the criterion benchmarks of a project
+//! (or of the Dampen workspace itself). Results are compared with a baseline
+//! stored by a previous `dampen bench --save-baseline` run, and benchmarks whose
+//! mean time grew by more than the threshold are reported as regressions.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use walkdir::WalkDir;
+
+/// Name of the baseline used when `--baseline` is not given
+const DEFAULT_BASELINE: &str = "dampen";
+
+/// Directory criterion writes the results of the last run to
+const CURRENT_RUN: &str = "new";
+
+/// Bench command arguments
+#[derive(clap::Args)]
+pub struct BenchArgs {
+    /// Package to benchmark (if workspace has multiple packages)
+    #[arg(short, long)]
+    package: Option<String>,
+
+    /// Benchmark name filter (runs benchmarks matching this string)
+    #[arg(value_name = "BENCHNAME")]
+    bench_filter: Option<String>,
+
+    /// Store the results as the baseline instead of comparing with it
+    #[arg(long)]
+    save_baseline: bool,
+
+    /// Name of the stored baseline
+    #[arg(long, default_value = DEFAULT_BASELINE)]
+    baseline: String,
+
+    /// Slowdown, in percent of the baseline mean, reported as a regression
+    #[arg(long, default_value_t = 5.0)]
+    threshold: f64,
+
+    /// Arguments to pass to the benchmark binaries
+    #[arg(last = true)]
+    bench_args: Vec<String>,
+
+    /// Verbose output
+    #[arg(short, long)]
+    verbose: bool,
+}
+
+/// Execute the bench command
+///
+/// Runs the criterion benchmarks with cargo bench, then compares them with the
+/// stored baseline.
+///
+/// # Examples
+///
+/// ```bash
+/// # Record the baseline, e.g. on the main branch
+/// dampen bench --save-baseline
+///
+/// # Compare the working tree with it
+/// dampen bench
+///
+/// # Only the parser benchmarks, failing on a 10% slowdown
+/// dampen bench parser --threshold 10
+/// ```
+pub fn execute(args: &BenchArgs) -> Result<(), String> {
+    if !Path::new("Cargo.toml").exists() {
+        return Err("Cargo.toml not found. Are you in a Rust project directory?".to_string());
+    }
+
+    let mut cmd = Command::new("cargo");
+    cmd.arg("bench");
+
+    if let Some(ref package) = args.package {
+        cmd.arg("-p").arg(package);
+    }
+
+    if args.verbose {
+        cmd.arg("--verbose");
+    }
+
+    cmd.arg("--");
+    if let Some(ref filter) = args.bench_filter {
+        cmd.arg(filter);
+    }
+    if args.save_baseline {
+        cmd.arg("--save-baseline").arg(&args.baseline);
+    }
+    cmd.args(&args.bench_args);
+
+    if args.verbose {
+        eprintln!("Executing: {:?}", cmd);
+    }
+
+    let status = cmd
+        .status()
+        .map_err(|e| format!("Failed to execute cargo: {}", e))?;
+    if !status.success() {
+        return Err("Benchmarks failed".to_string());
+    }
+
+    let criterion_dir = target_dir().join("criterion");
+    if args.save_baseline {
+        println!(
+            "Saved baseline '{}' in {}",
+            args.baseline,
+            criterion_dir.display()
+        );
+        return Ok(());
+    }
+
+    let comparisons = compare(&criterion_dir, &args.baseline)?;
+    if comparisons.is_empty() {
+        println!(
+            "No baseline '{}' to compare with, record one with `dampen bench --save-baseline`",
+            args.baseline
+        );
+        return Ok(());
+    }
+
+    let regressions = report(&comparisons, args.threshold);
+    if regressions > 0 {
+        return Err(format!(
+            "{} benchmark(s) regressed by more than {}% against baseline '{}'",
+            regressions, args.threshold, args.baseline
+        ));
+    }
+
+    Ok(())
+}
+
+/// Mean times of one benchmark, in nanoseconds
+#[derive(Debug, Clone, PartialEq)]
+struct Comparison {
+    name: String,
+    baseline: f64,
+    current: f64,
+}
+
+impl Comparison {
+    /// Change of the mean time, in percent of the baseline
+    fn change(&self) -> f64 {
+        if self.baseline == 0.0 {
+            0.0
+        } else {
+            (self.current - self.baseline) * 100.0 / self.baseline
+        }
+    }
+}
+
+fn target_dir() -> PathBuf {
+    std::env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("target"))
+}
+
+/// Pair the last run of each benchmark under `criterion_dir` with `baseline`
+///
+/// Criterion stores each benchmark in its own directory, holding one
+/// `estimates.json` per run: `new` for the last run and one per saved baseline.
+/// Benchmarks without the baseline are skipped.
+fn compare(criterion_dir: &Path, baseline: &str) -> Result<Vec<Comparison>, String> {
+    let mut comparisons = Vec::new();
+
+    for entry in WalkDir::new(criterion_dir)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_dir() && entry.file_name() == CURRENT_RUN)
+    {
+        let Some(bench_dir) = entry.path().parent() else {
+            continue;
+        };
+        let baseline_file = bench_dir.join(baseline).join("estimates.json");
+        if !baseline_file.exists() {
+            continue;
+        }
+
+        let name = bench_dir
+            .strip_prefix(criterion_dir)
+            .unwrap_or(bench_dir)
+            .to_string_lossy()
+            .replace('\\', "/");
+        comparisons.push(Comparison {
+            name,
+            baseline: mean_estimate(&baseline_file)?,
+            current: mean_estimate(&entry.path().join("estimates.json"))?,
+        });
+    }
+
+    Ok(comparisons)
+}
+
+/// Mean time of a criterion `estimates.json`, in nanoseconds
+fn mean_estimate(path: &Path) -> Result<f64, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let estimates: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?;
+
+    estimates["mean"]["point_estimate"]
+        .as_f64()
+        .ok_or_else(|| format!("No mean estimate in {}", path.display()))
+}
+
+/// Print the comparisons and return the number of regressions
+fn report(comparisons: &[Comparison], threshold: f64) -> usize {
+    let width = comparisons
+        .iter()
+        .map(|comparison| comparison.name.len())
+        .max()
+        .unwrap_or(0);
+    let mut regressions = 0;
+
+    println!();
+    println!("Benchmark comparison:");
+    for comparison in comparisons {
+        let change = comparison.change();
+        let marker = if change > threshold {
+            regressions += 1;
+            "  REGRESSION"
+        } else if change < -threshold {
+            "  improved"
+        } else {
+            ""
+        };
+        println!(
+            "  {:<width$}  {:>12} -> {:>12}  {:+.1}%{}",
+            comparison.name,
+            format_time(comparison.baseline),
+            format_time(comparison.current),
+            change,
+            marker,
+        );
+    }
+
+    regressions
+}
+
+fn format_time(nanos: f64) -> String {
+    if nanos >= 1_000_000_000.0 {
+        format!("{:.2} s", nanos / 1_000_000_000.0)
+    } else if nanos >= 1_000_000.0 {
+        format!("{:.2} ms", nanos / 1_000_000.0)
+    } else if nanos >= 1_000.0 {
+        format!("{:.2} µs", nanos / 1_000.0)
+    } else {
+        format!("{:.1} ns", nanos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_estimate(dir: &Path, mean: f64) -> Result<(), String> {
+        std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        std::fs::write(
+            dir.join("estimates.json"),
+            format!(r#"{{"mean":{{"point_estimate":{}}}}}"#, mean),
+        )
+        .map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn test_compare_pairs_last_run_with_baseline() -> Result<(), String> {
+        let dir = tempfile::tempdir().map_err(|e| e.to_string())?;
+        let criterion = dir.path();
+        write_estimate(&criterion.join("parser/parse/small/new"), 1200.0)?;
+        write_estimate(&criterion.join("parser/parse/small/dampen"), 1000.0)?;
+        write_estimate(&criterion.join("parser/parse/large/new"), 900.0)?;
+        write_estimate(&criterion.join("parser/parse/large/dampen"), 1000.0)?;
+        // Never stored as a baseline
+        write_estimate(&criterion.join("expressions/evaluate/field/new"), 50.0)?;
+
+        let comparisons = compare(criterion, "dampen")?;
+        assert_eq!(
+            comparisons,
+            vec![
+                Comparison {
+                    name: "parser/parse/large".to_string(),
+                    baseline: 1000.0,
+                    current: 900.0,
+                },
+                Comparison {
+                    name: "parser/parse/small".to_string(),
+                    baseline: 1000.0,
+                    current: 1200.0,
+                },
+            ]
+        );
+        assert_eq!(report(&comparisons, 5.0), 1);
+        assert_eq!(report(&comparisons, 25.0), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_time_picks_unit() {
+        assert_eq!(format_time(12.34), "12.3 ns");
+        assert_eq!(format_time(1_500.0), "1.50 µs");
+        assert_eq!(format_time(2_500_000.0), "2.50 ms");
+    }
+}
@@ -2,7 +2,9 @@
 
 //! Build command - generates production Rust code from Dampen UI files
 
-use std::path::Path;
+use crate::commands::profile::Profile;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
 
 /// Build command arguments
 #[derive(clap::Args)]
@@ -38,6 +40,10 @@ pub struct BuildArgs {
     /// Build in release mode with codegen
     #[arg(long)]
     release: bool,
+
+    /// Environment profile: values of `.env.<PROFILE>` bound as `{env.config.*}`
+    #[arg(long, value_name = "PROFILE")]
+    profile: Option<String>,
 }
 
 /// Execute the build command
@@ -65,6 +71,9 @@ pub struct BuildArgs {
 ///
 /// # Enable additional features
 /// dampen build --features tokio,logging
+///
+/// # Compile the values of .env.prod into the generated code
+/// dampen build --release --profile prod
 /// ```
 pub fn execute(args: &BuildArgs) -> Result<(), String> {
     execute_production_build(args)
@@ -93,6 +102,10 @@ fn execute_production_build(args: &BuildArgs) -> Result<(), String> {
         return Err(format!("Pre-flight check failed: {}", e));
     }
 
+    if args.release {
+        verify_assets(args.package.as_deref())?;
+    }
+
     let mode = if args.release {
         "codegen"
     } else {
@@ -152,6 +165,18 @@ fn execute_production_build(args: &BuildArgs) -> Result<(), String> {
 
     cmd.arg("--features").arg(all_features.join(","));
 
+    if let Some(ref name) = args.profile {
+        let profile = Profile::load(Path::new("."), name)?;
+        if args.verbose {
+            eprintln!(
+                "Environment profile: {} ({} values)",
+                profile.name,
+                profile.values.len()
+            );
+        }
+        profile.apply(&mut cmd);
+    }
+
     if args.verbose {
         let features_str = all_features.join(",");
         let cargo_cmd = if args.release {
@@ -221,6 +246,8 @@ pub fn execute_release_build(
         return Err(format!("Pre-flight check failed: {}", e));
     }
 
+    verify_assets(package.as_deref())?;
+
     let mode = "codegen";
 
     if verbose {
@@ -293,3 +320,47 @@ pub fn execute_release_build(
 
     Ok(())
 }
+
+/// Check that the files loaded by the `<image>` and `<svg>` widgets of the
+/// views exist, as codegen embeds them
+fn verify_assets(package: Option<&str>) -> Result<(), String> {
+    let ui_dir = match package {
+        Some(pkg) => crate::commands::check::resolve_package_ui_path(pkg),
+        None => Some(PathBuf::from("src/ui")),
+    };
+    let Some(ui_dir) = ui_dir.filter(|dir| dir.exists()) else {
+        return Ok(());
+    };
+    let project_dir = ui_dir
+        .ancestors()
+        .find(|dir| dir.join("Cargo.toml").exists())
+        .unwrap_or(Path::new("."));
+
+    let mut missing = Vec::new();
+    let files = WalkDir::new(&ui_dir)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "dampen"));
+    for entry in files {
+        // Invalid views were reported by the pre-flight checks
+        let Ok(content) = std::fs::read_to_string(entry.path()) else {
+            continue;
+        };
+        let Ok(document) = dampen_core::parse(&content) else {
+            continue;
+        };
+        for path in dampen_core::assets::missing(&document, project_dir) {
+            missing.push(format!("  {}: {}", entry.path().display(), path));
+        }
+    }
+
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Asset check failed, files referenced by views not found:\n{}",
+            missing.join("\n")
+        ))
+    }
+}
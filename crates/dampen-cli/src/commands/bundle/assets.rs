@@ -0,0 +1,131 @@
+//! Assets embedded in bundles
+//!
+//! A bundle embeds the theme files of the project (`.dampen` files in a
+//! `theme` directory) and the files loaded by its `<image>` and `<svg>`
+//! widgets (see [`dampen_core::assets`]). Bound paths (`src="{avatar}"`), URLs
+//! and absolute paths are not embedded.
+//!
+//! Widgets load paths relative to the working directory of the application,
+//! so each asset keeps the path written in the view. It is looked up from the
+//! project directory and its parents, as views of workspace members often use
+//! paths relative to the workspace root.
+
+use dampen_core::assets::{locate, referenced_paths};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// File embedded in a bundle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Asset {
+    /// File to copy
+    pub source: PathBuf,
+    /// Path of the file in the bundle, relative to the working directory
+    pub path: PathBuf,
+}
+
+/// Collect the assets of the project in `project_dir`
+///
+/// Returns the assets, sorted by path, and the paths referenced by views that
+/// don't exist.
+pub fn collect(project_dir: &Path) -> (Vec<Asset>, Vec<PathBuf>) {
+    let mut assets = BTreeMap::new();
+    let mut missing = Vec::new();
+
+    let files = WalkDir::new(project_dir.join("src"))
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "dampen"));
+    for entry in files {
+        let file = entry.path();
+        if is_theme_file(file) {
+            let path = file.strip_prefix(project_dir).unwrap_or(file).to_path_buf();
+            assets.insert(
+                path.clone(),
+                Asset {
+                    source: file.to_path_buf(),
+                    path,
+                },
+            );
+            continue;
+        }
+
+        // Invalid views are reported by `dampen check`, not by the bundle
+        let Ok(content) = std::fs::read_to_string(file) else {
+            continue;
+        };
+        let Ok(document) = dampen_core::parse(&content) else {
+            continue;
+        };
+
+        for path in referenced_paths(&document.root) {
+            let relative = PathBuf::from(&path);
+            if relative.is_absolute() || assets.contains_key(&relative) {
+                continue;
+            }
+            match locate(&path, project_dir) {
+                Some(source) => {
+                    assets.insert(
+                        relative.clone(),
+                        Asset {
+                            source,
+                            path: relative,
+                        },
+                    );
+                }
+                None if !missing.contains(&relative) => missing.push(relative),
+                None => {}
+            }
+        }
+    }
+
+    (assets.into_values().collect(), missing)
+}
+
+fn is_theme_file(path: &Path) -> bool {
+    path.parent()
+        .and_then(|dir| dir.file_name())
+        .is_some_and(|dir| dir == "theme")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collect_assets() {
+        let dir = tempfile::tempdir().unwrap();
+        let project = dir.path();
+        std::fs::create_dir_all(project.join("src/ui/theme")).unwrap();
+        std::fs::create_dir_all(project.join("assets")).unwrap();
+        std::fs::write(project.join("assets/logo.png"), b"png").unwrap();
+        std::fs::write(project.join("assets/icon.svg"), b"<svg/>").unwrap();
+        std::fs::write(project.join("src/ui/theme/theme.dampen"), "<dampen/>").unwrap();
+        std::fs::write(
+            project.join("src/ui/window.dampen"),
+            r#"<dampen version="1.1">
+                <column>
+                    <image src="assets/logo.png" mobile-src="assets/logo.png" />
+                    <image src="{avatar}" />
+                    <image src="https://example.com/banner.png" />
+                    <svg path="assets/icon.svg" />
+                    <image src="assets/missing.png" />
+                </column>
+            </dampen>"#,
+        )
+        .unwrap();
+
+        let (assets, missing) = collect(project);
+        let paths: Vec<&Path> = assets.iter().map(|asset| asset.path.as_path()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                Path::new("assets/icon.svg"),
+                Path::new("assets/logo.png"),
+                Path::new("src/ui/theme/theme.dampen"),
+            ]
+        );
+        assert_eq!(assets[1].source, project.join("assets/logo.png"));
+        assert_eq!(missing, vec![PathBuf::from("assets/missing.png")]);
+    }
+}
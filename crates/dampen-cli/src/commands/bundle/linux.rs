@@ -0,0 +1,193 @@
+//! Linux bundles: Debian packages and AppImages
+//!
+//! Both install the binary and the assets in one directory (`usr/lib/<name>`)
+//! and start the application from it, with a desktop entry for application
+//! menus.
+
+use super::{BundleContext, clean_dir, copy_file, run_tool, write_file, write_script};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Build `<name>_<version>_<arch>.deb` with `dpkg-deb`
+pub fn bundle_deb(context: &BundleContext) -> Result<PathBuf, String> {
+    let arch = deb_arch(std::env::consts::ARCH);
+    let package = format!("{}_{}_{}", context.name, context.version, arch);
+    let root = context.out_dir.join("deb").join(&package);
+    clean_dir(&root)?;
+
+    let lib_dir = root.join("usr/lib").join(&context.name);
+    context.install_into(&lib_dir, &lib_dir)?;
+    write_script(
+        &root.join("usr/bin").join(&context.name),
+        &launcher(
+            &format!("/usr/lib/{}", context.name),
+            &context.binary_name(),
+        ),
+    )?;
+    write_file(
+        &root
+            .join("usr/share/applications")
+            .join(format!("{}.desktop", context.name)),
+        &desktop_entry(context),
+    )?;
+    if let Some(icon) = png_icon(context) {
+        copy_file(
+            icon,
+            &root
+                .join("usr/share/icons/hicolor/256x256/apps")
+                .join(format!("{}.png", context.name)),
+        )?;
+    }
+    write_file(&root.join("DEBIAN/control"), &control_file(context, arch))?;
+
+    let deb = context.out_dir.join(format!("{}.deb", package));
+    run_tool(
+        Command::new("dpkg-deb")
+            .arg("--build")
+            .arg("--root-owner-group")
+            .arg(&root)
+            .arg(&deb),
+        "Install it with your package manager (e.g. 'apt install dpkg').",
+    )?;
+    Ok(deb)
+}
+
+/// Build `<Name>-<version>-<arch>.AppImage` with `appimagetool`
+pub fn bundle_appimage(context: &BundleContext) -> Result<PathBuf, String> {
+    let Some(icon) = png_icon(context) else {
+        return Err("AppImages require a PNG icon: pass --icon or add assets/icon.png".to_string());
+    };
+
+    let app_dir = context
+        .out_dir
+        .join("appimage")
+        .join(format!("{}.AppDir", context.name));
+    clean_dir(&app_dir)?;
+
+    let lib_dir = app_dir.join("usr/lib").join(&context.name);
+    context.install_into(&lib_dir, &lib_dir)?;
+    write_script(
+        &app_dir.join("AppRun"),
+        &launcher(
+            &format!(
+                "$(dirname \"$(readlink -f \"$0\")\")/usr/lib/{}",
+                context.name
+            ),
+            &context.binary_name(),
+        ),
+    )?;
+    write_file(
+        &app_dir.join(format!("{}.desktop", context.name)),
+        &desktop_entry(context),
+    )?;
+    copy_file(icon, &app_dir.join(format!("{}.png", context.name)))?;
+
+    let arch = std::env::consts::ARCH;
+    let appimage = context.out_dir.join(format!(
+        "{}-{}-{}.AppImage",
+        context.display_name.replace(' ', "_"),
+        context.version,
+        arch
+    ));
+    run_tool(
+        Command::new("appimagetool")
+            .env("ARCH", arch)
+            .arg(&app_dir)
+            .arg(&appimage),
+        "Download it from https://github.com/AppImage/appimagetool/releases and add it to your PATH.",
+    )?;
+    Ok(appimage)
+}
+
+/// `DEBIAN/control` of the package
+pub fn control_file(context: &BundleContext, arch: &str) -> String {
+    let maintainer = context
+        .authors
+        .first()
+        .map(String::as_str)
+        .unwrap_or("Unknown <unknown@example.com>");
+    let description = if context.description.is_empty() {
+        &context.display_name
+    } else {
+        &context.description
+    };
+    format!(
+        "Package: {}\nVersion: {}\nArchitecture: {}\nMaintainer: {}\nSection: misc\nPriority: optional\nDescription: {}\n",
+        context.name.replace('_', "-").to_lowercase(),
+        context.version,
+        arch,
+        maintainer,
+        description
+    )
+}
+
+/// Desktop entry listing the application in application menus
+pub fn desktop_entry(context: &BundleContext) -> String {
+    let mut entry = format!(
+        "[Desktop Entry]\nType=Application\nName={}\nExec={}\nIcon={}\nTerminal=false\nCategories=Utility;\n",
+        context.display_name, context.name, context.name
+    );
+    if !context.description.is_empty() {
+        entry.push_str(&format!("Comment={}\n", context.description));
+    }
+    entry
+}
+
+/// Debian name of a Rust target architecture
+pub fn deb_arch(arch: &str) -> &str {
+    match arch {
+        "x86_64" => "amd64",
+        "x86" => "i386",
+        "aarch64" => "arm64",
+        "arm" => "armhf",
+        "powerpc64" => "ppc64el",
+        arch => arch,
+    }
+}
+
+/// Script starting the binary from `dir`, where the assets are
+fn launcher(dir: &str, binary: &str) -> String {
+    format!("#!/bin/sh\ncd \"{}\" && exec ./{} \"$@\"\n", dir, binary)
+}
+
+fn png_icon(context: &BundleContext) -> Option<&Path> {
+    context
+        .icon
+        .as_deref()
+        .filter(|icon| icon.extension().is_some_and(|ext| ext == "png"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context() -> BundleContext {
+        BundleContext {
+            name: "todo_app".to_string(),
+            display_name: "Todo App".to_string(),
+            version: "1.2.0".to_string(),
+            description: "Todo list".to_string(),
+            authors: vec!["Jane Doe <jane@example.com>".to_string()],
+            identifier: "com.example.todo".to_string(),
+            binary: PathBuf::from("target/release/todo_app"),
+            assets: Vec::new(),
+            icon: None,
+            out_dir: PathBuf::from("target/bundle"),
+        }
+    }
+
+    #[test]
+    fn test_deb_control_and_desktop_entry() {
+        let control = control_file(&context(), deb_arch("x86_64"));
+        assert!(control.contains("Package: todo-app\n"));
+        assert!(control.contains("Architecture: amd64\n"));
+        assert!(control.contains("Maintainer: Jane Doe <jane@example.com>\n"));
+        assert!(control.ends_with("Description: Todo list\n"));
+
+        let entry = desktop_entry(&context());
+        assert!(entry.starts_with("[Desktop Entry]\n"));
+        assert!(entry.contains("Name=Todo App\n"));
+        assert!(entry.contains("Exec=todo_app\n"));
+        assert!(entry.contains("Comment=Todo list\n"));
+    }
+}
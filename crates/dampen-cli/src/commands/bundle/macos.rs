@@ -0,0 +1,150 @@
+//! macOS bundles: application bundles and disk images
+//!
+//! The binary and the assets go in `Contents/Resources`, and the executable
+//! of the bundle is a launcher starting the binary from there.
+
+use super::{BundleContext, clean_dir, copy_file, run_tool, write_file, write_script};
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Build `<Name>.app`
+pub fn bundle_app(context: &BundleContext) -> Result<PathBuf, String> {
+    let app = context
+        .out_dir
+        .join("macos")
+        .join(format!("{}.app", context.display_name));
+    clean_dir(&app)?;
+
+    let resources = app.join("Contents/Resources");
+    context.install_into(&resources, &resources)?;
+    write_script(
+        &app.join("Contents/MacOS").join(&context.name),
+        &format!(
+            "#!/bin/sh\ncd \"$(dirname \"$0\")/../Resources\" && exec ./{} \"$@\"\n",
+            context.binary_name()
+        ),
+    )?;
+
+    let has_icon = match &context.icon {
+        Some(icon) if icon.extension().is_some_and(|ext| ext == "icns") => {
+            copy_file(icon, &resources.join("AppIcon.icns"))?;
+            true
+        }
+        Some(icon) => {
+            run_tool(
+                Command::new("sips")
+                    .args(["-s", "format", "icns"])
+                    .arg(icon)
+                    .arg("--out")
+                    .arg(resources.join("AppIcon.icns")),
+                "Convert the icon to .icns and pass it with --icon.",
+            )?;
+            true
+        }
+        None => false,
+    };
+    write_file(
+        &app.join("Contents/Info.plist"),
+        &info_plist(context, has_icon),
+    )?;
+
+    Ok(app)
+}
+
+/// Build `<Name>-<version>.dmg` containing the application bundle
+pub fn bundle_dmg(context: &BundleContext) -> Result<PathBuf, String> {
+    let app = bundle_app(context)?;
+    let dmg = context
+        .out_dir
+        .join(format!("{}-{}.dmg", context.display_name, context.version));
+    run_tool(
+        Command::new("hdiutil")
+            .arg("create")
+            .arg("-volname")
+            .arg(&context.display_name)
+            .arg("-srcfolder")
+            .arg(&app)
+            .args(["-ov", "-format", "UDZO"])
+            .arg(&dmg),
+        "Disk images can only be created on macOS.",
+    )?;
+    Ok(dmg)
+}
+
+/// `Info.plist` of the application bundle
+pub fn info_plist(context: &BundleContext, has_icon: bool) -> String {
+    let icon = if has_icon {
+        "    <key>CFBundleIconFile</key>\n    <string>AppIcon</string>\n"
+    } else {
+        ""
+    };
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>CFBundleName</key>
+    <string>{name}</string>
+    <key>CFBundleDisplayName</key>
+    <string>{name}</string>
+    <key>CFBundleIdentifier</key>
+    <string>{identifier}</string>
+    <key>CFBundleVersion</key>
+    <string>{version}</string>
+    <key>CFBundleShortVersionString</key>
+    <string>{version}</string>
+    <key>CFBundleExecutable</key>
+    <string>{executable}</string>
+    <key>CFBundlePackageType</key>
+    <string>APPL</string>
+{icon}    <key>NSHighResolutionCapable</key>
+    <true/>
+</dict>
+</plist>
+"#,
+        name = escape_xml(&context.display_name),
+        identifier = escape_xml(&context.identifier),
+        version = escape_xml(&context.version),
+        executable = escape_xml(&context.name),
+        icon = icon,
+    )
+}
+
+/// Escape the XML special characters of `value`
+pub(super) fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_info_plist() {
+        let context = BundleContext {
+            name: "todo-app".to_string(),
+            display_name: "Todo & Co".to_string(),
+            version: "1.2.0".to_string(),
+            description: String::new(),
+            authors: Vec::new(),
+            identifier: "com.example.todo".to_string(),
+            binary: PathBuf::from("target/release/todo-app"),
+            assets: Vec::new(),
+            icon: None,
+            out_dir: PathBuf::from("target/bundle"),
+        };
+
+        let plist = info_plist(&context, true);
+        assert!(plist.contains("<string>Todo &amp; Co</string>"));
+        assert!(
+            plist.contains("<key>CFBundleIdentifier</key>\n    <string>com.example.todo</string>")
+        );
+        assert!(plist.contains("<key>CFBundleExecutable</key>\n    <string>todo-app</string>"));
+        assert!(plist.contains("<string>AppIcon</string>"));
+        assert!(!info_plist(&context, false).contains("CFBundleIconFile"));
+    }
+}
@@ -0,0 +1,417 @@
+#![allow(clippy::print_stderr, clippy::print_stdout)]
+
+//! Bundle command - packages a release build for distribution
+//!
+//! `dampen bundle` builds the application in codegen mode, then lays it out
+//! with its assets as the packages of each platform:
+//!
+//! | Platform | Formats | Packaging tool |
+//! |----------|---------|----------------|
+//! | Windows | `msi`, `exe` | `wix` (WiX Toolset 4+), `rcedit` for the icon |
+//! | macOS | `app`, `dmg` | `sips` for a PNG icon, `hdiutil` |
+//! | Linux | `appimage`, `deb` | `appimagetool`, `dpkg-deb` |
+//!
+//! The bundles embed the theme files and the images and SVGs referenced by the
+//! `.dampen` files (see [`assets`]), at the same paths relative to the working
+//! directory of the application, which the launchers set to the bundle's
+//! resources.
+//!
+//! Bundles are described by the `Cargo.toml` of the package, and by its
+//! optional `[package.metadata.dampen]` table:
+//!
+//! ```toml
+//! [package.metadata.dampen]
+//! display_name = "Todo App"
+//! identifier = "com.example.todo"
+//! icon = "assets/icon.png"
+//! ```
+
+pub mod assets;
+pub mod linux;
+pub mod macos;
+pub mod windows;
+
+use heck::ToTitleCase;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Bundle command arguments
+#[derive(clap::Args)]
+pub struct BundleArgs {
+    /// Package to bundle (if workspace has multiple packages)
+    #[arg(short, long)]
+    package: Option<String>,
+
+    /// Bundle formats, those of the current platform by default
+    #[arg(long, value_enum, value_delimiter = ',')]
+    format: Vec<BundleFormat>,
+
+    /// Application icon: PNG, ICNS on macOS or ICO on Windows
+    #[arg(long, value_name = "FILE")]
+    icon: Option<PathBuf>,
+
+    /// Directory receiving the bundles
+    #[arg(long, default_value = "target/bundle")]
+    out_dir: PathBuf,
+
+    /// Additional features to enable (beyond codegen)
+    #[arg(long, value_delimiter = ',')]
+    features: Vec<String>,
+
+    /// Bundle the existing release binary instead of building it
+    #[arg(long)]
+    no_build: bool,
+
+    /// Verbose output
+    #[arg(short, long)]
+    verbose: bool,
+}
+
+/// Package format produced by `dampen bundle`
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BundleFormat {
+    /// Windows installer
+    Msi,
+    /// Windows portable directory, with the icon set on the executable
+    Exe,
+    /// macOS application bundle
+    App,
+    /// macOS disk image containing the application bundle
+    Dmg,
+    /// Linux portable AppImage
+    #[value(name = "appimage")]
+    AppImage,
+    /// Debian package
+    Deb,
+}
+
+impl BundleFormat {
+    /// Formats bundled by default on the current platform
+    pub fn platform_defaults() -> Vec<BundleFormat> {
+        if cfg!(target_os = "windows") {
+            vec![BundleFormat::Msi]
+        } else if cfg!(target_os = "macos") {
+            vec![BundleFormat::App, BundleFormat::Dmg]
+        } else {
+            vec![BundleFormat::AppImage, BundleFormat::Deb]
+        }
+    }
+}
+
+/// Everything a bundle is made of
+#[derive(Debug, Clone)]
+pub struct BundleContext {
+    /// Name of the package, e.g. `todo-app`
+    pub name: String,
+    /// Name shown to users, e.g. `Todo App`
+    pub display_name: String,
+    pub version: String,
+    pub description: String,
+    pub authors: Vec<String>,
+    /// Reverse-DNS identifier, e.g. `com.example.todo`
+    pub identifier: String,
+    /// Release binary
+    pub binary: PathBuf,
+    /// Assets to embed
+    pub assets: Vec<assets::Asset>,
+    pub icon: Option<PathBuf>,
+    pub out_dir: PathBuf,
+}
+
+impl BundleContext {
+    /// File name of the binary
+    pub fn binary_name(&self) -> String {
+        self.binary
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| self.name.clone())
+    }
+
+    /// Copy the binary and the assets into `dir`, the assets at their relative path
+    pub fn install_into(&self, dir: &Path, binary_dir: &Path) -> Result<(), String> {
+        copy_file(&self.binary, &binary_dir.join(self.binary_name()))?;
+        for asset in &self.assets {
+            copy_file(&asset.source, &dir.join(&asset.path))?;
+        }
+        Ok(())
+    }
+}
+
+/// Execute the bundle command
+///
+/// # Examples
+///
+/// ```bash
+/// # Bundles of the current platform, in target/bundle/
+/// dampen bundle
+///
+/// # A Debian package only, with an icon
+/// dampen bundle --format deb --icon assets/icon.png
+///
+/// # Bundle a package of the workspace without building it again
+/// dampen bundle -p my-app --no-build
+/// ```
+pub fn execute(args: &BundleArgs) -> Result<(), String> {
+    let project_dir = args
+        .package
+        .as_deref()
+        .and_then(package_root)
+        .unwrap_or_else(|| PathBuf::from("."));
+    let manifest = read_manifest(&project_dir)?;
+
+    if !args.no_build {
+        crate::commands::build::execute_release_build(
+            args.package.clone(),
+            args.features.clone(),
+            args.verbose,
+            None,
+        )?;
+    }
+
+    let binary_name = format!("{}{}", manifest.binary, std::env::consts::EXE_SUFFIX);
+    let binary = [
+        project_dir.join("target/release"),
+        PathBuf::from("target/release"),
+    ]
+    .into_iter()
+    .map(|dir| dir.join(&binary_name))
+    .find(|path| path.exists())
+    .ok_or_else(|| {
+        format!(
+            "Release binary '{}' not found in target/release. Run 'dampen release' first.",
+            binary_name
+        )
+    })?;
+
+    let icon = args
+        .icon
+        .clone()
+        .or_else(|| manifest.icon.as_ref().map(|icon| project_dir.join(icon)))
+        .or_else(|| {
+            let default = project_dir.join("assets/icon.png");
+            default.exists().then_some(default)
+        });
+    if let Some(ref icon) = icon
+        && !icon.exists()
+    {
+        return Err(format!("Icon '{}' not found", icon.display()));
+    }
+
+    let (assets, missing) = assets::collect(&project_dir);
+    for path in &missing {
+        eprintln!(
+            "Warning: {} is referenced by a view but does not exist, it is not bundled",
+            path.display()
+        );
+    }
+    if args.verbose {
+        eprintln!("Bundling {} assets:", assets.len());
+        for asset in &assets {
+            eprintln!("  {}", asset.path.display());
+        }
+    }
+
+    let context = BundleContext {
+        display_name: manifest
+            .display_name
+            .unwrap_or_else(|| manifest.name.to_title_case()),
+        identifier: manifest
+            .identifier
+            .unwrap_or_else(|| format!("dev.dampen.{}", manifest.name.replace('_', "-"))),
+        name: manifest.name,
+        version: manifest.version,
+        description: manifest.description,
+        authors: manifest.authors,
+        binary,
+        assets,
+        icon,
+        out_dir: args.out_dir.clone(),
+    };
+    std::fs::create_dir_all(&context.out_dir)
+        .map_err(|e| format!("Failed to create '{}': {}", context.out_dir.display(), e))?;
+
+    let formats = if args.format.is_empty() {
+        BundleFormat::platform_defaults()
+    } else {
+        args.format.clone()
+    };
+    for format in formats {
+        let bundle = match format {
+            BundleFormat::Msi => windows::bundle_msi(&context)?,
+            BundleFormat::Exe => windows::bundle_exe(&context)?,
+            BundleFormat::App => macos::bundle_app(&context)?,
+            BundleFormat::Dmg => macos::bundle_dmg(&context)?,
+            BundleFormat::AppImage => linux::bundle_appimage(&context)?,
+            BundleFormat::Deb => linux::bundle_deb(&context)?,
+        };
+        eprintln!("Bundled {}", bundle.display());
+    }
+
+    Ok(())
+}
+
+/// Fields of `Cargo.toml` describing the bundle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Manifest {
+    pub name: String,
+    pub version: String,
+    pub description: String,
+    pub authors: Vec<String>,
+    /// Name of the binary: the first `[[bin]]`, or the package
+    pub binary: String,
+    pub display_name: Option<String>,
+    pub identifier: Option<String>,
+    pub icon: Option<String>,
+}
+
+/// Read the `Cargo.toml` of `dir`
+pub fn read_manifest(dir: &Path) -> Result<Manifest, String> {
+    let path = dir.join("Cargo.toml");
+    let content = std::fs::read_to_string(&path)
+        .map_err(|_| "Cargo.toml not found. Are you in a Rust project directory?".to_string())?;
+    parse_manifest(&content).map_err(|e| format!("Invalid {}: {}", path.display(), e))
+}
+
+/// Parse the fields of a `Cargo.toml` describing the bundle
+pub fn parse_manifest(content: &str) -> Result<Manifest, String> {
+    let toml: toml::Value = toml::from_str(content).map_err(|e| e.to_string())?;
+    let package = toml
+        .get("package")
+        .ok_or_else(|| "missing [package] table".to_string())?;
+    let string = |value: Option<&toml::Value>| value.and_then(|v| v.as_str()).map(String::from);
+
+    let name = string(package.get("name")).ok_or_else(|| "missing package name".to_string())?;
+    // Workspace-inherited values (`version.workspace = true`) are not resolved
+    let version = string(package.get("version")).unwrap_or_else(|| "0.1.0".to_string());
+    let binary = toml
+        .get("bin")
+        .and_then(|bins| bins.as_array())
+        .and_then(|bins| bins.first())
+        .and_then(|bin| string(bin.get("name")))
+        .unwrap_or_else(|| name.clone());
+    let metadata = package.get("metadata").and_then(|m| m.get("dampen"));
+    let metadata_field = |field: &str| string(metadata.and_then(|m| m.get(field)));
+
+    Ok(Manifest {
+        description: string(package.get("description")).unwrap_or_default(),
+        authors: package
+            .get("authors")
+            .and_then(|authors| authors.as_array())
+            .map(|authors| {
+                authors
+                    .iter()
+                    .filter_map(|author| author.as_str().map(String::from))
+                    .collect()
+            })
+            .unwrap_or_default(),
+        display_name: metadata_field("display_name"),
+        identifier: metadata_field("identifier"),
+        icon: metadata_field("icon"),
+        name,
+        version,
+        binary,
+    })
+}
+
+/// Directory of a package of the workspace
+fn package_root(package: &str) -> Option<PathBuf> {
+    ["examples", "crates", "."]
+        .into_iter()
+        .map(|prefix| Path::new(prefix).join(package))
+        .find(|root| root.join("Cargo.toml").exists())
+}
+
+/// Copy `from` to `to`, creating the directories of `to`
+fn copy_file(from: &Path, to: &Path) -> Result<(), String> {
+    if let Some(parent) = to.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create '{}': {}", parent.display(), e))?;
+    }
+    std::fs::copy(from, to)
+        .map(|_| ())
+        .map_err(|e| format!("Failed to copy '{}': {}", from.display(), e))
+}
+
+/// Write `content` to `path`, creating its directories
+fn write_file(path: &Path, content: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create '{}': {}", parent.display(), e))?;
+    }
+    std::fs::write(path, content)
+        .map_err(|e| format!("Failed to write '{}': {}", path.display(), e))
+}
+
+/// Write a launcher script, executable on Unix
+fn write_script(path: &Path, content: &str) -> Result<(), String> {
+    write_file(path, content)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))
+            .map_err(|e| format!("Failed to make '{}' executable: {}", path.display(), e))?;
+    }
+    Ok(())
+}
+
+/// Empty the staging directory `dir` of a previous bundle
+fn clean_dir(dir: &Path) -> Result<(), String> {
+    if dir.exists() {
+        std::fs::remove_dir_all(dir)
+            .map_err(|e| format!("Failed to remove '{}': {}", dir.display(), e))?;
+    }
+    std::fs::create_dir_all(dir).map_err(|e| format!("Failed to create '{}': {}", dir.display(), e))
+}
+
+/// Run a packaging tool, explaining how to install it when it is missing
+fn run_tool(cmd: &mut Command, install_hint: &str) -> Result<(), String> {
+    let tool = cmd.get_program().to_string_lossy().to_string();
+    let status = cmd.status().map_err(|e| {
+        if e.kind() == std::io::ErrorKind::NotFound {
+            format!("'{}' not found. {}", tool, install_hint)
+        } else {
+            format!("Failed to execute {}: {}", tool, e)
+        }
+    })?;
+    if !status.success() {
+        return Err(format!("{} failed", tool));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_manifest() {
+        let manifest = parse_manifest(
+            r#"
+            [package]
+            name = "todo-app"
+            version = "1.2.0"
+            description = "Todo list"
+            authors = ["Jane Doe <jane@example.com>"]
+
+            [package.metadata.dampen]
+            identifier = "com.example.todo"
+            icon = "assets/todo.png"
+
+            [[bin]]
+            name = "todo"
+            path = "src/main.rs"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(manifest.name, "todo-app");
+        assert_eq!(manifest.version, "1.2.0");
+        assert_eq!(manifest.binary, "todo");
+        assert_eq!(manifest.authors, vec!["Jane Doe <jane@example.com>"]);
+        assert_eq!(manifest.identifier.as_deref(), Some("com.example.todo"));
+        assert_eq!(manifest.icon.as_deref(), Some("assets/todo.png"));
+        assert_eq!(manifest.display_name, None);
+
+        assert!(parse_manifest("[dependencies]").is_err());
+    }
+}
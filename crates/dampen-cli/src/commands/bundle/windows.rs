@@ -0,0 +1,236 @@
+//! Windows bundles: portable directories and MSI installers
+//!
+//! The portable directory holds the executable, with the icon set by `rcedit`,
+//! next to the assets. The installer installs that directory in
+//! `Program Files` and adds a Start menu shortcut starting the application
+//! from it.
+
+use super::macos::escape_xml;
+use super::{BundleContext, clean_dir, run_tool, write_file};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Lay out the portable directory `windows/<name>`, returning its executable
+pub fn bundle_exe(context: &BundleContext) -> Result<PathBuf, String> {
+    let dir = portable_dir(context);
+    clean_dir(&dir)?;
+    context.install_into(&dir, &dir)?;
+
+    let exe = dir.join(context.binary_name());
+    match &context.icon {
+        Some(icon) if icon.extension().is_some_and(|ext| ext == "ico") => run_tool(
+            Command::new("rcedit").arg(&exe).arg("--set-icon").arg(icon),
+            "Download it from https://github.com/electron/rcedit/releases and add it to your PATH.",
+        )?,
+        Some(icon) => eprintln!(
+            "Warning: Windows executables need an .ico icon, {} is not used",
+            icon.display()
+        ),
+        None => {}
+    }
+    Ok(exe)
+}
+
+/// Build `<name>-<version>.msi` with the WiX Toolset
+pub fn bundle_msi(context: &BundleContext) -> Result<PathBuf, String> {
+    bundle_exe(context)?;
+
+    let wxs = context
+        .out_dir
+        .join("windows")
+        .join(format!("{}.wxs", context.name));
+    write_file(&wxs, &wix_source(context, &portable_dir(context)))?;
+
+    let msi = context
+        .out_dir
+        .join(format!("{}-{}.msi", context.name, context.version));
+    let arch = match std::env::consts::ARCH {
+        "aarch64" => "arm64",
+        "x86" => "x86",
+        _ => "x64",
+    };
+    run_tool(
+        Command::new("wix")
+            .arg("build")
+            .arg(&wxs)
+            .args(["-arch", arch, "-o"])
+            .arg(&msi),
+        "Install the WiX Toolset with 'dotnet tool install --global wix'.",
+    )?;
+    Ok(msi)
+}
+
+/// WiX source of the installer of the portable directory `dir`
+pub fn wix_source(context: &BundleContext, dir: &Path) -> String {
+    let manufacturer = context
+        .authors
+        .first()
+        .map(|author| author.split(" <").next().unwrap_or(author).to_string())
+        .unwrap_or_else(|| context.display_name.clone());
+    let binary = context.binary_name();
+
+    let mut tree = DirTree::default();
+    tree.files.push(binary.clone());
+    for asset in &context.assets {
+        tree.insert(&asset.path);
+    }
+    let mut files = String::new();
+    tree.write(&mut files, dir, 3, Some((&binary, &context.display_name)));
+
+    let icon = match &context.icon {
+        Some(icon) if icon.extension().is_some_and(|ext| ext == "ico") => format!(
+            "    <Icon Id=\"AppIcon.ico\" SourceFile=\"{}\" />\n    <Property Id=\"ARPPRODUCTICON\" Value=\"AppIcon.ico\" />\n",
+            escape_xml(&icon.to_string_lossy())
+        ),
+        _ => String::new(),
+    };
+
+    format!(
+        r#"<Wix xmlns="http://wixtoolset.org/schemas/v4/wxs">
+  <Package Name="{name}" Manufacturer="{manufacturer}" Version="{version}" UpgradeCode="{upgrade_code}">
+    <MajorUpgrade DowngradeErrorMessage="A newer version of [ProductName] is already installed." />
+    <MediaTemplate EmbedCab="yes" />
+{icon}    <StandardDirectory Id="ProgramFiles6432Folder">
+      <Directory Id="INSTALLFOLDER" Name="{name}">
+{files}      </Directory>
+    </StandardDirectory>
+  </Package>
+</Wix>
+"#,
+        name = escape_xml(&context.display_name),
+        manufacturer = escape_xml(&manufacturer),
+        // MSI versions are numeric: pre-release and build suffixes are dropped
+        version = context
+            .version
+            .split(['-', '+'])
+            .next()
+            .unwrap_or(&context.version),
+        upgrade_code = upgrade_code(&context.identifier),
+        icon = icon,
+        files = files,
+    )
+}
+
+/// Stable installer upgrade code derived from the application identifier, so
+/// new versions replace the installed one
+pub fn upgrade_code(identifier: &str) -> String {
+    // 128-bit FNV-1a
+    let mut hash: u128 = 0x6c62272e07bb014262b821756295c58d;
+    for byte in identifier.bytes() {
+        hash ^= u128::from(byte);
+        hash = hash.wrapping_mul(0x0000000001000000000000000000013B);
+    }
+    let hex = format!("{:032X}", hash);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+fn portable_dir(context: &BundleContext) -> PathBuf {
+    context.out_dir.join("windows").join(&context.name)
+}
+
+/// Files of the installer, by directory
+#[derive(Default)]
+struct DirTree {
+    files: Vec<String>,
+    dirs: BTreeMap<String, DirTree>,
+}
+
+impl DirTree {
+    fn insert(&mut self, path: &Path) {
+        let mut components: Vec<String> = path
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy().to_string())
+            .collect();
+        let Some(file) = components.pop() else {
+            return;
+        };
+        let mut tree = self;
+        for dir in components {
+            tree = tree.dirs.entry(dir).or_default();
+        }
+        tree.files.push(file);
+    }
+
+    /// Write the components of the tree, the binary in `shortcut` getting a
+    /// Start menu shortcut named after the application
+    fn write(&self, out: &mut String, dir: &Path, depth: usize, shortcut: Option<(&str, &str)>) {
+        let indent = "  ".repeat(depth + 1);
+        for file in &self.files {
+            let source = escape_xml(&dir.join(file).to_string_lossy());
+            if let Some((_, name)) = shortcut.filter(|(binary, _)| binary == file) {
+                out.push_str(&format!(
+                    "{indent}<Component>\n{indent}  <File Source=\"{source}\">\n{indent}    <Shortcut Name=\"{}\" Directory=\"ProgramMenuFolder\" WorkingDirectory=\"INSTALLFOLDER\" Advertise=\"yes\" />\n{indent}  </File>\n{indent}</Component>\n",
+                    escape_xml(name)
+                ));
+            } else {
+                out.push_str(&format!(
+                    "{indent}<Component>\n{indent}  <File Source=\"{source}\" />\n{indent}</Component>\n"
+                ));
+            }
+        }
+        for (name, tree) in &self.dirs {
+            out.push_str(&format!(
+                "{indent}<Directory Name=\"{}\">\n",
+                escape_xml(name)
+            ));
+            tree.write(out, &dir.join(name), depth + 1, None);
+            out.push_str(&format!("{indent}</Directory>\n"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::bundle::assets::Asset;
+
+    #[test]
+    fn test_wix_source() {
+        let context = BundleContext {
+            name: "todo-app".to_string(),
+            display_name: "Todo App".to_string(),
+            version: "1.2.0-beta.1".to_string(),
+            description: String::new(),
+            authors: vec!["Jane Doe <jane@example.com>".to_string()],
+            identifier: "com.example.todo".to_string(),
+            binary: PathBuf::from("target/release/todo-app.exe"),
+            assets: vec![Asset {
+                source: PathBuf::from("assets/logo.png"),
+                path: PathBuf::from("assets/logo.png"),
+            }],
+            icon: None,
+            out_dir: PathBuf::from("target/bundle"),
+        };
+
+        let wxs = wix_source(&context, Path::new("stage"));
+        assert!(wxs.contains(r#"Name="Todo App" Manufacturer="Jane Doe" Version="1.2.0""#));
+        assert!(wxs.contains(&format!(
+            "UpgradeCode=\"{}\"",
+            upgrade_code("com.example.todo")
+        )));
+        assert!(wxs.contains("WorkingDirectory=\"INSTALLFOLDER\""));
+        assert!(wxs.contains("<Directory Name=\"assets\">"));
+        assert!(wxs.contains(&format!(
+            "<File Source=\"{}\" />",
+            Path::new("stage").join("assets").join("logo.png").display()
+        )));
+
+        assert_eq!(
+            upgrade_code("com.example.todo"),
+            upgrade_code("com.example.todo")
+        );
+        assert_ne!(
+            upgrade_code("com.example.todo"),
+            upgrade_code("com.example.notes")
+        );
+        assert_eq!(upgrade_code("com.example.todo").len(), 36);
+    }
+}
@@ -70,6 +70,31 @@ impl HandlerRegistry {
         Ok(registry)
     }
 
+    /// Builds a registry from the `#[ui_handler]` functions of the Rust files under `src_dir`.
+    ///
+    /// Used by `dampen check --strict` when no handler registry JSON is found.
+    /// Parameter and return types are not inferred.
+    pub fn from_rust_sources(src_dir: &Path) -> Self {
+        let mut registry = Self::new();
+        let sources = walkdir::WalkDir::new(src_dir)
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "rs"));
+        for entry in sources {
+            let Ok(content) = fs::read_to_string(entry.path()) else {
+                continue;
+            };
+            for name in crate::commands::test::declared_handlers(&content) {
+                registry.add_handler(HandlerDefinition {
+                    name,
+                    param_type: None,
+                    returns_command: false,
+                });
+            }
+        }
+        registry
+    }
+
     /// Checks if a handler is registered.
     ///
     /// # Arguments
@@ -176,4 +201,21 @@ mod tests {
         // HashSet should prevent duplicates
         assert_eq!(registry.len(), 1);
     }
+
+    #[test]
+    fn test_registry_from_rust_sources() -> Result<(), std::io::Error> {
+        let dir = std::env::temp_dir().join(format!("dampen-handlers-{}", std::process::id()));
+        fs::create_dir_all(dir.join("ui"))?;
+        fs::write(
+            dir.join("ui/window.rs"),
+            "#[ui_handler]\npub fn increment(model: &mut Model) {}\n\nfn helper() {}\n",
+        )?;
+        fs::write(dir.join("notes.txt"), "#[ui_handler]\nfn ignored() {}\n")?;
+
+        let registry = HandlerRegistry::from_rust_sources(&dir);
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(registry.all_names(), vec!["increment".to_string()]);
+        Ok(())
+    }
 }
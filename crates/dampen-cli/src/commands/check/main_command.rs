@@ -3,9 +3,9 @@
 //! Check command - validates Dampen UI files
 
 use clap::Args;
-use dampen_core::ir::layout::{Direction, Position};
+use dampen_core::ir::layout::{Direction, Overflow, Position};
 use dampen_core::{
-    ir::{AttributeValue, EventKind, WidgetKind},
+    ir::{AttributeValue, CLASS_TOGGLE_PREFIX, EventKind, WidgetKind},
     parser,
     parser::style_parser,
 };
@@ -113,12 +113,13 @@ pub enum CheckError {
         col: u32,
     },
 
-    #[error("Unknown style class '{class}' referenced in {file}:{line}:{col}")]
+    #[error("Unknown style class '{class}' referenced in {file}:{line}:{col}{suggestion}")]
     UnknownStyleClass {
         class: String,
         file: PathBuf,
         line: u32,
         col: u32,
+        suggestion: String,
     },
 
     #[error("Invalid breakpoint attribute '{attr}' in {file}:{line}:{col}")]
@@ -175,13 +176,18 @@ pub struct CheckArgs {
     #[arg(long)]
     pub custom_widgets: Option<String>,
 
-    /// Treat warnings as errors (strict mode for CI/CD)
+    /// Strict mode for CI/CD: without a handler registry, check handlers against
+    /// the `#[ui_handler]` functions in src/
     #[arg(long)]
     pub strict: bool,
 
     /// Show minimum required schema version for each widget type
     #[arg(long)]
     pub show_widget_versions: bool,
+
+    /// Keep running and re-check files as they are saved
+    #[arg(long)]
+    pub watch: bool,
 }
 
 /// Resolves the UI directory path for a specific package
@@ -286,22 +292,30 @@ fn display_widget_version_table() {
         ("scrollable", WidgetKind::Scrollable),
         ("stack", WidgetKind::Stack),
         ("text", WidgetKind::Text),
+        ("markdown", WidgetKind::Markdown),
         ("image", WidgetKind::Image),
         ("svg", WidgetKind::Svg),
+        ("icon", WidgetKind::Icon),
         ("button", WidgetKind::Button),
         ("text_input", WidgetKind::TextInput),
+        ("number_input", WidgetKind::NumberInput),
+        ("code_editor", WidgetKind::CodeEditor),
         ("checkbox", WidgetKind::Checkbox),
         ("slider", WidgetKind::Slider),
         ("pick_list", WidgetKind::PickList),
         ("toggler", WidgetKind::Toggler),
         ("radio", WidgetKind::Radio),
+        ("radio_group", WidgetKind::RadioGroup),
         ("space", WidgetKind::Space),
         ("rule", WidgetKind::Rule),
         ("progress_bar", WidgetKind::ProgressBar),
+        ("skeleton", WidgetKind::Skeleton),
         ("combobox", WidgetKind::ComboBox),
         ("tooltip", WidgetKind::Tooltip),
+        ("tooltip_content", WidgetKind::TooltipContent),
         ("grid", WidgetKind::Grid),
         ("canvas", WidgetKind::Canvas),
+        ("chart", WidgetKind::Chart),
         ("date_picker", WidgetKind::DatePicker),
         ("time_picker", WidgetKind::TimePicker),
         ("color_picker", WidgetKind::ColorPicker),
@@ -312,6 +326,7 @@ fn display_widget_version_table() {
         ("float", WidgetKind::Float),
         ("data_table", WidgetKind::DataTable),
         ("data_column", WidgetKind::DataColumn),
+        ("tree", WidgetKind::Tree),
     ];
 
     for (name, widget) in widgets {
@@ -330,8 +345,6 @@ fn display_widget_version_table() {
 }
 
 pub fn run_checks(input: Option<String>, strict: bool, verbose: bool) -> Result<(), CheckError> {
-    use crate::commands::check::handlers::HandlerRegistry;
-
     // Resolve UI directory
     let input_path = resolve_ui_directory(input.as_deref())
         .map_err(|msg| CheckError::Io(std::io::Error::new(std::io::ErrorKind::NotFound, msg)))?;
@@ -340,45 +353,9 @@ pub fn run_checks(input: Option<String>, strict: bool, verbose: bool) -> Result<
         eprintln!("Using UI directory: {}", input_path.display());
     }
 
-    // Attempt auto-discovery for handlers and model
-    // Note: In library usage (run_checks), we don't support explicit paths for now
-    // to keep the API simple. If needed, we can add them later.
-    let handlers_path = resolve_optional_file(None, "handlers.json");
-    if verbose && let Some(ref path) = handlers_path {
-        eprintln!("Using handler registry: {}", path.display());
-    }
-
-    let model_path = resolve_optional_file(None, "model.json");
-    if verbose && let Some(ref path) = model_path {
-        eprintln!("Using model info: {}", path.display());
-    }
-
-    // Load handler registry if auto-discovered
-    let handler_registry = if let Some(path) = handlers_path {
-        let registry = HandlerRegistry::load_from_json(&path).map_err(|e| {
-            CheckError::HandlerRegistryLoadError {
-                path: path.clone(),
-                source: serde_json::Error::io(std::io::Error::other(e.to_string())),
-            }
-        })?;
-        Some(registry)
-    } else {
-        None
-    };
-
-    // Load model info if auto-discovered
-    let model_info = if let Some(path) = model_path {
-        let model =
-            crate::commands::check::model::ModelInfo::load_from_json(&path).map_err(|e| {
-                CheckError::ModelInfoLoadError {
-                    path: path.clone(),
-                    source: serde_json::Error::io(std::io::Error::other(e.to_string())),
-                }
-            })?;
-        Some(model)
-    } else {
-        None
-    };
+    // Auto-discover handlers and model
+    let (handler_registry, model_info) =
+        load_validation_context(&input_path, None, None, strict, verbose)?;
 
     let mut errors = Vec::new();
     let mut files_checked = 0;
@@ -420,63 +397,13 @@ pub fn run_checks(input: Option<String>, strict: bool, verbose: bool) -> Result<
             continue;
         }
 
-        // Special handling for theme.dampen files
-        if file_path.file_name().is_some_and(|n| n == "theme.dampen") {
-            if let Err(theme_error) =
-                dampen_core::parser::theme_parser::parse_theme_document(&content)
-            {
-                errors.push(CheckError::XmlValidationError {
-                    file: file_path.to_path_buf(),
-                    line: 1,
-                    col: 1,
-                    message: format!("Theme validation error: {}", theme_error),
-                });
-            }
-            continue;
-        }
-
-        match parser::parse(&content) {
-            Ok(document) => {
-                // Validate the document structure
-                validate_document(
-                    &document,
-                    file_path,
-                    &handler_registry,
-                    &model_info,
-                    &mut errors,
-                );
-
-                // Validate references (themes, classes)
-                validate_references(&document, file_path, &mut errors);
-
-                // Validate widgets with styles, layout, breakpoints, and states
-                validate_widget_with_styles(&document.root, file_path, &document, &mut errors);
-
-                // Validate widget versions
-                let version_warnings = dampen_core::validate_widget_versions(&document);
-                if !version_warnings.is_empty() {
-                    for warning in version_warnings {
-                        eprintln!(
-                            "Warning: {} in {}:{}:{}",
-                            warning.format_message(),
-                            file_path.display(),
-                            warning.span.line,
-                            warning.span.column
-                        );
-                        eprintln!("  Suggestion: {}", warning.suggestion());
-                        eprintln!();
-                    }
-                }
-            }
-            Err(parse_error) => {
-                errors.push(CheckError::ParseError {
-                    file: file_path.to_path_buf(),
-                    line: parse_error.span.line,
-                    col: parse_error.span.column,
-                    message: parse_error.to_string(),
-                });
-            }
-        }
+        check_file(
+            file_path,
+            &content,
+            &handler_registry,
+            &model_info,
+            &mut errors,
+        );
     }
 
     if verbose {
@@ -514,6 +441,10 @@ pub fn execute(args: &CheckArgs) -> Result<(), CheckError> {
         return Ok(());
     }
 
+    if args.watch {
+        return crate::commands::check::watch::run(args);
+    }
+
     // If custom paths provided, run full logic (legacy execute implementation)
     // Otherwise delegate to run_checks
     if args.handlers.is_some() || args.model.is_some() || args.custom_widgets.is_some() {
@@ -548,8 +479,6 @@ fn run_checks_internal(
     model: Option<String>,
     _custom_widgets: Option<String>,
 ) -> Result<(), CheckError> {
-    use crate::commands::check::handlers::HandlerRegistry;
-
     // Resolve UI directory
     let input_path = resolve_ui_directory(input.as_deref())
         .map_err(|msg| CheckError::Io(std::io::Error::new(std::io::ErrorKind::NotFound, msg)))?;
@@ -558,43 +487,14 @@ fn run_checks_internal(
         eprintln!("Using UI directory: {}", input_path.display());
     }
 
-    // Resolve optional files
-    let handlers_path = resolve_optional_file(handlers.as_deref(), "handlers.json");
-    if verbose && let Some(ref path) = handlers_path {
-        eprintln!("Using handler registry: {}", path.display());
-    }
-
-    let model_path = resolve_optional_file(model.as_deref(), "model.json");
-    if verbose && let Some(ref path) = model_path {
-        eprintln!("Using model info: {}", path.display());
-    }
-
-    // Load handler registry if provided or auto-discovered (US2: Handler Registry Validation)
-    let handler_registry = if let Some(path) = handlers_path {
-        let registry = HandlerRegistry::load_from_json(&path).map_err(|e| {
-            CheckError::HandlerRegistryLoadError {
-                path: path.clone(),
-                source: serde_json::Error::io(std::io::Error::other(e.to_string())),
-            }
-        })?;
-        Some(registry)
-    } else {
-        None
-    };
-
-    // Load model info if provided or auto-discovered (US3: Binding Validation Against Model)
-    let model_info = if let Some(path) = model_path {
-        let model =
-            crate::commands::check::model::ModelInfo::load_from_json(&path).map_err(|e| {
-                CheckError::ModelInfoLoadError {
-                    path: path.clone(),
-                    source: serde_json::Error::io(std::io::Error::other(e.to_string())),
-                }
-            })?;
-        Some(model)
-    } else {
-        None
-    };
+    // Load handler registry and model info if provided or auto-discovered
+    let (handler_registry, model_info) = load_validation_context(
+        &input_path,
+        handlers.as_deref(),
+        model.as_deref(),
+        strict,
+        verbose,
+    )?;
 
     let mut errors = Vec::new();
     let mut files_checked = 0;
@@ -629,64 +529,13 @@ fn run_checks_internal(
             continue;
         }
 
-        // Special handling for theme.dampen files
-        if file_path.file_name().is_some_and(|n| n == "theme.dampen") {
-            if let Err(theme_error) =
-                dampen_core::parser::theme_parser::parse_theme_document(&content)
-            {
-                errors.push(CheckError::XmlValidationError {
-                    file: file_path.to_path_buf(),
-                    line: 1, // theme_parser doesn't always provide spans yet
-                    col: 1,
-                    message: format!("Theme validation error: {}", theme_error),
-                });
-            }
-            continue;
-        }
-
-        match parser::parse(&content) {
-            Ok(document) => {
-                // Validate the document structure
-                validate_document(
-                    &document,
-                    file_path,
-                    &handler_registry,
-                    &model_info,
-                    &mut errors,
-                );
-
-                // Validate references (themes, classes)
-                validate_references(&document, file_path, &mut errors);
-
-                // Validate widgets with styles, layout, breakpoints, and states
-                validate_widget_with_styles(&document.root, file_path, &document, &mut errors);
-
-                // Validate widget versions (Phase 8: Widget Version Validation)
-                // This produces warnings for widgets requiring higher schema versions
-                let version_warnings = dampen_core::validate_widget_versions(&document);
-                if !version_warnings.is_empty() {
-                    for warning in version_warnings {
-                        eprintln!(
-                            "Warning: {} in {}:{}:{}",
-                            warning.format_message(),
-                            file_path.display(),
-                            warning.span.line,
-                            warning.span.column
-                        );
-                        eprintln!("  Suggestion: {}", warning.suggestion());
-                        eprintln!();
-                    }
-                }
-            }
-            Err(parse_error) => {
-                errors.push(CheckError::ParseError {
-                    file: file_path.to_path_buf(),
-                    line: parse_error.span.line,
-                    col: parse_error.span.column,
-                    message: parse_error.to_string(),
-                });
-            }
-        }
+        check_file(
+            file_path,
+            &content,
+            &handler_registry,
+            &model_info,
+            &mut errors,
+        );
     }
 
     if verbose {
@@ -723,6 +572,157 @@ fn run_checks_internal(
     }
 }
 
+/// Source directory of the project the UI files in `ui_dir` belong to
+///
+/// UI files live either under `src/` (e.g. `src/ui/`) or in a directory next to it.
+fn project_src_dir(ui_dir: &Path) -> PathBuf {
+    ui_dir
+        .ancestors()
+        .find(|dir| dir.file_name().is_some_and(|name| name == "src"))
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| ui_dir.parent().unwrap_or(Path::new("")).join("src"))
+}
+
+/// Handler registry and model info the documents are validated against
+pub(crate) type ValidationContext = (
+    Option<crate::commands::check::handlers::HandlerRegistry>,
+    Option<crate::commands::check::model::ModelInfo>,
+);
+
+/// Load the handler registry and model info from the given paths, or auto-discover them
+///
+/// In strict mode, a project without a handler registry JSON is checked against
+/// the `#[ui_handler]` functions found in the `src/` directory of the project
+/// `ui_dir` belongs to.
+pub(crate) fn load_validation_context(
+    ui_dir: &Path,
+    handlers: Option<&str>,
+    model: Option<&str>,
+    strict: bool,
+    verbose: bool,
+) -> Result<ValidationContext, CheckError> {
+    use crate::commands::check::handlers::HandlerRegistry;
+
+    let handlers_path = resolve_optional_file(handlers, "handlers.json");
+    if verbose && let Some(ref path) = handlers_path {
+        eprintln!("Using handler registry: {}", path.display());
+    }
+
+    let model_path = resolve_optional_file(model, "model.json");
+    if verbose && let Some(ref path) = model_path {
+        eprintln!("Using model info: {}", path.display());
+    }
+
+    // Load handler registry if provided or auto-discovered (US2: Handler Registry Validation)
+    let handler_registry = if let Some(path) = handlers_path {
+        let registry = HandlerRegistry::load_from_json(&path).map_err(|e| {
+            CheckError::HandlerRegistryLoadError {
+                path: path.clone(),
+                source: serde_json::Error::io(std::io::Error::other(e.to_string())),
+            }
+        })?;
+        Some(registry)
+    } else if strict {
+        let registry = HandlerRegistry::from_rust_sources(&project_src_dir(ui_dir));
+        if verbose && !registry.is_empty() {
+            eprintln!(
+                "Using {} #[ui_handler] function(s) found in src/",
+                registry.len()
+            );
+        }
+        Some(registry).filter(|registry| !registry.is_empty())
+    } else {
+        None
+    };
+
+    // Load model info if provided or auto-discovered (US3: Binding Validation Against Model)
+    let model_info = if let Some(path) = model_path {
+        let model =
+            crate::commands::check::model::ModelInfo::load_from_json(&path).map_err(|e| {
+                CheckError::ModelInfoLoadError {
+                    path: path.clone(),
+                    source: serde_json::Error::io(std::io::Error::other(e.to_string())),
+                }
+            })?;
+        Some(model)
+    } else {
+        None
+    };
+
+    Ok((handler_registry, model_info))
+}
+
+/// Parse and validate the content of one .dampen file, printing its warnings
+pub(crate) fn check_file(
+    file_path: &Path,
+    content: &str,
+    handler_registry: &Option<crate::commands::check::handlers::HandlerRegistry>,
+    model_info: &Option<crate::commands::check::model::ModelInfo>,
+    errors: &mut Vec<CheckError>,
+) {
+    // Special handling for theme.dampen files
+    if file_path.file_name().is_some_and(|n| n == "theme.dampen") {
+        if let Err(theme_error) = dampen_core::parser::theme_parser::parse_theme_document(content) {
+            let span = theme_error.span.unwrap_or_default();
+            errors.push(CheckError::XmlValidationError {
+                file: file_path.to_path_buf(),
+                line: span.line,
+                col: span.column,
+                message: format!("Theme validation error: {}", theme_error),
+            });
+        }
+        return;
+    }
+
+    match parser::parse(content) {
+        Ok(document) => {
+            // Validate the document structure
+            validate_document(&document, file_path, handler_registry, model_info, errors);
+
+            // Validate references (themes, classes)
+            validate_references(&document, file_path, errors);
+
+            // Validate widgets with styles, layout, breakpoints, and states
+            validate_widget_with_styles(&document.root, file_path, &document, errors);
+
+            // Validate widget versions (Phase 8: Widget Version Validation)
+            // This produces warnings for widgets requiring higher schema versions
+            for warning in dampen_core::validate_widget_versions(&document) {
+                eprintln!(
+                    "Warning: {} in {}:{}:{}",
+                    warning.format_message(),
+                    file_path.display(),
+                    warning.span.line,
+                    warning.span.column
+                );
+                eprintln!("  Suggestion: {}", warning.suggestion());
+                eprintln!();
+            }
+
+            // Warn about widgets screen readers cannot describe
+            for warning in dampen_core::validate_accessibility(&document) {
+                eprintln!(
+                    "Warning: {} in {}:{}:{}",
+                    warning.format_message(),
+                    file_path.display(),
+                    warning.span.line,
+                    warning.span.column
+                );
+                eprintln!("  Suggestion: {}", warning.suggestion());
+                eprintln!();
+            }
+        }
+        Err(parse_error) => {
+            errors.push(CheckError::ParseError {
+                file: file_path.to_path_buf(),
+                line: parse_error.span.line,
+                col: parse_error.span.column,
+                message: parse_error.to_string(),
+            });
+        }
+    }
+}
+
 fn validate_xml_declaration(content: &str, file_path: &Path, errors: &mut Vec<CheckError>) {
     // XML declaration is now optional (since Dampen v0.2.9)
     // If present, it must be valid. If absent, we assume UTF-8.
@@ -913,7 +913,13 @@ fn validate_widget_node(
     }
 
     // Validate widget attributes (US1: Unknown Attribute Detection)
-    let mut attr_names: Vec<String> = node.attributes.keys().map(|s| s.to_string()).collect();
+    // Class toggles (e.g. "class:active") are checked as `class`
+    let mut attr_names: Vec<String> = node
+        .attributes
+        .keys()
+        .filter(|name| !name.starts_with(CLASS_TOGGLE_PREFIX))
+        .map(|s| s.to_string())
+        .collect();
     if node.id.is_some() {
         attr_names.push("id".to_string());
     }
@@ -923,19 +929,24 @@ fn validate_widget_node(
         let schema = attributes::WidgetAttributeSchema::for_widget(&node.kind);
         let all_valid = schema.all_valid_names();
         let suggestion = suggestions::suggest(&attr, &all_valid, 3);
+        let span = node.attribute_span(&attr);
 
         errors.push(CheckError::UnknownAttribute {
             attr,
             widget: widget_name.clone(),
             file: file_path.to_path_buf(),
-            line: node.span.line,
-            col: node.span.column,
+            line: span.line,
+            col: span.column,
             suggestion,
         });
     }
 
     // Validate required attributes (US7: Required Attribute Validation)
-    let missing_required = attributes::validate_required_attributes(&node.kind, &attr_names);
+    let mut missing_required = attributes::validate_required_attributes(&node.kind, &attr_names);
+    // Rich text content may be given as <span> children instead of `value`
+    if node.kind == WidgetKind::Text && !node.children.is_empty() {
+        missing_required.retain(|attr| attr != "value");
+    }
     for missing_attr in missing_required {
         errors.push(CheckError::XmlValidationError {
             file: file_path.to_path_buf(),
@@ -985,6 +996,10 @@ fn validate_widget_node(
     // Validate attribute bindings (US3: Binding Validation Against Model)
     if let Some(model) = model_info {
         for (attr_name, attr_value) in &node.attributes {
+            // Option templates bind to the items of the options collection
+            if node.is_option_template(attr_name) {
+                continue;
+            }
             validate_attribute_bindings(
                 attr_name,
                 attr_value,
@@ -1145,6 +1160,13 @@ fn validate_expr_fields(
         dampen_core::expr::Expr::Literal(_) => {
             // Literals don't reference fields, nothing to validate
         }
+        dampen_core::expr::Expr::Filter(filter) => {
+            // Validate the filtered value and the filter arguments
+            validate_expr_fields(&filter.input, file_path, line, col, model, errors);
+            for arg in &filter.args {
+                validate_expr_fields(arg, file_path, line, col, model, errors);
+            }
+        }
         dampen_core::expr::Expr::SharedFieldAccess(shared_access) => {
             // Validate shared field paths similar to regular field access
             if shared_access.path.is_empty() || shared_access.path.iter().any(|f| f.is_empty()) {
@@ -1227,6 +1249,9 @@ fn validate_binding_expr(
         dampen_core::expr::Expr::Literal(_) => {
             // Literals are always valid
         }
+        dampen_core::expr::Expr::Filter(_) => {
+            // Unknown filters are reported when the binding is evaluated
+        }
         dampen_core::expr::Expr::SharedFieldAccess(_) => {
             // Shared field access is valid if the field exists in shared state
             // For now, we'll assume they're valid
@@ -1250,8 +1275,11 @@ impl WidgetKindExt for WidgetKind {
             WidgetKind::Text,
             WidgetKind::Image,
             WidgetKind::Svg,
+            WidgetKind::Icon,
             WidgetKind::Button,
             WidgetKind::TextInput,
+            WidgetKind::NumberInput,
+            WidgetKind::CodeEditor,
             WidgetKind::Checkbox,
             WidgetKind::Slider,
             WidgetKind::PickList,
@@ -1259,9 +1287,14 @@ impl WidgetKindExt for WidgetKind {
             WidgetKind::Space,
             WidgetKind::Rule,
             WidgetKind::Radio,
+            WidgetKind::RadioGroup,
+            WidgetKind::Span,
+            WidgetKind::Markdown,
             WidgetKind::ComboBox,
             WidgetKind::ProgressBar,
+            WidgetKind::Skeleton,
             WidgetKind::Tooltip,
+            WidgetKind::TooltipContent,
             WidgetKind::Grid,
             WidgetKind::Canvas,
             WidgetKind::CanvasRect,
@@ -1269,6 +1302,10 @@ impl WidgetKindExt for WidgetKind {
             WidgetKind::CanvasLine,
             WidgetKind::CanvasText,
             WidgetKind::CanvasGroup,
+            WidgetKind::CanvasPath,
+            WidgetKind::CanvasArc,
+            WidgetKind::CanvasBezier,
+            WidgetKind::Chart,
             WidgetKind::DatePicker,
             WidgetKind::TimePicker,
             WidgetKind::ColorPicker,
@@ -1281,6 +1318,7 @@ impl WidgetKindExt for WidgetKind {
             WidgetKind::DataColumn,
             WidgetKind::TreeView,
             WidgetKind::TreeNode,
+            WidgetKind::Tree,
             WidgetKind::TabBar,
             WidgetKind::Tab,
             WidgetKind::For,
@@ -1386,14 +1424,24 @@ fn validate_widget_with_styles(
         });
     }
 
-    // Validate style class references
-    for class_name in &node.classes {
+    // Validate style class references, static and toggled
+    let toggled = node
+        .class_toggles()
+        .map(|(class, _)| (class, format!("{}{}", CLASS_TOGGLE_PREFIX, class)));
+    let classes = node
+        .classes
+        .iter()
+        .map(|class| (class.as_str(), "class".to_string()));
+    for (class_name, attribute) in classes.chain(toggled) {
         if !document.style_classes.contains_key(class_name) {
+            let defined: Vec<&str> = document.style_classes.keys().map(String::as_str).collect();
+            let span = node.attribute_span(&attribute);
             errors.push(CheckError::UnknownStyleClass {
-                class: class_name.clone(),
+                class: class_name.to_string(),
                 file: file_path.to_path_buf(),
-                line: node.span.line,
-                col: node.span.column,
+                line: span.line,
+                col: span.column,
+                suggestion: crate::commands::check::suggestions::suggest(class_name, &defined, 3),
             });
         }
     }
@@ -1509,6 +1557,45 @@ fn validate_style_attributes(
                     });
                 }
             }
+            "border_top" | "border_right" | "border_bottom" | "border_left" => {
+                if let AttributeValue::Static(value) = attr_value
+                    && let Err(msg) = style_parser::parse_border_side(value)
+                {
+                    errors.push(CheckError::InvalidStyleValue {
+                        attr: attr_name.clone(),
+                        file: file_path.to_path_buf(),
+                        line: node.span.line,
+                        col: node.span.column,
+                        message: msg,
+                    });
+                }
+            }
+            "elevation" => {
+                if let AttributeValue::Static(value) = attr_value
+                    && let Err(msg) = style_parser::parse_elevation(value)
+                {
+                    errors.push(CheckError::InvalidStyleValue {
+                        attr: attr_name.clone(),
+                        file: file_path.to_path_buf(),
+                        line: node.span.line,
+                        col: node.span.column,
+                        message: msg,
+                    });
+                }
+            }
+            "outline" => {
+                if let AttributeValue::Static(value) = attr_value
+                    && let Err(msg) = style_parser::parse_outline(value)
+                {
+                    errors.push(CheckError::InvalidStyleValue {
+                        attr: attr_name.clone(),
+                        file: file_path.to_path_buf(),
+                        line: node.span.line,
+                        col: node.span.column,
+                        message: msg,
+                    });
+                }
+            }
             "shadow" => {
                 if let AttributeValue::Static(value) = attr_value
                     && let Err(msg) = style_parser::parse_shadow_attr(value)
@@ -1626,6 +1713,58 @@ fn validate_layout_attributes(
                     });
                 }
             }
+            "wrap" => {
+                if let AttributeValue::Static(value) = attr_value
+                    && let Err(msg) = style_parser::parse_wrap(value)
+                {
+                    errors.push(CheckError::InvalidStyleValue {
+                        attr: attr_name.clone(),
+                        file: file_path.to_path_buf(),
+                        line: node.span.line,
+                        col: node.span.column,
+                        message: msg,
+                    });
+                }
+            }
+            "grow" => {
+                if let AttributeValue::Static(value) = attr_value
+                    && let Err(msg) = style_parser::parse_grow(value)
+                {
+                    errors.push(CheckError::InvalidStyleValue {
+                        attr: attr_name.clone(),
+                        file: file_path.to_path_buf(),
+                        line: node.span.line,
+                        col: node.span.column,
+                        message: msg,
+                    });
+                }
+            }
+            "aspect_ratio" => {
+                if let AttributeValue::Static(value) = attr_value
+                    && let Err(msg) = style_parser::parse_aspect_ratio(value)
+                {
+                    errors.push(CheckError::InvalidStyleValue {
+                        attr: attr_name.clone(),
+                        file: file_path.to_path_buf(),
+                        line: node.span.line,
+                        col: node.span.column,
+                        message: msg,
+                    });
+                }
+            }
+            "overflow" => {
+                if let AttributeValue::Static(value) = attr_value
+                    && let Err(msg) = Overflow::parse(value)
+                {
+                    errors.push(CheckError::InvalidStyleValue {
+                        attr: attr_name.clone(),
+                        file: file_path.to_path_buf(),
+                        line: node.span.line,
+                        col: node.span.column,
+                        message: msg,
+                    });
+                }
+            }
             "position" => {
                 if !matches!(node.kind, WidgetKind::Tooltip)
                     && let AttributeValue::Static(value) = attr_value
@@ -1671,7 +1810,7 @@ fn validate_layout_attributes(
     }
 }
 
-/// Validate breakpoint attributes (mobile:, tablet:, desktop:)
+/// Validate breakpoint attributes (mobile-, tablet-, desktop- and custom breakpoints)
 fn validate_breakpoint_attributes(
     node: &dampen_core::ir::WidgetNode,
     file_path: &Path,
@@ -1681,7 +1820,7 @@ fn validate_breakpoint_attributes(
         for (attr_name, attr_value) in attrs {
             // Valider que l'attribut de base est valide
             let base_attr = attr_name.as_str();
-            let full_attr = format!("{:?}:{}", breakpoint, base_attr);
+            let full_attr = format!("{}-{}", breakpoint, base_attr);
 
             // Utiliser les mêmes validateurs que pour les attributs normaux
             let is_style_attr = matches!(
@@ -1802,7 +1941,13 @@ fn validate_state_attributes(
                         | "border_color"
                         | "border_radius"
                         | "border_style"
+                        | "border_top"
+                        | "border_right"
+                        | "border_bottom"
+                        | "border_left"
+                        | "outline"
                         | "shadow"
+                        | "elevation"
                         | "opacity"
                         | "transform"
                         | "width"
@@ -1846,6 +1991,11 @@ fn validate_state_attributes(
                         }
                         "border_radius" => style_parser::parse_border_radius(value).map(|_| ()),
                         "border_style" => style_parser::parse_border_style(value).map(|_| ()),
+                        "border_top" | "border_right" | "border_bottom" | "border_left" => {
+                            style_parser::parse_border_side(value).map(|_| ())
+                        }
+                        "outline" => style_parser::parse_outline(value).map(|_| ()),
+                        "elevation" => style_parser::parse_elevation(value).map(|_| ()),
                         "shadow" => style_parser::parse_shadow_attr(value).map(|_| ()),
                         "transform" => style_parser::parse_transform(value).map(|_| ()),
                         "width" | "height" | "min_width" | "max_width" | "min_height"
@@ -9,6 +9,7 @@ pub mod model;
 pub mod suggestions;
 pub mod themes;
 pub mod tree_view;
+mod watch;
 
 // Re-exports for convenience
 pub use attributes::WidgetAttributeSchema;
@@ -163,6 +163,32 @@ impl ModelInfo {
         paths
     }
 
+    /// Gets the paths of all leaf fields with their type names.
+    ///
+    /// # Returns
+    ///
+    /// A vector of (path, type name) pairs (e.g., ("user.name", "String")).
+    pub fn typed_field_paths(&self) -> Vec<(String, String)> {
+        let mut paths = Vec::new();
+
+        for field in &self.fields {
+            Self::collect_typed_paths(field, &field.name, &mut paths);
+        }
+
+        paths
+    }
+
+    /// Helper function to collect typed leaf paths recursively.
+    fn collect_typed_paths(field: &ModelField, path: &str, paths: &mut Vec<(String, String)>) {
+        if field.is_nested {
+            for child in &field.children {
+                Self::collect_typed_paths(child, &format!("{}.{}", path, child.name), paths);
+            }
+        } else {
+            paths.push((path.to_string(), field.type_name.clone()));
+        }
+    }
+
     /// Helper function to collect nested field paths recursively.
     fn collect_nested_paths(field: &ModelField, prefix: &str, paths: &mut Vec<String>) {
         if field.is_nested {
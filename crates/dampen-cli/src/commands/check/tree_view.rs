@@ -154,7 +154,7 @@ mod tests {
             id: Some(id.to_string()),
             attributes,
             events: vec![],
-            children: vec![],
+            children: Default::default(),
             span: Span::new(0, 0, line, 1),
             style: None,
             layout: None,
@@ -162,6 +162,9 @@ mod tests {
             classes: vec![],
             breakpoint_attributes: HashMap::new(),
             inline_state_variants: HashMap::new(),
+            accessibility: None,
+            attribute_spans: HashMap::new(),
+            comments: Vec::new(),
         }
     }
 
@@ -177,7 +180,7 @@ mod tests {
             id: None,
             attributes,
             events: vec![],
-            children: vec![],
+            children: Default::default(),
             span: Span::new(0, 0, line, 1),
             style: None,
             layout: None,
@@ -185,6 +188,9 @@ mod tests {
             classes: vec![],
             breakpoint_attributes: HashMap::new(),
             inline_state_variants: HashMap::new(),
+            accessibility: None,
+            attribute_spans: HashMap::new(),
+            comments: Vec::new(),
         }
     }
 
@@ -194,7 +200,7 @@ mod tests {
             id: Some("test".to_string()),
             attributes: HashMap::new(),
             events: vec![],
-            children: vec![],
+            children: Default::default(),
             span: Span::new(0, 0, line, 1),
             style: None,
             layout: None,
@@ -202,6 +208,9 @@ mod tests {
             classes: vec![],
             breakpoint_attributes: HashMap::new(),
             inline_state_variants: HashMap::new(),
+            accessibility: None,
+            attribute_spans: HashMap::new(),
+            comments: Vec::new(),
         }
     }
 
@@ -219,7 +228,8 @@ mod tests {
                 create_test_node("node1", "Node 1", 10),
                 create_test_node("node2", "Node 2", 15),
                 create_test_node("node3", "Node 3", 20),
-            ],
+            ]
+            .into(),
             span: Span::new(0, 0, 1, 1),
             style: None,
             layout: None,
@@ -227,6 +237,9 @@ mod tests {
             classes: vec![],
             breakpoint_attributes: HashMap::new(),
             inline_state_variants: HashMap::new(),
+            accessibility: None,
+            attribute_spans: HashMap::new(),
+            comments: Vec::new(),
         };
 
         validator.validate_tree_view(&tree_view);
@@ -246,7 +259,8 @@ mod tests {
             children: vec![
                 create_test_node("node1", "Node 1", 10),
                 create_test_node("node1", "Node 1 Duplicate", 15),
-            ],
+            ]
+            .into(),
             span: Span::new(0, 0, 1, 1),
             style: None,
             layout: None,
@@ -254,6 +268,9 @@ mod tests {
             classes: vec![],
             breakpoint_attributes: HashMap::new(),
             inline_state_variants: HashMap::new(),
+            accessibility: None,
+            attribute_spans: HashMap::new(),
+            comments: Vec::new(),
         };
 
         validator.validate_tree_view(&tree_view);
@@ -271,7 +288,7 @@ mod tests {
             id: None,
             attributes: HashMap::new(),
             events: vec![],
-            children: vec![create_node_without_id(10), create_node_without_label(15)],
+            children: vec![create_node_without_id(10), create_node_without_label(15)].into(),
             span: Span::new(0, 0, 1, 1),
             style: None,
             layout: None,
@@ -279,6 +296,9 @@ mod tests {
             classes: vec![],
             breakpoint_attributes: HashMap::new(),
             inline_state_variants: HashMap::new(),
+            accessibility: None,
+            attribute_spans: HashMap::new(),
+            comments: Vec::new(),
         };
 
         validator.validate_tree_view(&tree_view);
@@ -0,0 +1,207 @@
+#![allow(clippy::print_stderr, clippy::print_stdout)]
+
+//! Watch mode for `dampen check --watch`
+//!
+//! Checks every file once, then re-checks the files saved while the command
+//! runs and prints what changed: the errors of a re-checked file, or a line
+//! saying it is now clean. A theme change re-checks every file, since all views
+//! are styled by it.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use dampen_dev::reload::is_theme_file_path;
+use dampen_dev::watcher::{FileWatcher, FileWatcherConfig, reload_order};
+use walkdir::WalkDir;
+
+use super::main_command::{
+    CheckArgs, CheckError, ValidationContext, check_file, load_validation_context,
+    resolve_ui_directory,
+};
+
+/// Time to wait for other files saved together (e.g. "save all") before re-checking
+const BATCH_WINDOW: Duration = Duration::from_millis(150);
+
+/// Run the checker until interrupted, re-checking files as they change
+pub fn run(args: &CheckArgs) -> Result<(), CheckError> {
+    let input_path = resolve_ui_directory(args.input.as_deref())
+        .map_err(|msg| CheckError::Io(std::io::Error::new(std::io::ErrorKind::NotFound, msg)))?;
+    let context = load_validation_context(
+        &input_path,
+        args.handlers.as_deref(),
+        args.model.as_deref(),
+        args.strict,
+        args.verbose,
+    )?;
+
+    let mut watcher = FileWatcher::new(FileWatcherConfig {
+        watch_paths: vec![input_path.clone()],
+        ..FileWatcherConfig::default()
+    })
+    .map_err(|e| CheckError::Io(std::io::Error::other(e.to_string())))?;
+    watcher
+        .watch(input_path.clone())
+        .map_err(|e| CheckError::Io(std::io::Error::other(e.to_string())))?;
+
+    let mut state = WatchState::default();
+    let files = dampen_files(&input_path);
+    for file in &files {
+        for line in state.record(file, check_path(file, &context)) {
+            println!("{}", line);
+        }
+    }
+    println!("{}", state.summary(files.len()));
+    println!(
+        "Watching {} for changes (Ctrl+C to stop)",
+        input_path.display()
+    );
+
+    while let Ok(first) = watcher.receiver().recv() {
+        std::thread::sleep(BATCH_WINDOW);
+        let changed = reload_order(std::iter::once(first).chain(watcher.receiver().try_iter()));
+
+        let targets = files_to_recheck(&changed, || dampen_files(&input_path));
+        println!();
+        for file in &targets {
+            for line in state.record(file, check_path(file, &context)) {
+                println!("{}", line);
+            }
+        }
+        println!("{}", state.summary(targets.len()));
+    }
+
+    Ok(())
+}
+
+/// Check a single file, as `dampen check` does for each file of the UI directory
+fn check_path(path: &Path, (handler_registry, model_info): &ValidationContext) -> Vec<CheckError> {
+    let mut errors = Vec::new();
+    match fs::read_to_string(path) {
+        Ok(content) => check_file(path, &content, handler_registry, model_info, &mut errors),
+        Err(e) => errors.push(CheckError::Io(e)),
+    }
+    errors
+}
+
+/// All .dampen files under `dir`, in a stable order
+fn dampen_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = WalkDir::new(dir)
+        .follow_links(true)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .map(|e| e.into_path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "dampen"))
+        .collect();
+    files.sort();
+    files
+}
+
+/// Files to re-check after `changed` were saved
+///
+/// A changed theme affects every view, so all files are re-checked.
+fn files_to_recheck(changed: &[PathBuf], all_files: impl FnOnce() -> Vec<PathBuf>) -> Vec<PathBuf> {
+    if changed.iter().any(|path| is_theme_file_path(path)) {
+        all_files()
+    } else {
+        changed.to_vec()
+    }
+}
+
+/// Error counts of the checked files, to report what changed between checks
+#[derive(Debug, Default)]
+struct WatchState {
+    error_counts: BTreeMap<PathBuf, usize>,
+}
+
+impl WatchState {
+    /// Record the errors of a checked file and return the lines to print
+    fn record(&mut self, path: &Path, errors: Vec<CheckError>) -> Vec<String> {
+        let previous = self
+            .error_counts
+            .insert(path.to_path_buf(), errors.len())
+            .unwrap_or(0);
+
+        if errors.is_empty() {
+            if previous > 0 {
+                return vec![format!("  [FIXED] {}", path.display())];
+            }
+            return Vec::new();
+        }
+        errors
+            .iter()
+            .map(|error| format!("  [ERROR] {}", error))
+            .collect()
+    }
+
+    /// One-line status of the whole UI directory
+    fn summary(&self, checked: usize) -> String {
+        let errors: usize = self.error_counts.values().sum();
+        let files = self
+            .error_counts
+            .values()
+            .filter(|&&count| count > 0)
+            .count();
+        if errors == 0 {
+            format!("✓ Checked {} file(s), no errors", checked)
+        } else {
+            format!(
+                "Checked {} file(s): {} error(s) in {} file(s)",
+                checked, errors, files
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(file: &str) -> CheckError {
+        CheckError::ParseError {
+            file: PathBuf::from(file),
+            line: 1,
+            col: 1,
+            message: "Unknown widget: buton".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_state_reports_new_and_fixed_errors() {
+        let mut state = WatchState::default();
+        let window = Path::new("ui/window.dampen");
+
+        assert!(state.record(window, Vec::new()).is_empty());
+        let lines = state.record(window, vec![error("ui/window.dampen")]);
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].starts_with("  [ERROR] Parse error in ui/window.dampen:1:1"));
+        assert_eq!(
+            state.summary(1),
+            "Checked 1 file(s): 1 error(s) in 1 file(s)"
+        );
+
+        assert_eq!(
+            state.record(window, Vec::new()),
+            vec!["  [FIXED] ui/window.dampen"]
+        );
+        assert_eq!(state.summary(1), "✓ Checked 1 file(s), no errors");
+    }
+
+    #[test]
+    fn test_theme_change_rechecks_every_file() {
+        let all = || {
+            vec![
+                PathBuf::from("ui/theme/theme.dampen"),
+                PathBuf::from("ui/settings.dampen"),
+                PathBuf::from("ui/window.dampen"),
+            ]
+        };
+
+        let view = vec![PathBuf::from("ui/window.dampen")];
+        assert_eq!(files_to_recheck(&view, all), view);
+
+        let theme = vec![PathBuf::from("ui/theme/theme.dampen")];
+        assert_eq!(files_to_recheck(&theme, all), all());
+    }
+}
@@ -0,0 +1,250 @@
+#![allow(clippy::print_stderr, clippy::print_stdout)]
+
+//! Debug command - talks to the debug server of a running application
+//!
+//! Applications built with `#[dampen_app(debug_server_variant = "...")]` serve
+//! debuggers in development mode (see `dampen_dev::debug_server`). `dampen debug
+//! attach` sends them a single command, or bridges JSON commands from stdin to
+//! the server so that other tools can drive it over stdio. Each command carries
+//! the session token the application printed when its server started.
+
+use dampen_dev::debug_server::{self, AuthenticatedCommand, DebugCommand, DebugResponse};
+use std::io::{BufRead, BufReader, Write};
+use std::net::{Ipv4Addr, TcpStream};
+
+/// Debug command arguments
+#[derive(clap::Args)]
+pub struct DebugArgs {
+    #[command(subcommand)]
+    command: DebugSubcommand,
+}
+
+#[derive(clap::Subcommand)]
+enum DebugSubcommand {
+    /// Connect to the debug server of a running application
+    Attach(AttachArgs),
+}
+
+/// `dampen debug attach` arguments
+#[derive(clap::Args)]
+struct AttachArgs {
+    /// Port of the debug server (default: DAMPEN_DEBUG_PORT, or 7420)
+    #[arg(long)]
+    port: Option<u16>,
+
+    /// Session token printed by the application (default: DAMPEN_DEBUG_TOKEN)
+    #[arg(long)]
+    token: Option<String>,
+
+    /// Command to send; without one, JSON commands are read from stdin, one per line
+    #[command(subcommand)]
+    command: Option<AttachCommand>,
+}
+
+#[derive(clap::Subcommand, Clone)]
+enum AttachCommand {
+    /// List the views and the current view
+    Views,
+
+    /// Dump the model of a view
+    Model {
+        /// View path (default: current view)
+        view: Option<String>,
+    },
+
+    /// Evaluate a binding expression against the model of a view
+    Eval {
+        /// Expression, e.g. "items.len() > 0"
+        expr: String,
+
+        /// View path (default: current view)
+        #[arg(long)]
+        view: Option<String>,
+    },
+
+    /// Run a handler of the current view
+    Dispatch {
+        /// Handler name
+        handler: String,
+
+        /// Value passed to the handler
+        value: Option<String>,
+    },
+}
+
+impl From<AttachCommand> for DebugCommand {
+    fn from(command: AttachCommand) -> Self {
+        match command {
+            AttachCommand::Views => DebugCommand::Views,
+            AttachCommand::Model { view } => DebugCommand::Model { view },
+            AttachCommand::Eval { expr, view } => DebugCommand::Evaluate { expr, view },
+            AttachCommand::Dispatch { handler, value } => DebugCommand::Dispatch { handler, value },
+        }
+    }
+}
+
+/// Execute the debug command
+///
+/// # Examples
+///
+/// ```bash
+/// # List the views of the running application
+/// dampen debug attach views
+///
+/// # Dump the model of the current view
+/// dampen debug attach model
+///
+/// # Evaluate an expression against the live model
+/// dampen debug attach eval "count * 2"
+///
+/// # Run a handler with a value
+/// dampen debug attach dispatch set_name Alice
+///
+/// # Pass the token printed by the application
+/// dampen debug attach --token 4f1c2e... views
+///
+/// # Drive the server over stdio
+/// echo '{"command": "views"}' | dampen debug attach
+/// ```
+pub fn execute(args: &DebugArgs) -> Result<(), String> {
+    match &args.command {
+        DebugSubcommand::Attach(args) => attach(args),
+    }
+}
+
+fn attach(args: &AttachArgs) -> Result<(), String> {
+    let port = args.port.unwrap_or_else(debug_server::port);
+    let token = args
+        .token
+        .clone()
+        .or_else(|| std::env::var(debug_server::TOKEN_ENV).ok())
+        .filter(|token| !token.trim().is_empty())
+        .ok_or_else(|| {
+            format!(
+                "No session token for the debug server\n\
+                 Tip: Pass the token the application printed with --token, or set {}",
+                debug_server::TOKEN_ENV
+            )
+        })?;
+    let stream = TcpStream::connect((Ipv4Addr::LOCALHOST, port)).map_err(|e| {
+        format!(
+            "Failed to connect to the debug server on port {}: {}\n\
+             Tip: Start the application with 'dampen run' and set debug_server_variant in #[dampen_app]",
+            port, e
+        )
+    })?;
+    let mut writer = stream
+        .try_clone()
+        .map_err(|e| format!("Failed to connect to the debug server: {}", e))?;
+    let mut reader = BufReader::new(stream);
+
+    let Some(command) = &args.command else {
+        // Bridge stdin to the server, one command and one response per line
+        for line in std::io::stdin().lock().lines() {
+            let line = line.map_err(|e| format!("Failed to read stdin: {}", e))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let request = with_token(&line, &token)?;
+            println!("{}", exchange(&mut reader, &mut writer, &request)?);
+        }
+        return Ok(());
+    };
+
+    let command = AuthenticatedCommand {
+        token,
+        command: DebugCommand::from(command.clone()),
+    };
+    let request = serde_json::to_string(&command)
+        .map_err(|e| format!("Failed to encode the command: {}", e))?;
+    let response = exchange(&mut reader, &mut writer, &request)?;
+
+    match serde_json::from_str::<DebugResponse>(&response) {
+        Ok(DebugResponse::Ok(value)) => {
+            let output = serde_json::to_string_pretty(&value).unwrap_or_else(|_| value.to_string());
+            println!("{}", output);
+            Ok(())
+        }
+        Ok(DebugResponse::Error(error)) => Err(error),
+        Err(e) => Err(format!("Invalid response from the debug server: {}", e)),
+    }
+}
+
+/// Add the session token to a JSON command read from stdin
+fn with_token(line: &str, token: &str) -> Result<String, String> {
+    let mut command = serde_json::from_str::<serde_json::Value>(line)
+        .map_err(|e| format!("Invalid command '{}': {}", line.trim(), e))?;
+    let Some(fields) = command.as_object_mut() else {
+        return Err(format!(
+            "Invalid command '{}': expected a JSON object",
+            line.trim()
+        ));
+    };
+    fields.insert("token".to_string(), token.into());
+    Ok(command.to_string())
+}
+
+/// Send one command line and read its response line
+fn exchange(
+    reader: &mut BufReader<TcpStream>,
+    writer: &mut TcpStream,
+    line: &str,
+) -> Result<String, String> {
+    writeln!(writer, "{}", line.trim())
+        .map_err(|e| format!("Failed to send the command: {}", e))?;
+
+    let mut response = String::new();
+    let read = reader
+        .read_line(&mut response)
+        .map_err(|e| format!("Failed to read the response: {}", e))?;
+    if read == 0 {
+        return Err("The application closed the connection".to_string());
+    }
+    Ok(response.trim_end().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attach_commands_map_to_debug_commands() {
+        let command = DebugCommand::from(AttachCommand::Eval {
+            expr: "count * 2".to_string(),
+            view: None,
+        });
+        assert_eq!(
+            serde_json::to_string(&command).ok().as_deref(),
+            Some(r#"{"command":"evaluate","expr":"count * 2"}"#)
+        );
+
+        let command = DebugCommand::from(AttachCommand::Dispatch {
+            handler: "set_name".to_string(),
+            value: Some("Alice".to_string()),
+        });
+        assert_eq!(
+            serde_json::to_string(&command).ok().as_deref(),
+            Some(r#"{"command":"dispatch","handler":"set_name","value":"Alice"}"#)
+        );
+    }
+
+    #[test]
+    fn test_commands_carry_the_session_token() {
+        let command = AuthenticatedCommand {
+            token: "secret".to_string(),
+            command: DebugCommand::from(AttachCommand::Views),
+        };
+        assert_eq!(
+            serde_json::to_string(&command).ok().as_deref(),
+            Some(r#"{"token":"secret","command":"views"}"#)
+        );
+
+        assert_eq!(
+            with_token(r#"{"command": "views"}"#, "secret")
+                .ok()
+                .as_deref(),
+            Some(r#"{"command":"views","token":"secret"}"#)
+        );
+        assert!(with_token("[1, 2]", "secret").is_err());
+    }
+}
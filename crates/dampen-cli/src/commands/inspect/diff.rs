@@ -0,0 +1,405 @@
+//! Diff of the generated code of a view against its last build
+//!
+//! `dampen inspect --codegen --diff` generates the code of a view again, the
+//! way the build script of `dampen new` does, and compares it with the code of
+//! the last build, read from the `OUT_DIR` of the build script: the file of
+//! the view in `ui_generated/` (see [`dampen_core::codegen::modules`]), or its
+//! module in `ui_generated.rs` for build scripts writing a single file. Both sides are
+//! formatted with prettyplease and compared by line.
+//!
+//! When the build script kept the source of the view (see
+//! [`dampen_core::codegen::cache`]), each change of the `.dampen` file is
+//! shown with the code it changes: the change alone is applied to the source
+//! of the last build, which is generated again. The remaining changes come
+//! from the handlers, the theme or the version of dampen-core.
+
+use dampen_core::codegen::cache::CodegenCache;
+use dampen_core::codegen::inventory;
+use dampen_core::codegen::modules::ViewModule;
+use dampen_core::codegen::{CodegenConfig, GeneratedCode, generate_application_with_config};
+use dampen_core::parser::theme_parser::parse_theme_document;
+use serde::Serialize;
+use similar::TextDiff;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Lines changed at one place of a file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Hunk {
+    /// First changed line of the old file, from 1
+    pub old_line: usize,
+    /// First changed line of the new file, from 1
+    pub new_line: usize,
+    /// Lines of the old file
+    pub removed: Vec<String>,
+    /// Lines of the new file
+    pub added: Vec<String>,
+}
+
+/// A change of the `.dampen` file and the code it changes
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct XmlChange {
+    /// The change of the source
+    pub source: Hunk,
+    /// The changes of the generated code
+    pub code: Vec<Hunk>,
+    /// Why the change could not be generated alone
+    pub error: Option<String>,
+}
+
+/// Changes of the generated code of a view since its last build
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CodegenDiff {
+    /// Changes of the source, if the build kept it
+    pub xml_changes: Vec<XmlChange>,
+    /// Changes of the code that no change of the source explains
+    pub other: Vec<Hunk>,
+    /// Whether the source of the last build was known
+    pub has_source: bool,
+}
+
+impl CodegenDiff {
+    /// Whether the generated code is the same as in the last build
+    pub fn is_empty(&self) -> bool {
+        self.other.is_empty() && self.xml_changes.iter().all(|change| change.code.is_empty())
+    }
+}
+
+/// Output of the last build of a view
+#[derive(Debug, Clone)]
+pub struct LastBuild {
+    /// The file it was read from
+    pub generated_file: PathBuf,
+    /// Code generated for the view
+    pub code: String,
+    /// Source of the view, if the build script kept it
+    pub source: Option<String>,
+}
+
+/// Diff the code of the `.dampen` file `file` against its last build
+///
+/// `out_dir` is the `OUT_DIR` of the build script, found in the target
+/// directory of the project if `None`.
+pub fn diff_view(file: &Path, out_dir: Option<&Path>) -> Result<(LastBuild, CodegenDiff), String> {
+    let file = fs::canonicalize(file)
+        .map_err(|e| format!("Failed to read file '{}': {}", file.display(), e))?;
+    let project_dir = file
+        .ancestors()
+        .find(|dir| dir.join("Cargo.toml").is_file())
+        .ok_or_else(|| "Cargo.toml not found. Is the view in a Rust project?".to_string())?;
+    let ui_dir = project_dir.join("src/ui");
+    let view_path = file
+        .strip_prefix(&ui_dir)
+        .map_err(|_| format!("'{}' is not a view of src/ui", file.display()))?
+        .with_extension("");
+
+    let out_dir = match out_dir {
+        Some(out_dir) => out_dir.to_path_buf(),
+        None => find_out_dir(project_dir)?,
+    };
+    let last_build = read_last_build(&out_dir, &view_path)?;
+
+    let generator = Generator::new(project_dir, &file, &view_path);
+    let source = fs::read_to_string(&file)
+        .map_err(|e| format!("Failed to read file '{}': {}", file.display(), e))?;
+    let diff = compare(
+        &last_build.code,
+        last_build.source.as_deref(),
+        &source,
+        |source| generator.generate(source),
+    )?;
+    Ok((last_build, diff))
+}
+
+/// Compare the code generated from `source` with `last_code`
+///
+/// `generate` returns the formatted code of a source. With the source of the
+/// last build, the changes of the code are attributed to the changes of the
+/// source.
+pub fn compare(
+    last_code: &str,
+    last_source: Option<&str>,
+    source: &str,
+    generate: impl Fn(&str) -> Result<String, String>,
+) -> Result<CodegenDiff, String> {
+    let last_code = format_code(last_code);
+    let Some(last_source) = last_source else {
+        return Ok(CodegenDiff {
+            xml_changes: Vec::new(),
+            other: hunks(&last_code, &generate(source)?),
+            has_source: false,
+        });
+    };
+
+    // The code of the last source with the current handlers and theme
+    let baseline = generate(last_source)?;
+    let xml_changes = hunks(last_source, source)
+        .into_iter()
+        .map(|hunk| match generate(&apply(last_source, &hunk)) {
+            Ok(code) => XmlChange {
+                code: hunks(&baseline, &code),
+                source: hunk,
+                error: None,
+            },
+            Err(e) => XmlChange {
+                code: Vec::new(),
+                source: hunk,
+                error: Some(e),
+            },
+        })
+        .collect();
+
+    Ok(CodegenDiff {
+        xml_changes,
+        other: hunks(&last_code, &baseline),
+        has_source: true,
+    })
+}
+
+/// Changed lines between `old` and `new`
+pub fn hunks(old: &str, new: &str) -> Vec<Hunk> {
+    // Diffing the lines, so that a missing final newline is not a change
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let diff = TextDiff::from_slices(&old_lines, &new_lines);
+    diff.grouped_ops(0)
+        .into_iter()
+        .filter_map(|group| {
+            let first = group.first()?;
+            let last = group.last()?;
+            let old_range = first.old_range().start..last.old_range().end;
+            let new_range = first.new_range().start..last.new_range().end;
+            let lines = |lines: &[&str], range: std::ops::Range<usize>| {
+                lines
+                    .get(range)
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|line| line.to_string())
+                    .collect()
+            };
+            Some(Hunk {
+                old_line: old_range.start + 1,
+                new_line: new_range.start + 1,
+                removed: lines(&old_lines, old_range),
+                added: lines(&new_lines, new_range),
+            })
+        })
+        .collect()
+}
+
+/// `source` with only the change `hunk` applied
+fn apply(source: &str, hunk: &Hunk) -> String {
+    let mut lines: Vec<&str> = source.lines().collect();
+    let start = (hunk.old_line - 1).min(lines.len());
+    let end = (start + hunk.removed.len()).min(lines.len());
+    lines.splice(start..end, hunk.added.iter().map(String::as_str));
+    lines.join("\n")
+}
+
+/// `code` formatted with prettyplease, unchanged if it is not valid Rust
+fn format_code(code: &str) -> String {
+    let mut generated = GeneratedCode::new(code.to_string(), String::new(), PathBuf::new());
+    match generated.format() {
+        Ok(()) => generated.code,
+        Err(_) => code.to_string(),
+    }
+}
+
+/// Generates the code of a view as the build script of `dampen new` does
+struct Generator {
+    handlers: Vec<dampen_core::HandlerSignature>,
+    theme: Option<dampen_core::ir::theme::ThemeDocument>,
+    config: CodegenConfig,
+}
+
+impl Generator {
+    fn new(project_dir: &Path, file: &Path, view_path: &Path) -> Self {
+        let theme = fs::read_to_string(project_dir.join("src/ui/theme/theme.dampen"))
+            .ok()
+            .and_then(|content| parse_theme_document(&content).ok());
+        Self {
+            handlers: inventory::extract_handler_signatures_from_file(&file.with_extension("rs")),
+            theme,
+            config: CodegenConfig::default()
+                .with_module_path(ViewModule::new(view_path).ui_module_path()),
+        }
+    }
+
+    fn generate(&self, source: &str) -> Result<String, String> {
+        let document = dampen_core::parse(source).map_err(|e| format!("Parse error: {}", e))?;
+        let output = generate_application_with_config(
+            &document,
+            &self.handlers,
+            self.theme.as_ref(),
+            &self.config,
+        )
+        .map_err(|e| format!("Code generation error: {}", e))?;
+        Ok(format_code(&output.code))
+    }
+}
+
+/// The most recently built `OUT_DIR` of the project's build script
+fn find_out_dir(project_dir: &Path) -> Result<PathBuf, String> {
+    let manifest = crate::commands::bundle::read_manifest(project_dir)?;
+    let mut target_dirs: Vec<PathBuf> = std::env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .into_iter()
+        .collect();
+    target_dirs.extend(project_dir.ancestors().map(|dir| dir.join("target")));
+
+    let mut builds: Vec<(SystemTime, PathBuf)> = Vec::new();
+    for target_dir in target_dirs.iter().filter(|dir| dir.is_dir()) {
+        // target/<profile>/build and target/<triple>/<profile>/build
+        for depth in ["*", "*/*"] {
+            let pattern = format!(
+                "{}/{}/build/{}-*/out/ui_generated.rs",
+                glob::Pattern::escape(&target_dir.to_string_lossy()),
+                depth,
+                manifest.name
+            );
+            let Ok(paths) = glob::glob(&pattern) else {
+                continue;
+            };
+            for path in paths.flatten() {
+                if let Ok(modified) = fs::metadata(&path).and_then(|m| m.modified()) {
+                    builds.push((modified, path));
+                }
+            }
+        }
+    }
+
+    builds
+        .into_iter()
+        .max_by_key(|(modified, _)| *modified)
+        .and_then(|(_, path)| path.parent().map(Path::to_path_buf))
+        .ok_or_else(|| {
+            format!(
+                "No codegen build of '{}' found. Run 'dampen build' first, or pass --out-dir.",
+                manifest.name
+            )
+        })
+}
+
+/// Read the code and source of the view `view_path` built in `out_dir`
+fn read_last_build(out_dir: &Path, view_path: &Path) -> Result<LastBuild, String> {
+    let module = ViewModule::new(view_path);
+    let source = CodegenCache::new(out_dir).source(&module.cache_name());
+
+    let view_file = out_dir.join("ui_generated").join(module.file());
+    if let Ok(code) = fs::read_to_string(&view_file) {
+        return Ok(LastBuild {
+            generated_file: view_file,
+            code,
+            source,
+        });
+    }
+
+    let generated_file = out_dir.join("ui_generated.rs");
+    let generated = fs::read_to_string(&generated_file)
+        .map_err(|e| format!("Failed to read '{}': {}", generated_file.display(), e))?;
+    let source_file = Path::new("src/ui").join(view_path).with_extension("dampen");
+    let code = view_module(&generated, &source_file).ok_or_else(|| {
+        format!(
+            "'{}' has no code for {}",
+            generated_file.display(),
+            source_file.display()
+        )
+    })?;
+    Ok(LastBuild {
+        generated_file,
+        code: code.to_string(),
+        source,
+    })
+}
+
+/// Code of the module generated from `source_file` in `ui_generated.rs`
+///
+/// Build scripts write each view as a `pub mod` preceded by a
+/// `// Generated from: <file>` comment.
+fn view_module<'a>(generated: &'a str, source_file: &Path) -> Option<&'a str> {
+    let mut sections = generated.split("// Generated from: ").skip(1);
+    let section = sections.find(|section| {
+        section
+            .lines()
+            .next()
+            .is_some_and(|file| Path::new(file.trim()).ends_with(source_file))
+    })?;
+    let body = section.split_once('\n')?.1;
+    let body = body.split_once("{\n")?.1;
+    Some(body.rsplit_once("\n}")?.0)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_hunks_and_apply() {
+        let old = "<column>\n  <text value=\"A\" />\n  <button label=\"Go\" />\n</column>";
+        let new =
+            "<column>\n  <text value=\"B\" />\n  <button label=\"Go\" />\n  <space />\n</column>";
+
+        let changes = hunks(old, new);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].old_line, 2);
+        assert_eq!(changes[0].removed, vec!["  <text value=\"A\" />"]);
+        assert_eq!(changes[0].added, vec!["  <text value=\"B\" />"]);
+        assert_eq!(changes[1].new_line, 4);
+        assert!(changes[1].removed.is_empty());
+
+        assert_eq!(
+            apply(old, &changes[1]),
+            "<column>\n  <text value=\"A\" />\n  <button label=\"Go\" />\n  <space />\n</column>"
+        );
+    }
+
+    #[test]
+    fn test_compare_attributes_code_to_xml_changes() {
+        // A fake generator: one line of code per line of source, upper-cased
+        let generate = |source: &str| -> Result<String, String> {
+            if source.contains("broken") {
+                return Err("Parse error".to_string());
+            }
+            Ok(source.to_uppercase())
+        };
+        let last = "a\nb\nc";
+        let current = "a\nB2\nc\nbroken";
+
+        // The last build used an older generator, which wrote "A" as "A0"
+        let diff = compare("A0\nB\nC", Some(last), current, generate).unwrap();
+        assert!(diff.has_source);
+        assert_eq!(diff.xml_changes.len(), 2);
+        assert_eq!(diff.xml_changes[0].source.added, vec!["B2"]);
+        assert_eq!(diff.xml_changes[0].code[0].removed, vec!["B"]);
+        assert_eq!(diff.xml_changes[0].code[0].added, vec!["B2"]);
+        assert_eq!(diff.xml_changes[1].error.as_deref(), Some("Parse error"));
+        assert_eq!(diff.other[0].removed, vec!["A0"]);
+        assert_eq!(diff.other[0].added, vec!["A"]);
+
+        // Without the source, every change is unexplained
+        let diff = compare("A\nB\nC", None, "a\nb2\nc", generate).unwrap();
+        assert!(!diff.has_source);
+        assert_eq!(diff.other.len(), 1);
+        assert!(compare("A", None, "a", generate).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_view_module() {
+        let generated = "// Auto-generated - DO NOT EDIT\n\n\
+            // Generated from: src/ui/window.dampen\npub mod window {\nfn window() {}\n}\n\n\
+            // Generated from: src/ui/admin/users.dampen\npub mod users {\nfn users() {}\n}\n\n";
+
+        assert_eq!(
+            view_module(generated, Path::new("src/ui/admin/users.dampen")),
+            Some("fn users() {}")
+        );
+        assert_eq!(
+            view_module(generated, Path::new("src/ui/window.dampen")),
+            Some("fn window() {}")
+        );
+        assert!(view_module(generated, Path::new("src/ui/settings.dampen")).is_none());
+    }
+}
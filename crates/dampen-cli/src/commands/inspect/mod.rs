@@ -0,0 +1,831 @@
+#![allow(clippy::print_stderr, clippy::print_stdout)]
+
+//! Inspect command - view IR, generated code and bindings
+
+mod diff;
+
+use crate::commands::check::model::ModelInfo;
+use crate::commands::check::suggestions;
+use dampen_core::{HandlerSignature, generate_application, parse};
+use serde::Serialize;
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(clap::Args)]
+pub struct InspectArgs {
+    /// Path to the .dampen file to inspect
+    #[arg(short, long)]
+    file: String,
+
+    /// Show generated Rust code instead of IR
+    #[arg(long)]
+    codegen: bool,
+
+    /// Show the changes of the generated code since the last build, by XML change
+    #[arg(long, requires = "codegen")]
+    diff: bool,
+
+    /// OUT_DIR of the last build (used with --diff, default: the latest build in target/)
+    #[arg(long, requires = "diff")]
+    out_dir: Option<PathBuf>,
+
+    /// List every binding expression with its location and field resolution status
+    #[arg(long, conflicts_with = "codegen")]
+    bindings: bool,
+
+    /// Path to model info JSON used to resolve binding fields
+    /// (default: auto-discover model.json)
+    #[arg(long)]
+    model_info: Option<String>,
+
+    /// Output format: human (default) or json
+    #[arg(long, default_value = "human")]
+    format: String,
+
+    /// Model struct name (used with --codegen)
+    #[arg(long, default_value = "Model")]
+    model: String,
+
+    /// Message enum name (used with --codegen)
+    #[arg(long, default_value = "Message")]
+    message: String,
+
+    /// Handler names (for codegen validation, comma-separated)
+    #[arg(long, value_delimiter = ',', num_args = 1..)]
+    handlers: Vec<String>,
+}
+
+pub fn execute(args: &InspectArgs) -> Result<(), String> {
+    // Read the file
+    let content = fs::read_to_string(&args.file)
+        .map_err(|e| format!("Failed to read file '{}': {}", args.file, e))?;
+
+    // Parse the XML
+    let document = parse(&content).map_err(|e| format!("Parse error: {}", e))?;
+
+    if args.bindings {
+        return inspect_bindings(&document, args);
+    }
+
+    if args.diff {
+        return inspect_codegen_diff(args);
+    }
+
+    if args.codegen {
+        // Generate code
+        let handler_signatures: Vec<HandlerSignature> = args
+            .handlers
+            .iter()
+            .map(|name| {
+                HandlerSignature {
+                    name: name.clone(),
+                    param_type: None, // Could be enhanced to parse type info
+                    returns_command: false,
+                    palette: None,
+                }
+            })
+            .collect();
+
+        let output =
+            generate_application(&document, &args.model, &args.message, &handler_signatures)
+                .map_err(|e| format!("Code generation error: {}", e))?;
+
+        match args.format.as_str() {
+            "json" => {
+                let json = serde_json::json!({
+                    "code": output.code,
+                    "warnings": output.warnings,
+                });
+                let json_str = serde_json::to_string_pretty(&json)
+                    .map_err(|e| format!("JSON serialization error: {}", e))?;
+                println!("{}", json_str);
+            }
+            "human" => {
+                println!("// Generated Rust code from: {}\n", args.file);
+                if !output.warnings.is_empty() {
+                    println!("// Warnings:");
+                    for warning in &output.warnings {
+                        println!("//   - {}", warning);
+                    }
+                    println!();
+                }
+                println!("{}", output.code);
+            }
+            _ => return Err(format!("Unknown format: {}", args.format)),
+        }
+    } else {
+        // Show IR tree
+        match args.format.as_str() {
+            "json" => {
+                let json = serde_json::to_string_pretty(&document)
+                    .map_err(|e| format!("JSON serialization error: {}", e))?;
+                println!("{}", json);
+            }
+            "human" => {
+                print_ir_tree(&document, 0);
+            }
+            _ => return Err(format!("Unknown format: {}", args.format)),
+        }
+    }
+
+    Ok(())
+}
+
+/// Show the changes of the generated code since the last build
+fn inspect_codegen_diff(args: &InspectArgs) -> Result<(), String> {
+    let (last_build, diff) =
+        diff::diff_view(std::path::Path::new(&args.file), args.out_dir.as_deref())?;
+
+    match args.format.as_str() {
+        "json" => {
+            let json = serde_json::to_string_pretty(&diff)
+                .map_err(|e| format!("JSON serialization error: {}", e))?;
+            println!("{}", json);
+        }
+        "human" => print_codegen_diff(&args.file, &last_build, &diff),
+        _ => return Err(format!("Unknown format: {}", args.format)),
+    }
+    Ok(())
+}
+
+fn print_codegen_diff(file: &str, last_build: &diff::LastBuild, diff: &diff::CodegenDiff) {
+    println!("Generated code of {}", file);
+    println!("  compared with {}\n", last_build.generated_file.display());
+
+    if diff.is_empty() && diff.xml_changes.is_empty() {
+        println!("No changes since the last build.");
+        return;
+    }
+
+    for change in &diff.xml_changes {
+        println!("XML change at line {}:", change.source.old_line);
+        print_hunk(&change.source, "  ");
+        match &change.error {
+            Some(error) => println!("  => not generated alone: {}", error),
+            None if change.code.is_empty() => println!("  => no change of the generated code"),
+            None => {
+                println!("  => generated code:");
+                for hunk in &change.code {
+                    println!("    @@ line {} @@", hunk.new_line);
+                    print_hunk(hunk, "    ");
+                }
+            }
+        }
+        println!();
+    }
+
+    if !diff.other.is_empty() {
+        if diff.has_source {
+            println!("Changes not caused by the XML (handlers, theme or dampen version):");
+        } else {
+            println!("Changes of the generated code (the build did not keep the view's source):");
+        }
+        for hunk in &diff.other {
+            println!("  @@ line {} @@", hunk.new_line);
+            print_hunk(hunk, "  ");
+        }
+    }
+}
+
+fn print_hunk(hunk: &diff::Hunk, prefix: &str) {
+    for line in &hunk.removed {
+        println!("{}- {}", prefix, line);
+    }
+    for line in &hunk.added {
+        println!("{}+ {}", prefix, line);
+    }
+}
+
+/// Print IR tree in human-readable format
+fn print_ir_tree(doc: &dampen_core::DampenDocument, indent: usize) {
+    let prefix = "  ".repeat(indent);
+
+    println!("{}DampenDocument {{", prefix);
+    println!(
+        "{}  version: v{}.{}",
+        prefix, doc.version.major, doc.version.minor
+    );
+    println!("{}  root:", prefix);
+    print_widget_node(&doc.root, indent + 2);
+    println!("{}}}", prefix);
+}
+
+fn print_widget_node(node: &dampen_core::WidgetNode, indent: usize) {
+    let prefix = "  ".repeat(indent);
+
+    println!("{}WidgetNode {{", prefix);
+    println!("{}  kind: {:?}", prefix, node.kind);
+
+    if let Some(id) = &node.id {
+        println!("{}  id: Some({:?})", prefix, id);
+    }
+
+    // Print documentation and other comments
+    if let Some(doc) = node.doc() {
+        println!("{}  doc: {:?}", prefix, doc);
+    }
+    let comments: Vec<&String> = node
+        .comments
+        .iter()
+        .filter(|comment| !comment.starts_with(dampen_core::ir::DOC_ANNOTATION))
+        .collect();
+    if !comments.is_empty() {
+        println!("{}  comments: {:?}", prefix, comments);
+    }
+
+    // Print attributes
+    if !node.attributes.is_empty() {
+        println!("{}  attributes: {{", prefix);
+        let mut attributes: Vec<_> = node.attributes.iter().collect();
+        attributes.sort_by(|a, b| a.0.cmp(b.0));
+        for (key, value) in attributes {
+            print!("{}    {:?}: ", prefix, key);
+            print_attribute_value(value);
+            println!();
+        }
+        println!("{}  }}", prefix);
+    }
+
+    // Print events
+    if !node.events.is_empty() {
+        println!("{}  events: [", prefix);
+        for event in &node.events {
+            println!(
+                "{}    {:?} -> {} (line {}, col {})",
+                prefix, event.event, event.handler, event.span.line, event.span.column
+            );
+        }
+        println!("{}  ]", prefix);
+    }
+
+    // Print accessibility metadata
+    if let Some(accessibility) = &node.accessibility {
+        println!("{}  accessibility: {{", prefix);
+        if let Some(label) = &accessibility.label {
+            print!("{}    label: ", prefix);
+            print_attribute_value(label);
+            println!();
+        }
+        if let Some(role) = &accessibility.role {
+            println!("{}    role: {}", prefix, role);
+        }
+        if let Some(described_by) = &accessibility.described_by {
+            println!("{}    described_by: {:?}", prefix, described_by);
+        }
+        println!("{}  }}", prefix);
+    }
+
+    // Print children
+    if !node.children.is_empty() {
+        println!("{}  children: [", prefix);
+        for child in &node.children {
+            print_widget_node(child, indent + 2);
+        }
+        println!("{}  ]", prefix);
+    }
+
+    println!(
+        "{}  span: line {}, column {}",
+        prefix, node.span.line, node.span.column
+    );
+    println!("{}}}", prefix);
+}
+
+fn print_attribute_value(value: &dampen_core::AttributeValue) {
+    match value {
+        dampen_core::AttributeValue::Static(s) => {
+            print!("Static({:?})", s);
+        }
+        dampen_core::AttributeValue::Binding(expr) => {
+            print!("Binding(");
+            print_expr(&expr.expr);
+            print!(")");
+        }
+        dampen_core::AttributeValue::Interpolated(parts) => {
+            print!("Interpolated([");
+            for (i, part) in parts.iter().enumerate() {
+                if i > 0 {
+                    print!(", ");
+                }
+                match part {
+                    dampen_core::InterpolatedPart::Literal(s) => {
+                        print!("Literal({:?})", s);
+                    }
+                    dampen_core::InterpolatedPart::Binding(expr) => {
+                        print!("Binding(");
+                        print_expr(&expr.expr);
+                        print!(")");
+                    }
+                }
+            }
+            print!("])");
+        }
+    }
+}
+
+fn print_expr(expr: &dampen_core::Expr) {
+    match expr {
+        dampen_core::Expr::FieldAccess(fa) => {
+            print!("FieldAccess({})", fa.path.join("."));
+        }
+        dampen_core::Expr::MethodCall(mc) => {
+            print!("MethodCall(");
+            print_expr(&mc.receiver);
+            print!(".{}(", mc.method);
+            for (i, arg) in mc.args.iter().enumerate() {
+                if i > 0 {
+                    print!(", ");
+                }
+                print_expr(arg);
+            }
+            print!("))");
+        }
+        dampen_core::Expr::BinaryOp(bo) => {
+            print!("BinaryOp(");
+            print_expr(&bo.left);
+            print!(" {:?} ", bo.op);
+            print_expr(&bo.right);
+            print!(")");
+        }
+        dampen_core::Expr::UnaryOp(uo) => {
+            print!("UnaryOp({:?} ", uo.op);
+            print_expr(&uo.operand);
+            print!(")");
+        }
+        dampen_core::Expr::Conditional(ce) => {
+            print!("Conditional(");
+            print_expr(&ce.condition);
+            print!(" then ");
+            print_expr(&ce.then_branch);
+            print!(" else ");
+            print_expr(&ce.else_branch);
+            print!(")");
+        }
+        dampen_core::Expr::Literal(lit) => match lit {
+            dampen_core::LiteralExpr::String(s) => print!("Literal({:?})", s),
+            dampen_core::LiteralExpr::Integer(i) => print!("Literal({})", i),
+            dampen_core::LiteralExpr::Float(f) => print!("Literal({})", f),
+            dampen_core::LiteralExpr::Bool(b) => print!("Literal({})", b),
+        },
+        dampen_core::Expr::SharedFieldAccess(sa) => {
+            print!("SharedFieldAccess(shared.{})", sa.path.join("."));
+        }
+        dampen_core::Expr::Filter(fe) => {
+            print!("Filter(");
+            print_expr(&fe.input);
+            print!(" | {}(", fe.name);
+            for (i, arg) in fe.args.iter().enumerate() {
+                if i > 0 {
+                    print!(", ");
+                }
+                print_expr(arg);
+            }
+            print!("))");
+        }
+    }
+}
+
+/// Resolution status of a field referenced by a binding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum FieldStatus {
+    /// Field exists on the model
+    Resolved,
+    /// Field does not exist on the model
+    Unknown,
+    /// Field belongs to shared state (not checked)
+    Shared,
+    /// Field is a `<for>` loop variable (not checked)
+    Local,
+    /// No model info available to check the field
+    Unchecked,
+}
+
+/// A field referenced by a binding expression
+#[derive(Debug, Clone, Serialize)]
+struct FieldReport {
+    path: String,
+    status: FieldStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suggestion: Option<String>,
+}
+
+/// A binding expression found in the document
+#[derive(Debug, Clone, Serialize)]
+struct BindingReport {
+    line: u32,
+    column: u32,
+    widget: String,
+    attribute: String,
+    expression: String,
+    fields: Vec<FieldReport>,
+}
+
+/// Print every binding of the document with its resolution status
+fn inspect_bindings(doc: &dampen_core::DampenDocument, args: &InspectArgs) -> Result<(), String> {
+    let model_info = load_model_info(args.model_info.as_deref())?;
+
+    let mut reports = Vec::new();
+    collect_bindings(
+        &doc.root,
+        model_info.as_ref(),
+        &mut Vec::new(),
+        &mut reports,
+    );
+
+    match args.format.as_str() {
+        "json" => {
+            let json = serde_json::to_string_pretty(&reports)
+                .map_err(|e| format!("JSON serialization error: {}", e))?;
+            println!("{}", json);
+        }
+        "human" => print_bindings_table(&reports, model_info.is_some()),
+        _ => return Err(format!("Unknown format: {}", args.format)),
+    }
+
+    Ok(())
+}
+
+/// Load model info from the given path, or auto-discover `model.json`
+fn load_model_info(explicit_path: Option<&str>) -> Result<Option<ModelInfo>, String> {
+    let path = match explicit_path {
+        Some(path) => Some(PathBuf::from(path)),
+        None => ["model.json", "src/model.json"]
+            .iter()
+            .map(PathBuf::from)
+            .find(|path| path.exists()),
+    };
+
+    path.map(|path| {
+        ModelInfo::load_from_json(&path)
+            .map_err(|e| format!("Failed to load model info from {}: {}", path.display(), e))
+    })
+    .transpose()
+}
+
+/// Collect binding reports from a node and its children, in document order
+///
+/// `locals` is the stack of loop variables in scope: the `each` and `index`
+/// names of every enclosing `<for>`.
+fn collect_bindings(
+    node: &dampen_core::WidgetNode,
+    model_info: Option<&ModelInfo>,
+    locals: &mut Vec<String>,
+    reports: &mut Vec<BindingReport>,
+) {
+    let mut attributes: Vec<_> = node.attributes.iter().collect();
+    attributes.sort_by(|a, b| a.0.cmp(b.0));
+
+    for (name, value) in attributes {
+        let exprs: Vec<&dampen_core::Expr> = match value {
+            dampen_core::AttributeValue::Static(_) => continue,
+            dampen_core::AttributeValue::Binding(binding) => vec![&binding.expr],
+            dampen_core::AttributeValue::Interpolated(parts) => parts
+                .iter()
+                .filter_map(|part| match part {
+                    dampen_core::InterpolatedPart::Binding(binding) => Some(&binding.expr),
+                    dampen_core::InterpolatedPart::Literal(_) => None,
+                })
+                .collect(),
+        };
+
+        for expr in exprs {
+            let mut fields = Vec::new();
+            collect_fields(expr, model_info, locals, &mut fields);
+
+            reports.push(BindingReport {
+                line: node.span.line,
+                column: node.span.column,
+                widget: node.kind.to_string(),
+                attribute: name.clone(),
+                expression: format!("{{{}}}", format_expr(expr)),
+                fields,
+            });
+        }
+    }
+
+    // Loop variables are visible to the children of a <for>
+    let scope_len = locals.len();
+    if node.kind == dampen_core::WidgetKind::For {
+        let each = match node.attributes.get("each") {
+            Some(dampen_core::AttributeValue::Static(name)) => name.clone(),
+            _ => "item".to_string(),
+        };
+        locals.push(each);
+        locals.push("index".to_string());
+    }
+
+    for child in &node.children {
+        collect_bindings(child, model_info, locals, reports);
+    }
+
+    locals.truncate(scope_len);
+}
+
+/// Collect the fields referenced by an expression with their resolution status
+fn collect_fields(
+    expr: &dampen_core::Expr,
+    model_info: Option<&ModelInfo>,
+    locals: &[String],
+    fields: &mut Vec<FieldReport>,
+) {
+    match expr {
+        dampen_core::Expr::FieldAccess(fa) => {
+            let path = fa.path.join(".");
+            if fields.iter().any(|f| f.path == path) {
+                return;
+            }
+
+            let is_local = fa.path.first().is_some_and(|first| locals.contains(first));
+            let (status, suggestion) = match model_info {
+                _ if is_local => (FieldStatus::Local, None),
+                None => (FieldStatus::Unchecked, None),
+                Some(model) => {
+                    let parts: Vec<&str> = fa.path.iter().map(|s| s.as_str()).collect();
+                    if model.contains_field(&parts) {
+                        (FieldStatus::Resolved, None)
+                    } else {
+                        let available = model.all_field_paths();
+                        let candidates: Vec<&str> = available.iter().map(|s| s.as_str()).collect();
+                        let suggestion = suggestions::find_closest_match(&path, &candidates, 3)
+                            .map(|(candidate, _)| candidate.to_string());
+                        (FieldStatus::Unknown, suggestion)
+                    }
+                }
+            };
+
+            fields.push(FieldReport {
+                path,
+                status,
+                suggestion,
+            });
+        }
+        dampen_core::Expr::SharedFieldAccess(sa) => {
+            let path = format!("shared.{}", sa.path.join("."));
+            if !fields.iter().any(|f| f.path == path) {
+                fields.push(FieldReport {
+                    path,
+                    status: FieldStatus::Shared,
+                    suggestion: None,
+                });
+            }
+        }
+        dampen_core::Expr::MethodCall(mc) => {
+            collect_fields(&mc.receiver, model_info, locals, fields);
+            for arg in &mc.args {
+                collect_fields(arg, model_info, locals, fields);
+            }
+        }
+        dampen_core::Expr::BinaryOp(bo) => {
+            collect_fields(&bo.left, model_info, locals, fields);
+            collect_fields(&bo.right, model_info, locals, fields);
+        }
+        dampen_core::Expr::UnaryOp(uo) => {
+            collect_fields(&uo.operand, model_info, locals, fields);
+        }
+        dampen_core::Expr::Conditional(ce) => {
+            collect_fields(&ce.condition, model_info, locals, fields);
+            collect_fields(&ce.then_branch, model_info, locals, fields);
+            collect_fields(&ce.else_branch, model_info, locals, fields);
+        }
+        dampen_core::Expr::Literal(_) => {}
+        dampen_core::Expr::Filter(fe) => {
+            collect_fields(&fe.input, model_info, locals, fields);
+            for arg in &fe.args {
+                collect_fields(arg, model_info, locals, fields);
+            }
+        }
+    }
+}
+
+/// Format an expression using binding syntax
+fn format_expr(expr: &dampen_core::Expr) -> String {
+    match expr {
+        dampen_core::Expr::FieldAccess(fa) => fa.path.join("."),
+        dampen_core::Expr::SharedFieldAccess(sa) => format!("shared.{}", sa.path.join(".")),
+        dampen_core::Expr::MethodCall(mc) => {
+            let args: Vec<String> = mc.args.iter().map(format_expr).collect();
+            format!(
+                "{}.{}({})",
+                format_expr(&mc.receiver),
+                mc.method,
+                args.join(", ")
+            )
+        }
+        dampen_core::Expr::BinaryOp(bo) => {
+            let op = match bo.op {
+                dampen_core::BinaryOp::Eq => "==",
+                dampen_core::BinaryOp::Ne => "!=",
+                dampen_core::BinaryOp::Lt => "<",
+                dampen_core::BinaryOp::Le => "<=",
+                dampen_core::BinaryOp::Gt => ">",
+                dampen_core::BinaryOp::Ge => ">=",
+                dampen_core::BinaryOp::And => "&&",
+                dampen_core::BinaryOp::Or => "||",
+                dampen_core::BinaryOp::Add => "+",
+                dampen_core::BinaryOp::Sub => "-",
+                dampen_core::BinaryOp::Mul => "*",
+                dampen_core::BinaryOp::Div => "/",
+            };
+            format!(
+                "{} {} {}",
+                format_expr(&bo.left),
+                op,
+                format_expr(&bo.right)
+            )
+        }
+        dampen_core::Expr::UnaryOp(uo) => {
+            let op = match uo.op {
+                dampen_core::UnaryOp::Not => "!",
+                dampen_core::UnaryOp::Neg => "-",
+            };
+            format!("{}{}", op, format_expr(&uo.operand))
+        }
+        dampen_core::Expr::Conditional(ce) => format!(
+            "if {} then {} else {}",
+            format_expr(&ce.condition),
+            format_expr(&ce.then_branch),
+            format_expr(&ce.else_branch)
+        ),
+        dampen_core::Expr::Literal(lit) => match lit {
+            dampen_core::LiteralExpr::String(s) => format!("{:?}", s),
+            dampen_core::LiteralExpr::Integer(i) => i.to_string(),
+            dampen_core::LiteralExpr::Float(f) => f.to_string(),
+            dampen_core::LiteralExpr::Bool(b) => b.to_string(),
+        },
+        dampen_core::Expr::Filter(fe) => {
+            if fe.args.is_empty() {
+                format!("{} | {}", format_expr(&fe.input), fe.name)
+            } else {
+                let args: Vec<String> = fe.args.iter().map(format_expr).collect();
+                format!(
+                    "{} | {}({})",
+                    format_expr(&fe.input),
+                    fe.name,
+                    args.join(", ")
+                )
+            }
+        }
+    }
+}
+
+/// Print binding reports as an aligned table
+fn print_bindings_table(reports: &[BindingReport], has_model_info: bool) {
+    if reports.is_empty() {
+        println!("No bindings found.");
+        return;
+    }
+
+    let rows: Vec<[String; 5]> = reports
+        .iter()
+        .map(|report| {
+            let fields: Vec<String> = report
+                .fields
+                .iter()
+                .map(|field| match field.status {
+                    FieldStatus::Resolved => format!("{} ✓", field.path),
+                    FieldStatus::Unknown => match &field.suggestion {
+                        Some(suggestion) => {
+                            format!("{} ✗ (did you mean '{}'?)", field.path, suggestion)
+                        }
+                        None => format!("{} ✗", field.path),
+                    },
+                    FieldStatus::Shared => format!("{} (shared)", field.path),
+                    FieldStatus::Local => format!("{} (loop)", field.path),
+                    FieldStatus::Unchecked => field.path.clone(),
+                })
+                .collect();
+
+            [
+                format!("{}:{}", report.line, report.column),
+                report.widget.clone(),
+                report.attribute.clone(),
+                report.expression.clone(),
+                fields.join(", "),
+            ]
+        })
+        .collect();
+
+    let headers = ["LOCATION", "WIDGET", "ATTRIBUTE", "EXPRESSION", "FIELDS"];
+    let mut widths = headers.map(|h| h.chars().count());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let print_row = |cells: [&str; 5]| {
+        let line: Vec<String> = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        println!("{}", line.join("  ").trim_end());
+    };
+
+    print_row(headers);
+    for row in &rows {
+        print_row([&row[0], &row[1], &row[2], &row[3], &row[4]]);
+    }
+
+    let unknown = reports
+        .iter()
+        .flat_map(|report| &report.fields)
+        .filter(|field| field.status == FieldStatus::Unknown)
+        .count();
+
+    println!();
+    if has_model_info {
+        println!(
+            "{} binding(s), {} unresolved field reference(s)",
+            reports.len(),
+            unknown
+        );
+    } else {
+        println!(
+            "{} binding(s) (no model info found, fields were not checked; use --model-info)",
+            reports.len()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::check::model::ModelField;
+
+    fn model_with_fields(names: &[&str]) -> ModelInfo {
+        let mut model = ModelInfo::new();
+        for name in names {
+            model.add_field(ModelField {
+                name: name.to_string(),
+                type_name: "String".to_string(),
+                is_nested: false,
+                children: vec![],
+            });
+        }
+        model
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_collect_bindings_flags_unknown_fields() {
+        let doc = parse(
+            r#"<column><text value="{username}" /><text value="Hi {ussername}!" /></column>"#,
+        )
+        .expect("valid document");
+        let model = model_with_fields(&["username"]);
+
+        let mut reports = Vec::new();
+        collect_bindings(&doc.root, Some(&model), &mut Vec::new(), &mut reports);
+
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].expression, "{username}");
+        assert_eq!(reports[0].fields[0].status, FieldStatus::Resolved);
+        assert_eq!(reports[1].fields[0].path, "ussername");
+        assert_eq!(reports[1].fields[0].status, FieldStatus::Unknown);
+        assert_eq!(reports[1].fields[0].suggestion.as_deref(), Some("username"));
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_collect_bindings_without_model_info() {
+        let doc = parse(r#"<text value="{items.len() > 0}" />"#).expect("valid document");
+
+        let mut reports = Vec::new();
+        collect_bindings(&doc.root, None, &mut Vec::new(), &mut reports);
+
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].expression, "{items.len() > 0}");
+        assert_eq!(reports[0].fields.len(), 1);
+        assert_eq!(reports[0].fields[0].status, FieldStatus::Unchecked);
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_collect_bindings_skips_loop_variables() {
+        let doc = parse(
+            r#"<column>
+                <for each="task" in="{tasks}">
+                    <text value="{index}: {task.title}" />
+                </for>
+                <text value="{task.title}" />
+            </column>"#,
+        )
+        .expect("valid document");
+        let model = model_with_fields(&["tasks"]);
+
+        let mut reports = Vec::new();
+        collect_bindings(&doc.root, Some(&model), &mut Vec::new(), &mut reports);
+
+        assert_eq!(reports.len(), 4);
+        assert_eq!(reports[0].expression, "{tasks}");
+        assert_eq!(reports[0].fields[0].status, FieldStatus::Resolved);
+        assert_eq!(reports[1].fields[0].path, "index");
+        assert_eq!(reports[1].fields[0].status, FieldStatus::Local);
+        assert_eq!(reports[2].fields[0].path, "task.title");
+        assert_eq!(reports[2].fields[0].status, FieldStatus::Local);
+        // Outside the loop, `task` is a model field again
+        assert_eq!(reports[3].fields[0].status, FieldStatus::Unknown);
+    }
+}
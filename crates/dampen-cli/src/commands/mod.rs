@@ -1,19 +1,28 @@
 //! CLI commands
 
 pub mod add;
+pub mod bench;
 pub mod build;
+pub mod bundle;
 pub mod check;
+pub mod debug;
 pub mod inspect;
 pub mod new;
+pub mod preview;
+pub mod profile;
 pub mod release;
 pub mod run;
 pub mod test;
 
 pub use add::{AddArgs, execute as add_execute};
+pub use bench::{BenchArgs, execute as bench_execute};
 pub use build::{BuildArgs, execute as build_execute};
+pub use bundle::{BundleArgs, execute as bundle_execute};
 pub use check::{CheckArgs, execute as check_execute};
+pub use debug::{DebugArgs, execute as debug_execute};
 pub use inspect::{InspectArgs, execute as inspect_execute};
 pub use new::{NewArgs, execute as new_execute};
+pub use preview::{PreviewArgs, execute as preview_execute};
 pub use release::{ReleaseArgs, execute as release_execute};
 pub use run::{RunArgs, execute as run_execute};
 pub use test::{TestArgs, execute as test_execute};
@@ -0,0 +1,169 @@
+#![allow(clippy::print_stderr, clippy::print_stdout)]
+
+//! Preview command - render a view with mock data
+//!
+//! The model and shared state are mocked from the fields the view binds (see
+//! `dampen_core::mock`): lists get three items, numbers and text plausible
+//! values, so the evaluated tree shows what the view renders without the
+//! application running.
+
+use crate::commands::check::model::ModelInfo;
+use dampen_core::mock::{self, MockModel};
+use dampen_core::{AppState, BindingValue, EvaluatedNode, UiBindable, parse};
+use std::fs;
+use std::path::Path;
+
+/// Preview command arguments
+#[derive(clap::Args)]
+pub struct PreviewArgs {
+    /// Path to the .dampen file to preview
+    #[arg(short, long)]
+    file: String,
+
+    /// Path to model info JSON giving the types of the model fields
+    /// (default: auto-discover model.json)
+    #[arg(long)]
+    model_info: Option<String>,
+
+    /// Output format: human (default) or json
+    #[arg(long, default_value = "human")]
+    format: String,
+}
+
+/// Execute the preview command
+///
+/// # Examples
+///
+/// ```bash
+/// # Render a view with mock data
+/// dampen preview --file src/ui/window.dampen
+///
+/// # Type the mock model after model.json, as JSON
+/// dampen preview --file src/ui/window.dampen --model-info model.json --format json
+/// ```
+pub fn execute(args: &PreviewArgs) -> Result<(), String> {
+    let content = fs::read_to_string(&args.file)
+        .map_err(|e| format!("Failed to read file '{}': {}", args.file, e))?;
+    let document = parse(&content).map_err(|e| format!("Parse error: {}", e))?;
+
+    let mut state = mock::app_state(document);
+    if let Some(model_info) = load_model_info(args.model_info.as_deref())? {
+        apply_model_info(&mut state, &model_info);
+    }
+
+    let tree = state
+        .render()
+        .map_err(|e| format!("Failed to render '{}': {}", args.file, e))?;
+
+    match args.format.as_str() {
+        "json" => {
+            let json = serde_json::to_string_pretty(&tree)
+                .map_err(|e| format!("JSON serialization error: {}", e))?;
+            println!("{}", json);
+        }
+        "human" => print_node(&tree, 0),
+        _ => return Err(format!("Unknown format: {}", args.format)),
+    }
+
+    Ok(())
+}
+
+/// Load model info from an explicit path, or from `model.json` if present
+fn load_model_info(explicit_path: Option<&str>) -> Result<Option<ModelInfo>, String> {
+    let path = match explicit_path {
+        Some(path) => Some(Path::new(path).to_path_buf()),
+        None => ["model.json", "src/model.json"]
+            .iter()
+            .map(Path::new)
+            .find(|path| path.exists())
+            .map(Path::to_path_buf),
+    };
+
+    path.map(|path| {
+        ModelInfo::load_from_json(&path)
+            .map_err(|e| format!("Failed to load model info from {}: {}", path.display(), e))
+    })
+    .transpose()
+}
+
+/// Type the mock model fields after the model info
+///
+/// Lists and objects shaped by the view keep their mock items, since the model
+/// info does not describe the fields of list items.
+fn apply_model_info(state: &mut AppState<MockModel, MockModel>, model_info: &ModelInfo) {
+    for (path, type_name) in model_info.typed_field_paths() {
+        let segments: Vec<&str> = path.split('.').collect();
+        if matches!(
+            state.model.get_field(&segments),
+            Some(BindingValue::List(_) | BindingValue::Object(_))
+        ) {
+            continue;
+        }
+        let name = segments.last().copied().unwrap_or(path.as_str());
+        state
+            .model
+            .set(&path, mock::mock_value(name, Some(&type_name)));
+    }
+}
+
+/// Print an evaluated tree, one widget per line
+fn print_node(node: &EvaluatedNode, indent: usize) {
+    let prefix = "  ".repeat(indent);
+
+    let mut line = format!("{}{:?}", prefix, node.kind);
+    if let Some(id) = &node.id {
+        line.push_str(&format!(" #{}", id));
+    }
+    for (name, value) in &node.attributes {
+        line.push_str(&format!(" {}={:?}", name, value.to_display_string()));
+    }
+    for event in &node.events {
+        line.push_str(&format!(" {:?}->{}", event.event, event.handler));
+        if let Some(value) = &event.value {
+            line.push_str(&format!("({:?})", value));
+        }
+    }
+    println!("{}", line);
+
+    for child in &node.children {
+        print_node(child, indent + 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::check::model::ModelField;
+
+    #[test]
+    fn test_model_info_types_mock_fields() {
+        let document = parse(
+            r#"<dampen version="1.1"><column><text value="{count}"/><for each="item" in="{items}"><text value="{item.name}"/></for></column></dampen>"#,
+        )
+        .unwrap_or_else(|_| unreachable!());
+        let mut state = mock::app_state(document);
+
+        let mut model_info = ModelInfo::new();
+        for (name, type_name) in [("count", "f64"), ("items", "Vec<Item>")] {
+            model_info.add_field(ModelField {
+                name: name.to_string(),
+                type_name: type_name.to_string(),
+                is_nested: false,
+                children: Vec::new(),
+            });
+        }
+        apply_model_info(&mut state, &model_info);
+
+        assert!(matches!(
+            state.model.get_field(&["count"]),
+            Some(BindingValue::Float(_))
+        ));
+        // The items keep the fields the view binds
+        assert!(
+            state
+                .evaluate("items.len()")
+                .is_ok_and(|len| len == BindingValue::Integer(3))
+        );
+        assert!(state.render().is_ok());
+    }
+}
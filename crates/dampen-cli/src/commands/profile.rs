@@ -0,0 +1,170 @@
+//! Environment profiles of `dampen run` and `dampen build`
+//!
+//! `--profile staging` reads the values of `.env.staging`, over those of
+//! `.env` when it exists, from the project directory:
+//!
+//! ```text
+//! # .env.staging
+//! API_URL=https://staging.example.com
+//! BETA_FEATURES=true
+//! ```
+//!
+//! The values are passed to cargo as `DAMPEN_CONFIG_<KEY>` variables and bound
+//! in views as `{env.config.api_url}` (see `dampen_core::env`): read when the
+//! view is built in interpreted mode, and compiled into the generated code in
+//! codegen mode. The name of the profile is passed as `DAMPEN_PROFILE`, which
+//! the build script of new projects turns into a `dampen_profile = "staging"`
+//! cfg.
+
+use dampen_core::env::{PROFILE_ENV, config_var};
+use std::path::Path;
+use std::process::Command;
+
+/// Values of an environment profile
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Profile {
+    /// Name of the profile, e.g. `staging`
+    pub name: String,
+    /// Keys and values, in the order of the env files
+    pub values: Vec<(String, String)>,
+}
+
+impl Profile {
+    /// Load the profile `name` from the env files of `dir`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `.env.<name>` does not exist, or if an env file
+    /// can't be read or has an invalid line.
+    pub fn load(dir: &Path, name: &str) -> Result<Self, String> {
+        let path = dir.join(format!(".env.{}", name));
+        if !path.exists() {
+            return Err(format!(
+                "Profile '{}' not found: create {} with its KEY=value pairs",
+                name,
+                path.display()
+            ));
+        }
+
+        let mut values: Vec<(String, String)> = Vec::new();
+        for file in [dir.join(".env"), path] {
+            if !file.exists() {
+                continue;
+            }
+            let content = std::fs::read_to_string(&file)
+                .map_err(|e| format!("Failed to read {}: {}", file.display(), e))?;
+            let parsed = parse_env_file(&content)
+                .map_err(|e| format!("Invalid env file {}: {}", file.display(), e))?;
+            for (key, value) in parsed {
+                values.retain(|(existing, _)| *existing != key);
+                values.push((key, value));
+            }
+        }
+
+        Ok(Profile {
+            name: name.to_string(),
+            values,
+        })
+    }
+
+    /// Pass the profile to `cmd` as environment variables
+    pub fn apply(&self, cmd: &mut Command) {
+        cmd.env(PROFILE_ENV, &self.name);
+        for (key, value) in &self.values {
+            cmd.env(config_var(key), value);
+        }
+    }
+}
+
+/// Parse the `KEY=value` lines of an env file
+///
+/// Blank lines and `#` comments are skipped, `export ` prefixes are allowed,
+/// and values may be wrapped in single or double quotes.
+///
+/// # Errors
+///
+/// Returns an error naming the first line without `=` or with an invalid key.
+pub fn parse_env_file(content: &str) -> Result<Vec<(String, String)>, String> {
+    let mut values = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(format!("line {}: expected KEY=value", index + 1));
+        };
+
+        let key = key.trim();
+        let valid_key = key.chars().next().is_some_and(|c| c.is_ascii_alphabetic())
+            && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid_key {
+            return Err(format!("line {}: invalid key '{}'", index + 1, key));
+        }
+
+        let value = value.trim();
+        let value = ['"', '\'']
+            .iter()
+            .find_map(|quote| {
+                value
+                    .strip_prefix(*quote)
+                    .and_then(|value| value.strip_suffix(*quote))
+            })
+            .unwrap_or(value);
+        values.push((key.to_string(), value.to_string()));
+    }
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_env_file() {
+        let values = parse_env_file(
+            "# Staging\nAPI_URL=https://staging.example.com\n\nexport TITLE=\"My App\"\nbeta='true'\n",
+        )
+        .unwrap();
+        assert_eq!(
+            values,
+            vec![
+                (
+                    "API_URL".to_string(),
+                    "https://staging.example.com".to_string()
+                ),
+                ("TITLE".to_string(), "My App".to_string()),
+                ("beta".to_string(), "true".to_string()),
+            ]
+        );
+
+        assert!(parse_env_file("API_URL").is_err());
+        assert!(parse_env_file("1KEY=value").is_err());
+    }
+
+    #[test]
+    fn test_profile_overrides_base_env_file() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join(".env"),
+            "API_URL=http://localhost\nDEBUG=1\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join(".env.prod"),
+            "API_URL=https://api.example.com\n",
+        )
+        .unwrap();
+
+        let profile = Profile::load(dir.path(), "prod").unwrap();
+        assert_eq!(
+            profile.values,
+            vec![
+                ("DEBUG".to_string(), "1".to_string()),
+                ("API_URL".to_string(), "https://api.example.com".to_string()),
+            ]
+        );
+        assert!(Profile::load(dir.path(), "staging").is_err());
+    }
+}
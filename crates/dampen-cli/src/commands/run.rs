@@ -5,7 +5,10 @@
 //! This command wraps `cargo run` with the `interpreted` feature flag enabled,
 //! providing fast iteration with hot-reload capabilities.
 
-use std::path::Path;
+use crate::commands::profile::Profile;
+use dampen_core::log::LOG_ENV;
+use dampen_dev::state_file::STATE_ENV;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 /// Run command arguments
@@ -30,6 +33,18 @@ pub struct RunArgs {
     /// Additional features to enable (beyond interpreted)
     #[arg(long, value_delimiter = ',')]
     features: Vec<String>,
+
+    /// Log filter of the application, e.g. `debug` or `my_app=trace`
+    #[arg(long, value_name = "FILTER")]
+    log: Option<String>,
+
+    /// State file imported at startup, as exported from the state toolbar (F8)
+    #[arg(long, value_name = "FILE")]
+    state: Option<PathBuf>,
+
+    /// Environment profile: values of `.env.<PROFILE>` bound as `{env.config.*}`
+    #[arg(long, value_name = "PROFILE")]
+    profile: Option<String>,
 }
 
 /// Execute the run command
@@ -60,6 +75,15 @@ pub struct RunArgs {
 ///
 /// # Enable additional features
 /// dampen run --features tokio,logging
+///
+/// # Print debug logs of handlers and hot-reloads
+/// dampen run --log debug
+///
+/// # Start with the models of a state file
+/// dampen run --state fixture.json
+///
+/// # Bind the values of .env.staging as {env.config.*}
+/// dampen run --profile staging
 /// ```
 pub fn execute(args: &RunArgs) -> Result<(), String> {
     // Run checks first (strict=false so warnings don't block, but errors do)
@@ -85,6 +109,26 @@ pub fn execute(args: &RunArgs) -> Result<(), String> {
         return Err("Cargo.toml not found. Are you in a Rust project directory?".to_string());
     }
 
+    // State files are imported by the development tooling of interpreted mode
+    let state_file = match &args.state {
+        Some(_) if args.release => {
+            return Err(
+                "--state is only supported in interpreted mode (without --release)".to_string(),
+            );
+        }
+        Some(path) => Some(
+            path.canonicalize()
+                .map_err(|e| format!("State file '{}' not found: {}", path.display(), e))?,
+        ),
+        None => None,
+    };
+
+    let profile = args
+        .profile
+        .as_deref()
+        .map(|name| Profile::load(Path::new("."), name))
+        .transpose()?;
+
     let mode = if args.release {
         "codegen"
     } else {
@@ -97,6 +141,13 @@ pub fn execute(args: &RunArgs) -> Result<(), String> {
             "Profile: {}",
             if args.release { "release" } else { "debug" }
         );
+        if let Some(ref profile) = profile {
+            eprintln!(
+                "Environment profile: {} ({} values)",
+                profile.name,
+                profile.values.len()
+            );
+        }
     }
 
     // For release mode, we need to build first then run
@@ -139,6 +190,11 @@ pub fn execute(args: &RunArgs) -> Result<(), String> {
 
         build_cmd.arg("--features").arg(features.join(","));
 
+        // Generated code compiles the profile values in
+        if let Some(ref profile) = profile {
+            profile.apply(&mut build_cmd);
+        }
+
         if args.verbose {
             let features_str = features.join(",");
             eprintln!(
@@ -174,6 +230,14 @@ pub fn execute(args: &RunArgs) -> Result<(), String> {
         // In release mode, run release binary
         run_cmd.arg("--release");
 
+        if let Some(ref profile) = profile {
+            profile.apply(&mut run_cmd);
+        }
+
+        if let Some(ref filter) = args.log {
+            run_cmd.env(LOG_ENV, filter);
+        }
+
         // Add application arguments if provided
         if !args.app_args.is_empty() {
             run_cmd.arg("--");
@@ -215,6 +279,18 @@ pub fn execute(args: &RunArgs) -> Result<(), String> {
 
         cmd.arg("--features").arg(features.join(","));
 
+        if let Some(ref filter) = args.log {
+            cmd.env(LOG_ENV, filter);
+        }
+
+        if let Some(ref path) = state_file {
+            cmd.env(STATE_ENV, path);
+        }
+
+        if let Some(ref profile) = profile {
+            profile.apply(&mut cmd);
+        }
+
         // Add application arguments if provided
         if !args.app_args.is_empty() {
             cmd.arg("--");
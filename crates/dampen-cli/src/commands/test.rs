@@ -4,10 +4,22 @@
 //!
 //! This command wraps `cargo test` to provide a consistent CLI experience
 //! for running tests in Dampen applications.
+//!
+//! With `--coverage`, the tests record which handlers were dispatched and which
+//! bindings were evaluated (see `dampen_core::coverage`), and the command reports
+//! the `#[ui_handler]` functions and binding fields the tests never reached.
 
-use std::path::Path;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use dampen_core::coverage::{COVERAGE_DIR_ENV, CoverageHits};
+use dampen_core::{AttributeValue, Expr, InterpolatedPart, WidgetNode};
+use walkdir::WalkDir;
+
+/// Directory the coverage hits are written to, relative to the project
+const COVERAGE_DIR: &str = "target/dampen-coverage";
+
 /// Test command arguments
 #[derive(clap::Args)]
 pub struct TestArgs {
@@ -46,6 +58,10 @@ pub struct TestArgs {
     /// Additional features to enable
     #[arg(long, value_delimiter = ',')]
     features: Vec<String>,
+
+    /// Report handlers never dispatched and bindings never evaluated by the tests
+    #[arg(long)]
+    coverage: bool,
 }
 
 /// Execute the test command
@@ -75,6 +91,9 @@ pub struct TestArgs {
 ///
 /// # Run only ignored tests
 /// dampen test --only-ignored
+///
+/// # Report untested handlers and bindings
+/// dampen test --coverage
 /// ```
 pub fn execute(args: &TestArgs) -> Result<(), String> {
     // Check if Cargo.toml exists
@@ -133,6 +152,21 @@ pub fn execute(args: &TestArgs) -> Result<(), String> {
         cmd.args(&args.test_args);
     }
 
+    // Start from an empty coverage directory, shared by every test process
+    let coverage_dir = if args.coverage {
+        let dir = std::env::current_dir()
+            .map_err(|e| format!("Failed to get current directory: {}", e))?
+            .join(COVERAGE_DIR);
+        if dir.exists() {
+            std::fs::remove_dir_all(&dir)
+                .map_err(|e| format!("Failed to clear {}: {}", dir.display(), e))?;
+        }
+        cmd.env(COVERAGE_DIR_ENV, &dir);
+        Some(dir)
+    } else {
+        None
+    };
+
     // Execute cargo test
     if args.verbose {
         let mut command_str = String::from("cargo test");
@@ -155,9 +189,241 @@ pub fn execute(args: &TestArgs) -> Result<(), String> {
         .status()
         .map_err(|e| format!("Failed to execute cargo: {}", e))?;
 
+    // Coverage is reported for failing runs too
+    if let Some(dir) = coverage_dir {
+        let hits = CoverageHits::read_dir(&dir)
+            .map_err(|e| format!("Failed to read coverage from {}: {}", dir.display(), e))?;
+        let report = CoverageReport::collect(Path::new("src"), &hits)?;
+        report.print();
+    }
+
     if !status.success() {
         return Err("Tests failed".to_string());
     }
 
     Ok(())
 }
+
+/// Declared UI logic that the tests did or did not reach
+#[derive(Debug, Default, PartialEq)]
+struct CoverageReport {
+    handlers: Coverage,
+    bindings: Coverage,
+}
+
+/// Coverage of one kind of declaration
+#[derive(Debug, Default, PartialEq)]
+struct Coverage {
+    total: usize,
+    missed: Vec<String>,
+}
+
+impl Coverage {
+    fn new(declared: BTreeSet<String>, hits: &BTreeSet<String>) -> Self {
+        Self {
+            total: declared.len(),
+            missed: declared.difference(hits).cloned().collect(),
+        }
+    }
+
+    fn print(&self, label: &str, verb: &str) {
+        let covered = self.total - self.missed.len();
+        let percent = if self.total == 0 {
+            100.0
+        } else {
+            covered as f64 * 100.0 / self.total as f64
+        };
+        println!(
+            "  {}: {}/{} {} ({:.1}%)",
+            label, covered, self.total, verb, percent
+        );
+        for name in &self.missed {
+            println!("    - {}", name);
+        }
+    }
+}
+
+impl CoverageReport {
+    /// Compare the handlers and bindings declared under `src_dir` with the recorded hits
+    fn collect(src_dir: &Path, hits: &CoverageHits) -> Result<Self, String> {
+        let mut handlers = BTreeSet::new();
+        let mut bindings = BTreeSet::new();
+
+        for path in source_files(src_dir) {
+            let is_rust = path.extension().is_some_and(|ext| ext == "rs");
+            let is_ui = path.extension().is_some_and(|ext| ext == "dampen");
+            if !is_rust && !is_ui {
+                continue;
+            }
+            let content = std::fs::read_to_string(&path)
+                .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+            if is_rust {
+                handlers.extend(declared_handlers(&content));
+            } else if let Ok(document) = dampen_core::parse(&content) {
+                // Invalid files are reported by `dampen check`, not here
+                collect_binding_paths(&document.root, &mut bindings);
+            }
+        }
+
+        Ok(Self {
+            handlers: Coverage::new(handlers, &hits.handlers),
+            bindings: Coverage::new(bindings, &hits.bindings),
+        })
+    }
+
+    fn print(&self) {
+        println!();
+        println!("UI coverage:");
+        self.handlers.print("Handlers", "dispatched");
+        self.bindings.print("Bindings", "evaluated");
+    }
+}
+
+fn source_files(dir: &Path) -> impl Iterator<Item = PathBuf> {
+    WalkDir::new(dir)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.into_path())
+}
+
+/// Names of the functions annotated with `#[ui_handler]` in a Rust source file
+pub(crate) fn declared_handlers(content: &str) -> Vec<String> {
+    let mut handlers = Vec::new();
+    let mut pending = false;
+
+    for line in content.lines().map(str::trim) {
+        if line.starts_with("#[ui_handler") {
+            pending = true;
+            continue;
+        }
+        if !pending || line.starts_with("#[") || line.starts_with("//") {
+            continue;
+        }
+        pending = false;
+
+        // `fn name(`, possibly after `pub`, `pub(crate)`, ...
+        let Some((_, rest)) = line.split_once("fn ") else {
+            continue;
+        };
+        let name: String = rest
+            .chars()
+            .take_while(|c| c.is_alphanumeric() || *c == '_')
+            .collect();
+        if !name.is_empty() {
+            handlers.push(name);
+        }
+    }
+
+    handlers
+}
+
+/// Collect the field paths read by the bindings of a node and its children
+///
+/// Paths are kept as written, which is how the evaluator records them: loop
+/// variables (`item.name`) and `model` scopes are not resolved.
+fn collect_binding_paths(node: &WidgetNode, paths: &mut BTreeSet<String>) {
+    let attributes = node
+        .attributes
+        .values()
+        .chain(node.breakpoint_attributes.values().flat_map(|a| a.values()));
+
+    for value in attributes {
+        match value {
+            AttributeValue::Static(_) => {}
+            AttributeValue::Binding(binding) => collect_expr_paths(&binding.expr, paths),
+            AttributeValue::Interpolated(parts) => {
+                for part in parts {
+                    if let InterpolatedPart::Binding(binding) = part {
+                        collect_expr_paths(&binding.expr, paths);
+                    }
+                }
+            }
+        }
+    }
+
+    for child in &node.children {
+        collect_binding_paths(child, paths);
+    }
+}
+
+fn collect_expr_paths(expr: &Expr, paths: &mut BTreeSet<String>) {
+    match expr {
+        Expr::FieldAccess(field) => {
+            paths.insert(field.path.join("."));
+        }
+        Expr::SharedFieldAccess(shared) => {
+            paths.insert(format!("shared.{}", shared.path.join(".")));
+        }
+        Expr::MethodCall(call) => {
+            collect_expr_paths(&call.receiver, paths);
+            for arg in &call.args {
+                collect_expr_paths(arg, paths);
+            }
+        }
+        Expr::BinaryOp(binary) => {
+            collect_expr_paths(&binary.left, paths);
+            collect_expr_paths(&binary.right, paths);
+        }
+        Expr::UnaryOp(unary) => collect_expr_paths(&unary.operand, paths),
+        Expr::Conditional(conditional) => {
+            collect_expr_paths(&conditional.condition, paths);
+            collect_expr_paths(&conditional.then_branch, paths);
+            collect_expr_paths(&conditional.else_branch, paths);
+        }
+        Expr::Filter(filter) => {
+            collect_expr_paths(&filter.input, paths);
+            for arg in &filter.args {
+                collect_expr_paths(arg, paths);
+            }
+        }
+        Expr::Literal(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_declared_handlers_finds_annotated_functions() {
+        let source = r#"
+            #[ui_handler]
+            pub fn increment(model: &mut Model) {}
+
+            #[ui_handler]
+            /// Resets the counter
+            #[allow(dead_code)]
+            pub(crate) fn reset(model: &mut Model) {}
+
+            fn helper() {}
+        "#;
+
+        assert_eq!(declared_handlers(source), vec!["increment", "reset"]);
+    }
+
+    #[test]
+    fn test_coverage_lists_missed_declarations() -> Result<(), String> {
+        let document = dampen_core::parse(
+            r#"<column>
+                <text value="{count} items" />
+                <text value="{if user.admin then shared.theme else 'none'}" />
+                <button label="Add" on_click="increment" />
+            </column>"#,
+        )
+        .map_err(|e| e.to_string())?;
+        let mut bindings = BTreeSet::new();
+        collect_binding_paths(&document.root, &mut bindings);
+        assert_eq!(
+            bindings.iter().map(String::as_str).collect::<Vec<_>>(),
+            vec!["count", "shared.theme", "user.admin"]
+        );
+
+        let hits = BTreeSet::from(["count".to_string(), "user.admin".to_string()]);
+        let coverage = Coverage::new(bindings, &hits);
+        assert_eq!(coverage.total, 3);
+        assert_eq!(coverage.missed, vec!["shared.theme"]);
+        Ok(())
+    }
+}
@@ -23,18 +23,30 @@ pub enum Commands {
     /// Add UI windows or other components
     Add(commands::AddArgs),
 
+    /// Run benchmarks and report regressions against a stored baseline
+    Bench(commands::BenchArgs),
+
     /// Build application (interpreted by default, use --release for codegen)
     Build(commands::BuildArgs),
 
+    /// Package a release build as installers and bundles of the platform
+    Bundle(commands::BundleArgs),
+
     /// Validate .dampen files without building
     Check(commands::CheckArgs),
 
+    /// Talk to the debug server of a running application
+    Debug(commands::DebugArgs),
+
     /// Inspect IR or generated code
     Inspect(commands::InspectArgs),
 
     /// Create a new Dampen project
     New(commands::NewArgs),
 
+    /// Render a view with mock data
+    Preview(commands::PreviewArgs),
+
     /// Build optimized production binary (alias for build --release)
     Release(commands::ReleaseArgs),
 
@@ -51,10 +63,14 @@ pub fn run() {
 
     let result = match cli.command {
         Commands::Add(args) => commands::add_execute(&args),
+        Commands::Bench(args) => commands::bench_execute(&args),
         Commands::Build(args) => commands::build_execute(&args).map_err(|e| e.to_string()),
+        Commands::Bundle(args) => commands::bundle_execute(&args),
         Commands::Check(args) => commands::check_execute(&args).map_err(|e| e.to_string()),
+        Commands::Debug(args) => commands::debug_execute(&args),
         Commands::Inspect(args) => commands::inspect_execute(&args),
         Commands::New(args) => commands::new_execute(&args),
+        Commands::Preview(args) => commands::preview_execute(&args),
         Commands::Release(args) => commands::release_execute(&args),
         Commands::Run(args) => commands::run_execute(&args).map_err(|e| e.to_string()),
         Commands::Test(args) => commands::test_execute(&args),
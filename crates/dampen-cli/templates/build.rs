@@ -11,6 +11,11 @@ use std::fs;
 #[cfg(feature = "codegen")]
 use std::path::{Path, PathBuf};
 
+/// Style library files merged into every view, relative to src/ui
+/// (e.g. `&["styles/buttons.dampen"]`), as listed in `styles` of `#[dampen_app]`
+#[cfg(feature = "codegen")]
+const STYLE_LIBRARIES: &[&str] = &[];
+
 fn main() {
     // Only generate code in codegen mode
     #[cfg(feature = "codegen")]
@@ -66,8 +71,41 @@ fn generate_ui_code() {
         None
     };
 
-    // Find all .dampen files
-    let dampen_files = find_dampen_files(&ui_dir);
+    // Parse the style libraries, whose themes and classes every view gets
+    let mut style_libraries = Vec::new();
+    for library in STYLE_LIBRARIES {
+        let library_path = ui_dir.join(library);
+        println!("cargo:rerun-if-changed={}", library_path.display());
+        match fs::read_to_string(&library_path) {
+            Ok(content) => match parser::parse(&content) {
+                Ok(doc) => style_libraries.push(doc),
+                Err(e) => {
+                    eprintln!(
+                        "Warning: Failed to parse style library {}: {}",
+                        library_path.display(),
+                        e
+                    );
+                }
+            },
+            Err(e) => {
+                eprintln!(
+                    "Warning: Failed to read style library {}: {}",
+                    library_path.display(),
+                    e
+                );
+            }
+        }
+    }
+
+    // Find all .dampen files, style libraries are not views
+    let dampen_files: Vec<_> = find_dampen_files(&ui_dir)
+        .into_iter()
+        .filter(|file| {
+            !STYLE_LIBRARIES
+                .iter()
+                .any(|library| *file == ui_dir.join(library))
+        })
+        .collect();
 
     if dampen_files.is_empty() {
         eprintln!("Warning: No .dampen files found in src/ui/");
@@ -114,6 +152,7 @@ fn generate_ui_code() {
                 name: name.clone(),
                 param_type: None,
                 returns_command: false,
+                palette: None,
             })
             .collect();
 
@@ -126,7 +165,7 @@ fn generate_ui_code() {
             }
         };
 
-        let document = match parser::parse(&dampen_content) {
+        let document = match parser::parse_with_styles(&dampen_content, &style_libraries) {
             Ok(doc) => doc,
             Err(e) => {
                 eprintln!("Error: Failed to parse {}: {}", dampen_file.display(), e);
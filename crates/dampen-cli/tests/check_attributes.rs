@@ -337,7 +337,13 @@ fn test_textinput_password_attribute() {
 fn test_scrollable_no_specific_attributes() {
     let schema = WidgetAttributeSchema::for_widget(&WidgetKind::Scrollable);
 
-    assert!(schema.optional.is_empty());
+    // The scoped sub-model attribute shared by containers, the distance
+    // triggering `on_reach_end` and the bound scroll position
+    assert_eq!(schema.optional.len(), 4);
+    assert!(schema.optional.contains("model"));
+    assert!(schema.optional.contains("reach_end_distance"));
+    assert!(schema.optional.contains("offset"));
+    assert!(schema.optional.contains("stick_to_bottom"));
     assert!(schema.all_valid().contains("on_scroll"));
 }
 
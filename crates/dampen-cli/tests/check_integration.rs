@@ -33,6 +33,7 @@ fn test_unknown_attribute_detection_integration() {
         custom_widgets: None,
         strict: false,
         show_widget_versions: false,
+        watch: false,
     };
 
     let result = execute(&args);
@@ -116,6 +117,7 @@ fn test_handler_validation_with_registry() {
         custom_widgets: None,
         strict: false,
         show_widget_versions: false,
+        watch: false,
     };
 
     let result = execute(&args);
@@ -159,6 +161,7 @@ fn test_handler_validation_with_unknown_handler() {
         custom_widgets: None,
         strict: false,
         show_widget_versions: false,
+        watch: false,
     };
 
     let result = execute(&args);
@@ -191,6 +194,7 @@ fn test_handler_validation_without_registry() {
         custom_widgets: None,
         strict: false,
         show_widget_versions: false,
+        watch: false,
     };
 
     let result = execute(&args);
@@ -245,6 +249,7 @@ fn test_binding_validation_with_model() {
         custom_widgets: None,
         strict: false,
         show_widget_versions: false,
+        watch: false,
     };
 
     let result = execute(&args);
@@ -289,6 +294,7 @@ fn test_binding_validation_with_invalid_field() {
         custom_widgets: None,
         strict: false,
         show_widget_versions: false,
+        watch: false,
     };
 
     let result = execute(&args);
@@ -321,6 +327,7 @@ fn test_binding_validation_without_model() {
         custom_widgets: None,
         strict: false,
         show_widget_versions: false,
+        watch: false,
     };
 
     let result = execute(&args);
@@ -355,6 +362,7 @@ fn test_valid_radio_group_integration() {
         custom_widgets: None,
         strict: false,
         show_widget_versions: false,
+        watch: false,
     };
 
     let result = execute(&args);
@@ -390,6 +398,7 @@ fn test_valid_theme_integration() {
         custom_widgets: None,
         strict: false,
         show_widget_versions: false,
+        watch: false,
     };
 
     let result = execute(&args);
@@ -424,6 +433,7 @@ fn test_strict_mode_with_errors() {
         custom_widgets: None,
         strict: false,
         show_widget_versions: false,
+        watch: false,
     };
 
     let result_normal = execute(&args_normal);
@@ -439,6 +449,7 @@ fn test_strict_mode_with_errors() {
         custom_widgets: None,
         strict: true,
         show_widget_versions: false,
+        watch: false,
     };
 
     let result_strict = execute(&args_strict);
@@ -473,6 +484,7 @@ fn test_strict_mode_with_no_warnings() {
         custom_widgets: None,
         strict: true,
         show_widget_versions: false,
+        watch: false,
     };
 
     let result = execute(&args);
@@ -505,6 +517,7 @@ fn test_required_attribute_validation_text_missing_value() {
         custom_widgets: None,
         strict: false,
         show_widget_versions: false,
+        watch: false,
     };
 
     let result = execute(&args);
@@ -541,6 +554,7 @@ fn test_required_attribute_validation_image_missing_src() {
         custom_widgets: None,
         strict: false,
         show_widget_versions: false,
+        watch: false,
     };
 
     let result = execute(&args);
@@ -577,6 +591,7 @@ fn test_required_attribute_validation_radio_missing_label() {
         custom_widgets: None,
         strict: false,
         show_widget_versions: false,
+        watch: false,
     };
 
     let result = execute(&args);
@@ -615,6 +630,7 @@ fn test_required_attribute_validation_all_present() {
         custom_widgets: None,
         strict: false,
         show_widget_versions: false,
+        watch: false,
     };
 
     let result = execute(&args);
@@ -703,6 +719,7 @@ fn test_complete_validation_pipeline_all_flags() {
         custom_widgets: None,
         strict: true,
         show_widget_versions: false,
+        watch: false,
     };
 
     let result = execute(&args);
@@ -763,6 +780,7 @@ fn test_complete_validation_pipeline_with_errors() {
         custom_widgets: None,
         strict: true,
         show_widget_versions: false,
+        watch: false,
     };
 
     let result = execute(&args);
@@ -15,6 +15,7 @@ fn create_check_args(input: Option<String>, verbose: bool) -> CheckArgs {
         custom_widgets: None,
         strict: false,
         show_widget_versions: false,
+        watch: false,
     }
 }
 
@@ -103,6 +104,7 @@ fn test_explicit_handlers_overrides_auto_discovery() {
         custom_widgets: None,
         strict: false,
         show_widget_versions: false,
+        watch: false,
     };
 
     // Change to temp directory
@@ -143,6 +145,7 @@ fn test_backward_compatibility_with_explicit_paths() {
         custom_widgets: None,
         strict: false,
         show_widget_versions: false,
+        watch: false,
     };
 
     let result = execute(&args);
@@ -91,8 +91,7 @@ fn test_generated_model_has_ui_model_derive() {
         "Should define Model struct"
     );
     assert!(
-        rs_content
-            .contains("use dampen_macros::{UiModel, dampen_ui, ui_handler, inventory_handlers}"),
+        rs_content.contains("use dampen_macros::{UiModel, dampen_ui, ui_handler}"),
         "Should import UiModel and handler macros"
     );
 }
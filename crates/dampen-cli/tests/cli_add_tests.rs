@@ -358,3 +358,47 @@ fn test_add_ui_error_message_helpful() {
                 .or(predicate::str::contains("remove the existing file")),
         ); // Actionable suggestion
 }
+
+#[test]
+#[serial]
+fn test_add_view_scaffolds_discoverable_view() {
+    let temp = TempDir::new().unwrap();
+    let project_path = create_test_project(&temp);
+    fs::write(
+        project_path.join("src/main.rs"),
+        r#"#[dampen_app(ui_dir = "src/ui", message_type = "Message", exclude = ["debug"])]
+struct App;
+
+fn main() {}
+"#,
+    )
+    .unwrap();
+
+    dampen_cmd()
+        .arg("add")
+        .arg("view")
+        .arg("dashboard")
+        .current_dir(&project_path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Created UI window 'dashboard'"))
+        .stdout(predicate::str::contains("Auto-discovered by #[dampen_app]"));
+
+    let rs_content = fs::read_to_string(project_path.join("src/ui/dashboard.rs")).unwrap();
+    assert!(rs_content.contains("#[derive(Default, UiModel"));
+    assert!(!rs_content.contains("inventory_handlers!"));
+    assert!(project_path.join("src/ui/dashboard.dampen").exists());
+
+    let mod_content = fs::read_to_string(project_path.join("src/ui/mod.rs")).unwrap();
+    assert!(mod_content.contains("pub mod dashboard;"));
+
+    // A view matching an exclude pattern is created, with a warning
+    dampen_cmd()
+        .arg("add")
+        .arg("view")
+        .arg("debug")
+        .current_dir(&project_path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("exclude pattern \"debug\""));
+}
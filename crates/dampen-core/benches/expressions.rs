@@ -0,0 +1,72 @@
+//! Expression evaluation benchmarks
+//!
+//! Measures the evaluation throughput of binding expressions against a model,
+//! from plain field access to method calls and conditionals. Expressions are
+//! parsed once, as the runtime does when loading a document.
+
+use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
+use dampen_core::{BindingExpr, evaluate_binding_expr, parse_binding_expr};
+use dampen_macros::UiModel;
+
+#[derive(Default, UiModel)]
+struct Model {
+    count: i64,
+    name: String,
+    items: Vec<String>,
+    is_loading: bool,
+}
+
+/// Expressions by kind
+const EXPRESSIONS: [(&str, &str); 6] = [
+    ("field", "count"),
+    ("arithmetic", "count * 2 + 1"),
+    ("comparison", "count > 0 && !is_loading"),
+    ("method", "name.to_uppercase()"),
+    ("collection", "items.len() > 0"),
+    (
+        "conditional",
+        "if is_loading then 'Loading...' else 'Ready'",
+    ),
+];
+
+fn model() -> Model {
+    Model {
+        count: 42,
+        name: "Alice".to_string(),
+        items: (0..100).map(|i| format!("Item {}", i)).collect(),
+        is_loading: false,
+    }
+}
+
+fn parsed(expr: &str) -> BindingExpr {
+    parse_binding_expr(expr, 0, 1, 1).unwrap_or_else(|e| unreachable!("{}: {}", expr, e))
+}
+
+fn bench_expressions(c: &mut Criterion) {
+    let model = model();
+    let mut group = c.benchmark_group("expressions");
+    group.throughput(Throughput::Elements(1));
+
+    for (kind, expr) in EXPRESSIONS {
+        let expr = parsed(expr);
+        group.bench_with_input(BenchmarkId::new("evaluate", kind), &expr, |b, expr| {
+            b.iter(|| evaluate_binding_expr(black_box(expr), &model));
+        });
+    }
+
+    // All kinds in a row, as a widget tree binding several attributes would
+    let all: Vec<BindingExpr> = EXPRESSIONS.iter().map(|(_, expr)| parsed(expr)).collect();
+    group.throughput(Throughput::Elements(all.len() as u64));
+    group.bench_function("evaluate_all", |b| {
+        b.iter(|| {
+            for expr in &all {
+                let _ = black_box(evaluate_binding_expr(expr, &model));
+            }
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_expressions);
+criterion_main!(benches);
@@ -0,0 +1,69 @@
+//! Parser benchmarks
+//!
+//! Measures parse time of small, medium and large documents. Throughput is
+//! reported in bytes, so that the results read as parse time per KB of XML.
+
+use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
+use dampen_core::parse;
+
+/// Document sizes, as number of list rows
+const SIZES: [(&str, usize); 3] = [("small", 10), ("medium", 100), ("large", 1000)];
+
+/// Generate a styled view with `rows` rows bound to the model
+fn document(rows: usize) -> String {
+    let mut xml = String::from(
+        r##"<dampen version="1.1">
+    <styles>
+        <style name="card">
+            <base padding="12" border_radius="8" border_width="1" border_color="#dddddd" background="#ffffff" />
+            <hover background="#f5f5f5" />
+        </style>
+        <style name="label">
+            <base color="#333333" />
+        </style>
+        <style name="btn">
+            <base padding="8 16" border_radius="6" />
+            <disabled opacity="0.5" />
+        </style>
+        <style name="btn_primary">
+            <base background="#3498db" color="#ffffff" />
+            <hover background="#5dade2" />
+            <active background="#2874a6" />
+        </style>
+    </styles>
+    <column spacing="10" padding="20">
+        <text value="Total: {count}" size="24" class="label" />
+"##,
+    );
+
+    for i in 0..rows {
+        xml.push_str(&format!(
+            r#"        <row spacing="8" class="card">
+            <text value="Item {i}: {{name}}" class="label" />
+            <checkbox label="Done" checked="{{done}}" on_toggle="toggle" />
+            <button label="Add" on_click="increment" enabled="{{count < 100}}" class="btn btn_primary" />
+        </row>
+"#
+        ));
+    }
+
+    xml.push_str("    </column>\n</dampen>\n");
+    xml
+}
+
+fn bench_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parser");
+
+    for (name, rows) in SIZES {
+        let xml = document(rows);
+        group.throughput(Throughput::Bytes(xml.len() as u64));
+        group.bench_with_input(BenchmarkId::new("parse", name), &xml, |b, xml| {
+            b.iter(|| parse(black_box(xml)));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse);
+criterion_main!(benches);
@@ -0,0 +1,144 @@
+//! Files loaded by views
+//!
+//! `<image>` and `<svg>` widgets load the files of their static `src`, `path`,
+//! `placeholder` and `error_src` attributes:
+//!
+//! ```xml
+//! <image src="assets/logo.png" mobile-src="assets/logo-small.png" />
+//! <svg src="assets/icons/check.svg" />
+//! ```
+//!
+//! Paths are relative to the working directory in interpreted mode, and
+//! [`resolve`] falls back to the directory of the executable, so an installed
+//! application finds the assets bundled next to it wherever it is started
+//! from. Generated code embeds the files it finds when it is built, with
+//! `include_bytes!`, and reads the others with [`read`].
+//!
+//! `dampen build --release` checks that every referenced file exists with
+//! [`missing`], and `dampen bundle` copies them into the bundles.
+
+use std::path::{Path, PathBuf};
+
+use crate::ir::{AttributeValue, DampenDocument, WidgetKind, WidgetNode};
+
+/// Attributes of `<image>` and `<svg>` naming a file
+pub const PATH_ATTRIBUTES: [&str; 4] = ["src", "path", "placeholder", "error_src"];
+
+/// Whether `src` names a local file, as opposed to a URL or inline data
+pub fn is_local(src: &str) -> bool {
+    !src.is_empty()
+        && !["http://", "https://", "data:"]
+            .iter()
+            .any(|scheme| src.starts_with(scheme))
+}
+
+/// Static local file paths of the `<image>` and `<svg>` widgets of `node` and
+/// its children, breakpoint variants included, in document order
+pub fn referenced_paths(node: &WidgetNode) -> Vec<String> {
+    let mut paths = Vec::new();
+    collect_paths(node, &mut paths);
+    paths
+}
+
+fn collect_paths(node: &WidgetNode, paths: &mut Vec<String>) {
+    if matches!(node.kind, WidgetKind::Image | WidgetKind::Svg) {
+        let attributes =
+            std::iter::once(&node.attributes).chain(node.breakpoint_attributes.values());
+        for attributes in attributes {
+            for name in PATH_ATTRIBUTES {
+                if let Some(AttributeValue::Static(path)) = attributes.get(name)
+                    && is_local(path)
+                    && !paths.contains(path)
+                {
+                    paths.push(path.clone());
+                }
+            }
+        }
+    }
+    for child in node.children.iter() {
+        collect_paths(child, paths);
+    }
+}
+
+/// Find the file `path` refers to in a project, looking in `root` then in its
+/// parent directories
+///
+/// Views of workspace members often use paths relative to the workspace
+/// root, such as `examples/gallery/assets/photo.jpg`.
+pub fn locate(path: &str, root: &Path) -> Option<PathBuf> {
+    let path = Path::new(path);
+    if path.is_absolute() {
+        return path.is_file().then(|| path.to_path_buf());
+    }
+    root.ancestors()
+        .map(|dir| dir.join(path))
+        .find(|candidate| candidate.is_file())
+}
+
+/// Paths referenced by `document` that [`locate`] can't find from `root`
+pub fn missing(document: &DampenDocument, root: &Path) -> Vec<String> {
+    referenced_paths(&document.root)
+        .into_iter()
+        .filter(|path| locate(path, root).is_none())
+        .collect()
+}
+
+/// Path of the asset `path` at runtime
+///
+/// Relative paths missing from the working directory are looked up in the
+/// directory of the executable. The path is returned unchanged when it is
+/// found in neither, so that loading it reports the original path.
+pub fn resolve(path: &str) -> PathBuf {
+    let relative = Path::new(path);
+    if relative.is_absolute() || relative.exists() {
+        return relative.to_path_buf();
+    }
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(|dir| dir.join(relative)))
+        .filter(|candidate| candidate.exists())
+        .unwrap_or_else(|| relative.to_path_buf())
+}
+
+/// Contents of the asset `path`, empty if it can't be read
+pub fn read(path: &str) -> Vec<u8> {
+    std::fs::read(resolve(path)).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_referenced_paths_and_missing() {
+        let document = crate::parse(
+            r#"<dampen version="1.1">
+                <column>
+                    <image src="assets/logo.png" mobile-src="assets/logo-small.png" />
+                    <image src="{avatar}" placeholder="assets/logo.png" />
+                    <image src="https://example.com/banner.png" />
+                    <svg path="Cargo.toml" />
+                    <text value="assets/readme.png" />
+                </column>
+            </dampen>"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            referenced_paths(&document.root),
+            vec!["assets/logo.png", "assets/logo-small.png", "Cargo.toml"]
+        );
+
+        // Cargo.toml is found in the parent directory of src
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        assert_eq!(
+            missing(&document, &root),
+            vec!["assets/logo.png", "assets/logo-small.png"]
+        );
+        assert_eq!(
+            resolve("assets/missing.png"),
+            PathBuf::from("assets/missing.png")
+        );
+        assert!(read("assets/missing.png").is_empty());
+    }
+}
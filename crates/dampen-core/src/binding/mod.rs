@@ -101,6 +101,12 @@ pub enum BindingValue {
     List(Vec<BindingValue>),
     /// Object/record with named fields
     Object(std::collections::HashMap<String, BindingValue>),
+    /// Date and time with its UTC offset
+    DateTime(chrono::DateTime<chrono::FixedOffset>),
+    /// Signed span of time
+    Duration(chrono::TimeDelta),
+    /// Exact decimal number (e.g., money)
+    Decimal(rust_decimal::Decimal),
     /// Custom opaque value (not serializable)
     #[serde(skip)]
     Custom(std::sync::Arc<dyn std::any::Any + Send + Sync>),
@@ -117,6 +123,9 @@ impl PartialEq for BindingValue {
             (Self::Bool(l0), Self::Bool(r0)) => l0 == r0,
             (Self::List(l0), Self::List(r0)) => l0 == r0,
             (Self::Object(l0), Self::Object(r0)) => l0 == r0,
+            (Self::DateTime(l0), Self::DateTime(r0)) => l0 == r0,
+            (Self::Duration(l0), Self::Duration(r0)) => l0 == r0,
+            (Self::Decimal(l0), Self::Decimal(r0)) => l0 == r0,
             (Self::Custom(l0), Self::Custom(r0)) => std::sync::Arc::ptr_eq(l0, r0),
             (Self::None, Self::None) => true,
             _ => false,
@@ -127,7 +136,9 @@ impl PartialEq for BindingValue {
 impl BindingValue {
     /// Convert to display string for rendering
     ///
-    /// Used when a binding value needs to be displayed as text.
+    /// Used when a binding value needs to be displayed as text. Dates are
+    /// shown as `YYYY-MM-DD HH:MM:SS` and durations as `H:MM:SS`; use the
+    /// `date` and `duration` filters for other formats.
     ///
     /// # Examples
     ///
@@ -145,6 +156,9 @@ impl BindingValue {
             BindingValue::Bool(b) => b.to_string(),
             BindingValue::List(l) => format!("[{} items]", l.len()),
             BindingValue::Object(map) => format!("{{Object with {} fields}}", map.len()),
+            BindingValue::DateTime(dt) => dt.format("%Y-%m-%d %H:%M:%S").to_string(),
+            BindingValue::Duration(d) => format_clock_duration(d),
+            BindingValue::Decimal(d) => d.to_string(),
             BindingValue::Custom(_) => "[Custom Value]".to_string(),
             BindingValue::None => String::new(),
         }
@@ -160,6 +174,8 @@ impl BindingValue {
     /// * Non-empty strings → `true`
     /// * Non-zero numbers → `true`
     /// * Non-empty lists → `true`
+    /// * Dates → `true`
+    /// * Non-zero durations and decimals → `true`
     /// * `None` → `false`
     /// * `Custom` → `true`
     pub fn to_bool(&self) -> bool {
@@ -170,6 +186,9 @@ impl BindingValue {
             BindingValue::Float(f) => *f != 0.0,
             BindingValue::List(l) => !l.is_empty(),
             BindingValue::Object(map) => !map.is_empty(),
+            BindingValue::DateTime(_) => true,
+            BindingValue::Duration(d) => !d.is_zero(),
+            BindingValue::Decimal(d) => !d.is_zero(),
             BindingValue::Custom(_) => true,
             BindingValue::None => false,
         }
@@ -193,6 +212,75 @@ impl BindingValue {
     }
 }
 
+/// An option of a `<pick_list>` or `<combobox>`: the text shown and the value dispatched
+///
+/// Options of a static list use the same string for both; options bound to a
+/// collection take them from the `display` and `value` attributes.
+///
+/// # Example
+///
+/// ```rust
+/// use dampen_core::SelectOption;
+///
+/// let option = SelectOption::new("France", "fr");
+/// assert_eq!(option.to_string(), "France");
+/// assert_eq!(option.value, "fr");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectOption {
+    /// Text shown in the list
+    pub label: String,
+    /// Value passed to the `on_select` handler
+    pub value: String,
+}
+
+impl SelectOption {
+    /// Create an option from its label and value
+    pub fn new(label: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            value: value.into(),
+        }
+    }
+
+    /// Options of a comma-separated static list, e.g. `"Small, Medium, Large"`
+    pub fn parse_list(list: &str) -> Vec<Self> {
+        list.split(',')
+            .map(str::trim)
+            .filter(|option| !option.is_empty())
+            .map(|option| Self::new(option, option))
+            .collect()
+    }
+
+    /// The option whose value is `selected`, if any
+    pub fn find<'a>(options: &'a [Self], selected: &str) -> Option<&'a Self> {
+        options.iter().find(|option| option.value == selected)
+    }
+}
+
+impl std::fmt::Display for SelectOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.label)
+    }
+}
+
+/// Format a duration as `H:MM:SS`, e.g. `1:02:03` or `-0:00:30`
+fn format_clock_duration(duration: &chrono::TimeDelta) -> String {
+    let sign = if *duration < chrono::TimeDelta::zero() {
+        "-"
+    } else {
+        ""
+    };
+    let total = duration.num_seconds().unsigned_abs();
+    format!(
+        "{}{}:{:02}:{:02}",
+        sign,
+        total / 3600,
+        (total / 60) % 60,
+        total % 60
+    )
+}
+
 /// Trait for converting types to BindingValue
 ///
 /// This trait is implemented for common Rust types to allow them to be
@@ -288,6 +376,52 @@ impl<T: ToBindingValue> ToBindingValue for std::collections::HashMap<String, T>
     }
 }
 
+/// Convert `DateTime<Tz>` to `BindingValue::DateTime`, keeping its offset
+impl<Tz: chrono::TimeZone> ToBindingValue for chrono::DateTime<Tz> {
+    fn to_binding_value(&self) -> BindingValue {
+        BindingValue::DateTime(self.fixed_offset())
+    }
+}
+
+/// Convert `NaiveDateTime` to `BindingValue::DateTime`, interpreted as UTC
+impl ToBindingValue for chrono::NaiveDateTime {
+    fn to_binding_value(&self) -> BindingValue {
+        BindingValue::DateTime(self.and_utc().fixed_offset())
+    }
+}
+
+/// Convert `NaiveDate` to `BindingValue::DateTime` at midnight UTC
+impl ToBindingValue for chrono::NaiveDate {
+    fn to_binding_value(&self) -> BindingValue {
+        BindingValue::DateTime(
+            self.and_time(chrono::NaiveTime::MIN)
+                .and_utc()
+                .fixed_offset(),
+        )
+    }
+}
+
+/// Convert `TimeDelta` to `BindingValue::Duration`
+impl ToBindingValue for chrono::TimeDelta {
+    fn to_binding_value(&self) -> BindingValue {
+        BindingValue::Duration(*self)
+    }
+}
+
+/// Convert `std::time::Duration` to `BindingValue::Duration`, saturating at `TimeDelta::MAX`
+impl ToBindingValue for std::time::Duration {
+    fn to_binding_value(&self) -> BindingValue {
+        BindingValue::Duration(chrono::TimeDelta::from_std(*self).unwrap_or(chrono::TimeDelta::MAX))
+    }
+}
+
+/// Convert `Decimal` to `BindingValue::Decimal`
+impl ToBindingValue for rust_decimal::Decimal {
+    fn to_binding_value(&self) -> BindingValue {
+        BindingValue::Decimal(*self)
+    }
+}
+
 /// Convert `Arc<dyn Any + Send + Sync>` to `BindingValue::Custom`
 impl ToBindingValue for std::sync::Arc<dyn std::any::Any + Send + Sync> {
     fn to_binding_value(&self) -> BindingValue {
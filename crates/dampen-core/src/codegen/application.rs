@@ -109,8 +109,11 @@ mod tests {
                 font_size_large: Some(24.0),
                 font_weight: crate::ir::theme::FontWeight::Normal,
                 line_height: Some(1.5),
+                scale_ratio: None,
+                fonts: Vec::new(),
             },
             spacing: SpacingScale { unit: Some(8.0) },
+            shadows: Default::default(),
             base_styles: std::collections::HashMap::new(),
             extends: None,
         }
@@ -5,8 +5,8 @@
 
 use crate::CodegenError;
 use crate::expr::ast::{
-    BinaryOp, BinaryOpExpr, ConditionalExpr, Expr, FieldAccessExpr, LiteralExpr, MethodCallExpr,
-    SharedFieldAccessExpr, UnaryOp, UnaryOpExpr,
+    BinaryOp, BinaryOpExpr, ConditionalExpr, Expr, FieldAccessExpr, FilterExpr, LiteralExpr,
+    MethodCallExpr, SharedFieldAccessExpr, UnaryOp, UnaryOpExpr,
 };
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
@@ -30,6 +30,7 @@ pub fn generate_expr(expr: &Expr) -> TokenStream {
         Expr::UnaryOp(unary_op) => generate_unary_op(unary_op),
         Expr::Conditional(conditional) => generate_conditional(conditional),
         Expr::Literal(literal) => generate_literal(literal),
+        Expr::Filter(filter) => generate_filter(filter),
     }
 }
 
@@ -52,6 +53,7 @@ pub fn generate_bool_expr(expr: &Expr) -> TokenStream {
         Expr::UnaryOp(unary_op) => generate_unary_op_raw(unary_op),
         Expr::Conditional(conditional) => generate_conditional_raw(conditional),
         Expr::Literal(literal) => generate_literal_raw(literal),
+        Expr::Filter(filter) => generate_filter(filter),
     }
 }
 
@@ -85,6 +87,13 @@ pub fn validate_expression_inlinable(expr: &Expr) -> Result<(), CodegenError> {
             Ok(())
         }
         Expr::Literal(_) => Ok(()),
+        Expr::Filter(filter_expr) => {
+            validate_expression_inlinable(&filter_expr.input)?;
+            for arg in &filter_expr.args {
+                validate_expression_inlinable(arg)?;
+            }
+            Ok(())
+        }
     }
 }
 
@@ -99,12 +108,42 @@ fn generate_field_access(expr: &FieldAccessExpr) -> TokenStream {
     if expr.path.is_empty() {
         return quote! { String::new() };
     }
+    if let Some(reserved) = generate_reserved_field_access(expr) {
+        return quote! { #reserved.to_string() };
+    }
 
     let field_access: Vec<_> = expr.path.iter().map(|s| format_ident!("{}", s)).collect();
 
     quote! { model.#(#field_access).*.to_string() }
 }
 
+/// Generate code for a `system.<field>` or `env.<field>` access, reading the
+/// system preferences or the environment values
+///
+/// # Returns
+/// `Some` TokenStream generating `dampen_core::system::preferences().field`,
+/// `dampen_core::env::values().field`, or `option_env!("DAMPEN_CONFIG_KEY")`
+/// for `env.config.key`, if the path starts with `system` or `env`, `None`
+/// otherwise
+fn generate_reserved_field_access(expr: &FieldAccessExpr) -> Option<TokenStream> {
+    match expr.path.as_slice() {
+        [root, field] if root == "system" => {
+            let field = format_ident!("{}", field);
+            Some(quote! { dampen_core::system::preferences().#field })
+        }
+        [root, field] if root == "env" => {
+            let field = format_ident!("{}", field);
+            Some(quote! { dampen_core::env::values().#field })
+        }
+        // Profile values are compiled in, from the variables cargo was run with
+        [root, config, key] if root == "env" && config == "config" => {
+            let var = crate::env::config_var(key);
+            Some(quote! { option_env!(#var).unwrap_or_default() })
+        }
+        _ => None,
+    }
+}
+
 /// Generate code for a shared field access expression
 ///
 /// # Arguments
@@ -244,6 +283,70 @@ fn generate_literal(expr: &LiteralExpr) -> TokenStream {
     }
 }
 
+/// Generate code for a filter expression
+///
+/// Filters are applied at runtime on the `BindingValue` of their input, so any
+/// Rust type implementing `ToBindingValue` can be filtered.
+///
+/// # Returns
+/// TokenStream generating the formatted `String` (empty if the filter fails)
+fn generate_filter(expr: &FilterExpr) -> TokenStream {
+    let input = generate_bool_expr(&expr.input);
+    let args: Vec<TokenStream> = expr.args.iter().map(generate_bool_expr).collect();
+    generate_filter_call(&expr.name, &input, &args)
+}
+
+fn generate_filter_call(name: &str, input: &TokenStream, args: &[TokenStream]) -> TokenStream {
+    if name == "filter_by" {
+        return generate_filter_by(input, args);
+    }
+    quote! {
+        dampen_core::apply_filter(
+            &dampen_core::BindingValue::from_value(&(#input)),
+            #name,
+            &[#(dampen_core::BindingValue::from_value(&(#args))),*],
+        )
+        .map(|value| value.to_display_string())
+        .unwrap_or_default()
+    }
+}
+
+/// Generate code for `filter_by(query, field)`
+///
+/// The input collection keeps its item type, so the result is a `Vec` of
+/// references to the matching items, iterated by `<for>` loops like the input.
+/// Items are matched on their `BindingValue`, so their type must implement
+/// `ToBindingValue`.
+fn generate_filter_by(input: &TokenStream, args: &[TokenStream]) -> TokenStream {
+    let query = match args.first() {
+        Some(query) => {
+            quote! { dampen_core::BindingValue::from_value(&(#query)).to_display_string() }
+        }
+        None => quote! { String::new() },
+    };
+    let field = match args.get(1) {
+        Some(field) => quote! {
+            Some(dampen_core::BindingValue::from_value(&(#field)).to_display_string()).as_deref()
+        },
+        None => quote! { None },
+    };
+    quote! {
+        {
+            let query = #query;
+            (#input)
+                .iter()
+                .filter(|item| {
+                    dampen_core::matches_query(
+                        &dampen_core::BindingValue::from_value(*item),
+                        &query,
+                        #field,
+                    )
+                })
+                .collect::<Vec<_>>()
+        }
+    }
+}
+
 /// Generate Rust code for interpolated strings
 ///
 /// Converts interpolated strings like "Count: {count}" into format! macro calls.
@@ -305,6 +408,9 @@ fn generate_field_access_raw_with_locals(
     if expr.path.is_empty() {
         return quote! { false };
     }
+    if let Some(reserved) = generate_reserved_field_access(expr) {
+        return reserved;
+    }
 
     // Check if the first element is a local variable
     if let Some(first) = expr.path.first()
@@ -338,6 +444,7 @@ pub fn generate_bool_expr_with_locals(
             generate_conditional_raw_with_locals(conditional, local_vars)
         }
         Expr::Literal(literal) => generate_literal_raw(literal),
+        Expr::Filter(filter) => generate_filter_with_locals(filter, local_vars),
     }
 }
 
@@ -356,6 +463,7 @@ pub fn generate_expr_with_locals(
         Expr::UnaryOp(unary_op) => generate_unary_op_with_locals(unary_op, local_vars),
         Expr::Conditional(conditional) => generate_conditional_with_locals(conditional, local_vars),
         Expr::Literal(literal) => generate_literal(literal),
+        Expr::Filter(filter) => generate_filter_with_locals(filter, local_vars),
     }
 }
 
@@ -367,6 +475,9 @@ fn generate_field_access_with_locals(
     if expr.path.is_empty() {
         return quote! { String::new() };
     }
+    if let Some(reserved) = generate_reserved_field_access(expr) {
+        return quote! { #reserved.to_string() };
+    }
 
     // Check if the first element is a local variable
     if let Some(first) = expr.path.first()
@@ -459,6 +570,19 @@ fn generate_conditional_with_locals(
     }
 }
 
+fn generate_filter_with_locals(
+    expr: &FilterExpr,
+    local_vars: &std::collections::HashSet<String>,
+) -> TokenStream {
+    let input = generate_bool_expr_with_locals(&expr.input, local_vars);
+    let args: Vec<_> = expr
+        .args
+        .iter()
+        .map(|a| generate_bool_expr_with_locals(a, local_vars))
+        .collect();
+    generate_filter_call(&expr.name, &input, &args)
+}
+
 fn generate_method_call_raw_with_locals(
     expr: &MethodCallExpr,
     local_vars: &std::collections::HashSet<String>,
@@ -0,0 +1,199 @@
+//! Build cache of generated view code
+//!
+//! Cargo reruns a project's build script whenever a file of `src/ui` changes,
+//! and the script regenerates every view. The cache keeps the output generated
+//! for each view in the build script's `OUT_DIR`, keyed by a hash of everything
+//! the output depends on, so that unchanged views are not generated again.
+//!
+//! Keys use [`DampenDocument::stable_hash`], which is the same in every build:
+//! reformatting a `.dampen` file, or a different `HashMap` order, is not a miss.
+//!
+//! The cache also keeps the source each view was last built from, which
+//! `dampen inspect --codegen --diff` compares with the current file.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! let cache = CodegenCache::new(&out_dir);
+//! let key = CodegenCache::key(&document, &handlers, theme.as_ref(), &config);
+//! let output = match cache.get("window", key) {
+//!     Some(output) => output,
+//!     None => {
+//!         let output =
+//!             generate_application_with_config(&document, &handlers, theme.as_ref(), &config)?;
+//!         cache.insert("window", key, &output)?;
+//!         output
+//!     }
+//! };
+//! ```
+
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+use crate::DampenDocument;
+use crate::codegen::{CodegenConfig, CodegenOutput};
+use crate::handler::HandlerSignature;
+use crate::ir::stable::{StableHasher, stable_hash};
+use crate::ir::theme::ThemeDocument;
+
+/// Name of the cache directory in `OUT_DIR`
+const CACHE_DIR: &str = "dampen-cache";
+
+/// A cached output, with the key it was generated for
+#[derive(serde::Serialize, serde::Deserialize)]
+struct CacheEntry {
+    key: u64,
+    output: CodegenOutput,
+}
+
+/// Cache of the code generated for each view
+#[derive(Debug, Clone)]
+pub struct CodegenCache {
+    dir: PathBuf,
+}
+
+impl CodegenCache {
+    /// Creates a cache stored in `out_dir/dampen-cache`
+    pub fn new(out_dir: impl AsRef<Path>) -> Self {
+        Self {
+            dir: out_dir.as_ref().join(CACHE_DIR),
+        }
+    }
+
+    /// Key of the output generated from these inputs.
+    ///
+    /// The arguments are those of
+    /// [`generate_application_with_config`](super::generate_application_with_config).
+    /// The version of `dampen-core` is part of the key, so that upgrading it
+    /// regenerates every view.
+    pub fn key(
+        document: &DampenDocument,
+        handlers: &[HandlerSignature],
+        theme_document: Option<&ThemeDocument>,
+        config: &CodegenConfig,
+    ) -> u64 {
+        let mut hasher = StableHasher::default();
+        crate::VERSION.hash(&mut hasher);
+        document.stable_hash().hash(&mut hasher);
+        handlers.len().hash(&mut hasher);
+        for handler in handlers {
+            handler.name.hash(&mut hasher);
+            handler.param_type.hash(&mut hasher);
+            handler.returns_command.hash(&mut hasher);
+            handler.palette.hash(&mut hasher);
+        }
+        theme_document.map(stable_hash).hash(&mut hasher);
+        config.model_type.hash(&mut hasher);
+        config.message_type.hash(&mut hasher);
+        config
+            .persistence
+            .as_ref()
+            .map(|persistence| &persistence.app_name)
+            .hash(&mut hasher);
+        config.module_path.hash(&mut hasher);
+        config.imports.hash(&mut hasher);
+        config.visibility.hash(&mut hasher);
+        config.emit_message_enum.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Output cached for `view`, if it was generated for `key`
+    pub fn get(&self, view: &str, key: u64) -> Option<CodegenOutput> {
+        let content = fs::read_to_string(self.entry_path(view)).ok()?;
+        let entry: CacheEntry = serde_json::from_str(&content).ok()?;
+        (entry.key == key).then_some(entry.output)
+    }
+
+    /// Stores the output generated for `view` from the inputs of `key`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache directory or entry cannot be written.
+    pub fn insert(&self, view: &str, key: u64, output: &CodegenOutput) -> std::io::Result<()> {
+        let entry = CacheEntry {
+            key,
+            output: output.clone(),
+        };
+        let json = serde_json::to_string(&entry).map_err(std::io::Error::other)?;
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.entry_path(view), json)
+    }
+
+    /// Source of `view` in its last build, if it was stored
+    pub fn source(&self, view: &str) -> Option<String> {
+        fs::read_to_string(self.source_path(view)).ok()
+    }
+
+    /// Stores the source `view` is built from
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache directory or source cannot be written.
+    pub fn insert_source(&self, view: &str, source: &str) -> std::io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.source_path(view), source)
+    }
+
+    fn entry_path(&self, view: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", view))
+    }
+
+    fn source_path(&self, view: &str) -> PathBuf {
+        self.dir.join(format!("{}.dampen", view))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::parser::parse;
+
+    fn output(code: &str) -> CodegenOutput {
+        CodegenOutput {
+            code: code.to_string(),
+            warnings: vec!["unused handler".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_key_ignores_formatting() {
+        let a = parse(r#"<column><text value="A" /></column>"#).unwrap();
+        let b = parse("<column>\n  <text value=\"A\" />\n</column>").unwrap();
+        let c = parse(r#"<column><text value="B" /></column>"#).unwrap();
+        let config = CodegenConfig::default();
+        let key = |doc: &DampenDocument| CodegenCache::key(doc, &[], None, &config);
+
+        assert_eq!(key(&a), key(&b));
+        assert_ne!(key(&a), key(&c));
+        assert_ne!(
+            key(&a),
+            CodegenCache::key(&a, &[], None, &config.clone().with_model_type("AppModel"))
+        );
+    }
+
+    #[test]
+    fn test_get_returns_output_of_same_key() {
+        let out_dir =
+            std::env::temp_dir().join(format!("dampen-cache-test-{}", std::process::id()));
+        let cache = CodegenCache::new(&out_dir);
+
+        assert!(cache.get("window", 1).is_none());
+        cache.insert("window", 1, &output("fn view() {}")).unwrap();
+
+        let cached = cache.get("window", 1).unwrap();
+        assert_eq!(cached.code, "fn view() {}");
+        assert_eq!(cached.warnings, vec!["unused handler"]);
+        assert!(cache.get("window", 2).is_none());
+
+        assert!(cache.source("window").is_none());
+        cache
+            .insert_source("window", "<text value=\"A\" />")
+            .unwrap();
+        assert_eq!(cache.source("window").unwrap(), "<text value=\"A\" />");
+
+        let _ = fs::remove_dir_all(&out_dir);
+    }
+}
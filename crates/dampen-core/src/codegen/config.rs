@@ -25,6 +25,24 @@ pub struct CodegenConfig {
 
     /// Optional persistence configuration
     pub persistence: Option<PersistenceConfig>,
+
+    /// Path of the module defining the model and handlers, whose items the
+    /// generated code imports (e.g. "crate::ui::window"), or `None` when the
+    /// generated code is included in that module
+    pub module_path: Option<String>,
+
+    /// Additional `use` trees of the generated code (e.g. "crate::widgets::*")
+    pub imports: Vec<String>,
+
+    /// Visibility of the generated application items (e.g. "pub", "pub(crate)",
+    /// or "" for private)
+    pub visibility: String,
+
+    /// Whether to generate the message enum
+    ///
+    /// When `false`, an enum named after `message_type` with a variant per
+    /// handler must be in scope of the generated code.
+    pub emit_message_enum: bool,
 }
 
 /// Configuration for window state persistence
@@ -50,6 +68,10 @@ impl CodegenConfig {
             model_type: "Model".to_string(),
             message_type: "Message".to_string(),
             persistence: None,
+            module_path: Some("crate::ui::window".to_string()),
+            imports: Vec::new(),
+            visibility: "pub".to_string(),
+            emit_message_enum: true,
         }
     }
 
@@ -73,6 +95,37 @@ impl CodegenConfig {
         self
     }
 
+    /// Set the path of the module whose items the generated code imports
+    pub fn with_module_path(mut self, module_path: impl Into<String>) -> Self {
+        self.module_path = Some(module_path.into());
+        self
+    }
+
+    /// Do not import a module, for code included in the module defining the
+    /// model and handlers
+    pub fn without_module_import(mut self) -> Self {
+        self.module_path = None;
+        self
+    }
+
+    /// Add a `use` tree to the generated code
+    pub fn with_import(mut self, import: impl Into<String>) -> Self {
+        self.imports.push(import.into());
+        self
+    }
+
+    /// Set the visibility of the generated application items
+    pub fn with_visibility(mut self, visibility: impl Into<String>) -> Self {
+        self.visibility = visibility.into();
+        self
+    }
+
+    /// Enable or disable the generation of the message enum
+    pub fn with_message_enum(mut self, emit_message_enum: bool) -> Self {
+        self.emit_message_enum = emit_message_enum;
+        self
+    }
+
     /// Enable or disable code formatting
     pub fn with_formatting(mut self, format_output: bool) -> Self {
         self.format_output = format_output;
@@ -111,6 +164,30 @@ impl CodegenConfig {
             ));
         }
 
+        if let Some(module_path) = &self.module_path
+            && syn::parse_str::<syn::Path>(module_path).is_err()
+        {
+            return Err(format!(
+                "Module path '{}' is not a valid Rust path",
+                module_path
+            ));
+        }
+
+        if let Some(import) = self
+            .imports
+            .iter()
+            .find(|import| syn::parse_str::<syn::UseTree>(import).is_err())
+        {
+            return Err(format!("Import '{}' is not a valid use tree", import));
+        }
+
+        if syn::parse_str::<syn::Visibility>(&self.visibility).is_err() {
+            return Err(format!(
+                "Visibility '{}' is not a valid Rust visibility",
+                self.visibility
+            ));
+        }
+
         Ok(())
     }
 }
@@ -192,4 +269,29 @@ mod tests {
         let config = CodegenConfig::new(PathBuf::from("target")).with_message_type("invalid");
         assert!(config.validate().is_err());
     }
+
+    #[test]
+    fn test_module_layout_validation() {
+        let config = CodegenConfig::default()
+            .with_module_path("crate::views::settings")
+            .with_import("crate::widgets::{chart, gauge}")
+            .with_visibility("pub(crate)");
+        assert!(config.validate().is_ok());
+        assert!(
+            CodegenConfig::default()
+                .without_module_import()
+                .with_visibility("")
+                .validate()
+                .is_ok()
+        );
+
+        let config = CodegenConfig::default().with_module_path("crate::ui::");
+        assert!(config.validate().is_err());
+
+        let config = CodegenConfig::default().with_import("crate::{widgets");
+        assert!(config.validate().is_err());
+
+        let config = CodegenConfig::default().with_visibility("public");
+        assert!(config.validate().is_err());
+    }
 }
@@ -74,7 +74,7 @@ fn to_upper_camel_case(s: &str) -> String {
     let mut result = String::new();
     let mut capitalize_next = true;
     for c in s.chars() {
-        if c == '_' {
+        if c == '_' || c == '.' {
             capitalize_next = true;
         } else if capitalize_next {
             result.push(c.to_ascii_uppercase());
@@ -268,6 +268,13 @@ pub fn validate_expression_inlinable(expr: &crate::Expr) -> Result<(), CodegenEr
             Ok(())
         }
         crate::Expr::Literal(_) => Ok(()),
+        crate::Expr::Filter(filter_expr) => {
+            validate_expression_inlinable(&filter_expr.input)?;
+            for arg in &filter_expr.args {
+                validate_expression_inlinable(arg)?;
+            }
+            Ok(())
+        }
     }
 }
 
@@ -281,6 +288,7 @@ mod tests {
             name: "increment".to_string(),
             param_type: None,
             returns_command: false,
+            palette: None,
         }];
 
         let result = generate_handler_dispatch(&handlers, "Model", "Message").unwrap();
@@ -294,6 +302,7 @@ mod tests {
             name: "set_value".to_string(),
             param_type: Some("String".to_string()),
             returns_command: false,
+            palette: None,
         }];
 
         let result = generate_handler_dispatch(&handlers, "Model", "Message").unwrap();
@@ -308,6 +317,7 @@ mod tests {
             name: "save".to_string(),
             param_type: None,
             returns_command: true,
+            palette: None,
         }];
 
         let result = generate_handler_dispatch(&handlers, "Model", "Message").unwrap();
@@ -322,11 +332,13 @@ mod tests {
                 name: "increment".to_string(),
                 param_type: None,
                 returns_command: false,
+                palette: None,
             },
             HandlerSignature {
                 name: "set_value".to_string(),
                 param_type: Some("String".to_string()),
                 returns_command: false,
+                palette: None,
             },
         ];
 
@@ -1,9 +1,18 @@
 //! Handler inventory extraction for build scripts
 //!
 //! This module provides utilities for build.rs scripts to extract handler metadata
-//! from Rust source files that use the `inventory_handlers!` macro.
+//! from Rust source files. Handlers are discovered by parsing the module of a view
+//! for its `#[ui_handler]` functions, so that the generated code follows the
+//! handlers actually defined; an `inventory_handlers!` list is no longer required.
+//!
+//! [`find_missing_handlers`] then reports the handlers a view references but its
+//! module does not define, with their location in the `.dampen` file.
 
 use crate::HandlerSignature;
+use crate::ir::node::WidgetNode;
+use crate::ir::{DampenDocument, Span};
+use crate::parser::attribute_standard::closest_name;
+use crate::selection::selection_handler;
 use std::path::Path;
 
 /// Extract handler names from an `inventory_handlers!` macro invocation in a Rust file.
@@ -89,9 +98,10 @@ fn parse_handler_list_from_tokens(tokens: &proc_macro2::TokenStream) -> Vec<Stri
 
 /// Extract full handler metadata from a Rust file.
 ///
-/// This function looks for the `inventory_handlers!` macro to get handler names,
-/// then analyzes the function signatures marked with `#[ui_handler]` to determine
-/// their parameter and return types.
+/// Handlers are the top-level functions marked with `#[ui_handler]`, in source
+/// order, with their parameter and return types. Names listed by an
+/// `inventory_handlers!` invocation without such a function are appended with a
+/// signature taking no parameter.
 ///
 /// # Arguments
 ///
@@ -99,107 +109,198 @@ fn parse_handler_list_from_tokens(tokens: &proc_macro2::TokenStream) -> Vec<Stri
 ///
 /// # Returns
 ///
-/// A vector of HandlerSignature objects with complete metadata
+/// A vector of HandlerSignature objects with complete metadata, empty if the file
+/// cannot be read
 pub fn extract_handler_signatures_from_file(rs_file_path: &Path) -> Vec<HandlerSignature> {
     let content = match std::fs::read_to_string(rs_file_path) {
         Ok(content) => content,
         Err(_) => return vec![],
     };
 
-    let handler_names = extract_handler_names_from_source(&content);
+    extract_handler_signatures_from_source(&content)
+}
 
-    // Parse the file to extract function signatures
-    let syntax = match syn::parse_file(&content) {
-        Ok(syntax) => syntax,
-        Err(_) => {
-            // Fallback to simple signatures if parsing fails
-            return handler_names
-                .into_iter()
-                .map(|name| HandlerSignature {
-                    name,
-                    param_type: None,
-                    returns_command: false,
-                })
-                .collect();
-        }
+/// Extract full handler metadata from Rust source code.
+fn extract_handler_signatures_from_source(source: &str) -> Vec<HandlerSignature> {
+    let mut handlers: Vec<HandlerSignature> = match syn::parse_file(source) {
+        Ok(syntax) => syntax
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::Item::Fn(func) => handler_signature(func),
+                _ => None,
+            })
+            .collect(),
+        Err(_) => Vec::new(),
     };
 
-    // Extract signatures for each handler by finding the corresponding function
-    handler_names
-        .into_iter()
-        .map(|name| {
-            // Look for the function with this name and #[ui_handler] attribute
-            if let Some(signature) = find_handler_function_signature(&syntax, &name) {
-                signature
-            } else {
-                // Fallback to simple signature
-                HandlerSignature {
-                    name,
-                    param_type: None,
-                    returns_command: false,
-                }
-            }
-        })
-        .collect()
+    for name in extract_handler_names_from_source(source) {
+        if !handlers.iter().any(|handler| handler.name == name) {
+            handlers.push(HandlerSignature {
+                name,
+                param_type: None,
+                returns_command: false,
+                palette: None,
+            });
+        }
+    }
+
+    handlers
 }
 
 /// Find a function with the given name and extract its signature
+#[cfg(test)]
 fn find_handler_function_signature(
     syntax: &syn::File,
     handler_name: &str,
 ) -> Option<HandlerSignature> {
-    use syn::{FnArg, Item, ReturnType};
-
-    for item in &syntax.items {
-        if let Item::Fn(func) = item {
-            // Check if this is the function we're looking for
-            if func.sig.ident == handler_name {
-                // Check if it has #[ui_handler] attribute
-                let has_ui_handler_attr = func.attrs.iter().any(|attr| {
-                    attr.path().segments.last().map(|s| s.ident.to_string())
-                        == Some("ui_handler".to_string())
-                });
+    syntax.items.iter().find_map(|item| match item {
+        syn::Item::Fn(func) if func.sig.ident == handler_name => handler_signature(func),
+        _ => None,
+    })
+}
+
+/// Signature of a function marked with `#[ui_handler]`, `None` for other functions
+fn handler_signature(func: &syn::ItemFn) -> Option<HandlerSignature> {
+    use syn::{FnArg, ReturnType};
+
+    let ui_handler_attr = func.attrs.iter().find(|attr| {
+        attr.path().segments.last().map(|s| s.ident.to_string()) == Some("ui_handler".to_string())
+    })?;
+
+    // Analyze the signature
+    let mut param_type: Option<String> = None;
+    let mut param_count = 0;
+
+    for input in &func.sig.inputs {
+        if let FnArg::Typed(pat_type) = input {
+            param_count += 1;
+            // If there's more than one parameter (first is always &mut Model)
+            // then the second one is the value parameter
+            if param_count > 1 {
+                let ty = &pat_type.ty;
+                let type_str = quote::quote!(#ty).to_string();
+                // Clean up the type string (remove extra spaces)
+                param_type = Some(type_str.replace(" ", ""));
+            }
+        }
+    }
+
+    // Check if it returns Command
+    let returns_command = if let ReturnType::Type(_, ty) = &func.sig.output {
+        let return_str = quote::quote!(#ty).to_string();
+        return_str.contains("Command")
+    } else {
+        false
+    };
+
+    Some(HandlerSignature {
+        name: func.sig.ident.to_string(),
+        param_type,
+        returns_command,
+        palette: palette_label(ui_handler_attr),
+    })
+}
+
+/// A handler referenced by a view but not defined by its module
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingHandler {
+    /// Name of the handler
+    pub name: String,
+    /// Location of the reference in the `.dampen` file
+    pub span: Span,
+    /// Closest defined handler, if any is close enough
+    pub suggestion: Option<String>,
+}
 
-                if !has_ui_handler_attr {
-                    continue;
-                }
-
-                // Analyze the signature
-                let mut param_type: Option<String> = None;
-                let mut param_count = 0;
-
-                for input in &func.sig.inputs {
-                    if let FnArg::Typed(pat_type) = input {
-                        param_count += 1;
-                        // If there's more than one parameter (first is always &mut Model)
-                        // then the second one is the value parameter
-                        if param_count > 1 {
-                            let ty = &pat_type.ty;
-                            let type_str = quote::quote!(#ty).to_string();
-                            // Clean up the type string (remove extra spaces)
-                            param_type = Some(type_str.replace(" ", ""));
-                        }
-                    }
-                }
-
-                // Check if it returns Command
-                let returns_command = if let ReturnType::Type(_, ty) = &func.sig.output {
-                    let return_str = quote::quote!(#ty).to_string();
-                    return_str.contains("Command")
-                } else {
-                    false
-                };
-
-                return Some(HandlerSignature {
-                    name: handler_name.to_string(),
-                    param_type,
-                    returns_command,
+impl MissingHandler {
+    /// Error message for the build output, as `view:line:column: message`
+    pub fn message(&self, view: &Path, module: &Path) -> String {
+        let mut message = format!(
+            "{}:{}:{}: handler '{}' is not defined: {} has no #[ui_handler] function named '{}'",
+            view.display(),
+            self.span.line,
+            self.span.column,
+            self.name,
+            module.display(),
+            self.name
+        );
+        if let Some(suggestion) = &self.suggestion {
+            message.push_str(&format!(" (did you mean '{}'?)", suggestion));
+        }
+        message
+    }
+}
+
+/// Find the handlers referenced by a document that are not among `handlers`.
+///
+/// Every reference is reported, in document order. Selection handlers
+/// (`selection.toggle`) are generated and never missing.
+///
+/// # Example
+///
+/// ```rust
+/// use dampen_core::codegen::inventory::find_missing_handlers;
+/// use dampen_core::{HandlerSignature, parse};
+///
+/// let document = parse(r#"<button label="Save" on_click="sav" />"#).unwrap();
+/// let handlers = vec![HandlerSignature {
+///     name: "save".to_string(),
+///     param_type: None,
+///     returns_command: false,
+///     palette: None,
+/// }];
+///
+/// let missing = find_missing_handlers(&document, &handlers);
+/// assert_eq!(missing[0].name, "sav");
+/// assert_eq!(missing[0].suggestion.as_deref(), Some("save"));
+/// ```
+pub fn find_missing_handlers(
+    document: &DampenDocument,
+    handlers: &[HandlerSignature],
+) -> Vec<MissingHandler> {
+    fn collect(
+        node: &WidgetNode,
+        handlers: &[HandlerSignature],
+        missing: &mut Vec<MissingHandler>,
+    ) {
+        for event in &node.events {
+            if !handlers.iter().any(|handler| handler.name == event.handler)
+                && selection_handler(&event.handler).is_none()
+            {
+                missing.push(MissingHandler {
+                    name: event.handler.clone(),
+                    span: event.span,
+                    suggestion: closest_name(
+                        &event.handler,
+                        handlers.iter().map(|handler| handler.name.as_str()),
+                    )
+                    .map(str::to_string),
                 });
             }
         }
+        for child in &node.children {
+            collect(child, handlers, missing);
+        }
     }
 
-    None
+    let mut missing = Vec::new();
+    collect(&document.root, handlers, &mut missing);
+    missing
+}
+
+/// Label of `#[ui_handler(palette = "...")]`, if the attribute has one
+fn palette_label(attr: &syn::Attribute) -> Option<String> {
+    let mut label = None;
+    if let syn::Meta::List(_) = &attr.meta {
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("palette") {
+                label = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+            }
+            Ok(())
+        });
+    }
+    label
 }
 
 #[cfg(test)]
@@ -253,4 +354,88 @@ mod tests {
         let handlers = extract_handler_names_from_source(source);
         assert_eq!(handlers, vec!["greet"]);
     }
+
+    #[test]
+    fn test_discover_handlers_without_inventory() {
+        let source = r#"
+            #[ui_handler]
+            pub fn save(model: &mut Model) -> Command<Message> {
+                Command::none()
+            }
+
+            pub fn helper() {}
+
+            #[ui_handler]
+            pub fn rename(model: &mut Model, name: String) {}
+
+            inventory_handlers! { save, legacy }
+        "#;
+
+        let handlers = extract_handler_signatures_from_source(source);
+        let summary: Vec<_> = handlers
+            .iter()
+            .map(|h| (h.name.as_str(), h.param_type.as_deref(), h.returns_command))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("save", None, true),
+                ("rename", Some("String"), false),
+                ("legacy", None, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_missing_handlers() {
+        let document = crate::parse(
+            r#"<column>
+    <button label="Save" on_click="save" />
+    <button label="Reset" on_click="reste" />
+    <text_input value="{name}" on_input="rename" />
+</column>"#,
+        )
+        .unwrap_or_default();
+        let handlers = extract_handler_signatures_from_source(
+            "#[ui_handler] fn save(model: &mut Model) {}\n#[ui_handler] fn reset(model: &mut Model) {}",
+        );
+
+        let missing = find_missing_handlers(&document, &handlers);
+        let names: Vec<_> = missing
+            .iter()
+            .map(|m| (m.name.as_str(), m.span.line, m.suggestion.as_deref()))
+            .collect();
+        assert_eq!(
+            names,
+            vec![("reste", 3, Some("reset")), ("rename", 4, None)]
+        );
+
+        let message = missing[0].message(
+            Path::new("src/ui/window.dampen"),
+            Path::new("src/ui/window.rs"),
+        );
+        assert_eq!(
+            message,
+            "src/ui/window.dampen:3:37: handler 'reste' is not defined: src/ui/window.rs has no #[ui_handler] function named 'reste' (did you mean 'reset'?)"
+        );
+    }
+
+    #[test]
+    fn test_extract_palette_label() -> Result<(), syn::Error> {
+        let syntax = syn::parse_file(
+            r#"
+            #[ui_handler(palette = "Save File")]
+            fn save_file(model: &mut Model) {}
+
+            #[ui_handler]
+            fn reset(model: &mut Model) {}
+        "#,
+        )?;
+
+        let save = find_handler_function_signature(&syntax, "save_file");
+        let reset = find_handler_function_signature(&syntax, "reset");
+        assert_eq!(save.and_then(|s| s.palette), Some("Save File".to_string()));
+        assert_eq!(reset.map(|s| s.palette), Some(None));
+        Ok(())
+    }
 }
@@ -32,14 +32,17 @@
 
 pub mod application;
 pub mod bindings;
+pub mod cache;
 pub mod config;
 pub mod handlers;
 pub mod inventory;
+pub mod modules;
 pub mod status_mapping;
 pub mod subscription;
 pub mod theme;
 pub mod update;
 pub mod view;
+pub mod window;
 
 use crate::DampenDocument;
 use crate::HandlerSignature;
@@ -84,6 +87,9 @@ pub struct HandlerInfo {
 
     /// Source line number
     pub source_line: u32,
+
+    /// Label listing the handler in the command palette, if any
+    pub palette: Option<&'static str>,
 }
 
 impl HandlerInfo {
@@ -116,6 +122,7 @@ impl HandlerInfo {
             name: self.name.to_string(),
             param_type,
             returns_command,
+            palette: self.palette.map(str::to_string),
         }
     }
 }
@@ -123,7 +130,7 @@ impl HandlerInfo {
 /// Result of code generation
 ///
 /// Contains the generated Rust code and any warnings or notes.
-#[derive(Debug)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct CodegenOutput {
     /// Generated Rust code
     pub code: String,
@@ -260,9 +267,14 @@ pub fn generate_application(
     handlers: &[HandlerSignature],
 ) -> Result<CodegenOutput, CodegenError> {
     let warnings = Vec::new();
+    let handlers = &update::with_selection_handlers(document, handlers);
 
-    let message_enum = generate_message_enum(handlers)?;
+    let message_enum = generate_message_enum(handlers, message_name)?;
+    let prelude = generate_prelude(&CodegenConfig::default())?;
 
+    let document = &theme::resolve_elevation(document, None);
+    let document = &theme::resolve_typography(document, None);
+    let document = &theme::resolve_theme_overrides(document, None);
     let view_fn = view::generate_view(document, model_name, message_name)?;
 
     let update_arms = update::generate_arms(handlers, message_name)?;
@@ -272,7 +284,7 @@ pub fn generate_application(
 
     let combined = quote! {
         use iced::{Element, Task};
-        use crate::ui::window::*;
+        #prelude
 
         #message_enum
 
@@ -298,7 +310,7 @@ pub fn generate_application(
 }
 
 use crate::ir::theme::ThemeDocument;
-pub use config::PersistenceConfig;
+pub use config::{CodegenConfig, PersistenceConfig};
 
 /// Generate complete application code with theme and subscription support
 ///
@@ -326,89 +338,10 @@ pub fn generate_application_with_theme_and_subscriptions(
     handlers: &[HandlerSignature],
     theme_document: Option<&ThemeDocument>,
 ) -> Result<CodegenOutput, CodegenError> {
-    let warnings = Vec::new();
-
-    // Create subscription config from theme document
-    let sub_config =
-        subscription::SubscriptionConfig::from_theme_document(theme_document, message_name);
-
-    // Generate message enum with system theme variant if needed
-    let message_enum = generate_message_enum_with_subscription(handlers, Some(&sub_config))?;
-
-    let view_fn = view::generate_view(document, model_name, message_name)?;
-
-    let update_arms = update::generate_arms(handlers, message_name)?;
-
-    // Generate system theme update arm if needed
-    let system_theme_arm = subscription::generate_system_theme_update_arm(&sub_config);
-
-    let model_ident = syn::Ident::new(model_name, proc_macro2::Span::call_site());
-    let message_ident = syn::Ident::new(message_name, proc_macro2::Span::call_site());
-
-    // Generate theme code if theme document is provided
-    let theme_code = if let Some(theme_doc) = theme_document {
-        match theme::generate_theme_code(theme_doc, &document.style_classes, "app") {
-            Ok(generated) => {
-                let code_str = generated.code;
-                syn::parse_str::<TokenStream>(&code_str).unwrap_or_default()
-            }
-            Err(e) => {
-                return Err(CodegenError::ThemeError(e));
-            }
-        }
-    } else {
-        TokenStream::new()
-    };
-
-    // Generate subscription function
-    let subscription_fn = subscription::generate_subscription_function(&sub_config);
-
-    let has_theme = theme_document.is_some();
-    let theme_method = if has_theme {
-        quote! {
-            pub fn theme(_model: &#model_ident) -> iced::Theme {
-                app_theme()
-            }
-        }
-    } else {
-        quote! {}
-    };
-
-    let combined = quote! {
-        use iced::{Element, Task, Theme};
-        use crate::ui::window::*;
-        use std::collections::HashMap;
-        use dampen_core::handler::CanvasEvent;
-
-        #theme_code
-
-
-        #message_enum
-
-        pub fn new_model() -> (#model_ident, Task<#message_ident>) {
-            (#model_ident::default(), Task::none())
-        }
-
-        pub fn update_model(model: &mut #model_ident, message: #message_ident) -> Task<#message_ident> {
-            match message {
-                #update_arms
-                #system_theme_arm
-            }
-        }
-
-        pub fn view_model(model: &#model_ident) -> Element<'_, #message_ident> {
-            #view_fn
-        }
-
-        #theme_method
-
-        #subscription_fn
-    };
-
-    Ok(CodegenOutput {
-        code: combined.to_string(),
-        warnings,
-    })
+    let config = CodegenConfig::default()
+        .with_model_type(model_name)
+        .with_message_type(message_name);
+    generate_application_with_config(document, handlers, theme_document, &config)
 }
 
 /// Generate complete application code with theme, subscription, and persistence support
@@ -440,28 +373,102 @@ pub fn generate_application_full(
     handlers: &[HandlerSignature],
     theme_document: Option<&ThemeDocument>,
     persistence: Option<&PersistenceConfig>,
+) -> Result<CodegenOutput, CodegenError> {
+    let mut config = CodegenConfig::default()
+        .with_model_type(model_name)
+        .with_message_type(message_name);
+    config.persistence = persistence.cloned();
+    generate_application_with_config(document, handlers, theme_document, &config)
+}
+
+/// Generate complete application code laid out by a [`CodegenConfig`]
+///
+/// Generates the same code as [`generate_application_full`], with the model,
+/// message and persistence settings of `config`, imported modules, visibility
+/// of the generated items and message enum adapted to the crate including it.
+/// Theme and style class functions are always `pub`.
+///
+/// # Example
+///
+/// ```rust
+/// use dampen_core::codegen::{CodegenConfig, generate_application_with_config};
+/// use dampen_core::parse;
+///
+/// let document = parse(r#"<text value="Settings" />"#).unwrap();
+/// let config = CodegenConfig::default()
+///     .with_module_path("crate::views::settings")
+///     .with_visibility("pub(crate)");
+///
+/// let output = generate_application_with_config(&document, &[], None, &config).unwrap();
+/// assert!(output.code.contains("use crate :: views :: settings :: *"));
+/// assert!(output.code.contains("pub (crate) fn view_model"));
+/// ```
+///
+/// # Errors
+///
+/// Returns `CodegenError::SyntaxError` if a path, import or visibility of
+/// `config` is not valid Rust.
+pub fn generate_application_with_config(
+    document: &DampenDocument,
+    handlers: &[HandlerSignature],
+    theme_document: Option<&ThemeDocument>,
+    config: &CodegenConfig,
 ) -> Result<CodegenOutput, CodegenError> {
     let warnings = Vec::new();
+    let handlers = &update::with_selection_handlers(document, handlers);
+    let model_name = config.model_type.as_str();
+    let message_name = config.message_type.as_str();
+    let persistence = config.persistence.as_ref();
+    let vis: syn::Visibility = syn::parse_str(&config.visibility)?;
+    let prelude = generate_prelude(config)?;
 
     // Create subscription config from theme document
     let sub_config =
-        subscription::SubscriptionConfig::from_theme_document(theme_document, message_name);
+        subscription::SubscriptionConfig::from_theme_document(theme_document, message_name)
+            .with_remote_images(document)
+            .with_notifications(handlers)
+            .with_debounced_inputs(document)
+            .with_command_palette(handlers)
+            .with_system_preferences(document, theme_document)
+            .with_visibility(vis.clone());
 
     // Determine if we need window events (for persistence)
     let has_persistence = persistence.is_some();
 
+    let model_ident = syn::Ident::new(model_name, proc_macro2::Span::call_site());
+    let message_ident = syn::Ident::new(message_name, proc_macro2::Span::call_site());
+
     // Generate message enum with system theme variant and window events if needed
-    let message_enum = generate_message_enum_full(handlers, Some(&sub_config), has_persistence)?;
+    let message_enum = if config.emit_message_enum {
+        generate_message_enum_full(
+            handlers,
+            Some(&sub_config),
+            has_persistence,
+            &message_ident,
+            &vis,
+        )?
+    } else {
+        TokenStream::new()
+    };
 
+    let document = &theme::resolve_elevation(document, theme_document);
+    let document = &theme::resolve_typography(document, theme_document);
+    let document = &theme::resolve_theme_overrides(document, theme_document);
     let view_fn = view::generate_view(document, model_name, message_name)?;
+    let view_fn = subscription::generate_overlay_layers(&sub_config, view_fn);
 
     let update_arms = update::generate_arms(handlers, message_name)?;
 
-    // Generate system theme update arm if needed
+    // Generate system theme, image loader, notification, debounce, palette and
+    // system preferences update arms if needed
     let system_theme_arm = subscription::generate_system_theme_update_arm(&sub_config);
-
-    let model_ident = syn::Ident::new(model_name, proc_macro2::Span::call_site());
-    let message_ident = syn::Ident::new(message_name, proc_macro2::Span::call_site());
+    let image_loader_arm = subscription::generate_image_loader_update_arm(&sub_config);
+    let notification_arm = subscription::generate_notification_update_arm(&sub_config);
+    let debounce_arm = subscription::generate_debounce_update_arm(&sub_config);
+    let palette_arm = subscription::generate_palette_update_arm(&sub_config);
+    let system_preferences_arm = subscription::generate_system_preferences_update_arm(&sub_config);
+    let palette_commands_fn = subscription::generate_palette_commands_function(&sub_config);
+    let font_loading = theme::generate_font_loading(theme_document);
 
     // Generate theme code if theme document is provided
     let theme_code = if let Some(theme_doc) = theme_document {
@@ -478,10 +485,17 @@ pub fn generate_application_full(
         TokenStream::new()
     };
 
+    // Handlers take the user's model, the application functions its wrapper
+    let app_model = if has_persistence {
+        quote! { AppModel }
+    } else {
+        quote! { #model_ident }
+    };
+
     let has_theme = theme_document.is_some();
     let theme_method = if has_theme {
         quote! {
-            pub fn theme(_model: &AppModel) -> iced::Theme {
+            #vis fn theme(_model: &#app_model) -> iced::Theme {
                 app_theme()
             }
         }
@@ -489,6 +503,24 @@ pub fn generate_application_full(
         quote! {}
     };
 
+    // Theme switches requested by the handlers (see crate::theme_switch)
+    let (theme_capture, theme_switch) = if has_theme {
+        (
+            quote! {
+                let theme_capture = dampen_core::theme_switch::capture();
+            },
+            quote! {
+                let mut theme_switch = dampen_core::theme_switch::ThemeSwitch::new();
+                theme_capture.finish(&mut theme_switch);
+                if let Some(name) = theme_switch.take_request() {
+                    app_set_current_theme(&name);
+                }
+            },
+        )
+    } else {
+        (TokenStream::new(), TokenStream::new())
+    };
+
     // Generate persistence-related code
     let (
         wrapper_struct,
@@ -499,11 +531,13 @@ pub fn generate_application_full(
         window_settings_fn,
     ) = if let Some(config) = persistence {
         let app_name = &config.app_name;
+        let (default_width, default_height) =
+            window::default_persisted_size(document.window.as_ref());
 
         // Generate wrapper struct that includes persisted_window_state
         let wrapper = quote! {
             /// Application model wrapper with persistence state
-            pub struct AppModel {
+            #vis struct AppModel {
                 /// User's model
                 pub inner: #model_ident,
                 /// Persisted window state for saving on close
@@ -513,14 +547,14 @@ pub fn generate_application_full(
 
         // Generate new_model that initializes the wrapper
         let new_model = quote! {
-            pub fn new_model() -> (AppModel, Task<#message_ident>) {
-                let persisted_state = dampen_dev::persistence::load_or_default(#app_name, 800, 600);
+            #vis fn new_model() -> (AppModel, Task<#message_ident>) {
+                let persisted_state = dampen_dev::persistence::load_or_default(#app_name, #default_width, #default_height);
                 (
                     AppModel {
                         inner: #model_ident::default(),
                         persisted_window_state: persisted_state,
                     },
-                    Task::none(),
+                    #font_loading,
                 )
             }
         };
@@ -530,10 +564,16 @@ pub fn generate_application_full(
 
         // Generate update_model with window event handling
         let update_model = quote! {
-            pub fn update_model(model: &mut AppModel, message: #message_ident) -> Task<#message_ident> {
-                match message {
+            #vis fn update_model(model: &mut AppModel, message: #message_ident) -> Task<#message_ident> {
+                #theme_capture
+                let task = match message {
                     #update_arms_inner
                     #system_theme_arm
+                    #image_loader_arm
+                    #notification_arm
+                    #debounce_arm
+                #palette_arm
+                    #system_preferences_arm
                     #message_ident::Window(id, event) => {
                         match event {
                             iced::window::Event::Opened { .. } => {
@@ -556,6 +596,10 @@ pub fn generate_application_full(
                                 model.persisted_window_state.y = Some(position.y as i32);
                                 Task::none()
                             }
+                            iced::window::Event::Rescaled(scale_factor) => {
+                                model.persisted_window_state.scale_factor = Some(scale_factor);
+                                Task::none()
+                            }
                             iced::window::Event::CloseRequested => {
                                 let _ = dampen_dev::persistence::save_window_state(
                                     #app_name,
@@ -566,29 +610,42 @@ pub fn generate_application_full(
                             _ => Task::none(),
                         }
                     }
-                }
+                };
+                #theme_switch
+                task
             }
         };
 
         // Generate view_model that accesses inner model
         // Note: view_fn uses `model` as the variable name, so we shadow it with the inner model
         let view_model = quote! {
-            pub fn view_model(app_model: &AppModel) -> Element<'_, #message_ident> {
+            #vis fn view_model(app_model: &AppModel) -> Element<'_, #message_ident> {
                 let model = &app_model.inner;
                 #view_fn
             }
         };
 
-        // Generate subscription with window events
+        // Generate subscription with window events (and image loader requests or toasts)
+        let window_events = match subscription::generate_service_subscriptions(&sub_config) {
+            Some(services) => quote! {
+                iced::Subscription::batch(vec![
+                    iced::window::events().map(|(id, e)| #message_ident::Window(id, e)),
+                    #services,
+                ])
+            },
+            None => quote! {
+                iced::window::events()
+                    .map(|(id, e)| #message_ident::Window(id, e))
+            },
+        };
         let subscription = if sub_config.system_theme_variant.is_some() {
             let variant_name = sub_config
                 .system_theme_variant
                 .as_ref()
                 .map(|v| syn::Ident::new(v, proc_macro2::Span::call_site()));
             quote! {
-                pub fn subscription_model(_model: &AppModel) -> iced::Subscription<#message_ident> {
-                    let window_events = iced::window::events()
-                        .map(|(id, e)| #message_ident::Window(id, e));
+                #vis fn subscription_model(_model: &AppModel) -> iced::Subscription<#message_ident> {
+                    let window_events = #window_events;
 
                     if app_follows_system() {
                         let system_theme = dampen_iced::watch_system_theme()
@@ -601,14 +658,17 @@ pub fn generate_application_full(
             }
         } else {
             quote! {
-                pub fn subscription_model(_model: &AppModel) -> iced::Subscription<#message_ident> {
-                    iced::window::events()
-                        .map(|(id, e)| #message_ident::Window(id, e))
+                #vis fn subscription_model(_model: &AppModel) -> iced::Subscription<#message_ident> {
+                    #window_events
                 }
             }
         };
 
-        // Generate window_settings function
+        // Generate window_settings function, applying the `<window>` declaration
+        let declared_settings = document
+            .window
+            .as_ref()
+            .map(window::generate_window_builder_calls);
         let window_settings = quote! {
             /// Returns a WindowSettingsBuilder for configuring the initial window state.
             ///
@@ -625,10 +685,12 @@ pub fn generate_application_full(
             ///         .build())
             ///     .run()
             /// ```
-            pub fn window_settings() -> dampen_dev::persistence::WindowSettingsBuilder {
+            #vis fn window_settings() -> dampen_dev::persistence::WindowSettingsBuilder {
                 dampen_dev::persistence::WindowSettingsBuilder::new(#app_name)
+                    #declared_settings
             }
         };
+        let title = generate_title_function(document, &app_model, &vis);
 
         (
             wrapper,
@@ -636,36 +698,56 @@ pub fn generate_application_full(
             update_model,
             view_model,
             subscription,
-            window_settings,
+            quote! { #window_settings #title },
         )
     } else {
         // No persistence - generate simpler code without wrapper
         let wrapper = TokenStream::new();
 
         let new_model = quote! {
-            pub fn new_model() -> (#model_ident, Task<#message_ident>) {
-                (#model_ident::default(), Task::none())
+            #vis fn new_model() -> (#model_ident, Task<#message_ident>) {
+                (#model_ident::default(), #font_loading)
             }
         };
 
         let update_model = quote! {
-            pub fn update_model(model: &mut #model_ident, message: #message_ident) -> Task<#message_ident> {
-                match message {
+            #vis fn update_model(model: &mut #model_ident, message: #message_ident) -> Task<#message_ident> {
+                #theme_capture
+                let task = match message {
                     #update_arms
                     #system_theme_arm
-                }
+                    #image_loader_arm
+                    #notification_arm
+                    #debounce_arm
+                #palette_arm
+                    #system_preferences_arm
+                };
+                #theme_switch
+                task
             }
         };
 
         let view_model = quote! {
-            pub fn view_model(model: &#model_ident) -> Element<'_, #message_ident> {
+            #vis fn view_model(model: &#model_ident) -> Element<'_, #message_ident> {
                 #view_fn
             }
         };
 
         let subscription = subscription::generate_subscription_function(&sub_config);
 
-        let window_settings = TokenStream::new();
+        let window_settings = match &document.window {
+            Some(config) => {
+                let settings = window::generate_window_settings(config);
+                quote! {
+                    /// Returns the window settings declared by `<window>`.
+                    #vis fn window_settings() -> iced::window::Settings {
+                        #settings
+                    }
+                }
+            }
+            None => TokenStream::new(),
+        };
+        let title = generate_title_function(document, &app_model, &vis);
 
         (
             wrapper,
@@ -673,13 +755,13 @@ pub fn generate_application_full(
             update_model,
             view_model,
             subscription,
-            window_settings,
+            quote! { #window_settings #title },
         )
     };
 
     let combined = quote! {
         use iced::{Element, Task, Theme};
-        use crate::ui::window::*;
+        #prelude
         use std::collections::HashMap;
         use dampen_core::handler::CanvasEvent;
 
@@ -700,6 +782,8 @@ pub fn generate_application_full(
 
         #subscription_fn
 
+        #palette_commands_fn
+
         #window_settings_fn
     };
 
@@ -709,17 +793,51 @@ pub fn generate_application_full(
     })
 }
 
-/// Generate Message enum from handler signatures
-fn generate_message_enum(handlers: &[HandlerSignature]) -> Result<TokenStream, syn::Error> {
-    generate_message_enum_with_subscription(handlers, None)
+/// Generate the `use` items of the module path and imports of a configuration
+fn generate_prelude(config: &CodegenConfig) -> Result<TokenStream, syn::Error> {
+    let module_import = match &config.module_path {
+        Some(path) => {
+            let path: syn::Path = syn::parse_str(path)?;
+            quote! { use #path::*; }
+        }
+        None => TokenStream::new(),
+    };
+    let imports = config
+        .imports
+        .iter()
+        .map(|import| syn::parse_str::<syn::UseTree>(import))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(quote! {
+        #module_import
+        #(use #imports;)*
+    })
 }
 
-/// Generate Message enum from handler signatures with optional subscription config
-fn generate_message_enum_with_subscription(
+/// Generate the `title()` function returning the title declared by `<window>`
+fn generate_title_function(
+    document: &DampenDocument,
+    model_type: &TokenStream,
+    vis: &syn::Visibility,
+) -> TokenStream {
+    match document.window.as_ref().and_then(|w| w.title.as_ref()) {
+        Some(title) => quote! {
+            #vis fn title(_model: &#model_type) -> String {
+                #title.to_string()
+            }
+        },
+        None => TokenStream::new(),
+    }
+}
+
+/// Generate Message enum from handler signatures
+fn generate_message_enum(
     handlers: &[HandlerSignature],
-    sub_config: Option<&subscription::SubscriptionConfig>,
+    message_name: &str,
 ) -> Result<TokenStream, syn::Error> {
-    generate_message_enum_full(handlers, sub_config, false)
+    let message_ident = syn::Ident::new(message_name, proc_macro2::Span::call_site());
+    let vis: syn::Visibility = syn::parse_quote!(pub);
+    generate_message_enum_full(handlers, None, false, &message_ident, &vis)
 }
 
 /// Generate Message enum with all optional variants
@@ -727,6 +845,8 @@ fn generate_message_enum_full(
     handlers: &[HandlerSignature],
     sub_config: Option<&subscription::SubscriptionConfig>,
     include_window_events: bool,
+    message_ident: &syn::Ident,
+    vis: &syn::Visibility,
 ) -> Result<TokenStream, syn::Error> {
     let handler_variants: Vec<_> = handlers
         .iter()
@@ -762,6 +882,22 @@ fn generate_message_enum_full(
     // Add system theme variant if configured
     let system_theme_variant = sub_config.and_then(subscription::generate_system_theme_variant);
 
+    // Add image loader variant if the document may display remote images
+    let image_loader_variant = sub_config.and_then(subscription::generate_image_loader_variant);
+
+    // Add toasts variant if a handler may show notifications
+    let notification_variant = sub_config.and_then(subscription::generate_notification_variant);
+
+    // Add debounced input variant if an input sets `debounce_ms`
+    let debounce_variant = sub_config.and_then(subscription::generate_debounce_variant);
+
+    // Add command palette variant if a handler is labelled for it
+    let palette_variant = sub_config.and_then(subscription::generate_palette_variant);
+
+    // Add system preferences variant if the view binds them or a theme has a high-contrast variant
+    let system_preferences_variant =
+        sub_config.and_then(subscription::generate_system_preferences_variant);
+
     // Add window events variant if persistence is enabled
     let window_variant = if include_window_events {
         Some(quote! {
@@ -775,12 +911,17 @@ fn generate_message_enum_full(
     let all_variants: Vec<TokenStream> = handler_variants
         .into_iter()
         .chain(system_theme_variant)
+        .chain(image_loader_variant)
+        .chain(notification_variant)
+        .chain(debounce_variant)
+        .chain(palette_variant)
+        .chain(system_preferences_variant)
         .chain(window_variant)
         .collect();
 
     Ok(quote! {
         #[derive(Debug, Clone)]
-        pub enum Message {
+        #vis enum #message_ident {
             #(#all_variants),*
         }
     })
@@ -791,7 +932,7 @@ fn to_upper_camel_case(s: &str) -> String {
     let mut result = String::new();
     let mut capitalize_next = true;
     for c in s.chars() {
-        if c == '_' {
+        if c == '_' || c == '.' {
             capitalize_next = true;
         } else if capitalize_next {
             result.push(c.to_ascii_uppercase());
@@ -811,44 +952,7 @@ fn generate_update_arms_for_inner(
     handlers: &[HandlerSignature],
     message_name: &str,
 ) -> Result<TokenStream, CodegenError> {
-    use quote::format_ident;
-
-    let message_ident = format_ident!("{}", message_name);
-
-    let match_arms: Vec<TokenStream> = handlers
-        .iter()
-        .map(|handler| {
-            let handler_name = format_ident!("{}", handler.name);
-            let variant_name = to_upper_camel_case(&handler.name);
-            let variant_ident = syn::Ident::new(&variant_name, proc_macro2::Span::call_site());
-
-            if let Some(_param_type) = &handler.param_type {
-                quote! {
-                    #message_ident::#variant_ident(value) => {
-                        #handler_name(&mut model.inner, value);
-                        iced::Task::none()
-                    }
-                }
-            } else if handler.returns_command {
-                quote! {
-                    #message_ident::#variant_ident => {
-                        #handler_name(&mut model.inner)
-                    }
-                }
-            } else {
-                quote! {
-                    #message_ident::#variant_ident => {
-                        #handler_name(&mut model.inner);
-                        iced::Task::none()
-                    }
-                }
-            }
-        })
-        .collect();
-
-    Ok(quote! {
-        #(#match_arms)*
-    })
+    update::generate_arms_for_model(handlers, message_name, quote! { &mut model.inner })
 }
 
 /// Basic constant folding optimizations for generated code
@@ -891,9 +995,11 @@ pub fn validate_handlers(
     let mut referenced_handlers = Vec::new();
     collect_handlers(&document.root, &mut referenced_handlers);
 
-    // Check each referenced handler exists
+    // Check each referenced handler exists; selection handlers are generated
     for handler in referenced_handlers {
-        if !handler_names.contains(&handler) {
+        if !handler_names.contains(&handler)
+            && crate::selection::selection_handler(&handler).is_none()
+        {
             return Err(CodegenError::MissingHandler(handler));
         }
     }
@@ -935,15 +1041,17 @@ mod tests {
                 name: "increment".to_string(),
                 param_type: None,
                 returns_command: false,
+                palette: None,
             },
             HandlerSignature {
                 name: "update_value".to_string(),
                 param_type: Some("String".to_string()),
                 returns_command: false,
+                palette: None,
             },
         ];
 
-        let tokens = generate_message_enum(&handlers).unwrap();
+        let tokens = generate_message_enum(&handlers, "Message").unwrap();
         let code = tokens.to_string();
 
         assert!(code.contains("Increment"));
@@ -959,6 +1067,7 @@ mod tests {
             name: "increment".to_string(),
             param_type: None,
             returns_command: false,
+            palette: None,
         }];
 
         assert!(validate_handlers(&doc, &handlers).is_ok());
@@ -967,4 +1076,83 @@ mod tests {
         let handlers_empty: Vec<HandlerSignature> = vec![];
         assert!(validate_handlers(&doc, &handlers_empty).is_err());
     }
+
+    #[test]
+    fn test_application_layout_from_config() {
+        let doc = parse(r#"<button label="Save" on_click="save" />"#).unwrap();
+        let handlers = vec![HandlerSignature {
+            name: "save".to_string(),
+            param_type: None,
+            returns_command: false,
+            palette: None,
+        }];
+
+        let default = generate_application_with_theme_and_subscriptions(
+            &doc,
+            "Model",
+            "AppMessage",
+            &handlers,
+            None,
+        )
+        .unwrap();
+        assert!(default.code.contains("use crate :: ui :: window :: *"));
+        assert!(default.code.contains("pub enum AppMessage"));
+
+        let config = CodegenConfig::default()
+            .without_module_import()
+            .with_import("super::model::Model")
+            .with_visibility("")
+            .with_message_enum(false);
+        let code = generate_application_with_config(&doc, &handlers, None, &config)
+            .unwrap()
+            .code;
+        assert!(!code.contains("crate :: ui"));
+        assert!(code.contains("use super :: model :: Model ;"));
+        assert!(!code.contains("enum Message"));
+        assert!(code.contains("Message :: Save"));
+        assert!(!code.contains("pub fn view_model"));
+        assert!(code.contains("fn view_model"));
+    }
+
+    #[test]
+    fn test_theme_overrides_and_switches() {
+        let doc = parse(
+            r#"<column>
+                <container theme="dark"><text value="Dark" /></container>
+            </column>"#,
+        )
+        .unwrap();
+        let theme_document = crate::parser::theme_parser::parse_theme_document(
+            r##"<dampen><themes>
+                <theme name="light"><palette primary="#3498db" secondary="#2ecc71" success="#27ae60" warning="#f39c12" danger="#e74c3c" background="#ecf0f1" surface="#ffffff" text="#2c3e50" text_secondary="#7f8c8d" /></theme>
+                <theme name="dark" extends="light"><palette background="#1a1a2e" text="#eaeaea" /></theme>
+            </themes></dampen>"##,
+        )
+        .unwrap();
+
+        let code = generate_application_with_theme_and_subscriptions(
+            &doc,
+            "Model",
+            "Message",
+            &[],
+            Some(&theme_document),
+        )
+        .unwrap()
+        .code
+        .replace(char::is_whitespace, "");
+        assert!(
+            code.contains("iced::widget::themer(app_theme_named(&\"dark\".to_string()),content)")
+        );
+        assert!(code.contains(
+            "theme_capture.finish(&muttheme_switch);ifletSome(name)=theme_switch.take_request(){app_set_current_theme(&name);}"
+        ));
+
+        // Without theme document, the override and the switches are left out
+        let code =
+            generate_application_with_theme_and_subscriptions(&doc, "Model", "Message", &[], None)
+                .unwrap()
+                .code;
+        assert!(!code.contains("themer"));
+        assert!(!code.contains("theme_switch"));
+    }
 }
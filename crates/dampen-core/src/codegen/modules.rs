@@ -0,0 +1,192 @@
+//! Modules of the code generated for the views of `src/ui`
+//!
+//! Build scripts write the code of each view to its own file in `OUT_DIR`,
+//! and `ui_generated.rs` includes the files in modules nested like the
+//! directories of the views: `src/ui/admin/settings.dampen` is generated in
+//! `ui_generated/admin/settings.rs` and included as `admin::settings`. Views
+//! with the same file name in different directories get their own module,
+//! file and cache entry.
+//!
+//! # Example
+//!
+//! ```rust
+//! use dampen_core::codegen::modules::{ViewModule, generated_index};
+//! use std::path::Path;
+//!
+//! let admin = ViewModule::new(Path::new("admin/settings.dampen"));
+//! assert_eq!(admin.module_path(), "admin::settings");
+//! assert_eq!(admin.ui_module_path(), "crate::ui::admin::settings");
+//! assert_eq!(admin.file(), Path::new("admin/settings.rs"));
+//!
+//! let index = generated_index(&[(admin, "src/ui/admin/settings.dampen".into())]);
+//! assert!(index.contains("pub mod admin {"));
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Module of the generated code of a view
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ViewModule {
+    segments: Vec<String>,
+}
+
+impl ViewModule {
+    /// Module of the view at `view_path`, relative to `src/ui` (e.g. `admin/settings.dampen`)
+    pub fn new(view_path: &Path) -> Self {
+        Self {
+            segments: view_path
+                .with_extension("")
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy().to_string())
+                .collect(),
+        }
+    }
+
+    /// Path of the module in `ui_generated.rs`, e.g. `admin::settings`
+    pub fn module_path(&self) -> String {
+        self.segments.join("::")
+    }
+
+    /// Path of the view's own module, with its model and handlers, e.g. `crate::ui::admin::settings`
+    ///
+    /// This is the module path of the view's [`CodegenConfig`](super::CodegenConfig).
+    pub fn ui_module_path(&self) -> String {
+        format!("crate::ui::{}", self.module_path())
+    }
+
+    /// File of the generated code, relative to `OUT_DIR/ui_generated`
+    pub fn file(&self) -> PathBuf {
+        let mut file: PathBuf = self.segments.iter().collect();
+        file.set_extension("rs");
+        file
+    }
+
+    /// Name of the view in the [`CodegenCache`](super::cache::CodegenCache), e.g. `admin.settings`
+    pub fn cache_name(&self) -> String {
+        self.segments.join(".")
+    }
+}
+
+/// Views and submodules of a module of `ui_generated.rs`
+#[derive(Default)]
+struct IndexModule<'a> {
+    view: Option<&'a Path>,
+    children: BTreeMap<&'a str, IndexModule<'a>>,
+}
+
+/// Code of `ui_generated.rs`, including the file of each view in its module
+///
+/// `views` pairs the module of each view with its source file, shown in a
+/// `// Generated from:` comment above the module.
+pub fn generated_index(views: &[(ViewModule, PathBuf)]) -> String {
+    let mut root = IndexModule::default();
+    for (module, source) in views {
+        let node = module.segments.iter().fold(&mut root, |node, segment| {
+            node.children.entry(segment.as_str()).or_default()
+        });
+        node.view = Some(source);
+    }
+
+    let mut code = String::new();
+    code.push_str("// Auto-generated - DO NOT EDIT\n");
+    code.push_str("// Regenerate with: cargo build --features codegen\n");
+    write_modules(&mut code, &root, &mut Vec::new());
+    code
+}
+
+fn write_modules<'a>(code: &mut String, module: &IndexModule<'a>, path: &mut Vec<&'a str>) {
+    let indent = "    ".repeat(path.len());
+    for (name, child) in &module.children {
+        path.push(name);
+        // Items are separated by a blank line
+        if !code.ends_with("{\n") {
+            code.push('\n');
+        }
+        if let Some(source) = child.view {
+            code.push_str(&format!(
+                "{indent}// Generated from: {}\n",
+                source.display()
+            ));
+        }
+        code.push_str(&format!("{indent}pub mod {name} {{\n"));
+        if child.view.is_some() {
+            code.push_str(&format!(
+                "{indent}    include!(concat!(env!(\"OUT_DIR\"), \"/ui_generated/{}.rs\"));\n",
+                path.join("/")
+            ));
+        }
+        write_modules(code, child, path);
+        code.push_str(&format!("{indent}}}\n"));
+        path.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nested_views_sharing_a_stem() {
+        let views: Vec<(ViewModule, PathBuf)> = ["settings", "admin/settings", "user/settings"]
+            .into_iter()
+            .map(|view| {
+                (
+                    ViewModule::new(Path::new(&format!("{view}.dampen"))),
+                    PathBuf::from(format!("src/ui/{view}.dampen")),
+                )
+            })
+            .collect();
+
+        let module_paths: Vec<String> = views.iter().map(|(m, _)| m.module_path()).collect();
+        assert_eq!(
+            module_paths,
+            vec!["settings", "admin::settings", "user::settings"]
+        );
+        let files: Vec<PathBuf> = views.iter().map(|(m, _)| m.file()).collect();
+        assert_eq!(
+            files,
+            vec![
+                PathBuf::from("settings.rs"),
+                Path::new("admin").join("settings.rs"),
+                Path::new("user").join("settings.rs"),
+            ]
+        );
+        let cache_names: Vec<String> = views.iter().map(|(m, _)| m.cache_name()).collect();
+        assert_eq!(
+            cache_names,
+            vec!["settings", "admin.settings", "user.settings"]
+        );
+
+        let index = generated_index(&views);
+        assert_eq!(index.matches("pub mod settings {").count(), 3);
+        assert!(index.contains(
+            "pub mod admin {\n    // Generated from: src/ui/admin/settings.dampen\n    pub mod settings {\n        include!(concat!(env!(\"OUT_DIR\"), \"/ui_generated/admin/settings.rs\"));\n    }\n}\n"
+        ));
+        assert!(index.contains("\"/ui_generated/user/settings.rs\""));
+        assert!(index.contains(
+            "// Generated from: src/ui/settings.dampen\npub mod settings {\n    include!(concat!(env!(\"OUT_DIR\"), \"/ui_generated/settings.rs\"));\n}\n"
+        ));
+    }
+
+    #[test]
+    fn test_view_next_to_its_directory() {
+        let views = vec![
+            (
+                ViewModule::new(Path::new("admin.dampen")),
+                PathBuf::from("src/ui/admin.dampen"),
+            ),
+            (
+                ViewModule::new(Path::new("admin/users.dampen")),
+                PathBuf::from("src/ui/admin/users.dampen"),
+            ),
+        ];
+
+        // A single `admin` module includes the view and nests its directory
+        let index = generated_index(&views);
+        assert_eq!(index.matches("pub mod admin {").count(), 1);
+        assert!(index.contains(
+            "pub mod admin {\n    include!(concat!(env!(\"OUT_DIR\"), \"/ui_generated/admin.rs\"));\n\n    // Generated from: src/ui/admin/users.dampen\n    pub mod users {"
+        ));
+    }
+}
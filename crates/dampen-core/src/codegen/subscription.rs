@@ -6,8 +6,17 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
+use crate::HandlerSignature;
+use crate::ir::DampenDocument;
+use crate::ir::node::{AttributeValue, InterpolatedPart, WidgetKind, WidgetNode};
 use crate::ir::theme::ThemeDocument;
 
+/// Message variant emitted by the `on_input` of debounced inputs
+///
+/// The input displays the text typed until the handler message is emitted by
+/// `dampen_iced::debounce::ready`, so the variant only triggers a new render.
+pub const DEBOUNCE_VARIANT: &str = "InputDebounced";
+
 /// Configuration for subscription code generation
 #[derive(Debug, Clone)]
 pub struct SubscriptionConfig {
@@ -17,6 +26,20 @@ pub struct SubscriptionConfig {
     pub message_name: String,
     /// Variant name for system theme changed message (e.g., "SystemThemeChanged")
     pub system_theme_variant: Option<String>,
+    /// Variant name for the remote image loader message (e.g., "ImagesLoaded")
+    pub image_loader_variant: Option<String>,
+    /// Variant name for the toast notification message (e.g., "ToastsChanged")
+    pub notification_variant: Option<String>,
+    /// Variant name for the debounced input message (e.g., "InputDebounced")
+    pub debounce_variant: Option<String>,
+    /// Variant name for the command palette message (e.g., "Palette")
+    pub palette_variant: Option<String>,
+    /// Commands of the palette, as (handler, label)
+    pub palette_commands: Vec<(String, String)>,
+    /// Variant name for the system preferences message (e.g., "SystemPreferencesChanged")
+    pub system_preferences_variant: Option<String>,
+    /// Visibility of the generated functions
+    pub visibility: syn::Visibility,
 }
 
 impl Default for SubscriptionConfig {
@@ -25,6 +48,13 @@ impl Default for SubscriptionConfig {
             system_theme: false,
             message_name: "Message".to_string(),
             system_theme_variant: None,
+            image_loader_variant: None,
+            notification_variant: None,
+            debounce_variant: None,
+            palette_variant: None,
+            palette_commands: Vec::new(),
+            system_preferences_variant: None,
+            visibility: syn::parse_quote!(pub),
         }
     }
 }
@@ -45,7 +75,87 @@ impl SubscriptionConfig {
             } else {
                 None
             },
+            image_loader_variant: None,
+            notification_variant: None,
+            debounce_variant: None,
+            palette_variant: None,
+            palette_commands: Vec::new(),
+            system_preferences_variant: None,
+            visibility: syn::parse_quote!(pub),
+        }
+    }
+
+    /// Enable the remote image loader if the document may display remote images
+    ///
+    /// This is the case when an `<image>` has a bound or `http(s)` source, or sets
+    /// `placeholder`/`error_src`.
+    pub fn with_remote_images(mut self, document: &DampenDocument) -> Self {
+        if loads_remote_images(&document.root) {
+            self.image_loader_variant = Some("ImagesLoaded".to_string());
+        }
+        self
+    }
+
+    /// Enable toast notifications if a handler returns a task
+    ///
+    /// Toasts are shown by returning the task of `dampen_iced::notify::info` and
+    /// its siblings, so handlers without a task cannot show them.
+    pub fn with_notifications(mut self, handlers: &[HandlerSignature]) -> Self {
+        if handlers.iter().any(|handler| handler.returns_command) {
+            self.notification_variant = Some("ToastsChanged".to_string());
+        }
+        self
+    }
+
+    /// Enable debounced handler messages if an input of the document sets `debounce_ms`
+    pub fn with_debounced_inputs(mut self, document: &DampenDocument) -> Self {
+        if debounces_inputs(&document.root) {
+            self.debounce_variant = Some(DEBOUNCE_VARIANT.to_string());
         }
+        self
+    }
+
+    /// Enable the command palette if a handler is labelled for it
+    ///
+    /// Handlers are listed with `#[ui_handler(palette = "...")]`; those taking a
+    /// value cannot be run from the palette and are skipped.
+    pub fn with_command_palette(mut self, handlers: &[HandlerSignature]) -> Self {
+        self.palette_commands = handlers
+            .iter()
+            .filter(|handler| handler.param_type.is_none())
+            .filter_map(|handler| Some((handler.name.clone(), handler.palette.clone()?)))
+            .collect();
+        if !self.palette_commands.is_empty() {
+            self.palette_variant = Some("Palette".to_string());
+        }
+        self
+    }
+
+    /// Watch the system preferences if the document binds `system.*` or a theme
+    /// has a high-contrast variant
+    ///
+    /// The view is rendered again when the reduced-motion or high-contrast
+    /// setting of the system changes.
+    pub fn with_system_preferences(
+        mut self,
+        document: &DampenDocument,
+        theme_doc: Option<&ThemeDocument>,
+    ) -> Self {
+        let high_contrast_themes = theme_doc.is_some_and(|doc| {
+            doc.themes
+                .keys()
+                .any(|name| name.ends_with(crate::system::HIGH_CONTRAST_SUFFIX))
+        });
+        if high_contrast_themes || binds_system(&document.root) {
+            self.system_preferences_variant = Some("SystemPreferencesChanged".to_string());
+        }
+        self
+    }
+
+    /// Set the visibility of the generated functions
+    pub fn with_visibility(mut self, visibility: syn::Visibility) -> Self {
+        self.visibility = visibility;
+        self
     }
 
     /// Set the system theme variant name
@@ -83,8 +193,36 @@ impl SubscriptionConfig {
 /// ```
 pub fn generate_subscription_function(config: &SubscriptionConfig) -> TokenStream {
     let message_ident = syn::Ident::new(&config.message_name, proc_macro2::Span::call_site());
+    let vis = &config.visibility;
+
+    if let Some(services) = generate_service_subscriptions(config) {
+        let body = if let Some(ref variant_name) = config.system_theme_variant {
+            let variant_ident = syn::Ident::new(variant_name, proc_macro2::Span::call_site());
+            quote! {
+                if app_follows_system() {
+                    let system_theme = dampen_iced::watch_system_theme()
+                        .map(#message_ident::#variant_ident);
+                    iced::Subscription::batch(vec![system_theme, services])
+                } else {
+                    services
+                }
+            }
+        } else {
+            quote! { services }
+        };
 
-    if let Some(ref variant_name) = config.system_theme_variant {
+        quote! {
+            /// Get the application subscription for system events
+            ///
+            /// This function returns a subscription that starts loading remote images,
+            /// renders toast changes, listens to the command palette keys and, when
+            /// `follow_system` is enabled, monitors system theme changes.
+            #vis fn subscription_model() -> iced::Subscription<#message_ident> {
+                let services = #services;
+                #body
+            }
+        }
+    } else if let Some(ref variant_name) = config.system_theme_variant {
         let variant_ident = syn::Ident::new(variant_name, proc_macro2::Span::call_site());
 
         quote! {
@@ -92,7 +230,7 @@ pub fn generate_subscription_function(config: &SubscriptionConfig) -> TokenStrea
             ///
             /// This function returns a subscription that monitors system theme changes
             /// when `follow_system` is enabled in the theme configuration.
-            pub fn subscription_model() -> iced::Subscription<#message_ident> {
+            #vis fn subscription_model() -> iced::Subscription<#message_ident> {
                 if app_follows_system() {
                     dampen_iced::watch_system_theme()
                         .map(#message_ident::#variant_ident)
@@ -106,7 +244,7 @@ pub fn generate_subscription_function(config: &SubscriptionConfig) -> TokenStrea
             /// Get the application subscription for system events
             ///
             /// Returns no subscription when system theme following is disabled.
-            pub fn subscription_model() -> iced::Subscription<#message_ident> {
+            #vis fn subscription_model() -> iced::Subscription<#message_ident> {
                 iced::Subscription::none()
             }
         }
@@ -173,6 +311,380 @@ pub fn generate_system_theme_update_arm(config: &SubscriptionConfig) -> Option<T
     })
 }
 
+/// Generate the ImagesLoaded variant for the Message enum
+///
+/// The variant is emitted when a render requested remote images and each time
+/// one of them finished loading.
+///
+/// # Arguments
+///
+/// * `config` - Subscription configuration
+///
+/// # Returns
+///
+/// Option containing the TokenStream for the variant, or None if not needed
+pub fn generate_image_loader_variant(config: &SubscriptionConfig) -> Option<TokenStream> {
+    config.image_loader_variant.as_ref().map(|variant_name| {
+        let variant_ident = syn::Ident::new(variant_name, proc_macro2::Span::call_site());
+        quote! {
+            /// Remote images requested or loaded
+            #variant_ident
+        }
+    })
+}
+
+/// Generate the update match arm for ImagesLoaded
+///
+/// The arm starts the downloads queued by the last render; the returned task
+/// emits the same message when each image is loaded, which renders the view again.
+///
+/// # Example Output
+///
+/// ```rust,ignore
+/// Message::ImagesLoaded => {
+///     dampen_iced::image_loader::load_pending().map(|()| Message::ImagesLoaded)
+/// }
+/// ```
+pub fn generate_image_loader_update_arm(config: &SubscriptionConfig) -> Option<TokenStream> {
+    let message_ident = syn::Ident::new(&config.message_name, proc_macro2::Span::call_site());
+
+    config.image_loader_variant.as_ref().map(|variant_name| {
+        let variant_ident = syn::Ident::new(variant_name, proc_macro2::Span::call_site());
+
+        quote! {
+            #message_ident::#variant_ident => {
+                dampen_iced::image_loader::load_pending().map(|()| #message_ident::#variant_ident)
+            }
+        }
+    })
+}
+
+/// Generate the subscription expression notifying the app of requested images
+pub fn generate_image_loader_subscription(config: &SubscriptionConfig) -> Option<TokenStream> {
+    let message_ident = syn::Ident::new(&config.message_name, proc_macro2::Span::call_site());
+
+    config.image_loader_variant.as_ref().map(|variant_name| {
+        let variant_ident = syn::Ident::new(variant_name, proc_macro2::Span::call_site());
+        quote! {
+            dampen_iced::image_loader::requests().map(|()| #message_ident::#variant_ident)
+        }
+    })
+}
+
+/// Generate the ToastsChanged variant for the Message enum
+///
+/// The variant is emitted when a toast is shown or dismissed.
+pub fn generate_notification_variant(config: &SubscriptionConfig) -> Option<TokenStream> {
+    config.notification_variant.as_ref().map(|variant_name| {
+        let variant_ident = syn::Ident::new(variant_name, proc_macro2::Span::call_site());
+        quote! {
+            /// Toasts shown or dismissed
+            #variant_ident
+        }
+    })
+}
+
+/// Generate the update match arm for ToastsChanged
+///
+/// The message only triggers a new render of the toast overlay.
+pub fn generate_notification_update_arm(config: &SubscriptionConfig) -> Option<TokenStream> {
+    let message_ident = syn::Ident::new(&config.message_name, proc_macro2::Span::call_site());
+
+    config.notification_variant.as_ref().map(|variant_name| {
+        let variant_ident = syn::Ident::new(variant_name, proc_macro2::Span::call_site());
+        quote! {
+            #message_ident::#variant_ident => iced::Task::none(),
+        }
+    })
+}
+
+/// Generate the subscription expression notifying the app of toast changes
+pub fn generate_notification_subscription(config: &SubscriptionConfig) -> Option<TokenStream> {
+    let message_ident = syn::Ident::new(&config.message_name, proc_macro2::Span::call_site());
+
+    config.notification_variant.as_ref().map(|variant_name| {
+        let variant_ident = syn::Ident::new(variant_name, proc_macro2::Span::call_site());
+        quote! {
+            dampen_iced::notify::changes().map(|()| #message_ident::#variant_ident)
+        }
+    })
+}
+
+/// Generate the InputDebounced variant for the Message enum
+pub fn generate_debounce_variant(config: &SubscriptionConfig) -> Option<TokenStream> {
+    config.debounce_variant.as_ref().map(|variant_name| {
+        let variant_ident = syn::Ident::new(variant_name, proc_macro2::Span::call_site());
+        quote! {
+            /// Text typed in a debounced input
+            #variant_ident
+        }
+    })
+}
+
+/// Generate the update match arm for InputDebounced
+///
+/// The message only renders the text typed in the input; its handler runs when
+/// the debounced message is emitted.
+pub fn generate_debounce_update_arm(config: &SubscriptionConfig) -> Option<TokenStream> {
+    let message_ident = syn::Ident::new(&config.message_name, proc_macro2::Span::call_site());
+
+    config.debounce_variant.as_ref().map(|variant_name| {
+        let variant_ident = syn::Ident::new(variant_name, proc_macro2::Span::call_site());
+        quote! {
+            #message_ident::#variant_ident => iced::Task::none(),
+        }
+    })
+}
+
+/// Generate the subscription expression emitting the debounced handler messages
+pub fn generate_debounce_subscription(config: &SubscriptionConfig) -> Option<TokenStream> {
+    let message_ident = syn::Ident::new(&config.message_name, proc_macro2::Span::call_site());
+
+    config.debounce_variant.as_ref().map(|_| {
+        quote! {
+            dampen_iced::debounce::ready::<#message_ident>()
+        }
+    })
+}
+
+/// Generate the SystemPreferencesChanged variant for the Message enum
+pub fn generate_system_preferences_variant(config: &SubscriptionConfig) -> Option<TokenStream> {
+    config
+        .system_preferences_variant
+        .as_ref()
+        .map(|variant_name| {
+            let variant_ident = syn::Ident::new(variant_name, proc_macro2::Span::call_site());
+            quote! {
+                /// Reduced-motion or high-contrast setting changed
+                #variant_ident
+            }
+        })
+}
+
+/// Generate the update match arm for SystemPreferencesChanged
+///
+/// The preferences are already stored in `dampen_core::system`, so the message
+/// only triggers a new render.
+pub fn generate_system_preferences_update_arm(
+    config: &SubscriptionConfig,
+) -> Option<TokenStream> {
+    let message_ident = syn::Ident::new(&config.message_name, proc_macro2::Span::call_site());
+
+    config
+        .system_preferences_variant
+        .as_ref()
+        .map(|variant_name| {
+            let variant_ident = syn::Ident::new(variant_name, proc_macro2::Span::call_site());
+            quote! {
+                #message_ident::#variant_ident => iced::Task::none(),
+            }
+        })
+}
+
+/// Generate the subscription expression notifying the app of preference changes
+pub fn generate_system_preferences_subscription(
+    config: &SubscriptionConfig,
+) -> Option<TokenStream> {
+    let message_ident = syn::Ident::new(&config.message_name, proc_macro2::Span::call_site());
+
+    config
+        .system_preferences_variant
+        .as_ref()
+        .map(|variant_name| {
+            let variant_ident = syn::Ident::new(variant_name, proc_macro2::Span::call_site());
+            quote! {
+                dampen_iced::system_preferences::changes().map(|_| #message_ident::#variant_ident)
+            }
+        })
+}
+
+/// Generate the Palette variant for the Message enum
+pub fn generate_palette_variant(config: &SubscriptionConfig) -> Option<TokenStream> {
+    config.palette_variant.as_ref().map(|variant_name| {
+        let variant_ident = syn::Ident::new(variant_name, proc_macro2::Span::call_site());
+        quote! {
+            /// Command palette event
+            #variant_ident(dampen_iced::palette::Event)
+        }
+    })
+}
+
+/// Generate the `palette_commands()` function listing the labelled handlers
+pub fn generate_palette_commands_function(config: &SubscriptionConfig) -> Option<TokenStream> {
+    config.palette_variant.as_ref()?;
+
+    let vis = &config.visibility;
+    let commands = config.palette_commands.iter().map(|(handler, label)| {
+        quote! { dampen_core::PaletteCommand::new(#handler, #label) }
+    });
+    Some(quote! {
+        /// Commands listed in the command palette
+        #vis fn palette_commands() -> Vec<dampen_core::PaletteCommand> {
+            vec![#(#commands),*]
+        }
+    })
+}
+
+/// Generate the update match arm for Palette
+///
+/// The chosen handler is run by updating the model with its message.
+///
+/// # Example Output
+///
+/// ```rust,ignore
+/// Message::Palette(event) => {
+///     match dampen_iced::palette::update(event, &palette_commands()) {
+///         dampen_iced::palette::Action::Focus => dampen_iced::palette::focus(),
+///         dampen_iced::palette::Action::Dispatch(handler) => match handler.as_str() {
+///             "save_file" => update_model(model, Message::SaveFile),
+///             _ => iced::Task::none(),
+///         },
+///         dampen_iced::palette::Action::None => iced::Task::none(),
+///     }
+/// }
+/// ```
+pub fn generate_palette_update_arm(config: &SubscriptionConfig) -> Option<TokenStream> {
+    let message_ident = syn::Ident::new(&config.message_name, proc_macro2::Span::call_site());
+
+    config.palette_variant.as_ref().map(|variant_name| {
+        let variant_ident = syn::Ident::new(variant_name, proc_macro2::Span::call_site());
+        let dispatch_arms = config.palette_commands.iter().map(|(handler, _)| {
+            let handler_variant = syn::Ident::new(
+                &super::to_upper_camel_case(handler),
+                proc_macro2::Span::call_site(),
+            );
+            quote! {
+                #handler => update_model(model, #message_ident::#handler_variant),
+            }
+        });
+
+        quote! {
+            #message_ident::#variant_ident(event) => {
+                match dampen_iced::palette::update(event, &palette_commands()) {
+                    dampen_iced::palette::Action::Focus => dampen_iced::palette::focus(),
+                    dampen_iced::palette::Action::Dispatch(handler) => match handler.as_str() {
+                        #(#dispatch_arms)*
+                        _ => iced::Task::none(),
+                    },
+                    dampen_iced::palette::Action::None => iced::Task::none(),
+                }
+            }
+        }
+    })
+}
+
+/// Generate the subscription expression emitting the command palette keys
+pub fn generate_palette_subscription(config: &SubscriptionConfig) -> Option<TokenStream> {
+    let message_ident = syn::Ident::new(&config.message_name, proc_macro2::Span::call_site());
+
+    config.palette_variant.as_ref().map(|variant_name| {
+        let variant_ident = syn::Ident::new(variant_name, proc_macro2::Span::call_site());
+        quote! {
+            dampen_iced::palette::subscription().map(#message_ident::#variant_ident)
+        }
+    })
+}
+
+/// Stack the toasts and the command palette over the view expression, in their
+/// layers of `dampen_iced::layers`, when they are enabled
+///
+/// # Example Output
+///
+/// ```rust,ignore
+/// Into::<Element<'_, Message>>::into(
+///     dampen_iced::layers::Layers::new({ view })
+///         .push_maybe(dampen_iced::layers::Layer::Toast, dampen_iced::notify::layer())
+///         .push_maybe(
+///             dampen_iced::layers::Layer::Modal,
+///             dampen_iced::palette::layer(&palette_commands(), Message::Palette),
+///         ),
+/// )
+/// ```
+pub fn generate_overlay_layers(config: &SubscriptionConfig, view: TokenStream) -> TokenStream {
+    let message_ident = syn::Ident::new(&config.message_name, proc_macro2::Span::call_site());
+
+    let toasts = config.notification_variant.as_ref().map(|_| {
+        quote! {
+            .push_maybe(dampen_iced::layers::Layer::Toast, dampen_iced::notify::layer())
+        }
+    });
+    let palette = config.palette_variant.as_ref().map(|variant_name| {
+        let variant_ident = syn::Ident::new(variant_name, proc_macro2::Span::call_site());
+        quote! {
+            .push_maybe(
+                dampen_iced::layers::Layer::Modal,
+                dampen_iced::palette::layer(&palette_commands(), #message_ident::#variant_ident),
+            )
+        }
+    });
+    if toasts.is_none() && palette.is_none() {
+        return view;
+    }
+
+    quote! {
+        Into::<Element<'_, #message_ident>>::into(
+            dampen_iced::layers::Layers::new({ #view })
+                #toasts
+                #palette
+        )
+    }
+}
+
+/// Generate the subscription of the image loader, toasts, debounced inputs and
+/// command palette, batched if several are enabled
+pub fn generate_service_subscriptions(config: &SubscriptionConfig) -> Option<TokenStream> {
+    let services: Vec<TokenStream> = [
+        generate_image_loader_subscription(config),
+        generate_notification_subscription(config),
+        generate_debounce_subscription(config),
+        generate_palette_subscription(config),
+        generate_system_preferences_subscription(config),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    match services.as_slice() {
+        [] => None,
+        [service] => Some(service.clone()),
+        _ => Some(quote! { iced::Subscription::batch(vec![#(#services),*]) }),
+    }
+}
+
+/// Whether an attribute of the tree binds the system preferences
+fn binds_system(node: &WidgetNode) -> bool {
+    let binds = node.attributes.values().any(|value| match value {
+        AttributeValue::Binding(binding) => binding.uses_system(),
+        AttributeValue::Interpolated(parts) => parts.iter().any(|part| match part {
+            InterpolatedPart::Binding(binding) => binding.uses_system(),
+            InterpolatedPart::Literal(_) => false,
+        }),
+        AttributeValue::Static(_) => false,
+    });
+    binds || node.children.iter().any(binds_system)
+}
+
+/// Whether an element of the tree debounces its input events
+fn debounces_inputs(node: &WidgetNode) -> bool {
+    node.attributes.contains_key("debounce_ms") || node.children.iter().any(debounces_inputs)
+}
+
+/// Whether an `<image>` of the tree may load its source asynchronously
+fn loads_remote_images(node: &WidgetNode) -> bool {
+    let is_remote = node.kind == WidgetKind::Image
+        && (node.attributes.contains_key("placeholder")
+            || node.attributes.contains_key("error_src")
+            || match node.attributes.get("src") {
+                Some(AttributeValue::Static(src)) => {
+                    src.starts_with("http://") || src.starts_with("https://")
+                }
+                Some(_) => true,
+                None => false,
+            });
+
+    is_remote || node.children.iter().any(loads_remote_images)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -193,8 +705,11 @@ mod tests {
                     font_size_large: Some(24.0),
                     font_weight: crate::ir::theme::FontWeight::Normal,
                     line_height: Some(1.5),
+                    scale_ratio: None,
+                    fonts: Vec::new(),
                 },
                 spacing: SpacingScale { unit: Some(8.0) },
+                shadows: Default::default(),
                 base_styles: HashMap::new(),
                 extends: None,
             },
@@ -234,6 +749,13 @@ mod tests {
             system_theme: true,
             message_name: "Message".to_string(),
             system_theme_variant: Some("SystemThemeChanged".to_string()),
+            image_loader_variant: None,
+            notification_variant: None,
+            debounce_variant: None,
+            palette_variant: None,
+            palette_commands: Vec::new(),
+            system_preferences_variant: None,
+            visibility: syn::parse_quote!(pub),
         };
 
         let tokens = generate_subscription_function(&config);
@@ -269,6 +791,13 @@ mod tests {
             system_theme: true,
             message_name: "Message".to_string(),
             system_theme_variant: Some("SystemThemeChanged".to_string()),
+            image_loader_variant: None,
+            notification_variant: None,
+            debounce_variant: None,
+            palette_variant: None,
+            palette_commands: Vec::new(),
+            system_preferences_variant: None,
+            visibility: syn::parse_quote!(pub),
         };
 
         let tokens = generate_system_theme_variant(&config);
@@ -285,6 +814,13 @@ mod tests {
             system_theme: true,
             message_name: "Message".to_string(),
             system_theme_variant: Some("SystemThemeChanged".to_string()),
+            image_loader_variant: None,
+            notification_variant: None,
+            debounce_variant: None,
+            palette_variant: None,
+            palette_commands: Vec::new(),
+            system_preferences_variant: None,
+            visibility: syn::parse_quote!(pub),
         };
 
         let tokens = generate_system_theme_update_arm(&config);
@@ -305,4 +841,109 @@ mod tests {
             code
         );
     }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_remote_images_enable_image_loader() {
+        let remote = crate::parser::parse(r#"<column><image src="{avatar}" /></column>"#)
+            .expect("valid document");
+        let local =
+            crate::parser::parse(r#"<image src="assets/logo.png" />"#).expect("valid document");
+
+        let config = SubscriptionConfig::default().with_remote_images(&remote);
+        assert_eq!(
+            config.image_loader_variant,
+            Some("ImagesLoaded".to_string())
+        );
+        let code = generate_subscription_function(&config).to_string();
+        assert!(code.contains("image_loader :: requests"), "code: {}", code);
+        let arm = generate_image_loader_update_arm(&config)
+            .unwrap()
+            .to_string();
+        assert!(arm.contains("load_pending"), "code: {}", arm);
+
+        let config = SubscriptionConfig::default().with_remote_images(&local);
+        assert_eq!(config.image_loader_variant, None);
+    }
+
+    #[test]
+    fn test_handlers_returning_tasks_enable_notifications() {
+        let handler = |returns_command| HandlerSignature {
+            name: "save".to_string(),
+            param_type: None,
+            returns_command,
+            palette: None,
+        };
+
+        let config = SubscriptionConfig::default().with_notifications(&[handler(true)]);
+        let code = generate_subscription_function(&config).to_string();
+        assert!(code.contains("notify :: changes ()"), "code: {}", code);
+        let view = generate_overlay_layers(&config, quote! { view }).to_string();
+        assert!(
+            view.contains("push_maybe (dampen_iced :: layers :: Layer :: Toast , dampen_iced :: notify :: layer ())"),
+            "view: {}",
+            view
+        );
+
+        let config = SubscriptionConfig {
+            image_loader_variant: Some("ImagesLoaded".to_string()),
+            ..SubscriptionConfig::default()
+        }
+        .with_notifications(&[handler(true)]);
+        let code = generate_service_subscriptions(&config).unwrap().to_string();
+        assert!(
+            code.starts_with("iced :: Subscription :: batch"),
+            "code: {}",
+            code
+        );
+
+        let config = SubscriptionConfig::default().with_notifications(&[handler(false)]);
+        assert_eq!(config.notification_variant, None);
+        assert!(generate_notification_update_arm(&config).is_none());
+    }
+
+    #[test]
+    fn test_palette_labels_enable_command_palette() {
+        let handler =
+            |name: &str, param_type: Option<&str>, palette: Option<&str>| HandlerSignature {
+                name: name.to_string(),
+                param_type: param_type.map(str::to_string),
+                returns_command: false,
+                palette: palette.map(str::to_string),
+            };
+
+        let config = SubscriptionConfig::default().with_command_palette(&[
+            handler("save_file", None, Some("Save File")),
+            handler("rename", Some("String"), Some("Rename")),
+            handler("reset", None, None),
+        ]);
+        assert_eq!(
+            config.palette_commands,
+            [("save_file".to_string(), "Save File".to_string())]
+        );
+
+        let arm = generate_palette_update_arm(&config)
+            .map(|arm| arm.to_string())
+            .unwrap_or_default();
+        assert!(
+            arm.contains("\"save_file\" => update_model (model , Message :: SaveFile)"),
+            "arm: {}",
+            arm
+        );
+        let commands = generate_palette_commands_function(&config)
+            .map(|commands| commands.to_string())
+            .unwrap_or_default();
+        assert!(commands.contains("PaletteCommand :: new (\"save_file\" , \"Save File\")"));
+        let code = generate_subscription_function(&config).to_string();
+        assert!(
+            code.contains("palette :: subscription ()"),
+            "code: {}",
+            code
+        );
+
+        let config =
+            SubscriptionConfig::default().with_command_palette(&[handler("reset", None, None)]);
+        assert_eq!(config.palette_variant, None);
+        assert!(generate_palette_variant(&config).is_none());
+    }
 }
@@ -5,10 +5,13 @@
 //! with zero runtime parsing overhead.
 
 use super::GeneratedCode;
+use crate::DampenDocument;
+use crate::ir::stable::sorted_entries;
 use crate::ir::style::{
-    Background, Border, BorderRadius, Color, Gradient, Shadow, StyleProperties,
+    Background, Border, BorderRadius, BorderSides, Color, ColorStop, Gradient, Shadow,
+    StyleProperties,
 };
-use crate::ir::theme::{StyleClass, ThemeDocument, WidgetState};
+use crate::ir::theme::{StyleClass, Theme, ThemeDocument, WidgetState};
 use proc_macro2::TokenStream;
 use quote::quote;
 use std::collections::HashMap;
@@ -44,52 +47,44 @@ fn generate_background_expr(bg: &Background) -> TokenStream {
 }
 
 /// Generate TokenStream for a Gradient IR type to iced::Gradient
-fn generate_gradient_expr(gradient: &Gradient) -> TokenStream {
-    match gradient {
-        Gradient::Linear { angle, stops } => {
-            let radians = angle * (std::f32::consts::PI / 180.0);
+///
+/// Radial gradients are drawn as linear ones, see [`Gradient::to_linear`]. Stops
+/// referencing a theme color read it from the `_theme` of the style function.
+pub(super) fn generate_gradient_expr(gradient: &Gradient) -> TokenStream {
+    let (angle, stops) = gradient.to_linear();
+    let radians = angle.to_radians();
+
+    let stop_exprs: Vec<TokenStream> = stops
+        .iter()
+        .map(|stop| {
+            let offset = stop.offset;
+            let color_expr = generate_stop_color_expr(stop);
+            quote! { .add_stop(#offset, #color_expr) }
+        })
+        .collect();
 
-            let stop_exprs: Vec<TokenStream> = stops
-                .iter()
-                .take(8)
-                .map(|stop| {
-                    let offset = stop.offset;
-                    let color_expr = generate_color_expr(&stop.color);
-                    quote! { .add_stop(#offset, #color_expr) }
-                })
-                .collect();
-
-            quote! {
-                iced::Background::Gradient(
-                    iced::Gradient::Linear(
-                        iced::gradient::Linear::new(iced::Radians(#radians))
-                            #(#stop_exprs)*
-                    )
-                )
-            }
-        }
-        Gradient::Radial { stops, .. } => {
-            // Iced 0.14 only supports linear gradients, convert to linear as fallback
-            let radians = 0.0f32;
-            let stop_exprs: Vec<TokenStream> = stops
-                .iter()
-                .take(8)
-                .map(|stop| {
-                    let offset = stop.offset;
-                    let color_expr = generate_color_expr(&stop.color);
-                    quote! { .add_stop(#offset, #color_expr) }
-                })
-                .collect();
-
-            quote! {
-                iced::Background::Gradient(
-                    iced::Gradient::Linear(
-                        iced::gradient::Linear::new(iced::Radians(#radians))
-                            #(#stop_exprs)*
-                    )
-                )
-            }
-        }
+    quote! {
+        iced::Background::Gradient(
+            iced::Gradient::Linear(
+                iced::gradient::Linear::new(iced::Radians(#radians))
+                    #(#stop_exprs)*
+            )
+        )
+    }
+}
+
+/// Generate TokenStream for the color of a gradient stop, resolving theme colors
+fn generate_stop_color_expr(stop: &ColorStop) -> TokenStream {
+    let palette = quote! { _theme.extended_palette() };
+    match stop.theme_color.as_deref() {
+        Some("primary") => quote! { #palette.primary.base.color },
+        Some("secondary") => quote! { #palette.secondary.base.color },
+        Some("success") => quote! { #palette.success.base.color },
+        Some("warning") => quote! { #palette.warning.base.color },
+        Some("danger") => quote! { #palette.danger.base.color },
+        Some("background") => quote! { #palette.background.base.color },
+        Some("text") => quote! { #palette.background.base.text },
+        _ => generate_color_expr(&stop.color),
     }
 }
 
@@ -237,6 +232,9 @@ fn merge_style_properties(
             .transform
             .clone()
             .or_else(|| base.transform.clone()),
+        border_sides: BorderSides::cascade(base.border_sides, override_props.border_sides),
+        outline: override_props.outline.or(base.outline),
+        elevation: override_props.elevation.or(base.elevation),
     }
 }
 
@@ -262,7 +260,7 @@ fn generate_state_match_for_button(style_class: &StyleClass) -> TokenStream {
     });
 
     // State overrides
-    for (state, override_style) in &style_class.state_variants {
+    for (state, override_style) in sorted_entries(&style_class.state_variants) {
         let merged_style = merge_style_properties(&style_class.style, override_style);
         let style_expr = generate_button_style_struct(&merged_style);
 
@@ -450,6 +448,21 @@ pub fn generate_theme_code(
         "    let name = {}_current_theme_name();\n",
         module_name
     ));
+    // Themes with a high-contrast variant switch to it when the system asks for it
+    if document
+        .themes
+        .keys()
+        .any(|name| name.ends_with(crate::system::HIGH_CONTRAST_SUFFIX))
+    {
+        code.push_str("    if dampen_core::system::preferences().high_contrast {\n");
+        code.push_str(&format!(
+            "        if let Some(theme) = {}_theme_named(&dampen_core::system::high_contrast_variant(&name)) {{\n",
+            module_name
+        ));
+        code.push_str("            return theme;\n");
+        code.push_str("        }\n");
+        code.push_str("    }\n");
+    }
     code.push_str(&format!(
         "    {}_theme_named(&name).unwrap_or_else(|| {}_default_theme())\n",
         module_name, module_name
@@ -600,6 +613,106 @@ pub fn generate_theme_code(
     ))
 }
 
+/// Replace the `elevation` of the widgets and classes of `document` with shadows
+///
+/// Generated code cannot read the shadows of the theme at runtime, so levels are
+/// resolved against the default theme of the theme document, or else the global
+/// theme of the view. Levels neither sets use the built-in presets.
+pub(super) fn resolve_elevation(
+    document: &DampenDocument,
+    theme_document: Option<&ThemeDocument>,
+) -> DampenDocument {
+    let shadows = default_theme(document, theme_document)
+        .map(|theme| theme.shadows)
+        .unwrap_or_default();
+
+    let mut document = document.clone();
+    shadows.apply_to_node(&mut document.root);
+    for class in document.style_classes.values_mut() {
+        shadows.apply_to_class(class);
+    }
+    document
+}
+
+/// Set the size, weight and font of the `<text>` widgets of `document` from the
+/// typography of the theme
+///
+/// Like the elevation, text styles such as `style="heading1"` are resolved
+/// against the default theme, see [`Typography::apply_to_node`](crate::ir::Typography::apply_to_node).
+pub(super) fn resolve_typography(
+    document: &DampenDocument,
+    theme_document: Option<&ThemeDocument>,
+) -> DampenDocument {
+    let typography = default_theme(document, theme_document)
+        .map(|theme| theme.typography)
+        .unwrap_or_default();
+
+    let mut document = document.clone();
+    typography.apply_to_node(&mut document.root);
+    document
+}
+
+/// Remove the `theme="..."` overrides of `document` without a theme document
+///
+/// Generated views apply an override with the `app_theme_named` function of the
+/// theme code, which only exists with a theme document.
+pub(super) fn resolve_theme_overrides(
+    document: &DampenDocument,
+    theme_document: Option<&ThemeDocument>,
+) -> DampenDocument {
+    fn remove_overrides(node: &mut crate::WidgetNode) {
+        node.theme_ref = None;
+        for child in &mut node.children {
+            remove_overrides(child);
+        }
+    }
+
+    let mut document = document.clone();
+    if theme_document.is_none() {
+        remove_overrides(&mut document.root);
+    }
+    document
+}
+
+/// Default theme of the theme document, or else the global theme of the view
+fn default_theme(
+    document: &DampenDocument,
+    theme_document: Option<&ThemeDocument>,
+) -> Option<Theme> {
+    match theme_document {
+        Some(theme_document) => theme_document
+            .resolve_inheritance()
+            .remove(theme_document.effective_default(None)),
+        None => document
+            .global_theme
+            .as_ref()
+            .and_then(|name| document.themes.get(name))
+            .cloned(),
+    }
+}
+
+/// Generate the task registering the font files of the themes
+///
+/// Font paths are relative to the theme directory, `src/ui/theme`, and the
+/// files are embedded in the binary.
+pub(super) fn generate_font_loading(theme_document: Option<&ThemeDocument>) -> TokenStream {
+    let fonts = theme_document
+        .map(|theme_document| theme_document.fonts())
+        .unwrap_or_default();
+    if fonts.is_empty() {
+        return quote! { Task::none() };
+    }
+
+    let loads = fonts.iter().map(|font| {
+        let path = format!("/src/ui/theme/{}", font.trim_start_matches("./"));
+        quote! {
+            iced::font::load(include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), #path)).as_slice())
+                .discard()
+        }
+    });
+    quote! { Task::batch(vec![#(#loads),*]) }
+}
+
 /// Convert a color to RGB tuple (0.0-1.0 range)
 fn color_to_rgb8_tuple(color: Option<&Color>) -> (f32, f32, f32) {
     match color {
@@ -612,7 +725,7 @@ fn color_to_rgb8_tuple(color: Option<&Color>) -> (f32, f32, f32) {
 mod tests {
     use super::*;
     use crate::ir::style::Color;
-    use crate::ir::theme::{SpacingScale, Theme, ThemePalette, Typography};
+    use crate::ir::theme::{ShadowScale, SpacingScale, Theme, ThemePalette, Typography};
 
     fn create_test_palette_with_hex(hex: &str) -> ThemePalette {
         ThemePalette {
@@ -639,8 +752,11 @@ mod tests {
                 font_size_large: Some(24.0),
                 font_weight: crate::ir::theme::FontWeight::Normal,
                 line_height: Some(1.5),
+                scale_ratio: None,
+                fonts: Vec::new(),
             },
             spacing: SpacingScale { unit: Some(8.0) },
+            shadows: Default::default(),
             base_styles: std::collections::HashMap::new(),
             extends: None,
         }
@@ -694,6 +810,29 @@ mod tests {
         assert!(code.contains("themes.insert(\"dark\""));
     }
 
+    #[test]
+    fn test_generate_theme_code_high_contrast_variant() {
+        let doc = ThemeDocument {
+            themes: std::collections::HashMap::from([
+                ("dark".to_string(), create_test_theme("dark", "#5dade2")),
+                (
+                    "dark_high_contrast".to_string(),
+                    create_test_theme("dark_high_contrast", "#ffffff"),
+                ),
+            ]),
+            default_theme: Some("dark".to_string()),
+            follow_system: false,
+        };
+
+        let code = generate_theme_code(&doc, &HashMap::new(), "app").unwrap().code;
+
+        assert!(code.contains("if dampen_core::system::preferences().high_contrast {"));
+        assert!(code.contains(
+            "app_theme_named(&dampen_core::system::high_contrast_variant(&name))"
+        ));
+        assert!(code.contains("fn app_dark_high_contrast()"));
+    }
+
     #[test]
     fn test_generate_theme_code_empty_themes_error() {
         let doc = ThemeDocument {
@@ -769,6 +908,9 @@ mod tests {
                 shadow: None,
                 opacity: None,
                 transform: None,
+                border_sides: None,
+                outline: None,
+                elevation: None,
             },
             layout: None,
             extends: vec![],
@@ -805,6 +947,9 @@ mod tests {
                 shadow: None,
                 opacity: None,
                 transform: None,
+                border_sides: None,
+                outline: None,
+                elevation: None,
             },
         );
 
@@ -817,6 +962,9 @@ mod tests {
                 shadow: None,
                 opacity: None,
                 transform: None,
+                border_sides: None,
+                outline: None,
+                elevation: None,
             },
             layout: None,
             extends: vec![],
@@ -841,4 +989,136 @@ mod tests {
         assert!(code.contains("Status :: Active"));
         assert!(code.contains("Status :: Hovered"));
     }
+    #[test]
+    fn test_generate_gradient_with_theme_color() -> Result<(), String> {
+        let gradient =
+            crate::parser::gradient::parse_gradient("linear-gradient(to right, @primary, #000)")?;
+
+        let code = generate_gradient_expr(&gradient).to_string();
+        assert!(
+            code.contains(
+                "add_stop (0f32 , _theme . extended_palette () . primary . base . color)"
+            )
+        );
+        assert!(
+            code.contains(
+                "add_stop (1f32 , iced :: Color :: from_rgba (0f32 , 0f32 , 0f32 , 1f32))"
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_elevation_uses_theme_shadows() -> Result<(), String> {
+        let theme_doc = crate::parser::theme_parser::parse_theme_document(
+            r##"<dampen>
+                <themes>
+                    <theme name="light">
+                        <palette primary="#3498db" secondary="#2ecc71" success="#27ae60"
+                            warning="#f39c12" danger="#e74c3c" background="#ecf0f1"
+                            surface="#ffffff" text="#2c3e50" text_secondary="#7f8c8d" />
+                        <shadows level2="0 4 10 #00000033" />
+                    </theme>
+                </themes>
+            </dampen>"##,
+        )
+        .map_err(|e| e.message)?;
+        let doc = crate::parser::parse(
+            r##"<dampen>
+                <style_classes>
+                    <class name="card" elevation="3" />
+                </style_classes>
+                <column>
+                    <container elevation="2" />
+                    <container elevation="1" shadow="1 1 1 #000000" />
+                    <container elevation="0" />
+                </column>
+            </dampen>"##,
+        )
+        .map_err(|e| e.message)?;
+
+        let shadows = |doc: &crate::DampenDocument| -> Vec<Option<f32>> {
+            doc.root
+                .children
+                .iter()
+                .map(|child| {
+                    child
+                        .style
+                        .as_ref()
+                        .and_then(|style| style.shadow)
+                        .map(|shadow| shadow.blur_radius)
+                })
+                .collect()
+        };
+
+        // Levels set by the theme replace the presets, and an explicit shadow
+        // wins over the elevation
+        let resolved = resolve_elevation(&doc, Some(&theme_doc));
+        assert_eq!(shadows(&resolved), [Some(10.0), Some(1.0), None]);
+        assert_eq!(
+            resolved.style_classes["card"].style.shadow,
+            ShadowScale::preset(3)
+        );
+
+        // Without a theme document, the presets are used
+        let resolved = resolve_elevation(&doc, None);
+        let preset = ShadowScale::preset(2).map(|shadow| shadow.blur_radius);
+        assert_eq!(shadows(&resolved)[0], preset);
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_typography_scales_text_styles() -> Result<(), String> {
+        let theme_doc = crate::parser::theme_parser::parse_theme_document(
+            r##"<dampen>
+                <themes>
+                    <theme name="light">
+                        <palette primary="#3498db" secondary="#2ecc71" success="#27ae60"
+                            warning="#f39c12" danger="#e74c3c" background="#ecf0f1"
+                            surface="#ffffff" text="#2c3e50" text_secondary="#7f8c8d" />
+                        <typography font_family="Inter, sans-serif" font_size_base="20"
+                            scale_ratio="1.5" fonts="fonts/Inter.ttf" />
+                    </theme>
+                </themes>
+            </dampen>"##,
+        )
+        .map_err(|e| e.message)?;
+        let doc = crate::parser::parse(
+            r##"<dampen>
+                <column>
+                    <text value="Title" style="heading3" />
+                    <text value="Note" style="caption" size="9" />
+                    <text value="Body" />
+                    <markdown src="{readme}" />
+                </column>
+            </dampen>"##,
+        )
+        .map_err(|e| e.message)?;
+
+        let resolved = resolve_typography(&doc, Some(&theme_doc));
+        let attribute = |index: usize, name: &str| match resolved.root.children[index]
+            .attributes
+            .get(name)
+        {
+            Some(crate::ir::AttributeValue::Static(value)) => Some(value.clone()),
+            _ => None,
+        };
+        // 20 * 1.5^2, explicit sizes are kept, plain text gets the base size
+        assert_eq!(attribute(0, "size").as_deref(), Some("45"));
+        assert_eq!(attribute(0, "weight").as_deref(), Some("bold"));
+        assert_eq!(attribute(1, "size").as_deref(), Some("9"));
+        assert_eq!(attribute(2, "size").as_deref(), Some("20"));
+        assert_eq!(attribute(2, "font").as_deref(), Some("Inter"));
+        assert_eq!(attribute(3, "size").as_deref(), Some("20"));
+        assert_eq!(attribute(3, "scale_ratio").as_deref(), Some("1.5"));
+
+        let fonts = generate_font_loading(Some(&theme_doc)).to_string();
+        assert!(fonts.contains("iced :: font :: load"));
+        assert!(fonts.contains("\"/src/ui/theme/fonts/Inter.ttf\""));
+        assert_eq!(
+            generate_font_loading(None).to_string(),
+            quote! { Task::none() }.to_string()
+        );
+        Ok(())
+    }
 }
@@ -1,15 +1,16 @@
 //! Update function generation
 
-use crate::HandlerSignature;
+use crate::selection::{SelectionAction, SelectionMove, selection_handler};
+use crate::{DampenDocument, HandlerSignature, WidgetNode};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
-/// Convert snake_case to UpperCamelCase
+/// Convert snake_case to UpperCamelCase (`selection.toggle` becomes `SelectionToggle`)
 fn to_upper_camel_case(s: &str) -> String {
     let mut result = String::new();
     let mut capitalize_next = true;
     for c in s.chars() {
-        if c == '_' {
+        if c == '_' || c == '.' {
             capitalize_next = true;
         } else if capitalize_next {
             result.push(c.to_ascii_uppercase());
@@ -21,6 +22,40 @@ fn to_upper_camel_case(s: &str) -> String {
     result
 }
 
+/// Add the selection handlers referenced by `document` to `handlers`
+///
+/// A `<field>.<action>` handler (e.g. `selection.toggle:{item.id}`) that is not
+/// a user handler runs a [`SelectionAction`] on the
+/// [`SelectionState`](crate::SelectionState) field of the model. It gets a
+/// `String` variant for the actions taking an id, and a unit variant otherwise.
+pub fn with_selection_handlers(
+    document: &DampenDocument,
+    handlers: &[HandlerSignature],
+) -> Vec<HandlerSignature> {
+    fn collect(node: &WidgetNode, handlers: &mut Vec<HandlerSignature>) {
+        for event in &node.events {
+            if handlers.iter().any(|handler| handler.name == event.handler) {
+                continue;
+            }
+            if let Some((_, action)) = selection_handler(&event.handler) {
+                handlers.push(HandlerSignature {
+                    name: event.handler.clone(),
+                    param_type: action.takes_id().then(|| "String".to_string()),
+                    returns_command: false,
+                    palette: None,
+                });
+            }
+        }
+        for child in &node.children {
+            collect(child, handlers);
+        }
+    }
+
+    let mut handlers = handlers.to_vec();
+    collect(&document.root, &mut handlers);
+    handlers
+}
+
 /// Generate the update match arms for use in a standalone function
 pub fn generate_update_match_arms(
     handlers: &[HandlerSignature],
@@ -35,38 +70,134 @@ pub fn generate_update_match_arms(
     })
 }
 
+/// Generate a `message_for` function building the message of a handler by name
+///
+/// The value is converted as interpreted dispatch receives it: `String` and
+/// `&str` parameters take it as is, other parameter types parse it. Returns
+/// `None` for unknown handlers and for missing or unparsable values. Used to
+/// drive generated code with the handler names of interpreted mode, e.g. by
+/// parity tests.
+///
+/// # Example Output
+///
+/// ```rust,ignore
+/// pub fn message_for(handler: &str, value: Option<String>) -> Option<Message> {
+///     match handler {
+///         "increment" => Some(Message::Increment),
+///         "set_count" => value?.parse().ok().map(Message::SetCount),
+///         _ => None,
+///     }
+/// }
+/// ```
+pub fn generate_dispatch_function(
+    handlers: &[HandlerSignature],
+    message_name: &str,
+) -> TokenStream {
+    let message_ident = format_ident!("{}", message_name);
+
+    let arms = handlers.iter().map(|handler| {
+        let name = &handler.name;
+        let variant_ident = format_ident!("{}", to_upper_camel_case(&handler.name));
+        match handler.param_type.as_deref() {
+            None => quote! { #name => Some(#message_ident::#variant_ident), },
+            Some("String" | "&str") => quote! {
+                #name => value.map(#message_ident::#variant_ident),
+            },
+            Some(_) => quote! {
+                #name => value?.parse().ok().map(#message_ident::#variant_ident),
+            },
+        }
+    });
+
+    quote! {
+        #[allow(unused_variables)]
+        pub fn message_for(handler: &str, value: Option<String>) -> Option<#message_ident> {
+            match handler {
+                #(#arms)*
+                _ => None,
+            }
+        }
+    }
+}
+
 /// Generate ONLY the match arms (without the match message { ... } wrapper)
+///
+/// Each handler call goes through [`intercept`](crate::handler::intercept), or
+/// [`intercept_value`](crate::handler::intercept_value) for non-`String`
+/// arguments, so middleware registered with
+/// [`register_global_middleware`](crate::handler::register_global_middleware)
+/// wraps generated dispatch the same way registry middleware wraps interpreted
+/// dispatch. When middleware is registered, arguments are passed to it in their
+/// interpreted string form (`to_string()`), and a rewritten value is parsed back
+/// like [`message_for`](generate_dispatch_function) parses it: the handler is
+/// not called when the rewritten value cannot be parsed. Non-`String` argument
+/// types must therefore implement `Display` and `FromStr`.
 pub fn generate_arms(
     handlers: &[HandlerSignature],
     message_name: &str,
+) -> Result<TokenStream, super::CodegenError> {
+    generate_arms_for_model(handlers, message_name, quote! { model })
+}
+
+/// Generate the match arms, passing `model` (an expression of type `&mut Model`)
+/// to the handlers
+pub(crate) fn generate_arms_for_model(
+    handlers: &[HandlerSignature],
+    message_name: &str,
+    model: TokenStream,
 ) -> Result<TokenStream, super::CodegenError> {
     let message_ident = format_ident!("{}", message_name);
 
     let match_arms: Vec<TokenStream> = handlers
         .iter()
         .map(|handler| {
+            if let Some((field, action)) = selection_handler(&handler.name) {
+                return generate_selection_arm(&message_ident, field, action, &model);
+            }
+
             let handler_name = format_ident!("{}", handler.name);
             let variant_name = to_upper_camel_case(&handler.name);
             let variant_ident = syn::Ident::new(&variant_name, proc_macro2::Span::call_site());
 
-            if let Some(_param_type) = &handler.param_type {
-                quote! {
-                    #message_ident::#variant_ident(value) => {
-                        #handler_name(model, value);
-                        iced::Task::none()
+            let name = &handler.name;
+
+            if let Some(param_type) = &handler.param_type {
+                if param_type == "String" {
+                    quote! {
+                        #message_ident::#variant_ident(value) => {
+                            dampen_core::handler::intercept(#name, Some(value), move |value| {
+                                #handler_name(#model, value.unwrap_or_default());
+                                iced::Task::none()
+                            })
+                            .unwrap_or_else(iced::Task::none)
+                        }
+                    }
+                } else {
+                    quote! {
+                        #message_ident::#variant_ident(value) => {
+                            dampen_core::handler::intercept_value(#name, value, move |value| {
+                                #handler_name(#model, value);
+                                iced::Task::none()
+                            })
+                            .unwrap_or_else(iced::Task::none)
+                        }
                     }
                 }
             } else if handler.returns_command {
                 quote! {
                     #message_ident::#variant_ident => {
-                        #handler_name(model)
+                        dampen_core::handler::intercept(#name, None, move |_| #handler_name(#model))
+                            .unwrap_or_else(iced::Task::none)
                     }
                 }
             } else {
                 quote! {
                     #message_ident::#variant_ident => {
-                        #handler_name(model);
-                        iced::Task::none()
+                        dampen_core::handler::intercept(#name, None, move |_| {
+                            #handler_name(#model);
+                            iced::Task::none()
+                        })
+                        .unwrap_or_else(iced::Task::none)
                     }
                 }
             }
@@ -77,3 +208,48 @@ pub fn generate_arms(
         #(#match_arms)*
     })
 }
+
+/// Generate the match arm of a selection handler, applying `action` to the
+/// `field` path of the model
+fn generate_selection_arm(
+    message_ident: &syn::Ident,
+    field: &str,
+    action: SelectionAction,
+    model: &TokenStream,
+) -> TokenStream {
+    let variant_ident = format_ident!(
+        "{}",
+        to_upper_camel_case(&format!("{}.{}", field, action.name()))
+    );
+    let field_idents = field.split('.').map(|segment| format_ident!("{}", segment));
+    let action_tokens = match action {
+        SelectionAction::Select => quote! { Select },
+        SelectionAction::Toggle => quote! { Toggle },
+        SelectionAction::Extend => quote! { Extend },
+        SelectionAction::Clear => quote! { Clear },
+        SelectionAction::SelectAll => quote! { SelectAll },
+        SelectionAction::ToggleFocused => quote! { ToggleFocused },
+        SelectionAction::Move { to, extend } => {
+            let to = match to {
+                SelectionMove::Next => quote! { Next },
+                SelectionMove::Previous => quote! { Previous },
+                SelectionMove::First => quote! { First },
+                SelectionMove::Last => quote! { Last },
+            };
+            quote! { Move { to: dampen_core::selection::SelectionMove::#to, extend: #extend } }
+        }
+    };
+
+    let (pattern, id) = if action.takes_id() {
+        (quote! { (value) }, quote! { Some(value.as_str()) })
+    } else {
+        (quote! {}, quote! { None })
+    };
+
+    quote! {
+        #message_ident::#variant_ident #pattern => {
+            (#model).#(#field_idents).*.apply(dampen_core::selection::SelectionAction::#action_tokens, #id);
+            iced::Task::none()
+        }
+    }
+}
@@ -6,10 +6,11 @@
 
 use crate::DampenDocument;
 use crate::codegen::bindings::generate_expr;
-use crate::ir::layout::{LayoutConstraints, Length as LayoutLength};
-use crate::ir::node::{AttributeValue, InterpolatedPart, WidgetKind};
+use crate::ir::layout::{LayoutConstraints, Length as LayoutLength, Overflow};
+use crate::ir::node::{AttributeValue, InterpolatedPart, PathCommand, WidgetKind};
+use crate::ir::stable::sorted_entries;
 use crate::ir::style::{
-    Background, Border, BorderRadius, Color, Gradient, Shadow, StyleProperties,
+    Background, Border, BorderRadius, BorderSides, Color, Shadow, StyleProperties,
 };
 use crate::ir::theme::StyleClass;
 use proc_macro2::TokenStream;
@@ -25,15 +26,81 @@ pub fn generate_view(
     let message_ident = syn::Ident::new(message_name, proc_macro2::Span::call_site());
     let model_ident = syn::Ident::new("model", proc_macro2::Span::call_site());
 
-    let root_widget = generate_widget(
-        &document.root,
-        &model_ident,
-        &message_ident,
-        &document.style_classes,
-    )?;
+    // `model="{...}"` scopes are applied to the bindings up front
+    let mut root = crate::ir::scope::resolve_model_scopes(&document.root);
+    // Anchored floats are generated together with their anchor
+    crate::ir::placement::attach_anchored_floats(&mut root);
+
+    let root_widget =
+        generate_widget(&root, &model_ident, &message_ident, &document.style_classes)?;
+
+    // `<float layer="...">` portals are stacked over the whole view
+    let portals = crate::ir::layer::portal_floats(&root)
+        .into_iter()
+        .map(|(layer, float)| {
+            generate_portal(
+                layer,
+                float,
+                &model_ident,
+                &message_ident,
+                &document.style_classes,
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if portals.is_empty() {
+        return Ok(quote! {
+            #root_widget
+        });
+    }
+
+    Ok(quote! {
+        Into::<Element<'_, #message_ident>>::into(
+            dampen_iced::layers::Layers::new(#root_widget)
+                #(#portals)*
+        )
+    })
+}
+
+/// Generate the `push_maybe` call adding a portal float to the layers of the view
+fn generate_portal(
+    layer: crate::ir::layer::Layer,
+    node: &crate::WidgetNode,
+    model_ident: &syn::Ident,
+    message_ident: &syn::Ident,
+    style_classes: &HashMap<String, StyleClass>,
+) -> Result<TokenStream, super::CodegenError> {
+    let content_widgets = node
+        .children
+        .iter()
+        .map(|child| generate_widget(child, model_ident, message_ident, style_classes))
+        .collect::<Result<Vec<_>, _>>()?;
+    let layer = format_ident!("{}", format!("{:?}", layer));
+    let position = match node.attributes.get("position") {
+        Some(AttributeValue::Static(position)) => position.clone(),
+        _ => String::new(),
+    };
+
+    // Shown unless `show` is false
+    let show = match node.attributes.get("show") {
+        Some(AttributeValue::Binding(b)) => super::bindings::generate_bool_expr(&b.expr),
+        Some(AttributeValue::Static(s)) => {
+            let v = s == "true" || s == "1";
+            quote! { #v }
+        }
+        Some(_) => quote! { false },
+        None => quote! { true },
+    };
 
     Ok(quote! {
-        #root_widget
+        .push_maybe(
+            dampen_iced::layers::Layer::#layer,
+            (#show).then(|| {
+                dampen_iced::layers::portal(
+                    iced::widget::column(vec![#(#content_widgets),*]),
+                    #position,
+                )
+            }),
+        )
     })
 }
 
@@ -95,6 +162,30 @@ impl<'a> MergedLayout<'a> {
             .and_then(|l| l.height.as_ref())
             .or_else(|| self.class_layout.and_then(|l| l.height.as_ref()))
     }
+
+    fn wrap(&self) -> Option<bool> {
+        self.node_layout
+            .and_then(|l| l.wrap)
+            .or_else(|| self.class_layout.and_then(|l| l.wrap))
+    }
+
+    fn grow(&self) -> Option<u8> {
+        self.node_layout
+            .and_then(|l| l.grow)
+            .or_else(|| self.class_layout.and_then(|l| l.grow))
+    }
+
+    fn aspect_ratio(&self) -> Option<f32> {
+        self.node_layout
+            .and_then(|l| l.aspect_ratio)
+            .or_else(|| self.class_layout.and_then(|l| l.aspect_ratio))
+    }
+
+    fn overflow(&self) -> Option<Overflow> {
+        self.node_layout
+            .and_then(|l| l.overflow)
+            .or_else(|| self.class_layout.and_then(|l| l.overflow))
+    }
 }
 
 /// Generate code for a widget node
@@ -121,8 +212,44 @@ fn generate_widget_with_locals(
     style_classes: &HashMap<String, StyleClass>,
     local_vars: &std::collections::HashSet<String>,
 ) -> Result<TokenStream, super::CodegenError> {
-    match node.kind {
-        WidgetKind::Text => generate_text_with_locals(node, model_ident, style_classes, local_vars),
+    if let Some(theme_ref) = &node.theme_ref {
+        return generate_theme_override(
+            node,
+            theme_ref,
+            model_ident,
+            message_ident,
+            style_classes,
+            local_vars,
+        );
+    }
+
+    if node.bound_visual_attributes().next().is_some() {
+        return generate_visual_bindings(
+            node,
+            model_ident,
+            message_ident,
+            style_classes,
+            local_vars,
+        );
+    }
+
+    let widget = match node.kind {
+        WidgetKind::Text => {
+            generate_text_with_locals(node, model_ident, message_ident, style_classes, local_vars)
+        }
+        WidgetKind::Span => {
+            // Spans are handled by generate_text_with_locals
+            Err(super::CodegenError::InvalidWidget(
+                "Span must be inside a <text>".to_string(),
+            ))
+        }
+        WidgetKind::Markdown => {
+            generate_markdown_with_locals(node, model_ident, message_ident, local_vars)
+        }
+        WidgetKind::CodeEditor => {
+            generate_code_editor_with_locals(node, model_ident, message_ident, local_vars)
+        }
+        WidgetKind::Tree => generate_tree_with_locals(node, model_ident, message_ident, local_vars),
         WidgetKind::Button => {
             generate_button_with_locals(node, model_ident, message_ident, style_classes, local_vars)
         }
@@ -171,7 +298,10 @@ fn generate_widget_with_locals(
         WidgetKind::Toggler => generate_toggler(node, model_ident, message_ident, style_classes),
         WidgetKind::Slider => generate_slider(node, model_ident, message_ident, style_classes),
         WidgetKind::Radio => generate_radio(node, model_ident, message_ident, style_classes),
+        WidgetKind::RadioGroup => generate_radio_group(node, model_ident, message_ident),
+        WidgetKind::NumberInput => generate_number_input(node, model_ident, message_ident),
         WidgetKind::ProgressBar => generate_progress_bar(node, model_ident, style_classes),
+        WidgetKind::Skeleton => generate_skeleton(node),
         WidgetKind::TextInput => generate_text_input_with_locals(
             node,
             model_ident,
@@ -179,13 +309,23 @@ fn generate_widget_with_locals(
             style_classes,
             local_vars,
         ),
-        WidgetKind::Image => generate_image(node),
-        WidgetKind::Svg => generate_svg(node),
+        WidgetKind::Image => generate_image(node, model_ident, local_vars),
+        WidgetKind::Svg => generate_svg(node, model_ident, local_vars),
+        WidgetKind::Icon => generate_icon(node, model_ident, local_vars),
         WidgetKind::PickList => generate_pick_list(node, model_ident, message_ident, style_classes),
         WidgetKind::ComboBox => generate_combo_box(node, model_ident, message_ident, style_classes),
         WidgetKind::Tooltip => generate_tooltip(node, model_ident, message_ident, style_classes),
+        WidgetKind::TooltipContent => generate_container_with_locals(
+            node,
+            "container",
+            model_ident,
+            message_ident,
+            style_classes,
+            local_vars,
+        ),
         WidgetKind::Grid => generate_grid(node, model_ident, message_ident, style_classes),
         WidgetKind::Canvas => generate_canvas(node, model_ident, message_ident, style_classes),
+        WidgetKind::Chart => generate_chart(node, model_ident, local_vars),
         WidgetKind::Float => generate_float(node, model_ident, message_ident, style_classes),
         WidgetKind::For => {
             generate_for_with_locals(node, model_ident, message_ident, style_classes, local_vars)
@@ -240,7 +380,10 @@ fn generate_widget_with_locals(
         | WidgetKind::CanvasCircle
         | WidgetKind::CanvasLine
         | WidgetKind::CanvasText
-        | WidgetKind::CanvasGroup => {
+        | WidgetKind::CanvasGroup
+        | WidgetKind::CanvasPath
+        | WidgetKind::CanvasArc
+        | WidgetKind::CanvasBezier => {
             // These are handled by generate_canvas logic, shouldn't appear as top-level widgets
             Err(super::CodegenError::InvalidWidget(format!(
                 "{:?} is not a top-level widget and must be inside a <canvas>",
@@ -260,6 +403,157 @@ fn generate_widget_with_locals(
                 "Tab must be inside TabBar".to_string(),
             ))
         }
+    }?;
+
+    Ok(generate_context_menu_event(
+        node,
+        widget,
+        message_ident,
+        local_vars,
+    ))
+}
+
+/// Generate a widget with a `theme="..."` override, applied to its subtree
+///
+/// The theme is looked up by name with the `app_theme_named` function of the
+/// theme code; an unknown name keeps the theme of the application.
+fn generate_theme_override(
+    node: &crate::WidgetNode,
+    theme_ref: &AttributeValue,
+    model_ident: &syn::Ident,
+    message_ident: &syn::Ident,
+    style_classes: &HashMap<String, StyleClass>,
+    local_vars: &std::collections::HashSet<String>,
+) -> Result<TokenStream, super::CodegenError> {
+    let mut inner = node.clone();
+    inner.theme_ref = None;
+    let widget = generate_widget_with_locals(
+        &inner,
+        model_ident,
+        message_ident,
+        style_classes,
+        local_vars,
+    )?;
+    let name = generate_attribute_value_with_locals(theme_ref, model_ident, local_vars);
+
+    Ok(quote! {
+        {
+            let content: Element<'_, #message_ident> = #widget;
+            Into::<Element<'_, #message_ident>>::into(
+                iced::widget::themer(app_theme_named(&#name), content)
+                    .text_color(|theme: &iced::Theme| theme.palette().text),
+            )
+        }
+    })
+}
+
+/// Generate a widget whose layout or style attributes are bound to the model
+///
+/// The widget is generated without them, in a container applying them: their
+/// values are formatted and parsed when the view is built, with
+/// `dampen_core::parser::parse_visual_attributes` like the interpreter. Once a
+/// style attribute is bound, the container applies the static ones too, so
+/// the style is parsed as a whole.
+fn generate_visual_bindings(
+    node: &crate::WidgetNode,
+    model_ident: &syn::Ident,
+    message_ident: &syn::Ident,
+    style_classes: &HashMap<String, StyleClass>,
+    local_vars: &std::collections::HashSet<String>,
+) -> Result<TokenStream, super::CodegenError> {
+    let bound_style = node
+        .bound_visual_attributes()
+        .any(|(name, _)| crate::parser::is_style_attribute(name));
+
+    let mut inner = node.clone();
+    let mut values = Vec::new();
+    for (name, value) in sorted_entries(&node.attributes) {
+        let is_bound = !matches!(value, AttributeValue::Static(_))
+            && crate::parser::is_visual_attribute(&node.kind, name);
+        let is_style = bound_style && crate::parser::is_style_attribute(name);
+        if is_bound || is_style {
+            inner.attributes.remove(name);
+            let name_lit = proc_macro2::Literal::string(name);
+            let value_expr = generate_attribute_value_with_locals(value, model_ident, local_vars);
+            values.push(quote! { (#name_lit, #value_expr) });
+        }
+    }
+    if bound_style {
+        inner.style = None;
+    }
+    let widget = generate_widget_with_locals(
+        &inner,
+        model_ident,
+        message_ident,
+        style_classes,
+        local_vars,
+    )?;
+
+    // The attributes are already those of the kind of the node
+    Ok(quote! {
+        {
+            let content: Element<'_, #message_ident> = #widget;
+            let mut container = iced::widget::container(content);
+            if let Ok((layout, style)) = dampen_core::parser::parse_visual_attributes(
+                &dampen_core::ir::WidgetKind::Container,
+                [#(#values),*],
+            ) {
+                if let Some(layout) = layout {
+                    if layout.width.is_some() {
+                        container = container.width(dampen_iced::style_mapping::map_length(&layout.width));
+                    }
+                    if layout.height.is_some() {
+                        container = container.height(dampen_iced::style_mapping::map_length(&layout.height));
+                    }
+                    if layout.padding.is_some() {
+                        container = container.padding(dampen_iced::style_mapping::map_padding(&layout));
+                    }
+                    if let Some(align_x) = layout.align_x {
+                        container = container.align_x(dampen_iced::style_mapping::map_alignment_to_horizontal(align_x));
+                    }
+                    if let Some(align_y) = layout.align_y {
+                        container = container.align_y(dampen_iced::style_mapping::map_alignment_to_vertical(align_y));
+                    }
+                }
+                if let Some(style) = style {
+                    container = container.style(move |theme: &iced::Theme| {
+                        dampen_iced::style_mapping::map_style_properties_with_theme(&style, theme)
+                    });
+                }
+            }
+            Into::<Element<'_, #message_ident>>::into(container)
+        }
+    })
+}
+
+/// Wrap a widget with an `on_context_menu` event in a mouse area emitting it on right-click
+fn generate_context_menu_event(
+    node: &crate::WidgetNode,
+    widget: TokenStream,
+    message_ident: &syn::Ident,
+    local_vars: &std::collections::HashSet<String>,
+) -> TokenStream {
+    let Some(event) = node
+        .events
+        .iter()
+        .find(|e| e.event == crate::EventKind::ContextMenu)
+    else {
+        return widget;
+    };
+
+    let handler_ident = format_ident!("{}", to_upper_camel_case(&event.handler));
+    let param_expr = generate_event_payload(event, |expr| {
+        super::bindings::generate_expr_with_locals(expr, local_vars)
+    });
+
+    quote! {
+        {
+            let content: Element<'_, #message_ident> = #widget;
+            Into::<Element<'_, #message_ident>>::into(
+                iced::widget::mouse_area(content)
+                    .on_right_press(#message_ident::#handler_ident #param_expr)
+            )
+        }
     }
 }
 
@@ -273,24 +567,98 @@ fn generate_widget_with_locals(
 /// 1. Inline styles (node.style) - highest priority
 /// 2. CSS classes (node.classes) - medium priority
 /// 3. Default Iced styles - lowest priority (fallback)
+///
+/// Per-side borders and outlines are drawn by a wrapper, see [`apply_decoration`].
 fn apply_widget_style(
     widget: TokenStream,
     node: &crate::WidgetNode,
     widget_type: &str,
     style_classes: &HashMap<String, StyleClass>,
+) -> Result<TokenStream, super::CodegenError> {
+    let widget = apply_style_closure(widget, node, widget_type, style_classes)?;
+    Ok(apply_decoration(widget, node, widget_type, style_classes))
+}
+
+/// Wrap a widget in `dampen_iced::decoration::decorated` when it has per-side
+/// borders or an outline
+///
+/// Inline sides and outline cascade over those of the first class. Text inputs
+/// draw their outline as their border instead.
+fn apply_decoration(
+    widget: TokenStream,
+    node: &crate::WidgetNode,
+    widget_type: &str,
+    style_classes: &HashMap<String, StyleClass>,
+) -> TokenStream {
+    let class_style = node
+        .classes
+        .first()
+        .and_then(|class_name| style_classes.get(class_name))
+        .map(|style_class| &style_class.style);
+    let inline_style = node.style.as_ref();
+
+    let sides = BorderSides::cascade(
+        class_style.and_then(|style| style.border_sides),
+        inline_style.and_then(|style| style.border_sides),
+    )
+    .unwrap_or_default();
+    let outline = inline_style
+        .and_then(|style| style.outline)
+        .or_else(|| class_style.and_then(|style| style.outline))
+        .filter(|_| widget_type != "text_input");
+
+    if sides.is_empty() && outline.is_none() {
+        return widget;
+    }
+
+    let mut decoration = quote! { dampen_iced::decoration::Decoration::default() };
+    for (method, side) in [
+        ("top", sides.top),
+        ("right", sides.right),
+        ("bottom", sides.bottom),
+        ("left", sides.left),
+    ] {
+        if let Some(side) = side {
+            let method = format_ident!("{}", method);
+            let width = side.width;
+            let color = generate_color_expr(&side.color);
+            decoration = quote! { #decoration.#method(#width, #color) };
+        }
+    }
+    if let Some(outline) = outline {
+        let width = outline.width;
+        let color = generate_color_expr(&outline.color);
+        let offset = outline.offset;
+        decoration = quote! { #decoration.outline(#width, #color, #offset) };
+
+        let border = inline_style
+            .and_then(|style| style.border.as_ref())
+            .or_else(|| class_style.and_then(|style| style.border.as_ref()));
+        if let Some(border) = border {
+            let radius = generate_border_radius_expr(&border.radius);
+            decoration = quote! { #decoration.radius(#radius) };
+        }
+    }
+
+    quote! { dampen_iced::decoration::decorated(#widget, #decoration) }
+}
+
+/// Apply the style closure of inline styles or CSS classes to a widget
+fn apply_style_closure(
+    widget: TokenStream,
+    node: &crate::WidgetNode,
+    widget_type: &str,
+    style_classes: &HashMap<String, StyleClass>,
 ) -> Result<TokenStream, super::CodegenError> {
     // Check if widget has any styling
     let has_inline_style = node.style.is_some();
     let has_classes = !node.classes.is_empty();
 
-    // Check for dynamic class binding (e.g., class="{if filter == 'All' then 'btn_primary' else 'btn_filter'}")
-    let class_binding = node.attributes.get("class").and_then(|attr| match attr {
-        AttributeValue::Binding(expr) => Some(expr),
-        _ => None,
-    });
-    let has_class_binding = class_binding.is_some();
+    // Check for dynamic classes (e.g., class="{if filter == 'All' then 'btn_primary' else 'btn_filter'}"
+    // or class:active="{selected}")
+    let has_dynamic_classes = node.has_dynamic_classes();
 
-    if !has_inline_style && !has_classes && !has_class_binding {
+    if !has_inline_style && !has_classes && !has_dynamic_classes {
         // No styling needed, return widget as-is
         return Ok(widget);
     }
@@ -310,8 +678,12 @@ fn apply_widget_style(
         Ok(quote! {
             #widget.style(#style_closure)
         })
+    } else if has_dynamic_classes {
+        // Priority 2: Dynamic classes
+        let class_expr = generate_active_class_expr(node);
+        generate_dynamic_class_style(widget, class_expr, widget_type, style_classes)
     } else if let Some(class_name) = node.classes.first() {
-        // Priority 2: CSS class (use first class for now)
+        // Priority 3: CSS class (use first class for now)
         // Generate a wrapper closure that matches the widget's expected signature
         let style_fn_ident = format_ident!("style_{}", class_name.replace('-', "_"));
 
@@ -388,33 +760,69 @@ fn apply_widget_style(
                 })
             }
         }
-    } else if let Some(binding_expr) = class_binding {
-        // Priority 3: Dynamic class binding
-        generate_dynamic_class_style(widget, binding_expr, widget_type, style_classes)
     } else {
         Ok(widget)
     }
 }
 
-/// Generate style application for dynamic class bindings
+/// Generate the name of the class applied to a widget with dynamic classes
+///
+/// Generated styles apply a single class: the last active one, the classes
+/// toggled by `class:<name>` coming after those of `class`, by name.
+fn generate_active_class_expr(node: &crate::WidgetNode) -> TokenStream {
+    let classes = match node.attributes.get("class") {
+        Some(attr) => generate_attribute_value(attr, &format_ident!("model")),
+        None => quote! { String::new() },
+    };
+
+    let mut toggles: Vec<(&str, &AttributeValue)> = node.class_toggles().collect();
+    toggles.sort_by_key(|(class, _)| *class);
+    let toggles = toggles.into_iter().map(|(class, condition)| {
+        let class_lit = proc_macro2::Literal::string(class);
+        let condition = match condition {
+            AttributeValue::Binding(expr) => super::bindings::generate_bool_expr(&expr.expr),
+            AttributeValue::Static(value) => {
+                let value = value.trim() == "true";
+                quote! { #value }
+            }
+            AttributeValue::Interpolated(_) => {
+                let value = generate_attribute_value(condition, &format_ident!("model"));
+                quote! { #value.trim() == "true" }
+            }
+        };
+        quote! {
+            if #condition {
+                __classes.push(#class_lit.to_string());
+            }
+        }
+    });
+
+    quote! {
+        {
+            let mut __classes: Vec<String> =
+                #classes.split_whitespace().map(str::to_string).collect();
+            #(#toggles)*
+            __classes.pop().unwrap_or_default()
+        }
+    }
+}
+
+/// Generate style application for dynamic classes
 ///
-/// Generates code that evaluates the binding at runtime and dispatches
-/// to the appropriate style function based on the class name.
+/// Generates code that evaluates the class name at runtime and dispatches
+/// to the appropriate style function based on it.
 fn generate_dynamic_class_style(
     widget: TokenStream,
-    binding_expr: &crate::expr::BindingExpr,
+    class_expr: TokenStream,
     widget_type: &str,
     style_classes: &HashMap<String, StyleClass>,
 ) -> Result<TokenStream, super::CodegenError> {
-    // Generate code to evaluate the binding
-    let class_expr = super::bindings::generate_expr(&binding_expr.expr);
-
     match widget_type {
         "button" => {
             // Generate match arms only for button-compatible style classes
             // (those with state variants, which generate fn(theme, status) -> button::Style)
             let mut match_arms = Vec::new();
-            for (class_name, style_class) in style_classes.iter() {
+            for (class_name, style_class) in sorted_entries(style_classes) {
                 // Only include classes that have state variants (button styles)
                 if !style_class.state_variants.is_empty() {
                     let style_fn = format_ident!("style_{}", class_name.replace('-', "_"));
@@ -441,7 +849,7 @@ fn generate_dynamic_class_style(
             // For checkboxes, map checkbox status to button status
             // Only use style classes with state variants
             let mut checkbox_match_arms = Vec::new();
-            for (class_name, style_class) in style_classes.iter() {
+            for (class_name, style_class) in sorted_entries(style_classes) {
                 if !style_class.state_variants.is_empty() {
                     let style_fn = format_ident!("style_{}", class_name.replace('-', "_"));
                     let class_lit = proc_macro2::Literal::string(class_name);
@@ -479,7 +887,7 @@ fn generate_dynamic_class_style(
             // For other widgets (container, etc.), use container style functions
             // Only include classes without state variants (container styles)
             let mut container_match_arms = Vec::new();
-            for (class_name, style_class) in style_classes.iter() {
+            for (class_name, style_class) in sorted_entries(style_classes) {
                 if style_class.state_variants.is_empty() {
                     let style_fn = format_ident!("style_{}", class_name.replace('-', "_"));
                     let class_lit = proc_macro2::Literal::string(class_name);
@@ -524,7 +932,7 @@ fn generate_state_style_match(
     // Collect all state variants
     let mut state_arms = Vec::new();
 
-    for (state, state_props) in &style_class.state_variants {
+    for (state, state_props) in sorted_entries(&style_class.state_variants) {
         let state_variant = match state {
             WidgetState::Hover => quote! { dampen_core::ir::WidgetState::Hover },
             WidgetState::Focus => quote! { dampen_core::ir::WidgetState::Focus },
@@ -838,49 +1246,13 @@ fn generate_background_expr(bg: &Background) -> TokenStream {
             let color_expr = generate_color_expr(color);
             quote! { iced::Background::Color(#color_expr) }
         }
-        Background::Gradient(gradient) => generate_gradient_expr(gradient),
+        Background::Gradient(gradient) => super::theme::generate_gradient_expr(gradient),
         Background::Image { .. } => {
             quote! { iced::Background::Color(iced::Color::TRANSPARENT) }
         }
     }
 }
 
-/// Generate iced::Gradient from Gradient IR
-fn generate_gradient_expr(gradient: &Gradient) -> TokenStream {
-    match gradient {
-        Gradient::Linear { angle, stops } => {
-            let radians = angle * (std::f32::consts::PI / 180.0);
-            let color_exprs: Vec<_> = stops
-                .iter()
-                .map(|s| generate_color_expr(&s.color))
-                .collect();
-            let offsets: Vec<_> = stops.iter().map(|s| s.offset).collect();
-
-            quote! {
-                iced::Background::Gradient(iced::Gradient::Linear(
-                    iced::gradient::Linear::new(#radians)
-                        #(.add_stop(#offsets, #color_exprs))*
-                ))
-            }
-        }
-        Gradient::Radial { stops, .. } => {
-            // Fallback to linear for radial (Iced limitation)
-            let color_exprs: Vec<_> = stops
-                .iter()
-                .map(|s| generate_color_expr(&s.color))
-                .collect();
-            let offsets: Vec<_> = stops.iter().map(|s| s.offset).collect();
-
-            quote! {
-                iced::Background::Gradient(iced::Gradient::Linear(
-                    iced::gradient::Linear::new(0.0)
-                        #(.add_stop(#offsets, #color_exprs))*
-                ))
-            }
-        }
-    }
-}
-
 /// Generate iced::Border from Border IR
 fn generate_border_expr(border: &Border) -> TokenStream {
     let width = border.width;
@@ -1033,12 +1405,26 @@ fn generate_text_input_style_struct(
             || quote! { iced::Background::Color(_theme.extended_palette().background.base.color) },
         );
 
-    let border_expr = props
+    let mut border_expr = props
         .border
         .as_ref()
         .map(generate_border_expr)
         .unwrap_or_else(|| quote! { iced::Border::default() });
 
+    // Text inputs draw their outline as their border, so that `focus:outline`
+    // gives a focus ring
+    if let Some(outline) = props.outline {
+        let width = outline.width;
+        let color = generate_color_expr(&outline.color);
+        border_expr = quote! {
+            iced::Border {
+                width: #width,
+                color: #color,
+                ..#border_expr
+            }
+        };
+    }
+
     // Use theme colors as fallback instead of hardcoded colors
     let value_color = props
         .color
@@ -1167,10 +1553,13 @@ fn generate_text(
     })?;
 
     let value_expr = generate_attribute_value(value_attr, model_ident);
+    let clamp = text_clamp(node);
+    let value_expr = generate_clamped_value(&clamp, value_expr);
 
     let mut text_widget = quote! {
         iced::widget::text(#value_expr)
     };
+    text_widget = apply_text_clamp(text_widget, &clamp, node);
 
     // Apply size attribute
     if let Some(size) = node.attributes.get("size").and_then(|attr| {
@@ -1183,24 +1572,9 @@ fn generate_text(
         text_widget = quote! { #text_widget.size(#size) };
     }
 
-    // Apply weight attribute (bold, normal, etc.)
-    if let Some(weight) = node.attributes.get("weight").and_then(|attr| {
-        if let AttributeValue::Static(s) = attr {
-            Some(s.clone())
-        } else {
-            None
-        }
-    }) {
-        let weight_expr = match weight.to_lowercase().as_str() {
-            "bold" => quote! { iced::font::Weight::Bold },
-            "semibold" => quote! { iced::font::Weight::Semibold },
-            "medium" => quote! { iced::font::Weight::Medium },
-            "light" => quote! { iced::font::Weight::Light },
-            _ => quote! { iced::font::Weight::Normal },
-        };
-        text_widget = quote! {
-            #text_widget.font(iced::Font { weight: #weight_expr, ..Default::default() })
-        };
+    // Apply weight (bold, normal, etc.) and font family attributes
+    if let Some(font_expr) = generate_text_font_expr(node) {
+        text_widget = quote! { #text_widget.font(#font_expr) };
     }
 
     // Apply inline style color if present
@@ -1215,37 +1589,164 @@ fn generate_text(
     Ok(maybe_wrap_in_container(text_widget, node))
 }
 
-/// Generate Length expression from string
-fn generate_length_expr(s: &str) -> TokenStream {
-    let s = s.trim().to_lowercase();
-    if s == "fill" {
-        quote! { iced::Length::Fill }
-    } else if s == "shrink" {
-        quote! { iced::Length::Shrink }
-    } else if let Some(pct) = s.strip_suffix('%') {
-        if let Ok(p) = pct.parse::<f32>() {
-            // Iced doesn't have a direct percentage, use FillPortion as approximation
-            let portion = ((p / 100.0) * 16.0).round() as u16;
-            let portion = portion.max(1);
-            quote! { iced::Length::FillPortion(#portion) }
-        } else {
-            quote! { iced::Length::Shrink }
-        }
-    } else if let Ok(px) = s.parse::<f32>() {
-        quote! { iced::Length::Fixed(#px) }
-    } else {
-        quote! { iced::Length::Shrink }
+/// Wrapping and clamping of a text from its static `wrap`, `max_lines` and `truncate`
+fn text_clamp(node: &crate::WidgetNode) -> crate::TextClamp {
+    let static_attr = |name: &str| match node.attributes.get(name) {
+        Some(AttributeValue::Static(s)) => Some(s.as_str()),
+        _ => None,
+    };
+    let width = node.layout.as_ref().and_then(|layout| match layout.width {
+        Some(LayoutLength::Fixed(width)) => Some(width),
+        _ => layout.max_width,
+    });
+    crate::TextClamp::new(
+        static_attr("wrap"),
+        static_attr("max_lines"),
+        static_attr("truncate"),
+        width,
+        text_size(node),
+    )
+}
+
+/// Static font size of a text, iced's default of 16 without `size`
+fn text_size(node: &crate::WidgetNode) -> f32 {
+    match node.attributes.get("size") {
+        Some(AttributeValue::Static(s)) => s.parse().unwrap_or(16.0),
+        _ => 16.0,
     }
 }
 
-/// Generate Length expression from LayoutLength IR type
-fn generate_layout_length_expr(length: &LayoutLength) -> TokenStream {
-    match length {
-        LayoutLength::Fixed(px) => quote! { iced::Length::Fixed(#px) },
-        LayoutLength::Fill => quote! { iced::Length::Fill },
-        LayoutLength::Shrink => quote! { iced::Length::Shrink },
-        LayoutLength::FillPortion(portion) => {
-            let p = *portion as u16;
+/// Ellipsize the value of a text at runtime when it has `truncate="ellipsis"`
+fn generate_clamped_value(clamp: &crate::TextClamp, value_expr: TokenStream) -> TokenStream {
+    if !clamp.ellipsis {
+        return value_expr;
+    }
+    let wrap = generate_text_wrap_expr(clamp.wrap, quote! { dampen_core::TextWrap });
+    let max_lines = match clamp.max_lines {
+        Some(lines) => quote! { Some(#lines) },
+        None => quote! { None },
+    };
+    let chars_per_line = match clamp.chars_per_line {
+        Some(chars) => quote! { Some(#chars) },
+        None => quote! { None },
+    };
+    quote! {
+        dampen_core::TextClamp {
+            wrap: #wrap,
+            max_lines: #max_lines,
+            ellipsis: true,
+            chars_per_line: #chars_per_line,
+        }
+        .apply(&#value_expr)
+        .into_owned()
+    }
+}
+
+/// Apply the wrapping of a text, and the height of its `max_lines`
+fn apply_text_clamp(
+    text_widget: TokenStream,
+    clamp: &crate::TextClamp,
+    node: &crate::WidgetNode,
+) -> TokenStream {
+    let mut text_widget = text_widget;
+    if clamp.wrap != crate::TextWrap::Word {
+        let wrapping = generate_text_wrap_expr(clamp.wrap, quote! { iced::widget::text::Wrapping });
+        text_widget = quote! { #text_widget.wrapping(#wrapping) };
+    }
+    let has_height = node
+        .layout
+        .as_ref()
+        .is_some_and(|layout| layout.height.is_some());
+    if let Some(height) = clamp.height(text_size(node))
+        && !has_height
+    {
+        text_widget = quote! { #text_widget.height(#height) };
+    }
+    text_widget
+}
+
+/// Generate a wrap variant of `wrap_type`, `dampen_core::TextWrap` or iced's `Wrapping`
+fn generate_text_wrap_expr(wrap: crate::TextWrap, wrap_type: TokenStream) -> TokenStream {
+    match wrap {
+        crate::TextWrap::Word => quote! { #wrap_type::Word },
+        crate::TextWrap::Glyph => quote! { #wrap_type::Glyph },
+        crate::TextWrap::WordOrGlyph => quote! { #wrap_type::WordOrGlyph },
+        crate::TextWrap::None => quote! { #wrap_type::None },
+    }
+}
+
+/// Generate the font of a text from its static `weight` and `font` attributes
+fn generate_text_font_expr(node: &crate::WidgetNode) -> Option<TokenStream> {
+    let static_attr = |name: &str| match node.attributes.get(name) {
+        Some(AttributeValue::Static(s)) => Some(s.clone()),
+        _ => None,
+    };
+    let weight = static_attr("weight");
+    let family = static_attr("font");
+    if weight.is_none() && family.is_none() {
+        return None;
+    }
+
+    let weight_expr = match weight.unwrap_or_default().to_lowercase().as_str() {
+        "bold" => quote! { iced::font::Weight::Bold },
+        "semibold" => quote! { iced::font::Weight::Semibold },
+        "medium" => quote! { iced::font::Weight::Medium },
+        "light" => quote! { iced::font::Weight::Light },
+        "thin" => quote! { iced::font::Weight::Thin },
+        "black" => quote! { iced::font::Weight::Black },
+        _ => quote! { iced::font::Weight::Normal },
+    };
+    Some(match family.as_deref().map(generate_font_family_expr) {
+        Some(family_expr) => quote! {
+            iced::Font { family: #family_expr, weight: #weight_expr, ..Default::default() }
+        },
+        None => quote! { iced::Font { weight: #weight_expr, ..Default::default() } },
+    })
+}
+
+/// Generate a font family expression from a `font` attribute
+///
+/// Generic families map to their iced variant, other names to a loaded font.
+fn generate_font_family_expr(family: &str) -> TokenStream {
+    match family.trim() {
+        "sans-serif" => quote! { iced::font::Family::SansSerif },
+        "serif" => quote! { iced::font::Family::Serif },
+        "monospace" => quote! { iced::font::Family::Monospace },
+        name => quote! { iced::font::Family::Name(#name) },
+    }
+}
+
+/// Generate Length expression from string
+fn generate_length_expr(s: &str) -> TokenStream {
+    let s = s.trim().to_lowercase();
+    if s == "fill" {
+        quote! { iced::Length::Fill }
+    } else if s == "shrink" {
+        quote! { iced::Length::Shrink }
+    } else if let Some(pct) = s.strip_suffix('%') {
+        if let Ok(p) = pct.parse::<f32>() {
+            // Iced doesn't have a direct percentage, use FillPortion as approximation
+            let portion = ((p / 100.0) * 16.0).round() as u16;
+            let portion = portion.max(1);
+            quote! { iced::Length::FillPortion(#portion) }
+        } else {
+            quote! { iced::Length::Shrink }
+        }
+    } else if let Ok(px) = s.parse::<f32>() {
+        quote! { iced::Length::Fixed(#px) }
+    } else {
+        quote! { iced::Length::Shrink }
+    }
+}
+
+/// Generate Length expression from LayoutLength IR type
+fn generate_layout_length_expr(length: &LayoutLength) -> TokenStream {
+    match length {
+        LayoutLength::Fixed(px) => quote! { iced::Length::Fixed(#px) },
+        LayoutLength::Fill => quote! { iced::Length::Fill },
+        LayoutLength::Shrink => quote! { iced::Length::Shrink },
+        LayoutLength::FillPortion(portion) => {
+            let p = *portion as u16;
             quote! { iced::Length::FillPortion(#p) }
         }
         LayoutLength::Percentage(pct) => {
@@ -1422,12 +1923,7 @@ fn generate_button(
         let variant_name = to_upper_camel_case(&event.handler);
         let handler_ident = format_ident!("{}", variant_name);
 
-        let param_expr = if let Some(ref param) = event.param {
-            let param_tokens = generate_expr(&param.expr);
-            quote! { (#param_tokens) }
-        } else {
-            quote! {}
-        };
+        let param_expr = generate_event_payload(event, generate_expr);
 
         // Generate on_press call based on enabled condition
         button = match enabled_condition {
@@ -1458,12 +1954,40 @@ fn generate_button(
     Ok(quote! { #button.into() })
 }
 
+/// Generate the payload of a handler message from the event parameters
+///
+/// Returns nothing for events without parameters and `(value)` for a single
+/// parameter. Multiple parameters are converted to the handler's argument type
+/// (a tuple or params struct) through [`FromEventArgs`](crate::handler::FromEventArgs).
+fn generate_event_payload(
+    event: &crate::EventBinding,
+    generate: impl Fn(&crate::expr::Expr) -> TokenStream,
+) -> TokenStream {
+    if !event.params.is_empty() {
+        let args = event.params.iter().map(|param| generate(&param.expr));
+        return quote! {
+            (dampen_core::handler::FromEventArgs::from_event_args(&[
+                #(::std::string::ToString::to_string(&(#args))),*
+            ])
+            .unwrap_or_default())
+        };
+    }
+
+    match &event.param {
+        Some(param) => {
+            let param_tokens = generate(&param.expr);
+            quote! { (#param_tokens) }
+        }
+        None => quote! {},
+    }
+}
+
 /// Helper function to convert snake_case to UpperCamelCase
 fn to_upper_camel_case(s: &str) -> String {
     let mut result = String::new();
     let mut capitalize_next = true;
     for c in s.chars() {
-        if c == '_' {
+        if c == '_' || c == '.' {
             capitalize_next = true;
         } else if capitalize_next {
             result.push(c.to_ascii_uppercase());
@@ -1901,74 +2425,125 @@ fn generate_toggler(
 }
 
 /// Generate slider widget
+///
+/// `min`, `max`, `step` and `value` are static numbers or bindings to numeric
+/// fields; `on_change` receives the value converted to the handler's numeric type.
 fn generate_slider(
     node: &crate::WidgetNode,
     model_ident: &syn::Ident,
     message_ident: &syn::Ident,
     style_classes: &HashMap<String, StyleClass>,
 ) -> Result<TokenStream, super::CodegenError> {
-    let min = node.attributes.get("min").and_then(|attr| {
-        if let AttributeValue::Static(s) = attr {
-            s.parse::<f32>().ok()
-        } else {
-            None
-        }
-    });
-
-    let max = node.attributes.get("max").and_then(|attr| {
-        if let AttributeValue::Static(s) = attr {
-            s.parse::<f32>().ok()
-        } else {
-            None
-        }
-    });
-
     let value_attr = node.attributes.get("value").ok_or_else(|| {
         super::CodegenError::InvalidWidget("slider requires value attribute".to_string())
     })?;
-    let value_expr = generate_attribute_value(value_attr, model_ident);
+    let value_expr = generate_f32_attribute(Some(value_attr), 0.0, model_ident);
+    let min_expr = generate_f32_attribute(node.attributes.get("min"), 0.0, model_ident);
+    let max_expr = generate_f32_attribute(node.attributes.get("max"), 100.0, model_ident);
 
     let on_change = node
         .events
         .iter()
         .find(|e| e.event == crate::EventKind::Change);
 
-    let mut slider = quote! {
-        iced::widget::slider(0.0..=100.0, #value_expr, |v| {})
+    let mut slider = if let Some(event) = on_change {
+        let variant_name = to_upper_camel_case(&event.handler);
+        let handler_ident = format_ident!("{}", variant_name);
+        quote! {
+            iced::widget::slider(#min_expr..=#max_expr, #value_expr, |v| #message_ident::#handler_ident(v as _))
+        }
+    } else {
+        quote! {
+            iced::widget::slider(#min_expr..=#max_expr, #value_expr, |v| {})
+        }
     };
 
-    if let Some(m) = min {
-        slider = quote! { #slider.min(#m) };
-    }
-    if let Some(m) = max {
-        slider = quote! { #slider.max(#m) };
+    // Apply step attribute (increment size)
+    if let Some(step) = node.attributes.get("step") {
+        let step_expr = generate_f32_attribute(Some(step), 1.0, model_ident);
+        slider = quote! { #slider.step(#step_expr) };
     }
 
-    // Apply step attribute (increment size)
-    let step = node.attributes.get("step").and_then(|attr| {
-        if let AttributeValue::Static(s) = attr {
-            s.parse::<f32>().ok()
-        } else {
-            None
-        }
-    });
+    // Apply styles
+    slider = apply_widget_style(slider, node, "slider", style_classes)?;
+
+    Ok(with_value_label(slider, node, &value_expr))
+}
 
-    if let Some(s) = step {
-        slider = quote! { #slider.step(#s) };
+/// Generate an `f32` expression for a numeric attribute
+///
+/// Bindings are cast from the field's numeric type; other values are parsed,
+/// falling back to `default`.
+fn generate_f32_attribute(
+    attr: Option<&AttributeValue>,
+    default: f32,
+    model_ident: &syn::Ident,
+) -> TokenStream {
+    match attr {
+        Some(AttributeValue::Static(s)) => {
+            let value = s.trim().parse::<f32>().unwrap_or(default);
+            quote! { #value }
+        }
+        Some(AttributeValue::Binding(expr)) => {
+            let value = super::bindings::generate_bool_expr(&expr.expr);
+            quote! { ((#value) as f32) }
+        }
+        Some(attr) => {
+            let value = generate_attribute_value(attr, model_ident);
+            quote! { #value.trim().parse::<f32>().unwrap_or(#default) }
+        }
+        None => quote! { #default },
     }
+}
 
-    if let Some(event) = on_change {
-        let variant_name = to_upper_camel_case(&event.handler);
-        let handler_ident = format_ident!("{}", variant_name);
-        slider = quote! {
-            iced::widget::slider(0.0..=100.0, #value_expr, |v| #message_ident::#handler_ident(v))
-        };
+/// Place a text showing the widget's value after it when `show_value="true"`
+///
+/// The text is formatted with the `value_format` filter, if any.
+fn with_value_label(
+    widget: TokenStream,
+    node: &crate::WidgetNode,
+    value_expr: &TokenStream,
+) -> TokenStream {
+    let show_value = matches!(
+        node.attributes.get("show_value"),
+        Some(AttributeValue::Static(s)) if s.trim() == "true"
+    );
+    if !show_value {
+        return quote! { #widget.into() };
     }
 
-    // Apply styles
-    slider = apply_widget_style(slider, node, "slider", style_classes)?;
+    let format = match node.attributes.get("value_format") {
+        Some(AttributeValue::Static(spec)) => {
+            crate::expr::ValueFormat::parse(spec).unwrap_or_default()
+        }
+        _ => crate::expr::ValueFormat::default(),
+    };
+    let name = &format.name;
+    let args = format.args.iter().map(|arg| match arg {
+        crate::BindingValue::Integer(i) => quote! { dampen_core::BindingValue::Integer(#i) },
+        crate::BindingValue::Float(f) => quote! { dampen_core::BindingValue::Float(#f) },
+        crate::BindingValue::Bool(b) => quote! { dampen_core::BindingValue::Bool(#b) },
+        other => {
+            let s = other.to_display_string();
+            quote! { dampen_core::BindingValue::String(#s.to_string()) }
+        }
+    });
 
-    Ok(quote! { #slider.into() })
+    quote! {
+        iced::widget::row![
+            #widget,
+            iced::widget::text(
+                dampen_core::ValueFormat {
+                    name: #name.to_string(),
+                    args: vec![#(#args),*],
+                }
+                .apply(&dampen_core::BindingValue::Float(#value_expr as f64))
+            ),
+        ]
+        .spacing(8)
+        .align_y(iced::alignment::Vertical::Center)
+        .into()
+    }
 }
 
 /// Generate radio widget
@@ -2033,15 +2608,9 @@ fn generate_progress_bar(
     let value_attr = node.attributes.get("value").ok_or_else(|| {
         super::CodegenError::InvalidWidget("progress_bar requires value attribute".to_string())
     })?;
-    let value_expr = generate_attribute_value(value_attr, model_ident);
-
-    let max_attr = node.attributes.get("max").and_then(|attr| {
-        if let AttributeValue::Static(s) = attr {
-            s.parse::<f32>().ok()
-        } else {
-            None
-        }
-    });
+    let value_expr = generate_f32_attribute(Some(value_attr), 0.0, model_ident);
+    let min_expr = generate_f32_attribute(node.attributes.get("min"), 0.0, model_ident);
+    let max_expr = generate_f32_attribute(node.attributes.get("max"), 100.0, model_ident);
 
     // Parse style attribute (default to "primary")
     let style_str = node
@@ -2227,37 +2796,21 @@ fn generate_progress_bar(
         quote! {}
     };
 
-    if let Some(max) = max_attr {
-        Ok(quote! {
-            iced::widget::progress_bar(0.0..=#max, #value_expr)
-                #girth_expr
-                #width_expr
-                .style(|theme: &iced::Theme| {
-                    let palette = theme.extended_palette();
-                    iced::widget::progress_bar::Style {
-                        background: iced::Background::Color(#background_color_expr),
-                        bar: iced::Background::Color(#bar_color_expr),
-                        border: #border_expr,
-                    }
-                })
-                .into()
-        })
-    } else {
-        Ok(quote! {
-            iced::widget::progress_bar(0.0..=100.0, #value_expr)
-                #girth_expr
-                #width_expr
-                .style(|theme: &iced::Theme| {
-                    let palette = theme.extended_palette();
-                    iced::widget::progress_bar::Style {
-                        background: iced::Background::Color(#background_color_expr),
-                        bar: iced::Background::Color(#bar_color_expr),
-                        border: #border_expr,
-                    }
-                })
-                .into()
-        })
-    }
+    let progress_bar = quote! {
+        iced::widget::progress_bar(#min_expr..=#max_expr, #value_expr)
+            #girth_expr
+            #width_expr
+            .style(|theme: &iced::Theme| {
+                let palette = theme.extended_palette();
+                iced::widget::progress_bar::Style {
+                    background: iced::Background::Color(#background_color_expr),
+                    bar: iced::Background::Color(#bar_color_expr),
+                    border: #border_expr,
+                }
+            })
+    };
+
+    Ok(with_value_label(progress_bar, node, &value_expr))
 }
 
 /// Parse a color string into TokenStream for code generation
@@ -2405,7 +2958,7 @@ fn generate_text_input(
         .unwrap_or(false);
 
     if is_password {
-        text_input = quote! { #text_input.password() };
+        text_input = quote! { #text_input.secure(true) };
     }
 
     // Apply styles
@@ -2415,16 +2968,40 @@ fn generate_text_input(
 }
 
 /// Generate image widget
-fn generate_image(node: &crate::WidgetNode) -> Result<TokenStream, super::CodegenError> {
+fn generate_image(
+    node: &crate::WidgetNode,
+    model_ident: &syn::Ident,
+    local_vars: &std::collections::HashSet<String>,
+) -> Result<TokenStream, super::CodegenError> {
     let src_attr = node.attributes.get("src").ok_or_else(|| {
         super::CodegenError::InvalidWidget("image requires src attribute".to_string())
     })?;
 
-    let src = match src_attr {
-        AttributeValue::Static(s) => s.clone(),
-        _ => String::new(),
+    // Bound and remote sources go through the async image loader
+    let optional_src = |name: &str| match node.attributes.get(name) {
+        Some(attr) => {
+            let value = generate_attribute_value_with_locals(attr, model_ident, local_vars);
+            quote! { Some(#value).as_deref() }
+        }
+        None => quote! { None },
+    };
+    let handle = match src_attr {
+        AttributeValue::Static(src)
+            if !src.starts_with("http://")
+                && !src.starts_with("https://")
+                && !node.attributes.contains_key("placeholder")
+                && !node.attributes.contains_key("error_src") =>
+        {
+            let bytes = generate_asset_bytes(src);
+            quote! { iced::widget::image::Handle::from_bytes(#bytes) }
+        }
+        _ => {
+            let src = generate_attribute_value_with_locals(src_attr, model_ident, local_vars);
+            let placeholder = optional_src("placeholder");
+            let error_src = optional_src("error_src");
+            quote! { dampen_iced::image_loader::handle(&#src, #placeholder, #error_src) }
+        }
     };
-    let src_lit = proc_macro2::Literal::string(&src);
 
     let width = node.attributes.get("width").and_then(|attr| {
         if let AttributeValue::Static(s) = attr {
@@ -2443,7 +3020,7 @@ fn generate_image(node: &crate::WidgetNode) -> Result<TokenStream, super::Codege
     });
 
     let mut image = quote! {
-        iced::widget::image::Image::new(iced::widget::image::Handle::from_memory(std::fs::read(#src_lit).unwrap_or_default()))
+        iced::widget::image::Image::new(#handle)
     };
 
     // Apply native width/height if specified with integer values
@@ -2510,22 +3087,96 @@ fn generate_image(node: &crate::WidgetNode) -> Result<TokenStream, super::Codege
     }
 }
 
+/// Generate the contents of the local asset `path` of an image or SVG
+///
+/// In a build script, the file is looked up from the package directory (see
+/// [`crate::assets::locate`]) and embedded with `include_bytes!`, so the
+/// binary doesn't depend on its working directory. Files that aren't found
+/// are read when the view is rendered.
+fn generate_asset_bytes(path: &str) -> TokenStream {
+    let embedded = std::env::var("CARGO_MANIFEST_DIR")
+        .ok()
+        .and_then(|dir| crate::assets::locate(path, std::path::Path::new(&dir)))
+        .and_then(|file| std::fs::canonicalize(file).ok());
+    match embedded {
+        Some(file) => {
+            let file_lit = proc_macro2::Literal::string(&file.to_string_lossy());
+            quote! { include_bytes!(#file_lit).as_slice() }
+        }
+        None => {
+            let path_lit = proc_macro2::Literal::string(path);
+            quote! { dampen_core::assets::read(#path_lit) }
+        }
+    }
+}
+
+/// Generate icon widget code
+fn generate_icon(
+    node: &crate::WidgetNode,
+    model_ident: &syn::Ident,
+    local_vars: &std::collections::HashSet<String>,
+) -> Result<TokenStream, super::CodegenError> {
+    let name_attr = node.attributes.get("name").ok_or_else(|| {
+        super::CodegenError::InvalidWidget("icon requires name attribute".to_string())
+    })?;
+    let name = generate_attribute_value_raw_with_locals(name_attr, model_ident, local_vars);
+
+    let size = match node.attributes.get("size") {
+        Some(AttributeValue::Static(s)) => {
+            let size = s.parse::<f32>().unwrap_or(16.0);
+            quote! { #size }
+        }
+        Some(AttributeValue::Binding(expr)) => {
+            let tokens = super::bindings::generate_bool_expr_with_locals(&expr.expr, local_vars);
+            quote! { (#tokens) as f32 }
+        }
+        _ => quote! { 16.0f32 },
+    };
+
+    // Icons follow the theme's text color unless tinted
+    let tint = match node.attributes.get("tint") {
+        Some(attr) => generate_attribute_value_raw_with_locals(attr, model_ident, local_vars),
+        None => quote! { "@text" },
+    };
+
+    let icon = quote! {
+        iced::widget::svg::Svg::new(dampen_iced::svg::icon_handle(&(#name).to_string()))
+            .width(#size)
+            .height(#size)
+            .style(dampen_iced::svg::tint((#tint).to_string()))
+    };
+
+    Ok(maybe_wrap_in_container(icon, node))
+}
+
 /// Generate SVG widget
-fn generate_svg(node: &crate::WidgetNode) -> Result<TokenStream, super::CodegenError> {
-    // Support both "src" (standard) and "path" (legacy) for backward compatibility
-    let path_attr = node
-        .attributes
-        .get("src")
-        .or_else(|| node.attributes.get("path"))
-        .ok_or_else(|| {
-            super::CodegenError::InvalidWidget("svg requires src attribute".to_string())
-        })?;
+fn generate_svg(
+    node: &crate::WidgetNode,
+    model_ident: &syn::Ident,
+    local_vars: &std::collections::HashSet<String>,
+) -> Result<TokenStream, super::CodegenError> {
+    // Inline or bound markup takes precedence over "src"
+    let handle = if let Some(content_attr) = node.attributes.get("content") {
+        let content =
+            generate_attribute_value_raw_with_locals(content_attr, model_ident, local_vars);
+        quote! { dampen_iced::svg::content_handle(&(#content).to_string()) }
+    } else {
+        // Support both "src" (standard) and "path" (legacy) for backward compatibility
+        let path_attr = node
+            .attributes
+            .get("src")
+            .or_else(|| node.attributes.get("path"))
+            .ok_or_else(|| {
+                super::CodegenError::InvalidWidget("svg requires src attribute".to_string())
+            })?;
 
-    let path = match path_attr {
-        AttributeValue::Static(s) => s.clone(),
-        _ => String::new(),
+        let path = match path_attr {
+            AttributeValue::Static(s) => s.clone(),
+            _ => String::new(),
+        };
+        let bytes = generate_asset_bytes(&path);
+        quote! { iced::widget::svg::Handle::from_memory(#bytes) }
     };
-    let path_lit = proc_macro2::Literal::string(&path);
 
     let width = node.attributes.get("width").and_then(|attr| {
         if let AttributeValue::Static(s) = attr {
@@ -2544,9 +3195,14 @@ fn generate_svg(node: &crate::WidgetNode) -> Result<TokenStream, super::CodegenE
     });
 
     let mut svg = quote! {
-        iced::widget::svg::Svg::new(iced::widget::svg::Handle::from_path(#path_lit))
+        iced::widget::svg::Svg::new(#handle)
     };
 
+    if let Some(tint_attr) = node.attributes.get("tint") {
+        let tint = generate_attribute_value_raw_with_locals(tint_attr, model_ident, local_vars);
+        svg = quote! { #svg.style(dampen_iced::svg::tint((#tint).to_string())) };
+    }
+
     // Apply native width/height if specified with integer values
     if let (Some(w), Some(h)) = (width, height) {
         svg = quote! { #svg.width(#w).height(#h) };
@@ -2618,99 +3274,365 @@ fn generate_pick_list(
     message_ident: &syn::Ident,
     _style_classes: &HashMap<String, StyleClass>,
 ) -> Result<TokenStream, super::CodegenError> {
-    let options_attr = node.attributes.get("options").ok_or_else(|| {
-        super::CodegenError::InvalidWidget("pick_list requires options attribute".to_string())
-    })?;
-
-    let options: Vec<String> = match options_attr {
-        AttributeValue::Static(s) => s.split(',').map(|s| s.trim().to_string()).collect(),
-        _ => Vec::new(),
-    };
-    let options_ref: Vec<&str> = options.iter().map(|s| s.as_str()).collect();
-
-    let selected_attr = node.attributes.get("selected");
-    let selected_expr = selected_attr
-        .map(|attr| generate_attribute_value(attr, model_ident))
-        .unwrap_or(quote! { None });
-
-    let on_select = node
-        .events
-        .iter()
-        .find(|e| e.event == crate::EventKind::Select);
-
-    if let Some(event) = on_select {
-        let variant_name = to_upper_camel_case(&event.handler);
-        let handler_ident = format_ident!("{}", variant_name);
-        Ok(quote! {
-            iced::widget::pick_list(&[#(#options_ref),*], #selected_expr, |v| #message_ident::#handler_ident(v)).into()
-        })
-    } else {
-        Ok(quote! {
-            iced::widget::pick_list(&[#(#options_ref),*], #selected_expr, |_| ()).into()
-        })
-    }
+    generate_select(node, "pick_list", model_ident, message_ident)
 }
 
 /// Generate combo box widget
+///
+/// Like the runtime builder, a combo box is rendered as a pick list.
 fn generate_combo_box(
     node: &crate::WidgetNode,
     model_ident: &syn::Ident,
     message_ident: &syn::Ident,
     _style_classes: &HashMap<String, StyleClass>,
+) -> Result<TokenStream, super::CodegenError> {
+    generate_select(node, "combobox", model_ident, message_ident)
+}
+
+/// Generate a pick list of [`SelectOption`](crate::SelectOption)s
+///
+/// Options bound to a collection are mapped per item, with `display` and
+/// `value` evaluated with the item as `item`. Selecting an option sends its value.
+fn generate_select(
+    node: &crate::WidgetNode,
+    widget_name: &str,
+    model_ident: &syn::Ident,
+    message_ident: &syn::Ident,
 ) -> Result<TokenStream, super::CodegenError> {
     let options_attr = node.attributes.get("options").ok_or_else(|| {
-        super::CodegenError::InvalidWidget("combobox requires options attribute".to_string())
+        super::CodegenError::InvalidWidget(format!("{} requires options attribute", widget_name))
     })?;
+    let options_expr = generate_select_options(node, options_attr, model_ident);
 
-    let options: Vec<String> = match options_attr {
-        AttributeValue::Static(s) => s.split(',').map(|s| s.trim().to_string()).collect(),
-        _ => Vec::new(),
-    };
-    let options_ref: Vec<&str> = options.iter().map(|s| s.as_str()).collect();
-
-    let selected_attr = node.attributes.get("selected");
-    let selected_expr = selected_attr
+    let selected_expr = node
+        .attributes
+        .get("selected")
         .map(|attr| generate_attribute_value(attr, model_ident))
-        .unwrap_or(quote! { None });
+        .unwrap_or(quote! { String::new() });
 
     let on_select = node
         .events
         .iter()
         .find(|e| e.event == crate::EventKind::Select);
 
-    if let Some(event) = on_select {
+    let on_select_expr = if let Some(event) = on_select {
         let variant_name = to_upper_camel_case(&event.handler);
         let handler_ident = format_ident!("{}", variant_name);
-        Ok(quote! {
-            iced::widget::combo_box(&[#(#options_ref),*], "", #selected_expr, |v, _| #message_ident::#handler_ident(v)).into()
-        })
+        quote! { |option: dampen_core::SelectOption| #message_ident::#handler_ident(option.value) }
     } else {
-        Ok(quote! {
-            iced::widget::combo_box(&[#(#options_ref),*], "", #selected_expr, |_, _| ()).into()
-        })
-    }
-}
+        quote! { |_| () }
+    };
 
-/// Generate tooltip widget
+    Ok(quote! {
+        {
+            let options: Vec<dampen_core::SelectOption> = #options_expr;
+            let selected = dampen_core::SelectOption::find(&options, &#selected_expr).cloned();
+            iced::widget::pick_list(options, selected, #on_select_expr).into()
+        }
+    })
+}
+
+/// Generate a `Vec<SelectOption>` expression for an `options` attribute
+///
+/// Bound options are mapped per item of the collection; the value of a radio
+/// group option is the item itself.
+fn generate_select_options(
+    node: &crate::WidgetNode,
+    options_attr: &AttributeValue,
+    model_ident: &syn::Ident,
+) -> TokenStream {
+    match options_attr {
+        AttributeValue::Binding(expr) => {
+            let collection_expr = super::bindings::generate_bool_expr(&expr.expr);
+            let locals: std::collections::HashSet<String> =
+                ["item".to_string(), "index".to_string()].into();
+            let label_expr = node
+                .attributes
+                .get("display")
+                .map(|attr| generate_attribute_value_with_locals(attr, model_ident, &locals))
+                .unwrap_or(quote! { item.to_string() });
+            let value_expr = match node.attributes.get("value") {
+                Some(attr) if node.is_option_template("value") => {
+                    generate_attribute_value_with_locals(attr, model_ident, &locals)
+                }
+                _ if node.kind == WidgetKind::RadioGroup => quote! { item.to_string() },
+                _ => quote! { label.clone() },
+            };
+            quote! {
+                #collection_expr
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| {
+                        let _ = index;
+                        let label: String = #label_expr;
+                        let value: String = #value_expr;
+                        dampen_core::SelectOption::new(label, value)
+                    })
+                    .collect::<Vec<_>>()
+            }
+        }
+        attr => {
+            let options_str = generate_attribute_value(attr, model_ident);
+            quote! { dampen_core::SelectOption::parse_list(&#options_str) }
+        }
+    }
+}
+
+/// Generate radio group widget
+///
+/// Options come from `options` or `<radio>` children; each radio sends its
+/// option's value, and the one matching `value` is selected.
+fn generate_radio_group(
+    node: &crate::WidgetNode,
+    model_ident: &syn::Ident,
+    message_ident: &syn::Ident,
+) -> Result<TokenStream, super::CodegenError> {
+    let options_expr = match node.attributes.get("options") {
+        Some(options_attr) => generate_select_options(node, options_attr, model_ident),
+        None => {
+            let options = node.children.iter().map(|child| {
+                let label = child
+                    .attributes
+                    .get("label")
+                    .map(|attr| generate_attribute_value(attr, model_ident))
+                    .unwrap_or(quote! { String::new() });
+                let value = child
+                    .attributes
+                    .get("value")
+                    .map(|attr| generate_attribute_value(attr, model_ident))
+                    .unwrap_or(quote! { String::new() });
+                quote! { dampen_core::SelectOption::new(#label, #value) }
+            });
+            quote! { vec![#(#options),*] }
+        }
+    };
+
+    let selected_expr = node
+        .attributes
+        .get("value")
+        .map(|attr| generate_attribute_value(attr, model_ident))
+        .unwrap_or(quote! { String::new() });
+
+    let on_select = node
+        .events
+        .iter()
+        .find(|e| e.event == crate::EventKind::Select);
+
+    let on_select_expr = if let Some(event) = on_select {
+        let variant_name = to_upper_camel_case(&event.handler);
+        let handler_ident = format_ident!("{}", variant_name);
+        quote! { move |_| #message_ident::#handler_ident(value.clone()) }
+    } else {
+        quote! { |_| () }
+    };
+
+    let horizontal = matches!(
+        node.attributes.get("direction"),
+        Some(AttributeValue::Static(s)) if s.trim().starts_with("horizontal")
+    );
+    let mut group = if horizontal {
+        quote! { iced::widget::row(radios) }
+    } else {
+        quote! { iced::widget::column(radios) }
+    };
+    if let Some(AttributeValue::Static(s)) = node.attributes.get("spacing")
+        && let Ok(spacing) = s.parse::<f32>()
+    {
+        group = quote! { #group.spacing(#spacing) };
+    }
+
+    Ok(quote! {
+        {
+            let options: Vec<dampen_core::SelectOption> = #options_expr;
+            let selected_value = #selected_expr;
+            let selected = options.iter().position(|option| option.value == selected_value);
+            let radios: Vec<iced::Element<'_, #message_ident>> = options
+                .into_iter()
+                .enumerate()
+                .map(|(index, option)| {
+                    let value = option.value;
+                    iced::widget::radio(option.label, index, selected, #on_select_expr).into()
+                })
+                .collect();
+            #group.into()
+        }
+    })
+}
+
+/// Generate a number input: a text input followed by step buttons
+///
+/// `on_change` receives the clamped number cast to the handler's type, e.g.
+/// `i64` or `f64`. An edit that is not a number sends the current value.
+fn generate_number_input(
+    node: &crate::WidgetNode,
+    model_ident: &syn::Ident,
+    message_ident: &syn::Ident,
+) -> Result<TokenStream, super::CodegenError> {
+    let range_attr = |name: &str| match node.attributes.get(name) {
+        Some(attr) => {
+            let value = generate_attribute_value(attr, model_ident);
+            quote! { Some(#value).as_deref() }
+        }
+        None => quote! { None },
+    };
+    let min = range_attr("min");
+    let max = range_attr("max");
+    let step = range_attr("step");
+    let decimals = range_attr("decimals");
+
+    let value_expr = match node.attributes.get("value") {
+        Some(AttributeValue::Binding(expr)) => {
+            let value = super::bindings::generate_bool_expr(&expr.expr);
+            quote! { ((#value) as f64) }
+        }
+        Some(attr) => {
+            let value = generate_attribute_value(attr, model_ident);
+            quote! { #value.trim().parse::<f64>().unwrap_or_default() }
+        }
+        None => quote! { 0.0 },
+    };
+
+    let placeholder = match node.attributes.get("placeholder") {
+        Some(AttributeValue::Static(s)) => s.clone(),
+        _ => String::new(),
+    };
+
+    let enabled = match node.attributes.get("disabled") {
+        Some(AttributeValue::Binding(expr)) => {
+            let disabled = super::bindings::generate_bool_expr(&expr.expr);
+            quote! { !(#disabled) }
+        }
+        Some(AttributeValue::Static(s)) => {
+            let enabled = s.trim() != "true";
+            quote! { #enabled }
+        }
+        _ => quote! { true },
+    };
+
+    let on_change = node
+        .events
+        .iter()
+        .find(|e| e.event == crate::EventKind::Change)
+        .map(|event| format_ident!("{}", to_upper_camel_case(&event.handler)));
+
+    let mut field = quote! { iced::widget::text_input(#placeholder, &range.format(value)) };
+    let mut decrement = quote! { iced::widget::button(iced::widget::text("-")) };
+    let mut increment = quote! { iced::widget::button(iced::widget::text("+")) };
+    if let Some(handler_ident) = on_change {
+        field = quote! {
+            #field.on_input_maybe(#enabled.then(|| {
+                let range = range.clone();
+                move |text: String| {
+                    #message_ident::#handler_ident(range.parse_value(&text).unwrap_or(value) as _)
+                }
+            }))
+        };
+        decrement = quote! {
+            #decrement.on_press_maybe(
+                #enabled.then(|| #message_ident::#handler_ident(range.step_by(value, -1.0) as _))
+            )
+        };
+        increment = quote! {
+            #increment.on_press_maybe(
+                #enabled.then(|| #message_ident::#handler_ident(range.step_by(value, 1.0) as _))
+            )
+        };
+    }
+
+    Ok(quote! {
+        {
+            let range = dampen_core::NumberRange::parse(#min, #max, #step, #decimals)
+                .unwrap_or_default();
+            let value = range.clamp(#value_expr);
+            iced::widget::row![#field, #decrement, #increment].spacing(4).into()
+        }
+    })
+}
+
+/// Generate a loading placeholder
+fn generate_skeleton(node: &crate::WidgetNode) -> Result<TokenStream, super::CodegenError> {
+    let lines = match node.attributes.get("lines") {
+        Some(AttributeValue::Static(s)) => s.trim().parse::<usize>().ok(),
+        _ => None,
+    };
+    let lines = match lines {
+        Some(lines) => quote! { #lines },
+        None => quote! { dampen_iced::skeleton::DEFAULT_LINES },
+    };
+
+    let mut skeleton = quote! { dampen_iced::skeleton::Skeleton::new(#lines) };
+    if let Some(AttributeValue::Static(width)) = node.attributes.get("width") {
+        let width_expr = generate_length_expr(width);
+        skeleton = quote! { #skeleton.width(#width_expr) };
+    }
+
+    Ok(quote! { #skeleton.into() })
+}
+
+/// Generate tooltip widget
 fn generate_tooltip(
     node: &crate::WidgetNode,
     model_ident: &syn::Ident,
     message_ident: &syn::Ident,
     style_classes: &HashMap<String, StyleClass>,
 ) -> Result<TokenStream, super::CodegenError> {
-    let child = node.children.first().ok_or_else(|| {
-        super::CodegenError::InvalidWidget("tooltip must have exactly one child".to_string())
-    })?;
+    let child = node
+        .children
+        .iter()
+        .find(|child| child.kind != WidgetKind::TooltipContent)
+        .ok_or_else(|| {
+            super::CodegenError::InvalidWidget("tooltip must have exactly one child".to_string())
+        })?;
     let child_widget = generate_widget(child, model_ident, message_ident, style_classes)?;
 
-    let message_attr = node.attributes.get("message").ok_or_else(|| {
-        super::CodegenError::InvalidWidget("tooltip requires message attribute".to_string())
-    })?;
-    let message_expr = generate_attribute_value(message_attr, model_ident);
+    // Rich <tooltip_content> takes precedence over the message
+    let tooltip_expr = match node
+        .children
+        .iter()
+        .find(|child| child.kind == WidgetKind::TooltipContent)
+    {
+        Some(content) => generate_widget(content, model_ident, message_ident, style_classes)?,
+        None => {
+            let message_attr = node.attributes.get("message").ok_or_else(|| {
+                super::CodegenError::InvalidWidget("tooltip requires message attribute".to_string())
+            })?;
+            generate_attribute_value(message_attr, model_ident)
+        }
+    };
 
-    Ok(quote! {
-        iced::widget::tooltip(#child_widget, #message_expr, iced::widget::tooltip::Position::FollowCursor).into()
-    })
+    let position = match node.attributes.get("position") {
+        Some(AttributeValue::Static(s)) => match s.trim() {
+            "top" => quote! { Top },
+            "bottom" => quote! { Bottom },
+            "left" => quote! { Left },
+            "right" => quote! { Right },
+            _ => quote! { FollowCursor },
+        },
+        _ => quote! { FollowCursor },
+    };
+
+    let mut tooltip = quote! {
+        iced::widget::tooltip(#child_widget, #tooltip_expr, iced::widget::tooltip::Position::#position)
+    };
+
+    match node.attributes.get("delay_ms") {
+        Some(AttributeValue::Static(s)) => {
+            if let Ok(ms) = s.trim().parse::<u64>() {
+                tooltip = quote! { #tooltip.delay(std::time::Duration::from_millis(#ms)) };
+            }
+        }
+        Some(attr) => {
+            let delay_expr = generate_attribute_value(attr, model_ident);
+            tooltip = quote! {
+                #tooltip.delay(std::time::Duration::from_millis(
+                    (#delay_expr).to_string().trim().parse::<u64>().unwrap_or(0)
+                ))
+            };
+        }
+        None => {}
+    }
+
+    Ok(quote! { #tooltip.into() })
 }
 
 /// Generate grid widget
@@ -2874,6 +3796,106 @@ fn generate_canvas(
     Ok(content_expr)
 }
 
+/// Generate chart widget code
+fn generate_chart(
+    node: &crate::WidgetNode,
+    model_ident: &syn::Ident,
+    local_vars: &std::collections::HashSet<String>,
+) -> Result<TokenStream, super::CodegenError> {
+    let data_attr = node.attributes.get("data").ok_or_else(|| {
+        super::CodegenError::InvalidWidget("chart requires data attribute".to_string())
+    })?;
+    let data_expr = generate_attribute_value_raw_with_locals(data_attr, model_ident, local_vars);
+
+    let kind_expr = match node.attributes.get("type") {
+        Some(AttributeValue::Static(s)) => match s.trim() {
+            "bar" => quote! { dampen_iced::canvas::ChartKind::Bar },
+            "pie" => quote! { dampen_iced::canvas::ChartKind::Pie },
+            _ => quote! { dampen_iced::canvas::ChartKind::Line },
+        },
+        Some(attr) => {
+            let value = generate_attribute_value_raw_with_locals(attr, model_ident, local_vars);
+            quote! {
+                dampen_iced::canvas::ChartKind::parse(&(#value).to_string()).unwrap_or_default()
+            }
+        }
+        None => quote! { dampen_iced::canvas::ChartKind::Line },
+    };
+
+    let field = |name: &str| match node.attributes.get(name) {
+        Some(AttributeValue::Static(s)) => Some(format_ident!("{}", s.trim())),
+        _ => None,
+    };
+    let x_field = field("x");
+    let y_field = field("y");
+
+    let value_expr = match &y_field {
+        Some(y) => quote! { item.#y as f32 },
+        None => quote! { *item as f32 },
+    };
+    let points_expr = match &x_field {
+        Some(x) => quote! {
+            (#data_expr)
+                .iter()
+                .map(|item| dampen_iced::canvas::ChartPoint::new(item.#x.to_string(), #value_expr))
+                .collect()
+        },
+        None => quote! {
+            (#data_expr)
+                .iter()
+                .enumerate()
+                .map(|(index, item)| dampen_iced::canvas::ChartPoint::new(index.to_string(), #value_expr))
+                .collect()
+        },
+    };
+
+    let series_expr = match (node.attributes.get("label"), &y_field) {
+        (Some(attr), _) => {
+            let value = generate_attribute_value_raw_with_locals(attr, model_ident, local_vars);
+            quote! { Some((#value).to_string()) }
+        }
+        (None, Some(y)) => {
+            let name = y.to_string();
+            quote! { Some(#name.to_string()) }
+        }
+        (None, None) => quote! { None },
+    };
+
+    let bool_expr = |name: &str, default: bool| match node.attributes.get(name) {
+        Some(AttributeValue::Static(s)) => {
+            let value = s.trim() == "true";
+            quote! { #value }
+        }
+        Some(AttributeValue::Binding(expr)) => {
+            super::bindings::generate_bool_expr_with_locals(&expr.expr, local_vars)
+        }
+        _ => quote! { #default },
+    };
+    let show_axes = bool_expr("show_axes", true);
+    let show_legend = bool_expr("show_legend", false);
+    let color = generate_color_option_attr(node, "color", model_ident);
+
+    let length_expr = |name: &str, default: TokenStream| match node.attributes.get(name) {
+        Some(AttributeValue::Static(s)) => generate_length_expr(s),
+        _ => default,
+    };
+    let width = length_expr("width", quote! { iced::Length::Fill });
+    let height = length_expr("height", quote! { iced::Length::Fixed(300.0) });
+
+    Ok(quote! {
+        iced::widget::canvas(
+            dampen_iced::canvas::ChartProgram::new(#kind_expr, #points_expr)
+                .color(#color)
+                .series(#series_expr)
+                .show_axes(#show_axes)
+                .show_legend(#show_legend)
+        )
+        .width(#width)
+        .height(#height)
+        .into()
+    })
+}
+
 /// Generate float widget
 fn generate_float(
     node: &crate::WidgetNode,
@@ -2881,6 +3903,14 @@ fn generate_float(
     message_ident: &syn::Ident,
     style_classes: &HashMap<String, StyleClass>,
 ) -> Result<TokenStream, super::CodegenError> {
+    if node.attributes.contains_key("anchor") {
+        return generate_anchored_float(node, model_ident, message_ident, style_classes);
+    }
+    // Portals are generated over the view by `generate_view`
+    if crate::ir::layer::portal_layer(node).is_some() {
+        return Ok(quote! { iced::widget::column(Vec::new()).into() });
+    }
+
     let child = node.children.first().ok_or_else(|| {
         super::CodegenError::InvalidWidget("float must have exactly one child".to_string())
     })?;
@@ -2935,6 +3965,74 @@ fn generate_float(
     Ok(quote! { #float.into() })
 }
 
+/// Generate an anchored float, moved in place of its anchor by
+/// `attach_anchored_floats`: its first child is the anchor, followed by the
+/// content shown next to it
+fn generate_anchored_float(
+    node: &crate::WidgetNode,
+    model_ident: &syn::Ident,
+    message_ident: &syn::Ident,
+    style_classes: &HashMap<String, StyleClass>,
+) -> Result<TokenStream, super::CodegenError> {
+    let (anchor, content) = node.children.split_first().ok_or_else(|| {
+        super::CodegenError::InvalidWidget("float anchor was not found".to_string())
+    })?;
+    let anchor_widget = generate_widget(anchor, model_ident, message_ident, style_classes)?;
+    let content_widgets = content
+        .iter()
+        .map(|child| generate_widget(child, model_ident, message_ident, style_classes))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let placement = match node.attributes.get("placement") {
+        Some(AttributeValue::Static(value)) => {
+            crate::ir::Placement::parse(value).map_err(super::CodegenError::InvalidWidget)?
+        }
+        _ => crate::ir::Placement::default(),
+    };
+    let side = format_ident!("{}", format!("{:?}", placement.side));
+    let align = format_ident!("{}", format!("{:?}", placement.align));
+
+    let gap = match node.attributes.get("gap") {
+        Some(AttributeValue::Static(value)) => value.trim().parse::<f32>().map_err(|_| {
+            super::CodegenError::InvalidWidget(format!("Invalid float gap: {}", value))
+        })?,
+        _ => 4.0,
+    };
+
+    // Shown unless `show` is false
+    let show = node
+        .attributes
+        .get("show")
+        .map(|attr| match attr {
+            AttributeValue::Binding(b) => super::bindings::generate_bool_expr(&b.expr),
+            AttributeValue::Static(s) => {
+                let v = s == "true";
+                quote! { #v }
+            }
+            _ => quote! { false },
+        })
+        .unwrap_or(quote! { true });
+
+    Ok(quote! {
+        {
+            let anchor: iced::Element<'_, #message_ident> = #anchor_widget.into();
+            if #show {
+                dampen_iced::popover::anchored(
+                    anchor,
+                    iced::widget::column(vec![#(#content_widgets),*]),
+                    dampen_core::ir::Placement {
+                        side: dampen_core::ir::placement::Side::#side,
+                        align: dampen_core::ir::placement::PlacementAlign::#align,
+                    },
+                    #gap,
+                )
+            } else {
+                anchor
+            }
+        }
+    })
+}
+
 /// Generate for loop widget (iterates over collection)
 ///
 /// Expects attributes:
@@ -3080,7 +4178,16 @@ fn generate_date_picker(
         quote! { #message_ident::None }
     };
 
-    let on_submit = if let Some(h) = node
+    // on_change receives the picked date as a `chrono::NaiveDate`, on_submit as a string
+    let handler = |kind: crate::EventKind| {
+        node.events
+            .iter()
+            .find(|e| e.event == kind)
+            .map(|e| format_ident!("{}", to_upper_camel_case(&e.handler)))
+    };
+    let on_submit = if let Some(msg) = handler(crate::EventKind::Change) {
+        quote! { |date| #message_ident::#msg(chrono::NaiveDate::from(date)) }
+    } else if let Some(h) = node
         .events
         .iter()
         .find(|e| e.event == crate::EventKind::Submit)
@@ -3096,10 +4203,24 @@ fn generate_date_picker(
         quote! { |_| #message_ident::None }
     };
 
+    // Without a child, a button shows the date and sends on_open when pressed
     let underlay = if let Some(child) = node.children.first() {
         generate_widget(child, model_ident, message_ident, style_classes)?
     } else {
-        quote! { iced::widget::text("Missing child") }
+        let format = match node.attributes.get("format") {
+            Some(AttributeValue::Static(format)) => format.as_str(),
+            _ => "%Y-%m-%d",
+        };
+        let on_open = match handler(crate::EventKind::Open) {
+            Some(msg) => quote! { Some(#message_ident::#msg) },
+            None => quote! { None },
+        };
+        quote! {
+            iced::widget::button(iced::widget::text(
+                chrono::NaiveDate::from(#date).format(#format).to_string()
+            ))
+            .on_press_maybe(#on_open)
+        }
     };
 
     Ok(quote! {
@@ -3425,18 +4546,30 @@ fn generate_attribute_value_raw(attr: &AttributeValue, _model_ident: &syn::Ident
 fn generate_text_with_locals(
     node: &crate::WidgetNode,
     model_ident: &syn::Ident,
+    message_ident: &syn::Ident,
     _style_classes: &HashMap<String, StyleClass>,
     local_vars: &std::collections::HashSet<String>,
 ) -> Result<TokenStream, super::CodegenError> {
-    let value_attr = node.attributes.get("value").ok_or_else(|| {
-        super::CodegenError::InvalidWidget("text requires value attribute".to_string())
-    })?;
+    let markdown = matches!(
+        node.attributes.get("markdown"),
+        Some(AttributeValue::Static(s)) if s == "true"
+    );
 
-    let value_expr = generate_attribute_value_with_locals(value_attr, model_ident, local_vars);
+    let mut text_widget = if markdown || !node.children.is_empty() {
+        generate_rich_text_with_locals(node, model_ident, message_ident, markdown, local_vars)?
+    } else {
+        let value_attr = node.attributes.get("value").ok_or_else(|| {
+            super::CodegenError::InvalidWidget("text requires value attribute".to_string())
+        })?;
 
-    let mut text_widget = quote! {
-        iced::widget::text(#value_expr)
+        let value_expr = generate_attribute_value_with_locals(value_attr, model_ident, local_vars);
+        let value_expr = generate_clamped_value(&text_clamp(node), value_expr);
+
+        quote! {
+            iced::widget::text(#value_expr)
+        }
     };
+    text_widget = apply_text_clamp(text_widget, &text_clamp(node), node);
 
     // Apply size attribute
     if let Some(size) = node.attributes.get("size").and_then(|attr| {
@@ -3449,24 +4582,9 @@ fn generate_text_with_locals(
         text_widget = quote! { #text_widget.size(#size) };
     }
 
-    // Apply weight attribute
-    if let Some(weight) = node.attributes.get("weight").and_then(|attr| {
-        if let AttributeValue::Static(s) = attr {
-            Some(s.clone())
-        } else {
-            None
-        }
-    }) {
-        let weight_expr = match weight.to_lowercase().as_str() {
-            "bold" => quote! { iced::font::Weight::Bold },
-            "semibold" => quote! { iced::font::Weight::Semibold },
-            "medium" => quote! { iced::font::Weight::Medium },
-            "light" => quote! { iced::font::Weight::Light },
-            _ => quote! { iced::font::Weight::Normal },
-        };
-        text_widget = quote! {
-            #text_widget.font(iced::Font { weight: #weight_expr, ..Default::default() })
-        };
+    // Apply weight (bold, normal, etc.) and font family attributes
+    if let Some(font_expr) = generate_text_font_expr(node) {
+        text_widget = quote! { #text_widget.font(#font_expr) };
     }
 
     // Apply inline style color if present
@@ -3480,19 +4598,294 @@ fn generate_text_with_locals(
     Ok(maybe_wrap_in_container(text_widget, node))
 }
 
-/// Generate button widget with local variable context
-fn generate_button_with_locals(
+/// Generate rich text from `<span>` children or a `markdown="true"` value
+fn generate_rich_text_with_locals(
     node: &crate::WidgetNode,
     model_ident: &syn::Ident,
     message_ident: &syn::Ident,
-    style_classes: &HashMap<String, StyleClass>,
+    markdown: bool,
     local_vars: &std::collections::HashSet<String>,
 ) -> Result<TokenStream, super::CodegenError> {
-    let label_attr = node.attributes.get("label").ok_or_else(|| {
-        super::CodegenError::InvalidWidget("button requires label attribute".to_string())
-    })?;
+    let spans = if markdown {
+        let value_attr = node.attributes.get("value").ok_or_else(|| {
+            super::CodegenError::InvalidWidget("markdown text requires value attribute".to_string())
+        })?;
+        let value_expr = generate_attribute_value_with_locals(value_attr, model_ident, local_vars);
+        quote! { dampen_iced::rich_text::markdown_spans(&#value_expr) }
+    } else {
+        let spans = node
+            .children
+            .iter()
+            .map(|span| generate_span_with_locals(span, model_ident, local_vars));
+        quote! { vec![#(#spans),*] }
+    };
 
-    let label_expr = generate_attribute_value_with_locals(label_attr, model_ident, local_vars);
+    let mut rich_text = quote! {
+        iced::widget::rich_text({
+            let spans: Vec<iced::widget::text::Span<'_, String>> = #spans;
+            spans
+        })
+    };
+
+    if let Some(event) = node
+        .events
+        .iter()
+        .find(|e| e.event == crate::EventKind::Link)
+    {
+        let handler_ident = format_ident!("{}", to_upper_camel_case(&event.handler));
+        rich_text = quote! {
+            #rich_text.on_link_click(|link: String| #message_ident::#handler_ident(link))
+        };
+    }
+
+    Ok(rich_text)
+}
+
+/// Generate a span of rich text
+fn generate_span_with_locals(
+    node: &crate::WidgetNode,
+    model_ident: &syn::Ident,
+    local_vars: &std::collections::HashSet<String>,
+) -> TokenStream {
+    let static_attr = |name: &str| match node.attributes.get(name) {
+        Some(AttributeValue::Static(s)) => Some(s.as_str()),
+        _ => None,
+    };
+
+    let value_expr = node
+        .attributes
+        .get("value")
+        .map(|attr| generate_attribute_value_with_locals(attr, model_ident, local_vars))
+        .unwrap_or_else(|| quote! { String::new() });
+
+    let mut span = quote! { iced::widget::span(#value_expr) };
+
+    if let Some(size) = static_attr("size").and_then(|s| s.parse::<f32>().ok()) {
+        span = quote! { #span.size(#size) };
+    }
+
+    let weight = match static_attr("weight").map(str::to_lowercase).as_deref() {
+        Some("bold") => Some(quote! { iced::font::Weight::Bold }),
+        Some("semibold") => Some(quote! { iced::font::Weight::Semibold }),
+        Some("medium") => Some(quote! { iced::font::Weight::Medium }),
+        Some("light") => Some(quote! { iced::font::Weight::Light }),
+        _ => None,
+    };
+    let italic = static_attr("italic") == Some("true");
+    if weight.is_some() || italic {
+        let weight = weight.unwrap_or_else(|| quote! { iced::font::Weight::Normal });
+        let style = if italic {
+            quote! { iced::font::Style::Italic }
+        } else {
+            quote! { iced::font::Style::Normal }
+        };
+        span = quote! {
+            #span.font(iced::Font { weight: #weight, style: #style, ..Default::default() })
+        };
+    }
+
+    if let Some(ref style_props) = node.style
+        && let Some(ref color) = style_props.color
+    {
+        let color_expr = generate_color_expr(color);
+        span = quote! { #span.color(#color_expr) };
+    }
+
+    // Links are underlined unless `underline="false"`
+    let link = node.attributes.get("link");
+    let underline = static_attr("underline").map_or(link.is_some(), |s| s == "true");
+    if underline {
+        span = quote! { #span.underline(true) };
+    }
+    if static_attr("strikethrough") == Some("true") {
+        span = quote! { #span.strikethrough(true) };
+    }
+    if let Some(link) = link {
+        let link_expr = generate_attribute_value_with_locals(link, model_ident, local_vars);
+        span = quote! { #span.link(#link_expr) };
+    }
+
+    span
+}
+
+/// Generate a Markdown document from its `src`
+fn generate_markdown_with_locals(
+    node: &crate::WidgetNode,
+    model_ident: &syn::Ident,
+    message_ident: &syn::Ident,
+    local_vars: &std::collections::HashSet<String>,
+) -> Result<TokenStream, super::CodegenError> {
+    let src_attr = node.attributes.get("src").ok_or_else(|| {
+        super::CodegenError::InvalidWidget("markdown requires src attribute".to_string())
+    })?;
+    let src_expr = generate_attribute_value_with_locals(src_attr, model_ident, local_vars);
+    let static_attr = |name: &str| match node.attributes.get(name) {
+        Some(AttributeValue::Static(s)) => Some(s.trim().to_string()),
+        _ => None,
+    };
+
+    let mut markdown = quote! { dampen_iced::markdown::Markdown::new(&#src_expr) };
+    if let Some(size) = static_attr("size").and_then(|s| s.parse::<f32>().ok()) {
+        markdown = quote! { #markdown.size(#size) };
+    }
+    if let Some(ratio) = static_attr("scale_ratio").and_then(|s| s.parse::<f32>().ok()) {
+        markdown = quote! { #markdown.scale_ratio(#ratio) };
+    }
+    if let Some(font) = static_attr("font") {
+        markdown = quote! { #markdown.font(#font) };
+    }
+    if let Some(ref style_props) = node.style
+        && let Some(ref color) = style_props.color
+    {
+        let color_expr = generate_color_expr(color);
+        markdown = quote! { #markdown.color(#color_expr) };
+    }
+
+    if let Some(event) = node
+        .events
+        .iter()
+        .find(|e| e.event == crate::EventKind::Link)
+    {
+        let handler_ident = format_ident!("{}", to_upper_camel_case(&event.handler));
+        markdown = quote! {
+            #markdown.on_link(|link: String| #message_ident::#handler_ident(link))
+        };
+    }
+
+    Ok(maybe_wrap_in_container(markdown, node))
+}
+
+/// Generate a code editor
+fn generate_code_editor_with_locals(
+    node: &crate::WidgetNode,
+    model_ident: &syn::Ident,
+    message_ident: &syn::Ident,
+    local_vars: &std::collections::HashSet<String>,
+) -> Result<TokenStream, super::CodegenError> {
+    let value_expr = node
+        .attributes
+        .get("value")
+        .map(|attr| generate_attribute_value_with_locals(attr, model_ident, local_vars))
+        .unwrap_or_else(|| quote! { String::new() });
+    let static_attr = |name: &str| match node.attributes.get(name) {
+        Some(AttributeValue::Static(s)) => Some(s.trim().to_string()),
+        _ => None,
+    };
+
+    let mut editor = quote! { dampen_iced::code_editor::CodeEditor::new(#value_expr) };
+    if let Some(language) = static_attr("language") {
+        editor = quote! { #editor.language(#language) };
+    }
+    if let Some(size) = static_attr("size").and_then(|s| s.parse::<f32>().ok()) {
+        editor = quote! { #editor.size(#size) };
+    }
+    if static_attr("line_numbers").as_deref() == Some("false") {
+        editor = quote! { #editor.line_numbers(false) };
+    }
+    if let Some(height) = static_attr("height") {
+        let height_expr = generate_length_expr(&height);
+        editor = quote! { #editor.height(#height_expr) };
+    }
+
+    if let Some(event) = node
+        .events
+        .iter()
+        .find(|e| e.event == crate::EventKind::Change)
+    {
+        let handler_ident = format_ident!("{}", to_upper_camel_case(&event.handler));
+        editor = quote! {
+            #editor.on_change(|text: String| #message_ident::#handler_ident(text))
+        };
+    }
+
+    Ok(maybe_wrap_in_container(editor, node))
+}
+
+/// Generate a tree of model items
+fn generate_tree_with_locals(
+    node: &crate::WidgetNode,
+    model_ident: &syn::Ident,
+    message_ident: &syn::Ident,
+    local_vars: &std::collections::HashSet<String>,
+) -> Result<TokenStream, super::CodegenError> {
+    let items_attr = node.attributes.get("items").ok_or_else(|| {
+        super::CodegenError::InvalidWidget("tree requires 'items' attribute".to_string())
+    })?;
+    let static_attr = |name: &str| match node.attributes.get(name) {
+        Some(AttributeValue::Static(s)) => Some(s.trim().to_string()),
+        _ => None,
+    };
+    let children_ident = format_ident!(
+        "{}",
+        static_attr("children").unwrap_or_else(|| "children".to_string())
+    );
+    let each = static_attr("each").unwrap_or_else(|| "node".to_string());
+    let each_ident = format_ident!("{}", each);
+
+    // `label` and `key` are evaluated for each item, named by `each`
+    let items_expr = generate_attribute_value_raw_with_locals(items_attr, model_ident, local_vars);
+    let mut item_vars = local_vars.clone();
+    item_vars.insert(each);
+    let label_expr = node
+        .attributes
+        .get("label")
+        .map(|attr| generate_attribute_value_with_locals(attr, model_ident, &item_vars))
+        .unwrap_or_else(|| quote! { String::new() });
+    let key_fn = match node.attributes.get("key") {
+        Some(attr) => {
+            let key_expr = generate_attribute_value_with_locals(attr, model_ident, &item_vars);
+            quote! { |#each_ident, _| #key_expr }
+        }
+        None => quote! { |_, path| path.to_string() },
+    };
+
+    let mut tree = quote! {
+        dampen_iced::tree::Tree::new(dampen_iced::tree::rows(
+            &(#items_expr),
+            |#each_ident| &#each_ident.#children_ident,
+            |#each_ident| #label_expr,
+            #key_fn,
+        ))
+    };
+    if let Some(indent) = static_attr("indent").and_then(|s| s.parse::<f32>().ok()) {
+        tree = quote! { #tree.indent(#indent) };
+    }
+    if let Some(size) = static_attr("size").and_then(|s| s.parse::<f32>().ok()) {
+        tree = quote! { #tree.size(#size) };
+    }
+    if let Some(id) = &node.id {
+        tree = quote! { #tree.id(#id) };
+    }
+
+    for event in &node.events {
+        let handler_ident = format_ident!("{}", to_upper_camel_case(&event.handler));
+        tree = match event.event {
+            crate::EventKind::Expand => quote! {
+                #tree.on_expand(|key: String| #message_ident::#handler_ident(key))
+            },
+            crate::EventKind::Select => quote! {
+                #tree.on_select(|key: String| #message_ident::#handler_ident(key))
+            },
+            _ => tree,
+        };
+    }
+
+    Ok(maybe_wrap_in_container(tree, node))
+}
+
+/// Generate button widget with local variable context
+fn generate_button_with_locals(
+    node: &crate::WidgetNode,
+    model_ident: &syn::Ident,
+    message_ident: &syn::Ident,
+    style_classes: &HashMap<String, StyleClass>,
+    local_vars: &std::collections::HashSet<String>,
+) -> Result<TokenStream, super::CodegenError> {
+    let label_attr = node.attributes.get("label").ok_or_else(|| {
+        super::CodegenError::InvalidWidget("button requires label attribute".to_string())
+    })?;
+
+    let label_expr = generate_attribute_value_with_locals(label_attr, model_ident, local_vars);
 
     let on_click = node
         .events
@@ -3507,12 +4900,9 @@ fn generate_button_with_locals(
         let variant_name = to_upper_camel_case(&event.handler);
         let handler_ident = format_ident!("{}", variant_name);
 
-        let param_expr = if let Some(ref param) = event.param {
-            let param_tokens = super::bindings::generate_expr_with_locals(&param.expr, local_vars);
-            quote! { (#param_tokens) }
-        } else {
-            quote! {}
-        };
+        let param_expr = generate_event_payload(event, |expr| {
+            super::bindings::generate_expr_with_locals(expr, local_vars)
+        });
 
         button = quote! {
             #button.on_press(#message_ident::#handler_ident #param_expr)
@@ -3538,40 +4928,118 @@ fn generate_container_with_locals(
         .children
         .iter()
         .map(|child| {
-            generate_widget_with_locals(
+            let widget = generate_widget_with_locals(
                 child,
                 model_ident,
                 message_ident,
                 style_classes,
                 local_vars,
-            )
+            )?;
+
+            // Children with a `grow` take their portions of the free space
+            // along the main axis of the row or column
+            let grow = get_merged_layout(child, style_classes)
+                .and_then(|l| l.grow())
+                .map(u16::from);
+            Ok(match (grow, widget_type) {
+                (Some(grow), "row") => quote! {
+                    Into::<Element<'_, #message_ident>>::into(
+                        iced::widget::container(#widget).width(iced::Length::FillPortion(#grow))
+                    )
+                },
+                (Some(grow), "column") => quote! {
+                    Into::<Element<'_, #message_ident>>::into(
+                        iced::widget::container(#widget).height(iced::Length::FillPortion(#grow))
+                    )
+                },
+                _ => widget,
+            })
         })
-        .collect::<Result<_, _>>()?;
+        .collect::<Result<_, super::CodegenError>>()?;
+
+    // While `loading` is true, the children are swapped for a placeholder
+    let loading = match node.attributes.get("loading") {
+        Some(AttributeValue::Binding(expr)) => Some(
+            super::bindings::generate_bool_expr_with_locals(&expr.expr, local_vars),
+        ),
+        Some(AttributeValue::Static(s)) if s.trim() == "true" => Some(quote! { true }),
+        _ => None,
+    };
+    let children_vec = match &loading {
+        Some(loading) => quote! {
+            if #loading {
+                vec![dampen_iced::skeleton::Skeleton::new(dampen_iced::skeleton::DEFAULT_LINES).into()]
+            } else {
+                vec![#(#children),*]
+            }
+        },
+        None => quote! { vec![#(#children),*] },
+    };
+
+    // Get merged layout from node.layout and style classes
+    let merged_layout = get_merged_layout(node, style_classes);
+    let overflow = merged_layout.as_ref().and_then(|l| l.overflow());
 
     let mut container = match widget_type {
         "column" => {
-            quote! { iced::widget::column({ let children: Vec<Element<'_, #message_ident>> = vec![#(#children),*]; children }) }
+            quote! { iced::widget::column({ let children: Vec<Element<'_, #message_ident>> = #children_vec; children }) }
         }
         "row" => {
-            quote! { iced::widget::row({ let children: Vec<Element<'_, #message_ident>> = vec![#(#children),*]; children }) }
+            quote! { iced::widget::row({ let children: Vec<Element<'_, #message_ident>> = #children_vec; children }) }
         }
         "scrollable" => {
-            quote! { iced::widget::scrollable(iced::widget::column({ let children: Vec<Element<'_, #message_ident>> = vec![#(#children),*]; children })) }
+            let mut content = quote! { iced::widget::column({ let children: Vec<Element<'_, #message_ident>> = #children_vec; children }) };
+            // Emit `on_reach_end` when scrolled near the bottom
+            if let Some(event) = node
+                .events
+                .iter()
+                .find(|e| e.event == crate::EventKind::ReachEnd)
+            {
+                let handler_ident = format_ident!("{}", to_upper_camel_case(&event.handler));
+                let distance = match node.attributes.get("reach_end_distance") {
+                    Some(AttributeValue::Static(s)) => s.trim().parse::<f32>().ok(),
+                    _ => None,
+                }
+                .unwrap_or(crate::pagination::DEFAULT_REACH_END_DISTANCE);
+                content = quote! {
+                    dampen_iced::reach_end::reach_end(#content, #message_ident::#handler_ident, #distance)
+                };
+            }
+            let mut scrollable = quote! { iced::widget::scrollable(#content) };
+            // Pass the scroll position to `on_scroll`
+            if let Some(event) = node
+                .events
+                .iter()
+                .find(|e| e.event == crate::EventKind::Scroll)
+            {
+                let handler_ident = format_ident!("{}", to_upper_camel_case(&event.handler));
+                scrollable = quote! {
+                    #scrollable.on_scroll(|viewport| {
+                        #message_ident::#handler_ident(dampen_iced::scroll_position::scroll_event(&viewport))
+                    })
+                };
+            }
+            scrollable
         }
         _ => {
             // container wraps a single child
-            if children.len() == 1 {
+            let mut content = if children.len() == 1 && loading.is_none() {
                 let child = &children[0];
-                quote! { iced::widget::container(#child) }
+                quote! { #child }
+            } else {
+                quote! { iced::widget::column({ let children: Vec<Element<'_, #message_ident>> = #children_vec; children }) }
+            };
+            if overflow == Some(Overflow::Scroll) {
+                content = quote! { iced::widget::scrollable(#content) };
+            }
+            if overflow == Some(Overflow::Clip) {
+                quote! { iced::widget::container(#content).clip(true) }
             } else {
-                quote! { iced::widget::container(iced::widget::column({ let children: Vec<Element<'_, #message_ident>> = vec![#(#children),*]; children })) }
+                quote! { iced::widget::container(#content) }
             }
         }
     };
 
-    // Get merged layout from node.layout and style classes
-    let merged_layout = get_merged_layout(node, style_classes);
-
     // Get spacing from attributes or merged layout
     let spacing = node
         .attributes
@@ -3663,11 +5131,52 @@ fn generate_container_with_locals(
         container = quote! { #container.align_y(#alignment_expr) };
     }
 
+    // Wrap children onto multiple lines, once the row/column is configured
+    if merged_layout.as_ref().and_then(|l| l.wrap()) == Some(true)
+        && (widget_type == "column" || widget_type == "row")
+    {
+        container = quote! { #container.wrap() };
+    }
+
     // Apply styles
     if widget_type == "container" {
         container = apply_widget_style(container, node, "container", style_classes)?;
     }
 
+    // Derive the height of the container from its width
+    if let Some(ratio) = merged_layout.as_ref().and_then(|l| l.aspect_ratio())
+        && widget_type == "container"
+    {
+        container = quote! { dampen_iced::aspect_ratio::aspect_ratio(#container, #ratio) };
+    }
+
+    // Drive the scroll offset from `offset` and `stick_to_bottom`
+    if widget_type == "scrollable" {
+        let offset = match node.attributes.get("offset") {
+            Some(AttributeValue::Binding(expr)) => {
+                let value =
+                    super::bindings::generate_bool_expr_with_locals(&expr.expr, local_vars);
+                Some(quote! { Some((#value) as f32) })
+            }
+            Some(AttributeValue::Static(s)) => s
+                .trim()
+                .parse::<f32>()
+                .ok()
+                .map(|offset| quote! { Some(#offset) }),
+            _ => None,
+        };
+        let stick_to_bottom = matches!(
+            node.attributes.get("stick_to_bottom"),
+            Some(AttributeValue::Static(s)) if s.trim() == "true"
+        );
+        if offset.is_some() || stick_to_bottom {
+            let offset = offset.unwrap_or_else(|| quote! { None });
+            container = quote! {
+                dampen_iced::scroll_position::scroll_position(#container, #offset, #stick_to_bottom)
+            };
+        }
+    }
+
     // Use explicit into() conversion to help type inference with nested containers
     Ok(quote! { Into::<Element<'_, #message_ident>>::into(#container) })
 }
@@ -3724,12 +5233,38 @@ fn generate_for_with_locals(
         })
         .collect::<Result<_, _>>()?;
 
+    // Only the items of `page` are rendered when the loop has a `page_size`
+    let page_attribute = |name: &str| match node.attributes.get(name) {
+        Some(AttributeValue::Static(s)) => {
+            let page = crate::pagination::parse_page(s);
+            quote! { #page }
+        }
+        Some(attr) => {
+            let value = generate_attribute_value_with_locals(attr, model_ident, local_vars);
+            quote! { dampen_core::pagination::parse_page(&#value) }
+        }
+        None => quote! { 0usize },
+    };
+    let page_items = if node.attributes.contains_key("page_size") {
+        let page = page_attribute("page");
+        let page_size = page_attribute("page_size");
+        quote! {
+            {
+                let items = &(#collection_expr);
+                let page = dampen_core::pagination::page_range(items.len(), #page, #page_size);
+                items.iter().enumerate().skip(page.start).take(page.len())
+            }
+        }
+    } else {
+        quote! { (#collection_expr).iter().enumerate() }
+    };
+
     // Generate the for loop that builds widgets
     // Use explicit type annotations to help Rust's type inference
     Ok(quote! {
         {
             let mut widgets: Vec<Element<'_, #message_ident>> = Vec::new();
-            for (index, #var_ident) in (#collection_expr).iter().enumerate() {
+            for (index, #var_ident) in #page_items {
                 let _ = index;
                 #(
                     let child_widget: Element<'_, #message_ident> = #children;
@@ -3809,12 +5344,9 @@ fn generate_checkbox_with_locals(
         let variant_name = to_upper_camel_case(&event.handler);
         let handler_ident = format_ident!("{}", variant_name);
 
-        let param_expr = if let Some(ref param) = event.param {
-            let param_tokens = super::bindings::generate_expr_with_locals(&param.expr, local_vars);
-            quote! { (#param_tokens) }
-        } else {
-            quote! {}
-        };
+        let param_expr = generate_event_payload(event, |expr| {
+            super::bindings::generate_expr_with_locals(expr, local_vars)
+        });
 
         checkbox = quote! {
             #checkbox.on_toggle(move |_| #message_ident::#handler_ident #param_expr)
@@ -3862,7 +5394,7 @@ fn generate_text_input_with_locals(
 
     // Get value attribute
     let value_attr = node.attributes.get("value");
-    let value_expr = if let Some(attr) = value_attr {
+    let mut value_expr = if let Some(attr) = value_attr {
         generate_attribute_value_with_locals(attr, model_ident, local_vars)
     } else {
         quote! { String::new() }
@@ -3873,6 +5405,18 @@ fn generate_text_input_with_locals(
         .iter()
         .find(|e| e.event == crate::EventKind::Input);
 
+    // A debounced input displays the text typed since its handler last ran
+    let debounce_ms = match node.attributes.get("debounce_ms") {
+        Some(AttributeValue::Static(ms)) => ms.trim().parse::<u64>().ok(),
+        _ => None,
+    };
+    if let (Some(_), Some(event)) = (debounce_ms, on_input) {
+        let key = &event.handler;
+        value_expr = quote! {
+            dampen_iced::debounce::pending_text(#key).unwrap_or_else(|| #value_expr)
+        };
+    }
+
     let on_submit = node
         .events
         .iter()
@@ -3882,11 +5426,53 @@ fn generate_text_input_with_locals(
         iced::widget::text_input(#placeholder_lit, &#value_expr)
     };
 
-    // Apply on_input
+    // Apply on_input, enforcing max_length and filter before dispatching
     if let Some(event) = on_input {
         let variant_name = to_upper_camel_case(&event.handler);
         let handler_ident = format_ident!("{}", variant_name);
-        text_input = quote! { #text_input.on_input(|v| #message_ident::#handler_ident(v)) };
+        // Debounced messages are emitted by `dampen_iced::debounce::ready`
+        let dispatch = |value: TokenStream| match debounce_ms {
+            Some(ms) => {
+                let key = &event.handler;
+                let rendered = format_ident!("{}", super::subscription::DEBOUNCE_VARIANT);
+                quote! {
+                    {
+                        let v = #value;
+                        dampen_iced::debounce::submit(
+                            #key,
+                            v.clone(),
+                            #message_ident::#handler_ident(v),
+                            std::time::Duration::from_millis(#ms),
+                        );
+                        #message_ident::#rendered
+                    }
+                }
+            }
+            None => quote! { #message_ident::#handler_ident(#value) },
+        };
+        if node.attributes.contains_key("max_length") || node.attributes.contains_key("filter") {
+            let constraint = |name: &str| match node.attributes.get(name) {
+                Some(attr) => {
+                    let value = generate_attribute_value_with_locals(attr, model_ident, local_vars);
+                    quote! { Some(#value).as_deref() }
+                }
+                None => quote! { None },
+            };
+            let max_length = constraint("max_length");
+            let filter = constraint("filter");
+            let message = dispatch(quote! { constraints.apply(&current, v) });
+            text_input = quote! {
+                #text_input.on_input({
+                    let constraints = dampen_core::InputConstraints::parse(#max_length, #filter)
+                        .unwrap_or_default();
+                    let current = #value_expr;
+                    move |v| #message
+                })
+            };
+        } else {
+            let message = dispatch(quote! { v });
+            text_input = quote! { #text_input.on_input(|v| #message) };
+        }
     }
 
     // Apply on_submit
@@ -3896,6 +5482,24 @@ fn generate_text_input_with_locals(
         text_input = quote! { #text_input.on_submit(#message_ident::#handler_ident) };
     }
 
+    // Mask the value with mask="password" (or password="true")
+    let secure = match (node.attributes.get("mask"), node.attributes.get("password")) {
+        (Some(AttributeValue::Static(mask)), _) => {
+            (mask.trim() == "password").then(|| quote! { true })
+        }
+        (Some(attr), _) => {
+            let mask = generate_attribute_value_with_locals(attr, model_ident, local_vars);
+            Some(quote! { #mask.trim() == "password" })
+        }
+        (None, Some(AttributeValue::Static(password))) => {
+            (password == "true" || password == "1").then(|| quote! { true })
+        }
+        _ => None,
+    };
+    if let Some(secure) = secure {
+        text_input = quote! { #text_input.secure(#secure) };
+    }
+
     // Apply size
     if let Some(size) = node.attributes.get("size").and_then(|attr| {
         if let AttributeValue::Static(s) = attr {
@@ -4033,6 +5637,9 @@ fn generate_canvas_shapes(
             WidgetKind::CanvasLine => shape_exprs.push(generate_line_shape(node, model_ident)?),
             WidgetKind::CanvasText => shape_exprs.push(generate_text_shape(node, model_ident)?),
             WidgetKind::CanvasGroup => shape_exprs.push(generate_group_shape(node, model_ident)?),
+            WidgetKind::CanvasPath => shape_exprs.push(generate_path_shape(node, model_ident)?),
+            WidgetKind::CanvasArc => shape_exprs.push(generate_arc_shape(node, model_ident)?),
+            WidgetKind::CanvasBezier => shape_exprs.push(generate_bezier_shape(node, model_ident)?),
             _ => {}
         }
     }
@@ -4138,17 +5745,127 @@ fn generate_text_shape(
     })
 }
 
-fn generate_group_shape(
+fn generate_group_shape(
+    node: &crate::WidgetNode,
+    model_ident: &syn::Ident,
+) -> Result<TokenStream, super::CodegenError> {
+    let children = generate_canvas_shapes(&node.children, model_ident)?;
+    let transform = generate_transform_attr(node, model_ident);
+
+    Ok(quote! {
+        dampen_iced::canvas::CanvasShape::Group(dampen_iced::canvas::GroupShape {
+            transform: #transform,
+            children: vec![#(#children),*],
+        })
+    })
+}
+
+fn generate_path_shape(
+    node: &crate::WidgetNode,
+    model_ident: &syn::Ident,
+) -> Result<TokenStream, super::CodegenError> {
+    let commands = match node.attributes.get("d") {
+        // Static path data is parsed at build time
+        Some(AttributeValue::Static(d)) => {
+            let commands = crate::parser::canvas::parse_path_data(d)
+                .map_err(|e| super::CodegenError::InvalidWidget(format!("path: {}", e)))?;
+            let commands = commands.iter().map(|command| match *command {
+                PathCommand::MoveTo(x, y) => {
+                    quote! { dampen_core::ir::PathCommand::MoveTo(#x, #y) }
+                }
+                PathCommand::LineTo(x, y) => {
+                    quote! { dampen_core::ir::PathCommand::LineTo(#x, #y) }
+                }
+                PathCommand::QuadraticTo(cx, cy, x, y) => {
+                    quote! { dampen_core::ir::PathCommand::QuadraticTo(#cx, #cy, #x, #y) }
+                }
+                PathCommand::CubicTo(cx1, cy1, cx2, cy2, x, y) => {
+                    quote! { dampen_core::ir::PathCommand::CubicTo(#cx1, #cy1, #cx2, #cy2, #x, #y) }
+                }
+                PathCommand::Close => quote! { dampen_core::ir::PathCommand::Close },
+            });
+            quote! { vec![#(#commands),*] }
+        }
+        Some(attr) => {
+            let d = generate_attribute_value(attr, model_ident);
+            quote! { dampen_core::parser::canvas::parse_path_data(&#d).unwrap_or_default() }
+        }
+        None => quote! { Vec::new() },
+    };
+    let fill = generate_color_option_attr(node, "fill", model_ident);
+    let stroke = generate_color_option_attr(node, "stroke", model_ident);
+    let stroke_width = generate_f32_attr(node, "stroke_width", 1.0, model_ident);
+
+    Ok(quote! {
+        dampen_iced::canvas::CanvasShape::Path(dampen_iced::canvas::PathShape {
+            commands: #commands,
+            fill: #fill,
+            stroke: #stroke,
+            stroke_width: #stroke_width,
+        })
+    })
+}
+
+fn generate_arc_shape(
+    node: &crate::WidgetNode,
+    model_ident: &syn::Ident,
+) -> Result<TokenStream, super::CodegenError> {
+    let cx = generate_f32_attr(node, "cx", 0.0, model_ident);
+    let cy = generate_f32_attr(node, "cy", 0.0, model_ident);
+    let radius = generate_f32_attr(node, "radius", 0.0, model_ident);
+    let start_angle = generate_f32_attr(node, "start_angle", 0.0, model_ident);
+    let end_angle = generate_f32_attr(node, "end_angle", 0.0, model_ident);
+    let fill = generate_color_option_attr(node, "fill", model_ident);
+    let stroke = generate_color_option_attr(node, "stroke", model_ident);
+    let stroke_width = generate_f32_attr(node, "stroke_width", 1.0, model_ident);
+
+    Ok(quote! {
+        dampen_iced::canvas::CanvasShape::Arc(dampen_iced::canvas::ArcShape {
+            cx: #cx,
+            cy: #cy,
+            radius: #radius,
+            start_angle: #start_angle,
+            end_angle: #end_angle,
+            fill: #fill,
+            stroke: #stroke,
+            stroke_width: #stroke_width,
+        })
+    })
+}
+
+fn generate_bezier_shape(
     node: &crate::WidgetNode,
     model_ident: &syn::Ident,
 ) -> Result<TokenStream, super::CodegenError> {
-    let children = generate_canvas_shapes(&node.children, model_ident)?;
-    let transform = generate_transform_attr(node, model_ident);
+    let x1 = generate_f32_attr(node, "x1", 0.0, model_ident);
+    let y1 = generate_f32_attr(node, "y1", 0.0, model_ident);
+    let cx1 = generate_f32_attr(node, "cx1", 0.0, model_ident);
+    let cy1 = generate_f32_attr(node, "cy1", 0.0, model_ident);
+    let x2 = generate_f32_attr(node, "x2", 0.0, model_ident);
+    let y2 = generate_f32_attr(node, "y2", 0.0, model_ident);
+    let control2 = if node.attributes.contains_key("cx2") && node.attributes.contains_key("cy2") {
+        let cx2 = generate_f32_attr(node, "cx2", 0.0, model_ident);
+        let cy2 = generate_f32_attr(node, "cy2", 0.0, model_ident);
+        quote! { Some((#cx2, #cy2)) }
+    } else {
+        quote! { None }
+    };
+    let fill = generate_color_option_attr(node, "fill", model_ident);
+    let stroke = generate_color_option_attr(node, "stroke", model_ident);
+    let stroke_width = generate_f32_attr(node, "stroke_width", 1.0, model_ident);
 
     Ok(quote! {
-        dampen_iced::canvas::CanvasShape::Group(dampen_iced::canvas::GroupShape {
-            transform: #transform,
-            children: vec![#(#children),*],
+        dampen_iced::canvas::CanvasShape::Bezier(dampen_iced::canvas::BezierShape {
+            x1: #x1,
+            y1: #y1,
+            cx1: #cx1,
+            cy1: #cy1,
+            control2: #control2,
+            x2: #x2,
+            y2: #y2,
+            fill: #fill,
+            stroke: #stroke,
+            stroke_width: #stroke_width,
         })
     })
 }
@@ -4404,12 +6121,8 @@ fn generate_menu_item_struct(
         let variant_name = to_upper_camel_case(&event.handler);
         let variant_ident = syn::Ident::new(&variant_name, proc_macro2::Span::call_site());
 
-        let msg = if let Some(param) = &event.param {
-            let param_expr = crate::codegen::bindings::generate_expr(&param.expr);
-            quote! { #message_ident::#variant_ident(#param_expr) }
-        } else {
-            quote! { #message_ident::#variant_ident }
-        };
+        let payload = generate_event_payload(event, crate::codegen::bindings::generate_expr);
+        let msg = quote! { #message_ident::#variant_ident #payload };
 
         btn = quote! { #btn.on_press(#msg) };
     }
@@ -4502,12 +6215,9 @@ fn generate_context_menu(
                     let variant_ident =
                         syn::Ident::new(&variant_name, proc_macro2::Span::call_site());
 
-                    let msg = if let Some(param) = &event.param {
-                        let param_expr = crate::codegen::bindings::generate_expr(&param.expr);
-                        quote! { #message_ident::#variant_ident(#param_expr) }
-                    } else {
-                        quote! { #message_ident::#variant_ident }
-                    };
+                    let payload =
+                        generate_event_payload(event, crate::codegen::bindings::generate_expr);
+                    let msg = quote! { #message_ident::#variant_ident #payload };
                     btn = quote! { #btn.on_press(#msg) };
                 }
 
@@ -5067,6 +6777,359 @@ mod tests {
         assert!(code.contains("HandleClick"));
     }
 
+    #[test]
+    fn test_button_with_multiple_params() {
+        let xml = r#"<column><button label="Up" on_click="move_item:{id},'up'" /></column>"#;
+        let doc = parse(xml).unwrap();
+
+        let result = generate_view(&doc, "Model", "Message").unwrap();
+        let code = result.to_string();
+
+        assert!(
+            code.contains("MoveItem (dampen_core :: handler :: FromEventArgs :: from_event_args")
+        );
+        assert!(code.contains("\"up\""));
+    }
+
+    #[test]
+    fn test_context_menu_event_and_attached_menu() {
+        let xml = r#"<column>
+            <text value="Item" on_context_menu="select:'a'">
+                <context_menu><menu><menu_item label="Delete" on_click="delete" /></menu></context_menu>
+            </text>
+        </column>"#;
+        let doc = parse(xml).unwrap();
+
+        let result = generate_view(&doc, "Model", "Message").unwrap();
+        let code = result.to_string();
+
+        assert!(code.contains("iced_aw :: ContextMenu :: new"));
+        assert!(code.contains("on_right_press (Message :: Select (\"a\" . to_string ()))"));
+    }
+
+    #[test]
+    fn test_rich_text_spans_and_markdown() {
+        let xml = r#"<column>
+            <text on_link="open_url">Go <span weight="bold" link="https://example.com">here</span></text>
+            <text markdown="true" value="**bold**" />
+        </column>"#;
+        let doc = parse(xml).unwrap();
+
+        let result = generate_view(&doc, "Model", "Message").unwrap();
+        let code = result.to_string();
+
+        assert!(code.contains("iced :: widget :: rich_text"));
+        assert!(code.contains("iced :: font :: Weight :: Bold"));
+        assert!(code.contains(". link (\"https://example.com\" . to_string ())"));
+        assert!(code.contains("Message :: OpenUrl (link)"));
+        assert!(code.contains("dampen_iced :: rich_text :: markdown_spans"));
+    }
+
+    #[test]
+    fn test_remote_image_uses_loader() {
+        let xml = r#"<column>
+            <image src="{avatar_url}" placeholder="assets/avatar.png" />
+            <image src="assets/logo.png" />
+        </column>"#;
+        let doc = parse(xml).unwrap();
+
+        let result = generate_view(&doc, "Model", "Message").unwrap();
+        let code = result.to_string();
+
+        assert!(code.contains("dampen_iced :: image_loader :: handle"));
+        assert!(code.contains("Some (\"assets/avatar.png\" . to_string ()) . as_deref ()"));
+        assert!(code.contains(
+            "Handle :: from_bytes (dampen_core :: assets :: read (\"assets/logo.png\"))"
+        ));
+    }
+
+    #[test]
+    fn test_canvas_curves() {
+        let xml = r#"<canvas width="200" height="200">
+            <path d="M0 0 L10 0 Z" stroke="black" />
+            <path d="{outline}" />
+            <arc cx="50" cy="50" radius="20" start_angle="0" end_angle="3.14" />
+            <bezier x1="0" y1="0" cx1="10" cy1="40" cx2="40" cy2="40" x2="50" y2="0" />
+        </canvas>"#;
+        let doc = parse(xml).unwrap();
+
+        let result = generate_view(&doc, "Model", "Message").unwrap();
+        let code = result.to_string();
+
+        assert!(code.contains("dampen_core :: ir :: PathCommand :: LineTo (10f32 , 0f32)"));
+        assert!(code.contains("dampen_core :: parser :: canvas :: parse_path_data"));
+        assert!(code.contains("dampen_iced :: canvas :: ArcShape"));
+        assert!(code.contains("control2 : Some ((40f32 , 40f32))"));
+    }
+
+    #[test]
+    fn test_svg_inline_content_and_tint() {
+        let xml = r#"<column>
+            <svg tint="@primary"><svg viewBox="0 0 24 24"><path d="M0 0 L24 24" /></svg></svg>
+            <svg content="{icon}" />
+        </column>"#;
+        let doc = parse(xml).unwrap();
+
+        let result = generate_view(&doc, "Model", "Message").unwrap();
+        let code = result.to_string();
+
+        assert!(code.contains("dampen_iced :: svg :: content_handle"));
+        assert!(code.contains("xmlns=\\\"http://www.w3.org/2000/svg\\\""));
+        assert!(code.contains("dampen_iced :: svg :: tint ((\"@primary\") . to_string ())"));
+        assert!(code.contains("icon"));
+    }
+
+    #[test]
+    fn test_icon_from_builtin_set() {
+        let xml = r#"<row><icon name="trash" size="20" /><icon name="{status_icon}" tint="@danger" /></row>"#;
+        let doc = parse(xml).unwrap();
+
+        let result = generate_view(&doc, "Model", "Message").unwrap();
+        let code = result.to_string();
+
+        assert!(code.contains("dampen_iced :: svg :: icon_handle (& (\"trash\") . to_string ())"));
+        assert!(code.contains(". width (20f32)"));
+        assert!(code.contains("dampen_iced :: svg :: tint ((\"@text\") . to_string ())"));
+        assert!(code.contains("dampen_iced :: svg :: tint ((\"@danger\") . to_string ())"));
+    }
+
+    #[test]
+    fn test_tooltip_with_rich_content_and_delay() {
+        let xml = r#"<tooltip position="top" delay_ms="400">
+            <button label="Delete" />
+            <tooltip_content padding="8">
+                <icon name="warning" />
+                <text value="Deletes all items" />
+            </tooltip_content>
+        </tooltip>"#;
+        let doc = parse(xml).unwrap();
+
+        let result = generate_view(&doc, "Model", "Message").unwrap();
+        let code = result.to_string();
+
+        assert!(code.contains("iced :: widget :: tooltip :: Position :: Top"));
+        assert!(code.contains(". delay (std :: time :: Duration :: from_millis (400u64))"));
+        assert!(code.contains("Deletes all items"));
+        assert!(code.contains("icon_handle"));
+    }
+
+    #[test]
+    fn test_slider_bound_range_and_value_label() {
+        let xml = r#"<column>
+            <slider min="{min}" max="{max}" step="0.5" value="{volume}" on_change="set_volume"
+                show_value="true" value_format="decimal(1)" />
+            <progress_bar value="{progress}" max="1" />
+        </column>"#;
+        let doc = parse(xml).unwrap();
+
+        let result = generate_view(&doc, "Model", "Message").unwrap();
+        let code = result.to_string();
+
+        assert!(code.contains(
+            "iced :: widget :: slider (((model . min) as f32) ..= ((model . max) as f32) , ((model . volume) as f32)"
+        ));
+        assert!(code.contains("Message :: SetVolume (v as _)"));
+        assert!(code.contains(". step (0.5f32)"));
+        assert!(code.contains("name : \"decimal\" . to_string ()"));
+        assert!(code.contains("dampen_core :: BindingValue :: Integer (1i64)"));
+        assert!(code.contains("progress_bar (0f32 ..= 1f32 , ((model . progress) as f32))"));
+    }
+
+    #[test]
+    fn test_pick_list_bound_to_collection() {
+        let xml = r#"<column>
+            <pick_list options="{countries}" display="{item.name}" value="{item.code}"
+                selected="{country}" on_select="select_country" />
+            <pick_list options="Small,Large" selected="{size}" on_select="set_size" />
+        </column>"#;
+        let doc = parse(xml).unwrap();
+
+        let result = generate_view(&doc, "Model", "Message").unwrap();
+        let code = result.to_string();
+
+        assert!(code.contains("model . countries . iter () . enumerate ()"));
+        assert!(code.contains("let label : String = item . name . to_string ()"));
+        assert!(code.contains("let value : String = item . code . to_string ()"));
+        assert!(code.contains("Message :: SelectCountry (option . value)"));
+        assert!(code.contains(
+            "dampen_core :: SelectOption :: parse_list (& \"Small,Large\" . to_string ())"
+        ));
+        assert!(code.contains("SelectOption :: find (& options , & model . size . to_string ())"));
+    }
+
+    #[test]
+    fn test_radio_group_from_options_and_children() {
+        let xml = r#"<column>
+            <radio_group value="{plan}" options="{plans}" on_select="set_plan" />
+            <radio_group value="{size}" direction="horizontal" spacing="12" on_select="set_size">
+                <radio label="Small" value="s" />
+                <radio label="Large" value="l" />
+            </radio_group>
+        </column>"#;
+        let doc = parse(xml).unwrap();
+
+        let result = generate_view(&doc, "Model", "Message").unwrap();
+        let code = result.to_string();
+
+        assert!(code.contains("model . plans . iter () . enumerate ()"));
+        assert!(code.contains("let value : String = item . to_string ()"));
+        assert!(code.contains("let selected_value = model . plan . to_string ()"));
+        assert!(code.contains("move | _ | Message :: SetPlan (value . clone ())"));
+        assert!(code.contains(
+            "dampen_core :: SelectOption :: new (\"Small\" . to_string () , \"s\" . to_string ())"
+        ));
+        assert!(code.contains("iced :: widget :: row (radios) . spacing (12f32)"));
+    }
+
+    #[test]
+    fn test_text_wrap_and_line_clamp() {
+        let xml = r#"<column>
+            <text value="{title}" wrap="none" truncate="ellipsis" width="110" size="10" />
+            <text value="{summary}" max_lines="2" />
+            <text value="{body}" />
+        </column>"#;
+        let doc = parse(xml).unwrap();
+
+        let result = generate_view(&doc, "Model", "Message").unwrap();
+        let code = result.to_string();
+
+        assert!(code.contains("dampen_core :: TextClamp { wrap : dampen_core :: TextWrap :: None , max_lines : None , ellipsis : true , chars_per_line : Some (20usize) , }"));
+        assert!(code.contains(". wrapping (iced :: widget :: text :: Wrapping :: None)"));
+        // 2 lines of 16px text, without ellipsis the value is untouched
+        assert!(code.contains(". height (42f32)"));
+        assert_eq!(code.matches(". apply (&").count(), 1);
+        assert_eq!(code.matches(". wrapping (").count(), 1);
+    }
+
+    #[test]
+    fn test_text_input_mask_and_constraints() {
+        let xml = r#"<column>
+            <text_input value="{pin}" mask="password" max_length="4" filter="numeric" on_input="set_pin" />
+            <text_input value="{name}" on_input="set_name" />
+        </column>"#;
+        let doc = parse(xml).unwrap();
+
+        let result = generate_view(&doc, "Model", "Message").unwrap();
+        let code = result.to_string();
+
+        assert!(code.contains(
+            "dampen_core :: InputConstraints :: parse (Some (\"4\" . to_string ()) . as_deref () , Some (\"numeric\" . to_string ()) . as_deref ())"
+        ));
+        assert!(code.contains("Message :: SetPin (constraints . apply (& current , v))"));
+        assert!(code.contains(". secure (true)"));
+        assert!(code.contains(". on_input (| v | Message :: SetName (v))"));
+    }
+
+    #[test]
+    fn test_number_input_and_date_picker_on_change() {
+        let xml = r#"<column>
+            <number_input value="{qty}" min="0" max="99" on_change="set_qty" />
+            <date_picker value="{due_date}" on_change="set_due_date" on_open="open_calendar" />
+        </column>"#;
+        let doc = parse(xml).unwrap();
+
+        let result = generate_view(&doc, "Model", "Message").unwrap();
+        let code = result.to_string();
+
+        assert!(code.contains("let value = range . clamp (((model . qty) as f64))"));
+        assert!(
+            code.contains(
+                "Message :: SetQty (range . parse_value (& text) . unwrap_or (value) as _)"
+            )
+        );
+        assert!(code.contains("Message :: SetQty (range . step_by (value , 1.0) as _)"));
+        assert!(
+            code.contains("| date | Message :: SetDueDate (chrono :: NaiveDate :: from (date))")
+        );
+        assert!(code.contains(". on_press_maybe (Some (Message :: OpenCalendar))"));
+    }
+
+    #[test]
+    fn test_skeleton_and_loading_container() {
+        let xml = r#"<column>
+            <skeleton lines="2" />
+            <container loading="{is_loading}">
+                <text value="{user.name}" />
+            </container>
+        </column>"#;
+        let doc = parse(xml).unwrap();
+
+        let result = generate_view(&doc, "Model", "Message").unwrap();
+        let code = result.to_string();
+
+        assert!(code.contains("dampen_iced :: skeleton :: Skeleton :: new (2usize)"));
+        assert!(code.contains("if model . is_loading {"));
+        assert!(code.contains("Skeleton :: new (dampen_iced :: skeleton :: DEFAULT_LINES)"));
+        assert!(code.contains("model . user . name"));
+    }
+
+    #[test]
+    fn test_markdown_with_links() {
+        let xml = r#"<column>
+            <markdown src="{readme}" size="15" on_link="open_url" />
+        </column>"#;
+        let doc = parse(xml).unwrap();
+
+        let result = generate_view(&doc, "Model", "Message").unwrap();
+        let code = result.to_string();
+
+        assert!(code.contains("dampen_iced :: markdown :: Markdown :: new (& model . readme"));
+        assert!(code.contains(". size (15f32)"));
+        assert!(code.contains(". on_link (| link : String | Message :: OpenUrl (link))"));
+    }
+
+    #[test]
+    fn test_tree_with_lazy_children() {
+        let xml = r#"<column>
+            <tree id="files" items="{nodes}" children="children" label="{node.name}" on_expand="load_children" />
+        </column>"#;
+        let doc = parse(xml).unwrap();
+
+        let result = generate_view(&doc, "Model", "Message").unwrap();
+        let code = result.to_string();
+
+        assert!(code.contains("dampen_iced :: tree :: rows (& (model . nodes)"));
+        assert!(code.contains("| node | & node . children"));
+        assert!(code.contains("| _ , path | path . to_string ()"));
+        assert!(code.contains(". id (\"files\")"));
+        assert!(code.contains(". on_expand (| key : String | Message :: LoadChildren (key))"));
+    }
+
+    #[test]
+    fn test_code_editor() {
+        let xml = r#"<column>
+            <code_editor language="rust" value="{source}" on_change="update_source" height="300" />
+        </column>"#;
+        let doc = parse(xml).unwrap();
+
+        let result = generate_view(&doc, "Model", "Message").unwrap();
+        let code = result.to_string();
+
+        assert!(code.contains("dampen_iced :: code_editor :: CodeEditor :: new (model . source"));
+        assert!(code.contains(". language (\"rust\")"));
+        assert!(code.contains(". height (iced :: Length :: Fixed (300f32))"));
+        assert!(code.contains(". on_change (| text : String | Message :: UpdateSource (text))"));
+    }
+
+    #[test]
+    fn test_chart_bound_to_collection() {
+        let xml = r#"<column>
+            <chart type="bar" data="{sales}" x="month" y="total" show_legend="true" />
+            <chart type="pie" data="{shares}" />
+        </column>"#;
+        let doc = parse(xml).unwrap();
+
+        let result = generate_view(&doc, "Model", "Message").unwrap();
+        let code = result.to_string();
+
+        assert!(code.contains("dampen_iced :: canvas :: ChartKind :: Bar"));
+        assert!(code.contains("item . month . to_string ()"));
+        assert!(code.contains("item . total as f32"));
+        assert!(code.contains(". show_legend (true)"));
+        assert!(code.contains("dampen_iced :: canvas :: ChartKind :: Pie"));
+        assert!(code.contains("* item as f32"));
+    }
+
     #[test]
     fn test_container_with_children() {
         let xml = r#"<column spacing="10"><text value="A" /><text value="B" /></column>"#;
@@ -5098,7 +7161,7 @@ mod tests {
                 attrs
             },
             events: vec![],
-            children: vec![],
+            children: Default::default(),
             span: Default::default(),
             style: Some(StyleProperties {
                 background: Some(Background::Color(Color::from_rgb8(52, 152, 219))),
@@ -5107,12 +7170,18 @@ mod tests {
                 shadow: None,
                 opacity: None,
                 transform: None,
+                border_sides: None,
+                outline: None,
+                elevation: None,
             }),
             layout: None,
             theme_ref: None,
             classes: vec![],
             breakpoint_attributes: HashMap::new(),
             inline_state_variants: HashMap::new(),
+            accessibility: None,
+            attribute_spans: HashMap::new(),
+            comments: Vec::new(),
         };
 
         let model_ident = syn::Ident::new("model", proc_macro2::Span::call_site());
@@ -5150,7 +7219,7 @@ mod tests {
                 attrs
             },
             events: vec![],
-            children: vec![],
+            children: Default::default(),
             span: Default::default(),
             style: None,
             layout: None,
@@ -5158,6 +7227,9 @@ mod tests {
             classes: vec!["primary-button".to_string()],
             breakpoint_attributes: HashMap::new(),
             inline_state_variants: HashMap::new(),
+            accessibility: None,
+            attribute_spans: HashMap::new(),
+            comments: Vec::new(),
         };
 
         let model_ident = syn::Ident::new("model", proc_macro2::Span::call_site());
@@ -5187,7 +7259,7 @@ mod tests {
             id: None,
             attributes: HashMap::new(),
             events: vec![],
-            children: vec![],
+            children: Default::default(),
             span: Default::default(),
             style: Some(StyleProperties {
                 background: Some(Background::Color(Color::from_rgb8(240, 240, 240))),
@@ -5206,12 +7278,18 @@ mod tests {
                 shadow: None,
                 opacity: None,
                 transform: None,
+                border_sides: None,
+                outline: None,
+                elevation: None,
             }),
             layout: None,
             theme_ref: None,
             classes: vec![],
             breakpoint_attributes: HashMap::new(),
             inline_state_variants: HashMap::new(),
+            accessibility: None,
+            attribute_spans: HashMap::new(),
+            comments: Vec::new(),
         };
 
         let model_ident = syn::Ident::new("model", proc_macro2::Span::call_site());
@@ -5234,6 +7312,162 @@ mod tests {
         assert!(code.contains("background"));
         assert!(code.contains("border"));
     }
+
+    #[test]
+    fn test_per_side_borders_and_outline_are_decorated() {
+        use crate::ir::theme::StyleClass;
+        use std::collections::HashMap;
+
+        let doc = crate::parser::parse(
+            r##"<column>
+                <container border_bottom="1 #cccccc" outline="2 #3498db 1" border_radius="4" />
+                <text_input value="" outline="2 #3498db" />
+            </column>"##,
+        )
+        .unwrap();
+        let model_ident = syn::Ident::new("model", proc_macro2::Span::call_site());
+        let message_ident = syn::Ident::new("Message", proc_macro2::Span::call_site());
+        let style_classes: HashMap<String, StyleClass> = HashMap::new();
+
+        let code = generate_container(
+            &doc.root.children[0],
+            "container",
+            &model_ident,
+            &message_ident,
+            &style_classes,
+        )
+        .unwrap()
+        .to_string();
+        assert!(code.contains("dampen_iced :: decoration :: decorated"));
+        assert!(code.contains(". bottom (1f32"));
+        assert!(code.contains(". outline (2f32"));
+        assert!(code.contains(". radius ("));
+        assert!(!code.contains(". top ("));
+
+        // Text inputs draw their outline as their border
+        let code = generate_text_input(
+            &doc.root.children[1],
+            &model_ident,
+            &message_ident,
+            &style_classes,
+        )
+        .unwrap()
+        .to_string();
+        assert!(!code.contains("decoration"));
+        assert!(code.contains("width : 2f32"));
+    }
+
+    #[test]
+    fn test_wrapping_rows_and_grown_children() {
+        use crate::ir::theme::StyleClass;
+        use std::collections::HashMap;
+
+        let doc = crate::parser::parse(
+            r#"<column>
+                <row wrap="true" spacing="8">
+                    <text value="Tag" />
+                    <text_input value="" grow="2" />
+                </row>
+                <column>
+                    <text value="Body" grow="1" />
+                </column>
+            </column>"#,
+        )
+        .unwrap();
+        let model_ident = syn::Ident::new("model", proc_macro2::Span::call_site());
+        let message_ident = syn::Ident::new("Message", proc_macro2::Span::call_site());
+        let style_classes: HashMap<String, StyleClass> = HashMap::new();
+
+        let code = generate_widget(
+            &doc.root.children[0],
+            &model_ident,
+            &message_ident,
+            &style_classes,
+        )
+        .unwrap()
+        .to_string();
+        assert!(code.contains(". spacing (8f32) . wrap ()"));
+        assert!(code.contains(". width (iced :: Length :: FillPortion (2u16))"));
+
+        // Columns grow their children vertically, and do not wrap by default
+        let code = generate_widget(
+            &doc.root.children[1],
+            &model_ident,
+            &message_ident,
+            &style_classes,
+        )
+        .unwrap()
+        .to_string();
+        assert!(code.contains(". height (iced :: Length :: FillPortion (1u16))"));
+        assert!(!code.contains(". wrap ()"));
+    }
+
+    #[test]
+    fn test_aspect_ratio_and_overflow() {
+        use crate::ir::theme::StyleClass;
+        use std::collections::HashMap;
+
+        let doc = crate::parser::parse(
+            r#"<column>
+                <container width="fill" aspect_ratio="16:9" overflow="clip">
+                    <text value="Poster" />
+                </container>
+                <container height="120" overflow="scroll">
+                    <text value="Line" />
+                    <text value="Line" />
+                </container>
+            </column>"#,
+        )
+        .unwrap();
+        let model_ident = syn::Ident::new("model", proc_macro2::Span::call_site());
+        let message_ident = syn::Ident::new("Message", proc_macro2::Span::call_site());
+        let style_classes: HashMap<String, StyleClass> = HashMap::new();
+
+        let code = generate_widget(
+            &doc.root.children[0],
+            &model_ident,
+            &message_ident,
+            &style_classes,
+        )
+        .unwrap()
+        .to_string();
+        assert!(code.contains("dampen_iced :: aspect_ratio :: aspect_ratio ("));
+        assert!(code.contains(". clip (true)"));
+
+        let code = generate_widget(
+            &doc.root.children[1],
+            &model_ident,
+            &message_ident,
+            &style_classes,
+        )
+        .unwrap()
+        .to_string();
+        assert!(code.contains("iced :: widget :: container (iced :: widget :: scrollable ("));
+        assert!(!code.contains("aspect_ratio"));
+    }
+
+    #[test]
+    fn test_anchored_float_is_generated_with_its_anchor() {
+        let doc = crate::parser::parse(
+            r#"<column>
+                <button id="menu_button" label="Options" on_click="toggle_menu" />
+                <text value="Below" />
+                <float anchor="menu_button" placement="top-end" gap="8" show="{menu_open}">
+                    <text value="Rename" />
+                </float>
+            </column>"#,
+        )
+        .unwrap();
+
+        let code = generate_view(&doc, "Model", "Message").unwrap().to_string();
+        assert!(code.contains("dampen_iced :: popover :: anchored ("));
+        assert!(code.contains("Side :: Top"));
+        assert!(code.contains("PlacementAlign :: End"));
+        assert!(code.contains("8f32"));
+        assert!(code.contains("menu_open"));
+        // The float is shown by its anchor, before the text following it
+        assert!(code.find("Rename") < code.find("Below"));
+    }
 }
 
 /// Generate TabBar widget code with content
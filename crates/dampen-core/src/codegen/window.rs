@@ -0,0 +1,94 @@
+//! Window settings code generation
+//!
+//! This module turns the `<window>` declaration of a view document into the
+//! window settings of the application, for both the generated code of
+//! production builds and the `#[dampen_app]` macro.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::ir::WindowConfig;
+
+/// Generate an `iced::window::Settings` expression from a window declaration
+pub fn generate_window_settings(config: &WindowConfig) -> TokenStream {
+    let mut fields = Vec::new();
+    if let Some((width, height)) = config.size() {
+        fields.push(quote! { size: iced::Size::new(#width as f32, #height as f32), });
+    }
+    if let Some((width, height)) = config.min_size() {
+        fields.push(quote! { min_size: Some(iced::Size::new(#width as f32, #height as f32)), });
+    }
+    if let Some((width, height)) = config.max_size() {
+        fields.push(quote! { max_size: Some(iced::Size::new(#width as f32, #height as f32)), });
+    }
+    if let Some(resizable) = config.resizable {
+        fields.push(quote! { resizable: #resizable, });
+    }
+    if let Some(decorations) = config.decorations {
+        fields.push(quote! { decorations: #decorations, });
+    }
+
+    quote! {
+        iced::window::Settings {
+            #(#fields)*
+            ..Default::default()
+        }
+    }
+}
+
+/// Generate the `WindowSettingsBuilder` calls applying a window declaration
+///
+/// The declared size becomes the default size, used until a window state is
+/// persisted.
+pub fn generate_window_builder_calls(config: &WindowConfig) -> TokenStream {
+    let mut calls = Vec::new();
+    if let Some((width, height)) = config.size() {
+        calls.push(quote! { .default_size(#width, #height) });
+    }
+    if let Some((width, height)) = config.min_size() {
+        calls.push(quote! { .min_size(#width, #height) });
+    }
+    if let Some((width, height)) = config.max_size() {
+        calls.push(quote! { .max_size(#width, #height) });
+    }
+    if let Some(resizable) = config.resizable {
+        calls.push(quote! { .resizable(#resizable) });
+    }
+    if let Some(decorations) = config.decorations {
+        calls.push(quote! { .decorations(#decorations) });
+    }
+
+    quote! { #(#calls)* }
+}
+
+/// Size of the window before any state is persisted
+///
+/// Falls back to the 800x600 default of window persistence.
+pub fn default_persisted_size(config: Option<&WindowConfig>) -> (u32, u32) {
+    config.and_then(WindowConfig::size).unwrap_or((800, 600))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_window_settings_sets_declared_fields_only() {
+        let config = WindowConfig {
+            width: Some(640),
+            height: Some(480),
+            resizable: Some(false),
+            ..WindowConfig::default()
+        };
+
+        let code = generate_window_settings(&config).to_string();
+        assert!(code.contains("size : iced :: Size :: new (640u32 as f32 , 480u32 as f32)"));
+        assert!(code.contains("resizable : false"));
+        assert!(!code.contains("min_size"));
+        assert!(!code.contains("decorations"));
+
+        let calls = generate_window_builder_calls(&config).to_string();
+        assert!(calls.contains(". default_size (640u32 , 480u32)"));
+        assert!(calls.contains(". resizable (false)"));
+    }
+}
@@ -0,0 +1,180 @@
+//! Coverage recording of UI logic
+//!
+//! When the `DAMPEN_COVERAGE_DIR` environment variable is set (as done by
+//! `dampen test --coverage`), every handler dispatch and every binding field read
+//! is recorded to a file in that directory, one file per process. The CLI then
+//! compares the recorded hits with the `#[ui_handler]` functions and bindings
+//! declared by the project.
+//!
+//! Handlers are recorded in both modes, by the [`HandlerRegistry`](crate::HandlerRegistry)
+//! and by [`intercept`](crate::handler::intercept). Bindings are recorded by the
+//! expression evaluator, so only interpreted mode reports them.
+//!
+//! Recording is a no-op when the variable is not set.
+
+use std::collections::{BTreeSet, HashSet};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+
+/// Environment variable naming the directory coverage hits are written to
+pub const COVERAGE_DIR_ENV: &str = "DAMPEN_COVERAGE_DIR";
+
+/// Extension of the hit files written to the coverage directory
+const HITS_EXTENSION: &str = "hits";
+
+/// What a coverage hit refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HitKind {
+    /// A handler was dispatched
+    Handler,
+    /// A binding field was read
+    Binding,
+}
+
+impl HitKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            HitKind::Handler => "handler",
+            HitKind::Binding => "binding",
+        }
+    }
+}
+
+/// Handlers and bindings recorded during a run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CoverageHits {
+    /// Names of the dispatched handlers
+    pub handlers: BTreeSet<String>,
+    /// Dotted paths of the read binding fields (e.g. `user.name`, `shared.theme`)
+    pub bindings: BTreeSet<String>,
+}
+
+impl CoverageHits {
+    /// Read and merge every hit file of a coverage directory
+    ///
+    /// A missing directory yields no hits.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or one of its hit files cannot be read.
+    pub fn read_dir(dir: &Path) -> io::Result<Self> {
+        let mut hits = Self::default();
+        if !dir.exists() {
+            return Ok(hits);
+        }
+
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == HITS_EXTENSION) {
+                hits.merge(&fs::read_to_string(&path)?);
+            }
+        }
+        Ok(hits)
+    }
+
+    /// Add the hits of a hit file's content, ignoring malformed lines
+    fn merge(&mut self, content: &str) {
+        for line in content.lines() {
+            match line.split_once('\t') {
+                Some(("handler", name)) => {
+                    self.handlers.insert(name.to_string());
+                }
+                Some(("binding", path)) => {
+                    self.bindings.insert(path.to_string());
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Appends each distinct hit of the process to its hit file
+struct Recorder {
+    file: File,
+    seen: HashSet<(HitKind, String)>,
+}
+
+impl Recorder {
+    fn open(dir: &Path) -> io::Result<Self> {
+        fs::create_dir_all(dir)?;
+        let path = dir.join(format!("{}.{}", std::process::id(), HITS_EXTENSION));
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            file,
+            seen: HashSet::new(),
+        })
+    }
+
+    fn record(&mut self, kind: HitKind, name: String) {
+        if self.seen.contains(&(kind, name.clone())) {
+            return;
+        }
+        // Hits are written as they happen: test processes may exit without cleanup
+        if writeln!(self.file, "{}\t{}", kind.as_str(), name).is_ok() {
+            self.seen.insert((kind, name));
+        }
+    }
+}
+
+fn recorder() -> Option<&'static Mutex<Recorder>> {
+    static RECORDER: OnceLock<Option<Mutex<Recorder>>> = OnceLock::new();
+    RECORDER
+        .get_or_init(|| {
+            let dir = std::env::var_os(COVERAGE_DIR_ENV)?;
+            Recorder::open(Path::new(&dir)).ok().map(Mutex::new)
+        })
+        .as_ref()
+}
+
+/// Record a hit, if coverage is enabled
+///
+/// `name` is only built when recording, so callers can pass a formatting closure.
+pub fn record(kind: HitKind, name: impl FnOnce() -> String) {
+    if let Some(recorder) = recorder()
+        && let Ok(mut recorder) = recorder.lock()
+    {
+        recorder.record(kind, name());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recorded_hits_are_read_back_once() -> Result<(), String> {
+        let dir = std::env::temp_dir().join(format!("dampen-coverage-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let mut recorder = Recorder::open(&dir).map_err(|e| e.to_string())?;
+        recorder.record(HitKind::Handler, "increment".to_string());
+        recorder.record(HitKind::Handler, "increment".to_string());
+        recorder.record(HitKind::Binding, "user.name".to_string());
+        fs::write(dir.join("other.hits"), "handler\treset\nnot a hit\n")
+            .map_err(|e| e.to_string())?;
+
+        let hits = CoverageHits::read_dir(&dir).map_err(|e| e.to_string())?;
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(
+            hits.handlers.into_iter().collect::<Vec<_>>(),
+            vec!["increment", "reset"]
+        );
+        assert_eq!(
+            hits.bindings.into_iter().collect::<Vec<_>>(),
+            vec!["user.name"]
+        );
+        assert_eq!(recorder.seen.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_missing_directory_has_no_hits() -> Result<(), String> {
+        let hits = CoverageHits::read_dir(Path::new("/nonexistent/dampen-coverage"))
+            .map_err(|e| e.to_string())?;
+        assert_eq!(hits, CoverageHits::default());
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+//! Values of the environment the view is rendered in
+//!
+//! [`EnvValues`] holds the size of the window, the current breakpoint, the
+//! active theme, the platform and the build profile. They are bound with the
+//! reserved `env` root, without being copied to the model:
+//!
+//! ```xml
+//! <text value="{env.viewport_width} x {env.viewport_height}" />
+//! <if condition="{env.breakpoint == 'mobile'}">
+//!     <button label="Menu" on_click="open_menu" />
+//! </if>
+//! <text value="Debug build on {env.platform}" visible="{env.debug}" />
+//! ```
+//!
+//! The runtime maintains them: the window size and breakpoint come from
+//! [`crate::viewport`], and the widget builder stores the name of the active
+//! theme with [`set_theme`] when it builds the view.
+//!
+//! `{env.config.<key>}` binds the values of the environment profile selected
+//! with `dampen run --profile staging`, which passes them to the application
+//! as `DAMPEN_CONFIG_<KEY>` variables (see [`config`]). Generated code reads
+//! them when it is compiled instead.
+
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use crate::binding::{BindingValue, UiBindable};
+
+/// Variable holding the name of the environment profile, e.g. `staging`
+pub const PROFILE_ENV: &str = "DAMPEN_PROFILE";
+
+/// Prefix of the variables holding the values of the environment profile
+pub const CONFIG_ENV_PREFIX: &str = "DAMPEN_CONFIG_";
+
+/// Values bound as `{env.<field>}`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EnvValues {
+    /// Width of the window, in logical pixels, 0 until the runtime stored it
+    pub viewport_width: f32,
+    /// Height of the window, in logical pixels, 0 until the runtime stored it
+    pub viewport_height: f32,
+    /// Name of the current breakpoint (`mobile`, or a custom one such as `md`),
+    /// empty when none applies
+    pub breakpoint: String,
+    /// Name of the active theme, empty without themes
+    pub theme: String,
+    /// `windows`, `mac`, `linux`, or the name of another operating system
+    pub platform: &'static str,
+    /// Whether the application is a debug build
+    pub debug: bool,
+}
+
+impl UiBindable for EnvValues {
+    fn get_field(&self, path: &[&str]) -> Option<BindingValue> {
+        match path {
+            ["viewport_width"] => Some(BindingValue::Float(self.viewport_width.into())),
+            ["viewport_height"] => Some(BindingValue::Float(self.viewport_height.into())),
+            ["breakpoint"] => Some(BindingValue::String(self.breakpoint.clone())),
+            ["theme"] => Some(BindingValue::String(self.theme.clone())),
+            ["platform"] => Some(BindingValue::String(self.platform.to_string())),
+            ["debug"] => Some(BindingValue::Bool(self.debug)),
+            ["config", key] => config(key).map(BindingValue::String),
+            _ => None,
+        }
+    }
+
+    fn available_fields() -> Vec<String> {
+        [
+            "viewport_width",
+            "viewport_height",
+            "breakpoint",
+            "theme",
+            "platform",
+            "debug",
+        ]
+        .iter()
+        .map(|field| field.to_string())
+        .collect()
+    }
+}
+
+static THEME: Mutex<String> = Mutex::new(String::new());
+
+fn theme() -> MutexGuard<'static, String> {
+    THEME.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Store the name of the active theme
+pub fn set_theme(name: &str) {
+    let mut theme = theme();
+    if *theme != name {
+        *theme = name.to_string();
+    }
+}
+
+/// Variable holding the profile value `key`: `api_url` is `DAMPEN_CONFIG_API_URL`
+pub fn config_var(key: &str) -> String {
+    format!("{}{}", CONFIG_ENV_PREFIX, key.to_uppercase())
+}
+
+/// Value `key` of the environment profile, bound as `{env.config.<key>}`
+pub fn config(key: &str) -> Option<String> {
+    std::env::var(config_var(key)).ok()
+}
+
+/// Name of the platform bound as `{env.platform}`
+pub fn platform() -> &'static str {
+    match std::env::consts::OS {
+        "macos" => "mac",
+        os => os,
+    }
+}
+
+/// Current values of the environment
+pub fn values() -> EnvValues {
+    let size = crate::viewport::size().unwrap_or_default();
+    EnvValues {
+        viewport_width: size.width,
+        viewport_height: size.height,
+        breakpoint: crate::viewport::breakpoint()
+            .map(|breakpoint| breakpoint.to_string())
+            .unwrap_or_default(),
+        theme: theme().clone(),
+        platform: platform(),
+        debug: cfg!(debug_assertions),
+    }
+}
+
+/// Value of a `{env.<field>}` binding, `path` not including `env`
+pub fn get_field(path: &[&str]) -> Option<BindingValue> {
+    values().get_field(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_env_fields_are_bound() {
+        set_theme("dark");
+        assert_eq!(
+            get_field(&["theme"]).map(|value| value.to_display_string()),
+            Some("dark".to_string())
+        );
+        assert_eq!(
+            get_field(&["debug"]),
+            Some(BindingValue::Bool(cfg!(debug_assertions)))
+        );
+        assert_eq!(get_field(&["locale"]), None);
+        assert_eq!(config_var("api_url"), "DAMPEN_CONFIG_API_URL");
+        assert_eq!(get_field(&["config", "missing_key_of_the_test"]), None);
+
+        // Bound as `{env.<field>}` when the model has no such field
+        let binding =
+            crate::expr::tokenizer::tokenize_binding_expr("env.platform", 0, 1, 1).unwrap();
+        assert_eq!(
+            crate::evaluate_binding_expr(&binding, &()).map(|value| value.to_display_string()),
+            Ok(platform().to_string())
+        );
+    }
+}
@@ -4,6 +4,7 @@ use crate::ir::span::Span;
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct BindingExpr {
     pub expr: Expr,
+    #[serde(skip_serializing_if = "crate::ir::stable::skip_source")]
     pub span: Span,
 }
 
@@ -19,6 +20,8 @@ pub enum Expr {
     UnaryOp(UnaryOpExpr),
     Conditional(ConditionalExpr),
     Literal(LiteralExpr),
+    /// Formatting filter: `{created | date("%Y-%m-%d")}`
+    Filter(FilterExpr),
 }
 
 /// Field access path
@@ -90,6 +93,14 @@ pub struct ConditionalExpr {
     pub else_branch: Box<Expr>,
 }
 
+/// Filter applied to a value with the `|` operator
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct FilterExpr {
+    pub input: Box<Expr>,
+    pub name: String,
+    pub args: Vec<Expr>,
+}
+
 /// Literal value
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum LiteralExpr {
@@ -141,6 +152,7 @@ impl Expr {
                     || c.then_branch.uses_shared()
                     || c.else_branch.uses_shared()
             }
+            Expr::Filter(f) => f.input.uses_shared() || f.args.iter().any(|a| a.uses_shared()),
         }
     }
 
@@ -159,6 +171,30 @@ impl Expr {
             Expr::Conditional(c) => {
                 c.condition.uses_model() || c.then_branch.uses_model() || c.else_branch.uses_model()
             }
+            Expr::Filter(f) => f.input.uses_model() || f.args.iter().any(|a| a.uses_model()),
+        }
+    }
+
+    /// Check if this expression reads the system preferences.
+    ///
+    /// Returns `true` if this expression or any of its sub-expressions
+    /// reference `{system.field}` (see [`crate::system`]).
+    pub fn uses_system(&self) -> bool {
+        match self {
+            Expr::FieldAccess(f) => f.path.len() == 2 && f.path[0] == "system",
+            Expr::SharedFieldAccess(_) => false,
+            Expr::Literal(_) => false,
+            Expr::MethodCall(m) => {
+                m.receiver.uses_system() || m.args.iter().any(|a| a.uses_system())
+            }
+            Expr::BinaryOp(b) => b.left.uses_system() || b.right.uses_system(),
+            Expr::UnaryOp(u) => u.operand.uses_system(),
+            Expr::Conditional(c) => {
+                c.condition.uses_system()
+                    || c.then_branch.uses_system()
+                    || c.else_branch.uses_system()
+            }
+            Expr::Filter(f) => f.input.uses_system() || f.args.iter().any(|a| a.uses_system()),
         }
     }
 }
@@ -177,4 +213,11 @@ impl BindingExpr {
     pub fn uses_model(&self) -> bool {
         self.expr.uses_model()
     }
+
+    /// Check if this binding expression reads the system preferences.
+    ///
+    /// Convenience method that delegates to `Expr::uses_system()`.
+    pub fn uses_system(&self) -> bool {
+        self.expr.uses_system()
+    }
 }
@@ -22,6 +22,12 @@ pub enum BindingErrorKind {
 
     /// Invalid operation
     InvalidOperation,
+
+    /// Filter does not exist or does not apply to the value
+    UnknownFilter,
+
+    /// Expression is not syntactically valid
+    InvalidSyntax,
 }
 
 impl std::fmt::Display for BindingError {
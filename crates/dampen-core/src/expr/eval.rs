@@ -3,11 +3,18 @@
 //! This module provides unified evaluation functions that support both
 //! local model access and shared state context, eliminating code duplication.
 
+use std::cmp::Ordering;
+
+use chrono::{Datelike, Timelike};
+use rust_decimal::Decimal;
+
 use crate::binding::{BindingValue, UiBindable};
+use crate::coverage::{self, HitKind};
 use crate::expr::error::{BindingError, BindingErrorKind};
+use crate::expr::filters::apply_filter;
 use crate::expr::{
-    BinaryOp, BinaryOpExpr, ConditionalExpr, Expr, FieldAccessExpr, LiteralExpr, MethodCallExpr,
-    SharedFieldAccessExpr, UnaryOp, UnaryOpExpr,
+    BinaryOp, BinaryOpExpr, ConditionalExpr, Expr, FieldAccessExpr, FilterExpr, LiteralExpr,
+    MethodCallExpr, SharedFieldAccessExpr, UnaryOp, UnaryOpExpr,
 };
 
 /// Evaluate an expression against a model
@@ -38,7 +45,7 @@ pub fn evaluate_expr_with_shared(
     shared: Option<&dyn UiBindable>,
 ) -> Result<BindingValue, BindingError> {
     match expr {
-        Expr::FieldAccess(field_expr) => evaluate_field_access(field_expr, model),
+        Expr::FieldAccess(field_expr) => evaluate_field_access(field_expr, model, shared),
         Expr::SharedFieldAccess(shared_expr) => evaluate_shared_field_access(shared_expr, shared),
         Expr::MethodCall(method_expr) => evaluate_method_call(method_expr, model, shared),
         Expr::BinaryOp(binary_expr) => evaluate_binary_op(binary_expr, model, shared),
@@ -47,17 +54,38 @@ pub fn evaluate_expr_with_shared(
             evaluate_conditional(conditional_expr, model, shared)
         }
         Expr::Literal(literal_expr) => Ok(evaluate_literal(literal_expr)),
+        Expr::Filter(filter_expr) => evaluate_filter(filter_expr, model, shared),
     }
 }
 
 /// Evaluate field access: `counter` or `user.name`
+///
+/// A dotted path the model does not provide falls back to the shared context, so
+/// named shared contexts are bound as `{session.user}`, then to the system
+/// preferences for `{system.reduced_motion}` (see [`crate::system`]) and the
+/// environment values for `{env.viewport_width}` (see [`crate::env`]).
 fn evaluate_field_access(
     field_expr: &FieldAccessExpr,
     model: &dyn UiBindable,
+    shared: Option<&dyn UiBindable>,
 ) -> Result<BindingValue, BindingError> {
+    coverage::record(HitKind::Binding, || field_expr.path.join("."));
     let path: Vec<&str> = field_expr.path.iter().map(|s| s.as_str()).collect();
 
-    model.get_field(&path).ok_or_else(|| {
+    let value = model
+        .get_field(&path)
+        .or_else(|| {
+            shared
+                .filter(|_| path.len() > 1)
+                .and_then(|shared| shared.get_field(&path))
+        })
+        .or_else(|| match path.as_slice() {
+            ["system", field @ ..] => crate::system::get_field(field),
+            ["env", field @ ..] => crate::env::get_field(field),
+            _ => None,
+        });
+
+    value.ok_or_else(|| {
         let field_name = field_expr.path.join(".");
         BindingError {
             kind: BindingErrorKind::UnknownField,
@@ -73,6 +101,9 @@ fn evaluate_shared_field_access(
     shared_expr: &SharedFieldAccessExpr,
     shared: Option<&dyn UiBindable>,
 ) -> Result<BindingValue, BindingError> {
+    coverage::record(HitKind::Binding, || {
+        format!("shared.{}", shared_expr.path.join("."))
+    });
     let Some(shared_ctx) = shared else {
         return Ok(BindingValue::String(String::new()));
     };
@@ -99,30 +130,61 @@ fn evaluate_method_call(
     let receiver = evaluate_expr_with_shared(&method_expr.receiver, model, shared)?;
     let method = &method_expr.method;
 
-    let _args: Vec<BindingValue> = method_expr
+    let args: Vec<BindingValue> = method_expr
         .args
         .iter()
         .map(|arg| evaluate_expr_with_shared(arg, model, shared))
         .collect::<Result<Vec<_>, _>>()?;
 
-    evaluate_method(receiver, method)
+    evaluate_method(receiver, method, &args)
 }
 
 /// Unified method evaluation for both model and shared contexts
-fn evaluate_method(receiver: BindingValue, method: &str) -> Result<BindingValue, BindingError> {
-    match (receiver.clone(), method) {
-        (BindingValue::String(s), "len") => Ok(BindingValue::Integer(s.len() as i64)),
-        (BindingValue::String(s), "to_uppercase") => Ok(BindingValue::String(s.to_uppercase())),
-        (BindingValue::String(s), "to_lowercase") => Ok(BindingValue::String(s.to_lowercase())),
-        (BindingValue::String(s), "trim") => Ok(BindingValue::String(s.trim().to_string())),
-        (BindingValue::List(l), "len") => Ok(BindingValue::Integer(l.len() as i64)),
-        (BindingValue::List(l), "is_empty") => Ok(BindingValue::Bool(l.is_empty())),
-        (BindingValue::Integer(i), "to_string") => Ok(BindingValue::String(i.to_string())),
-        (BindingValue::Float(f), "to_string") => Ok(BindingValue::String(f.to_string())),
-        (BindingValue::Float(f), "round") => Ok(BindingValue::Float(f.round())),
-        (BindingValue::Float(f), "floor") => Ok(BindingValue::Float(f.floor())),
-        (BindingValue::Float(f), "ceil") => Ok(BindingValue::Float(f.ceil())),
-        (BindingValue::Bool(b), "to_string") => Ok(BindingValue::String(b.to_string())),
+fn evaluate_method(
+    receiver: BindingValue,
+    method: &str,
+    args: &[BindingValue],
+) -> Result<BindingValue, BindingError> {
+    match (receiver.clone(), method, args) {
+        // `selection.contains(item.id)`: ids may be numbers or their text
+        (BindingValue::List(l), "contains", [id]) => Ok(BindingValue::Bool(l.iter().any(|item| {
+            values_equal(item, id) || item.to_display_string() == id.to_display_string()
+        }))),
+        (BindingValue::String(s), "contains", [part]) => {
+            Ok(BindingValue::Bool(s.contains(&part.to_display_string())))
+        }
+
+        (BindingValue::String(s), "len", []) => Ok(BindingValue::Integer(s.len() as i64)),
+        (BindingValue::String(s), "to_uppercase", []) => Ok(BindingValue::String(s.to_uppercase())),
+        (BindingValue::String(s), "to_lowercase", []) => Ok(BindingValue::String(s.to_lowercase())),
+        (BindingValue::String(s), "trim", []) => Ok(BindingValue::String(s.trim().to_string())),
+        (BindingValue::List(l), "len", []) => Ok(BindingValue::Integer(l.len() as i64)),
+        (BindingValue::List(l), "is_empty", []) => Ok(BindingValue::Bool(l.is_empty())),
+        (BindingValue::Integer(i), "to_string", []) => Ok(BindingValue::String(i.to_string())),
+        (BindingValue::Float(f), "to_string", []) => Ok(BindingValue::String(f.to_string())),
+        (BindingValue::Float(f), "round", []) => Ok(BindingValue::Float(f.round())),
+        (BindingValue::Float(f), "floor", []) => Ok(BindingValue::Float(f.floor())),
+        (BindingValue::Float(f), "ceil", []) => Ok(BindingValue::Float(f.ceil())),
+        (BindingValue::Bool(b), "to_string", []) => Ok(BindingValue::String(b.to_string())),
+        (BindingValue::DateTime(dt), "year", []) => Ok(BindingValue::Integer(dt.year() as i64)),
+        (BindingValue::DateTime(dt), "month", []) => Ok(BindingValue::Integer(dt.month() as i64)),
+        (BindingValue::DateTime(dt), "day", []) => Ok(BindingValue::Integer(dt.day() as i64)),
+        (BindingValue::DateTime(dt), "hour", []) => Ok(BindingValue::Integer(dt.hour() as i64)),
+        (BindingValue::DateTime(dt), "minute", []) => Ok(BindingValue::Integer(dt.minute() as i64)),
+        (BindingValue::DateTime(dt), "second", []) => Ok(BindingValue::Integer(dt.second() as i64)),
+        (BindingValue::DateTime(dt), "timestamp", []) => Ok(BindingValue::Integer(dt.timestamp())),
+        (BindingValue::Duration(d), "num_days", []) => Ok(BindingValue::Integer(d.num_days())),
+        (BindingValue::Duration(d), "num_hours", []) => Ok(BindingValue::Integer(d.num_hours())),
+        (BindingValue::Duration(d), "num_minutes", []) => {
+            Ok(BindingValue::Integer(d.num_minutes()))
+        }
+        (BindingValue::Duration(d), "num_seconds", []) => {
+            Ok(BindingValue::Integer(d.num_seconds()))
+        }
+        (BindingValue::Decimal(d), "to_string", []) => Ok(BindingValue::String(d.to_string())),
+        (BindingValue::Decimal(d), "round", []) => Ok(BindingValue::Decimal(d.round())),
+        (BindingValue::Decimal(d), "floor", []) => Ok(BindingValue::Decimal(d.floor())),
+        (BindingValue::Decimal(d), "ceil", []) => Ok(BindingValue::Decimal(d.ceil())),
         _ => Err(BindingError {
             kind: BindingErrorKind::UnknownMethod,
             message: format!("Method '{}' not supported on {:?}", method, receiver),
@@ -146,51 +208,104 @@ fn evaluate_binary_op(
         BinaryOp::Sub => evaluate_sub(left, right),
         BinaryOp::Mul => evaluate_mul(left, right),
         BinaryOp::Div => evaluate_div(left, right),
-        BinaryOp::Eq => Ok(BindingValue::Bool(left == right)),
-        BinaryOp::Ne => Ok(BindingValue::Bool(left != right)),
-        BinaryOp::Lt => Ok(BindingValue::Bool(compare_values(&left, &right, |a, b| {
-            a < b
-        }))),
-        BinaryOp::Le => Ok(BindingValue::Bool(compare_values(&left, &right, |a, b| {
-            a <= b
-        }))),
-        BinaryOp::Gt => Ok(BindingValue::Bool(compare_values(&left, &right, |a, b| {
-            a > b
-        }))),
-        BinaryOp::Ge => Ok(BindingValue::Bool(compare_values(&left, &right, |a, b| {
-            a >= b
-        }))),
+        BinaryOp::Eq => Ok(BindingValue::Bool(values_equal(&left, &right))),
+        BinaryOp::Ne => Ok(BindingValue::Bool(!values_equal(&left, &right))),
+        BinaryOp::Lt => Ok(BindingValue::Bool(compare_values(
+            &left,
+            &right,
+            Ordering::is_lt,
+        ))),
+        BinaryOp::Le => Ok(BindingValue::Bool(compare_values(
+            &left,
+            &right,
+            Ordering::is_le,
+        ))),
+        BinaryOp::Gt => Ok(BindingValue::Bool(compare_values(
+            &left,
+            &right,
+            Ordering::is_gt,
+        ))),
+        BinaryOp::Ge => Ok(BindingValue::Bool(compare_values(
+            &left,
+            &right,
+            Ordering::is_ge,
+        ))),
         BinaryOp::And => Ok(BindingValue::Bool(left.to_bool() && right.to_bool())),
         BinaryOp::Or => Ok(BindingValue::Bool(left.to_bool() || right.to_bool())),
     }
 }
 
 fn evaluate_add(left: BindingValue, right: BindingValue) -> Result<BindingValue, BindingError> {
+    if let Some((a, b)) = decimal_operands(&left, &right) {
+        return checked(a.checked_add(b).map(BindingValue::Decimal), "add");
+    }
     match (left, right) {
         (BindingValue::Integer(a), BindingValue::Integer(b)) => Ok(BindingValue::Integer(a + b)),
         (BindingValue::Float(a), BindingValue::Float(b)) => Ok(BindingValue::Float(a + b)),
         (BindingValue::String(a), BindingValue::String(b)) => Ok(BindingValue::String(a + &b)),
+        (BindingValue::DateTime(dt), BindingValue::Duration(d))
+        | (BindingValue::Duration(d), BindingValue::DateTime(dt)) => {
+            checked(dt.checked_add_signed(d).map(BindingValue::DateTime), "add")
+        }
+        (BindingValue::Duration(a), BindingValue::Duration(b)) => {
+            checked(a.checked_add(&b).map(BindingValue::Duration), "add")
+        }
         _ => Err(invalid_operation_error("add")),
     }
 }
 
 fn evaluate_sub(left: BindingValue, right: BindingValue) -> Result<BindingValue, BindingError> {
+    if let Some((a, b)) = decimal_operands(&left, &right) {
+        return checked(a.checked_sub(b).map(BindingValue::Decimal), "subtract");
+    }
     match (left, right) {
         (BindingValue::Integer(a), BindingValue::Integer(b)) => Ok(BindingValue::Integer(a - b)),
         (BindingValue::Float(a), BindingValue::Float(b)) => Ok(BindingValue::Float(a - b)),
+        (BindingValue::DateTime(a), BindingValue::DateTime(b)) => {
+            Ok(BindingValue::Duration(a.signed_duration_since(b)))
+        }
+        (BindingValue::DateTime(dt), BindingValue::Duration(d)) => checked(
+            dt.checked_sub_signed(d).map(BindingValue::DateTime),
+            "subtract",
+        ),
+        (BindingValue::Duration(a), BindingValue::Duration(b)) => {
+            checked(a.checked_sub(&b).map(BindingValue::Duration), "subtract")
+        }
         _ => Err(invalid_operation_error("subtract")),
     }
 }
 
 fn evaluate_mul(left: BindingValue, right: BindingValue) -> Result<BindingValue, BindingError> {
+    if let Some((a, b)) = decimal_operands(&left, &right) {
+        return checked(a.checked_mul(b).map(BindingValue::Decimal), "multiply");
+    }
     match (left, right) {
         (BindingValue::Integer(a), BindingValue::Integer(b)) => Ok(BindingValue::Integer(a * b)),
         (BindingValue::Float(a), BindingValue::Float(b)) => Ok(BindingValue::Float(a * b)),
+        (BindingValue::Duration(d), BindingValue::Integer(n))
+        | (BindingValue::Integer(n), BindingValue::Duration(d)) => checked(
+            i32::try_from(n)
+                .ok()
+                .and_then(|n| d.checked_mul(n))
+                .map(BindingValue::Duration),
+            "multiply",
+        ),
         _ => Err(invalid_operation_error("multiply")),
     }
 }
 
 fn evaluate_div(left: BindingValue, right: BindingValue) -> Result<BindingValue, BindingError> {
+    if let Some((a, b)) = decimal_operands(&left, &right) {
+        if b.is_zero() {
+            return Err(BindingError {
+                kind: BindingErrorKind::InvalidOperation,
+                message: "Division by zero".to_string(),
+                span: crate::ir::span::Span::new(0, 0, 0, 0),
+                suggestion: None,
+            });
+        }
+        return checked(a.checked_div(b).map(BindingValue::Decimal), "divide");
+    }
     match (left, right) {
         (BindingValue::Integer(a), BindingValue::Integer(b)) if b != 0 => {
             Ok(BindingValue::Integer(a / b))
@@ -210,6 +325,19 @@ fn evaluate_div(left: BindingValue, right: BindingValue) -> Result<BindingValue,
             span: crate::ir::span::Span::new(0, 0, 0, 0),
             suggestion: None,
         }),
+        (BindingValue::Duration(_), BindingValue::Integer(0)) => Err(BindingError {
+            kind: BindingErrorKind::InvalidOperation,
+            message: "Division by zero".to_string(),
+            span: crate::ir::span::Span::new(0, 0, 0, 0),
+            suggestion: None,
+        }),
+        (BindingValue::Duration(d), BindingValue::Integer(n)) => checked(
+            i32::try_from(n)
+                .ok()
+                .and_then(|n| d.checked_div(n))
+                .map(BindingValue::Duration),
+            "divide",
+        ),
         _ => Err(invalid_operation_error("divide")),
     }
 }
@@ -223,23 +351,57 @@ fn invalid_operation_error(operation: &str) -> BindingError {
     }
 }
 
+/// Result of a checked operation, or an out-of-range error
+fn checked(result: Option<BindingValue>, operation: &str) -> Result<BindingValue, BindingError> {
+    result.ok_or_else(|| BindingError {
+        kind: BindingErrorKind::InvalidOperation,
+        message: format!("Cannot {}: result out of range", operation),
+        span: crate::ir::span::Span::new(0, 0, 0, 0),
+        suggestion: None,
+    })
+}
+
+/// Both operands as decimals, when one is a decimal and the other a decimal or integer
+fn decimal_operands(left: &BindingValue, right: &BindingValue) -> Option<(Decimal, Decimal)> {
+    match (left, right) {
+        (BindingValue::Decimal(a), BindingValue::Decimal(b)) => Some((*a, *b)),
+        (BindingValue::Decimal(a), BindingValue::Integer(b)) => Some((*a, Decimal::from(*b))),
+        (BindingValue::Integer(a), BindingValue::Decimal(b)) => Some((Decimal::from(*a), *b)),
+        _ => None,
+    }
+}
+
+/// Equality for `==` and `!=`, comparing decimals and integers by value
+fn values_equal(left: &BindingValue, right: &BindingValue) -> bool {
+    match decimal_operands(left, right) {
+        Some((a, b)) => a == b,
+        None => left == right,
+    }
+}
+
 /// Helper for comparison operations
 ///
-/// Compares values by their numeric representation for ordering operations
-/// (`<`, `<=`, `>`, `>=`). Objects (HashMap) are not compared as they have
-/// no natural ordering - such comparisons return `false`.
+/// Orders numbers, dates, durations and decimals by value, and strings and lists
+/// by length, for ordering operations (`<`, `<=`, `>`, `>=`). Objects (HashMap)
+/// are not compared as they have no natural ordering - such comparisons return `false`.
 fn compare_values<F>(left: &BindingValue, right: &BindingValue, cmp: F) -> bool
 where
-    F: Fn(f64, f64) -> bool,
+    F: Fn(Ordering) -> bool,
 {
-    match (left, right) {
-        (BindingValue::Integer(a), BindingValue::Integer(b)) => cmp(*a as f64, *b as f64),
-        (BindingValue::Float(a), BindingValue::Float(b)) => cmp(*a, *b),
-        (BindingValue::String(a), BindingValue::String(b)) => cmp(a.len() as f64, b.len() as f64),
-        (BindingValue::List(a), BindingValue::List(b)) => cmp(a.len() as f64, b.len() as f64),
-        (BindingValue::Object(_), _) | (_, BindingValue::Object(_)) => false,
-        _ => false,
+    if let Some((a, b)) = decimal_operands(left, right) {
+        return cmp(a.cmp(&b));
     }
+    let ordering = match (left, right) {
+        (BindingValue::Integer(a), BindingValue::Integer(b)) => Some(a.cmp(b)),
+        (BindingValue::Float(a), BindingValue::Float(b)) => a.partial_cmp(b),
+        (BindingValue::String(a), BindingValue::String(b)) => Some(a.len().cmp(&b.len())),
+        (BindingValue::List(a), BindingValue::List(b)) => Some(a.len().cmp(&b.len())),
+        (BindingValue::DateTime(a), BindingValue::DateTime(b)) => Some(a.cmp(b)),
+        (BindingValue::Duration(a), BindingValue::Duration(b)) => Some(a.cmp(b)),
+        (BindingValue::Object(_), _) | (_, BindingValue::Object(_)) => None,
+        _ => None,
+    };
+    ordering.is_some_and(cmp)
 }
 
 /// Evaluate unary operation: `!valid` or `-offset`
@@ -255,6 +417,8 @@ fn evaluate_unary_op(
         UnaryOp::Neg => match operand {
             BindingValue::Integer(i) => Ok(BindingValue::Integer(-i)),
             BindingValue::Float(f) => Ok(BindingValue::Float(-f)),
+            BindingValue::Duration(d) => Ok(BindingValue::Duration(-d)),
+            BindingValue::Decimal(d) => Ok(BindingValue::Decimal(-d)),
             _ => Err(BindingError {
                 kind: BindingErrorKind::InvalidOperation,
                 message: "Cannot negate this type".to_string(),
@@ -280,6 +444,22 @@ fn evaluate_conditional(
     }
 }
 
+/// Evaluate filter: `created | date("%Y-%m-%d")`
+fn evaluate_filter(
+    filter_expr: &FilterExpr,
+    model: &dyn UiBindable,
+    shared: Option<&dyn UiBindable>,
+) -> Result<BindingValue, BindingError> {
+    let input = evaluate_expr_with_shared(&filter_expr.input, model, shared)?;
+    let args: Vec<BindingValue> = filter_expr
+        .args
+        .iter()
+        .map(|arg| evaluate_expr_with_shared(arg, model, shared))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    apply_filter(&input, &filter_expr.name, &args)
+}
+
 /// Evaluate literal value
 fn evaluate_literal(literal_expr: &LiteralExpr) -> BindingValue {
     match literal_expr {
@@ -0,0 +1,469 @@
+//! Formatting filters for binding expressions
+//!
+//! Filters post-process a binding value with the `|` operator. Formatting
+//! filters produce a string:
+//!
+//! - `{created | date}` / `{created | date("%d %B %Y")}` - format a date (`%Y-%m-%d` by default)
+//! - `{created | time}` / `{created | time("%H:%M:%S")}` - format a time of day (`%H:%M` by default)
+//! - `{elapsed | duration}` - human-readable duration, e.g. `1h 5m 30s`
+//! - `{price | decimal(2)}` - number with a fixed count of decimal places (2 by default)
+//!
+//! `filter_by` narrows a list for a `<for>` loop, typically with the query of a
+//! search input:
+//!
+//! - `{items | filter_by(query, "name")}` - items whose `name` contains `query`,
+//!   ignoring case (see [`matches_query`]); without a field, items are matched
+//!   on their display string
+//!
+//! Date patterns use the `strftime` syntax of `chrono`.
+//!
+//! Widgets displaying their own value (e.g. `<slider show_value="true">`) take a
+//! filter call without input in a `value_format` attribute, parsed as a [`ValueFormat`].
+
+use chrono::format::StrftimeItems;
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::binding::BindingValue;
+use crate::expr::error::{BindingError, BindingErrorKind};
+use crate::expr::{Expr, LiteralExpr, parse_binding_expr};
+
+/// Names of the built-in filters
+pub const FILTERS: &[&str] = &["date", "time", "duration", "decimal", "filter_by"];
+
+/// Apply the filter `name` to `value`
+///
+/// # Errors
+///
+/// Returns an error if the filter is unknown, or if `value` or `args` have
+/// types the filter does not accept.
+///
+/// # Example
+///
+/// ```rust
+/// use dampen_core::{BindingValue, ToBindingValue, apply_filter};
+///
+/// let date = chrono::NaiveDate::from_ymd_opt(2024, 3, 9)
+///     .map(|d| d.to_binding_value())
+///     .unwrap_or(BindingValue::None);
+/// let formatted = apply_filter(&date, "date", &[BindingValue::String("%d/%m/%Y".into())]);
+/// assert_eq!(formatted, Ok(BindingValue::String("09/03/2024".to_string())));
+/// ```
+pub fn apply_filter(
+    value: &BindingValue,
+    name: &str,
+    args: &[BindingValue],
+) -> Result<BindingValue, BindingError> {
+    match name {
+        "date" => format_date(name, value, args, "%Y-%m-%d"),
+        "time" => format_date(name, value, args, "%H:%M"),
+        "duration" => format_duration(value),
+        "decimal" => format_decimal(value, args),
+        "filter_by" => filter_list(value, args),
+        _ => Err(filter_error(
+            BindingErrorKind::UnknownFilter,
+            format!("Unknown filter '{}'", name),
+            Some(format!("Available filters: {}", FILTERS.join(", "))),
+        )),
+    }
+}
+
+/// A filter applied to the value a widget displays, e.g. `value_format="decimal(1)"`
+///
+/// # Example
+///
+/// ```rust
+/// use dampen_core::{BindingValue, ValueFormat};
+///
+/// let format = ValueFormat::parse("decimal(1)").unwrap_or_default();
+/// assert_eq!(format.apply(&BindingValue::Float(0.25)), "0.3");
+/// ```
+///
+/// The default format applies no filter.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValueFormat {
+    /// Filter name, one of [`FILTERS`] (empty for no filter)
+    pub name: String,
+    /// Literal arguments of the filter
+    pub args: Vec<BindingValue>,
+}
+
+impl ValueFormat {
+    /// Parse a filter call such as `decimal` or `decimal(2)`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `spec` is not a single call of a known filter with
+    /// literal arguments.
+    pub fn parse(spec: &str) -> Result<Self, BindingError> {
+        let invalid = || {
+            filter_error(
+                BindingErrorKind::InvalidSyntax,
+                format!("Invalid value format '{}'", spec),
+                Some("Use a filter call such as \"decimal(1)\"".to_string()),
+            )
+        };
+
+        let binding =
+            parse_binding_expr(&format!("value | {}", spec), 0, 1, 1).map_err(|_| invalid())?;
+        let Expr::Filter(filter) = binding.expr else {
+            return Err(invalid());
+        };
+        if !matches!(*filter.input, Expr::FieldAccess(_)) {
+            return Err(invalid());
+        }
+        if !FILTERS.contains(&filter.name.as_str()) {
+            return Err(filter_error(
+                BindingErrorKind::UnknownFilter,
+                format!("Unknown filter '{}'", filter.name),
+                Some(format!("Available filters: {}", FILTERS.join(", "))),
+            ));
+        }
+
+        let args = filter
+            .args
+            .iter()
+            .map(|arg| match arg {
+                Expr::Literal(LiteralExpr::String(s)) => Ok(BindingValue::String(s.clone())),
+                Expr::Literal(LiteralExpr::Integer(i)) => Ok(BindingValue::Integer(*i)),
+                Expr::Literal(LiteralExpr::Float(f)) => Ok(BindingValue::Float(*f)),
+                Expr::Literal(LiteralExpr::Bool(b)) => Ok(BindingValue::Bool(*b)),
+                _ => Err(invalid()),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            name: filter.name,
+            args,
+        })
+    }
+
+    /// Format `value`, falling back to its plain display string if the filter
+    /// does not apply to it
+    pub fn apply(&self, value: &BindingValue) -> String {
+        if self.name.is_empty() {
+            return value.to_display_string();
+        }
+        apply_filter(value, &self.name, &self.args)
+            .map(|formatted| formatted.to_display_string())
+            .unwrap_or_else(|_| value.to_display_string())
+    }
+}
+
+/// Whether `item` matches the search `query`
+///
+/// The text of `field` (or of the item itself without a field) must contain the
+/// query, ignoring case and surrounding whitespace. An empty query matches
+/// every item.
+///
+/// # Example
+///
+/// ```rust
+/// use dampen_core::{BindingValue, matches_query};
+///
+/// let item = BindingValue::String("Grace Hopper".to_string());
+/// assert!(matches_query(&item, "hop", None));
+/// assert!(!matches_query(&item, "ada", None));
+/// assert!(matches_query(&item, " ", None));
+/// ```
+pub fn matches_query(item: &BindingValue, query: &str, field: Option<&str>) -> bool {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return true;
+    }
+    let text = match field {
+        Some(field) => match item.get_field(field) {
+            Some(value) => value.to_display_string(),
+            None => return false,
+        },
+        None => item.to_display_string(),
+    };
+    text.to_lowercase().contains(&query)
+}
+
+/// `filter_by(query, field)`: the items of a list matching `query`
+fn filter_list(value: &BindingValue, args: &[BindingValue]) -> Result<BindingValue, BindingError> {
+    let BindingValue::List(items) = value else {
+        return Err(type_error("filter_by", "a list", value));
+    };
+    let query = match args.first() {
+        Some(query) => query.to_display_string(),
+        None => {
+            return Err(filter_error(
+                BindingErrorKind::InvalidOperation,
+                "Filter 'filter_by' expects a query argument".to_string(),
+                Some("Use filter_by(query, \"field\")".to_string()),
+            ));
+        }
+    };
+    let field = match args.get(1) {
+        Some(BindingValue::String(field)) => Some(field.as_str()),
+        Some(other) => return Err(type_error("filter_by", "a field name argument", other)),
+        None => None,
+    };
+
+    Ok(BindingValue::List(
+        items
+            .iter()
+            .filter(|item| matches_query(item, &query, field))
+            .cloned()
+            .collect(),
+    ))
+}
+
+/// `date` and `time`: format a date with an optional `strftime` pattern
+fn format_date(
+    name: &str,
+    value: &BindingValue,
+    args: &[BindingValue],
+    default_pattern: &str,
+) -> Result<BindingValue, BindingError> {
+    let BindingValue::DateTime(datetime) = value else {
+        return Err(type_error(name, "a date", value));
+    };
+    let pattern = match args.first() {
+        Some(BindingValue::String(pattern)) => pattern.as_str(),
+        Some(other) => return Err(type_error(name, "a format string argument", other)),
+        None => default_pattern,
+    };
+
+    // Validate the pattern up front: formatting an invalid one panics
+    let items = StrftimeItems::new(pattern).parse().map_err(|_| {
+        filter_error(
+            BindingErrorKind::InvalidOperation,
+            format!("Invalid date format '{}'", pattern),
+            None,
+        )
+    })?;
+
+    Ok(BindingValue::String(
+        datetime.format_with_items(items.iter()).to_string(),
+    ))
+}
+
+/// `duration`: largest units first, e.g. `2d 3h`, `1h 5m 30s` or `0s`
+fn format_duration(value: &BindingValue) -> Result<BindingValue, BindingError> {
+    let BindingValue::Duration(duration) = value else {
+        return Err(type_error("duration", "a duration", value));
+    };
+
+    let total = duration.num_seconds().unsigned_abs();
+    let units = [
+        (total / 86_400, "d"),
+        ((total / 3600) % 24, "h"),
+        ((total / 60) % 60, "m"),
+        (total % 60, "s"),
+    ];
+    let mut parts: Vec<String> = units
+        .iter()
+        .filter(|(amount, _)| *amount > 0)
+        .map(|(amount, unit)| format!("{}{}", amount, unit))
+        .collect();
+    if parts.is_empty() {
+        parts.push("0s".to_string());
+    }
+
+    let sign = if duration.num_seconds() < 0 { "-" } else { "" };
+    Ok(BindingValue::String(format!("{}{}", sign, parts.join(" "))))
+}
+
+/// `decimal(places)`: round half away from zero and pad to `places` decimals
+fn format_decimal(
+    value: &BindingValue,
+    args: &[BindingValue],
+) -> Result<BindingValue, BindingError> {
+    let places = match args.first() {
+        Some(BindingValue::Integer(places)) if (0..=28).contains(places) => *places as u32,
+        Some(other) => return Err(type_error("decimal", "a place count from 0 to 28", other)),
+        None => 2,
+    };
+
+    let decimal = match value {
+        BindingValue::Decimal(d) => *d,
+        BindingValue::Integer(i) => Decimal::from(*i),
+        BindingValue::Float(f) => {
+            Decimal::try_from(*f).map_err(|_| type_error("decimal", "a finite number", value))?
+        }
+        _ => return Err(type_error("decimal", "a number", value)),
+    };
+
+    let rounded = decimal.round_dp_with_strategy(places, RoundingStrategy::MidpointAwayFromZero);
+    Ok(BindingValue::String(format!(
+        "{:.*}",
+        places as usize, rounded
+    )))
+}
+
+fn type_error(filter: &str, expected: &str, got: &BindingValue) -> BindingError {
+    filter_error(
+        BindingErrorKind::TypeMismatch,
+        format!(
+            "Filter '{}' expects {}, got '{}'",
+            filter,
+            expected,
+            got.to_display_string()
+        ),
+        None,
+    )
+}
+
+fn filter_error(
+    kind: BindingErrorKind,
+    message: String,
+    suggestion: Option<String>,
+) -> BindingError {
+    BindingError {
+        kind,
+        message,
+        span: crate::ir::span::Span::new(0, 0, 0, 0),
+        suggestion,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binding::{ToBindingValue, UiBindable};
+    use crate::expr::{evaluate_binding_expr, tokenize_binding_expr};
+
+    struct Order {
+        placed: chrono::DateTime<chrono::FixedOffset>,
+        delivered: chrono::DateTime<chrono::FixedOffset>,
+        price: Decimal,
+    }
+
+    impl UiBindable for Order {
+        fn get_field(&self, path: &[&str]) -> Option<BindingValue> {
+            match path {
+                ["placed"] => Some(self.placed.to_binding_value()),
+                ["delivered"] => Some(self.delivered.to_binding_value()),
+                ["price"] => Some(self.price.to_binding_value()),
+                _ => None,
+            }
+        }
+
+        fn available_fields() -> Vec<String> {
+            vec![
+                "placed".to_string(),
+                "delivered".to_string(),
+                "price".to_string(),
+            ]
+        }
+    }
+
+    fn order() -> Result<Order, String> {
+        let parse = |s: &str| chrono::DateTime::parse_from_rfc3339(s).map_err(|e| e.to_string());
+        Ok(Order {
+            placed: parse("2024-03-09T10:15:00+01:00")?,
+            delivered: parse("2024-03-11T12:45:30+01:00")?,
+            price: Decimal::new(12345, 3),
+        })
+    }
+
+    fn eval(expr: &str, model: &Order) -> Result<BindingValue, String> {
+        let binding = tokenize_binding_expr(expr, 0, 1, 1)?;
+        evaluate_binding_expr(&binding, model).map_err(|e| e.message)
+    }
+
+    fn string(s: &str) -> BindingValue {
+        BindingValue::String(s.to_string())
+    }
+
+    #[test]
+    fn test_date_filters() -> Result<(), String> {
+        let model = order()?;
+
+        assert_eq!(eval("placed | date", &model)?, string("2024-03-09"));
+        assert_eq!(
+            eval("placed | date(\"%d/%m/%Y %H:%M\")", &model)?,
+            string("09/03/2024 10:15")
+        );
+        assert_eq!(eval("placed | time", &model)?, string("10:15"));
+        assert!(eval("placed | date(\"%Q\")", &model).is_err());
+        assert!(eval("price | date", &model).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_duration_and_decimal_filters() -> Result<(), String> {
+        let model = order()?;
+
+        assert_eq!(
+            eval("delivered - placed | duration", &model)?,
+            string("2d 2h 30m 30s")
+        );
+        assert_eq!(eval("placed - placed | duration", &model)?, string("0s"));
+        assert_eq!(eval("price | decimal", &model)?, string("12.35"));
+        assert_eq!(eval("price | decimal(0)", &model)?, string("12"));
+        assert_eq!(eval("3 | decimal(1)", &model)?, string("3.0"));
+
+        let unknown = eval("price | currency", &model);
+        assert_eq!(unknown, Err("Unknown filter 'currency'".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_by() -> Result<(), String> {
+        let person = |name: &str| {
+            BindingValue::Object([("name".to_string(), string(name))].into_iter().collect())
+        };
+        let people = BindingValue::List(vec![person("Ada"), person("Grace"), person("Adele")]);
+        let filter =
+            |args: &[BindingValue]| apply_filter(&people, "filter_by", args).map_err(|e| e.message);
+
+        assert_eq!(
+            filter(&[string("AD"), string("name")])?,
+            BindingValue::List(vec![person("Ada"), person("Adele")])
+        );
+        assert_eq!(filter(&[string(""), string("name")])?, people);
+        assert_eq!(
+            filter(&[string("ada"), string("age")])?,
+            BindingValue::List(Vec::new())
+        );
+        assert!(filter(&[]).is_err());
+        assert!(apply_filter(&string("Ada"), "filter_by", &[string("a")]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_value_format() -> Result<(), String> {
+        let format = ValueFormat::parse("decimal(2)").map_err(|e| e.message)?;
+        assert_eq!(format.name, "decimal");
+        assert_eq!(format.args, vec![BindingValue::Integer(2)]);
+        assert_eq!(format.apply(&BindingValue::Float(0.125)), "0.13");
+        // A value the filter does not accept is displayed as is
+        assert_eq!(format.apply(&string("n/a")), "n/a");
+        assert_eq!(
+            ValueFormat::default().apply(&BindingValue::Float(0.5)),
+            "0.5"
+        );
+
+        for invalid in ["", "decimal(", "decimal(places)", "decimal | date", "1 + 2"] {
+            assert!(ValueFormat::parse(invalid).is_err(), "{}", invalid);
+        }
+        let unknown = ValueFormat::parse("percent").map_err(|e| e.kind);
+        assert_eq!(unknown, Err(BindingErrorKind::UnknownFilter));
+        Ok(())
+    }
+
+    #[test]
+    fn test_temporal_and_decimal_operations() -> Result<(), String> {
+        let model = order()?;
+
+        assert_eq!(
+            eval("placed < delivered", &model)?,
+            BindingValue::Bool(true)
+        );
+        assert_eq!(
+            eval("delivered - placed > placed - placed", &model)?,
+            BindingValue::Bool(true)
+        );
+        assert_eq!(
+            eval("price * 2", &model)?,
+            BindingValue::Decimal(Decimal::new(24690, 3))
+        );
+        assert_eq!(eval("price > 12", &model)?, BindingValue::Bool(true));
+        assert_eq!(eval("placed.year()", &model)?, BindingValue::Integer(2024));
+        assert!(eval("price / 0", &model).is_err());
+        Ok(())
+    }
+}
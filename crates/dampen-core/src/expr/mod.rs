@@ -1,15 +1,17 @@
 pub mod ast;
 pub mod error;
 pub mod eval;
+pub mod filters;
 pub mod tokenizer;
 
 pub use ast::{
-    BinaryOp, BinaryOpExpr, BindingExpr, ConditionalExpr, Expr, FieldAccessExpr, LiteralExpr,
-    MethodCallExpr, SharedFieldAccessExpr, UnaryOp, UnaryOpExpr,
+    BinaryOp, BinaryOpExpr, BindingExpr, ConditionalExpr, Expr, FieldAccessExpr, FilterExpr,
+    LiteralExpr, MethodCallExpr, SharedFieldAccessExpr, UnaryOp, UnaryOpExpr,
 };
 pub use error::{BindingError, BindingErrorKind};
 pub use eval::{
     evaluate_binding_expr, evaluate_binding_expr_with_shared, evaluate_expr,
     evaluate_expr_with_shared, evaluate_formatted, evaluate_formatted_with_shared,
 };
-pub use tokenizer::tokenize_binding_expr;
+pub use filters::{ValueFormat, apply_filter, matches_query};
+pub use tokenizer::{parse_binding_expr, tokenize_binding_expr};
@@ -2,8 +2,9 @@
 #![allow(dead_code)]
 
 use crate::expr::{
-    BinaryOp, BinaryOpExpr, BindingExpr, ConditionalExpr, Expr, FieldAccessExpr, LiteralExpr,
-    MethodCallExpr, SharedFieldAccessExpr, UnaryOp, UnaryOpExpr,
+    BinaryOp, BinaryOpExpr, BindingError, BindingErrorKind, BindingExpr, ConditionalExpr, Expr,
+    FieldAccessExpr, FilterExpr, LiteralExpr, MethodCallExpr, SharedFieldAccessExpr, UnaryOp,
+    UnaryOpExpr,
 };
 use crate::ir::span::Span;
 
@@ -14,10 +15,31 @@ pub fn tokenize_binding_expr(
     line: u32,
     column: u32,
 ) -> Result<BindingExpr, String> {
+    parse_binding_expr(input, start_pos, line, column).map_err(|error| error.message)
+}
+
+/// Parse a binding expression, locating syntax errors at the offending token
+///
+/// `start_pos`, `line` and `column` give the location of `input` in the source.
+pub fn parse_binding_expr(
+    input: &str,
+    start_pos: usize,
+    line: u32,
+    column: u32,
+) -> Result<BindingExpr, BindingError> {
     let mut parser = ExprParser::new(input, start_pos, line, column);
-    let expr = parser.parse()?;
-    let span = Span::new(start_pos, start_pos + input.len(), line, column);
-    Ok(BindingExpr { expr, span })
+    match parser.parse() {
+        Ok(expr) => Ok(BindingExpr {
+            expr,
+            span: Span::new(start_pos, start_pos + input.len(), line, column),
+        }),
+        Err(message) => Err(BindingError {
+            kind: BindingErrorKind::InvalidSyntax,
+            message,
+            span: parser.token_span(),
+            suggestion: None,
+        }),
+    }
 }
 
 struct ExprParser<'a> {
@@ -39,8 +61,75 @@ impl<'a> ExprParser<'a> {
         }
     }
 
+    /// Span of the token at the current position, where parsing stopped
+    fn token_span(&self) -> Span {
+        let rest = self.input.get(self.pos..).unwrap_or_default();
+        let token = rest.trim_start();
+        let start = self.input.len() - token.len();
+        let len = match token.chars().next() {
+            Some(c) if c.is_alphanumeric() || c == '_' => token
+                .find(|c: char| !c.is_alphanumeric() && c != '_')
+                .unwrap_or(token.len()),
+            Some(c) => c.len_utf8(),
+            None => 0,
+        };
+        let column = self.column + self.input[..start].chars().count() as u32;
+        Span::new(
+            self.start_pos + start,
+            self.start_pos + start + len,
+            self.line,
+            column,
+        )
+    }
+
     fn parse(&mut self) -> Result<Expr, String> {
-        self.parse_conditional()
+        let expr = self.parse_conditional()?;
+        self.parse_filters(expr)
+    }
+
+    /// Parse trailing filters: `created | date("%Y-%m-%d")`
+    fn parse_filters(&mut self, mut expr: Expr) -> Result<Expr, String> {
+        loop {
+            self.skip_whitespace();
+            if !self.peek_str("|") || self.peek_str("||") {
+                return Ok(expr);
+            }
+            self.consume_str("|")?;
+            self.skip_whitespace();
+            let name = self.parse_identifier()?;
+
+            let mut args = Vec::new();
+            self.skip_whitespace();
+            if self.peek_str("(") {
+                self.consume_str("(")?;
+
+                // Parse arguments
+                loop {
+                    self.skip_whitespace();
+                    if self.peek_str(")") {
+                        break;
+                    }
+
+                    let arg = self.parse_conditional()?;
+                    args.push(arg);
+
+                    self.skip_whitespace();
+                    if self.peek_str(",") {
+                        self.consume_str(",")?;
+                    } else {
+                        break;
+                    }
+                }
+
+                self.consume_str(")")?;
+            }
+
+            expr = Expr::Filter(FilterExpr {
+                input: Box::new(expr),
+                name,
+                args,
+            });
+        }
     }
 
     fn parse_conditional(&mut self) -> Result<Expr, String> {
@@ -54,9 +143,14 @@ impl<'a> ExprParser<'a> {
             self.skip_whitespace();
             let then_branch = self.parse()?;
             self.skip_whitespace();
-            self.consume_keyword("else")?;
-            self.skip_whitespace();
-            let else_branch = self.parse()?;
+            // Without `else`, the expression is an empty string when the condition is false
+            let else_branch = if self.peek_keyword("else") {
+                self.consume_keyword("else")?;
+                self.skip_whitespace();
+                self.parse()?
+            } else {
+                Expr::Literal(LiteralExpr::String(String::new()))
+            };
 
             return Ok(Expr::Conditional(ConditionalExpr {
                 condition: Box::new(condition),
@@ -0,0 +1,268 @@
+//! Formatting of `.dampen` markup
+//!
+//! The formatter re-indents a document by the nesting of its elements and
+//! removes trailing whitespace. It works on the text, so documents that do not
+//! parse are formatted too, and it never adds or removes lines: editors can map
+//! each formatted line back to the source.
+//!
+//! Attributes continued on the next lines are indented one level deeper than
+//! their element. Lines starting inside a comment, a CDATA section or a quoted
+//! attribute value are left as written, since their whitespace may matter.
+//!
+//! ```rust
+//! use dampen_core::format::{FormatOptions, format_document};
+//!
+//! let source = "<column>\n<text value=\"Hi\" />   \n</column>\n";
+//! let formatted = format_document(source, &FormatOptions::default());
+//! assert_eq!(formatted, "<column>\n    <text value=\"Hi\" />\n</column>\n");
+//! ```
+
+/// Formatting settings
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatOptions {
+    /// Indentation of one nesting level
+    pub indent: String,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            indent: "    ".to_string(),
+        }
+    }
+}
+
+/// Format a document, line by line
+///
+/// The result has the same lines as `source`, with the same line endings.
+pub fn format_document(source: &str, options: &FormatOptions) -> String {
+    let mut scanner = Scanner::default();
+    let mut formatted = String::with_capacity(source.len());
+
+    for line in source.split_inclusive('\n') {
+        let (text, ending) = split_line_ending(line);
+        formatted.push_str(&scanner.format_line(text, options));
+        formatted.push_str(ending);
+    }
+    formatted
+}
+
+/// Format each line of a document, without line endings
+///
+/// Line `n` of the result is line `n` of `source`, formatted.
+pub fn format_lines(source: &str, options: &FormatOptions) -> Vec<String> {
+    let mut scanner = Scanner::default();
+    source
+        .split_inclusive('\n')
+        .map(|line| scanner.format_line(split_line_ending(line).0, options))
+        .collect()
+}
+
+/// Split the `\n` or `\r\n` ending off a line
+fn split_line_ending(line: &str) -> (&str, &str) {
+    let text = line.strip_suffix('\n').unwrap_or(line);
+    let text = text.strip_suffix('\r').unwrap_or(text);
+    (text, &line[text.len()..])
+}
+
+/// Where the scanner is in the markup
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum State {
+    /// Between tags
+    #[default]
+    Content,
+    /// In a start tag opened at `depth`, in the value quoted by `quote` if any
+    StartTag { depth: usize, quote: Option<u8> },
+    /// In an end tag
+    EndTag,
+    /// In a comment
+    Comment,
+    /// In a CDATA section
+    CData,
+    /// In a processing instruction or declaration
+    Markup,
+}
+
+/// Nesting of the elements, tracked line by line
+#[derive(Debug, Default)]
+struct Scanner {
+    state: State,
+    depth: usize,
+}
+
+impl Scanner {
+    /// Format `line`, then move past it
+    fn format_line(&mut self, line: &str, options: &FormatOptions) -> String {
+        let trimmed = line.trim_start();
+        let indent = match self.state {
+            State::Content if trimmed.starts_with("</") => Some(self.depth.saturating_sub(1)),
+            State::Content => Some(self.depth),
+            State::StartTag { depth, quote: None } => {
+                if trimmed.starts_with('>') || trimmed.starts_with("/>") {
+                    Some(depth)
+                } else {
+                    Some(depth + 1)
+                }
+            }
+            State::StartTag { .. }
+            | State::EndTag
+            | State::Comment
+            | State::CData
+            | State::Markup => None,
+        };
+
+        self.scan(line);
+
+        // Trailing whitespace of a quoted value or a CDATA section is kept
+        let keeps_trailing = matches!(
+            self.state,
+            State::StartTag { quote: Some(_), .. } | State::CData
+        );
+        match indent {
+            None => line.to_string(),
+            Some(_) if trimmed.trim_end().is_empty() => String::new(),
+            Some(level) if keeps_trailing => options.indent.repeat(level) + trimmed,
+            Some(level) => options.indent.repeat(level) + trimmed.trim_end(),
+        }
+    }
+
+    /// Update the state and the depth with the markup of `line`
+    fn scan(&mut self, line: &str) {
+        let bytes = line.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            // Markup is ASCII, so comparing bytes never splits a character
+            let rest = &bytes[i..];
+            match self.state {
+                State::Content if rest.starts_with(b"<!--") => {
+                    self.state = State::Comment;
+                    i += 4;
+                    continue;
+                }
+                State::Content if rest.starts_with(b"<![CDATA[") => {
+                    self.state = State::CData;
+                    i += 9;
+                    continue;
+                }
+                State::Content if rest.starts_with(b"<?") || rest.starts_with(b"<!") => {
+                    self.state = State::Markup;
+                }
+                State::Content if rest.starts_with(b"</") => {
+                    self.state = State::EndTag;
+                    self.depth = self.depth.saturating_sub(1);
+                }
+                State::Content if rest.starts_with(b"<") => {
+                    self.state = State::StartTag {
+                        depth: self.depth,
+                        quote: None,
+                    };
+                    self.depth += 1;
+                }
+                State::StartTag { depth, quote: None } => match bytes[i] {
+                    quote @ (b'"' | b'\'') => {
+                        self.state = State::StartTag {
+                            depth,
+                            quote: Some(quote),
+                        };
+                    }
+                    b'/' if rest.starts_with(b"/>") => {
+                        self.state = State::Content;
+                        self.depth = depth;
+                        i += 2;
+                        continue;
+                    }
+                    b'>' => self.state = State::Content,
+                    _ => {}
+                },
+                State::StartTag {
+                    depth,
+                    quote: Some(quote),
+                } if bytes[i] == quote => {
+                    self.state = State::StartTag { depth, quote: None };
+                }
+                State::EndTag | State::Markup if bytes[i] == b'>' => {
+                    self.state = State::Content;
+                }
+                State::Comment if rest.starts_with(b"-->") => {
+                    self.state = State::Content;
+                    i += 3;
+                    continue;
+                }
+                State::CData if rest.starts_with(b"]]>") => {
+                    self.state = State::Content;
+                    i += 3;
+                    continue;
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(source: &str) -> String {
+        format_document(source, &FormatOptions::default())
+    }
+
+    #[test]
+    fn test_indents_by_nesting() {
+        let source = "<dampen>\n<column>\n  <row>\n<text value=\"a\"/>\n</row>\n        </column>\n</dampen>\n";
+        assert_eq!(
+            format(source),
+            "<dampen>\n    <column>\n        <row>\n            <text value=\"a\"/>\n        </row>\n    </column>\n</dampen>\n"
+        );
+    }
+
+    #[test]
+    fn test_indents_continued_attributes() {
+        let source = "<column>\n<text\nvalue=\"a\"\n  size=\"12\"\n/>\n<button label=\"b\"\non_click=\"go\">\n</button>\n</column>";
+        assert_eq!(
+            format(source),
+            "<column>\n    <text\n        value=\"a\"\n        size=\"12\"\n    />\n    <button label=\"b\"\n        on_click=\"go\">\n    </button>\n</column>"
+        );
+    }
+
+    #[test]
+    fn test_keeps_comments_cdata_and_values() {
+        let source =
+            "<column>\n<!-- note\n   kept  \n-->\n<text value=\"line\n   two  \"/>\n</column>\r\n";
+        assert_eq!(
+            format(source),
+            "<column>\n    <!-- note\n   kept  \n-->\n    <text value=\"line\n   two  \"/>\n</column>\r\n"
+        );
+
+        // Markup inside comments and values does not nest, text may be any Unicode
+        let source = "<column>\n<!-- <row> café -->\n<text value=\"<b>☕\" />\n</column>\n";
+        assert_eq!(
+            format(source),
+            "<column>\n    <!-- <row> café -->\n    <text value=\"<b>☕\" />\n</column>\n"
+        );
+    }
+
+    #[test]
+    fn test_lines_are_preserved() {
+        let source = "<?xml version=\"1.0\"?>\n\n<column>   \n\t<text value=\"a\" />\n\n</column>";
+        let options = FormatOptions {
+            indent: "\t".to_string(),
+        };
+        assert_eq!(
+            format_lines(source, &options),
+            [
+                "<?xml version=\"1.0\"?>",
+                "",
+                "<column>",
+                "\t<text value=\"a\" />",
+                "",
+                "</column>"
+            ]
+        );
+        assert_eq!(
+            format_document(source, &options),
+            format_lines(source, &options).join("\n")
+        );
+    }
+}
@@ -0,0 +1,157 @@
+//! Multiple event parameters
+//!
+//! An event attribute can pass several comma-separated parameters to its handler:
+//!
+//! ```xml
+//! <button label="Up" on_click="move_item:{item.id},{index},'up'" />
+//! ```
+//!
+//! Each parameter is evaluated to its string form. Interpreted mode encodes the
+//! list into the single `String` value carried by the handler message (see
+//! [`encode_event_args`]); generated code passes the list directly. Handlers
+//! receive the parameters as a tuple, or as any type implementing
+//! [`FromEventArgs`], with each element converted through [`FromStr`].
+
+use std::str::FromStr;
+
+/// Error converting event parameters to a handler argument type
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum EventArgsError {
+    /// The encoded value is not a parameter list
+    #[error("Malformed event arguments: {0}")]
+    Malformed(String),
+
+    /// Wrong number of parameters
+    #[error("Expected {expected} event arguments, found {found}")]
+    Arity { expected: usize, found: usize },
+
+    /// A parameter could not be converted to the target type
+    #[error("Cannot convert event argument {index} ('{value}') to {target}")]
+    Conversion {
+        index: usize,
+        value: String,
+        target: &'static str,
+    },
+}
+
+/// Conversion from a list of event parameters
+///
+/// Implemented for tuples of up to eight [`FromStr`] elements. Implement it for a
+/// dedicated params struct to give the parameters names; generated code also
+/// requires `Default`, used when conversion fails.
+///
+/// # Example
+///
+/// ```rust
+/// use dampen_core::handler::{EventArgsError, FromEventArgs};
+///
+/// #[derive(Default)]
+/// struct MoveArgs {
+///     id: u32,
+///     direction: String,
+/// }
+///
+/// impl FromEventArgs for MoveArgs {
+///     fn from_event_args(args: &[String]) -> Result<Self, EventArgsError> {
+///         let (id, direction) = <(u32, String)>::from_event_args(args)?;
+///         Ok(MoveArgs { id, direction })
+///     }
+/// }
+/// ```
+pub trait FromEventArgs: Sized {
+    /// Convert the parameters, in declaration order
+    fn from_event_args(args: &[String]) -> Result<Self, EventArgsError>;
+}
+
+fn convert<T: FromStr>(args: &[String], index: usize) -> Result<T, EventArgsError> {
+    let value = &args[index];
+    value.parse().map_err(|_| EventArgsError::Conversion {
+        index,
+        value: value.clone(),
+        target: std::any::type_name::<T>(),
+    })
+}
+
+macro_rules! impl_from_event_args {
+    ($len:literal; $($index:tt $name:ident),+) => {
+        impl<$($name: FromStr),+> FromEventArgs for ($($name,)+) {
+            fn from_event_args(args: &[String]) -> Result<Self, EventArgsError> {
+                if args.len() != $len {
+                    return Err(EventArgsError::Arity {
+                        expected: $len,
+                        found: args.len(),
+                    });
+                }
+                Ok(($(convert::<$name>(args, $index)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_event_args!(1; 0 A);
+impl_from_event_args!(2; 0 A, 1 B);
+impl_from_event_args!(3; 0 A, 1 B, 2 C);
+impl_from_event_args!(4; 0 A, 1 B, 2 C, 3 D);
+impl_from_event_args!(5; 0 A, 1 B, 2 C, 3 D, 4 E);
+impl_from_event_args!(6; 0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+impl_from_event_args!(7; 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
+impl_from_event_args!(8; 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H);
+
+/// Encode evaluated parameters into a single handler value
+pub fn encode_event_args(args: &[String]) -> String {
+    serde_json::to_string(args).unwrap_or_default()
+}
+
+/// Decode a handler value produced by [`encode_event_args`]
+///
+/// # Example
+///
+/// ```rust
+/// use dampen_core::handler::{decode_event_args, encode_event_args};
+///
+/// let value = encode_event_args(&["42".to_string(), "3".to_string(), "up".to_string()]);
+/// let (id, index, direction): (u32, usize, String) = decode_event_args(&value).unwrap();
+/// assert_eq!((id, index, direction.as_str()), (42, 3, "up"));
+/// ```
+pub fn decode_event_args<T: FromEventArgs>(value: &str) -> Result<T, EventArgsError> {
+    let args: Vec<String> =
+        serde_json::from_str(value).map_err(|e| EventArgsError::Malformed(e.to_string()))?;
+    T::from_event_args(&args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_round_trip_with_commas_and_quotes() {
+        let value = encode_event_args(&args(&["a, \"b\"", "7"]));
+        assert_eq!(
+            decode_event_args::<(String, i64)>(&value),
+            Ok(("a, \"b\"".to_string(), 7))
+        );
+    }
+
+    #[test]
+    fn test_arity_and_conversion_errors() {
+        assert_eq!(
+            <(u32, u32)>::from_event_args(&args(&["1"])),
+            Err(EventArgsError::Arity {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert!(matches!(
+            <(u32, bool)>::from_event_args(&args(&["1", "maybe"])),
+            Err(EventArgsError::Conversion { index: 1, .. })
+        ));
+        assert!(matches!(
+            decode_event_args::<(u32,)>("not a list"),
+            Err(EventArgsError::Malformed(_))
+        ));
+    }
+}
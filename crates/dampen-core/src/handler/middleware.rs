@@ -0,0 +1,320 @@
+//! Handler middleware
+//!
+//! Middleware wraps every handler dispatch, which makes it the place for
+//! cross-cutting concerns such as logging, analytics, confirmation prompts or a
+//! read-only mode. A middleware receives a [`Next`] continuation, the handler name
+//! and the dispatched value; it can inspect or rewrite the value, call
+//! [`Next::run`] to continue the chain, or return without calling it to cancel the
+//! dispatch.
+//!
+//! Interpreted mode runs the chain registered on the
+//! [`HandlerRegistry`](super::HandlerRegistry). Code generated for production
+//! builds has no registry, so it runs the global chain registered with
+//! [`register_global_middleware`] through [`intercept`].
+
+use std::any::Any;
+use std::sync::{Arc, OnceLock, RwLock};
+
+/// Result of a dispatch: the command returned by the handler, if any
+pub type DispatchResult = Option<Box<dyn Any>>;
+
+/// Middleware wrapping handler dispatch: `fn(next, name, value)`
+pub type HandlerMiddleware =
+    Arc<dyn Fn(Next<'_>, &str, Option<String>) -> DispatchResult + Send + Sync>;
+
+/// Continuation of a middleware chain
+///
+/// Calling [`run`](Self::run) invokes the remaining middleware and finally the
+/// handler. Dropping it without calling `run` cancels the dispatch.
+pub struct Next<'a> {
+    inner: Box<dyn FnOnce(Option<String>) -> DispatchResult + 'a>,
+}
+
+impl<'a> Next<'a> {
+    fn new(inner: impl FnOnce(Option<String>) -> DispatchResult + 'a) -> Self {
+        Self {
+            inner: Box::new(inner),
+        }
+    }
+
+    /// Continue the chain with the given (possibly rewritten) value
+    pub fn run(self, value: Option<String>) -> DispatchResult {
+        (self.inner)(value)
+    }
+}
+
+impl std::fmt::Debug for Next<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Next")
+    }
+}
+
+/// Run `handler` through the given middleware, outermost first
+pub(crate) fn run_chain<'a>(
+    middleware: &'a [HandlerMiddleware],
+    name: &'a str,
+    value: Option<String>,
+    handler: impl FnOnce(Option<String>) -> DispatchResult + 'a,
+) -> DispatchResult {
+    match middleware.split_first() {
+        None => handler(value),
+        Some((first, rest)) => first(
+            Next::new(move |value| run_chain(rest, name, value, handler)),
+            name,
+            value,
+        ),
+    }
+}
+
+fn global_middleware() -> &'static RwLock<Vec<HandlerMiddleware>> {
+    static MIDDLEWARE: OnceLock<RwLock<Vec<HandlerMiddleware>>> = OnceLock::new();
+    MIDDLEWARE.get_or_init(|| RwLock::new(Vec::new()))
+}
+
+/// Register a middleware for generated (codegen mode) handler dispatch
+///
+/// Middleware registered first runs outermost.
+///
+/// # Example
+///
+/// ```rust
+/// use dampen_core::handler::register_global_middleware;
+///
+/// register_global_middleware(|next, name, value| {
+///     println!("dispatching {name}");
+///     next.run(value)
+/// });
+/// ```
+pub fn register_global_middleware<F>(middleware: F)
+where
+    F: Fn(Next<'_>, &str, Option<String>) -> DispatchResult + Send + Sync + 'static,
+{
+    if let Ok(mut chain) = global_middleware().write() {
+        chain.push(Arc::new(middleware));
+    }
+}
+
+/// Remove every middleware registered with [`register_global_middleware`]
+pub fn clear_global_middleware() {
+    if let Ok(mut chain) = global_middleware().write() {
+        chain.clear();
+    }
+}
+
+/// The global middleware chain, `None` when no middleware is registered
+fn global_chain() -> Option<Vec<HandlerMiddleware>> {
+    match global_middleware().read() {
+        Ok(chain) if !chain.is_empty() => Some(chain.clone()),
+        _ => None,
+    }
+}
+
+/// Run a generated handler call through the global middleware chain
+///
+/// `value` is the string form of the handler argument when it has one; the
+/// handler receives the value returned by the chain. Returns `None` when a
+/// middleware cancelled the dispatch.
+pub fn intercept<T: 'static>(
+    name: &str,
+    value: Option<String>,
+    handler: impl FnOnce(Option<String>) -> T,
+) -> Option<T> {
+    crate::coverage::record(crate::coverage::HitKind::Handler, || name.to_string());
+    let Some(chain) = global_chain() else {
+        return Some(handler(value));
+    };
+
+    run_chain(&chain, name, value, |value| {
+        Some(Box::new(handler(value)) as Box<dyn Any>)
+    })
+    .and_then(|result| result.downcast::<T>().ok())
+    .map(|result| *result)
+}
+
+/// Run a generated handler call with a typed argument through the global
+/// middleware chain
+///
+/// Without middleware the handler is called with `value` directly. Otherwise
+/// the chain sees the string form of `value` (`to_string()`), and a rewritten
+/// value is parsed back: the handler is not called when it cannot be parsed.
+/// Returns `None` when the dispatch was cancelled.
+pub fn intercept_value<V, T>(name: &str, value: V, handler: impl FnOnce(V) -> T) -> Option<T>
+where
+    V: std::fmt::Display + std::str::FromStr,
+    T: 'static,
+{
+    crate::coverage::record(crate::coverage::HitKind::Handler, || name.to_string());
+    let Some(chain) = global_chain() else {
+        return Some(handler(value));
+    };
+
+    let original = value.to_string();
+    run_chain(&chain, name, Some(original.clone()), |rewritten| {
+        let value = match rewritten {
+            Some(rewritten) if rewritten != original => rewritten.trim().parse().ok()?,
+            _ => value,
+        };
+        Some(Box::new(handler(value)) as Box<dyn Any>)
+    })
+    .and_then(|result| result.downcast::<T>().ok())
+    .map(|result| *result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HandlerRegistry;
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Exclusive use of the global chain by a test, cleared when dropped
+    struct GlobalMiddlewareGuard {
+        _lock: std::sync::MutexGuard<'static, ()>,
+    }
+
+    impl GlobalMiddlewareGuard {
+        fn new() -> Self {
+            static LOCK: Mutex<()> = Mutex::new(());
+            let lock = LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            clear_global_middleware();
+            Self { _lock: lock }
+        }
+    }
+
+    impl Drop for GlobalMiddlewareGuard {
+        fn drop(&mut self) {
+            clear_global_middleware();
+        }
+    }
+
+    #[derive(Default)]
+    struct Model {
+        text: String,
+        count: i32,
+    }
+
+    fn registry() -> HandlerRegistry {
+        let registry = HandlerRegistry::new();
+        registry.register_simple("increment", |model| {
+            if let Some(model) = model.downcast_mut::<Model>() {
+                model.count += 1;
+            }
+        });
+        registry.register_with_value("set_text", |model, value| {
+            if let (Some(model), Ok(value)) =
+                (model.downcast_mut::<Model>(), value.downcast::<String>())
+            {
+                model.text = *value;
+            }
+        });
+        registry
+    }
+
+    #[test]
+    fn test_middleware_runs_in_registration_order() {
+        let registry = registry();
+        let log = Arc::new(Mutex::new(Vec::new()));
+
+        for label in ["outer", "inner"] {
+            let log = log.clone();
+            registry.register_middleware(move |next, name, value| {
+                if let Ok(mut log) = log.lock() {
+                    log.push(format!("{label}:{name}"));
+                }
+                next.run(value)
+            });
+        }
+
+        let mut model = Model::default();
+        registry.dispatch("increment", &mut model, None);
+
+        assert_eq!(model.count, 1);
+        assert_eq!(
+            log.lock().map(|log| log.clone()).unwrap_or_default(),
+            vec!["outer:increment", "inner:increment"]
+        );
+    }
+
+    #[test]
+    fn test_middleware_can_rewrite_value_and_cancel() {
+        let registry = registry();
+        registry.register_middleware(|next, name, value| match name {
+            "increment" => None,
+            _ => next.run(value.map(|value| value.to_uppercase())),
+        });
+
+        let mut model = Model::default();
+        registry.dispatch("increment", &mut model, None);
+        registry.dispatch_with_shared("set_text", &mut model, &(), Some("hello".to_string()));
+
+        assert_eq!(model.count, 0);
+        assert_eq!(model.text, "HELLO");
+    }
+
+    #[test]
+    fn test_intercept_runs_global_chain() {
+        let _guard = GlobalMiddlewareGuard::new();
+        register_global_middleware(|next, name, value| {
+            if name == "intercept_test_blocked" {
+                None
+            } else {
+                next.run(value)
+            }
+        });
+
+        let allowed = intercept("intercept_test_allowed", Some("a".to_string()), |value| {
+            value
+        });
+        let blocked = intercept("intercept_test_blocked", None, |_| 1);
+
+        assert_eq!(allowed, Some(Some("a".to_string())));
+        assert_eq!(blocked, None);
+    }
+
+    /// Value counting how many times it was formatted
+    struct Counted(i32);
+
+    static FORMATTED: AtomicUsize = AtomicUsize::new(0);
+
+    impl std::fmt::Display for Counted {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            FORMATTED.fetch_add(1, Ordering::SeqCst);
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl std::str::FromStr for Counted {
+        type Err = std::num::ParseIntError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            s.parse().map(Counted)
+        }
+    }
+
+    #[test]
+    fn test_intercept_value_formats_only_for_middleware() {
+        let _guard = GlobalMiddlewareGuard::new();
+        FORMATTED.store(0, Ordering::SeqCst);
+
+        // Without middleware the value goes straight to the handler
+        assert_eq!(
+            intercept_value("set_count", Counted(1), |value| value.0),
+            Some(1)
+        );
+        assert_eq!(FORMATTED.load(Ordering::SeqCst), 0);
+
+        register_global_middleware(|next, name, value| match name {
+            "set_count" => next.run(value.map(|value| format!("{value}0"))),
+            _ => next.run(Some("not a number".to_string())),
+        });
+        assert_eq!(
+            intercept_value("set_count", Counted(4), |value| value.0),
+            Some(40)
+        );
+        assert_eq!(
+            intercept_value("set_other", Counted(4), |value| value.0),
+            None
+        );
+        assert_eq!(FORMATTED.load(Ordering::SeqCst), 2);
+    }
+}
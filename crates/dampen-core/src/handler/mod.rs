@@ -1,13 +1,39 @@
 //! Handler system for event dispatch
 
+pub mod args;
+pub mod middleware;
+
+pub use args::{EventArgsError, FromEventArgs, decode_event_args, encode_event_args};
+pub use middleware::{
+    DispatchResult, HandlerMiddleware, Next, clear_global_middleware, intercept, intercept_value,
+    register_global_middleware,
+};
+
 use std::any::Any;
 use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, RwLock};
 
+use crate::binding::UiBindable;
+use crate::selection::{SelectionAction, SelectionState};
+use crate::shared::{SharedContext, SharedContexts};
+
 /// Registry of event handlers
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct HandlerRegistry {
     handlers: Arc<RwLock<HashMap<String, HandlerEntry>>>,
+    middleware: Arc<RwLock<Vec<HandlerMiddleware>>>,
+}
+
+impl std::fmt::Debug for HandlerRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HandlerRegistry")
+            .field("handlers", &self.handlers)
+            .field(
+                "middleware",
+                &self.middleware.read().map(|chain| chain.len()).unwrap_or(0),
+            )
+            .finish()
+    }
 }
 
 /// Entry in the handler registry
@@ -67,6 +93,7 @@ impl HandlerRegistry {
     pub fn new() -> Self {
         Self {
             handlers: Arc::new(RwLock::new(HashMap::new())),
+            middleware: Arc::new(RwLock::new(Vec::new())),
         }
     }
 
@@ -90,6 +117,94 @@ impl HandlerRegistry {
         }
     }
 
+    /// Register a handler receiving multiple event parameters
+    ///
+    /// The handler value produced by `on_click="name:{a},{b}"` is decoded into `T`
+    /// (a tuple or a type implementing [`FromEventArgs`]). The handler is not called
+    /// when the value cannot be converted.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// registry.register_with_args("move_item", |model, (id, index, direction): (u32, usize, String)| {
+    ///     let model = model.downcast_mut::<Model>().unwrap();
+    ///     model.move_item(id, index, &direction);
+    /// });
+    /// ```
+    pub fn register_with_args<T, F>(&self, name: &str, handler: F)
+    where
+        T: FromEventArgs + 'static,
+        F: Fn(&mut dyn Any, T) + Send + Sync + 'static,
+    {
+        self.register_with_value(name, move |model, value| {
+            if let Ok(value) = value.downcast::<String>()
+                && let Ok(args) = decode_event_args::<T>(&value)
+            {
+                handler(model, args);
+            }
+        });
+    }
+
+    /// Register a handler receiving a numeric value, e.g. from `<slider on_change>`
+    /// or `<number_input on_change>`
+    ///
+    /// The handler value is parsed into `T` (`f32`, `f64`, `i32`, ...). The handler
+    /// is not called when the value cannot be parsed, e.g. a fractional value for
+    /// an integer type. Any [`FromStr`](std::str::FromStr) type works, such as the
+    /// `chrono::NaiveDate` sent by `<date_picker on_change>`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// registry.register_with_number("set_volume", |model, volume: f32| {
+    ///     let model = model.downcast_mut::<Model>().unwrap();
+    ///     model.volume = volume;
+    /// });
+    /// ```
+    pub fn register_with_number<T, F>(&self, name: &str, handler: F)
+    where
+        T: std::str::FromStr + 'static,
+        F: Fn(&mut dyn Any, T) + Send + Sync + 'static,
+    {
+        self.register_with_value(name, move |model, value| {
+            if let Ok(value) = value.downcast::<String>()
+                && let Ok(number) = value.trim().parse::<T>()
+            {
+                handler(model, number);
+            }
+        });
+    }
+
+    /// Register the `<field>.<action>` handlers of a [`SelectionState`] field
+    ///
+    /// Registers one handler per [`SelectionAction`], named after `field`
+    /// (e.g. `selection.toggle`), running the action on the selection returned
+    /// by `selection`. Ids are parsed from the handler value.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// registry.register_selection("selection", |model: &mut Model| &mut model.selection);
+    /// ```
+    pub fn register_selection<M, T, F>(&self, field: &str, selection: F)
+    where
+        M: 'static,
+        T: Clone + PartialEq + std::str::FromStr + 'static,
+        F: Fn(&mut M) -> &mut SelectionState<T> + Send + Sync + 'static,
+    {
+        let selection = Arc::new(selection);
+        for &action in SelectionAction::ALL {
+            let selection = Arc::clone(&selection);
+            let name = format!("{}.{}", field, action.name());
+            self.register_with_value(&name, move |model, value| {
+                if let Some(model) = model.downcast_mut::<M>() {
+                    let id = value.downcast::<String>().ok();
+                    selection(model).apply(action, id.as_deref().map(String::as_str));
+                }
+            });
+        }
+    }
+
     /// Register a handler that returns a command
     pub fn register_with_command<F>(&self, name: &str, handler: F)
     where
@@ -209,7 +324,71 @@ impl HandlerRegistry {
         }
     }
 
+    /// Register a handler receiving the shared context of state `S`.
+    ///
+    /// The context is resolved from the value passed to
+    /// [`dispatch_with_shared`](Self::dispatch_with_shared) with
+    /// [`SharedContexts::resolve`], so the handler works both with a single
+    /// `SharedContext<S>` and with an application declaring several named
+    /// contexts. The handler is skipped if no context holds an `S`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// registry.register_with_context("logout", |model, session: &SharedContext<Session>| {
+    ///     session.write().user = None;
+    /// });
+    /// ```
+    pub fn register_with_context<S, F>(&self, name: &str, handler: F)
+    where
+        S: UiBindable + Send + Sync + 'static,
+        F: Fn(&mut dyn Any, &SharedContext<S>) + Send + Sync + 'static,
+    {
+        self.register_with_shared(name, move |model, shared| {
+            if let Some(context) = SharedContexts::resolve::<S>(shared) {
+                handler(model, &context);
+            }
+        });
+    }
+
+    /// Register a handler receiving a value and the shared context of state `S`.
+    ///
+    /// See [`register_with_context`](Self::register_with_context) for how the
+    /// context is resolved.
+    pub fn register_with_value_and_context<S, F>(&self, name: &str, handler: F)
+    where
+        S: UiBindable + Send + Sync + 'static,
+        F: Fn(&mut dyn Any, Box<dyn Any>, &SharedContext<S>) + Send + Sync + 'static,
+    {
+        self.register_with_value_and_shared(name, move |model, value, shared| {
+            if let Some(context) = SharedContexts::resolve::<S>(shared) {
+                handler(model, value, &context);
+            }
+        });
+    }
+
+    /// Register a handler receiving the shared context of state `S` and returning a command.
+    ///
+    /// See [`register_with_context`](Self::register_with_context) for how the
+    /// context is resolved. An unresolved context yields an empty command.
+    pub fn register_with_command_and_context<S, F>(&self, name: &str, handler: F)
+    where
+        S: UiBindable + Send + Sync + 'static,
+        F: Fn(&mut dyn Any, &SharedContext<S>) -> Box<dyn Any> + Send + Sync + 'static,
+    {
+        self.register_with_command_and_shared(name, move |model, shared| {
+            match SharedContexts::resolve::<S>(shared) {
+                Some(context) => handler(model, &context),
+                None => Box::new(()),
+            }
+        });
+    }
+
     /// Register a handler that receives a canvas event
+    ///
+    /// The `dispatch*` methods decode the event from the message value, so the
+    /// handler receives a typed [`CanvasEvent`]. Values that are not a canvas
+    /// event payload are ignored.
     pub fn register_canvas_event<F>(&self, name: &str, handler: F)
     where
         F: Fn(&mut dyn Any, CanvasEvent) + Send + Sync + 'static,
@@ -227,6 +406,81 @@ impl HandlerRegistry {
         self.handlers.read().ok()?.get(name).cloned()
     }
 
+    /// Names of the handlers taking no value, sorted
+    ///
+    /// These are the handlers the command palette can run when none is labelled
+    /// with `#[ui_handler(palette = "...")]`.
+    pub fn names_without_value(&self) -> Vec<String> {
+        let Ok(handlers) = self.handlers.read() else {
+            return Vec::new();
+        };
+        let mut names: Vec<String> = handlers
+            .iter()
+            .filter(|(_, entry)| {
+                matches!(
+                    entry,
+                    HandlerEntry::Simple(_)
+                        | HandlerEntry::WithCommand(_)
+                        | HandlerEntry::WithShared(_)
+                        | HandlerEntry::WithCommandAndShared(_)
+                )
+            })
+            .map(|(name, _)| name.clone())
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// Register a middleware wrapping every dispatch
+    ///
+    /// Middleware runs for [`dispatch`](Self::dispatch),
+    /// [`dispatch_with_command`](Self::dispatch_with_command) and
+    /// [`dispatch_with_shared`](Self::dispatch_with_shared), in registration order
+    /// (the first registered runs outermost). It only runs for handlers that exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dampen_core::HandlerRegistry;
+    ///
+    /// let registry = HandlerRegistry::new();
+    ///
+    /// // Read-only mode: block everything except navigation
+    /// registry.register_middleware(|next, name, value| {
+    ///     if name.starts_with("go_to_") {
+    ///         next.run(value)
+    ///     } else {
+    ///         None
+    ///     }
+    /// });
+    /// ```
+    pub fn register_middleware<F>(&self, middleware: F)
+    where
+        F: Fn(Next<'_>, &str, Option<String>) -> DispatchResult + Send + Sync + 'static,
+    {
+        if let Ok(mut chain) = self.middleware.write() {
+            chain.push(Arc::new(middleware));
+        }
+    }
+
+    /// Run `handler` through the registered middleware
+    fn run_middleware(
+        &self,
+        handler_name: &str,
+        value: Option<String>,
+        handler: impl FnOnce(Option<String>) -> DispatchResult,
+    ) -> DispatchResult {
+        crate::coverage::record(crate::coverage::HitKind::Handler, || {
+            handler_name.to_string()
+        });
+        let chain = self
+            .middleware
+            .read()
+            .map(|chain| chain.clone())
+            .unwrap_or_default();
+        middleware::run_chain(&chain, handler_name, value, handler)
+    }
+
     /// Dispatches a handler by name, executing it with the provided model and optional value.
     ///
     /// This is a convenience method that combines `get()` and handler invocation.
@@ -260,23 +514,30 @@ impl HandlerRegistry {
     /// ```
     pub fn dispatch(&self, handler_name: &str, model: &mut dyn Any, value: Option<String>) {
         if let Some(entry) = self.get(handler_name) {
-            match entry {
-                HandlerEntry::Simple(h) => h(model),
-                HandlerEntry::WithValue(h) => {
-                    let val = value.unwrap_or_default();
-                    h(model, Box::new(val));
-                }
-                HandlerEntry::WithCommand(h) => {
-                    h(model);
-                }
-                // Shared handlers require shared context - silently skip in dispatch()
-                HandlerEntry::WithShared(_)
-                | HandlerEntry::WithValueAndShared(_)
-                | HandlerEntry::WithCommandAndShared(_)
-                | HandlerEntry::WithCanvasEvent(_) => {
-                    // These handlers require shared context or event data. Use specific dispatch methods.
+            self.run_middleware(handler_name, value, |value| {
+                match entry {
+                    HandlerEntry::Simple(h) => h(model),
+                    HandlerEntry::WithValue(h) => {
+                        let val = value.unwrap_or_default();
+                        h(model, Box::new(val));
+                    }
+                    HandlerEntry::WithCommand(h) => {
+                        h(model);
+                    }
+                    HandlerEntry::WithCanvasEvent(h) => {
+                        if let Some(event) = canvas_event(value) {
+                            h(model, event);
+                        }
+                    }
+                    // Shared handlers require shared context - silently skip in dispatch()
+                    HandlerEntry::WithShared(_)
+                    | HandlerEntry::WithValueAndShared(_)
+                    | HandlerEntry::WithCommandAndShared(_) => {
+                        // These handlers require shared context. Use dispatch_with_shared().
+                    }
                 }
-            }
+                None
+            });
         }
     }
 
@@ -329,27 +590,30 @@ impl HandlerRegistry {
         model: &mut dyn Any,
         value: Option<String>,
     ) -> Option<Box<dyn Any>> {
-        if let Some(entry) = self.get(handler_name) {
-            match entry {
-                HandlerEntry::Simple(h) => {
-                    h(model);
-                    None
-                }
-                HandlerEntry::WithValue(h) => {
-                    let val = value.unwrap_or_default();
-                    h(model, Box::new(val));
-                    None
+        let entry = self.get(handler_name)?;
+
+        self.run_middleware(handler_name, value, |value| match entry {
+            HandlerEntry::Simple(h) => {
+                h(model);
+                None
+            }
+            HandlerEntry::WithValue(h) => {
+                let val = value.unwrap_or_default();
+                h(model, Box::new(val));
+                None
+            }
+            HandlerEntry::WithCommand(h) => Some(h(model)),
+            HandlerEntry::WithCanvasEvent(h) => {
+                if let Some(event) = canvas_event(value) {
+                    h(model, event);
                 }
-                HandlerEntry::WithCommand(h) => Some(h(model)),
-                // Shared handlers require shared context - not supported here
-                HandlerEntry::WithShared(_)
-                | HandlerEntry::WithValueAndShared(_)
-                | HandlerEntry::WithCommandAndShared(_)
-                | HandlerEntry::WithCanvasEvent(_) => None,
+                None
             }
-        } else {
-            None
-        }
+            // Shared handlers require shared context - not supported here
+            HandlerEntry::WithShared(_)
+            | HandlerEntry::WithValueAndShared(_)
+            | HandlerEntry::WithCommandAndShared(_) => None,
+        })
     }
 
     /// Dispatches a handler with shared context and returns any command it produces.
@@ -395,7 +659,7 @@ impl HandlerRegistry {
     ) -> Option<Box<dyn Any>> {
         let entry = self.get(handler_name)?;
 
-        match entry {
+        self.run_middleware(handler_name, value, |value| match entry {
             // Existing variants (backward compatible - ignore shared)
             HandlerEntry::Simple(h) => {
                 h(model);
@@ -417,9 +681,13 @@ impl HandlerRegistry {
                 None
             }
             HandlerEntry::WithCommandAndShared(h) => Some(h(model, shared)),
-            // Canvas handlers require event data - ignore here
-            HandlerEntry::WithCanvasEvent(_) => None,
-        }
+            HandlerEntry::WithCanvasEvent(h) => {
+                if let Some(event) = canvas_event(value) {
+                    h(model, event);
+                }
+                None
+            }
+        })
     }
 
     /// Dispatch a canvas event handler
@@ -444,6 +712,11 @@ impl HandlerRegistry {
     }
 }
 
+/// Canvas event carried by the value of a handler message
+fn canvas_event(value: Option<String>) -> Option<CanvasEvent> {
+    value?.parse().ok()
+}
+
 impl Default for HandlerRegistry {
     fn default() -> Self {
         Self::new()
@@ -461,6 +734,9 @@ pub struct HandlerSignature {
 
     /// Whether handler returns Command
     pub returns_command: bool,
+
+    /// Command palette label, from `#[ui_handler(palette = "...")]`
+    pub palette: Option<String>,
 }
 
 /// Build-time analysis structure for circular dependency detection
@@ -611,13 +887,83 @@ impl Default for HandlerCallGraph {
 }
 
 /// Canvas event data passed to handlers
+///
+/// Handlers taking a `CanvasEvent` receive it typed in both modes. Interpreted
+/// mode carries it in the handler message as JSON (see
+/// [`to_payload`](Self::to_payload)), which the registry decodes for handlers
+/// registered with [`register_canvas_event`](HandlerRegistry::register_canvas_event);
+/// generated code passes the struct directly.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct CanvasEvent {
     pub kind: CanvasEventKind,
+    /// Pointer position, relative to the top-left corner of the canvas
     pub x: f32,
     pub y: f32,
+    /// Movement since the previous event of a drag
     pub delta_x: Option<f32>,
     pub delta_y: Option<f32>,
+    /// Button pressed or released; for drags, the button held down
+    #[serde(default)]
+    pub button: Option<PointerButton>,
+    /// Keyboard modifiers held during the event
+    #[serde(default)]
+    pub modifiers: PointerModifiers,
+}
+
+impl CanvasEvent {
+    /// Pointer position, relative to the top-left corner of the canvas
+    pub fn position(&self) -> (f32, f32) {
+        (self.x, self.y)
+    }
+
+    /// Movement since the previous event of a drag
+    pub fn delta(&self) -> Option<(f32, f32)> {
+        self.delta_x.zip(self.delta_y)
+    }
+
+    /// Encodes the event as the value of an interpreted handler message
+    pub fn to_payload(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+/// Formats the event as its payload, the inverse of [`FromStr`](std::str::FromStr)
+impl std::fmt::Display for CanvasEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_payload())
+    }
+}
+
+/// Parses the payload written by [`CanvasEvent::to_payload`]
+impl std::str::FromStr for CanvasEvent {
+    type Err = serde_json::Error;
+
+    fn from_str(payload: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(payload)
+    }
+}
+
+/// Mouse button of a canvas event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum PointerButton {
+    Left,
+    Right,
+    Middle,
+    Back,
+    Forward,
+    Other(u16),
+}
+
+/// Keyboard modifiers of a canvas event
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub struct PointerModifiers {
+    pub shift: bool,
+    pub control: bool,
+    pub alt: bool,
+    /// Command key on macOS, Windows key elsewhere
+    pub logo: bool,
 }
 
 /// Type of canvas interaction
@@ -628,3 +974,137 @@ pub enum CanvasEventKind {
     Move,
     Release,
 }
+
+/// Scroll position passed to `on_scroll` handlers
+///
+/// Like [`CanvasEvent`], interpreted mode carries it as JSON in the handler
+/// message (see [`to_payload`](Self::to_payload)), to be parsed by handlers
+/// registered with [`register_with_value`](HandlerRegistry::register_with_value);
+/// generated code passes the struct directly.
+#[derive(Debug, Clone, Copy, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ScrollEvent {
+    /// Offset of the content, in pixels
+    pub x: f32,
+    pub y: f32,
+    /// Offset of the content, from 0 at the start to 1 at the end
+    pub relative_x: f32,
+    pub relative_y: f32,
+    /// Size of the visible part of the content
+    pub viewport_width: f32,
+    pub viewport_height: f32,
+    /// Size of the whole content
+    pub content_width: f32,
+    pub content_height: f32,
+}
+
+impl ScrollEvent {
+    /// Whether the content is scrolled to its bottom, within a pixel
+    pub fn at_bottom(&self) -> bool {
+        self.y + self.viewport_height >= self.content_height - 1.0
+    }
+
+    /// Encodes the event as the value of an interpreted handler message
+    pub fn to_payload(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+/// Formats the event as its payload, the inverse of [`FromStr`](std::str::FromStr)
+impl std::fmt::Display for ScrollEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_payload())
+    }
+}
+
+/// Parses the payload written by [`ScrollEvent::to_payload`]
+impl std::str::FromStr for ScrollEvent {
+    type Err = serde_json::Error;
+
+    fn from_str(payload: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scroll_event_payload_round_trip() {
+        let event = ScrollEvent {
+            y: 700.0,
+            relative_y: 1.0,
+            viewport_width: 400.0,
+            viewport_height: 300.0,
+            content_width: 400.0,
+            content_height: 1000.0,
+            ..ScrollEvent::default()
+        };
+        assert!(event.at_bottom());
+        assert_eq!(event.to_payload().parse::<ScrollEvent>().ok(), Some(event));
+        assert!(!ScrollEvent { y: 600.0, ..event }.at_bottom());
+    }
+
+    #[test]
+    fn test_canvas_event_payload_round_trip() {
+        let event = CanvasEvent {
+            kind: CanvasEventKind::Drag,
+            x: 10.0,
+            y: 20.5,
+            delta_x: Some(1.0),
+            delta_y: Some(-2.0),
+            button: Some(PointerButton::Right),
+            modifiers: PointerModifiers {
+                shift: true,
+                ..PointerModifiers::default()
+            },
+        };
+        assert_eq!(event.to_payload().parse::<CanvasEvent>().ok(), Some(event));
+
+        // Payloads written before buttons and modifiers were reported
+        let old: Result<CanvasEvent, _> =
+            r#"{"kind":"Click","x":1.0,"y":2.0,"delta_x":null,"delta_y":null}"#.parse();
+        assert!(old.is_ok_and(|event| event.button.is_none() && event.delta().is_none()));
+    }
+
+    #[test]
+    fn test_dispatch_decodes_canvas_event() {
+        let registry = HandlerRegistry::new();
+        registry.register_canvas_event("on_click", |model, event| {
+            if let Some(clicks) = model.downcast_mut::<Vec<(f32, f32)>>() {
+                clicks.push(event.position());
+            }
+        });
+
+        let event = CanvasEvent {
+            kind: CanvasEventKind::Click,
+            x: 3.0,
+            y: 4.0,
+            delta_x: None,
+            delta_y: None,
+            button: Some(PointerButton::Left),
+            modifiers: PointerModifiers::default(),
+        };
+        let mut clicks: Vec<(f32, f32)> = Vec::new();
+        registry.dispatch("on_click", &mut clicks, Some(event.to_payload()));
+        registry.dispatch_with_command("on_click", &mut clicks, Some("3,4".to_string()));
+        registry.dispatch_with_shared("on_click", &mut clicks, &(), Some(event.to_payload()));
+
+        assert_eq!(clicks, vec![(3.0, 4.0), (3.0, 4.0)]);
+    }
+
+    #[test]
+    fn test_register_selection() {
+        let registry = HandlerRegistry::new();
+        registry.register_selection("selection", |model: &mut SelectionState<i64>| model);
+
+        let mut selection = SelectionState::<i64>::multiple().with_items([1, 2, 3]);
+        registry.dispatch("selection.select", &mut selection, Some("1".to_string()));
+        registry.dispatch("selection.extend_next", &mut selection, None);
+        registry.dispatch("selection.toggle", &mut selection, Some("x".to_string()));
+        assert_eq!(selection.selected(), &[1, 2]);
+
+        registry.dispatch("selection.clear", &mut selection, None);
+        assert!(selection.is_empty());
+    }
+}
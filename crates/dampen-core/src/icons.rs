@@ -0,0 +1,155 @@
+//! Built-in icon set
+//!
+//! The `<icon>` widget renders one of these icons by name, so applications do not
+//! need to ship their own assets for common actions:
+//!
+//! ```xml
+//! <icon name="trash" size="16" tint="@danger" />
+//! ```
+//!
+//! Icons are monochrome 24×24 outlines stored as SVG path data. They are drawn
+//! with the theme's text color unless `tint` is set.
+
+/// Path data of the bundled icons, sorted by name
+const ICONS: &[(&str, &str)] = &[
+    ("arrow-down", "M12 5v14M19 12l-7 7-7-7"),
+    ("arrow-left", "M19 12H5M12 19l-7-7 7-7"),
+    ("arrow-right", "M5 12h14M12 5l7 7-7 7"),
+    ("arrow-up", "M12 19V5M5 12l7-7 7 7"),
+    ("bell", "M6 17v-6a6 6 0 0 1 12 0v6l2 2H4zM10 21h4"),
+    ("calendar", "M4 5h16v16H4zM4 10h16M8 3v4M16 3v4"),
+    ("check", "M20 6 9 17l-5-5"),
+    ("chevron-down", "M6 9l6 6 6-6"),
+    ("chevron-left", "M15 18l-6-6 6-6"),
+    ("chevron-right", "M9 18l6-6-6-6"),
+    ("chevron-up", "M18 15l-6-6-6 6"),
+    ("clock", "M12 3a9 9 0 1 0 0 18 9 9 0 1 0 0-18zM12 7v5l3 3"),
+    ("close", "M18 6 6 18M6 6l12 12"),
+    ("copy", "M9 9h11v11H9zM5 15H4V4h11v1"),
+    ("download", "M12 4v12M7 11l5 5 5-5M4 20h16"),
+    ("edit", "M4 20h4L19 9l-4-4L4 16zM13 7l4 4"),
+    (
+        "error",
+        "M12 3a9 9 0 1 0 0 18 9 9 0 1 0 0-18zM12 7v6M12 16h.01",
+    ),
+    (
+        "eye",
+        "M2 12s4-7 10-7 10 7 10 7-4 7-10 7S2 12 2 12zM12 9a3 3 0 1 0 0 6 3 3 0 1 0 0-6z",
+    ),
+    ("file", "M6 3h8l5 5v13H6zM14 3v5h5"),
+    ("filter", "M3 4h18l-7 8v6l-4 2v-8z"),
+    ("folder", "M3 6h6l2 2h10v11H3z"),
+    (
+        "heart",
+        "M12 20s-8-4.5-8-10.5A4.5 4.5 0 0 1 12 7a4.5 4.5 0 0 1 8 2.5c0 6-8 10.5-8 10.5z",
+    ),
+    ("home", "M3 10.5 12 3l9 7.5V21h-6v-6H9v6H3z"),
+    (
+        "info",
+        "M12 3a9 9 0 1 0 0 18 9 9 0 1 0 0-18zM12 11v5M12 8h.01",
+    ),
+    (
+        "link",
+        "M10 14a4 4 0 0 0 5.7 0l3-3a4 4 0 0 0-5.7-5.7l-1 1M14 10a4 4 0 0 0-5.7 0l-3 3a4 4 0 0 0 5.7 5.7l1-1",
+    ),
+    ("lock", "M5 11h14v10H5zM8 11V7a4 4 0 0 1 8 0v4"),
+    ("mail", "M3 5h18v14H3zM3 5l9 8 9-8"),
+    ("menu", "M3 6h18M3 12h18M3 18h18"),
+    ("minus", "M5 12h14"),
+    ("moon", "M20 14.5A8 8 0 1 1 9.5 4a6.5 6.5 0 0 0 10.5 10.5z"),
+    ("more", "M5 12h.01M12 12h.01M19 12h.01"),
+    ("pause", "M7 4h3v16H7zM14 4h3v16h-3z"),
+    ("play", "M7 4l13 8-13 8z"),
+    ("plus", "M12 5v14M5 12h14"),
+    ("refresh", "M20 12a8 8 0 1 1-2.3-5.7M20 4v5h-5"),
+    ("save", "M5 3h11l3 3v15H5zM8 3v5h8V3M8 21v-7h8v7"),
+    ("search", "M11 4a7 7 0 1 0 0 14 7 7 0 1 0 0-14zM21 21l-5-5"),
+    (
+        "settings",
+        "M12 9a3 3 0 1 0 0 6 3 3 0 1 0 0-6zM12 2v3M12 19v3M2 12h3M19 12h3M4.9 4.9 7 7M17 17l2.1 2.1M4.9 19.1 7 17M17 7l2.1-2.1",
+    ),
+    (
+        "star",
+        "M12 3l2.8 5.7 6.2.9-4.5 4.4 1.1 6.2-5.6-2.9-5.6 2.9 1.1-6.2L3 9.6l6.2-.9z",
+    ),
+    (
+        "sun",
+        "M12 8a4 4 0 1 0 0 8 4 4 0 1 0 0-8zM12 2v2M12 20v2M2 12h2M20 12h2M4.9 4.9l1.4 1.4M17.7 17.7l1.4 1.4M4.9 19.1l1.4-1.4M17.7 6.3l1.4-1.4",
+    ),
+    (
+        "trash",
+        "M3 6h18M8 6V4h8v2M6 6l1 15h10l1-15M10 11v6M14 11v6",
+    ),
+    ("upload", "M12 20V8M7 13l5-5 5 5M4 4h16"),
+    (
+        "user",
+        "M12 4a4 4 0 1 0 0 8 4 4 0 1 0 0-8zM4 21c0-4 4-6 8-6s8 2 8 6",
+    ),
+    ("warning", "M12 3 2 20h20zM12 10v4M12 17h.01"),
+];
+
+/// Names of all built-in icons, in alphabetical order
+pub fn names() -> impl Iterator<Item = &'static str> {
+    ICONS.iter().map(|(name, _)| *name)
+}
+
+/// Returns `true` if `name` is a built-in icon
+pub fn contains(name: &str) -> bool {
+    path_data(name).is_some()
+}
+
+/// Get the SVG document of a built-in icon
+///
+/// # Example
+///
+/// ```rust
+/// use dampen_core::icons;
+///
+/// let svg = icons::svg("trash").unwrap();
+/// assert!(svg.starts_with("<svg"));
+/// assert!(icons::svg("unknown").is_none());
+/// ```
+pub fn svg(name: &str) -> Option<String> {
+    let data = path_data(name)?;
+    Some(format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 24 24\" fill=\"none\" \
+         stroke=\"black\" stroke-width=\"2\" stroke-linecap=\"round\" stroke-linejoin=\"round\">\
+         <path d=\"{}\"/></svg>",
+        data
+    ))
+}
+
+fn path_data(name: &str) -> Option<&'static str> {
+    ICONS
+        .binary_search_by(|(icon, _)| icon.cmp(&name))
+        .ok()
+        .map(|index| ICONS[index].1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::canvas::parse_path_data;
+
+    #[test]
+    fn test_icons_are_sorted_and_valid() {
+        let names: Vec<&str> = names().collect();
+        let mut sorted = names.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(names, sorted);
+
+        for name in names {
+            assert!(contains(name));
+        }
+    }
+
+    #[test]
+    fn test_simple_icons_parse_as_path_data() {
+        // Icons without arcs or shorthand curves use the canvas path subset
+        for name in ["check", "close", "home", "trash", "upload"] {
+            let data = path_data(name).unwrap_or_default();
+            assert!(parse_path_data(data).is_ok(), "{} should parse", name);
+        }
+    }
+}
@@ -0,0 +1,299 @@
+//! Constraints on the text typed into a `<text_input>`
+//!
+//! `max_length` and `filter` are enforced before `on_input` is dispatched, so
+//! handlers only ever receive accepted values:
+//!
+//! ```xml
+//! <text_input value="{zip}" max_length="5" filter="numeric" on_input="set_zip" />
+//! <text_input value="{code}" filter="regex:'[A-Z]{0,3}'" on_input="set_code" />
+//! ```
+//!
+//! An edit breaking the filter is rejected, leaving the value unchanged. A paste
+//! longer than `max_length` is truncated.
+//!
+//! [`NumberRange`] plays the same role for `<number_input>`, whose `on_change`
+//! handler only receives numbers within `min`..=`max`.
+
+use regex::Regex;
+
+/// Characters allowed in a text input
+#[derive(Debug, Clone)]
+pub enum InputFilter {
+    /// Digits, with an optional leading `-` and a single `.`
+    Numeric,
+    /// Letters
+    Alpha,
+    /// Letters and digits
+    Alphanumeric,
+    /// Values matching a regular expression as a whole
+    Regex(Regex),
+}
+
+impl InputFilter {
+    /// Parse a filter: `numeric`, `alpha`, `alphanumeric` or `regex:'pattern'`
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let spec = spec.trim();
+        if let Some(pattern) = spec.strip_prefix("regex:") {
+            let pattern = pattern.trim();
+            let pattern = pattern
+                .strip_prefix('\'')
+                .and_then(|p| p.strip_suffix('\''))
+                .unwrap_or(pattern);
+            return Regex::new(&format!("^(?:{})$", pattern))
+                .map(InputFilter::Regex)
+                .map_err(|e| format!("Invalid regex '{}': {}", pattern, e));
+        }
+
+        match spec {
+            "numeric" => Ok(InputFilter::Numeric),
+            "alpha" => Ok(InputFilter::Alpha),
+            "alphanumeric" => Ok(InputFilter::Alphanumeric),
+            _ => Err(format!(
+                "Unknown filter '{}'. Expected numeric, alpha, alphanumeric or regex:'pattern'",
+                spec
+            )),
+        }
+    }
+
+    /// Whether `value` only contains allowed characters
+    ///
+    /// Partial numbers such as `-` or `3.` are accepted so they can be typed.
+    pub fn accepts(&self, value: &str) -> bool {
+        match self {
+            InputFilter::Numeric => {
+                let digits = value.strip_prefix('-').unwrap_or(value);
+                digits.chars().all(|c| c.is_ascii_digit() || c == '.')
+                    && digits.matches('.').count() <= 1
+            }
+            InputFilter::Alpha => value.chars().all(char::is_alphabetic),
+            InputFilter::Alphanumeric => value.chars().all(char::is_alphanumeric),
+            InputFilter::Regex(regex) => regex.is_match(value),
+        }
+    }
+}
+
+/// The `max_length` and `filter` of a text input
+#[derive(Debug, Clone, Default)]
+pub struct InputConstraints {
+    /// Maximum number of characters
+    pub max_length: Option<usize>,
+    /// Allowed characters
+    pub filter: Option<InputFilter>,
+}
+
+impl InputConstraints {
+    /// Parse the `max_length` and `filter` attribute values
+    pub fn parse(max_length: Option<&str>, filter: Option<&str>) -> Result<Self, String> {
+        let max_length = max_length
+            .map(|max| match max.trim().parse::<usize>() {
+                Ok(max) if max > 0 => Ok(max),
+                _ => Err(format!(
+                    "Invalid max_length '{}': expected a positive integer",
+                    max
+                )),
+            })
+            .transpose()?;
+        let filter = filter.map(InputFilter::parse).transpose()?;
+        Ok(Self { max_length, filter })
+    }
+
+    /// Whether no constraint is set
+    pub fn is_empty(&self) -> bool {
+        self.max_length.is_none() && self.filter.is_none()
+    }
+
+    /// The value to dispatch when the user edits `current` into `input`
+    ///
+    /// Returns `current` if the filter rejects `input`, and `input` truncated to
+    /// `max_length` otherwise. An empty value is always accepted.
+    pub fn apply(&self, current: &str, input: String) -> String {
+        if let Some(filter) = &self.filter
+            && !input.is_empty()
+            && !filter.accepts(&input)
+        {
+            return current.to_string();
+        }
+        match self.max_length {
+            Some(max) if input.chars().count() > max => input.chars().take(max).collect(),
+            _ => input,
+        }
+    }
+}
+
+/// The `min`, `max`, `step` and `decimals` of a number input
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberRange {
+    /// Smallest accepted value
+    pub min: Option<f64>,
+    /// Largest accepted value
+    pub max: Option<f64>,
+    /// Amount added or removed by the step buttons
+    pub step: f64,
+    /// Number of fractional digits; `0` for integers
+    pub decimals: usize,
+}
+
+impl Default for NumberRange {
+    fn default() -> Self {
+        Self {
+            min: None,
+            max: None,
+            step: 1.0,
+            decimals: 0,
+        }
+    }
+}
+
+impl NumberRange {
+    /// Parse the `min`, `max`, `step` and `decimals` attribute values
+    ///
+    /// `decimals` defaults to the number of fractional digits of `step`, so
+    /// `step="0.25"` accepts two decimals.
+    pub fn parse(
+        min: Option<&str>,
+        max: Option<&str>,
+        step: Option<&str>,
+        decimals: Option<&str>,
+    ) -> Result<Self, String> {
+        let number = |name: &str, value: Option<&str>| {
+            value
+                .map(|value| match value.trim().parse::<f64>() {
+                    Ok(number) if number.is_finite() => Ok(number),
+                    _ => Err(format!("Invalid {} '{}': expected a number", name, value)),
+                })
+                .transpose()
+        };
+        let (min, max) = (number("min", min)?, number("max", max)?);
+        if let (Some(min), Some(max)) = (min, max)
+            && min > max
+        {
+            return Err(format!("max ({}) is lower than min ({})", max, min));
+        }
+
+        let step_value = number("step", step)?.unwrap_or(1.0);
+        if step_value <= 0.0 {
+            return Err(format!(
+                "Invalid step '{}': expected a positive number",
+                step_value
+            ));
+        }
+        let decimals = match decimals {
+            Some(decimals) => decimals.trim().parse::<usize>().map_err(|_| {
+                format!(
+                    "Invalid decimals '{}': expected a non-negative integer",
+                    decimals
+                )
+            })?,
+            None => step.map(fraction_digits).unwrap_or(0),
+        };
+
+        Ok(Self {
+            min,
+            max,
+            step: step_value,
+            decimals,
+        })
+    }
+
+    /// Clamp `value` to `min`..=`max` and round it to `decimals`
+    pub fn clamp(&self, value: f64) -> f64 {
+        let factor = 10f64.powi(self.decimals as i32);
+        let mut value = (value * factor).round() / factor;
+        if let Some(min) = self.min {
+            value = value.max(min);
+        }
+        if let Some(max) = self.max {
+            value = value.min(max);
+        }
+        value
+    }
+
+    /// The value after pressing a step button, `steps` being `1` or `-1`
+    pub fn step_by(&self, value: f64, steps: f64) -> f64 {
+        self.clamp(value + self.step * steps)
+    }
+
+    /// The number typed in the input, clamped to the range
+    ///
+    /// Returns `None` when `text` is not a number or has more than `decimals`
+    /// fractional digits.
+    pub fn parse_value(&self, text: &str) -> Option<f64> {
+        let text = text.trim();
+        let value = text.parse::<f64>().ok().filter(|value| value.is_finite())?;
+        (fraction_digits(text) <= self.decimals).then(|| self.clamp(value))
+    }
+
+    /// Format `value` with `decimals` fractional digits
+    pub fn format(&self, value: f64) -> String {
+        format!("{:.*}", self.decimals, value)
+    }
+}
+
+/// Number of digits after the decimal point of a number literal
+fn fraction_digits(number: &str) -> usize {
+    number
+        .trim()
+        .split_once('.')
+        .map(|(_, fraction)| fraction.len())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filters() -> Result<(), String> {
+        let numeric = InputFilter::parse("numeric")?;
+        assert!(numeric.accepts("-12.5"));
+        assert!(numeric.accepts("-"));
+        assert!(!numeric.accepts("1.2.3"));
+        assert!(!numeric.accepts("12a"));
+
+        assert!(InputFilter::parse("alpha")?.accepts("Zoë"));
+        assert!(!InputFilter::parse("alphanumeric")?.accepts("a b"));
+
+        let code = InputFilter::parse("regex:'[A-Z]{0,3}'")?;
+        assert!(code.accepts("AB"));
+        assert!(!code.accepts("ABCD"));
+
+        assert!(InputFilter::parse("digits").is_err());
+        assert!(InputFilter::parse("regex:'['").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_truncates_and_rejects() -> Result<(), String> {
+        let constraints = InputConstraints::parse(Some("4"), Some("numeric"))?;
+        assert_eq!(constraints.apply("12", "123".to_string()), "123");
+        assert_eq!(constraints.apply("12", "123456".to_string()), "1234");
+        assert_eq!(constraints.apply("12", "12x".to_string()), "12");
+        assert_eq!(constraints.apply("12", "12345x".to_string()), "12");
+        assert_eq!(constraints.apply("12", String::new()), "");
+
+        assert!(InputConstraints::parse(Some("0"), None).is_err());
+        assert!(InputConstraints::default().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_number_range() -> Result<(), String> {
+        let range = NumberRange::parse(Some("0"), Some("99"), None, None)?;
+        assert_eq!(range.parse_value("42"), Some(42.0));
+        assert_eq!(range.parse_value("150"), Some(99.0));
+        assert_eq!(range.parse_value("4.5"), None);
+        assert_eq!(range.parse_value("-"), None);
+        assert_eq!(range.step_by(0.0, -1.0), 0.0);
+        assert_eq!(range.format(7.0), "7");
+
+        let range = NumberRange::parse(None, None, Some("0.25"), None)?;
+        assert_eq!(range.decimals, 2);
+        assert_eq!(range.step_by(0.1, 1.0), 0.35);
+        assert_eq!(range.format(1.5), "1.50");
+
+        assert!(NumberRange::parse(Some("5"), Some("1"), None, None).is_err());
+        assert!(NumberRange::parse(None, None, Some("0"), None).is_err());
+        assert!(NumberRange::parse(None, None, None, Some("two")).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+use crate::ir::node::AttributeValue;
+
+/// Accessibility metadata of a widget.
+///
+/// Built from the `aria_label`, `role` and `described_by` attributes, which any
+/// widget accepts:
+///
+/// ```xml
+/// <button aria_label="Delete item" on_click="delete">
+///     <icon name="trash" />
+/// </button>
+/// <text_input value="{email}" described_by="email_hint" />
+/// <text id="email_hint" value="We never share your address" />
+/// ```
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct Accessibility {
+    /// Name announced by screen readers, static or bound
+    pub label: Option<AttributeValue>,
+    /// Semantic role, overriding the widget's default role
+    pub role: Option<AccessibilityRole>,
+    /// Id of the widget describing this one
+    pub described_by: Option<String>,
+}
+
+/// Semantic role of a widget, used by the `role` attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum AccessibilityRole {
+    Alert,
+    Button,
+    Checkbox,
+    Dialog,
+    Group,
+    Heading,
+    Image,
+    Link,
+    List,
+    ListItem,
+    Navigation,
+    ProgressBar,
+    Radio,
+    Region,
+    Slider,
+    Switch,
+    Tab,
+    TabList,
+    Text,
+    TextInput,
+}
+
+impl AccessibilityRole {
+    /// All roles, in the order of their names
+    pub const ALL: &'static [AccessibilityRole] = &[
+        AccessibilityRole::Alert,
+        AccessibilityRole::Button,
+        AccessibilityRole::Checkbox,
+        AccessibilityRole::Dialog,
+        AccessibilityRole::Group,
+        AccessibilityRole::Heading,
+        AccessibilityRole::Image,
+        AccessibilityRole::Link,
+        AccessibilityRole::List,
+        AccessibilityRole::ListItem,
+        AccessibilityRole::Navigation,
+        AccessibilityRole::ProgressBar,
+        AccessibilityRole::Radio,
+        AccessibilityRole::Region,
+        AccessibilityRole::Slider,
+        AccessibilityRole::Switch,
+        AccessibilityRole::Tab,
+        AccessibilityRole::TabList,
+        AccessibilityRole::Text,
+        AccessibilityRole::TextInput,
+    ];
+
+    /// Parse the value of the `role` attribute
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|role| role.as_str().eq_ignore_ascii_case(s))
+    }
+
+    /// Name of the role as written in XML
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccessibilityRole::Alert => "alert",
+            AccessibilityRole::Button => "button",
+            AccessibilityRole::Checkbox => "checkbox",
+            AccessibilityRole::Dialog => "dialog",
+            AccessibilityRole::Group => "group",
+            AccessibilityRole::Heading => "heading",
+            AccessibilityRole::Image => "image",
+            AccessibilityRole::Link => "link",
+            AccessibilityRole::List => "list",
+            AccessibilityRole::ListItem => "list_item",
+            AccessibilityRole::Navigation => "navigation",
+            AccessibilityRole::ProgressBar => "progress_bar",
+            AccessibilityRole::Radio => "radio",
+            AccessibilityRole::Region => "region",
+            AccessibilityRole::Slider => "slider",
+            AccessibilityRole::Switch => "switch",
+            AccessibilityRole::Tab => "tab",
+            AccessibilityRole::TabList => "tab_list",
+            AccessibilityRole::Text => "text",
+            AccessibilityRole::TextInput => "text_input",
+        }
+    }
+}
+
+impl std::fmt::Display for AccessibilityRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
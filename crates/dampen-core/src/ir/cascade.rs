@@ -0,0 +1,383 @@
+//! Style cascade of a widget.
+//!
+//! A widget's style is resolved in layers, each overriding the properties set
+//! by the previous ones: the defaults of the active theme for the widget's kind,
+//! then its classes in the order they are listed, then its inline attributes.
+//! [`StyleCascade`] keeps the layers apart so tools can show where each computed
+//! value comes from.
+
+use std::collections::HashMap;
+
+use crate::ir::node::{WidgetKind, WidgetNode};
+use crate::ir::style::{Background, BorderSides, Color, StyleProperties};
+use crate::ir::theme::{StyleClass, Theme, ThemePalette};
+
+/// Origin of a layer of the cascade.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StyleSource {
+    /// Defaults of the named theme for the widget's kind
+    Theme(String),
+    /// A style class of the widget
+    Class(String),
+    /// Inline style attributes of the widget
+    Inline,
+}
+
+impl std::fmt::Display for StyleSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StyleSource::Theme(name) => write!(f, "theme `{}`", name),
+            StyleSource::Class(name) => write!(f, "class `{}`", name),
+            StyleSource::Inline => write!(f, "inline"),
+        }
+    }
+}
+
+/// A layer of the cascade: the properties set by one source.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyleLayer {
+    pub source: StyleSource,
+    pub style: StyleProperties,
+}
+
+/// The layers of a widget's style, from lowest to highest precedence.
+///
+/// # Examples
+///
+/// ```rust
+/// use dampen_core::ir::cascade::{StyleCascade, StyleSource};
+/// use dampen_core::parse;
+///
+/// let doc = parse(r##"<dampen>
+///     <styles>
+///         <style name="primary"><base background="#3498db" color="#ffffff" /></style>
+///     </styles>
+///     <button label="Save" class="primary" background="#e74c3c" />
+/// </dampen>"##).unwrap();
+///
+/// let cascade = StyleCascade::resolve(&doc.root, &doc.style_classes, None);
+/// assert_eq!(cascade.layers.len(), 2);
+/// assert_eq!(cascade.origin("background"), Some(&StyleSource::Inline));
+/// assert_eq!(
+///     cascade.origin("color"),
+///     Some(&StyleSource::Class("primary".to_string()))
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct StyleCascade {
+    /// Layers that set at least one property
+    pub layers: Vec<StyleLayer>,
+    /// Classes of the widget that are not defined
+    pub missing_classes: Vec<String>,
+}
+
+impl StyleCascade {
+    /// Resolve the cascade of `node` with the style classes of its document and
+    /// the active theme, if any
+    ///
+    /// Classes are the static classes of the widget: bound and toggled classes
+    /// are only known at runtime, see [`StyleCascade::resolve_with_classes`].
+    pub fn resolve(
+        node: &WidgetNode,
+        style_classes: &HashMap<String, StyleClass>,
+        theme: Option<&Theme>,
+    ) -> Self {
+        Self::resolve_with_classes(node, &node.classes, style_classes, theme)
+    }
+
+    /// Resolve the cascade of `node` with its classes evaluated when the view
+    /// is built
+    ///
+    /// `classes` are the classes of `class`, then the toggled classes whose
+    /// condition holds (`class:active="{selected}"`).
+    pub fn resolve_with_classes(
+        node: &WidgetNode,
+        classes: &[String],
+        style_classes: &HashMap<String, StyleClass>,
+        theme: Option<&Theme>,
+    ) -> Self {
+        let mut cascade = StyleCascade::default();
+
+        if let Some(theme) = theme
+            && let Some(style) = theme_defaults(&node.kind, &theme.palette)
+        {
+            cascade.push(StyleSource::Theme(theme.name.clone()), style);
+        }
+
+        for name in classes {
+            match style_classes.get(name) {
+                Some(class) => {
+                    cascade.push(StyleSource::Class(name.clone()), class.style.clone());
+                }
+                None => cascade.missing_classes.push(name.clone()),
+            }
+        }
+
+        if let Some(style) = &node.style {
+            cascade.push(StyleSource::Inline, style.clone());
+        }
+
+        cascade
+    }
+
+    fn push(&mut self, source: StyleSource, style: StyleProperties) {
+        if style != StyleProperties::default() {
+            self.layers.push(StyleLayer { source, style });
+        }
+    }
+
+    /// The final style, each layer overriding the properties of the previous ones
+    pub fn computed(&self) -> StyleProperties {
+        self.layers
+            .iter()
+            .fold(StyleProperties::default(), |base, layer| {
+                merge(base, &layer.style)
+            })
+    }
+
+    /// The layer setting the computed value of `property`, named as in
+    /// [`describe_style`]
+    pub fn origin(&self, property: &str) -> Option<&StyleSource> {
+        self.layers
+            .iter()
+            .rev()
+            .find(|layer| {
+                describe_style(&layer.style)
+                    .iter()
+                    .any(|(name, _)| *name == property)
+            })
+            .map(|layer| &layer.source)
+    }
+}
+
+/// Default style of a widget kind under a theme palette, as applied by the
+/// runtime below classes and inline styles
+///
+/// Buttons take the `primary` background and containers the `surface`
+/// background; every widget but containers takes the `text` color.
+pub fn theme_defaults(kind: &WidgetKind, palette: &ThemePalette) -> Option<StyleProperties> {
+    let (background, color) = match kind {
+        WidgetKind::Button => (palette.primary, palette.text),
+        WidgetKind::Container => (palette.surface, None),
+        _ => (None, palette.text),
+    };
+    let style = StyleProperties {
+        background: background.map(Background::Color),
+        color,
+        ..StyleProperties::default()
+    };
+
+    (style.background.is_some() || style.color.is_some()).then_some(style)
+}
+
+/// Set properties of a style, by attribute name, with their values as written
+/// in XML where possible
+pub fn describe_style(style: &StyleProperties) -> Vec<(&'static str, String)> {
+    let mut properties = Vec::new();
+
+    if let Some(background) = &style.background {
+        let value = match background {
+            Background::Color(color) => describe_color(color),
+            Background::Gradient(_) => "gradient".to_string(),
+            Background::Image { path, .. } => format!("url({})", path),
+        };
+        properties.push(("background", value));
+    }
+    if let Some(color) = &style.color {
+        properties.push(("color", describe_color(color)));
+    }
+    if let Some(border) = &style.border {
+        properties.push((
+            "border",
+            format!(
+                "{}px {:?} {}",
+                border.width,
+                border.style,
+                describe_color(&border.color)
+            )
+            .to_lowercase(),
+        ));
+        let radius = &border.radius;
+        if [radius.top_right, radius.bottom_right, radius.bottom_left]
+            .iter()
+            .all(|r| *r == radius.top_left)
+        {
+            if radius.top_left != 0.0 {
+                properties.push(("border_radius", format!("{}", radius.top_left)));
+            }
+        } else {
+            properties.push((
+                "border_radius",
+                format!(
+                    "{} {} {} {}",
+                    radius.top_left, radius.top_right, radius.bottom_right, radius.bottom_left
+                ),
+            ));
+        }
+    }
+    if let Some(shadow) = &style.shadow {
+        properties.push((
+            "shadow",
+            format!(
+                "{} {} {} {}",
+                shadow.offset_x,
+                shadow.offset_y,
+                shadow.blur_radius,
+                describe_color(&shadow.color)
+            ),
+        ));
+    }
+    if let Some(opacity) = style.opacity {
+        properties.push(("opacity", format!("{}", opacity)));
+    }
+    if let Some(transform) = &style.transform {
+        properties.push(("transform", format!("{:?}", transform)));
+    }
+    if let Some(sides) = &style.border_sides {
+        let sides = [
+            ("border_top", &sides.top),
+            ("border_right", &sides.right),
+            ("border_bottom", &sides.bottom),
+            ("border_left", &sides.left),
+        ];
+        for (name, side) in sides {
+            if let Some(side) = side {
+                properties.push((
+                    name,
+                    format!("{} {}", side.width, describe_color(&side.color)),
+                ));
+            }
+        }
+    }
+    if let Some(outline) = &style.outline {
+        properties.push((
+            "outline",
+            format!(
+                "{} {} {}",
+                outline.width,
+                describe_color(&outline.color),
+                outline.offset
+            ),
+        ));
+    }
+    if let Some(elevation) = style.elevation {
+        properties.push(("elevation", elevation.to_string()));
+    }
+
+    properties
+}
+
+/// Hex value of a color, with its alpha channel if translucent
+pub fn describe_color(color: &Color) -> String {
+    if color.a < 1.0 {
+        color.to_rgba_hex()
+    } else {
+        color.to_hex()
+    }
+}
+
+fn merge(base: StyleProperties, overrides: &StyleProperties) -> StyleProperties {
+    StyleProperties {
+        background: overrides.background.clone().or(base.background),
+        color: overrides.color.or(base.color),
+        border: overrides.border.clone().or(base.border),
+        shadow: overrides.shadow.or(base.shadow),
+        opacity: overrides.opacity.or(base.opacity),
+        transform: overrides.transform.clone().or(base.transform),
+        border_sides: BorderSides::cascade(base.border_sides, overrides.border_sides),
+        outline: overrides.outline.or(base.outline),
+        elevation: overrides.elevation.or(base.elevation),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::theme::{FontWeight, SpacingScale, Typography};
+    use crate::parse;
+
+    fn theme() -> Theme {
+        Theme {
+            name: "light".to_string(),
+            palette: ThemePalette::light(),
+            typography: Typography {
+                font_family: None,
+                font_size_base: None,
+                font_size_small: None,
+                font_size_large: None,
+                font_weight: FontWeight::Normal,
+                line_height: None,
+                scale_ratio: None,
+                fonts: Vec::new(),
+            },
+            spacing: SpacingScale { unit: None },
+            shadows: Default::default(),
+            base_styles: HashMap::new(),
+            extends: None,
+        }
+    }
+
+    #[test]
+    fn test_cascade_layers_in_order() {
+        let doc = parse(
+            r##"<dampen>
+    <styles>
+        <style name="card"><base background="#ffffff" opacity="0.9" /></style>
+        <style name="danger"><base background="#e74c3c" /></style>
+    </styles>
+    <button label="Delete" class="card danger missing" opacity="0.5" />
+</dampen>"##,
+        )
+        .unwrap_or_else(|e| unreachable!("{}", e));
+        let theme = theme();
+
+        let cascade = StyleCascade::resolve(&doc.root, &doc.style_classes, Some(&theme));
+        let sources: Vec<String> = cascade
+            .layers
+            .iter()
+            .map(|layer| layer.source.to_string())
+            .collect();
+        assert_eq!(
+            sources,
+            ["theme `light`", "class `card`", "class `danger`", "inline"]
+        );
+        assert_eq!(cascade.missing_classes, ["missing"]);
+
+        let computed = describe_style(&cascade.computed());
+        assert!(computed.contains(&("background", "#e74c3c".to_string())));
+        assert!(computed.contains(&("opacity", "0.5".to_string())));
+        assert_eq!(
+            cascade.origin("background"),
+            Some(&StyleSource::Class("danger".to_string()))
+        );
+        assert_eq!(
+            cascade.origin("color"),
+            Some(&StyleSource::Theme("light".to_string()))
+        );
+        assert_eq!(cascade.origin("border"), None);
+    }
+
+    #[test]
+    fn test_theme_defaults_by_kind() {
+        let palette = ThemePalette::light();
+        let button = theme_defaults(&WidgetKind::Button, &palette);
+        assert_eq!(
+            button.and_then(|style| style.background),
+            palette.primary.map(Background::Color)
+        );
+
+        let text = theme_defaults(&WidgetKind::Text, &palette);
+        assert_eq!(text.and_then(|style| style.background), None);
+
+        assert_eq!(
+            theme_defaults(
+                &WidgetKind::Text,
+                &ThemePalette {
+                    text: None,
+                    ..palette
+                }
+            ),
+            None
+        );
+    }
+}
@@ -0,0 +1,233 @@
+//! Structural diffing of widget trees.
+//!
+//! Hot-reload replaces the whole document, but most edits only touch a small part
+//! of the tree. This module compares two IR trees node by node and reports the
+//! paths of the subtrees that actually changed, so backends can keep the
+//! widgets (and their internal state) of unchanged subtrees.
+//!
+//! The diff walks both trees together and compares each pair of nodes by their
+//! own properties only, stopping at the first difference on each branch and
+//! skipping children shared by both trees. Source spans are ignored: moving a
+//! widget down a few lines in the XML file does not make it "changed".
+//!
+//! [`structural_hash`] is not used by the diff: it hashes a whole subtree for
+//! backends that key caches by content, such as memoized subtrees. It is
+//! [stable](crate::ir::stable), so it can be compared with hashes computed by
+//! another process.
+
+use std::hash::{Hash, Hasher};
+
+use crate::ir::DampenDocument;
+use crate::ir::node::WidgetNode;
+use crate::ir::stable::{StableHasher, hash_serialized};
+
+/// Path of a node in the widget tree, as child indices from the root.
+///
+/// The root node has an empty path; `[1, 0]` is the first child of the root's
+/// second child.
+pub type NodePath = Vec<usize>;
+
+/// Result of comparing two documents.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DocumentDiff {
+    /// Paths (in the new tree) of the subtrees that must be rebuilt
+    pub changed_paths: Vec<NodePath>,
+
+    /// Whether theme definitions, the global theme or system-theme following changed
+    pub themes_changed: bool,
+
+    /// Whether style class definitions changed
+    pub style_classes_changed: bool,
+}
+
+impl DocumentDiff {
+    /// Returns `true` if the documents are structurally identical
+    pub fn is_unchanged(&self) -> bool {
+        self.changed_paths.is_empty() && !self.themes_changed && !self.style_classes_changed
+    }
+
+    /// Returns `true` if the whole tree must be rebuilt
+    ///
+    /// This is the case when the root itself changed, or when themes or style
+    /// classes changed (they can affect every widget).
+    pub fn is_full_rebuild(&self) -> bool {
+        self.themes_changed
+            || self.style_classes_changed
+            || self.changed_paths.iter().any(|path| path.is_empty())
+    }
+}
+
+/// Compute the structural hash of a widget subtree.
+///
+/// Two subtrees have the same hash when they have the same kind, id, attributes,
+/// events, styles, layout, classes and children, regardless of their source spans.
+/// This visits the whole subtree; [`diff_nodes`] compares trees without it.
+pub fn structural_hash(node: &WidgetNode) -> u64 {
+    let mut hasher = StableHasher::default();
+    shallow_hash(node).hash(&mut hasher);
+    node.children.len().hash(&mut hasher);
+    for child in &node.children {
+        structural_hash(child).hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// Compare two widget trees and return the paths of changed subtrees.
+///
+/// A node is reported (and its children are not inspected further) when its kind,
+/// id, own properties or number of children differ. Otherwise children are compared
+/// pairwise by index.
+pub fn diff_nodes(old: &WidgetNode, new: &WidgetNode) -> Vec<NodePath> {
+    let mut changed = Vec::new();
+    let mut path = Vec::new();
+    diff_recursive(old, new, &mut path, &mut changed);
+    changed
+}
+
+/// Compare two documents.
+///
+/// # Example
+///
+/// ```rust
+/// use dampen_core::parse;
+/// use dampen_core::ir::diff::diff_documents;
+///
+/// let old = parse(r#"<column><text value="A" /><text value="B" /></column>"#).unwrap();
+/// let new = parse(r#"<column><text value="A" /><text value="C" /></column>"#).unwrap();
+///
+/// let diff = diff_documents(&old, &new);
+/// assert_eq!(diff.changed_paths, vec![vec![1]]);
+/// assert!(!diff.is_full_rebuild());
+/// ```
+pub fn diff_documents(old: &DampenDocument, new: &DampenDocument) -> DocumentDiff {
+    DocumentDiff {
+        changed_paths: diff_nodes(&old.root, &new.root),
+        themes_changed: old.themes != new.themes
+            || old.global_theme != new.global_theme
+            || old.follow_system != new.follow_system,
+        style_classes_changed: old.style_classes != new.style_classes,
+    }
+}
+
+fn diff_recursive(
+    old: &WidgetNode,
+    new: &WidgetNode,
+    path: &mut NodePath,
+    changed: &mut Vec<NodePath>,
+) {
+    // Subtrees shared by both trees (see `Children`) are not compared
+    if std::ptr::eq(old, new) {
+        return;
+    }
+
+    if old.kind != new.kind
+        || old.id != new.id
+        || old.children.len() != new.children.len()
+        || shallow_hash(old) != shallow_hash(new)
+    {
+        changed.push(path.clone());
+        return;
+    }
+
+    // Equal subtrees report no path, so children are compared directly rather
+    // than hashing the whole subtree at every level
+    if old.children.ptr_eq(&new.children) {
+        return;
+    }
+    for (index, (old_child, new_child)) in old.children.iter().zip(&new.children).enumerate() {
+        path.push(index);
+        diff_recursive(old_child, new_child, path, changed);
+        path.pop();
+    }
+}
+
+/// Hash of a node's own properties, excluding children and spans.
+fn shallow_hash(node: &WidgetNode) -> u64 {
+    let mut hasher = StableHasher::default();
+    node.kind.hash(&mut hasher);
+    node.id.hash(&mut hasher);
+    node.classes.hash(&mut hasher);
+    hash_serialized(&node.attributes, &mut hasher);
+    hash_serialized(&node.events, &mut hasher);
+    hash_serialized(&node.style, &mut hasher);
+    hash_serialized(&node.layout, &mut hasher);
+    hash_serialized(&node.theme_ref, &mut hasher);
+    hash_serialized(&node.breakpoint_attributes, &mut hasher);
+    hash_serialized(&node.inline_state_variants, &mut hasher);
+    hash_serialized(&node.accessibility, &mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::ir::WidgetKind;
+    use crate::ir::node::AttributeValue;
+    use crate::parser::parse;
+
+    #[test]
+    fn test_identical_documents_are_unchanged() {
+        let xml = r#"<column><text value="A" /><button label="Go" on_click="go" /></column>"#;
+        let diff = diff_documents(&parse(xml).unwrap(), &parse(xml).unwrap());
+        assert!(diff.is_unchanged());
+    }
+
+    #[test]
+    fn test_spans_are_ignored() {
+        let old = parse(r#"<column><text value="A" /></column>"#).unwrap();
+        let new = parse("<column>\n\n    <text value=\"A\" />\n</column>").unwrap();
+        assert_eq!(structural_hash(&old.root), structural_hash(&new.root));
+    }
+
+    #[test]
+    fn test_changed_leaf_reports_its_path() {
+        let old =
+            parse(r#"<column><row><text value="A" /><text value="B" /></row></column>"#).unwrap();
+        let new =
+            parse(r#"<column><row><text value="A" /><text value="X" /></row></column>"#).unwrap();
+        assert_eq!(diff_nodes(&old.root, &new.root), vec![vec![0, 1]]);
+    }
+
+    #[test]
+    fn test_added_child_reports_parent() {
+        let old = parse(r#"<column><row><text value="A" /></row></column>"#).unwrap();
+        let new =
+            parse(r#"<column><row><text value="A" /><text value="B" /></row></column>"#).unwrap();
+        assert_eq!(diff_nodes(&old.root, &new.root), vec![vec![0]]);
+    }
+
+    #[test]
+    fn test_deep_tree_reports_changed_leaf() {
+        fn nested(depth: usize, value: &str) -> WidgetNode {
+            let value = AttributeValue::Static(value.to_string());
+            let mut node = WidgetNode {
+                kind: WidgetKind::Text,
+                attributes: [("value".to_string(), value)].into(),
+                ..Default::default()
+            };
+            for _ in 0..depth {
+                node = WidgetNode {
+                    kind: WidgetKind::Column,
+                    children: vec![node].into(),
+                    ..Default::default()
+                };
+            }
+            node
+        }
+
+        let old = nested(500, "A");
+        assert!(diff_nodes(&old, &nested(500, "A")).is_empty());
+        assert_eq!(diff_nodes(&old, &nested(500, "B")), vec![vec![0; 500]]);
+    }
+
+    #[test]
+    fn test_root_change_is_full_rebuild() {
+        let old = parse(r#"<column spacing="10"><text value="A" /></column>"#).unwrap();
+        let new = parse(r#"<column spacing="20"><text value="A" /></column>"#).unwrap();
+        let diff = diff_documents(&old, &new);
+        assert_eq!(diff.changed_paths, vec![Vec::<usize>::new()]);
+        assert!(diff.is_full_rebuild());
+    }
+}
@@ -0,0 +1,140 @@
+//! Overlay layers of a window
+//!
+//! Content drawn over the view is stacked in fixed [`Layer`]s, lowest first:
+//! the view itself, dropdowns, modals, toasts and the development overlays
+//! (error overlay, inspector). Two overlays of the same layer keep the order in
+//! which they were added.
+//!
+//! A `<float>` with a `layer` attribute is a portal: instead of being laid out
+//! where it is declared, it is rendered over the whole window in that layer,
+//! aligned by its `position` (centered by default):
+//!
+//! ```xml
+//! <float layer="modal" show="{confirm_open}">
+//!     <container padding="24">
+//!         <text value="Delete this file?" />
+//!     </container>
+//! </float>
+//! ```
+//!
+//! Portals are rendered with the model of the document, so they must not be
+//! declared inside a `<for>` loop.
+
+use crate::ir::node::{AttributeValue, WidgetKind, WidgetNode};
+use serde::{Deserialize, Serialize};
+
+/// Layer of content drawn over the view, ordered from bottom to top
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+pub enum Layer {
+    /// The view itself
+    #[default]
+    Content,
+    /// Menus, pick lists and other content opened from a widget
+    Dropdown,
+    /// Dialogs and palettes blocking the view below
+    Modal,
+    /// Notifications
+    Toast,
+    /// Development tools, such as the error overlay
+    DevOverlay,
+}
+
+impl Layer {
+    /// Every layer, from bottom to top
+    pub const ALL: [Layer; 5] = [
+        Layer::Content,
+        Layer::Dropdown,
+        Layer::Modal,
+        Layer::Toast,
+        Layer::DevOverlay,
+    ];
+
+    /// Parse from string: `content`, `dropdown`, `modal`, `toast` or `dev-overlay`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dampen_core::ir::layer::Layer;
+    ///
+    /// assert_eq!(Layer::parse("modal"), Ok(Layer::Modal));
+    /// assert!(Layer::Modal < Layer::Toast);
+    /// assert!(Layer::parse("top").is_err());
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let s = s.trim().to_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|layer| layer.name() == s)
+            .ok_or_else(|| {
+                format!(
+                    "Invalid layer '{}'. Expected one of: content, dropdown, modal, toast, dev-overlay",
+                    s
+                )
+            })
+    }
+
+    /// Name of the layer in XML
+    pub fn name(self) -> &'static str {
+        match self {
+            Layer::Content => "content",
+            Layer::Dropdown => "dropdown",
+            Layer::Modal => "modal",
+            Layer::Toast => "toast",
+            Layer::DevOverlay => "dev-overlay",
+        }
+    }
+}
+
+/// Layer of a `<float>` portal, `None` for other nodes
+///
+/// Anchored floats are shown next to their anchor and are never portals.
+pub fn portal_layer(node: &WidgetNode) -> Option<Layer> {
+    if node.kind != WidgetKind::Float || node.attributes.contains_key("anchor") {
+        return None;
+    }
+    match node.attributes.get("layer") {
+        Some(AttributeValue::Static(layer)) => Layer::parse(layer).ok(),
+        _ => None,
+    }
+}
+
+/// The `<float>` portals of the tree, in document order
+pub fn portal_floats(root: &WidgetNode) -> Vec<(Layer, &WidgetNode)> {
+    fn collect<'a>(node: &'a WidgetNode, portals: &mut Vec<(Layer, &'a WidgetNode)>) {
+        if let Some(layer) = portal_layer(node) {
+            portals.push((layer, node));
+        }
+        for child in &node.children {
+            collect(child, portals);
+        }
+    }
+
+    let mut portals = Vec::new();
+    collect(root, &mut portals);
+    portals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn test_portal_floats() {
+        let xml = r#"<column>
+            <float layer="toast"><text value="Saved" /></float>
+            <row>
+                <float layer="modal"><text value="Confirm" /></float>
+                <float position="TopRight"><text value="Badge" /></float>
+            </row>
+        </column>"#;
+        let doc = parse(xml).unwrap();
+
+        let layers: Vec<Layer> = portal_floats(&doc.root)
+            .into_iter()
+            .map(|(layer, _)| layer)
+            .collect();
+        assert_eq!(layers, vec![Layer::Toast, Layer::Modal]);
+    }
+}
@@ -36,6 +36,16 @@ pub struct LayoutConstraints {
     /// Layout direction
     pub direction: Option<Direction>,
 
+    /// Wrap children onto multiple lines (row, column)
+    pub wrap: Option<bool>,
+    /// Portions of the free space along the main axis of the parent row or column
+    pub grow: Option<u8>,
+
+    /// Ratio of the width to the height (`16:9` is 1.78)
+    pub aspect_ratio: Option<f32>,
+    /// Handling of content larger than the widget
+    pub overflow: Option<Overflow>,
+
     /// Positioning
     pub position: Option<Position>,
     pub top: Option<f32>,
@@ -53,8 +63,9 @@ impl LayoutConstraints {
     /// - min_height > max_height
     /// - spacing is negative
     /// - padding values are negative
-    /// - fill_portion is not 1-255
+    /// - fill_portion or grow is not 1-255
     /// - percentage is not 0.0-100.0
+    /// - aspect_ratio is not positive
     pub fn validate(&self) -> Result<(), String> {
         if let (Some(min), Some(max)) = (self.min_width, self.max_width)
             && min > max
@@ -95,6 +106,10 @@ impl LayoutConstraints {
             return Err(format!("fill_portion must be 1-255, got {}", n));
         }
 
+        if self.grow == Some(0) {
+            return Err("grow must be 1-255, got 0".to_string());
+        }
+
         if let Some(Length::Percentage(p)) = self.width
             && !(0.0..=100.0).contains(&p)
         {
@@ -107,6 +122,12 @@ impl LayoutConstraints {
             return Err(format!("percentage must be 0.0-100.0, got {}", p));
         }
 
+        if let Some(ratio) = self.aspect_ratio
+            && !(ratio.is_finite() && ratio > 0.0)
+        {
+            return Err(format!("aspect_ratio must be positive, got {}", ratio));
+        }
+
         // Position-related validation
         if self.position.is_some() {
             // If position is set, at least one offset should be provided
@@ -384,8 +405,38 @@ impl Position {
     }
 }
 
+/// Handling of content larger than the widget
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Overflow {
+    /// Content is drawn past the bounds (default)
+    Visible,
+    /// Content is cut at the bounds
+    Clip,
+    /// Content scrolls vertically within the bounds
+    Scroll,
+}
+
+impl Overflow {
+    /// Parse from string
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.trim().to_lowercase().as_str() {
+            "visible" => Ok(Overflow::Visible),
+            "clip" => Ok(Overflow::Clip),
+            "scroll" => Ok(Overflow::Scroll),
+            _ => Err(format!(
+                "Invalid overflow: '{}'. Expected visible, clip or scroll",
+                s
+            )),
+        }
+    }
+}
+
 /// Responsive breakpoint
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+///
+/// Serialized as a string, so breakpoints can key the maps of breakpoint
+/// attributes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+#[serde(from = "String", into = "String")]
 pub enum Breakpoint {
     /// < 640px
     Mobile,
@@ -393,6 +444,8 @@ pub enum Breakpoint {
     Tablet,
     /// >= 1024px
     Desktop,
+    /// Breakpoint declared by a `<breakpoints>` element (see [`Breakpoints`])
+    Custom(String),
 }
 
 impl Breakpoint {
@@ -406,15 +459,141 @@ impl Breakpoint {
     }
 
     /// Parse from string
+    ///
+    /// `mobile`, `tablet` and `desktop` are always known; any other name is a
+    /// custom breakpoint, which must be an identifier (`sm`, `xl`, `wide_2`).
+    /// Use [`Breakpoints::parse`] to accept only the breakpoints of a document.
     pub fn parse(s: &str) -> Result<Self, String> {
-        match s.trim().to_lowercase().as_str() {
+        let name = s.trim().to_lowercase();
+        match name.as_str() {
             "mobile" => Ok(Breakpoint::Mobile),
             "tablet" => Ok(Breakpoint::Tablet),
             "desktop" => Ok(Breakpoint::Desktop),
+            _ if is_breakpoint_name(&name) => Ok(Breakpoint::Custom(name)),
             _ => Err(format!(
-                "Invalid breakpoint: '{}'. Expected mobile, tablet, or desktop",
+                "Invalid breakpoint: '{}'. Expected mobile, tablet, desktop or a custom breakpoint name",
                 s
             )),
         }
     }
+
+    /// Name of the breakpoint, as written in attribute prefixes
+    pub fn name(&self) -> &str {
+        match self {
+            Breakpoint::Mobile => "mobile",
+            Breakpoint::Tablet => "tablet",
+            Breakpoint::Desktop => "desktop",
+            Breakpoint::Custom(name) => name,
+        }
+    }
+}
+
+impl From<String> for Breakpoint {
+    fn from(name: String) -> Self {
+        match name.as_str() {
+            "Mobile" => Breakpoint::Mobile,
+            "Tablet" => Breakpoint::Tablet,
+            "Desktop" => Breakpoint::Desktop,
+            _ => Breakpoint::Custom(name),
+        }
+    }
+}
+
+impl From<Breakpoint> for String {
+    fn from(breakpoint: Breakpoint) -> Self {
+        match breakpoint {
+            Breakpoint::Custom(name) => name,
+            fixed => format!("{:?}", fixed),
+        }
+    }
+}
+
+impl std::fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Whether `name` can name a custom breakpoint
+fn is_breakpoint_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_lowercase())
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// Breakpoints of a document
+///
+/// Without a `<breakpoints>` element, a document has the fixed `mobile`,
+/// `tablet` and `desktop` breakpoints. Declaring its own replaces them:
+///
+/// ```xml
+/// <breakpoints sm="480" md="768" lg="1200" xl="1600" />
+/// ```
+///
+/// Each value is the minimum width of the breakpoint: a window 900px wide is
+/// `md`, and no breakpoint applies below the smallest one (480px here).
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Breakpoints {
+    /// Custom breakpoints and their minimum width, by increasing width;
+    /// empty for the fixed breakpoints
+    custom: Vec<(String, f32)>,
+}
+
+impl Breakpoints {
+    /// Custom breakpoints from their names and minimum widths
+    pub fn custom(breakpoints: impl IntoIterator<Item = (String, f32)>) -> Self {
+        let mut custom: Vec<_> = breakpoints.into_iter().collect();
+        custom.sort_by(|a, b| a.1.total_cmp(&b.1));
+        Breakpoints { custom }
+    }
+
+    /// Whether these are the fixed `mobile`, `tablet` and `desktop` breakpoints
+    pub fn is_default(&self) -> bool {
+        self.custom.is_empty()
+    }
+
+    /// The breakpoints, by increasing minimum width
+    pub fn all(&self) -> Vec<(Breakpoint, f32)> {
+        if self.is_default() {
+            return vec![
+                (Breakpoint::Mobile, 0.0),
+                (Breakpoint::Tablet, 640.0),
+                (Breakpoint::Desktop, 1024.0),
+            ];
+        }
+        self.custom
+            .iter()
+            .map(|(name, width)| (Breakpoint::Custom(name.clone()), *width))
+            .collect()
+    }
+
+    /// Minimum widths at which the breakpoint changes
+    pub fn thresholds(&self) -> Vec<f32> {
+        self.all()
+            .into_iter()
+            .map(|(_, width)| width)
+            .filter(|width| *width > 0.0)
+            .collect()
+    }
+
+    /// Breakpoint named `name`, if it is one of these breakpoints
+    pub fn parse(&self, name: &str) -> Option<Breakpoint> {
+        let breakpoint = Breakpoint::parse(name).ok()?;
+        self.all()
+            .into_iter()
+            .any(|(known, _)| known == breakpoint)
+            .then_some(breakpoint)
+    }
+
+    /// Breakpoint of a viewport `width` pixels wide, `None` below the smallest one
+    pub fn at_width(&self, width: f32) -> Option<Breakpoint> {
+        if self.is_default() {
+            return Some(Breakpoint::from_viewport_width(width));
+        }
+        self.custom
+            .iter()
+            .rev()
+            .find(|(_, min_width)| width >= *min_width)
+            .map(|(name, _)| Breakpoint::Custom(name.clone()))
+    }
 }
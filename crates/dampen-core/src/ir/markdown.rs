@@ -0,0 +1,458 @@
+//! Markdown documents
+//!
+//! The `<markdown>` widget renders a document written in a safe subset of
+//! CommonMark:
+//!
+//! - ATX (`# Title`) and setext (`Title` underlined with `===` or `---`) headings
+//! - Paragraphs, with a hard line break after two trailing spaces
+//! - Bullet (`-`, `*`, `+`) and ordered (`1.`, `1)`) lists, one level deep
+//! - Fenced (```` ``` ```` or `~~~`) and indented code blocks
+//! - Thematic breaks (`---`, `***`, `___`)
+//! - The inline subset of [`parse_markdown`]: emphasis and links
+//!
+//! Everything else (HTML, images, tables, ...) is rendered as literal text, so
+//! untrusted content such as chat messages can be displayed safely.
+
+use super::rich_text::{TextSpan, parse_markdown};
+
+/// A block of a Markdown document
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum MarkdownBlock {
+    /// Heading of level 1 to 6
+    Heading { level: u8, spans: Vec<TextSpan> },
+    /// Paragraph of inline text
+    Paragraph(Vec<TextSpan>),
+    /// List items, numbered from `start` when the list is ordered
+    List {
+        start: Option<u64>,
+        items: Vec<Vec<TextSpan>>,
+    },
+    /// Code shown verbatim, with the language of its info string
+    CodeBlock {
+        language: Option<String>,
+        code: String,
+    },
+    /// Thematic break
+    Rule,
+}
+
+/// Marker of a list item
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ListMarker {
+    Bullet(char),
+    Ordered(u64, char),
+}
+
+impl ListMarker {
+    /// Whether an item with this marker continues a list started with `other`
+    fn continues(&self, other: &ListMarker) -> bool {
+        match (self, other) {
+            (ListMarker::Bullet(a), ListMarker::Bullet(b)) => a == b,
+            (ListMarker::Ordered(_, a), ListMarker::Ordered(_, b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+/// List being parsed
+struct OpenList {
+    marker: ListMarker,
+    items: Vec<String>,
+}
+
+/// Parse a Markdown document into blocks
+///
+/// # Example
+///
+/// ```rust
+/// use dampen_core::ir::markdown::{MarkdownBlock, parse_blocks};
+///
+/// let blocks = parse_blocks("# Changelog\n\n- Added **markdown**\n- Fixed links");
+/// assert_eq!(blocks.len(), 2);
+/// assert!(matches!(blocks[0], MarkdownBlock::Heading { level: 1, .. }));
+/// assert!(matches!(&blocks[1], MarkdownBlock::List { items, .. } if items.len() == 2));
+/// ```
+pub fn parse_blocks(source: &str) -> Vec<MarkdownBlock> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut blocks = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut list: Option<OpenList> = None;
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        let indent = indentation(line);
+        let content = line.trim();
+
+        if content.is_empty() {
+            flush_paragraph(&mut blocks, &mut paragraph);
+            // A list continues over blank lines when the next item has the same marker
+            let next = lines[i + 1..].iter().find(|line| !line.trim().is_empty());
+            let continues = match (&list, next.and_then(|line| list_item(line))) {
+                (Some(open), Some((marker, _))) => marker.continues(&open.marker),
+                _ => false,
+            };
+            if !continues {
+                flush_list(&mut blocks, &mut list);
+            }
+            i += 1;
+            continue;
+        }
+
+        if indent < 4
+            && let Some((fence, len)) = fence(content)
+        {
+            flush_paragraph(&mut blocks, &mut paragraph);
+            flush_list(&mut blocks, &mut list);
+            let language = content[len..].split_whitespace().next().map(str::to_string);
+
+            let mut code = Vec::new();
+            i += 1;
+            while i < lines.len() {
+                let closing = lines[i].trim();
+                if indentation(lines[i]) < 4
+                    && closing.len() >= len
+                    && closing.chars().all(|c| c == fence)
+                {
+                    i += 1;
+                    break;
+                }
+                code.push(strip_indent(lines[i], indent));
+                i += 1;
+            }
+
+            blocks.push(MarkdownBlock::CodeBlock {
+                language,
+                code: code.join("\n"),
+            });
+            continue;
+        }
+
+        if indent >= 4 && paragraph.is_empty() && list.is_none() {
+            let mut code = Vec::new();
+            while i < lines.len() && (lines[i].trim().is_empty() || indentation(lines[i]) >= 4) {
+                code.push(strip_indent(lines[i], 4));
+                i += 1;
+            }
+            while code.last().is_some_and(|line| line.trim().is_empty()) {
+                code.pop();
+            }
+
+            blocks.push(MarkdownBlock::CodeBlock {
+                language: None,
+                code: code.join("\n"),
+            });
+            continue;
+        }
+
+        if indent < 4
+            && !paragraph.is_empty()
+            && let Some(level) = setext_level(content)
+        {
+            let text = paragraph_text(&paragraph);
+            paragraph.clear();
+            blocks.push(MarkdownBlock::Heading {
+                level,
+                spans: parse_markdown(&text),
+            });
+            i += 1;
+            continue;
+        }
+
+        if indent < 4 && is_rule(content) {
+            flush_paragraph(&mut blocks, &mut paragraph);
+            flush_list(&mut blocks, &mut list);
+            blocks.push(MarkdownBlock::Rule);
+            i += 1;
+            continue;
+        }
+
+        if indent < 4
+            && let Some((level, text)) = atx_heading(content)
+        {
+            flush_paragraph(&mut blocks, &mut paragraph);
+            flush_list(&mut blocks, &mut list);
+            blocks.push(MarkdownBlock::Heading {
+                level,
+                spans: parse_markdown(text),
+            });
+            i += 1;
+            continue;
+        }
+
+        if indent < 4
+            && let Some((marker, text)) = list_item(line)
+        {
+            flush_paragraph(&mut blocks, &mut paragraph);
+            match &mut list {
+                Some(open) if marker.continues(&open.marker) => open.items.push(text.to_string()),
+                _ => {
+                    flush_list(&mut blocks, &mut list);
+                    list = Some(OpenList {
+                        marker,
+                        items: vec![text.to_string()],
+                    });
+                }
+            }
+            i += 1;
+            continue;
+        }
+
+        // Continuation of the last list item, or of the paragraph
+        match list.as_mut().and_then(|open| open.items.last_mut()) {
+            Some(item) => {
+                if !item.is_empty() {
+                    item.push(' ');
+                }
+                item.push_str(content);
+            }
+            None => paragraph.push(line),
+        }
+        i += 1;
+    }
+
+    flush_paragraph(&mut blocks, &mut paragraph);
+    flush_list(&mut blocks, &mut list);
+    blocks
+}
+
+fn flush_paragraph(blocks: &mut Vec<MarkdownBlock>, paragraph: &mut Vec<&str>) {
+    if paragraph.is_empty() {
+        return;
+    }
+    blocks.push(MarkdownBlock::Paragraph(parse_markdown(&paragraph_text(
+        paragraph,
+    ))));
+    paragraph.clear();
+}
+
+fn flush_list(blocks: &mut Vec<MarkdownBlock>, list: &mut Option<OpenList>) {
+    if let Some(open) = list.take() {
+        blocks.push(MarkdownBlock::List {
+            start: match open.marker {
+                ListMarker::Ordered(start, _) => Some(start),
+                ListMarker::Bullet(_) => None,
+            },
+            items: open.items.iter().map(|item| parse_markdown(item)).collect(),
+        });
+    }
+}
+
+/// Join the lines of a paragraph: soft breaks become spaces, two trailing
+/// spaces a line break
+fn paragraph_text(lines: &[&str]) -> String {
+    let mut text = String::new();
+    for (i, line) in lines.iter().enumerate() {
+        text.push_str(line.trim());
+        if i + 1 < lines.len() {
+            text.push(if line.ends_with("  ") { '\n' } else { ' ' });
+        }
+    }
+    text
+}
+
+/// Width of the leading whitespace, a tab counting as four spaces
+fn indentation(line: &str) -> usize {
+    line.chars()
+        .take_while(|c| c.is_whitespace())
+        .map(|c| if c == '\t' { 4 } else { 1 })
+        .sum()
+}
+
+/// Remove up to `width` columns of leading whitespace
+fn strip_indent(line: &str, width: usize) -> String {
+    let mut removed = 0;
+    for (i, c) in line.char_indices() {
+        if removed >= width || !(c == ' ' || c == '\t') {
+            return line[i..].to_string();
+        }
+        removed += if c == '\t' { 4 } else { 1 };
+    }
+    String::new()
+}
+
+/// Opening code fence: its character and length
+fn fence(content: &str) -> Option<(char, usize)> {
+    let fence = content.chars().next().filter(|&c| c == '`' || c == '~')?;
+    let len = content.chars().take_while(|&c| c == fence).count();
+    // A backtick fence cannot have backticks in its info string
+    (len >= 3 && !(fence == '`' && content[len..].contains('`'))).then_some((fence, len))
+}
+
+/// `# Title` heading: its level and text, without the closing sequence
+fn atx_heading(content: &str) -> Option<(u8, &str)> {
+    let level = content.chars().take_while(|&c| c == '#').count();
+    let rest = &content[level..];
+    if !(1..=6).contains(&level) || !(rest.is_empty() || rest.starts_with([' ', '\t'])) {
+        return None;
+    }
+
+    let text = rest.trim();
+    let text = match text.trim_end_matches('#') {
+        stripped if stripped.is_empty() || stripped.ends_with([' ', '\t']) => stripped.trim_end(),
+        _ => text,
+    };
+    Some((level as u8, text))
+}
+
+/// Level of a setext underline: 1 for `===`, 2 for `---`
+fn setext_level(content: &str) -> Option<u8> {
+    match content.chars().next()? {
+        '=' if content.chars().all(|c| c == '=') => Some(1),
+        '-' if content.chars().all(|c| c == '-') => Some(2),
+        _ => None,
+    }
+}
+
+/// Whether the line is a thematic break: three or more `-`, `*` or `_`
+fn is_rule(content: &str) -> bool {
+    let mut marks = content.chars().filter(|c| !c.is_whitespace());
+    let Some(mark) = marks.next().filter(|c| matches!(c, '-' | '*' | '_')) else {
+        return false;
+    };
+    let mut count = 1;
+    for c in marks {
+        if c != mark {
+            return false;
+        }
+        count += 1;
+    }
+    count >= 3
+}
+
+/// List item: its marker and text
+fn list_item(line: &str) -> Option<(ListMarker, &str)> {
+    let content = line.trim_start();
+    let first = content.chars().next()?;
+
+    let (marker, rest) = if matches!(first, '-' | '*' | '+') {
+        (ListMarker::Bullet(first), &content[1..])
+    } else {
+        let digits = content.chars().take_while(char::is_ascii_digit).count();
+        if !(1..=9).contains(&digits) {
+            return None;
+        }
+        let delimiter = content[digits..].chars().next()?;
+        if delimiter != '.' && delimiter != ')' {
+            return None;
+        }
+        let start = content[..digits].parse().ok()?;
+        (
+            ListMarker::Ordered(start, delimiter),
+            &content[digits + 1..],
+        )
+    };
+
+    if rest.is_empty() {
+        return Some((marker, ""));
+    }
+    rest.starts_with([' ', '\t'])
+        .then_some((marker, rest.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(text: &str) -> Vec<TextSpan> {
+        vec![TextSpan {
+            text: text.to_string(),
+            ..Default::default()
+        }]
+    }
+
+    #[test]
+    fn test_headings_paragraphs_and_rules() {
+        let source = "# Title #\n\nSome **bold**\ntext.  \nNext line\n\nSub\n---\n\n***\n###### Deep\n####### Not";
+        let blocks = parse_blocks(source);
+
+        assert_eq!(
+            blocks[0],
+            MarkdownBlock::Heading {
+                level: 1,
+                spans: plain("Title")
+            }
+        );
+        let MarkdownBlock::Paragraph(spans) = &blocks[1] else {
+            panic!("expected a paragraph, got {:?}", blocks[1]);
+        };
+        assert_eq!(spans[1].text, "bold");
+        assert_eq!(spans[2].text, " text.\nNext line");
+        assert_eq!(
+            blocks[2],
+            MarkdownBlock::Heading {
+                level: 2,
+                spans: plain("Sub")
+            }
+        );
+        assert_eq!(blocks[3], MarkdownBlock::Rule);
+        assert_eq!(
+            blocks[4],
+            MarkdownBlock::Heading {
+                level: 6,
+                spans: plain("Deep")
+            }
+        );
+        assert_eq!(blocks[5], MarkdownBlock::Paragraph(plain("####### Not")));
+    }
+
+    #[test]
+    fn test_lists() {
+        let source = "- one\n- two\n  continued\n\n- three\n\n3. a\n4) b\n\n* other";
+        let blocks = parse_blocks(source);
+
+        assert_eq!(
+            blocks,
+            vec![
+                MarkdownBlock::List {
+                    start: None,
+                    items: vec![plain("one"), plain("two continued"), plain("three")],
+                },
+                MarkdownBlock::List {
+                    start: Some(3),
+                    items: vec![plain("a")],
+                },
+                MarkdownBlock::List {
+                    start: Some(4),
+                    items: vec![plain("b")],
+                },
+                MarkdownBlock::List {
+                    start: None,
+                    items: vec![plain("other")],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_code_blocks_are_verbatim() {
+        let source = "```rust\nfn main() {\n    **not bold**\n}\n```\n\n    indented\n      code\n\n~~~\nunclosed";
+        let blocks = parse_blocks(source);
+
+        assert_eq!(
+            blocks,
+            vec![
+                MarkdownBlock::CodeBlock {
+                    language: Some("rust".to_string()),
+                    code: "fn main() {\n    **not bold**\n}".to_string(),
+                },
+                MarkdownBlock::CodeBlock {
+                    language: None,
+                    code: "indented\n  code".to_string(),
+                },
+                MarkdownBlock::CodeBlock {
+                    language: None,
+                    code: "unclosed".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_html_is_literal() {
+        assert_eq!(
+            parse_blocks("<script>alert(1)</script>"),
+            vec![MarkdownBlock::Paragraph(plain("<script>alert(1)</script>"))]
+        );
+    }
+}
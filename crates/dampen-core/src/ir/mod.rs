@@ -1,27 +1,46 @@
+pub mod accessibility;
+pub mod cascade;
+pub mod diff;
+pub mod layer;
 pub mod layout;
+pub mod markdown;
 pub mod menu;
 pub mod node;
+pub mod placement;
+pub mod rich_text;
+pub mod scope;
 pub mod span;
+pub mod stable;
 pub mod style;
 pub mod theme;
+pub mod window;
 
 use std::collections::HashMap;
 
+pub use accessibility::{Accessibility, AccessibilityRole};
+pub use diff::{DocumentDiff, NodePath, diff_documents, structural_hash};
 pub use layout::{
-    Alignment, Breakpoint, Direction, Justification, LayoutConstraints, Length, Padding,
+    Alignment, Breakpoint, Breakpoints, Direction, Justification, LayoutConstraints, Length,
+    Overflow, Padding,
 };
 pub use menu::MenuPosition;
 pub use node::InterpolatedPart;
-pub use node::{AttributeValue, EventBinding, EventKind, WidgetKind, WidgetNode};
-pub use span::Span;
+pub use node::{
+    AttributeValue, CLASS_TOGGLE_PREFIX, Children, DOC_ANNOTATION, EventBinding, EventKind,
+    OPTION_TEMPLATE_ATTRIBUTES, PathCommand, WidgetKind, WidgetNode,
+};
+pub use placement::Placement;
+pub use rich_text::TextSpan;
+pub use span::{AttributeSpan, Span};
 pub use style::{
     Background, Border, BorderRadius, BorderStyle, Color, Gradient, ImageFit, Shadow,
     StyleProperties, Transform,
 };
 pub use theme::{
-    FontWeight, IcedPaletteColors, SpacingScale, StateSelector, StyleClass, Theme, ThemeDocument,
-    ThemeError, ThemeErrorKind, ThemePalette, Typography, WidgetState,
+    FontWeight, IcedPaletteColors, ShadowScale, SpacingScale, StateSelector, StyleClass, Theme,
+    TextStyle, ThemeDocument, ThemeError, ThemeErrorKind, ThemePalette, Typography, WidgetState,
 };
+pub use window::WindowConfig;
 
 /// A complete parsed Dampen UI document.
 ///
@@ -46,9 +65,11 @@ pub struct DampenDocument {
     pub root: WidgetNode,
 
     /// Theme definitions
+    #[serde(serialize_with = "stable::sorted")]
     pub themes: HashMap<String, crate::ir::theme::Theme>,
 
     /// Style class definitions
+    #[serde(serialize_with = "stable::sorted")]
     pub style_classes: HashMap<String, crate::ir::theme::StyleClass>,
 
     /// Global theme name
@@ -56,6 +77,18 @@ pub struct DampenDocument {
 
     /// Whether to follow system theme (light/dark mode)
     pub follow_system: bool,
+
+    /// Whether unknown attributes and style classes are errors (`<dampen strict="true">`)
+    #[serde(default)]
+    pub strict: bool,
+
+    /// Settings of the application window (`<window>`)
+    #[serde(default)]
+    pub window: Option<WindowConfig>,
+
+    /// Responsive breakpoints (`<breakpoints>`), the fixed ones by default
+    #[serde(default)]
+    pub breakpoints: Breakpoints,
 }
 
 impl Default for DampenDocument {
@@ -68,7 +101,69 @@ impl Default for DampenDocument {
             style_classes: HashMap::new(),
             global_theme: None,
             follow_system: true,
+            strict: false,
+            window: None,
+            breakpoints: Breakpoints::default(),
+        }
+    }
+}
+
+impl DampenDocument {
+    /// Merges the themes and style classes of a style library into this document.
+    ///
+    /// Style libraries are `.dampen` files holding only `<themes>` and `<styles>`,
+    /// shared by several views. Definitions of this document take precedence over
+    /// those of the library, and the library's global theme is used only if this
+    /// document has none. The widget tree is unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dampen_core::parse;
+    ///
+    /// let library = parse(r#"<dampen>
+    ///     <styles>
+    ///         <style name="card"><base padding="16" /></style>
+    ///     </styles>
+    /// </dampen>"#).unwrap();
+    ///
+    /// let mut view = parse(r#"<dampen><container class="card" /></dampen>"#).unwrap();
+    /// view.merge(&library);
+    /// assert!(view.style_classes.contains_key("card"));
+    /// ```
+    pub fn merge(&mut self, other: &DampenDocument) {
+        for (name, theme) in &other.themes {
+            self.themes
+                .entry(name.clone())
+                .or_insert_with(|| theme.clone());
+        }
+        for (name, class) in &other.style_classes {
+            self.style_classes
+                .entry(name.clone())
+                .or_insert_with(|| class.clone());
         }
+        if self.global_theme.is_none() {
+            self.global_theme.clone_from(&other.global_theme);
+        }
+    }
+
+    /// Hash of the document's content, identical across runs and platforms.
+    ///
+    /// Source spans are ignored, and themes, style classes and attributes hash
+    /// independently of their `HashMap` order. The build cache uses it to reuse
+    /// generated code, and hot-reload to skip documents whose content is unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dampen_core::parse;
+    ///
+    /// let a = parse(r#"<text value="Hi" size="14" />"#).unwrap();
+    /// let b = parse("\n<text size=\"14\"\n      value=\"Hi\" />").unwrap();
+    /// assert_eq!(a.stable_hash(), b.stable_hash());
+    /// ```
+    pub fn stable_hash(&self) -> u64 {
+        stable::stable_hash(self)
     }
 }
 
@@ -1,17 +1,25 @@
+use crate::ir::accessibility::Accessibility;
 use crate::ir::layout::{Breakpoint, LayoutConstraints};
-use crate::ir::span::Span;
+use crate::ir::span::{AttributeSpan, Span};
 use crate::ir::style::StyleProperties;
 use crate::ir::theme::WidgetState;
+use crate::parser::{is_visual_attribute, parse_layout_attributes, parse_style_attributes};
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
 
 /// A node in the widget tree
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, Default)]
 pub struct WidgetNode {
     pub kind: WidgetKind,
     pub id: Option<String>,
+    #[serde(serialize_with = "crate::ir::stable::sorted")]
     pub attributes: HashMap<String, AttributeValue>,
     pub events: Vec<EventBinding>,
-    pub children: Vec<WidgetNode>,
+    pub children: Children,
+    #[serde(skip_serializing_if = "crate::ir::stable::skip_source")]
     pub span: Span,
 
     // Styling extensions
@@ -19,10 +27,305 @@ pub struct WidgetNode {
     pub layout: Option<LayoutConstraints>,
     pub theme_ref: Option<AttributeValue>,
     pub classes: Vec<String>,
+    #[serde(serialize_with = "crate::ir::stable::sorted_nested")]
     pub breakpoint_attributes: HashMap<Breakpoint, HashMap<String, AttributeValue>>,
     /// State-specific styles from inline attributes (e.g., hover:background="#ff0000")
-    #[serde(default)]
+    #[serde(default, serialize_with = "crate::ir::stable::sorted")]
     pub inline_state_variants: HashMap<WidgetState, StyleProperties>,
+    /// Accessibility metadata (aria_label, role, described_by)
+    #[serde(default)]
+    pub accessibility: Option<Box<Accessibility>>,
+    /// Source locations of the attributes, by attribute name
+    #[serde(
+        default,
+        serialize_with = "crate::ir::stable::sorted",
+        skip_serializing_if = "crate::ir::stable::skip_source"
+    )]
+    pub attribute_spans: HashMap<String, AttributeSpan>,
+    /// Text of the `<!-- comments -->` written before the element, in order
+    ///
+    /// Comments don't affect rendering; see [`WidgetNode::doc`].
+    #[serde(default, skip_serializing_if = "crate::ir::stable::skip_comments")]
+    pub comments: Vec<String>,
+}
+
+/// Prefix of the comments documenting the following widget
+///
+/// ```xml
+/// <!-- @doc: Saves the form, disabled until it is valid -->
+/// <button label="Save" on_click="save" enabled="{form.valid}" />
+/// ```
+pub const DOC_ANNOTATION: &str = "@doc:";
+
+/// Prefix of the attributes toggling a class, kept in [`WidgetNode::attributes`]
+///
+/// ```xml
+/// <container class="card" class:card-active="{selected}" />
+/// ```
+pub const CLASS_TOGGLE_PREFIX: &str = "class:";
+
+/// Attributes of a `<pick_list>` or `<combobox>` evaluated once per option of a
+/// bound `options` list, with the option as `{item}` and its position as `{index}`
+///
+/// `display` is the text shown for the option (the option itself by default) and
+/// `value` the value dispatched when it is selected (`display` by default).
+/// A `<radio_group>` only has `display`: its `value` is the selected option.
+pub const OPTION_TEMPLATE_ATTRIBUTES: &[&str] = &["display", "value"];
+
+impl WidgetNode {
+    /// Documentation of this widget, from its `<!-- @doc: ... -->` comments
+    ///
+    /// Several annotations are joined by line breaks.
+    pub fn doc(&self) -> Option<String> {
+        let lines: Vec<&str> = self
+            .comments
+            .iter()
+            .filter_map(|comment| comment.strip_prefix(DOC_ANNOTATION))
+            .map(str::trim)
+            .collect();
+        (!lines.is_empty()).then(|| lines.join("\n"))
+    }
+
+    /// Location of an attribute's value, or of the whole element if unknown
+    pub fn attribute_span(&self, name: &str) -> Span {
+        self.attribute_spans
+            .get(name)
+            .map_or(self.span, |span| span.value)
+    }
+
+    /// Whether the `options` of this pick list, combo box or radio group are bound
+    /// to a collection
+    pub fn has_bound_options(&self) -> bool {
+        matches!(
+            self.kind,
+            WidgetKind::PickList | WidgetKind::ComboBox | WidgetKind::RadioGroup
+        ) && matches!(
+            self.attributes.get("options"),
+            Some(AttributeValue::Binding(_))
+        )
+    }
+
+    /// Whether attribute `name` is evaluated per option rather than against the model
+    ///
+    /// See [`OPTION_TEMPLATE_ATTRIBUTES`].
+    pub fn is_option_template(&self, name: &str) -> bool {
+        let templates: &[&str] = match self.kind {
+            WidgetKind::RadioGroup => &["display"],
+            _ => OPTION_TEMPLATE_ATTRIBUTES,
+        };
+        templates.contains(&name) && self.has_bound_options()
+    }
+
+    /// This node as shown at `breakpoint`
+    ///
+    /// The attributes prefixed with the breakpoint (`mobile-spacing="10"`)
+    /// replace the base attributes of the same name. The node is borrowed when
+    /// it has no attribute for this breakpoint; otherwise the copy has no
+    /// breakpoint attributes left and shares the children of the node.
+    ///
+    /// Layout and style are parsed again from the resulting attributes; an
+    /// invalid breakpoint value keeps the base layout or style.
+    pub fn for_breakpoint(&self, breakpoint: Breakpoint) -> Cow<'_, WidgetNode> {
+        let Some(overrides) = self.breakpoint_attributes.get(&breakpoint) else {
+            return Cow::Borrowed(self);
+        };
+        let mut resolved = self.clone();
+        resolved.breakpoint_attributes.clear();
+        for (name, value) in overrides {
+            resolved.attributes.insert(name.clone(), value.clone());
+        }
+        if let Ok(layout) = parse_layout_attributes(&resolved.kind, &resolved.attributes) {
+            resolved.layout = layout;
+        }
+        if let Ok(style) = parse_style_attributes(&resolved.attributes) {
+            resolved.style = style;
+        }
+        Cow::Owned(resolved)
+    }
+
+    /// Classes toggled by a `class:<name>` attribute, with their condition
+    ///
+    /// A toggled class applies after the classes of `class` while its
+    /// condition is true.
+    pub fn class_toggles(&self) -> impl Iterator<Item = (&str, &AttributeValue)> {
+        self.attributes.iter().filter_map(|(name, value)| {
+            name.strip_prefix(CLASS_TOGGLE_PREFIX)
+                .map(|class| (class, value))
+        })
+    }
+
+    /// Whether the classes of this node are only known when the view is built
+    ///
+    /// They are when `class` is bound or a class is toggled; [`WidgetNode::classes`]
+    /// then only holds the static ones.
+    pub fn has_dynamic_classes(&self) -> bool {
+        self.attributes
+            .get("class")
+            .is_some_and(|class| !matches!(class, AttributeValue::Static(_)))
+            || self.class_toggles().next().is_some()
+    }
+
+    /// Layout and style attributes of this node bound to the model
+    ///
+    /// Their values are only known when the view is built, so they are not
+    /// part of [`WidgetNode::layout`] and [`WidgetNode::style`].
+    pub fn bound_visual_attributes(&self) -> impl Iterator<Item = (&str, &AttributeValue)> {
+        self.attributes.iter().filter_map(|(name, value)| {
+            (!matches!(value, AttributeValue::Static(_)) && is_visual_attribute(&self.kind, name))
+                .then_some((name.as_str(), value))
+        })
+    }
+
+    /// This node with its bound layout and style attributes set to `values`
+    ///
+    /// `values` holds the evaluated value of each attribute of
+    /// [`WidgetNode::bound_visual_attributes`]; an empty value unsets the
+    /// attribute. Layout and style are parsed again from the resulting
+    /// attributes, and the copy shares the children of the node.
+    ///
+    /// # Errors
+    ///
+    /// Returns the parse error of an invalid value.
+    pub fn with_visual_values(
+        &self,
+        values: impl IntoIterator<Item = (String, String)>,
+    ) -> Result<WidgetNode, String> {
+        let mut resolved = self.clone();
+        for (name, value) in values {
+            if value.trim().is_empty() {
+                resolved.attributes.remove(&name);
+            } else {
+                resolved
+                    .attributes
+                    .insert(name, AttributeValue::Static(value));
+            }
+        }
+        resolved.layout = parse_layout_attributes(&resolved.kind, &resolved.attributes)?;
+        resolved.style = parse_style_attributes(&resolved.attributes)?;
+        Ok(resolved)
+    }
+}
+
+/// Children of a [`WidgetNode`], shared between the clones of a tree
+///
+/// Cloning a node only bumps a reference count: the original and the clone
+/// share their subtrees until one of them is mutated. Mutable access (through
+/// `DerefMut`, `&mut` iteration or [`Children::make_mut`]) copies the list of
+/// children first if it is shared, the subtrees themselves staying shared, so
+/// editing one node of a cloned tree copies the nodes on its path only.
+///
+/// Children read and mutate like a `Vec<WidgetNode>`:
+///
+/// ```rust
+/// use dampen_core::ir::{WidgetKind, WidgetNode};
+///
+/// let mut column = WidgetNode::default();
+/// column.children.push(WidgetNode::default());
+///
+/// let mut copy = column.clone();
+/// assert!(copy.children.ptr_eq(&column.children));
+///
+/// copy.children[0].kind = WidgetKind::Text;
+/// assert!(!copy.children.ptr_eq(&column.children));
+/// assert_eq!(column.children[0].kind, WidgetKind::Column);
+/// ```
+#[derive(Clone, Default)]
+pub struct Children(Arc<Vec<WidgetNode>>);
+
+impl Children {
+    /// Whether both lists are the same shared allocation
+    ///
+    /// Shared children are equal without being compared.
+    pub fn ptr_eq(&self, other: &Children) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+
+    /// Mutable access to the children, copying the list first if it is shared
+    pub fn make_mut(&mut self) -> &mut Vec<WidgetNode> {
+        Arc::make_mut(&mut self.0)
+    }
+
+    /// The children, copied only if they are shared
+    pub fn into_vec(self) -> Vec<WidgetNode> {
+        Arc::unwrap_or_clone(self.0)
+    }
+}
+
+impl Deref for Children {
+    type Target = Vec<WidgetNode>;
+
+    fn deref(&self) -> &Vec<WidgetNode> {
+        &self.0
+    }
+}
+
+impl DerefMut for Children {
+    fn deref_mut(&mut self) -> &mut Vec<WidgetNode> {
+        self.make_mut()
+    }
+}
+
+impl PartialEq for Children {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr_eq(other) || self.0 == other.0
+    }
+}
+
+impl fmt::Debug for Children {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<Vec<WidgetNode>> for Children {
+    fn from(children: Vec<WidgetNode>) -> Self {
+        Children(Arc::new(children))
+    }
+}
+
+impl FromIterator<WidgetNode> for Children {
+    fn from_iter<I: IntoIterator<Item = WidgetNode>>(iter: I) -> Self {
+        Children(Arc::new(iter.into_iter().collect()))
+    }
+}
+
+impl IntoIterator for Children {
+    type Item = WidgetNode;
+    type IntoIter = std::vec::IntoIter<WidgetNode>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_vec().into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Children {
+    type Item = &'a WidgetNode;
+    type IntoIter = std::slice::Iter<'a, WidgetNode>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Children {
+    type Item = &'a mut WidgetNode;
+    type IntoIter = std::slice::IterMut<'a, WidgetNode>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.make_mut().iter_mut()
+    }
+}
+
+impl serde::Serialize for Children {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Children {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(Children::from)
+    }
 }
 
 /// Enumeration of all supported widget types
@@ -37,8 +340,14 @@ pub enum WidgetKind {
     Text,
     Image,
     Svg,
+    /// Icon from the built-in icon set
+    Icon,
     Button,
     TextInput,
+    /// Numeric field with step buttons, bounded by `min` and `max`
+    NumberInput,
+    /// Multi-line, syntax-highlighted text area with line numbers
+    CodeEditor,
     Checkbox,
     Slider,
     PickList,
@@ -46,10 +355,20 @@ pub enum WidgetKind {
     Space,
     Rule,
     Radio,
+    /// Group of radio buttons selecting one value, from `options` or `<radio>` children
+    RadioGroup,
+    /// Inline run of formatted text inside a `<text>` widget
+    Span,
+    /// Document written in a safe subset of CommonMark
+    Markdown,
     // Advanced widgets
     ComboBox,
     ProgressBar,
+    /// Shimmering placeholder lines shown while content loads
+    Skeleton,
     Tooltip,
+    /// Rich content shown by a `<tooltip>` instead of its message
+    TooltipContent,
     Grid,
     Canvas,
     CanvasRect,
@@ -57,6 +376,14 @@ pub enum WidgetKind {
     CanvasLine,
     CanvasText,
     CanvasGroup,
+    /// Free-form path described by SVG-like path data
+    CanvasPath,
+    /// Circular arc between two angles
+    CanvasArc,
+    /// Quadratic or cubic Bézier curve
+    CanvasBezier,
+    /// Line, bar or pie chart bound to a collection
+    Chart,
     /// Date selection widget with calendar overlay
     DatePicker,
     /// Time selection widget with hour/minute/second picker
@@ -74,6 +401,8 @@ pub enum WidgetKind {
     // Tree widget
     TreeView,
     TreeNode,
+    /// Tree of model items, with children loaded when a node is expanded
+    Tree,
     // Tab widgets
     TabBar,
     Tab,
@@ -168,6 +497,20 @@ pub enum TooltipPosition {
     Right,
 }
 
+impl TooltipPosition {
+    /// Parse the value of a tooltip's `position` attribute
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "follow_cursor" => Some(Self::FollowCursor),
+            "top" => Some(Self::Top),
+            "bottom" => Some(Self::Bottom),
+            "left" => Some(Self::Left),
+            "right" => Some(Self::Right),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct GridAttributes {
     pub columns: u32,
@@ -198,6 +541,11 @@ pub struct EventBinding {
     pub handler: String,
     /// Optional parameter expression (e.g., for on_click="delete:{item.id}")
     pub param: Option<crate::expr::BindingExpr>,
+    /// Parameter expressions when several are given
+    /// (e.g., for on_click="move:{item.id},{index},'up'"); `param` is `None` then
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub params: Vec<crate::expr::BindingExpr>,
+    #[serde(skip_serializing_if = "crate::ir::stable::skip_source")]
     pub span: Span,
 }
 
@@ -222,6 +570,13 @@ pub enum EventKind {
     Cancel,
     Open,
     Close,
+    DragStart,
+    DragOver,
+    Drop,
+    ContextMenu,
+    Link,
+    ReachEnd,
+    Expand,
 }
 
 impl std::fmt::Display for WidgetKind {
@@ -235,8 +590,11 @@ impl std::fmt::Display for WidgetKind {
             WidgetKind::Text => "text",
             WidgetKind::Image => "image",
             WidgetKind::Svg => "svg",
+            WidgetKind::Icon => "icon",
             WidgetKind::Button => "button",
             WidgetKind::TextInput => "text_input",
+            WidgetKind::NumberInput => "number_input",
+            WidgetKind::CodeEditor => "code_editor",
             WidgetKind::Checkbox => "checkbox",
             WidgetKind::Slider => "slider",
             WidgetKind::PickList => "pick_list",
@@ -244,9 +602,14 @@ impl std::fmt::Display for WidgetKind {
             WidgetKind::Space => "space",
             WidgetKind::Rule => "rule",
             WidgetKind::Radio => "radio",
+            WidgetKind::RadioGroup => "radio_group",
             WidgetKind::ComboBox => "combobox",
             WidgetKind::ProgressBar => "progress_bar",
+            WidgetKind::Skeleton => "skeleton",
+            WidgetKind::Span => "span",
+            WidgetKind::Markdown => "markdown",
             WidgetKind::Tooltip => "tooltip",
+            WidgetKind::TooltipContent => "tooltip_content",
             WidgetKind::Grid => "grid",
             WidgetKind::Canvas => "canvas",
             WidgetKind::CanvasRect => "rect",
@@ -254,6 +617,10 @@ impl std::fmt::Display for WidgetKind {
             WidgetKind::CanvasLine => "line",
             WidgetKind::CanvasText => "canvas_text",
             WidgetKind::CanvasGroup => "group",
+            WidgetKind::CanvasPath => "path",
+            WidgetKind::CanvasArc => "arc",
+            WidgetKind::CanvasBezier => "bezier",
+            WidgetKind::Chart => "chart",
             WidgetKind::DatePicker => "date_picker",
             WidgetKind::TimePicker => "time_picker",
             WidgetKind::ColorPicker => "color_picker",
@@ -266,6 +633,7 @@ impl std::fmt::Display for WidgetKind {
             WidgetKind::DataColumn => "data_column",
             WidgetKind::TreeView => "tree_view",
             WidgetKind::TreeNode => "tree_node",
+            WidgetKind::Tree => "tree",
             WidgetKind::TabBar => "tab_bar",
             WidgetKind::Tab => "tab",
             WidgetKind::For => "for",
@@ -288,8 +656,11 @@ impl WidgetKind {
             "text",
             "image",
             "svg",
+            "icon",
             "button",
             "text_input",
+            "number_input",
+            "code_editor",
             "checkbox",
             "slider",
             "pick_list",
@@ -297,9 +668,14 @@ impl WidgetKind {
             "space",
             "rule",
             "radio",
+            "radio_group",
+            "span",
+            "markdown",
             "combobox",
             "progress_bar",
+            "skeleton",
             "tooltip",
+            "tooltip_content",
             "grid",
             "canvas",
             "rect",
@@ -307,6 +683,10 @@ impl WidgetKind {
             "line",
             "canvas_text",
             "group",
+            "path",
+            "arc",
+            "bezier",
+            "chart",
             "date_picker",
             "time_picker",
             "color_picker",
@@ -319,6 +699,7 @@ impl WidgetKind {
             "data_column",
             "tree_view",
             "tree_node",
+            "tree",
             "tab_bar",
             "tab",
             "for",
@@ -359,7 +740,9 @@ impl WidgetKind {
         // Canvas is a v1.1 widget (experimental, not fully functional)
         // All other widgets are part of v1.0
         match self {
-            WidgetKind::Canvas => crate::ir::SchemaVersion { major: 1, minor: 1 },
+            WidgetKind::Canvas | WidgetKind::Chart => {
+                crate::ir::SchemaVersion { major: 1, minor: 1 }
+            }
             WidgetKind::DatePicker
             | WidgetKind::TimePicker
             | WidgetKind::ColorPicker
@@ -410,6 +793,9 @@ mod tests {
             classes: Default::default(),
             breakpoint_attributes: Default::default(),
             inline_state_variants: Default::default(),
+            accessibility: None,
+            attribute_spans: Default::default(),
+            comments: Default::default(),
         };
 
         // Add state variant
@@ -473,6 +859,64 @@ mod tests {
                 .contains_key(&WidgetState::Disabled)
         );
     }
+
+    #[test]
+    fn test_for_breakpoint_replaces_base_attributes() {
+        let document =
+            crate::parse(r#"<column spacing="20" mobile-spacing="10"><text value="A" /></column>"#)
+                .expect("Should parse");
+        let root = &document.root;
+
+        assert!(matches!(
+            root.for_breakpoint(Breakpoint::Desktop),
+            Cow::Borrowed(_)
+        ));
+        let mobile = root.for_breakpoint(Breakpoint::Mobile);
+        assert_eq!(
+            mobile.attributes.get("spacing"),
+            Some(&AttributeValue::Static("10".to_string()))
+        );
+        assert_eq!(
+            mobile.layout.as_ref().and_then(|layout| layout.spacing),
+            Some(10.0)
+        );
+        assert!(mobile.breakpoint_attributes.is_empty());
+        assert!(mobile.children.ptr_eq(&root.children));
+    }
+
+    #[test]
+    fn test_with_visual_values_parses_bound_attributes() {
+        let document = crate::parse(
+            r#"<container width="{sidebar_width}" padding="8" background="{panel_color}" />"#,
+        )
+        .expect("Should parse");
+        let root = &document.root;
+
+        let mut bound: Vec<&str> = root
+            .bound_visual_attributes()
+            .map(|(name, _)| name)
+            .collect();
+        bound.sort_unstable();
+        assert_eq!(bound, ["background", "width"]);
+        assert!(root.style.is_none());
+
+        let resolved = root
+            .with_visual_values([
+                ("width".to_string(), "240".to_string()),
+                ("background".to_string(), String::new()),
+            ])
+            .expect("Should resolve");
+        let layout = resolved.layout.as_ref().expect("Should have a layout");
+        assert_eq!(layout.width, Some(crate::ir::layout::Length::Fixed(240.0)));
+        assert!(layout.padding.is_some());
+        assert!(resolved.style.is_none());
+        assert_eq!(resolved.bound_visual_attributes().count(), 0);
+
+        assert!(
+            root.with_visual_values([("width".to_string(), "wide".to_string())])
+                .is_err()
+        );
+    }
 }
 
 // Canvas Shape Types
@@ -490,6 +934,12 @@ pub enum CanvasShape {
     Text(TextShape),
     /// A group of shapes with a common transformation.
     Group(GroupShape),
+    /// A free-form path.
+    Path(PathShape),
+    /// A circular arc.
+    Arc(ArcShape),
+    /// A Bézier curve (boxed, as it has the most attributes).
+    Bezier(Box<BezierShape>),
 }
 
 /// Attributes for a declarative rectangle shape.
@@ -562,6 +1012,87 @@ pub struct TextShape {
     pub color: Option<AttributeValue>,
 }
 
+/// Attributes for a declarative path shape.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PathShape {
+    /// The path data (e.g., `M10 10 L50 80 Z`).
+    pub d: AttributeValue,
+    /// The fill color.
+    pub fill: Option<AttributeValue>,
+    /// The stroke color.
+    pub stroke: Option<AttributeValue>,
+    /// The width of the stroke.
+    pub stroke_width: Option<AttributeValue>,
+}
+
+/// Attributes for a declarative arc shape.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ArcShape {
+    /// The X coordinate of the center.
+    pub cx: AttributeValue,
+    /// The Y coordinate of the center.
+    pub cy: AttributeValue,
+    /// The radius of the arc.
+    pub radius: AttributeValue,
+    /// The start angle in radians.
+    pub start_angle: AttributeValue,
+    /// The end angle in radians.
+    pub end_angle: AttributeValue,
+    /// The fill color.
+    pub fill: Option<AttributeValue>,
+    /// The stroke color.
+    pub stroke: Option<AttributeValue>,
+    /// The width of the stroke.
+    pub stroke_width: Option<AttributeValue>,
+}
+
+/// Attributes for a declarative Bézier curve.
+///
+/// The curve is cubic when the second control point is set, quadratic otherwise.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct BezierShape {
+    /// The X coordinate of the starting point.
+    pub x1: AttributeValue,
+    /// The Y coordinate of the starting point.
+    pub y1: AttributeValue,
+    /// The X coordinate of the first control point.
+    pub cx1: AttributeValue,
+    /// The Y coordinate of the first control point.
+    pub cy1: AttributeValue,
+    /// The X coordinate of the second control point.
+    pub cx2: Option<AttributeValue>,
+    /// The Y coordinate of the second control point.
+    pub cy2: Option<AttributeValue>,
+    /// The X coordinate of the ending point.
+    pub x2: AttributeValue,
+    /// The Y coordinate of the ending point.
+    pub y2: AttributeValue,
+    /// The fill color.
+    pub fill: Option<AttributeValue>,
+    /// The stroke color.
+    pub stroke: Option<AttributeValue>,
+    /// The width of the stroke.
+    pub stroke_width: Option<AttributeValue>,
+}
+
+/// A drawing command of a path, in absolute coordinates.
+///
+/// Produced by [`parse_path_data`](crate::parser::canvas::parse_path_data), which
+/// resolves relative and shorthand commands.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum PathCommand {
+    /// Start a new subpath at (x, y).
+    MoveTo(f32, f32),
+    /// Draw a straight line to (x, y).
+    LineTo(f32, f32),
+    /// Draw a quadratic curve with control point (cx, cy) to (x, y).
+    QuadraticTo(f32, f32, f32, f32),
+    /// Draw a cubic curve with control points (cx1, cy1), (cx2, cy2) to (x, y).
+    CubicTo(f32, f32, f32, f32, f32, f32),
+    /// Close the current subpath.
+    Close,
+}
+
 /// Attributes for a group of canvas shapes.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct GroupShape {
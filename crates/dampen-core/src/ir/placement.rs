@@ -0,0 +1,316 @@
+//! Placement of anchored floating content
+//!
+//! A `<float anchor="...">` is shown next to the widget with this id, on a side
+//! of it given by its `placement`. Content that would leave the window is
+//! flipped to the opposite side when it fits there, and is then kept within
+//! the window along the side.
+
+use crate::ir::node::{AttributeValue, WidgetKind, WidgetNode};
+use serde::{Deserialize, Serialize};
+
+/// Side of the anchor the content is placed on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Side {
+    Top,
+    /// Below the anchor (default)
+    #[default]
+    Bottom,
+    Left,
+    Right,
+}
+
+/// Alignment of the content along the side of the anchor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PlacementAlign {
+    /// Aligned with the left or top edge of the anchor
+    Start,
+    /// Centered on the anchor (default)
+    #[default]
+    Center,
+    /// Aligned with the right or bottom edge of the anchor
+    End,
+}
+
+/// Rectangle in window coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Bounds {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+/// Placement of content next to its anchor (`placement="bottom-start"`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Placement {
+    pub side: Side,
+    pub align: PlacementAlign,
+}
+
+impl Placement {
+    /// Parse from string: a side (`top`, `bottom`, `left`, `right`) optionally
+    /// followed by an alignment (`-start`, `-end`)
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dampen_core::ir::placement::{Placement, PlacementAlign, Side};
+    ///
+    /// let placement = Placement::parse("bottom-start").unwrap();
+    /// assert_eq!(placement.side, Side::Bottom);
+    /// assert_eq!(placement.align, PlacementAlign::Start);
+    /// assert_eq!(Placement::parse("right").unwrap().align, PlacementAlign::Center);
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let s = s.trim().to_lowercase();
+        let (side, align) = match s.split_once('-') {
+            Some((side, align)) => (side, Some(align)),
+            None => (s.as_str(), None),
+        };
+
+        let side = match side {
+            "top" => Side::Top,
+            "bottom" => Side::Bottom,
+            "left" => Side::Left,
+            "right" => Side::Right,
+            _ => return Err(Self::invalid(&s)),
+        };
+        let align = match align {
+            None => PlacementAlign::Center,
+            Some("start") => PlacementAlign::Start,
+            Some("end") => PlacementAlign::End,
+            Some(_) => return Err(Self::invalid(&s)),
+        };
+
+        Ok(Self { side, align })
+    }
+
+    fn invalid(s: &str) -> String {
+        format!(
+            "Invalid placement: '{}'. Expected top, bottom, left or right, optionally followed by -start or -end",
+            s
+        )
+    }
+
+    /// The placement on the opposite side of the anchor
+    pub fn flipped(self) -> Self {
+        let side = match self.side {
+            Side::Top => Side::Bottom,
+            Side::Bottom => Side::Top,
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        };
+        Self { side, ..self }
+    }
+
+    /// Bounds of content of `width` and `height` placed `gap` pixels away from
+    /// `anchor`, within `window`
+    ///
+    /// The content is flipped to the opposite side when it does not fit on its
+    /// side but fits there, then moved along the side to stay in the window.
+    pub fn resolve(
+        self,
+        anchor: Bounds,
+        width: f32,
+        height: f32,
+        gap: f32,
+        window: Bounds,
+    ) -> Bounds {
+        let mut bounds = self.place(anchor, width, height, gap);
+        if !self.fits(bounds, window) {
+            let flipped = self.flipped();
+            let flipped_bounds = flipped.place(anchor, width, height, gap);
+            if flipped.fits(flipped_bounds, window) {
+                bounds = flipped_bounds;
+            }
+        }
+
+        // Keep the content in the window, favoring its top left corner
+        let max_x = (window.x + window.width - width).max(window.x);
+        let max_y = (window.y + window.height - height).max(window.y);
+        Bounds {
+            x: bounds.x.min(max_x).max(window.x),
+            y: bounds.y.min(max_y).max(window.y),
+            ..bounds
+        }
+    }
+
+    /// Bounds of the content on this side of `anchor`, ignoring the window
+    fn place(self, anchor: Bounds, width: f32, height: f32, gap: f32) -> Bounds {
+        let along = |start: f32, length: f32, extent: f32| match self.align {
+            PlacementAlign::Start => start,
+            PlacementAlign::Center => start + (length - extent) / 2.0,
+            PlacementAlign::End => start + length - extent,
+        };
+
+        let (x, y) = match self.side {
+            Side::Top => (
+                along(anchor.x, anchor.width, width),
+                anchor.y - gap - height,
+            ),
+            Side::Bottom => (
+                along(anchor.x, anchor.width, width),
+                anchor.y + anchor.height + gap,
+            ),
+            Side::Left => (
+                anchor.x - gap - width,
+                along(anchor.y, anchor.height, height),
+            ),
+            Side::Right => (
+                anchor.x + anchor.width + gap,
+                along(anchor.y, anchor.height, height),
+            ),
+        };
+
+        Bounds {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Whether `bounds` stays in `window` across the side of the anchor
+    fn fits(self, bounds: Bounds, window: Bounds) -> bool {
+        match self.side {
+            Side::Top => bounds.y >= window.y,
+            Side::Bottom => bounds.y + bounds.height <= window.y + window.height,
+            Side::Left => bounds.x >= window.x,
+            Side::Right => bounds.x + bounds.width <= window.x + window.width,
+        }
+    }
+}
+
+/// The `<float>` elements of the tree under `root` anchored to the widget `id`
+pub fn anchored_floats<'a>(root: &'a WidgetNode, id: &str) -> Vec<&'a WidgetNode> {
+    fn collect<'a>(node: &'a WidgetNode, id: &str, floats: &mut Vec<&'a WidgetNode>) {
+        if anchor_of(node) == Some(id) {
+            floats.push(node);
+        }
+        for child in &node.children {
+            collect(child, id, floats);
+        }
+    }
+
+    let mut floats = Vec::new();
+    collect(root, id, &mut floats);
+    floats
+}
+
+/// Move each `<float anchor="...">` of the tree in place of its anchor
+///
+/// The anchor becomes the first child of the float, followed by the content
+/// of the float, so that code generation can build both together.
+pub fn attach_anchored_floats(root: &mut WidgetNode) {
+    fn detach(node: &mut WidgetNode, floats: &mut Vec<WidgetNode>) {
+        for child in &mut node.children {
+            detach(child, floats);
+        }
+        let (anchored, children): (Vec<_>, Vec<_>) = std::mem::take(&mut node.children)
+            .into_iter()
+            .partition(|child| anchor_of(child).is_some());
+        node.children = children.into();
+        floats.extend(anchored);
+    }
+
+    fn attach(node: &mut WidgetNode, float: &mut Option<WidgetNode>, id: &str) {
+        if node.id.as_deref() == Some(id)
+            && let Some(mut float) = float.take()
+        {
+            float.children.insert(0, std::mem::take(node));
+            *node = float;
+            return;
+        }
+        for child in &mut node.children {
+            attach(child, float, id);
+        }
+    }
+
+    let mut pending = Vec::new();
+    detach(root, &mut pending);
+
+    // A float can be anchored inside the content of another one
+    while !pending.is_empty() {
+        let count = pending.len();
+        pending.retain_mut(|float| {
+            let id = anchor_of(float).unwrap_or_default().to_string();
+            let mut slot = Some(std::mem::take(float));
+            attach(root, &mut slot, &id);
+            match slot {
+                Some(unattached) => {
+                    *float = unattached;
+                    true
+                }
+                None => false,
+            }
+        });
+        if pending.len() == count {
+            break;
+        }
+    }
+}
+
+fn anchor_of(node: &WidgetNode) -> Option<&str> {
+    match node.attributes.get("anchor") {
+        Some(AttributeValue::Static(anchor)) if node.kind == WidgetKind::Float => {
+            Some(anchor.as_str())
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: Bounds = Bounds {
+        x: 0.0,
+        y: 0.0,
+        width: 800.0,
+        height: 600.0,
+    };
+
+    fn anchor(x: f32, y: f32) -> Bounds {
+        Bounds {
+            x,
+            y,
+            width: 100.0,
+            height: 30.0,
+        }
+    }
+
+    #[test]
+    fn test_placement_aligns_along_the_side() -> Result<(), String> {
+        let bounds = Placement::parse("bottom-start")?.resolve(
+            anchor(100.0, 100.0),
+            200.0,
+            150.0,
+            4.0,
+            WINDOW,
+        );
+        assert_eq!((bounds.x, bounds.y), (100.0, 134.0));
+
+        let bounds =
+            Placement::parse("right-end")?.resolve(anchor(100.0, 100.0), 50.0, 50.0, 0.0, WINDOW);
+        assert_eq!((bounds.x, bounds.y), (200.0, 80.0));
+
+        assert!(Placement::parse("bottom-middle").is_err());
+        assert!(Placement::parse("under").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_placement_flips_and_stays_in_the_window() -> Result<(), String> {
+        // No room below the anchor: the content opens above it
+        let bounds =
+            Placement::parse("bottom-end")?.resolve(anchor(20.0, 500.0), 200.0, 150.0, 4.0, WINDOW);
+        assert_eq!(bounds.y, 500.0 - 4.0 - 150.0);
+        // Aligned on the right edge, it would leave the window on the left
+        assert_eq!(bounds.x, 0.0);
+
+        // No room on either side: the content stays on its side, moved into the window
+        let bounds =
+            Placement::parse("bottom")?.resolve(anchor(350.0, 300.0), 100.0, 500.0, 0.0, WINDOW);
+        assert_eq!(bounds.y, 100.0);
+        Ok(())
+    }
+}
@@ -0,0 +1,200 @@
+//! Inline rich text content
+//!
+//! A `<text>` widget renders rich text when it contains `<span>` children, or when
+//! `markdown="true"` is set and its `value` is written in a small, safe subset of
+//! Markdown:
+//!
+//! - `**bold**`
+//! - `*italic*` or `_italic_`
+//! - `[label](url)` links, which emit the widget's `on_link` event with the URL
+//! - `\` to escape the next character
+//!
+//! Everything else (headings, lists, images, HTML, ...) is rendered literally, so
+//! untrusted content can be displayed without surprises.
+
+/// A run of text with uniform formatting
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub struct TextSpan {
+    /// Text content
+    pub text: String,
+    /// Whether the text is bold
+    pub bold: bool,
+    /// Whether the text is italic
+    pub italic: bool,
+    /// Link target, if the span is a link
+    pub link: Option<String>,
+}
+
+/// Parse the supported Markdown subset into text spans
+///
+/// Adjacent runs with the same formatting are merged. Emphasis markers without a
+/// matching closing marker are kept as literal text.
+///
+/// # Example
+///
+/// ```rust
+/// use dampen_core::ir::rich_text::parse_markdown;
+///
+/// let spans = parse_markdown("Read the **docs** at [dampen](https://example.com)");
+/// assert_eq!(spans.len(), 4);
+/// assert!(spans[1].bold);
+/// assert_eq!(spans[3].link.as_deref(), Some("https://example.com"));
+/// ```
+pub fn parse_markdown(source: &str) -> Vec<TextSpan> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut spans = Vec::new();
+    let mut current = String::new();
+    let (mut bold, mut italic) = (false, false);
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c == '\\' && i + 1 < chars.len() {
+            current.push(chars[i + 1]);
+            i += 2;
+            continue;
+        }
+
+        if c == '*' && chars.get(i + 1) == Some(&'*') && toggles(&chars, i, 2, bold) {
+            push_span(&mut spans, &mut current, bold, italic);
+            bold = !bold;
+            i += 2;
+            continue;
+        }
+
+        if (c == '*' || c == '_') && toggles(&chars, i, 1, italic) {
+            push_span(&mut spans, &mut current, bold, italic);
+            italic = !italic;
+            i += 1;
+            continue;
+        }
+
+        if c == '['
+            && let Some((label, url, end)) = parse_link(&chars, i)
+        {
+            push_span(&mut spans, &mut current, bold, italic);
+            spans.push(TextSpan {
+                text: label,
+                bold,
+                italic,
+                link: Some(url),
+            });
+            i = end;
+            continue;
+        }
+
+        current.push(c);
+        i += 1;
+    }
+
+    push_span(&mut spans, &mut current, bold, italic);
+    spans
+}
+
+/// Append the pending text as a span, merging it with the previous one if possible
+fn push_span(spans: &mut Vec<TextSpan>, current: &mut String, bold: bool, italic: bool) {
+    if current.is_empty() {
+        return;
+    }
+    let text = std::mem::take(current);
+
+    match spans.last_mut() {
+        Some(last) if last.bold == bold && last.italic == italic && last.link.is_none() => {
+            last.text.push_str(&text);
+        }
+        _ => spans.push(TextSpan {
+            text,
+            bold,
+            italic,
+            link: None,
+        }),
+    }
+}
+
+/// Whether the emphasis marker of `len` characters at `i` opens or closes emphasis
+fn toggles(chars: &[char], i: usize, len: usize, active: bool) -> bool {
+    if active {
+        return can_close(chars, i, len);
+    }
+    let marker = &chars[i..i + len];
+    can_open(chars, i, len)
+        && (i + len + 1..chars.len())
+            .any(|j| chars[j..].starts_with(marker) && can_close(chars, j, len))
+}
+
+fn can_open(chars: &[char], i: usize, len: usize) -> bool {
+    let next_is_text = chars.get(i + len).is_some_and(|c| !c.is_whitespace());
+    // `_` only marks emphasis at word boundaries (e.g., not in `snake_case`)
+    let after_word = i > 0 && chars[i - 1].is_alphanumeric();
+    next_is_text && !(chars[i] == '_' && after_word)
+}
+
+fn can_close(chars: &[char], i: usize, len: usize) -> bool {
+    let prev_is_text = i > 0 && !chars[i - 1].is_whitespace() && chars[i - 1] != '\\';
+    let before_word = chars.get(i + len).is_some_and(|c| c.is_alphanumeric());
+    prev_is_text && !(chars[i] == '_' && before_word)
+}
+
+/// Parse `[label](url)` at `start`, returning the label, URL and the index after it
+fn parse_link(chars: &[char], start: usize) -> Option<(String, String, usize)> {
+    let label_end = start + chars[start..].iter().position(|&c| c == ']')?;
+    if chars.get(label_end + 1) != Some(&'(') {
+        return None;
+    }
+    let url_end = label_end + 1 + chars[label_end + 1..].iter().position(|&c| c == ')')?;
+
+    let label: String = chars[start + 1..label_end].iter().collect();
+    let url: String = chars[label_end + 2..url_end].iter().collect();
+    if label.contains('[') || url.is_empty() || url.contains(char::is_whitespace) {
+        return None;
+    }
+
+    Some((label, url, url_end + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(text: &str, bold: bool, italic: bool) -> TextSpan {
+        TextSpan {
+            text: text.to_string(),
+            bold,
+            italic,
+            link: None,
+        }
+    }
+
+    #[test]
+    fn test_emphasis_and_links() {
+        assert_eq!(
+            parse_markdown("a **b *c*** _d_ [e](f)"),
+            vec![
+                span("a ", false, false),
+                span("b ", true, false),
+                span("c", true, true),
+                span(" ", false, false),
+                span("d", false, true),
+                span(" ", false, false),
+                TextSpan {
+                    text: "e".to_string(),
+                    link: Some("f".to_string()),
+                    ..Default::default()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unmatched_markers_are_literal() {
+        assert_eq!(
+            parse_markdown("2 * 3 * 4, snake_case_name, **open, [x](bad url), \\*lit\\*"),
+            vec![span(
+                "2 * 3 * 4, snake_case_name, **open, [x](bad url), *lit*",
+                false,
+                false
+            )]
+        );
+    }
+}
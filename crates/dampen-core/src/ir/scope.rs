@@ -0,0 +1,305 @@
+//! Binding scopes introduced by the `model` attribute.
+//!
+//! A container with `model="{cart}"` switches the binding scope of its
+//! attributes and descendants to the `cart` sub-model: `{total}` inside it
+//! reads `cart.total` from the root model. Scopes nest, and loop variables
+//! (`<for each="item">`, `index`, and the option of bound `options`) are
+//! never scoped.
+//!
+//! The widget builder resolves scopes while rendering; code generation
+//! rewrites the tree once with [`resolve_model_scopes`].
+
+use std::collections::HashSet;
+
+use crate::expr::{BindingExpr, Expr};
+use crate::ir::node::{AttributeValue, InterpolatedPart, WidgetKind, WidgetNode};
+
+/// Name of the attribute switching the binding scope of a container
+pub const MODEL_ATTRIBUTE: &str = "model";
+
+/// Returns the sub-model path of a node's `model` attribute (e.g., `["cart"]` for `model="{cart}"`)
+///
+/// Returns `None` if the node has no `model` attribute or it is not a plain field binding.
+pub fn model_scope(node: &WidgetNode) -> Option<&[String]> {
+    match node.attributes.get(MODEL_ATTRIBUTE) {
+        Some(AttributeValue::Binding(BindingExpr {
+            expr: Expr::FieldAccess(field),
+            ..
+        })) if !field.path.is_empty() => Some(&field.path),
+        _ => None,
+    }
+}
+
+/// Prefix the model field accesses of `expr` with `scope`, leaving loop variables untouched
+pub fn scope_expr(expr: &mut Expr, scope: &[String], locals: &HashSet<String>) {
+    if scope.is_empty() {
+        return;
+    }
+    match expr {
+        Expr::FieldAccess(field) => {
+            let is_local = field
+                .path
+                .first()
+                .is_some_and(|first| locals.contains(first));
+            if !is_local {
+                field.path.splice(0..0, scope.iter().cloned());
+            }
+        }
+        Expr::MethodCall(call) => {
+            scope_expr(&mut call.receiver, scope, locals);
+            for arg in &mut call.args {
+                scope_expr(arg, scope, locals);
+            }
+        }
+        Expr::BinaryOp(binary) => {
+            scope_expr(&mut binary.left, scope, locals);
+            scope_expr(&mut binary.right, scope, locals);
+        }
+        Expr::UnaryOp(unary) => scope_expr(&mut unary.operand, scope, locals),
+        Expr::Conditional(conditional) => {
+            scope_expr(&mut conditional.condition, scope, locals);
+            scope_expr(&mut conditional.then_branch, scope, locals);
+            scope_expr(&mut conditional.else_branch, scope, locals);
+        }
+        Expr::Filter(filter) => {
+            scope_expr(&mut filter.input, scope, locals);
+            for arg in &mut filter.args {
+                scope_expr(arg, scope, locals);
+            }
+        }
+        Expr::SharedFieldAccess(_) | Expr::Literal(_) => {}
+    }
+}
+
+/// Returns a copy of the tree with every `model` scope applied to the bindings it covers.
+///
+/// The `model` attributes are removed, so the result only contains bindings
+/// relative to the root model (or to loop variables). Subtrees without any
+/// `model` attribute are shared with `root` rather than copied.
+pub fn resolve_model_scopes(root: &WidgetNode) -> WidgetNode {
+    let mut root = root.clone();
+    apply_scopes(&mut root, &[], &HashSet::new());
+    root
+}
+
+fn apply_scopes(node: &mut WidgetNode, scope: &[String], locals: &HashSet<String>) {
+    // The model path itself is relative to the enclosing scope
+    let mut node_scope = scope.to_vec();
+    if let Some(AttributeValue::Binding(binding)) = node.attributes.remove(MODEL_ATTRIBUTE) {
+        let mut expr = binding.expr;
+        scope_expr(&mut expr, scope, locals);
+        if let Expr::FieldAccess(field) = expr {
+            node_scope = field.path;
+        }
+    }
+
+    // The per-option attributes of bound options also see the option
+    let mut option_locals = locals.clone();
+    option_locals.insert("item".to_string());
+    option_locals.insert("index".to_string());
+    let templates: HashSet<String> = node
+        .attributes
+        .keys()
+        .filter(|name| node.is_option_template(name))
+        .cloned()
+        .collect();
+    for (name, value) in node.attributes.iter_mut() {
+        if templates.contains(name) {
+            scope_attribute(value, &node_scope, &option_locals);
+        } else {
+            scope_attribute(value, &node_scope, locals);
+        }
+    }
+    for attributes in node.breakpoint_attributes.values_mut() {
+        for value in attributes.values_mut() {
+            scope_attribute(value, &node_scope, locals);
+        }
+    }
+    if let Some(theme_ref) = &mut node.theme_ref {
+        scope_attribute(theme_ref, &node_scope, locals);
+    }
+    for event in &mut node.events {
+        for param in event.param.iter_mut().chain(event.params.iter_mut()) {
+            scope_expr(&mut param.expr, &node_scope, locals);
+        }
+    }
+
+    // Loop variables are visible to the children of a <for>
+    let mut child_locals = locals.clone();
+    if node.kind == WidgetKind::For {
+        let each = match node.attributes.get("each") {
+            Some(AttributeValue::Static(name)) => name.clone(),
+            _ => "item".to_string(),
+        };
+        child_locals.insert(each);
+        child_locals.insert("index".to_string());
+    }
+
+    for index in 0..node.children.len() {
+        // Unscoped subtrees are left untouched, so they stay shared with the
+        // original tree
+        if node_scope.is_empty() && !has_model_scope(&node.children[index]) {
+            continue;
+        }
+        apply_scopes(&mut node.children[index], &node_scope, &child_locals);
+    }
+}
+
+/// Whether a subtree contains a `model` attribute
+fn has_model_scope(node: &WidgetNode) -> bool {
+    node.attributes.contains_key(MODEL_ATTRIBUTE) || node.children.iter().any(has_model_scope)
+}
+
+fn scope_attribute(value: &mut AttributeValue, scope: &[String], locals: &HashSet<String>) {
+    match value {
+        AttributeValue::Binding(binding) => scope_expr(&mut binding.expr, scope, locals),
+        AttributeValue::Interpolated(parts) => {
+            for part in parts {
+                if let InterpolatedPart::Binding(binding) = part {
+                    scope_expr(&mut binding.expr, scope, locals);
+                }
+            }
+        }
+        AttributeValue::Static(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    fn field_path(value: Option<&AttributeValue>) -> Option<Vec<String>> {
+        match value {
+            Some(AttributeValue::Binding(BindingExpr {
+                expr: Expr::FieldAccess(field),
+                ..
+            })) => Some(field.path.clone()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_model_scope_prefixes_child_bindings() -> Result<(), String> {
+        let document = parse(
+            r#"<column model="{cart}">
+                <text value="{total}" />
+                <text value="{shared.currency}" />
+                <column model="{discount}">
+                    <text value="{rate}" />
+                </column>
+            </column>"#,
+        )
+        .map_err(|e| e.to_string())?;
+
+        assert_eq!(model_scope(&document.root), Some(&["cart".to_string()][..]));
+
+        let root = resolve_model_scopes(&document.root);
+        assert!(!root.attributes.contains_key(MODEL_ATTRIBUTE));
+        assert_eq!(
+            field_path(root.children[0].attributes.get("value")),
+            Some(vec!["cart".to_string(), "total".to_string()])
+        );
+        assert!(matches!(
+            root.children[1].attributes.get("value"),
+            Some(AttributeValue::Binding(BindingExpr {
+                expr: Expr::SharedFieldAccess(_),
+                ..
+            }))
+        ));
+        assert_eq!(
+            field_path(root.children[2].children[0].attributes.get("value")),
+            Some(vec![
+                "cart".to_string(),
+                "discount".to_string(),
+                "rate".to_string()
+            ])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_model_scope_skips_loop_variables() -> Result<(), String> {
+        let document = parse(
+            r#"<column model="{cart}">
+                <for each="line" in="{lines}">
+                    <button label="{line.name}" on_click="remove:{index}" />
+                </for>
+            </column>"#,
+        )
+        .map_err(|e| e.to_string())?;
+
+        let root = resolve_model_scopes(&document.root);
+        let for_node = &root.children[0];
+        assert_eq!(
+            field_path(for_node.attributes.get("in")),
+            Some(vec!["cart".to_string(), "lines".to_string()])
+        );
+        let button = &for_node.children[0];
+        assert_eq!(
+            field_path(button.attributes.get("label")),
+            Some(vec!["line".to_string(), "name".to_string()])
+        );
+        let param = button.events[0].param.as_ref().map(|p| &p.expr);
+        assert!(matches!(param, Some(Expr::FieldAccess(f)) if f.path == ["index"]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_model_scope_skips_bound_option_templates() -> Result<(), String> {
+        let document = parse(
+            r#"<column model="{form}">
+                <pick_list options="{countries}" display="{item.name}" value="{item.code}"
+                    selected="{country}" />
+            </column>"#,
+        )
+        .map_err(|e| e.to_string())?;
+
+        let root = resolve_model_scopes(&document.root);
+        let pick_list = &root.children[0];
+        assert_eq!(
+            field_path(pick_list.attributes.get("options")),
+            Some(vec!["form".to_string(), "countries".to_string()])
+        );
+        assert_eq!(
+            field_path(pick_list.attributes.get("display")),
+            Some(vec!["item".to_string(), "name".to_string()])
+        );
+        assert_eq!(
+            field_path(pick_list.attributes.get("selected")),
+            Some(vec!["form".to_string(), "country".to_string()])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_unscoped_subtrees_are_shared() -> Result<(), String> {
+        let document = parse(
+            r#"<column>
+                <row><text value="{title}" /></row>
+                <column model="{cart}"><text value="{total}" /></column>
+            </column>"#,
+        )
+        .map_err(|e| e.to_string())?;
+
+        let root = resolve_model_scopes(&document.root);
+        assert!(
+            root.children[0]
+                .children
+                .ptr_eq(&document.root.children[0].children)
+        );
+        assert_eq!(
+            field_path(root.children[1].children[0].attributes.get("value")),
+            Some(vec!["cart".to_string(), "total".to_string()])
+        );
+        assert_eq!(
+            field_path(
+                document.root.children[1].children[0]
+                    .attributes
+                    .get("value")
+            ),
+            Some(vec!["total".to_string()])
+        );
+        Ok(())
+    }
+}
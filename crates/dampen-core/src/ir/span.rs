@@ -47,3 +47,12 @@ impl Span {
         }
     }
 }
+
+/// Source locations of an attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub struct AttributeSpan {
+    /// Location of the attribute name
+    pub name: Span,
+    /// Location of the attribute value, excluding the quotes
+    pub value: Span,
+}
@@ -0,0 +1,311 @@
+//! Deterministic serialization and hashing of the IR.
+//!
+//! Attributes, themes and style classes are kept in `HashMap`s, whose iteration
+//! order changes from one run to the next. The helpers of this module serialize
+//! these maps sorted by key, so that JSON dumps of a document are reproducible,
+//! and [`stable_hash`] hashes a value to the same number in every process, on
+//! every platform and with every Rust version, so that it can be stored on disk.
+//!
+//! Source spans and comments are ignored by [`stable_hash`]: moving a widget
+//! down a few lines in the XML file, or documenting it, does not change the hash.
+//! The span and comment fields of the IR are skipped with [`skip_source`] and
+//! [`skip_comments`] while hashing; maps are hashed whole, whatever their keys.
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+thread_local! {
+    /// Whether a value is being serialized by [`hash_serialized`]
+    static HASHING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Skips a source location field while hashing
+/// (`#[serde(skip_serializing_if = "...")]`)
+pub(crate) fn skip_source<T>(_: &T) -> bool {
+    HASHING.with(Cell::get)
+}
+
+/// Skips the comments of a widget while hashing, and when there are none
+/// (`#[serde(skip_serializing_if = "...")]`)
+pub(crate) fn skip_comments(comments: &[String]) -> bool {
+    comments.is_empty() || HASHING.with(Cell::get)
+}
+
+/// Restores the previous [`HASHING`] flag when dropped
+struct HashingGuard(bool);
+
+impl HashingGuard {
+    fn new() -> Self {
+        Self(HASHING.with(|hashing| hashing.replace(true)))
+    }
+}
+
+impl Drop for HashingGuard {
+    fn drop(&mut self) {
+        HASHING.with(|hashing| hashing.set(self.0));
+    }
+}
+
+/// 64-bit FNV-1a hasher.
+///
+/// Unlike `DefaultHasher`, its algorithm is fixed, and integers are hashed as
+/// little-endian `u64` whatever their width and the platform's endianness.
+#[derive(Debug, Clone, Copy)]
+pub struct StableHasher(u64);
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for StableHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write_u64(u64::from(i));
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write_u64(u64::from(i));
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Hash of the serialized form of a value, ignoring source spans and comments.
+///
+/// Maps hash independently of their iteration order.
+///
+/// # Example
+///
+/// ```rust
+/// use dampen_core::ir::stable::stable_hash;
+/// use std::collections::HashMap;
+///
+/// let a: HashMap<&str, u8> = [("x", 1), ("y", 2)].into_iter().collect();
+/// let b: HashMap<&str, u8> = [("y", 2), ("x", 1)].into_iter().collect();
+/// assert_eq!(stable_hash(&a), stable_hash(&b));
+/// ```
+pub fn stable_hash<T: Serialize + ?Sized>(value: &T) -> u64 {
+    let mut hasher = StableHasher::default();
+    hash_serialized(value, &mut hasher);
+    hasher.finish()
+}
+
+/// Feed the serialized form of a value to a hasher, ignoring source spans and comments.
+pub(crate) fn hash_serialized<T: Serialize + ?Sized, H: Hasher>(value: &T, hasher: &mut H) {
+    let serialized = {
+        let _hashing = HashingGuard::new();
+        serde_json::to_value(value)
+    };
+    match serialized {
+        Ok(value) => hash_value(&value, hasher),
+        Err(_) => 0u8.hash(hasher),
+    }
+}
+
+fn hash_value<H: Hasher>(value: &Value, hasher: &mut H) {
+    match value {
+        Value::Null => 0u8.hash(hasher),
+        Value::Bool(b) => b.hash(hasher),
+        Value::Number(n) => n.to_string().hash(hasher),
+        Value::String(s) => s.hash(hasher),
+        Value::Array(items) => {
+            items.len().hash(hasher);
+            for item in items {
+                hash_value(item, hasher);
+            }
+        }
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            keys.len().hash(hasher);
+            for key in keys {
+                key.hash(hasher);
+                if let Some(value) = map.get(key) {
+                    hash_value(value, hasher);
+                }
+            }
+        }
+    }
+}
+
+/// A map serialized sorted by key
+struct Sorted<'a, K, V>(&'a HashMap<K, V>);
+
+impl<K: Serialize + Ord, V: Serialize> Serialize for Sorted<'_, K, V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(sorted_entries(self.0))
+    }
+}
+
+/// Entries of a map sorted by key
+pub(crate) fn sorted_entries<K: Ord, V>(map: &HashMap<K, V>) -> Vec<(&K, &V)> {
+    let mut entries: Vec<(&K, &V)> = map.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    entries
+}
+
+/// Serializes a map sorted by key (`#[serde(serialize_with = "...")]`).
+pub(crate) fn sorted<K, V, S>(map: &HashMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    K: Serialize + Ord,
+    V: Serialize,
+    S: Serializer,
+{
+    Sorted(map).serialize(serializer)
+}
+
+/// Serializes a map of maps sorted by key, at both levels.
+pub(crate) fn sorted_nested<K, K2, V, S>(
+    map: &HashMap<K, HashMap<K2, V>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    K: Serialize + Ord,
+    K2: Serialize + Ord,
+    V: Serialize,
+    S: Serializer,
+{
+    serializer.collect_map(
+        sorted_entries(map)
+            .into_iter()
+            .map(|(key, inner)| (key, Sorted(inner))),
+    )
+}
+
+/// A map whose keys are not strings, as a list of `[key, value]` pairs sorted
+/// by key (`#[serde(with = "...")]`).
+///
+/// JSON objects only have string keys, so such maps could not be dumped as JSON.
+pub(crate) mod sorted_pairs {
+    use super::*;
+
+    pub(crate) fn serialize<K, V, S>(map: &HashMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        K: Serialize + Ord,
+        V: Serialize,
+        S: Serializer,
+    {
+        serializer.collect_seq(sorted_entries(map))
+    }
+
+    pub(crate) fn deserialize<'de, K, V, D>(deserializer: D) -> Result<HashMap<K, V>, D::Error>
+    where
+        K: Deserialize<'de> + Eq + Hash,
+        V: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let pairs: Vec<(K, V)> = Vec::deserialize(deserializer)?;
+        Ok(pairs.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::parser::parse;
+
+    #[test]
+    fn test_hasher_is_fnv1a() {
+        let mut hasher = StableHasher::default();
+        hasher.write(b"a");
+        assert_eq!(hasher.finish(), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn test_document_hash_ignores_spans_and_order() {
+        let a = parse(r#"<column><text value="A" size="14" /></column>"#).unwrap();
+        let b = parse("<column>\n    <text size=\"14\" value=\"A\" />\n</column>").unwrap();
+        let c = parse(r#"<column><text value="B" size="14" /></column>"#).unwrap();
+        let d =
+            parse(r#"<column><!-- @doc: Title --><text value="A" size="14" /></column>"#).unwrap();
+        assert_eq!(a.stable_hash(), b.stable_hash());
+        assert_eq!(a.stable_hash(), d.stable_hash());
+        assert_ne!(a.stable_hash(), c.stable_hash());
+    }
+
+    #[test]
+    fn test_document_hash_keeps_user_named_entries() {
+        let styles = |padding: u8| {
+            parse(&format!(
+                r#"<dampen>
+                    <styles>
+                        <style name="span"><base padding="{padding}" /></style>
+                        <style name="comments"><base padding="{padding}" /></style>
+                    </styles>
+                    <text value="A" class="span comments" />
+                </dampen>"#
+            ))
+            .unwrap()
+        };
+        assert_ne!(styles(1).stable_hash(), styles(2).stable_hash());
+
+        // Spans are still dumped as JSON
+        let json = serde_json::to_string(&styles(1)).unwrap();
+        assert!(json.contains("\"attribute_spans\""));
+    }
+
+    #[test]
+    fn test_json_dump_is_sorted() {
+        let xml = r#"<dampen>
+            <styles>
+                <style name="zebra"><base padding="1" /></style>
+                <style name="alpha"><base padding="2" /></style>
+            </styles>
+            <text value="A" size="14" color="red" weight="bold" wrap="true" />
+        </dampen>"#;
+        let first = serde_json::to_string(&parse(xml).unwrap()).unwrap();
+        for _ in 0..8 {
+            assert_eq!(serde_json::to_string(&parse(xml).unwrap()).unwrap(), first);
+        }
+        let alpha = first.find("\"alpha\"").unwrap();
+        let zebra = first.find("\"zebra\"").unwrap();
+        assert!(alpha < zebra);
+    }
+
+    #[test]
+    fn test_combined_state_variants_round_trip() {
+        use crate::ir::style::StyleProperties;
+        use crate::ir::theme::{StateSelector, StyleClass, WidgetState};
+
+        let class = StyleClass {
+            name: "btn".to_string(),
+            style: StyleProperties::default(),
+            layout: None,
+            extends: Vec::new(),
+            state_variants: HashMap::new(),
+            combined_state_variants: [(
+                StateSelector::combined(vec![WidgetState::Hover, WidgetState::Active]),
+                StyleProperties::default(),
+            )]
+            .into_iter()
+            .collect(),
+        };
+        let json = serde_json::to_string(&class).unwrap();
+        let back: StyleClass = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, class);
+    }
+}
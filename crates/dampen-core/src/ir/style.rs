@@ -21,6 +21,13 @@ pub struct StyleProperties {
     pub opacity: Option<f32>,
     /// Visual transformations
     pub transform: Option<Transform>,
+    /// Borders of individual sides, drawn over `border`
+    pub border_sides: Option<BorderSides>,
+    /// Outline drawn around the widget, typically as a focus ring
+    pub outline: Option<Outline>,
+    /// Elevation level (0-5), drawn as the matching shadow of the theme
+    /// when `shadow` is not set
+    pub elevation: Option<u8>,
 }
 
 impl StyleProperties {
@@ -36,6 +43,12 @@ impl StyleProperties {
             return Err(format!("opacity must be 0.0-1.0, got {}", opacity));
         }
 
+        if let Some(elevation) = self.elevation
+            && elevation > 5
+        {
+            return Err(format!("elevation must be 0-5, got {}", elevation));
+        }
+
         if let Some(ref color) = self.color {
             color.validate()?;
         }
@@ -48,6 +61,14 @@ impl StyleProperties {
             border.validate()?;
         }
 
+        if let Some(ref sides) = self.border_sides {
+            sides.validate()?;
+        }
+
+        if let Some(ref outline) = self.outline {
+            outline.validate()?;
+        }
+
         Ok(())
     }
 }
@@ -175,21 +196,30 @@ impl Color {
 
     /// Convert to hex string
     pub fn to_hex(&self) -> String {
-        let r = (self.r.clamp(0.0, 1.0) * 255.0) as u8;
-        let g = (self.g.clamp(0.0, 1.0) * 255.0) as u8;
-        let b = (self.b.clamp(0.0, 1.0) * 255.0) as u8;
+        let [r, g, b, _] = self.to_rgba8();
         format!("#{:02x}{:02x}{:02x}", r, g, b)
     }
 
     /// Convert to hex string with alpha channel
     pub fn to_rgba_hex(&self) -> String {
-        let r = (self.r.clamp(0.0, 1.0) * 255.0) as u8;
-        let g = (self.g.clamp(0.0, 1.0) * 255.0) as u8;
-        let b = (self.b.clamp(0.0, 1.0) * 255.0) as u8;
-        let a = (self.a.clamp(0.0, 1.0) * 255.0) as u8;
+        let [r, g, b, a] = self.to_rgba8();
         format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
     }
 
+    /// Convert to RGBA bytes (0-255 range), rounding each component
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dampen_core::ir::style::Color;
+    ///
+    /// let color = Color::from_rgba8(52, 152, 219, 128);
+    /// assert_eq!(color.to_rgba8(), [52, 152, 219, 128]);
+    /// ```
+    pub fn to_rgba8(&self) -> [u8; 4] {
+        [self.r, self.g, self.b, self.a].map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8)
+    }
+
     /// Create color from RGB bytes (0-255 range)
     ///
     /// # Arguments
@@ -242,6 +272,27 @@ impl Color {
         }
     }
 
+    /// Interpolate linearly towards `other`, `t` going from 0.0 (`self`) to 1.0 (`other`)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dampen_core::ir::style::Color;
+    ///
+    /// let gray = Color::from_rgb8(0, 0, 0).mix(&Color::from_rgb8(255, 255, 255), 0.5);
+    /// assert_eq!(gray.r, 0.5);
+    /// ```
+    pub fn mix(&self, other: &Color, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |from: f32, to: f32| from + (to - from) * t;
+        Self {
+            r: mix(self.r, other.r),
+            g: mix(self.g, other.g),
+            b: mix(self.b, other.b),
+            a: mix(self.a, other.a),
+        }
+    }
+
     /// Validate color values
     pub fn validate(&self) -> Result<(), String> {
         if self.r < 0.0 || self.r > 1.0 {
@@ -321,14 +372,47 @@ impl Gradient {
 
         Ok(())
     }
+
+    /// The linear gradient drawing this gradient: its angle in degrees and its stops
+    ///
+    /// Iced only draws linear gradients. A radial gradient is approximated by a
+    /// vertical gradient mirroring its stops around the middle, so that its center
+    /// color runs across the middle and its outer color reaches the top and bottom
+    /// edges. Iced keeps 8 stops, which leaves room for 4 radial stops.
+    pub fn to_linear(&self) -> (f32, Vec<ColorStop>) {
+        match self {
+            Gradient::Linear { angle, stops } => (*angle, stops.iter().take(8).cloned().collect()),
+            Gradient::Radial { stops, .. } => {
+                let stops = &stops[..stops.len().min(4)];
+                let outer_half = stops.iter().rev().map(|stop| ColorStop {
+                    offset: 0.5 - stop.offset / 2.0,
+                    ..stop.clone()
+                });
+                // A stop at the center would be repeated on both halves
+                let inner_half =
+                    stops
+                        .iter()
+                        .filter(|stop| stop.offset > 0.0)
+                        .map(|stop| ColorStop {
+                            offset: 0.5 + stop.offset / 2.0,
+                            ..stop.clone()
+                        });
+                (180.0, outer_half.chain(inner_half).collect())
+            }
+        }
+    }
 }
 
 /// Color stop for gradients
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ColorStop {
     pub color: Color,
     /// Offset in gradient (0.0 = start, 1.0 = end)
     pub offset: f32,
+    /// Theme palette color (`primary` for `@primary`) replacing `color` when rendered
+    /// with a theme; `color` is then only a fallback
+    #[serde(default)]
+    pub theme_color: Option<String>,
 }
 
 /// Radial gradient shape
@@ -452,6 +536,154 @@ impl BorderStyle {
     }
 }
 
+/// Border of a single side: `border_top="1 #cccccc"`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BorderSide {
+    pub width: f32,
+    pub color: Color,
+}
+
+impl BorderSide {
+    /// Parse from string format: "width color"
+    ///
+    /// # Example
+    /// ```rust
+    /// use dampen_core::ir::style::BorderSide;
+    ///
+    /// let side = BorderSide::parse("1 #cccccc").unwrap();
+    /// assert_eq!(side.width, 1.0);
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let Some((width, color)) = s.trim().split_once(char::is_whitespace) else {
+            return Err(format!(
+                "Invalid border side format: '{}'. Expected: width color",
+                s
+            ));
+        };
+
+        let side = BorderSide {
+            width: width
+                .parse()
+                .map_err(|_| format!("Invalid border width: {}", width))?,
+            color: Color::parse(color.trim())?,
+        };
+        side.validate()?;
+        Ok(side)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.width < 0.0 {
+            return Err(format!(
+                "Border width must be non-negative, got {}",
+                self.width
+            ));
+        }
+        self.color.validate()
+    }
+}
+
+/// Borders of individual sides
+///
+/// Sides left to `None` keep the widget `border`.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct BorderSides {
+    pub top: Option<BorderSide>,
+    pub right: Option<BorderSide>,
+    pub bottom: Option<BorderSide>,
+    pub left: Option<BorderSide>,
+}
+
+impl BorderSides {
+    /// Whether no side is set
+    pub fn is_empty(&self) -> bool {
+        self.top.is_none() && self.right.is_none() && self.bottom.is_none() && self.left.is_none()
+    }
+
+    /// Cascade `overrides` over `base` side by side, so that a state variant
+    /// setting `border_top` keeps the other sides of the base style
+    pub fn cascade(base: Option<Self>, overrides: Option<Self>) -> Option<Self> {
+        match (base, overrides) {
+            (Some(base), Some(overrides)) => Some(BorderSides {
+                top: overrides.top.or(base.top),
+                right: overrides.right.or(base.right),
+                bottom: overrides.bottom.or(base.bottom),
+                left: overrides.left.or(base.left),
+            }),
+            (base, overrides) => overrides.or(base),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        for side in [self.top, self.right, self.bottom, self.left]
+            .iter()
+            .flatten()
+        {
+            side.validate()?;
+        }
+        Ok(())
+    }
+}
+
+/// Outline drawn outside of the widget bounds, without affecting layout
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Outline {
+    pub width: f32,
+    pub color: Color,
+    /// Gap between the widget bounds and the outline
+    pub offset: f32,
+}
+
+impl Outline {
+    /// Parse from string format: "width color [offset]"
+    ///
+    /// # Example
+    /// ```rust
+    /// use dampen_core::ir::style::Outline;
+    ///
+    /// let outline = Outline::parse("2 #3498db 1").unwrap();
+    /// assert_eq!(outline.width, 2.0);
+    /// assert_eq!(outline.offset, 1.0);
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let Some((width, rest)) = s.trim().split_once(char::is_whitespace) else {
+            return Err(format!(
+                "Invalid outline format: '{}'. Expected: width color [offset]",
+                s
+            ));
+        };
+
+        // The offset is the last word, when it is a number
+        let rest = rest.trim();
+        let (color, offset) = match rest
+            .rsplit_once(char::is_whitespace)
+            .map(|(color, offset)| (color, offset.parse::<f32>()))
+        {
+            Some((color, Ok(offset))) => (color.trim(), offset),
+            _ => (rest, 0.0),
+        };
+
+        let outline = Outline {
+            width: width
+                .parse()
+                .map_err(|_| format!("Invalid outline width: {}", width))?,
+            color: Color::parse(color)?,
+            offset,
+        };
+        outline.validate()?;
+        Ok(outline)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.width < 0.0 || self.offset < 0.0 {
+            return Err(format!(
+                "Outline width and offset must be non-negative, got {} and {}",
+                self.width, self.offset
+            ));
+        }
+        self.color.validate()
+    }
+}
+
 /// Drop shadow
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Shadow {
@@ -5,7 +5,9 @@
 //! All types are backend-agnostic and serializable.
 
 use super::layout::LayoutConstraints;
-use super::style::{Color, StyleProperties};
+use super::node::{AttributeValue, WidgetKind, WidgetNode};
+use super::span::Span;
+use super::style::{Color, Shadow, StyleProperties};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -16,7 +18,11 @@ pub struct Theme {
     pub palette: ThemePalette,
     pub typography: Typography,
     pub spacing: SpacingScale,
+    /// Shadows of the `elevation` levels
+    #[serde(default)]
+    pub shadows: ShadowScale,
     /// Default styles per widget type
+    #[serde(serialize_with = "crate::ir::stable::sorted")]
     pub base_styles: HashMap<String, StyleProperties>,
     /// Parent theme for inheritance
     #[serde(default)]
@@ -89,6 +95,7 @@ impl Theme {
                         "THEME_007: Circular theme inheritance detected: {} → {}",
                         chain, parent_name
                     ),
+                    span: None,
                 });
             }
 
@@ -99,6 +106,7 @@ impl Theme {
                         "THEME_006: Parent theme '{}' not found for theme '{}'",
                         parent_name, self.name
                     ),
+                    span: None,
                 });
             }
 
@@ -110,6 +118,7 @@ impl Theme {
                         "THEME_008: Theme inheritance depth exceeds 5 levels for '{}'",
                         self.name
                     ),
+                    span: None,
                 });
             }
 
@@ -132,6 +141,7 @@ impl Theme {
             palette: self.palette.inherit_from(&parent.palette),
             typography: self.typography.inherit_from(&parent.typography),
             spacing: self.spacing.inherit_from(&parent.spacing),
+            shadows: self.shadows.inherit_from(&parent.shadows),
             base_styles: self.base_styles.clone(),
             extends: self.extends.clone(),
         }
@@ -275,6 +285,27 @@ impl ThemePalette {
         }
     }
 
+    /// Palette part of the way to `target`, `t` going from 0.0 (`self`) to 1.0 (`target`)
+    ///
+    /// Colors only one of the palettes sets switch to `target` at once.
+    pub fn blend(&self, target: &ThemePalette, t: f32) -> Self {
+        let blend = |from: Option<Color>, to: Option<Color>| match (from, to) {
+            (Some(from), Some(to)) => Some(from.mix(&to, t)),
+            (_, to) => to,
+        };
+        Self {
+            primary: blend(self.primary, target.primary),
+            secondary: blend(self.secondary, target.secondary),
+            success: blend(self.success, target.success),
+            warning: blend(self.warning, target.warning),
+            danger: blend(self.danger, target.danger),
+            background: blend(self.background, target.background),
+            surface: blend(self.surface, target.surface),
+            text: blend(self.text, target.text),
+            text_secondary: blend(self.text_secondary, target.text_secondary),
+        }
+    }
+
     /// Get colors for Iced Palette (6 colors)
     ///
     /// Returns RGB tuples (r, g, b) in 0.0-1.0 range for Iced compatibility.
@@ -376,7 +407,7 @@ pub struct IcedPaletteColors {
 }
 
 /// Typography configuration
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct Typography {
     pub font_family: Option<String>,
     pub font_size_base: Option<f32>,
@@ -384,6 +415,12 @@ pub struct Typography {
     pub font_size_large: Option<f32>,
     pub font_weight: FontWeight,
     pub line_height: Option<f32>,
+    /// Ratio between two steps of the modular scale of the [`TextStyle`]s
+    #[serde(default)]
+    pub scale_ratio: Option<f32>,
+    /// Font files (TTF or OTF) to register, relative to the theme directory
+    #[serde(default)]
+    pub fonts: Vec<String>,
 }
 
 impl Typography {
@@ -427,6 +464,12 @@ impl Typography {
             }
         }
 
+        if let Some(ratio) = self.scale_ratio
+            && ratio <= 1.0
+        {
+            errors.push(format!("scale_ratio must be greater than 1, got {}", ratio));
+        }
+
         if let Some(height) = self.line_height {
             if height <= 0.0 {
                 errors.push(format!("line_height must be positive, got {}", height));
@@ -485,15 +528,159 @@ impl Typography {
             font_size_large: self.font_size_large.or(parent.font_size_large),
             font_weight: self.font_weight,
             line_height: self.line_height.or(parent.line_height),
+            scale_ratio: self.scale_ratio.or(parent.scale_ratio),
+            fonts: if self.fonts.is_empty() {
+                parent.fonts.clone()
+            } else {
+                self.fonts.clone()
+            },
         }
     }
+
+    /// Base font size, 16 when the theme does not set it
+    pub fn base_size(&self) -> f32 {
+        self.font_size_base.unwrap_or(16.0)
+    }
+
+    /// Font size of a named text style: the base size times the scale ratio
+    /// (1.25 by default) raised to the step of the style
+    ///
+    /// Every style follows the base size, so changing it rescales all text.
+    pub fn size_of(&self, style: TextStyle) -> f32 {
+        let ratio = self.scale_ratio.unwrap_or(1.25);
+        let size = self.base_size() * ratio.powi(style.step());
+        (size * 10.0).round() / 10.0
+    }
+
+    /// Font weight of a named text style: bold for headings, else the theme weight
+    pub fn weight_of(&self, style: TextStyle) -> FontWeight {
+        if style.is_heading() {
+            FontWeight::Bold
+        } else {
+            self.font_weight
+        }
+    }
+
+    /// First family of `font_family`, without quotes: `"Inter", sans-serif` gives `Inter`
+    pub fn family(&self) -> Option<&str> {
+        self.font_family
+            .as_deref()
+            .and_then(|families| families.split(',').next())
+            .map(|family| family.trim().trim_matches(|c| c == '"' || c == '\''))
+            .filter(|family| !family.is_empty())
+    }
+
+    /// Set the size, weight and font of the `<text>` widgets of a tree
+    ///
+    /// A `style="heading1"` gets the size and weight of its [`TextStyle`], other
+    /// text the base size when the theme sets it. The family of the theme becomes
+    /// the `font`. `<markdown>` widgets get the base size, the family and the
+    /// `scale_ratio` of their headings. Attributes set on the widget are kept.
+    pub fn apply_to_node(&self, node: &mut WidgetNode) {
+        if node.kind == WidgetKind::Text {
+            let style = match node.attributes.get("style") {
+                Some(AttributeValue::Static(style)) => TextStyle::parse(style),
+                _ => None,
+            };
+            let size = match style {
+                Some(style) => Some(self.size_of(style)),
+                None => self.font_size_base,
+            };
+            if let Some(size) = size {
+                node.attributes
+                    .entry("size".to_string())
+                    .or_insert_with(|| AttributeValue::Static(size.to_string()));
+            }
+            if let Some(style) = style
+                && self.weight_of(style) != FontWeight::Normal
+            {
+                node.attributes.entry("weight".to_string()).or_insert_with(|| {
+                    AttributeValue::Static(self.weight_of(style).name().to_string())
+                });
+            }
+            if let Some(family) = self.family() {
+                node.attributes
+                    .entry("font".to_string())
+                    .or_insert_with(|| AttributeValue::Static(family.to_string()));
+            }
+        }
+        if node.kind == WidgetKind::Markdown {
+            let attributes = [
+                ("size", self.font_size_base.map(|size| size.to_string())),
+                ("font", self.family().map(str::to_string)),
+                (
+                    "scale_ratio",
+                    self.scale_ratio.map(|ratio| ratio.to_string()),
+                ),
+            ];
+            for (name, value) in attributes {
+                if let Some(value) = value {
+                    node.attributes
+                        .entry(name.to_string())
+                        .or_insert(AttributeValue::Static(value));
+                }
+            }
+        }
+        for child in &mut node.children {
+            self.apply_to_node(child);
+        }
+    }
+}
+
+/// Named text style of the modular scale: `<text style="heading1" />`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TextStyle {
+    Heading1,
+    Heading2,
+    Heading3,
+    Heading4,
+    Body,
+    Caption,
+}
+
+impl TextStyle {
+    /// Names of the styles, as written in XML
+    pub const NAMES: &[&str] = &[
+        "heading1", "heading2", "heading3", "heading4", "body", "caption",
+    ];
+
+    /// Parse a style name, `None` if it is not a text style
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim() {
+            "heading1" => Some(TextStyle::Heading1),
+            "heading2" => Some(TextStyle::Heading2),
+            "heading3" => Some(TextStyle::Heading3),
+            "heading4" => Some(TextStyle::Heading4),
+            "body" => Some(TextStyle::Body),
+            "caption" => Some(TextStyle::Caption),
+            _ => None,
+        }
+    }
+
+    /// Steps above the base size on the modular scale
+    pub fn step(&self) -> i32 {
+        match self {
+            TextStyle::Heading1 => 4,
+            TextStyle::Heading2 => 3,
+            TextStyle::Heading3 => 2,
+            TextStyle::Heading4 => 1,
+            TextStyle::Body => 0,
+            TextStyle::Caption => -1,
+        }
+    }
+
+    /// Whether the style is a heading
+    pub fn is_heading(&self) -> bool {
+        self.step() > 0
+    }
 }
 
 /// Font weight
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum FontWeight {
     Thin,
     Light,
+    #[default]
     Normal,
     Medium,
     Bold,
@@ -517,6 +704,18 @@ impl FontWeight {
         }
     }
 
+    /// Name of the weight, as parsed
+    pub fn name(&self) -> &'static str {
+        match self {
+            FontWeight::Thin => "thin",
+            FontWeight::Light => "light",
+            FontWeight::Normal => "normal",
+            FontWeight::Medium => "medium",
+            FontWeight::Bold => "bold",
+            FontWeight::Black => "black",
+        }
+    }
+
     /// Convert to CSS numeric value
     pub fn to_css(&self) -> u16 {
         match self {
@@ -587,6 +786,95 @@ impl SpacingScale {
     }
 }
 
+/// Shadows of the elevation levels 1 to 5 (`elevation="2"`)
+///
+/// Levels the theme does not set use [`ShadowScale::preset`]. Level 0 is flat.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ShadowScale {
+    /// Shadows set by the theme, from level 1 to 5
+    pub levels: [Option<Shadow>; 5],
+}
+
+impl ShadowScale {
+    /// Highest elevation level
+    pub const MAX_LEVEL: u8 = 5;
+
+    /// Built-in shadow of an elevation level, deeper and softer as it rises
+    pub fn preset(level: u8) -> Option<Shadow> {
+        let (offset_y, blur_radius, alpha) = match level {
+            1 => (1.0, 3.0, 0.12),
+            2 => (2.0, 6.0, 0.16),
+            3 => (4.0, 12.0, 0.19),
+            4 => (8.0, 24.0, 0.22),
+            5 => (16.0, 48.0, 0.26),
+            _ => return None,
+        };
+        Some(Shadow {
+            offset_x: 0.0,
+            offset_y,
+            blur_radius,
+            color: Color {
+                r: 0.0,
+                g: 0.0,
+                b: 0.0,
+                a: alpha,
+            },
+        })
+    }
+
+    /// Shadow of an elevation level, `None` for level 0
+    pub fn get(&self, level: u8) -> Option<Shadow> {
+        let index = usize::from(level).checked_sub(1)?;
+        self.levels
+            .get(index)
+            .copied()
+            .flatten()
+            .or_else(|| Self::preset(level))
+    }
+
+    /// Set the shadow of `style` from its `elevation`, unless it has an explicit `shadow`
+    pub fn apply(&self, style: &mut StyleProperties) {
+        if style.shadow.is_none()
+            && let Some(level) = style.elevation
+        {
+            style.shadow = self.get(level);
+        }
+    }
+
+    /// Resolve the elevation of a widget tree, its inline states included
+    pub fn apply_to_node(&self, node: &mut WidgetNode) {
+        if let Some(style) = &mut node.style {
+            self.apply(style);
+        }
+        for style in node.inline_state_variants.values_mut() {
+            self.apply(style);
+        }
+        for child in &mut node.children {
+            self.apply_to_node(child);
+        }
+    }
+
+    /// Resolve the elevation of a style class and its state variants
+    pub fn apply_to_class(&self, class: &mut StyleClass) {
+        self.apply(&mut class.style);
+        for style in class.state_variants.values_mut() {
+            self.apply(style);
+        }
+        for style in class.combined_state_variants.values_mut() {
+            self.apply(style);
+        }
+    }
+
+    /// Merge with a parent shadow scale, inheriting missing levels
+    pub fn inherit_from(&self, parent: &ShadowScale) -> Self {
+        let mut levels = self.levels;
+        for (level, parent_level) in levels.iter_mut().zip(parent.levels) {
+            *level = level.or(parent_level);
+        }
+        Self { levels }
+    }
+}
+
 /// Style class definition
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StyleClass {
@@ -596,9 +884,10 @@ pub struct StyleClass {
     /// Inherit from other classes
     pub extends: Vec<String>,
     /// State-specific overrides (single states)
+    #[serde(serialize_with = "crate::ir::stable::sorted")]
     pub state_variants: HashMap<WidgetState, StyleProperties>,
     /// Combined state overrides (e.g., hover:active)
-    #[serde(default)]
+    #[serde(default, with = "crate::ir::stable::sorted_pairs")]
     pub combined_state_variants: HashMap<StateSelector, StyleProperties>,
 }
 
@@ -723,7 +1012,7 @@ impl WidgetState {
 }
 
 /// State selector for style matching - can be single or combined states
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
 pub enum StateSelector {
     /// Single state (e.g., "hover")
     Single(WidgetState),
@@ -787,6 +1076,8 @@ pub enum ThemeErrorKind {
 pub struct ThemeError {
     pub kind: ThemeErrorKind,
     pub message: String,
+    /// Location of the error in the theme file, if it comes from parsing one
+    pub span: Option<Span>,
 }
 
 impl std::fmt::Display for ThemeError {
@@ -816,6 +1107,7 @@ impl std::fmt::Display for ThemeErrorKind {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ThemeDocument {
     /// All defined themes (light, dark, custom, etc.)
+    #[serde(serialize_with = "crate::ir::stable::sorted")]
     pub themes: HashMap<String, Theme>,
 
     /// Default theme name to use on startup
@@ -839,6 +1131,7 @@ impl ThemeDocument {
                 kind: ThemeErrorKind::NoThemesDefined,
                 message: "THEME_001: At least one theme must be defined in theme.dampen"
                     .to_string(),
+                span: None,
             });
         }
 
@@ -853,15 +1146,12 @@ impl ThemeDocument {
                     default,
                     available.join(", ")
                 ),
+                span: None,
             });
         }
 
         for (name, theme) in &self.themes {
-            let allow_partial = theme.extends.is_some();
-            theme.validate(allow_partial).map_err(|e| ThemeError {
-                kind: ThemeErrorKind::MissingPaletteColor,
-                message: format!("THEME_003: Invalid theme '{}': {}", name, e),
-            })?;
+            validate_theme(name, theme)?;
         }
 
         Ok(())
@@ -935,6 +1225,28 @@ impl ThemeDocument {
         }
         "light"
     }
+
+    /// Font files declared by the themes, sorted and without duplicates
+    pub fn fonts(&self) -> Vec<&str> {
+        let mut fonts: Vec<&str> = self
+            .themes
+            .values()
+            .flat_map(|theme| theme.typography.fonts.iter().map(String::as_str))
+            .collect();
+        fonts.sort_unstable();
+        fonts.dedup();
+        fonts
+    }
+}
+
+/// Validate the theme named `name` of a document
+pub(crate) fn validate_theme(name: &str, theme: &Theme) -> Result<(), ThemeError> {
+    let allow_partial = theme.extends.is_some();
+    theme.validate(allow_partial).map_err(|e| ThemeError {
+        kind: ThemeErrorKind::MissingPaletteColor,
+        message: format!("THEME_003: Invalid theme '{}': {}", name, e),
+        span: None,
+    })
 }
 
 #[cfg(test)]
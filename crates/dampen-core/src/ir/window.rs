@@ -0,0 +1,120 @@
+//! Window declaration of a view document
+//!
+//! A `<window>` element in `<dampen>` declares the settings of the application
+//! window, instead of setting them in `main.rs`:
+//!
+//! ```xml
+//! <dampen>
+//!     <window title="My App" width="800" height="600" min_width="400" resizable="false" />
+//!     <column>...</column>
+//! </dampen>
+//! ```
+//!
+//! Only the title can change while the application runs; the other settings
+//! apply when the window is created.
+
+use serde::{Deserialize, Serialize};
+
+/// Settings of the application window (`<window>`)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct WindowConfig {
+    pub title: Option<String>,
+    /// Initial width, in logical pixels
+    pub width: Option<u32>,
+    /// Initial height, in logical pixels
+    pub height: Option<u32>,
+    pub min_width: Option<u32>,
+    pub min_height: Option<u32>,
+    pub max_width: Option<u32>,
+    pub max_height: Option<u32>,
+    pub resizable: Option<bool>,
+    /// Whether the window has a title bar and borders
+    pub decorations: Option<bool>,
+}
+
+impl WindowConfig {
+    /// Attributes of the `<window>` element
+    pub const ATTRIBUTES: &'static [&'static str] = &[
+        "title",
+        "width",
+        "height",
+        "min_width",
+        "min_height",
+        "max_width",
+        "max_height",
+        "resizable",
+        "decorations",
+    ];
+
+    /// Default size of a window missing one of `width` and `height`
+    pub const DEFAULT_SIZE: (u32, u32) = (1024, 768);
+
+    /// Initial size, if `width` or `height` is declared
+    pub fn size(&self) -> Option<(u32, u32)> {
+        if self.width.is_none() && self.height.is_none() {
+            return None;
+        }
+        Some((
+            self.width.unwrap_or(Self::DEFAULT_SIZE.0),
+            self.height.unwrap_or(Self::DEFAULT_SIZE.1),
+        ))
+    }
+
+    /// Minimum size, if `min_width` or `min_height` is declared
+    pub fn min_size(&self) -> Option<(u32, u32)> {
+        if self.min_width.is_none() && self.min_height.is_none() {
+            return None;
+        }
+        Some((self.min_width.unwrap_or(0), self.min_height.unwrap_or(0)))
+    }
+
+    /// Maximum size, if `max_width` or `max_height` is declared
+    pub fn max_size(&self) -> Option<(u32, u32)> {
+        if self.max_width.is_none() && self.max_height.is_none() {
+            return None;
+        }
+        Some((
+            self.max_width.unwrap_or(u32::MAX),
+            self.max_height.unwrap_or(u32::MAX),
+        ))
+    }
+
+    /// Validate that the minimum size does not exceed the maximum size
+    pub fn validate(&self) -> Result<(), String> {
+        let check = |min: Option<u32>, max: Option<u32>, name: &str| match (min, max) {
+            (Some(min), Some(max)) if min > max => Err(format!(
+                "Window min_{} ({}) is greater than max_{} ({})",
+                name, min, name, max
+            )),
+            _ => Ok(()),
+        };
+        check(self.min_width, self.max_width, "width")?;
+        check(self.min_height, self.max_height, "height")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_sizes_fill_missing_dimensions() {
+        let config = WindowConfig {
+            width: Some(800),
+            min_height: Some(300),
+            max_width: Some(1600),
+            ..WindowConfig::default()
+        };
+        assert_eq!(config.size(), Some((800, 768)));
+        assert_eq!(config.min_size(), Some((0, 300)));
+        assert_eq!(config.max_size(), Some((1600, u32::MAX)));
+        assert_eq!(WindowConfig::default().size(), None);
+
+        let config = WindowConfig {
+            min_width: Some(900),
+            max_width: Some(600),
+            ..WindowConfig::default()
+        };
+        assert!(config.validate().is_err());
+    }
+}
@@ -65,16 +65,31 @@
 //! See individual modules for detailed documentation.
 
 // Module declarations
+pub mod assets;
 pub mod binding;
 pub mod codegen;
+pub mod coverage;
+pub mod env;
 pub mod expr;
+pub mod format;
 pub mod handler;
+pub mod icons;
+pub mod input;
 pub mod ir;
+pub mod log;
+pub mod mock;
+pub mod pagination;
+pub mod palette;
 pub mod parser;
 pub mod schema;
+pub mod selection;
 pub mod shared;
 pub mod state;
+pub mod system;
+pub mod text;
+pub mod theme_switch;
 pub mod traits;
+pub mod viewport;
 
 // Public exports
 
@@ -83,7 +98,7 @@ pub mod traits;
 /// This module provides the core abstraction for data binding in Dampen.
 /// Types implementing `UiBindable` can have their fields accessed from
 /// binding expressions in XML.
-pub use binding::{BindingValue, ToBindingValue, UiBindable};
+pub use binding::{BindingValue, SelectOption, ToBindingValue, UiBindable};
 
 /// Expression evaluation and AST types.
 ///
@@ -91,23 +106,66 @@ pub use binding::{BindingValue, ToBindingValue, UiBindable};
 /// `{counter}`, `{items.len()}`, and `{if x > 0 then 'yes' else 'no'}`.
 pub use expr::{
     BinaryOp, BinaryOpExpr, BindingError, BindingErrorKind, BindingExpr, ConditionalExpr, Expr,
-    FieldAccessExpr, LiteralExpr, MethodCallExpr, SharedFieldAccessExpr, UnaryOp, UnaryOpExpr,
-    evaluate_binding_expr, evaluate_expr, evaluate_formatted,
+    FieldAccessExpr, FilterExpr, LiteralExpr, MethodCallExpr, SharedFieldAccessExpr, UnaryOp,
+    UnaryOpExpr, ValueFormat, apply_filter, evaluate_binding_expr, evaluate_expr,
+    evaluate_formatted, matches_query,
 };
 
+/// Values of the environment the view is rendered in.
+///
+/// This module holds the window size, breakpoint, active theme, platform and
+/// build profile bound as `{env.viewport_width}`, `{env.breakpoint}`, etc.
+pub use env::EnvValues;
+
 /// Event handler management and signatures.
 ///
 /// This module provides the registry for event handlers and signature
 /// validation for compile-time checking.
 pub use handler::{HandlerEntry, HandlerRegistry, HandlerSignature};
 
+/// Text input constraints.
+///
+/// This module enforces the `max_length` and `filter` of a text input, and the
+/// range of a number input, before its value is dispatched.
+pub use input::{InputConstraints, InputFilter, NumberRange};
+
+/// Command palette model.
+///
+/// This module lists handlers as palette commands, fuzzy-searches them and
+/// tracks the query and selection of the open palette.
+pub use palette::{CommandPalette, PaletteCommand};
+
+/// Selection model for lists and grids.
+///
+/// This module tracks the selected rows of a list with the click, Ctrl+click,
+/// Shift+click and keyboard semantics of desktop lists.
+pub use selection::{SelectionMode, SelectionState};
+
+/// Accessibility preferences of the system.
+///
+/// This module holds the reduced-motion and high-contrast settings bound as
+/// `{system.reduced_motion}` and `{system.high_contrast}`.
+pub use system::SystemPreferences;
+
+/// Wrapping and clamping of text.
+///
+/// This module maps the `wrap`, `max_lines` and `truncate` attributes of a text
+/// to its wrapping, height and ellipsized value.
+pub use text::{TextClamp, TextWrap};
+
+/// Size of the application window.
+///
+/// This module holds the window size the breakpoint-prefixed attributes of
+/// widgets are resolved against.
+pub use viewport::ViewportSize;
+
 /// Intermediate Representation (IR) types.
 ///
 /// This module contains all types representing the parsed structure of
 /// a Dampen UI document, suitable for rendering or code generation.
 pub use ir::{
-    AttributeValue, DampenDocument, EventBinding, EventKind, InterpolatedPart, SchemaVersion, Span,
-    WidgetKind, WidgetNode,
+    Accessibility, AccessibilityRole, AttributeValue, DampenDocument, EventBinding, EventKind,
+    InterpolatedPart, SchemaVersion, Span, WidgetKind, WidgetNode,
 };
 
 /// XML parsing and error types.
@@ -115,8 +173,10 @@ pub use ir::{
 /// This module provides the parser that converts XML markup into the IR.
 pub use parser::error::{ParseError, ParseErrorKind};
 pub use parser::{
-    MAX_SUPPORTED_VERSION, ValidationWarning, parse, parse_version_string,
-    validate_version_supported, validate_widget_versions,
+    AccessibilityIssue, AccessibilityWarning, MAX_SUPPORTED_VERSION, ParseLimits,
+    ValidationWarning, parse, parse_version_string, parse_with_limits, parse_with_recovery,
+    parse_with_styles, reparse_with_recovery, validate_accessibility, validate_version_supported,
+    validate_widget_versions,
 };
 
 /// Widget schema definitions and constants.
@@ -147,16 +207,28 @@ pub use state::AppState;
 ///
 /// This module provides the [`ThemeContext`] struct for
 /// managing theme state including active theme, switching, and hot-reload.
-pub use state::ThemeContext;
+pub use state::{ThemeContext, ThemeDiff};
+
+/// Ephemeral widget state preserved across hot-reload.
+///
+/// This module provides the [`WidgetStateTracker`] struct for recording
+/// scroll offsets, text cursors and focus keyed by widget id.
+pub use state::{WidgetStateSnapshot, WidgetStateTracker};
+
+/// Headless embedding of a view, without any UI backend.
+///
+/// [`AppState::render`] evaluates the widget tree into an [`EvaluatedNode`]
+/// tree of plain data.
+pub use state::{EvaluatedEvent, EvaluatedNode, HeadlessError};
 
 /// Shared state container for inter-window communication.
 ///
 /// This module provides the [`SharedContext`] struct for
 /// sharing state across multiple views in a Dampen application.
-pub use shared::SharedContext;
+pub use shared::{SharedContext, SharedContexts};
 
 /// Tokenize a binding expression for debugging or custom processing.
-pub use expr::tokenize_binding_expr;
+pub use expr::{parse_binding_expr, tokenize_binding_expr};
 
 /// Version of the Dampen framework
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
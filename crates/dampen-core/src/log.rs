@@ -0,0 +1,39 @@
+//! Structured logging facade
+//!
+//! Dampen logs through [`tracing`]. Handlers and hot-reloads run inside spans
+//! annotated with the view, the handler name and the reload generation of the
+//! view, so that every event logged by a handler carries them:
+//!
+//! ```rust,ignore
+//! use dampen_core::log;
+//!
+//! fn increment(model: &mut Model) {
+//!     model.count += 1;
+//!     log::debug!(count = model.count, "incremented");
+//!     // DEBUG handler{view="counter" handler="increment" generation=2}: incremented count=3
+//! }
+//! ```
+//!
+//! Nothing is printed until a subscriber is installed; in development mode,
+//! `dampen_dev::logging::init()` installs one, filtered by the `DAMPEN_LOG`
+//! environment variable (set by `dampen run --log <level>`).
+
+use tracing::Span;
+
+pub use tracing::{Level, debug, error, info, trace, warn};
+
+/// Environment variable holding the log filter of development mode, e.g. `debug`
+pub const LOG_ENV: &str = "DAMPEN_LOG";
+
+/// Span of a handler of `view`, at the given reload generation of the view
+///
+/// Events logged while the span is entered are annotated with the view, the
+/// handler name and the generation.
+pub fn handler_span(view: &str, handler: &str, generation: u64) -> Span {
+    tracing::info_span!("handler", view, handler, generation)
+}
+
+/// Span of the hot-reload of `view` producing the given reload generation
+pub fn reload_span(view: &str, generation: u64) -> Span {
+    tracing::info_span!("reload", view, generation)
+}
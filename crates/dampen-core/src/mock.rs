@@ -0,0 +1,935 @@
+//! Mock models for previews
+//!
+//! A [`MockModel`] holds plausible fake data, so that a view renders
+//! meaningfully without the real application: previews, visual tests and
+//! editor hovers bind against it like against the real model.
+//!
+//! The fields come from a model's `available_fields()`, from typed field
+//! lists (e.g. the `model.json` of `dampen check`), or from the bindings of a
+//! document itself. Their values follow the type when it is known, how the
+//! document uses the field otherwise (`<for in>` sources are lists, `<if>`
+//! conditions are booleans, `| date` inputs are dates), and finally the field
+//! name: `email` gets an address, `price` a float, `is_done` a boolean, and
+//! plural names such as `items` get 3-item lists.
+//!
+//! Values are deterministic, so that renders of the same document compare
+//! equal.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::binding::{BindingValue, UiBindable};
+use crate::expr::{BinaryOp, Expr, LiteralExpr, UnaryOp};
+use crate::ir::DampenDocument;
+use crate::ir::node::{AttributeValue, InterpolatedPart, WidgetKind, WidgetNode};
+use crate::ir::scope::resolve_model_scopes;
+use crate::shared::SharedContext;
+use crate::state::AppState;
+
+/// Number of items of mock lists
+pub const LIST_LENGTH: usize = 3;
+
+/// A model of fake data, bound like a real model
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MockModel {
+    fields: HashMap<String, BindingValue>,
+}
+
+impl MockModel {
+    /// Create a mock model without fields
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mock the fields listed by `M::available_fields()`
+    pub fn of<M: UiBindable>() -> Self {
+        Self::from_fields(M::available_fields())
+    }
+
+    /// Mock dotted field paths (e.g. `user.name`), typed after their name
+    pub fn from_fields<I, P>(paths: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<str>,
+    {
+        let mut shapes = Shape::Object(BTreeMap::new());
+        for path in paths {
+            let path: Vec<&str> = path.as_ref().split('.').collect();
+            shapes.touch(&path, None);
+        }
+        Self::from_shape(&shapes)
+    }
+
+    /// Mock dotted field paths with their Rust type (e.g. `("tags", "Vec<String>")`)
+    ///
+    /// Types that are not recognized (such as the name of a struct) fall back
+    /// to the field name.
+    pub fn from_typed_fields<I, P, T>(fields: I) -> Self
+    where
+        I: IntoIterator<Item = (P, T)>,
+        P: AsRef<str>,
+        T: AsRef<str>,
+    {
+        let mut shapes = Shape::Object(BTreeMap::new());
+        for (path, type_name) in fields {
+            let path: Vec<&str> = path.as_ref().split('.').collect();
+            shapes.touch_type(&path, type_name.as_ref());
+        }
+        Self::from_shape(&shapes)
+    }
+
+    /// Mock the model fields bound by a document, shaped by their use
+    pub fn from_document(document: &DampenDocument) -> Self {
+        Self::from_shape(&DocumentShapes::of(document).model)
+    }
+
+    /// Mock the shared fields (`{shared.*}`) bound by a document
+    pub fn shared_from_document(document: &DampenDocument) -> Self {
+        Self::from_shape(&DocumentShapes::of(document).shared)
+    }
+
+    /// Replace the value at a dotted path, creating the objects leading to it
+    pub fn set(&mut self, path: &str, value: BindingValue) {
+        let mut segments = path.split('.');
+        let Some(first) = segments.next() else {
+            return;
+        };
+        let mut slot = self
+            .fields
+            .entry(first.to_string())
+            .or_insert(BindingValue::None);
+        for segment in segments {
+            if !matches!(slot, BindingValue::Object(_)) {
+                *slot = BindingValue::Object(HashMap::new());
+            }
+            let BindingValue::Object(fields) = slot else {
+                return;
+            };
+            slot = fields
+                .entry(segment.to_string())
+                .or_insert(BindingValue::None);
+        }
+        *slot = value;
+    }
+
+    /// Builder form of [`set`](Self::set)
+    pub fn with(mut self, path: &str, value: BindingValue) -> Self {
+        self.set(path, value);
+        self
+    }
+
+    /// The whole model as a [`BindingValue::Object`]
+    pub fn to_value(&self) -> BindingValue {
+        BindingValue::Object(self.fields.clone())
+    }
+
+    fn from_shape(shape: &Shape) -> Self {
+        let fields = match shape {
+            Shape::Object(fields) => fields
+                .iter()
+                .map(|(name, shape)| (name.clone(), shape.value(name, None)))
+                .collect(),
+            _ => HashMap::new(),
+        };
+        Self { fields }
+    }
+}
+
+impl UiBindable for MockModel {
+    fn get_field(&self, path: &[&str]) -> Option<BindingValue> {
+        let (first, rest) = path.split_first()?;
+        let mut value = self.fields.get(*first)?;
+        for segment in rest {
+            match value {
+                BindingValue::Object(fields) => value = fields.get(*segment)?,
+                _ => return None,
+            }
+        }
+        Some(value.clone())
+    }
+
+    /// Fields depend on the instance, so none are listed
+    fn available_fields() -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// Create a state rendering `document` with the mock model and shared state it binds
+pub fn app_state(document: DampenDocument) -> AppState<MockModel, MockModel> {
+    let shapes = DocumentShapes::of(&document);
+    AppState::with_shared(
+        document,
+        MockModel::from_shape(&shapes.model),
+        crate::handler::HandlerRegistry::new(),
+        SharedContext::new(MockModel::from_shape(&shapes.shared)),
+    )
+}
+
+/// A plausible value for a field, from its Rust type if given, else from its name
+///
+/// # Examples
+///
+/// ```rust
+/// use dampen_core::BindingValue;
+/// use dampen_core::mock::mock_value;
+///
+/// assert_eq!(mock_value("count", Some("u32")), BindingValue::Integer(3));
+/// assert_eq!(mock_value("is_done", None), BindingValue::Bool(true));
+/// ```
+pub fn mock_value(name: &str, type_name: Option<&str>) -> BindingValue {
+    let shape = type_name.map(type_shape).unwrap_or_default();
+    shape.value(name, None)
+}
+
+/// Path segment standing for the items of a list
+const ITEMS: &str = "[]";
+
+/// Type of a mocked value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Bool,
+    Integer,
+    Float,
+    Text,
+    DateTime,
+    Duration,
+    Decimal,
+}
+
+/// What is known of a field while collecting it
+#[derive(Debug, Clone, Default, PartialEq)]
+enum Shape {
+    /// Only the name is known
+    #[default]
+    Unknown,
+    Value(Kind),
+    /// Text compared with these literals; list items take them in turn
+    Examples(Vec<String>),
+    Object(BTreeMap<String, Shape>),
+    List(Box<Shape>),
+}
+
+impl Shape {
+    /// Record a use of `path` under this shape; [`ITEMS`] segments are list items
+    ///
+    /// A field used with sub-fields is an object; a value kind only refines
+    /// a shape that is still unknown.
+    fn touch(&mut self, path: &[&str], kind: Option<Kind>) {
+        match path.split_first() {
+            None => {
+                if let (Shape::Unknown, Some(kind)) = (&*self, kind) {
+                    *self = Shape::Value(kind);
+                }
+            }
+            Some((&ITEMS, rest)) => {
+                if !matches!(self, Shape::List(_)) {
+                    *self = Shape::List(Box::default());
+                }
+                if let Shape::List(items) = self {
+                    items.touch(rest, kind);
+                }
+            }
+            Some((field, rest)) => {
+                if !matches!(self, Shape::Object(_)) {
+                    *self = Shape::Object(BTreeMap::new());
+                }
+                if let Shape::Object(fields) = self {
+                    fields
+                        .entry((*field).to_string())
+                        .or_default()
+                        .touch(rest, kind);
+                }
+            }
+        }
+    }
+
+    /// Record a field of a known Rust type
+    fn touch_type(&mut self, path: &[&str], type_name: &str) {
+        self.touch(path, None);
+        if let Some(field) = self.get_mut(path)
+            && matches!(field, Shape::Unknown | Shape::Value(_))
+        {
+            *field = type_shape(type_name);
+        }
+    }
+
+    /// Record a text field compared with a string literal
+    fn touch_example(&mut self, path: &[&str], text: &str) {
+        self.touch(path, None);
+        let Some(field) = self.get_mut(path) else {
+            return;
+        };
+        match field {
+            Shape::Unknown | Shape::Value(Kind::Text) => {
+                *field = Shape::Examples(vec![text.to_string()]);
+            }
+            Shape::Examples(examples) if !examples.iter().any(|example| example == text) => {
+                examples.push(text.to_string());
+            }
+            _ => {}
+        }
+    }
+
+    fn get_mut(&mut self, path: &[&str]) -> Option<&mut Shape> {
+        match path.split_first() {
+            None => Some(self),
+            Some((&ITEMS, rest)) => match self {
+                Shape::List(items) => items.get_mut(rest),
+                _ => None,
+            },
+            Some((field, rest)) => match self {
+                Shape::Object(fields) => fields.get_mut(*field)?.get_mut(rest),
+                _ => None,
+            },
+        }
+    }
+
+    /// Mock value of a field named `name`; `item` is the position in the enclosing list
+    fn value(&self, name: &str, item: Option<usize>) -> BindingValue {
+        match self {
+            Shape::Object(fields) => BindingValue::Object(
+                fields
+                    .iter()
+                    .map(|(field, shape)| (field.clone(), shape.value(field, item)))
+                    .collect(),
+            ),
+            Shape::List(items) => {
+                let name = singular(name);
+                BindingValue::List(
+                    (0..LIST_LENGTH)
+                        .map(|index| items.value(&name, Some(index)))
+                        .collect(),
+                )
+            }
+            Shape::Value(kind) => kind_value(*kind, name, item),
+            Shape::Examples(examples) => {
+                let index = item.unwrap_or(0) % examples.len().max(1);
+                examples
+                    .get(index)
+                    .map(|example| BindingValue::String(example.clone()))
+                    .unwrap_or_else(|| kind_value(Kind::Text, name, item))
+            }
+            Shape::Unknown => match name_shape(name) {
+                Shape::Unknown => kind_value(Kind::Text, name, item),
+                shape => shape.value(name, item),
+            },
+        }
+    }
+}
+
+/// Shape of a Rust type, e.g. `Vec<String>` is a list of text
+fn type_shape(type_name: &str) -> Shape {
+    let type_name = type_name
+        .trim()
+        .trim_start_matches('&')
+        .trim_start_matches("mut ")
+        .trim();
+
+    if let Some(items) = type_name
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+    {
+        return Shape::List(Box::new(type_shape(items)));
+    }
+    if let Some((outer, inner)) = type_name
+        .strip_suffix('>')
+        .and_then(|rest| rest.split_once('<'))
+    {
+        let outer = outer.rsplit("::").next().unwrap_or(outer);
+        return match outer {
+            "Vec" | "VecDeque" | "HashSet" | "BTreeSet" | "IndexSet" => {
+                Shape::List(Box::new(type_shape(inner)))
+            }
+            "Option" | "Box" | "Rc" | "Arc" | "Cell" | "RefCell" | "Cow" => type_shape(inner),
+            "DateTime" => Shape::Value(Kind::DateTime),
+            _ => Shape::Unknown,
+        };
+    }
+
+    let name = type_name.rsplit("::").next().unwrap_or(type_name);
+    match name {
+        "bool" => Shape::Value(Kind::Bool),
+        "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64" | "u128"
+        | "usize" => Shape::Value(Kind::Integer),
+        "f32" | "f64" => Shape::Value(Kind::Float),
+        "String" | "str" | "char" => Shape::Value(Kind::Text),
+        "Decimal" => Shape::Value(Kind::Decimal),
+        "NaiveDateTime" | "NaiveDate" | "NaiveTime" => Shape::Value(Kind::DateTime),
+        "Duration" | "TimeDelta" => Shape::Value(Kind::Duration),
+        _ => Shape::Unknown,
+    }
+}
+
+/// Shape guessed from a field name, [`Shape::Unknown`] for plain text
+fn name_shape(name: &str) -> Shape {
+    const BOOLS: &[&str] = &[
+        "enabled",
+        "disabled",
+        "visible",
+        "hidden",
+        "checked",
+        "selected",
+        "active",
+        "done",
+        "completed",
+        "loading",
+        "expanded",
+        "open",
+        "valid",
+        "dirty",
+        "busy",
+        "toggled",
+        "finished",
+        "archived",
+        "favorite",
+        "read",
+    ];
+    const INTEGERS: &[&str] = &[
+        "count", "total", "age", "year", "month", "day", "hour", "minute", "second", "index", "id",
+        "quantity", "qty", "size", "length", "page", "step", "level", "position", "rank", "number",
+        "port",
+    ];
+    const FLOATS: &[&str] = &[
+        "price",
+        "amount",
+        "cost",
+        "balance",
+        "rating",
+        "ratio",
+        "progress",
+        "percent",
+        "temperature",
+        "latitude",
+        "longitude",
+        "opacity",
+        "volume",
+        "weight",
+        "score",
+    ];
+
+    let name = name.to_lowercase();
+    let last_word = name.rsplit('_').next().unwrap_or(&name);
+
+    let is_bool = ["is_", "has_", "can_", "show_", "should_", "allow_"]
+        .iter()
+        .any(|prefix| name.starts_with(prefix))
+        || BOOLS.contains(&last_word);
+    if is_bool {
+        Shape::Value(Kind::Bool)
+    } else if INTEGERS.contains(&last_word) {
+        Shape::Value(Kind::Integer)
+    } else if FLOATS.iter().any(|word| name.contains(word)) {
+        Shape::Value(Kind::Float)
+    } else if name.ends_with("_at")
+        || [
+            "date",
+            "deadline",
+            "birthday",
+            "timestamp",
+            "created",
+            "updated",
+            "modified",
+        ]
+        .contains(&last_word)
+    {
+        Shape::Value(Kind::DateTime)
+    } else if ["duration", "elapsed"].contains(&last_word) {
+        Shape::Value(Kind::Duration)
+    } else if is_plural(last_word) {
+        Shape::List(Box::default())
+    } else {
+        Shape::Unknown
+    }
+}
+
+fn is_plural(word: &str) -> bool {
+    word.len() > 3
+        && word.ends_with('s')
+        && !["ss", "us", "is", "news"]
+            .iter()
+            .any(|suffix| word.ends_with(suffix))
+}
+
+/// Singular of a list name, naming its items (e.g. `categories` → `category`)
+fn singular(name: &str) -> String {
+    if !is_plural(name) {
+        return name.to_string();
+    }
+    if let Some(stem) = name.strip_suffix("ies") {
+        return format!("{}y", stem);
+    }
+    for suffix in ["ches", "shes", "xes", "ses"] {
+        if name.ends_with(suffix) {
+            return name[..name.len() - 2].to_string();
+        }
+    }
+    name[..name.len() - 1].to_string()
+}
+
+fn kind_value(kind: Kind, name: &str, item: Option<usize>) -> BindingValue {
+    let index = item.unwrap_or(0);
+    let name = name.to_lowercase();
+    let last_word = name.rsplit('_').next().unwrap_or(&name);
+    let has = |words: &[&str]| words.iter().any(|word| name.contains(word));
+
+    match kind {
+        Kind::Bool => {
+            let negative = has(&[
+                "disabled", "hidden", "loading", "error", "busy", "failed", "deleted", "archived",
+            ]);
+            // Items alternate, so that both states show in lists
+            BindingValue::Bool(!negative && index.is_multiple_of(2))
+        }
+        Kind::Integer => BindingValue::Integer(if last_word == "year" {
+            2024
+        } else if last_word == "age" {
+            32 + index as i64
+        } else if ["id", "index", "position", "rank", "number"].contains(&last_word) {
+            index as i64 + 1
+        } else if has(&["progress", "percent"]) {
+            [42, 75, 10][index % 3]
+        } else {
+            [3, 5, 8][index % 3]
+        }),
+        Kind::Float => BindingValue::Float(if has(&["rating", "score"]) {
+            [4.5, 3.8, 4.9][index % 3]
+        } else if has(&["progress", "ratio", "opacity"]) {
+            [0.42, 0.75, 0.1][index % 3]
+        } else if has(&["percent"]) {
+            [42.0, 75.0, 10.0][index % 3]
+        } else if has(&["temperature"]) {
+            21.5
+        } else if has(&["price", "amount", "cost", "balance"]) {
+            [19.99, 4.5, 12.0][index % 3]
+        } else {
+            [1.5, 2.75, 0.5][index % 3]
+        }),
+        Kind::Decimal => {
+            BindingValue::Decimal(rust_decimal::Decimal::new([1999, 450, 1200][index % 3], 2))
+        }
+        Kind::DateTime => {
+            // 2024-03-09 14:30:00 UTC, a day apart per item
+            chrono::DateTime::from_timestamp(1_709_994_600 + 86_400 * index as i64, 0)
+                .map(|date| BindingValue::DateTime(date.fixed_offset()))
+                .unwrap_or(BindingValue::None)
+        }
+        Kind::Duration => chrono::TimeDelta::try_seconds(5025 + 60 * index as i64)
+            .map(BindingValue::Duration)
+            .unwrap_or(BindingValue::None),
+        Kind::Text => BindingValue::String(text_value(&name, item)),
+    }
+}
+
+fn text_value(name: &str, item: Option<usize>) -> String {
+    const FIRST_NAMES: [&str; 3] = ["Alex", "Sam", "Jordan"];
+    const LAST_NAMES: [&str; 3] = ["Morgan", "Rivera", "Lee"];
+    const WORDS: [&str; 3] = ["Lorem ipsum", "Dolor sit amet", "Consectetur adipiscing"];
+    const SENTENCES: [&str; 3] = [
+        "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
+        "Sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.",
+        "Ut enim ad minim veniam, quis nostrud exercitation ullamco.",
+    ];
+
+    let index = item.unwrap_or(0) % 3;
+    let has = |words: &[&str]| words.iter().any(|word| name.contains(word));
+
+    if has(&["email", "mail"]) {
+        format!("{}@example.com", FIRST_NAMES[index].to_lowercase())
+    } else if has(&["first_name", "firstname"]) {
+        FIRST_NAMES[index].to_string()
+    } else if has(&["last_name", "lastname", "surname"]) {
+        LAST_NAMES[index].to_string()
+    } else if has(&["file", "path"]) {
+        ["report.pdf", "notes.txt", "photo.png"][index].to_string()
+    } else if has(&["name", "author", "owner", "user"]) {
+        format!("{} {}", FIRST_NAMES[index], LAST_NAMES[index])
+    } else if has(&["url", "link", "href", "website"]) {
+        "https://example.com".to_string()
+    } else if has(&["phone"]) {
+        format!("+1 555 010{}", index)
+    } else if has(&["city"]) {
+        ["Paris", "Berlin", "Lisbon"][index].to_string()
+    } else if has(&["country"]) {
+        ["France", "Germany", "Portugal"][index].to_string()
+    } else if has(&["color", "colour"]) {
+        ["#3498db", "#e74c3c", "#2ecc71"][index].to_string()
+    } else if has(&["status", "state"]) {
+        ["Active", "Pending", "Done"][index].to_string()
+    } else if has(&[
+        "description",
+        "body",
+        "content",
+        "text",
+        "message",
+        "note",
+        "bio",
+        "summary",
+        "comment",
+    ]) {
+        SENTENCES[index].to_string()
+    } else if let Some(index) = item
+        && !has(&["title", "label", "heading", "subject"])
+    {
+        // List items are numbered after the list (e.g. "Tag 2" for `tags`)
+        format!("{} {}", capitalize(&name.replace('_', " ")), index + 1)
+    } else {
+        WORDS[index].to_string()
+    }
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// A field path, `true` if it is in the shared state
+type FieldPath = (bool, Vec<String>);
+
+/// Shapes of the model and shared fields bound by a document
+struct DocumentShapes {
+    model: Shape,
+    shared: Shape,
+    /// Loop variables in scope, with the list items they stand for (`None` for `index`)
+    locals: Vec<(String, Option<FieldPath>)>,
+}
+
+impl DocumentShapes {
+    fn of(document: &DampenDocument) -> Self {
+        let mut shapes = Self {
+            model: Shape::Object(BTreeMap::new()),
+            shared: Shape::Object(BTreeMap::new()),
+            locals: Vec::new(),
+        };
+        // Bindings under `model="{...}"` then read from the root model
+        shapes.node(&resolve_model_scopes(&document.root));
+        shapes
+    }
+
+    fn node(&mut self, node: &WidgetNode) {
+        for (name, value) in &node.attributes {
+            if node.is_option_template(name) || (node.kind == WidgetKind::For && name == "in") {
+                if let AttributeValue::Binding(binding) = value
+                    && let Expr::Filter(filter) = &binding.expr
+                    && filter.name == "filter_by"
+                    && let Some(query) = filter.args.first()
+                {
+                    self.expr(query, Some(Kind::Text));
+                }
+                continue;
+            }
+            let kind = attribute_kind(&node.kind, name);
+            self.attribute(value, kind);
+        }
+        for attributes in node.breakpoint_attributes.values() {
+            for (name, value) in attributes {
+                self.attribute(value, attribute_kind(&node.kind, name));
+            }
+        }
+        for event in &node.events {
+            for param in event.param.iter().chain(&event.params) {
+                self.expr(&param.expr, None);
+            }
+        }
+
+        // Option templates see each option as `item`
+        if node.has_bound_options()
+            && let Some(options) = node.attributes.get("options").and_then(list_source)
+            && let Some(options) = self.items(options)
+        {
+            self.locals.push(("item".to_string(), Some(options)));
+            self.locals.push(("index".to_string(), None));
+            for (name, value) in &node.attributes {
+                if node.is_option_template(name) {
+                    self.attribute(value, None);
+                }
+            }
+            self.locals.truncate(self.locals.len() - 2);
+        }
+
+        let loop_items = match node.kind {
+            WidgetKind::For => node
+                .attributes
+                .get("in")
+                .and_then(list_source)
+                .and_then(|source| self.items(source)),
+            _ => None,
+        };
+        match loop_items {
+            Some(items) => {
+                let each = match node.attributes.get("each") {
+                    Some(AttributeValue::Static(name)) => name.clone(),
+                    _ => "item".to_string(),
+                };
+                self.locals.push((each, Some(items)));
+                self.locals.push(("index".to_string(), None));
+                for child in &node.children {
+                    self.node(child);
+                }
+                self.locals.truncate(self.locals.len() - 2);
+            }
+            None => {
+                for child in &node.children {
+                    self.node(child);
+                }
+            }
+        }
+    }
+
+    fn attribute(&mut self, value: &AttributeValue, kind: Option<Kind>) {
+        match value {
+            AttributeValue::Static(_) => {}
+            AttributeValue::Binding(binding) => self.expr(&binding.expr, kind),
+            AttributeValue::Interpolated(parts) => {
+                for part in parts {
+                    if let InterpolatedPart::Binding(binding) = part {
+                        self.expr(&binding.expr, None);
+                    }
+                }
+            }
+        }
+    }
+
+    fn expr(&mut self, expr: &Expr, kind: Option<Kind>) {
+        match expr {
+            Expr::FieldAccess(field) => {
+                if let Some((shared, path)) = self.resolve(false, &field.path) {
+                    self.touch(shared, &path, kind);
+                }
+            }
+            Expr::SharedFieldAccess(field) => {
+                if let Some((shared, path)) = self.resolve(true, &field.path) {
+                    self.touch(shared, &path, kind);
+                }
+            }
+            Expr::MethodCall(call) => {
+                let receiver = match call.method.as_str() {
+                    "to_uppercase" | "to_lowercase" | "trim" => Some(Kind::Text),
+                    _ => None,
+                };
+                self.expr(&call.receiver, receiver);
+                for arg in &call.args {
+                    self.expr(arg, None);
+                }
+            }
+            Expr::BinaryOp(binary) => {
+                let (left, right) = match binary.op {
+                    BinaryOp::And | BinaryOp::Or => (Some(Kind::Bool), Some(Kind::Bool)),
+                    BinaryOp::Eq | BinaryOp::Ne => {
+                        (literal_kind(&binary.right), literal_kind(&binary.left))
+                    }
+                    _ => (
+                        literal_kind(&binary.right)
+                            .filter(|kind| *kind == Kind::Float)
+                            .or(Some(Kind::Integer)),
+                        literal_kind(&binary.left)
+                            .filter(|kind| *kind == Kind::Float)
+                            .or(Some(Kind::Integer)),
+                    ),
+                };
+                self.expr(&binary.left, left);
+                self.expr(&binary.right, right);
+
+                // Compared text takes the literals, so that either branch renders
+                if matches!(binary.op, BinaryOp::Eq | BinaryOp::Ne) {
+                    self.example(&binary.left, &binary.right);
+                    self.example(&binary.right, &binary.left);
+                }
+            }
+            Expr::UnaryOp(unary) => {
+                let kind = match unary.op {
+                    UnaryOp::Not => Kind::Bool,
+                    UnaryOp::Neg => Kind::Integer,
+                };
+                self.expr(&unary.operand, Some(kind));
+            }
+            Expr::Conditional(conditional) => {
+                self.expr(&conditional.condition, Some(Kind::Bool));
+                self.expr(&conditional.then_branch, kind);
+                self.expr(&conditional.else_branch, kind);
+            }
+            Expr::Filter(filter) => {
+                let input = match filter.name.as_str() {
+                    "date" | "time" => Some(Kind::DateTime),
+                    "duration" => Some(Kind::Duration),
+                    "decimal" => Some(Kind::Float),
+                    _ => None,
+                };
+                self.expr(&filter.input, input);
+                for arg in &filter.args {
+                    self.expr(arg, None);
+                }
+            }
+            Expr::Literal(_) => {}
+        }
+    }
+
+    /// Record `field` as compared with `literal`, if it is a field and a string literal
+    fn example(&mut self, field: &Expr, literal: &Expr) {
+        let Expr::Literal(LiteralExpr::String(text)) = literal else {
+            return;
+        };
+        let path = match field {
+            Expr::FieldAccess(field) => self.resolve(false, &field.path),
+            Expr::SharedFieldAccess(field) => self.resolve(true, &field.path),
+            _ => None,
+        };
+        if let Some((shared, path)) = path {
+            let path: Vec<&str> = path.iter().map(String::as_str).collect();
+            let root = if shared {
+                &mut self.shared
+            } else {
+                &mut self.model
+            };
+            root.touch_example(&path, text);
+        }
+    }
+
+    /// Path of a field in the model or shared state; loop variables read list items
+    fn resolve(&self, shared: bool, path: &[String]) -> Option<FieldPath> {
+        let (first, rest) = path.split_first()?;
+        if !shared
+            && let Some((_, items)) = self.locals.iter().rev().find(|(name, _)| name == first)
+        {
+            let (shared, mut items) = items.clone()?;
+            items.extend(rest.iter().cloned());
+            return Some((shared, items));
+        }
+        Some((shared, path.to_vec()))
+    }
+
+    /// Record a list source and return the path of its items
+    fn items(&mut self, source: &Expr) -> Option<FieldPath> {
+        let (shared, mut path) = match source {
+            Expr::FieldAccess(field) => self.resolve(false, &field.path)?,
+            Expr::SharedFieldAccess(field) => self.resolve(true, &field.path)?,
+            _ => return None,
+        };
+        path.push(ITEMS.to_string());
+        self.touch(shared, &path, None);
+        Some((shared, path))
+    }
+
+    fn touch(&mut self, shared: bool, path: &[String], kind: Option<Kind>) {
+        let path: Vec<&str> = path.iter().map(String::as_str).collect();
+        let root = if shared {
+            &mut self.shared
+        } else {
+            &mut self.model
+        };
+        root.touch(&path, kind);
+    }
+}
+
+/// The expression of a `<for in>` or bound `options` attribute
+fn list_source(value: &AttributeValue) -> Option<&Expr> {
+    match value {
+        // `{items | filter_by(query, "name")}` iterates a subset of `items`
+        AttributeValue::Binding(binding) => match &binding.expr {
+            Expr::Filter(filter) if filter.name == "filter_by" => Some(&filter.input),
+            expr => Some(expr),
+        },
+        _ => None,
+    }
+}
+
+/// Kind of value an attribute of a widget expects, if it implies one
+fn attribute_kind(kind: &WidgetKind, attribute: &str) -> Option<Kind> {
+    match (kind, attribute) {
+        (
+            _,
+            "condition" | "checked" | "toggled" | "is_toggled" | "disabled" | "enabled" | "visible",
+        ) => Some(Kind::Bool),
+        (WidgetKind::Slider | WidgetKind::ProgressBar, "value" | "min" | "max" | "step") => {
+            Some(Kind::Float)
+        }
+        (WidgetKind::For, "page" | "page_size") => Some(Kind::Integer),
+        _ => None,
+    }
+}
+
+fn literal_kind(expr: &Expr) -> Option<Kind> {
+    match expr {
+        Expr::Literal(LiteralExpr::String(_)) => Some(Kind::Text),
+        Expr::Literal(LiteralExpr::Integer(_)) => Some(Kind::Integer),
+        Expr::Literal(LiteralExpr::Float(_)) => Some(Kind::Float),
+        Expr::Literal(LiteralExpr::Bool(_)) => Some(Kind::Bool),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_values_follow_types_then_names() {
+        assert_eq!(mock_value("tags", Some("Vec<String>")), {
+            BindingValue::List(vec![
+                BindingValue::String("Tag 1".to_string()),
+                BindingValue::String("Tag 2".to_string()),
+                BindingValue::String("Tag 3".to_string()),
+            ])
+        });
+        assert_eq!(
+            mock_value("label", Some("Option<f64>")),
+            BindingValue::Float(1.5)
+        );
+        assert_eq!(
+            mock_value("email", None),
+            BindingValue::String("alex@example.com".to_string())
+        );
+        assert_eq!(mock_value("price", None), BindingValue::Float(19.99));
+        assert_eq!(mock_value("is_loading", None), BindingValue::Bool(false));
+        assert_eq!(mock_value("item_count", None), BindingValue::Integer(3));
+        assert_eq!(
+            mock_value("status", None),
+            BindingValue::String("Active".to_string())
+        );
+    }
+
+    #[test]
+    fn test_fields_nest_and_can_be_overridden() {
+        let model = MockModel::from_fields(["user.name", "user.email", "title"])
+            .with("title", BindingValue::String("Inbox".to_string()));
+
+        assert_eq!(
+            model.get_field(&["user", "name"]),
+            Some(BindingValue::String("Alex Morgan".to_string()))
+        );
+        assert_eq!(
+            model.get_field(&["title"]),
+            Some(BindingValue::String("Inbox".to_string()))
+        );
+        assert_eq!(model.get_field(&["missing"]), None);
+    }
+
+    #[test]
+    fn test_documents_shape_their_bindings() {
+        let document = crate::parse(
+            r#"<dampen version="1.1"><column>
+                <text value="{total * 2}"/>
+                <for each="task" in="{tasks}">
+                    <text value="{task.title}" size="{if task.state == 'Idle' then 14 else 16}"/>
+                    <text value="{task.state == 'Editing'}"/>
+                </for>
+            </column></dampen>"#,
+        )
+        .unwrap_or_else(|_| unreachable!());
+        let state = app_state(document);
+
+        assert_eq!(state.evaluate("total * 2"), Ok(BindingValue::Integer(6)));
+        assert_eq!(state.evaluate("tasks.len()"), Ok(BindingValue::Integer(3)));
+        // Compared fields take the literals in turn, so both branches render
+        let tree = state.render().unwrap_or_else(|_| unreachable!());
+        let sizes: Vec<String> = tree
+            .children
+            .iter()
+            .flat_map(|node| &node.children)
+            .filter_map(|node| node.attributes.get("size"))
+            .map(BindingValue::to_display_string)
+            .collect();
+        assert_eq!(sizes, ["14", "16", "14"]);
+    }
+}
@@ -0,0 +1,98 @@
+//! Pagination and infinite scrolling of collections
+//!
+//! A `<for>` loop with a `page_size` renders one page of its collection.
+//! `page` selects the page, starting at 0, and defaults to the first one:
+//!
+//! ```xml
+//! <for each="item" in="{items}" page_size="20" page="{current_page}">
+//!     <text value="{item.name}" />
+//! </for>
+//! ```
+//!
+//! `index` stays the index of the item in the whole collection.
+//!
+//! For infinite lists, a `<scrollable>` emits `on_reach_end` when it is scrolled
+//! to less than `reach_end_distance` pixels (default
+//! [`DEFAULT_REACH_END_DISTANCE`]) from its bottom. It is emitted again when
+//! the content grew and the end is still in reach, and after scrolling away
+//! from the end and back:
+//!
+//! ```xml
+//! <scrollable height="fill" on_reach_end="load_more">
+//!     <for each="item" in="{items}">
+//!         <text value="{item.name}" />
+//!     </for>
+//! </scrollable>
+//! ```
+
+use std::ops::Range;
+
+/// Distance to the bottom, in pixels, below which `on_reach_end` is emitted
+pub const DEFAULT_REACH_END_DISTANCE: f32 = 100.0;
+
+/// Range of the items of `page` in a collection of `len` items
+///
+/// A `page_size` of 0 disables pagination. Pages past the end are empty.
+///
+/// # Example
+///
+/// ```rust
+/// use dampen_core::pagination::page_range;
+///
+/// assert_eq!(page_range(45, 1, 20), 20..40);
+/// assert_eq!(page_range(45, 2, 20), 40..45);
+/// assert_eq!(page_range(45, 3, 20), 45..45);
+/// ```
+pub fn page_range(len: usize, page: usize, page_size: usize) -> Range<usize> {
+    if page_size == 0 {
+        return 0..len;
+    }
+    let start = page.saturating_mul(page_size).min(len);
+    let end = start.saturating_add(page_size).min(len);
+    start..end
+}
+
+/// Number of pages of a collection of `len` items; 1 if `page_size` is 0
+pub fn page_count(len: usize, page_size: usize) -> usize {
+    if page_size == 0 {
+        1
+    } else {
+        len.div_ceil(page_size)
+    }
+}
+
+/// Page or page size written in an attribute; 0 unless a non-negative integer
+pub fn parse_page(value: &str) -> usize {
+    value.trim().parse().unwrap_or(0)
+}
+
+/// Whether a scrollable is scrolled to less than `distance` pixels from its end
+///
+/// `offset` is the vertical scroll offset, `viewport` the visible height and
+/// `content` the height of the content. Content fitting in the viewport is
+/// always at its end.
+pub fn reached_end(offset: f32, viewport: f32, content: f32, distance: f32) -> bool {
+    content - (offset + viewport) <= distance.max(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_range() {
+        assert_eq!(page_range(10, 0, 0), 0..10);
+        assert_eq!(page_range(10, 0, 4), 0..4);
+        assert_eq!(page_range(10, 2, 4), 8..10);
+        assert_eq!(page_range(10, usize::MAX, 4), 10..10);
+        assert_eq!(page_count(10, 4), 3);
+        assert_eq!(page_count(0, 4), 0);
+    }
+
+    #[test]
+    fn test_reached_end() {
+        assert!(!reached_end(0.0, 100.0, 1000.0, 100.0));
+        assert!(reached_end(800.0, 100.0, 1000.0, 100.0));
+        assert!(reached_end(0.0, 500.0, 200.0, 0.0));
+    }
+}
@@ -0,0 +1,218 @@
+//! Command palette model
+//!
+//! The command palette (Ctrl+K) lists handlers by label, fuzzy-searches them and
+//! runs the chosen one. Handlers are listed with `#[ui_handler(palette = "Save File")]`;
+//! an app without such labels lists every registered handler taking no value.
+//!
+//! This module holds the part independent of rendering: the [`PaletteCommand`]s,
+//! the [`fuzzy_score`] ranking them and the [`CommandPalette`] state. The palette
+//! is rendered by `dampen_iced::palette`.
+
+/// A handler listed in the command palette
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaletteCommand {
+    /// Handler run when the command is chosen
+    pub handler: String,
+    /// Text shown and searched in the palette
+    pub label: String,
+}
+
+impl PaletteCommand {
+    /// Create a command running `handler`, shown as `label`
+    pub fn new(handler: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            handler: handler.into(),
+            label: label.into(),
+        }
+    }
+
+    /// Create a command labelled after its handler: `save_file` is shown as "Save file"
+    pub fn from_handler(handler: &str) -> Self {
+        let words = handler.split('_').filter(|word| !word.is_empty());
+        let label = words.collect::<Vec<_>>().join(" ");
+        let mut chars = label.chars();
+        let label = match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => handler.to_string(),
+        };
+        Self::new(handler, label)
+    }
+}
+
+/// Score of `label` for the search `query`, higher being a better match
+///
+/// Every character of the query (ignoring case and spaces) must appear in the
+/// label in order, otherwise `None` is returned. Consecutive characters and
+/// characters starting a word score more, so "sf" ranks "Save File" above
+/// "Safe mode". An empty query matches everything with a score of 0.
+pub fn fuzzy_score(query: &str, label: &str) -> Option<u32> {
+    let label: Vec<char> = label.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut start = 0;
+    let mut previous: Option<usize> = None;
+
+    for c in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let index = (start..label.len()).find(|&i| label[i] == c)?;
+        score += 1;
+        if previous.is_some_and(|previous| previous + 1 == index) {
+            score += 4;
+        }
+        if index == 0 || !label[index - 1].is_alphanumeric() {
+            score += 2;
+        }
+        previous = Some(index);
+        start = index + 1;
+    }
+
+    Some(score)
+}
+
+/// The commands matching `query`, best first
+///
+/// Commands scoring the same keep their order.
+pub fn search<'a>(query: &str, commands: &'a [PaletteCommand]) -> Vec<&'a PaletteCommand> {
+    let mut matches: Vec<(u32, &PaletteCommand)> = commands
+        .iter()
+        .filter_map(|command| fuzzy_score(query, &command.label).map(|score| (score, command)))
+        .collect();
+    matches.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    matches.into_iter().map(|(_, command)| command).collect()
+}
+
+/// State of the command palette: whether it is open, the query and the selected match
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommandPalette {
+    open: bool,
+    query: String,
+    selected: usize,
+}
+
+impl CommandPalette {
+    /// Whether the palette is shown
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// The search query
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Index of the selected match
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Show the palette with an empty query
+    pub fn open(&mut self) {
+        *self = Self {
+            open: true,
+            ..Self::default()
+        };
+    }
+
+    /// Hide the palette
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    /// Show the palette if hidden, hide it otherwise
+    pub fn toggle(&mut self) {
+        if self.open {
+            self.close();
+        } else {
+            self.open();
+        }
+    }
+
+    /// Change the query, selecting the best match
+    pub fn set_query(&mut self, query: String) {
+        self.query = query;
+        self.selected = 0;
+    }
+
+    /// Select the next of `count` matches, wrapping to the first
+    pub fn select_next(&mut self, count: usize) {
+        if count > 0 {
+            self.selected = (self.selected + 1) % count;
+        }
+    }
+
+    /// Select the previous of `count` matches, wrapping to the last
+    pub fn select_previous(&mut self, count: usize) {
+        if count > 0 {
+            self.selected = (self.selected + count - 1) % count;
+        }
+    }
+
+    /// Close the palette and return the handler of the selected match, if any
+    pub fn submit(&mut self, commands: &[PaletteCommand]) -> Option<String> {
+        let selected = self.selected;
+        self.choose(selected, commands)
+    }
+
+    /// Close the palette and return the handler of the match at `index`, if any
+    pub fn choose(&mut self, index: usize, commands: &[PaletteCommand]) -> Option<String> {
+        if !self.open {
+            return None;
+        }
+        self.close();
+        search(&self.query, commands)
+            .get(index)
+            .map(|command| command.handler.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commands() -> Vec<PaletteCommand> {
+        vec![
+            PaletteCommand::new("safe_mode", "Safe mode"),
+            PaletteCommand::new("save_file", "Save File"),
+            PaletteCommand::from_handler("open_recent"),
+        ]
+    }
+
+    #[test]
+    fn test_fuzzy_search_ranks_word_starts() {
+        let commands = commands();
+        assert_eq!(commands[2].label, "Open recent");
+
+        let handlers = |query| {
+            search(query, &commands)
+                .into_iter()
+                .map(|command| command.handler.as_str())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(handlers("sf"), ["save_file", "safe_mode"]);
+        assert_eq!(handlers("SAVE"), ["save_file"]);
+        assert_eq!(handlers("recent"), ["open_recent"]);
+        assert!(handlers("xyz").is_empty());
+        assert_eq!(handlers("").len(), 3);
+    }
+
+    #[test]
+    fn test_palette_selection_and_submit() {
+        let commands = commands();
+        let mut palette = CommandPalette::default();
+        assert_eq!(palette.submit(&commands), None);
+
+        palette.toggle();
+        assert!(palette.is_open());
+        palette.select_previous(3);
+        assert_eq!(palette.selected(), 2);
+        palette.select_next(3);
+        assert_eq!(palette.selected(), 0);
+
+        palette.set_query("sf".to_string());
+        palette.select_next(2);
+        assert_eq!(palette.submit(&commands), Some("safe_mode".to_string()));
+        assert!(!palette.is_open());
+
+        palette.open();
+        assert_eq!(palette.query(), "");
+        assert_eq!(palette.choose(5, &commands), None);
+    }
+}
@@ -3,9 +3,12 @@
 //! This module enforces the standard attribute contract to ensure parity between
 //! Interpreted and Codegen modes.
 
-use crate::ir::Span;
 use crate::ir::WidgetKind;
+use crate::ir::WidgetNode;
+use crate::ir::theme::StyleClass;
+use crate::ir::{CLASS_TOGGLE_PREFIX, Span};
 use crate::parser::error::{ParseError, ParseErrorKind};
+use crate::schema::get_widget_schema;
 use std::collections::HashMap;
 
 /// Deprecated attributes that should be warned about or migrated.
@@ -15,6 +18,7 @@ const DEPRECATED_ATTRIBUTES: &[(&str, &str, &str)] = &[
     ("active", "toggled", "toggler"),
     ("is_toggled", "toggled", "toggler"),
     ("secure", "password", "text_input"),
+    ("delay", "delay_ms", "tooltip"),
 ];
 
 /// Validate and normalize attributes for a widget node.
@@ -79,10 +83,19 @@ pub fn normalize_attributes(
     attributes: &mut HashMap<String, crate::ir::AttributeValue>,
 ) -> Vec<(String, String)> {
     let mut warnings = Vec::new();
-    let widget_name = widget_kind.to_string();
+
+    // Most nodes have none of the deprecated names: compare names before hashing
+    let has_deprecated = attributes
+        .keys()
+        .any(|name| DEPRECATED_ATTRIBUTES.iter().any(|(old, _, _)| name == old));
+    if !has_deprecated {
+        return warnings;
+    }
 
     for (old_name, new_name, applicable_widgets) in DEPRECATED_ATTRIBUTES {
         if attributes.contains_key(*old_name) {
+            let widget_name = widget_kind.to_string();
+
             // Check if this applies to this widget
             if applicable_widgets
                 .split(',')
@@ -100,6 +113,133 @@ pub fn normalize_attributes(
     warnings
 }
 
+/// Maximum edit distance between an unknown name and a suggested one
+const SUGGESTION_DISTANCE: usize = 3;
+
+/// Check a widget tree for unknown attributes and style classes (strict mode).
+///
+/// Every attribute (including breakpoint-prefixed ones) must be listed by the
+/// widget's schema, and every class must be defined in `style_classes`. Custom
+/// widgets accept any attribute and are not checked.
+///
+/// # Returns
+///
+/// One error per unknown name, in document order, suggesting the closest valid
+/// name when one is within a small edit distance.
+pub fn validate_known_names(
+    node: &WidgetNode,
+    style_classes: &HashMap<String, StyleClass>,
+) -> Vec<ParseError> {
+    let mut errors = Vec::new();
+    collect_unknown_names(node, style_classes, &mut errors);
+    errors
+}
+
+fn collect_unknown_names(
+    node: &WidgetNode,
+    style_classes: &HashMap<String, StyleClass>,
+    errors: &mut Vec<ParseError>,
+) {
+    if !matches!(node.kind, WidgetKind::Custom(_)) {
+        let valid = get_widget_schema(&node.kind).all_valid_names();
+
+        // Breakpoint attributes (e.g. "mobile-spacing") are checked without their prefix
+        let breakpoint_names = node
+            .attribute_spans
+            .keys()
+            .filter(|_| !node.breakpoint_attributes.is_empty())
+            .filter_map(|name| {
+                let (prefix, bare) = name.split_once('-')?;
+                node.breakpoint_attributes
+                    .keys()
+                    .any(|breakpoint| breakpoint.name() == prefix)
+                    .then_some((name, bare))
+            });
+        // Class toggles (e.g. "class:active") are checked as `class`
+        let mut unknown: Vec<(&str, &str, Span)> = node
+            .attributes
+            .keys()
+            .map(|name| match name.strip_prefix(CLASS_TOGGLE_PREFIX) {
+                Some(_) => (name, "class"),
+                None => (name, name.as_str()),
+            })
+            .chain(breakpoint_names)
+            .filter(|(_, bare)| !valid.contains(bare))
+            .map(|(name, bare)| {
+                let span = node
+                    .attribute_spans
+                    .get(name)
+                    .map_or(node.span, |span| span.name);
+                (name.as_str(), bare, span)
+            })
+            .collect();
+        unknown.sort_by_key(|(_, _, span)| span.start);
+
+        for (name, bare, span) in unknown {
+            errors.push(ParseError {
+                kind: ParseErrorKind::UnknownAttribute,
+                message: format!("Unknown attribute '{}' for {} widgets", name, node.kind),
+                span,
+                suggestion: closest_name(bare, valid.iter().copied())
+                    .map(|close| format!("Did you mean '{}'?", close)),
+            });
+        }
+    }
+
+    let mut toggled: Vec<(&str, &str)> = node
+        .attributes
+        .keys()
+        .filter_map(|name| {
+            name.strip_prefix(CLASS_TOGGLE_PREFIX)
+                .map(|class| (class, name.as_str()))
+        })
+        .collect();
+    toggled.sort_by_key(|(_, attribute)| node.attribute_span(attribute).start);
+    let classes = node.classes.iter().map(|class| (class.as_str(), "class"));
+    for (class, attribute) in classes.chain(toggled) {
+        if !style_classes.contains_key(class) {
+            errors.push(ParseError {
+                kind: ParseErrorKind::InvalidValue,
+                message: format!("Unknown style class '{}'", class),
+                span: node.attribute_span(attribute),
+                suggestion: closest_name(class, style_classes.keys().map(String::as_str))
+                    .map(|close| format!("Did you mean '{}'?", close)),
+            });
+        }
+    }
+
+    for child in &node.children {
+        collect_unknown_names(child, style_classes, errors);
+    }
+}
+
+/// The candidate closest to `name`, if within [`SUGGESTION_DISTANCE`] edits
+pub(crate) fn closest_name<'a>(
+    name: &str,
+    candidates: impl Iterator<Item = &'a str>,
+) -> Option<&'a str> {
+    candidates
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= SUGGESTION_DISTANCE)
+        .min()
+        .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance between two names
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
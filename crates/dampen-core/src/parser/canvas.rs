@@ -1,6 +1,6 @@
 //! Canvas and shape parsing logic.
 
-use crate::ir::{AttributeValue, Span, WidgetKind, WidgetNode};
+use crate::ir::{AttributeValue, PathCommand, Span, WidgetKind, WidgetNode};
 use crate::parser::error::{ParseError, ParseErrorKind};
 use std::collections::HashMap;
 
@@ -13,6 +13,9 @@ pub fn is_canvas_shape(kind: &WidgetKind) -> bool {
             | WidgetKind::CanvasLine
             | WidgetKind::CanvasText
             | WidgetKind::CanvasGroup
+            | WidgetKind::CanvasPath
+            | WidgetKind::CanvasArc
+            | WidgetKind::CanvasBezier
             | WidgetKind::For // Control flow allowed
             | WidgetKind::If // Control flow allowed
     )
@@ -27,7 +30,7 @@ pub fn validate_canvas_children(children: &[WidgetNode], _span: Span) -> Result<
             return Err(ParseError {
                 kind: ParseErrorKind::InvalidChild,
                 message: format!(
-                    "Invalid child for Canvas: {:?}. Only shapes (rect, circle, line, text, group) are allowed.",
+                    "Invalid child for Canvas: {:?}. Only shapes (rect, circle, line, text, group, path, arc, bezier) are allowed.",
                     child.kind
                 ),
                 span: child.span,
@@ -91,6 +94,199 @@ fn parse_args(args: &str) -> Result<Vec<f32>, String> {
         .collect()
 }
 
+/// Parse SVG-like path data into absolute drawing commands.
+///
+/// Supported commands, in absolute (uppercase) and relative (lowercase) form:
+/// - `M x y` - move to
+/// - `L x y`, `H x`, `V y` - line to
+/// - `Q cx cy x y` - quadratic curve to
+/// - `C cx1 cy1 cx2 cy2 x y` - cubic curve to
+/// - `Z` - close path
+///
+/// Numbers may be separated by whitespace or commas, and a command letter may be
+/// followed by several argument sets (`L 10 10 20 20`), as in SVG.
+///
+/// # Example
+///
+/// ```rust
+/// use dampen_core::ir::PathCommand;
+/// use dampen_core::parser::canvas::parse_path_data;
+///
+/// let commands = parse_path_data("M10 10 l40 0 V50 Z").unwrap();
+/// assert_eq!(
+///     commands,
+///     vec![
+///         PathCommand::MoveTo(10.0, 10.0),
+///         PathCommand::LineTo(50.0, 10.0),
+///         PathCommand::LineTo(50.0, 50.0),
+///         PathCommand::Close,
+///     ]
+/// );
+/// ```
+pub fn parse_path_data(data: &str) -> Result<Vec<PathCommand>, String> {
+    let tokens = tokenize_path_data(data)?;
+    let mut commands = Vec::new();
+    let mut current = (0.0_f32, 0.0_f32);
+    let mut subpath_start = current;
+    let mut i = 0;
+
+    while i < tokens.len() {
+        let PathToken::Command(command) = tokens[i] else {
+            return Err(format!(
+                "Expected a command letter at the start of the path, found {}",
+                tokens[i]
+            ));
+        };
+        i += 1;
+
+        let arity = match command.to_ascii_uppercase() {
+            'M' | 'L' => 2,
+            'H' | 'V' => 1,
+            'Q' => 4,
+            'C' => 6,
+            'Z' => 0,
+            other => {
+                return Err(format!(
+                    "Unsupported path command '{}'. Supported: M, L, H, V, Q, C, Z",
+                    other
+                ));
+            }
+        };
+        let relative = command.is_ascii_lowercase();
+
+        if arity == 0 {
+            commands.push(PathCommand::Close);
+            current = subpath_start;
+            continue;
+        }
+
+        // A command applies to every following group of arguments
+        let mut first = true;
+        loop {
+            let args: Vec<f32> = tokens[i..]
+                .iter()
+                .take(arity)
+                .map_while(|token| match token {
+                    PathToken::Number(value) => Some(*value),
+                    PathToken::Command(_) => None,
+                })
+                .collect();
+            if args.is_empty() && !first {
+                break;
+            }
+            if args.len() < arity {
+                return Err(format!(
+                    "Path command '{}' requires {} numbers",
+                    command, arity
+                ));
+            }
+            i += arity;
+
+            let (ox, oy) = if relative { current } else { (0.0, 0.0) };
+            let point = |x: f32, y: f32| (ox + x, oy + y);
+
+            let next = match command.to_ascii_uppercase() {
+                'M' if first => {
+                    let (x, y) = point(args[0], args[1]);
+                    subpath_start = (x, y);
+                    PathCommand::MoveTo(x, y)
+                }
+                // Extra coordinate pairs after a move are implicit line-tos
+                'M' | 'L' => {
+                    let (x, y) = point(args[0], args[1]);
+                    PathCommand::LineTo(x, y)
+                }
+                'H' => PathCommand::LineTo(ox + args[0], current.1),
+                'V' => PathCommand::LineTo(current.0, oy + args[0]),
+                'Q' => {
+                    let (cx, cy) = point(args[0], args[1]);
+                    let (x, y) = point(args[2], args[3]);
+                    PathCommand::QuadraticTo(cx, cy, x, y)
+                }
+                _ => {
+                    let (cx1, cy1) = point(args[0], args[1]);
+                    let (cx2, cy2) = point(args[2], args[3]);
+                    let (x, y) = point(args[4], args[5]);
+                    PathCommand::CubicTo(cx1, cy1, cx2, cy2, x, y)
+                }
+            };
+
+            current = match next {
+                PathCommand::MoveTo(x, y)
+                | PathCommand::LineTo(x, y)
+                | PathCommand::QuadraticTo(_, _, x, y)
+                | PathCommand::CubicTo(_, _, _, _, x, y) => (x, y),
+                PathCommand::Close => subpath_start,
+            };
+            commands.push(next);
+            first = false;
+        }
+    }
+
+    if !matches!(commands.first(), None | Some(PathCommand::MoveTo(..))) {
+        return Err("Path data must start with a move command (M)".to_string());
+    }
+
+    Ok(commands)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PathToken {
+    Command(char),
+    Number(f32),
+}
+
+impl std::fmt::Display for PathToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PathToken::Command(c) => write!(f, "'{}'", c),
+            PathToken::Number(n) => write!(f, "{}", n),
+        }
+    }
+}
+
+fn tokenize_path_data(data: &str) -> Result<Vec<PathToken>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = data.char_indices().peekable();
+
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() || c == ',' {
+            chars.next();
+        } else if c.is_ascii_alphabetic() && c != 'e' && c != 'E' {
+            tokens.push(PathToken::Command(c));
+            chars.next();
+        } else {
+            // Numbers may be packed without separators, e.g. "10-5" or "0.5.5"
+            let mut end = start;
+            let mut seen_dot = false;
+            let mut seen_exp = false;
+            while let Some(&(index, c)) = chars.peek() {
+                let is_sign = (c == '-' || c == '+')
+                    && (index == start || data[..index].ends_with(['e', 'E']));
+                let accept = c.is_ascii_digit()
+                    || is_sign
+                    || (c == '.' && !seen_dot && !seen_exp)
+                    || ((c == 'e' || c == 'E') && !seen_exp && index > start);
+                if !accept {
+                    break;
+                }
+                seen_dot |= c == '.';
+                seen_exp |= c == 'e' || c == 'E';
+                end = index + c.len_utf8();
+                chars.next();
+            }
+
+            let number = &data[start..end];
+            let value = number
+                .parse::<f32>()
+                .map_err(|_| format!("Invalid number in path data: '{}'", &data[start..]))?;
+            tokens.push(PathToken::Number(value));
+        }
+    }
+
+    Ok(tokens)
+}
+
 /// Validate shape attributes.
 pub fn validate_shape_attributes(
     kind: &WidgetKind,
@@ -136,6 +332,59 @@ pub fn validate_shape_attributes(
         });
     }
 
+    if let Some(AttributeValue::Static(d)) = attributes.get("d")
+        && let Err(e) = parse_path_data(d)
+    {
+        return Err(ParseError {
+            kind: ParseErrorKind::InvalidValue,
+            message: format!("Invalid path data: {}", e),
+            span,
+            suggestion: Some("Use SVG path syntax, e.g. d=\"M10 10 L50 80 Z\"".to_string()),
+        });
+    }
+
+    if *kind == WidgetKind::CanvasBezier
+        && attributes.contains_key("cx2") != attributes.contains_key("cy2")
+    {
+        return Err(ParseError {
+            kind: ParseErrorKind::InvalidValue,
+            message: "Bezier requires both cx2 and cy2 for a cubic curve".to_string(),
+            span,
+            suggestion: Some("Set both cx2 and cy2, or neither for a quadratic curve".to_string()),
+        });
+    }
+
+    // Numeric attributes of the new shapes must be numbers when static
+    for name in [
+        "cx",
+        "cy",
+        "start_angle",
+        "end_angle",
+        "x1",
+        "y1",
+        "x2",
+        "y2",
+        "cx1",
+        "cy1",
+        "cx2",
+        "cy2",
+        "stroke_width",
+    ] {
+        if matches!(
+            kind,
+            WidgetKind::CanvasPath | WidgetKind::CanvasArc | WidgetKind::CanvasBezier
+        ) && let Some(AttributeValue::Static(value)) = attributes.get(name)
+            && value.trim().parse::<f32>().is_err()
+        {
+            return Err(ParseError {
+                kind: ParseErrorKind::InvalidValue,
+                message: format!("{} for {:?} must be a number: {}", name, kind, value),
+                span,
+                suggestion: Some(format!("Use a numeric value, e.g. {}=\"0\"", name)),
+            });
+        }
+    }
+
     if let Some(AttributeValue::Static(r_str)) = attributes.get("radius")
         && let Ok(r) = r_str.parse::<f32>()
         && r < 0.0
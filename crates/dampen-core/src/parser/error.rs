@@ -32,6 +32,8 @@ pub enum ParseErrorKind {
     InvalidTimeFormat,
     /// Invalid date range (min > max)
     InvalidDateRange,
+    /// Document exceeds a resource limit (size, depth, node count, attribute length)
+    LimitExceeded,
 }
 
 impl std::fmt::Display for ParseError {
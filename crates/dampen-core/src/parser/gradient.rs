@@ -4,25 +4,47 @@
 
 use crate::ir::style::{Color, ColorStop, Gradient, RadialShape};
 
+/// Theme palette colors a color stop can reference as `@name`
+pub const THEME_COLORS: &[&str] = &[
+    "primary",
+    "secondary",
+    "success",
+    "warning",
+    "danger",
+    "background",
+    "text",
+];
+
 /// Parse a linear or radial gradient from a string
 ///
 /// # Formats
-/// - `linear-gradient(<angle>, <color-stop>, <color-stop>, ...)`
-/// - `radial-gradient(<shape>, <color-stop>, <color-stop>, ...)`
+/// - `linear-gradient([<angle> | to <side>,] <color-stop>, <color-stop>, ...)`
+/// - `radial-gradient([circle | ellipse,] <color-stop>, <color-stop>, ...)`
+///
+/// A color stop is a color or a theme palette color (`@primary`), followed by an
+/// optional offset (`50%` or `0.5`). Stops without offset are spread evenly
+/// between their neighbours, the first and last ones defaulting to 0% and 100%.
 ///
 /// # Examples
 /// ```rust
 /// use dampen_core::parser::gradient::parse_gradient;
 ///
 /// let grad = parse_gradient("linear-gradient(90deg, red, blue)").unwrap();
+/// let grad = parse_gradient("linear-gradient(to bottom, @primary, rgb(0, 0, 0) 80%)").unwrap();
 /// ```
 pub fn parse_gradient(s: &str) -> Result<Gradient, String> {
     let s = s.trim();
 
-    if s.starts_with("linear-gradient(") && s.ends_with(')') {
-        parse_linear_gradient(s)
-    } else if s.starts_with("radial-gradient(") && s.ends_with(')') {
-        parse_radial_gradient(s)
+    if let Some(inner) = s
+        .strip_prefix("linear-gradient(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        parse_linear_gradient(inner)
+    } else if let Some(inner) = s
+        .strip_prefix("radial-gradient(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        parse_radial_gradient(inner)
     } else {
         Err(format!(
             "Invalid gradient format: '{}'. Expected linear-gradient(...) or radial-gradient(...)",
@@ -31,34 +53,95 @@ pub fn parse_gradient(s: &str) -> Result<Gradient, String> {
     }
 }
 
-/// Parse linear gradient: `linear-gradient(<angle>, <color-stop>, ...)`
-fn parse_linear_gradient(s: &str) -> Result<Gradient, String> {
-    let inner = &s[16..s.len() - 1]; // Remove "linear-gradient(" and ")"
-    let parts: Vec<&str> = inner.split(',').collect();
+/// Parse the arguments of a linear gradient: `[<angle>,] <color-stop>, ...`
+fn parse_linear_gradient(inner: &str) -> Result<Gradient, String> {
+    let parts = split_arguments(inner);
+
+    // The direction is optional and defaults to top to bottom, as in CSS
+    let (angle, stop_parts) = match parts.split_first() {
+        Some((first, rest)) if is_direction(first) => (parse_direction(first)?, rest),
+        _ => (180.0, parts.as_slice()),
+    };
 
-    if parts.len() < 2 {
-        return Err("Linear gradient requires at least angle and one color stop".to_string());
+    if stop_parts.is_empty() {
+        return Err("Linear gradient requires at least one color stop".to_string());
     }
 
-    let angle = parse_angle(parts[0])?;
-    let stops = parse_color_stops(&parts[1..])?;
+    let stops = parse_color_stops(stop_parts)?;
+    Ok(Gradient::Linear {
+        angle: angle.rem_euclid(360.0),
+        stops,
+    })
+}
+
+/// Parse the arguments of a radial gradient: `[<shape>,] <color-stop>, ...`
+fn parse_radial_gradient(inner: &str) -> Result<Gradient, String> {
+    let parts = split_arguments(inner);
 
-    Ok(Gradient::Linear { angle, stops })
+    // The shape is optional and defaults to an ellipse, as in CSS
+    let (shape, stop_parts) = match parts.split_first() {
+        Some((first, rest)) if parse_shape(first).is_ok() => (parse_shape(first)?, rest),
+        _ => (RadialShape::Ellipse, parts.as_slice()),
+    };
+
+    if stop_parts.is_empty() {
+        return Err("Radial gradient requires at least one color stop".to_string());
+    }
+
+    let stops = parse_color_stops(stop_parts)?;
+    Ok(Gradient::Radial { shape, stops })
 }
 
-/// Parse radial gradient: `radial-gradient(<shape>, <color-stop>, ...)`
-fn parse_radial_gradient(s: &str) -> Result<Gradient, String> {
-    let inner = &s[16..s.len() - 1]; // Remove "radial-gradient(" and ")"
-    let parts: Vec<&str> = inner.split(',').collect();
+/// Split gradient arguments on the commas outside of parentheses, so that
+/// `rgb(255, 0, 0) 50%` stays a single argument
+fn split_arguments(inner: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
 
-    if parts.len() < 2 {
-        return Err("Radial gradient requires at least shape and one color stop".to_string());
+    for (index, c) in inner.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(inner[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
     }
+    parts.push(inner[start..].trim());
+
+    parts
+}
 
-    let shape = parse_shape(parts[0])?;
-    let stops = parse_color_stops(&parts[1..])?;
+/// Whether a linear gradient argument is a direction rather than a color stop
+fn is_direction(s: &str) -> bool {
+    s.starts_with("to ") || parse_angle(s).is_ok()
+}
 
-    Ok(Gradient::Radial { shape, stops })
+/// Parse a linear gradient direction: an angle, or `to <side>` (`to right`, `to top left`)
+fn parse_direction(s: &str) -> Result<f32, String> {
+    let Some(sides) = s.strip_prefix("to ") else {
+        return parse_angle(s);
+    };
+
+    let mut sides: Vec<&str> = sides.split_whitespace().collect();
+    sides.sort_unstable();
+    match sides.as_slice() {
+        ["top"] => Ok(0.0),
+        ["right", "top"] => Ok(45.0),
+        ["right"] => Ok(90.0),
+        ["bottom", "right"] => Ok(135.0),
+        ["bottom"] => Ok(180.0),
+        ["bottom", "left"] => Ok(225.0),
+        ["left"] => Ok(270.0),
+        ["left", "top"] => Ok(315.0),
+        _ => Err(format!(
+            "Invalid gradient direction: '{}'. Expected to top, to right, to bottom or to left",
+            s
+        )),
+    }
 }
 
 /// Parse angle: "90deg", "1.5rad", "0.25turn"
@@ -99,50 +182,148 @@ fn parse_shape(s: &str) -> Result<RadialShape, String> {
     }
 }
 
-/// Parse color stops: "red", "red 0%", "rgb(255,0,0) 50%"
+/// Parse color stops: "red", "red 0%", "rgb(255,0,0) 50%", "@primary 100%"
+///
+/// Stops without offset are spread evenly between the stops around them.
 pub fn parse_color_stops(parts: &[&str]) -> Result<Vec<ColorStop>, String> {
     let mut stops = Vec::new();
+    let mut offsets = Vec::new();
 
     for part in parts {
-        let part = part.trim();
-        let stop = parse_color_stop(part)?;
+        let (stop, offset) = parse_stop(part.trim())?;
         stops.push(stop);
+        offsets.push(offset);
+    }
+
+    // The first and last stops default to the ends of the gradient
+    if let Some(first) = offsets.first_mut() {
+        first.get_or_insert(0.0);
+    }
+    if let Some(last) = offsets.last_mut() {
+        last.get_or_insert(1.0);
+    }
+
+    // Interpolate each run of missing offsets between the known ones around it
+    let mut previous = 0;
+    for index in 1..offsets.len() {
+        let Some(end) = offsets[index] else {
+            continue;
+        };
+        let start = offsets[previous].unwrap_or(0.0);
+        let steps = (index - previous) as f32;
+        for (step, missing) in (previous + 1..index).enumerate() {
+            offsets[missing] = Some(start + (end - start) * (step + 1) as f32 / steps);
+        }
+        previous = index;
+    }
+
+    for (stop, offset) in stops.iter_mut().zip(offsets) {
+        stop.offset = offset.unwrap_or(0.0);
     }
 
     Ok(stops)
 }
 
 /// Parse a single color stop
+///
+/// A stop without offset gets an offset of 0.0; see [`parse_color_stops`] for
+/// spreading stops evenly.
 pub fn parse_color_stop(s: &str) -> Result<ColorStop, String> {
-    // Split by whitespace to separate color and optional offset
-    let parts: Vec<&str> = s.split_whitespace().collect();
+    let (mut stop, offset) = parse_stop(s)?;
+    stop.offset = offset.unwrap_or(0.0);
+    Ok(stop)
+}
 
-    if parts.is_empty() {
+/// Parse a color stop and its offset, if given
+fn parse_stop(s: &str) -> Result<(ColorStop, Option<f32>), String> {
+    if s.is_empty() {
         return Err("Empty color stop".to_string());
     }
 
-    let color_str = parts[0];
-    let color = Color::parse(color_str)?;
-
-    // Optional offset
-    let offset = if parts.len() > 1 {
-        let offset_str = parts[1];
-        if let Some(num) = offset_str.strip_suffix('%') {
-            let value: f32 = num
-                .parse()
-                .map_err(|_| format!("Invalid offset: {}", offset_str))?;
-            value / 100.0
-        } else {
-            let value: f32 = offset_str
-                .parse()
-                .map_err(|_| format!("Invalid offset: {}", offset_str))?;
-            value
+    // The offset is the last word, when it is a number or a percentage
+    let (color_str, offset) = match s.rsplit_once(char::is_whitespace) {
+        Some((color, offset)) if offset.ends_with('%') || offset.parse::<f32>().is_ok() => {
+            (color.trim(), Some(parse_offset(offset)?))
         }
-    } else {
-        // No offset specified, will be determined by position
-        // For now, we'll use 0.0 and caller should normalize
-        0.0
+        _ => (s, None),
+    };
+
+    let stop = match color_str.strip_prefix('@') {
+        Some(name) if THEME_COLORS.contains(&name) => ColorStop {
+            // Used when no theme is available
+            color: Color::from_rgb8(128, 128, 128),
+            offset: 0.0,
+            theme_color: Some(name.to_string()),
+        },
+        Some(name) => {
+            return Err(format!(
+                "Unknown theme color '@{}'. Expected one of: @{}",
+                name,
+                THEME_COLORS.join(", @")
+            ));
+        }
+        None => ColorStop {
+            color: Color::parse(color_str)?,
+            offset: 0.0,
+            theme_color: None,
+        },
+    };
+
+    Ok((stop, offset))
+}
+
+/// Parse a color stop offset: "50%" or "0.5"
+fn parse_offset(s: &str) -> Result<f32, String> {
+    let value = match s.strip_suffix('%') {
+        Some(num) => num.parse::<f32>().map(|value| value / 100.0),
+        None => s.parse::<f32>(),
     };
+    value.map_err(|_| format!("Invalid offset: {}", s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn offsets(gradient: &Gradient) -> Vec<f32> {
+        let (Gradient::Linear { stops, .. } | Gradient::Radial { stops, .. }) = gradient;
+        stops.iter().map(|stop| stop.offset).collect()
+    }
+
+    #[test]
+    fn test_linear_gradient_directions_and_offsets() -> Result<(), String> {
+        let gradient = parse_gradient("linear-gradient(to right, red, rgb(0, 128, 0) 40%, blue)")?;
+        assert!(matches!(gradient, Gradient::Linear { angle, .. } if angle == 90.0));
+        assert_eq!(offsets(&gradient), [0.0, 0.4, 1.0]);
+        gradient.validate()?;
 
-    Ok(ColorStop { color, offset })
+        let gradient = parse_gradient("linear-gradient(#fff, #888, #444, #000)")?;
+        assert!(matches!(gradient, Gradient::Linear { angle, .. } if angle == 180.0));
+        assert_eq!(offsets(&gradient), [0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0]);
+
+        let gradient = parse_gradient("linear-gradient(-90deg, red, blue)")?;
+        assert!(matches!(gradient, Gradient::Linear { angle, .. } if angle == 270.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gradient_theme_color_stops() -> Result<(), String> {
+        let gradient = parse_gradient("radial-gradient(@primary, @background 50%)")?;
+        let Gradient::Radial { shape, stops } = &gradient else {
+            return Err("expected a radial gradient".to_string());
+        };
+        assert_eq!(*shape, RadialShape::Ellipse);
+        assert_eq!(stops[0].theme_color.as_deref(), Some("primary"));
+        assert_eq!(stops[1].theme_color.as_deref(), Some("background"));
+        assert_eq!(offsets(&gradient), [0.0, 0.5]);
+
+        // Radial stops are mirrored around the middle of a vertical gradient
+        let (angle, linear) = gradient.to_linear();
+        assert_eq!(angle, 180.0);
+        let linear: Vec<_> = linear.iter().map(|stop| stop.offset).collect();
+        assert_eq!(linear, [0.25, 0.5, 0.75]);
+
+        assert!(parse_gradient("linear-gradient(@accent, red)").is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,447 @@
+//! Incremental reparsing
+//!
+//! The language server parses a document again on every keystroke. Most edits
+//! change a single widget, so instead of parsing the whole file,
+//! [`reparse_with_recovery`] parses the smallest element enclosing the edit and
+//! patches it into the previous document, moving the spans of the widgets after
+//! it.
+
+use crate::ir::{AttributeValue, DampenDocument, InterpolatedPart, Span, WidgetKind, WidgetNode};
+use crate::parser::error::ParseError;
+use crate::parser::{
+    ParseLimits, SourceIndex, attribute_standard, preprocess_xml, recover_node,
+    validate_float_anchors, validate_nesting_constraints, validate_widget_versions_strict,
+};
+use roxmltree::Document;
+
+/// The bytes `start..old_end` of the old source, replaced with the bytes
+/// `start..new_end` of the new source
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SourceEdit {
+    start: usize,
+    old_end: usize,
+    new_end: usize,
+}
+
+impl SourceEdit {
+    /// The edit turning `old` into `new`, without their common prefix and suffix
+    fn between(old: &str, new: &str) -> Self {
+        let mut start = old
+            .bytes()
+            .zip(new.bytes())
+            .take_while(|(a, b)| a == b)
+            .count();
+        while !new.is_char_boundary(start) {
+            start -= 1;
+        }
+
+        let max_suffix = old.len().min(new.len()) - start;
+        let mut suffix = old
+            .bytes()
+            .rev()
+            .zip(new.bytes().rev())
+            .take(max_suffix)
+            .take_while(|(a, b)| a == b)
+            .count();
+        while !new.is_char_boundary(new.len() - suffix) {
+            suffix -= 1;
+        }
+
+        SourceEdit {
+            start,
+            old_end: old.len() - suffix,
+            new_end: new.len() - suffix,
+        }
+    }
+
+    /// Offset in the new source of an offset of the old source outside the edit
+    fn shift(&self, offset: usize) -> usize {
+        if offset >= self.old_end {
+            offset - self.old_end + self.new_end
+        } else {
+            offset
+        }
+    }
+}
+
+/// Widgets whose children are validated together with them, so that the
+/// children cannot be parsed again on their own
+const VALIDATES_CHILDREN: &[WidgetKind] = &[
+    WidgetKind::Tooltip,
+    WidgetKind::Canvas,
+    WidgetKind::DatePicker,
+    WidgetKind::TimePicker,
+    WidgetKind::ContextMenu,
+    WidgetKind::RadioGroup,
+];
+
+/// Parse an edited document again, reusing the unchanged parts of `previous`
+///
+/// `previous` is the document parsed without errors from `old_source`. Only the
+/// innermost widget enclosing the changes between `old_source` and `source` is
+/// parsed again, then patched into a copy of `previous`; the result is the same
+/// as [`parse_with_recovery`](super::parse_with_recovery) on `source`.
+///
+/// # Returns
+///
+/// The document and its errors, in source order, or `None` if the document
+/// must be parsed from scratch: the changes are outside the root widget, the
+/// edited element is no longer well-formed on its own, or context menus are
+/// involved.
+///
+/// # Examples
+///
+/// ```rust
+/// use dampen_core::parser::{parse, reparse_with_recovery};
+///
+/// let old = r#"<column><text value="Hello" /><button label="Go" /></column>"#;
+/// let previous = parse(old).unwrap();
+///
+/// let new = old.replace("Hello", "Hi");
+/// let (document, errors) = reparse_with_recovery(&previous, old, &new).unwrap();
+/// assert!(errors.is_empty());
+/// assert_eq!(document, parse(&new).unwrap());
+/// ```
+pub fn reparse_with_recovery(
+    previous: &DampenDocument,
+    old_source: &str,
+    source: &str,
+) -> Option<(DampenDocument, Vec<ParseError>)> {
+    let edit = SourceEdit::between(old_source, source);
+    if edit.start == edit.old_end && edit.start == edit.new_end {
+        return Some((previous.clone(), Vec::new()));
+    }
+
+    let path = enclosing_path(&previous.root, &edit)?;
+    let old = node_at(&previous.root, &path)?;
+    let parent_validates_children = path.len().checked_sub(1).is_some_and(|len| {
+        node_at(&previous.root, &path[..len])
+            .is_some_and(|parent| VALIDATES_CHILDREN.contains(&parent.kind))
+    });
+    if parent_validates_children || changes_parent(old) {
+        return None;
+    }
+
+    let limits = ParseLimits::default();
+    limits.check_source(source).ok()?;
+    limits.check_element_count(source).ok()?;
+
+    let base = old.span;
+    let mut errors = Vec::new();
+    let fragment = source.get(base.start..edit.shift(base.end))?;
+    let mut widget = parse_fragment(fragment, &limits, &mut errors)?;
+    if changes_parent(&widget) {
+        return None;
+    }
+    if !previous.breakpoints.is_default()
+        && let Err(error) = super::apply_custom_breakpoints(&mut widget, &previous.breakpoints)
+    {
+        errors.push(error);
+    }
+
+    // Move the spans of the fragment to its position in the source
+    let mut to_source = |span: Span| Span {
+        start: span.start + base.start,
+        end: span.end + base.start,
+        line: span.line + base.line - 1,
+        column: if span.line == 1 {
+            span.column + base.column - 1
+        } else {
+            span.column
+        },
+    };
+    remap_spans(&mut widget, &mut to_source);
+    for error in &mut errors {
+        error.span = to_source(error.span);
+    }
+
+    // Move the spans after the edit by the lines and columns it added
+    let (old_line, old_column) = super::calculate_line_col(old_source, edit.old_end);
+    let (new_line, new_column) = super::calculate_line_col(source, edit.new_end);
+    let mut document = previous.clone();
+    remap_spans(&mut document.root, &mut |span: Span| {
+        if span.start < edit.start {
+            return Span {
+                end: edit.shift(span.end),
+                ..span
+            };
+        }
+        Span {
+            start: edit.shift(span.start),
+            end: edit.shift(span.end),
+            // Spans inside the edit are replaced below
+            line: (span.line + new_line).saturating_sub(old_line),
+            column: if span.line == old_line {
+                (span.column + new_column).saturating_sub(old_column)
+            } else {
+                span.column
+            },
+        }
+    });
+    *node_at_mut(&mut document.root, &path)? = widget;
+
+    validate_document(&document, source, &mut errors);
+    errors.sort_by_key(|error| error.span.start);
+    Some((document, errors))
+}
+
+/// Child indices leading to the innermost widget strictly enclosing the edit
+///
+/// The inline content of `<text>` and `<svg>` is not made of widgets, so the
+/// search stops at them.
+fn enclosing_path(root: &WidgetNode, edit: &SourceEdit) -> Option<Vec<usize>> {
+    let encloses = |node: &WidgetNode| node.span.start < edit.start && edit.old_end < node.span.end;
+    if !encloses(root) {
+        return None;
+    }
+
+    let mut path = Vec::new();
+    let mut node = root;
+    while !matches!(node.kind, WidgetKind::Text | WidgetKind::Svg) {
+        let Some((index, child)) = node
+            .children
+            .iter()
+            .enumerate()
+            .find(|(_, child)| encloses(child))
+        else {
+            break;
+        };
+        path.push(index);
+        node = child;
+    }
+    Some(path)
+}
+
+fn node_at<'a>(root: &'a WidgetNode, path: &[usize]) -> Option<&'a WidgetNode> {
+    path.iter()
+        .try_fold(root, |node, &index| node.children.get(index))
+}
+
+fn node_at_mut<'a>(root: &'a mut WidgetNode, path: &[usize]) -> Option<&'a mut WidgetNode> {
+    path.iter()
+        .try_fold(root, |node, &index| node.children.get_mut(index))
+}
+
+/// Whether parsing `node` affects its parent: tooltip contents are validated by
+/// their parent, and context menus are attached to the widget before them
+fn changes_parent(node: &WidgetNode) -> bool {
+    node.kind == WidgetKind::TooltipContent || contains_context_menu(node)
+}
+
+fn contains_context_menu(node: &WidgetNode) -> bool {
+    node.kind == WidgetKind::ContextMenu || node.children.iter().any(contains_context_menu)
+}
+
+/// Parse a single element in recovery mode, with spans relative to `fragment`
+fn parse_fragment(
+    fragment: &str,
+    limits: &ParseLimits,
+    errors: &mut Vec<ParseError>,
+) -> Option<WidgetNode> {
+    let (processed, inserted) = preprocess_xml(fragment);
+    let _source_index = SourceIndex::install(fragment, inserted);
+
+    let doc = Document::parse(&processed).ok()?;
+    let root = doc.root_element();
+    // The edit must not have split the element in several
+    if root.range() != (0..processed.len()) {
+        return None;
+    }
+    limits
+        .check_document(&doc, |node| super::get_span(node, fragment))
+        .ok()?;
+
+    Some(recover_node(root, fragment, errors))
+}
+
+/// Run the validations of the whole widget tree
+fn validate_document(document: &DampenDocument, source: &str, errors: &mut Vec<ParseError>) {
+    let root = &document.root;
+    if is_dampen_document(source) {
+        errors.extend(validate_widget_versions_strict(root, &document.version).err());
+    }
+    errors.extend(validate_nesting_constraints(root, None).err());
+    errors.extend(validate_float_anchors(root).err());
+    if document.strict {
+        errors.extend(attribute_standard::validate_known_names(
+            root,
+            &document.style_classes,
+        ));
+    }
+}
+
+/// Whether the root element of `source` is a `<dampen>` wrapper
+fn is_dampen_document(source: &str) -> bool {
+    let mut rest = source.trim_start();
+    loop {
+        let end = if rest.starts_with("<?") {
+            "?>"
+        } else if rest.starts_with("<!--") {
+            "-->"
+        } else if rest.starts_with("<!") {
+            ">"
+        } else {
+            break;
+        };
+        rest = rest
+            .find(end)
+            .map_or("", |i| &rest[i + end.len()..])
+            .trim_start();
+    }
+    rest.strip_prefix("<dampen")
+        .is_some_and(|tag| tag.starts_with(|c: char| c.is_whitespace() || c == '>' || c == '/'))
+}
+
+/// Apply `f` to every span of a widget tree
+fn remap_spans(node: &mut WidgetNode, f: &mut impl FnMut(Span) -> Span) {
+    node.span = f(node.span);
+    for attribute in node.attribute_spans.values_mut() {
+        attribute.name = f(attribute.name);
+        attribute.value = f(attribute.value);
+    }
+    for value in node.attributes.values_mut() {
+        remap_value(value, f);
+    }
+    for attributes in node.breakpoint_attributes.values_mut() {
+        for value in attributes.values_mut() {
+            remap_value(value, f);
+        }
+    }
+    if let Some(theme_ref) = &mut node.theme_ref {
+        remap_value(theme_ref, f);
+    }
+    for event in &mut node.events {
+        event.span = f(event.span);
+        for param in event.param.iter_mut().chain(&mut event.params) {
+            param.span = f(param.span);
+        }
+    }
+    for child in &mut node.children {
+        remap_spans(child, f);
+    }
+}
+
+fn remap_value(value: &mut AttributeValue, f: &mut impl FnMut(Span) -> Span) {
+    match value {
+        AttributeValue::Static(_) => {}
+        AttributeValue::Binding(binding) => binding.span = f(binding.span),
+        AttributeValue::Interpolated(parts) => {
+            for part in parts {
+                if let InterpolatedPart::Binding(binding) = part {
+                    binding.span = f(binding.span);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_with_recovery;
+
+    const VIEW: &str = r##"<dampen version="1.1">
+    <styles>
+        <style name="card"><base padding="8" /></style>
+    </styles>
+    <column spacing="10">
+        <text value="Count: {count}" />
+        <row class="card">
+            <button label="Increment" on_click="increment" />
+            <text_input value="{name}" on_input="set_name" />
+        </row>
+        <text value="Héllo wörld" /> <checkbox label="Done: {done}" checked="{done}" />
+        <button label="Reset" on_click="set:{count},0" />
+    </column>
+</dampen>"##;
+
+    /// Reparse `old` edited into `new` and compare with a full parse of `new`
+    fn reparse(old: &str, new: &str) -> Option<Vec<ParseError>> {
+        let (previous, errors) = parse_with_recovery(old);
+        assert!(errors.is_empty(), "{:?}", errors);
+        let previous = previous?;
+
+        let (document, errors) = reparse_with_recovery(&previous, old, new)?;
+        let (expected, expected_errors) = parse_with_recovery(new);
+        assert_eq!(Some(document), expected);
+        assert_eq!(errors, expected_errors);
+        Some(errors)
+    }
+
+    #[test]
+    fn test_edit_of_attribute_value() {
+        let new = VIEW.replace("Increment", "Add one");
+        assert_eq!(reparse(VIEW, &new).map(|errors| errors.len()), Some(0));
+    }
+
+    #[test]
+    fn test_edit_adding_lines_moves_later_spans() {
+        let new = VIEW.replace(
+            r#"<button label="Increment" on_click"#,
+            "<button label=\"Increment\"\n                on_click",
+        );
+        assert_eq!(reparse(VIEW, &new).map(|errors| errors.len()), Some(0));
+    }
+
+    #[test]
+    fn test_edit_before_wide_chars_on_the_same_line() {
+        let new = VIEW.replace("Héllo", "Hé");
+        assert_eq!(reparse(VIEW, &new).map(|errors| errors.len()), Some(0));
+
+        let new = VIEW.replace("Done: {done}", "Finished: {done}");
+        assert_eq!(reparse(VIEW, &new).map(|errors| errors.len()), Some(0));
+    }
+
+    #[test]
+    fn test_edit_introducing_errors() {
+        let new = VIEW.replace("<checkbox label", "<checkbx label");
+        assert_eq!(reparse(VIEW, &new).map(|errors| errors.len()), Some(1));
+
+        let new = VIEW.replace("Count: {count}", "Count: {count +}");
+        assert_eq!(reparse(VIEW, &new).map(|errors| errors.len()), Some(1));
+    }
+
+    #[test]
+    fn test_edit_of_an_unwrapped_document() {
+        let old = r#"<column>
+    <text value="One" />
+    <text value="Two" />
+</column>"#;
+        let new = old.replace("One", "Uno");
+        assert_eq!(reparse(old, &new).map(|errors| errors.len()), Some(0));
+    }
+
+    #[test]
+    fn test_edits_parsed_from_scratch() {
+        // Outside of the root widget
+        let new = VIEW.replace(r#"padding="8""#, r#"padding="12""#);
+        assert_eq!(reparse(VIEW, &new), None);
+
+        // The element is no longer well-formed
+        let new = VIEW.replace(
+            r#"<text value="Count: {count}" />"#,
+            r#"<text value="Count: {count}""#,
+        );
+        assert_eq!(reparse(VIEW, &new), None);
+    }
+
+    #[test]
+    fn test_unchanged_source() {
+        assert_eq!(reparse(VIEW, VIEW).map(|errors| errors.len()), Some(0));
+    }
+
+    #[test]
+    fn test_source_edit_between() {
+        assert_eq!(
+            SourceEdit::between("<text value=\"a\" />", "<text value=\"abc\" />"),
+            SourceEdit {
+                start: 14,
+                old_end: 14,
+                new_end: 16,
+            }
+        );
+        // Never splits a multi-byte char
+        let edit = SourceEdit::between("é", "è");
+        assert_eq!((edit.start, edit.old_end, edit.new_end), (0, 2, 2));
+    }
+}
@@ -0,0 +1,348 @@
+//! Resource limits for parsing
+//!
+//! Documents are parsed on every keystroke by the language server and on every
+//! save by the hot-reload watcher, so a malformed or adversarial file must fail
+//! quickly instead of overflowing the stack or hanging the process. [`ParseLimits`]
+//! bounds the size of a document before its widgets are built.
+
+use crate::ir::Span;
+use crate::parser::error::{ParseError, ParseErrorKind};
+use roxmltree::{Document, Node};
+
+/// Limits checked by [`parse_with_limits`](super::parse_with_limits)
+///
+/// The defaults are far above what hand-written views need, while keeping the
+/// recursive passes of the parser well within the stack of a thread.
+///
+/// # Examples
+///
+/// ```rust
+/// use dampen_core::parser::{ParseLimits, parse_with_limits};
+///
+/// let limits = ParseLimits {
+///     max_depth: 2,
+///     ..ParseLimits::default()
+/// };
+/// let xml = r#"<column><row><text value="Too deep" /></row></column>"#;
+/// assert!(parse_with_limits(xml, &limits).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseLimits {
+    /// Maximum size of the document, in bytes
+    pub max_document_size: usize,
+    /// Maximum nesting depth of elements, the root element being at depth 1
+    pub max_depth: usize,
+    /// Maximum number of elements
+    pub max_nodes: usize,
+    /// Maximum length of an attribute value, in bytes
+    pub max_attribute_length: usize,
+    /// Maximum length of a `{...}` binding expression, in bytes
+    pub max_expression_length: usize,
+}
+
+impl Default for ParseLimits {
+    fn default() -> Self {
+        Self {
+            max_document_size: 8 * 1024 * 1024,
+            max_depth: 128,
+            max_nodes: 100_000,
+            max_attribute_length: 64 * 1024,
+            max_expression_length: 4 * 1024,
+        }
+    }
+}
+
+impl ParseLimits {
+    /// Check the size and nesting depth of the raw XML, before it is parsed
+    ///
+    /// The XML parser recurses on nested elements, so the depth is checked on
+    /// the source text.
+    pub(crate) fn check_source(&self, xml: &str) -> Result<(), ParseError> {
+        if xml.len() > self.max_document_size {
+            return Err(limit_error(
+                format!(
+                    "Document is {} bytes, more than the limit of {} bytes",
+                    xml.len(),
+                    self.max_document_size
+                ),
+                Span::new(0, 0, 1, 1),
+                "Split the view into several documents",
+            ));
+        }
+
+        if let Some(offset) = too_deep_element(xml, self.max_depth) {
+            let (line, column) = super::calculate_line_col(xml, offset);
+            return Err(limit_error(
+                format!(
+                    "Elements are nested more than {} levels deep",
+                    self.max_depth
+                ),
+                Span::new(offset, offset, line, column),
+                "Flatten the layout or extract parts of it into separate views",
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Check the number of elements of the raw XML
+    ///
+    /// Used when only part of a document is parsed again, see
+    /// [`reparse_with_recovery`](super::incremental::reparse_with_recovery).
+    pub(crate) fn check_element_count(&self, xml: &str) -> Result<(), ParseError> {
+        match find_start_tag(xml, |index, _| index >= self.max_nodes) {
+            Some(offset) => {
+                let (line, column) = super::calculate_line_col(xml, offset);
+                Err(too_many_elements(
+                    self.max_nodes,
+                    Span::new(offset, offset, line, column),
+                ))
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Check the number of elements and the attributes of a parsed XML document
+    pub(crate) fn check_document(
+        &self,
+        doc: &Document,
+        span: impl Fn(Node) -> Span,
+    ) -> Result<(), ParseError> {
+        for (index, node) in doc.descendants().filter(Node::is_element).enumerate() {
+            if index >= self.max_nodes {
+                return Err(too_many_elements(self.max_nodes, span(node)));
+            }
+            self.check_attributes(node, &span)?;
+        }
+
+        Ok(())
+    }
+
+    fn check_attributes(&self, node: Node, span: &impl Fn(Node) -> Span) -> Result<(), ParseError> {
+        for attr in node.attributes() {
+            let value = attr.value();
+            if value.len() > self.max_attribute_length {
+                return Err(limit_error(
+                    format!(
+                        "Attribute '{}' is {} bytes long, more than the limit of {} bytes",
+                        attr.name(),
+                        value.len(),
+                        self.max_attribute_length
+                    ),
+                    span(node),
+                    "Move large content to a file or to the model",
+                ));
+            }
+
+            if let Some(length) = longest_expression(value)
+                && length > self.max_expression_length
+            {
+                return Err(limit_error(
+                    format!(
+                        "Binding expression in attribute '{}' is {} bytes long, more than the limit of {} bytes",
+                        attr.name(),
+                        length,
+                        self.max_expression_length
+                    ),
+                    span(node),
+                    "Compute the value in the model and bind to the field instead",
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Byte offset of the first element nested deeper than `max_depth`
+fn too_deep_element(xml: &str, max_depth: usize) -> Option<usize> {
+    find_start_tag(xml, |_, depth| depth > max_depth)
+}
+
+/// Byte offset of the first start tag for which `found` returns `true`
+///
+/// `found` is given the index of the element in document order and its depth,
+/// the root element being at depth 1. Comments, processing instructions, CDATA
+/// sections and declarations are skipped. Malformed markup is left to the XML
+/// parser to report.
+fn find_start_tag(xml: &str, mut found: impl FnMut(usize, usize) -> bool) -> Option<usize> {
+    let bytes = xml.as_bytes();
+    let mut depth = 0usize;
+    let mut index = 0;
+    let mut pos = 0;
+
+    while let Some(next) = xml[pos..].find('<') {
+        let start = pos + next;
+        let rest = &xml[start..];
+
+        let skip_to = |end: &str| rest.find(end).map_or(xml.len(), |i| start + i + end.len());
+        pos = if rest.starts_with("<!--") {
+            skip_to("-->")
+        } else if rest.starts_with("<![CDATA[") {
+            skip_to("]]>")
+        } else if rest.starts_with("<?") {
+            skip_to("?>")
+        } else if rest.starts_with("<!") {
+            skip_to(">")
+        } else if rest.starts_with("</") {
+            depth = depth.saturating_sub(1);
+            skip_to(">")
+        } else {
+            // Find the end of the start tag, `>` being allowed in quoted values
+            let mut quote = None;
+            let mut end = xml.len();
+            for (i, &byte) in bytes.iter().enumerate().skip(start + 1) {
+                match (quote, byte) {
+                    (Some(q), _) if byte == q => quote = None,
+                    (None, b'"' | b'\'') => quote = Some(byte),
+                    (None, b'>') => {
+                        end = i;
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+            if found(index, depth + 1) {
+                return Some(start);
+            }
+            index += 1;
+            // Self-closing elements have no children
+            if bytes.get(end.wrapping_sub(1)) != Some(&b'/') {
+                depth += 1;
+            }
+            (end + 1).min(xml.len())
+        };
+    }
+
+    None
+}
+
+/// Length of the longest `{...}` segment of an attribute value, braces excluded
+///
+/// An unclosed binding runs to the end of the value.
+fn longest_expression(value: &str) -> Option<usize> {
+    value
+        .split('{')
+        .skip(1)
+        .map(|rest| rest.find('}').unwrap_or(rest.len()))
+        .max()
+}
+
+fn too_many_elements(max_nodes: usize, span: Span) -> ParseError {
+    limit_error(
+        format!("Document has more than {} elements", max_nodes),
+        span,
+        "Split the view into several documents, or generate repeated widgets with <for>",
+    )
+}
+
+fn limit_error(message: String, span: Span, suggestion: &str) -> ParseError {
+    ParseError {
+        kind: ParseErrorKind::LimitExceeded,
+        message,
+        span,
+        suggestion: Some(suggestion.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_with_limits;
+
+    fn nested(depth: usize) -> String {
+        format!(
+            "{}<text value=\"Deep\" />{}",
+            "<column>".repeat(depth),
+            "</column>".repeat(depth)
+        )
+    }
+
+    #[test]
+    fn test_depth_limit() {
+        let limits = ParseLimits {
+            max_depth: 10,
+            ..ParseLimits::default()
+        };
+        assert!(parse_with_limits(&nested(9), &limits).is_ok());
+
+        let error = parse_with_limits(&nested(10), &limits).err();
+        assert_eq!(error.map(|e| e.kind), Some(ParseErrorKind::LimitExceeded));
+    }
+
+    #[test]
+    fn test_deep_document_fails_without_overflow() {
+        // Deep enough to overflow the stack of the XML parser
+        let error = parse_with_limits(&nested(100_000), &ParseLimits::default()).err();
+        assert_eq!(error.map(|e| e.kind), Some(ParseErrorKind::LimitExceeded));
+    }
+
+    #[test]
+    fn test_node_limit() {
+        let limits = ParseLimits {
+            max_nodes: 3,
+            ..ParseLimits::default()
+        };
+        let xml = r#"<column><text value="a" /><text value="b" /><text value="c" /></column>"#;
+        let error = parse_with_limits(xml, &limits).err();
+        assert_eq!(
+            error.map(|e| (e.kind, e.span.column)),
+            Some((ParseErrorKind::LimitExceeded, 45))
+        );
+    }
+
+    #[test]
+    fn test_attribute_and_expression_limits() {
+        let limits = ParseLimits {
+            max_attribute_length: 32,
+            max_expression_length: 8,
+            ..ParseLimits::default()
+        };
+        assert!(parse_with_limits(r#"<text value="{count} of {total}" />"#, &limits).is_ok());
+
+        let long_value = format!(r#"<text value="{}" />"#, "a".repeat(33));
+        assert!(parse_with_limits(&long_value, &limits).is_err());
+
+        let long_expr = r#"<text value="{count + total}" />"#;
+        let error = parse_with_limits(long_expr, &limits).err();
+        assert_eq!(error.map(|e| e.kind), Some(ParseErrorKind::LimitExceeded));
+    }
+
+    #[test]
+    fn test_document_size_limit() {
+        let limits = ParseLimits {
+            max_document_size: 16,
+            ..ParseLimits::default()
+        };
+        assert!(parse_with_limits(r#"<text value="Long enough" />"#, &limits).is_err());
+    }
+
+    #[test]
+    fn test_too_deep_element_skips_markup() {
+        let xml = r#"<?xml version="1.0"?>
+<!-- <column><column> -->
+<column label="a > b"><row /><![CDATA[<row>]]><row><text /></row></column>"#;
+        assert_eq!(too_deep_element(xml, 3), None);
+        assert_eq!(too_deep_element(xml, 2), xml.find("<text"));
+    }
+
+    #[test]
+    fn test_element_count_of_source() {
+        let limits = ParseLimits {
+            max_nodes: 3,
+            ..ParseLimits::default()
+        };
+        let xml = r#"<column><text value="a" /><!-- <text /> --><text value="b" /></column>"#;
+        assert!(limits.check_element_count(xml).is_ok());
+
+        let xml = r#"<column><text value="a" /><text value="b" /><text value="c" /></column>"#;
+        let error = limits.check_element_count(xml).err();
+        assert_eq!(error.map(|e| e.span.column), Some(45));
+    }
+
+    #[test]
+    fn test_longest_expression() {
+        assert_eq!(longest_expression("static"), None);
+        assert_eq!(longest_expression("{a} and {bcd}"), Some(3));
+        assert_eq!(longest_expression("{unclosed"), Some(8));
+    }
+}
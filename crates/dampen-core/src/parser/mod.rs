@@ -3,23 +3,30 @@ pub mod canvas;
 pub mod color_validator;
 pub mod error;
 pub mod gradient;
+pub mod incremental;
 pub mod lexer;
+pub mod limits;
 pub mod style_parser;
 pub mod theme_parser;
 
-use crate::expr::tokenize_binding_expr;
+use crate::expr::parse_binding_expr;
 use crate::expr::{BindingExpr, Expr, LiteralExpr};
 use crate::ir::style::StyleProperties;
 use crate::ir::theme::WidgetState;
 use crate::ir::{
-    AttributeValue, Breakpoint, DampenDocument, EventBinding, EventKind, InterpolatedPart,
-    SchemaVersion, Span, WidgetKind, WidgetNode,
+    Accessibility, AccessibilityRole, AttributeSpan, AttributeValue, Breakpoint, Breakpoints,
+    DampenDocument, EventBinding, EventKind, InterpolatedPart, SchemaVersion, Span, WidgetKind,
+    WidgetNode, WindowConfig,
 };
 use crate::parser::error::{ParseError, ParseErrorKind};
 use chrono::{NaiveDate, NaiveTime};
 use roxmltree::{Document, Node, NodeType};
+use std::cell::RefCell;
 use std::collections::HashMap;
 
+pub use incremental::reparse_with_recovery;
+pub use limits::ParseLimits;
+
 /// Maximum schema version supported by this framework release.
 ///
 /// Files declaring a version higher than this will be rejected with an error.
@@ -223,25 +230,195 @@ fn validate_widget_tree(
     }
 }
 
+/// Kind of accessibility issue reported by [`validate_accessibility`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum AccessibilityIssue {
+    /// An interactive widget has no name screen readers could announce
+    MissingLabel,
+    /// `described_by` refers to an id that does not exist in the document
+    UnknownDescription(String),
+}
+
+/// Warning about a widget that assistive technologies cannot describe.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessibilityWarning {
+    /// The widget the warning is about
+    pub widget_kind: WidgetKind,
+    /// What is wrong with the widget
+    pub issue: AccessibilityIssue,
+    /// Source location of the widget
+    pub span: Span,
+}
+
+impl AccessibilityWarning {
+    /// Format the warning as a human-readable message
+    pub fn format_message(&self) -> String {
+        match &self.issue {
+            AccessibilityIssue::MissingLabel => {
+                format!("Widget '{}' has no accessible label", self.widget_kind)
+            }
+            AccessibilityIssue::UnknownDescription(id) => format!(
+                "Widget '{}' is described by unknown id '{}'",
+                self.widget_kind, id
+            ),
+        }
+    }
+
+    /// Get a suggestion for resolving the warning
+    pub fn suggestion(&self) -> String {
+        match (&self.issue, &self.widget_kind) {
+            (AccessibilityIssue::MissingLabel, WidgetKind::TextInput) => {
+                "Add an aria_label or a placeholder".to_string()
+            }
+            (AccessibilityIssue::MissingLabel, _) => {
+                "Add an aria_label, a label attribute or a text child".to_string()
+            }
+            (AccessibilityIssue::UnknownDescription(_), _) => {
+                "Set described_by to the id of an existing widget".to_string()
+            }
+        }
+    }
+}
+
+/// Check that interactive widgets can be described by assistive technologies.
+///
+/// Buttons need an `aria_label`, a `label` or some text content, and text inputs
+/// an `aria_label` or a `placeholder`. `described_by` must refer to the id of a
+/// widget of the document.
+///
+/// # Examples
+///
+/// ```rust
+/// use dampen_core::{parse, validate_accessibility};
+///
+/// let xml = r#"<row><button on_click="delete"><icon name="trash" /></button></row>"#;
+/// let doc = parse(xml).unwrap();
+/// assert_eq!(validate_accessibility(&doc).len(), 1);
+/// ```
+pub fn validate_accessibility(document: &DampenDocument) -> Vec<AccessibilityWarning> {
+    let mut ids = std::collections::HashSet::new();
+    collect_widget_ids(&document.root, &mut ids);
+
+    let mut warnings = Vec::new();
+    validate_accessibility_tree(&document.root, &ids, &mut warnings);
+    warnings
+}
+
+fn collect_widget_ids<'a>(node: &'a WidgetNode, ids: &mut std::collections::HashSet<&'a str>) {
+    if let Some(id) = &node.id {
+        ids.insert(id.as_str());
+    }
+    for child in &node.children {
+        collect_widget_ids(child, ids);
+    }
+}
+
+fn validate_accessibility_tree(
+    node: &WidgetNode,
+    ids: &std::collections::HashSet<&str>,
+    warnings: &mut Vec<AccessibilityWarning>,
+) {
+    let labelled = node
+        .accessibility
+        .as_ref()
+        .is_some_and(|a| a.label.is_some());
+
+    let missing_label = !labelled
+        && match node.kind {
+            WidgetKind::Button => {
+                !node.attributes.contains_key("label") && !node.children.iter().any(has_text)
+            }
+            WidgetKind::TextInput => !node.attributes.contains_key("placeholder"),
+            _ => false,
+        };
+    if missing_label {
+        warnings.push(AccessibilityWarning {
+            widget_kind: node.kind.clone(),
+            issue: AccessibilityIssue::MissingLabel,
+            span: node.span,
+        });
+    }
+
+    if let Some(id) = node
+        .accessibility
+        .as_ref()
+        .and_then(|a| a.described_by.as_deref())
+        && !ids.contains(id)
+    {
+        warnings.push(AccessibilityWarning {
+            widget_kind: node.kind.clone(),
+            issue: AccessibilityIssue::UnknownDescription(id.to_string()),
+            span: node.span,
+        });
+    }
+
+    for child in &node.children {
+        validate_accessibility_tree(child, ids, warnings);
+    }
+}
+
+/// Whether a widget or one of its descendants provides text to announce
+fn has_text(node: &WidgetNode) -> bool {
+    let own_text = match node.kind {
+        WidgetKind::Text | WidgetKind::Span => node.attributes.contains_key("value"),
+        WidgetKind::Markdown => node.attributes.contains_key("src"),
+        _ => false,
+    };
+    own_text
+        || node
+            .accessibility
+            .as_ref()
+            .is_some_and(|a| a.label.is_some())
+        || node.children.iter().any(has_text)
+}
+
+/// Separator replacing the colon of state-prefixed attributes (`hover:background`)
+const STATE_SEPARATOR: &str = "_state_";
+
 /// Preprocess XML to handle state attributes without XML namespaces.
 ///
-/// Converts attributes like `hover:background` to `hover__state__background`
+/// Converts attributes like `hover:background` to `hover_state_background`
 /// to avoid "unknown namespace prefix" errors from roxmltree.
-fn preprocess_xml(xml: &str) -> String {
-    let mut result = xml.to_string();
+///
+/// Also returns the offset in the result right after each inserted separator,
+/// so positions can be mapped back to `xml` (see [`SourceIndex`]).
+fn preprocess_xml(xml: &str) -> (String, Vec<usize>) {
     let states = ["hover", "active", "focus", "disabled"];
     let prefixes = [' ', '\n', '\t', '\r'];
 
-    for state in states {
-        for prefix in prefixes {
-            // We want to replace " hover:" with " hover__state__"
-            // Note: We deliberately remove the colon to make it a valid non-namespaced attribute
-            let target = format!("{}{}:", prefix, state);
-            let sub = format!("{}{}_state_", prefix, state);
-            result = result.replace(&target, &sub);
+    let mut result = String::with_capacity(xml.len());
+    let mut inserted = Vec::new();
+    let mut copied = 0;
+    for (colon, _) in xml.match_indices(':') {
+        // Replace " hover:" with " hover_state_"
+        let before = &xml[..colon];
+        let is_state = states.iter().any(|state| {
+            before
+                .strip_suffix(state)
+                .is_some_and(|rest| rest.ends_with(prefixes))
+        });
+        // Replace " class:active=" with " class_state_active=", see CLASS_TOGGLE_PREFIX
+        let is_class_toggle = before
+            .strip_suffix("class")
+            .is_some_and(|rest| rest.ends_with(prefixes))
+            && is_attribute_name_start(&xml[colon + 1..]);
+        if is_state || is_class_toggle {
+            result.push_str(&xml[copied..colon]);
+            result.push_str(STATE_SEPARATOR);
+            inserted.push(result.len());
+            copied = colon + 1;
         }
     }
-    result
+    result.push_str(&xml[copied..]);
+    (result, inserted)
+}
+
+/// Whether `rest` starts with a class name followed by `=`, as after `class:`
+fn is_attribute_name_start(rest: &str) -> bool {
+    let name_len = rest
+        .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-'))
+        .unwrap_or(rest.len());
+    name_len > 0 && rest[name_len..].trim_start().starts_with('=')
 }
 
 /// Parse XML markup into a DampenDocument.
@@ -276,16 +453,122 @@ fn preprocess_xml(xml: &str) -> String {
 /// - Invalid attribute values
 /// - Malformed binding expressions
 pub fn parse(xml: &str) -> Result<DampenDocument, ParseError> {
+    parse_document(xml, &[], &ParseLimits::default(), None)
+}
+
+/// Parse XML markup into a DampenDocument, with custom resource limits.
+///
+/// [`parse`] and the other parsing functions apply [`ParseLimits::default`].
+/// Use this to accept larger generated documents, or to be stricter with
+/// untrusted input.
+///
+/// # Errors
+///
+/// Returns a [`ParseErrorKind::LimitExceeded`] error if the document exceeds one
+/// of the limits, and otherwise the same errors as [`parse`].
+pub fn parse_with_limits(xml: &str, limits: &ParseLimits) -> Result<DampenDocument, ParseError> {
+    parse_document(xml, &[], limits, None)
+}
+
+/// Parse XML markup into a DampenDocument using shared style libraries.
+///
+/// The themes and style classes of each library are merged into the document
+/// with [`DampenDocument::merge`], in order: the document's own definitions come
+/// first, then those of the first library, and so on. In strict mode, the
+/// classes of the libraries are known classes.
+///
+/// # Examples
+///
+/// ```rust
+/// use dampen_core::parser::{parse, parse_with_styles};
+///
+/// let library = parse(r#"<dampen>
+///     <styles>
+///         <style name="card"><base padding="16" /></style>
+///     </styles>
+/// </dampen>"#).unwrap();
+///
+/// let xml = r#"<dampen strict="true"><container class="card" /></dampen>"#;
+/// let doc = parse_with_styles(xml, &[library]).unwrap();
+/// assert!(doc.style_classes.contains_key("card"));
+/// ```
+///
+/// # Errors
+///
+/// Returns the same errors as [`parse`].
+pub fn parse_with_styles(
+    xml: &str,
+    libraries: &[DampenDocument],
+) -> Result<DampenDocument, ParseError> {
+    parse_document(xml, libraries, &ParseLimits::default(), None)
+}
+
+/// Parse XML markup into a DampenDocument, collecting every error in one pass.
+///
+/// Unlike [`parse`], parsing does not stop at the first invalid widget: the
+/// widget is replaced with an empty placeholder, its error is recorded and
+/// parsing continues with the rest of the tree. Editors and error overlays use
+/// this to show every problem at once.
+///
+/// # Returns
+///
+/// The (possibly partial) document and all errors found. The document is
+/// `None` only if the markup is not well-formed XML.
+///
+/// # Examples
+///
+/// ```rust
+/// use dampen_core::parser::parse_with_recovery;
+///
+/// let xml = r#"<column>
+///     <text value="Hello" />
+///     <buton label="Typo" />
+///     <icon size="16" />
+/// </column>"#;
+/// let (document, errors) = parse_with_recovery(xml);
+///
+/// assert_eq!(errors.len(), 2);
+/// assert_eq!(document.map(|doc| doc.root.children.len()), Some(3));
+/// ```
+pub fn parse_with_recovery(xml: &str) -> (Option<DampenDocument>, Vec<ParseError>) {
+    let mut errors = Vec::new();
+    let document = match parse_document(xml, &[], &ParseLimits::default(), Some(&mut errors)) {
+        Ok(document) => Some(document),
+        Err(error) => {
+            errors.push(error);
+            None
+        }
+    };
+
+    // Report errors in source order
+    errors.sort_by_key(|error| error.span.start);
+    (document, errors)
+}
+
+/// Parse a document merged with `libraries`, recording widget errors in `errors`
+/// instead of failing when given
+///
+/// Exceeding `limits` is always fatal, even when recording errors.
+fn parse_document(
+    xml: &str,
+    libraries: &[DampenDocument],
+    limits: &ParseLimits,
+    mut errors: Option<&mut Vec<ParseError>>,
+) -> Result<DampenDocument, ParseError> {
+    limits.check_source(xml)?;
+
     // Preprocess XML to handle state attributes
-    let processed_xml = preprocess_xml(xml);
+    let (processed_xml, inserted) = preprocess_xml(xml);
+    let _source_index = SourceIndex::install(xml, inserted);
 
     // Parse XML using roxmltree
     let doc = Document::parse(&processed_xml).map_err(|e| ParseError {
         kind: ParseErrorKind::XmlSyntax,
         message: e.to_string(),
-        span: Span::new(0, 0, 1, 1),
+        span: text_pos_span(xml, e.pos().row, e.pos().col),
         suggestion: None,
     })?;
+    limits.check_document(&doc, |node| get_span(node, xml))?;
 
     // Find root element (skip XML declaration)
     let root = doc.root().first_child().ok_or_else(|| ParseError {
@@ -300,23 +583,37 @@ pub fn parse(xml: &str) -> Result<DampenDocument, ParseError> {
 
     if root_tag == "dampen" {
         // Parse <dampen> document with themes and widgets
-        parse_dampen_document(root, xml)
+        parse_dampen_document(root, xml, libraries, errors)
     } else {
         // Parse direct widget (backward compatibility)
         // Default to version 1.0 for backward compatibility
-        let root_widget = parse_node(root, xml)?;
+        let root_widget = match errors.as_deref_mut() {
+            Some(errors) => recover_root(root, xml, errors),
+            None => attach_context_menu(parse_node(root, xml)?)?,
+        };
 
         // Validate nesting constraints
-        validate_nesting_constraints(&root_widget, None)?;
+        report(
+            validate_nesting_constraints(&root_widget, None),
+            errors.as_deref_mut(),
+        )?;
+        report(validate_float_anchors(&root_widget), errors)?;
 
-        Ok(DampenDocument {
+        let mut document = DampenDocument {
             version: SchemaVersion::default(),
             root: root_widget,
             themes: HashMap::new(),
             style_classes: HashMap::new(),
             global_theme: None,
             follow_system: true,
-        })
+            strict: false,
+            window: None,
+            breakpoints: Breakpoints::default(),
+        };
+        for library in libraries {
+            document.merge(library);
+        }
+        Ok(document)
     }
 }
 
@@ -324,30 +621,74 @@ pub fn parse(xml: &str) -> Result<DampenDocument, ParseError> {
 fn validate_widget_attributes(
     kind: &WidgetKind,
     attributes: &std::collections::HashMap<String, AttributeValue>,
+    attribute_spans: &HashMap<String, AttributeSpan>,
     span: Span,
 ) -> Result<(), ParseError> {
+    // Errors about an attribute's value point at the value
+    let value_span = |name: &str| attribute_spans.get(name).map_or(span, |s| s.value);
+
+    validate_model_scope(
+        kind,
+        attributes,
+        value_span(crate::ir::scope::MODEL_ATTRIBUTE),
+    )?;
+
     match kind {
-        WidgetKind::ComboBox | WidgetKind::PickList => {
-            require_non_empty_attribute(
-                kind,
-                "options",
-                attributes,
-                span,
-                "Add a comma-separated list: options=\"Option1,Option2\"",
-            )?;
+        // Bound options are only known at runtime
+        WidgetKind::ComboBox | WidgetKind::PickList | WidgetKind::RadioGroup
+            if !matches!(attributes.get("options"), Some(AttributeValue::Binding(_))) =>
+        {
+            // A radio group may list <radio> children instead (see validate_radio_group_children)
+            if *kind != WidgetKind::RadioGroup {
+                require_non_empty_attribute(
+                    kind,
+                    "options",
+                    attributes,
+                    span,
+                    "Add a comma-separated list: options=\"Option1,Option2\", \
+                     or bind a collection: options=\"{countries}\"",
+                )?;
+            }
+            if attributes.contains_key("display") {
+                return Err(ParseError {
+                    kind: ParseErrorKind::InvalidValue,
+                    message: format!(
+                        "display for {:?} requires options bound to a collection",
+                        kind
+                    ),
+                    span: value_span("display"),
+                    suggestion: Some(
+                        "Bind the options: options=\"{countries}\" display=\"{item.name}\""
+                            .to_string(),
+                    ),
+                });
+            }
+        }
+        WidgetKind::TextInput => {
+            validate_input_constraints(kind, attributes, value_span)?;
+        }
+        WidgetKind::NumberInput => {
+            validate_number_range(kind, attributes, value_span)?;
+        }
+        WidgetKind::Skeleton => {
+            validate_numeric_range(kind, "lines", attributes, value_span("lines"), 1..=100usize)?;
+        }
+        WidgetKind::Slider | WidgetKind::ProgressBar => {
+            validate_value_range(kind, attributes, value_span("max"), value_span("step"))?;
+            validate_value_format(kind, attributes, value_span("value_format"))?;
         }
         WidgetKind::DatePicker => {
-            validate_date_format(kind, attributes, span)?;
-            validate_date_range(kind, attributes, span)?;
+            validate_date_format(kind, attributes, value_span("value"))?;
+            validate_date_range(kind, attributes, value_span("max_date"))?;
         }
         WidgetKind::TimePicker => {
-            validate_time_format(kind, attributes, span)?;
+            validate_time_format(kind, attributes, value_span("value"))?;
         }
         WidgetKind::Canvas => {
             // Width and height are optional (defaulted in builder if missing)
             // But validation ensures they are numbers if present
-            validate_numeric_range(kind, "width", attributes, span, 50..=4000)?;
-            validate_numeric_range(kind, "height", attributes, span, 50..=4000)?;
+            validate_numeric_range(kind, "width", attributes, value_span("width"), 50..=4000)?;
+            validate_numeric_range(kind, "height", attributes, value_span("height"), 50..=4000)?;
 
             // T069: Warn if both 'program' attribute and children shapes are present
             if attributes.contains_key("program") {
@@ -358,6 +699,63 @@ fn validate_widget_attributes(
                 // Actually, I can check this in parse_node or validate_canvas_children.
             }
         }
+        WidgetKind::Svg => {
+            validate_svg_tint(kind, attributes, value_span("tint"))?;
+        }
+        WidgetKind::Icon => {
+            require_attribute(
+                kind,
+                "name",
+                attributes,
+                span,
+                "Add name attribute: name=\"trash\"",
+            )?;
+            validate_icon_name(kind, attributes, value_span("name"))?;
+            validate_svg_tint(kind, attributes, value_span("tint"))?;
+        }
+        WidgetKind::Markdown => {
+            require_attribute(
+                kind,
+                "src",
+                attributes,
+                span,
+                "Add src attribute: src=\"{readme}\"",
+            )?;
+            validate_numeric_range(
+                kind,
+                "scale_ratio",
+                attributes,
+                value_span("scale_ratio"),
+                1.0..=4.0f32,
+            )?;
+        }
+        WidgetKind::Tree => {
+            require_attribute(
+                kind,
+                "items",
+                attributes,
+                span,
+                "Add items attribute: items=\"{nodes}\"",
+            )?;
+            require_attribute(
+                kind,
+                "label",
+                attributes,
+                span,
+                "Add label attribute: label=\"{node.name}\"",
+            )?;
+            validate_tree_names(kind, attributes, value_span)?;
+        }
+        WidgetKind::Chart => {
+            require_attribute(
+                kind,
+                "data",
+                attributes,
+                span,
+                "Add data attribute: data=\"{points}\"",
+            )?;
+            validate_chart_type(kind, attributes, value_span("type"))?;
+        }
         WidgetKind::Grid => {
             require_attribute(
                 kind,
@@ -366,17 +764,23 @@ fn validate_widget_attributes(
                 span,
                 "Add columns attribute: columns=\"5\"",
             )?;
-            validate_numeric_range(kind, "columns", attributes, span, 1..=20)?;
+            validate_numeric_range(kind, "columns", attributes, value_span("columns"), 1..=20)?;
         }
         WidgetKind::Tooltip => {
-            require_attribute(
+            // `message` may be replaced by a <tooltip_content> child, checked with the children
+            validate_tooltip_position(kind, attributes, value_span("position"))?;
+            validate_numeric_range(
                 kind,
-                "message",
+                "delay_ms",
                 attributes,
-                span,
-                "Add message attribute: message=\"Help text\"",
+                value_span("delay_ms"),
+                0..=60_000u64,
             )?;
         }
+        WidgetKind::Float => {
+            validate_float_placement(kind, attributes, value_span("placement"))?;
+            validate_float_layer(attributes, value_span("layer"))?;
+        }
         WidgetKind::For => {
             require_attribute(
                 kind,
@@ -397,7 +801,10 @@ fn validate_widget_attributes(
         | WidgetKind::CanvasCircle
         | WidgetKind::CanvasLine
         | WidgetKind::CanvasText
-        | WidgetKind::CanvasGroup => {
+        | WidgetKind::CanvasGroup
+        | WidgetKind::CanvasPath
+        | WidgetKind::CanvasArc
+        | WidgetKind::CanvasBezier => {
             canvas::validate_shape_attributes(kind, attributes, span)?;
         }
         _ => {}
@@ -405,93 +812,354 @@ fn validate_widget_attributes(
     Ok(())
 }
 
-/// Helper to validate date format for static value
-fn validate_date_format(
+/// Validate the `model` attribute of a container: a plain field binding such as `{cart}`
+fn validate_model_scope(
     kind: &WidgetKind,
     attributes: &HashMap<String, AttributeValue>,
     span: Span,
 ) -> Result<(), ParseError> {
-    if let Some(AttributeValue::Static(value)) = attributes.get("value") {
-        let format = if let Some(AttributeValue::Static(f)) = attributes.get("format") {
-            f.as_str()
-        } else {
-            "%Y-%m-%d"
-        };
+    let Some(value) = attributes.get(crate::ir::scope::MODEL_ATTRIBUTE) else {
+        return Ok(());
+    };
 
-        if NaiveDate::parse_from_str(value, format).is_err() {
-            return Err(ParseError {
-                kind: ParseErrorKind::InvalidDateFormat,
-                message: format!(
-                    "Invalid date format for {:?}: '{}' does not match format '{}'",
-                    kind, value, format
-                ),
-                span,
-                suggestion: Some(
-                    "Use ISO 8601 format (YYYY-MM-DD) or specify correct format attribute (e.g., format=\"%d/%m/%Y\")".to_string()
-                ),
-            });
-        }
+    let is_field = matches!(
+        value,
+        AttributeValue::Binding(BindingExpr {
+            expr: Expr::FieldAccess(field),
+            ..
+        }) if !field.path.is_empty()
+    );
+    if !is_field {
+        return Err(ParseError {
+            kind: ParseErrorKind::InvalidValue,
+            message: format!(
+                "Invalid model attribute for {:?}: expected a field binding",
+                kind
+            ),
+            span,
+            suggestion: Some(
+                "Bind a field of the model, e.g. model=\"{cart}\"; its children then bind to {total} instead of {cart.total}"
+                    .to_string(),
+            ),
+        });
     }
     Ok(())
 }
 
-/// Helper to validate time format for static value
-fn validate_time_format(
+/// Validate the static `tint` of an SVG or icon: a color or a theme palette color (`@primary`)
+fn validate_svg_tint(
     kind: &WidgetKind,
     attributes: &HashMap<String, AttributeValue>,
     span: Span,
 ) -> Result<(), ParseError> {
-    if let Some(AttributeValue::Static(value)) = attributes.get("value") {
-        let format = if let Some(AttributeValue::Static(f)) = attributes.get("format") {
-            f.as_str()
-        } else {
-            "%H:%M:%S"
-        };
+    let Some(AttributeValue::Static(value)) = attributes.get("tint") else {
+        return Ok(());
+    };
 
-        if NaiveTime::parse_from_str(value, format).is_err() {
-            return Err(ParseError {
-                kind: ParseErrorKind::InvalidTimeFormat,
-                message: format!(
-                    "Invalid time format for {:?}: '{}' does not match format '{}'",
-                    kind, value, format
-                ),
-                span,
-                suggestion: Some(
-                    "Use 24-hour format (HH:MM:SS) or specify correct format attribute (e.g., format=\"%I:%M %p\")".to_string()
-                ),
-            });
-        }
+    let valid = match value.trim().strip_prefix('@') {
+        Some(name) => gradient::THEME_COLORS.contains(&name),
+        None => style_parser::parse_color_attr(value).is_ok(),
+    };
+
+    if !valid {
+        return Err(ParseError {
+            kind: ParseErrorKind::InvalidValue,
+            message: format!("Invalid tint '{}' for {:?}", value, kind),
+            span,
+            suggestion: Some(
+                "Use a color (#RRGGBB) or a theme color: @primary, @secondary, @success, \
+                 @warning, @danger, @background, @text"
+                    .to_string(),
+            ),
+        });
     }
     Ok(())
 }
 
-/// Helper to validate date range (min <= max)
-fn validate_date_range(
+/// Validate that a static icon `name` is part of the built-in icon set
+fn validate_icon_name(
     kind: &WidgetKind,
     attributes: &HashMap<String, AttributeValue>,
     span: Span,
 ) -> Result<(), ParseError> {
-    let min_date = if let Some(AttributeValue::Static(m)) = attributes.get("min_date") {
-        NaiveDate::parse_from_str(m, "%Y-%m-%d").ok()
-    } else {
-        None
-    };
-
-    let max_date = if let Some(AttributeValue::Static(m)) = attributes.get("max_date") {
-        NaiveDate::parse_from_str(m, "%Y-%m-%d").ok()
-    } else {
-        None
-    };
-
-    if let (Some(min), Some(max)) = (min_date, max_date)
-        && min > max
+    if let Some(AttributeValue::Static(name)) = attributes.get("name")
+        && !crate::icons::contains(name.trim())
     {
         return Err(ParseError {
-            kind: ParseErrorKind::InvalidDateRange,
-            message: format!(
-                "Invalid date range for {:?}: min_date ({}) is after max_date ({})",
-                kind, min, max
-            ),
+            kind: ParseErrorKind::InvalidValue,
+            message: format!("Unknown icon '{}' for {:?}", name, kind),
+            span,
+            suggestion: Some(format!(
+                "Available icons: {}",
+                crate::icons::names().collect::<Vec<_>>().join(", ")
+            )),
+        });
+    }
+    Ok(())
+}
+
+/// Validate the static `type` of a chart
+fn validate_chart_type(
+    kind: &WidgetKind,
+    attributes: &HashMap<String, AttributeValue>,
+    span: Span,
+) -> Result<(), ParseError> {
+    if let Some(AttributeValue::Static(value)) = attributes.get("type")
+        && !matches!(value.trim(), "line" | "bar" | "pie")
+    {
+        return Err(ParseError {
+            kind: ParseErrorKind::InvalidValue,
+            message: format!("Invalid chart type '{}' for {:?}", value, kind),
+            span,
+            suggestion: Some("Use type=\"line\", type=\"bar\" or type=\"pie\"".to_string()),
+        });
+    }
+    Ok(())
+}
+
+/// Validate that `children` and `each` of a tree name a field and a variable
+///
+/// Both are read when walking the items, so they can't be bound.
+fn validate_tree_names(
+    kind: &WidgetKind,
+    attributes: &HashMap<String, AttributeValue>,
+    value_span: impl Fn(&str) -> Span,
+) -> Result<(), ParseError> {
+    for (name, example) in [
+        ("children", "children=\"children\""),
+        ("each", "each=\"node\""),
+    ] {
+        let valid = match attributes.get(name) {
+            Some(AttributeValue::Static(value)) => {
+                let value = value.trim();
+                !value.is_empty()
+                    && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+                    && !value.starts_with(|c: char| c.is_ascii_digit())
+            }
+            Some(_) => false,
+            None => true,
+        };
+        if !valid {
+            return Err(ParseError {
+                kind: ParseErrorKind::InvalidValue,
+                message: format!("'{}' of {:?} must be a static identifier", name, kind),
+                span: value_span(name),
+                suggestion: Some(format!("Use {}", example)),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Validate the static `min`, `max` and `step` of a slider or progress bar
+///
+/// Bound values are only known at runtime and are not checked.
+fn validate_value_range(
+    kind: &WidgetKind,
+    attributes: &HashMap<String, AttributeValue>,
+    max_span: Span,
+    step_span: Span,
+) -> Result<(), ParseError> {
+    let number = |name: &str| match attributes.get(name) {
+        Some(AttributeValue::Static(value)) => value.trim().parse::<f32>().ok(),
+        _ => None,
+    };
+
+    if let (Some(min), Some(max)) = (number("min"), number("max"))
+        && min >= max
+    {
+        return Err(ParseError {
+            kind: ParseErrorKind::InvalidValue,
+            message: format!(
+                "max for {:?} must be greater than min ({}), found {}",
+                kind, min, max
+            ),
+            span: max_span,
+            suggestion: Some("Swap min and max, or widen the range".to_string()),
+        });
+    }
+    if let Some(step) = number("step")
+        && step <= 0.0
+    {
+        return Err(ParseError {
+            kind: ParseErrorKind::InvalidValue,
+            message: format!("step for {:?} must be positive, found {}", kind, step),
+            span: step_span,
+            suggestion: Some("Use a step such as step=\"0.5\"".to_string()),
+        });
+    }
+    Ok(())
+}
+
+/// Validate the static `value_format` filter of a slider or progress bar
+fn validate_value_format(
+    kind: &WidgetKind,
+    attributes: &HashMap<String, AttributeValue>,
+    span: Span,
+) -> Result<(), ParseError> {
+    if let Some(AttributeValue::Static(format)) = attributes.get("value_format")
+        && let Err(error) = crate::expr::ValueFormat::parse(format)
+    {
+        return Err(ParseError {
+            kind: ParseErrorKind::InvalidValue,
+            message: format!("{} for {:?}", error.message, kind),
+            span,
+            suggestion: error.suggestion,
+        });
+    }
+    Ok(())
+}
+
+/// Validate the static `mask`, `max_length` and `filter` of a text input
+fn validate_input_constraints(
+    kind: &WidgetKind,
+    attributes: &HashMap<String, AttributeValue>,
+    value_span: impl Fn(&str) -> Span,
+) -> Result<(), ParseError> {
+    if let Some(AttributeValue::Static(mask)) = attributes.get("mask")
+        && mask.trim() != "password"
+    {
+        return Err(ParseError {
+            kind: ParseErrorKind::InvalidValue,
+            message: format!("Unknown mask '{}' for {:?}", mask, kind),
+            span: value_span("mask"),
+            suggestion: Some("Use mask=\"password\"".to_string()),
+        });
+    }
+
+    let static_value = |name: &str| match attributes.get(name) {
+        Some(AttributeValue::Static(value)) => Some(value.as_str()),
+        _ => None,
+    };
+    let invalid = |name: &str, message: String| ParseError {
+        kind: ParseErrorKind::InvalidValue,
+        message: format!("{} for {:?}", message, kind),
+        span: value_span(name),
+        suggestion: None,
+    };
+    crate::input::InputConstraints::parse(static_value("max_length"), None)
+        .map_err(|message| invalid("max_length", message))?;
+    crate::input::InputConstraints::parse(None, static_value("filter"))
+        .map_err(|message| invalid("filter", message))?;
+    Ok(())
+}
+
+/// Validate the static `min`, `max`, `step` and `decimals` of a number input
+fn validate_number_range(
+    kind: &WidgetKind,
+    attributes: &HashMap<String, AttributeValue>,
+    value_span: impl Fn(&str) -> Span,
+) -> Result<(), ParseError> {
+    let static_value = |name: &str| match attributes.get(name) {
+        Some(AttributeValue::Static(value)) => Some(value.as_str()),
+        _ => None,
+    };
+    let invalid = |name: &str, message: String| ParseError {
+        kind: ParseErrorKind::InvalidValue,
+        message: format!("{} for {:?}", message, kind),
+        span: value_span(name),
+        suggestion: None,
+    };
+    crate::input::NumberRange::parse(static_value("min"), None, None, None)
+        .map_err(|message| invalid("min", message))?;
+    crate::input::NumberRange::parse(None, static_value("max"), None, None)
+        .map_err(|message| invalid("max", message))?;
+    crate::input::NumberRange::parse(None, None, static_value("step"), None)
+        .map_err(|message| invalid("step", message))?;
+    crate::input::NumberRange::parse(None, None, None, static_value("decimals"))
+        .map_err(|message| invalid("decimals", message))?;
+    crate::input::NumberRange::parse(static_value("min"), static_value("max"), None, None)
+        .map_err(|message| invalid("max", message))?;
+    Ok(())
+}
+
+/// Helper to validate date format for static value
+fn validate_date_format(
+    kind: &WidgetKind,
+    attributes: &HashMap<String, AttributeValue>,
+    span: Span,
+) -> Result<(), ParseError> {
+    if let Some(AttributeValue::Static(value)) = attributes.get("value") {
+        let format = if let Some(AttributeValue::Static(f)) = attributes.get("format") {
+            f.as_str()
+        } else {
+            "%Y-%m-%d"
+        };
+
+        if NaiveDate::parse_from_str(value, format).is_err() {
+            return Err(ParseError {
+                kind: ParseErrorKind::InvalidDateFormat,
+                message: format!(
+                    "Invalid date format for {:?}: '{}' does not match format '{}'",
+                    kind, value, format
+                ),
+                span,
+                suggestion: Some(
+                    "Use ISO 8601 format (YYYY-MM-DD) or specify correct format attribute (e.g., format=\"%d/%m/%Y\")".to_string()
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Helper to validate time format for static value
+fn validate_time_format(
+    kind: &WidgetKind,
+    attributes: &HashMap<String, AttributeValue>,
+    span: Span,
+) -> Result<(), ParseError> {
+    if let Some(AttributeValue::Static(value)) = attributes.get("value") {
+        let format = if let Some(AttributeValue::Static(f)) = attributes.get("format") {
+            f.as_str()
+        } else {
+            "%H:%M:%S"
+        };
+
+        if NaiveTime::parse_from_str(value, format).is_err() {
+            return Err(ParseError {
+                kind: ParseErrorKind::InvalidTimeFormat,
+                message: format!(
+                    "Invalid time format for {:?}: '{}' does not match format '{}'",
+                    kind, value, format
+                ),
+                span,
+                suggestion: Some(
+                    "Use 24-hour format (HH:MM:SS) or specify correct format attribute (e.g., format=\"%I:%M %p\")".to_string()
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Helper to validate date range (min <= max)
+fn validate_date_range(
+    kind: &WidgetKind,
+    attributes: &HashMap<String, AttributeValue>,
+    span: Span,
+) -> Result<(), ParseError> {
+    let min_date = if let Some(AttributeValue::Static(m)) = attributes.get("min_date") {
+        NaiveDate::parse_from_str(m, "%Y-%m-%d").ok()
+    } else {
+        None
+    };
+
+    let max_date = if let Some(AttributeValue::Static(m)) = attributes.get("max_date") {
+        NaiveDate::parse_from_str(m, "%Y-%m-%d").ok()
+    } else {
+        None
+    };
+
+    if let (Some(min), Some(max)) = (min_date, max_date)
+        && min > max
+    {
+        return Err(ParseError {
+            kind: ParseErrorKind::InvalidDateRange,
+            message: format!(
+                "Invalid date range for {:?}: min_date ({}) is after max_date ({})",
+                kind, min, max
+            ),
             span,
             suggestion: Some("Ensure min_date is before or equal to max_date".to_string()),
         });
@@ -575,8 +1243,99 @@ fn validate_numeric_range<T: PartialOrd + std::fmt::Display + std::str::FromStr>
     Ok(())
 }
 
-/// Validate Tooltip widget has exactly one child
-fn validate_tooltip_children(children: &[WidgetNode], span: Span) -> Result<(), ParseError> {
+/// Helper to validate the position of a tooltip
+fn validate_tooltip_position(
+    kind: &WidgetKind,
+    attributes: &HashMap<String, AttributeValue>,
+    span: Span,
+) -> Result<(), ParseError> {
+    if let Some(AttributeValue::Static(value)) = attributes.get("position")
+        && crate::ir::node::TooltipPosition::parse(value).is_none()
+    {
+        return Err(ParseError {
+            kind: ParseErrorKind::InvalidValue,
+            message: format!("Invalid position '{}' for {:?}", value, kind),
+            span,
+            suggestion: Some(
+                "Use position=\"top\", \"bottom\", \"left\", \"right\" or \"follow_cursor\""
+                    .to_string(),
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Helper to validate the placement of an anchored float
+fn validate_float_placement(
+    kind: &WidgetKind,
+    attributes: &HashMap<String, AttributeValue>,
+    span: Span,
+) -> Result<(), ParseError> {
+    if let Some(AttributeValue::Static(value)) = attributes.get("placement")
+        && let Err(message) = crate::ir::Placement::parse(value)
+    {
+        return Err(ParseError {
+            kind: ParseErrorKind::InvalidValue,
+            message: format!("{} for {:?}", message, kind),
+            span,
+            suggestion: Some(
+                "Use placement=\"bottom-start\", \"top\", \"right-end\", etc.".to_string(),
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Validate the `layer` of a portal float
+fn validate_float_layer(
+    attributes: &HashMap<String, AttributeValue>,
+    span: Span,
+) -> Result<(), ParseError> {
+    if let Some(AttributeValue::Static(value)) = attributes.get("layer")
+        && let Err(message) = crate::ir::layer::Layer::parse(value)
+    {
+        return Err(ParseError {
+            kind: ParseErrorKind::InvalidValue,
+            message,
+            span,
+            suggestion: Some("Use layer=\"modal\", \"dropdown\" or \"toast\"".to_string()),
+        });
+    }
+    Ok(())
+}
+
+/// Validate Tooltip widget has exactly one child, besides an optional `<tooltip_content>`
+fn validate_tooltip_children(
+    attributes: &HashMap<String, AttributeValue>,
+    children: &[WidgetNode],
+    span: Span,
+) -> Result<(), ParseError> {
+    let rich_contents = children
+        .iter()
+        .filter(|child| child.kind == WidgetKind::TooltipContent)
+        .count();
+    if rich_contents > 1 {
+        return Err(ParseError {
+            kind: ParseErrorKind::InvalidValue,
+            message: "Tooltip widget can have only one <tooltip_content>".to_string(),
+            span,
+            suggestion: Some("Merge the contents into a single <tooltip_content>".to_string()),
+        });
+    }
+    if rich_contents == 0 && !attributes.contains_key("message") {
+        return Err(ParseError {
+            kind: ParseErrorKind::MissingAttribute,
+            message: "Tooltip requires a message attribute or a <tooltip_content> child"
+                .to_string(),
+            span,
+            suggestion: Some("Add message attribute: message=\"Help text\"".to_string()),
+        });
+    }
+
+    let children: Vec<&WidgetNode> = children
+        .iter()
+        .filter(|child| child.kind != WidgetKind::TooltipContent)
+        .collect();
     if children.is_empty() {
         return Err(ParseError {
             kind: ParseErrorKind::InvalidValue,
@@ -623,13 +1382,16 @@ fn validate_canvas_children(
     canvas::validate_canvas_children(children, span)
 }
 
-/// Validate DatePicker/TimePicker has exactly one child
+/// Validate the underlay of a DatePicker/TimePicker
+///
+/// A time picker needs exactly one child. A date picker without a child shows a
+/// button with its date instead.
 fn validate_datetime_picker_children(
     kind: &WidgetKind,
     children: &[WidgetNode],
     span: Span,
 ) -> Result<(), ParseError> {
-    if children.is_empty() {
+    if children.is_empty() && *kind != WidgetKind::DatePicker {
         return Err(ParseError {
             kind: ParseErrorKind::InvalidValue,
             message: format!(
@@ -658,8 +1420,12 @@ fn validate_datetime_picker_children(
     Ok(())
 }
 
-/// Validate ContextMenu has exactly 2 children: underlay + menu
+/// Validate ContextMenu children: underlay + menu, or a single menu when attached
 fn validate_context_menu_children(children: &[WidgetNode], span: Span) -> Result<(), ParseError> {
+    if children.len() == 1 && children[0].kind == WidgetKind::Menu {
+        // Attached form, wrapped around its parent widget (see `attach_context_menu`)
+        return Ok(());
+    }
     if children.len() != 2 {
         return Err(ParseError {
             kind: ParseErrorKind::InvalidValue,
@@ -681,8 +1447,317 @@ fn validate_context_menu_children(children: &[WidgetNode], span: Span) -> Result
     Ok(())
 }
 
+/// Validate RadioGroup options: an `options` attribute or `<radio>` children, not both
+fn validate_radio_group_children(
+    attributes: &HashMap<String, AttributeValue>,
+    children: &[WidgetNode],
+    span: Span,
+) -> Result<(), ParseError> {
+    if let Some(child) = children.iter().find(|c| c.kind != WidgetKind::Radio) {
+        return Err(ParseError {
+            kind: ParseErrorKind::InvalidChild,
+            message: format!(
+                "RadioGroup can only contain <radio> elements, found <{}>",
+                child.kind
+            ),
+            span: child.span,
+            suggestion: Some(
+                "Use <radio label=\"...\" value=\"...\" /> elements inside <radio_group>"
+                    .to_string(),
+            ),
+        });
+    }
+
+    if children.is_empty() {
+        if !matches!(attributes.get("options"), Some(AttributeValue::Binding(_))) {
+            require_non_empty_attribute(
+                &WidgetKind::RadioGroup,
+                "options",
+                attributes,
+                span,
+                "Add a comma-separated list: options=\"Free,Pro\", \
+                 bind a collection: options=\"{plans}\", or add <radio> children",
+            )?;
+        }
+    } else if attributes.contains_key("options") {
+        return Err(ParseError {
+            kind: ParseErrorKind::InvalidValue,
+            message: "RadioGroup cannot have both an 'options' attribute and <radio> children"
+                .to_string(),
+            span,
+            suggestion: Some(
+                "Remove the 'options' attribute to use the <radio> children, or remove the children"
+                    .to_string(),
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Wrap each widget in the `<context_menu>` attached to it (see [`attach_context_menu`])
+fn attach_context_menus(widgets: &mut [WidgetNode]) -> Result<(), ParseError> {
+    for widget in widgets.iter_mut() {
+        if widget.children.iter().any(is_attached_context_menu) {
+            *widget = attach_context_menu(std::mem::take(widget))?;
+        }
+    }
+    Ok(())
+}
+
+/// Whether a node is a `<context_menu>` attached to its parent (a single `<menu>` child)
+fn is_attached_context_menu(node: &WidgetNode) -> bool {
+    node.kind == WidgetKind::ContextMenu
+        && node.children.len() == 1
+        && node.children[0].kind == WidgetKind::Menu
+}
+
+/// Wrap a widget in the `<context_menu>` attached to it, if any
+///
+/// ```xml
+/// <row on_context_menu="select:{item.id}">
+///     <text value="{item.name}" />
+///     <context_menu>
+///         <menu>
+///             <menu_item label="Delete" on_click="delete:{item.id}" />
+///         </menu>
+///     </context_menu>
+/// </row>
+/// ```
+///
+/// The result is the same as the underlay + menu form: `<context_menu>[row, menu]`.
+fn attach_context_menu(mut widget: WidgetNode) -> Result<WidgetNode, ParseError> {
+    if widget.kind == WidgetKind::ContextMenu {
+        return Ok(widget);
+    }
+
+    let mut attached = widget
+        .children
+        .iter()
+        .enumerate()
+        .filter(|(_, child)| is_attached_context_menu(child))
+        .map(|(index, _)| index);
+
+    let Some(index) = attached.next() else {
+        return Ok(widget);
+    };
+    if let Some(duplicate) = attached.next() {
+        return Err(ParseError {
+            kind: ParseErrorKind::InvalidChild,
+            message: "A widget can only have one attached <context_menu>".to_string(),
+            span: widget.children[duplicate].span,
+            suggestion: Some("Merge the menus into a single <context_menu>".to_string()),
+        });
+    }
+
+    let mut context_menu = widget.children.remove(index);
+    context_menu.children.insert(0, widget);
+    Ok(context_menu)
+}
+
+/// Parse the mixed content of a `<text>` widget into `<span>` children
+///
+/// Runs of plain text become spans holding only a `value`. Whitespace is collapsed
+/// as in HTML, and the whitespace at the start and end of the content is removed.
+fn parse_inline_children(
+    node: Node,
+    source: &str,
+    children: &mut Vec<WidgetNode>,
+) -> Result<(), ParseError> {
+    let inline: Vec<Node> = node
+        .children()
+        .filter(|child| child.is_element() || child.is_text())
+        .collect();
+    let last = inline.len().saturating_sub(1);
+
+    for (index, child) in inline.into_iter().enumerate() {
+        if child.is_element() {
+            children.push(parse_node(child, source)?);
+            continue;
+        }
+
+        let raw = child.text().unwrap_or_default();
+        let mut text = collapse_whitespace(raw);
+        if index == 0 {
+            text = text.trim_start().to_string();
+        }
+        if index == last {
+            text = text.trim_end().to_string();
+        }
+        // Skip empty runs and indentation between elements
+        if text.is_empty() || (text == " " && raw.contains('\n')) {
+            continue;
+        }
+
+        let span = get_span(child, source);
+        children.push(WidgetNode {
+            kind: WidgetKind::Span,
+            attributes: HashMap::from([("value".to_string(), parse_attribute_value(&text, span)?)]),
+            span,
+            ..Default::default()
+        });
+    }
+
+    Ok(())
+}
+
+/// Parse the text content of a `<span>` element into its `value`
+fn parse_span_content(node: Node, source: &str) -> Result<AttributeValue, ParseError> {
+    let content: String = node
+        .children()
+        .filter(|child| child.is_text())
+        .filter_map(|child| child.text())
+        .collect();
+    parse_attribute_value(&collapse_whitespace(&content), get_span(node, source))
+}
+
+/// Extract the markup of an inline `<svg>` element nested in an `<svg>` widget
+///
+/// The SVG namespace is declared if the markup does not, so that it can be
+/// rendered as a standalone document.
+fn inline_svg_content(node: Node) -> Option<String> {
+    let svg = node
+        .children()
+        .find(|child| child.is_element() && child.tag_name().name() == "svg")?;
+    let markup = node.document().input_text().get(svg.range())?;
+
+    let start_tag = markup.split('>').next().unwrap_or_default();
+    if start_tag.contains("xmlns=") {
+        Some(markup.to_string())
+    } else {
+        Some(markup.replacen("<svg", "<svg xmlns=\"http://www.w3.org/2000/svg\"", 1))
+    }
+}
+
+/// Collapse runs of whitespace (including newlines) into a single space
+fn collapse_whitespace(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut in_whitespace = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !in_whitespace {
+                result.push(' ');
+            }
+            in_whitespace = true;
+        } else {
+            result.push(c);
+            in_whitespace = false;
+        }
+    }
+    result
+}
+
+/// In recovery mode, record the error of `result` and carry on; otherwise return it
+fn report(
+    result: Result<(), ParseError>,
+    errors: Option<&mut Vec<ParseError>>,
+) -> Result<(), ParseError> {
+    match (result, errors) {
+        (Err(error), Some(errors)) => {
+            errors.push(error);
+            Ok(())
+        }
+        (result, _) => result,
+    }
+}
+
+/// Parse a node in recovery mode, replacing it with a placeholder if it is invalid
+///
+/// Children are recovered first, so errors in the children of an invalid node
+/// are still reported.
+fn recover_node(node: Node, source: &str, errors: &mut Vec<ParseError>) -> WidgetNode {
+    // Inline <text> and <svg> content is not made of widgets
+    let children = if matches!(node.tag_name().name(), "text" | "svg") {
+        None
+    } else {
+        Some(
+            node.children()
+                .filter(|child| child.node_type() == NodeType::Element)
+                .map(|child| recover_node(child, source, errors))
+                .collect(),
+        )
+    };
+
+    parse_node_with_children(node, source, children).unwrap_or_else(|error| {
+        errors.push(error);
+        placeholder_node(node, source)
+    })
+}
+
+/// Parse the root widget in recovery mode
+fn recover_root(node: Node, source: &str, errors: &mut Vec<ParseError>) -> WidgetNode {
+    let widget = recover_node(node, source, errors);
+    // Only clone the tree if attaching a menu may fail
+    if !widget.children.iter().any(is_attached_context_menu) {
+        return widget;
+    }
+    attach_context_menu(widget.clone()).unwrap_or_else(|error| {
+        errors.push(error);
+        widget
+    })
+}
+
+/// Empty widget standing in for an invalid node in a recovered document
+fn placeholder_node(node: Node, source: &str) -> WidgetNode {
+    WidgetNode {
+        kind: WidgetKind::Space,
+        span: get_span(node, source),
+        ..WidgetNode::default()
+    }
+}
+
 /// Parse a single XML node into a WidgetNode
 fn parse_node(node: Node, source: &str) -> Result<WidgetNode, ParseError> {
+    parse_node_with_children(node, source, None)
+}
+
+/// Parse a single XML node, using `recovered_children` instead of parsing its widget children if given
+fn parse_node_with_children(
+    node: Node,
+    source: &str,
+    recovered_children: Option<Vec<WidgetNode>>,
+) -> Result<WidgetNode, ParseError> {
+    let element = parse_element(node, source)?;
+
+    // Parse children
+    let mut children = Vec::new();
+    if element.kind == WidgetKind::Text {
+        parse_inline_children(node, source, &mut children)?;
+    } else if let Some(recovered) = recovered_children {
+        children = recovered;
+    } else if element.kind != WidgetKind::Svg {
+        for child in node.children() {
+            if child.node_type() == NodeType::Element {
+                children.push(parse_node(child, source)?);
+            }
+        }
+    }
+    attach_context_menus(&mut children)?;
+
+    finish_element(element, children)
+}
+
+/// An element whose attributes are parsed, waiting for its children
+///
+/// Parsing is split around the children so that the frame of the recursive
+/// [`parse_node_with_children`] stays small: deeply nested documents would
+/// overflow the stack otherwise.
+struct ParsedElement {
+    kind: WidgetKind,
+    span: Span,
+    id: Option<String>,
+    attributes: HashMap<String, AttributeValue>,
+    breakpoint_attributes: HashMap<Breakpoint, HashMap<String, AttributeValue>>,
+    inline_state_variants: HashMap<WidgetState, HashMap<String, AttributeValue>>,
+    events: Vec<EventBinding>,
+    accessibility: Option<Box<Accessibility>>,
+    attribute_spans: HashMap<String, AttributeSpan>,
+    classes: Vec<String>,
+    theme_ref: Option<AttributeValue>,
+    comments: Vec<String>,
+}
+
+/// Parse the tag and attributes of an element
+fn parse_element(node: Node, source: &str) -> Result<ParsedElement, ParseError> {
     // Only process element nodes
     if node.node_type() != NodeType::Element {
         return Err(ParseError {
@@ -704,8 +1779,10 @@ fn parse_node(node: Node, source: &str) -> Result<WidgetNode, ParseError> {
         "text" => WidgetKind::Text,
         "image" => WidgetKind::Image,
         "svg" => WidgetKind::Svg,
+        "icon" => WidgetKind::Icon,
         "button" => WidgetKind::Button,
         "text_input" => WidgetKind::TextInput,
+        "number_input" => WidgetKind::NumberInput,
         "checkbox" => WidgetKind::Checkbox,
         "slider" => WidgetKind::Slider,
         "pick_list" => WidgetKind::PickList,
@@ -713,9 +1790,15 @@ fn parse_node(node: Node, source: &str) -> Result<WidgetNode, ParseError> {
         "space" => WidgetKind::Space,
         "rule" => WidgetKind::Rule,
         "radio" => WidgetKind::Radio,
+        "radio_group" => WidgetKind::RadioGroup,
+        "span" => WidgetKind::Span,
+        "markdown" => WidgetKind::Markdown,
+        "code_editor" => WidgetKind::CodeEditor,
         "combobox" => WidgetKind::ComboBox,
         "progress_bar" => WidgetKind::ProgressBar,
+        "skeleton" => WidgetKind::Skeleton,
         "tooltip" => WidgetKind::Tooltip,
+        "tooltip_content" => WidgetKind::TooltipContent,
         "grid" => WidgetKind::Grid,
         "canvas" => WidgetKind::Canvas,
         "rect" => WidgetKind::CanvasRect,
@@ -723,6 +1806,10 @@ fn parse_node(node: Node, source: &str) -> Result<WidgetNode, ParseError> {
         "line" => WidgetKind::CanvasLine,
         "canvas_text" => WidgetKind::CanvasText,
         "group" => WidgetKind::CanvasGroup,
+        "path" => WidgetKind::CanvasPath,
+        "arc" => WidgetKind::CanvasArc,
+        "bezier" => WidgetKind::CanvasBezier,
+        "chart" => WidgetKind::Chart,
         "date_picker" => WidgetKind::DatePicker,
         "time_picker" => WidgetKind::TimePicker,
         "color_picker" => WidgetKind::ColorPicker,
@@ -734,6 +1821,7 @@ fn parse_node(node: Node, source: &str) -> Result<WidgetNode, ParseError> {
         "data_table" => WidgetKind::DataTable,
         "data_column" => WidgetKind::DataColumn,
         "tree_view" => WidgetKind::TreeView,
+        "tree" => WidgetKind::Tree,
         "tree_node" => WidgetKind::TreeNode,
         "tab_bar" => WidgetKind::TabBar,
         "tab" => WidgetKind::Tab,
@@ -753,6 +1841,8 @@ fn parse_node(node: Node, source: &str) -> Result<WidgetNode, ParseError> {
         }
     };
 
+    let span = get_span(node, source);
+
     // Parse attributes - separate breakpoint-prefixed and state-prefixed from regular
     let mut attributes = std::collections::HashMap::new();
     let mut breakpoint_attributes: HashMap<Breakpoint, HashMap<String, AttributeValue>> =
@@ -761,11 +1851,16 @@ fn parse_node(node: Node, source: &str) -> Result<WidgetNode, ParseError> {
         HashMap::new();
     let mut events = Vec::new();
     let mut id = None;
+    let mut accessibility = None;
+    let mut attribute_spans = HashMap::new();
 
     // Pre-scan attributes for validation
     for attr in node.attributes() {
         if kind == WidgetKind::ColorPicker && attr.name() == "value" {
-            color_validator::validate_color_format(attr.value(), get_span(node, source))?;
+            color_validator::validate_color_format(
+                attr.value(),
+                range_span(attr.range_value(), source),
+            )?;
         }
     }
 
@@ -787,7 +1882,20 @@ fn parse_node(node: Node, source: &str) -> Result<WidgetNode, ParseError> {
         } else {
             attr.name().to_string()
         };
+        // Class toggles are kept with their `class:` prefix
+        let name = match name.strip_prefix("class_state_") {
+            Some(class) => format!("{}{}", crate::ir::CLASS_TOGGLE_PREFIX, class),
+            None => name,
+        };
         let value = attr.value();
+        let value_span = range_span(attr.range_value(), source);
+        attribute_spans.insert(
+            name.clone(),
+            AttributeSpan {
+                name: range_span(attr.range_qname(), source),
+                value: value_span,
+            },
+        );
 
         // Check for id attribute
         if name == "id" {
@@ -795,6 +1903,11 @@ fn parse_node(node: Node, source: &str) -> Result<WidgetNode, ParseError> {
             continue;
         }
 
+        // Extract accessibility metadata
+        if parse_accessibility_attribute(&mut accessibility, &name, value, value_span)? {
+            continue;
+        }
+
         // Check for event attributes (on_click, on_change, etc.)
         if name.starts_with("on_") {
             let event_kind = match name.as_str() {
@@ -813,6 +1926,13 @@ fn parse_node(node: Node, source: &str) -> Result<WidgetNode, ParseError> {
                 "on_open" => Some(EventKind::Open),
                 "on_close" => Some(EventKind::Close),
                 "on_row_click" => Some(EventKind::RowClick),
+                "on_drag_start" => Some(EventKind::DragStart),
+                "on_drag_over" => Some(EventKind::DragOver),
+                "on_drop" => Some(EventKind::Drop),
+                "on_context_menu" => Some(EventKind::ContextMenu),
+                "on_link" => Some(EventKind::Link),
+                "on_reach_end" => Some(EventKind::ReachEnd),
+                "on_expand" => Some(EventKind::Expand),
                 _ => None,
             };
 
@@ -828,51 +1948,36 @@ fn parse_node(node: Node, source: &str) -> Result<WidgetNode, ParseError> {
             };
 
             if let Some(event) = event_kind {
-                // Parse handler name and optional parameter
-                // Syntax: "handler_name", "handler_name:{expression}", or "handler_name:'value'"
-                let (handler_name, param) = if let Some(colon_pos) = value.find(':') {
+                // Parse handler name and optional parameters
+                // Syntax: "handler_name", "handler_name:{expression}", "handler_name:'value'",
+                // or several comma-separated parameters: "handler_name:{a},{b},'c'"
+                let (handler_name, param, params) = if let Some(colon_pos) = value.find(':') {
                     let handler = value[..colon_pos].to_string();
-                    let param_str = &value[colon_pos + 1..];
-
-                    // Check for single-quoted string: 'value'
-                    if param_str.starts_with('\'')
-                        && param_str.ends_with('\'')
-                        && param_str.len() >= 2
-                    {
-                        let quoted_value = &param_str[1..param_str.len() - 1];
-                        // Create a static string binding expression
-                        let expr = BindingExpr {
-                            expr: Expr::Literal(LiteralExpr::String(quoted_value.to_string())),
-                            span: Span::new(
-                                colon_pos + 1,
-                                colon_pos + 1 + param_str.len(),
-                                1,
-                                colon_pos as u32 + 1,
-                            ),
-                        };
-                        (handler, Some(expr))
-                    } else {
-                        // Remove surrounding braces if present: {item.id} -> item.id
-                        let param_clean = param_str.trim_matches('{').trim_matches('}');
-
-                        // Parse parameter as binding expression
-                        match crate::expr::tokenize_binding_expr(param_clean, 0, 1, 1) {
-                            Ok(expr) => (handler, Some(expr)),
-                            Err(_) => {
-                                // If parsing fails, treat the whole string as handler name
-                                (value.to_string(), None)
-                            }
-                        }
+                    let parts = split_event_params(&value[colon_pos + 1..]);
+
+                    let parsed: Option<Vec<BindingExpr>> = parts
+                        .iter()
+                        .map(|(offset, part)| {
+                            parse_event_param(value, colon_pos + 1 + offset, part, value_span)
+                        })
+                        .collect();
+
+                    match parsed {
+                        // If parsing fails, treat the whole string as handler name
+                        None => (value.to_string(), None, Vec::new()),
+                        Some(mut exprs) if exprs.len() == 1 => (handler, exprs.pop(), Vec::new()),
+                        Some(exprs) => (handler, None, exprs),
                     }
                 } else {
-                    (value.to_string(), None)
+                    (value.to_string(), None, Vec::new())
                 };
 
                 events.push(EventBinding {
                     event,
                     handler: handler_name,
                     param,
-                    span: get_span(node, source),
+                    params,
+                    span: value_span,
                 });
                 continue;
             }
@@ -880,10 +1985,12 @@ fn parse_node(node: Node, source: &str) -> Result<WidgetNode, ParseError> {
 
         // Check for breakpoint-prefixed attributes (e.g., "mobile-spacing", "tablet-width")
         // Note: We use hyphen instead of colon to avoid XML namespace issues
+        // Custom breakpoints are applied once the whole document is parsed
         if let Some((prefix, attr_name)) = name.split_once('-')
             && let Ok(breakpoint) = crate::ir::layout::Breakpoint::parse(prefix)
+            && !matches!(breakpoint, Breakpoint::Custom(_))
         {
-            let attr_value = parse_attribute_value(value, get_span(node, source))?;
+            let attr_value = parse_attribute_value(value, value_span)?;
             breakpoint_attributes
                 .entry(breakpoint)
                 .or_default()
@@ -894,7 +2001,7 @@ fn parse_node(node: Node, source: &str) -> Result<WidgetNode, ParseError> {
         if let Some((state_prefix, attr_name)) = name.split_once(':')
             && let Some(state) = WidgetState::from_prefix(state_prefix)
         {
-            let attr_value = parse_attribute_value(value, get_span(node, source))?;
+            let attr_value = parse_attribute_value(value, value_span)?;
             inline_state_variants
                 .entry(state)
                 .or_default()
@@ -906,7 +2013,7 @@ fn parse_node(node: Node, source: &str) -> Result<WidgetNode, ParseError> {
         if let Some((state_prefix, attr_name)) = name.split_once("_state_")
             && let Some(state) = WidgetState::from_prefix(state_prefix)
         {
-            let attr_value = parse_attribute_value(value, get_span(node, source))?;
+            let attr_value = parse_attribute_value(value, value_span)?;
             inline_state_variants
                 .entry(state)
                 .or_default()
@@ -918,70 +2025,155 @@ fn parse_node(node: Node, source: &str) -> Result<WidgetNode, ParseError> {
         // TODO: Add proper logging when verbose mode is implemented
 
         // Parse attribute value (check for bindings)
-        let attr_value = parse_attribute_value(value, get_span(node, source))?;
+        let attr_value = parse_attribute_value(value, value_span)?;
         attributes.insert(name.to_string(), attr_value);
     }
 
+    // <span>content</span> is shorthand for <span value="content" />
+    if kind == WidgetKind::Span && !attributes.contains_key("value") {
+        let value = parse_span_content(node, source)?;
+        attributes.insert("value".to_string(), value);
+    }
+
+    // <svg><svg>...</svg></svg> is shorthand for <svg content="<svg>...</svg>" />
+    if kind == WidgetKind::Svg
+        && let Some(content) = inline_svg_content(node)
+    {
+        attributes.insert("content".to_string(), AttributeValue::Static(content));
+    }
+
     // Extract class attribute into classes field
-    let classes = if let Some(AttributeValue::Static(class_attr)) = attributes.get("class") {
-        class_attr
+    let classes = match attributes.get("class") {
+        Some(AttributeValue::Static(class_attr)) => class_attr
             .split_whitespace()
             .map(|s| s.to_string())
-            .collect()
-    } else {
-        Vec::new()
+            .collect(),
+        // The words of `class="card {if selected then 'card-active'}"` outside its bindings
+        Some(AttributeValue::Interpolated(parts)) => {
+            let text: String = parts
+                .iter()
+                .map(|part| match part {
+                    InterpolatedPart::Literal(literal) => literal.as_str(),
+                    InterpolatedPart::Binding(_) => "\0",
+                })
+                .collect();
+            text.split_whitespace()
+                .filter(|word| !word.contains('\0'))
+                .map(|s| s.to_string())
+                .collect()
+        }
+        _ => Vec::new(),
     };
 
     // Extract theme attribute into theme_ref field (supports both static and binding)
     let theme_ref = attributes.get("theme").cloned();
 
-    // Parse children
-    let mut children = Vec::new();
-    for child in node.children() {
-        if child.node_type() == NodeType::Element {
-            children.push(parse_node(child, source)?);
-        }
-    }
+    Ok(ParsedElement {
+        kind,
+        span,
+        id,
+        attributes,
+        breakpoint_attributes,
+        inline_state_variants,
+        events,
+        accessibility,
+        attribute_spans,
+        classes,
+        theme_ref,
+        comments: leading_comments(node),
+    })
+}
+
+/// Text of the comments between an element and the previous element, in order
+fn leading_comments(node: Node) -> Vec<String> {
+    let mut comments: Vec<String> = std::iter::successors(node.prev_sibling(), Node::prev_sibling)
+        .take_while(|sibling| sibling.node_type() != NodeType::Element)
+        .filter(|sibling| sibling.node_type() == NodeType::Comment)
+        .filter_map(|comment| comment.text())
+        .map(|text| text.trim().to_string())
+        .collect();
+    comments.reverse();
+    comments
+}
+
+/// Validate an element with its children and build its widget node
+fn finish_element(
+    element: ParsedElement,
+    children: Vec<WidgetNode>,
+) -> Result<WidgetNode, ParseError> {
+    let ParsedElement {
+        kind,
+        span,
+        id,
+        mut attributes,
+        breakpoint_attributes,
+        inline_state_variants,
+        events,
+        accessibility,
+        mut attribute_spans,
+        classes,
+        theme_ref,
+        comments,
+    } = element;
 
     // Validate Tooltip has exactly one child
     if kind == WidgetKind::Tooltip {
-        validate_tooltip_children(&children, get_span(node, source))?;
+        validate_tooltip_children(&attributes, &children, span)?;
+    } else if children
+        .iter()
+        .any(|child| child.kind == WidgetKind::TooltipContent)
+    {
+        return Err(ParseError {
+            kind: ParseErrorKind::InvalidChild,
+            message: "<tooltip_content> must be a child of <tooltip>".to_string(),
+            span,
+            suggestion: None,
+        });
     }
 
     // Validate Canvas has no children (leaf widget)
     if kind == WidgetKind::Canvas {
-        validate_canvas_children(&attributes, &children, get_span(node, source))?;
+        validate_canvas_children(&attributes, &children, span)?;
     }
 
-    // Validate DatePicker/TimePicker has exactly one child
+    // Validate DatePicker/TimePicker has at most one child
     if matches!(kind, WidgetKind::DatePicker | WidgetKind::TimePicker) {
-        validate_datetime_picker_children(&kind, &children, get_span(node, source))?;
+        validate_datetime_picker_children(&kind, &children, span)?;
     }
 
     if kind == WidgetKind::ContextMenu {
-        validate_context_menu_children(&children, get_span(node, source))?;
+        validate_context_menu_children(&children, span)?;
+    }
+
+    if kind == WidgetKind::RadioGroup {
+        validate_radio_group_children(&attributes, &children, span)?;
     }
 
     // Parse layout and style attributes into structured fields
     let layout = parse_layout_attributes(&kind, &attributes).map_err(|e| ParseError {
         kind: ParseErrorKind::InvalidValue,
         message: e,
-        span: get_span(node, source),
+        span,
         suggestion: None,
     })?;
     let style = parse_style_attributes(&attributes).map_err(|e| ParseError {
         kind: ParseErrorKind::InvalidValue,
         message: e,
-        span: get_span(node, source),
+        span,
         suggestion: None,
     })?;
 
     // Normalize deprecated attributes to standard names (with warnings)
-    let _attr_warnings = attribute_standard::normalize_attributes(&kind, &mut attributes);
+    let attr_warnings = attribute_standard::normalize_attributes(&kind, &mut attributes);
     // TODO: Log warnings in verbose mode
+    for (old_name, new_name) in attr_warnings {
+        if let Some(attribute_span) = attribute_spans.remove(&old_name) {
+            attribute_spans.insert(new_name, attribute_span);
+        }
+    }
 
     // Validate widget-specific required attributes
-    validate_widget_attributes(&kind, &attributes, get_span(node, source))?;
+    validate_widget_attributes(&kind, &attributes, &attribute_spans, span)?;
 
     // Convert inline_state_variants from HashMap<WidgetState, HashMap<String, AttributeValue>>
     // to HashMap<WidgetState, StyleProperties>
@@ -990,7 +2182,7 @@ fn parse_node(node: Node, source: &str) -> Result<WidgetNode, ParseError> {
         if let Some(state_style) = parse_style_attributes(&state_attrs).map_err(|e| ParseError {
             kind: ParseErrorKind::InvalidValue,
             message: format!("Invalid style in {:?} state: {}", state, e),
-            span: get_span(node, source),
+            span,
             suggestion: None,
         })? {
             final_state_variants.insert(state, state_style);
@@ -1002,35 +2194,100 @@ fn parse_node(node: Node, source: &str) -> Result<WidgetNode, ParseError> {
         id,
         attributes,
         events,
-        children,
-        span: get_span(node, source),
+        children: children.into(),
+        span,
         style,
         layout,
         theme_ref,
         classes,
         breakpoint_attributes,
         inline_state_variants: final_state_variants,
+        accessibility,
+        attribute_spans,
+        comments,
+    })
+}
+
+/// Store an accessibility attribute in `accessibility`
+///
+/// Returns `false` if `name` is not an accessibility attribute.
+fn parse_accessibility_attribute(
+    accessibility: &mut Option<Box<Accessibility>>,
+    name: &str,
+    value: &str,
+    span: Span,
+) -> Result<bool, ParseError> {
+    if !matches!(name, "aria_label" | "role" | "described_by") {
+        return Ok(false);
+    }
+
+    let accessibility = accessibility.get_or_insert_with(Box::default);
+    match name {
+        "aria_label" => accessibility.label = Some(parse_attribute_value(value, span)?),
+        "role" => accessibility.role = Some(parse_accessibility_role(value, span)?),
+        "described_by" => accessibility.described_by = Some(value.trim().to_string()),
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+/// Parse the value of a `role` attribute
+fn parse_accessibility_role(value: &str, span: Span) -> Result<AccessibilityRole, ParseError> {
+    AccessibilityRole::parse(value).ok_or_else(|| ParseError {
+        kind: ParseErrorKind::InvalidValue,
+        message: format!("Invalid role '{}'", value),
+        span,
+        suggestion: Some(format!(
+            "Valid roles: {}",
+            AccessibilityRole::ALL
+                .iter()
+                .map(|role| role.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
     })
 }
 
 /// Parse a `<dampen>` document with themes and widgets
-fn parse_dampen_document(root: Node, source: &str) -> Result<DampenDocument, ParseError> {
+///
+/// In recovery mode (`errors` given), invalid themes and widgets are skipped and
+/// their errors recorded.
+fn parse_dampen_document(
+    root: Node,
+    source: &str,
+    libraries: &[DampenDocument],
+    mut errors: Option<&mut Vec<ParseError>>,
+) -> Result<DampenDocument, ParseError> {
     let mut themes = HashMap::new();
     let mut style_classes = HashMap::new();
     let mut root_widget = None;
     let mut global_theme = None;
     let mut follow_system = true;
+    let mut window = None;
+    let mut breakpoints = Breakpoints::default();
 
     // Parse version attribute from <dampen> root element
     let span = get_span(root, source);
     let version = if let Some(version_attr) = root.attribute("version") {
-        let parsed = parse_version_string(version_attr, span)?;
-        validate_version_supported(&parsed, span)?;
-        parsed
+        let parsed = parse_version_string(version_attr, span).and_then(|parsed| {
+            validate_version_supported(&parsed, span)?;
+            Ok(parsed)
+        });
+        match (parsed, errors.as_deref_mut()) {
+            (Ok(parsed), _) => parsed,
+            (Err(error), Some(errors)) => {
+                errors.push(error);
+                SchemaVersion::default()
+            }
+            (Err(error), None) => return Err(error),
+        }
     } else {
         // Default to version 1.0 for backward compatibility
         SchemaVersion::default()
     };
+    let strict = root
+        .attribute("strict")
+        .is_some_and(|strict| strict.parse::<bool>().unwrap_or(false));
 
     // Iterate through children of <dampen>
     for child in root.children() {
@@ -1047,8 +2304,15 @@ fn parse_dampen_document(root: Node, source: &str) -> Result<DampenDocument, Par
                     if theme_node.node_type() == NodeType::Element
                         && theme_node.tag_name().name() == "theme"
                     {
-                        let theme =
-                            crate::parser::theme_parser::parse_theme_from_node(theme_node, source)?;
+                        let theme = match crate::parser::theme_parser::parse_theme_from_node(
+                            theme_node, source,
+                        ) {
+                            Ok(theme) => theme,
+                            Err(error) => {
+                                report(Err(error), errors.as_deref_mut())?;
+                                continue;
+                            }
+                        };
                         let name = theme_node
                             .attribute("name")
                             .map(|s| s.to_string())
@@ -1063,9 +2327,16 @@ fn parse_dampen_document(root: Node, source: &str) -> Result<DampenDocument, Par
                     if class_node.node_type() == NodeType::Element {
                         let tag = class_node.tag_name().name();
                         if tag == "class" || tag == "style" {
-                            let class = crate::parser::theme_parser::parse_style_class_from_node(
-                                class_node, source,
-                            )?;
+                            let class =
+                                match crate::parser::theme_parser::parse_style_class_from_node(
+                                    class_node, source,
+                                ) {
+                                    Ok(class) => class,
+                                    Err(error) => {
+                                        report(Err(error), errors.as_deref_mut())?;
+                                        continue;
+                                    }
+                                };
                             style_classes.insert(class.name.clone(), class);
                         }
                     }
@@ -1082,51 +2353,302 @@ fn parse_dampen_document(root: Node, source: &str) -> Result<DampenDocument, Par
                     follow_system = enabled.parse::<bool>().unwrap_or(true);
                 }
             }
+            "window" => match parse_window_config(child, source) {
+                Ok(config) => window = Some(config),
+                Err(error) => report(Err(error), errors.as_deref_mut())?,
+            },
+            "breakpoints" => match parse_breakpoints(child, source) {
+                Ok(parsed) => breakpoints = parsed,
+                Err(error) => report(Err(error), errors.as_deref_mut())?,
+            },
             _ => {
                 // This should be a widget - parse as root
                 if root_widget.is_some() {
-                    return Err(ParseError {
-                        kind: ParseErrorKind::XmlSyntax,
-                        message: "Multiple root widgets found in <dampen>".to_string(),
-                        span: get_span(child, source),
-                        suggestion: Some("Only one root widget is allowed".to_string()),
-                    });
+                    report(
+                        Err(ParseError {
+                            kind: ParseErrorKind::XmlSyntax,
+                            message: "Multiple root widgets found in <dampen>".to_string(),
+                            span: get_span(child, source),
+                            suggestion: Some("Only one root widget is allowed".to_string()),
+                        }),
+                        errors.as_deref_mut(),
+                    )?;
+                    continue;
                 }
-                root_widget = Some(parse_node(child, source)?);
+                root_widget = Some(match errors.as_deref_mut() {
+                    Some(errors) => recover_root(child, source, errors),
+                    None => attach_context_menu(parse_node(child, source)?)?,
+                });
             }
         }
     }
 
     // Ensure we have a root widget, or provide a default if themes are present
-    let root_widget = if let Some(w) = root_widget {
+    let root_widget = if let Some(mut w) = root_widget {
+        // `<breakpoints>` may follow the widgets, so their prefixes are only
+        // known now
+        if !breakpoints.is_default() {
+            report(
+                apply_custom_breakpoints(&mut w, &breakpoints),
+                errors.as_deref_mut(),
+            )?;
+        }
         w
     } else if !themes.is_empty() || !style_classes.is_empty() {
         // Create an empty default container if this is a theme/style-only file
         WidgetNode::default()
     } else {
-        return Err(ParseError {
-            kind: ParseErrorKind::XmlSyntax,
-            message: "No root widget found in <dampen>".to_string(),
-            span: get_span(root, source),
-            suggestion: Some("Add a widget like <column> or <row> inside <dampen>".to_string()),
-        });
+        report(
+            Err(ParseError {
+                kind: ParseErrorKind::XmlSyntax,
+                message: "No root widget found in <dampen>".to_string(),
+                span: get_span(root, source),
+                suggestion: Some("Add a widget like <column> or <row> inside <dampen>".to_string()),
+            }),
+            errors.as_deref_mut(),
+        )?;
+        WidgetNode::default()
     };
 
     // T098: Enforce strict version validation
     // Widgets requiring a newer schema version than declared must be rejected
-    validate_widget_versions_strict(&root_widget, &version)?;
+    report(
+        validate_widget_versions_strict(&root_widget, &version),
+        errors.as_deref_mut(),
+    )?;
 
     // Enforce nesting constraints (e.g. DataColumn must be inside DataTable)
-    validate_nesting_constraints(&root_widget, None)?;
+    report(
+        validate_nesting_constraints(&root_widget, None),
+        errors.as_deref_mut(),
+    )?;
+    report(validate_float_anchors(&root_widget), errors.as_deref_mut())?;
 
-    Ok(DampenDocument {
+    let mut document = DampenDocument {
         version,
         root: root_widget,
         themes,
         style_classes,
         global_theme,
         follow_system,
-    })
+        strict,
+        window,
+        breakpoints,
+    };
+    for library in libraries {
+        document.merge(library);
+    }
+
+    // In strict mode, unknown attributes and classes are errors rather than ignored
+    if strict {
+        for error in
+            attribute_standard::validate_known_names(&document.root, &document.style_classes)
+        {
+            report(Err(error), errors.as_deref_mut())?;
+        }
+    }
+
+    Ok(document)
+}
+
+/// Parse the `<breakpoints>` element of a `<dampen>` document
+///
+/// Each attribute names a breakpoint and gives its minimum width in pixels:
+/// `<breakpoints sm="480" md="768" />`.
+fn parse_breakpoints(node: Node, source: &str) -> Result<Breakpoints, ParseError> {
+    let span = get_span(node, source);
+    let mut custom = Vec::new();
+    for attr in node.attributes() {
+        let (name, value) = (attr.name(), attr.value());
+        let breakpoint = Breakpoint::parse(name).map_err(|message| ParseError {
+            kind: ParseErrorKind::InvalidValue,
+            message,
+            span,
+            suggestion: None,
+        })?;
+        if !matches!(breakpoint, Breakpoint::Custom(_)) {
+            return Err(ParseError {
+                kind: ParseErrorKind::InvalidValue,
+                message: format!(
+                    "'{}' is a built-in breakpoint and cannot be declared in <breakpoints>",
+                    breakpoint
+                ),
+                span,
+                suggestion: Some(
+                    "Give custom breakpoints other names, e.g. sm=\"0\", or remove <breakpoints> \
+                     to use mobile, tablet and desktop"
+                        .to_string(),
+                ),
+            });
+        }
+        let width = value
+            .trim()
+            .parse::<f32>()
+            .ok()
+            .filter(|width| width.is_finite() && *width >= 0.0)
+            .ok_or_else(|| ParseError {
+                kind: ParseErrorKind::InvalidValue,
+                message: format!("Invalid width of breakpoint '{}': '{}'", name, value),
+                span,
+                suggestion: Some("Expected a minimum width in pixels, e.g. 768".to_string()),
+            })?;
+        custom.push((breakpoint.name().to_string(), width));
+    }
+    if custom.is_empty() {
+        return Err(ParseError {
+            kind: ParseErrorKind::MissingAttribute,
+            message: "<breakpoints> declares no breakpoint".to_string(),
+            span,
+            suggestion: Some("Add breakpoints as name=\"min width\", e.g. sm=\"480\"".to_string()),
+        });
+    }
+    Ok(Breakpoints::custom(custom))
+}
+
+/// Move the attributes prefixed with a custom breakpoint (`md-spacing`) of
+/// a tree to its breakpoint attributes
+///
+/// The fixed prefixes (`mobile-spacing`) are filed while parsing each widget,
+/// before `<breakpoints>` is known. Once a document declares its own
+/// breakpoints, these and any other prefix that is not declared are errors.
+fn apply_custom_breakpoints(
+    node: &mut WidgetNode,
+    breakpoints: &Breakpoints,
+) -> Result<(), ParseError> {
+    let undeclared = |prefix: &str, name: &str, node: &WidgetNode| {
+        let declared: Vec<String> = breakpoints
+            .all()
+            .into_iter()
+            .map(|(breakpoint, _)| breakpoint.to_string())
+            .collect();
+        ParseError {
+            kind: ParseErrorKind::InvalidValue,
+            message: format!("Unknown breakpoint '{}' in attribute '{}'", prefix, name),
+            span: node.attribute_span(name),
+            suggestion: Some(format!("Declared breakpoints: {}", declared.join(", "))),
+        }
+    };
+
+    if let Some((breakpoint, bare)) = node
+        .breakpoint_attributes
+        .iter()
+        .filter(|(breakpoint, _)| breakpoints.parse(breakpoint.name()).is_none())
+        .find_map(|(breakpoint, attributes)| attributes.keys().min().map(|bare| (breakpoint, bare)))
+    {
+        let name = format!("{}-{}", breakpoint, bare);
+        return Err(undeclared(breakpoint.name(), &name, node));
+    }
+
+    let mut prefixed: Vec<String> = node
+        .attributes
+        .keys()
+        .filter(|name| {
+            name.split_once('-')
+                .is_some_and(|(prefix, _)| Breakpoint::parse(prefix).is_ok())
+        })
+        .cloned()
+        .collect();
+    prefixed.sort();
+    for name in prefixed {
+        let Some((prefix, bare)) = name.split_once('-') else {
+            continue;
+        };
+        let Some(breakpoint) = breakpoints.parse(prefix) else {
+            return Err(undeclared(prefix, &name, node));
+        };
+        let bare = bare.to_string();
+        if let Some(value) = node.attributes.remove(&name) {
+            node.breakpoint_attributes
+                .entry(breakpoint)
+                .or_default()
+                .insert(bare, value);
+        }
+    }
+    for child in &mut node.children {
+        apply_custom_breakpoints(child, breakpoints)?;
+    }
+    Ok(())
+}
+
+/// Parse the `<window>` element of a `<dampen>` document
+fn parse_window_config(node: Node, source: &str) -> Result<WindowConfig, ParseError> {
+    let span = get_span(node, source);
+    let invalid = |name: &str, value: &str, expected: &str| ParseError {
+        kind: ParseErrorKind::InvalidValue,
+        message: format!("Invalid window {}: '{}'", name, value),
+        span,
+        suggestion: Some(format!("Expected {}", expected)),
+    };
+
+    let mut config = WindowConfig::default();
+    for attr in node.attributes() {
+        let (name, value) = (attr.name(), attr.value());
+        let size = || {
+            value
+                .trim()
+                .parse::<u32>()
+                .map_err(|_| invalid(name, value, "a size in pixels, e.g. 800"))
+        };
+        let flag = || {
+            value
+                .trim()
+                .parse::<bool>()
+                .map_err(|_| invalid(name, value, "true or false"))
+        };
+
+        match name {
+            "title" => config.title = Some(value.to_string()),
+            "width" => config.width = Some(size()?),
+            "height" => config.height = Some(size()?),
+            "min_width" => config.min_width = Some(size()?),
+            "min_height" => config.min_height = Some(size()?),
+            "max_width" => config.max_width = Some(size()?),
+            "max_height" => config.max_height = Some(size()?),
+            "resizable" => config.resizable = Some(flag()?),
+            "decorations" => config.decorations = Some(flag()?),
+            _ => {
+                return Err(ParseError {
+                    kind: ParseErrorKind::UnknownAttribute,
+                    message: format!("Unknown window attribute '{}'", name),
+                    span,
+                    suggestion: Some(format!(
+                        "Valid attributes: {}",
+                        WindowConfig::ATTRIBUTES.join(", ")
+                    )),
+                });
+            }
+        }
+    }
+
+    config.validate().map_err(|message| ParseError {
+        kind: ParseErrorKind::InvalidValue,
+        message,
+        span,
+        suggestion: None,
+    })?;
+    Ok(config)
+}
+
+/// Validate that the `anchor` of every float is the id of a widget
+fn validate_float_anchors(root: &WidgetNode) -> Result<(), ParseError> {
+    fn check(node: &WidgetNode, ids: &std::collections::HashSet<&str>) -> Result<(), ParseError> {
+        if node.kind == WidgetKind::Float
+            && let Some(AttributeValue::Static(anchor)) = node.attributes.get("anchor")
+            && !ids.contains(anchor.as_str())
+        {
+            return Err(ParseError {
+                kind: ParseErrorKind::InvalidValue,
+                message: format!("Float is anchored to unknown id '{}'", anchor),
+                span: node.span,
+                suggestion: Some("Set anchor to the id of a widget of this view".to_string()),
+            });
+        }
+        node.children.iter().try_for_each(|child| check(child, ids))
+    }
+
+    let mut ids = std::collections::HashSet::new();
+    collect_widget_ids(root, &mut ids);
+    check(root, &ids)
 }
 
 /// Recursively validate widget nesting constraints
@@ -1168,6 +2690,54 @@ fn validate_nesting_constraints(
         }
     }
 
+    // Rule: Span must be inside Text and cannot contain widgets
+    if node.kind == WidgetKind::Span {
+        if parent_kind != Some(&WidgetKind::Text) {
+            return Err(ParseError {
+                kind: ParseErrorKind::InvalidChild,
+                message: "Span must be a direct child of Text".to_string(),
+                span: node.span,
+                suggestion: Some("Wrap this span in a <text>".to_string()),
+            });
+        }
+        if let Some(child) = node.children.first() {
+            return Err(ParseError {
+                kind: ParseErrorKind::InvalidChild,
+                message: "Span can only contain text".to_string(),
+                span: child.span,
+                suggestion: None,
+            });
+        }
+    }
+
+    // Rule: Text can only contain Span children (and inline text)
+    if node.kind == WidgetKind::Text
+        && let Some(child) = node.children.iter().find(|c| c.kind != WidgetKind::Span)
+    {
+        return Err(ParseError {
+            kind: ParseErrorKind::InvalidChild,
+            message: format!(
+                "Text can only contain <span> elements, found <{}>",
+                child.kind
+            ),
+            span: child.span,
+            suggestion: Some("Move the widget out of the <text>".to_string()),
+        });
+    }
+
+    // Rule: an attached ContextMenu (single <menu> child) must be inside the widget it targets
+    if is_attached_context_menu(node) {
+        return Err(ParseError {
+            kind: ParseErrorKind::InvalidChild,
+            message: "<context_menu> with only a <menu> must be placed inside a widget".to_string(),
+            span: node.span,
+            suggestion: Some(
+                "Move the <context_menu> inside the widget it applies to, or add an underlay widget before the <menu>"
+                    .to_string(),
+            ),
+        });
+    }
+
     // Recurse
     for child in &node.children {
         validate_nesting_constraints(child, Some(&node.kind))?;
@@ -1209,6 +2779,82 @@ fn validate_widget_versions_strict(
     Ok(())
 }
 
+/// Split event parameters at top-level commas
+///
+/// Commas inside `{...}`, `(...)` or quotes do not separate parameters. Returns each
+/// trimmed parameter with its byte offset in `value`.
+fn split_event_params(value: &str) -> Vec<(usize, &str)> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+    let mut start = 0;
+
+    for (index, c) in value.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '{' | '(') => depth += 1,
+            (None, '}' | ')') => depth = depth.saturating_sub(1),
+            (None, ',') if depth == 0 => {
+                parts.push((start, &value[start..index]));
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push((start, &value[start..]));
+
+    parts
+        .into_iter()
+        .map(|(offset, part)| {
+            let trimmed = part.trim_start();
+            (offset + part.len() - trimmed.len(), trimmed.trim_end())
+        })
+        .collect()
+}
+
+/// Parse a single event parameter: `'value'`, `{expression}` or `expression`
+///
+/// `param` starts at byte `offset` of the attribute `value`, located at `value_span`.
+fn parse_event_param(
+    value: &str,
+    offset: usize,
+    param: &str,
+    value_span: Span,
+) -> Option<BindingExpr> {
+    // Check for single-quoted string: 'value'
+    if param.starts_with('\'') && param.ends_with('\'') && param.len() >= 2 {
+        let quoted_value = &param[1..param.len() - 1];
+        // Create a static string binding expression
+        return Some(BindingExpr {
+            expr: Expr::Literal(LiteralExpr::String(quoted_value.to_string())),
+            span: sub_span(value_span, value, offset, offset + param.len()),
+        });
+    }
+
+    // Remove surrounding braces if present: {item.id} -> item.id
+    let param_clean = param.trim_matches('{').trim_matches('}');
+    let start = offset + (param.len() - param.trim_start_matches('{').len());
+    let span = sub_span(value_span, value, start, start + param_clean.len());
+
+    // Parse parameter as binding expression
+    crate::expr::tokenize_binding_expr(param_clean, span.start, span.line, span.column).ok()
+}
+
+/// Span of `value[start..end]`, for an attribute `value` located at `value_span`
+fn sub_span(value_span: Span, value: &str, start: usize, end: usize) -> Span {
+    let column = value
+        .get(..start)
+        .map_or(0, |before| before.chars().count());
+    Span::new(
+        value_span.start + start,
+        value_span.start + end,
+        value_span.line,
+        value_span.column + column as u32,
+    )
+}
+
 /// Parse comma-separated list into `Vec<String>`
 pub fn parse_comma_separated(value: &str) -> Vec<String> {
     value
@@ -1251,6 +2897,9 @@ fn parse_attribute_value(value: &str, span: Span) -> Result<AttributeValue, Pars
         let mut remaining = value;
 
         while let Some(start_pos) = remaining.find('{') {
+            // Offset of `remaining` in `value`
+            let consumed = value.len() - remaining.len();
+
             // Add literal before {
             if start_pos > 0 {
                 parts.push(InterpolatedPart::Literal(
@@ -1264,24 +2913,16 @@ fn parse_attribute_value(value: &str, span: Span) -> Result<AttributeValue, Pars
                 let expr_end = start_pos + end_pos;
                 let expr_str = &remaining[expr_start..expr_end];
 
-                // Parse the expression
-                let binding_expr = tokenize_binding_expr(
-                    expr_str,
-                    span.start + expr_start,
-                    span.line,
-                    span.column + expr_start as u32,
-                )
-                .map_err(|e| ParseError {
-                    kind: ParseErrorKind::InvalidExpression,
-                    message: format!("Invalid expression: {}", e),
-                    span: Span::new(
-                        span.start + expr_start,
-                        span.start + expr_end,
-                        span.line,
-                        span.column + expr_start as u32,
-                    ),
-                    suggestion: None,
-                })?;
+                // Parse the expression, locating errors at the offending token
+                let expr_span = sub_span(span, value, consumed + expr_start, consumed + expr_end);
+                let binding_expr =
+                    parse_binding_expr(expr_str, expr_span.start, span.line, expr_span.column)
+                        .map_err(|e| ParseError {
+                            kind: ParseErrorKind::InvalidExpression,
+                            message: format!("Invalid expression: {}", e.message),
+                            span: e.span,
+                            suggestion: e.suggestion,
+                        })?;
 
                 parts.push(InterpolatedPart::Binding(binding_expr));
 
@@ -1321,134 +2962,300 @@ fn parse_attribute_value(value: &str, span: Span) -> Result<AttributeValue, Pars
 
 /// Extract span information from roxmltree node
 fn get_span(node: Node, source: &str) -> Span {
-    let range = node.range();
-
-    // Calculate line and column from byte offset
-    let (line, col) = calculate_line_col(source, range.start);
-
-    Span {
-        start: range.start,
-        end: range.end,
-        line,
-        column: col,
-    }
+    range_span(node.range(), source)
 }
 
-/// Calculate line and column from byte offset
-///
-/// Optimized to stop early once the target offset is reached.
-fn calculate_line_col(source: &str, offset: usize) -> (u32, u32) {
-    if offset == 0 {
-        return (1, 1);
-    }
-
-    let mut line = 1;
-    let mut col = 1;
+/// Span of a byte range of the preprocessed XML, located in the original source
+fn range_span(range: std::ops::Range<usize>, source: &str) -> Span {
+    SourceIndex::with(source, |index| {
+        let start = index.source_offset(range.start);
+        let (line, column) = index.line_col(source, start);
+        Span::new(start, index.source_offset(range.end), line, column)
+    })
+    .unwrap_or_else(|| {
+        // Calculate line and column from byte offset
+        let (line, column) = calculate_line_col(source, range.start);
+        Span::new(range.start, range.end, line, column)
+    })
+}
 
-    for (i, c) in source.char_indices().take(offset.saturating_add(1)) {
-        if i >= offset {
-            break;
-        }
-        if c == '\n' {
-            line += 1;
-            col = 1;
-        } else {
-            col += 1;
-        }
-    }
+/// Empty span at a 1-based line and column, as reported for XML syntax errors
+fn text_pos_span(source: &str, line: u32, column: u32) -> Span {
+    let line_start: usize = source
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1) as usize)
+        .map(str::len)
+        .sum();
+    let offset = source[line_start..]
+        .char_indices()
+        .nth(column.saturating_sub(1) as usize)
+        .map_or(source.len(), |(i, _)| line_start + i);
+    Span::new(offset, offset, line, column)
+}
 
-    (line, col)
+thread_local! {
+    /// Index of the source being parsed, see [`SourceIndex::install`]
+    static SOURCE_INDEX: RefCell<Option<SourceIndex>> = const { RefCell::new(None) };
 }
 
-/// Parse layout-related attributes from the attributes map
-fn parse_layout_attributes(
-    kind: &WidgetKind,
-    attributes: &HashMap<String, AttributeValue>,
-) -> Result<Option<crate::ir::layout::LayoutConstraints>, String> {
-    use crate::ir::layout::LayoutConstraints;
-    use crate::parser::style_parser::{
-        parse_alignment, parse_constraint, parse_float_attr, parse_int_attr, parse_justification,
-        parse_length_attr, parse_padding_attr, parse_spacing,
-    };
+/// Position information about the source being parsed
+///
+/// Spans are computed for every node and attribute, so scanning the source
+/// each time would make parsing quadratic in the file size.
+struct SourceIndex {
+    /// Address and length of the indexed source
+    address: usize,
+    len: usize,
+    /// Byte offsets at which the lines start
+    line_starts: Vec<usize>,
+    /// Offsets in the preprocessed XML right after each inserted state separator
+    inserted: Vec<usize>,
+}
 
-    let mut layout = LayoutConstraints::default();
-    let mut has_any = false;
+/// Restores the previously installed [`SourceIndex`] when dropped
+struct SourceIndexGuard {
+    previous: Option<SourceIndex>,
+}
 
-    // Parse width
-    if let Some(AttributeValue::Static(value)) = attributes.get("width") {
-        layout.width = Some(parse_length_attr(value)?);
-        has_any = true;
+impl SourceIndex {
+    /// Index the lines of `source`
+    fn new(source: &str, inserted: Vec<usize>) -> Self {
+        SourceIndex {
+            address: source.as_ptr() as usize,
+            len: source.len(),
+            line_starts: std::iter::once(0)
+                .chain(source.match_indices('\n').map(|(i, _)| i + 1))
+                .collect(),
+            inserted,
+        }
     }
 
-    // Parse height
-    if let Some(AttributeValue::Static(value)) = attributes.get("height") {
-        layout.height = Some(parse_length_attr(value)?);
-        has_any = true;
+    /// Make the index of `source` available to span computations until the guard is dropped
+    fn install(source: &str, inserted: Vec<usize>) -> SourceIndexGuard {
+        let index = SourceIndex::new(source, inserted);
+        let previous = SOURCE_INDEX.with(|cell| cell.replace(Some(index)));
+        SourceIndexGuard { previous }
     }
 
-    // Parse min/max constraints
-    if let Some(AttributeValue::Static(value)) = attributes.get("min_width") {
-        layout.min_width = Some(parse_constraint(value)?);
-        has_any = true;
+    /// Run `f` with the index of `source`, if installed
+    fn with<T>(source: &str, f: impl FnOnce(&SourceIndex) -> T) -> Option<T> {
+        SOURCE_INDEX.with(|cell| match &*cell.borrow() {
+            Some(index)
+                if index.address == source.as_ptr() as usize && index.len == source.len() =>
+            {
+                Some(f(index))
+            }
+            _ => None,
+        })
     }
 
-    if let Some(AttributeValue::Static(value)) = attributes.get("max_width") {
-        layout.max_width = Some(parse_constraint(value)?);
-        has_any = true;
+    /// Line and column of a byte offset of the indexed source
+    fn line_col(&self, source: &str, offset: usize) -> (u32, u32) {
+        let line = self.line_starts.partition_point(|&start| start <= offset);
+        let line_start = self.line_starts[line.saturating_sub(1)];
+        let column = source
+            .get(line_start..offset)
+            .map_or(0, |before| before.chars().count());
+        (line as u32, column as u32 + 1)
     }
 
-    if let Some(AttributeValue::Static(value)) = attributes.get("min_height") {
-        layout.min_height = Some(parse_constraint(value)?);
-        has_any = true;
+    /// Map an offset in the preprocessed XML to the original source
+    fn source_offset(&self, offset: usize) -> usize {
+        let shifts = self.inserted.partition_point(|&end| end <= offset);
+        offset.saturating_sub(shifts * (STATE_SEPARATOR.len() - 1))
     }
+}
 
-    if let Some(AttributeValue::Static(value)) = attributes.get("max_height") {
-        layout.max_height = Some(parse_constraint(value)?);
-        has_any = true;
+impl Drop for SourceIndexGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        SOURCE_INDEX.with(|cell| cell.replace(previous));
     }
+}
 
-    // Parse padding
-    if let Some(AttributeValue::Static(value)) = attributes.get("padding") {
-        layout.padding = Some(parse_padding_attr(value)?);
-        has_any = true;
-    }
+/// Calculate line and column from byte offset
+///
+/// The column counts chars from the start of the line. While parsing, spans
+/// use the [`SourceIndex`] instead.
+fn calculate_line_col(source: &str, offset: usize) -> (u32, u32) {
+    let before = &source.as_bytes()[..offset.min(source.len())];
+    let line_start = before
+        .iter()
+        .rposition(|&b| b == b'\n')
+        .map_or(0, |newline| newline + 1);
+    let line = 1 + before[..line_start].iter().filter(|&&b| b == b'\n').count();
+
+    // Count UTF-8 leading bytes only, so multi-byte chars count once
+    let col = 1 + before[line_start..]
+        .iter()
+        .filter(|&&b| b & 0xC0 != 0x80)
+        .count();
+
+    (line as u32, col as u32)
+}
 
-    // Parse spacing
-    if let Some(AttributeValue::Static(value)) = attributes.get("spacing") {
-        layout.spacing = Some(parse_spacing(value)?);
-        has_any = true;
+/// Attributes parsed by [`parse_layout_attributes`]
+const LAYOUT_ATTRIBUTES: &[&str] = &[
+    "width",
+    "height",
+    "min_width",
+    "max_width",
+    "min_height",
+    "max_height",
+    "padding",
+    "spacing",
+    "align_items",
+    "justify_content",
+    "align_self",
+    "align_x",
+    "align_y",
+    "align",
+    "direction",
+    "wrap",
+    "grow",
+    "aspect_ratio",
+    "overflow",
+    "position",
+    "top",
+    "right",
+    "bottom",
+    "left",
+    "z_index",
+];
+
+/// Attributes parsed by [`parse_style_attributes`]
+const STYLE_ATTRIBUTES: &[&str] = &[
+    "background",
+    "color",
+    "border_width",
+    "border_color",
+    "border_radius",
+    "border_style",
+    "border_top",
+    "border_right",
+    "border_bottom",
+    "border_left",
+    "outline",
+    "shadow",
+    "elevation",
+    "opacity",
+    "transform",
+];
+
+/// Whether `name` is a layout or style attribute of a widget of kind `kind`
+pub(crate) fn is_visual_attribute(kind: &WidgetKind, name: &str) -> bool {
+    match name {
+        // Text has its own `wrap`, tooltips and floats their own `position`
+        "wrap" => !matches!(kind, WidgetKind::Text),
+        "position" => !matches!(kind, WidgetKind::Tooltip | WidgetKind::Float),
+        _ => LAYOUT_ATTRIBUTES.contains(&name) || is_style_attribute(name),
     }
+}
 
-    // Parse alignment
-    if let Some(AttributeValue::Static(value)) = attributes.get("align_items") {
-        layout.align_items = Some(parse_alignment(value)?);
-        has_any = true;
-    }
+/// Whether `name` is a style attribute
+pub(crate) fn is_style_attribute(name: &str) -> bool {
+    STYLE_ATTRIBUTES.contains(&name)
+}
 
-    if let Some(AttributeValue::Static(value)) = attributes.get("justify_content") {
-        layout.justify_content = Some(parse_justification(value)?);
-        has_any = true;
-    }
+/// Layout and style of a widget of kind `kind` from the values of its attributes
+///
+/// Layout and style attributes bound to the model (`width="{sidebar_width}"`)
+/// are parsed once evaluated, when the view is built: by
+/// [`WidgetNode::with_visual_values`] in the interpreter and by this function
+/// in generated code. An empty value unsets the attribute, and attributes other
+/// than layout and style are ignored.
+///
+/// # Errors
+///
+/// Returns the error of the first invalid value.
+pub fn parse_visual_attributes<'a>(
+    kind: &WidgetKind,
+    values: impl IntoIterator<Item = (&'a str, String)>,
+) -> Result<
+    (
+        Option<crate::ir::layout::LayoutConstraints>,
+        Option<StyleProperties>,
+    ),
+    String,
+> {
+    let attributes: HashMap<String, AttributeValue> = values
+        .into_iter()
+        .filter(|(name, value)| is_visual_attribute(kind, name) && !value.trim().is_empty())
+        .map(|(name, value)| (name.to_string(), AttributeValue::Static(value)))
+        .collect();
+    Ok((
+        parse_layout_attributes(kind, &attributes)?,
+        parse_style_attributes(&attributes)?,
+    ))
+}
 
-    if let Some(AttributeValue::Static(value)) = attributes.get("align_self") {
-        layout.align_self = Some(parse_alignment(value)?);
-        has_any = true;
-    }
+/// Parse layout-related attributes from the attributes map
+///
+/// Bound values are skipped: they are parsed once evaluated, see
+/// [`parse_visual_attributes`].
+pub(crate) fn parse_layout_attributes(
+    kind: &WidgetKind,
+    attributes: &HashMap<String, AttributeValue>,
+) -> Result<Option<crate::ir::layout::LayoutConstraints>, String> {
+    use crate::ir::layout::LayoutConstraints;
+    use crate::parser::style_parser::{
+        parse_alignment, parse_aspect_ratio, parse_constraint, parse_float_attr, parse_grow,
+        parse_int_attr, parse_justification, parse_length_attr, parse_padding_attr, parse_spacing,
+        parse_wrap,
+    };
 
-    // Parse direct alignment (align_x, align_y)
-    if let Some(AttributeValue::Static(value)) = attributes.get("align_x") {
-        layout.align_x = Some(parse_alignment(value)?);
-        has_any = true;
-    }
+    let mut layout = LayoutConstraints::default();
+    let mut has_any = false;
+    let mut align = None;
 
-    if let Some(AttributeValue::Static(value)) = attributes.get("align_y") {
-        layout.align_y = Some(parse_alignment(value)?);
+    // A single pass over the attributes: most widgets have few of them
+    for (name, value) in attributes {
+        let AttributeValue::Static(value) = value else {
+            continue;
+        };
+        match name.as_str() {
+            // Size and min/max constraints
+            "width" => layout.width = Some(parse_length_attr(value)?),
+            "height" => layout.height = Some(parse_length_attr(value)?),
+            "min_width" => layout.min_width = Some(parse_constraint(value)?),
+            "max_width" => layout.max_width = Some(parse_constraint(value)?),
+            "min_height" => layout.min_height = Some(parse_constraint(value)?),
+            "max_height" => layout.max_height = Some(parse_constraint(value)?),
+            // Padding and spacing
+            "padding" => layout.padding = Some(parse_padding_attr(value)?),
+            "spacing" => layout.spacing = Some(parse_spacing(value)?),
+            // Alignment, including direct alignment (align_x, align_y)
+            "align_items" => layout.align_items = Some(parse_alignment(value)?),
+            "justify_content" => layout.justify_content = Some(parse_justification(value)?),
+            "align_self" => layout.align_self = Some(parse_alignment(value)?),
+            "align_x" => layout.align_x = Some(parse_alignment(value)?),
+            "align_y" => layout.align_y = Some(parse_alignment(value)?),
+            "align" => align = Some(parse_alignment(value)?),
+            "direction" => layout.direction = Some(crate::ir::layout::Direction::parse(value)?),
+            // Wrapping of rows and columns, and share of the free space of their children
+            // Text has its own `wrap="word|glyph|none"`
+            "wrap" if !matches!(kind, WidgetKind::Text) => layout.wrap = Some(parse_wrap(value)?),
+            "grow" => layout.grow = Some(parse_grow(value)?),
+            // Proportions and overflow
+            "aspect_ratio" => layout.aspect_ratio = Some(parse_aspect_ratio(value)?),
+            "overflow" => layout.overflow = Some(crate::ir::layout::Overflow::parse(value)?),
+            // Position (skip for Tooltip and Float - they have their own position attribute)
+            // and offsets
+            "position" if !matches!(kind, WidgetKind::Tooltip | WidgetKind::Float) => {
+                layout.position = Some(crate::ir::layout::Position::parse(value)?)
+            }
+            "top" => layout.top = Some(parse_float_attr(value, "top")?),
+            "right" => layout.right = Some(parse_float_attr(value, "right")?),
+            "bottom" => layout.bottom = Some(parse_float_attr(value, "bottom")?),
+            "left" => layout.left = Some(parse_float_attr(value, "left")?),
+            "z_index" => layout.z_index = Some(parse_int_attr(value, "z_index")?),
+            _ => continue,
+        }
         has_any = true;
     }
 
-    // Parse align shorthand (sets both align_items and justify_content)
-    if let Some(AttributeValue::Static(value)) = attributes.get("align") {
-        let alignment = parse_alignment(value)?;
+    // Align shorthand (sets both align_items and justify_content), overriding them
+    if let Some(alignment) = align {
         layout.align_items = Some(alignment);
         layout.justify_content = Some(match alignment {
             crate::ir::layout::Alignment::Start => crate::ir::layout::Justification::Start,
@@ -1456,48 +3263,6 @@ fn parse_layout_attributes(
             crate::ir::layout::Alignment::End => crate::ir::layout::Justification::End,
             crate::ir::layout::Alignment::Stretch => crate::ir::layout::Justification::Center,
         });
-        has_any = true;
-    }
-
-    // Parse direction
-    if let Some(AttributeValue::Static(value)) = attributes.get("direction") {
-        layout.direction = Some(crate::ir::layout::Direction::parse(value)?);
-        has_any = true;
-    }
-
-    // Parse position (skip for Tooltip - it has its own position attribute)
-    if !matches!(kind, WidgetKind::Tooltip)
-        && let Some(AttributeValue::Static(value)) = attributes.get("position")
-    {
-        layout.position = Some(crate::ir::layout::Position::parse(value)?);
-        has_any = true;
-    }
-
-    // Parse position offsets
-    if let Some(AttributeValue::Static(value)) = attributes.get("top") {
-        layout.top = Some(parse_float_attr(value, "top")?);
-        has_any = true;
-    }
-
-    if let Some(AttributeValue::Static(value)) = attributes.get("right") {
-        layout.right = Some(parse_float_attr(value, "right")?);
-        has_any = true;
-    }
-
-    if let Some(AttributeValue::Static(value)) = attributes.get("bottom") {
-        layout.bottom = Some(parse_float_attr(value, "bottom")?);
-        has_any = true;
-    }
-
-    if let Some(AttributeValue::Static(value)) = attributes.get("left") {
-        layout.left = Some(parse_float_attr(value, "left")?);
-        has_any = true;
-    }
-
-    // Parse z-index
-    if let Some(AttributeValue::Static(value)) = attributes.get("z_index") {
-        layout.z_index = Some(parse_int_attr(value, "z_index")?);
-        has_any = true;
     }
 
     // Validate the layout
@@ -1512,15 +3277,27 @@ fn parse_layout_attributes(
 }
 
 /// Parse style-related attributes from the attributes map
-fn parse_style_attributes(
+///
+/// Bound values are skipped: they are parsed once evaluated, see
+/// [`parse_visual_attributes`].
+pub(crate) fn parse_style_attributes(
     attributes: &HashMap<String, AttributeValue>,
 ) -> Result<Option<crate::ir::style::StyleProperties>, String> {
     use crate::parser::style_parser::{
         build_border, build_style_properties, parse_background_attr, parse_border_color,
-        parse_border_radius, parse_border_style, parse_border_width, parse_color_attr,
-        parse_opacity, parse_shadow_attr, parse_transform,
+        parse_border_radius, parse_border_side, parse_border_style, parse_border_width,
+        parse_color_attr, parse_elevation, parse_opacity, parse_outline, parse_shadow_attr,
+        parse_transform,
     };
 
+    // Most widgets have no style attribute: skip the lookups below for them
+    if !attributes
+        .keys()
+        .any(|name| STYLE_ATTRIBUTES.contains(&name.as_str()))
+    {
+        return Ok(None);
+    }
+
     let mut background = None;
     let mut color = None;
     let mut border_width = None;
@@ -1530,6 +3307,9 @@ fn parse_style_attributes(
     let mut shadow = None;
     let mut opacity = None;
     let mut transform = None;
+    let mut border_sides = crate::ir::style::BorderSides::default();
+    let mut outline = None;
+    let mut elevation = None;
     let mut has_any = false;
 
     // Parse background
@@ -1565,12 +3345,37 @@ fn parse_style_attributes(
         has_any = true;
     }
 
+    // Parse per-side borders
+    for (name, side) in [
+        ("border_top", &mut border_sides.top),
+        ("border_right", &mut border_sides.right),
+        ("border_bottom", &mut border_sides.bottom),
+        ("border_left", &mut border_sides.left),
+    ] {
+        if let Some(AttributeValue::Static(value)) = attributes.get(name) {
+            *side = Some(parse_border_side(value)?);
+            has_any = true;
+        }
+    }
+
+    // Parse outline
+    if let Some(AttributeValue::Static(value)) = attributes.get("outline") {
+        outline = Some(parse_outline(value)?);
+        has_any = true;
+    }
+
     // Parse shadow
     if let Some(AttributeValue::Static(value)) = attributes.get("shadow") {
         shadow = Some(parse_shadow_attr(value)?);
         has_any = true;
     }
 
+    // Parse elevation
+    if let Some(AttributeValue::Static(value)) = attributes.get("elevation") {
+        elevation = Some(parse_elevation(value)?);
+        has_any = true;
+    }
+
     // Parse opacity
     if let Some(AttributeValue::Static(value)) = attributes.get("opacity") {
         opacity = Some(parse_opacity(value)?);
@@ -1585,7 +3390,11 @@ fn parse_style_attributes(
 
     if has_any {
         let border = build_border(border_width, border_color, border_radius, border_style)?;
-        let style = build_style_properties(background, color, border, shadow, opacity, transform)?;
+        let mut style =
+            build_style_properties(background, color, border, shadow, opacity, transform)?;
+        style.border_sides = (!border_sides.is_empty()).then_some(border_sides);
+        style.outline = outline;
+        style.elevation = elevation;
         Ok(Some(style))
     } else {
         Ok(None)
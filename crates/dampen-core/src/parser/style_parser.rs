@@ -4,7 +4,8 @@
 
 use crate::ir::layout::{Alignment, Justification, Length, Padding};
 use crate::ir::style::{
-    Background, Border, BorderRadius, BorderStyle, Color, Shadow, StyleProperties, Transform,
+    Background, Border, BorderRadius, BorderSide, BorderStyle, Color, Outline, Shadow,
+    StyleProperties, Transform,
 };
 use crate::parser::gradient::parse_gradient;
 
@@ -73,6 +74,24 @@ pub fn parse_border_style(s: &str) -> Result<BorderStyle, String> {
     BorderStyle::parse(s)
 }
 
+/// Parse the border of a single side: `border_top="1 #cccccc"`
+pub fn parse_border_side(s: &str) -> Result<BorderSide, String> {
+    BorderSide::parse(s)
+}
+
+/// Parse outline: `outline="2 #3498db 1"`
+pub fn parse_outline(s: &str) -> Result<Outline, String> {
+    Outline::parse(s)
+}
+
+/// Parse elevation: a level from 0 (flat) to 5
+pub fn parse_elevation(s: &str) -> Result<u8, String> {
+    match s.trim().parse::<u8>() {
+        Ok(level) if level <= 5 => Ok(level),
+        _ => Err(format!("Invalid elevation: '{}'. Expected 0 to 5", s)),
+    }
+}
+
 /// Parse opacity
 pub fn parse_opacity(s: &str) -> Result<f32, String> {
     let value: f32 = s.parse().map_err(|_| format!("Invalid opacity: {}", s))?;
@@ -128,6 +147,48 @@ pub fn parse_fill_portion(s: &str) -> Result<u8, String> {
     Ok(value)
 }
 
+/// Parse wrap value: "true" or "false"
+pub fn parse_wrap(s: &str) -> Result<bool, String> {
+    match s.trim() {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        _ => Err(format!("Invalid wrap: '{}'. Expected true or false", s)),
+    }
+}
+
+/// Parse grow value: portions of the free space (1-255)
+pub fn parse_grow(s: &str) -> Result<u8, String> {
+    match s.trim().parse::<u8>() {
+        Ok(value) if value > 0 => Ok(value),
+        _ => Err(format!("grow must be 1-255, got {}", s)),
+    }
+}
+
+/// Parse aspect ratio: "16:9", "4/3" or "1.5"
+pub fn parse_aspect_ratio(s: &str) -> Result<f32, String> {
+    let invalid = || {
+        format!(
+            "Invalid aspect_ratio: '{}'. Expected width:height (16:9) or a number",
+            s
+        )
+    };
+
+    let ratio = match s.trim().split_once([':', '/']) {
+        Some((width, height)) => {
+            let width: f32 = width.trim().parse().map_err(|_| invalid())?;
+            let height: f32 = height.trim().parse().map_err(|_| invalid())?;
+            width / height
+        }
+        None => s.trim().parse().map_err(|_| invalid())?,
+    };
+
+    if ratio.is_finite() && ratio > 0.0 {
+        Ok(ratio)
+    } else {
+        Err(format!("aspect_ratio must be positive, got {}", s))
+    }
+}
+
 /// Parse percentage value
 pub fn parse_percentage(s: &str) -> Result<f32, String> {
     if !s.ends_with('%') {
@@ -171,6 +232,9 @@ pub fn build_style_properties(
         shadow,
         opacity,
         transform,
+        border_sides: None,
+        outline: None,
+        elevation: None,
     };
 
     style.validate()?;
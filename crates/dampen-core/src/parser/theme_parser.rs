@@ -3,24 +3,29 @@
 //! This module provides parsers for theme definitions and style classes.
 
 use crate::ir::layout::LayoutConstraints;
+use crate::ir::span::Span;
 use crate::ir::style::{Color, StyleProperties};
 use crate::ir::theme::{
-    FontWeight, SpacingScale, StyleClass, Theme, ThemeDocument, ThemeError, ThemeErrorKind,
-    ThemePalette, Typography, WidgetState,
+    FontWeight, ShadowScale, SpacingScale, StyleClass, Theme, ThemeDocument, ThemeError,
+    ThemeErrorKind, ThemePalette, Typography, WidgetState, validate_theme,
 };
 use std::collections::HashMap;
 
 /// Parse a complete theme.dampen document
+///
+/// Errors carry the span of the element they were found in.
 pub fn parse_theme_document(xml: &str) -> Result<ThemeDocument, ThemeError> {
     let doc = roxmltree::Document::parse(xml).map_err(|e| ThemeError {
         kind: ThemeErrorKind::MissingPaletteColor,
         message: format!("THEME_003: Failed to parse XML: {}", e),
+        span: Some(super::text_pos_span(xml, e.pos().row, e.pos().col)),
     })?;
 
     // Get the first child element (the <dampen> root)
     let root = doc.root().first_child().ok_or_else(|| ThemeError {
         kind: ThemeErrorKind::MissingPaletteColor,
         message: "THEME_003: No root element found".to_string(),
+        span: None,
     })?;
 
     // Verify root element
@@ -28,11 +33,13 @@ pub fn parse_theme_document(xml: &str) -> Result<ThemeDocument, ThemeError> {
         return Err(ThemeError {
             kind: ThemeErrorKind::MissingPaletteColor,
             message: "THEME_003: Root element must be <dampen>".to_string(),
+            span: Some(node_span(root)),
         });
     }
 
     let mut themes = HashMap::new();
     let mut default_theme = None;
+    let mut default_theme_span = None;
     let mut follow_system = true;
 
     // Parse child elements
@@ -60,8 +67,13 @@ pub fn parse_theme_document(xml: &str) -> Result<ThemeDocument, ThemeError> {
                                     "THEME_005: Duplicate theme name: '{}'",
                                     theme.name
                                 ),
+                                span: Some(node_span(grandchild)),
                             });
                         }
+                        validate_theme(&theme.name, &theme).map_err(|error| ThemeError {
+                            span: Some(node_span(grandchild)),
+                            ..error
+                        })?;
                         themes.insert(theme.name.clone(), theme);
                     }
                 }
@@ -69,6 +81,7 @@ pub fn parse_theme_document(xml: &str) -> Result<ThemeDocument, ThemeError> {
             "default_theme" => {
                 if let Some(name) = child.attribute("name") {
                     default_theme = Some(name.to_string());
+                    default_theme_span = Some(node_span(child));
                 }
             }
             "follow_system" => {
@@ -86,10 +99,23 @@ pub fn parse_theme_document(xml: &str) -> Result<ThemeDocument, ThemeError> {
         follow_system,
     };
 
-    document.validate()?;
+    document.validate().map_err(|error| ThemeError {
+        span: match error.kind {
+            ThemeErrorKind::InvalidDefaultTheme => default_theme_span,
+            _ => Some(node_span(root)),
+        },
+        ..error
+    })?;
     Ok(document)
 }
 
+/// Span of an element of a theme file
+fn node_span(node: roxmltree::Node) -> Span {
+    let range = node.range();
+    let position = node.document().text_pos_at(range.start);
+    Span::new(range.start, range.end, position.row, position.col)
+}
+
 /// Parse a theme node (simplified version for ThemeDocument parsing)
 fn parse_theme_from_node_simple(node: roxmltree::Node) -> Result<Theme, ThemeError> {
     let name = node
@@ -101,6 +127,7 @@ fn parse_theme_from_node_simple(node: roxmltree::Node) -> Result<Theme, ThemeErr
 
     let mut palette_attrs = HashMap::new();
     let mut typography_attrs = HashMap::new();
+    let mut shadow_attrs = HashMap::new();
     let mut spacing_unit = None;
 
     // Parse child elements
@@ -127,18 +154,31 @@ fn parse_theme_from_node_simple(node: roxmltree::Node) -> Result<Theme, ThemeErr
                     spacing_unit = unit.parse::<f32>().ok();
                 }
             }
+            "shadows" => {
+                for attr in child.attributes() {
+                    shadow_attrs.insert(attr.name().to_string(), attr.value().to_string());
+                }
+            }
             _ => {}
         }
     }
 
+    // Span of the section holding an invalid value, or of the theme
+    let section_span = |tag: &str| {
+        let section = node.children().find(|child| child.has_tag_name(tag));
+        Some(node_span(section.unwrap_or(node)))
+    };
+
     let palette = parse_palette(&palette_attrs).map_err(|e| ThemeError {
         kind: ThemeErrorKind::MissingPaletteColor,
         message: format!("THEME_003: Invalid palette: {}", e),
+        span: section_span("palette"),
     })?;
 
     let typography = parse_typography(&typography_attrs).map_err(|e| ThemeError {
         kind: ThemeErrorKind::MissingPaletteColor,
         message: format!("THEME_003: Invalid typography: {}", e),
+        span: section_span("typography"),
     })?;
 
     let spacing = SpacingScale { unit: spacing_unit };
@@ -146,6 +186,13 @@ fn parse_theme_from_node_simple(node: roxmltree::Node) -> Result<Theme, ThemeErr
     spacing.validate().map_err(|e| ThemeError {
         kind: ThemeErrorKind::MissingPaletteColor,
         message: format!("THEME_003: Invalid spacing: {}", e),
+        span: section_span("spacing"),
+    })?;
+
+    let shadows = parse_shadow_scale(&shadow_attrs).map_err(|e| ThemeError {
+        kind: ThemeErrorKind::MissingPaletteColor,
+        message: format!("THEME_003: Invalid shadows: {}", e),
+        span: section_span("shadows"),
     })?;
 
     let theme = Theme {
@@ -153,6 +200,7 @@ fn parse_theme_from_node_simple(node: roxmltree::Node) -> Result<Theme, ThemeErr
         palette,
         typography,
         spacing,
+        shadows,
         base_styles: HashMap::new(),
         extends,
     };
@@ -177,6 +225,7 @@ pub fn parse_theme(
         palette,
         typography,
         spacing,
+        shadows: ShadowScale::default(),
         base_styles: HashMap::new(),
         extends: extends.clone(),
     };
@@ -241,6 +290,25 @@ pub fn parse_typography(attrs: &HashMap<String, String>) -> Result<Typography, S
         None
     };
 
+    let scale_ratio = if let Some(s) = attrs.get("scale_ratio") {
+        Some(s.parse().map_err(|_| "Invalid scale_ratio")?)
+    } else {
+        None
+    };
+
+    // Font files: fonts="fonts/Inter-Regular.ttf, fonts/Inter-Bold.ttf"
+    let fonts = attrs
+        .get("fonts")
+        .map(|fonts| {
+            fonts
+                .split(',')
+                .map(str::trim)
+                .filter(|font| !font.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+
     Ok(Typography {
         font_family,
         font_size_base,
@@ -248,9 +316,33 @@ pub fn parse_typography(attrs: &HashMap<String, String>) -> Result<Typography, S
         font_size_large,
         font_weight,
         line_height,
+        scale_ratio,
+        fonts,
     })
 }
 
+/// Parse the shadows of the elevation levels: `<shadows level1="0 1 3 #0000001f" />`
+pub fn parse_shadow_scale(attrs: &HashMap<String, String>) -> Result<ShadowScale, String> {
+    let mut shadows = ShadowScale::default();
+
+    for (name, value) in attrs {
+        let level = name
+            .strip_prefix("level")
+            .and_then(|level| level.parse::<u8>().ok())
+            .filter(|level| (1..=ShadowScale::MAX_LEVEL).contains(level))
+            .ok_or_else(|| {
+                format!(
+                    "Unknown shadow level '{}'. Expected level1 to level{}",
+                    name,
+                    ShadowScale::MAX_LEVEL
+                )
+            })?;
+        shadows.levels[usize::from(level) - 1] = Some(crate::ir::style::Shadow::parse(value)?);
+    }
+
+    Ok(shadows)
+}
+
 /// Parse a style class definition
 pub fn parse_style_class(
     name: String,
@@ -331,7 +423,35 @@ pub fn parse_style_properties_from_attrs(
         transform = Some(parse_transform(value)?);
     }
 
-    build_style_properties(background, color, border, shadow, opacity, transform)
+    let mut style = build_style_properties(background, color, border, shadow, opacity, transform)?;
+
+    // Parse per-side borders and outline
+    let border_sides = crate::ir::style::BorderSides {
+        top: attrs
+            .get("border_top")
+            .map(|v| parse_border_side(v))
+            .transpose()?,
+        right: attrs
+            .get("border_right")
+            .map(|v| parse_border_side(v))
+            .transpose()?,
+        bottom: attrs
+            .get("border_bottom")
+            .map(|v| parse_border_side(v))
+            .transpose()?,
+        left: attrs
+            .get("border_left")
+            .map(|v| parse_border_side(v))
+            .transpose()?,
+    };
+    style.border_sides = (!border_sides.is_empty()).then_some(border_sides);
+    style.outline = attrs.get("outline").map(|v| parse_outline(v)).transpose()?;
+    style.elevation = attrs
+        .get("elevation")
+        .map(|v| parse_elevation(v))
+        .transpose()?;
+
+    Ok(style)
 }
 
 /// Parse layout constraints from attributes
@@ -408,6 +528,28 @@ pub fn parse_layout_constraints(
         has_any = true;
     }
 
+    // Parse wrapping and grow
+    if let Some(value) = attrs.get("wrap") {
+        constraints.wrap = Some(parse_wrap(value)?);
+        has_any = true;
+    }
+
+    if let Some(value) = attrs.get("grow") {
+        constraints.grow = Some(parse_grow(value)?);
+        has_any = true;
+    }
+
+    // Parse proportions and overflow
+    if let Some(value) = attrs.get("aspect_ratio") {
+        constraints.aspect_ratio = Some(parse_aspect_ratio(value)?);
+        has_any = true;
+    }
+
+    if let Some(value) = attrs.get("overflow") {
+        constraints.overflow = Some(crate::ir::layout::Overflow::parse(value)?);
+        has_any = true;
+    }
+
     if has_any {
         constraints.validate()?;
         Ok(Some(constraints))
@@ -549,6 +691,7 @@ pub fn parse_theme_from_node(
 
     let mut palette_attrs = HashMap::new();
     let mut typography_attrs = HashMap::new();
+    let mut shadow_attrs = HashMap::new();
     let mut spacing_unit = None;
 
     // Parse child elements
@@ -567,6 +710,10 @@ pub fn parse_theme_from_node(
             for attr in child.attributes() {
                 typography_attrs.insert(attr.name().to_string(), attr.value().to_string());
             }
+        } else if tag == "shadows" {
+            for attr in child.attributes() {
+                shadow_attrs.insert(attr.name().to_string(), attr.value().to_string());
+            }
         } else if tag == "spacing"
             && let Some(unit) = child.attribute("unit")
         {
@@ -582,6 +729,10 @@ pub fn parse_theme_from_node(
         spacing_unit,
         extends,
     )
+    .and_then(|mut theme| {
+        theme.shadows = parse_shadow_scale(&shadow_attrs)?;
+        Ok(theme)
+    })
     .map_err(|e| ParseError {
         kind: ParseErrorKind::InvalidValue,
         message: format!("Failed to parse theme: {}", e),
@@ -672,6 +823,10 @@ pub fn parse_style_class_from_node(
             "justify_content",
             "align_self",
             "direction",
+            "wrap",
+            "grow",
+            "aspect_ratio",
+            "overflow",
         ];
 
         if layout_attr_names.contains(&key) {
@@ -740,6 +895,10 @@ pub fn parse_style_class_from_node(
                 | "justify_content"
                 | "align_self"
                 | "direction"
+                | "wrap"
+                | "grow"
+                | "aspect_ratio"
+                | "overflow"
         )
     }) {
         layout = parse_layout_constraints(&base_attrs).map_err(|e| ParseError {
@@ -767,6 +926,10 @@ pub fn parse_style_class_from_node(
                         | "justify_content"
                         | "align_self"
                         | "direction"
+                        | "wrap"
+                        | "grow"
+                        | "aspect_ratio"
+                        | "overflow"
                 )
             })
             .cloned()
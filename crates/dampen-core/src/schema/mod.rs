@@ -54,7 +54,13 @@ pub const COMMON_STYLE_ATTRIBUTES: &[&str] = &[
     "border_width",
     "border_radius",
     "border_style",
+    "border_top",
+    "border_right",
+    "border_bottom",
+    "border_left",
+    "outline",
     "shadow",
+    "elevation",
     "opacity",
     "transform",
     "style",
@@ -82,6 +88,10 @@ pub const COMMON_LAYOUT_ATTRIBUTES: &[&str] = &[
     "align_y",
     "align_self",
     "direction",
+    "wrap",
+    "grow",
+    "aspect_ratio",
+    "overflow",
     "position",
     "top",
     "right",
@@ -106,10 +116,46 @@ pub const COMMON_EVENTS: &[&str] = &[
     "on_scroll",
 ];
 
+/// Events of the text widget: the common events plus `on_link` for rich text links.
+pub const TEXT_EVENTS: &[&str] = &[
+    "on_click",
+    "on_press",
+    "on_release",
+    "on_change",
+    "on_input",
+    "on_submit",
+    "on_select",
+    "on_toggle",
+    "on_scroll",
+    "on_link",
+];
+
+/// Drag-and-drop attributes accepted by every widget.
+///
+/// Any widget repeated by a `<for>` loop can be made `draggable` to reorder the list.
+pub const DRAG_ATTRIBUTES: &[&str] = &["draggable", "on_drag_start", "on_drag_over", "on_drop"];
+
+/// Context menu attributes accepted by every widget.
+///
+/// `on_context_menu` is emitted when the widget is right-clicked.
+pub const CONTEXT_MENU_ATTRIBUTES: &[&str] = &["on_context_menu"];
+
+/// Accessibility attributes accepted by every widget.
+///
+/// They populate [`crate::ir::Accessibility`] rather than the widget's attributes.
+pub const ACCESSIBILITY_ATTRIBUTES: &[&str] = &["aria_label", "role", "described_by"];
+
+/// Memoization attribute accepted by every widget.
+///
+/// `memo="true"` lets the interpreted builder reuse the subtree until the values
+/// it binds change.
+pub const MEMO_ATTRIBUTES: &[&str] = &["memo"];
+
 impl WidgetSchema {
     /// Returns a `HashSet` containing all valid attributes for this schema.
     ///
-    /// This combines required, optional, events, style, and layout attributes.
+    /// This combines required, optional, events, style, layout, drag, context menu,
+    /// accessibility and memoization attributes.
     pub fn all_valid(&self) -> HashSet<&'static str> {
         let mut set = HashSet::new();
         set.extend(self.required.iter().cloned());
@@ -117,6 +163,10 @@ impl WidgetSchema {
         set.extend(self.events.iter().cloned());
         set.extend(self.style_attributes.iter().cloned());
         set.extend(self.layout_attributes.iter().cloned());
+        set.extend(DRAG_ATTRIBUTES.iter().cloned());
+        set.extend(CONTEXT_MENU_ATTRIBUTES.iter().cloned());
+        set.extend(ACCESSIBILITY_ATTRIBUTES.iter().cloned());
+        set.extend(MEMO_ATTRIBUTES.iter().cloned());
         set
     }
 
@@ -128,6 +178,10 @@ impl WidgetSchema {
         names.extend_from_slice(self.events);
         names.extend_from_slice(self.style_attributes);
         names.extend_from_slice(self.layout_attributes);
+        names.extend_from_slice(DRAG_ATTRIBUTES);
+        names.extend_from_slice(CONTEXT_MENU_ATTRIBUTES);
+        names.extend_from_slice(ACCESSIBILITY_ATTRIBUTES);
+        names.extend_from_slice(MEMO_ATTRIBUTES);
         names
     }
 }
@@ -147,14 +201,54 @@ pub fn get_widget_schema(kind: &WidgetKind) -> WidgetSchema {
     match kind {
         WidgetKind::Text => WidgetSchema {
             required: &["value"],
-            optional: &["size", "weight", "color"],
-            events: COMMON_EVENTS,
+            optional: &[
+                "size",
+                "weight",
+                "color",
+                "markdown",
+                "font",
+                "wrap",
+                "truncate",
+                "max_lines",
+            ],
+            events: TEXT_EVENTS,
+            style_attributes: COMMON_STYLE_ATTRIBUTES,
+            layout_attributes: COMMON_LAYOUT_ATTRIBUTES,
+        },
+        WidgetKind::Span => WidgetSchema {
+            required: &[],
+            optional: &[
+                "value",
+                "color",
+                "size",
+                "weight",
+                "italic",
+                "underline",
+                "strikethrough",
+                "link",
+            ],
+            events: &[],
+            style_attributes: &[],
+            layout_attributes: &[],
+        },
+        WidgetKind::Markdown => WidgetSchema {
+            required: &["src"],
+            optional: &["size", "font", "color", "scale_ratio"],
+            events: &["on_link"],
             style_attributes: COMMON_STYLE_ATTRIBUTES,
             layout_attributes: COMMON_LAYOUT_ATTRIBUTES,
         },
         WidgetKind::Image => WidgetSchema {
             required: &["src"],
-            optional: &["width", "height", "fit", "filter_method", "path"],
+            optional: &[
+                "width",
+                "height",
+                "fit",
+                "filter_method",
+                "path",
+                "placeholder",
+                "error_src",
+            ],
             events: COMMON_EVENTS,
             style_attributes: COMMON_STYLE_ATTRIBUTES,
             layout_attributes: COMMON_LAYOUT_ATTRIBUTES,
@@ -168,11 +262,43 @@ pub fn get_widget_schema(kind: &WidgetKind) -> WidgetSchema {
         },
         WidgetKind::TextInput => WidgetSchema {
             required: &[],
-            optional: &["placeholder", "value", "password", "icon", "size"],
+            optional: &[
+                "placeholder",
+                "value",
+                "password",
+                "mask",
+                "max_length",
+                "filter",
+                "debounce_ms",
+                "icon",
+                "size",
+            ],
             events: &["on_input", "on_submit", "on_change", "on_paste"],
             style_attributes: COMMON_STYLE_ATTRIBUTES,
             layout_attributes: COMMON_LAYOUT_ATTRIBUTES,
         },
+        WidgetKind::NumberInput => WidgetSchema {
+            required: &[],
+            optional: &[
+                "value",
+                "min",
+                "max",
+                "step",
+                "decimals",
+                "placeholder",
+                "disabled",
+            ],
+            events: &["on_change"],
+            style_attributes: COMMON_STYLE_ATTRIBUTES,
+            layout_attributes: COMMON_LAYOUT_ATTRIBUTES,
+        },
+        WidgetKind::CodeEditor => WidgetSchema {
+            required: &[],
+            optional: &["value", "language", "size", "line_numbers"],
+            events: &["on_change"],
+            style_attributes: COMMON_STYLE_ATTRIBUTES,
+            layout_attributes: COMMON_LAYOUT_ATTRIBUTES,
+        },
         WidgetKind::Checkbox => WidgetSchema {
             required: &[],
             optional: &["checked", "label", "icon", "size"],
@@ -195,44 +321,58 @@ pub fn get_widget_schema(kind: &WidgetKind) -> WidgetSchema {
             style_attributes: COMMON_STYLE_ATTRIBUTES,
             layout_attributes: COMMON_LAYOUT_ATTRIBUTES,
         },
+        WidgetKind::RadioGroup => WidgetSchema {
+            required: &[],
+            optional: &["value", "options", "display", "disabled"],
+            events: &["on_select"],
+            style_attributes: COMMON_STYLE_ATTRIBUTES,
+            layout_attributes: COMMON_LAYOUT_ATTRIBUTES,
+        },
         WidgetKind::Slider => WidgetSchema {
             required: &[],
-            optional: &["min", "max", "value", "step"],
+            optional: &["min", "max", "value", "step", "show_value", "value_format"],
             events: &["on_change", "on_release"],
             style_attributes: COMMON_STYLE_ATTRIBUTES,
             layout_attributes: COMMON_LAYOUT_ATTRIBUTES,
         },
         WidgetKind::Column | WidgetKind::Row | WidgetKind::Container => WidgetSchema {
             required: &[],
-            optional: &[],
+            optional: &["model", "loading"],
             events: COMMON_EVENTS,
             style_attributes: COMMON_STYLE_ATTRIBUTES,
             layout_attributes: COMMON_LAYOUT_ATTRIBUTES,
         },
         WidgetKind::Scrollable => WidgetSchema {
             required: &[],
-            optional: &[],
-            events: &["on_scroll"],
+            optional: &["model", "reach_end_distance", "offset", "stick_to_bottom"],
+            events: &["on_scroll", "on_reach_end"],
             style_attributes: COMMON_STYLE_ATTRIBUTES,
             layout_attributes: COMMON_LAYOUT_ATTRIBUTES,
         },
         WidgetKind::Stack => WidgetSchema {
             required: &[],
-            optional: &[],
+            optional: &["model"],
             events: COMMON_EVENTS,
             style_attributes: COMMON_STYLE_ATTRIBUTES,
             layout_attributes: COMMON_LAYOUT_ATTRIBUTES,
         },
         WidgetKind::Svg => WidgetSchema {
             required: &["src"],
-            optional: &["width", "height", "path"],
+            optional: &["width", "height", "path", "content", "tint"],
+            events: COMMON_EVENTS,
+            style_attributes: COMMON_STYLE_ATTRIBUTES,
+            layout_attributes: COMMON_LAYOUT_ATTRIBUTES,
+        },
+        WidgetKind::Icon => WidgetSchema {
+            required: &["name"],
+            optional: &["size", "tint"],
             events: COMMON_EVENTS,
             style_attributes: COMMON_STYLE_ATTRIBUTES,
             layout_attributes: COMMON_LAYOUT_ATTRIBUTES,
         },
         WidgetKind::PickList => WidgetSchema {
             required: &[],
-            optional: &["placeholder", "selected", "options"],
+            optional: &["placeholder", "selected", "options", "display", "value"],
             events: &["on_select"],
             style_attributes: COMMON_STYLE_ATTRIBUTES,
             layout_attributes: COMMON_LAYOUT_ATTRIBUTES,
@@ -251,9 +391,16 @@ pub fn get_widget_schema(kind: &WidgetKind) -> WidgetSchema {
             style_attributes: COMMON_STYLE_ATTRIBUTES,
             layout_attributes: COMMON_LAYOUT_ATTRIBUTES,
         },
+        WidgetKind::Skeleton => WidgetSchema {
+            required: &[],
+            optional: &["lines"],
+            events: &[],
+            style_attributes: COMMON_STYLE_ATTRIBUTES,
+            layout_attributes: COMMON_LAYOUT_ATTRIBUTES,
+        },
         WidgetKind::ComboBox => WidgetSchema {
             required: &[],
-            optional: &["placeholder", "value", "selected", "options"],
+            optional: &["placeholder", "value", "selected", "options", "display"],
             events: &["on_input", "on_select"],
             style_attributes: COMMON_STYLE_ATTRIBUTES,
             layout_attributes: COMMON_LAYOUT_ATTRIBUTES,
@@ -264,6 +411,8 @@ pub fn get_widget_schema(kind: &WidgetKind) -> WidgetSchema {
                 "value",
                 "min",
                 "max",
+                "show_value",
+                "value_format",
                 "style",
                 "bar_color",
                 "background_color",
@@ -277,13 +426,20 @@ pub fn get_widget_schema(kind: &WidgetKind) -> WidgetSchema {
         },
         WidgetKind::Tooltip => WidgetSchema {
             required: &[],
-            optional: &["message", "position", "delay"],
+            optional: &["message", "position", "delay", "delay_ms"],
             events: COMMON_EVENTS,
             style_attributes: COMMON_STYLE_ATTRIBUTES,
             // Tooltip is a special case that typically wraps another widget but doesn't have layout itself in the same way?
             // Data model says "no layout attributes".
             layout_attributes: &[],
         },
+        WidgetKind::TooltipContent => WidgetSchema {
+            required: &[],
+            optional: &[],
+            events: &[],
+            style_attributes: COMMON_STYLE_ATTRIBUTES,
+            layout_attributes: COMMON_LAYOUT_ATTRIBUTES,
+        },
         WidgetKind::Grid => WidgetSchema {
             required: &[],
             optional: &["columns"],
@@ -333,10 +489,46 @@ pub fn get_widget_schema(kind: &WidgetKind) -> WidgetSchema {
             style_attributes: &[],
             layout_attributes: &[],
         },
+        WidgetKind::Chart => WidgetSchema {
+            required: &["data"],
+            optional: &[
+                "type",
+                "x",
+                "y",
+                "label",
+                "color",
+                "show_axes",
+                "show_legend",
+            ],
+            events: &[],
+            style_attributes: COMMON_STYLE_ATTRIBUTES,
+            layout_attributes: COMMON_LAYOUT_ATTRIBUTES,
+        },
+        WidgetKind::CanvasPath => WidgetSchema {
+            required: &["d"],
+            optional: &["fill", "stroke", "stroke_width"],
+            events: &[],
+            style_attributes: &[],
+            layout_attributes: &[],
+        },
+        WidgetKind::CanvasArc => WidgetSchema {
+            required: &["cx", "cy", "radius", "start_angle", "end_angle"],
+            optional: &["fill", "stroke", "stroke_width"],
+            events: &[],
+            style_attributes: &[],
+            layout_attributes: &[],
+        },
+        WidgetKind::CanvasBezier => WidgetSchema {
+            required: &["x1", "y1", "cx1", "cy1", "x2", "y2"],
+            optional: &["cx2", "cy2", "fill", "stroke", "stroke_width"],
+            events: &[],
+            style_attributes: &[],
+            layout_attributes: &[],
+        },
         WidgetKind::DatePicker => WidgetSchema {
             required: &[],
             optional: &["value", "format", "show", "min_date", "max_date"],
-            events: &["on_submit", "on_cancel"],
+            events: &["on_change", "on_submit", "on_cancel", "on_open"],
             style_attributes: COMMON_STYLE_ATTRIBUTES,
             layout_attributes: COMMON_LAYOUT_ATTRIBUTES,
         },
@@ -384,7 +576,16 @@ pub fn get_widget_schema(kind: &WidgetKind) -> WidgetSchema {
         },
         WidgetKind::Float => WidgetSchema {
             required: &[],
-            optional: &[],
+            optional: &[
+                "anchor",
+                "placement",
+                "gap",
+                "layer",
+                "show",
+                "position",
+                "offset_x",
+                "offset_y",
+            ],
             events: COMMON_EVENTS,
             style_attributes: COMMON_STYLE_ATTRIBUTES,
             layout_attributes: COMMON_LAYOUT_ATTRIBUTES,
@@ -418,7 +619,7 @@ pub fn get_widget_schema(kind: &WidgetKind) -> WidgetSchema {
         },
         WidgetKind::For => WidgetSchema {
             required: &["each", "in"],
-            optional: &["template"],
+            optional: &["template", "page", "page_size"],
             events: &[],
             style_attributes: COMMON_STYLE_ATTRIBUTES,
             layout_attributes: COMMON_LAYOUT_ATTRIBUTES,
@@ -430,6 +631,13 @@ pub fn get_widget_schema(kind: &WidgetKind) -> WidgetSchema {
             style_attributes: COMMON_STYLE_ATTRIBUTES,
             layout_attributes: COMMON_LAYOUT_ATTRIBUTES,
         },
+        WidgetKind::Tree => WidgetSchema {
+            required: &["items", "label"],
+            optional: &["children", "each", "key", "indent", "size"],
+            events: &["on_expand", "on_select"],
+            style_attributes: COMMON_STYLE_ATTRIBUTES,
+            layout_attributes: COMMON_LAYOUT_ATTRIBUTES,
+        },
         WidgetKind::TreeView => WidgetSchema {
             required: &[],
             optional: &[
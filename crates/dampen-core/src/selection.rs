@@ -0,0 +1,550 @@
+//! Selection model for lists and grids
+//!
+//! [`SelectionState`] tracks the selected rows of a list, with the click and
+//! keyboard semantics of desktop lists: a click selects a row, Ctrl+click
+//! toggles it, Shift+click selects the range from the last clicked row, and the
+//! arrow keys move the selection (extending it with Shift).
+//!
+//! A model holds one per list, and the rows generated by `<for>` bind to it:
+//!
+//! ```xml
+//! <for each="item" in="{items}">
+//!     <checkbox label="{item.name}"
+//!               checked="{selection.contains(item.id)}"
+//!               on_toggle="selection.toggle:{item.id}" />
+//! </for>
+//! ```
+//!
+//! A handler named `<field>.<action>` runs a [`SelectionAction`] on the
+//! `SelectionState` field of the model. Generated code calls
+//! [`SelectionState::apply`] directly; interpreted mode needs the field to be
+//! registered with
+//! [`HandlerRegistry::register_selection`](crate::HandlerRegistry::register_selection).
+//! Keyboard navigation is provided by `dampen_iced::selection`.
+//!
+//! In bindings, a selection is the list of its selected ids, so `len()`,
+//! `is_empty()` and `contains(id)` can be used on it.
+
+use std::str::FromStr;
+
+use crate::binding::{BindingValue, ToBindingValue};
+
+/// Whether a selection holds one or several items
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub enum SelectionMode {
+    /// At most one item; Ctrl and Shift clicks select the clicked item
+    #[default]
+    Single,
+    /// Any number of items
+    Multiple,
+}
+
+/// Item the focus moves to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SelectionMove {
+    /// The item after the focused one (Down)
+    Next,
+    /// The item before the focused one (Up)
+    Previous,
+    /// The first item (Home)
+    First,
+    /// The last item (End)
+    Last,
+}
+
+/// Action of a `<field>.<action>` handler
+///
+/// | Handler                  | Action                                      |
+/// |--------------------------|---------------------------------------------|
+/// | `select:{id}`            | Select only `id` (click)                    |
+/// | `toggle:{id}`            | Add or remove `id` (Ctrl+click)             |
+/// | `extend:{id}`            | Select the range up to `id` (Shift+click)   |
+/// | `clear`                  | Select nothing                              |
+/// | `select_all`             | Select every item                           |
+/// | `toggle_focused`         | Add or remove the focused item (Space)      |
+/// | `next`, `previous`, `first`, `last` | Move the focus and select it     |
+/// | `extend_next`, `extend_previous`, `extend_first`, `extend_last` | Move the focus and extend the range |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SelectionAction {
+    Select,
+    Toggle,
+    Extend,
+    Clear,
+    SelectAll,
+    ToggleFocused,
+    /// Move the focus, extending the range from the anchor if `extend`
+    Move {
+        to: SelectionMove,
+        extend: bool,
+    },
+}
+
+impl SelectionAction {
+    /// Every action, in the order of the table above
+    pub const ALL: &'static [SelectionAction] = &[
+        SelectionAction::Select,
+        SelectionAction::Toggle,
+        SelectionAction::Extend,
+        SelectionAction::Clear,
+        SelectionAction::SelectAll,
+        SelectionAction::ToggleFocused,
+        SelectionAction::Move {
+            to: SelectionMove::Next,
+            extend: false,
+        },
+        SelectionAction::Move {
+            to: SelectionMove::Previous,
+            extend: false,
+        },
+        SelectionAction::Move {
+            to: SelectionMove::First,
+            extend: false,
+        },
+        SelectionAction::Move {
+            to: SelectionMove::Last,
+            extend: false,
+        },
+        SelectionAction::Move {
+            to: SelectionMove::Next,
+            extend: true,
+        },
+        SelectionAction::Move {
+            to: SelectionMove::Previous,
+            extend: true,
+        },
+        SelectionAction::Move {
+            to: SelectionMove::First,
+            extend: true,
+        },
+        SelectionAction::Move {
+            to: SelectionMove::Last,
+            extend: true,
+        },
+    ];
+
+    /// Name of the action in handler names
+    pub fn name(self) -> &'static str {
+        match self {
+            SelectionAction::Select => "select",
+            SelectionAction::Toggle => "toggle",
+            SelectionAction::Extend => "extend",
+            SelectionAction::Clear => "clear",
+            SelectionAction::SelectAll => "select_all",
+            SelectionAction::ToggleFocused => "toggle_focused",
+            SelectionAction::Move { to, extend } => match (to, extend) {
+                (SelectionMove::Next, false) => "next",
+                (SelectionMove::Previous, false) => "previous",
+                (SelectionMove::First, false) => "first",
+                (SelectionMove::Last, false) => "last",
+                (SelectionMove::Next, true) => "extend_next",
+                (SelectionMove::Previous, true) => "extend_previous",
+                (SelectionMove::First, true) => "extend_first",
+                (SelectionMove::Last, true) => "extend_last",
+            },
+        }
+    }
+
+    /// Action with this name
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|action| action.name() == name)
+    }
+
+    /// Whether the action applies to the item given as handler parameter
+    pub fn takes_id(self) -> bool {
+        matches!(
+            self,
+            SelectionAction::Select | SelectionAction::Toggle | SelectionAction::Extend
+        )
+    }
+}
+
+/// Field and action of a `<field>.<action>` handler name
+///
+/// The field may be a path (`filters.selection.clear`). Returns `None` if the
+/// name has no dot or its last segment is not a [`SelectionAction`].
+///
+/// # Example
+///
+/// ```rust
+/// use dampen_core::selection::{SelectionAction, selection_handler};
+///
+/// assert_eq!(
+///     selection_handler("selection.toggle"),
+///     Some(("selection", SelectionAction::Toggle))
+/// );
+/// assert_eq!(selection_handler("toggle"), None);
+/// ```
+pub fn selection_handler(name: &str) -> Option<(&str, SelectionAction)> {
+    let (field, action) = name.rsplit_once('.')?;
+    if field.is_empty() {
+        return None;
+    }
+    Some((field, SelectionAction::from_name(action)?))
+}
+
+/// Selected items of a list, identified by ids of type `T`
+///
+/// The selection knows the ids of the list, in display order, to select ranges
+/// and move the focus: update them with [`set_items`](Self::set_items) when the
+/// list changes. Ids are kept in the order they were selected.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SelectionState<T> {
+    mode: SelectionMode,
+    items: Vec<T>,
+    selected: Vec<T>,
+    anchor: Option<T>,
+    focused: Option<T>,
+}
+
+impl<T> Default for SelectionState<T> {
+    fn default() -> Self {
+        Self::new(SelectionMode::default())
+    }
+}
+
+impl<T> SelectionState<T> {
+    /// Empty selection of a list without items
+    pub fn new(mode: SelectionMode) -> Self {
+        Self {
+            mode,
+            items: Vec::new(),
+            selected: Vec::new(),
+            anchor: None,
+            focused: None,
+        }
+    }
+
+    /// Selection of at most one item
+    pub fn single() -> Self {
+        Self::new(SelectionMode::Single)
+    }
+
+    /// Selection of any number of items
+    pub fn multiple() -> Self {
+        Self::new(SelectionMode::Multiple)
+    }
+
+    /// Whether one or several items can be selected
+    pub fn mode(&self) -> SelectionMode {
+        self.mode
+    }
+
+    /// Ids of the list, in display order
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Selected ids, in selection order
+    pub fn selected(&self) -> &[T] {
+        &self.selected
+    }
+
+    /// Number of selected items
+    pub fn len(&self) -> usize {
+        self.selected.len()
+    }
+
+    /// Whether nothing is selected
+    pub fn is_empty(&self) -> bool {
+        self.selected.is_empty()
+    }
+
+    /// Item having the keyboard focus, the last one clicked or navigated to
+    pub fn focused(&self) -> Option<&T> {
+        self.focused.as_ref()
+    }
+}
+
+impl<T: Clone + PartialEq> SelectionState<T> {
+    /// This selection, for a list of these ids
+    pub fn with_items(mut self, items: impl IntoIterator<Item = T>) -> Self {
+        self.set_items(items);
+        self
+    }
+
+    /// Set the ids of the list, in display order
+    ///
+    /// Ids no longer in the list are unselected.
+    pub fn set_items(&mut self, items: impl IntoIterator<Item = T>) {
+        self.items = items.into_iter().collect();
+        let items = &self.items;
+        self.selected.retain(|id| items.contains(id));
+        if self.anchor.as_ref().is_some_and(|id| !items.contains(id)) {
+            self.anchor = None;
+        }
+        if self.focused.as_ref().is_some_and(|id| !items.contains(id)) {
+            self.focused = None;
+        }
+    }
+
+    /// Whether `id` is selected
+    ///
+    /// Any type comparable to the ids is accepted, e.g. `&str` for `String` ids.
+    pub fn contains<K>(&self, id: K) -> bool
+    where
+        T: PartialEq<K>,
+    {
+        self.selected.iter().any(|selected| *selected == id)
+    }
+
+    /// Select only `id` (click)
+    pub fn select(&mut self, id: T) {
+        self.selected = vec![id.clone()];
+        self.anchor = Some(id.clone());
+        self.focused = Some(id);
+    }
+
+    /// Add `id` to the selection, or remove it if selected (Ctrl+click)
+    ///
+    /// In single mode, selects `id` or clears the selection.
+    pub fn toggle(&mut self, id: T) {
+        if let Some(index) = self.selected.iter().position(|selected| *selected == id) {
+            self.selected.remove(index);
+        } else if self.mode == SelectionMode::Single {
+            self.selected = vec![id.clone()];
+        } else {
+            self.selected.push(id.clone());
+        }
+        self.anchor = Some(id.clone());
+        self.focused = Some(id);
+    }
+
+    /// Select the items from the anchor to `id`, in list order (Shift+click)
+    ///
+    /// The anchor is the last item clicked without Shift. Selects only `id`
+    /// in single mode, without an anchor, or if either is not in the list.
+    pub fn extend(&mut self, id: T) {
+        let range = self.anchor.as_ref().and_then(|anchor| {
+            let from = self.items.iter().position(|item| item == anchor)?;
+            let to = self.items.iter().position(|item| *item == id)?;
+            Some(from.min(to)..=from.max(to))
+        });
+
+        match range {
+            Some(range) if self.mode == SelectionMode::Multiple => {
+                self.selected = self.items[range].to_vec();
+                self.focused = Some(id);
+            }
+            _ => self.select(id),
+        }
+    }
+
+    /// Apply a click on `id`, with the Ctrl (Cmd on macOS) and Shift modifiers
+    pub fn click(&mut self, id: T, control: bool, shift: bool) {
+        if shift {
+            self.extend(id);
+        } else if control {
+            self.toggle(id);
+        } else {
+            self.select(id);
+        }
+    }
+
+    /// Select nothing
+    pub fn clear(&mut self) {
+        self.selected.clear();
+        self.anchor = None;
+    }
+
+    /// Select every item of the list; in single mode, only the focused one
+    pub fn select_all(&mut self) {
+        match self.mode {
+            SelectionMode::Multiple => self.selected = self.items.clone(),
+            SelectionMode::Single => {
+                if let Some(focused) = self.focused.clone() {
+                    self.selected = vec![focused];
+                }
+            }
+        }
+    }
+
+    /// Add the focused item to the selection, or remove it (Space)
+    pub fn toggle_focused(&mut self) {
+        if let Some(focused) = self.focused.clone() {
+            self.toggle(focused);
+        }
+    }
+
+    /// Move the focus and select the focused item (arrow keys)
+    ///
+    /// With `extend` (Shift), the range from the anchor to the focused item is
+    /// selected instead. Nothing happens in an empty list.
+    pub fn move_focus(&mut self, to: SelectionMove, extend: bool) {
+        let last = match self.items.len().checked_sub(1) {
+            Some(last) => last,
+            None => return,
+        };
+        let current = self
+            .focused
+            .as_ref()
+            .and_then(|focused| self.items.iter().position(|item| item == focused));
+
+        let index = match (to, current) {
+            (SelectionMove::First, _) | (SelectionMove::Next, None) => 0,
+            (SelectionMove::Last, _) | (SelectionMove::Previous, None) => last,
+            (SelectionMove::Next, Some(index)) => (index + 1).min(last),
+            (SelectionMove::Previous, Some(index)) => index.saturating_sub(1),
+        };
+
+        let id = self.items[index].clone();
+        if extend {
+            if self.anchor.is_none() {
+                self.anchor = self.focused.clone();
+            }
+            self.extend(id);
+        } else {
+            self.select(id);
+        }
+    }
+
+    /// Run `action`, parsing `id` for the actions taking one
+    ///
+    /// Returns `false`, leaving the selection unchanged, if the action takes an
+    /// id and `id` is missing or does not parse.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dampen_core::selection::{SelectionAction, SelectionState};
+    ///
+    /// let mut selection = SelectionState::<u32>::multiple().with_items([1, 2, 3]);
+    /// assert!(selection.apply(SelectionAction::Toggle, Some("2")));
+    /// assert!(selection.apply(SelectionAction::Toggle, Some("3")));
+    /// assert_eq!(selection.selected(), &[2, 3]);
+    /// assert!(!selection.apply(SelectionAction::Select, Some("two")));
+    /// ```
+    pub fn apply(&mut self, action: SelectionAction, id: Option<&str>) -> bool
+    where
+        T: FromStr,
+    {
+        let id = if action.takes_id() {
+            match id.and_then(|id| id.trim().parse::<T>().ok()) {
+                Some(id) => Some(id),
+                None => return false,
+            }
+        } else {
+            None
+        };
+
+        match (action, id) {
+            (SelectionAction::Select, Some(id)) => self.select(id),
+            (SelectionAction::Toggle, Some(id)) => self.toggle(id),
+            (SelectionAction::Extend, Some(id)) => self.extend(id),
+            (SelectionAction::Clear, _) => self.clear(),
+            (SelectionAction::SelectAll, _) => self.select_all(),
+            (SelectionAction::ToggleFocused, _) => self.toggle_focused(),
+            (SelectionAction::Move { to, extend }, _) => self.move_focus(to, extend),
+            (SelectionAction::Select | SelectionAction::Toggle | SelectionAction::Extend, None) => {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// A selection is bound as the list of its selected ids
+impl<T: ToBindingValue> ToBindingValue for SelectionState<T> {
+    fn to_binding_value(&self) -> BindingValue {
+        BindingValue::List(self.selected.iter().map(T::to_binding_value).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(mode: SelectionMode) -> SelectionState<i64> {
+        SelectionState::new(mode).with_items([1, 2, 3, 4, 5])
+    }
+
+    #[test]
+    fn test_click_semantics() {
+        let mut selection = list(SelectionMode::Multiple);
+        selection.click(2, false, false);
+        selection.click(4, true, false);
+        assert_eq!(selection.selected(), &[2, 4]);
+
+        // Shift extends from the last Ctrl+click
+        selection.click(2, false, true);
+        assert_eq!(selection.selected(), &[2, 3, 4]);
+        assert_eq!(selection.focused(), Some(&2));
+
+        selection.click(3, true, false);
+        assert!(!selection.contains(3));
+        assert!(selection.contains(4));
+
+        selection.click(5, false, false);
+        assert_eq!(selection.selected(), &[5]);
+    }
+
+    #[test]
+    fn test_single_mode() {
+        let mut selection = list(SelectionMode::Single);
+        selection.toggle(1);
+        selection.toggle(2);
+        assert_eq!(selection.selected(), &[2]);
+        selection.extend(4);
+        assert_eq!(selection.selected(), &[4]);
+        selection.toggle(4);
+        assert!(selection.is_empty());
+    }
+
+    #[test]
+    fn test_keyboard_navigation() {
+        let mut selection = list(SelectionMode::Multiple);
+        selection.move_focus(SelectionMove::Next, false);
+        assert_eq!(selection.selected(), &[1]);
+        selection.move_focus(SelectionMove::Next, true);
+        selection.move_focus(SelectionMove::Next, true);
+        assert_eq!(selection.selected(), &[1, 2, 3]);
+        selection.move_focus(SelectionMove::Previous, true);
+        assert_eq!(selection.selected(), &[1, 2]);
+
+        selection.move_focus(SelectionMove::Last, false);
+        selection.move_focus(SelectionMove::Next, false);
+        assert_eq!(selection.selected(), &[5]);
+        selection.move_focus(SelectionMove::First, true);
+        assert_eq!(selection.selected(), &[1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_set_items_drops_removed_ids() {
+        let mut selection = list(SelectionMode::Multiple);
+        selection.select_all();
+        selection.set_items([2, 4, 6]);
+        assert_eq!(selection.selected(), &[2, 4]);
+    }
+
+    #[test]
+    fn test_handler_names() {
+        assert_eq!(
+            selection_handler("filters.tags.extend_last"),
+            Some((
+                "filters.tags",
+                SelectionAction::Move {
+                    to: SelectionMove::Last,
+                    extend: true
+                }
+            ))
+        );
+        assert_eq!(selection_handler("selection.explode"), None);
+        assert_eq!(selection_handler(".clear"), None);
+        for action in SelectionAction::ALL {
+            assert_eq!(SelectionAction::from_name(action.name()), Some(*action));
+        }
+
+        let mut selection = SelectionState::<String>::multiple();
+        assert!(selection.apply(SelectionAction::Toggle, Some("a")));
+        assert!(!selection.apply(SelectionAction::Toggle, None));
+        assert!(selection.contains("a"));
+        assert_eq!(
+            selection.to_binding_value(),
+            BindingValue::List(vec![BindingValue::String("a".to_string())])
+        );
+    }
+}
@@ -0,0 +1,354 @@
+//! Named collection of shared contexts.
+//!
+//! [`SharedContexts`] lets an application declare several independent shared
+//! states (e.g. a session and the user settings) instead of a single one.
+//! Bindings address each context by name (`{session.user}`), and handlers
+//! receive the specific context they request through [`SharedContexts::resolve`].
+
+use std::any::Any;
+
+use crate::binding::{BindingValue, UiBindable};
+use crate::shared::SharedContext;
+
+/// Type-erased [`SharedContext`], so contexts of different state types can be stored together
+trait ErasedContext: Send + Sync {
+    fn get_field(&self, path: &[&str]) -> Option<BindingValue>;
+    fn as_any(&self) -> &dyn Any;
+    fn clone_box(&self) -> Box<dyn ErasedContext>;
+}
+
+impl<S> ErasedContext for SharedContext<S>
+where
+    S: UiBindable + Send + Sync + 'static,
+{
+    fn get_field(&self, path: &[&str]) -> Option<BindingValue> {
+        UiBindable::get_field(self, path)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ErasedContext> {
+        Box::new(self.clone())
+    }
+}
+
+/// A set of shared contexts, each registered under a name.
+///
+/// As a [`UiBindable`], the first segment of a path selects the context and the
+/// rest is resolved against its state: `["session", "user"]` reads `user` from
+/// the `session` context.
+///
+/// # Example
+///
+/// ```rust
+/// use dampen_core::{BindingValue, SharedContext, SharedContexts, UiBindable};
+///
+/// #[derive(Default)]
+/// struct Session {
+///     user: String,
+/// }
+///
+/// impl UiBindable for Session {
+///     fn get_field(&self, path: &[&str]) -> Option<BindingValue> {
+///         match path {
+///             ["user"] => Some(BindingValue::String(self.user.clone())),
+///             _ => None,
+///         }
+///     }
+///     fn available_fields() -> Vec<String> {
+///         vec!["user".to_string()]
+///     }
+/// }
+///
+/// let session = SharedContext::new(Session::default());
+/// let contexts = SharedContexts::new().with("session", session.clone());
+///
+/// session.write().user = "ada".to_string();
+/// assert_eq!(
+///     contexts.get_field(&["session", "user"]),
+///     Some(BindingValue::String("ada".to_string()))
+/// );
+/// assert!(contexts.get::<Session>("session").is_some());
+/// ```
+#[derive(Default)]
+pub struct SharedContexts {
+    contexts: Vec<(String, Box<dyn ErasedContext>)>,
+}
+
+impl SharedContexts {
+    /// Create an empty set of contexts
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a context under `name`, replacing any context already registered under it
+    pub fn with<S>(mut self, name: impl Into<String>, context: SharedContext<S>) -> Self
+    where
+        S: UiBindable + Send + Sync + 'static,
+    {
+        self.insert(name, context);
+        self
+    }
+
+    /// Add a context under `name`, replacing any context already registered under it
+    pub fn insert<S>(&mut self, name: impl Into<String>, context: SharedContext<S>)
+    where
+        S: UiBindable + Send + Sync + 'static,
+    {
+        let name = name.into();
+        let context: Box<dyn ErasedContext> = Box::new(context);
+        match self
+            .contexts
+            .iter_mut()
+            .find(|(existing, _)| *existing == name)
+        {
+            Some((_, slot)) => *slot = context,
+            None => self.contexts.push((name, context)),
+        }
+    }
+
+    /// Get the context registered under `name`, if it holds an `S`
+    pub fn get<S>(&self, name: &str) -> Option<&SharedContext<S>>
+    where
+        S: UiBindable + Send + Sync + 'static,
+    {
+        self.contexts
+            .iter()
+            .find(|(existing, _)| existing == name)
+            .and_then(|(_, context)| context.as_any().downcast_ref())
+    }
+
+    /// Get the first context holding an `S`, whatever its name
+    pub fn find<S>(&self) -> Option<&SharedContext<S>>
+    where
+        S: UiBindable + Send + Sync + 'static,
+    {
+        self.contexts
+            .iter()
+            .find_map(|(_, context)| context.as_any().downcast_ref())
+    }
+
+    /// Names of the registered contexts, in registration order
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.contexts.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Resolve the context of state `S` from the shared value passed to handlers.
+    ///
+    /// `shared` may be a `SharedContext<S>` (single shared state), a
+    /// `SharedContext<SharedContexts>` (as created by `#[dampen_app(shared = [...])]`)
+    /// or a bare `SharedContexts`. Returns `None` if no context holds an `S`.
+    pub fn resolve<S>(shared: &dyn Any) -> Option<SharedContext<S>>
+    where
+        S: UiBindable + Send + Sync + 'static,
+    {
+        if let Some(context) = shared.downcast_ref::<SharedContext<S>>() {
+            return Some(context.clone());
+        }
+        if let Some(contexts) = shared.downcast_ref::<SharedContext<SharedContexts>>() {
+            return contexts.read().find::<S>().cloned();
+        }
+        shared
+            .downcast_ref::<SharedContexts>()
+            .and_then(|contexts| contexts.find::<S>().cloned())
+    }
+}
+
+impl Clone for SharedContexts {
+    fn clone(&self) -> Self {
+        Self {
+            contexts: self
+                .contexts
+                .iter()
+                .map(|(name, context)| (name.clone(), context.clone_box()))
+                .collect(),
+        }
+    }
+}
+
+impl std::fmt::Debug for SharedContexts {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedContexts")
+            .field("names", &self.names().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl UiBindable for SharedContexts {
+    fn get_field(&self, path: &[&str]) -> Option<BindingValue> {
+        let (name, rest) = path.split_first()?;
+        if rest.is_empty() {
+            return None;
+        }
+        self.contexts
+            .iter()
+            .find(|(existing, _)| existing == name)
+            .and_then(|(_, context)| context.get_field(rest))
+    }
+
+    /// Context names are only known at runtime, so no fields are listed
+    fn available_fields() -> Vec<String> {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Session {
+        user: String,
+    }
+
+    impl UiBindable for Session {
+        fn get_field(&self, path: &[&str]) -> Option<BindingValue> {
+            match path {
+                ["user"] => Some(BindingValue::String(self.user.clone())),
+                _ => None,
+            }
+        }
+
+        fn available_fields() -> Vec<String> {
+            vec!["user".to_string()]
+        }
+    }
+
+    #[derive(Default)]
+    struct Settings {
+        theme: String,
+    }
+
+    impl UiBindable for Settings {
+        fn get_field(&self, path: &[&str]) -> Option<BindingValue> {
+            match path {
+                ["theme"] => Some(BindingValue::String(self.theme.clone())),
+                _ => None,
+            }
+        }
+
+        fn available_fields() -> Vec<String> {
+            vec!["theme".to_string()]
+        }
+    }
+
+    fn contexts() -> SharedContexts {
+        SharedContexts::new()
+            .with(
+                "session",
+                SharedContext::new(Session {
+                    user: "ada".to_string(),
+                }),
+            )
+            .with(
+                "settings",
+                SharedContext::new(Settings {
+                    theme: "dark".to_string(),
+                }),
+            )
+    }
+
+    #[test]
+    fn test_get_field_routes_by_name() {
+        let contexts = contexts();
+
+        assert_eq!(
+            contexts.get_field(&["session", "user"]),
+            Some(BindingValue::String("ada".to_string()))
+        );
+        assert_eq!(
+            contexts.get_field(&["settings", "theme"]),
+            Some(BindingValue::String("dark".to_string()))
+        );
+        assert_eq!(contexts.get_field(&["session", "theme"]), None);
+        assert_eq!(contexts.get_field(&["session"]), None);
+        assert_eq!(contexts.get_field(&["unknown", "user"]), None);
+    }
+
+    #[test]
+    fn test_get_by_name_and_type() {
+        let contexts = contexts();
+
+        assert!(contexts.get::<Session>("session").is_some());
+        assert!(contexts.get::<Settings>("session").is_none());
+        assert!(contexts.find::<Settings>().is_some());
+        assert_eq!(
+            contexts.names().collect::<Vec<_>>(),
+            vec!["session", "settings"]
+        );
+    }
+
+    #[test]
+    fn test_insert_replaces_same_name() {
+        let mut contexts = contexts();
+        contexts.insert("session", SharedContext::new(Settings::default()));
+
+        assert!(contexts.get::<Session>("session").is_none());
+        assert_eq!(contexts.names().count(), 2);
+    }
+
+    #[test]
+    fn test_resolve_from_handler_argument() {
+        let contexts = contexts();
+        let wrapped = SharedContext::new(contexts.clone());
+
+        // Contexts are shared, not copied, by resolution
+        if let Some(settings) = SharedContexts::resolve::<Settings>(&wrapped) {
+            settings.write().theme = "light".to_string();
+        }
+        assert_eq!(
+            contexts.get_field(&["settings", "theme"]),
+            Some(BindingValue::String("light".to_string()))
+        );
+
+        assert!(SharedContexts::resolve::<Session>(&contexts).is_some());
+        let single = SharedContext::new(Session::default());
+        assert!(SharedContexts::resolve::<Session>(&single).is_some());
+        assert!(SharedContexts::resolve::<Settings>(&single).is_none());
+    }
+
+    #[test]
+    fn test_registry_dispatch_routes_to_requested_context() {
+        let contexts = contexts();
+        let wrapped = SharedContext::new(contexts.clone());
+        let registry = crate::HandlerRegistry::new();
+        registry.register_with_value_and_context(
+            "set_theme",
+            |_model, value, settings: &SharedContext<Settings>| {
+                if let Ok(theme) = value.downcast::<String>() {
+                    settings.write().theme = *theme;
+                }
+            },
+        );
+
+        let mut model = ();
+        registry.dispatch_with_shared("set_theme", &mut model, &wrapped, Some("blue".to_string()));
+
+        assert_eq!(
+            contexts.get_field(&["settings", "theme"]),
+            Some(BindingValue::String("blue".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_namespaced_bindings_resolve_against_contexts() -> Result<(), String> {
+        let contexts = contexts();
+        let model = Settings::default();
+        let binding = crate::expr::tokenize_binding_expr("session.user", 0, 1, 1)?;
+
+        let value =
+            crate::expr::evaluate_binding_expr_with_shared(&binding, &model, Some(&contexts))
+                .map_err(|e| e.message)?;
+        assert_eq!(value, BindingValue::String("ada".to_string()));
+
+        // The model still wins when it has the field
+        let binding = crate::expr::tokenize_binding_expr("theme", 0, 1, 1)?;
+        let value =
+            crate::expr::evaluate_binding_expr_with_shared(&binding, &model, Some(&contexts))
+                .map_err(|e| e.message)?;
+        assert_eq!(value, BindingValue::String(String::new()));
+        Ok(())
+    }
+}
@@ -57,12 +57,17 @@
 //! # See Also
 //!
 //! - [`AppState`](crate::state::AppState) - Per-view state container that can hold a `SharedContext`
+//! - [`SharedContexts`] - Several named shared contexts, bound as `{name.field}`
 //! - [`UiBindable`] - Trait required for shared state types
 
 use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
 
 use crate::binding::{BindingValue, UiBindable};
 
+mod contexts;
+
+pub use contexts::SharedContexts;
+
 /// Thread-safe shared state container.
 ///
 /// `SharedContext` wraps user-defined shared state in an `Arc<RwLock<S>>`,
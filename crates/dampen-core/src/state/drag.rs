@@ -0,0 +1,152 @@
+//! Drag-and-drop state shared by draggable widgets.
+//!
+//! Widgets marked `draggable="true"` inside a `<for>` loop can be reordered by
+//! dragging. Each rendered item only knows its own index, so the gesture in progress
+//! (which list, which item was picked up, which item is under the cursor) is kept in
+//! a [`DragTracker`] shared by all items and owned by the
+//! [`AppState`](crate::state::AppState), which survives view rebuilds.
+
+use std::sync::{Arc, RwLock};
+
+/// A drag gesture in progress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DragSession {
+    /// Source location `(line, column)` of the draggable template, identifying the list
+    pub list: (u32, u32),
+    /// Index of the item being dragged
+    pub source: usize,
+    /// Index of the item currently under the cursor, if any
+    pub hovered: Option<usize>,
+    /// Whether the mouse button was released outside of the list
+    pub released: bool,
+}
+
+/// Thread-safe holder for the drag gesture in progress.
+///
+/// The tracker is cheap to clone; clones share the same session.
+///
+/// # Example
+///
+/// ```rust
+/// use dampen_core::state::DragTracker;
+///
+/// let tracker = DragTracker::new();
+/// tracker.begin((4, 9), 0);
+/// assert!(tracker.hover((4, 9), 2));
+/// assert_eq!(tracker.drop_on((4, 9), 2), Some((0, 2)));
+/// assert!(tracker.session().is_none());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DragTracker {
+    session: Arc<RwLock<Option<DragSession>>>,
+}
+
+impl DragTracker {
+    /// Create a tracker with no drag in progress
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start dragging item `source` of `list`, replacing any previous session
+    pub fn begin(&self, list: (u32, u32), source: usize) {
+        if let Ok(mut session) = self.session.write() {
+            *session = Some(DragSession {
+                list,
+                source,
+                hovered: Some(source),
+                released: false,
+            });
+        }
+    }
+
+    /// Get the drag in progress, if any
+    pub fn session(&self) -> Option<DragSession> {
+        self.session.read().ok().and_then(|session| *session)
+    }
+
+    /// Record that the cursor is over item `index` of `list`
+    ///
+    /// Returns `true` if the hovered item changed (a drag-over event should be emitted).
+    pub fn hover(&self, list: (u32, u32), index: usize) -> bool {
+        let Ok(mut guard) = self.session.write() else {
+            return false;
+        };
+        match guard.as_mut() {
+            Some(session) if session.released => {
+                *guard = None;
+                false
+            }
+            Some(session) if session.list == list && session.hovered != Some(index) => {
+                session.hovered = Some(index);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Finish the drag by releasing the mouse over item `target` of `list`
+    ///
+    /// Returns `(source, target)` when an item of the same list was dropped on a
+    /// different position. The session ends in every case where it belongs to `list`.
+    pub fn drop_on(&self, list: (u32, u32), target: usize) -> Option<(usize, usize)> {
+        let mut guard = self.session.write().ok()?;
+        let session = (*guard).filter(|session| session.list == list)?;
+        *guard = None;
+        (session.source != target).then_some((session.source, target))
+    }
+
+    /// Mark the drag as released outside of the list
+    ///
+    /// The session is kept until the next hover so that a drop on another item,
+    /// processed after this call for the same release, still sees it.
+    pub fn release(&self, list: (u32, u32)) {
+        if let Ok(mut guard) = self.session.write()
+            && let Some(session) = guard.as_mut()
+            && session.list == list
+        {
+            session.released = true;
+        }
+    }
+
+    /// Abandon the drag in progress
+    pub fn cancel(&self) {
+        if let Ok(mut session) = self.session.write() {
+            *session = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIST: (u32, u32) = (3, 5);
+
+    #[test]
+    fn test_drop_reports_source_and_target() {
+        let tracker = DragTracker::new();
+        tracker.begin(LIST, 1);
+
+        assert!(tracker.hover(LIST, 3));
+        assert!(!tracker.hover(LIST, 3));
+        assert!(!tracker.hover((9, 9), 0));
+
+        // The source item sees the release first, then the target item
+        tracker.release(LIST);
+        assert_eq!(tracker.drop_on(LIST, 3), Some((1, 3)));
+        assert!(tracker.session().is_none());
+    }
+
+    #[test]
+    fn test_drop_on_source_or_outside_is_ignored() {
+        let tracker = DragTracker::new();
+        tracker.begin(LIST, 2);
+        assert_eq!(tracker.drop_on(LIST, 2), None);
+        assert!(tracker.session().is_none());
+
+        tracker.begin(LIST, 2);
+        tracker.release(LIST);
+        assert!(!tracker.hover(LIST, 0));
+        assert!(tracker.session().is_none());
+    }
+}
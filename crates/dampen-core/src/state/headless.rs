@@ -0,0 +1,476 @@
+//! Headless use of an [`AppState`], without any UI backend
+//!
+//! Embedders that do not render with iced (tests, servers, other toolkits) can
+//! drive a view entirely through data:
+//!
+//! - [`AppState::load_document`] parses and hot-reloads a `.dampen` source
+//! - [`AppState::set_model_snapshot`] / [`AppState::model_snapshot`] exchange the
+//!   model as JSON
+//! - [`AppState::dispatch`] runs a named handler with an optional value
+//! - [`AppState::render`] evaluates the widget tree into an [`EvaluatedNode`]
+//! - [`AppState::model_fields`] and [`AppState::evaluate`] inspect the model
+//!   through its bindable fields, without requiring `Serialize`
+//!
+//! Rendering follows the semantics of the widget builder: `<for>` repeats its
+//! children once per item with the loop variable and `index` in scope, `<if>`
+//! keeps its children only when its condition holds, and `model="{...}"`
+//! scopes the bindings of a container to a sub-model.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use super::AppState;
+use crate::binding::{BindingValue, UiBindable};
+use crate::expr::{
+    BindingError, evaluate_binding_expr_with_shared, evaluate_formatted_with_shared,
+    parse_binding_expr,
+};
+use crate::handler::encode_event_args;
+use crate::ir::node::{AttributeValue, EventKind, WidgetKind, WidgetNode};
+use crate::ir::scope::{MODEL_ATTRIBUTE, model_scope};
+use crate::pagination::{page_range, parse_page};
+use crate::parser::error::ParseError;
+
+/// A widget of an evaluated tree, with every binding resolved against the model
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EvaluatedNode {
+    /// Widget type
+    pub kind: WidgetKind,
+    /// XML `id`, if any
+    pub id: Option<String>,
+    /// Attribute values; static attributes are strings, bindings keep their type
+    ///
+    /// A pick list or combo box bound to a collection has `display` and `value`
+    /// lists, with one entry per option; a radio group has a `display` list.
+    pub attributes: BTreeMap<String, BindingValue>,
+    /// Events the widget emits, with their evaluated parameters
+    pub events: Vec<EvaluatedEvent>,
+    /// Child widgets; `<for>` and `<if>` hold the children they produced
+    pub children: Vec<EvaluatedNode>,
+}
+
+/// An event of an [`EvaluatedNode`]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EvaluatedEvent {
+    /// Event type (e.g., `Click`)
+    pub event: EventKind,
+    /// Handler the event dispatches to
+    pub handler: String,
+    /// Value passed to the handler, as [`AppState::dispatch`] expects it
+    pub value: Option<String>,
+}
+
+/// Errors of the headless API
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum HeadlessError {
+    /// The document source failed to parse
+    #[error("{0}")]
+    Parse(ParseError),
+    /// The model snapshot does not match the model type
+    #[error("Invalid model snapshot: {0}")]
+    Snapshot(String),
+    /// No handler is registered under this name
+    #[error("Unknown handler '{0}'")]
+    UnknownHandler(String),
+    /// A binding of the document failed to evaluate
+    #[error("{0}")]
+    Binding(BindingError),
+}
+
+impl<M: UiBindable, S: UiBindable + Send + Sync + 'static> AppState<M, S> {
+    /// Parse `source` and replace the document with it, as [`hot_reload`](Self::hot_reload) does
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeadlessError::Parse`] if the source is invalid; the current
+    /// document is kept then.
+    pub fn load_document(&mut self, source: &str) -> Result<(), HeadlessError> {
+        let document = crate::parse(source).map_err(HeadlessError::Parse)?;
+        self.hot_reload(document);
+        Ok(())
+    }
+
+    /// Replace the model with one deserialized from a JSON snapshot
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeadlessError::Snapshot`] if the snapshot does not deserialize
+    /// into `M`; the current model is kept then.
+    pub fn set_model_snapshot(&mut self, snapshot: serde_json::Value) -> Result<(), HeadlessError>
+    where
+        M: DeserializeOwned,
+    {
+        self.model =
+            serde_json::from_value(snapshot).map_err(|e| HeadlessError::Snapshot(e.to_string()))?;
+        Ok(())
+    }
+
+    /// Serialize the model to a JSON snapshot
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeadlessError::Snapshot`] if the model fails to serialize.
+    pub fn model_snapshot(&self) -> Result<serde_json::Value, HeadlessError>
+    where
+        M: Serialize,
+    {
+        serde_json::to_value(&self.model).map_err(|e| HeadlessError::Snapshot(e.to_string()))
+    }
+
+    /// Run the handler registered under `handler` with an optional value
+    ///
+    /// The value is passed as the widget builder would: the string form of an
+    /// event parameter, or the text of an input. Handlers needing shared state
+    /// receive the shared context, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeadlessError::UnknownHandler`] if no such handler is registered.
+    pub fn dispatch(&mut self, handler: &str, value: Option<String>) -> Result<(), HeadlessError>
+    where
+        M: 'static,
+    {
+        if !self.handler_registry.contains(handler) {
+            return Err(HeadlessError::UnknownHandler(handler.to_string()));
+        }
+        match &self.shared_context {
+            Some(shared) => {
+                self.handler_registry
+                    .dispatch_with_shared(handler, &mut self.model, shared, value);
+            }
+            None => self
+                .handler_registry
+                .dispatch(handler, &mut self.model, value),
+        }
+        Ok(())
+    }
+
+    /// Evaluate the widget tree against the current model and shared state
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeadlessError::Binding`] for the first binding that fails to evaluate.
+    pub fn render(&self) -> Result<EvaluatedNode, HeadlessError> {
+        let shared = self
+            .shared_context
+            .as_ref()
+            .map(|shared| shared as &dyn UiBindable);
+        let mut renderer = Renderer {
+            model: &self.model,
+            shared,
+            locals: Vec::new(),
+            scope: Vec::new(),
+        };
+        renderer
+            .render(&self.document.root)
+            .map_err(HeadlessError::Binding)
+    }
+
+    /// Values of the bindable fields of the model, by field path
+    ///
+    /// Nested models are listed with their dotted path (e.g., `footer.note`).
+    pub fn model_fields(&self) -> BTreeMap<String, BindingValue> {
+        M::available_fields()
+            .into_iter()
+            .filter_map(|field| {
+                let path: Vec<&str> = field.split('.').collect();
+                let value = self.model.get_field(&path)?;
+                Some((field, value))
+            })
+            .collect()
+    }
+
+    /// Evaluate a binding expression (without braces) against the model and shared state
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeadlessError::Binding`] if the expression is invalid or fails to evaluate.
+    pub fn evaluate(&self, expr: &str) -> Result<BindingValue, HeadlessError> {
+        let expr = parse_binding_expr(expr, 0, 1, 1).map_err(HeadlessError::Binding)?;
+        let shared = self
+            .shared_context
+            .as_ref()
+            .map(|shared| shared as &dyn UiBindable);
+        evaluate_binding_expr_with_shared(&expr, &self.model, shared)
+            .map_err(HeadlessError::Binding)
+    }
+}
+
+/// Evaluation state while walking the tree: loop variables and the `model` scope
+struct Renderer<'a> {
+    model: &'a dyn UiBindable,
+    shared: Option<&'a dyn UiBindable>,
+    locals: Vec<(String, BindingValue)>,
+    scope: Vec<String>,
+}
+
+impl Renderer<'_> {
+    fn render(&mut self, node: &WidgetNode) -> Result<EvaluatedNode, BindingError> {
+        // The model path itself is relative to the enclosing scope
+        // unless it starts at a loop variable
+        let outer_scope = self.scope.clone();
+        if let Some(path) = model_scope(node) {
+            let is_local = path
+                .first()
+                .is_some_and(|first| self.locals.iter().any(|(name, _)| name == first));
+            if is_local {
+                self.scope.clear();
+            }
+            self.scope.extend(path.iter().cloned());
+        }
+        let rendered = self.render_scoped(node);
+        self.scope = outer_scope;
+        rendered
+    }
+
+    fn render_scoped(&mut self, node: &WidgetNode) -> Result<EvaluatedNode, BindingError> {
+        let mut attributes = BTreeMap::new();
+        for (name, value) in &node.attributes {
+            if name != MODEL_ATTRIBUTE && !node.is_option_template(name) {
+                attributes.insert(name.clone(), self.attribute(value)?);
+            }
+        }
+        if node.has_bound_options() {
+            self.render_options(node, &mut attributes)?;
+        }
+
+        let mut events = Vec::with_capacity(node.events.len());
+        for event in &node.events {
+            let value = match &event.param {
+                Some(param) => Some(self.evaluate(param)?.to_display_string()),
+                None if !event.params.is_empty() => {
+                    let mut values = Vec::with_capacity(event.params.len());
+                    for param in &event.params {
+                        values.push(self.evaluate(param)?.to_display_string());
+                    }
+                    Some(encode_event_args(&values))
+                }
+                None => None,
+            };
+            events.push(EvaluatedEvent {
+                event: event.event.clone(),
+                handler: event.handler.clone(),
+                value,
+            });
+        }
+
+        let children = match node.kind {
+            WidgetKind::For => self.render_for(node, &attributes)?,
+            WidgetKind::If if !condition_holds(node, &attributes) => Vec::new(),
+            _ => self.render_children(node)?,
+        };
+
+        Ok(EvaluatedNode {
+            kind: node.kind.clone(),
+            id: node.id.clone(),
+            attributes,
+            events,
+            children,
+        })
+    }
+
+    fn render_children(&mut self, node: &WidgetNode) -> Result<Vec<EvaluatedNode>, BindingError> {
+        node.children
+            .iter()
+            .map(|child| self.render(child))
+            .collect()
+    }
+
+    fn render_for(
+        &mut self,
+        node: &WidgetNode,
+        attributes: &BTreeMap<String, BindingValue>,
+    ) -> Result<Vec<EvaluatedNode>, BindingError> {
+        let Some(BindingValue::List(items)) = attributes.get("in") else {
+            return Ok(Vec::new());
+        };
+        let each = match node.attributes.get("each") {
+            Some(AttributeValue::Static(name)) => name.clone(),
+            _ => "item".to_string(),
+        };
+
+        let page = page_range(
+            items.len(),
+            page_attribute(attributes, "page"),
+            page_attribute(attributes, "page_size"),
+        );
+
+        let mut children = Vec::with_capacity(page.len() * node.children.len());
+        for (index, item) in items.iter().enumerate().skip(page.start).take(page.len()) {
+            self.locals.push((each.clone(), item.clone()));
+            self.locals
+                .push(("index".to_string(), BindingValue::Integer(index as i64)));
+            let rendered = self.render_children(node);
+            self.locals.truncate(self.locals.len() - 2);
+            children.extend(rendered?);
+        }
+        Ok(children)
+    }
+
+    /// Evaluates the `display` and `value` of each bound option into lists
+    ///
+    /// A radio group has no `value` list: its `value` is the selected option.
+    fn render_options(
+        &mut self,
+        node: &WidgetNode,
+        attributes: &mut BTreeMap<String, BindingValue>,
+    ) -> Result<(), BindingError> {
+        let items = match attributes.get("options") {
+            Some(BindingValue::List(items)) => items.clone(),
+            _ => Vec::new(),
+        };
+
+        let has_values = node.is_option_template("value");
+        let mut labels = Vec::with_capacity(items.len());
+        let mut values = Vec::with_capacity(items.len());
+        for (index, item) in items.iter().enumerate() {
+            self.locals.push(("item".to_string(), item.clone()));
+            self.locals
+                .push(("index".to_string(), BindingValue::Integer(index as i64)));
+            let label = match node.attributes.get("display") {
+                Some(display) => self.attribute(display),
+                None => Ok(item.clone()),
+            };
+            let value = match node.attributes.get("value") {
+                Some(value) if has_values => self.attribute(value),
+                _ => label.clone(),
+            };
+            self.locals.truncate(self.locals.len() - 2);
+            labels.push(label?);
+            if has_values {
+                values.push(value?);
+            }
+        }
+
+        attributes.insert("display".to_string(), BindingValue::List(labels));
+        if has_values {
+            attributes.insert("value".to_string(), BindingValue::List(values));
+        }
+        Ok(())
+    }
+
+    fn attribute(&self, value: &AttributeValue) -> Result<BindingValue, BindingError> {
+        match value {
+            AttributeValue::Static(text) => Ok(BindingValue::String(text.clone())),
+            AttributeValue::Binding(binding) => self.evaluate(binding),
+            AttributeValue::Interpolated(parts) => {
+                evaluate_formatted_with_shared(parts, &self.bindable(), self.shared)
+                    .map(BindingValue::String)
+            }
+        }
+    }
+
+    fn evaluate(&self, binding: &crate::expr::BindingExpr) -> Result<BindingValue, BindingError> {
+        evaluate_binding_expr_with_shared(binding, &self.bindable(), self.shared)
+    }
+
+    fn bindable(&self) -> ScopedModel<'_> {
+        ScopedModel {
+            model: self.model,
+            locals: &self.locals,
+            scope: &self.scope,
+        }
+    }
+}
+
+/// The model seen by a binding: loop variables first, then the model under the current scope
+struct ScopedModel<'a> {
+    model: &'a dyn UiBindable,
+    locals: &'a [(String, BindingValue)],
+    scope: &'a [String],
+}
+
+impl ScopedModel<'_> {
+    fn local(&self, path: &[&str]) -> Option<Option<BindingValue>> {
+        let (first, rest) = path.split_first()?;
+        let (_, value) = self.locals.iter().rev().find(|(name, _)| name == first)?;
+        Some(nested_field(value, rest))
+    }
+}
+
+impl UiBindable for ScopedModel<'_> {
+    fn get_field(&self, path: &[&str]) -> Option<BindingValue> {
+        if let Some(value) = self.local(path) {
+            return value;
+        }
+        if self.scope.is_empty() {
+            return self.model.get_field(path);
+        }
+
+        // A scope may itself start at a loop variable (`model="{line.product}"`)
+        let scoped: Vec<&str> = self
+            .scope
+            .iter()
+            .map(String::as_str)
+            .chain(path.iter().copied())
+            .collect();
+        match self.local(&scoped) {
+            Some(value) => value,
+            None => self.model.get_field(&scoped),
+        }
+    }
+
+    /// Fields depend on the scope, so none are listed
+    fn available_fields() -> Vec<String> {
+        Vec::new()
+    }
+}
+
+fn nested_field(value: &BindingValue, path: &[&str]) -> Option<BindingValue> {
+    match path.split_first() {
+        None => Some(value.clone()),
+        Some((first, rest)) => match value {
+            BindingValue::Object(fields) => nested_field(fields.get(*first)?, rest),
+            _ => None,
+        },
+    }
+}
+
+/// `page` or `page_size` of a `<for>`, 0 if missing
+fn page_attribute(attributes: &BTreeMap<String, BindingValue>, name: &str) -> usize {
+    attributes
+        .get(name)
+        .map_or(0, |value| parse_page(&value.to_display_string()))
+}
+
+/// `<if>` condition: a static value must read `true` or `1`, a binding is tested with [`BindingValue::to_bool`]
+fn condition_holds(node: &WidgetNode, attributes: &BTreeMap<String, BindingValue>) -> bool {
+    match node.attributes.get("condition") {
+        Some(AttributeValue::Static(text)) => text == "true" || text == "1",
+        Some(_) => attributes
+            .get("condition")
+            .is_some_and(BindingValue::to_bool),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_scoped_model_resolves_locals_before_model() {
+        let mut product = HashMap::new();
+        product.insert("name".to_string(), BindingValue::String("tea".to_string()));
+        let locals = vec![("line".to_string(), BindingValue::Object(product))];
+        let model = ();
+        let scoped = ScopedModel {
+            model: &model,
+            locals: &locals,
+            scope: &["line".to_string()],
+        };
+
+        assert_eq!(
+            scoped.get_field(&["line", "name"]),
+            Some(BindingValue::String("tea".to_string()))
+        );
+        assert_eq!(
+            scoped.get_field(&["name"]),
+            Some(BindingValue::String("tea".to_string()))
+        );
+        assert_eq!(scoped.get_field(&["line", "price"]), None);
+    }
+}
@@ -63,10 +63,17 @@
 //! - [`HandlerRegistry`] - Event handler registry
 //! - [`UiBindable`] - Trait for bindable models
 //! - [`SharedContext`] - Shared state container
+//! - [`EvaluatedNode`] - Widget tree evaluated without a UI backend
 
+mod drag;
+mod headless;
 mod theme_context;
+mod widget_state;
 
-pub use theme_context::ThemeContext;
+pub use drag::{DragSession, DragTracker};
+pub use headless::{EvaluatedEvent, EvaluatedNode, HeadlessError};
+pub use theme_context::{ThemeContext, ThemeDiff};
+pub use widget_state::{ScrollOffset, TextCursor, WidgetStateSnapshot, WidgetStateTracker};
 
 use std::marker::PhantomData;
 use std::sync::{RwLockReadGuard, RwLockWriteGuard};
@@ -96,6 +103,8 @@ use crate::{binding::UiBindable, handler::HandlerRegistry, ir::DampenDocument};
 /// * `handler_registry` - Registry of event handlers for UI interactions
 /// * `shared_context` - Optional reference to shared state across views
 /// * `theme_context` - Optional theme context for theming support
+/// * `widget_state` - Ephemeral widget state (scroll offsets, cursors, focus) kept across hot-reload
+/// * `drag` - Drag-and-drop gesture in progress for `draggable` widgets
 #[derive(Debug, Clone)]
 pub struct AppState<M: UiBindable = (), S: UiBindable + Send + Sync + 'static = ()> {
     /// The parsed UI document containing widget tree and themes.
@@ -115,6 +124,16 @@ pub struct AppState<M: UiBindable = (), S: UiBindable + Send + Sync + 'static =
     /// None when no theme.dampen file is present.
     pub theme_context: Option<ThemeContext>,
 
+    /// Ephemeral widget state recorded by the backend, keyed by XML id.
+    /// Preserved across hot-reload so scroll positions and focus survive a rebuild.
+    pub widget_state: WidgetStateTracker,
+
+    /// Drag-and-drop gesture in progress, shared by `draggable` widgets.
+    pub drag: DragTracker,
+
+    /// Number of hot-reloads applied to this state, annotating its log spans.
+    pub reload_generation: u64,
+
     /// Type marker to capture the generic parameters.
     _marker: PhantomData<(M, S)>,
 }
@@ -148,6 +167,9 @@ impl<M: UiBindable> AppState<M, ()> {
             handler_registry: HandlerRegistry::default(),
             shared_context: None,
             theme_context: None,
+            widget_state: WidgetStateTracker::default(),
+            drag: DragTracker::default(),
+            reload_generation: 0,
             _marker: PhantomData,
         }
     }
@@ -177,6 +199,9 @@ impl<M: UiBindable> AppState<M, ()> {
             handler_registry: HandlerRegistry::default(),
             shared_context: None,
             theme_context: None,
+            widget_state: WidgetStateTracker::default(),
+            drag: DragTracker::default(),
+            reload_generation: 0,
             _marker: PhantomData,
         }
     }
@@ -206,6 +231,9 @@ impl<M: UiBindable> AppState<M, ()> {
             handler_registry,
             shared_context: None,
             theme_context: None,
+            widget_state: WidgetStateTracker::default(),
+            drag: DragTracker::default(),
+            reload_generation: 0,
             _marker: PhantomData,
         }
     }
@@ -244,6 +272,9 @@ impl<M: UiBindable> AppState<M, ()> {
             handler_registry,
             shared_context: None,
             theme_context: None,
+            widget_state: WidgetStateTracker::default(),
+            drag: DragTracker::default(),
+            reload_generation: 0,
             _marker: PhantomData,
         }
     }
@@ -301,6 +332,9 @@ impl<M: UiBindable, S: UiBindable + Send + Sync + 'static> AppState<M, S> {
             handler_registry,
             shared_context: Some(shared_context),
             theme_context: None,
+            widget_state: WidgetStateTracker::default(),
+            drag: DragTracker::default(),
+            reload_generation: 0,
             _marker: PhantomData,
         }
     }
@@ -352,7 +386,8 @@ impl<M: UiBindable, S: UiBindable + Send + Sync + 'static> AppState<M, S> {
     ///
     /// This method is designed for development mode hot-reload scenarios where the UI
     /// definition (XML) changes but the application state (model and shared state)
-    /// should be preserved.
+    /// should be preserved. Recorded widget state is kept for every widget id that still
+    /// exists in the new document, so the backend can restore it after rebuilding.
     ///
     /// # Examples
     ///
@@ -379,6 +414,8 @@ impl<M: UiBindable, S: UiBindable + Send + Sync + 'static> AppState<M, S> {
     /// ```
     pub fn hot_reload(&mut self, new_document: DampenDocument) {
         self.document = new_document;
+        self.widget_state.retain_ids(&self.document);
+        self.reload_generation += 1;
     }
 
     /// Set the theme context for this AppState.
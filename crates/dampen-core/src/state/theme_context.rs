@@ -3,8 +3,9 @@
 //! This module provides the [`ThemeContext`] struct that holds the current
 //! active theme and manages theme switching at runtime.
 
-use crate::ir::theme::{Theme, ThemeDocument, ThemeError, ThemeErrorKind};
+use crate::ir::theme::{Theme, ThemeDocument, ThemeError, ThemeErrorKind, ThemePalette};
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 /// Runtime theme context shared across all windows.
 ///
@@ -32,6 +33,17 @@ pub struct ThemeContext {
     system_preference: Option<String>,
     follow_system: bool,
     user_preference: Option<String>,
+    transition: Option<ThemeTransition>,
+}
+
+/// Palette transition from the previous theme to the active one
+#[derive(Debug, Clone)]
+struct ThemeTransition {
+    from: ThemePalette,
+    started: Instant,
+    duration: Duration,
+    /// Active theme with the palette reached so far
+    current: Theme,
 }
 
 impl ThemeContext {
@@ -59,6 +71,7 @@ impl ThemeContext {
             return Err(ThemeError {
                 kind: ThemeErrorKind::NoThemesDefined,
                 message: "THEME_001: Cannot create ThemeContext with no themes".to_string(),
+                span: None,
             });
         }
 
@@ -75,6 +88,7 @@ impl ThemeContext {
                     "THEME_006: Active theme '{}' not found in document",
                     active_theme
                 ),
+                span: None,
             });
         }
 
@@ -84,16 +98,50 @@ impl ThemeContext {
             system_preference: system_preference.map(|s| s.to_string()),
             follow_system: document.follow_system,
             user_preference: None,
+            transition: None,
         })
     }
 
     /// Get the currently active theme.
     ///
+    /// When the system asks for high contrast (see [`crate::system`]), the
+    /// `<name>_high_contrast` variant of the active theme is returned if it exists.
+    /// During a transition (see [`ThemeContext::switch_theme`]), its palette is
+    /// part of the way from the previous theme.
+    ///
     /// # Returns
     ///
     /// Reference to the active [`Theme`].
-    #[allow(clippy::unwrap_used)]
     pub fn active(&self) -> &Theme {
+        if let Some(transition) = &self.transition {
+            return &transition.current;
+        }
+        self.active_with_contrast(crate::system::preferences().high_contrast)
+    }
+
+    /// Get a theme by name, for the `theme="..."` overrides of a subtree.
+    ///
+    /// Like [`ThemeContext::active`], the high-contrast variant of the theme is
+    /// returned when the system asks for it.
+    pub fn theme(&self, name: &str) -> Option<&Theme> {
+        if crate::system::preferences().high_contrast
+            && let Some(theme) = self.themes.get(&crate::system::high_contrast_variant(name))
+        {
+            return Some(theme);
+        }
+        self.themes.get(name)
+    }
+
+    /// The active theme, or its high-contrast variant if `high_contrast` and it exists
+    #[allow(clippy::unwrap_used)]
+    fn active_with_contrast(&self, high_contrast: bool) -> &Theme {
+        if high_contrast
+            && let Some(theme) = self
+                .themes
+                .get(&crate::system::high_contrast_variant(&self.active_theme))
+        {
+            return theme;
+        }
         self.themes.get(&self.active_theme).unwrap()
     }
 
@@ -116,14 +164,89 @@ impl ThemeContext {
             return Err(ThemeError {
                 kind: ThemeErrorKind::ThemeNotFound,
                 message: format!("THEME_006: Theme '{}' not found", name),
+                span: None,
             });
         }
 
         self.active_theme = name.to_string();
         self.user_preference = Some(name.to_string());
+        self.transition = None;
         Ok(())
     }
 
+    /// Switch to a different theme by name, fading over `transition`
+    /// (see [`crate::theme_switch::ThemeSwitch::transition`]).
+    ///
+    /// Without transition, this is [`ThemeContext::set_theme`]. Otherwise the
+    /// palette of the active theme fades from the previous one as
+    /// [`ThemeContext::tick`] is called.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ThemeError::ThemeNotFound` if the theme doesn't exist.
+    pub fn switch_theme(
+        &mut self,
+        name: &str,
+        transition: Option<Duration>,
+    ) -> Result<(), ThemeError> {
+        let from = self.active().palette.clone();
+        self.set_theme(name)?;
+
+        if let Some(duration) = transition {
+            self.start_transition(from, duration, Instant::now());
+        }
+        Ok(())
+    }
+
+    /// Fade the palette of the active theme from `from`, starting at `started`
+    fn start_transition(&mut self, from: ThemePalette, duration: Duration, started: Instant) {
+        self.transition = Some(ThemeTransition {
+            from: from.clone(),
+            started,
+            duration,
+            current: Theme {
+                palette: from,
+                ..self.active().clone()
+            },
+        });
+    }
+
+    /// Advance the transition between themes to `now`.
+    ///
+    /// # Returns
+    ///
+    /// `true` while the transition runs: the view must be rendered again on the
+    /// next frame.
+    pub fn tick(&mut self, now: Instant) -> bool {
+        let Some(transition) = &self.transition else {
+            return false;
+        };
+
+        let elapsed = now.saturating_duration_since(transition.started);
+        if elapsed >= transition.duration {
+            self.transition = None;
+            return false;
+        }
+
+        let t = elapsed.as_secs_f32() / transition.duration.as_secs_f32();
+        let from = transition.from.clone();
+        let target = self
+            .active_with_contrast(crate::system::preferences().high_contrast)
+            .clone();
+        if let Some(transition) = &mut self.transition {
+            transition.current = Theme {
+                palette: from.blend(&target.palette, t),
+                ..target
+            };
+        }
+        true
+    }
+
+    /// Check if a transition between themes is running.
+    pub fn is_transitioning(&self) -> bool {
+        self.transition.is_some()
+    }
+
     /// Update the system preference and potentially switch theme.
     ///
     /// If the document is configured to follow system preference,
@@ -175,6 +298,7 @@ impl ThemeContext {
         let fallback_theme = document.effective_default(self.system_preference.as_deref());
 
         self.themes = resolved_themes;
+        self.transition = None;
         self.active_theme = if self.themes.contains_key(&old_active) {
             old_active
         } else {
@@ -182,17 +306,134 @@ impl ThemeContext {
         };
     }
 
+    /// Compute the changes between the loaded themes and a new theme document.
+    ///
+    /// Themes are compared after inheritance resolution, so editing a parent
+    /// theme also reports every theme that extends it.
+    ///
+    /// # Arguments
+    ///
+    /// * `document` - The new parsed theme document
+    pub fn diff(&self, document: &ThemeDocument) -> ThemeDiff {
+        let resolved_themes = document.resolve_inheritance();
+
+        let mut removed: Vec<String> = self
+            .themes
+            .keys()
+            .filter(|name| !resolved_themes.contains_key(*name))
+            .cloned()
+            .collect();
+        removed.sort();
+
+        let changed = resolved_themes
+            .into_iter()
+            .filter(|(name, theme)| self.themes.get(name) != Some(theme))
+            .collect();
+
+        ThemeDiff {
+            changed,
+            removed,
+            fallback_theme: document
+                .effective_default(self.system_preference.as_deref())
+                .to_string(),
+            follow_system: (document.follow_system != self.follow_system)
+                .then_some(document.follow_system),
+        }
+    }
+
+    /// Apply a [`ThemeDiff`] in place (for hot-reload).
+    ///
+    /// Only the themes listed in the diff are replaced; unchanged themes keep
+    /// their resolved values. The active theme is preserved unless it was removed,
+    /// in which case the diff's fallback theme becomes active.
+    ///
+    /// # Arguments
+    ///
+    /// * `diff` - Changes computed with [`ThemeContext::diff`]
+    ///
+    /// # Returns
+    ///
+    /// `true` if the active theme changed (either its values or which theme is active)
+    /// and styles resolved from it must be recomputed.
+    pub fn apply_update(&mut self, diff: ThemeDiff) -> bool {
+        if diff.is_empty() {
+            return false;
+        }
+
+        let old_active = self.active_theme.clone();
+        let mut active_changed = diff.affects(&old_active);
+
+        self.transition = None;
+        for name in &diff.removed {
+            self.themes.remove(name);
+        }
+        self.themes.extend(diff.changed);
+
+        if !self.themes.contains_key(&self.active_theme) {
+            self.active_theme = diff.fallback_theme;
+        }
+
+        if let Some(follow) = diff.follow_system {
+            self.set_follow_system(follow);
+        }
+
+        active_changed |= self.active_theme != old_active;
+        active_changed
+    }
+
     /// Get all available theme names.
     pub fn available_themes(&self) -> Vec<&str> {
         self.themes.keys().map(|s| s.as_str()).collect()
     }
 
+    /// Font files declared by the themes, sorted and without duplicates.
+    pub fn fonts(&self) -> Vec<&str> {
+        let mut fonts: Vec<&str> = self
+            .themes
+            .values()
+            .flat_map(|theme| theme.typography.fonts.iter().map(String::as_str))
+            .collect();
+        fonts.sort_unstable();
+        fonts.dedup();
+        fonts
+    }
+
     /// Check if a theme with the given name exists.
     pub fn has_theme(&self, name: &str) -> bool {
         self.themes.contains_key(name)
     }
 }
 
+/// Changes between the themes of a [`ThemeContext`] and a new theme document.
+///
+/// Produced by [`ThemeContext::diff`] and applied with [`ThemeContext::apply_update`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ThemeDiff {
+    /// Resolved themes that were added or whose values changed
+    pub changed: HashMap<String, Theme>,
+
+    /// Names of themes that no longer exist
+    pub removed: Vec<String>,
+
+    /// Theme to activate if the active theme was removed
+    pub fallback_theme: String,
+
+    /// New `follow_system` setting, if it changed
+    pub follow_system: Option<bool>,
+}
+
+impl ThemeDiff {
+    /// Returns `true` if applying this diff would not change anything
+    pub fn is_empty(&self) -> bool {
+        self.changed.is_empty() && self.removed.is_empty() && self.follow_system.is_none()
+    }
+
+    /// Returns `true` if the theme with the given name is changed or removed
+    pub fn affects(&self, name: &str) -> bool {
+        self.changed.contains_key(name) || self.removed.iter().any(|removed| removed == name)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -220,8 +461,11 @@ mod tests {
                 font_size_large: Some(24.0),
                 font_weight: crate::ir::theme::FontWeight::Normal,
                 line_height: Some(1.5),
+                scale_ratio: None,
+                fonts: Vec::new(),
             },
             spacing: SpacingScale { unit: Some(8.0) },
+            shadows: Default::default(),
             base_styles: HashMap::new(),
             extends: None,
         }
@@ -246,6 +490,24 @@ mod tests {
         assert_eq!(ctx.active_name(), "dark");
     }
 
+    #[test]
+    fn test_high_contrast_variant_replaces_active_theme() {
+        let mut doc = create_test_document();
+        doc.themes.insert(
+            "dark_high_contrast".to_string(),
+            create_test_theme("dark_high_contrast"),
+        );
+        let mut ctx = ThemeContext::from_document(doc, Some("dark")).unwrap();
+
+        assert_eq!(ctx.active_with_contrast(false).name, "dark");
+        assert_eq!(ctx.active_with_contrast(true).name, "dark_high_contrast");
+        assert_eq!(ctx.active_name(), "dark");
+
+        // Themes without a variant are kept
+        ctx.set_theme("light").unwrap();
+        assert_eq!(ctx.active_with_contrast(true).name, "light");
+    }
+
     #[test]
     fn test_from_document_without_system_preference() {
         let doc = create_test_document();
@@ -267,6 +529,34 @@ mod tests {
         assert!(ctx.set_theme("nonexistent").is_err());
     }
 
+    #[test]
+    fn test_transition_blends_palettes() {
+        let mut doc = create_test_document();
+        let dark = doc.themes.get_mut("dark").unwrap();
+        dark.palette.background = Some(Color::from_hex("#000000").unwrap());
+        let mut ctx = ThemeContext::from_document(doc, None).unwrap();
+        let light_background = ctx.active().palette.background.unwrap();
+
+        let from = ctx.active().palette.clone();
+        ctx.set_theme("dark").unwrap();
+        let started = Instant::now();
+        ctx.start_transition(from, Duration::from_millis(200), started);
+        assert!(ctx.is_transitioning());
+        assert_eq!(ctx.active().name, "dark");
+        assert_eq!(ctx.active().palette.background, Some(light_background));
+
+        assert!(ctx.tick(started + Duration::from_millis(100)));
+        let background = ctx.active().palette.background.unwrap();
+        assert!((background.r - light_background.r / 2.0).abs() < 0.001);
+
+        assert!(!ctx.tick(started + Duration::from_millis(200)));
+        assert!(!ctx.is_transitioning());
+        assert_eq!(
+            ctx.active().palette.background,
+            Some(Color::from_hex("#000000").unwrap())
+        );
+    }
+
     #[test]
     fn test_update_system_preference() {
         let doc = create_test_document();
@@ -300,6 +590,54 @@ mod tests {
         assert_eq!(ctx.active_name(), "dark");
     }
 
+    #[test]
+    fn test_diff_unchanged_document_is_empty() {
+        let ctx = ThemeContext::from_document(create_test_document(), None).unwrap();
+
+        let diff = ctx.diff(&create_test_document());
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn test_apply_update_replaces_only_changed_themes() {
+        let mut ctx = ThemeContext::from_document(create_test_document(), None).unwrap();
+
+        let mut new_doc = create_test_document();
+        if let Some(dark) = new_doc.themes.get_mut("dark") {
+            dark.palette.primary = Some(Color::from_hex("#000000").unwrap());
+        }
+
+        let diff = ctx.diff(&new_doc);
+        assert_eq!(diff.changed.keys().collect::<Vec<_>>(), vec!["dark"]);
+        assert!(diff.removed.is_empty());
+
+        // Active theme ("light") is not affected
+        assert!(!ctx.apply_update(diff));
+        assert_eq!(ctx.active_name(), "light");
+
+        ctx.set_theme("dark").unwrap();
+        assert_eq!(
+            ctx.active().palette.primary,
+            Some(Color::from_hex("#000000").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_apply_update_falls_back_when_active_removed() {
+        let mut ctx = ThemeContext::from_document(create_test_document(), None).unwrap();
+
+        let mut new_doc = create_test_document();
+        new_doc.default_theme = Some("dark".to_string());
+        new_doc.themes.remove("light");
+
+        let diff = ctx.diff(&new_doc);
+        assert_eq!(diff.removed, vec!["light".to_string()]);
+
+        assert!(ctx.apply_update(diff));
+        assert_eq!(ctx.active_name(), "dark");
+        assert!(!ctx.has_theme("light"));
+    }
+
     #[test]
     fn test_inheritance_resolution() {
         let mut themes = HashMap::new();
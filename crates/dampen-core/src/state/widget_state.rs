@@ -0,0 +1,337 @@
+//! Ephemeral widget state preserved across hot-reload.
+//!
+//! Backends keep some state inside their widgets rather than in the model: scroll
+//! offsets, text input cursors, keyboard focus and expanded tree nodes. Rebuilding the widget tree after a
+//! hot-reload resets that state. [`WidgetStateTracker`] records it as the user
+//! interacts with the UI, keyed by the widget's XML `id`, so the backend can restore
+//! it once the new document has been applied.
+//!
+//! Only widgets with an `id` attribute are tracked.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+
+use serde::{Deserialize, Serialize};
+
+use crate::ir::DampenDocument;
+use crate::ir::diff::NodePath;
+use crate::ir::node::WidgetNode;
+
+/// Absolute scroll offset of a scrollable, in logical pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct ScrollOffset {
+    /// Horizontal offset
+    pub x: f32,
+    /// Vertical offset
+    pub y: f32,
+}
+
+/// Cursor position and selection of a text input, in characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TextCursor {
+    /// Cursor position
+    pub position: usize,
+    /// Selected range `(start, end)`, if any
+    pub selection: Option<(usize, usize)>,
+}
+
+impl TextCursor {
+    /// Cursor left by an edit turning `before` into `after`
+    ///
+    /// The cursor ends up after the changed text, i.e. before the part both
+    /// values end with. Backends whose inputs report the edited value but not
+    /// the cursor record this one instead.
+    pub fn after_edit(before: &str, after: &str) -> Self {
+        let prefix = before
+            .chars()
+            .zip(after.chars())
+            .take_while(|(a, b)| a == b)
+            .count();
+        let before_len = before.chars().count();
+        let after_len = after.chars().count();
+        let suffix = before
+            .chars()
+            .rev()
+            .zip(after.chars().rev())
+            .take_while(|(a, b)| a == b)
+            .count()
+            .min(before_len.min(after_len) - prefix);
+
+        Self {
+            position: after_len - suffix,
+            selection: None,
+        }
+    }
+}
+
+/// Snapshot of ephemeral widget state, keyed by XML id.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct WidgetStateSnapshot {
+    /// Scroll offsets of scrollables
+    pub scroll_offsets: HashMap<String, ScrollOffset>,
+    /// Cursor positions and selections of text inputs
+    pub text_cursors: HashMap<String, TextCursor>,
+    /// Id of the focused widget, if any
+    pub focused: Option<String>,
+    /// Keys of the expanded nodes of trees
+    pub expanded_nodes: HashMap<String, HashSet<String>>,
+}
+
+impl WidgetStateSnapshot {
+    /// Returns `true` if the snapshot holds no state to restore
+    pub fn is_empty(&self) -> bool {
+        self.scroll_offsets.is_empty()
+            && self.text_cursors.is_empty()
+            && self.focused.is_none()
+            && self.expanded_nodes.is_empty()
+    }
+
+    /// Drop entries whose widget id no longer exists in the given document
+    pub fn retain_ids(&mut self, document: &DampenDocument) {
+        let mut ids = HashSet::new();
+        collect_ids(&document.root, &mut ids);
+        self.retain(&ids);
+    }
+
+    /// Keep only the entries of widgets in the subtrees of `document` at `paths`
+    ///
+    /// A path lists the child indices from the root, as in
+    /// [`diff_nodes`](crate::ir::diff::diff_nodes). Paths missing from the
+    /// document are ignored.
+    pub fn retain_subtrees(&mut self, document: &DampenDocument, paths: &[NodePath]) {
+        let mut ids = HashSet::new();
+        for path in paths {
+            if let Some(node) = path
+                .iter()
+                .try_fold(&document.root, |node, &index| node.children.get(index))
+            {
+                collect_ids(node, &mut ids);
+            }
+        }
+        self.retain(&ids);
+    }
+
+    fn retain(&mut self, ids: &HashSet<String>) {
+        self.scroll_offsets.retain(|id, _| ids.contains(id));
+        self.text_cursors.retain(|id, _| ids.contains(id));
+        self.expanded_nodes.retain(|id, _| ids.contains(id));
+        if self.focused.as_ref().is_some_and(|id| !ids.contains(id)) {
+            self.focused = None;
+        }
+    }
+}
+
+/// Recursively collects widget ids from a node and its children.
+fn collect_ids(node: &WidgetNode, ids: &mut HashSet<String>) {
+    if let Some(id) = &node.id {
+        ids.insert(id.clone());
+    }
+    for child in &node.children {
+        collect_ids(child, ids);
+    }
+}
+
+/// Thread-safe recorder for ephemeral widget state.
+///
+/// The tracker is cheap to clone; clones share the same underlying snapshot, so the
+/// widget builder can record into it from event callbacks while the application
+/// holds it in its [`AppState`](crate::state::AppState).
+///
+/// # Example
+///
+/// ```rust
+/// use dampen_core::state::{ScrollOffset, WidgetStateTracker};
+///
+/// let tracker = WidgetStateTracker::new();
+/// tracker.record_scroll("messages", ScrollOffset { x: 0.0, y: 120.0 });
+/// tracker.record_focus("search");
+///
+/// let snapshot = tracker.snapshot();
+/// assert_eq!(snapshot.scroll_offsets["messages"].y, 120.0);
+/// assert_eq!(snapshot.focused.as_deref(), Some("search"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct WidgetStateTracker {
+    state: Arc<RwLock<WidgetStateSnapshot>>,
+}
+
+impl WidgetStateTracker {
+    /// Create an empty tracker
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the scroll offset of a scrollable
+    pub fn record_scroll(&self, id: &str, offset: ScrollOffset) {
+        if let Ok(mut state) = self.state.write() {
+            state.scroll_offsets.insert(id.to_string(), offset);
+        }
+    }
+
+    /// Record the cursor of a text input
+    pub fn record_cursor(&self, id: &str, cursor: TextCursor) {
+        if let Ok(mut state) = self.state.write() {
+            state.text_cursors.insert(id.to_string(), cursor);
+        }
+    }
+
+    /// Record the focused widget
+    pub fn record_focus(&self, id: &str) {
+        if let Ok(mut state) = self.state.write() {
+            state.focused = Some(id.to_string());
+        }
+    }
+
+    /// Record that a node of a tree was expanded or collapsed
+    pub fn record_expanded(&self, id: &str, key: &str, expanded: bool) {
+        if let Ok(mut state) = self.state.write() {
+            let nodes = state.expanded_nodes.entry(id.to_string()).or_default();
+            if expanded {
+                nodes.insert(key.to_string());
+            } else {
+                nodes.remove(key);
+            }
+        }
+    }
+
+    /// Clear the focused widget
+    pub fn clear_focus(&self) {
+        if let Ok(mut state) = self.state.write() {
+            state.focused = None;
+        }
+    }
+
+    /// Get a copy of the recorded state
+    pub fn snapshot(&self) -> WidgetStateSnapshot {
+        self.state
+            .read()
+            .map(|state| state.clone())
+            .unwrap_or_default()
+    }
+
+    /// Replace the recorded state (e.g., with a snapshot taken before a reload)
+    pub fn restore(&self, snapshot: WidgetStateSnapshot) {
+        if let Ok(mut state) = self.state.write() {
+            *state = snapshot;
+        }
+    }
+
+    /// Drop entries whose widget id no longer exists in the given document
+    pub fn retain_ids(&self, document: &DampenDocument) {
+        if let Ok(mut state) = self.state.write() {
+            state.retain_ids(document);
+        }
+    }
+
+    /// Forget all recorded state
+    pub fn clear(&self) {
+        if let Ok(mut state) = self.state.write() {
+            *state = WidgetStateSnapshot::default();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse;
+
+    #[test]
+    fn test_tracker_records_state() {
+        let tracker = WidgetStateTracker::new();
+        tracker.record_scroll("list", ScrollOffset { x: 0.0, y: 42.0 });
+        tracker.record_cursor(
+            "name",
+            TextCursor {
+                position: 3,
+                selection: None,
+            },
+        );
+        tracker.record_focus("name");
+
+        let snapshot = tracker.snapshot();
+        assert_eq!(snapshot.scroll_offsets.get("list").map(|o| o.y), Some(42.0));
+        assert_eq!(
+            snapshot.text_cursors.get("name").map(|c| c.position),
+            Some(3)
+        );
+        assert_eq!(snapshot.focused.as_deref(), Some("name"));
+    }
+
+    #[test]
+    fn test_cursor_after_edit() {
+        let position = |before, after| TextCursor::after_edit(before, after).position;
+        assert_eq!(position("", "a"), 1);
+        assert_eq!(position("abcd", "abxcd"), 3);
+        assert_eq!(position("abcd", "acd"), 1);
+        assert_eq!(position("aa", "aaa"), 3);
+        assert_eq!(position("héllo", "hé llo"), 3);
+    }
+
+    #[test]
+    fn test_tracker_records_expanded_nodes() {
+        let tracker = WidgetStateTracker::new();
+        tracker.record_expanded("files", "0", true);
+        tracker.record_expanded("files", "0.2", true);
+        tracker.record_expanded("files", "0", false);
+
+        let snapshot = tracker.snapshot();
+        assert!(!snapshot.is_empty());
+        let expanded = &snapshot.expanded_nodes["files"];
+        assert!(expanded.contains("0.2"));
+        assert!(!expanded.contains("0"));
+    }
+
+    #[test]
+    fn test_clones_share_state() {
+        let tracker = WidgetStateTracker::new();
+        let clone = tracker.clone();
+        clone.record_focus("search");
+        assert_eq!(tracker.snapshot().focused.as_deref(), Some("search"));
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_retain_ids_drops_removed_widgets() {
+        let tracker = WidgetStateTracker::new();
+        tracker.record_scroll("kept", ScrollOffset { x: 0.0, y: 10.0 });
+        tracker.record_scroll("removed", ScrollOffset { x: 0.0, y: 20.0 });
+        tracker.record_focus("removed");
+
+        let document =
+            parse(r#"<scrollable id="kept"><column /></scrollable>"#).expect("valid document");
+        tracker.retain_ids(&document);
+
+        let snapshot = tracker.snapshot();
+        assert!(snapshot.scroll_offsets.contains_key("kept"));
+        assert!(!snapshot.scroll_offsets.contains_key("removed"));
+        assert!(snapshot.focused.is_none());
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_retain_subtrees_keeps_changed_widgets() {
+        let mut snapshot = WidgetStateSnapshot::default();
+        snapshot
+            .scroll_offsets
+            .insert("list".to_string(), ScrollOffset { x: 0.0, y: 10.0 });
+        snapshot
+            .text_cursors
+            .insert("name".to_string(), TextCursor::default());
+        snapshot.focused = Some("name".to_string());
+
+        let document = parse(
+            r#"<column><scrollable id="list"><column /></scrollable><row><text_input id="name" value="" /></row></column>"#,
+        )
+        .expect("valid document");
+
+        let mut changed = snapshot.clone();
+        changed.retain_subtrees(&document, &[vec![1], vec![5]]);
+        assert!(changed.scroll_offsets.is_empty());
+        assert!(changed.text_cursors.contains_key("name"));
+        assert_eq!(changed.focused.as_deref(), Some("name"));
+
+        snapshot.retain_subtrees(&document, &[]);
+        assert!(snapshot.is_empty());
+    }
+}
@@ -0,0 +1,149 @@
+//! Accessibility preferences of the system
+//!
+//! [`SystemPreferences`] holds the reduced-motion and high-contrast settings of
+//! the desktop. They are bound with the reserved `system` root:
+//!
+//! ```xml
+//! <if condition="{system.reduced_motion}">
+//!     <text value="Animations are disabled" />
+//! </if>
+//! ```
+//!
+//! The detected values are stored by `dampen_iced::system_preferences`, which
+//! reads them from the platform. An application may force either setting, for
+//! instance from its own settings page, with [`set_reduced_motion`] and
+//! [`set_high_contrast`]; the override wins over the detected value until it is
+//! reset with `None`.
+//!
+//! The preferences are honored by the framework too: animations, such as the
+//! skeleton shimmer, stand still with reduced motion, and the
+//! `<name>_high_contrast` variant of the active theme, when defined, replaces
+//! it in high contrast (see [`high_contrast_variant`]).
+
+use std::sync::{LazyLock, Mutex, MutexGuard, PoisonError};
+
+use crate::binding::{BindingValue, UiBindable};
+
+/// Suffix of the high-contrast variant of a theme
+pub const HIGH_CONTRAST_SUFFIX: &str = "_high_contrast";
+
+/// Accessibility preferences of the system
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SystemPreferences {
+    /// Animations should be avoided
+    pub reduced_motion: bool,
+    /// Colors should have the highest contrast
+    pub high_contrast: bool,
+}
+
+impl UiBindable for SystemPreferences {
+    fn get_field(&self, path: &[&str]) -> Option<BindingValue> {
+        match path {
+            ["reduced_motion"] => Some(BindingValue::Bool(self.reduced_motion)),
+            ["high_contrast"] => Some(BindingValue::Bool(self.high_contrast)),
+            _ => None,
+        }
+    }
+
+    fn available_fields() -> Vec<String> {
+        vec!["reduced_motion".to_string(), "high_contrast".to_string()]
+    }
+}
+
+/// Detected preferences and the overrides of the application
+#[derive(Debug, Default)]
+struct Store {
+    detected: SystemPreferences,
+    reduced_motion: Option<bool>,
+    high_contrast: Option<bool>,
+}
+
+static STORE: LazyLock<Mutex<Store>> = LazyLock::new(Mutex::default);
+
+fn store() -> MutexGuard<'static, Store> {
+    STORE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Current preferences: the detected values, unless overridden
+pub fn preferences() -> SystemPreferences {
+    let store = store();
+    SystemPreferences {
+        reduced_motion: store
+            .reduced_motion
+            .unwrap_or(store.detected.reduced_motion),
+        high_contrast: store.high_contrast.unwrap_or(store.detected.high_contrast),
+    }
+}
+
+/// Store the preferences read from the platform
+///
+/// Returns `true` if they changed.
+pub fn set_detected(preferences: SystemPreferences) -> bool {
+    let mut store = store();
+    let changed = store.detected != preferences;
+    store.detected = preferences;
+    changed
+}
+
+/// Force reduced motion on or off, or follow the system again with `None`
+pub fn set_reduced_motion(reduced_motion: Option<bool>) {
+    store().reduced_motion = reduced_motion;
+}
+
+/// Force high contrast on or off, or follow the system again with `None`
+pub fn set_high_contrast(high_contrast: Option<bool>) {
+    store().high_contrast = high_contrast;
+}
+
+/// Value of a `{system.<field>}` binding, `path` not including `system`
+pub fn get_field(path: &[&str]) -> Option<BindingValue> {
+    preferences().get_field(path)
+}
+
+/// Name of the high-contrast variant of a theme: `dark` becomes `dark_high_contrast`
+pub fn high_contrast_variant(theme: &str) -> String {
+    format!("{}{}", theme, HIGH_CONTRAST_SUFFIX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overrides_win_over_detected_preferences() {
+        assert!(set_detected(SystemPreferences {
+            reduced_motion: true,
+            high_contrast: false,
+        }));
+        assert!(preferences().reduced_motion);
+
+        set_reduced_motion(Some(false));
+        set_high_contrast(Some(true));
+        assert_eq!(
+            preferences(),
+            SystemPreferences {
+                reduced_motion: false,
+                high_contrast: true,
+            }
+        );
+        assert_eq!(
+            get_field(&["high_contrast"]).map(|value| value.to_display_string()),
+            Some("true".to_string())
+        );
+
+        // Bound as `{system.<field>}` when the model has no such field
+        let binding =
+            crate::expr::tokenizer::tokenize_binding_expr("system.high_contrast", 0, 1, 1)
+                .unwrap();
+        assert_eq!(
+            crate::evaluate_binding_expr(&binding, &()).map(|value| value.to_display_string()),
+            Ok("true".to_string())
+        );
+
+        set_reduced_motion(None);
+        set_high_contrast(None);
+        assert!(preferences().reduced_motion);
+        assert!(!preferences().high_contrast);
+        assert_eq!(get_field(&["animations"]), None);
+    }
+}
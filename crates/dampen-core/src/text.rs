@@ -0,0 +1,198 @@
+//! Wrapping and clamping of long text
+//!
+//! Long bound strings are kept from blowing up layouts with three `<text>`
+//! attributes:
+//!
+//! ```xml
+//! <text value="{title}" wrap="none" truncate="ellipsis" width="200" />
+//! <text value="{summary}" max_lines="2" truncate="ellipsis" width="320" />
+//! ```
+//!
+//! - `wrap`: `word` (default), `glyph`, `word_or_glyph` or `none`
+//! - `max_lines`: lines shown at most, the text being cut below them
+//! - `truncate="ellipsis"`: end cut text with `…`
+//!
+//! iced cannot ellipsize text, so [`TextClamp::apply`] shortens the string
+//! before it is laid out, estimating how many characters fit on a line from the
+//! width and font size of the text.
+
+use std::borrow::Cow;
+
+/// Line height of text, as a factor of the font size (iced's default)
+pub const LINE_HEIGHT: f32 = 1.3;
+
+/// Average advance of a glyph, as a factor of the font size
+const GLYPH_WIDTH: f32 = 0.55;
+
+/// Ellipsis ending truncated text
+pub const ELLIPSIS: char = '…';
+
+/// How text wraps when it is wider than its bounds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextWrap {
+    /// At word boundaries
+    #[default]
+    Word,
+    /// Between any two glyphs
+    Glyph,
+    /// At word boundaries, or between glyphs for words longer than a line
+    WordOrGlyph,
+    /// Never, text stays on one line per paragraph
+    None,
+}
+
+impl TextWrap {
+    /// Parse a `wrap` attribute
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.trim() {
+            "word" => Ok(TextWrap::Word),
+            "glyph" => Ok(TextWrap::Glyph),
+            "word_or_glyph" => Ok(TextWrap::WordOrGlyph),
+            "none" => Ok(TextWrap::None),
+            other => Err(format!(
+                "Invalid wrap '{}'. Expected word, glyph, word_or_glyph or none",
+                other
+            )),
+        }
+    }
+}
+
+/// Lines and ellipsis of a `<text>`
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TextClamp {
+    /// How the text wraps
+    pub wrap: TextWrap,
+    /// Lines shown at most
+    pub max_lines: Option<usize>,
+    /// Whether cut text ends with an ellipsis
+    pub ellipsis: bool,
+    /// Characters estimated to fit on a line, when the width is known
+    pub chars_per_line: Option<usize>,
+}
+
+impl TextClamp {
+    /// Clamp from the `wrap`, `max_lines` and `truncate` attributes of a text
+    ///
+    /// `width` is the fixed or maximum width of the text and `size` its font
+    /// size. Invalid attribute values are ignored.
+    pub fn new(
+        wrap: Option<&str>,
+        max_lines: Option<&str>,
+        truncate: Option<&str>,
+        width: Option<f32>,
+        size: f32,
+    ) -> Self {
+        let chars_per_line = width
+            .filter(|width| *width > 0.0 && size > 0.0)
+            .map(|width| ((width / (size * GLYPH_WIDTH)).floor() as usize).max(1));
+        TextClamp {
+            wrap: wrap
+                .and_then(|wrap| TextWrap::parse(wrap).ok())
+                .unwrap_or_default(),
+            max_lines: max_lines
+                .and_then(|lines| lines.trim().parse::<usize>().ok())
+                .filter(|lines| *lines > 0),
+            ellipsis: truncate.is_some_and(|truncate| truncate.trim() == "ellipsis"),
+            chars_per_line,
+        }
+    }
+
+    /// Whether the clamp changes nothing
+    pub fn is_none(&self) -> bool {
+        self.wrap == TextWrap::Word && self.max_lines.is_none() && !self.ellipsis
+    }
+
+    /// Height of the lines shown, `None` without `max_lines`
+    pub fn height(&self, size: f32) -> Option<f32> {
+        self.max_lines
+            .map(|lines| (lines as f32 * size * LINE_HEIGHT).ceil())
+    }
+
+    /// Cut `text` to the lines it may take, ending it with an ellipsis
+    ///
+    /// Text is returned unchanged without `truncate="ellipsis"`, the height of
+    /// [`TextClamp::height`] hiding the extra lines.
+    pub fn apply<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if !self.ellipsis {
+            return Cow::Borrowed(text);
+        }
+
+        let wraps = self.wrap != TextWrap::None;
+        let mut lines_left = self.max_lines.unwrap_or(usize::MAX);
+        let mut clamped = String::new();
+
+        for (index, paragraph) in text.split('\n').enumerate() {
+            if lines_left == 0 {
+                return Cow::Owned(with_ellipsis(clamped, None));
+            }
+            if index > 0 {
+                clamped.push('\n');
+            }
+
+            let chars = paragraph.chars().count();
+            let lines = match self.chars_per_line {
+                Some(per_line) if wraps => chars.div_ceil(per_line).max(1),
+                _ => 1,
+            };
+            let too_wide = !wraps && self.chars_per_line.is_some_and(|per_line| chars > per_line);
+
+            if lines > lines_left || too_wide {
+                let budget = self
+                    .chars_per_line
+                    .map(|per_line| per_line * if wraps { lines_left } else { 1 })
+                    .unwrap_or(chars);
+                clamped.push_str(paragraph);
+                return Cow::Owned(with_ellipsis(clamped, Some(budget)));
+            }
+
+            clamped.push_str(paragraph);
+            lines_left -= lines;
+        }
+
+        Cow::Borrowed(text)
+    }
+}
+
+/// End `text` with an ellipsis, its last paragraph cut to `budget` characters
+fn with_ellipsis(mut text: String, budget: Option<usize>) -> String {
+    let start = text.rfind('\n').map_or(0, |index| index + 1);
+    let last: String = text[start..].chars().collect();
+    let keep = budget
+        .unwrap_or(usize::MAX)
+        .min(last.chars().count())
+        .saturating_sub(usize::from(budget.is_some()));
+    text.truncate(start);
+    text.extend(last.chars().take(keep));
+    text.truncate(text.trim_end().len());
+    text.push(ELLIPSIS);
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamp_cuts_text_with_an_ellipsis() {
+        // 100px at 10px fits 18 characters per line
+        let one_line = TextClamp::new(Some("none"), None, Some("ellipsis"), Some(100.0), 10.0);
+        assert_eq!(one_line.chars_per_line, Some(18));
+        assert_eq!(one_line.apply("Short"), "Short");
+        assert_eq!(
+            one_line.apply("A title far too long for its width"),
+            "A title far too l…"
+        );
+
+        let two_lines = TextClamp::new(None, Some("2"), Some("ellipsis"), Some(100.0), 10.0);
+        assert_eq!(two_lines.height(10.0), Some(26.0));
+        let summary = "The quick brown fox jumps over the lazy dog, twice";
+        assert_eq!(two_lines.apply(summary), "The quick brown fox jumps over the…");
+        assert_eq!(two_lines.apply("One\nTwo\nThree"), "One\nTwo…");
+
+        // Without truncate, extra lines are only hidden by the height
+        let hidden = TextClamp::new(Some("glyph"), Some("1"), None, None, 16.0);
+        assert_eq!(hidden.wrap, TextWrap::Glyph);
+        assert_eq!(hidden.apply(summary), summary);
+        assert!(TextClamp::new(Some("bogus"), Some("0"), None, None, 16.0).is_none());
+    }
+}
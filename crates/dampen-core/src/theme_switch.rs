@@ -0,0 +1,197 @@
+//! Switching the active theme at runtime
+//!
+//! Handlers switch the theme of the application with [`set_theme`], without
+//! access to the runtime. The request is applied once the handler returns, to
+//! every view:
+//!
+//! ```rust,ignore
+//! #[ui_handler]
+//! pub fn toggle_dark_mode(model: &mut Model) {
+//!     model.dark = !model.dark;
+//!     dampen_core::theme_switch::set_theme(if model.dark { "dark" } else { "light" });
+//! }
+//! ```
+//!
+//! The built-in `set_theme` handler does the same from the view, e.g.
+//! `on_click="set_theme:'dark'"`, unless the view registers its own
+//! `set_theme` handler.
+//!
+//! The pending request and the transition settings belong to the application,
+//! in a [`ThemeSwitch`]. The runtime opens a [`capture`] around each handler
+//! call, which collects the requests of [`set_theme`] and [`set_transition`]
+//! into the switch of the application once the handler returns.
+//!
+//! With a transition, the palette of the previous theme fades into the new one
+//! instead of switching at once (see
+//! [`ThemeContext::switch_theme`](crate::state::ThemeContext::switch_theme)).
+//! Transitions are skipped with reduced motion (see [`crate::system`]).
+
+use std::cell::RefCell;
+use std::time::Duration;
+
+/// Requested theme and the transition settings of an application
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ThemeSwitch {
+    request: Option<String>,
+    transition: Option<Duration>,
+}
+
+impl ThemeSwitch {
+    /// Switch without transition and no pending request
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Switch to the theme `name` once the current message is handled
+    ///
+    /// A later request replaces a pending one. Unknown themes are ignored by the
+    /// runtime.
+    pub fn request(&mut self, name: &str) {
+        self.request = Some(name.to_string());
+    }
+
+    /// Take the pending request, called by the runtime after each message
+    pub fn take_request(&mut self) -> Option<String> {
+        self.request.take()
+    }
+
+    /// Animate theme switches over `duration`, or switch at once with `None` (the default)
+    pub fn set_transition(&mut self, duration: Option<Duration>) {
+        self.transition = duration.filter(|duration| !duration.is_zero());
+    }
+
+    /// Duration of the transition between themes, `None` without animation or
+    /// with reduced motion
+    pub fn transition(&self) -> Option<Duration> {
+        if crate::system::preferences().reduced_motion {
+            return None;
+        }
+        self.transition
+    }
+}
+
+/// Requests made by a handler, applied to a [`ThemeSwitch`] once it returns
+#[derive(Debug, Default)]
+struct Requests {
+    theme: Option<String>,
+    transition: Option<Option<Duration>>,
+}
+
+thread_local! {
+    /// Requests of the handler running on this thread, if the runtime captures them
+    static CAPTURED: RefCell<Option<Requests>> = const { RefCell::new(None) };
+}
+
+/// Collect the requests of the handlers called until [`Capture::finish`]
+///
+/// Captures nest: an inner capture hides its requests from the outer one.
+pub fn capture() -> Capture {
+    let previous = CAPTURED.with(|captured| captured.replace(Some(Requests::default())));
+    Capture {
+        previous: Some(previous),
+    }
+}
+
+/// Requests collected by [`capture`]
+///
+/// Dropping the capture without [`Capture::finish`] discards them.
+#[derive(Debug)]
+#[must_use = "the requests are discarded unless the capture is finished"]
+pub struct Capture {
+    previous: Option<Option<Requests>>,
+}
+
+impl Capture {
+    /// Stop capturing and apply the requests to `switch`
+    pub fn finish(mut self, switch: &mut ThemeSwitch) {
+        let Some(previous) = self.previous.take() else {
+            return;
+        };
+        let requests = CAPTURED.with(|captured| captured.replace(previous));
+        if let Some(requests) = requests {
+            if let Some(name) = requests.theme {
+                switch.request(&name);
+            }
+            if let Some(duration) = requests.transition {
+                switch.set_transition(duration);
+            }
+        }
+    }
+}
+
+impl Drop for Capture {
+    fn drop(&mut self) {
+        if let Some(previous) = self.previous.take() {
+            CAPTURED.with(|captured| captured.replace(previous));
+        }
+    }
+}
+
+/// Record a request in the capture of the running handler
+fn record(request: impl FnOnce(&mut Requests)) {
+    CAPTURED.with(|captured| {
+        if let Some(requests) = captured.borrow_mut().as_mut() {
+            request(requests);
+        }
+    });
+}
+
+/// Switch to the theme `name` once the current handler returns
+///
+/// A later request replaces a pending one. Unknown themes are ignored by the
+/// runtime. Outside of a handler called by the runtime, this does nothing.
+pub fn set_theme(name: &str) {
+    record(|requests| requests.theme = Some(name.to_string()));
+}
+
+/// Animate the theme switches of the application over `duration`, or switch at
+/// once with `None`, from a handler (see [`ThemeSwitch::set_transition`])
+pub fn set_transition(duration: Option<Duration>) {
+    record(|requests| requests.transition = Some(duration));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_captured_requests_apply_to_the_switch() {
+        let mut switch = ThemeSwitch::new();
+
+        // Outside of a capture, requests are ignored
+        set_theme("light");
+        capture().finish(&mut switch);
+        assert_eq!(switch.take_request(), None);
+
+        let outer = capture();
+        set_theme("light");
+        let inner = capture();
+        set_theme("dark");
+        set_transition(Some(Duration::from_millis(300)));
+        inner.finish(&mut switch);
+        assert_eq!(switch.take_request(), Some("dark".to_string()));
+        assert_eq!(switch.take_request(), None);
+
+        let mut outer_switch = ThemeSwitch::new();
+        outer.finish(&mut outer_switch);
+        assert_eq!(outer_switch.take_request(), Some("light".to_string()));
+        assert_eq!(outer_switch.transition, None);
+
+        // Read the field, the system may ask for reduced motion
+        assert_eq!(switch.transition, Some(Duration::from_millis(300)));
+        switch.set_transition(Some(Duration::ZERO));
+        assert_eq!(switch.transition, None);
+    }
+
+    #[test]
+    fn test_dropped_capture_discards_its_requests() {
+        let mut switch = ThemeSwitch::new();
+        let outer = capture();
+        {
+            let _inner = capture();
+            set_theme("dark");
+        }
+        outer.finish(&mut switch);
+        assert_eq!(switch.take_request(), None);
+    }
+}
@@ -0,0 +1,133 @@
+//! Size of the application window
+//!
+//! The breakpoint-prefixed attributes of a widget replace its base attributes
+//! when the window is in that breakpoint:
+//!
+//! ```xml
+//! <column spacing="20" mobile-spacing="10" tablet-spacing="15">
+//!     <text value="Responsive" size="32" mobile-size="18" />
+//! </column>
+//! ```
+//!
+//! The runtime stores the size of the window here as it changes
+//! (`dampen_iced::viewport` follows the window events), and the widget builder
+//! resolves the attributes of each widget against the current
+//! [`breakpoint`] while it builds the view. The document is never rewritten:
+//! resizing the window only renders the view again, and only when the
+//! breakpoint changes.
+//!
+//! Documents declaring their own breakpoints with `<breakpoints>` register
+//! their thresholds with [`watch`], so crossing them renders the view again
+//! too.
+
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use crate::ir::layout::{Breakpoint, Breakpoints};
+
+/// Inner size of the window, in logical pixels
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ViewportSize {
+    pub width: f32,
+    pub height: f32,
+}
+
+static SIZE: Mutex<Option<ViewportSize>> = Mutex::new(None);
+
+/// Widths at which a breakpoint changes, the fixed breakpoints' to start with
+static THRESHOLDS: Mutex<Vec<f32>> = Mutex::new(Vec::new());
+
+/// Breakpoints of the document built last, the fixed ones when `None`
+static BREAKPOINTS: Mutex<Option<Breakpoints>> = Mutex::new(None);
+
+fn breakpoints() -> MutexGuard<'static, Option<Breakpoints>> {
+    BREAKPOINTS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn thresholds() -> MutexGuard<'static, Vec<f32>> {
+    let mut thresholds = THRESHOLDS.lock().unwrap_or_else(PoisonError::into_inner);
+    if thresholds.is_empty() {
+        *thresholds = Breakpoints::default().thresholds();
+    }
+    thresholds
+}
+
+fn size_store() -> MutexGuard<'static, Option<ViewportSize>> {
+    SIZE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Current size of the window, `None` until the runtime stored one
+pub fn size() -> Option<ViewportSize> {
+    *size_store()
+}
+
+/// Store the size of the window
+///
+/// Returns `true` if the width crossed the threshold of a breakpoint, or if
+/// no size was stored yet.
+pub fn set_size(width: f32, height: f32) -> bool {
+    let previous = size_store().replace(ViewportSize { width, height });
+    let Some(previous) = previous else {
+        return true;
+    };
+    thresholds()
+        .iter()
+        .any(|threshold| (previous.width < *threshold) != (width < *threshold))
+}
+
+/// Report the crossings of the thresholds of `breakpoints` from [`set_size`],
+/// and resolve [`breakpoint`] against them
+pub fn watch(breakpoints: &Breakpoints) {
+    let mut current = self::breakpoints();
+    if current.as_ref() == Some(breakpoints) {
+        return;
+    }
+    *current = Some(breakpoints.clone());
+
+    let mut thresholds = thresholds();
+    for threshold in breakpoints.thresholds() {
+        if !thresholds.contains(&threshold) {
+            thresholds.push(threshold);
+        }
+    }
+}
+
+/// Breakpoint of the current size of the window, among the breakpoints
+/// passed to [`watch`] last
+pub fn breakpoint() -> Option<Breakpoint> {
+    let width = size()?.width;
+    match &*breakpoints() {
+        Some(breakpoints) => breakpoints.at_width(width),
+        None => Some(Breakpoint::from_viewport_width(width)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_size_reports_breakpoint_changes() {
+        assert!(set_size(1280.0, 800.0));
+        assert_eq!(breakpoint(), Some(Breakpoint::Desktop));
+        assert!(!set_size(1100.0, 800.0));
+        assert!(set_size(480.0, 800.0));
+        assert_eq!(breakpoint(), Some(Breakpoint::Mobile));
+        assert_eq!(
+            size(),
+            Some(ViewportSize {
+                width: 480.0,
+                height: 800.0,
+            })
+        );
+
+        // Sizes are global: custom thresholds are checked in the same test
+        set_size(700.0, 600.0);
+        assert!(!set_size(800.0, 600.0));
+        watch(&Breakpoints::custom([
+            ("sm".to_string(), 480.0),
+            ("md".to_string(), 768.0),
+        ]));
+        assert!(set_size(700.0, 600.0));
+        assert_eq!(breakpoint(), Some(Breakpoint::Custom("sm".to_string())));
+    }
+}
@@ -141,6 +141,24 @@ fn test_handler_registry_simple_handler() {
     }
 }
 
+#[test]
+fn test_handler_registry_number_handler() {
+    let registry = HandlerRegistry::new();
+    registry.register_with_number("set_count", |model: &mut dyn std::any::Any, count: i32| {
+        if let Some(model) = model.downcast_mut::<TestModel>() {
+            model.count = count;
+        }
+    });
+
+    let mut model = TestModel::default();
+    registry.dispatch("set_count", &mut model, Some("42".to_string()));
+    assert_eq!(model.count, 42);
+
+    // A value that does not parse as the handler's type is ignored
+    registry.dispatch("set_count", &mut model, Some("4.5".to_string()));
+    assert_eq!(model.count, 42);
+}
+
 // ===== Hot-Reload Tests =====
 
 #[test]
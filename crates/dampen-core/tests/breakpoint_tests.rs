@@ -181,8 +181,15 @@ fn test_breakpoint_parse_from_string() {
     assert_eq!(Breakpoint::parse("desktop").unwrap(), Breakpoint::Desktop);
     assert_eq!(Breakpoint::parse("DESKTOP").unwrap(), Breakpoint::Desktop);
 
+    // Other identifiers name custom breakpoints
+    assert_eq!(
+        Breakpoint::parse("laptop").unwrap(),
+        Breakpoint::Custom("laptop".to_string())
+    );
+
     // Invalid
-    assert!(Breakpoint::parse("laptop").is_err());
+    assert!(Breakpoint::parse("2xl").is_err());
+    assert!(Breakpoint::parse("wide-screen").is_err());
     assert!(Breakpoint::parse("").is_err());
 }
 
@@ -332,3 +339,82 @@ fn test_breakpoint_deserialization() {
     assert!(deserialized.contains_key(&Breakpoint::Mobile));
     assert!(deserialized.contains_key(&Breakpoint::Desktop));
 }
+
+#[test]
+fn test_custom_breakpoints() {
+    let xml = r#"<dampen version="1.1">
+        <breakpoints sm="480" md="768" lg="1200" xl="1600" />
+        <column spacing="20" md-spacing="10" lg-padding="30">
+            <text value="Hello" xl-size="32" />
+        </column>
+    </dampen>"#;
+    let doc = parse(xml).unwrap();
+
+    let md = Breakpoint::Custom("md".to_string());
+    assert_eq!(
+        doc.root.breakpoint_attributes[&md].get("spacing"),
+        Some(&AttributeValue::Static("10".to_string()))
+    );
+    assert!(!doc.root.attributes.contains_key("md-spacing"));
+    assert!(
+        doc.root.children[0]
+            .breakpoint_attributes
+            .contains_key(&Breakpoint::Custom("xl".to_string()))
+    );
+
+    // Each breakpoint applies from its minimum width
+    assert_eq!(doc.breakpoints.at_width(300.0), None);
+    assert_eq!(doc.breakpoints.at_width(900.0), Some(md.clone()));
+    assert_eq!(
+        doc.breakpoints.at_width(2000.0),
+        Some(Breakpoint::Custom("xl".to_string()))
+    );
+    assert_eq!(doc.breakpoints.parse("md"), Some(md));
+    assert_eq!(doc.breakpoints.parse("mobile"), None);
+
+    // Custom breakpoints key maps like the fixed ones
+    let json = serde_json::to_string(&doc.root.breakpoint_attributes).unwrap();
+    assert!(json.contains("\"md\""));
+}
+
+#[test]
+fn test_fixed_names_are_not_custom_breakpoints() {
+    let xml = r#"<dampen version="1.1">
+        <breakpoints mobile="0" md="768" />
+        <column mobile-spacing="10" />
+    </dampen>"#;
+    let error = parse(xml).unwrap_err();
+    assert!(error.message.contains("'mobile' is a built-in breakpoint"));
+}
+
+#[test]
+fn test_undeclared_breakpoint_prefixes_are_errors() {
+    // A fixed prefix once custom breakpoints are declared
+    let xml = r#"<dampen version="1.1">
+        <breakpoints sm="480" md="768" />
+        <column md-spacing="10">
+            <text value="Hello" tablet-size="24" />
+        </column>
+    </dampen>"#;
+    let error = parse(xml).unwrap_err();
+    assert_eq!(
+        error.message,
+        "Unknown breakpoint 'tablet' in attribute 'tablet-size'"
+    );
+    assert_eq!(
+        error.suggestion.as_deref(),
+        Some("Declared breakpoints: sm, md")
+    );
+    assert_eq!(error.span.line, 4);
+
+    // A custom prefix that is not declared
+    let xml = r#"<dampen version="1.1">
+        <breakpoints sm="480" md="768" />
+        <column xl-spacing="10" />
+    </dampen>"#;
+    let error = parse(xml).unwrap_err();
+    assert_eq!(
+        error.message,
+        "Unknown breakpoint 'xl' in attribute 'xl-spacing'"
+    );
+}
@@ -14,6 +14,7 @@ fn test_codegen_simple_button() {
         name: "handle_click".to_string(),
         param_type: None,
         returns_command: false,
+        palette: None,
     }];
 
     let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
@@ -48,6 +49,7 @@ fn test_codegen_column_with_children() {
         name: "submit".to_string(),
         param_type: None,
         returns_command: false,
+        palette: None,
     }];
 
     let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
@@ -64,6 +66,7 @@ fn test_codegen_conditional_enabled() {
         name: "submit".to_string(),
         param_type: None,
         returns_command: false,
+        palette: None,
     }];
 
     let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
@@ -80,6 +83,7 @@ fn test_codegen_handler_with_value() {
         name: "update_name".to_string(),
         param_type: Some("String".to_string()),
         returns_command: false,
+        palette: None,
     }];
 
     let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
@@ -124,11 +128,13 @@ fn test_codegen_complex_layout() {
             name: "update_new_item".to_string(),
             param_type: Some("String".to_string()),
             returns_command: false,
+            palette: None,
         },
         HandlerSignature {
             name: "add_item".to_string(),
             param_type: None,
             returns_command: false,
+            palette: None,
         },
     ];
 
@@ -152,6 +158,7 @@ fn test_codegen_method_calls() {
         name: "clear".to_string(),
         param_type: None,
         returns_command: false,
+        palette: None,
     }];
 
     let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
@@ -178,6 +185,7 @@ fn test_codegen_conditional_expressions() {
         name: "submit".to_string(),
         param_type: None,
         returns_command: false,
+        palette: None,
     }];
 
     let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
@@ -200,6 +208,7 @@ fn test_codegen_zero_runtime_dependencies() {
         name: "handle_click".to_string(),
         param_type: None,
         returns_command: false,
+        palette: None,
     }];
 
     let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
@@ -272,6 +281,7 @@ fn test_codegen_checkbox() {
         name: "toggle_accept".to_string(),
         param_type: Some("bool".to_string()),
         returns_command: false,
+        palette: None,
     }];
     let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
     insta::assert_snapshot!(output.code);
@@ -285,6 +295,7 @@ fn test_codegen_slider() {
         name: "set_volume".to_string(),
         param_type: Some("f32".to_string()),
         returns_command: false,
+        palette: None,
     }];
     let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
     insta::assert_snapshot!(output.code);
@@ -298,6 +309,7 @@ fn test_codegen_picklist() {
         name: "handle_select".to_string(),
         param_type: Some("String".to_string()),
         returns_command: false,
+        palette: None,
     }];
     let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
     insta::assert_snapshot!(output.code);
@@ -311,6 +323,7 @@ fn test_codegen_toggler() {
         name: "toggle_feature".to_string(),
         param_type: Some("bool".to_string()),
         returns_command: false,
+        palette: None,
     }];
     let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
     insta::assert_snapshot!(output.code);
@@ -356,6 +369,7 @@ fn test_codegen_radio() {
         name: "select_option".to_string(),
         param_type: Some("String".to_string()),
         returns_command: false,
+        palette: None,
     }];
     let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
     insta::assert_snapshot!(output.code);
@@ -382,6 +396,7 @@ fn test_codegen_tooltip() {
         name: "perform_action".to_string(),
         param_type: None,
         returns_command: false,
+        palette: None,
     }];
     let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
     insta::assert_snapshot!(output.code);
@@ -403,6 +418,7 @@ fn test_codegen_float() {
         name: "float_action".to_string(),
         param_type: None,
         returns_command: false,
+        palette: None,
     }];
     let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
     insta::assert_snapshot!(output.code);
@@ -445,21 +461,25 @@ fn test_codegen_complex_nested() {
             name: "toggle".to_string(),
             param_type: Some("bool".to_string()),
             returns_command: false,
+            palette: None,
         },
         HandlerSignature {
             name: "update_value".to_string(),
             param_type: Some("f32".to_string()),
             returns_command: false,
+            palette: None,
         },
         HandlerSignature {
             name: "save".to_string(),
             param_type: None,
             returns_command: false,
+            palette: None,
         },
         HandlerSignature {
             name: "cancel".to_string(),
             param_type: None,
             returns_command: false,
+            palette: None,
         },
     ];
     let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
@@ -512,6 +532,7 @@ fn test_codegen_data_table_event() {
         name: "select_user".to_string(),
         param_type: None,
         returns_command: false,
+        palette: None,
     }];
     let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
 
@@ -535,6 +556,7 @@ fn test_codegen_data_table_template() {
         name: "delete".to_string(),
         param_type: None,
         returns_command: false,
+        palette: None,
     }];
     let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
     insta::assert_snapshot!(output.code);
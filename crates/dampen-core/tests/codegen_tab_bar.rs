@@ -19,6 +19,7 @@ fn test_codegen_tab_bar_basic() {
         name: "on_tab_selected".to_string(),
         param_type: Some("usize".to_string()),
         returns_command: false,
+        palette: None,
     }];
 
     let result = generate_application(&doc, "Model", "Message", &handlers);
@@ -55,6 +56,7 @@ fn test_codegen_tab_bar_with_binding() {
         name: "on_tab_selected".to_string(),
         param_type: Some("usize".to_string()),
         returns_command: false,
+        palette: None,
     }];
 
     let result = generate_application(&doc, "Model", "Message", &handlers);
@@ -79,6 +81,7 @@ fn test_codegen_tab_bar_with_icons() {
         name: "on_tab_selected".to_string(),
         param_type: Some("usize".to_string()),
         returns_command: false,
+        palette: None,
     }];
 
     let result = generate_application(&doc, "Model", "Message", &handlers);
@@ -120,6 +123,7 @@ fn test_codegen_tab_bar_empty() {
         name: "on_tab_selected".to_string(),
         param_type: Some("usize".to_string()),
         returns_command: false,
+        palette: None,
     }];
 
     let result = generate_application(&doc, "Model", "Message", &handlers);
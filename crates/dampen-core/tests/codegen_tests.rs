@@ -1,5 +1,6 @@
 //! Code generation snapshot tests
 
+use dampen_core::codegen::generate_application_with_config;
 use dampen_core::{HandlerSignature, generate_application, parse, validate_handlers};
 
 #[test]
@@ -15,6 +16,7 @@ fn test_simple_button_codegen() {
         name: "increment".to_string(),
         param_type: None,
         returns_command: false,
+        palette: None,
     }];
 
     // Validate handlers
@@ -65,6 +67,7 @@ fn test_handler_with_value_codegen() {
         name: "update_input".to_string(),
         param_type: Some("String".to_string()),
         returns_command: false,
+        palette: None,
     }];
 
     let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
@@ -89,16 +92,19 @@ fn test_multiple_handlers_codegen() {
             name: "add".to_string(),
             param_type: None,
             returns_command: false,
+            palette: None,
         },
         HandlerSignature {
             name: "remove".to_string(),
             param_type: None,
             returns_command: false,
+            palette: None,
         },
         HandlerSignature {
             name: "reset".to_string(),
             param_type: None,
             returns_command: false,
+            palette: None,
         },
     ];
 
@@ -127,11 +133,13 @@ fn test_nested_layout_codegen() {
             name: "left".to_string(),
             param_type: None,
             returns_command: false,
+            palette: None,
         },
         HandlerSignature {
             name: "right".to_string(),
             param_type: None,
             returns_command: false,
+            palette: None,
         },
     ];
 
@@ -187,6 +195,7 @@ fn test_update_with_value() {
         name: "update_input".to_string(),
         param_type: Some("String".to_string()),
         returns_command: false,
+        palette: None,
     }];
 
     let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
@@ -196,6 +205,220 @@ fn test_update_with_value() {
     assert!(code.contains("String"));
 }
 
+#[test]
+fn test_canvas_event_handler_codegen() {
+    let xml = r#"<canvas width="200" height="100" on_click="pick" on_drag="pan" />"#;
+    let doc = parse(xml).unwrap();
+
+    let handlers: Vec<HandlerSignature> = ["pick", "pan"]
+        .into_iter()
+        .map(|name| HandlerSignature {
+            name: name.to_string(),
+            param_type: Some("CanvasEvent".to_string()),
+            returns_command: false,
+            palette: None,
+        })
+        .collect();
+
+    let output = generate_application_with_config(
+        &doc,
+        &handlers,
+        None,
+        &dampen_core::codegen::CodegenConfig::default(),
+    )
+    .unwrap();
+    let code = output.code.replace(' ', "");
+
+    assert!(code.contains("usedampen_core::handler::CanvasEvent;"));
+    assert!(code.contains("Pick(CanvasEvent)"));
+    assert!(code.contains("Message::Pan(value)=>"));
+    assert!(code.contains("pan(model,value);"));
+    assert!(code.contains("\"pick\"=>Message::Pick(event)"));
+}
+
+#[test]
+fn test_selection_handler_codegen() {
+    let xml = r#"<column>
+        <for each="item" in="{items}">
+            <button label="{item.name}" on_click="selection.toggle:{item.id}" />
+        </for>
+        <button label="Clear" on_click="selection.clear" />
+    </column>"#;
+    let doc = parse(xml).unwrap();
+    assert!(validate_handlers(&doc, &[]).is_ok());
+
+    let output = generate_application(&doc, "Model", "Message", &[]).unwrap();
+    let code = output.code.replace(' ', "");
+
+    assert!(code.contains("SelectionToggle(String)"));
+    assert!(code.contains(
+        "Message::SelectionToggle(value)=>{(model).selection.apply(dampen_core::selection::SelectionAction::Toggle,Some(value.as_str()));"
+    ));
+    assert!(code.contains(
+        "Message::SelectionClear=>{(model).selection.apply(dampen_core::selection::SelectionAction::Clear,None);"
+    ));
+}
+
+#[test]
+fn test_pagination_codegen() {
+    let xml = r#"<scrollable on_reach_end="load_more" reach_end_distance="50">
+        <for each="item" in="{items}" page_size="10" page="{page}">
+            <text value="{item}" />
+        </for>
+    </scrollable>"#;
+    let doc = parse(xml).unwrap();
+
+    let handlers = vec![HandlerSignature {
+        name: "load_more".to_string(),
+        param_type: None,
+        returns_command: false,
+        palette: None,
+    }];
+    let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
+    let code = output.code.replace(' ', "");
+
+    assert!(code.contains(
+        "dampen_core::pagination::page_range(items.len(),dampen_core::pagination::parse_page(&"
+    ));
+    assert!(code.contains(",10usize)"));
+    assert!(code.contains("Message::LoadMore,50f32)"));
+}
+
+#[test]
+fn test_scroll_position_codegen() {
+    let xml = r#"<column>
+        <scrollable offset="{log_offset}" on_scroll="log_scrolled">
+            <text value="{log}" />
+        </scrollable>
+        <scrollable stick_to_bottom="true">
+            <text value="{chat}" />
+        </scrollable>
+    </column>"#;
+    let doc = parse(xml).unwrap();
+
+    let handlers = vec![HandlerSignature {
+        name: "log_scrolled".to_string(),
+        param_type: Some("ScrollEvent".to_string()),
+        returns_command: false,
+        palette: None,
+    }];
+    let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
+    let code = output.code.replace(' ', "");
+
+    assert!(code.contains(
+        ".on_scroll(|viewport|{Message::LogScrolled(dampen_iced::scroll_position::scroll_event(&viewport))})"
+    ));
+    assert!(code.contains("dampen_iced::scroll_position::scroll_position("));
+    assert!(code.contains("log_offset)asf32),false)"));
+    assert!(code.contains(",None,true)"));
+}
+
+#[test]
+fn test_debounced_search_codegen() {
+    let xml = r#"<column>
+        <text_input value="{query}" on_input="search" debounce_ms="300" />
+        <for each="item" in='{items | filter_by(query, "name")}'>
+            <text value="{item.name}" />
+        </for>
+    </column>"#;
+    let doc = parse(xml).unwrap();
+
+    let handlers = vec![HandlerSignature {
+        name: "search".to_string(),
+        param_type: Some("String".to_string()),
+        returns_command: false,
+        palette: None,
+    }];
+    let output = generate_application_with_config(
+        &doc,
+        &handlers,
+        None,
+        &dampen_core::codegen::CodegenConfig::default(),
+    )
+    .unwrap();
+    let code = output.code.replace(' ', "");
+
+    assert!(code.contains("dampen_iced::debounce::pending_text(\"search\")"));
+    assert!(code.contains("Message::Search(v),std::time::Duration::from_millis(300u64)"));
+    assert!(code.contains("Message::InputDebounced=>iced::Task::none()"));
+    assert!(code.contains("dampen_iced::debounce::ready::<Message>()"));
+    assert!(code.contains("dampen_core::matches_query("));
+}
+
+#[test]
+fn test_system_preferences_codegen() {
+    let xml = r#"<column>
+        <if condition="{system.reduced_motion}">
+            <text value="Contrast: {system.high_contrast}" />
+        </if>
+    </column>"#;
+    let doc = parse(xml).unwrap();
+
+    let output = generate_application_with_config(
+        &doc,
+        &[],
+        None,
+        &dampen_core::codegen::CodegenConfig::default(),
+    )
+    .unwrap();
+    let code = output.code.replace(' ', "");
+
+    assert!(code.contains("dampen_core::system::preferences().reduced_motion"));
+    assert!(code.contains("dampen_core::system::preferences().high_contrast.to_string()"));
+    assert!(code.contains("SystemPreferencesChanged"));
+    assert!(code.contains("dampen_iced::system_preferences::changes()"));
+}
+
+#[test]
+fn test_env_bindings_codegen() {
+    let xml = r#"<column>
+        <if condition="{env.debug}">
+            <text value="{env.platform}: {env.viewport_width}px" />
+            <text value="{env.config.api_url}" />
+        </if>
+    </column>"#;
+    let doc = parse(xml).unwrap();
+
+    let output = generate_application_with_config(
+        &doc,
+        &[],
+        None,
+        &dampen_core::codegen::CodegenConfig::default(),
+    )
+    .unwrap();
+    let code = output.code.replace(' ', "");
+
+    assert!(code.contains("dampen_core::env::values().debug"));
+    assert!(code.contains("dampen_core::env::values().platform.to_string()"));
+    assert!(code.contains("dampen_core::env::values().viewport_width.to_string()"));
+    assert!(code.contains("option_env!(\"DAMPEN_CONFIG_API_URL\").unwrap_or_default()"));
+}
+
+#[test]
+fn test_portal_float_codegen() {
+    let xml = r#"<column>
+        <button label="Delete" on_click="confirm" />
+        <float layer="modal" show="{confirm_open}">
+            <text value="Delete this file?" />
+        </float>
+    </column>"#;
+    let doc = parse(xml).unwrap();
+
+    let handlers = vec![HandlerSignature {
+        name: "confirm".to_string(),
+        param_type: None,
+        returns_command: false,
+        palette: None,
+    }];
+    let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
+    let code = output.code.replace(' ', "");
+
+    assert!(code.contains("dampen_iced::layers::Layers::new("));
+    assert!(
+        code.contains(".push_maybe(dampen_iced::layers::Layer::Modal,(model.confirm_open).then(")
+    );
+}
+
 #[test]
 fn test_application_trait_generation() {
     let xml = r#"<column><text value="Test" /></column>"#;
@@ -220,6 +443,7 @@ fn test_update_function_generation() {
         name: "handle_click".to_string(),
         param_type: None,
         returns_command: false,
+        palette: None,
     }];
 
     let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
@@ -229,6 +453,40 @@ fn test_update_function_generation() {
     assert!(code.contains("handle_click"));
 }
 
+#[test]
+fn test_dispatch_function_generation() {
+    let handlers = vec![
+        HandlerSignature {
+            name: "increment".to_string(),
+            param_type: None,
+            returns_command: false,
+            palette: None,
+        },
+        HandlerSignature {
+            name: "set_name".to_string(),
+            param_type: Some("String".to_string()),
+            returns_command: false,
+            palette: None,
+        },
+        HandlerSignature {
+            name: "set_count".to_string(),
+            param_type: Some("i32".to_string()),
+            returns_command: false,
+            palette: None,
+        },
+    ];
+
+    let code =
+        dampen_core::codegen::update::generate_dispatch_function(&handlers, "Message").to_string();
+
+    assert!(code.contains("pub fn message_for"));
+    assert!(code.contains("\"increment\" => Some (Message :: Increment)"));
+    assert!(code.contains("\"set_name\" => value . map (Message :: SetName)"));
+    assert!(
+        code.contains("\"set_count\" => value ? . parse () . ok () . map (Message :: SetCount)")
+    );
+}
+
 #[test]
 fn test_view_function_generation() {
     let xml = r#"<column>
@@ -304,6 +562,49 @@ fn test_empty_document() {
     assert!(result.is_ok());
 }
 
+#[test]
+fn test_model_scope_codegen() {
+    let xml = r#"<column model="{cart}">
+        <text value="Total: {total}" />
+        <for each="line" in="{lines}">
+            <text value="{line.name}" />
+        </for>
+    </column>"#;
+
+    let doc = parse(xml).unwrap();
+    let handlers: Vec<HandlerSignature> = vec![];
+
+    let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
+
+    // Bindings inside the scope are read from the sub-model
+    let code = output.code.replace(char::is_whitespace, "");
+    assert!(code.contains("model.cart.total"), "{}", output.code);
+    assert!(code.contains("model.cart.lines"), "{}", output.code);
+    assert!(!code.contains("cart.line.name"), "{}", output.code);
+}
+
+#[test]
+fn test_filter_codegen() {
+    let xml = r#"<column>
+        <text value="Placed on {placed | date('%d/%m/%Y')}" />
+        <text value="{price | decimal(2)}" />
+    </column>"#;
+
+    let doc = parse(xml).unwrap();
+    let handlers: Vec<HandlerSignature> = vec![];
+
+    let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
+
+    // Filters run on the binding value of the field at runtime
+    let code = output.code.replace(char::is_whitespace, "");
+    assert!(
+        code.contains("dampen_core::apply_filter(&dampen_core::BindingValue::from_value(&(model.placed)),\"date\""),
+        "{}",
+        output.code
+    );
+    assert!(code.contains("\"decimal\""), "{}", output.code);
+}
+
 #[test]
 fn test_complex_example() {
     // This test verifies a realistic example
@@ -324,11 +625,13 @@ fn test_complex_example() {
             name: "add_item".to_string(),
             param_type: None,
             returns_command: false,
+            palette: None,
         },
         HandlerSignature {
             name: "clear_all".to_string(),
             param_type: None,
             returns_command: false,
+            palette: None,
         },
     ];
 
@@ -348,6 +651,72 @@ fn test_complex_example() {
     assert!(output.warnings.is_empty());
 }
 
+#[test]
+fn test_bound_layout_and_style_codegen() {
+    let xml = r#"<column>
+        <container width="{sidebar_width}" padding="8" background="{if dark then '#222' else '#eee'}" border_width="1">
+            <text value="Sidebar" />
+        </container>
+    </column>"#;
+
+    let doc = parse(xml).unwrap();
+    let handlers: Vec<HandlerSignature> = vec![];
+
+    let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
+
+    // Bound values are parsed when the view is built, with the static style
+    let code = output.code.replace(char::is_whitespace, "");
+    assert!(
+        code.contains("dampen_core::parser::parse_visual_attributes"),
+        "{}",
+        output.code
+    );
+    assert!(
+        code.contains("(\"width\",model.sidebar_width"),
+        "{}",
+        output.code
+    );
+    assert!(
+        code.contains("(\"border_width\",\"1\".to_string())"),
+        "{}",
+        output.code
+    );
+    assert!(!code.contains("(\"padding\","), "{}", output.code);
+}
+
+#[test]
+fn test_conditional_classes_codegen() {
+    let xml = r##"<dampen>
+        <styles>
+            <style name="card"><base background="#ffffff" /></style>
+            <style name="card-active"><base background="#3498db" /></style>
+        </styles>
+        <column>
+            <container class="card" class:card-active="{selected}">
+                <text value="Card" />
+            </container>
+        </column>
+    </dampen>"##;
+
+    let doc = parse(xml).unwrap();
+    let handlers: Vec<HandlerSignature> = vec![];
+
+    let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
+
+    // The last active class is matched when the view is built
+    let code = output.code.replace(char::is_whitespace, "");
+    assert!(
+        code.contains("ifmodel.selected{__classes.push(\"card-active\".to_string());}"),
+        "{}",
+        output.code
+    );
+    assert!(
+        code.contains("\"card-active\"=>style_card_active(_theme)"),
+        "{}",
+        output.code
+    );
+}
+
 #[cfg(test)]
 mod expression_codegen_tests {
     use dampen_core::codegen::bindings::{generate_expr, generate_interpolated};
@@ -742,6 +1111,7 @@ mod shared_codegen_integration_tests {
             name: "increment_shared".to_string(),
             param_type: None,
             returns_command: false,
+            palette: None,
         }];
 
         let output = generate_application(&doc, "Model", "Message", &handlers).unwrap();
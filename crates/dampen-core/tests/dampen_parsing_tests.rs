@@ -89,3 +89,13 @@ fn test_parse_dampen_theme_only_success() {
     // Should have a default empty root widget
     assert_eq!(doc.root.kind, dampen_core::WidgetKind::Column);
 }
+
+#[test]
+fn test_parse_theme_document_error_span() {
+    let xml = "<dampen>\n    <themes>\n        <theme name=\"light\">\n            <palette primary=\"not-a-color\" />\n        </theme>\n    </themes>\n</dampen>";
+
+    let error = dampen_core::parser::theme_parser::parse_theme_document(xml)
+        .expect_err("Should reject the invalid color");
+    let span = error.span.expect("Should locate the error");
+    assert_eq!((span.line, span.column), (4, 13));
+}
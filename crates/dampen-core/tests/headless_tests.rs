@@ -0,0 +1,244 @@
+//! Tests for the headless embedding API of AppState.
+//!
+//! These tests drive a view through data only: loading a document, setting a
+//! model snapshot, dispatching handlers and reading the evaluated widget tree.
+
+use dampen_core::ir::{EventKind, WidgetKind};
+use dampen_core::{AppState, BindingValue, EvaluatedNode, HandlerRegistry, HeadlessError};
+use dampen_macros::UiModel;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Default, UiModel, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TodoModel {
+    pub title: String,
+    pub items: Vec<String>,
+    pub show_footer: bool,
+    #[ui_nested]
+    pub footer: Footer,
+}
+
+#[derive(Default, UiModel, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Footer {
+    pub note: String,
+}
+
+const TODO_VIEW: &str = r#"
+    <column id="root">
+        <text value="{title}" />
+        <for each="item" in="{items}">
+            <button label="{index}: {item}" on_click="remove:{index}" />
+        </for>
+        <if condition="{show_footer}">
+            <column model="{footer}">
+                <text value="{note}" />
+            </column>
+        </if>
+    </column>
+"#;
+
+fn todo_state() -> Result<AppState<TodoModel>, HeadlessError> {
+    let registry = HandlerRegistry::new();
+    registry.register_with_value("remove", |model, value| {
+        if let (Some(model), Ok(index)) = (
+            model.downcast_mut::<TodoModel>(),
+            value.downcast::<String>(),
+        ) && let Ok(index) = index.parse::<usize>()
+            && index < model.items.len()
+        {
+            model.items.remove(index);
+        }
+    });
+
+    let document = dampen_core::parse("<column />").map_err(HeadlessError::Parse)?;
+    let mut state = AppState::with_handlers(document, registry);
+    state.load_document(TODO_VIEW)?;
+    state.set_model_snapshot(json!({
+        "title": "Groceries",
+        "items": ["milk", "bread"],
+        "show_footer": false,
+        "footer": { "note": "2 left" }
+    }))?;
+    Ok(state)
+}
+
+fn string(value: &str) -> BindingValue {
+    BindingValue::String(value.to_string())
+}
+
+fn labels(node: &EvaluatedNode) -> Vec<BindingValue> {
+    node.children
+        .iter()
+        .filter_map(|child| child.attributes.get("label").cloned())
+        .collect()
+}
+
+#[test]
+fn test_render_evaluates_bindings_loops_and_conditions() -> Result<(), HeadlessError> {
+    let state = todo_state()?;
+    let root = state.render()?;
+
+    assert_eq!(root.kind, WidgetKind::Column);
+    assert_eq!(root.id.as_deref(), Some("root"));
+    assert_eq!(
+        root.children[0].attributes.get("value"),
+        Some(&string("Groceries"))
+    );
+
+    let for_node = &root.children[1];
+    assert_eq!(
+        labels(for_node),
+        vec![string("0: milk"), string("1: bread")]
+    );
+    let event = &for_node.children[1].events[0];
+    assert_eq!(event.event, EventKind::Click);
+    assert_eq!(event.handler, "remove");
+    assert_eq!(event.value.as_deref(), Some("1"));
+
+    assert!(root.children[2].children.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_dispatch_updates_the_rendered_tree() -> Result<(), HeadlessError> {
+    let mut state = todo_state()?;
+
+    state.dispatch("remove", Some("0".to_string()))?;
+    assert_eq!(
+        labels(&state.render()?.children[1]),
+        vec![string("0: bread")]
+    );
+
+    assert_eq!(
+        state.dispatch("missing", None),
+        Err(HeadlessError::UnknownHandler("missing".to_string()))
+    );
+    Ok(())
+}
+
+#[test]
+fn test_render_paginates_loops() -> Result<(), HeadlessError> {
+    let mut state = todo_state()?;
+    state.load_document(
+        r#"<for each="item" in="{items}" page_size="2" page="1">
+            <button label="{index}: {item}" />
+        </for>"#,
+    )?;
+    state.set_model_snapshot(json!({
+        "title": "Groceries",
+        "items": ["milk", "bread", "eggs", "tea", "rice"],
+        "show_footer": false,
+        "footer": { "note": "" }
+    }))?;
+
+    assert_eq!(
+        labels(&state.render()?),
+        vec![string("2: eggs"), string("3: tea")]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_model_snapshot_round_trip() -> Result<(), HeadlessError> {
+    let mut state = todo_state()?;
+
+    let mut snapshot = state.model_snapshot()?;
+    assert_eq!(snapshot["items"], json!(["milk", "bread"]));
+
+    snapshot["show_footer"] = json!(true);
+    state.set_model_snapshot(snapshot)?;
+    // The footer column scopes its bindings to the `footer` sub-model
+    let footer = &state.render()?.children[2].children[0];
+    assert!(!footer.attributes.contains_key("model"));
+    assert_eq!(
+        footer.children[0].attributes.get("value"),
+        Some(&string("2 left"))
+    );
+
+    let invalid = state.set_model_snapshot(json!({ "title": 3 }));
+    assert!(matches!(invalid, Err(HeadlessError::Snapshot(_))));
+    assert!(state.model.show_footer);
+    Ok(())
+}
+
+#[test]
+fn test_invalid_document_keeps_the_current_one() -> Result<(), HeadlessError> {
+    let mut state = todo_state()?;
+
+    assert!(matches!(
+        state.load_document("<column><buton /></column>"),
+        Err(HeadlessError::Parse(_))
+    ));
+    assert_eq!(state.render()?.id.as_deref(), Some("root"));
+    Ok(())
+}
+
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Country {
+    pub code: String,
+    pub name: String,
+}
+
+impl dampen_core::ToBindingValue for Country {
+    fn to_binding_value(&self) -> BindingValue {
+        BindingValue::Object(
+            [
+                ("code".to_string(), string(&self.code)),
+                ("name".to_string(), string(&self.name)),
+            ]
+            .into_iter()
+            .collect(),
+        )
+    }
+}
+
+#[derive(Default, UiModel, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct CountryModel {
+    pub countries: Vec<Country>,
+    pub country: String,
+}
+
+#[test]
+fn test_render_evaluates_bound_pick_list_options() -> Result<(), HeadlessError> {
+    let document = dampen_core::parse("<column />").map_err(HeadlessError::Parse)?;
+    let mut state = AppState::<CountryModel>::new(document);
+    state.load_document(
+        r#"<pick_list options="{countries}" display="{item.name}" value="{item.code}"
+            selected="{country}" on_select="select_country" />"#,
+    )?;
+    state.set_model_snapshot(json!({
+        "countries": [
+            { "code": "fr", "name": "France" },
+            { "code": "jp", "name": "Japan" }
+        ],
+        "country": "jp"
+    }))?;
+
+    let root = state.render()?;
+    assert_eq!(
+        root.attributes.get("display"),
+        Some(&BindingValue::List(vec![string("France"), string("Japan")]))
+    );
+    assert_eq!(
+        root.attributes.get("value"),
+        Some(&BindingValue::List(vec![string("fr"), string("jp")]))
+    );
+    assert_eq!(root.attributes.get("selected"), Some(&string("jp")));
+    Ok(())
+}
+
+#[test]
+fn test_model_fields_and_evaluate_inspect_the_model() -> Result<(), HeadlessError> {
+    let state = todo_state()?;
+
+    let fields = state.model_fields();
+    assert_eq!(fields.get("title"), Some(&string("Groceries")));
+    assert_eq!(fields.get("footer.note"), Some(&string("2 left")));
+
+    assert_eq!(state.evaluate("items.len() + 1")?, BindingValue::Integer(3));
+    assert!(matches!(
+        state.evaluate("title +"),
+        Err(HeadlessError::Binding(_))
+    ));
+    Ok(())
+}
@@ -33,7 +33,7 @@ fn test_ir_serialization() {
                     map
                 },
                 events: vec![],
-                children: vec![],
+                children: Default::default(),
                 span: Span::new(0, 0, 1, 1),
                 style: None,
                 layout: None,
@@ -41,7 +41,11 @@ fn test_ir_serialization() {
                 classes: vec![],
                 breakpoint_attributes: HashMap::new(),
                 inline_state_variants: HashMap::new(),
-            }],
+                accessibility: None,
+                attribute_spans: HashMap::new(),
+                comments: Vec::new(),
+            }]
+            .into(),
             span: Span::new(0, 0, 1, 1),
             style: None,
             layout: None,
@@ -49,11 +53,17 @@ fn test_ir_serialization() {
             classes: vec![],
             breakpoint_attributes: HashMap::new(),
             inline_state_variants: HashMap::new(),
+            accessibility: None,
+            attribute_spans: HashMap::new(),
+            comments: Vec::new(),
         },
         themes: HashMap::new(),
         style_classes: HashMap::new(),
         global_theme: None,
         follow_system: true,
+        strict: false,
+        window: None,
+        breakpoints: Default::default(),
     };
 
     // Test serialization
@@ -122,6 +132,7 @@ fn test_event_binding_serialization() {
         event: EventKind::Click,
         handler: "handle_click".to_string(),
         param: None,
+        params: Vec::new(),
         span: Span::new(0, 0, 1, 1),
     };
 
@@ -16,9 +16,13 @@ fn test_parse_valid_date_picker() {
 }
 
 #[test]
-fn test_parse_date_picker_zero_children_error() {
-    let xml = r#"<date_picker />"#;
-    let result = parse(xml);
+fn test_parse_date_picker_without_child() {
+    let xml = r#"<date_picker value="{due_date}" on_change="set_due_date" />"#;
+    let doc = parse(xml).expect("A date picker without child shows its own button");
+    assert!(doc.root.children.is_empty());
+
+    // A time picker still needs its underlay
+    let result = parse(r#"<time_picker />"#);
 
     assert!(result.is_err());
     let err = result.unwrap_err();
@@ -1,5 +1,9 @@
-use dampen_core::ir::{AttributeValue, EventKind, InterpolatedPart, WidgetKind};
-use dampen_core::parser::parse;
+use dampen_core::ir::{
+    AccessibilityRole, AttributeValue, EventKind, InterpolatedPart, PathCommand, WidgetKind,
+};
+use dampen_core::parser::error::ParseErrorKind;
+use dampen_core::parser::{AccessibilityIssue, validate_accessibility};
+use dampen_core::parser::{parse, parse_with_recovery, parse_with_styles};
 
 #[test]
 fn test_parse_valid_simple() {
@@ -42,6 +46,57 @@ fn test_parse_valid_simple() {
     assert_eq!(button.events[0].handler, "handle_click");
 }
 
+#[test]
+fn test_parse_with_recovery_collects_all_errors() {
+    let xml = r#"<column>
+        <buton label="Typo" />
+        <row>
+            <icon />
+            <text value="Still parsed" />
+        </row>
+        <colum>
+            <grid />
+        </colum>
+    </column>"#;
+
+    let (document, errors) = parse_with_recovery(xml);
+
+    let kinds: Vec<ParseErrorKind> = errors.iter().map(|e| e.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            ParseErrorKind::UnknownWidget,
+            ParseErrorKind::MissingAttribute,
+            ParseErrorKind::UnknownWidget,
+            ParseErrorKind::MissingAttribute,
+        ]
+    );
+    assert_eq!(errors[0].span.line, 2);
+
+    // Invalid widgets are replaced with placeholders, valid ones are kept
+    let document = document.unwrap();
+    let children = &document.root.children;
+    assert_eq!(children.len(), 3);
+    assert_eq!(children[0].kind, WidgetKind::Space);
+    assert_eq!(children[1].children[1].kind, WidgetKind::Text);
+
+    // The first error matches the one reported by parse()
+    assert_eq!(parse(xml).unwrap_err(), errors[0]);
+}
+
+#[test]
+fn test_parse_with_recovery_valid_and_malformed() {
+    let xml = r#"<dampen><column><text value="Hello" /></column></dampen>"#;
+    let (document, errors) = parse_with_recovery(xml);
+    assert!(errors.is_empty());
+    assert_eq!(document, Some(parse(xml).unwrap()));
+
+    let (document, errors) = parse_with_recovery("<column><text></column>");
+    assert!(document.is_none());
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].kind, ParseErrorKind::XmlSyntax);
+}
+
 #[test]
 fn test_parse_valid_nested() {
     let xml = include_str!("fixtures/valid_nested.dampen");
@@ -119,6 +174,306 @@ fn test_parse_button_with_events() {
     assert!(events.contains(&(EventKind::Press, "press")));
 }
 
+#[test]
+fn test_parse_event_with_multiple_params() {
+    let xml =
+        r#"<button label="Up" on_click="move_item:{item.id}, {count.max(a, b)},'up, now'" />"#;
+    let doc = parse(xml).unwrap();
+
+    let event = &doc.root.events[0];
+    assert_eq!(event.handler, "move_item");
+    assert!(event.param.is_none());
+    assert_eq!(event.params.len(), 3);
+    assert!(matches!(
+        &event.params[2].expr,
+        dampen_core::expr::Expr::Literal(dampen_core::expr::LiteralExpr::String(s)) if s == "up, now"
+    ));
+}
+
+#[test]
+fn test_parse_event_with_single_param_keeps_param() {
+    let xml = r#"<button label="Delete" on_click="delete:{item.id}" />"#;
+    let doc = parse(xml).unwrap();
+
+    let event = &doc.root.events[0];
+    assert_eq!(event.handler, "delete");
+    assert!(event.param.is_some());
+    assert!(event.params.is_empty());
+}
+
+#[test]
+fn test_parse_drag_events() {
+    let xml = r#"<for each="task" in="{tasks}">
+    <row draggable="true" on_drag_start="pick" on_drag_over="hover" on_drop="reorder" />
+</for>"#;
+    let doc = parse(xml).unwrap();
+
+    let row = &doc.root.children[0];
+    assert!(row.attributes.contains_key("draggable"));
+
+    let events: Vec<_> = row
+        .events
+        .iter()
+        .map(|e| (e.event.clone(), e.handler.as_str()))
+        .collect();
+    assert!(events.contains(&(EventKind::DragStart, "pick")));
+    assert!(events.contains(&(EventKind::DragOver, "hover")));
+    assert!(events.contains(&(EventKind::Drop, "reorder")));
+}
+
+#[test]
+fn test_parse_attached_context_menu() {
+    let xml = r#"<column>
+    <row on_context_menu="select:{item.id}">
+        <text value="{item.name}" />
+        <context_menu>
+            <menu>
+                <menu_item label="Delete" on_click="delete" />
+            </menu>
+        </context_menu>
+    </row>
+</column>"#;
+    let doc = parse(xml).unwrap();
+
+    // The row is wrapped in its context menu: <context_menu>[row, menu]
+    let context_menu = &doc.root.children[0];
+    assert_eq!(context_menu.kind, WidgetKind::ContextMenu);
+    assert_eq!(context_menu.children.len(), 2);
+
+    let row = &context_menu.children[0];
+    assert_eq!(row.kind, WidgetKind::Row);
+    assert_eq!(row.children.len(), 1);
+    assert_eq!(row.events[0].event, EventKind::ContextMenu);
+    assert_eq!(row.events[0].handler, "select");
+    assert!(row.events[0].param.is_some());
+
+    assert_eq!(context_menu.children[1].kind, WidgetKind::Menu);
+}
+
+#[test]
+fn test_parse_attached_context_menu_errors() {
+    let root = r#"<context_menu><menu><menu_item label="A" /></menu></context_menu>"#;
+    assert!(parse(root).is_err());
+
+    let duplicate = r#"<row>
+    <context_menu><menu><menu_item label="A" /></menu></context_menu>
+    <context_menu><menu><menu_item label="B" /></menu></context_menu>
+</row>"#;
+    assert!(parse(duplicate).is_err());
+}
+
+#[test]
+fn test_parse_rich_text_spans() {
+    let xml = r##"<text on_link="open">
+    Hello <span color="#f00" weight="bold">{name}</span>, see
+    <span link="https://example.com">the docs</span>
+</text>"##;
+    let doc = parse(xml).unwrap();
+
+    let spans = &doc.root.children;
+    assert_eq!(spans.len(), 4);
+    assert!(spans.iter().all(|span| span.kind == WidgetKind::Span));
+    assert_eq!(
+        spans[0].attributes.get("value"),
+        Some(&AttributeValue::Static("Hello ".to_string()))
+    );
+    assert!(matches!(
+        spans[1].attributes.get("value"),
+        Some(AttributeValue::Interpolated(_)) | Some(AttributeValue::Binding(_))
+    ));
+    assert_eq!(
+        spans[2].attributes.get("value"),
+        Some(&AttributeValue::Static(", see ".to_string()))
+    );
+    assert!(spans[3].attributes.contains_key("link"));
+    assert_eq!(doc.root.events[0].event, EventKind::Link);
+}
+
+#[test]
+fn test_parse_rich_text_nesting_errors() {
+    assert!(parse(r#"<column><span value="stray" /></column>"#).is_err());
+    assert!(parse(r#"<text><button label="x" /></text>"#).is_err());
+
+    // Markdown text keeps its value and has no children
+    let doc = parse(r#"<text markdown="true" value="**bold**" />"#).unwrap();
+    assert!(doc.root.children.is_empty());
+}
+
+#[test]
+fn test_parse_canvas_curves() {
+    let xml = r#"<canvas width="200" height="200">
+        <path d="M10 10 l40 0 Q60 30 50 50 c0 10 -10 20 -20 20 z" fill="blue" />
+        <arc cx="50" cy="50" radius="20" start_angle="0" end_angle="3.14" stroke="red" />
+        <bezier x1="0" y1="0" cx1="10" cy1="40" x2="50" y2="0" />
+    </canvas>"#;
+    let doc = parse(xml).unwrap();
+
+    let kinds: Vec<_> = doc.root.children.iter().map(|c| c.kind.clone()).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            WidgetKind::CanvasPath,
+            WidgetKind::CanvasArc,
+            WidgetKind::CanvasBezier
+        ]
+    );
+
+    let commands = dampen_core::parser::canvas::parse_path_data(
+        "M10 10 l40 0 Q60 30 50 50 c0 10 -10 20 -20 20 z",
+    )
+    .unwrap();
+    assert_eq!(
+        commands,
+        vec![
+            PathCommand::MoveTo(10.0, 10.0),
+            PathCommand::LineTo(50.0, 10.0),
+            PathCommand::QuadraticTo(60.0, 30.0, 50.0, 50.0),
+            PathCommand::CubicTo(50.0, 60.0, 40.0, 70.0, 30.0, 70.0),
+            PathCommand::Close,
+        ]
+    );
+}
+
+#[test]
+fn test_parse_canvas_curve_errors() {
+    // Unsupported command, missing arguments, and no initial move
+    assert!(parse(r#"<canvas><path d="M0 0 A 5 5 0 0 1 10 10" /></canvas>"#).is_err());
+    assert!(parse(r#"<canvas><path d="M0 0 L10" /></canvas>"#).is_err());
+    assert!(parse(r#"<canvas><path d="L10 10" /></canvas>"#).is_err());
+
+    assert!(
+        parse(
+            r#"<canvas><arc cx="0" cy="0" radius="5" start_angle="half" end_angle="1" /></canvas>"#
+        )
+        .is_err()
+    );
+    assert!(
+        parse(r#"<canvas><bezier x1="0" y1="0" cx1="1" cy1="1" cx2="2" x2="3" y2="3" /></canvas>"#)
+            .is_err()
+    );
+}
+
+#[test]
+fn test_parse_svg_inline_content() {
+    let xml =
+        r#"<svg tint="@primary"><svg viewBox="0 0 24 24"><path d="M0 0 L24 24" /></svg></svg>"#;
+    let doc = parse(xml).unwrap();
+
+    assert!(doc.root.children.is_empty());
+    match doc.root.attributes.get("content") {
+        Some(AttributeValue::Static(content)) => {
+            assert!(content.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox"#));
+            assert!(content.ends_with("</svg>"));
+        }
+        other => panic!("Expected static content, got {:?}", other),
+    }
+
+    // Unknown palette color and invalid color
+    assert!(parse(r#"<svg src="icon.svg" tint="@accent" />"#).is_err());
+    assert!(parse(r#"<svg src="icon.svg" tint="not-a-color" />"#).is_err());
+    assert!(parse(r##"<svg src="icon.svg" tint="#336699" />"##).is_ok());
+}
+
+#[test]
+fn test_parse_accessibility_attributes() {
+    let doc = parse(
+        r#"<button aria_label="{delete_label}" role="button" described_by="hint" on_click="delete" />"#,
+    )
+    .unwrap();
+
+    let accessibility = doc.root.accessibility.unwrap();
+    assert!(matches!(
+        accessibility.label,
+        Some(AttributeValue::Binding(_))
+    ));
+    assert_eq!(accessibility.role, Some(AccessibilityRole::Button));
+    assert_eq!(accessibility.described_by.as_deref(), Some("hint"));
+    assert!(!doc.root.attributes.contains_key("aria_label"));
+
+    assert!(
+        parse(r#"<text value="Hi" />"#)
+            .unwrap()
+            .root
+            .accessibility
+            .is_none()
+    );
+    assert!(parse(r#"<text value="Hi" role="banner" />"#).is_err());
+}
+
+#[test]
+fn test_validate_accessibility() {
+    let xml = r#"
+        <column>
+            <button on_click="save"><icon name="save" /></button>
+            <button on_click="delete" aria_label="Delete"><icon name="trash" /></button>
+            <button label="Cancel" on_click="cancel" />
+            <button on_click="ok"><text value="OK" /></button>
+            <text_input value="{name}" on_input="set_name" />
+            <text_input value="{email}" placeholder="Email" described_by="missing" />
+        </column>
+    "#;
+    let doc = parse(xml).unwrap();
+    let warnings = validate_accessibility(&doc);
+
+    assert_eq!(warnings.len(), 3);
+    assert_eq!(warnings[0].widget_kind, WidgetKind::Button);
+    assert_eq!(warnings[0].issue, AccessibilityIssue::MissingLabel);
+    assert_eq!(warnings[1].widget_kind, WidgetKind::TextInput);
+    assert_eq!(warnings[1].issue, AccessibilityIssue::MissingLabel);
+    assert_eq!(
+        warnings[2].issue,
+        AccessibilityIssue::UnknownDescription("missing".to_string())
+    );
+}
+
+#[test]
+fn test_parse_tooltip_options() {
+    let doc = parse(
+        r#"<tooltip position="follow_cursor" delay_ms="300">
+            <button label="Save" />
+            <tooltip_content>
+                <text value="Save changes" />
+            </tooltip_content>
+        </tooltip>"#,
+    )
+    .unwrap();
+    assert_eq!(doc.root.children.len(), 2);
+    assert_eq!(doc.root.children[1].kind, WidgetKind::TooltipContent);
+
+    // `delay` is migrated to `delay_ms`
+    let doc = parse(r#"<tooltip message="Hi" delay="250"><button /></tooltip>"#).unwrap();
+    assert!(doc.root.attributes.contains_key("delay_ms"));
+
+    assert!(parse(r#"<tooltip><button /></tooltip>"#).is_err());
+    assert!(parse(r#"<tooltip message="Hi" position="middle"><button /></tooltip>"#).is_err());
+    assert!(parse(r#"<tooltip message="Hi" delay_ms="120000"><button /></tooltip>"#).is_err());
+    assert!(parse(r#"<column><tooltip_content /></column>"#).is_err());
+}
+
+#[test]
+fn test_parse_icon() {
+    let doc = parse(r#"<icon name="trash" size="16" tint="@danger" />"#).unwrap();
+    assert_eq!(doc.root.kind, WidgetKind::Icon);
+
+    assert!(parse(r#"<icon name="{icon_name}" />"#).is_ok());
+    assert!(parse(r#"<icon size="16" />"#).is_err());
+    assert!(parse(r#"<icon name="no-such-icon" />"#).is_err());
+}
+
+#[test]
+fn test_parse_chart() {
+    let doc = parse(r#"<chart type="pie" data="{shares}" x="name" y="percent" />"#).unwrap();
+    assert_eq!(doc.root.kind, WidgetKind::Chart);
+    assert!(matches!(
+        doc.root.attributes.get("data"),
+        Some(AttributeValue::Binding(_))
+    ));
+
+    // Missing data and unknown chart type
+    assert!(parse(r#"<chart type="line" />"#).is_err());
+    assert!(parse(r#"<chart type="radar" data="{points}" />"#).is_err());
+}
+
 #[test]
 fn test_parse_binding_expressions() {
     let xml = r#"<text value="Total: {count} items" />"#;
@@ -1085,6 +1440,65 @@ fn test_parse_style_attributes() {
     assert_eq!(style.opacity, Some(0.5));
 }
 
+#[test]
+fn test_parse_per_side_borders_and_outline() {
+    use dampen_core::ir::style::{BorderSides, Color};
+    use dampen_core::ir::theme::WidgetState;
+
+    let xml = r##"<dampen>
+    <style_classes>
+        <class name="field" border_bottom="1 #cccccc" border_top="1 #cccccc" />
+    </style_classes>
+    <column>
+        <container border_left="4 #3498db" outline="2 #3498db 1" />
+        <text_input value="" class="field" focus:outline="2 rgb(52, 152, 219)" />
+    </column>
+</dampen>"##;
+
+    let doc = parse(xml).unwrap();
+    let container = &doc.root.children[0];
+    let style = container.style.as_ref().unwrap();
+    let sides = style.border_sides.unwrap();
+    assert_eq!(sides.left.unwrap().width, 4.0);
+    assert!(sides.top.is_none());
+    let outline = style.outline.unwrap();
+    assert_eq!(outline.color, Color::from_rgb8(52, 152, 219));
+    assert_eq!(outline.offset, 1.0);
+    // A plain border is not created by per-side borders
+    assert!(style.border.is_none());
+
+    // Colors with spaces leave the offset at 0
+    let input = &doc.root.children[1];
+    let focus = &input.inline_state_variants[&WidgetState::Focus];
+    assert_eq!(focus.outline.unwrap().offset, 0.0);
+
+    // Sides cascade one by one
+    let class_sides = doc.style_classes["field"].style.border_sides;
+    let overrides = BorderSides {
+        top: sides.left,
+        ..BorderSides::default()
+    };
+    let merged = BorderSides::cascade(class_sides, Some(overrides)).unwrap();
+    assert_eq!(merged.top.unwrap().width, 4.0);
+    assert_eq!(merged.bottom.unwrap().width, 1.0);
+
+    assert!(parse(r#"<container border_top="thick #ccc" />"#).is_err());
+    assert!(parse(r#"<container outline="2" />"#).is_err());
+}
+
+#[test]
+fn test_parse_elevation() {
+    use dampen_core::ir::theme::WidgetState;
+
+    let doc = parse(r#"<button label="Save" elevation="2" hover:elevation="4" />"#).unwrap();
+    assert_eq!(doc.root.style.as_ref().unwrap().elevation, Some(2));
+    let hover = &doc.root.inline_state_variants[&WidgetState::Hover];
+    assert_eq!(hover.elevation, Some(4));
+
+    assert!(parse(r#"<container elevation="6" />"#).is_err());
+    assert!(parse(r#"<container elevation="high" />"#).is_err());
+}
+
 #[test]
 fn test_parse_combined_layout_and_style() {
     let xml = "<column padding=\"40\" spacing=\"20\" width=\"fill\" background=\"#ffffff\"><text value=\"Test\" /></column>";
@@ -1136,6 +1550,87 @@ fn test_parse_alignment_attributes() {
     assert_eq!(layout.align_self, Some(Alignment::End));
 }
 
+#[test]
+fn test_parse_wrap_and_grow() {
+    let xml = r#"<row wrap="true"><text value="Tag" grow="2" /></row>"#;
+
+    let doc = parse(xml).unwrap();
+    assert_eq!(doc.root.layout.as_ref().unwrap().wrap, Some(true));
+    let child = &doc.root.children[0];
+    assert_eq!(child.layout.as_ref().unwrap().grow, Some(2));
+
+    assert!(parse(r#"<row wrap="yes" />"#).is_err());
+    assert!(parse(r#"<row><text value="Tag" grow="0" /></row>"#).is_err());
+}
+
+#[test]
+fn test_parse_aspect_ratio_and_overflow() {
+    use dampen_core::ir::layout::Overflow;
+
+    let doc = parse(r#"<container aspect_ratio="16:9" overflow="clip" />"#).unwrap();
+    let layout = doc.root.layout.as_ref().unwrap();
+    assert_eq!(layout.aspect_ratio, Some(16.0 / 9.0));
+    assert_eq!(layout.overflow, Some(Overflow::Clip));
+
+    let doc = parse(r#"<container aspect_ratio="1.5" overflow="scroll" />"#).unwrap();
+    let layout = doc.root.layout.as_ref().unwrap();
+    assert_eq!(layout.aspect_ratio, Some(1.5));
+    assert_eq!(layout.overflow, Some(Overflow::Scroll));
+
+    assert!(parse(r#"<container aspect_ratio="16:0" />"#).is_err());
+    assert!(parse(r#"<container aspect_ratio="wide" />"#).is_err());
+    assert!(parse(r#"<container overflow="hidden" />"#).is_err());
+}
+
+#[test]
+fn test_parse_anchored_float() {
+    let xml = r#"<column>
+        <button id="menu_button" label="Options" />
+        <float anchor="menu_button" placement="bottom-start" show="{menu_open}">
+            <text value="Rename" />
+        </float>
+    </column>"#;
+    let doc = parse(xml).unwrap();
+    let float = &doc.root.children[1];
+    assert_eq!(float.kind, WidgetKind::Float);
+    assert!(float.layout.is_none());
+
+    let unknown_anchor = r#"<column>
+        <float anchor="missing"><text value="Rename" /></float>
+    </column>"#;
+    let err = parse(unknown_anchor).unwrap_err();
+    assert!(err.message.contains("unknown id 'missing'"));
+
+    let bad_placement = r#"<column>
+        <button id="menu_button" label="Options" />
+        <float anchor="menu_button" placement="below"><text value="Rename" /></float>
+    </column>"#;
+    assert!(parse(bad_placement).is_err());
+}
+
+#[test]
+fn test_parse_window_declaration() {
+    let xml = r#"<dampen version="1.0">
+        <window title="My App" width="800" min_width="400" resizable="false" decorations="false" />
+        <column><text value="Hello" /></column>
+    </dampen>"#;
+    let doc = parse(xml).unwrap();
+    let window = doc.window.unwrap();
+    assert_eq!(window.title.as_deref(), Some("My App"));
+    assert_eq!(window.size(), Some((800, 768)));
+    assert_eq!(window.min_size(), Some((400, 0)));
+    assert_eq!(window.resizable, Some(false));
+    assert_eq!(window.decorations, Some(false));
+    assert_eq!(doc.root.kind, WidgetKind::Column);
+
+    let unknown = r#"<dampen><window fullscreen="true" /><column /></dampen>"#;
+    let err = parse(unknown).unwrap_err();
+    assert!(err.message.contains("Unknown window attribute 'fullscreen'"));
+
+    let min_over_max = r#"<dampen><window min_width="900" max_width="600" /><column /></dampen>"#;
+    assert!(parse(min_over_max).is_err());
+}
+
 #[test]
 fn test_parse_align_shorthand() {
     let xml = "<column align=\"center\" />";
@@ -1608,3 +2103,351 @@ fn test_evaluate_shared_in_conditional() {
     let result = evaluate_expr_with_shared(&binding.expr, &model, Some(&shared_user)).unwrap();
     assert_eq!(result, BindingValue::String("User".to_string()));
 }
+
+#[test]
+fn test_attribute_spans_point_at_values() {
+    let xml = "<column padding=\"10\">\n    <button hover:background=\"#fff\" label=\"Save\" on_click=\"save\" />\n</column>";
+    let doc = parse(xml).unwrap();
+    let button = &doc.root.children[0];
+
+    // State prefixes are rewritten before parsing but spans stay in source coordinates
+    let label = button.attribute_span("label");
+    assert_eq!(&xml[label.start..label.end], "Save");
+    assert_eq!((label.line, label.column), (2, 44));
+
+    let name = button.attribute_spans["label"].name;
+    assert_eq!(&xml[name.start..name.end], "label");
+
+    let event = &button.events[0].span;
+    assert_eq!(&xml[event.start..event.end], "save");
+
+    // Unknown attributes fall back to the element span
+    assert_eq!(button.attribute_span("missing"), button.span);
+}
+
+#[test]
+fn test_invalid_expression_span_points_at_token() {
+    let xml = "<column>\n    <text value=\"{count + * 2}\" />\n</column>";
+    let err = parse(xml).unwrap_err();
+
+    assert_eq!(err.kind, ParseErrorKind::InvalidExpression);
+    assert_eq!((err.span.line, err.span.column), (2, 27));
+    assert_eq!(&xml[err.span.start..err.span.end], "*");
+
+    // A truncated expression points just past its last token
+    let xml = "<text value=\"{count + }\" />";
+    let err = parse(xml).unwrap_err();
+    assert_eq!(err.span.start, xml.find('}').unwrap());
+}
+
+#[test]
+fn test_strict_mode_rejects_unknown_attributes() {
+    let xml = r#"<dampen strict="true">
+    <column>
+        <button labe="Ok" on_click="save" />
+    </column>
+</dampen>"#;
+    let err = parse(xml).unwrap_err();
+
+    assert_eq!(err.kind, ParseErrorKind::UnknownAttribute);
+    assert_eq!(&xml[err.span.start..err.span.end], "labe");
+    assert_eq!(err.suggestion.as_deref(), Some("Did you mean 'label'?"));
+
+    // Without strict="true", unknown attributes are kept as they are
+    let lenient = xml.replace(r#" strict="true""#, "");
+    let doc = parse(&lenient).unwrap();
+    assert!(!doc.strict);
+    assert!(doc.root.children[0].attributes.contains_key("labe"));
+}
+
+#[test]
+fn test_strict_mode_reports_every_unknown_name() {
+    let xml = r##"<dampen strict="true">
+    <style_classes>
+        <class name="primary" background="#3498db" />
+    </style_classes>
+    <column mobile-spaceing="4">
+        <text value="Hi" class="primay" />
+        <button label="Ok" on_clik="save" />
+    </column>
+</dampen>"##;
+    let (doc, errors) = parse_with_recovery(xml);
+
+    assert!(doc.unwrap().strict);
+    let reported: Vec<_> = errors
+        .iter()
+        .map(|e| (e.message.as_str(), e.suggestion.as_deref()))
+        .collect();
+    assert_eq!(
+        reported,
+        vec![
+            (
+                "Unknown attribute 'mobile-spaceing' for column widgets",
+                Some("Did you mean 'spacing'?")
+            ),
+            (
+                "Unknown style class 'primay'",
+                Some("Did you mean 'primary'?")
+            ),
+            (
+                "Unknown attribute 'on_clik' for button widgets",
+                Some("Did you mean 'on_click'?")
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_style_library_classes_are_merged() {
+    let library = parse(
+        r##"<dampen>
+    <style_classes>
+        <class name="card" padding="16" />
+        <class name="primary" background="#3498db" />
+    </style_classes>
+</dampen>"##,
+    )
+    .unwrap();
+    let xml = r##"<dampen strict="true">
+    <style_classes>
+        <class name="primary" background="#e74c3c" />
+    </style_classes>
+    <column class="card">
+        <button label="Ok" class="primary" />
+    </column>
+</dampen>"##;
+
+    // Library classes are unknown to the view on its own
+    assert_eq!(
+        parse(xml).unwrap_err().message,
+        "Unknown style class 'card'"
+    );
+
+    let doc = parse_with_styles(xml, std::slice::from_ref(&library)).unwrap();
+    assert_eq!(doc.style_classes.len(), 2);
+    assert_eq!(doc.style_classes["card"], library.style_classes["card"]);
+    // Classes defined by the view win over those of the library
+    assert_ne!(
+        doc.style_classes["primary"],
+        library.style_classes["primary"]
+    );
+}
+
+#[test]
+fn test_slider_value_range_and_format_validation() {
+    let xml = r#"<slider min="{min}" max="{max}" step="0.5" value="{volume}" show_value="true" value_format="decimal(1)" />"#;
+    let doc = parse(xml).unwrap();
+    assert!(doc.root.attributes.contains_key("value_format"));
+
+    let err = parse(r#"<slider min="10" max="5" value="{volume}" />"#).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::InvalidValue);
+    assert!(err.message.contains("must be greater than min"));
+
+    let xml = r#"<progress_bar value="{progress}" value_format="percent" />"#;
+    let err = parse(xml).unwrap_err();
+    assert_eq!(&xml[err.span.start..err.span.end], "percent");
+    assert!(err.message.contains("Unknown filter 'percent'"));
+
+    assert!(parse(r#"<slider value="{volume}" step="0" />"#).is_err());
+}
+
+#[test]
+fn test_radio_group_options_or_children() {
+    let doc =
+        parse(r#"<radio_group value="{plan}" options="{plans}" on_select="set_plan" />"#).unwrap();
+    assert_eq!(doc.root.kind, WidgetKind::RadioGroup);
+    assert!(doc.root.has_bound_options());
+    assert!(doc.root.is_option_template("display"));
+    assert!(!doc.root.is_option_template("value"));
+
+    let xml = r#"<radio_group value="{size}" on_select="set_size">
+        <radio label="Small" value="s" />
+        <radio label="Large" value="l" />
+    </radio_group>"#;
+    assert_eq!(parse(xml).unwrap().root.children.len(), 2);
+
+    let err = parse(r#"<radio_group value="{plan}" />"#).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::MissingAttribute);
+
+    let xml =
+        r#"<radio_group options="Free,Pro"><radio label="Free" value="free" /></radio_group>"#;
+    assert!(parse(xml).unwrap_err().message.contains("both"));
+
+    let xml = r#"<radio_group><text value="Free" /></radio_group>"#;
+    assert_eq!(parse(xml).unwrap_err().kind, ParseErrorKind::InvalidChild);
+}
+
+#[test]
+fn test_text_input_constraint_validation() {
+    let xml =
+        r#"<text_input value="{pin}" mask="password" max_length="4" filter="regex:'[0-9]*'" />"#;
+    assert!(parse(xml).is_ok());
+
+    let xml = r#"<text_input value="{pin}" max_length="four" />"#;
+    let err = parse(xml).unwrap_err();
+    assert_eq!(&xml[err.span.start..err.span.end], "four");
+
+    let err = parse(r#"<text_input value="{pin}" filter="digits" />"#).unwrap_err();
+    assert!(err.message.contains("Unknown filter 'digits'"));
+
+    let err = parse(r#"<text_input value="{pin}" mask="phone" />"#).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::InvalidValue);
+}
+
+#[test]
+fn test_number_input_range_validation() {
+    let xml = r#"<number_input value="{qty}" min="0" max="99" on_change="set_qty" />"#;
+    let doc = parse(xml).unwrap();
+    assert_eq!(doc.root.kind, WidgetKind::NumberInput);
+
+    let xml = r#"<number_input value="{qty}" min="10" max="1" />"#;
+    let err = parse(xml).unwrap_err();
+    assert_eq!(&xml[err.span.start..err.span.end], "1");
+
+    let err = parse(r#"<number_input value="{price}" step="-0.5" />"#).unwrap_err();
+    assert!(err.message.contains("Invalid step"));
+
+    let err = parse(r#"<number_input value="{qty}" decimals="two" />"#).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::InvalidValue);
+}
+
+#[test]
+fn test_skeleton_and_loading_attribute() {
+    let doc = parse(r#"<column loading="{is_loading}"><skeleton lines="3" /></column>"#).unwrap();
+    assert!(doc.root.attributes.contains_key("loading"));
+    assert_eq!(doc.root.children[0].kind, WidgetKind::Skeleton);
+
+    let xml = r#"<skeleton lines="0" />"#;
+    let err = parse(xml).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::InvalidValue);
+    assert_eq!(&xml[err.span.start..err.span.end], "0");
+}
+
+#[test]
+fn test_comments_attach_to_following_widget() {
+    let doc = parse(
+        r#"<dampen version="1.1">
+            <!-- Main layout -->
+            <column>
+                <!-- @doc: Saves the form -->
+                <!-- @doc: Disabled until it is valid -->
+                <button label="Save" on_click="save" />
+                <text value="Footer" />
+                <!-- trailing comment -->
+            </column>
+        </dampen>"#,
+    )
+    .unwrap();
+
+    assert_eq!(doc.root.comments, vec!["Main layout"]);
+    assert_eq!(doc.root.doc(), None);
+
+    let button = &doc.root.children[0];
+    assert_eq!(
+        button.comments,
+        vec!["@doc: Saves the form", "@doc: Disabled until it is valid"]
+    );
+    assert_eq!(
+        button.doc().as_deref(),
+        Some("Saves the form\nDisabled until it is valid")
+    );
+    assert!(doc.root.children[1].comments.is_empty());
+}
+
+#[test]
+fn test_conditional_classes() {
+    use dampen_core::expr::{Expr, LiteralExpr};
+
+    let doc = parse(
+        r#"<column>
+            <container class="card {if selected then 'card-active'}" class:card-hover="{hovered}" />
+            <text value="Top class: {rank}" />
+        </column>"#,
+    )
+    .unwrap();
+
+    let card = &doc.root.children[0];
+    assert_eq!(card.classes, vec!["card"]);
+    assert!(card.has_dynamic_classes());
+    let toggles: Vec<&str> = card.class_toggles().map(|(class, _)| class).collect();
+    assert_eq!(toggles, vec!["card-hover"]);
+    assert!(card.attributes.contains_key("class:card-hover"));
+    assert!(card.attribute_spans.contains_key("class:card-hover"));
+
+    // Without `else`, a conditional is empty when its condition is false
+    let Some(AttributeValue::Interpolated(parts)) = card.attributes.get("class") else {
+        panic!("Expected an interpolated class");
+    };
+    assert!(matches!(
+        parts.last(),
+        Some(InterpolatedPart::Binding(binding))
+            if matches!(
+                &binding.expr,
+                Expr::Conditional(conditional)
+                    if *conditional.else_branch == Expr::Literal(LiteralExpr::String(String::new()))
+            )
+    ));
+
+    // Only attributes are class toggles, not text mentioning "class:"
+    let text = &doc.root.children[1];
+    assert!(!text.has_dynamic_classes());
+    assert!(matches!(
+        text.attributes.get("value"),
+        Some(AttributeValue::Interpolated(parts))
+            if parts.first() == Some(&InterpolatedPart::Literal("Top class: ".to_string()))
+    ));
+}
+
+#[test]
+fn test_markdown_widget() {
+    let doc = parse(r#"<markdown src="{readme}" on_link="open_url" />"#).unwrap();
+    assert_eq!(doc.root.kind, WidgetKind::Markdown);
+    assert!(matches!(
+        doc.root.attributes.get("src"),
+        Some(AttributeValue::Binding(_))
+    ));
+    assert_eq!(doc.root.events[0].event, EventKind::Link);
+
+    let err = parse(r#"<markdown />"#).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::MissingAttribute);
+
+    let err = parse(r#"<markdown src="{readme}" scale_ratio="9" />"#).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::InvalidValue);
+}
+
+#[test]
+fn test_code_editor_widget() {
+    let doc = parse(
+        r#"<code_editor language="rust" value="{source}" on_change="update_source" line_numbers="false" />"#,
+    )
+    .unwrap();
+    assert_eq!(doc.root.kind, WidgetKind::CodeEditor);
+    assert!(matches!(
+        doc.root.attributes.get("value"),
+        Some(AttributeValue::Binding(_))
+    ));
+    assert_eq!(doc.root.events[0].event, EventKind::Change);
+    assert_eq!(doc.root.events[0].handler, "update_source");
+}
+
+#[test]
+fn test_tree_widget() {
+    let doc = parse(
+        r#"<tree items="{nodes}" children="children" label="{node.name}" on_expand="load_children" />"#,
+    )
+    .unwrap();
+    assert_eq!(doc.root.kind, WidgetKind::Tree);
+    assert!(matches!(
+        doc.root.attributes.get("label"),
+        Some(AttributeValue::Binding(_))
+    ));
+    assert_eq!(doc.root.events[0].event, EventKind::Expand);
+    assert_eq!(doc.root.events[0].handler, "load_children");
+
+    let err = parse(r#"<tree label="{node.name}" />"#).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::MissingAttribute);
+
+    let err =
+        parse(r#"<tree items="{nodes}" label="{node.name}" children="{field}" />"#).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::InvalidValue);
+}
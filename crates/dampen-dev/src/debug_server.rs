@@ -0,0 +1,428 @@
+//! Debug server of the interpreted runtime
+//!
+//! With `#[dampen_app(debug_server_variant = "...")]`, a development build
+//! listens on `127.0.0.1`, port [`DEFAULT_PORT`] or the `DAMPEN_DEBUG_PORT`
+//! environment variable. Debuggers such as `dampen debug attach` send one JSON
+//! command per line and receive one JSON response per line:
+//!
+//! ```text
+//! > {"token": "4f1c…", "command": "views"}
+//! < {"ok": {"current": "counter", "views": ["counter", "settings"]}}
+//! > {"token": "4f1c…", "command": "model", "view": "counter"}
+//! < {"ok": {"count": 3}}
+//! > {"token": "4f1c…", "command": "evaluate", "expr": "count * 2"}
+//! < {"ok": 6}
+//! > {"token": "4f1c…", "command": "dispatch", "handler": "increment"}
+//! < {"ok": null}
+//! ```
+//!
+//! Every command carries the token of the session, printed when the server
+//! starts: `DAMPEN_DEBUG_TOKEN`, or a random one. Any local process, including
+//! a web page posting to the port, can reach the server, so the connection is
+//! closed on the first line that is not a command with the right token.
+//!
+//! Commands are delivered to the application as [`DebugRequest`] messages and
+//! answered from its `update()`, against the live model. `model` and `evaluate`
+//! default to the current view; `dispatch` always targets the current view.
+
+use dampen_core::binding::{BindingValue, UiBindable};
+use dampen_core::state::AppState;
+use iced::Subscription;
+use iced::advanced::subscription::{EventStream, Hasher, Recipe};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::hash::Hash;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::sync::mpsc as reply_channel;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+
+/// Environment variable holding the port of the debug server
+pub const PORT_ENV: &str = "DAMPEN_DEBUG_PORT";
+
+/// Port of the debug server when `DAMPEN_DEBUG_PORT` is not set
+pub const DEFAULT_PORT: u16 = 7420;
+
+/// Environment variable holding the session token of the debug server
+pub const TOKEN_ENV: &str = "DAMPEN_DEBUG_TOKEN";
+
+/// Time the application has to answer a command
+const REPLY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A command sent by a debugger
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum DebugCommand {
+    /// List the views and the current view
+    Views,
+
+    /// Dump the bindable fields of the model of a view
+    Model {
+        /// View path, the current view if absent
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        view: Option<String>,
+    },
+
+    /// Evaluate a binding expression (without braces) against the model of a view
+    Evaluate {
+        /// Expression, e.g. `items.len() > 0`
+        expr: String,
+
+        /// View path, the current view if absent
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        view: Option<String>,
+    },
+
+    /// Run a handler of the current view, as if a widget sent it
+    Dispatch {
+        /// Handler name
+        handler: String,
+
+        /// Value passed to the handler
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        value: Option<String>,
+    },
+}
+
+impl DebugCommand {
+    /// View the command is about, if given
+    pub fn view(&self) -> Option<&str> {
+        match self {
+            Self::Model { view } | Self::Evaluate { view, .. } => view.as_deref(),
+            Self::Views | Self::Dispatch { .. } => None,
+        }
+    }
+}
+
+/// A [`DebugCommand`] with the session token, as sent on the wire
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuthenticatedCommand {
+    /// Session token of the server
+    pub token: String,
+
+    /// The command
+    #[serde(flatten)]
+    pub command: DebugCommand,
+}
+
+/// The response to a [`DebugCommand`], serialized as `{"ok": ...}` or `{"error": "..."}`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DebugResponse {
+    /// The command succeeded
+    Ok(Value),
+
+    /// The command failed
+    Error(String),
+}
+
+impl From<Result<Value, String>> for DebugResponse {
+    fn from(result: Result<Value, String>) -> Self {
+        match result {
+            Ok(value) => Self::Ok(value),
+            Err(error) => Self::Error(error),
+        }
+    }
+}
+
+/// A command waiting for the application to answer it
+#[derive(Debug, Clone)]
+pub struct DebugRequest {
+    /// The command to answer
+    pub command: DebugCommand,
+
+    reply: reply_channel::Sender<DebugResponse>,
+}
+
+impl DebugRequest {
+    /// Send the response to the debugger.
+    ///
+    /// Does nothing if the debugger disconnected or gave up waiting.
+    pub fn respond(&self, result: Result<Value, String>) {
+        let _ = self.reply.send(result.into());
+    }
+}
+
+/// Port of the debug server: `DAMPEN_DEBUG_PORT`, or [`DEFAULT_PORT`]
+pub fn port() -> u16 {
+    std::env::var(PORT_ENV)
+        .ok()
+        .and_then(|port| port.trim().parse().ok())
+        .unwrap_or(DEFAULT_PORT)
+}
+
+/// Session token of the debug server: `DAMPEN_DEBUG_TOKEN`, or a random one
+pub fn token() -> String {
+    std::env::var(TOKEN_ENV)
+        .ok()
+        .map(|token| token.trim().to_string())
+        .filter(|token| !token.is_empty())
+        .unwrap_or_else(|| uuid::Uuid::new_v4().simple().to_string())
+}
+
+/// Create a subscription serving debuggers on `127.0.0.1:port`.
+///
+/// The subscription yields a [`DebugRequest`] per command received; answer
+/// each with [`DebugRequest::respond`]. The session token (see [`token`]) is
+/// printed when the server starts.
+pub fn serve(port: u16) -> Subscription<DebugRequest> {
+    use iced::advanced::subscription::from_recipe;
+    from_recipe(DebugServerRecipe { port })
+}
+
+/// Answer the `views` command: the current view and every view path
+pub fn views(current: &str, views: &[&str]) -> Value {
+    serde_json::json!({ "current": current, "views": views })
+}
+
+/// Answer the `model` and `evaluate` commands against the state of a view.
+///
+/// # Errors
+///
+/// Returns the message sent to the debugger if the expression fails to
+/// evaluate, or if the command is not about a model.
+pub fn inspect<M, S>(state: &AppState<M, S>, command: &DebugCommand) -> Result<Value, String>
+where
+    M: UiBindable,
+    S: UiBindable + Send + Sync + 'static,
+{
+    match command {
+        DebugCommand::Model { .. } => Ok(Value::Object(
+            state
+                .model_fields()
+                .into_iter()
+                .map(|(field, value)| (field, binding_to_json(&value)))
+                .collect(),
+        )),
+        DebugCommand::Evaluate { expr, .. } => state
+            .evaluate(expr)
+            .map(|value| binding_to_json(&value))
+            .map_err(|error| error.to_string()),
+        DebugCommand::Views | DebugCommand::Dispatch { .. } => {
+            Err("not a model command".to_string())
+        }
+    }
+}
+
+/// Convert a binding value to plain JSON; values without a JSON type become strings
+pub fn binding_to_json(value: &BindingValue) -> Value {
+    match value {
+        BindingValue::String(text) => Value::String(text.clone()),
+        BindingValue::Integer(number) => Value::from(*number),
+        BindingValue::Float(number) => Value::from(*number),
+        BindingValue::Bool(boolean) => Value::Bool(*boolean),
+        BindingValue::List(items) => Value::Array(items.iter().map(binding_to_json).collect()),
+        BindingValue::Object(fields) => Value::Object(
+            fields
+                .iter()
+                .map(|(name, value)| (name.clone(), binding_to_json(value)))
+                .collect(),
+        ),
+        BindingValue::None => Value::Null,
+        other => Value::String(other.to_display_string()),
+    }
+}
+
+/// Subscription recipe running the debug server
+struct DebugServerRecipe {
+    port: u16,
+}
+
+impl Recipe for DebugServerRecipe {
+    type Output = DebugRequest;
+
+    fn hash(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Self>().hash(state);
+        self.port.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: EventStream,
+    ) -> futures::stream::BoxStream<'static, Self::Output> {
+        let (tx, rx) = mpsc::channel(100);
+        let port = self.port;
+        let token = token();
+
+        tokio::task::spawn_blocking(move || {
+            let listener = match TcpListener::bind((Ipv4Addr::LOCALHOST, port)) {
+                Ok(listener) => listener,
+                Err(e) => {
+                    eprintln!("[dampen-dev] Failed to start debug server on port {port}: {e}");
+                    return;
+                }
+            };
+            // Poll, so the server stops once the subscription is dropped
+            if let Err(e) = listener.set_nonblocking(true) {
+                eprintln!("[dampen-dev] Failed to start debug server: {e}");
+                return;
+            }
+            eprintln!("[dampen-dev] Debug server listening on 127.0.0.1:{port}, token {token}");
+
+            while !tx.is_closed() {
+                match listener.accept() {
+                    Ok((stream, _)) => {
+                        let (tx, token) = (tx.clone(), token.clone());
+                        std::thread::spawn(move || serve_connection(stream, &token, tx));
+                    }
+                    Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                        std::thread::sleep(Duration::from_millis(100));
+                    }
+                    Err(e) => eprintln!("[dampen-dev] Debug server connection failed: {e}"),
+                }
+            }
+        });
+
+        Box::pin(ReceiverStream::new(rx))
+    }
+}
+
+/// Answer the commands of one debugger until it disconnects, or sends a line
+/// that is not a command with the session token
+fn serve_connection(stream: TcpStream, token: &str, tx: mpsc::Sender<DebugRequest>) {
+    if stream.set_nonblocking(false).is_err() {
+        return;
+    }
+    let Ok(mut writer) = stream.try_clone() else {
+        return;
+    };
+
+    for line in BufReader::new(stream).lines() {
+        let Ok(line) = line else {
+            return;
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let command = match serde_json::from_str::<AuthenticatedCommand>(&line) {
+            Ok(command) if command.token == token => command.command,
+            Ok(_) => {
+                respond(
+                    &mut writer,
+                    &DebugResponse::Error("invalid token".to_string()),
+                );
+                return;
+            }
+            Err(e) => {
+                respond(
+                    &mut writer,
+                    &DebugResponse::Error(format!("invalid command: {e}")),
+                );
+                return;
+            }
+        };
+
+        let (reply, response) = reply_channel::channel();
+        if tx.blocking_send(DebugRequest { command, reply }).is_err() {
+            return;
+        }
+        let response = response
+            .recv_timeout(REPLY_TIMEOUT)
+            .unwrap_or_else(|_| DebugResponse::Error("no response".to_string()));
+        if !respond(&mut writer, &response) {
+            return;
+        }
+    }
+}
+
+/// Write a response line, returning whether the debugger is still there
+fn respond(writer: &mut TcpStream, response: &DebugResponse) -> bool {
+    serde_json::to_string(response).is_ok_and(|json| writeln!(writer, "{json}").is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_commands_and_responses_are_tagged_json() {
+        let command: Result<DebugCommand, _> =
+            serde_json::from_value(json!({"command": "evaluate", "expr": "count * 2"}));
+        assert_eq!(
+            command.ok(),
+            Some(DebugCommand::Evaluate {
+                expr: "count * 2".to_string(),
+                view: None,
+            })
+        );
+
+        let response = DebugResponse::from(Ok(json!(6)));
+        assert_eq!(serde_json::to_value(&response).ok(), Some(json!({"ok": 6})));
+        let response = DebugResponse::from(Err("unknown view".to_string()));
+        assert_eq!(
+            serde_json::to_value(&response).ok(),
+            Some(json!({"error": "unknown view"}))
+        );
+    }
+
+    /// Connect to `serve_connection` with the token `secret`, send `lines`
+    /// and return the lines received until the server closes the connection,
+    /// answering each request with its command
+    fn exchange(lines: &str) -> std::io::Result<(Vec<String>, usize)> {
+        use std::io::Read;
+
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?;
+        let mut client = TcpStream::connect(listener.local_addr()?)?;
+        let (stream, _) = listener.accept()?;
+
+        let (tx, mut rx) = mpsc::channel(10);
+        let server = std::thread::spawn(move || serve_connection(stream, "secret", tx));
+        let requests = std::thread::spawn(move || {
+            let mut count = 0;
+            while let Some(request) = rx.blocking_recv() {
+                request.respond(serde_json::to_value(&request.command).map_err(|e| e.to_string()));
+                count += 1;
+            }
+            count
+        });
+
+        client.write_all(lines.as_bytes())?;
+        client.shutdown(std::net::Shutdown::Write)?;
+        let mut output = String::new();
+        client.read_to_string(&mut output)?;
+        let _ = server.join();
+        let count = requests.join().unwrap_or_default();
+        Ok((output.lines().map(str::to_string).collect(), count))
+    }
+
+    #[test]
+    fn test_commands_need_the_session_token() -> std::io::Result<()> {
+        let (responses, requests) = exchange(concat!(
+            "{\"token\": \"secret\", \"command\": \"views\"}\n",
+            "{\"token\": \"guess\", \"command\": \"views\"}\n",
+            "{\"token\": \"secret\", \"command\": \"views\"}\n",
+        ))?;
+        assert_eq!(requests, 1);
+        assert_eq!(
+            responses,
+            [
+                r#"{"ok":{"command":"views"}}"#,
+                r#"{"error":"invalid token"}"#
+            ]
+        );
+
+        let (responses, requests) = exchange("{\"command\": \"views\"}\n")?;
+        assert_eq!(requests, 0);
+        assert_eq!(responses.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_connection_closes_on_the_first_invalid_line() -> std::io::Result<()> {
+        // An HTTP request smuggling a command in its body
+        let (responses, requests) = exchange(concat!(
+            "POST / HTTP/1.1\r\n",
+            "Host: 127.0.0.1:7420\r\n",
+            "\r\n",
+            "{\"token\": \"secret\", \"command\": \"dispatch\", \"handler\": \"reset\"}\n",
+        ))?;
+        assert_eq!(requests, 0);
+        assert_eq!(responses.len(), 1);
+        assert!(responses[0].starts_with(r#"{"error":"invalid command"#));
+        Ok(())
+    }
+}
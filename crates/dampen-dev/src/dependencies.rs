@@ -0,0 +1,279 @@
+//! Dependencies between the files of a reload batch
+//!
+//! `.dampen` files do not include each other: a document depends on the files
+//! whose definitions it uses.
+//!
+//! - A theme file (see [`is_theme_file_path`]) styles every view.
+//! - A style library defines the style classes and themes that other documents
+//!   use with `class`, `theme` or `<global_theme>`.
+//! - An asset (see [`is_asset_file`]) is used by the documents whose
+//!   attributes name it, e.g. `<image src="images/logo.png" />`. The path is
+//!   resolved against the directory of the document and its parents, like
+//!   [`locate`](dampen_core::assets::locate) looks it up, so `a/logo.png`
+//!   and `b/logo.png` are different assets.
+//!
+//! [`dependency_order`] sorts the files of a batch so that each file is
+//! reloaded after the files it depends on, and a view rebuilt in the batch
+//! already sees the new theme, classes and images.
+
+use crate::reload::is_theme_file_path;
+use crate::watcher::is_asset_file;
+use dampen_core::ir::DampenDocument;
+use dampen_core::ir::node::{AttributeValue, WidgetNode};
+use std::collections::BTreeSet;
+use std::path::{Component, Path, PathBuf};
+
+/// Definition a file provides to, or uses from, the other files
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Definition {
+    Class(String),
+    Theme(String),
+    /// Path of an asset, without `.` and `..` components
+    Asset(PathBuf),
+}
+
+/// A file of the batch, with what it defines and uses
+struct FileNode<'a> {
+    path: &'a Path,
+    provides: BTreeSet<Definition>,
+    uses: BTreeSet<Definition>,
+}
+
+impl<'a> FileNode<'a> {
+    fn new(path: &'a Path, document: Option<&DampenDocument>) -> Self {
+        let mut node = Self {
+            path,
+            provides: BTreeSet::new(),
+            uses: BTreeSet::new(),
+        };
+        if is_asset_file(path) {
+            node.provides.insert(Definition::Asset(normalize(path)));
+        }
+        if let Some(document) = document {
+            node.provides.extend(
+                document
+                    .style_classes
+                    .keys()
+                    .cloned()
+                    .map(Definition::Class),
+            );
+            node.provides
+                .extend(document.themes.keys().cloned().map(Definition::Theme));
+            node.uses
+                .extend(document.global_theme.clone().map(Definition::Theme));
+            let dir = path.parent().unwrap_or(Path::new(""));
+            collect_uses(&document.root, dir, &mut node.uses);
+        }
+        node
+    }
+
+    /// Whether this file must be reloaded before `other`
+    fn is_used_by(&self, other: &FileNode) -> bool {
+        if self.path == other.path {
+            return false;
+        }
+        if is_theme_file_path(self.path) {
+            return !is_theme_file_path(other.path) && !is_asset_file(other.path);
+        }
+        !self.provides.is_disjoint(&other.uses)
+    }
+}
+
+/// Collect the classes, themes and assets used by a node and its children,
+/// in a document of the directory `dir`
+fn collect_uses(node: &WidgetNode, dir: &Path, uses: &mut BTreeSet<Definition>) {
+    uses.extend(node.classes.iter().cloned().map(Definition::Class));
+    if let Some(AttributeValue::Static(theme)) = &node.theme_ref {
+        uses.insert(Definition::Theme(theme.clone()));
+    }
+    for value in node.attributes.values() {
+        if let AttributeValue::Static(value) = value {
+            let path = Path::new(value);
+            if is_asset_file(path) {
+                uses.extend(
+                    dir.ancestors()
+                        .map(|ancestor| Definition::Asset(normalize(&ancestor.join(path)))),
+                );
+            }
+        }
+    }
+    for child in node.children.iter() {
+        collect_uses(child, dir, uses);
+    }
+}
+
+/// `path` without its `.` components, and with `..` applied to the preceding one
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir if normalized.file_name().is_some() => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// Sort `items` so that each file comes after the files it depends on
+///
+/// `path` and `document` give the file of an item and its parsed document, if
+/// any. Files that do not depend on each other are sorted by path, so the order
+/// is the same for the same batch. Files of a dependency cycle come last, by
+/// path.
+pub(crate) fn dependency_order<T>(
+    items: Vec<T>,
+    path: impl Fn(&T) -> &Path,
+    document: impl Fn(&T) -> Option<&DampenDocument>,
+) -> Vec<T> {
+    let order = {
+        let nodes: Vec<FileNode> = items
+            .iter()
+            .map(|item| FileNode::new(path(item), document(item)))
+            .collect();
+
+        // Files each file must wait for, and the files waiting for it
+        let mut pending = vec![0usize; nodes.len()];
+        let mut dependents = vec![Vec::new(); nodes.len()];
+        for (i, node) in nodes.iter().enumerate() {
+            for (j, other) in nodes.iter().enumerate() {
+                if node.is_used_by(other) {
+                    pending[j] += 1;
+                    dependents[i].push(j);
+                }
+            }
+        }
+
+        let mut ready: BTreeSet<(&Path, usize)> = nodes
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| pending[*i] == 0)
+            .map(|(i, node)| (node.path, i))
+            .collect();
+        let mut order = Vec::with_capacity(nodes.len());
+        while let Some((_, i)) = ready.pop_first() {
+            order.push(i);
+            for &j in &dependents[i] {
+                pending[j] -= 1;
+                if pending[j] == 0 {
+                    ready.insert((nodes[j].path, j));
+                }
+            }
+        }
+
+        let mut cycle: Vec<usize> = (0..nodes.len()).filter(|i| pending[*i] > 0).collect();
+        cycle.sort_by_key(|&i| nodes[i].path);
+        order.extend(cycle);
+        order
+    };
+
+    let mut items: Vec<Option<T>> = items.into_iter().map(Some).collect();
+    order
+        .into_iter()
+        .filter_map(|i| items.get_mut(i).and_then(Option::take))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dampen_core::parser::parse;
+    use std::path::PathBuf;
+
+    fn order(files: &[(&str, Option<&str>)]) -> Vec<String> {
+        let files: Vec<(PathBuf, Option<DampenDocument>)> = files
+            .iter()
+            .map(|(path, xml)| (PathBuf::from(path), xml.and_then(|xml| parse(xml).ok())))
+            .collect();
+        dependency_order(files, |(path, _)| path, |(_, document)| document.as_ref())
+            .into_iter()
+            .map(|(path, _)| path.to_string_lossy().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_style_library_before_its_users() {
+        let library =
+            r#"<dampen><styles><style name="card"><base padding="8" /></style></styles></dampen>"#;
+        let user = r#"<column><container class="card"><text value="A" /></container></column>"#;
+        let other = r#"<column><text value="B" /></column>"#;
+
+        assert_eq!(
+            order(&[
+                ("src/ui/a_view.dampen", Some(user)),
+                ("src/ui/b_view.dampen", Some(other)),
+                ("src/ui/styles/cards.dampen", Some(library)),
+            ]),
+            vec![
+                "src/ui/b_view.dampen",
+                "src/ui/styles/cards.dampen",
+                "src/ui/a_view.dampen",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_theme_and_assets_before_views() {
+        let view = r#"<column><image src="assets/images/logo.png" /></column>"#;
+
+        assert_eq!(
+            order(&[
+                ("src/ui/a_view.dampen", Some(view)),
+                ("src/ui/theme/theme.dampen", None),
+                ("assets/images/logo.png", None),
+                ("assets/images/unused.png", None),
+            ]),
+            vec![
+                "assets/images/logo.png",
+                "assets/images/unused.png",
+                "src/ui/theme/theme.dampen",
+                "src/ui/a_view.dampen",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_assets_sharing_a_file_name() {
+        let view = r#"<column><image src="./logo.png" /></column>"#;
+
+        // Each view depends on the logo of its own directory only
+        assert_eq!(
+            order(&[
+                ("src/ui/a/logo.png", None),
+                ("src/ui/a/view.dampen", Some(view)),
+                ("src/ui/b/logo.png", None),
+                ("src/ui/b/view.dampen", Some(view)),
+            ]),
+            vec![
+                "src/ui/a/logo.png",
+                "src/ui/a/view.dampen",
+                "src/ui/b/logo.png",
+                "src/ui/b/view.dampen",
+            ]
+        );
+        assert_eq!(
+            order(&[
+                ("src/ui/a/view.dampen", Some(view)),
+                ("src/ui/b/logo.png", None),
+            ]),
+            vec!["src/ui/a/view.dampen", "src/ui/b/logo.png"]
+        );
+    }
+
+    #[test]
+    fn test_cycle_falls_back_to_path_order() {
+        let a = r#"<dampen><styles><style name="a"><base padding="8" /></style></styles><column class="b" /></dampen>"#;
+        let b = r#"<dampen><styles><style name="b"><base padding="8" /></style></styles><column class="a" /></dampen>"#;
+
+        assert_eq!(
+            order(&[
+                ("src/ui/b.dampen", Some(b)),
+                ("src/ui/a.dampen", Some(a)),
+                ("src/ui/c.dampen", None),
+            ]),
+            vec!["src/ui/c.dampen", "src/ui/a.dampen", "src/ui/b.dampen"]
+        );
+    }
+}
@@ -0,0 +1,372 @@
+//! Widget inspector
+//!
+//! With `#[dampen_app(inspector_variant = "...")]`, Ctrl+Shift+I turns on the
+//! inspector in development builds. The widget under the cursor is
+//! highlighted, and a panel under the view shows its tag, id, classes,
+//! resolved style and location. Clicking a widget opens its `.dampen` file at
+//! its line in an editor, instead of reaching the widget.
+//!
+//! The editor command is read from `DAMPEN_EDITOR`, with `{file}`, `{line}`
+//! and `{column}` placeholders, and defaults to [`DEFAULT_EDITOR`]:
+//!
+//! ```sh
+//! DAMPEN_EDITOR="zed {file}:{line}:{column}" dampen run
+//! ```
+//!
+//! Hit-testing is done by `dampen_iced::inspect`.
+
+use dampen_core::ir::span::Span;
+use dampen_core::ir::style::{Background, StyleProperties};
+use dampen_iced::inspect::{HitTest, Inspect, InspectedNode};
+use iced::keyboard::{self, Key};
+use iced::widget::{column, container, row, text};
+use iced::{Color, Element, Font, Length, Subscription};
+use std::path::Path;
+use std::process::Command;
+
+/// Environment variable holding the editor command
+pub const EDITOR_ENV: &str = "DAMPEN_EDITOR";
+
+/// Editor command used when `DAMPEN_EDITOR` is not set
+pub const DEFAULT_EDITOR: &str = "code --goto {file}:{line}:{column}";
+
+/// Inspector events, sent by the inspected view and the Ctrl+Shift+I keys
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// Turn the inspector on or off
+    Toggle,
+
+    /// The widget under the cursor changed
+    Hover(Option<InspectedNode>),
+
+    /// A widget was clicked, to be opened in the editor
+    Select(InspectedNode),
+}
+
+/// State of the inspector, kept by the application
+#[derive(Debug)]
+pub struct Inspector {
+    /// Hit test attached to the builder of the view while inspecting
+    pub hit_test: HitTest,
+
+    /// Editor command, with `{file}`, `{line}` and `{column}` placeholders
+    pub editor: String,
+
+    /// Widget under the cursor
+    hovered: Option<InspectedNode>,
+
+    /// Outcome of the last attempt to open the editor
+    status: Option<Result<String, String>>,
+
+    /// Whether the inspector is on
+    active: bool,
+}
+
+impl Default for Inspector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Inspector {
+    /// Create an inspector, off, using the editor of `DAMPEN_EDITOR`
+    pub fn new() -> Self {
+        Self {
+            hit_test: HitTest::new(),
+            editor: std::env::var(EDITOR_ENV)
+                .ok()
+                .filter(|editor| !editor.trim().is_empty())
+                .unwrap_or_else(|| DEFAULT_EDITOR.to_string()),
+            hovered: None,
+            status: None,
+            active: false,
+        }
+    }
+
+    /// Whether the inspector is on
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Turn the inspector on or off
+    pub fn toggle(&mut self) {
+        self.active = !self.active;
+        self.hovered = None;
+        self.status = None;
+        self.hit_test.clear();
+    }
+
+    /// Widget under the cursor
+    pub fn hovered(&self) -> Option<&InspectedNode> {
+        self.hovered.as_ref()
+    }
+
+    /// Show `node` in the panel
+    pub fn hover(&mut self, node: Option<InspectedNode>) {
+        self.hovered = node;
+    }
+
+    /// Open `file` at the location of `node` in the editor
+    ///
+    /// The outcome is shown in the panel and printed.
+    pub fn open(&mut self, file: &Path, node: &InspectedNode) {
+        let result = match editor_command(&self.editor, file, &node.span) {
+            Some(mut command) => command
+                .spawn()
+                .map(|_| format!("Opened {}:{}", file.display(), node.span.line))
+                .map_err(|e| format!("Failed to run '{}': {}", self.editor, e)),
+            None => Err(format!("{} is empty", EDITOR_ENV)),
+        };
+        match &result {
+            Ok(message) => eprintln!("[dampen-dev] {}", message),
+            Err(e) => eprintln!("[dampen-dev] {}", e),
+        }
+        self.status = Some(result);
+    }
+
+    /// Render `content` inspected, with the panel under it, if the inspector is on
+    ///
+    /// `content` must be built with the [`hit_test`](Self::hit_test) attached.
+    pub fn view<'a, Message: Clone + 'a>(
+        &'a self,
+        content: Element<'a, Message>,
+        on_event: fn(Event) -> Message,
+    ) -> Element<'a, Message> {
+        if !self.active {
+            return content;
+        }
+
+        let inspected = Inspect::new(
+            content,
+            &self.hit_test,
+            move |node| on_event(Event::Hover(node)),
+            move |node| on_event(Event::Select(node)),
+        );
+
+        let mut details = column![].spacing(4);
+        match &self.hovered {
+            Some(node) => {
+                let mut title = format!("<{}>", node.tag);
+                if let Some(id) = &node.id {
+                    title.push_str(&format!(" #{}", id));
+                }
+                for class in &node.classes {
+                    title.push_str(&format!(" .{}", class));
+                }
+                details = details.push(
+                    row![
+                        text(title).size(14).font(Font::MONOSPACE),
+                        text(format!(
+                            "line {}, column {} · {:.0}×{:.0}",
+                            node.span.line, node.span.column, node.bounds.width, node.bounds.height
+                        ))
+                        .size(13)
+                        .style(|_theme| text::Style {
+                            color: Some(Color::from_rgb(0.7, 0.7, 0.7)),
+                        }),
+                    ]
+                    .spacing(12),
+                );
+                let style = node.style.as_ref().map(describe_style).unwrap_or_default();
+                let style = if style.is_empty() {
+                    "no style".to_string()
+                } else {
+                    style.join("   ")
+                };
+                details = details.push(text(style).size(13).font(Font::MONOSPACE));
+            }
+            None => {
+                details = details.push(
+                    text("Hover a widget to inspect it, click to open it in the editor").size(13),
+                );
+            }
+        }
+        if let Some(status) = &self.status {
+            let (message, color) = match status {
+                Ok(message) => (message.as_str(), Color::from_rgb(0.6, 0.9, 0.6)),
+                Err(error) => (error.as_str(), Color::from_rgb(1.0, 0.5, 0.5)),
+            };
+            details = details.push(
+                text(message)
+                    .size(13)
+                    .style(move |_theme| text::Style { color: Some(color) }),
+            );
+        }
+
+        let panel = container(details.padding(8))
+            .width(Length::Fill)
+            .style(|_theme| container::Style {
+                background: Some(Color::from_rgb(0.1, 0.1, 0.12).into()),
+                text_color: Some(Color::WHITE),
+                ..Default::default()
+            });
+
+        column![container(inspected).height(Length::Fill), panel].into()
+    }
+}
+
+/// The editor command opening `file` at `span`
+///
+/// `template` is split on whitespace; its `{file}`, `{line}` and `{column}`
+/// placeholders are replaced. Without a `{file}` placeholder, the file is
+/// passed as the last argument. Returns `None` if `template` is empty.
+pub fn editor_command(template: &str, file: &Path, span: &Span) -> Option<Command> {
+    let file = file.to_string_lossy();
+    let mut words = template.split_whitespace().map(|word| {
+        word.replace("{file}", &file)
+            .replace("{line}", &span.line.to_string())
+            .replace("{column}", &span.column.to_string())
+    });
+    let mut command = Command::new(words.next()?);
+    command.args(words);
+    if !template.contains("{file}") {
+        command.arg(file.as_ref());
+    }
+    Some(command)
+}
+
+/// The properties set in `style`, as `name: value` lines
+pub fn describe_style(style: &StyleProperties) -> Vec<String> {
+    let mut lines = Vec::new();
+    if let Some(background) = &style.background {
+        lines.push(match background {
+            Background::Color(color) => format!("background: {}", color.to_rgba_hex()),
+            Background::Gradient(_) => "background: gradient".to_string(),
+            Background::Image { path, .. } => format!("background: url({})", path),
+        });
+    }
+    if let Some(color) = &style.color {
+        lines.push(format!("color: {}", color.to_rgba_hex()));
+    }
+    if let Some(border) = &style.border {
+        lines.push(format!(
+            "border: {} {} radius {}",
+            border.width,
+            border.color.to_rgba_hex(),
+            border.radius.top_left
+        ));
+    }
+    if let Some(shadow) = &style.shadow {
+        lines.push(format!(
+            "shadow: {} {} {} {}",
+            shadow.offset_x,
+            shadow.offset_y,
+            shadow.blur_radius,
+            shadow.color.to_rgba_hex()
+        ));
+    }
+    if let Some(opacity) = style.opacity {
+        lines.push(format!("opacity: {}", opacity));
+    }
+    if let Some(elevation) = style.elevation {
+        lines.push(format!("elevation: {}", elevation));
+    }
+    if let Some(transform) = &style.transform {
+        lines.push(format!("transform: {:?}", transform));
+    }
+    lines
+}
+
+/// Subscription to the Ctrl+Shift+I keys, turning the inspector on or off
+pub fn toggle_key() -> Subscription<Event> {
+    iced::event::listen_with(toggle_key_event)
+}
+
+fn toggle_key_event(
+    event: iced::Event,
+    _status: iced::event::Status,
+    _window: iced::window::Id,
+) -> Option<Event> {
+    match event {
+        iced::Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. })
+            if modifiers.command()
+                && modifiers.shift()
+                && matches!(key.as_ref(), Key::Character(c) if c.eq_ignore_ascii_case("i")) =>
+        {
+            Some(Event::Toggle)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dampen_core::ir::style::{Border, BorderRadius, BorderStyle, Color as StyleColor};
+
+    #[test]
+    fn test_editor_command_placeholders() {
+        let span = Span {
+            start: 0,
+            end: 0,
+            line: 12,
+            column: 5,
+        };
+        let file = Path::new("/app/src/ui/window.dampen");
+
+        let command = editor_command(DEFAULT_EDITOR, file, &span).unwrap_or_else(|| unreachable!());
+        assert_eq!(command.get_program(), "code");
+        let args: Vec<_> = command.get_args().collect();
+        assert_eq!(args, vec!["--goto", "/app/src/ui/window.dampen:12:5"]);
+
+        // Without {file}, the file comes last
+        let command =
+            editor_command("gedit +{line}", file, &span).unwrap_or_else(|| unreachable!());
+        let args: Vec<_> = command.get_args().collect();
+        assert_eq!(args, vec!["+12", "/app/src/ui/window.dampen"]);
+
+        assert!(editor_command("  ", file, &span).is_none());
+    }
+
+    #[test]
+    fn test_describe_style() {
+        let style = StyleProperties {
+            background: Some(Background::Color(StyleColor::from_rgb8(255, 0, 0))),
+            border: Some(Border {
+                width: 2.0,
+                color: StyleColor::from_rgb8(0, 0, 0),
+                radius: BorderRadius {
+                    top_left: 4.0,
+                    top_right: 4.0,
+                    bottom_right: 4.0,
+                    bottom_left: 4.0,
+                },
+                style: BorderStyle::Solid,
+            }),
+            opacity: Some(0.5),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            describe_style(&style),
+            vec![
+                "background: #ff0000ff",
+                "border: 2 #000000ff radius 4",
+                "opacity: 0.5"
+            ]
+        );
+        assert!(describe_style(&StyleProperties::default()).is_empty());
+    }
+
+    #[test]
+    fn test_toggle_clears_hovered_node() {
+        let mut inspector = Inspector::new();
+        assert!(!inspector.is_active());
+        inspector.toggle();
+        assert!(inspector.is_active());
+
+        inspector.hover(Some(InspectedNode {
+            tag: "button".to_string(),
+            id: Some("save".to_string()),
+            classes: vec!["primary".to_string()],
+            style: None,
+            span: Span::new(0, 0, 3, 5),
+            bounds: iced::Rectangle::default(),
+        }));
+        assert_eq!(inspector.hovered().map(|n| n.tag.as_str()), Some("button"));
+
+        inspector.toggle();
+        assert!(!inspector.is_active());
+        assert!(inspector.hovered().is_none());
+    }
+}
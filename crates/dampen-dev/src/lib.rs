@@ -6,16 +6,30 @@
 #![warn(missing_docs)]
 #![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 
+pub mod debug_server;
+pub mod inspector;
+pub mod logging;
 pub mod overlay;
+pub mod panic;
 pub mod persistence;
 pub mod reload;
+pub mod state_file;
 pub mod subscription;
 pub mod theme_loader;
+pub mod time_travel;
 pub mod watcher;
 
+mod dependencies;
+mod shortcut;
+
 // Re-export key types for convenience
-pub use overlay::ErrorOverlay;
+pub use overlay::{ErrorOverlay, OverlayPlacement, OverlayStyle, OverlayVariant};
+pub use panic::{PanicReport, catch_handler_panic, install_panic_hook};
 pub use reload::{HotReloadContext, ReloadResult};
-pub use subscription::{FileEvent, FileWatcherRecipe, watch_files};
+pub use subscription::{
+    FileEvent, FileWatcherRecipe, ReloadBatch, watch_files, watch_files_and_assets,
+};
 pub use theme_loader::{ThemeLoadError, discover_theme_file, load_theme_context};
-pub use watcher::{FileWatcher, FileWatcherConfig, FileWatcherError, FileWatcherState};
+pub use watcher::{
+    FileWatcher, FileWatcherConfig, FileWatcherError, FileWatcherState, is_asset_file, reload_order,
+};
@@ -0,0 +1,226 @@
+//! Log output and in-app log viewer for development mode
+//!
+//! [`init`] installs a `tracing` subscriber filtered by the `DAMPEN_LOG`
+//! environment variable (e.g. `DAMPEN_LOG=debug`, set by `dampen run --log debug`).
+//! Records are printed to stderr when `DAMPEN_LOG` is set, and always kept in
+//! a buffer of recent records shown by [`viewer`], toggled with F12 when
+//! `#[dampen_app(log_viewer_variant = "...")]` is set.
+
+use crate::shortcut::key_toggle;
+use dampen_core::log::LOG_ENV;
+use iced::keyboard::key::Named;
+use iced::widget::{column, container, scrollable, text};
+use iced::{Color, Element, Font, Length, Subscription};
+use std::collections::VecDeque;
+use std::fmt::Write;
+use std::sync::{Mutex, PoisonError};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber, span};
+use tracing_subscriber::EnvFilter;
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::util::SubscriberInitExt;
+
+/// Number of records kept for the log viewer
+const CAPACITY: usize = 500;
+
+/// Filter used when `DAMPEN_LOG` is not set
+const DEFAULT_FILTER: &str = "info";
+
+static RECORDS: Mutex<VecDeque<LogRecord>> = Mutex::new(VecDeque::new());
+
+/// A log record kept for the log viewer
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogRecord {
+    /// Level of the record
+    pub level: Level,
+
+    /// Module path the record was logged from
+    pub target: String,
+
+    /// Message, followed by the other fields of the record
+    pub message: String,
+
+    /// Spans the record was logged in, from the outermost,
+    /// e.g. `handler{view="counter" handler="increment" generation=2}`
+    pub spans: String,
+}
+
+/// Install the development mode subscriber.
+///
+/// Does nothing if a global subscriber is already installed, e.g. by the
+/// application itself.
+pub fn init() {
+    let filter =
+        EnvFilter::try_from_env(LOG_ENV).unwrap_or_else(|_| EnvFilter::new(DEFAULT_FILTER));
+    let print = std::env::var_os(LOG_ENV)
+        .is_some()
+        .then(|| tracing_subscriber::fmt::layer().with_writer(std::io::stderr));
+
+    let _ = tracing_subscriber::registry()
+        .with(filter)
+        .with(print)
+        .with(LogBuffer)
+        .try_init();
+}
+
+/// Recent log records, from the oldest.
+pub fn records() -> Vec<LogRecord> {
+    buffer().iter().cloned().collect()
+}
+
+/// Forget the recent log records.
+pub fn clear() {
+    buffer().clear();
+}
+
+fn buffer() -> std::sync::MutexGuard<'static, VecDeque<LogRecord>> {
+    RECORDS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn push(record: LogRecord) {
+    let mut records = buffer();
+    if records.len() == CAPACITY {
+        records.pop_front();
+    }
+    records.push_back(record);
+}
+
+/// Render the recent log records as a pane, scrolled to the latest record.
+pub fn viewer<'a, Message: 'a>() -> Element<'a, Message> {
+    let lines = records().into_iter().map(|record| {
+        let color = match record.level {
+            Level::ERROR => Color::from_rgb(1.0, 0.45, 0.45),
+            Level::WARN => Color::from_rgb(1.0, 0.8, 0.4),
+            Level::INFO => Color::from_rgb(0.6, 0.85, 1.0),
+            _ => Color::from_rgb(0.7, 0.7, 0.7),
+        };
+        let spans = if record.spans.is_empty() {
+            String::new()
+        } else {
+            format!("{}: ", record.spans)
+        };
+        text(format!(
+            "{:>5} {}{}: {}",
+            record.level, spans, record.target, record.message
+        ))
+        .size(12)
+        .font(Font::MONOSPACE)
+        .style(move |_theme| text::Style { color: Some(color) })
+        .into()
+    });
+
+    container(
+        scrollable(column(lines).spacing(2).padding(8))
+            .anchor_bottom()
+            .width(Length::Fill)
+            .height(Length::Fill),
+    )
+    .width(Length::Fill)
+    .height(Length::Fixed(200.0))
+    .style(|_theme| container::Style {
+        background: Some(Color::from_rgb(0.1, 0.1, 0.12).into()),
+        ..Default::default()
+    })
+    .into()
+}
+
+/// Subscription to the F12 key, toggling the log viewer
+pub fn toggle_key() -> Subscription<()> {
+    key_toggle(Named::F12)
+}
+
+/// Layer keeping the records in the buffer of the log viewer
+struct LogBuffer;
+
+/// Formatted fields of a span, stored in its extensions
+struct SpanFields(String);
+
+impl<S> Layer<S> for LogBuffer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut fields = FieldWriter::default();
+        attrs.record(&mut fields);
+        let formatted = format!("{}{{{}}}", span.name(), fields.fields);
+        span.extensions_mut().insert(SpanFields(formatted));
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let spans = ctx
+            .event_scope(event)
+            .map(|scope| {
+                scope
+                    .from_root()
+                    .filter_map(|span| span.extensions().get::<SpanFields>().map(|f| f.0.clone()))
+                    .collect::<Vec<_>>()
+                    .join(":")
+            })
+            .unwrap_or_default();
+
+        let mut fields = FieldWriter::default();
+        event.record(&mut fields);
+        let message = match (fields.message.is_empty(), fields.fields.is_empty()) {
+            (_, true) => fields.message,
+            (true, false) => fields.fields,
+            (false, false) => format!("{} {}", fields.message, fields.fields),
+        };
+
+        push(LogRecord {
+            level: *event.metadata().level(),
+            target: event.metadata().target().to_string(),
+            message,
+            spans,
+        });
+    }
+}
+
+/// Formats the fields of a span or an event as `name=value`
+#[derive(Default)]
+struct FieldWriter {
+    message: String,
+    fields: String,
+}
+
+impl Visit for FieldWriter {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{:?}", value);
+            return;
+        }
+        if !self.fields.is_empty() {
+            self.fields.push(' ');
+        }
+        let _ = write!(self.fields, "{}={:?}", field.name(), value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::Registry;
+
+    #[test]
+    fn test_records_are_annotated_with_their_spans() {
+        let subscriber = Registry::default().with(LogBuffer);
+        tracing::subscriber::with_default(subscriber, || {
+            let _span = dampen_core::log::handler_span("counter", "increment", 2).entered();
+            dampen_core::log::info!(count = 3, "incremented");
+        });
+
+        let record = records()
+            .into_iter()
+            .rfind(|record| record.message.starts_with("incremented"))
+            .unwrap_or_else(|| unreachable!("the record is buffered"));
+        assert_eq!(record.level, Level::INFO);
+        assert_eq!(record.message, "incremented count=3");
+        assert_eq!(
+            record.spans,
+            "handler{view=\"counter\" handler=\"increment\" generation=2}"
+        );
+    }
+}
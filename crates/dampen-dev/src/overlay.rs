@@ -1,26 +1,127 @@
 //! Error overlay UI components for displaying parse errors
 //!
 //! This module provides UI widgets for displaying error overlays during
-//! hot-reload when XML parsing or validation fails.
+//! hot-reload when XML parsing or validation fails, or when a handler panics.
+//!
+//! An [`OverlayStyle`] sets where the overlay is drawn (full screen or as a
+//! toast in a corner), its colors, opacity and font size:
+//!
+//! ```ignore
+//! let overlay = ErrorOverlay::new().with_style(OverlayStyle {
+//!     placement: OverlayPlacement::BottomRight,
+//!     variant: OverlayVariant::Dark,
+//!     opacity: 0.9,
+//!     ..OverlayStyle::default()
+//! });
+//! ```
+//!
+//! Only the full-screen overlay blocks the application. With
+//! [`warning_banner`](OverlayStyle::warning_banner), warnings are shown in a
+//! banner at the top of the window and the application stays interactive.
 
+use crate::panic::PanicReport;
+use dampen_core::ir::span::Span;
 use dampen_core::parser::error::ParseError;
+use iced::alignment::{Horizontal, Vertical};
 use iced::{
-    Alignment, Color, Element, Length,
-    widget::{button, column, container, text},
+    Alignment, Border, Color, Element, Font, Length, Theme,
+    widget::{button, column, container, opaque, row, scrollable, text},
 };
 use std::time::Instant;
 
+/// Where the overlay is drawn
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OverlayPlacement {
+    /// Over the whole window, blocking the application
+    #[default]
+    FullScreen,
+    /// Toast in the top left corner
+    TopLeft,
+    /// Toast in the top right corner
+    TopRight,
+    /// Toast in the bottom left corner
+    BottomLeft,
+    /// Toast in the bottom right corner
+    BottomRight,
+}
+
+/// Colors of the overlay
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OverlayVariant {
+    /// Dark or light, following the active theme
+    #[default]
+    Auto,
+    /// Light text on a dark background
+    Dark,
+    /// Dark text on a light background
+    Light,
+}
+
+impl OverlayVariant {
+    /// Whether the overlay is dark under `theme`
+    pub fn is_dark(self, theme: &Theme) -> bool {
+        match self {
+            OverlayVariant::Auto => theme.extended_palette().is_dark,
+            OverlayVariant::Dark => true,
+            OverlayVariant::Light => false,
+        }
+    }
+}
+
+/// Appearance of the [`ErrorOverlay`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OverlayStyle {
+    /// Where the overlay is drawn
+    pub placement: OverlayPlacement,
+    /// Colors of the overlay
+    pub variant: OverlayVariant,
+    /// Opacity of the background, from 0.0 (transparent) to 1.0
+    pub opacity: f32,
+    /// Size of the error message; titles and details are scaled from it
+    pub font_size: f32,
+    /// Show warnings in a banner at the top of the window, leaving the
+    /// application interactive
+    pub warning_banner: bool,
+}
+
+impl Default for OverlayStyle {
+    fn default() -> Self {
+        Self {
+            placement: OverlayPlacement::FullScreen,
+            variant: OverlayVariant::Auto,
+            opacity: 1.0,
+            font_size: 16.0,
+            warning_banner: false,
+        }
+    }
+}
+
 /// UI state for displaying parse errors during hot-reload
 #[derive(Debug, Clone)]
 pub struct ErrorOverlay {
     /// Parse error details
     pub error: Option<ParseError>,
 
+    /// Further errors found in the same file, listed below the main one
+    pub more_errors: Vec<ParseError>,
+
+    /// Content of the file, used to show the offending line under the message
+    pub source: Option<String>,
+
+    /// Panic of a handler, shown instead of the parse error
+    pub panic: Option<PanicReport>,
+
+    /// Whether the errors are warnings, the view still rendering
+    pub warning: bool,
+
     /// Whether overlay is visible
     pub visible: bool,
 
     /// Timestamp when error occurred
     pub timestamp: Instant,
+
+    /// Appearance of the overlay
+    pub style: OverlayStyle,
 }
 
 impl ErrorOverlay {
@@ -28,23 +129,92 @@ impl ErrorOverlay {
     pub fn new() -> Self {
         Self {
             error: None,
+            more_errors: Vec::new(),
+            source: None,
+            panic: None,
+            warning: false,
             visible: false,
             timestamp: Instant::now(),
+            style: OverlayStyle::default(),
         }
     }
 
+    /// Set the appearance of the overlay
+    pub fn with_style(mut self, style: OverlayStyle) -> Self {
+        self.style = style;
+        self
+    }
+
     /// Show the overlay with an error
     ///
     /// # Arguments
     /// * `error` - The parse error to display
     pub fn show(&mut self, error: ParseError) {
-        self.error = Some(error);
+        self.show_all(vec![error]);
+    }
+
+    /// Show the overlay with every error of a file
+    ///
+    /// The first error is displayed in full, the others as a list below it.
+    /// Does nothing if `errors` is empty.
+    ///
+    /// # Arguments
+    /// * `errors` - The parse errors to display, e.g. from `parse_with_recovery`
+    pub fn show_all(&mut self, errors: Vec<ParseError>) {
+        let mut errors = errors.into_iter();
+        let Some(first) = errors.next() else {
+            return;
+        };
+        self.error = Some(first);
+        self.more_errors = errors.collect();
+        self.source = None;
+        self.panic = None;
+        self.warning = false;
+        self.visible = true;
+        self.timestamp = Instant::now();
+    }
+
+    /// Show the overlay with warnings, e.g. the diagnostics of a view that
+    /// still renders
+    ///
+    /// Like [`show_all`](Self::show_all); with
+    /// [`warning_banner`](OverlayStyle::warning_banner), the warnings are
+    /// shown in a banner that doesn't block the application.
+    ///
+    /// # Arguments
+    /// * `warnings` - The warnings to display
+    pub fn show_warnings(&mut self, warnings: Vec<ParseError>) {
+        self.show_all(warnings);
+        self.warning = self.error.is_some();
+    }
+
+    /// Show the overlay with every error of a file, along with the file content
+    ///
+    /// The line of the first error is displayed with its span underlined.
+    ///
+    /// # Arguments
+    /// * `errors` - The parse errors to display
+    /// * `source` - The content of the file the errors were found in
+    pub fn show_in_source(&mut self, errors: Vec<ParseError>, source: String) {
+        self.show_all(errors);
+        if self.error.is_some() {
+            self.source = Some(source);
+        }
+    }
+
+    /// Show the overlay with the panic of a handler
+    ///
+    /// # Arguments
+    /// * `report` - The caught panic, from `catch_handler_panic`
+    pub fn show_panic(&mut self, report: PanicReport) {
+        self.panic = Some(report);
         self.visible = true;
         self.timestamp = Instant::now();
     }
 
     /// Hide the overlay
     pub fn hide(&mut self) {
+        self.panic = None;
         self.visible = false;
     }
 
@@ -53,9 +223,22 @@ impl ErrorOverlay {
         self.visible
     }
 
+    /// Whether the overlay blocks the application below it
+    ///
+    /// Only the full-screen overlay does; toasts and warning banners only
+    /// capture the mouse over themselves.
+    pub fn is_blocking(&self) -> bool {
+        self.style.placement == OverlayPlacement::FullScreen && !self.is_banner()
+    }
+
+    fn is_banner(&self) -> bool {
+        self.style.warning_banner && self.warning && self.panic.is_none()
+    }
+
     /// Render the error overlay as an Iced widget
     ///
-    /// Returns a full-screen overlay with error details and a dismiss button.
+    /// Returns the overlay with error details and a dismiss button, placed and
+    /// colored as set by its [`style`](Self::style).
     /// If the overlay is not visible, returns an empty container.
     ///
     /// # Type Parameters
@@ -70,81 +253,428 @@ impl ErrorOverlay {
     /// let widget = overlay.render(Message::DismissError);
     /// ```
     pub fn render<'a, Message: Clone + 'a>(&'a self, on_dismiss: Message) -> Element<'a, Message> {
+        self.render_with_reset(on_dismiss, None)
+    }
+
+    /// The error overlay for the `DevOverlay` layer of `dampen_iced::layers`
+    ///
+    /// Returns `None` while the overlay is hidden. The full-screen overlay
+    /// captures the mouse, so the view stacked below it is not interactive
+    /// (see [`is_blocking`](Self::is_blocking)).
+    ///
+    /// # Example
+    /// ```ignore
+    /// use dampen_iced::layers::{Layer, Layers};
+    ///
+    /// Layers::new(view)
+    ///     .push_maybe(Layer::DevOverlay, overlay.layer(Message::DismissError, None))
+    ///     .into()
+    /// ```
+    pub fn layer<'a, Message: Clone + 'a>(
+        &'a self,
+        on_dismiss: Message,
+        on_reset: Option<Message>,
+    ) -> Option<Element<'a, Message>> {
+        self.visible.then(|| {
+            let overlay = self.render_with_reset(on_dismiss, on_reset);
+            if self.is_blocking() {
+                opaque(overlay)
+            } else {
+                overlay
+            }
+        })
+    }
+
+    /// Render the error overlay, offering to reset the state after a panic
+    ///
+    /// Like [`render`](Self::render); when showing a panic, the dismiss button
+    /// continues with the model as it was before the handler ran, and a reset
+    /// button sends `on_reset` if given.
+    ///
+    /// # Arguments
+    /// * `on_dismiss` - Message to send when the dismiss button is clicked
+    /// * `on_reset` - Message to send to reset the state after a panic
+    pub fn render_with_reset<'a, Message: Clone + 'a>(
+        &'a self,
+        on_dismiss: Message,
+        on_reset: Option<Message>,
+    ) -> Element<'a, Message> {
         if !self.visible {
             return container(text("")).into();
         }
 
+        if let Some(report) = &self.panic {
+            let look = Look {
+                style: self.style,
+                warning: false,
+            };
+            return look.frame(render_panic(report, look, on_dismiss, on_reset));
+        }
+
         let error = match &self.error {
             Some(e) => e,
             None => return container(text("")).into(),
         };
 
+        let look = Look {
+            style: self.style,
+            warning: self.warning,
+        };
+        if self.is_banner() {
+            return render_banner(error, self.more_errors.len(), look, on_dismiss);
+        }
+
         // Title
-        let title = text("Hot-Reload Error")
-            .size(24)
-            .style(|_theme| text::Style {
-                color: Some(Color::WHITE),
-            });
+        let title = look.text(
+            if self.warning {
+                "Warning"
+            } else {
+                "Hot-Reload Error"
+            },
+            look.title_size(),
+            |colors| colors.accent,
+        );
 
         // Error message
-        let message = text(&error.message).size(16).style(|_theme| text::Style {
-            color: Some(Color::WHITE),
-        });
+        let message = look.text(&error.message, look.style.font_size, |colors| colors.text);
 
         // Location info
-        let location = text(format!(
-            "at line {}, column {}",
-            error.span.line, error.span.column
-        ))
-        .size(14)
-        .style(|_theme| text::Style {
-            color: Some(Color::from_rgb(0.9, 0.9, 0.9)),
-        });
+        let location = look.text(
+            format!("at line {}, column {}", error.span.line, error.span.column),
+            look.detail_size(),
+            |colors| colors.muted,
+        );
+
+        // Offending source line, with the span underlined
+        let excerpt = self
+            .source
+            .as_deref()
+            .and_then(|source| source_excerpt(source, &error.span))
+            .map(|excerpt| {
+                look.text(excerpt, look.detail_size(), |colors| colors.text)
+                    .font(Font::MONOSPACE)
+            });
 
         // Suggestion (if available)
-        let suggestion_widget = if let Some(ref suggestion) = error.suggestion {
-            let label = text(format!("💡 {}", suggestion))
-                .size(14)
-                .style(|_theme| text::Style {
-                    color: Some(Color::from_rgb(1.0, 1.0, 0.6)),
-                });
-            Some(label)
-        } else {
-            None
-        };
+        let suggestion_widget = error.suggestion.as_ref().map(|suggestion| {
+            look.text(
+                format!("💡 {}", suggestion),
+                look.detail_size(),
+                |colors| colors.suggestion,
+            )
+        });
 
         // Dismiss button
-        let dismiss_btn = button(text("Dismiss (Esc)").size(14).style(|_theme| text::Style {
-            color: Some(Color::BLACK),
-        }))
-        .on_press(on_dismiss)
-        .padding(10);
+        let dismiss_btn = button(text("Dismiss (Esc)").size(look.detail_size()))
+            .on_press(on_dismiss)
+            .padding(10);
 
         // Build content column
         let mut content = column![title, message, location]
             .spacing(12)
             .align_x(Alignment::Start);
 
+        if let Some(excerpt) = excerpt {
+            content = content.push(excerpt);
+        }
+
         if let Some(suggestion) = suggestion_widget {
             content = content.push(suggestion);
         }
 
+        if !self.more_errors.is_empty() {
+            let mut list = column![look.text(
+                format!("{} more error(s):", self.more_errors.len()),
+                look.style.font_size,
+                |colors| colors.text,
+            )]
+            .spacing(4);
+            for other in &self.more_errors {
+                list = list.push(look.text(
+                    format!(
+                        "line {}, column {}: {}",
+                        other.span.line, other.span.column, other.message
+                    ),
+                    look.detail_size(),
+                    |colors| colors.muted,
+                ));
+            }
+            content = content.push(list);
+        }
+
         content = content.push(dismiss_btn);
 
-        // Wrap in red container with padding
-        container(content)
+        look.frame(content)
+    }
+}
+
+/// Colors of the overlay under the active theme
+#[derive(Debug, Clone, Copy)]
+struct Colors {
+    background: Color,
+    text: Color,
+    muted: Color,
+    accent: Color,
+    suggestion: Color,
+}
+
+/// Style of the overlay and whether it shows warnings, from which its widgets
+/// are styled
+#[derive(Debug, Clone, Copy)]
+struct Look {
+    style: OverlayStyle,
+    warning: bool,
+}
+
+impl Look {
+    fn colors(self, theme: &Theme) -> Colors {
+        if self.style.variant.is_dark(theme) {
+            Colors {
+                background: Color::from_rgb(0.14, 0.11, 0.11),
+                text: Color::WHITE,
+                muted: Color::from_rgb(0.78, 0.78, 0.78),
+                accent: if self.warning {
+                    Color::from_rgb(0.95, 0.75, 0.3)
+                } else {
+                    Color::from_rgb(0.95, 0.4, 0.4)
+                },
+                suggestion: Color::from_rgb(1.0, 1.0, 0.6),
+            }
+        } else {
+            Colors {
+                background: Color::from_rgb(0.99, 0.96, 0.96),
+                text: Color::from_rgb(0.12, 0.12, 0.12),
+                muted: Color::from_rgb(0.38, 0.38, 0.38),
+                accent: if self.warning {
+                    Color::from_rgb(0.62, 0.42, 0.0)
+                } else {
+                    Color::from_rgb(0.75, 0.12, 0.12)
+                },
+                suggestion: Color::from_rgb(0.45, 0.38, 0.0),
+            }
+        }
+    }
+
+    fn title_size(self) -> f32 {
+        self.style.font_size * 1.5
+    }
+
+    fn detail_size(self) -> f32 {
+        (self.style.font_size - 2.0).max(8.0)
+    }
+
+    fn is_toast(self) -> bool {
+        self.style.placement != OverlayPlacement::FullScreen
+    }
+
+    /// Text of `size` in the color `pick` takes from the colors of the overlay
+    fn text<'a>(
+        self,
+        content: impl text::IntoFragment<'a>,
+        size: f32,
+        pick: fn(&Colors) -> Color,
+    ) -> text::Text<'a, Theme> {
+        text(content)
+            .size(size)
+            .style(move |theme: &Theme| text::Style {
+                color: Some(pick(&self.colors(theme))),
+            })
+    }
+
+    fn background(self, theme: &Theme) -> container::Style {
+        let colors = self.colors(theme);
+        container::Style {
+            background: Some(colors.background.scale_alpha(self.style.opacity).into()),
+            text_color: Some(colors.text),
+            border: if self.is_toast() {
+                Border {
+                    color: colors.accent,
+                    width: 1.0,
+                    radius: 8.0.into(),
+                }
+            } else {
+                Border::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Lay out `content` over the whole window or as a toast in a corner
+    fn frame<'a, Message: 'a>(
+        self,
+        content: impl Into<Element<'a, Message>>,
+    ) -> Element<'a, Message> {
+        let card = container(content).style(move |theme: &Theme| self.background(theme));
+        let (align_x, align_y) = match self.style.placement {
+            OverlayPlacement::FullScreen => {
+                return card
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .padding(40)
+                    .into();
+            }
+            OverlayPlacement::TopLeft => (Horizontal::Left, Vertical::Top),
+            OverlayPlacement::TopRight => (Horizontal::Right, Vertical::Top),
+            OverlayPlacement::BottomLeft => (Horizontal::Left, Vertical::Bottom),
+            OverlayPlacement::BottomRight => (Horizontal::Right, Vertical::Bottom),
+        };
+        container(opaque(card.padding(20).max_width(480)))
+            .padding(16)
             .width(Length::Fill)
             .height(Length::Fill)
-            .padding(40)
-            .style(|_theme| container::Style {
-                background: Some(Color::from_rgb(0.8, 0.2, 0.2).into()),
-                text_color: Some(Color::WHITE),
-                ..Default::default()
-            })
+            .align_x(align_x)
+            .align_y(align_y)
             .into()
     }
 }
 
+/// Render warnings as a banner at the top of the window
+///
+/// Only the banner captures the mouse, the application stays interactive.
+fn render_banner<'a, Message: Clone + 'a>(
+    warning: &'a ParseError,
+    more: usize,
+    look: Look,
+    on_dismiss: Message,
+) -> Element<'a, Message> {
+    let mut summary = format!(
+        "⚠ line {}, column {}: {}",
+        warning.span.line, warning.span.column, warning.message
+    );
+    if more > 0 {
+        summary.push_str(&format!(" ({} more)", more));
+    }
+
+    let banner = row![
+        look.text(summary, look.detail_size(), |colors| colors.text)
+            .width(Length::Fill),
+        button(text("Dismiss").size(look.detail_size()))
+            .on_press(on_dismiss)
+            .padding([4, 10]),
+    ]
+    .spacing(12)
+    .align_y(Alignment::Center);
+
+    let strip = container(banner)
+        .padding([8, 16])
+        .width(Length::Fill)
+        .style(move |theme: &Theme| container::Style {
+            border: Border {
+                color: look.colors(theme).accent,
+                width: 1.0,
+                radius: 0.0.into(),
+            },
+            ..look.background(theme)
+        });
+
+    container(opaque(strip))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+}
+
+/// Render the report of a handler panic, with buttons to continue or reset
+fn render_panic<'a, Message: Clone + 'a>(
+    report: &'a PanicReport,
+    look: Look,
+    on_continue: Message,
+    on_reset: Option<Message>,
+) -> Element<'a, Message> {
+    let title = look.text(
+        format!("Handler '{}' panicked", report.handler),
+        look.title_size(),
+        |colors| colors.accent,
+    );
+
+    let message = look.text(&report.message, look.style.font_size, |colors| colors.text);
+
+    let note = look.text(
+        "The model was restored to its state before the handler ran.",
+        look.detail_size(),
+        |colors| colors.muted,
+    );
+
+    let mut content = column![title, message]
+        .spacing(12)
+        .align_x(Alignment::Start);
+
+    if let Some(location) = &report.location {
+        content = content.push(look.text(
+            format!("at {}", location),
+            look.detail_size(),
+            |colors| colors.muted,
+        ));
+    }
+
+    content = content.push(note);
+
+    let mut actions = row![
+        button(text("Continue (Esc)").size(look.detail_size()))
+            .on_press(on_continue)
+            .padding(10)
+    ]
+    .spacing(12);
+    if let Some(on_reset) = on_reset {
+        actions = actions.push(
+            button(text("Reset state").size(look.detail_size()))
+                .on_press(on_reset)
+                .padding(10),
+        );
+    }
+    content = content.push(actions);
+
+    if !report.backtrace.is_empty() {
+        // Toasts keep their size, the backtrace scrolls
+        let height = if look.is_toast() {
+            Length::Fixed(240.0)
+        } else {
+            Length::Fill
+        };
+        content = content.push(
+            scrollable(
+                look.text(
+                    &report.backtrace,
+                    (look.style.font_size - 4.0).max(8.0),
+                    |colors| colors.text,
+                )
+                .font(Font::MONOSPACE),
+            )
+            .height(height),
+        );
+    }
+
+    content.into()
+}
+
+/// Format the source line of `span` with a caret line underlining the span
+///
+/// Returns `None` if the span's line is not in `source`. Spans covering
+/// several lines are underlined up to the end of their first line.
+fn source_excerpt(source: &str, span: &Span) -> Option<String> {
+    let line = source
+        .lines()
+        .nth(usize::try_from(span.line.checked_sub(1)?).ok()?)?;
+    let line = line.trim_end();
+    let offset = usize::try_from(span.column.saturating_sub(1)).ok()?;
+    let line_len = line.chars().count();
+
+    let span_len = source
+        .get(span.start..span.end)
+        .map(|covered| covered.lines().next().unwrap_or("").chars().count())
+        .unwrap_or(0);
+    let width = span_len.min(line_len.saturating_sub(offset)).max(1);
+
+    let number = span.line.to_string();
+    Some(format!(
+        "{} | {}\n{} | {}{}",
+        number,
+        line,
+        " ".repeat(number.len()),
+        " ".repeat(offset),
+        "^".repeat(width)
+    ))
+}
+
 impl Default for ErrorOverlay {
     fn default() -> Self {
         Self::new()
@@ -211,6 +741,22 @@ mod tests {
         assert!(overlay.error.is_some());
     }
 
+    #[test]
+    fn test_show_panic_replaces_parse_errors() {
+        let mut overlay = ErrorOverlay::new();
+        overlay.show_panic(PanicReport {
+            handler: "increment".to_string(),
+            message: "attempt to add with overflow".to_string(),
+            location: Some("src/ui/window.rs:12:5".to_string()),
+            backtrace: String::new(),
+        });
+        assert!(overlay.is_visible());
+        drop(overlay.render_with_reset(TestMessage::Dismiss, Some(TestMessage::Dismiss)));
+
+        overlay.hide();
+        assert!(overlay.panic.is_none());
+    }
+
     #[test]
     fn test_render_returns_empty_when_hidden() {
         let overlay = ErrorOverlay::new();
@@ -262,6 +808,87 @@ mod tests {
         drop(element);
     }
 
+    #[test]
+    fn test_show_all_keeps_every_error() {
+        let mut overlay = ErrorOverlay::new();
+        let error = |line| ParseError {
+            kind: ParseErrorKind::UnknownWidget,
+            message: format!("Unknown widget on line {}", line),
+            span: Span {
+                start: 0,
+                end: 1,
+                line,
+                column: 1,
+            },
+            suggestion: None,
+        };
+
+        overlay.show_all(vec![error(2), error(5), error(9)]);
+        assert!(overlay.is_visible());
+        assert_eq!(overlay.error, Some(error(2)));
+        assert_eq!(overlay.more_errors, vec![error(5), error(9)]);
+
+        // A single error replaces the previous list
+        overlay.show(error(3));
+        assert!(overlay.more_errors.is_empty());
+        drop(overlay.render(TestMessage::Dismiss));
+    }
+
+    #[test]
+    fn test_style_placement_and_warning_banner() {
+        let warning = ParseError {
+            kind: ParseErrorKind::InvalidValue,
+            message: "missing handler: save".to_string(),
+            span: Span {
+                start: 0,
+                end: 1,
+                line: 4,
+                column: 9,
+            },
+            suggestion: None,
+        };
+
+        let mut overlay = ErrorOverlay::new();
+        overlay.show_warnings(vec![warning.clone()]);
+        assert!(overlay.warning);
+        // Full screen by default, warnings included
+        assert!(overlay.is_blocking());
+
+        let mut overlay = ErrorOverlay::new().with_style(OverlayStyle {
+            warning_banner: true,
+            ..OverlayStyle::default()
+        });
+        overlay.show_warnings(vec![warning.clone(), warning.clone()]);
+        assert!(!overlay.is_blocking());
+        assert!(overlay.layer(TestMessage::Dismiss, None).is_some());
+
+        // Errors are never shown in the banner
+        overlay.show(warning.clone());
+        assert!(!overlay.warning);
+        assert!(overlay.is_blocking());
+
+        for placement in [
+            OverlayPlacement::TopLeft,
+            OverlayPlacement::TopRight,
+            OverlayPlacement::BottomLeft,
+            OverlayPlacement::BottomRight,
+        ] {
+            overlay.style = OverlayStyle {
+                placement,
+                variant: OverlayVariant::Light,
+                opacity: 0.8,
+                font_size: 12.0,
+                warning_banner: false,
+            };
+            assert!(!overlay.is_blocking());
+            drop(overlay.render(TestMessage::Dismiss));
+        }
+
+        assert!(OverlayVariant::Auto.is_dark(&Theme::Dark));
+        assert!(!OverlayVariant::Auto.is_dark(&Theme::Light));
+        assert!(!OverlayVariant::Light.is_dark(&Theme::Dark));
+    }
+
     #[test]
     fn test_timestamp_updated_on_show() {
         let mut overlay = ErrorOverlay::new();
@@ -285,4 +912,40 @@ mod tests {
         overlay.show(error);
         assert!(overlay.timestamp > initial_timestamp);
     }
+
+    #[test]
+    fn test_show_in_source_underlines_span() {
+        let source = "<column>\n    <text value=\"{count +}\" />\n</column>\n";
+        let start = source.find("{count").unwrap_or(0);
+        let error = ParseError {
+            kind: ParseErrorKind::InvalidExpression,
+            message: "Invalid expression".to_string(),
+            span: Span {
+                start,
+                end: start + 9,
+                line: 2,
+                column: 18,
+            },
+            suggestion: None,
+        };
+
+        assert_eq!(
+            source_excerpt(source, &error.span).as_deref(),
+            Some("2 |     <text value=\"{count +}\" />\n  |                  ^^^^^^^^^")
+        );
+
+        let mut overlay = ErrorOverlay::new();
+        overlay.show_in_source(vec![error.clone()], source.to_string());
+        assert_eq!(overlay.source.as_deref(), Some(source));
+        drop(overlay.render(TestMessage::Dismiss));
+
+        // Errors without a source line show no excerpt
+        let outside = Span {
+            line: 7,
+            ..error.span
+        };
+        assert_eq!(source_excerpt(source, &outside), None);
+        overlay.show(error);
+        assert!(overlay.source.is_none());
+    }
 }
@@ -0,0 +1,113 @@
+//! Catching handler panics in development mode
+//!
+//! A panic in an event handler normally kills the application. In development
+//! mode, `#[dampen_app(catch_panics = true)]` runs each handler through
+//! [`catch_handler_panic`] instead: the panic is turned into a [`PanicReport`],
+//! shown in the [`ErrorOverlay`](crate::ErrorOverlay), and the model is restored
+//! to its state before the handler ran.
+
+use std::backtrace::Backtrace;
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe, PanicHookInfo};
+use std::sync::Once;
+
+/// Details of a panic caught in a handler
+#[derive(Debug, Clone, PartialEq)]
+pub struct PanicReport {
+    /// Name of the handler that panicked
+    pub handler: String,
+
+    /// Panic message
+    pub message: String,
+
+    /// Source location of the panic, as `file:line:column`
+    pub location: Option<String>,
+
+    /// Backtrace captured when the panic occurred
+    pub backtrace: String,
+}
+
+thread_local! {
+    /// Last panic recorded by the hook on this thread
+    static LAST_PANIC: RefCell<Option<(String, Option<String>, String)>> =
+        const { RefCell::new(None) };
+}
+
+/// Install the panic hook recording the details of panics.
+///
+/// The previous hook still runs, so panics are printed as usual. Installing the
+/// hook more than once has no effect. [`catch_handler_panic`] installs it when
+/// first called.
+pub fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let report = (
+                panic_message(info),
+                info.location().map(|l| l.to_string()),
+                Backtrace::force_capture().to_string(),
+            );
+            LAST_PANIC.with(|last| *last.borrow_mut() = Some(report));
+            previous(info);
+        }));
+    });
+}
+
+/// Run the handler `handler`, catching its panic if it panics.
+///
+/// # Arguments
+/// * `handler` - Name of the handler, shown in the report
+/// * `run` - Runs the handler
+///
+/// # Returns
+/// The result of `run`, or the report of its panic.
+pub fn catch_handler_panic<T>(handler: &str, run: impl FnOnce() -> T) -> Result<T, PanicReport> {
+    install_panic_hook();
+    LAST_PANIC.with(|last| last.borrow_mut().take());
+
+    panic::catch_unwind(AssertUnwindSafe(run)).map_err(|payload| {
+        let (message, location, backtrace) = LAST_PANIC
+            .with(|last| last.borrow_mut().take())
+            .unwrap_or_else(|| (payload_message(payload.as_ref()), None, String::new()));
+        PanicReport {
+            handler: handler.to_string(),
+            message,
+            location,
+            backtrace,
+        }
+    })
+}
+
+fn panic_message(info: &PanicHookInfo<'_>) -> String {
+    payload_message(info.payload())
+}
+
+fn payload_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::panic)]
+    fn test_catch_handler_panic_reports_the_panic() {
+        assert_eq!(catch_handler_panic("increment", || 42), Ok(42));
+
+        let Err(report) = catch_handler_panic("divide", || -> u32 { panic!("division by zero") })
+        else {
+            unreachable!("the handler panics");
+        };
+        assert_eq!(report.handler, "divide");
+        assert_eq!(report.message, "division by zero");
+        assert!(report.location.is_some_and(|l| l.contains("panic.rs")));
+    }
+}
@@ -14,5 +14,8 @@ pub mod window_state;
 
 pub use error::PersistenceError;
 pub use monitor::position_is_reasonable;
-pub use storage::{WindowSettingsBuilder, get_config_path, load_or_default, save_window_state};
-pub use window_state::WindowState;
+pub use storage::{
+    WindowSettingsBuilder, get_config_path, load_or_default, load_window_or_default,
+    save_named_window_state, save_window_state,
+};
+pub use window_state::{MAIN_WINDOW, WindowState, WindowStateFile};
@@ -1,7 +1,8 @@
-use crate::persistence::{PersistenceError, WindowState};
+use crate::persistence::{MAIN_WINDOW, PersistenceError, WindowState, WindowStateFile};
 use directories::ProjectDirs;
 use std::fs;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 
 /// Builder for window settings with persistence support.
 ///
@@ -22,12 +23,14 @@ use std::path::PathBuf;
 /// ```
 pub struct WindowSettingsBuilder {
     app_name: String,
+    window: String,
     default_width: u32,
     default_height: u32,
     default_maximized: bool,
     min_size: Option<(u32, u32)>,
     max_size: Option<(u32, u32)>,
     resizable: bool,
+    decorations: bool,
 }
 
 impl WindowSettingsBuilder {
@@ -35,15 +38,25 @@ impl WindowSettingsBuilder {
     pub fn new(app_name: &str) -> Self {
         Self {
             app_name: app_name.to_string(),
+            window: MAIN_WINDOW.to_string(),
             default_width: 800,
             default_height: 600,
             default_maximized: false,
             min_size: None,
             max_size: None,
             resizable: true,
+            decorations: true,
         }
     }
 
+    /// Restore the state of a named window instead of the main window.
+    ///
+    /// Used by applications with several windows, or one window state per view.
+    pub fn window_name(mut self, window: &str) -> Self {
+        self.window = window.to_string();
+        self
+    }
+
     /// Set the default window size for first launch.
     ///
     /// This size is used when no persisted state exists.
@@ -79,11 +92,22 @@ impl WindowSettingsBuilder {
         self
     }
 
+    /// Set whether the window has a title bar and borders.
+    pub fn decorations(mut self, decorations: bool) -> Self {
+        self.decorations = decorations;
+        self
+    }
+
     /// Build the final `iced::window::Settings`.
     ///
     /// This loads any persisted state and merges it with the defaults.
     pub fn build(self) -> iced::window::Settings {
-        let mut state = load_or_default(&self.app_name, self.default_width, self.default_height);
+        let mut state = load_window_or_default(
+            &self.app_name,
+            &self.window,
+            self.default_width,
+            self.default_height,
+        );
 
         // Apply default maximized only if no persisted state was loaded
         // (we detect this by checking if the size matches our defaults exactly
@@ -111,6 +135,7 @@ impl WindowSettingsBuilder {
                 .max_size
                 .map(|(w, h)| iced::Size::new(w as f32, h as f32)),
             resizable: self.resizable,
+            decorations: self.decorations,
             ..Default::default()
         }
     }
@@ -138,7 +163,12 @@ pub fn get_config_path(app_name: &str) -> Option<PathBuf> {
     ProjectDirs::from("", "", app_name).map(|dirs| dirs.config_dir().join("window.json"))
 }
 
-fn load_window_state(app_name: &str) -> Result<WindowState, PersistenceError> {
+/// Path of the backup of a corrupted window state file.
+fn corrupted_path(path: &Path) -> PathBuf {
+    path.with_extension("json.corrupted")
+}
+
+fn load_window_states(app_name: &str) -> Result<WindowStateFile, PersistenceError> {
     let path = get_config_path(app_name).ok_or_else(|| PersistenceError::NoConfigDir {
         app_name: app_name.to_string(),
     })?;
@@ -155,14 +185,30 @@ fn load_window_state(app_name: &str) -> Result<WindowState, PersistenceError> {
         source: e,
     })?;
 
-    let state: WindowState =
-        serde_json::from_str(&content).map_err(|e| PersistenceError::ParseFailed {
+    WindowStateFile::parse(&content).map_err(|e| {
+        // Keep the corrupted file aside so that the next save does not overwrite it
+        let _ = fs::rename(&path, corrupted_path(&path));
+        PersistenceError::ParseFailed {
             path: path.clone(),
             source: e,
-        })?;
+        }
+    })
+}
 
-    state.validate()?;
+fn load_window_state(app_name: &str, window: &str) -> Result<WindowState, PersistenceError> {
+    let mut file = load_window_states(app_name)?;
+    let state = file
+        .windows
+        .remove(window)
+        .ok_or_else(|| PersistenceError::ReadFailed {
+            path: get_config_path(app_name).unwrap_or_default(),
+            source: std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("No state for window '{}'", window),
+            ),
+        })?;
 
+    // Invalid states are dropped when parsing the file
     Ok(state)
 }
 
@@ -179,10 +225,33 @@ fn load_window_state(app_name: &str) -> Result<WindowState, PersistenceError> {
 /// * `default_width` - Default window width if no saved state exists
 /// * `default_height` - Default window height if no saved state exists
 pub fn load_or_default(app_name: &str, default_width: u32, default_height: u32) -> WindowState {
+    load_window_or_default(app_name, MAIN_WINDOW, default_width, default_height)
+}
+
+/// Load the persisted state of a named window or return defaults.
+///
+/// Like [`load_or_default`], for applications with several windows, or one
+/// window state per view.
+///
+/// # Arguments
+///
+/// * `app_name` - Application identifier used to namespace the config file
+/// * `window` - Name of the window, e.g. [`MAIN_WINDOW`] or a view name
+/// * `default_width` - Default window width if no saved state exists
+/// * `default_height` - Default window height if no saved state exists
+pub fn load_window_or_default(
+    app_name: &str,
+    window: &str,
+    default_width: u32,
+    default_height: u32,
+) -> WindowState {
     #[cfg(debug_assertions)]
-    println!("DEBUG: Loading window state for '{}'", app_name);
+    println!(
+        "DEBUG: Loading state of window '{}' for '{}'",
+        window, app_name
+    );
 
-    match load_window_state(app_name) {
+    match load_window_state(app_name, window) {
         Ok(mut state) => {
             #[cfg(debug_assertions)]
             println!("DEBUG: Loaded state: {:?}", state);
@@ -229,8 +298,29 @@ pub fn load_or_default(app_name: &str, default_width: u32, default_height: u32)
 /// * `app_name` - Application identifier used to namespace the config file
 /// * `state` - The WindowState to persist
 pub fn save_window_state(app_name: &str, state: &WindowState) -> Result<(), PersistenceError> {
+    save_named_window_state(app_name, MAIN_WINDOW, state)
+}
+
+/// Save the state of a named window, keeping the state of the other windows.
+///
+/// The file is written atomically: the new content is written and synced to a
+/// temporary file, which then replaces the previous file.
+///
+/// # Arguments
+///
+/// * `app_name` - Application identifier used to namespace the config file
+/// * `window` - Name of the window, e.g. [`MAIN_WINDOW`] or a view name
+/// * `state` - The WindowState to persist
+pub fn save_named_window_state(
+    app_name: &str,
+    window: &str,
+    state: &WindowState,
+) -> Result<(), PersistenceError> {
     #[cfg(debug_assertions)]
-    println!("DEBUG: Saving window state for '{}': {:?}", app_name, state);
+    println!(
+        "DEBUG: Saving state of window '{}' for '{}': {:?}",
+        window, app_name, state
+    );
 
     let path = get_config_path(app_name).ok_or_else(|| {
         let e = PersistenceError::NoConfigDir {
@@ -261,8 +351,13 @@ pub fn save_window_state(app_name: &str, state: &WindowState) -> Result<(), Pers
         }
     }
 
-    let temp_path = path.with_extension("tmp");
-    let json = match serde_json::to_string_pretty(state) {
+    let mut file = load_window_states(app_name).unwrap_or_default();
+    file.version = WindowStateFile::VERSION;
+    file.windows.insert(window.to_string(), state.clone());
+
+    // Unique per process, so that concurrent writers never share a temporary file
+    let temp_path = path.with_extension(format!("{}.tmp", std::process::id()));
+    let json = match serde_json::to_string_pretty(&file) {
         Ok(j) => j,
         Err(e) => {
             let err = PersistenceError::WriteFailed {
@@ -274,7 +369,7 @@ pub fn save_window_state(app_name: &str, state: &WindowState) -> Result<(), Pers
         }
     };
 
-    if let Err(e) = fs::write(&temp_path, json) {
+    if let Err(e) = write_synced(&temp_path, json.as_bytes()) {
         let err = PersistenceError::WriteFailed {
             path: temp_path.clone(),
             source: e,
@@ -284,6 +379,7 @@ pub fn save_window_state(app_name: &str, state: &WindowState) -> Result<(), Pers
     }
 
     if let Err(e) = fs::rename(&temp_path, &path) {
+        let _ = fs::remove_file(&temp_path);
         let err = PersistenceError::WriteFailed {
             path: path.clone(),
             source: e,
@@ -294,3 +390,10 @@ pub fn save_window_state(app_name: &str, state: &WindowState) -> Result<(), Pers
 
     Ok(())
 }
+
+/// Write `contents` to `path` and flush them to disk.
+fn write_synced(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let mut file = fs::File::create(path)?;
+    file.write_all(contents)?;
+    file.sync_all()
+}
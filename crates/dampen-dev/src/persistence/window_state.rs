@@ -1,5 +1,9 @@
 use crate::persistence::error::PersistenceError;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Name of the main window of an application.
+pub const MAIN_WINDOW: &str = "main";
 
 /// Persisted window state for Dampen applications.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -31,6 +35,14 @@ pub struct WindowState {
     /// If true, the application should initialize the window in a maximized state,
     /// regardless of the saved width/height/position.
     pub maximized: bool,
+
+    /// Scale factor of the monitor the window was on, if known.
+    ///
+    /// Sizes and positions are logical pixels; with the scale factor they can be
+    /// converted to keep the same physical geometry on a monitor with another
+    /// scale factor (see [`WindowState::rescaled`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scale_factor: Option<f32>,
 }
 
 impl WindowState {
@@ -42,6 +54,32 @@ impl WindowState {
             x: None,
             y: None,
             maximized: false,
+            scale_factor: None,
+        }
+    }
+
+    /// Convert to a monitor with the given scale factor, keeping the physical
+    /// size and position of the window.
+    ///
+    /// Returns the state unchanged if its scale factor is unknown.
+    pub fn rescaled(&self, scale_factor: f32) -> Self {
+        let Some(saved) = self.scale_factor.filter(|saved| *saved > 0.0) else {
+            return self.clone();
+        };
+        if scale_factor <= 0.0 {
+            return self.clone();
+        }
+        let ratio = saved / scale_factor;
+        let scale_size = |value: u32| (value as f32 * ratio).round() as u32;
+        let scale_coord = |value: i32| (value as f32 * ratio).round() as i32;
+
+        Self {
+            width: scale_size(self.width),
+            height: scale_size(self.height),
+            x: self.x.map(scale_coord),
+            y: self.y.map(scale_coord),
+            maximized: self.maximized,
+            scale_factor: Some(scale_factor),
         }
     }
 
@@ -79,3 +117,67 @@ impl WindowState {
         Ok(())
     }
 }
+
+/// Versioned file storing the state of every window of an application.
+///
+/// Windows are keyed by name, e.g. [`MAIN_WINDOW`] or the name of a view.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WindowStateFile {
+    /// Format version, see [`WindowStateFile::VERSION`]
+    pub version: u32,
+
+    /// State of each window, by name
+    #[serde(default)]
+    pub windows: BTreeMap<String, WindowState>,
+}
+
+impl Default for WindowStateFile {
+    fn default() -> Self {
+        Self {
+            version: Self::VERSION,
+            windows: BTreeMap::new(),
+        }
+    }
+}
+
+impl WindowStateFile {
+    /// Current format version.
+    ///
+    /// Version 1 is the legacy format, a single [`WindowState`] for the main window.
+    pub const VERSION: u32 = 2;
+
+    /// Parse a window state file, tolerating corrupted entries.
+    ///
+    /// Legacy files are read as the state of the main window. Windows whose state
+    /// cannot be parsed or is invalid are dropped, keeping the other windows. Fails
+    /// only if the content is not a JSON object.
+    pub fn parse(content: &str) -> Result<Self, serde_json::Error> {
+        let value: serde_json::Value = serde_json::from_str(content)?;
+        let mut file = Self::default();
+
+        let Some(windows) = value.get("windows").and_then(|w| w.as_object()) else {
+            // Legacy format
+            let state: WindowState = serde_json::from_value(value)?;
+            match state.validate() {
+                Ok(()) => {
+                    file.windows.insert(MAIN_WINDOW.to_string(), state);
+                }
+                Err(e) => tracing::warn!("Ignoring state of window '{}': {}", MAIN_WINDOW, e),
+            }
+            return Ok(file);
+        };
+
+        for (name, state) in windows {
+            let state = serde_json::from_value::<WindowState>(state.clone())
+                .map_err(|e| e.to_string())
+                .and_then(|state| state.validate().map(|()| state).map_err(|e| e.to_string()));
+            match state {
+                Ok(state) => {
+                    file.windows.insert(name.clone(), state);
+                }
+                Err(e) => tracing::warn!("Ignoring state of window '{}': {}", name, e),
+            }
+        }
+        Ok(file)
+    }
+}
@@ -4,8 +4,10 @@
 //! state restoration, and error recovery.
 
 use dampen_core::binding::UiBindable;
+use dampen_core::ir::DampenDocument;
+use dampen_core::ir::diff::{NodePath, diff_documents};
 use dampen_core::parser::error::ParseError;
-use dampen_core::state::AppState;
+use dampen_core::state::{AppState, WidgetStateSnapshot};
 use serde::{Serialize, de::DeserializeOwned};
 use std::collections::HashMap;
 use std::marker::PhantomData;
@@ -277,6 +279,161 @@ pub enum ReloadResult<M: UiBindable> {
 
     /// Model deserialization failed, using default (accept reload with warning)
     StateRestoreWarning(AppState<M>, String),
+
+    /// Reload succeeded and only some subtrees changed
+    ///
+    /// Carries the paths (child indices from the root, in the new tree) of the
+    /// changed subtrees. Everything outside these paths is structurally identical
+    /// to the previous document, so backends can keep its widgets and their state.
+    Partial(AppState<M>, Vec<NodePath>),
+}
+
+/// Attempts an incremental hot-reload, reporting which subtrees changed.
+///
+/// Behaves like [`attempt_hot_reload`], but compares the old and new documents
+/// with [`ReloadScope::between`]. When the root, themes and style classes are
+/// unchanged, a successful reload is reported as [`ReloadResult::Partial`] with the
+/// paths of the changed subtrees (an empty list when nothing changed). Reloads that
+/// require a full rebuild are reported as [`ReloadResult::Success`].
+///
+/// # Example
+///
+/// ```no_run
+/// use dampen_dev::reload::{attempt_incremental_hot_reload, HotReloadContext, ReloadResult};
+/// use dampen_core::AppState;
+/// # use dampen_core::binding::UiBindable;
+/// # #[derive(Default, serde::Serialize, serde::Deserialize)]
+/// # struct Model;
+/// # impl UiBindable for Model {
+/// #     fn get_field(&self, _path: &[&str]) -> Option<dampen_core::binding::BindingValue> { None }
+/// #     fn available_fields() -> Vec<String> { vec![] }
+/// # }
+///
+/// fn handle_file_change(
+///     new_xml: &str,
+///     app_state: &AppState<Model>,
+///     context: &mut HotReloadContext<Model>,
+/// ) {
+///     let result = attempt_incremental_hot_reload(
+///         new_xml,
+///         app_state,
+///         context,
+///         dampen_core::HandlerRegistry::new,
+///     );
+///
+///     match result {
+///         ReloadResult::Partial(new_state, changed_paths) => {
+///             // Only rebuild the widgets under `changed_paths`
+///         }
+///         ReloadResult::Success(new_state) => {
+///             // Rebuild everything
+///         }
+///         _ => {
+///             // Handle errors
+///         }
+///     }
+/// }
+/// ```
+pub fn attempt_incremental_hot_reload<M, F>(
+    xml_source: &str,
+    current_state: &AppState<M>,
+    context: &mut HotReloadContext<M>,
+    create_handlers: F,
+) -> ReloadResult<M>
+where
+    M: UiBindable + Serialize + DeserializeOwned + Default,
+    F: FnOnce() -> dampen_core::handler::HandlerRegistry,
+{
+    match attempt_hot_reload(xml_source, current_state, context, create_handlers) {
+        ReloadResult::Success(new_state) => {
+            match ReloadScope::between(&current_state.document, &new_state.document) {
+                ReloadScope::Partial(changed_paths) => {
+                    ReloadResult::Partial(new_state, changed_paths)
+                }
+                ReloadScope::Full => ReloadResult::Success(new_state),
+            }
+        }
+        other => other,
+    }
+}
+
+/// Widgets affected by replacing a document with a reloaded one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReloadScope {
+    /// Only the subtrees at these paths changed, none when the document is unchanged
+    Partial(Vec<NodePath>),
+
+    /// The root, themes or style classes changed, so the whole tree is rebuilt
+    Full,
+}
+
+impl ReloadScope {
+    /// Compare a document with its reloaded version
+    ///
+    /// Documents with the same [stable hash](dampen_core::DampenDocument::stable_hash)
+    /// are unchanged, e.g. when a file is saved with only its layout edited. Others
+    /// are compared with [`diff_documents`].
+    pub fn between(old: &DampenDocument, new: &DampenDocument) -> Self {
+        if old.stable_hash() == new.stable_hash() {
+            return Self::Partial(Vec::new());
+        }
+        let diff = diff_documents(old, new);
+        if diff.is_full_rebuild() {
+            Self::Full
+        } else {
+            Self::Partial(diff.changed_paths)
+        }
+    }
+
+    /// Widget state to restore once the reloaded document of `state` is shown
+    ///
+    /// Widgets outside the changed subtrees keep their state in the backend, so
+    /// only the state of the rebuilt widgets is returned.
+    pub fn widget_state<M, S>(&self, state: &AppState<M, S>) -> WidgetStateSnapshot
+    where
+        M: UiBindable,
+        S: UiBindable + Send + Sync + 'static,
+    {
+        let mut snapshot = state.widget_state.snapshot();
+        if let Self::Partial(changed_paths) = self {
+            snapshot.retain_subtrees(&state.document, changed_paths);
+        }
+        snapshot
+    }
+}
+
+/// Replace the document of `state` with a reloaded one, reporting what changed
+///
+/// This is the reload `#[dampen_app]` runs when a view file is saved: the model
+/// and handlers are kept, and the document is always replaced so source spans
+/// follow the file even when nothing else changed. Restore
+/// [`ReloadScope::widget_state`] afterwards.
+///
+/// # Example
+///
+/// ```rust
+/// use dampen_core::{AppState, parse};
+/// use dampen_dev::reload::{ReloadScope, apply_incremental_hot_reload};
+///
+/// let mut state: AppState<()> = AppState::new(
+///     parse(r#"<column><text value="a" /><text value="b" /></column>"#).unwrap(),
+/// );
+/// let reloaded = parse(r#"<column><text value="a" /><text value="c" /></column>"#).unwrap();
+///
+/// let scope = apply_incremental_hot_reload(&mut state, reloaded);
+/// assert_eq!(scope, ReloadScope::Partial(vec![vec![1]]));
+/// ```
+pub fn apply_incremental_hot_reload<M, S>(
+    state: &mut AppState<M, S>,
+    document: DampenDocument,
+) -> ReloadScope
+where
+    M: UiBindable,
+    S: UiBindable + Send + Sync + 'static,
+{
+    let scope = ReloadScope::between(&state.document, &document);
+    state.hot_reload(document);
+    scope
 }
 
 /// Attempts to hot-reload the UI from a new XML source while preserving application state.
@@ -749,7 +906,10 @@ pub fn attempt_theme_hot_reload(
         return ThemeReloadResult::ValidationError(e.to_string());
     }
 
-    theme_context.reload(new_doc);
+    // Only replace the themes that changed, so styles resolved from unchanged
+    // themes are kept and the view does not fall back to default styles
+    let diff = theme_context.diff(&new_doc);
+    theme_context.apply_update(diff);
     ThemeReloadResult::Success
 }
 
@@ -1067,6 +1227,122 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_incremental_hot_reload_reports_changed_paths() {
+        use dampen_core::handler::HandlerRegistry;
+        use dampen_core::parser;
+
+        let xml_v1 = r#"<column><text value="Title" /><text value="Old" /></column>"#;
+        let state_v1 = AppState::with_all(
+            parser::parse(xml_v1).unwrap(),
+            TestModel::default(),
+            HandlerRegistry::new(),
+        );
+        let mut context = HotReloadContext::<TestModel>::new();
+
+        let xml_v2 = r#"<column><text value="Title" /><text value="New" /></column>"#;
+        let result =
+            attempt_incremental_hot_reload(xml_v2, &state_v1, &mut context, HandlerRegistry::new);
+
+        match result {
+            ReloadResult::Partial(new_state, changed_paths) => {
+                assert_eq!(changed_paths, vec![vec![1]]);
+                assert_eq!(new_state.document.root.children.len(), 2);
+            }
+            _ => panic!("Expected Partial, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_incremental_hot_reload_unchanged_content_rebuilds_nothing() {
+        use dampen_core::handler::HandlerRegistry;
+        use dampen_core::parser;
+
+        let xml_v1 = r#"<column><text value="Title" size="20" /></column>"#;
+        let state_v1 = AppState::with_all(
+            parser::parse(xml_v1).unwrap(),
+            TestModel::default(),
+            HandlerRegistry::new(),
+        );
+        let mut context = HotReloadContext::<TestModel>::new();
+
+        let xml_v2 = "<column>\n    <text size=\"20\" value=\"Title\" />\n</column>";
+        let result =
+            attempt_incremental_hot_reload(xml_v2, &state_v1, &mut context, HandlerRegistry::new);
+
+        match result {
+            ReloadResult::Partial(_, changed_paths) => assert!(changed_paths.is_empty()),
+            _ => panic!("Expected Partial, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_incremental_hot_reload_root_change_is_success() {
+        use dampen_core::handler::HandlerRegistry;
+        use dampen_core::parser;
+
+        let xml_v1 = r#"<column><text value="Title" /></column>"#;
+        let state_v1 = AppState::with_all(
+            parser::parse(xml_v1).unwrap(),
+            TestModel::default(),
+            HandlerRegistry::new(),
+        );
+        let mut context = HotReloadContext::<TestModel>::new();
+
+        let xml_v2 = r#"<row><text value="Title" /></row>"#;
+        let result =
+            attempt_incremental_hot_reload(xml_v2, &state_v1, &mut context, HandlerRegistry::new);
+
+        assert!(
+            matches!(result, ReloadResult::Success(_)),
+            "Expected Success, got {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn test_apply_incremental_hot_reload_restores_changed_widgets() {
+        use dampen_core::parser;
+        use dampen_core::state::ScrollOffset;
+
+        let mut state = AppState::with_model(
+            parser::parse(
+                r#"<column><scrollable id="list"><text value="A" /></scrollable><scrollable id="log"><text value="B" /></scrollable></column>"#,
+            )
+            .unwrap(),
+            TestModel::default(),
+        );
+        state
+            .widget_state
+            .record_scroll("list", ScrollOffset { x: 0.0, y: 10.0 });
+        state
+            .widget_state
+            .record_scroll("log", ScrollOffset { x: 0.0, y: 20.0 });
+
+        let scope = apply_incremental_hot_reload(
+            &mut state,
+            parser::parse(
+                r#"<column><scrollable id="list"><text value="A" /></scrollable><scrollable id="log" height="200"><text value="B" /></scrollable></column>"#,
+            )
+            .unwrap(),
+        );
+        assert_eq!(scope, ReloadScope::Partial(vec![vec![1]]));
+        assert_eq!(state.reload_generation, 1);
+        let restored = scope.widget_state(&state);
+        assert!(restored.scroll_offsets.contains_key("log"));
+        assert!(!restored.scroll_offsets.contains_key("list"));
+
+        let scope = apply_incremental_hot_reload(
+            &mut state,
+            parser::parse(
+                r#"<row><scrollable id="list"><text value="A" /></scrollable><scrollable id="log" height="200"><text value="B" /></scrollable></row>"#,
+            )
+            .unwrap(),
+        );
+        assert_eq!(scope, ReloadScope::Full);
+        assert_eq!(scope.widget_state(&state).scroll_offsets.len(), 2);
+    }
+
     #[test]
     fn test_attempt_hot_reload_preserves_model_across_multiple_reloads() {
         use dampen_core::handler::HandlerRegistry;
@@ -1543,8 +1819,11 @@ mod theme_reload_tests {
                 font_size_large: Some(24.0),
                 font_weight: dampen_core::ir::theme::FontWeight::Normal,
                 line_height: Some(1.5),
+                scale_ratio: None,
+                fonts: Vec::new(),
             },
             spacing: SpacingScale { unit: Some(8.0) },
+            shadows: Default::default(),
             base_styles: std::collections::HashMap::new(),
             extends: None,
         }
@@ -0,0 +1,25 @@
+//! Keyboard shortcuts of the development tools
+
+use iced::Subscription;
+use iced::keyboard::{self, Key, key::Named};
+
+/// Subscription to presses of the named `key`, e.g. F12 toggling a panel
+pub(crate) fn key_toggle(key: Named) -> Subscription<()> {
+    iced::event::listen_with(named_key_pressed)
+        .with(key)
+        .filter_map(|(key, pressed)| (pressed == key).then_some(()))
+}
+
+fn named_key_pressed(
+    event: iced::Event,
+    _status: iced::event::Status,
+    _window: iced::window::Id,
+) -> Option<Named> {
+    match event {
+        iced::Event::Keyboard(keyboard::Event::KeyPressed {
+            key: Key::Named(named),
+            ..
+        }) => Some(named),
+        _ => None,
+    }
+}
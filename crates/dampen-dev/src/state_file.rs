@@ -0,0 +1,404 @@
+//! Export and import of the model state
+//!
+//! With `#[dampen_app(state_file_variant = "...")]`, development builds can save
+//! the models of their views to a JSON file and load them back, to reproduce a
+//! bug report with the exact model data or to start a demo in a prepared state:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "view": "counter",
+//!   "models": { "counter": { "count": 3 } },
+//!   "shared": { "user_name": "Alice" }
+//! }
+//! ```
+//!
+//! F8 shows a toolbar exporting to `dampen-state.json` in the working directory
+//! and importing from the file given to `dampen run --state`, passed to the
+//! application in `DAMPEN_STATE`, which is also imported at startup.
+
+use crate::shortcut::key_toggle;
+use iced::keyboard::key::Named;
+use iced::widget::{button, column, container, row, text};
+use iced::{Color, Element, Length, Subscription};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Environment variable holding the state file imported at startup
+pub const STATE_ENV: &str = "DAMPEN_STATE";
+
+/// File the toolbar exports to, in the working directory
+pub const EXPORT_FILE: &str = "dampen-state.json";
+
+/// Toolbar controls, sent by its buttons and the F8 key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    /// Show or hide the toolbar
+    Toggle,
+
+    /// Export the state to [`StateTools::export_path`]
+    Export,
+
+    /// Import the state from [`StateTools::import_path`]
+    Import,
+}
+
+/// Errors of state files
+#[derive(Debug, thiserror::Error)]
+pub enum StateFileError {
+    /// Failed to read the state file.
+    #[error("Failed to read state file '{path}': {source}")]
+    ReadFailed {
+        /// Path to the file.
+        path: PathBuf,
+        /// Underlying I/O error.
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// Failed to write the state file.
+    #[error("Failed to write state file '{path}': {source}")]
+    WriteFailed {
+        /// Path to the file.
+        path: PathBuf,
+        /// Underlying I/O error.
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// The state file is not valid JSON, or not a state file.
+    #[error("Failed to parse state file '{path}': {source}")]
+    ParseFailed {
+        /// Path to the file.
+        path: PathBuf,
+        /// Underlying JSON error.
+        #[source]
+        source: serde_json::Error,
+    },
+
+    /// The state file was written by a newer version of Dampen.
+    #[error("Unsupported state file version {version} (expected {expected} or lower)")]
+    UnsupportedVersion {
+        /// Version of the file.
+        version: u32,
+        /// Latest supported version.
+        expected: u32,
+    },
+
+    /// A model does not serialize, or its data does not match the model type.
+    #[error("Invalid model of view '{view}': {source}")]
+    InvalidModel {
+        /// View path, `shared` for the shared state.
+        view: String,
+        /// Underlying JSON error.
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+/// The models of an application, as saved in a state file
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct StateFile {
+    /// Format version, see [`StateFile::VERSION`]
+    pub version: u32,
+
+    /// Path of the current view, shown after import
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub view: Option<String>,
+
+    /// Models by view path; views missing from the file keep their model on import
+    #[serde(default)]
+    pub models: BTreeMap<String, Value>,
+
+    /// The `shared_model` state, if the application has one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shared: Option<Value>,
+}
+
+impl StateFile {
+    /// Current format version.
+    pub const VERSION: u32 = 1;
+
+    /// Create an empty state file showing `view`
+    pub fn new(view: &str) -> Self {
+        Self {
+            version: Self::VERSION,
+            view: Some(view.to_string()),
+            models: BTreeMap::new(),
+            shared: None,
+        }
+    }
+
+    /// Read a state file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed, or if it was
+    /// written by a newer version.
+    pub fn read(path: &Path) -> Result<Self, StateFileError> {
+        let content =
+            std::fs::read_to_string(path).map_err(|source| StateFileError::ReadFailed {
+                path: path.to_path_buf(),
+                source,
+            })?;
+        let file: Self =
+            serde_json::from_str(&content).map_err(|source| StateFileError::ParseFailed {
+                path: path.to_path_buf(),
+                source,
+            })?;
+        if file.version > Self::VERSION {
+            return Err(StateFileError::UnsupportedVersion {
+                version: file.version,
+                expected: Self::VERSION,
+            });
+        }
+        Ok(file)
+    }
+
+    /// Write the state file, pretty-printed so that it can be edited by hand
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn write(&self, path: &Path) -> Result<(), StateFileError> {
+        let content =
+            serde_json::to_string_pretty(self).map_err(|source| StateFileError::ParseFailed {
+                path: path.to_path_buf(),
+                source,
+            })?;
+        std::fs::write(path, content).map_err(|source| StateFileError::WriteFailed {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// Add the model of a view
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StateFileError::InvalidModel`] if the model fails to serialize.
+    pub fn insert_model<M: Serialize>(
+        &mut self,
+        view: &str,
+        model: &M,
+    ) -> Result<(), StateFileError> {
+        let value = serde_json::to_value(model).map_err(|source| StateFileError::InvalidModel {
+            view: view.to_string(),
+            source,
+        })?;
+        self.models.insert(view.to_string(), value);
+        Ok(())
+    }
+
+    /// The model of a view, if the file has one
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StateFileError::InvalidModel`] if the data does not
+    /// deserialize into `M`.
+    pub fn model<M: DeserializeOwned>(&self, view: &str) -> Result<Option<M>, StateFileError> {
+        self.models
+            .get(view)
+            .map(|value| {
+                M::deserialize(value).map_err(|source| StateFileError::InvalidModel {
+                    view: view.to_string(),
+                    source,
+                })
+            })
+            .transpose()
+    }
+
+    /// Set the shared state
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StateFileError::InvalidModel`] if the state fails to serialize.
+    pub fn set_shared<S: Serialize>(&mut self, shared: &S) -> Result<(), StateFileError> {
+        let value =
+            serde_json::to_value(shared).map_err(|source| StateFileError::InvalidModel {
+                view: "shared".to_string(),
+                source,
+            })?;
+        self.shared = Some(value);
+        Ok(())
+    }
+
+    /// The shared state, if the file has one
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StateFileError::InvalidModel`] if the data does not
+    /// deserialize into `S`.
+    pub fn shared<S: DeserializeOwned>(&self) -> Result<Option<S>, StateFileError> {
+        self.shared
+            .as_ref()
+            .map(|value| {
+                S::deserialize(value).map_err(|source| StateFileError::InvalidModel {
+                    view: "shared".to_string(),
+                    source,
+                })
+            })
+            .transpose()
+    }
+}
+
+/// State file imported at startup: `DAMPEN_STATE`, if set
+pub fn startup_path() -> Option<PathBuf> {
+    std::env::var_os(STATE_ENV)
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Toolbar exporting and importing the state, shown under the view
+#[derive(Debug, Clone)]
+pub struct StateTools {
+    /// File written by [`Event::Export`]
+    pub export_path: PathBuf,
+
+    /// File read by [`Event::Import`]: the startup file, or the exported one
+    pub import_path: PathBuf,
+
+    /// Outcome of the last export or import
+    status: Option<Result<String, String>>,
+
+    /// Whether the toolbar is shown
+    visible: bool,
+}
+
+impl Default for StateTools {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StateTools {
+    /// Create a hidden toolbar using [`EXPORT_FILE`] and [`startup_path`]
+    pub fn new() -> Self {
+        let export_path = PathBuf::from(EXPORT_FILE);
+        Self {
+            import_path: startup_path().unwrap_or_else(|| export_path.clone()),
+            export_path,
+            status: None,
+            visible: false,
+        }
+    }
+
+    /// Whether the toolbar is shown
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Show or hide the toolbar
+    pub fn toggle(&mut self) {
+        self.visible = !self.visible;
+    }
+
+    /// Show the outcome of an export or import in the toolbar, and print it
+    pub fn report(&mut self, result: Result<String, StateFileError>) {
+        match &result {
+            Ok(message) => eprintln!("[dampen-dev] {}", message),
+            Err(e) => eprintln!("[dampen-dev] {}", e),
+        }
+        self.status = Some(result.map_err(|e| e.to_string()));
+    }
+
+    /// Render `content` with the toolbar under it, if shown
+    pub fn view<'a, Message: Clone + 'a>(
+        &'a self,
+        content: Element<'a, Message>,
+        on_event: fn(Event) -> Message,
+    ) -> Element<'a, Message> {
+        if !self.visible {
+            return content;
+        }
+
+        let (status, color) = match &self.status {
+            Some(Ok(message)) => (message.as_str(), Color::from_rgb(0.6, 0.9, 0.6)),
+            Some(Err(error)) => (error.as_str(), Color::from_rgb(1.0, 0.5, 0.5)),
+            None => ("", Color::WHITE),
+        };
+        let toolbar = container(
+            row![
+                button(text("Export state").size(13))
+                    .padding([4, 10])
+                    .on_press(on_event(Event::Export)),
+                button(text("Import state").size(13))
+                    .padding([4, 10])
+                    .on_press(on_event(Event::Import)),
+                text(status)
+                    .size(13)
+                    .style(move |_theme| text::Style { color: Some(color) }),
+            ]
+            .spacing(8)
+            .padding(8),
+        )
+        .width(Length::Fill)
+        .style(|_theme| container::Style {
+            background: Some(Color::from_rgb(0.1, 0.1, 0.12).into()),
+            ..Default::default()
+        });
+
+        column![container(content).height(Length::Fill), toolbar].into()
+    }
+}
+
+/// Subscription to the F8 key, toggling the toolbar
+pub fn toggle_key() -> Subscription<Event> {
+    key_toggle(Named::F8).map(|()| Event::Toggle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Counter {
+        count: i64,
+    }
+
+    #[test]
+    fn test_state_file_round_trips_models() {
+        let dir = tempfile::tempdir().unwrap_or_else(|_| unreachable!());
+        let path = dir.path().join("state.json");
+
+        let mut file = StateFile::new("counter");
+        assert!(file.insert_model("counter", &Counter { count: 3 }).is_ok());
+        assert!(file.write(&path).is_ok());
+
+        let file = StateFile::read(&path).unwrap_or_else(|_| unreachable!());
+        assert_eq!(file.view.as_deref(), Some("counter"));
+        assert_eq!(
+            file.model::<Counter>("counter").ok(),
+            Some(Some(Counter { count: 3 }))
+        );
+        assert_eq!(file.model::<Counter>("settings").ok(), Some(None));
+        assert!(
+            file.shared::<Counter>()
+                .is_ok_and(|shared| shared.is_none())
+        );
+
+        // Data of another shape is rejected instead of resetting the model
+        let mut file = file;
+        file.models
+            .insert("counter".to_string(), serde_json::json!({"count": "three"}));
+        assert!(matches!(
+            file.model::<Counter>("counter"),
+            Err(StateFileError::InvalidModel { .. })
+        ));
+    }
+
+    #[test]
+    fn test_newer_state_files_are_rejected() {
+        let dir = tempfile::tempdir().unwrap_or_else(|_| unreachable!());
+        let path = dir.path().join("state.json");
+        assert!(std::fs::write(&path, r#"{"version": 99, "models": {}}"#).is_ok());
+
+        assert!(matches!(
+            StateFile::read(&path),
+            Err(StateFileError::UnsupportedVersion { version: 99, .. })
+        ));
+    }
+}
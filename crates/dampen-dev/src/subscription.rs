@@ -3,15 +3,20 @@
 //! This module provides an Iced subscription that bridges file system events
 //! from the notify crate into Iced's async message system.
 
-use crate::watcher::{FileWatcher, FileWatcherConfig};
+use crate::dependencies::dependency_order;
+use crate::reload::is_theme_file_path;
+use crate::watcher::{FileWatcher, FileWatcherConfig, is_asset_file};
 use dampen_core::ir::DampenDocument;
+use dampen_core::ir::theme::ThemeDocument;
 use dampen_core::parser;
-use dampen_core::parser::error::ParseError;
+use dampen_core::parser::error::{ParseError, ParseErrorKind};
+use dampen_core::parser::theme_parser::parse_theme_document;
 
 use iced::Subscription;
 use iced::advanced::subscription::{EventStream, Hasher, Recipe};
 use std::hash::Hash;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::ReceiverStream;
 
@@ -26,16 +31,41 @@ pub enum FileEvent {
         document: Box<DampenDocument>,
     },
 
+    /// Theme file changed and parsed successfully
+    ///
+    /// Apply it with [`ThemeContext::diff`](dampen_core::ThemeContext::diff) and
+    /// [`ThemeContext::apply_update`](dampen_core::ThemeContext::apply_update) so only
+    /// the affected themes are recomputed.
+    ThemeChanged {
+        /// Path to the changed theme file
+        path: PathBuf,
+        /// Parsed and validated theme document (boxed to reduce enum size)
+        document: Box<ThemeDocument>,
+    },
+
     /// Parse error (XML syntax or validation)
     ParseError {
         /// Path to the file with error
         path: PathBuf,
         /// Parse error details
         error: ParseError,
+        /// Further errors in the same file, found by error recovery
+        more_errors: Vec<ParseError>,
         /// File content for error overlay display
         content: String,
     },
 
+    /// Image, SVG, font or translation file changed
+    ///
+    /// Reported for the directories passed to
+    /// [`FileWatcherRecipe::with_assets`]. Pass the path to
+    /// `dampen_iced::assets::invalidate` so the views show the new image
+    /// without restarting the application.
+    AssetChanged {
+        /// Path to the changed file
+        path: PathBuf,
+    },
+
     /// File watcher error (permissions, deleted file, etc.)
     WatcherError {
         /// Path to the file
@@ -43,6 +73,70 @@ pub enum FileEvent {
         /// Error description
         error: String,
     },
+
+    /// Files changed within the same batch window
+    ///
+    /// The subscription delivers every change as a batch, with one event per
+    /// changed file, so a burst of changes (e.g., `git checkout`, save-all) is
+    /// applied as a whole and in dependency order.
+    Batch(ReloadBatch),
+}
+
+/// Coalesced file changes, in reload order
+///
+/// Each file comes after the files it depends on: assets and theme files
+/// before views, style libraries before the documents using their classes and
+/// themes; see [`reload_order`](crate::watcher::reload_order). Each path
+/// appears at most once.
+#[derive(Debug, Clone, Default)]
+pub struct ReloadBatch {
+    /// Events for each changed file, in the order they should be applied
+    pub events: Vec<FileEvent>,
+}
+
+impl ReloadBatch {
+    /// Batch of the given events, sorted in reload order
+    ///
+    /// The dependencies of each file are read from the document of its
+    /// [`FileEvent::Success`] event.
+    pub fn new(events: Vec<FileEvent>) -> Self {
+        let events = dependency_order(
+            events,
+            |event| event_path(event).map_or(Path::new(""), PathBuf::as_path),
+            |event| match event {
+                FileEvent::Success { document, .. } => Some(document.as_ref()),
+                _ => None,
+            },
+        );
+        Self { events }
+    }
+
+    /// Paths of the files in this batch, in reload order
+    pub fn paths(&self) -> Vec<&PathBuf> {
+        self.events.iter().filter_map(event_path).collect()
+    }
+
+    /// Number of events in this batch
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns `true` if the batch contains no events
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+/// Path of the file of an event, `None` for a nested batch
+fn event_path(event: &FileEvent) -> Option<&PathBuf> {
+    match event {
+        FileEvent::Success { path, .. }
+        | FileEvent::ThemeChanged { path, .. }
+        | FileEvent::AssetChanged { path }
+        | FileEvent::ParseError { path, .. }
+        | FileEvent::WatcherError { path, .. } => Some(path),
+        FileEvent::Batch(_) => None,
+    }
 }
 
 /// Recipe for creating file watching subscriptions
@@ -66,6 +160,14 @@ pub struct FileWatcherRecipe {
 
     /// Whether to watch directories recursively
     pub recursive: bool,
+
+    /// Time window in milliseconds during which changes are coalesced into a
+    /// single [`FileEvent::Batch`]
+    pub batch_window_ms: u64,
+
+    /// Directories of the assets referenced by the documents, reported as
+    /// [`FileEvent::AssetChanged`]
+    pub asset_dirs: Vec<PathBuf>,
 }
 
 impl FileWatcherRecipe {
@@ -94,6 +196,8 @@ impl FileWatcherRecipe {
             debounce_ms,
             extension_filter: ".dampen".to_string(),
             recursive: true,
+            batch_window_ms: 50,
+            asset_dirs: Vec::new(),
         }
     }
 
@@ -120,6 +224,31 @@ impl FileWatcherRecipe {
         self.recursive = recursive;
         self
     }
+
+    /// Set the batch window used to coalesce simultaneous changes
+    ///
+    /// # Arguments
+    /// * `batch_window_ms` - Window in milliseconds (0 sends each change in its own batch)
+    ///
+    /// # Returns
+    /// Self for method chaining
+    pub fn with_batch_window(mut self, batch_window_ms: u64) -> Self {
+        self.batch_window_ms = batch_window_ms;
+        self
+    }
+
+    /// Also watch the asset files of the given directories
+    ///
+    /// # Arguments
+    /// * `asset_dirs` - Directories of the images, SVGs, fonts and translation
+    ///   files used by the documents, watched non-recursively
+    ///
+    /// # Returns
+    /// Self for method chaining
+    pub fn with_assets(mut self, asset_dirs: Vec<PathBuf>) -> Self {
+        self.asset_dirs = asset_dirs;
+        self
+    }
 }
 
 impl Recipe for FileWatcherRecipe {
@@ -143,6 +272,8 @@ impl Recipe for FileWatcherRecipe {
         self.debounce_ms.hash(state);
         self.extension_filter.hash(state);
         self.recursive.hash(state);
+        self.batch_window_ms.hash(state);
+        self.asset_dirs.hash(state);
     }
 
     fn stream(
@@ -154,6 +285,8 @@ impl Recipe for FileWatcherRecipe {
         let debounce_ms = self.debounce_ms;
         let extension_filter = self.extension_filter;
         let recursive = self.recursive;
+        let batch_window = Duration::from_millis(self.batch_window_ms);
+        let asset_dirs = self.asset_dirs;
 
         // Create async channel for bridging sync→async
         // Buffer size of 1000 handles burst file changes better than 100
@@ -227,6 +360,16 @@ impl Recipe for FileWatcherRecipe {
                 }
             }
 
+            for dir in asset_dirs {
+                if let Err(e) = watcher.watch_assets(dir.clone()) {
+                    eprintln!(
+                        "[dampen-dev] Failed to watch assets in {}: {}",
+                        dir.display(),
+                        e
+                    );
+                }
+            }
+
             // Read events from the file watcher's channel
             eprintln!("[dampen-dev] File watcher ready, waiting for events...");
             let receiver = watcher.receiver();
@@ -236,45 +379,31 @@ impl Recipe for FileWatcherRecipe {
             loop {
                 match receiver.recv_timeout(std::time::Duration::from_millis(100)) {
                     Ok(path) => {
-                        eprintln!("[dampen-dev] File changed: {}", path.display());
-                        // Read the file content
-                        let content = match std::fs::read_to_string(&path) {
-                            Ok(c) => c,
-                            Err(e) => {
-                                // File read error (permissions, deleted, etc.)
-                                if tx
-                                    .blocking_send(FileEvent::WatcherError {
-                                        path: path.clone(),
-                                        error: format!("Failed to read file: {}", e),
-                                    })
-                                    .is_err()
-                                {
-                                    // Channel closed, subscription dropped, exit gracefully
-                                    eprintln!("[dampen-dev] Channel closed, stopping file watcher");
-                                    break;
-                                }
-                                continue;
-                            }
-                        };
-
-                        // Parse the XML content
-                        let event = match parser::parse(&content) {
-                            Ok(document) => {
-                                // Success: send parsed document (boxed to reduce enum size)
-                                FileEvent::Success {
-                                    path: path.clone(),
-                                    document: Box::new(document),
-                                }
-                            }
-                            Err(error) => {
-                                // Parse error: send error with content for overlay
-                                FileEvent::ParseError {
-                                    path: path.clone(),
-                                    error,
-                                    content,
-                                }
+                        // Coalesce every change arriving within the batch window so a
+                        // burst of writes results in a single reload
+                        let mut changed = vec![path];
+                        let deadline = Instant::now() + batch_window;
+                        while let Some(remaining) = deadline.checked_duration_since(Instant::now())
+                        {
+                            match receiver.recv_timeout(remaining) {
+                                Ok(path) => changed.push(path),
+                                Err(_) => break,
                             }
-                        };
+                        }
+
+                        changed.sort();
+                        changed.dedup();
+                        let events: Vec<FileEvent> = changed
+                            .into_iter()
+                            .map(|path| {
+                                eprintln!("[dampen-dev] File changed: {}", path.display());
+                                load_file_event(path)
+                            })
+                            .collect();
+
+                        // Even a single change is sent as a batch, so applications
+                        // handle one kind of event
+                        let event = FileEvent::Batch(ReloadBatch::new(events));
 
                         // Send the event; if channel is closed, stop watching
                         if tx.blocking_send(event).is_err() {
@@ -305,6 +434,76 @@ impl Recipe for FileWatcherRecipe {
     }
 }
 
+/// Read and parse a changed file into a [`FileEvent`]
+fn load_file_event(path: PathBuf) -> FileEvent {
+    if is_asset_file(&path) {
+        return FileEvent::AssetChanged { path };
+    }
+
+    // Read the file content
+    let content = match std::fs::read_to_string(&path) {
+        Ok(c) => c,
+        Err(e) => {
+            // File read error (permissions, deleted, etc.)
+            return FileEvent::WatcherError {
+                error: format!("Failed to read file: {}", e),
+                path,
+            };
+        }
+    };
+
+    if is_theme_file_path(&path) {
+        return load_theme_event(path, content);
+    }
+
+    // Parse the XML content
+    match parser::parse(&content) {
+        // Success: send parsed document (boxed to reduce enum size)
+        Ok(document) => FileEvent::Success {
+            path,
+            document: Box::new(document),
+        },
+        // Parse error: report every error of the file, with content for overlay
+        Err(error) => {
+            let (_, mut errors) = parser::parse_with_recovery(&content);
+            errors.retain(|other| *other != error);
+            FileEvent::ParseError {
+                path,
+                error,
+                more_errors: errors,
+                content,
+            }
+        }
+    }
+}
+
+/// Parse and validate a changed theme file into a [`FileEvent`]
+fn load_theme_event(path: PathBuf, content: String) -> FileEvent {
+    let document = parse_theme_document(&content).and_then(|document| {
+        document.validate()?;
+        Ok(document)
+    });
+
+    match document {
+        Ok(document) => FileEvent::ThemeChanged {
+            path,
+            document: Box::new(document),
+        },
+        // Report theme errors through the regular parse error overlay
+        Err(error) => FileEvent::ParseError {
+            path,
+            error: ParseError {
+                kind: ParseErrorKind::InvalidValue,
+                message: error.message,
+                span: error.span.unwrap_or_default(),
+                suggestion: None,
+            },
+            more_errors: Vec::new(),
+            content,
+        },
+    }
+}
+
 /// Create a subscription that watches files and emits FileEvents
 ///
 /// This is the main public API for creating file watching subscriptions in Iced applications.
@@ -342,6 +541,38 @@ pub fn watch_files<P: AsRef<std::path::Path>>(
     from_recipe(recipe)
 }
 
+/// Create a subscription that watches files, and the assets of `asset_dirs`
+///
+/// Like [`watch_files`], also emitting [`FileEvent::AssetChanged`] when an
+/// image, SVG, font or translation file of `asset_dirs` changes.
+///
+/// # Example
+/// ```no_run
+/// use dampen_dev::subscription::watch_files_and_assets;
+/// use std::path::PathBuf;
+///
+/// let subscription = watch_files_and_assets(
+///     vec![PathBuf::from("src/ui")],
+///     vec![PathBuf::from("assets")],
+///     100,
+/// );
+/// ```
+pub fn watch_files_and_assets<P: AsRef<std::path::Path>>(
+    paths: Vec<P>,
+    asset_dirs: Vec<P>,
+    debounce_ms: u64,
+) -> Subscription<FileEvent> {
+    let path_bufs = paths.iter().map(|p| p.as_ref().to_path_buf()).collect();
+    let asset_dirs = asset_dirs
+        .iter()
+        .map(|p| p.as_ref().to_path_buf())
+        .collect();
+    let recipe = FileWatcherRecipe::new(path_bufs, debounce_ms).with_assets(asset_dirs);
+
+    use iced::advanced::subscription::from_recipe;
+    from_recipe(recipe)
+}
+
 /// Create a subscription that monitors system theme changes.
 ///
 /// This is a re-export from `dampen_iced` for backward compatibility.
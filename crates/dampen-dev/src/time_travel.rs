@@ -0,0 +1,398 @@
+//! Time-travel debugging of model state
+//!
+//! With `#[dampen_app(time_travel_variant = "...")]`, development builds record
+//! every handler message in a [`Timeline`], with the model of its view before
+//! and after the handler ran. F9 opens the timeline under the view; stepping
+//! backwards and forwards restores the recorded models and shows their view,
+//! which re-renders at that point.
+//!
+//! Running a handler while stepped back discards the entries after the current
+//! point, as an undo history would.
+
+use crate::shortcut::key_toggle;
+use dampen_core::binding::{BindingValue, UiBindable};
+use dampen_iced::HandlerMessage;
+use iced::keyboard::key::Named;
+use iced::widget::{button, column, container, row, scrollable, text};
+use iced::{Color, Element, Font, Length, Subscription};
+use std::any::Any;
+use std::collections::{BTreeMap, VecDeque};
+
+/// Number of entries kept by [`Timeline::new`]
+pub const DEFAULT_CAPACITY: usize = 100;
+
+/// Timeline controls, sent by the panel and the F9 key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    /// Show or hide the timeline panel
+    Toggle,
+
+    /// Restore the state before the current entry
+    Back,
+
+    /// Restore the state after the next entry
+    Forward,
+
+    /// Restore the latest state
+    Latest,
+}
+
+/// A field of the model changed by a handler
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    /// Field path, e.g. `footer.note`
+    pub field: String,
+
+    /// Value before the handler ran, if the field existed
+    pub before: Option<String>,
+
+    /// Value after the handler ran, if the field still exists
+    pub after: Option<String>,
+}
+
+/// A handler message recorded in the timeline
+pub struct TimelineEntry {
+    /// Path of the view whose handler ran
+    pub view: String,
+
+    /// Handler and value, e.g. `set_name("Alice")`
+    pub message: String,
+
+    /// Fields of the model changed by the handler
+    pub changes: Vec<FieldChange>,
+
+    before: Box<dyn Any>,
+    after: Box<dyn Any>,
+}
+
+/// Ring buffer of the handler messages of the app, with model snapshots
+pub struct Timeline {
+    entries: VecDeque<TimelineEntry>,
+    capacity: usize,
+
+    /// Number of entries whose state is applied; less than the length while travelling
+    position: usize,
+
+    /// Whether the panel is shown
+    visible: bool,
+}
+
+impl Default for Timeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Timeline {
+    /// Create an empty timeline keeping the last [`DEFAULT_CAPACITY`] entries
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY)
+    }
+
+    /// Create an empty timeline keeping the last `capacity` entries
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity: capacity.max(1),
+            position: 0,
+            visible: false,
+        }
+    }
+
+    /// Recorded entries, from the oldest
+    pub fn entries(&self) -> impl Iterator<Item = &TimelineEntry> {
+        self.entries.iter()
+    }
+
+    /// Number of entries whose state is applied
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Whether an earlier state is shown
+    pub fn is_travelling(&self) -> bool {
+        self.position < self.entries.len()
+    }
+
+    /// Whether the panel is shown
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Show or hide the panel
+    pub fn toggle(&mut self) {
+        self.visible = !self.visible;
+    }
+
+    /// Record a handler of `view` that turned `before` into `after`.
+    ///
+    /// Entries after the current point are discarded first; the oldest entry
+    /// is dropped when the timeline is full.
+    pub fn record<M: UiBindable + Clone + 'static>(
+        &mut self,
+        view: &str,
+        message: String,
+        before: M,
+        after: &M,
+    ) {
+        self.entries.truncate(self.position);
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+
+        self.entries.push_back(TimelineEntry {
+            view: view.to_string(),
+            message,
+            changes: changes(&before, after),
+            before: Box::new(before),
+            after: Box::new(after.clone()),
+        });
+        self.position = self.entries.len();
+    }
+
+    /// Step back over the current entry.
+    ///
+    /// Returns the view of the entry and its model before the handler ran, to
+    /// restore with [`restore`].
+    pub fn step_back(&mut self) -> Option<(&str, &dyn Any)> {
+        self.position = self.position.checked_sub(1)?;
+        let entry = self.entries.get(self.position)?;
+        Some((entry.view.as_str(), entry.before.as_ref()))
+    }
+
+    /// Step forward over the next entry.
+    ///
+    /// Returns the view of the entry and its model after the handler ran, to
+    /// restore with [`restore`].
+    pub fn step_forward(&mut self) -> Option<(&str, &dyn Any)> {
+        let entry = self.entries.get(self.position)?;
+        self.position += 1;
+        Some((entry.view.as_str(), entry.after.as_ref()))
+    }
+
+    /// Render `content` with the timeline panel under it, if shown
+    pub fn view<'a, Message: Clone + 'a>(
+        &'a self,
+        content: Element<'a, Message>,
+        on_event: fn(Event) -> Message,
+    ) -> Element<'a, Message> {
+        if !self.visible {
+            return content;
+        }
+        column![
+            container(content).height(Length::Fill),
+            self.panel(on_event)
+        ]
+        .into()
+    }
+
+    fn panel<'a, Message: Clone + 'a>(
+        &'a self,
+        on_event: fn(Event) -> Message,
+    ) -> Element<'a, Message> {
+        let control = |label: &'a str, event: Event, enabled: bool| {
+            button(text(label).size(13))
+                .padding([4, 10])
+                .on_press_maybe(enabled.then(|| on_event(event)))
+        };
+        let controls = row![
+            control("◀ Back", Event::Back, self.position > 0),
+            control("Forward ▶", Event::Forward, self.is_travelling()),
+            control("Latest", Event::Latest, self.is_travelling()),
+            text(format!("{} / {}", self.position, self.entries.len()))
+                .size(13)
+                .style(|_theme| text::Style {
+                    color: Some(Color::from_rgb(0.8, 0.8, 0.8)),
+                }),
+        ]
+        .spacing(8);
+
+        let entries = self.entries.iter().enumerate().map(|(index, entry)| {
+            // The state shown is the one after the entry before the current point
+            let color = if index + 1 == self.position {
+                Color::from_rgb(0.6, 0.85, 1.0)
+            } else if index < self.position {
+                Color::from_rgb(0.85, 0.85, 0.85)
+            } else {
+                Color::from_rgb(0.5, 0.5, 0.5)
+            };
+            let changes = entry
+                .changes
+                .iter()
+                .map(|change| {
+                    format!(
+                        "{}: {} → {}",
+                        change.field,
+                        change.before.as_deref().unwrap_or("-"),
+                        change.after.as_deref().unwrap_or("-")
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            text(format!(
+                "#{} {}: {}  {}",
+                index + 1,
+                entry.view,
+                entry.message,
+                changes
+            ))
+            .size(12)
+            .font(Font::MONOSPACE)
+            .style(move |_theme| text::Style { color: Some(color) })
+            .into()
+        });
+
+        container(
+            column![
+                controls,
+                scrollable(column(entries).spacing(2))
+                    .anchor_bottom()
+                    .width(Length::Fill)
+                    .height(Length::Fill),
+            ]
+            .spacing(8)
+            .padding(8),
+        )
+        .width(Length::Fill)
+        .height(Length::Fixed(220.0))
+        .style(|_theme| container::Style {
+            background: Some(Color::from_rgb(0.1, 0.1, 0.12).into()),
+            ..Default::default()
+        })
+        .into()
+    }
+}
+
+/// Replace `model` with a snapshot returned by the timeline.
+///
+/// Returns `false`, leaving the model unchanged, if the snapshot is of another type.
+pub fn restore<M: Clone + 'static>(model: &mut M, snapshot: &dyn Any) -> bool {
+    match snapshot.downcast_ref::<M>() {
+        Some(snapshot) => {
+            *model = snapshot.clone();
+            true
+        }
+        None => false,
+    }
+}
+
+/// Describe a handler message for the timeline, e.g. `set_name("Alice")`
+///
+/// Returns `None` for `HandlerMessage::None`, which runs no handler.
+pub fn describe(message: &HandlerMessage) -> Option<String> {
+    match message {
+        HandlerMessage::Handler(name, Some(value)) => Some(format!("{}({:?})", name, value)),
+        HandlerMessage::Handler(name, None) => Some(name.clone()),
+        HandlerMessage::None => None,
+    }
+}
+
+/// Subscription to the F9 key, toggling the timeline panel
+pub fn toggle_key() -> Subscription<Event> {
+    key_toggle(Named::F9).map(|()| Event::Toggle)
+}
+
+/// Fields whose value differs between two models
+fn changes<M: UiBindable>(before: &M, after: &M) -> Vec<FieldChange> {
+    let before = fields(before);
+    let mut after = fields(after);
+
+    let mut changes: Vec<FieldChange> = before
+        .into_iter()
+        .filter_map(|(field, old)| {
+            let new = after.remove(&field);
+            (new.as_ref() != Some(&old)).then(|| FieldChange {
+                field,
+                before: Some(old.to_display_string()),
+                after: new.map(|value| value.to_display_string()),
+            })
+        })
+        .collect();
+    changes.extend(after.into_iter().map(|(field, new)| FieldChange {
+        field,
+        before: None,
+        after: Some(new.to_display_string()),
+    }));
+    changes.sort_by(|a, b| a.field.cmp(&b.field));
+    changes
+}
+
+/// Values of the bindable fields of a model, by field path
+fn fields<M: UiBindable>(model: &M) -> BTreeMap<String, BindingValue> {
+    M::available_fields()
+        .into_iter()
+        .filter_map(|field| {
+            let path: Vec<&str> = field.split('.').collect();
+            let value = model.get_field(&path)?;
+            Some((field, value))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Default, Debug, PartialEq)]
+    struct Counter {
+        count: i64,
+    }
+
+    impl UiBindable for Counter {
+        fn get_field(&self, path: &[&str]) -> Option<BindingValue> {
+            match path {
+                ["count"] => Some(BindingValue::Integer(self.count)),
+                _ => None,
+            }
+        }
+
+        fn available_fields() -> Vec<String> {
+            vec!["count".to_string()]
+        }
+    }
+
+    #[test]
+    fn test_stepping_restores_recorded_models() {
+        let mut timeline = Timeline::with_capacity(2);
+        let mut model = Counter::default();
+        for _ in 0..3 {
+            let before = model.clone();
+            model.count += 1;
+            timeline.record("counter", "increment".to_string(), before, &model);
+        }
+
+        // The oldest entry was dropped
+        assert_eq!(timeline.entries().count(), 2);
+        let changes = &timeline.entries().last().map(|e| e.changes.clone());
+        assert_eq!(
+            changes.as_deref(),
+            Some(
+                &[FieldChange {
+                    field: "count".to_string(),
+                    before: Some("2".to_string()),
+                    after: Some("3".to_string()),
+                }][..]
+            )
+        );
+
+        let (view, snapshot) = timeline.step_back().unwrap_or_else(|| unreachable!());
+        assert_eq!(view, "counter");
+        assert!(restore(&mut model, snapshot));
+        assert_eq!(model.count, 2);
+        assert!(timeline.is_travelling());
+
+        // A handler run while travelling discards the later entries
+        let before = model.clone();
+        model.count = 10;
+        timeline.record("counter", "reset".to_string(), before, &model);
+        assert!(!timeline.is_travelling());
+        assert_eq!(
+            timeline
+                .entries()
+                .map(|e| e.message.as_str())
+                .collect::<Vec<_>>(),
+            ["increment", "reset"]
+        );
+        assert!(timeline.step_forward().is_none());
+    }
+}
@@ -2,13 +2,35 @@
 //!
 //! This module wraps the `notify` crate to provide file system watching
 //! with debouncing and filtering for .dampen files.
+//!
+//! The directories of the assets referenced by the documents can be watched
+//! too, with [`FileWatcher::watch_assets`]: changes to the images, SVGs, fonts
+//! and translation files they contain are reported as well (see
+//! [`is_asset_file`]).
 
+use crate::dependencies::dependency_order;
+use crate::reload::is_theme_file_path;
 use crossbeam_channel::{Receiver, Sender};
+use dampen_core::ir::DampenDocument;
+use dampen_core::parser;
 use notify::{RecursiveMode, Watcher};
 use notify_debouncer_full::{DebounceEventResult, Debouncer, FileIdMap, new_debouncer};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
+/// Extensions of the images, SVGs, fonts and translation files reported by
+/// [`FileWatcher::watch_assets`]
+pub const ASSET_EXTENSIONS: &[&str] = &[
+    "png", "jpg", "jpeg", "gif", "bmp", "webp", "ico", "svg", "ttf", "otf", "woff", "woff2", "ftl",
+    "po", "mo",
+];
+
+/// Directories holding translation files, whose JSON, TOML and YAML files are
+/// assets too
+pub const TRANSLATION_DIRS: &[&str] = &["i18n", "locales", "translations"];
+
 /// Configuration for file watcher behavior
 #[derive(Debug, Clone)]
 pub struct FileWatcherConfig {
@@ -118,6 +140,8 @@ pub struct FileWatcher {
     config: FileWatcherConfig,
     debouncer: Debouncer<notify::RecommendedWatcher, FileIdMap>,
     receiver: Receiver<PathBuf>,
+    /// Whether asset files are reported, set by [`FileWatcher::watch_assets`]
+    assets: Arc<AtomicBool>,
 }
 
 impl FileWatcher {
@@ -148,13 +172,16 @@ impl FileWatcher {
     pub fn new(config: FileWatcherConfig) -> Result<Self, FileWatcherError> {
         let (tx, rx) = crossbeam_channel::unbounded();
         let extension_filter = config.extension_filter.clone();
+        let assets = Arc::new(AtomicBool::new(false));
+        let report_assets = assets.clone();
 
         // Create debouncer with configured interval
         let debouncer = new_debouncer(
             Duration::from_millis(config.debounce_ms),
             None, // Use default tick rate
             move |result: DebounceEventResult| {
-                handle_debounced_events(result, &tx, &extension_filter);
+                let assets = report_assets.load(Ordering::Relaxed);
+                handle_debounced_events(result, &tx, &extension_filter, assets);
             },
         )
         .map_err(|e| FileWatcherError::InitializationFailed(e.to_string()))?;
@@ -163,6 +190,7 @@ impl FileWatcher {
             config,
             debouncer,
             receiver: rx,
+            assets,
         })
     }
 
@@ -226,6 +254,39 @@ impl FileWatcher {
         Ok(())
     }
 
+    /// Watch a directory of assets referenced by the documents
+    ///
+    /// The directory and its subdirectories are watched, and from then on the
+    /// changes to asset files (see [`is_asset_file`]) are reported along with
+    /// the `.dampen` files, in every watched path.
+    ///
+    /// # Errors
+    /// Returns the errors of [`FileWatcher::watch`]
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dampen_dev::watcher::{FileWatcher, FileWatcherConfig};
+    /// use std::path::PathBuf;
+    ///
+    /// let mut watcher = FileWatcher::new(FileWatcherConfig::default()).unwrap();
+    /// watcher.watch(PathBuf::from("src/ui")).unwrap();
+    /// watcher.watch_assets(PathBuf::from("assets")).expect("Failed to watch assets");
+    /// ```
+    pub fn watch_assets(&mut self, dir: PathBuf) -> Result<(), FileWatcherError> {
+        if !dir.exists() {
+            return Err(FileWatcherError::PathNotFound(dir));
+        }
+        self.debouncer
+            .watcher()
+            .watch(&dir, RecursiveMode::Recursive)
+            .map_err(|e| FileWatcherError::WatchError {
+                path: dir.clone(),
+                error: e.to_string(),
+            })?;
+        self.assets.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
     /// Remove a path from the watch list
     ///
     /// Stops watching the specified path for changes.
@@ -304,13 +365,15 @@ impl FileWatcher {
 ///
 /// **Simultaneous Multi-File Changes** (T124): The debouncing mechanism (100ms window)
 /// naturally batches rapid file changes together. When multiple files are modified
-/// simultaneously (e.g., save-all in IDE), all events within the debounce window
-/// are processed together in a single batch. Each file change triggers its own
-/// hot-reload attempt sequentially, with the most recent change winning.
+/// simultaneously (e.g., save-all in IDE, `git checkout`), the subscription coalesces
+/// the paths received within its batch window and delivers them as a single
+/// [`ReloadBatch`](crate::subscription::ReloadBatch), in dependency order (see
+/// [`reload_order`]).
 fn handle_debounced_events(
     result: DebounceEventResult,
     sender: &Sender<PathBuf>,
     extension_filter: &str,
+    assets: bool,
 ) {
     match result {
         Ok(events) => {
@@ -318,7 +381,9 @@ fn handle_debounced_events(
                 // Extract paths from the event
                 for path in &event.paths {
                     // Filter by extension
-                    if !path_matches_extension(path, extension_filter) {
+                    let matches = path_matches_extension(path, extension_filter)
+                        || (assets && is_asset_file(path));
+                    if !matches {
                         continue;
                     }
 
@@ -348,6 +413,96 @@ fn handle_debounced_events(
     }
 }
 
+/// Deduplicate changed paths and order them for reloading
+///
+/// Each file is reloaded after the files it depends on: assets (see
+/// [`is_asset_file`]) before the documents naming them, theme files before views, and style libraries before the documents
+/// using their classes and themes. `.dampen` files are read and parsed to find
+/// what they use; files that cannot be read or parsed depend on nothing.
+/// Files that do not depend on each other are sorted alphabetically to keep
+/// the order deterministic.
+///
+/// # Arguments
+/// * `paths` - Changed paths, possibly containing duplicates
+///
+/// # Returns
+/// Unique paths in reload order
+///
+/// # Example
+/// ```
+/// use dampen_dev::watcher::reload_order;
+/// use std::path::PathBuf;
+///
+/// let ordered = reload_order(vec![
+///     PathBuf::from("src/ui/window.dampen"),
+///     PathBuf::from("src/ui/theme/theme.dampen"),
+///     PathBuf::from("src/ui/window.dampen"),
+/// ]);
+///
+/// assert_eq!(
+///     ordered,
+///     vec![
+///         PathBuf::from("src/ui/theme/theme.dampen"),
+///         PathBuf::from("src/ui/window.dampen"),
+///     ]
+/// );
+/// ```
+pub fn reload_order(paths: impl IntoIterator<Item = PathBuf>) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = paths.into_iter().collect();
+    paths.sort();
+    paths.dedup();
+
+    let files: Vec<(PathBuf, Option<DampenDocument>)> = paths
+        .into_iter()
+        .map(|path| {
+            let document = (!is_asset_file(&path) && !is_theme_file_path(&path))
+                .then(|| std::fs::read_to_string(&path).ok())
+                .flatten()
+                .and_then(|content| parser::parse(&content).ok());
+            (path, document)
+        })
+        .collect();
+
+    dependency_order(files, |(path, _)| path, |(_, document)| document.as_ref())
+        .into_iter()
+        .map(|(path, _)| path)
+        .collect()
+}
+
+/// Check if a path points to an image, SVG, font or translation file
+///
+/// Translation files are `.ftl`, `.po` and `.mo` files, and the JSON, TOML
+/// and YAML files of a directory listed in [`TRANSLATION_DIRS`].
+///
+/// # Example
+/// ```
+/// use dampen_dev::watcher::is_asset_file;
+/// use std::path::Path;
+///
+/// assert!(is_asset_file(Path::new("assets/logo.png")));
+/// assert!(is_asset_file(Path::new("locales/fr.json")));
+/// assert!(!is_asset_file(Path::new("src/ui/window.dampen")));
+/// assert!(!is_asset_file(Path::new("package.json")));
+/// ```
+pub fn is_asset_file(path: &Path) -> bool {
+    let Some(extension) = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_ascii_lowercase())
+    else {
+        return false;
+    };
+    if ASSET_EXTENSIONS.contains(&extension.as_str()) {
+        return true;
+    }
+    matches!(extension.as_str(), "json" | "toml" | "yaml" | "yml")
+        && path
+            .parent()
+            .and_then(|dir| dir.file_name())
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| TRANSLATION_DIRS.contains(&name))
+}
+
 /// Check if a path matches the extension filter
 ///
 /// # Arguments
@@ -1,4 +1,7 @@
-use dampen_dev::persistence::{WindowState, get_config_path, load_or_default, save_window_state};
+use dampen_dev::persistence::{
+    MAIN_WINDOW, WindowState, WindowStateFile, get_config_path, load_or_default,
+    load_window_or_default, save_named_window_state, save_window_state,
+};
 use std::fs;
 use uuid::Uuid;
 
@@ -64,7 +67,12 @@ fn test_load_or_default_corrupted_file() {
         assert_eq!(state.width, 800);
         assert_eq!(state.height, 600);
 
+        // The corrupted file is kept aside
+        let corrupted = path.with_extension("json.corrupted");
+        assert!(corrupted.exists());
+
         // Cleanup
+        let _ = fs::remove_file(&corrupted);
         let _ = fs::remove_file(&path);
         let _ = fs::remove_dir(path.parent().unwrap());
     }
@@ -102,7 +110,9 @@ fn test_save_window_state_writes_valid_json() {
 
         assert!(path.exists());
         let content = fs::read_to_string(&path).unwrap();
-        let loaded: WindowState = serde_json::from_str(&content).unwrap();
+        let file: WindowStateFile = serde_json::from_str(&content).unwrap();
+        assert_eq!(file.version, WindowStateFile::VERSION);
+        let loaded = &file.windows[MAIN_WINDOW];
 
         assert_eq!(loaded.width, 800);
         assert_eq!(loaded.x, Some(50));
@@ -121,6 +131,7 @@ fn test_window_state_wayland_scenario() {
         x: None,
         y: None,
         maximized: false,
+        scale_factor: None,
     };
 
     assert!(state.position().is_none());
@@ -176,3 +187,65 @@ fn test_load_or_default_ignores_unreasonable_position() {
         let _ = fs::remove_dir(path.parent().unwrap());
     }
 }
+
+#[test]
+fn test_named_windows_are_saved_side_by_side() {
+    let app_name = random_app_name();
+    if let Some(path) = get_config_path(&app_name) {
+        save_window_state(&app_name, &WindowState::with_defaults(1024, 768)).unwrap();
+        save_named_window_state(&app_name, "settings", &WindowState::with_defaults(400, 300))
+            .unwrap();
+
+        assert_eq!(load_or_default(&app_name, 800, 600).width, 1024);
+        assert_eq!(
+            load_window_or_default(&app_name, "settings", 800, 600).width,
+            400
+        );
+        assert_eq!(
+            load_window_or_default(&app_name, "about", 800, 600).width,
+            800
+        );
+
+        // Cleanup
+        let _ = fs::remove_file(&path);
+        let _ = fs::remove_dir(path.parent().unwrap());
+    }
+}
+
+#[test]
+fn test_window_state_file_drops_corrupted_windows() {
+    let json = r#"{
+        "version": 2,
+        "windows": {
+            "main": {"width": 1024, "height": 768, "maximized": false},
+            "settings": {"width": "wide"},
+            "tiny": {"width": 10, "height": 10, "maximized": false}
+        }
+    }"#;
+    let file = WindowStateFile::parse(json).unwrap();
+    assert_eq!(file.windows.len(), 1);
+    assert_eq!(file.windows[MAIN_WINDOW].width, 1024);
+
+    // Legacy files hold the state of the main window
+    let legacy = r#"{"width": 640, "height": 480, "maximized": true}"#;
+    let file = WindowStateFile::parse(legacy).unwrap();
+    assert!(file.windows[MAIN_WINDOW].maximized);
+
+    assert!(WindowStateFile::parse("not json").is_err());
+}
+
+#[test]
+fn test_window_state_rescaled_keeps_physical_geometry() {
+    let mut state = WindowState::with_defaults(800, 600);
+    state.x = Some(100);
+    state.scale_factor = Some(2.0);
+
+    let rescaled = state.rescaled(1.0);
+    assert_eq!((rescaled.width, rescaled.height), (1600, 1200));
+    assert_eq!(rescaled.x, Some(200));
+    assert_eq!(rescaled.scale_factor, Some(1.0));
+
+    // Unknown scale factor: unchanged
+    let unknown = WindowState::with_defaults(800, 600);
+    assert_eq!(unknown.rescaled(2.0), unknown);
+}
@@ -4,7 +4,7 @@
 //! by testing the underlying components and integration.
 
 use dampen_core::parser;
-use dampen_dev::subscription::{FileWatcherRecipe, watch_files};
+use dampen_dev::subscription::{FileEvent, FileWatcherRecipe, ReloadBatch, watch_files};
 use dampen_dev::watcher::{FileWatcher, FileWatcherConfig};
 use std::fs;
 use std::hash::Hasher;
@@ -279,9 +279,72 @@ fn test_filewatcher_recipe_hash_uniqueness() {
         "Different debounce should hash differently"
     );
 
+    // Different batch window should hash differently
+    let recipe5 =
+        FileWatcherRecipe::new(vec![PathBuf::from("/tmp/test")], 100).with_batch_window(0);
+    assert_ne!(
+        hash_recipe(&recipe1),
+        hash_recipe(&recipe5),
+        "Different batch window should hash differently"
+    );
+
     println!("✓ Recipe hash uniqueness verified");
 }
 
+#[test]
+fn test_reload_batch_paths() {
+    // Verifies ReloadBatch exposes the paths of its events in order
+
+    let theme = PathBuf::from("src/ui/theme/theme.dampen");
+    let window = PathBuf::from("src/ui/window.dampen");
+    let document = parser::parse(r#"<column><text value="Hello" /></column>"#)
+        .expect("Should parse valid document");
+
+    let batch = ReloadBatch {
+        events: vec![
+            FileEvent::WatcherError {
+                path: theme.clone(),
+                error: "Failed to read file".to_string(),
+            },
+            FileEvent::Success {
+                path: window.clone(),
+                document: Box::new(document),
+            },
+        ],
+    };
+
+    assert_eq!(batch.len(), 2);
+    assert!(!batch.is_empty());
+    assert_eq!(batch.paths(), vec![&theme, &window]);
+}
+
+#[test]
+fn test_reload_batch_new_orders_by_dependencies() {
+    // Views using the classes of a style library are applied after it
+
+    let library = PathBuf::from("src/ui/styles.dampen");
+    let about = PathBuf::from("src/ui/about.dampen");
+    let window = PathBuf::from("src/ui/window.dampen");
+    let event = |path: &PathBuf, xml: &str| FileEvent::Success {
+        path: path.clone(),
+        document: Box::new(parser::parse(xml).expect("Should parse valid document")),
+    };
+
+    let batch = ReloadBatch::new(vec![
+        event(
+            &window,
+            r#"<column><container class="card"><text value="Hi" /></container></column>"#,
+        ),
+        event(
+            &library,
+            r#"<dampen><styles><style name="card"><base padding="8" /></style></styles></dampen>"#,
+        ),
+        event(&about, r#"<column><text value="About" /></column>"#),
+    ]);
+
+    assert_eq!(batch.paths(), vec![&about, &library, &window]);
+}
+
 #[test]
 fn test_subscription_api_creation() {
     // T076: Test that the public API can create subscriptions
@@ -4,7 +4,7 @@
 //! with proper debouncing and filtering.
 
 use crossbeam_channel;
-use dampen_dev::watcher::{FileWatcher, FileWatcherConfig};
+use dampen_dev::watcher::{FileWatcher, FileWatcherConfig, reload_order};
 use std::fs;
 use std::path::PathBuf;
 use std::thread;
@@ -417,6 +417,54 @@ fn test_deleted_file_handling() {
     // The important thing is that it doesn't cause a panic or error
 }
 
+#[test]
+fn test_reload_order_themes_first_and_deduplicated() {
+    // Batched changes (e.g., git checkout) must reload each file once,
+    // with theme files before the views that depend on them
+
+    let ordered = reload_order(vec![
+        PathBuf::from("src/ui/window.dampen"),
+        PathBuf::from("src/ui/about.dampen"),
+        PathBuf::from("src/ui/theme/theme.dampen"),
+        PathBuf::from("src/ui/window.dampen"),
+    ]);
+
+    assert_eq!(
+        ordered,
+        vec![
+            PathBuf::from("src/ui/theme/theme.dampen"),
+            PathBuf::from("src/ui/about.dampen"),
+            PathBuf::from("src/ui/window.dampen"),
+        ],
+        "Theme files should come first, duplicates should be removed"
+    );
+}
+
+#[test]
+fn test_reload_order_style_library_before_its_users() {
+    // A view is reloaded after the style library defining its classes,
+    // whatever their names
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let library = temp_dir.path().join("z_styles.dampen");
+    let view = temp_dir.path().join("a_view.dampen");
+    fs::write(
+        &library,
+        r#"<dampen><styles><style name="card"><base padding="8" /></style></styles></dampen>"#,
+    )
+    .expect("Failed to write library");
+    fs::write(
+        &view,
+        r#"<column><container class="card"><text value="Hi" /></container></column>"#,
+    )
+    .expect("Failed to write view");
+
+    assert_eq!(
+        reload_order(vec![view.clone(), library.clone()]),
+        vec![library, view]
+    );
+}
+
 #[test]
 fn test_file_change_detection_latency() {
     // T068: Verify file change detection <100ms (FR-010, SC-003)
@@ -549,3 +597,45 @@ fn test_file_change_detection_latency() {
 
     println!("✓ PASSED: File change detection latency meets <100ms requirement");
 }
+
+#[test]
+fn test_asset_change_detection() {
+    // Images of watched asset directories and their subdirectories are
+    // reported, other files are not
+
+    let temp_dir = setup_test_dir();
+    let image = temp_dir.path().join("logo.png");
+    let notes = temp_dir.path().join("notes.txt");
+    let icons = temp_dir.path().join("images");
+    let icon = icons.join("icon.png");
+    fs::write(&image, b"original").expect("Failed to create image");
+    fs::create_dir(&icons).expect("Failed to create images directory");
+    fs::write(&icon, b"original").expect("Failed to create icon");
+
+    let mut watcher =
+        FileWatcher::new(FileWatcherConfig::default()).expect("Failed to create watcher");
+    watcher
+        .watch_assets(temp_dir.path().to_path_buf())
+        .expect("Failed to watch assets");
+    thread::sleep(Duration::from_millis(50));
+
+    fs::write(&image, b"changed").expect("Failed to modify image");
+    fs::write(&icon, b"changed").expect("Failed to modify icon");
+    fs::write(&notes, "ignored").expect("Failed to create notes");
+
+    let timing = TestTiming::default();
+    let events = wait_for_events(watcher.receiver(), timing.test_timeout);
+    assert!(
+        events.iter().any(|p| p == &image),
+        "Expected event for {:?}, got {:?}",
+        image,
+        events
+    );
+    assert!(
+        events.iter().any(|p| p == &icon),
+        "Expected event for {:?}, got {:?}",
+        icon,
+        events
+    );
+    assert!(!events.contains(&notes));
+}
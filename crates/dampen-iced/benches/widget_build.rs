@@ -0,0 +1,156 @@
+//! Widget build benchmarks
+//!
+//! Measures style cascade resolution and the full `view()` build of small,
+//! medium and large documents with [`DampenWidgetBuilder`]. Documents are
+//! parsed once, as the runtime does between reloads.
+
+use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
+use dampen_core::ir::style::{Background, Color, StyleProperties};
+use dampen_core::ir::theme::{StyleClass, WidgetState};
+use dampen_core::{DampenDocument, HandlerRegistry, parse};
+use dampen_iced::DampenWidgetBuilder;
+use dampen_iced::style_mapping::{merge_style_properties, resolve_state_style};
+use dampen_macros::UiModel;
+use std::collections::HashMap;
+
+#[derive(Default, UiModel)]
+struct Model {
+    count: i64,
+    name: String,
+    done: bool,
+}
+
+/// Document sizes, as number of list rows
+const SIZES: [(&str, usize); 3] = [("small", 10), ("medium", 100), ("large", 1000)];
+
+/// Number of classes applied to a widget
+const DEPTHS: [usize; 3] = [1, 3, 6];
+
+/// Generate a styled view with `rows` rows bound to the model
+fn document(rows: usize) -> DampenDocument {
+    let mut xml = String::from(
+        r##"<dampen version="1.1">
+    <styles>
+        <style name="card">
+            <base padding="12" border_radius="8" border_width="1" border_color="#dddddd" background="#ffffff" />
+            <hover background="#f5f5f5" />
+        </style>
+        <style name="label">
+            <base color="#333333" />
+        </style>
+        <style name="btn">
+            <base padding="8 16" border_radius="6" />
+            <disabled opacity="0.5" />
+        </style>
+        <style name="btn_primary">
+            <base background="#3498db" color="#ffffff" />
+            <hover background="#5dade2" />
+        </style>
+    </styles>
+    <column spacing="10" padding="20">
+        <text value="Total: {count}" size="24" class="label" />
+"##,
+    );
+
+    for i in 0..rows {
+        xml.push_str(&format!(
+            r#"        <row spacing="8" class="card">
+            <text value="Item {i}: {{name}}" class="label" />
+            <checkbox label="Done" checked="{{done}}" on_toggle="toggle" />
+            <button label="Add" on_click="increment" enabled="{{count < 100}}" class="btn btn_primary" />
+        </row>
+"#
+        ));
+    }
+
+    xml.push_str("    </column>\n</dampen>\n");
+    parse(&xml).unwrap_or_else(|e| unreachable!("benchmark document: {}", e))
+}
+
+/// A class setting one property, with a hover variant
+fn class(index: usize) -> StyleClass {
+    let shade = index as f32 / 10.0;
+    let color = Color {
+        r: shade,
+        g: 0.4,
+        b: 0.6,
+        a: 1.0,
+    };
+
+    StyleClass {
+        name: format!("class_{}", index),
+        style: StyleProperties {
+            background: Some(Background::Color(color)),
+            opacity: index.is_multiple_of(2).then_some(1.0),
+            ..Default::default()
+        },
+        layout: None,
+        extends: Vec::new(),
+        state_variants: HashMap::from([(
+            WidgetState::Hover,
+            StyleProperties {
+                color: Some(color),
+                ..Default::default()
+            },
+        )]),
+        combined_state_variants: HashMap::new(),
+    }
+}
+
+/// Cascade `classes` in order, then the hover variants over the result
+fn cascade(classes: &[StyleClass]) -> StyleProperties {
+    let base = classes
+        .iter()
+        .fold(StyleProperties::default(), |style, class| {
+            merge_style_properties(&style, &class.style)
+        });
+    classes.iter().fold(base, |style, class| {
+        match resolve_state_style(class, WidgetState::Hover) {
+            Some(hover) => merge_style_properties(&style, hover),
+            None => style,
+        }
+    })
+}
+
+fn bench_style_cascade(c: &mut Criterion) {
+    let mut group = c.benchmark_group("style_cascade");
+
+    for depth in DEPTHS {
+        let classes: Vec<StyleClass> = (0..depth).map(class).collect();
+        group.bench_with_input(
+            BenchmarkId::new("resolve", depth),
+            &classes,
+            |b, classes| {
+                b.iter(|| cascade(black_box(classes)));
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn bench_build(c: &mut Criterion) {
+    let model = Model {
+        count: 42,
+        name: "Alice".to_string(),
+        done: false,
+    };
+    let registry = HandlerRegistry::new();
+    let mut group = c.benchmark_group("widget_build");
+
+    for (name, rows) in SIZES {
+        let document = document(rows);
+        // One row, one text, one checkbox and one button per row
+        group.throughput(Throughput::Elements((rows * 4 + 2) as u64));
+        group.bench_with_input(BenchmarkId::new("build", name), &document, |b, document| {
+            b.iter(|| {
+                DampenWidgetBuilder::new(black_box(document), &model, Some(&registry)).build()
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_style_cascade, bench_build);
+criterion_main!(benches);
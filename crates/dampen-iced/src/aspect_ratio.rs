@@ -0,0 +1,193 @@
+//! Aspect ratio
+//!
+//! Iced sizes widgets along each axis on its own. [`AspectRatio`] derives the
+//! height of its content from its width, so that media areas and thumbnails keep
+//! their proportions when the window is resized.
+//!
+//! ```xml
+//! <container width="fill" aspect_ratio="16:9" background="#000000">
+//!     <image src="poster.png" width="fill" height="fill" />
+//! </container>
+//! ```
+
+use iced::advanced::widget::{Operation, Tree};
+use iced::advanced::{Clipboard, Layout, Shell, Widget, layout, mouse, overlay, renderer};
+use iced::{Element, Event, Length, Rectangle, Size, Vector};
+
+/// Wrapper sizing its content to a ratio of the width to the height
+pub struct AspectRatio<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    ratio: f32,
+}
+
+impl<'a, Message, Theme, Renderer> AspectRatio<'a, Message, Theme, Renderer> {
+    /// Size `content` to `ratio`, the width divided by the height
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>, ratio: f32) -> Self {
+        Self {
+            content: content.into(),
+            ratio,
+        }
+    }
+}
+
+/// Size `content` to `ratio`, the width divided by the height
+pub fn aspect_ratio<'a, Message: 'a>(
+    content: impl Into<Element<'a, Message>>,
+    ratio: f32,
+) -> Element<'a, Message> {
+    AspectRatio::new(content, ratio).into()
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for AspectRatio<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        // The height follows the width
+        Size::new(self.content.as_widget().size().width, Length::Shrink)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content_width = self.content.as_widget().size().width;
+        let max = limits.width(content_width).max();
+
+        // Shrinking content keeps its own width; other widths take the space given
+        let width = if content_width == Length::Shrink {
+            self.content
+                .as_widget_mut()
+                .layout(&mut tree.children[0], renderer, limits)
+                .size()
+                .width
+        } else {
+            max.width
+        };
+
+        // Narrow the content when its height would not fit
+        let mut size = Size::new(width, width / self.ratio);
+        if size.height > max.height {
+            size = Size::new(max.height * self.ratio, max.height);
+        }
+
+        self.content.as_widget_mut().layout(
+            &mut tree.children[0],
+            renderer,
+            &layout::Limits::new(size, size),
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<AspectRatio<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(aspect_ratio: AspectRatio<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(aspect_ratio)
+    }
+}
@@ -0,0 +1,106 @@
+//! Handles of local image and SVG files
+//!
+//! Images and SVGs are drawn from handles pointing to their path, which iced
+//! caches by path: a file edited while the application runs would keep
+//! showing its old contents. The hot-reload watcher reports the edited files
+//! (`FileEvent::AssetChanged` of dampen-dev), and [`invalidate`] makes the
+//! next handles of a file read its new contents.
+//!
+//! Paths are resolved with [`dampen_core::assets::resolve`].
+
+use iced::widget::{image, svg};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// Handles of the files changed since the application started, created from
+/// their contents on first use
+#[derive(Default)]
+struct Reloaded {
+    image: Option<image::Handle>,
+    svg: Option<svg::Handle>,
+}
+
+static RELOADED: Mutex<Option<HashMap<PathBuf, Reloaded>>> = Mutex::new(None);
+
+fn reloaded() -> MutexGuard<'static, Option<HashMap<PathBuf, Reloaded>>> {
+    RELOADED.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Read the file `path` again the next time an image or SVG shows it
+pub fn invalidate(path: &Path) {
+    reloaded()
+        .get_or_insert_with(HashMap::new)
+        .insert(canonical(path), Reloaded::default());
+}
+
+/// Handle of the image file `src`
+pub fn image_handle(src: &str) -> image::Handle {
+    let path = dampen_core::assets::resolve(src);
+    let mut reloaded = reloaded();
+    match reloaded
+        .as_mut()
+        .and_then(|reloaded| reloaded.get_mut(&canonical(&path)))
+    {
+        Some(entry) => entry
+            .image
+            .get_or_insert_with(|| {
+                image::Handle::from_bytes(std::fs::read(&path).unwrap_or_default())
+            })
+            .clone(),
+        None => image::Handle::from_path(path),
+    }
+}
+
+/// Handle of the SVG file `src`
+pub fn svg_handle(src: &str) -> svg::Handle {
+    let path = dampen_core::assets::resolve(src);
+    let mut reloaded = reloaded();
+    match reloaded
+        .as_mut()
+        .and_then(|reloaded| reloaded.get_mut(&canonical(&path)))
+    {
+        Some(entry) => entry
+            .svg
+            .get_or_insert_with(|| {
+                svg::Handle::from_memory(std::fs::read(&path).unwrap_or_default())
+            })
+            .clone(),
+        None => svg::Handle::from_path(path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalidate_reads_changed_files() {
+        let dir = std::env::temp_dir().join(format!("dampen-assets-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("icon.svg");
+        std::fs::write(&file, "<svg/>").unwrap();
+        let src = file.to_string_lossy().to_string();
+
+        let initial = svg_handle(&src);
+        assert!(matches!(initial.data(), iced::advanced::svg::Data::Path(_)));
+        assert_eq!(svg_handle(&src).id(), initial.id());
+
+        std::fs::write(&file, "<svg width=\"2\"/>").unwrap();
+        invalidate(&file);
+        let changed = svg_handle(&src);
+        assert!(matches!(
+            changed.data(),
+            iced::advanced::svg::Data::Bytes(_)
+        ));
+        assert_ne!(changed.id(), initial.id());
+        assert_eq!(svg_handle(&src).id(), changed.id());
+        assert!(matches!(image_handle(&src), image::Handle::Bytes(..)));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -16,10 +16,16 @@
 //!
 
 use crate::HandlerMessage;
+use crate::aspect_ratio::AspectRatio;
+use crate::decoration::{Decorated, Decoration};
+use crate::diagnostics::{BuildDiagnostic, BuildDiagnosticKind};
+use crate::profiler::ProfilePhase;
 use dampen_core::binding::BindingValue;
 use dampen_core::expr::error::BindingError;
-use dampen_core::expr::evaluate_binding_expr_with_shared;
+use dampen_core::expr::{ValueFormat, evaluate_binding_expr_with_shared};
 use dampen_core::ir::WidgetKind;
+use dampen_core::ir::cascade::{StyleCascade, StyleSource};
+use dampen_core::ir::layout::{Breakpoint, Overflow};
 use dampen_core::ir::node::{AttributeValue, InterpolatedPart, WidgetNode};
 use dampen_core::ir::span::Span;
 use dampen_core::ir::theme::StyleClass;
@@ -302,20 +308,55 @@ pub fn resolve_handler_param(
         return Ok(value);
     }
 
-    match evaluate_binding_expr_with_shared(binding_expr, builder.model, builder.shared_context) {
+    match evaluate_binding_expr_with_shared(
+        binding_expr,
+        &builder.binding_model(),
+        builder.shared_context,
+    ) {
         Ok(value) => Ok(value),
-        Err(binding_error) => Err(HandlerResolutionError {
-            handler_name: String::from("unknown"),
-            widget_kind: String::from("unknown"),
-            widget_id: None,
-            param_expr: format!("{:?}", binding_expr),
-            binding_error,
-            span: binding_expr.span,
-            context_note: Some(String::from(
-                "Tried context resolution first, then model field access",
-            )),
-        }),
+        Err(binding_error) => {
+            builder.report(
+                BuildDiagnosticKind::BindingError,
+                binding_error.span,
+                || binding_error.message.clone(),
+            );
+            Err(HandlerResolutionError {
+                handler_name: String::from("unknown"),
+                widget_kind: String::from("unknown"),
+                widget_id: None,
+                param_expr: format!("{:?}", binding_expr),
+                binding_error,
+                span: binding_expr.span,
+                context_note: Some(String::from(
+                    "Tried context resolution first, then model field access",
+                )),
+            })
+        }
+    }
+}
+
+/// Resolves the parameters of a multi-parameter event (`on_click="name:{a},{b}"`)
+///
+/// Each parameter is resolved with [`resolve_handler_param`] and the string forms are
+/// encoded into a single handler value with
+/// [`encode_event_args`](dampen_core::handler::encode_event_args). Returns `None`
+/// if any parameter fails to resolve.
+pub fn resolve_event_params(
+    builder: &DampenWidgetBuilder<'_>,
+    params: &[dampen_core::expr::BindingExpr],
+) -> Option<String> {
+    let mut values = Vec::with_capacity(params.len());
+    for param in params {
+        match resolve_handler_param(builder, param) {
+            Ok(value) => values.push(value.to_display_string()),
+            Err(_e) => {
+                #[cfg(debug_assertions)]
+                eprintln!("[DampenWidgetBuilder] Event param error: {}", _e);
+                return None;
+            }
+        }
     }
+    Some(dampen_core::handler::encode_event_args(&values))
 }
 
 /// Creates a state-aware style closure for widgets that support status-based styling.
@@ -367,7 +408,7 @@ pub fn resolve_handler_param(
 /// ```
 #[allow(clippy::too_many_arguments)]
 pub fn create_state_aware_style_fn<'a, S, T, F, M>(
-    _builder: &DampenWidgetBuilder<'a>,
+    builder: &DampenWidgetBuilder<'a>,
     _node: &WidgetNode,
     _widget_kind: WidgetKind,
     style_class: Option<StyleClassRef>,
@@ -383,10 +424,13 @@ where
 {
     use crate::style_mapping::resolve_state_style;
 
+    let active_theme = builder.active_theme();
+
     Some(move |_theme: &iced::Theme, status: T| {
         let widget_state = status_mapper(status.clone());
 
-        let final_style_props = if let (Some(class), Some(state)) = (&style_class, widget_state) {
+        let mut final_style_props = if let (Some(class), Some(state)) = (&style_class, widget_state)
+        {
             if let Some(state_style) = resolve_state_style(class, state) {
                 merge_styles(base_style.clone(), state_style)
             } else {
@@ -395,6 +439,7 @@ where
         } else {
             base_style.clone()
         };
+        apply_elevation(&mut final_style_props, active_theme);
 
         style_converter(_theme, status, &final_style_props)
     })
@@ -404,7 +449,7 @@ where
 use dampen_core::UiBindable;
 
 /// A wrapper model that looks up fields in the binding context first, then the actual model.
-struct ContextAwareModel<'a, 'b> {
+pub(super) struct ContextAwareModel<'a, 'b> {
     builder: &'b DampenWidgetBuilder<'a>,
     model: &'b dyn UiBindable,
 }
@@ -433,8 +478,29 @@ impl<'a, 'b> UiBindable for ContextAwareModel<'a, 'b> {
             }
         }
 
-        // 2. Fallback to model
-        self.model.get_field(path)
+        // 2. Resolve against the model, relative to the current `model="{...}"` scope
+        let scope = self.builder.model_scope.borrow();
+        if scope.is_empty() {
+            return self.model.get_field(path);
+        }
+        let scoped: Vec<&str> = scope
+            .iter()
+            .map(String::as_str)
+            .chain(path.iter().copied())
+            .collect();
+
+        // A scope may itself start at a loop variable (`model="{line.product}"`)
+        if let Some(first_segment) = scoped.first() {
+            for context in self.builder.binding_context.borrow().iter().rev() {
+                if let Some(value) = context.get(*first_segment) {
+                    let nested_path: Vec<String> =
+                        scoped[1..].iter().map(|s| s.to_string()).collect();
+                    return self.builder.resolve_nested_field(value, &nested_path);
+                }
+            }
+        }
+
+        self.model.get_field(&scoped)
     }
 
     fn available_fields() -> Vec<String> {
@@ -445,17 +511,176 @@ impl<'a, 'b> UiBindable for ContextAwareModel<'a, 'b> {
 }
 
 impl<'a> DampenWidgetBuilder<'a> {
+    /// The model as seen by bindings: context variables first, then the model
+    /// relative to the current `model="{...}"` scope
+    pub(super) fn binding_model(&self) -> ContextAwareModel<'a, '_> {
+        ContextAwareModel {
+            builder: self,
+            model: self.model,
+        }
+    }
+
+    /// Breakpoint of the viewport the view is built for, if its width is known
+    pub(super) fn breakpoint(&self) -> Option<Breakpoint> {
+        let width = self
+            .viewport_width
+            .or_else(|| dampen_core::viewport::size().map(|size| size.width))?;
+        match self.breakpoints {
+            Some(breakpoints) => breakpoints.at_width(width),
+            None => Some(Breakpoint::from_viewport_width(width)),
+        }
+    }
+
+    /// Enter the binding scope of a `model="{...}"` container
+    ///
+    /// The model path is relative to the enclosing scope, unless it starts at a
+    /// context variable. Returns the enclosing scope, to restore once the node is built.
+    pub(super) fn enter_model_scope(&self, node: &WidgetNode) -> Option<Vec<String>> {
+        let path = dampen_core::ir::scope::model_scope(node)?;
+
+        let is_context_variable = path.first().is_some_and(|first| {
+            self.binding_context
+                .borrow()
+                .iter()
+                .any(|context| context.contains_key(first.as_str()))
+        });
+        let mut scope = if is_context_variable {
+            Vec::new()
+        } else {
+            self.model_scope.borrow().clone()
+        };
+        scope.extend(path.iter().cloned());
+
+        Some(self.model_scope.replace(scope))
+    }
+
+    /// `node` with its layout and style attributes bound to the model evaluated
+    ///
+    /// Returns `None` when no such attribute is bound, or when a value is
+    /// invalid: the node is then built with its static layout and style.
+    pub(super) fn resolve_visual_bindings(&self, node: &WidgetNode) -> Option<WidgetNode> {
+        let values: Vec<(String, String)> = node
+            .bound_visual_attributes()
+            .map(|(name, value)| (name.to_string(), self.evaluate_attribute(value)))
+            .collect();
+        if values.is_empty() {
+            return None;
+        }
+
+        match node.with_visual_values(values) {
+            Ok(resolved) => Some(resolved),
+            Err(error) => {
+                self.report(BuildDiagnosticKind::UnsupportedAttribute, node.span, || {
+                    format!("Invalid bound layout or style value: {}", error)
+                });
+                None
+            }
+        }
+    }
+
     /// Evaluate a binding expression using both context variables and the model.
     pub(crate) fn evaluate_binding_with_context(
         &self,
         expr: &dampen_core::expr::BindingExpr,
     ) -> Result<BindingValue, BindingError> {
-        let context_model = ContextAwareModel {
-            builder: self,
-            model: self.model,
+        let result = self.profile(ProfilePhase::Bindings, || {
+            evaluate_binding_expr_with_shared(expr, &self.binding_model(), self.shared_context)
+        });
+        if let Err(error) = &result {
+            self.report(BuildDiagnosticKind::BindingError, error.span, || {
+                error.message.clone()
+            });
+        }
+        result
+    }
+
+    /// Record a diagnostic when a collector is attached
+    ///
+    /// `message` is only built when recording, so callers can pass a formatting closure.
+    pub(super) fn report(
+        &self,
+        kind: BuildDiagnosticKind,
+        span: Span,
+        message: impl FnOnce() -> String,
+    ) {
+        if let Some(diagnostics) = self.diagnostics {
+            diagnostics.push(BuildDiagnostic {
+                kind,
+                message: message(),
+                span,
+            });
+        }
+    }
+
+    /// Evaluate a numeric attribute, static or bound, falling back to `default`
+    pub(super) fn resolve_f32_attribute(&self, node: &WidgetNode, name: &str, default: f32) -> f32 {
+        node.attributes
+            .get(name)
+            .map(|attr| self.evaluate_attribute(attr))
+            .and_then(|value| value.trim().parse::<f32>().ok())
+            .unwrap_or(default)
+    }
+
+    /// Place a text showing `value` after `widget` when `show_value="true"`
+    ///
+    /// The text is formatted with the `value_format` filter, if any.
+    pub(super) fn with_value_label(
+        &self,
+        widget: iced::Element<'a, HandlerMessage, iced::Theme, iced::Renderer>,
+        node: &WidgetNode,
+        value: f32,
+    ) -> iced::Element<'a, HandlerMessage, iced::Theme, iced::Renderer> {
+        if !resolve_boolean_attribute(self, node, "show_value", false) {
+            return widget;
+        }
+
+        let format = match node.attributes.get("value_format") {
+            Some(attr) => {
+                let spec = self.evaluate_attribute(attr);
+                ValueFormat::parse(&spec).unwrap_or_else(|error| {
+                    self.report(BuildDiagnosticKind::UnsupportedAttribute, node.span, || {
+                        error.message
+                    });
+                    ValueFormat::default()
+                })
+            }
+            None => ValueFormat::default(),
+        };
+        let label = format.apply(&BindingValue::Float(value as f64));
+
+        iced::widget::row![widget, iced::widget::text(label)]
+            .spacing(8)
+            .align_y(iced::alignment::Vertical::Center)
+            .into()
+    }
+
+    /// Report the events of `node` whose handler is not in the registry
+    ///
+    /// Such events still produce messages, but nothing happens when they are dispatched.
+    pub(super) fn check_handlers(&self, node: &WidgetNode) {
+        let (Some(diagnostics), Some(registry)) = (self.diagnostics, self.handler_registry) else {
+            return;
         };
+        for event in &node.events {
+            if !registry.contains(&event.handler) {
+                diagnostics.push(BuildDiagnostic {
+                    kind: BuildDiagnosticKind::MissingHandler,
+                    message: format!(
+                        "Handler '{}' of <{}> is not registered",
+                        event.handler, node.kind
+                    ),
+                    span: event.span,
+                });
+            }
+        }
+    }
 
-        evaluate_binding_expr_with_shared(expr, &context_model, self.shared_context)
+    /// Run `f`, attributing its duration to `phase` when a profiler is attached
+    pub(super) fn profile<R>(&self, phase: ProfilePhase, f: impl FnOnce() -> R) -> R {
+        match self.profiler {
+            Some(profiler) => profiler.measure(phase, f),
+            None => f(),
+        }
     }
 
     /// Evaluate an attribute value to a BindingValue (without converting to string)
@@ -660,7 +885,6 @@ impl<'a> DampenWidgetBuilder<'a> {
     /// # Returns
     ///
     /// The resolved theme name as a string, or None if no theme is specified
-    #[allow(dead_code)]
     pub(super) fn resolve_theme(&self, theme_ref: &Option<AttributeValue>) -> Option<String> {
         match theme_ref {
             None => None,
@@ -670,7 +894,11 @@ impl<'a> DampenWidgetBuilder<'a> {
                 if let Some(value) = self.resolve_from_context(expr) {
                     Some(value.to_display_string())
                 } else {
-                    match evaluate_binding_expr_with_shared(expr, self.model, self.shared_context) {
+                    match evaluate_binding_expr_with_shared(
+                        expr,
+                        &self.binding_model(),
+                        self.shared_context,
+                    ) {
                         Ok(value) => Some(value.to_display_string()),
                         Err(_) => {
                             #[cfg(debug_assertions)]
@@ -691,7 +919,7 @@ impl<'a> DampenWidgetBuilder<'a> {
                             } else {
                                 match evaluate_binding_expr_with_shared(
                                     expr,
-                                    self.model,
+                                    &self.binding_model(),
                                     self.shared_context,
                                 ) {
                                     Ok(value) => result.push_str(&value.to_display_string()),
@@ -715,16 +943,80 @@ impl<'a> DampenWidgetBuilder<'a> {
         }
     }
 
+    /// Theme the widget being built is styled with: the nearest `theme="..."`
+    /// override, or else the active theme of the theme context
+    pub(super) fn active_theme(&self) -> Option<&'a dampen_core::ir::theme::Theme> {
+        let scoped = *self.theme_scope.borrow();
+        scoped.or_else(|| self.theme_context.map(|ctx| ctx.active()))
+    }
+
+    /// Apply the `theme="..."` override of `node` to its subtree
+    ///
+    /// Returns the theme of the enclosing scope, to restore once the subtree is
+    /// built, or `None` if `node` sets no theme. Unknown themes are reported and
+    /// ignored.
+    pub(super) fn enter_theme_scope(
+        &self,
+        node: &WidgetNode,
+    ) -> Option<Option<&'a dampen_core::ir::theme::Theme>> {
+        let theme_context = self.theme_context?;
+        let name = self.resolve_theme(&node.theme_ref)?;
+
+        match theme_context.theme(&name) {
+            Some(theme) => Some(self.theme_scope.replace(Some(theme))),
+            None => {
+                self.report(BuildDiagnosticKind::UnsupportedAttribute, node.span, || {
+                    format!("Unknown theme '{}'", name)
+                });
+                None
+            }
+        }
+    }
+
+    /// Style `element` with the theme of the current `theme="..."` override
+    ///
+    /// Built-in Iced styles follow the palette of the override, and so does the
+    /// default text color.
+    pub(super) fn wrap_theme(
+        &self,
+        element: iced::Element<'a, HandlerMessage, iced::Theme, iced::Renderer>,
+    ) -> iced::Element<'a, HandlerMessage, iced::Theme, iced::Renderer> {
+        let theme = self
+            .active_theme()
+            .map(crate::theme_adapter::ThemeAdapter::to_iced);
+        iced::widget::themer(theme, element)
+            .text_color(|theme: &iced::Theme| theme.palette().text)
+            .into()
+    }
+
     /// Resolve active classes, handling dynamic bindings
+    ///
+    /// The classes of `class` come first, then the toggled classes
+    /// (`class:active="{selected}"`) whose condition holds, by name.
     pub(crate) fn resolve_active_classes(&self, node: &WidgetNode) -> Vec<String> {
-        if let Some(attr) = node.attributes.get("class") {
+        let mut classes: Vec<String> = if let Some(attr) = node.attributes.get("class") {
             self.evaluate_attribute(attr)
                 .split_whitespace()
                 .map(|s| s.to_string())
                 .collect()
         } else {
             node.classes.clone()
-        }
+        };
+
+        let mut toggled: Vec<&str> = node
+            .class_toggles()
+            .filter(
+                |(_, condition)| match self.evaluate_attribute_value(condition) {
+                    // `class:active="true"`, or a condition evaluated to text
+                    BindingValue::String(value) => parse_boolean_string(&value, false),
+                    value => value.to_bool(),
+                },
+            )
+            .map(|(class, _)| class)
+            .collect();
+        toggled.sort_unstable();
+        classes.extend(toggled.into_iter().map(str::to_string));
+        classes
     }
 
     /// Resolve styles from class names
@@ -732,46 +1024,40 @@ impl<'a> DampenWidgetBuilder<'a> {
         &self,
         node: &WidgetNode,
     ) -> Option<dampen_core::ir::style::StyleProperties> {
-        let classes = self.resolve_active_classes(node);
-        if classes.is_empty() {
-            return None;
-        }
-
-        let style_classes = self.style_classes?;
+        self.profile(ProfilePhase::Styles, || {
+            let classes = self.resolve_active_classes(node);
+            if classes.is_empty() {
+                return None;
+            }
 
-        // Merge styles from all classes (in order)
-        let mut merged_style = dampen_core::ir::style::StyleProperties::default();
+            let style_classes = self.style_classes?;
 
-        for class_name in classes {
-            if let Some(style_class) = style_classes.get(&class_name) {
-                // Merge the base style from this class
-                merged_style = merge_styles(merged_style, &style_class.style);
+            // Merge styles from all classes (in order)
+            let cascade = StyleCascade::resolve_with_classes(node, &classes, style_classes, None);
 
-                #[cfg(debug_assertions)]
-                eprintln!(
-                    "[DampenWidgetBuilder] Applied class '{}' to widget",
-                    class_name
-                );
-            } else {
-                #[cfg(debug_assertions)]
+            #[cfg(debug_assertions)]
+            for class_name in &cascade.missing_classes {
                 eprintln!("[DampenWidgetBuilder] Class '{}' not found", class_name);
             }
-        }
 
-        Some(merged_style)
+            let merged_style = cascade
+                .layers
+                .iter()
+                .filter(|layer| matches!(layer.source, StyleSource::Class(_)))
+                .fold(
+                    dampen_core::ir::style::StyleProperties::default(),
+                    |merged, layer| merge_styles(merged, &layer.style),
+                );
+
+            Some(merged_style)
+        })
     }
 
     /// Resolve theme-based styles from the active theme context
     ///
     /// This extracts color defaults from the theme palette for use as widget base styles.
-    /// Returns `None` if no theme context is available.
-    ///
-    /// # Theme Colors Used
-    ///
-    /// - `primary` → background for buttons, interactive elements
-    /// - `text` → text color for labels
-    /// - `background` → container backgrounds
-    /// - `surface` → card/pane backgrounds
+    /// Returns `None` if no theme context is available. The colors used for each
+    /// widget kind are those of [`dampen_core::ir::cascade::theme_defaults`].
     ///
     /// # Arguments
     ///
@@ -784,50 +1070,8 @@ impl<'a> DampenWidgetBuilder<'a> {
         &self,
         widget_kind: WidgetKind,
     ) -> Option<dampen_core::ir::style::StyleProperties> {
-        let theme_ctx = self.theme_context?;
-        let theme = theme_ctx.active();
-        let palette = &theme.palette;
-
-        // Determine which theme colors to use based on widget type
-        let mut style = dampen_core::ir::style::StyleProperties::default();
-
-        match widget_kind {
-            WidgetKind::Button => {
-                // Buttons use primary color for background
-                if let Some(ref primary) = palette.primary {
-                    style.background = Some(dampen_core::ir::style::Background::Color(*primary));
-                }
-                // Buttons use text color
-                if let Some(ref text) = palette.text {
-                    style.color = Some(*text);
-                }
-            }
-            WidgetKind::Container => {
-                // Containers use background/surface colors
-                if let Some(ref surface) = palette.surface {
-                    style.background = Some(dampen_core::ir::style::Background::Color(*surface));
-                }
-            }
-            WidgetKind::Text => {
-                // Text widgets use text color
-                if let Some(ref text) = palette.text {
-                    style.color = Some(*text);
-                }
-            }
-            _ => {
-                // Other widgets: use text color if available
-                if let Some(ref text) = palette.text {
-                    style.color = Some(*text);
-                }
-            }
-        }
-
-        // Check if we actually got any theme colors
-        if style.background.is_none() && style.color.is_none() {
-            None
-        } else {
-            Some(style)
-        }
+        let active_theme = self.active_theme()?;
+        dampen_core::ir::cascade::theme_defaults(&widget_kind, &active_theme.palette)
     }
 
     /// Create a style closure that resolves theme colors at render time.
@@ -850,7 +1094,7 @@ impl<'a> DampenWidgetBuilder<'a> {
     ) -> Option<
         impl Fn(&iced::Theme, iced::widget::button::Status) -> iced::widget::button::Style + 'a,
     > {
-        let theme_context = self.theme_context;
+        let active_theme = self.active_theme();
         let widget_kind = node.kind.clone();
 
         // Get class styles (these are static/resolved at build time, not from theme)
@@ -867,7 +1111,7 @@ impl<'a> DampenWidgetBuilder<'a> {
 
         // If no theme, no classes, and no inline styles, don't apply a closure
         // This allows Iced to use its default theme-based styling (e.g. for buttons)
-        if theme_context.is_none() && class_styles.is_none() && inline_style.is_none() {
+        if active_theme.is_none() && class_styles.is_none() && inline_style.is_none() {
             return None;
         }
 
@@ -876,13 +1120,12 @@ impl<'a> DampenWidgetBuilder<'a> {
                 use crate::style_mapping::{
                     map_button_status, merge_style_properties, resolve_state_style,
                 };
-                use iced::{Background, Border, Color};
+                use iced::{Border, Color};
 
                 // Resolve theme colors for this widget type at render time
                 let mut theme_style = dampen_core::ir::style::StyleProperties::default();
 
-                if let Some(ctx) = theme_context {
-                    let active_theme = ctx.active();
+                if let Some(active_theme) = active_theme {
                     let palette = &active_theme.palette;
 
                     match widget_kind {
@@ -926,7 +1169,7 @@ impl<'a> DampenWidgetBuilder<'a> {
                 }
 
                 // Handle state variants
-                let final_style_props = if let (Some(class), Some(state)) =
+                let mut final_style_props = if let (Some(class), Some(state)) =
                     (&class_styles, map_button_status(status))
                 {
                     if let Some(state_style) = resolve_state_style(class, state) {
@@ -937,6 +1180,7 @@ impl<'a> DampenWidgetBuilder<'a> {
                 } else {
                     merged
                 };
+                apply_elevation(&mut final_style_props, active_theme);
 
                 // Convert to Iced style
                 let mut style = iced::widget::button::Style {
@@ -947,15 +1191,8 @@ impl<'a> DampenWidgetBuilder<'a> {
                     snap: false,
                 };
 
-                if let Some(ref bg) = final_style_props.background
-                    && let dampen_core::ir::style::Background::Color(color) = bg
-                {
-                    style.background = Some(Background::Color(Color {
-                        r: color.r,
-                        g: color.g,
-                        b: color.b,
-                        a: color.a,
-                    }));
+                if let Some(ref bg) = final_style_props.background {
+                    style.background = Some(crate::convert::map_background_with_theme(bg, _theme));
                 }
 
                 if let Some(ref text_color) = final_style_props.color {
@@ -1024,33 +1261,35 @@ impl<'a> DampenWidgetBuilder<'a> {
         &self,
         node: &WidgetNode,
     ) -> Option<dampen_core::ir::style::StyleProperties> {
-        // Layer 1: Theme styles (base)
-        // Note: Don't use ? here, as we want to continue even if theme has no styles
-        let theme_styles = self.resolve_theme_styles(node.kind.clone());
+        self.profile(ProfilePhase::Styles, || {
+            // Layer 1: Theme styles (base)
+            // Note: Don't use ? here, as we want to continue even if theme has no styles
+            let theme_styles = self.resolve_theme_styles(node.kind.clone());
 
-        // Layer 2: Class styles (override theme)
-        let class_styles = self.resolve_class_styles(node);
+            // Layer 2: Class styles (override theme)
+            let class_styles = self.resolve_class_styles(node);
 
-        // Layer 3: Inline styles (override class)
-        let inline_style = &node.style;
+            // Layer 3: Inline styles (override class)
+            let inline_style = &node.style;
 
-        // If no styles at all, return None
-        if theme_styles.is_none() && class_styles.is_none() && inline_style.is_none() {
-            return None;
-        }
+            // If no styles at all, return None
+            if theme_styles.is_none() && class_styles.is_none() && inline_style.is_none() {
+                return None;
+            }
 
-        // Merge all layers: theme → class → inline
-        let mut merged = theme_styles.unwrap_or_default();
+            // Merge all layers: theme → class → inline
+            let mut merged = theme_styles.unwrap_or_default();
 
-        if let Some(class_style) = class_styles {
-            merged = merge_styles(merged, &class_style);
-        }
+            if let Some(class_style) = class_styles {
+                merged = merge_styles(merged, &class_style);
+            }
 
-        if let Some(inline_style) = inline_style {
-            merged = merge_styles(merged, inline_style);
-        }
+            if let Some(inline_style) = inline_style {
+                merged = merge_styles(merged, inline_style);
+            }
 
-        Some(merged)
+            Some(merged)
+        })
     }
 
     /// Resolve layout constraints from class names
@@ -1119,6 +1358,10 @@ impl<'a> DampenWidgetBuilder<'a> {
             (None, Some(node_style)) => Some(node_style.clone()),
             (None, None) => None,
         };
+        let resolved_style = resolved_style.map(|mut style| {
+            apply_elevation(&mut style, self.active_theme());
+            style
+        });
 
         // Resolve layouts: use the helper to avoid duplication
         let resolved_layout = self.resolve_layout(node);
@@ -1135,6 +1378,8 @@ impl<'a> DampenWidgetBuilder<'a> {
                 || layout.max_width.is_some()
                 || layout.min_height.is_some()
                 || layout.max_height.is_some()
+                || layout.aspect_ratio.is_some()
+                || layout.overflow.is_some()
         } else {
             false
         };
@@ -1146,7 +1391,22 @@ impl<'a> DampenWidgetBuilder<'a> {
             return element;
         }
 
-        let mut container = container(element);
+        // Per-side borders and outline are drawn over the container. Text inputs
+        // draw their outline as their border instead
+        let decoration = resolved_style
+            .as_ref()
+            .filter(|_| node.kind != WidgetKind::TextInput)
+            .and_then(Decoration::from_style);
+
+        // Scrolling content sits in a scrollable within the container
+        let overflow = resolved_layout.as_ref().and_then(|layout| layout.overflow);
+        let element = if overflow == Some(Overflow::Scroll) {
+            iced::widget::scrollable(element).into()
+        } else {
+            element
+        };
+
+        let mut container = container(element).clip(overflow == Some(Overflow::Clip));
 
         // Apply layout constraints (includes padding, width, height, etc.)
         if let Some(layout) = &resolved_layout {
@@ -1176,19 +1436,21 @@ impl<'a> DampenWidgetBuilder<'a> {
             // Use theme-aware styling that resolves colors at render time
             let widget_kind = node.kind.clone();
             let resolved_style = resolved_style.clone();
-            let theme_context = self.theme_context;
+            let active_theme = self.active_theme();
 
-            container = container.style(move |_theme: &iced::Theme| {
-                use crate::convert::map_style_properties;
+            container = container.style(move |theme: &iced::Theme| {
+                use crate::convert::map_style_properties_with_theme;
 
                 // Get the active theme at RUNTIME (not build time!)
-                let ctx = match theme_context {
-                    Some(ctx) => ctx,
+                let active_theme = match active_theme {
+                    Some(active_theme) => active_theme,
                     None => {
-                        return map_style_properties(&resolved_style.clone().unwrap_or_default());
+                        return map_style_properties_with_theme(
+                            &resolved_style.clone().unwrap_or_default(),
+                            theme,
+                        );
                     }
                 };
-                let active_theme = ctx.active();
                 let palette = &active_theme.palette;
 
                 // Resolve theme colors for this widget type at render time
@@ -1208,15 +1470,31 @@ impl<'a> DampenWidgetBuilder<'a> {
                     None => theme_style,
                 };
 
-                map_style_properties(&merged)
+                map_style_properties_with_theme(&merged, theme)
             });
         } else if let Some(style) = resolved_style {
-            use crate::convert::map_style_properties;
-            let iced_style = map_style_properties(&style);
-            container = container.style(move |_theme| iced_style);
+            use crate::convert::map_style_properties_with_theme;
+            container = container
+                .style(move |theme: &iced::Theme| map_style_properties_with_theme(&style, theme));
         }
 
-        container.into()
+        let element: iced::Element<'a, HandlerMessage, iced::Theme, iced::Renderer> =
+            match decoration {
+                Some(decoration) => {
+                    let decorated: Decorated<'a, HandlerMessage> =
+                        Decorated::new(container, decoration);
+                    decorated.into()
+                }
+                None => container.into(),
+            };
+
+        match resolved_layout.and_then(|layout| layout.aspect_ratio) {
+            Some(ratio) => {
+                let sized: AspectRatio<'a, HandlerMessage> = AspectRatio::new(element, ratio);
+                sized.into()
+            }
+            None => element,
+        }
     }
 }
 
@@ -1292,12 +1570,41 @@ pub(super) fn parse_length(s: &str) -> Option<iced::Length> {
     }
 }
 
+/// Wrap `element` in a slot of `grow` portions of the free space along the main
+/// axis of its `parent` row or column
+pub(super) fn apply_grow<'a>(
+    element: iced::Element<'a, HandlerMessage, iced::Theme, iced::Renderer>,
+    parent: &WidgetKind,
+    grow: u8,
+) -> iced::Element<'a, HandlerMessage, iced::Theme, iced::Renderer> {
+    let portion = iced::Length::FillPortion(u16::from(grow));
+    let slot = iced::widget::container(element);
+    match parent {
+        WidgetKind::Column => slot.height(portion).into(),
+        _ => slot.width(portion).into(),
+    }
+}
+
+/// Set the shadow of `style` from its `elevation`
+///
+/// Levels use the shadows of the active theme, or the built-in presets without
+/// a theme. An explicit `shadow` is kept.
+pub(super) fn apply_elevation(
+    style: &mut dampen_core::ir::style::StyleProperties,
+    active_theme: Option<&dampen_core::ir::theme::Theme>,
+) {
+    match active_theme {
+        Some(theme) => theme.shadows.apply(style),
+        None => dampen_core::ir::theme::ShadowScale::default().apply(style),
+    }
+}
+
 /// Merge two StyleProperties, with the second one taking precedence
 pub(super) fn merge_styles(
     base: dampen_core::ir::style::StyleProperties,
     override_style: &dampen_core::ir::style::StyleProperties,
 ) -> dampen_core::ir::style::StyleProperties {
-    use dampen_core::ir::style::StyleProperties;
+    use dampen_core::ir::style::{BorderSides, StyleProperties};
 
     StyleProperties {
         background: override_style.background.clone().or(base.background),
@@ -1306,6 +1613,9 @@ pub(super) fn merge_styles(
         shadow: override_style.shadow.or(base.shadow),
         opacity: override_style.opacity.or(base.opacity),
         transform: override_style.transform.clone().or(base.transform),
+        border_sides: BorderSides::cascade(base.border_sides, override_style.border_sides),
+        outline: override_style.outline.or(base.outline),
+        elevation: override_style.elevation.or(base.elevation),
     }
 }
 
@@ -1331,6 +1641,10 @@ pub(super) fn merge_layouts(
         align_x: override_layout.align_x.or(base.align_x),
         align_y: override_layout.align_y.or(base.align_y),
         direction: override_layout.direction.or(base.direction),
+        wrap: override_layout.wrap.or(base.wrap),
+        grow: override_layout.grow.or(base.grow),
+        aspect_ratio: override_layout.aspect_ratio.or(base.aspect_ratio),
+        overflow: override_layout.overflow.or(base.overflow),
         position: override_layout.position.or(base.position),
         top: override_layout.top.or(base.top),
         right: override_layout.right.or(base.right),
@@ -0,0 +1,67 @@
+//! Builder side of the subtree memoization of [`crate::memo`]
+
+use super::DampenWidgetBuilder;
+use crate::memo::{MEMO_ATTRIBUTE, hash_value, is_memoized};
+use dampen_core::expr::{BindingExpr, Expr, FieldAccessExpr};
+use dampen_core::ir::node::{AttributeValue, WidgetNode};
+use dampen_core::ir::structural_hash;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+impl<'a> DampenWidgetBuilder<'a> {
+    /// Subtree to build in place of a `memo="true"` node, with its bindings
+    /// replaced by their values
+    ///
+    /// Returns `None` without a memo cache, or when the subtree can't be
+    /// memoized and has to be built as is.
+    pub(super) fn memoized(&self, node: &WidgetNode) -> Option<Arc<WidgetNode>> {
+        let cache = self.memo?;
+        if !is_memoized(node) {
+            return None;
+        }
+
+        let hash = structural_hash(node);
+        let fields = cache.plan(hash, node)?;
+
+        // Fields are read like any binding, so loop variables and `system.`
+        // values are part of the key too
+        let mut hasher = DefaultHasher::new();
+        for path in fields.iter() {
+            path.hash(&mut hasher);
+            let expr = BindingExpr {
+                expr: Expr::FieldAccess(FieldAccessExpr { path: path.clone() }),
+                span: node.span,
+            };
+            let value = self.evaluate_binding_with_context(&expr).ok()?;
+            if !hash_value(&value, &mut hasher) {
+                return None;
+            }
+        }
+        let inputs = hasher.finish();
+
+        if let Some(resolved) = cache.get(hash, inputs) {
+            return Some(resolved);
+        }
+        let resolved = Arc::new(self.resolve_bindings(node));
+        cache.insert(hash, inputs, resolved.clone());
+        Some(resolved)
+    }
+
+    /// Copy of a subtree with its bindings evaluated to static values
+    fn resolve_bindings(&self, node: &WidgetNode) -> WidgetNode {
+        let mut resolved = node.clone();
+        resolved.attributes.remove(MEMO_ATTRIBUTE);
+        for attr in resolved.attributes.values_mut() {
+            if !matches!(attr, AttributeValue::Static(_)) {
+                *attr = AttributeValue::Static(self.evaluate_attribute(attr));
+            }
+        }
+        resolved.children = node
+            .children
+            .iter()
+            .map(|child| self.resolve_bindings(child))
+            .collect();
+        resolved
+    }
+}
@@ -62,17 +62,25 @@
 )]
 
 pub mod helpers;
+mod memoize;
 mod widgets;
 
 use crate::HandlerMessage;
+use crate::diagnostics::BuildDiagnostics;
+use crate::inspect::HitTest;
+use crate::memo::MemoCache;
+use crate::profiler::RenderProfiler;
 use dampen_core::binding::{BindingValue, UiBindable};
 use dampen_core::handler::HandlerRegistry;
 use dampen_core::ir::WidgetKind;
+use dampen_core::ir::layout::Breakpoints;
 use dampen_core::ir::node::WidgetNode;
 use dampen_core::ir::theme::StyleClass;
 use dampen_core::state::AppState;
 use dampen_core::state::ThemeContext;
+use dampen_core::state::{DragTracker, WidgetStateTracker};
 use iced::{Element, Renderer, Theme};
+use std::borrow::Cow;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
@@ -146,12 +154,45 @@ pub struct DampenWidgetBuilder<'a> {
     /// Optional theme context for theming support
     pub(super) theme_context: Option<&'a ThemeContext>,
 
+    /// Optional tracker recording scroll offsets and focus for hot-reload restoration
+    pub(super) widget_state: Option<&'a WidgetStateTracker>,
+
+    /// Optional tracker for the drag-and-drop gesture of `draggable` widgets
+    pub(super) drag: Option<&'a DragTracker>,
+
+    /// Optional profiler measuring build time per widget
+    pub(super) profiler: Option<&'a RenderProfiler>,
+
+    /// Optional collector of the problems found while building
+    pub(super) diagnostics: Option<&'a BuildDiagnostics>,
+
+    /// Optional cache of the `memo="true"` subtrees
+    pub(super) memo: Option<&'a MemoCache>,
+
+    /// Optional hit test finding the widget under the cursor
+    pub(super) hit_test: Option<&'a HitTest>,
+
+    /// Width of the viewport the breakpoint attributes are resolved against,
+    /// the size stored in `dampen_core::viewport` when `None`
+    pub(super) viewport_width: Option<f32>,
+
+    /// Breakpoints declared by the document, the fixed ones when `None`
+    pub(super) breakpoints: Option<&'a Breakpoints>,
+
     /// Factory function to create messages from handler names
     pub(super) message_factory: Rc<dyn Fn(&str, Option<String>) -> HandlerMessage + 'a>,
 
     /// Binding context stack for `<for>` loop variables
     /// Each context maps variable names to their BindingValues
     pub(super) binding_context: RefCell<Vec<HashMap<String, BindingValue>>>,
+
+    /// Sub-model path set by the nearest `model="{...}"` container
+    /// Model bindings are resolved relative to it
+    pub(super) model_scope: RefCell<Vec<String>>,
+
+    /// Theme set by the nearest `theme="..."` override, the active theme of
+    /// `theme_context` when `None`
+    pub(super) theme_scope: RefCell<Option<&'a dampen_core::ir::theme::Theme>>,
 }
 
 impl<'a> DampenWidgetBuilder<'a> {
@@ -197,10 +238,20 @@ impl<'a> DampenWidgetBuilder<'a> {
             handler_registry,
             style_classes: Some(&document.style_classes),
             theme_context: None,
+            widget_state: None,
+            drag: None,
+            profiler: None,
+            diagnostics: None,
+            memo: None,
+            hit_test: None,
+            viewport_width: None,
+            breakpoints: Some(&document.breakpoints),
             message_factory: Rc::new(|name, value| {
                 HandlerMessage::Handler(name.to_string(), value)
             }),
             binding_context: RefCell::new(Vec::new()),
+            model_scope: RefCell::new(Vec::new()),
+            theme_scope: RefCell::new(None),
         }
     }
 
@@ -291,6 +342,8 @@ impl<'a> DampenWidgetBuilder<'a> {
         }
 
         builder
+            .with_widget_state(&app_state.widget_state)
+            .with_drag_tracker(&app_state.drag)
     }
 }
 
@@ -354,8 +407,18 @@ impl<'a> DampenWidgetBuilder<'a> {
             handler_registry,
             style_classes: None,
             theme_context: None,
+            widget_state: None,
+            drag: None,
+            profiler: None,
+            diagnostics: None,
+            memo: None,
+            hit_test: None,
+            viewport_width: None,
+            breakpoints: None,
             message_factory: Rc::new(message_factory),
             binding_context: RefCell::new(Vec::new()),
+            model_scope: RefCell::new(Vec::new()),
+            theme_scope: RefCell::new(None),
         }
     }
 
@@ -436,14 +499,119 @@ impl<'a> DampenWidgetBuilder<'a> {
         self
     }
 
+    /// Set the widget state tracker for hot-reload state preservation
+    ///
+    /// When a tracker is provided, scrollables and text inputs with an `id`
+    /// record their scroll offset and focus into it, so the state can be
+    /// restored with [`restore_widget_state`](crate::widget_state::restore_widget_state)
+    /// after the document is reloaded.
+    ///
+    /// # Arguments
+    ///
+    /// * `widget_state` - Reference to the tracker, usually `AppState::widget_state`
+    pub fn with_widget_state(mut self, widget_state: &'a WidgetStateTracker) -> Self {
+        self.widget_state = Some(widget_state);
+        self
+    }
+
+    /// Set the drag tracker for `draggable` widgets
+    ///
+    /// Widgets marked `draggable="true"` inside a `<for>` loop are wrapped in a
+    /// [`DragArea`](crate::drag::DragArea) recording the gesture into this tracker.
+    /// Without a tracker, `draggable` is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `drag` - Reference to the tracker, usually `AppState::drag`
+    pub fn with_drag_tracker(mut self, drag: &'a DragTracker) -> Self {
+        self.drag = Some(drag);
+        self
+    }
+
+    /// Attach a render profiler
+    ///
+    /// When a profiler is attached, every call to [`build()`](Self::build) is
+    /// measured as one frame: binding evaluation, style resolution and widget
+    /// construction are timed per widget, and the slowest widgets are reported
+    /// when the frame exceeds the profiler's budget.
+    ///
+    /// # Arguments
+    ///
+    /// * `profiler` - Reference to a profiler kept across `view()` calls
+    pub fn with_profiler(mut self, profiler: &'a RenderProfiler) -> Self {
+        self.profiler = Some(profiler);
+        self
+    }
+
+    /// Attach a diagnostics collector
+    ///
+    /// When a collector is attached, every call to [`build()`](Self::build)
+    /// records why widgets fell back to a placeholder: failed bindings, events
+    /// naming unregistered handlers, and attributes the widget cannot use.
+    ///
+    /// # Arguments
+    ///
+    /// * `diagnostics` - Reference to a collector kept across `view()` calls
+    pub fn with_diagnostics(mut self, diagnostics: &'a BuildDiagnostics) -> Self {
+        self.diagnostics = Some(diagnostics);
+        self
+    }
+
+    /// Attach a memo cache
+    ///
+    /// When a cache is attached, subtrees marked `memo="true"` are evaluated
+    /// once and reused by the following builds until the values they bind
+    /// change (see [`crate::memo`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `memo` - Reference to a cache kept across `view()` calls
+    pub fn with_memo_cache(mut self, memo: &'a MemoCache) -> Self {
+        self.memo = Some(memo);
+        self
+    }
+
+    /// Attach a hit test
+    ///
+    /// When a hit test is attached, every widget is wrapped so that the widget
+    /// under the cursor can be found, highlighted and described (see
+    /// [`crate::inspect`]). Meant for development tools only.
+    ///
+    /// # Arguments
+    ///
+    /// * `hit_test` - Reference to a hit test kept across `view()` calls
+    pub fn with_hit_test(mut self, hit_test: &'a HitTest) -> Self {
+        self.hit_test = Some(hit_test);
+        self
+    }
+
+    /// Set the viewport width
+    ///
+    /// The breakpoint-prefixed attributes of each widget (`mobile-spacing`)
+    /// replace its base attributes when the width is in that breakpoint (see
+    /// [`dampen_core::viewport`]). Without it, the window size stored by the
+    /// runtime is used, and no breakpoint applies before one is stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Width of the viewport, in logical pixels
+    pub fn with_viewport_width(mut self, width: f32) -> Self {
+        self.viewport_width = Some(width);
+        self
+    }
+
     /// Build the widget tree and return an Iced Element
     ///
     /// This is the main entry point that processes the entire widget tree,
-    /// evaluates all bindings, connects events, and applies styles.
+    /// evaluates all bindings, connects events, and applies styles. The
+    /// `<float layer="...">` portals of the document are stacked over the tree
+    /// in their layer (see [`crate::layers`]).
     ///
     /// # Returns
     ///
-    /// An Iced `Element` ready to be used in your application's view
+    /// An Iced `Element` ready to be used in your application's view. Problems
+    /// found while building are available from the collector attached with
+    /// [`with_diagnostics()`](Self::with_diagnostics).
     ///
     /// # Type Requirements
     ///
@@ -461,7 +629,27 @@ impl<'a> DampenWidgetBuilder<'a> {
     where
         HandlerMessage: Clone + 'static,
     {
-        self.build_widget(self.node)
+        if let Some(diagnostics) = self.diagnostics {
+            diagnostics.begin_build();
+        }
+        if let Some(memo) = self.memo {
+            memo.begin_build();
+        }
+        if let Some(breakpoints) = self.breakpoints {
+            dampen_core::viewport::watch(breakpoints);
+        }
+        if let Some(theme_context) = self.theme_context {
+            dampen_core::env::set_theme(theme_context.active_name());
+        }
+
+        let Some(profiler) = self.profiler else {
+            return self.wrap_portals(self.build_widget(self.node));
+        };
+
+        profiler.begin_frame();
+        let element = self.wrap_portals(self.build_widget(self.node));
+        profiler.end_frame();
+        element
     }
 
     /// Recursively build a widget from a node
@@ -485,8 +673,41 @@ impl<'a> DampenWidgetBuilder<'a> {
     {
         #[cfg(debug_assertions)]
         eprintln!("[DampenWidgetBuilder] Building widget: {:?}", node.kind);
-        match node.kind {
+
+        // Breakpoint attributes are resolved here rather than in the document,
+        // so a resize only renders the view again
+        if let Some(breakpoint) = self.breakpoint()
+            && let Cow::Owned(resolved) = node.for_breakpoint(breakpoint)
+        {
+            return self.build_widget(&resolved);
+        }
+
+        // A memoized subtree is built from its cached, already evaluated copy
+        if let Some(resolved) = self.memoized(node) {
+            return self.build_widget(&resolved);
+        }
+
+        // Layout and style attributes bound to the model are parsed once evaluated
+        if let Some(resolved) = self.resolve_visual_bindings(node) {
+            return self.build_widget(&resolved);
+        }
+
+        if let Some(profiler) = self.profiler {
+            profiler.enter_widget(node);
+        }
+
+        // A `model="{...}"` container scopes its own bindings and its subtree
+        let outer_scope = self.enter_model_scope(node);
+        // So does a `theme="..."` override for the theme
+        let outer_theme = self.enter_theme_scope(node);
+
+        self.check_handlers(node);
+
+        let element = match node.kind {
             WidgetKind::Text => self.build_text(node),
+            // Spans are only valid inside <text>; render a stray one as plain text
+            WidgetKind::Span => self.build_text(node),
+            WidgetKind::Markdown => self.build_markdown(node),
             WidgetKind::Button => self.build_button(node),
             WidgetKind::Column => self.build_column(node),
             WidgetKind::Row => self.build_row(node),
@@ -506,8 +727,11 @@ impl<'a> DampenWidgetBuilder<'a> {
             WidgetKind::ComboBox => self.build_combo_box(node),
             WidgetKind::ProgressBar => self.build_progress_bar(node),
             WidgetKind::Tooltip => self.build_tooltip(node),
+            WidgetKind::TooltipContent => self.build_container(node),
             WidgetKind::Grid => self.build_grid(node),
             WidgetKind::Canvas => self.build_canvas(node),
+            WidgetKind::Icon => self.build_icon(node),
+            WidgetKind::Chart => self.build_chart(node),
             WidgetKind::Float => self.build_float(node),
             WidgetKind::For => self.build_for(node),
             WidgetKind::If => self.build_if(node),
@@ -519,8 +743,13 @@ impl<'a> DampenWidgetBuilder<'a> {
             WidgetKind::MenuSeparator => self.build_menu_separator(node),
             WidgetKind::ContextMenu => self.build_context_menu(node),
             WidgetKind::Radio => self.build_radio(node),
+            WidgetKind::RadioGroup => self.build_radio_group(node),
+            WidgetKind::NumberInput => self.build_number_input(node),
+            WidgetKind::CodeEditor => self.build_code_editor(node),
+            WidgetKind::Skeleton => self.build_skeleton(node),
             WidgetKind::DataTable => self.build_data_table(node),
             WidgetKind::TreeView => self.build_tree_view(node),
+            WidgetKind::Tree => self.build_tree(node),
             WidgetKind::TreeNode => {
                 // TreeNode is handled within build_tree_view, shouldn't appear as top-level
                 iced::widget::column(Vec::new()).into()
@@ -535,7 +764,34 @@ impl<'a> DampenWidgetBuilder<'a> {
             | WidgetKind::CanvasCircle
             | WidgetKind::CanvasLine
             | WidgetKind::CanvasText
-            | WidgetKind::CanvasGroup => iced::widget::column(Vec::new()).into(),
+            | WidgetKind::CanvasGroup
+            | WidgetKind::CanvasPath
+            | WidgetKind::CanvasArc
+            | WidgetKind::CanvasBezier => iced::widget::column(Vec::new()).into(),
+        };
+
+        let element = self.wrap_context_menu_event(node, element);
+        let element = self.wrap_draggable(node, element);
+        let element = self.wrap_anchored_floats(node, element);
+        let element = self.wrap_inspectable(node, element);
+
+        let element = match outer_theme {
+            Some(outer_theme) => {
+                let element = self.wrap_theme(element);
+                self.theme_scope.replace(outer_theme);
+                element
+            }
+            None => element,
+        };
+
+        if let Some(outer_scope) = outer_scope {
+            self.model_scope.replace(outer_scope);
         }
+
+        if let Some(profiler) = self.profiler {
+            profiler.exit_widget();
+        }
+
+        element
     }
 }
@@ -2,7 +2,9 @@
 
 use crate::HandlerMessage;
 use crate::builder::DampenWidgetBuilder;
-use crate::builder::helpers::{resolve_boolean_attribute, resolve_handler_param};
+use crate::builder::helpers::{
+    resolve_boolean_attribute, resolve_event_params, resolve_handler_param,
+};
 use dampen_core::ir::node::WidgetNode;
 use iced::{Element, Renderer, Theme};
 
@@ -88,7 +90,9 @@ impl<'a> DampenWidgetBuilder<'a> {
                 let handler_name = event_binding.handler.clone();
 
                 // Evaluate parameter if present
-                let param_value = if let Some(param_expr) = &event_binding.param {
+                let param_value = if !event_binding.params.is_empty() {
+                    resolve_event_params(self, &event_binding.params)
+                } else if let Some(param_expr) = &event_binding.param {
                     match resolve_handler_param(self, param_expr) {
                         Ok(value) => {
                             #[cfg(debug_assertions)]
@@ -4,8 +4,9 @@ use crate::HandlerMessage;
 use crate::builder::DampenWidgetBuilder;
 use crate::canvas::events::{CanvasEventHandlers, CanvasHandlerNames};
 use crate::canvas::{
-    CanvasContent, CanvasProgramWrapper, CanvasShape, CircleShape, CustomProgramContainer,
-    DeclarativeProgram, GroupShape, LineShape, RectShape, TextShape, Transform,
+    ArcShape, BezierShape, CanvasContent, CanvasProgramWrapper, CanvasShape, CircleShape,
+    CustomProgramContainer, DeclarativeProgram, GroupShape, LineShape, PathShape, RectShape,
+    TextShape, Transform,
 };
 use dampen_core::binding::BindingValue;
 use dampen_core::ir::WidgetKind;
@@ -108,9 +109,7 @@ impl<'a> DampenWidgetBuilder<'a> {
                 on_release,
             },
             msg_factory: |name, event| {
-                // Serialize event to JSON string
-                let json = serde_json::to_string(&event).unwrap_or_default();
-                HandlerMessage::Handler(name.to_string(), Some(json))
+                HandlerMessage::Handler(name.to_string(), Some(event.to_payload()))
             },
         })
     }
@@ -222,6 +221,61 @@ impl<'a> DampenWidgetBuilder<'a> {
                     children,
                 }))
             }
+            WidgetKind::CanvasPath => {
+                let data = node
+                    .attributes
+                    .get("d")
+                    .map(|a| self.evaluate_attribute(a))
+                    .unwrap_or_default();
+                let commands = match dampen_core::parser::canvas::parse_path_data(&data) {
+                    Ok(commands) => commands,
+                    Err(_e) => {
+                        #[cfg(debug_assertions)]
+                        eprintln!("[DampenWidgetBuilder] Invalid path data '{}': {}", data, _e);
+                        Vec::new()
+                    }
+                };
+
+                Some(CanvasShape::Path(PathShape {
+                    commands,
+                    fill: self.resolve_color(node, "fill"),
+                    stroke: self.resolve_color(node, "stroke"),
+                    stroke_width: self.resolve_f32(node, "stroke_width", 1.0),
+                }))
+            }
+            WidgetKind::CanvasArc => Some(CanvasShape::Arc(ArcShape {
+                cx: self.resolve_f32(node, "cx", 0.0),
+                cy: self.resolve_f32(node, "cy", 0.0),
+                radius: self.resolve_f32(node, "radius", 0.0),
+                start_angle: self.resolve_f32(node, "start_angle", 0.0),
+                end_angle: self.resolve_f32(node, "end_angle", 0.0),
+                fill: self.resolve_color(node, "fill"),
+                stroke: self.resolve_color(node, "stroke"),
+                stroke_width: self.resolve_f32(node, "stroke_width", 1.0),
+            })),
+            WidgetKind::CanvasBezier => {
+                // Cubic when the second control point is set, quadratic otherwise
+                let control2 = match (node.attributes.get("cx2"), node.attributes.get("cy2")) {
+                    (Some(_), Some(_)) => Some((
+                        self.resolve_f32(node, "cx2", 0.0),
+                        self.resolve_f32(node, "cy2", 0.0),
+                    )),
+                    _ => None,
+                };
+
+                Some(CanvasShape::Bezier(BezierShape {
+                    x1: self.resolve_f32(node, "x1", 0.0),
+                    y1: self.resolve_f32(node, "y1", 0.0),
+                    cx1: self.resolve_f32(node, "cx1", 0.0),
+                    cy1: self.resolve_f32(node, "cy1", 0.0),
+                    control2,
+                    x2: self.resolve_f32(node, "x2", 0.0),
+                    y2: self.resolve_f32(node, "y2", 0.0),
+                    fill: self.resolve_color(node, "fill"),
+                    stroke: self.resolve_color(node, "stroke"),
+                    stroke_width: self.resolve_f32(node, "stroke_width", 1.0),
+                }))
+            }
             WidgetKind::For => {
                 // T049: Add support for for-each loops inside canvas
                 let mut shapes = Vec::new();
@@ -0,0 +1,143 @@
+//! Chart widget builder
+
+use crate::HandlerMessage;
+use crate::builder::DampenWidgetBuilder;
+use crate::canvas::{ChartKind, ChartPoint, ChartProgram};
+use crate::diagnostics::BuildDiagnosticKind;
+use dampen_core::binding::BindingValue;
+use dampen_core::expr::evaluate_binding_expr_with_shared;
+use dampen_core::ir::node::{AttributeValue, WidgetNode};
+use iced::{Color, Element, Length, Renderer, Theme};
+
+impl<'a> DampenWidgetBuilder<'a> {
+    /// Build a Chart widget from a node
+    pub(in crate::builder) fn build_chart(
+        &self,
+        node: &WidgetNode,
+    ) -> Element<'a, HandlerMessage, Theme, Renderer>
+    where
+        HandlerMessage: Clone + 'static,
+    {
+        let kind = node
+            .attributes
+            .get("type")
+            .map(|attr| self.evaluate_attribute(attr))
+            .and_then(|value| ChartKind::parse(&value))
+            .unwrap_or_default();
+
+        let x_field = node.attributes.get("x").map(|a| self.evaluate_attribute(a));
+        let y_field = node.attributes.get("y").map(|a| self.evaluate_attribute(a));
+
+        let points = self
+            .resolve_chart_data(node)
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let label = match &x_field {
+                    Some(field) => item
+                        .get_field(field)
+                        .map(|value| value.to_display_string())
+                        .unwrap_or_default(),
+                    None => index.to_string(),
+                };
+                let value = match &y_field {
+                    Some(field) => item.get_field(field).map_or(0.0, |v| chart_value(&v)),
+                    None => chart_value(&item),
+                };
+                ChartPoint::new(label, value)
+            })
+            .collect();
+
+        let color = node.attributes.get("color").and_then(|attr| {
+            let value = self.evaluate_attribute(attr);
+            crate::builder::helpers::parse_color(&value).map(|c| Color {
+                r: c.r,
+                g: c.g,
+                b: c.b,
+                a: c.a,
+            })
+        });
+        let series = node
+            .attributes
+            .get("label")
+            .map(|a| self.evaluate_attribute(a))
+            .or_else(|| y_field.clone());
+
+        let program = ChartProgram::new(kind, points)
+            .color(color)
+            .series(series)
+            .show_axes(crate::builder::helpers::resolve_boolean_attribute(
+                self,
+                node,
+                "show_axes",
+                true,
+            ))
+            .show_legend(crate::builder::helpers::resolve_boolean_attribute(
+                self,
+                node,
+                "show_legend",
+                false,
+            ));
+
+        let width = node
+            .attributes
+            .get("width")
+            .and_then(|attr| crate::builder::helpers::parse_length(&self.evaluate_attribute(attr)))
+            .unwrap_or(Length::Fill);
+        let height = node
+            .attributes
+            .get("height")
+            .and_then(|attr| crate::builder::helpers::parse_length(&self.evaluate_attribute(attr)))
+            .unwrap_or(Length::Fixed(300.0));
+
+        iced::widget::canvas(program)
+            .width(width)
+            .height(height)
+            .into()
+    }
+
+    /// Evaluate the `data` binding of a chart into its items
+    fn resolve_chart_data(&self, node: &WidgetNode) -> Vec<BindingValue> {
+        let Some(AttributeValue::Binding(expr)) = node.attributes.get("data") else {
+            return Vec::new();
+        };
+
+        let binding_result = if let Some(ctx_value) = self.resolve_from_context(expr) {
+            Ok(ctx_value)
+        } else {
+            evaluate_binding_expr_with_shared(expr, &self.binding_model(), self.shared_context)
+        };
+
+        match binding_result {
+            Ok(BindingValue::List(items)) => items,
+            Ok(_) => {
+                #[cfg(debug_assertions)]
+                eprintln!("[DampenWidgetBuilder] Chart 'data' is not a list");
+                self.report(
+                    BuildDiagnosticKind::UnsupportedAttribute,
+                    node.attribute_span("data"),
+                    || "<chart> 'data' must be a list".to_string(),
+                );
+                Vec::new()
+            }
+            Err(e) => {
+                #[cfg(debug_assertions)]
+                eprintln!("[DampenWidgetBuilder] Chart evaluation error: {}", e);
+                self.report(BuildDiagnosticKind::BindingError, e.span, || {
+                    e.message.clone()
+                });
+                Vec::new()
+            }
+        }
+    }
+}
+
+/// Numeric value of a chart item, or `0.0` if it is not a number
+fn chart_value(value: &BindingValue) -> f32 {
+    match value {
+        BindingValue::Integer(i) => *i as f32,
+        BindingValue::Float(f) => *f as f32,
+        BindingValue::String(s) => s.trim().parse().unwrap_or(0.0),
+        _ => 0.0,
+    }
+}
@@ -2,7 +2,9 @@
 
 use crate::HandlerMessage;
 use crate::builder::DampenWidgetBuilder;
-use crate::builder::helpers::{resolve_boolean_attribute, resolve_handler_param};
+use crate::builder::helpers::{
+    resolve_boolean_attribute, resolve_event_params, resolve_handler_param,
+};
 use dampen_core::ir::node::WidgetNode;
 use dampen_core::ir::style::StyleProperties;
 use iced::{Element, Renderer, Theme};
@@ -172,7 +174,9 @@ impl<'a> DampenWidgetBuilder<'a> {
                 let handler_name = event_binding.handler.clone();
 
                 // Evaluate parameter if present, otherwise use toggle state
-                let param_value = if let Some(param_expr) = &event_binding.param {
+                let param_value = if !event_binding.params.is_empty() {
+                    resolve_event_params(self, &event_binding.params)
+                } else if let Some(param_expr) = &event_binding.param {
                     match resolve_handler_param(self, param_expr) {
                         Ok(value) => {
                             #[cfg(debug_assertions)]
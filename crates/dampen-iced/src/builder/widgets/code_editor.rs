@@ -0,0 +1,55 @@
+//! Code editor widget builder
+
+use crate::HandlerMessage;
+use crate::builder::DampenWidgetBuilder;
+use crate::builder::helpers::{parse_length, resolve_boolean_attribute};
+use crate::code_editor::CodeEditor;
+use dampen_core::ir::EventKind;
+use dampen_core::ir::node::WidgetNode;
+use iced::{Element, Renderer, Theme};
+
+impl<'a> DampenWidgetBuilder<'a> {
+    /// Build a code editor from Dampen XML definition
+    ///
+    /// Supports the following attributes:
+    /// - `value`: Code shown, usually bound to a model field
+    /// - `language`: Language highlighted, e.g. `rust` (default: plain text)
+    /// - `size`: Text size (default 14)
+    /// - `line_numbers`: Whether line numbers are shown (default true)
+    /// - `height`: Height, the code scrolls when longer (default: fits the code)
+    /// - `on_change`: Handler receiving the whole text after each edit
+    ///
+    /// Without `on_change`, the editor is read-only.
+    pub(in crate::builder) fn build_code_editor(
+        &self,
+        node: &WidgetNode,
+    ) -> Element<'a, HandlerMessage, Theme, Renderer> {
+        let attribute = |name: &str| {
+            node.attributes
+                .get(name)
+                .map(|attr| self.evaluate_attribute(attr))
+        };
+
+        let mut editor = CodeEditor::new(attribute("value").unwrap_or_default())
+            .line_numbers(resolve_boolean_attribute(self, node, "line_numbers", true));
+        if let Some(language) = attribute("language") {
+            editor = editor.language(&language);
+        }
+        if let Some(size) = attribute("size").and_then(|size| size.trim().parse().ok()) {
+            editor = editor.size(size);
+        }
+        if let Some(height) = attribute("height").and_then(|height| parse_length(&height)) {
+            editor = editor.height(height);
+        }
+
+        if let Some(event) = node.events.iter().find(|e| e.event == EventKind::Change)
+            && self.handler_registry.is_some()
+        {
+            let message_factory = self.message_factory.clone();
+            let handler = event.handler.clone();
+            editor = editor.on_change(move |text| message_factory(&handler, Some(text)));
+        }
+
+        self.apply_style_layout(editor, node)
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::HandlerMessage;
 use crate::builder::DampenWidgetBuilder;
+use dampen_core::SelectOption;
 use dampen_core::ir::node::WidgetNode;
 use iced::{Element, Renderer, Theme};
 
@@ -10,39 +11,19 @@ impl<'a> DampenWidgetBuilder<'a> {
     ///
     /// ComboBox is implemented using pick_list as a dropdown selector.
     /// Supports the following attributes:
-    /// - `options`: Comma-separated list of options
-    /// - `selected`: String binding for selected option
+    /// - `options`: Comma-separated list of options, or a binding to a collection
+    /// - `display`: Text shown for each bound option, e.g. `{item.name}`
+    /// - `value`: Value dispatched for each bound option, e.g. `{item.code}`
+    /// - `selected`: Binding to the value of the selected option
     /// - `placeholder`: Placeholder text when nothing is selected
-    /// - `on_select`: Handler called on selection with selected value
+    /// - `on_select`: Handler called on selection with the option's value
     ///
     /// Events: Select (sends HandlerMessage::Handler(name, Some(selected_value)))
     pub(in crate::builder) fn build_combo_box(
         &self,
         node: &WidgetNode,
     ) -> Element<'a, HandlerMessage, Theme, Renderer> {
-        let options_str = node
-            .attributes
-            .get("options")
-            .map(|attr| self.evaluate_attribute(attr))
-            .unwrap_or_default();
-
-        let options: Vec<String> = options_str
-            .split(',')
-            .map(|s| s.trim().to_string())
-            .filter(|s| !s.is_empty())
-            .collect();
-
-        let selected_str = node
-            .attributes
-            .get("selected")
-            .map(|attr| self.evaluate_attribute(attr))
-            .unwrap_or_default();
-
-        let selected = if selected_str.is_empty() {
-            None
-        } else {
-            options.iter().find(|o| *o == &selected_str).cloned()
-        };
+        let (options, selected) = self.resolve_select_options(node);
 
         #[cfg(debug_assertions)]
         eprintln!(
@@ -75,8 +56,8 @@ impl<'a> DampenWidgetBuilder<'a> {
                     "[DampenWidgetBuilder] ComboBox: Attaching on_select with handler '{}'",
                     handler_name
                 );
-                iced::widget::pick_list(options, selected, move |selected_value| {
-                    HandlerMessage::Handler(handler_name.clone(), Some(selected_value))
+                iced::widget::pick_list(options, selected, move |option: SelectOption| {
+                    HandlerMessage::Handler(handler_name.clone(), Some(option.value))
                 })
             } else {
                 #[cfg(debug_assertions)]
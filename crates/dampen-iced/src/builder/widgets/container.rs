@@ -2,6 +2,8 @@
 
 use crate::HandlerMessage;
 use crate::builder::DampenWidgetBuilder;
+use crate::builder::helpers::resolve_boolean_attribute;
+use crate::skeleton::{DEFAULT_LINES, Skeleton};
 use dampen_core::ir::node::WidgetNode;
 use iced::{Element, Renderer, Theme};
 
@@ -13,6 +15,11 @@ impl<'a> DampenWidgetBuilder<'a> {
     where
         HandlerMessage: Clone + 'static,
     {
+        // While loading, the children are replaced by a placeholder
+        if resolve_boolean_attribute(self, node, "loading", false) {
+            return self.apply_style_layout(Skeleton::new(DEFAULT_LINES), node);
+        }
+
         // Container can have multiple children - wrap them in a column if needed
         match node.children.len() {
             0 => {
@@ -1,5 +1,6 @@
 use crate::HandlerMessage;
 use crate::builder::DampenWidgetBuilder;
+use crate::diagnostics::BuildDiagnosticKind;
 use dampen_core::binding::BindingValue;
 use dampen_core::expr::evaluate_binding_expr_with_shared;
 use dampen_core::ir::node::{AttributeValue, WidgetKind, WidgetNode};
@@ -21,7 +22,11 @@ impl<'a> DampenWidgetBuilder<'a> {
                 let binding_result = if let Some(ctx_value) = self.resolve_from_context(expr) {
                     Ok(ctx_value)
                 } else {
-                    evaluate_binding_expr_with_shared(expr, self.model, self.shared_context)
+                    evaluate_binding_expr_with_shared(
+                        expr,
+                        &self.binding_model(),
+                        self.shared_context,
+                    )
                 };
 
                 match binding_result {
@@ -29,11 +34,19 @@ impl<'a> DampenWidgetBuilder<'a> {
                     Ok(_) => {
                         #[cfg(debug_assertions)]
                         eprintln!("[DampenWidgetBuilder] DataTable 'data' is not a list");
+                        self.report(
+                            BuildDiagnosticKind::UnsupportedAttribute,
+                            node.attribute_span("data"),
+                            || "<data_table> 'data' must be a list".to_string(),
+                        );
                         Vec::new()
                     }
                     Err(e) => {
                         #[cfg(debug_assertions)]
                         eprintln!("[DampenWidgetBuilder] DataTable evaluation error: {}", e);
+                        self.report(BuildDiagnosticKind::BindingError, e.span, || {
+                            e.message.clone()
+                        });
                         Vec::new()
                     }
                 }
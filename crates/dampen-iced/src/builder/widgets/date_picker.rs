@@ -5,6 +5,7 @@ use dampen_core::ir::node::WidgetNode;
 use iced::{Element, Renderer, Theme};
 use iced_aw::date_picker::Date;
 use iced_aw::widgets::date_picker::DatePicker;
+use std::fmt::Write;
 
 impl<'a> DampenWidgetBuilder<'a> {
     /// Build a date picker widget from Dampen XML definition
@@ -17,8 +18,14 @@ impl<'a> DampenWidgetBuilder<'a> {
     /// - `max_date`: Maximum selectable date (YYYY-MM-DD)
     ///
     /// Events:
+    /// - `on_change`: Called when a date is selected, with ISO date payload decoded with
+    ///   [`HandlerRegistry::register_with_number`](dampen_core::HandlerRegistry::register_with_number)
+    ///   into a `chrono::NaiveDate`. Takes precedence over `on_submit`.
     /// - `on_submit`: Called when a date is selected, with ISO date string payload
     /// - `on_cancel`: Called when the picker is dismissed without selection
+    /// - `on_open`: Called when the date button of a picker without child is pressed
+    ///
+    /// Without a child, the underlay is a button showing the date in `format`.
     pub(in crate::builder) fn build_date_picker(
         &self,
         node: &WidgetNode,
@@ -30,27 +37,26 @@ impl<'a> DampenWidgetBuilder<'a> {
             .map(|v| v == "true" || v == "1")
             .unwrap_or(false);
 
-        let date = if let Some(attr) = node.attributes.get("value") {
-            let val = self.evaluate_attribute(attr);
-            let format = node
-                .attributes
-                .get("format")
-                .map(|f| self.evaluate_attribute(f))
-                .unwrap_or_else(|| "%Y-%m-%d".to_string());
-            if let Ok(d) = NaiveDate::parse_from_str(&val, &format) {
-                Date::from(d)
-            } else {
-                Date::from(chrono::Local::now().naive_local().date())
-            }
-        } else {
-            Date::from(chrono::Local::now().naive_local().date())
-        };
+        let format = node
+            .attributes
+            .get("format")
+            .map(|f| self.evaluate_attribute(f))
+            .unwrap_or_else(|| "%Y-%m-%d".to_string());
+        let naive_date = node
+            .attributes
+            .get("value")
+            .map(|attr| self.evaluate_attribute(attr))
+            .and_then(|val| NaiveDate::parse_from_str(&val, &format).ok())
+            .unwrap_or_else(|| chrono::Local::now().naive_local().date());
+        let date = Date::from(naive_date);
 
-        let on_submit_handler = node
-            .events
-            .iter()
-            .find(|e| e.event == dampen_core::EventKind::Submit)
-            .map(|e| e.handler.clone());
+        let on_submit_handler = [
+            dampen_core::EventKind::Change,
+            dampen_core::EventKind::Submit,
+        ]
+        .iter()
+        .find_map(|kind| node.events.iter().find(|e| e.event == *kind))
+        .map(|e| e.handler.clone());
 
         let on_cancel_handler = node
             .events
@@ -76,7 +82,19 @@ impl<'a> DampenWidgetBuilder<'a> {
         let underlay = if let Some(c) = child {
             self.build_widget(c)
         } else {
-            iced::widget::text("DatePicker requires a child").into()
+            let on_open = node
+                .events
+                .iter()
+                .find(|e| e.event == dampen_core::EventKind::Open)
+                .map(|e| HandlerMessage::Handler(e.handler.clone(), None));
+            // An invalid format fails while writing, fall back to ISO
+            let mut label = String::new();
+            if write!(label, "{}", naive_date.format(&format)).is_err() {
+                label = naive_date.to_string();
+            }
+            iced::widget::button(iced::widget::text(label))
+                .on_press_maybe(on_open)
+                .into()
         };
 
         let picker = DatePicker::new(
@@ -0,0 +1,92 @@
+//! Draggable wrapper for `draggable="true"` widgets
+
+use crate::HandlerMessage;
+use crate::builder::DampenWidgetBuilder;
+use crate::builder::helpers::resolve_boolean_attribute;
+use crate::drag::DragArea;
+use dampen_core::binding::BindingValue;
+use dampen_core::handler::encode_event_args;
+use dampen_core::ir::EventKind;
+use dampen_core::ir::node::WidgetNode;
+use iced::{Element, Renderer, Theme};
+
+impl<'a> DampenWidgetBuilder<'a> {
+    /// Wrap a built widget in a [`DragArea`] if it is marked `draggable="true"`
+    ///
+    /// Draggable widgets must be repeated by a `<for>` loop: the loop `index` is the
+    /// position reported to the `on_drag_start`, `on_drag_over` and `on_drop` handlers.
+    /// Widgets are returned unchanged when no drag tracker is attached to the builder.
+    pub(in crate::builder) fn wrap_draggable(
+        &self,
+        node: &WidgetNode,
+        element: Element<'a, HandlerMessage, Theme, Renderer>,
+    ) -> Element<'a, HandlerMessage, Theme, Renderer> {
+        if !node.attributes.contains_key("draggable")
+            || !resolve_boolean_attribute(self, node, "draggable", false)
+        {
+            return element;
+        }
+
+        let Some(tracker) = self.drag else {
+            #[cfg(debug_assertions)]
+            eprintln!("[DampenWidgetBuilder] draggable ignored: no drag tracker attached");
+            return element;
+        };
+
+        let Some(index) = self.loop_index() else {
+            #[cfg(debug_assertions)]
+            eprintln!(
+                "[DampenWidgetBuilder] draggable ignored: {:?} at line {} is not inside a <for> loop",
+                node.kind, node.span.line
+            );
+            return element;
+        };
+
+        let mut area = DragArea::new(
+            element,
+            tracker.clone(),
+            (node.span.line, node.span.column),
+            index,
+        );
+
+        for event in &node.events {
+            match event.event {
+                EventKind::DragStart => {
+                    area = area.on_drag_start((self.message_factory)(
+                        &event.handler,
+                        Some(index.to_string()),
+                    ));
+                }
+                EventKind::DragOver => {
+                    area = area.on_drag_over((self.message_factory)(
+                        &event.handler,
+                        Some(index.to_string()),
+                    ));
+                }
+                EventKind::Drop => {
+                    let message_factory = self.message_factory.clone();
+                    let handler = event.handler.clone();
+                    area = area.on_drop(move |source, target| {
+                        let args = [source.to_string(), target.to_string()];
+                        message_factory(&handler, Some(encode_event_args(&args)))
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        area.into()
+    }
+
+    /// Index of the innermost `<for>` loop iteration, if any
+    fn loop_index(&self) -> Option<usize> {
+        self.binding_context
+            .borrow()
+            .iter()
+            .rev()
+            .find_map(|context| match context.get("index") {
+                Some(BindingValue::Integer(index)) => usize::try_from(*index).ok(),
+                _ => None,
+            })
+    }
+}
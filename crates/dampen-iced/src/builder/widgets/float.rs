@@ -2,9 +2,17 @@
 
 use crate::HandlerMessage;
 use crate::builder::DampenWidgetBuilder;
+use crate::layers::{Layers, portal};
+use crate::popover::Anchored;
+use dampen_core::ir::Placement;
+use dampen_core::ir::layer::{portal_floats, portal_layer};
 use dampen_core::ir::node::WidgetNode;
+use dampen_core::ir::placement::anchored_floats;
 use iced::{Element, Renderer, Theme};
 
+/// Default gap between an anchored float and its anchor, in pixels
+const DEFAULT_GAP: f32 = 4.0;
+
 impl<'a> DampenWidgetBuilder<'a> {
     pub(in crate::builder) fn build_float(
         &self,
@@ -13,11 +21,94 @@ impl<'a> DampenWidgetBuilder<'a> {
     where
         HandlerMessage: Clone + 'static,
     {
+        // Anchored floats are shown by their anchor, see `wrap_anchored_floats`,
+        // and portals over the window, see `wrap_portals`
+        if node.attributes.contains_key("anchor") || portal_layer(node).is_some() {
+            return iced::widget::column(Vec::new()).into();
+        }
+
         #[cfg(debug_assertions)]
         eprintln!("[DampenWidgetBuilder] Building float widget (placeholder)");
 
         // For now, render children in a column as a placeholder
         // In a full implementation, this would use absolute positioning
+        self.build_float_content(node)
+    }
+
+    /// Show the floats anchored to `node` next to its element
+    pub(in crate::builder) fn wrap_anchored_floats(
+        &self,
+        node: &WidgetNode,
+        element: Element<'a, HandlerMessage, Theme, Renderer>,
+    ) -> Element<'a, HandlerMessage, Theme, Renderer>
+    where
+        HandlerMessage: Clone + 'static,
+    {
+        let Some(id) = node.id.as_deref() else {
+            return element;
+        };
+
+        let floats: Vec<_> = anchored_floats(self.node, id)
+            .into_iter()
+            .filter(|float| self.is_float_shown(float))
+            .collect();
+        if floats.is_empty() {
+            return element;
+        }
+
+        let mut anchored = Anchored::new(element);
+        for float in floats {
+            let placement = float
+                .attributes
+                .get("placement")
+                .and_then(|attr| Placement::parse(&self.evaluate_attribute(attr)).ok())
+                .unwrap_or_default();
+            let gap = float
+                .attributes
+                .get("gap")
+                .and_then(|attr| self.evaluate_attribute(attr).trim().parse::<f32>().ok())
+                .unwrap_or(DEFAULT_GAP);
+
+            anchored = anchored.popover(self.build_float_content(float), placement, gap);
+        }
+        anchored.into()
+    }
+
+    /// Show the `<float layer="...">` portals of the document over `root`
+    pub(in crate::builder) fn wrap_portals(
+        &self,
+        root: Element<'a, HandlerMessage, Theme, Renderer>,
+    ) -> Element<'a, HandlerMessage, Theme, Renderer>
+    where
+        HandlerMessage: Clone + 'static,
+    {
+        let mut layers = Layers::new(root);
+        for (layer, float) in portal_floats(self.node) {
+            if !self.is_float_shown(float) {
+                continue;
+            }
+            let position = float
+                .attributes
+                .get("position")
+                .map(|attr| self.evaluate_attribute(attr))
+                .unwrap_or_default();
+            layers = layers.push(layer, portal(self.build_float_content(float), &position));
+        }
+        layers.into()
+    }
+
+    /// Whether a float is shown: unless `show` evaluates to false
+    fn is_float_shown(&self, float: &WidgetNode) -> bool {
+        float.attributes.get("show").is_none_or(|attr| {
+            let value = self.evaluate_attribute(attr);
+            value == "true" || value == "1"
+        })
+    }
+
+    fn build_float_content(&self, node: &WidgetNode) -> Element<'a, HandlerMessage, Theme, Renderer>
+    where
+        HandlerMessage: Clone + 'static,
+    {
         let children: Vec<_> = node
             .children
             .iter()
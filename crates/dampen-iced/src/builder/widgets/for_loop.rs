@@ -2,16 +2,20 @@
 
 use crate::HandlerMessage;
 use crate::builder::DampenWidgetBuilder;
+use crate::diagnostics::BuildDiagnosticKind;
 use dampen_core::binding::BindingValue;
 use dampen_core::expr::evaluate_binding_expr_with_shared;
 use dampen_core::ir::node::{AttributeValue, WidgetNode};
+use dampen_core::pagination::{page_range, parse_page};
 use iced::{Element, Renderer, Theme};
 
 impl<'a> DampenWidgetBuilder<'a> {
     /// Build a `<for>` loop widget
     ///
     /// Iterates over a collection and renders the child widgets for each item,
-    /// with the loop variable available in the binding context.
+    /// with the loop variable available in the binding context. With a
+    /// `page_size`, only the items of `page` are rendered (see
+    /// [`dampen_core::pagination`]).
     ///
     /// # Example XML
     ///
@@ -33,6 +37,11 @@ impl<'a> DampenWidgetBuilder<'a> {
             _ => {
                 #[cfg(debug_assertions)]
                 eprintln!("[DampenWidgetBuilder] For loop missing 'each' attribute");
+                self.report(
+                    BuildDiagnosticKind::UnsupportedAttribute,
+                    node.attribute_span("each"),
+                    || "<for> needs a static 'each' attribute naming the loop variable".to_string(),
+                );
                 return iced::widget::column(vec![]).into();
             }
         };
@@ -44,7 +53,11 @@ impl<'a> DampenWidgetBuilder<'a> {
                 let binding_result = if let Some(ctx_value) = self.resolve_from_context(expr) {
                     Ok(ctx_value)
                 } else {
-                    evaluate_binding_expr_with_shared(expr, self.model, self.shared_context)
+                    evaluate_binding_expr_with_shared(
+                        expr,
+                        &self.binding_model(),
+                        self.shared_context,
+                    )
                 };
 
                 match binding_result {
@@ -55,11 +68,24 @@ impl<'a> DampenWidgetBuilder<'a> {
                             "[DampenWidgetBuilder] For loop 'in' is not a list: {:?}",
                             other
                         );
+                        self.report(
+                            BuildDiagnosticKind::UnsupportedAttribute,
+                            node.attribute_span("in"),
+                            || {
+                                format!(
+                                    "<for> 'in' must be a list, got '{}'",
+                                    other.to_display_string()
+                                )
+                            },
+                        );
                         return iced::widget::column(vec![]).into();
                     }
                     Err(e) => {
                         #[cfg(debug_assertions)]
                         eprintln!("[DampenWidgetBuilder] For loop evaluation error: {}", e);
+                        self.report(BuildDiagnosticKind::BindingError, e.span, || {
+                            e.message.clone()
+                        });
                         return iced::widget::column(vec![]).into();
                     }
                 }
@@ -67,6 +93,11 @@ impl<'a> DampenWidgetBuilder<'a> {
             _ => {
                 #[cfg(debug_assertions)]
                 eprintln!("[DampenWidgetBuilder] For loop missing 'in' binding");
+                self.report(
+                    BuildDiagnosticKind::UnsupportedAttribute,
+                    node.attribute_span("in"),
+                    || "<for> needs an 'in' binding to a list".to_string(),
+                );
                 return iced::widget::column(vec![]).into();
             }
         };
@@ -78,10 +109,26 @@ impl<'a> DampenWidgetBuilder<'a> {
             var_name
         );
 
-        // Render children for each item
+        let page_attribute = |name: &str| {
+            node.attributes
+                .get(name)
+                .map_or(0, |attr| parse_page(&self.evaluate_attribute(attr)))
+        };
+        let page = page_range(
+            collection_values.len(),
+            page_attribute("page"),
+            page_attribute("page_size"),
+        );
+
+        // Render children for each item of the page
         let mut rendered_children = Vec::new();
 
-        for (index, item_value) in collection_values.iter().enumerate() {
+        for (index, item_value) in collection_values
+            .iter()
+            .enumerate()
+            .skip(page.start)
+            .take(page.len())
+        {
             // Push context
             self.push_context(&var_name, item_value.clone());
             self.push_context("index", BindingValue::Integer(index as i64));
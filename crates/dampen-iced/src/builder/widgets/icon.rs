@@ -0,0 +1,56 @@
+//! Icon widget builder
+
+use crate::HandlerMessage;
+use crate::builder::DampenWidgetBuilder;
+use crate::diagnostics::BuildDiagnosticKind;
+use dampen_core::ir::node::WidgetNode;
+use iced::{Element, Renderer, Theme};
+
+/// Default icon size in pixels
+const DEFAULT_ICON_SIZE: f32 = 16.0;
+
+impl<'a> DampenWidgetBuilder<'a> {
+    pub(in crate::builder) fn build_icon(
+        &self,
+        node: &WidgetNode,
+    ) -> Element<'a, HandlerMessage, Theme, Renderer>
+    where
+        HandlerMessage: Clone + 'static,
+    {
+        let name = node
+            .attributes
+            .get("name")
+            .map(|attr| self.evaluate_attribute(attr))
+            .unwrap_or_default();
+
+        if !dampen_core::icons::contains(name.trim()) {
+            #[cfg(debug_assertions)]
+            eprintln!("[DampenWidgetBuilder] Unknown icon '{}'", name);
+            self.report(
+                BuildDiagnosticKind::UnsupportedAttribute,
+                node.attribute_span("name"),
+                || format!("Unknown icon '{}'", name),
+            );
+            return iced::widget::text(format!("[icon: {}]", name)).into();
+        }
+
+        let size = node
+            .attributes
+            .get("size")
+            .and_then(|attr| self.evaluate_attribute(attr).parse::<f32>().ok())
+            .unwrap_or(DEFAULT_ICON_SIZE);
+
+        // Icons follow the theme's text color unless tinted
+        let tint = node
+            .attributes
+            .get("tint")
+            .map(|attr| self.evaluate_attribute(attr))
+            .unwrap_or_else(|| "@text".to_string());
+
+        iced::widget::svg(crate::svg::icon_handle(&name))
+            .width(size)
+            .height(size)
+            .style(crate::svg::tint(tint))
+            .into()
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::HandlerMessage;
 use crate::builder::DampenWidgetBuilder;
+use crate::diagnostics::BuildDiagnosticKind;
 use dampen_core::ir::node::WidgetNode;
 use iced::{Element, Renderer, Theme};
 
@@ -32,6 +33,9 @@ impl<'a> DampenWidgetBuilder<'a> {
             _ => {
                 #[cfg(debug_assertions)]
                 eprintln!("[DampenWidgetBuilder] If widget missing 'condition' attribute");
+                self.report(BuildDiagnosticKind::UnsupportedAttribute, node.span, || {
+                    "<if> needs a 'condition' attribute".to_string()
+                });
                 false
             }
         };
@@ -2,6 +2,8 @@
 
 use crate::HandlerMessage;
 use crate::builder::DampenWidgetBuilder;
+use crate::diagnostics::BuildDiagnosticKind;
+use crate::image_loader;
 use dampen_core::ir::node::WidgetNode;
 use iced::{Element, Renderer, Theme};
 
@@ -27,7 +29,21 @@ impl<'a> DampenWidgetBuilder<'a> {
             return iced::widget::text("[Image: no src]").into();
         }
 
-        let handle = iced::widget::image::Handle::from_path(src);
+        // Remote URLs are loaded in the background, showing the placeholder meanwhile
+        let placeholder = node
+            .attributes
+            .get("placeholder")
+            .map(|attr| self.evaluate_attribute(attr));
+        let error_src = node
+            .attributes
+            .get("error_src")
+            .map(|attr| self.evaluate_attribute(attr));
+        let handle = image_loader::handle(&src, placeholder.as_deref(), error_src.as_deref());
+        if let Some(error) = image_loader::load_error(&src) {
+            self.report(BuildDiagnosticKind::ImageLoadFailed, node.span, || {
+                format!("Failed to load image '{}': {}", src, error)
+            });
+        }
 
         let mut image = iced::widget::image(handle);
 
@@ -0,0 +1,37 @@
+//! Hit-testing wrapper of every widget, when a hit test is attached
+
+use crate::HandlerMessage;
+use crate::builder::DampenWidgetBuilder;
+use crate::inspect::{Inspectable, InspectedNode};
+use dampen_core::ir::WidgetKind;
+use dampen_core::ir::node::WidgetNode;
+use iced::{Element, Rectangle, Renderer, Theme};
+
+impl<'a> DampenWidgetBuilder<'a> {
+    /// Wrap a built widget in an [`Inspectable`] area if a hit test is attached
+    ///
+    /// `<for>` and `<if>` are not widgets of their own: the widgets they
+    /// produce are wrapped instead.
+    pub(in crate::builder) fn wrap_inspectable(
+        &self,
+        node: &WidgetNode,
+        element: Element<'a, HandlerMessage, Theme, Renderer>,
+    ) -> Element<'a, HandlerMessage, Theme, Renderer> {
+        let Some(hit_test) = self.hit_test else {
+            return element;
+        };
+        if matches!(node.kind, WidgetKind::For | WidgetKind::If) {
+            return element;
+        }
+
+        let inspected = InspectedNode {
+            tag: node.kind.to_string(),
+            id: node.id.clone(),
+            classes: self.resolve_active_classes(node),
+            style: self.resolve_complete_styles(node),
+            span: node.span,
+            bounds: Rectangle::default(),
+        };
+        Inspectable::new(element, inspected, hit_test).into()
+    }
+}
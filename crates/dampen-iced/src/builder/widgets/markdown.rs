@@ -0,0 +1,65 @@
+//! Markdown widget builder
+
+use crate::HandlerMessage;
+use crate::builder::DampenWidgetBuilder;
+use crate::builder::helpers::parse_color;
+use crate::markdown::Markdown;
+use dampen_core::ir::EventKind;
+use dampen_core::ir::node::WidgetNode;
+use iced::{Element, Renderer, Theme};
+
+impl<'a> DampenWidgetBuilder<'a> {
+    /// Build a Markdown document from Dampen XML definition
+    ///
+    /// Supports the following attributes:
+    /// - `src`: Markdown source, usually bound to a model field
+    /// - `size`, `font`, `scale_ratio`: Override the theme typography
+    /// - `color`: Text color (default: theme text color)
+    /// - `on_link`: Handler receiving the URL of a clicked link
+    pub(in crate::builder) fn build_markdown(
+        &self,
+        node: &WidgetNode,
+    ) -> Element<'a, HandlerMessage, Theme, Renderer> {
+        let attribute = |name: &str| {
+            node.attributes
+                .get(name)
+                .map(|attr| self.evaluate_attribute(attr))
+        };
+
+        let mut markdown = Markdown::new(&attribute("src").unwrap_or_default());
+        if let Some(theme) = self.active_theme() {
+            markdown = markdown.typography(&theme.typography);
+            if let Some(primary) = theme.palette.primary {
+                markdown = markdown.link_color(iced::Color::from_rgba(
+                    primary.r, primary.g, primary.b, primary.a,
+                ));
+            }
+        }
+        if let Some(size) = attribute("size").and_then(|size| size.trim().parse().ok()) {
+            markdown = markdown.size(size);
+        }
+        if let Some(ratio) = attribute("scale_ratio").and_then(|ratio| ratio.trim().parse().ok()) {
+            markdown = markdown.scale_ratio(ratio);
+        }
+        if let Some(font) = attribute("font") {
+            markdown = markdown.font(&font);
+        }
+
+        // Text color: direct color attribute > inline/class/theme styles > theme text color
+        let color = attribute("color")
+            .and_then(|color| parse_color(&color))
+            .or_else(|| self.resolve_complete_styles(node).and_then(|s| s.color))
+            .or_else(|| self.active_theme().and_then(|theme| theme.palette.text));
+        if let Some(color) = color {
+            markdown = markdown.color(iced::Color::from_rgba(color.r, color.g, color.b, color.a));
+        }
+
+        if let Some(event) = node.events.iter().find(|e| e.event == EventKind::Link) {
+            let message_factory = self.message_factory.clone();
+            let handler = event.handler.clone();
+            markdown = markdown.on_link(move |link| message_factory(&handler, Some(link)));
+        }
+
+        self.apply_style_layout(markdown, node)
+    }
+}
@@ -1,6 +1,8 @@
 use crate::HandlerMessage;
 use crate::builder::DampenWidgetBuilder;
-use crate::builder::helpers::{resolve_boolean_attribute, resolve_handler_param};
+use crate::builder::helpers::{
+    resolve_boolean_attribute, resolve_event_params, resolve_handler_param,
+};
 use dampen_core::ir::node::WidgetNode;
 use dampen_core::ir::{EventKind, WidgetKind};
 use iced::widget::{Space, button, container, row, rule, text};
@@ -138,6 +140,43 @@ impl<'a> DampenWidgetBuilder<'a> {
         underlay
     }
 
+    /// Wrap a built widget in a mouse area if it has an `on_context_menu` event
+    ///
+    /// The event is emitted when the widget is right-clicked, before the attached
+    /// `<context_menu>` (if any) opens at the cursor position.
+    pub(in crate::builder) fn wrap_context_menu_event(
+        &self,
+        node: &WidgetNode,
+        element: Element<'a, HandlerMessage, Theme, Renderer>,
+    ) -> Element<'a, HandlerMessage, Theme, Renderer> {
+        let Some(event) = node
+            .events
+            .iter()
+            .find(|e| e.event == EventKind::ContextMenu)
+        else {
+            return element;
+        };
+
+        let msg = if !event.params.is_empty() {
+            (self.message_factory)(&event.handler, resolve_event_params(self, &event.params))
+        } else if let Some(param_expr) = &event.param {
+            match resolve_handler_param(self, param_expr) {
+                Ok(value) => {
+                    (self.message_factory)(&event.handler, Some(value.to_display_string()))
+                }
+                Err(e) => {
+                    #[cfg(debug_assertions)]
+                    eprintln!("{}", e);
+                    (self.message_factory)(&event.handler, None)
+                }
+            }
+        } else {
+            (self.message_factory)(&event.handler, None)
+        };
+
+        iced::widget::mouse_area(element).on_right_press(msg).into()
+    }
+
     // Helper to build menu as a vertical column (for ContextMenu)
     fn build_menu_as_column(
         &self,
@@ -316,7 +355,12 @@ impl<'a> DampenWidgetBuilder<'a> {
                 });
 
             if let Some(event) = node.events.iter().find(|e| e.event == EventKind::Click) {
-                let msg = if let Some(param_expr) = &event.param {
+                let msg = if !event.params.is_empty() {
+                    (self.message_factory)(
+                        &event.handler,
+                        resolve_event_params(self, &event.params),
+                    )
+                } else if let Some(param_expr) = &event.param {
                     match resolve_handler_param(self, param_expr) {
                         Ok(value) => {
                             (self.message_factory)(&event.handler, Some(value.to_display_string()))
@@ -5,7 +5,9 @@
 
 mod button;
 mod canvas;
+mod chart;
 mod checkbox;
+mod code_editor;
 mod color_picker;
 mod column;
 mod combo_box;
@@ -13,18 +15,25 @@ mod container;
 mod custom;
 mod data_table;
 mod date_picker;
+mod draggable;
 mod float;
 mod for_loop;
 mod grid;
+mod icon;
 mod if_widget;
 mod image;
+mod inspectable;
+mod markdown;
 mod menu;
+mod number_input;
 mod pick_list;
 mod progress_bar;
 mod radio;
+mod radio_group;
 mod row;
 mod rule;
 mod scrollable;
+mod skeleton;
 mod slider;
 mod space;
 mod stack;
@@ -35,6 +44,7 @@ mod text_input;
 mod time_picker;
 mod toggler;
 mod tooltip;
+mod tree;
 mod tree_view;
 
 // Re-export the build methods as part of DampenWidgetBuilder implementation
@@ -0,0 +1,97 @@
+//! NumberInput widget builder
+
+use crate::HandlerMessage;
+use crate::builder::DampenWidgetBuilder;
+use crate::builder::helpers::resolve_boolean_attribute;
+use crate::diagnostics::BuildDiagnosticKind;
+use dampen_core::NumberRange;
+use dampen_core::ir::node::WidgetNode;
+use iced::{Element, Renderer, Theme};
+
+impl<'a> DampenWidgetBuilder<'a> {
+    /// Build a number input from Dampen XML definition
+    ///
+    /// The input is a text field followed by `-` and `+` step buttons.
+    ///
+    /// Supports the following attributes:
+    /// - `value`: Binding to the current number
+    /// - `min`, `max`: Bounds of the accepted values
+    /// - `step`: Amount added or removed by the step buttons (default 1)
+    /// - `decimals`: Fractional digits, defaulting to those of `step`
+    /// - `placeholder`: Placeholder text when empty
+    /// - `disabled`: Boolean attribute for disabled state
+    ///
+    /// Events: Change (sends HandlerMessage::Handler(name, Some(number)), decoded with
+    /// [`HandlerRegistry::register_with_number`](dampen_core::HandlerRegistry::register_with_number)).
+    /// The number is clamped to the range; an edit that is not a number sends the
+    /// current value.
+    pub(in crate::builder) fn build_number_input(
+        &self,
+        node: &WidgetNode,
+    ) -> Element<'a, HandlerMessage, Theme, Renderer> {
+        let attribute = |name: &str| {
+            node.attributes
+                .get(name)
+                .map(|attr| self.evaluate_attribute(attr))
+        };
+        let (min, max) = (attribute("min"), attribute("max"));
+        let (step, decimals) = (attribute("step"), attribute("decimals"));
+        let range = match NumberRange::parse(
+            min.as_deref(),
+            max.as_deref(),
+            step.as_deref(),
+            decimals.as_deref(),
+        ) {
+            Ok(range) => range,
+            Err(message) => {
+                self.report(BuildDiagnosticKind::UnsupportedAttribute, node.span, || {
+                    message
+                });
+                NumberRange::default()
+            }
+        };
+
+        let value = range.clamp(
+            attribute("value")
+                .and_then(|value| value.trim().parse::<f64>().ok())
+                .unwrap_or_default(),
+        );
+        let placeholder = attribute("placeholder").unwrap_or_default();
+        let is_disabled = resolve_boolean_attribute(self, node, "disabled", false);
+
+        let on_change = node
+            .events
+            .iter()
+            .find(|e| e.event == dampen_core::EventKind::Change)
+            .map(|e| e.handler.clone())
+            .filter(|_| self.handler_registry.is_some() && !is_disabled);
+
+        #[cfg(debug_assertions)]
+        eprintln!(
+            "[DampenWidgetBuilder] Building number_input: value={}, range={:?}, handler={:?}",
+            value, range, on_change
+        );
+
+        let text = range.format(value);
+        let mut field = iced::widget::text_input(&placeholder, &text);
+        let mut decrement = iced::widget::button(iced::widget::text("-"));
+        let mut increment = iced::widget::button(iced::widget::text("+"));
+
+        if let Some(handler_name) = on_change {
+            let send = |number: f64| {
+                HandlerMessage::Handler(handler_name.clone(), Some(range.format(number)))
+            };
+            decrement = decrement.on_press(send(range.step_by(value, -1.0)));
+            increment = increment.on_press(send(range.step_by(value, 1.0)));
+
+            let range = range.clone();
+            field = field.on_input(move |input| {
+                let number = range.parse_value(&input).unwrap_or(value);
+                HandlerMessage::Handler(handler_name.clone(), Some(range.format(number)))
+            });
+        }
+
+        let row = iced::widget::row![field, decrement, increment].spacing(4);
+        self.apply_style_layout(row, node)
+    }
+}
@@ -2,46 +2,29 @@
 
 use crate::HandlerMessage;
 use crate::builder::DampenWidgetBuilder;
-use dampen_core::ir::node::WidgetNode;
+use crate::diagnostics::BuildDiagnosticKind;
+use dampen_core::SelectOption;
+use dampen_core::binding::BindingValue;
+use dampen_core::ir::node::{AttributeValue, WidgetKind, WidgetNode};
 use iced::{Element, Renderer, Theme};
 
 impl<'a> DampenWidgetBuilder<'a> {
     /// Build a pick list widget from Dampen XML definition
     ///
     /// Supports the following attributes:
-    /// - `options`: Comma-separated list of options
-    /// - `selected`: String binding for selected option
+    /// - `options`: Comma-separated list of options, or a binding to a collection
+    /// - `display`: Text shown for each bound option, e.g. `{item.name}`
+    /// - `value`: Value dispatched for each bound option, e.g. `{item.code}`
+    /// - `selected`: Binding to the value of the selected option
     /// - `placeholder`: Placeholder text (currently unused)
-    /// - `on_select`: Handler called on selection with selected value
+    /// - `on_select`: Handler called on selection with the option's value
     ///
     /// Events: Select (sends HandlerMessage::Handler(name, Some(selected_value)))
     pub(in crate::builder) fn build_pick_list(
         &self,
         node: &WidgetNode,
     ) -> Element<'a, HandlerMessage, Theme, Renderer> {
-        let options_str = node
-            .attributes
-            .get("options")
-            .map(|attr| self.evaluate_attribute(attr))
-            .unwrap_or_default();
-
-        let options: Vec<String> = options_str
-            .split(',')
-            .map(|s| s.trim().to_string())
-            .filter(|s| !s.is_empty())
-            .collect();
-
-        let selected_str = node
-            .attributes
-            .get("selected")
-            .map(|attr| self.evaluate_attribute(attr))
-            .unwrap_or_default();
-
-        let selected = if selected_str.is_empty() {
-            None
-        } else {
-            options.iter().find(|o| *o == &selected_str).cloned()
-        };
+        let (options, selected) = self.resolve_select_options(node);
 
         #[cfg(debug_assertions)]
         eprintln!(
@@ -73,8 +56,8 @@ impl<'a> DampenWidgetBuilder<'a> {
                     "[DampenWidgetBuilder] PickList: Attaching on_select with handler '{}'",
                     handler_name
                 );
-                iced::widget::pick_list(options, selected, move |selected_value| {
-                    HandlerMessage::Handler(handler_name.clone(), Some(selected_value))
+                iced::widget::pick_list(options, selected, move |option: SelectOption| {
+                    HandlerMessage::Handler(handler_name.clone(), Some(option.value))
                 })
             } else {
                 #[cfg(debug_assertions)]
@@ -97,4 +80,82 @@ impl<'a> DampenWidgetBuilder<'a> {
 
         pick_list.into()
     }
+
+    /// Options of a pick list, combo box or radio group, and the selected one
+    ///
+    /// Static options are a comma-separated list. Bound options are evaluated
+    /// once per item of the collection, with the item as `{item}` in `display`
+    /// and `value`. The selected option is the one whose value matches `selected`
+    /// (`value` for a radio group).
+    pub(in crate::builder) fn resolve_select_options(
+        &self,
+        node: &WidgetNode,
+    ) -> (Vec<SelectOption>, Option<SelectOption>) {
+        let options = match node.attributes.get("options") {
+            Some(AttributeValue::Binding(expr)) => match self.evaluate_binding_with_context(expr) {
+                Ok(BindingValue::List(items)) => items
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| self.resolve_select_option(node, index, item))
+                    .collect(),
+                Ok(other) => {
+                    self.report(
+                        BuildDiagnosticKind::UnsupportedAttribute,
+                        node.attribute_span("options"),
+                        || {
+                            format!(
+                                "'options' must be a list, got '{}'",
+                                other.to_display_string()
+                            )
+                        },
+                    );
+                    Vec::new()
+                }
+                Err(_) => Vec::new(),
+            },
+            Some(attr) => SelectOption::parse_list(&self.evaluate_attribute(attr)),
+            None => Vec::new(),
+        };
+
+        let selected_attr = if node.kind == WidgetKind::RadioGroup {
+            "value"
+        } else {
+            "selected"
+        };
+        let selected_str = node
+            .attributes
+            .get(selected_attr)
+            .map(|attr| self.evaluate_attribute(attr))
+            .unwrap_or_default();
+        let selected = SelectOption::find(&options, &selected_str).cloned();
+
+        (options, selected)
+    }
+
+    /// Evaluate the `display` and `value` of one bound option
+    fn resolve_select_option(
+        &self,
+        node: &WidgetNode,
+        index: usize,
+        item: &BindingValue,
+    ) -> SelectOption {
+        self.push_context("item", item.clone());
+        self.push_context("index", BindingValue::Integer(index as i64));
+
+        let label = node
+            .attributes
+            .get("display")
+            .map(|attr| self.evaluate_attribute(attr))
+            .unwrap_or_else(|| item.to_display_string());
+        let value = match node.attributes.get("value") {
+            Some(attr) if node.is_option_template("value") => self.evaluate_attribute(attr),
+            // A radio group option's value is the item itself
+            _ if node.kind == WidgetKind::RadioGroup => item.to_display_string(),
+            _ => label.clone(),
+        };
+
+        self.pop_context(); // index
+        self.pop_context(); // item
+        SelectOption::new(label, value)
+    }
 }
@@ -123,22 +123,10 @@ impl<'a> DampenWidgetBuilder<'a> {
     where
         HandlerMessage: Clone + 'static,
     {
-        // Parse min, max, and value attributes
-        let min = match node.attributes.get("min") {
-            Some(AttributeValue::Static(s)) => s.parse::<f32>().ok().unwrap_or(0.0),
-            _ => 0.0,
-        };
-
-        let max = match node.attributes.get("max") {
-            Some(AttributeValue::Static(s)) => s.parse::<f32>().ok().unwrap_or(1.0),
-            _ => 1.0,
-        };
-
-        let value_str = match node.attributes.get("value") {
-            Some(attr) => self.evaluate_attribute(attr),
-            None => "0".to_string(),
-        };
-        let value = value_str.parse::<f32>().unwrap_or(0.0);
+        // Evaluate min, max, and value attributes (static or bound)
+        let min = self.resolve_f32_attribute(node, "min", 0.0);
+        let max = self.resolve_f32_attribute(node, "max", 1.0);
+        let value = self.resolve_f32_attribute(node, "value", 0.0);
 
         // Clamp value to [min, max] range
         let clamped_value = value.min(max).max(min);
@@ -244,6 +232,6 @@ impl<'a> DampenWidgetBuilder<'a> {
             }
         });
 
-        progress_bar.into()
+        self.with_value_label(progress_bar.into(), node, clamped_value)
     }
 }
@@ -0,0 +1,112 @@
+//! RadioGroup widget builder
+
+use crate::HandlerMessage;
+use crate::builder::DampenWidgetBuilder;
+use dampen_core::ir::layout::Direction;
+use dampen_core::ir::node::{AttributeValue, WidgetKind, WidgetNode};
+use iced::{Element, Renderer, Theme};
+
+impl<'a> DampenWidgetBuilder<'a> {
+    /// Build a radio group from Dampen XML definition
+    ///
+    /// Supports the following attributes:
+    /// - `value`: Binding to the value of the selected option
+    /// - `options`: Comma-separated list of options, or a binding to a collection
+    /// - `display`: Text shown for each bound option, e.g. `{item.label}`
+    /// - `direction`: `vertical` (default) or `horizontal`
+    /// - `disabled`: Disables every radio of the group
+    /// - `on_select`: Handler called with the value of the selected option
+    ///
+    /// `<radio>` children can be listed instead of `options`. Each one takes its
+    /// selection, `on_select` and `disabled` from the group unless it sets its own.
+    pub(in crate::builder) fn build_radio_group(
+        &self,
+        node: &WidgetNode,
+    ) -> Element<'a, HandlerMessage, Theme, Renderer> {
+        let mut radios: Vec<WidgetNode> = if node.children.is_empty() {
+            let (options, _) = self.resolve_select_options(node);
+            options
+                .into_iter()
+                .map(|option| WidgetNode {
+                    kind: WidgetKind::Radio,
+                    attributes: [
+                        ("label".to_string(), AttributeValue::Static(option.label)),
+                        ("value".to_string(), AttributeValue::Static(option.value)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                    span: node.span,
+                    ..Default::default()
+                })
+                .collect()
+        } else {
+            node.children.to_vec()
+        };
+
+        let selected = node
+            .attributes
+            .get("value")
+            .map(|attr| AttributeValue::Static(self.evaluate_attribute(attr)));
+        let on_select: Vec<_> = node
+            .events
+            .iter()
+            .filter(|e| e.event == dampen_core::EventKind::Select)
+            .cloned()
+            .collect();
+
+        for radio in &mut radios {
+            if let Some(selected) = &selected {
+                radio
+                    .attributes
+                    .entry("selected".to_string())
+                    .or_insert_with(|| selected.clone());
+            }
+            if let Some(disabled) = node.attributes.get("disabled") {
+                radio
+                    .attributes
+                    .entry("disabled".to_string())
+                    .or_insert_with(|| disabled.clone());
+            }
+            if !radio
+                .events
+                .iter()
+                .any(|e| e.event == dampen_core::EventKind::Select)
+            {
+                radio.events.extend(on_select.iter().cloned());
+            }
+        }
+
+        #[cfg(debug_assertions)]
+        eprintln!(
+            "[DampenWidgetBuilder] Building radio_group: {} option(s), selected={:?}",
+            radios.len(),
+            selected
+        );
+
+        let children: Vec<_> = radios
+            .iter()
+            .map(|radio| self.build_widget(radio))
+            .collect();
+
+        let layout = self.resolve_layout(node);
+        let spacing = layout.as_ref().and_then(|layout| layout.spacing);
+        let horizontal = matches!(
+            layout.as_ref().and_then(|layout| layout.direction),
+            Some(Direction::Horizontal | Direction::HorizontalReverse)
+        );
+
+        if horizontal {
+            let mut row = iced::widget::row(children);
+            if let Some(spacing) = spacing {
+                row = row.spacing(spacing);
+            }
+            self.apply_style_layout(row, node)
+        } else {
+            let mut column = iced::widget::column(children);
+            if let Some(spacing) = spacing {
+                column = column.spacing(spacing);
+            }
+            self.apply_style_layout(column, node)
+        }
+    }
+}
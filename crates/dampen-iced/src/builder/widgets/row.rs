@@ -15,26 +15,23 @@ impl<'a> DampenWidgetBuilder<'a> {
     where
         HandlerMessage: Clone + 'static,
     {
-        let children: Vec<_> = node
-            .children
-            .iter()
-            .map(|child| self.build_widget(child))
-            .collect();
+        let children = self.build_children_or_skeleton(node);
 
         let mut row = iced::widget::row(children);
 
         // Apply spacing and width/height from resolved layout
-        if let Some(layout) = self.resolve_layout(node) {
+        let layout = self.resolve_layout(node);
+        if let Some(layout) = &layout {
             if let Some(spacing) = layout.spacing {
                 row = row.spacing(spacing);
             }
             // Apply width and height directly to the row
             if layout.width.is_some() {
-                let iced_layout = map_layout_constraints(&layout);
+                let iced_layout = map_layout_constraints(layout);
                 row = row.width(iced_layout.width);
             }
             if layout.height.is_some() {
-                let iced_layout = map_layout_constraints(&layout);
+                let iced_layout = map_layout_constraints(layout);
                 row = row.height(iced_layout.height);
             }
             // Apply alignment - Row only supports align_y (vertical alignment of children)
@@ -43,6 +40,10 @@ impl<'a> DampenWidgetBuilder<'a> {
             }
         }
 
-        self.apply_style_layout(row, node)
+        if layout.and_then(|layout| layout.wrap) == Some(true) {
+            self.apply_style_layout(row.wrap(), node)
+        } else {
+            self.apply_style_layout(row, node)
+        }
     }
 }
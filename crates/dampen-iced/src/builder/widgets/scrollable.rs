@@ -2,7 +2,11 @@
 
 use crate::HandlerMessage;
 use crate::builder::DampenWidgetBuilder;
-use dampen_core::ir::node::WidgetNode;
+use crate::reach_end::reach_end;
+use crate::scroll_position::{scroll_event, scroll_position};
+use dampen_core::ir::node::{EventKind, WidgetNode};
+use dampen_core::pagination::DEFAULT_REACH_END_DISTANCE;
+use dampen_core::state::ScrollOffset;
 use iced::{Element, Renderer, Theme};
 
 impl<'a> DampenWidgetBuilder<'a> {
@@ -19,6 +23,23 @@ impl<'a> DampenWidgetBuilder<'a> {
             iced::widget::text("").into()
         };
 
+        // Emit `on_reach_end` when scrolled near the bottom
+        let content = match node.events.iter().find(|e| e.event == EventKind::ReachEnd) {
+            Some(event) => {
+                let distance = node
+                    .attributes
+                    .get("reach_end_distance")
+                    .and_then(|attr| self.evaluate_attribute(attr).trim().parse::<f32>().ok())
+                    .unwrap_or(DEFAULT_REACH_END_DISTANCE);
+                reach_end(
+                    content,
+                    HandlerMessage::Handler(event.handler.clone(), None),
+                    distance,
+                )
+            }
+            None => content,
+        };
+
         let mut scrollable = iced::widget::scrollable(content);
 
         // Handle width attribute
@@ -55,6 +76,53 @@ impl<'a> DampenWidgetBuilder<'a> {
             }
         }
 
+        // Give the scrollable a stable id so the scroll position can be
+        // restored after a hot-reload
+        if let Some(id) = node.id.clone() {
+            scrollable = scrollable.id(id);
+        }
+
+        // Record the offset for the hot-reload and pass it to `on_scroll`
+        let tracked = node.id.clone().zip(self.widget_state);
+        let on_scroll = node
+            .events
+            .iter()
+            .find(|e| e.event == EventKind::Scroll)
+            .map(|event| event.handler.clone());
+        if tracked.is_some() || on_scroll.is_some() {
+            scrollable = scrollable.on_scroll(move |viewport| {
+                let event = scroll_event(&viewport);
+                if let Some((id, tracker)) = &tracked {
+                    tracker.record_scroll(
+                        id,
+                        ScrollOffset {
+                            x: event.x,
+                            y: event.y,
+                        },
+                    );
+                }
+                match &on_scroll {
+                    Some(handler) => {
+                        HandlerMessage::Handler(handler.clone(), Some(event.to_payload()))
+                    }
+                    None => HandlerMessage::None,
+                }
+            });
+        }
+
+        // Drive the offset from `offset` and `stick_to_bottom`
+        let offset = node
+            .attributes
+            .get("offset")
+            .and_then(|attr| self.evaluate_attribute(attr).trim().parse::<f32>().ok());
+        let stick_to_bottom = node
+            .attributes
+            .get("stick_to_bottom")
+            .is_some_and(|attr| self.evaluate_attribute(attr).trim() == "true");
+        if offset.is_some() || stick_to_bottom {
+            return scroll_position(scrollable, offset, stick_to_bottom);
+        }
+
         scrollable.into()
     }
 }
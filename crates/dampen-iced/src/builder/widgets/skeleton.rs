@@ -0,0 +1,62 @@
+//! Skeleton widget builder and `loading` containers
+
+use crate::HandlerMessage;
+use crate::builder::DampenWidgetBuilder;
+use crate::builder::helpers::{apply_grow, parse_length, resolve_boolean_attribute};
+use crate::skeleton::{DEFAULT_LINES, Skeleton};
+use dampen_core::ir::node::WidgetNode;
+use iced::{Element, Renderer, Theme};
+
+impl<'a> DampenWidgetBuilder<'a> {
+    /// Build a loading placeholder from Dampen XML definition
+    ///
+    /// Supports the following attributes:
+    /// - `lines`: Number of placeholder lines (default 3)
+    /// - `width`: Width of the lines (default fill)
+    pub(in crate::builder) fn build_skeleton(
+        &self,
+        node: &WidgetNode,
+    ) -> Element<'a, HandlerMessage, Theme, Renderer> {
+        let lines = node
+            .attributes
+            .get("lines")
+            .and_then(|attr| self.evaluate_attribute(attr).trim().parse::<usize>().ok())
+            .unwrap_or(DEFAULT_LINES);
+
+        let mut skeleton = Skeleton::new(lines);
+        if let Some(width) = node
+            .attributes
+            .get("width")
+            .and_then(|attr| parse_length(&self.evaluate_attribute(attr)))
+        {
+            skeleton = skeleton.width(width);
+        }
+
+        self.apply_style_layout(skeleton, node)
+    }
+
+    /// Build the children of a column or row
+    ///
+    /// While the `loading` attribute is true, the children are replaced by a
+    /// single skeleton and are not built. Children with a `grow` take their
+    /// portions of the free space along the main axis of `node`.
+    pub(in crate::builder) fn build_children_or_skeleton(
+        &self,
+        node: &WidgetNode,
+    ) -> Vec<Element<'a, HandlerMessage, Theme, Renderer>> {
+        if resolve_boolean_attribute(self, node, "loading", false) {
+            return vec![Skeleton::new(DEFAULT_LINES).into()];
+        }
+
+        node.children
+            .iter()
+            .map(|child| {
+                let element = self.build_widget(child);
+                match self.resolve_layout(child).and_then(|layout| layout.grow) {
+                    Some(grow) => apply_grow(element, &node.kind, grow),
+                    None => element,
+                }
+            })
+            .collect()
+    }
+}
@@ -99,85 +99,55 @@ impl<'a> DampenWidgetBuilder<'a> {
     /// Build a slider widget from Dampen XML definition
     ///
     /// Supports the following attributes:
-    /// - `min`: Minimum value (default 0.0)
-    /// - `max`: Maximum value (default 100.0)
+    /// - `min`: Minimum value, static or bound (default 0.0)
+    /// - `max`: Maximum value, static or bound (default 100.0)
+    /// - `step`: Increment size, static or bound
     /// - `value`: Float binding for current value (clamped to [min, max])
-    /// - `on_change`: Handler called on change with stringified float value
+    /// - `show_value`: Whether to show the value next to the slider
+    /// - `value_format`: Filter formatting the shown value, e.g. `decimal(1)`
+    /// - `on_change`: Handler called on change with the new value
     /// - `disabled`: Boolean attribute for disabled state
     ///
-    /// Events: Change (sends HandlerMessage::Handler(name, Some(value.to_string())))
+    /// Events: Change (sends HandlerMessage::Handler(name, Some(value.to_string())),
+    /// decoded with [`HandlerRegistry::register_with_number`](dampen_core::HandlerRegistry::register_with_number))
     pub(in crate::builder) fn build_slider(
         &self,
         node: &WidgetNode,
     ) -> Element<'a, HandlerMessage, Theme, Renderer> {
-        let min = node
-            .attributes
-            .get("min")
-            .map(|attr| self.evaluate_attribute(attr))
-            .unwrap_or_else(|| "0.0".to_string())
-            .parse::<f32>()
-            .unwrap_or(0.0);
-
-        let max = node
-            .attributes
-            .get("max")
-            .map(|attr| self.evaluate_attribute(attr))
-            .unwrap_or_else(|| "100.0".to_string())
-            .parse::<f32>()
-            .unwrap_or(100.0);
-
-        let value_str = node
-            .attributes
-            .get("value")
-            .map(|attr| self.evaluate_attribute(attr))
-            .unwrap_or_else(|| "50.0".to_string());
-
-        let mut value = value_str.parse::<f32>().unwrap_or(50.0);
+        let min = self.resolve_f32_attribute(node, "min", 0.0);
+        let max = self.resolve_f32_attribute(node, "max", 100.0);
 
         // Clamp value to [min, max]
-        value = value.max(min).min(max);
+        let value = self
+            .resolve_f32_attribute(node, "value", 50.0)
+            .max(min)
+            .min(max);
 
         // Get optional step value
         let step = node
             .attributes
             .get("step")
             .map(|attr| self.evaluate_attribute(attr))
-            .and_then(|s| s.parse::<f32>().ok());
+            .and_then(|s| s.trim().parse::<f32>().ok());
 
         // Get handler from events
         let on_change = node
             .events
             .iter()
             .find(|e| e.event == dampen_core::EventKind::Change)
-            .map(|e| e.handler.clone());
+            .map(|e| e.handler.clone())
+            .filter(|_| self.handler_registry.is_some());
 
-        let slider = if let Some(handler_name) = on_change {
-            if self.handler_registry.is_some() {
-                let mut slider = iced::widget::slider(min..=max, value, move |new_value| {
+        let mut slider =
+            iced::widget::slider(min..=max, value, move |new_value| match &on_change {
+                Some(handler_name) => {
                     HandlerMessage::Handler(handler_name.clone(), Some(new_value.to_string()))
-                });
-                if let Some(step_val) = step {
-                    slider = slider.step(step_val);
                 }
-                slider
-            } else {
-                let mut slider = iced::widget::slider(min..=max, value, |_| {
-                    HandlerMessage::Handler("dummy".to_string(), None)
-                });
-                if let Some(step_val) = step {
-                    slider = slider.step(step_val);
-                }
-                slider
-            }
-        } else {
-            let mut slider = iced::widget::slider(min..=max, value, |_| {
-                HandlerMessage::Handler("dummy".to_string(), None)
+                None => HandlerMessage::Handler("dummy".to_string(), None),
             });
-            if let Some(step_val) = step {
-                slider = slider.step(step_val);
-            }
-            slider
-        };
+        if let Some(step_val) = step {
+            slider = slider.step(step_val);
+        }
 
         // Apply state-aware styling
         // Note: Iced slider::Status has no Disabled variant, so we check disabled attribute
@@ -219,6 +189,6 @@ impl<'a> DampenWidgetBuilder<'a> {
             slider
         };
 
-        slider.into()
+        self.with_value_label(slider.into(), node, value)
     }
 }
@@ -13,23 +13,38 @@ impl<'a> DampenWidgetBuilder<'a> {
     where
         HandlerMessage: Clone + 'static,
     {
-        // Support both 'src' and 'path' attributes
-        let src = node
+        // Inline or bound markup takes precedence over 'src' and 'path'
+        let content = node
             .attributes
-            .get("src")
-            .or_else(|| node.attributes.get("path"))
+            .get("content")
             .map(|attr| self.evaluate_attribute(attr))
             .unwrap_or_default();
 
-        if src.is_empty() {
-            #[cfg(debug_assertions)]
-            eprintln!("[DampenWidgetBuilder] SVG src is empty");
-            return iced::widget::text("[SVG: no src]").into();
-        }
+        let handle = if content.trim().is_empty() {
+            // Support both 'src' and 'path' attributes
+            let src = node
+                .attributes
+                .get("src")
+                .or_else(|| node.attributes.get("path"))
+                .map(|attr| self.evaluate_attribute(attr))
+                .unwrap_or_default();
+
+            if src.is_empty() {
+                #[cfg(debug_assertions)]
+                eprintln!("[DampenWidgetBuilder] SVG src is empty");
+                return iced::widget::text("[SVG: no src]").into();
+            }
 
-        let handle = iced::widget::svg::Handle::from_path(src);
+            crate::assets::svg_handle(&src)
+        } else {
+            crate::svg::content_handle(&content)
+        };
         let mut svg = iced::widget::svg(handle);
 
+        if let Some(tint_attr) = node.attributes.get("tint") {
+            svg = svg.style(crate::svg::tint(self.evaluate_attribute(tint_attr)));
+        }
+
         // Parse optional width
         if let Some(width_attr) = node.attributes.get("width")
             && let Ok(width) = self.evaluate_attribute(width_attr).parse::<f32>()
@@ -2,8 +2,16 @@
 
 use crate::HandlerMessage;
 use crate::builder::DampenWidgetBuilder;
-use crate::builder::helpers::parse_color;
+use crate::builder::helpers::{parse_color, resolve_boolean_attribute};
+use crate::fonts;
+use crate::rich_text::markdown_spans;
+use dampen_core::ir::EventKind;
+use dampen_core::ir::layout::Length;
 use dampen_core::ir::node::WidgetNode;
+use dampen_core::ir::{TextStyle, Typography};
+use dampen_core::{TextClamp, TextWrap};
+use iced::font::{Style, Weight};
+use iced::widget::text::{Span, Wrapping};
 use iced::{Element, Renderer, Theme};
 
 impl<'a> DampenWidgetBuilder<'a> {
@@ -14,21 +22,31 @@ impl<'a> DampenWidgetBuilder<'a> {
     where
         HandlerMessage: Clone + 'static,
     {
+        let markdown = resolve_boolean_attribute(self, node, "markdown", false);
+        if markdown || !node.children.is_empty() {
+            return self.build_rich_text(node, markdown);
+        }
+
         let value = node
             .attributes
             .get("value")
             .map(|attr| self.evaluate_attribute(attr))
             .unwrap_or_default();
 
-        let mut text_widget = iced::widget::text(value);
+        let (size, font) = self.resolve_text_font(node);
+        let clamp = self.resolve_text_clamp(node, size);
+        let mut text_widget =
+            iced::widget::text(clamp.apply(&value).into_owned()).wrapping(wrapping(clamp.wrap));
+        if let Some(height) = self.clamped_height(node, &clamp, size) {
+            text_widget = text_widget.height(height);
+        }
 
         // Resolve text color with theme awareness
         // Priority: direct color attribute > inline style color > class color > theme color > default
         let mut theme_color_applied = false;
 
-        if let Some(theme_ctx) = self.theme_context {
+        if let Some(active_theme) = self.active_theme() {
             // Get theme text color at render time
-            let active_theme = theme_ctx.active();
             if let Some(ref text_color) = active_theme.palette.text {
                 // Check if there's no direct color attribute, inline style, or class color overriding it
                 let has_direct_color = node.attributes.contains_key("color");
@@ -80,21 +98,11 @@ impl<'a> DampenWidgetBuilder<'a> {
             }
         }
 
-        if let Some(size_attr) = node.attributes.get("size") {
-            let size_str = self.evaluate_attribute(size_attr);
-            if let Ok(size) = size_str.parse::<f32>() {
-                text_widget = text_widget.size(size);
-            }
+        if let Some(size) = size {
+            text_widget = text_widget.size(size);
         }
-
-        if let Some(weight_attr) = node.attributes.get("weight") {
-            let weight_str = self.evaluate_attribute(weight_attr);
-            if weight_str == "bold" {
-                text_widget = text_widget.font(iced::Font {
-                    weight: iced::font::Weight::Bold,
-                    ..Default::default()
-                });
-            }
+        if let Some(font) = font {
+            text_widget = text_widget.font(font);
         }
 
         // Note: align_x and align_y are NOT applied here.
@@ -105,4 +113,217 @@ impl<'a> DampenWidgetBuilder<'a> {
 
         self.apply_style_layout(text_widget, node)
     }
+
+    /// Build rich text from `<span>` children or a `markdown="true"` value
+    fn build_rich_text(
+        &self,
+        node: &WidgetNode,
+        markdown: bool,
+    ) -> Element<'a, HandlerMessage, Theme, Renderer> {
+        let spans: Vec<Span<'a, String>> = if markdown {
+            let value = node
+                .attributes
+                .get("value")
+                .map(|attr| self.evaluate_attribute(attr))
+                .unwrap_or_default();
+            markdown_spans(&value)
+        } else {
+            node.children
+                .iter()
+                .map(|span| self.build_span(span))
+                .collect()
+        };
+
+        let (size, font) = self.resolve_text_font(node);
+        let clamp = self.resolve_text_clamp(node, size);
+        let mut rich_text = iced::widget::rich_text(spans).wrapping(wrapping(clamp.wrap));
+        if let Some(height) = self.clamped_height(node, &clamp, size) {
+            rich_text = rich_text.height(height);
+        }
+
+        // Base color: direct color attribute > inline/class/theme styles > theme text color
+        let color = node
+            .attributes
+            .get("color")
+            .and_then(|attr| parse_color(&self.evaluate_attribute(attr)))
+            .or_else(|| self.resolve_complete_styles(node).and_then(|s| s.color))
+            .or_else(|| self.active_theme().and_then(|theme| theme.palette.text));
+        if let Some(color) = color {
+            rich_text = rich_text.color(iced::Color {
+                r: color.r,
+                g: color.g,
+                b: color.b,
+                a: color.a,
+            });
+        }
+
+        if let Some(size) = size {
+            rich_text = rich_text.size(size);
+        }
+        if let Some(font) = font {
+            rich_text = rich_text.font(font);
+        }
+
+        if let Some(event) = node.events.iter().find(|e| e.event == EventKind::Link) {
+            let message_factory = self.message_factory.clone();
+            let handler = event.handler.clone();
+            rich_text =
+                rich_text.on_link_click(move |link: String| message_factory(&handler, Some(link)));
+        }
+
+        self.apply_style_layout(rich_text, node)
+    }
+
+    /// Size and font of a text
+    ///
+    /// Attributes win over the named text style (`style="heading1"`), which wins
+    /// over the base size and family of the theme typography.
+    fn resolve_text_font(&self, node: &WidgetNode) -> (Option<f32>, Option<iced::Font>) {
+        let default_typography = Typography::default();
+        let typography = self
+            .active_theme()
+            .map(|theme| &theme.typography)
+            .unwrap_or(&default_typography);
+        let attribute = |name: &str| {
+            node.attributes
+                .get(name)
+                .map(|attr| self.evaluate_attribute(attr))
+        };
+
+        let style = attribute("style").and_then(|style| TextStyle::parse(&style));
+        let size = attribute("size")
+            .and_then(|size| size.parse::<f32>().ok())
+            .or_else(|| style.map(|style| typography.size_of(style)))
+            .or(typography.font_size_base);
+        let weight = attribute("weight")
+            .or_else(|| style.map(|style| typography.weight_of(style).name().to_string()));
+        let family = attribute("font").or_else(|| typography.family().map(str::to_string));
+
+        let font = (weight.is_some() || family.is_some()).then(|| iced::Font {
+            family: family.as_deref().map(fonts::family).unwrap_or_default(),
+            weight: weight.as_deref().map(fonts::weight).unwrap_or_default(),
+            ..Default::default()
+        });
+        (size, font)
+    }
+
+    /// Wrapping and clamping of a text from its `wrap`, `max_lines` and `truncate`
+    ///
+    /// The characters fitting on a line are estimated from the fixed or maximum
+    /// width of the text.
+    fn resolve_text_clamp(&self, node: &WidgetNode, size: Option<f32>) -> TextClamp {
+        let attribute = |name: &str| {
+            node.attributes
+                .get(name)
+                .map(|attr| self.evaluate_attribute(attr))
+        };
+        let width = self
+            .resolve_layout(node)
+            .and_then(|layout| match layout.width {
+                Some(Length::Fixed(width)) => Some(width),
+                _ => layout.max_width,
+            });
+        TextClamp::new(
+            attribute("wrap").as_deref(),
+            attribute("max_lines").as_deref(),
+            attribute("truncate").as_deref(),
+            width,
+            size.unwrap_or(DEFAULT_TEXT_SIZE),
+        )
+    }
+
+    /// Height showing the `max_lines` of a text, unless it has its own height
+    fn clamped_height(
+        &self,
+        node: &WidgetNode,
+        clamp: &TextClamp,
+        size: Option<f32>,
+    ) -> Option<f32> {
+        let has_height = self
+            .resolve_layout(node)
+            .is_some_and(|layout| layout.height.is_some());
+        clamp
+            .height(size.unwrap_or(DEFAULT_TEXT_SIZE))
+            .filter(|_| !has_height)
+    }
+
+    /// Build a `<span>` of rich text
+    fn build_span(&self, node: &WidgetNode) -> Span<'a, String> {
+        let value = node
+            .attributes
+            .get("value")
+            .map(|attr| self.evaluate_attribute(attr))
+            .unwrap_or_default();
+        let mut span = iced::widget::span(value);
+
+        if let Some(color) = node
+            .attributes
+            .get("color")
+            .and_then(|attr| parse_color(&self.evaluate_attribute(attr)))
+        {
+            span = span.color(iced::Color {
+                r: color.r,
+                g: color.g,
+                b: color.b,
+                a: color.a,
+            });
+        }
+
+        if let Some(size_attr) = node.attributes.get("size")
+            && let Ok(size) = self.evaluate_attribute(size_attr).parse::<f32>()
+        {
+            span = span.size(size);
+        }
+
+        let weight = node
+            .attributes
+            .get("weight")
+            .map(|attr| fonts::weight(&self.evaluate_attribute(attr)))
+            .unwrap_or_default();
+        let italic = resolve_boolean_attribute(self, node, "italic", false);
+        if weight != Weight::Normal || italic {
+            span = span.font(iced::Font {
+                weight,
+                style: if italic { Style::Italic } else { Style::Normal },
+                ..Default::default()
+            });
+        }
+
+        // Links are underlined unless `underline="false"`
+        let link = node
+            .attributes
+            .get("link")
+            .map(|attr| self.evaluate_attribute(attr));
+        span = span
+            .underline(resolve_boolean_attribute(
+                self,
+                node,
+                "underline",
+                link.is_some(),
+            ))
+            .strikethrough(resolve_boolean_attribute(
+                self,
+                node,
+                "strikethrough",
+                false,
+            ));
+        if let Some(link) = link {
+            span = span.link(link);
+        }
+
+        span
+    }
+}
+
+/// Font size of iced text without a `size`
+const DEFAULT_TEXT_SIZE: f32 = 16.0;
+
+/// Wrapping of iced text
+fn wrapping(wrap: TextWrap) -> Wrapping {
+    match wrap {
+        TextWrap::Word => Wrapping::Word,
+        TextWrap::Glyph => Wrapping::Glyph,
+        TextWrap::WordOrGlyph => Wrapping::WordOrGlyph,
+        TextWrap::None => Wrapping::None,
+    }
 }
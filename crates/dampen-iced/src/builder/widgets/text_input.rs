@@ -2,8 +2,11 @@
 
 use crate::HandlerMessage;
 use crate::builder::DampenWidgetBuilder;
+use crate::diagnostics::BuildDiagnosticKind;
 use dampen_core::ir::node::WidgetNode;
 use dampen_core::ir::style::StyleProperties;
+use dampen_core::state::TextCursor;
+use dampen_core::{InputConstraints, InputFilter};
 use iced::{Element, Renderer, Theme};
 
 /// Convert Dampen StyleProperties to Iced text_input Style
@@ -79,6 +82,13 @@ fn apply_text_input_style(
         };
     }
 
+    // Text inputs draw their outline as their border, so that `focus:outline`
+    // gives a focus ring
+    if let Some(ref outline) = props.outline {
+        style.border.width = outline.width;
+        style.border.color = crate::style_mapping::map_color(&outline.color);
+    }
+
     style
 }
 
@@ -89,9 +99,16 @@ impl<'a> DampenWidgetBuilder<'a> {
     /// - `value`: String binding for current text value
     /// - `placeholder`: Placeholder text when empty
     /// - `on_input`: Handler called on text input with new value
-    /// - `password`: If "true", masks input with password character
+    /// - `mask`: If "password", masks input with password character
+    /// - `password`: If "true", same as `mask="password"`
+    /// - `max_length`: Maximum number of characters
+    /// - `filter`: `numeric`, `alpha`, `alphanumeric` or `regex:'pattern'`
+    /// - `debounce_ms`: Delay without typing before `on_input` is dispatched
     ///
     /// Events: Input (sends HandlerMessage::Handler(name, Some(new_text)))
+    ///
+    /// `max_length` and `filter` are enforced before dispatching: a rejected edit
+    /// sends the current value.
     pub(in crate::builder) fn build_text_input(
         &self,
         node: &WidgetNode,
@@ -102,25 +119,48 @@ impl<'a> DampenWidgetBuilder<'a> {
             .map(|attr| self.evaluate_attribute(attr))
             .unwrap_or_default();
 
-        let value = node
+        let mut value = node
             .attributes
             .get("value")
             .map(|attr| self.evaluate_attribute(attr))
             .unwrap_or_default();
 
-        // Check for password attribute
-        let is_password = node
-            .attributes
-            .get("password")
-            .map(|attr| self.evaluate_attribute(attr))
-            .map(|v| v == "true" || v == "1")
-            .unwrap_or(false);
+        // Check for mask="password" or the password attribute
+        let is_password = match node.attributes.get("mask") {
+            Some(attr) => self.evaluate_attribute(attr).trim() == "password",
+            None => node
+                .attributes
+                .get("password")
+                .map(|attr| self.evaluate_attribute(attr))
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(false),
+        };
 
-        #[cfg(debug_assertions)]
-        eprintln!(
-            "[DampenWidgetBuilder] Building text_input: placeholder='{}', value='{}', password={}",
-            placeholder, value, is_password
-        );
+        let constraint = |name: &str| {
+            node.attributes
+                .get(name)
+                .map(|attr| self.evaluate_attribute(attr))
+        };
+        let (max_length, filter) = (constraint("max_length"), constraint("filter"));
+        let constraints = match InputConstraints::parse(max_length.as_deref(), filter.as_deref()) {
+            Ok(constraints) => constraints,
+            Err(message) => {
+                let invalid = if filter
+                    .as_deref()
+                    .is_some_and(|f| InputFilter::parse(f).is_err())
+                {
+                    "filter"
+                } else {
+                    "max_length"
+                };
+                self.report(
+                    BuildDiagnosticKind::UnsupportedAttribute,
+                    node.attribute_span(invalid),
+                    || message,
+                );
+                InputConstraints::default()
+            }
+        };
 
         // Get handler from events
         let on_input = node
@@ -135,6 +175,24 @@ impl<'a> DampenWidgetBuilder<'a> {
             .find(|e| e.event == dampen_core::EventKind::Submit)
             .map(|e| e.handler.clone());
 
+        // A debounced input displays the text typed since its handler last ran
+        let debounce = node
+            .attributes
+            .get("debounce_ms")
+            .and_then(|attr| self.evaluate_attribute(attr).trim().parse::<u64>().ok())
+            .map(std::time::Duration::from_millis);
+        if let (Some(_), Some(handler_name)) = (debounce, &on_input)
+            && let Some(pending) = crate::debounce::pending_text(handler_name)
+        {
+            value = pending;
+        }
+
+        #[cfg(debug_assertions)]
+        eprintln!(
+            "[DampenWidgetBuilder] Building text_input: placeholder='{}', value='{}', password={}",
+            placeholder, value, is_password
+        );
+
         #[cfg(debug_assertions)]
         {
             if let Some(handler) = &on_input {
@@ -196,8 +254,14 @@ impl<'a> DampenWidgetBuilder<'a> {
             }
         }
 
-        // Note: Password masking with dots is not available in Iced 0.14's public API
-        // The input still works but text is visible
+        text_input = text_input.secure(is_password);
+
+        // Give the input a stable id so focus can be restored after a hot-reload
+        let tracked_id = node.id.clone();
+        if let Some(id) = &tracked_id {
+            text_input = text_input.id(id.clone());
+        }
+        let tracker = self.widget_state;
 
         // Connect events if handlers exist
         if let Some(handler_name) = on_input
@@ -208,8 +272,28 @@ impl<'a> DampenWidgetBuilder<'a> {
                 "[DampenWidgetBuilder] TextInput: Attaching on_input with handler '{}'",
                 handler_name
             );
+            let tracked_id = tracked_id.clone();
+            let current = value.clone();
             text_input = text_input.on_input(move |input_value| {
-                HandlerMessage::Handler(handler_name.clone(), Some(input_value))
+                let input_value = constraints.apply(&current, input_value);
+                // Typing implies focus. Iced does not expose the cursor position to
+                // the builder, so it is derived from the edit.
+                if let (Some(tracker), Some(id)) = (tracker, &tracked_id) {
+                    tracker.record_focus(id);
+                    tracker.record_cursor(id, TextCursor::after_edit(&current, &input_value));
+                }
+                match debounce {
+                    Some(delay) => {
+                        crate::debounce::submit(
+                            &handler_name,
+                            input_value.clone(),
+                            HandlerMessage::Handler(handler_name.clone(), Some(input_value)),
+                            delay,
+                        );
+                        HandlerMessage::None
+                    }
+                    None => HandlerMessage::Handler(handler_name.clone(), Some(input_value)),
+                }
             });
         }
 
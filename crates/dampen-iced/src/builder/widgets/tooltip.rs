@@ -2,8 +2,9 @@
 
 use crate::HandlerMessage;
 use crate::builder::DampenWidgetBuilder;
-use dampen_core::ir::node::{AttributeValue, WidgetNode};
+use dampen_core::ir::node::{AttributeValue, WidgetKind, WidgetNode};
 use iced::{Element, Renderer, Theme};
+use std::time::Duration;
 
 impl<'a> DampenWidgetBuilder<'a> {
     pub(in crate::builder) fn build_tooltip(
@@ -21,7 +22,7 @@ impl<'a> DampenWidgetBuilder<'a> {
 
         // Get position attribute (default: FollowCursor)
         let position_str = match node.attributes.get("position") {
-            Some(AttributeValue::Static(s)) => s.as_str(),
+            Some(AttributeValue::Static(s)) => s.trim(),
             _ => "follow_cursor",
         };
 
@@ -34,10 +35,34 @@ impl<'a> DampenWidgetBuilder<'a> {
             _ => iced::widget::tooltip::Position::FollowCursor,
         };
 
-        // Tooltip must have exactly one child
-        if let Some(child) = node.children.first() {
+        // Delay before showing the tooltip (default: immediately)
+        let delay = node
+            .attributes
+            .get("delay_ms")
+            .and_then(|attr| self.evaluate_attribute(attr).trim().parse::<u64>().ok())
+            .map(Duration::from_millis)
+            .unwrap_or_default();
+
+        // Rich <tooltip_content> takes precedence over the message
+        let tooltip_content: Element<'a, HandlerMessage, Theme, Renderer> = match node
+            .children
+            .iter()
+            .find(|child| child.kind == WidgetKind::TooltipContent)
+        {
+            Some(content) => self.build_widget(content),
+            None => iced::widget::text(message).into(),
+        };
+
+        // Tooltip must have exactly one child besides its content
+        if let Some(child) = node
+            .children
+            .iter()
+            .find(|child| child.kind != WidgetKind::TooltipContent)
+        {
             let child_widget = self.build_widget(child);
-            iced::widget::tooltip(child_widget, iced::widget::text(message), position).into()
+            iced::widget::tooltip(child_widget, tooltip_content, position)
+                .delay(delay)
+                .into()
         } else {
             // No child - return empty text
             iced::widget::text("").into()
@@ -0,0 +1,145 @@
+//! Tree widget builder
+
+use crate::HandlerMessage;
+use crate::builder::DampenWidgetBuilder;
+use crate::tree::{Tree, TreeRow};
+use dampen_core::binding::BindingValue;
+use dampen_core::ir::EventKind;
+use dampen_core::ir::node::{AttributeValue, WidgetNode};
+use iced::{Element, Renderer, Theme};
+
+impl<'a> DampenWidgetBuilder<'a> {
+    /// Build a tree from Dampen XML definition
+    ///
+    /// Supports the following attributes:
+    /// - `items`: Binding to the list of top-level items
+    /// - `children`: Field holding the children of an item (default: `children`);
+    ///   a `None` field means they are loaded on expansion
+    /// - `each`: Name of the item in `label` and `key` (default: `node`)
+    /// - `label`: Text of a node, e.g. `{node.name}`
+    /// - `key`: Key of a node (default: its position, e.g. `0.2`)
+    /// - `indent`, `size`: Indentation of a level and text size
+    /// - `on_expand`, `on_select`: Handlers receiving the key of a node
+    ///
+    /// Expanded nodes of a tree with an `id` are recorded into the widget state
+    /// tracker, and expanded again after a hot-reload.
+    pub(in crate::builder) fn build_tree(
+        &self,
+        node: &WidgetNode,
+    ) -> Element<'a, HandlerMessage, Theme, Renderer> {
+        let static_attribute = |name: &str, default: &str| match node.attributes.get(name) {
+            Some(AttributeValue::Static(value)) => value.trim().to_string(),
+            _ => default.to_string(),
+        };
+        let children = static_attribute("children", "children");
+        let each = static_attribute("each", "node");
+
+        let items = match node.attributes.get("items") {
+            Some(attr) => match self.evaluate_attribute_value(attr) {
+                BindingValue::List(items) => items,
+                _ => Vec::new(),
+            },
+            None => Vec::new(),
+        };
+        let mut rows = Vec::new();
+        self.push_tree_rows(node, &items, None, 0, &children, &each, &mut rows);
+
+        let mut tree = Tree::new(rows);
+        let number = |name: &str| {
+            node.attributes
+                .get(name)
+                .and_then(|attr| self.evaluate_attribute(attr).trim().parse().ok())
+        };
+        if let Some(indent) = number("indent") {
+            tree = tree.indent(indent);
+        }
+        if let Some(size) = number("size") {
+            tree = tree.size(size);
+        }
+
+        // Restore the expanded nodes after a hot-reload, and record them
+        if let Some((id, tracker)) = node.id.clone().zip(self.widget_state) {
+            if let Some(expanded) = tracker.snapshot().expanded_nodes.remove(&id) {
+                tree = tree.expanded(expanded);
+            }
+            let tracker = tracker.clone();
+            tree = tree
+                .id(id.clone())
+                .on_toggle(move |key, expanded| tracker.record_expanded(&id, key, expanded));
+        }
+
+        for event in &node.events {
+            let message_factory = self.message_factory.clone();
+            let handler = event.handler.clone();
+            let on_key = move |key| message_factory(&handler, Some(key));
+            tree = match event.event {
+                EventKind::Expand => tree.on_expand(on_key),
+                EventKind::Select => tree.on_select(on_key),
+                _ => tree,
+            };
+        }
+
+        self.apply_style_layout(tree, node)
+    }
+
+    /// Flatten bound items and their children into rows, `each` naming the item
+    #[allow(clippy::too_many_arguments)]
+    fn push_tree_rows(
+        &self,
+        node: &WidgetNode,
+        items: &[BindingValue],
+        parent: Option<&str>,
+        depth: usize,
+        children: &str,
+        each: &str,
+        rows: &mut Vec<TreeRow>,
+    ) {
+        for (index, item) in items.iter().enumerate() {
+            let path = match parent {
+                Some(parent) => format!("{parent}.{index}"),
+                None => index.to_string(),
+            };
+
+            self.push_context(each, item.clone());
+            let label = node
+                .attributes
+                .get("label")
+                .map(|attr| self.evaluate_attribute(attr))
+                .unwrap_or_default();
+            let key = node
+                .attributes
+                .get("key")
+                .map(|attr| self.evaluate_attribute(attr))
+                .unwrap_or_else(|| path.clone());
+            self.pop_context();
+
+            // A missing field is a leaf, a `None` one is not loaded yet
+            let item_children = match item {
+                BindingValue::Object(fields) => match fields.get(children) {
+                    Some(BindingValue::List(list)) => Some(list.as_slice()),
+                    Some(BindingValue::None) => None,
+                    _ => Some(&[][..]),
+                },
+                _ => Some(&[][..]),
+            };
+
+            rows.push(TreeRow {
+                key,
+                label,
+                depth,
+                children: item_children.map(<[BindingValue]>::len),
+            });
+            if let Some(item_children) = item_children {
+                self.push_tree_rows(
+                    node,
+                    item_children,
+                    Some(&path),
+                    depth + 1,
+                    children,
+                    each,
+                    rows,
+                );
+            }
+        }
+    }
+}
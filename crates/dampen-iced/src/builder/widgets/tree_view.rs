@@ -82,7 +82,11 @@ impl<'a> DampenWidgetBuilder<'a> {
                     let result = if let Some(ctx_value) = self.resolve_from_context(expr) {
                         Ok(ctx_value)
                     } else {
-                        evaluate_binding_expr_with_shared(expr, self.model, self.shared_context)
+                        evaluate_binding_expr_with_shared(
+                            expr,
+                            &self.binding_model(),
+                            self.shared_context,
+                        )
                     };
                     match result {
                         Ok(BindingValue::List(items)) => items
@@ -106,7 +110,11 @@ impl<'a> DampenWidgetBuilder<'a> {
                     let result = if let Some(ctx_value) = self.resolve_from_context(expr) {
                         Ok(ctx_value)
                     } else {
-                        evaluate_binding_expr_with_shared(expr, self.model, self.shared_context)
+                        evaluate_binding_expr_with_shared(
+                            expr,
+                            &self.binding_model(),
+                            self.shared_context,
+                        )
                     };
                     match result {
                         Ok(BindingValue::String(s)) => Some(s),
@@ -129,7 +137,11 @@ impl<'a> DampenWidgetBuilder<'a> {
                         let result = if let Some(ctx_value) = self.resolve_from_context(expr) {
                             Ok(ctx_value)
                         } else {
-                            evaluate_binding_expr_with_shared(expr, self.model, self.shared_context)
+                            evaluate_binding_expr_with_shared(
+                                expr,
+                                &self.binding_model(),
+                                self.shared_context,
+                            )
                         };
                         match result {
                             Ok(BindingValue::List(items)) => {
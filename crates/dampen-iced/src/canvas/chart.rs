@@ -0,0 +1,429 @@
+//! Built-in charts drawn on a canvas
+//!
+//! `<chart>` renders a collection as a line, bar or pie chart. Each item of the
+//! bound collection becomes a [`ChartPoint`], labelled by its `x` field and sized
+//! by its `y` field:
+//!
+//! ```xml
+//! <chart type="line" data="{points}" x="time" y="value" show_legend="true" />
+//! ```
+//!
+//! Colors come from the current theme's palette unless `color` is set, so charts
+//! follow theme switches like the other widgets.
+
+use iced::alignment::{Horizontal, Vertical};
+use iced::widget::canvas::path::Arc;
+use iced::widget::canvas::{self, Frame, Geometry, Path, Stroke};
+use iced::{Color, Point, Radians, Rectangle, Renderer, Size, Theme, mouse};
+
+/// Font size of axis labels and legend entries
+const LABEL_SIZE: f32 = 11.0;
+
+/// Minimum horizontal space between two x-axis labels
+const MIN_LABEL_SPACING: f32 = 48.0;
+
+/// Type of chart
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChartKind {
+    /// Points joined by a line
+    #[default]
+    Line,
+    /// One vertical bar per point
+    Bar,
+    /// One slice per point, proportional to its share of the total
+    Pie,
+}
+
+impl ChartKind {
+    /// Parse the value of the `type` attribute (`line`, `bar` or `pie`)
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "line" => Some(ChartKind::Line),
+            "bar" => Some(ChartKind::Bar),
+            "pie" => Some(ChartKind::Pie),
+            _ => None,
+        }
+    }
+}
+
+/// A labelled value of a chart
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartPoint {
+    /// Label shown on the x-axis, or in the legend of pie charts
+    pub label: String,
+    /// Value of the point
+    pub value: f32,
+}
+
+impl ChartPoint {
+    /// Creates a new [`ChartPoint`].
+    pub fn new(label: impl Into<String>, value: f32) -> Self {
+        Self {
+            label: label.into(),
+            value,
+        }
+    }
+}
+
+/// A canvas program drawing a [`ChartKind`] from a list of [`ChartPoint`]s.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartProgram {
+    kind: ChartKind,
+    points: Vec<ChartPoint>,
+    color: Option<Color>,
+    series: Option<String>,
+    show_axes: bool,
+    show_legend: bool,
+}
+
+impl ChartProgram {
+    /// Creates a new [`ChartProgram`] with axes and without legend.
+    pub fn new(kind: ChartKind, points: Vec<ChartPoint>) -> Self {
+        Self {
+            kind,
+            points,
+            color: None,
+            series: None,
+            show_axes: true,
+            show_legend: false,
+        }
+    }
+
+    /// Sets the color of the series, instead of the theme's primary color.
+    ///
+    /// Pie charts use it for their first slice.
+    pub fn color(mut self, color: Option<Color>) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Sets the name of the series shown in the legend of line and bar charts.
+    pub fn series(mut self, series: Option<String>) -> Self {
+        self.series = series;
+        self
+    }
+
+    /// Shows or hides the axes and their labels. Pie charts have no axes.
+    pub fn show_axes(mut self, show_axes: bool) -> Self {
+        self.show_axes = show_axes;
+        self
+    }
+
+    /// Shows or hides the legend.
+    pub fn show_legend(mut self, show_legend: bool) -> Self {
+        self.show_legend = show_legend;
+        self
+    }
+}
+
+impl<M> canvas::Program<M> for ChartProgram {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let colors = ChartColors::new(theme, self.color);
+
+        match self.kind {
+            ChartKind::Line | ChartKind::Bar => self.draw_cartesian(&mut frame, &colors),
+            ChartKind::Pie => self.draw_pie(&mut frame, &colors),
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+/// Colors of a chart, derived from the theme
+struct ChartColors {
+    text: Color,
+    axis: Color,
+    /// Colors of successive series or slices
+    series: Vec<Color>,
+}
+
+impl ChartColors {
+    fn new(theme: &Theme, color: Option<Color>) -> Self {
+        let palette = theme.extended_palette();
+        let mut series = vec![
+            palette.primary.base.color,
+            palette.success.base.color,
+            palette.warning.base.color,
+            palette.danger.base.color,
+            palette.secondary.base.color,
+            palette.primary.strong.color,
+            palette.success.strong.color,
+            palette.danger.strong.color,
+        ];
+        if let Some(color) = color {
+            series[0] = color;
+        }
+
+        Self {
+            text: palette.background.base.text,
+            axis: palette.background.strong.color,
+            series,
+        }
+    }
+
+    fn series(&self, index: usize) -> Color {
+        self.series[index % self.series.len()]
+    }
+}
+
+impl ChartProgram {
+    fn draw_cartesian(&self, frame: &mut Frame, colors: &ChartColors) {
+        let legend_height = if self.show_legend {
+            LABEL_SIZE * 2.0
+        } else {
+            0.0
+        };
+        let (left, bottom) = if self.show_axes {
+            (40.0, 20.0)
+        } else {
+            (4.0, 4.0)
+        };
+        let plot = Rectangle {
+            x: left,
+            y: 4.0 + legend_height,
+            width: (frame.width() - left - 8.0).max(1.0),
+            height: (frame.height() - bottom - legend_height - 4.0).max(1.0),
+        };
+
+        if self.show_legend {
+            let label = self.series.clone().unwrap_or_default();
+            draw_legend_entry(
+                frame,
+                colors,
+                Point::new(plot.x, 4.0),
+                colors.series(0),
+                label,
+            );
+        }
+
+        let (min, max) = value_range(&self.points);
+        let baseline = y_position(plot, 0.0, min, max);
+
+        if self.show_axes {
+            draw_axes(frame, colors, plot, baseline, min, max);
+        }
+
+        if self.points.is_empty() {
+            return;
+        }
+
+        let slot = plot.width / self.points.len() as f32;
+        let x_of = |index: usize| plot.x + slot * (index as f32 + 0.5);
+
+        if self.show_axes {
+            let step = ((MIN_LABEL_SPACING / slot).ceil() as usize).max(1);
+            for (index, point) in self.points.iter().enumerate().step_by(step) {
+                draw_label(
+                    frame,
+                    colors.text,
+                    point.label.clone(),
+                    Point::new(x_of(index), plot.y + plot.height + 4.0),
+                    Horizontal::Center,
+                    Vertical::Top,
+                );
+            }
+        }
+
+        let color = colors.series(0);
+        match self.kind {
+            ChartKind::Bar => {
+                let bar_width = slot * 0.7;
+                for (index, point) in self.points.iter().enumerate() {
+                    let top = y_position(plot, point.value, min, max);
+                    let bar = Path::rectangle(
+                        Point::new(x_of(index) - bar_width / 2.0, top.min(baseline)),
+                        Size::new(bar_width, (top - baseline).abs()),
+                    );
+                    frame.fill(&bar, color);
+                }
+            }
+            _ => {
+                let positions: Vec<Point> = self
+                    .points
+                    .iter()
+                    .enumerate()
+                    .map(|(index, point)| {
+                        Point::new(x_of(index), y_position(plot, point.value, min, max))
+                    })
+                    .collect();
+
+                let line = Path::new(|builder| {
+                    for (index, position) in positions.iter().enumerate() {
+                        if index == 0 {
+                            builder.move_to(*position);
+                        } else {
+                            builder.line_to(*position);
+                        }
+                    }
+                });
+                frame.stroke(&line, Stroke::default().with_color(color).with_width(2.0));
+
+                for position in positions {
+                    frame.fill(&Path::circle(position, 3.0), color);
+                }
+            }
+        }
+    }
+
+    fn draw_pie(&self, frame: &mut Frame, colors: &ChartColors) {
+        let total: f32 = self.points.iter().map(|p| p.value.max(0.0)).sum();
+        let legend_width = if self.show_legend {
+            (frame.width() / 3.0).min(160.0)
+        } else {
+            0.0
+        };
+
+        let center = Point::new((frame.width() - legend_width) / 2.0, frame.height() / 2.0);
+        let radius = (center.x.min(center.y) - 4.0).max(1.0);
+
+        if total > 0.0 {
+            let mut start = -std::f32::consts::FRAC_PI_2;
+            for (index, point) in self.points.iter().enumerate() {
+                let sweep = point.value.max(0.0) / total * std::f32::consts::TAU;
+                if sweep <= 0.0 {
+                    continue;
+                }
+
+                let slice = Path::new(|builder| {
+                    builder.arc(Arc {
+                        center,
+                        radius,
+                        start_angle: Radians(start),
+                        end_angle: Radians(start + sweep),
+                    });
+                    builder.line_to(center);
+                    builder.close();
+                });
+                frame.fill(&slice, colors.series(index));
+                start += sweep;
+            }
+        }
+
+        if self.show_legend {
+            let x = frame.width() - legend_width + 8.0;
+            for (index, point) in self.points.iter().enumerate() {
+                let y = 4.0 + index as f32 * LABEL_SIZE * 1.8;
+                draw_legend_entry(
+                    frame,
+                    colors,
+                    Point::new(x, y),
+                    colors.series(index),
+                    point.label.clone(),
+                );
+            }
+        }
+    }
+}
+
+/// Range of the y-axis, always including zero
+fn value_range(points: &[ChartPoint]) -> (f32, f32) {
+    let (min, max) = points
+        .iter()
+        .map(|point| point.value)
+        .filter(|value| value.is_finite())
+        .fold((0.0_f32, 0.0_f32), |(min, max), value| {
+            (min.min(value), max.max(value))
+        });
+
+    if max > min {
+        (min, max)
+    } else {
+        (min, min + 1.0)
+    }
+}
+
+fn y_position(plot: Rectangle, value: f32, min: f32, max: f32) -> f32 {
+    let value = if value.is_finite() { value } else { 0.0 };
+    plot.y + plot.height * (1.0 - (value - min) / (max - min))
+}
+
+fn draw_axes(
+    frame: &mut Frame,
+    colors: &ChartColors,
+    plot: Rectangle,
+    baseline: f32,
+    min: f32,
+    max: f32,
+) {
+    let stroke = Stroke::default().with_color(colors.axis).with_width(1.0);
+    let bottom = plot.y + plot.height;
+
+    frame.stroke(
+        &Path::line(Point::new(plot.x, plot.y), Point::new(plot.x, bottom)),
+        stroke,
+    );
+    frame.stroke(
+        &Path::line(
+            Point::new(plot.x, baseline),
+            Point::new(plot.x + plot.width, baseline),
+        ),
+        stroke,
+    );
+
+    for (value, y) in [(max, plot.y), (min, bottom)] {
+        draw_label(
+            frame,
+            colors.text,
+            format_value(value),
+            Point::new(plot.x - 4.0, y),
+            Horizontal::Right,
+            Vertical::Center,
+        );
+    }
+}
+
+fn draw_legend_entry(
+    frame: &mut Frame,
+    colors: &ChartColors,
+    position: Point,
+    color: Color,
+    label: String,
+) {
+    frame.fill_rectangle(position, Size::new(LABEL_SIZE, LABEL_SIZE), color);
+    draw_label(
+        frame,
+        colors.text,
+        label,
+        Point::new(position.x + LABEL_SIZE + 4.0, position.y + LABEL_SIZE / 2.0),
+        Horizontal::Left,
+        Vertical::Center,
+    );
+}
+
+fn draw_label(
+    frame: &mut Frame,
+    color: Color,
+    content: String,
+    position: Point,
+    align_x: Horizontal,
+    align_y: Vertical,
+) {
+    frame.fill_text(canvas::Text {
+        content,
+        position,
+        color,
+        size: LABEL_SIZE.into(),
+        align_x: align_x.into(),
+        align_y,
+        ..Default::default()
+    });
+}
+
+/// Format an axis value without needless decimals
+fn format_value(value: f32) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e9 {
+        (value as i64).to_string()
+    } else {
+        format!("{:.2}", value)
+    }
+}
@@ -1,7 +1,7 @@
 //! Canvas event handling logic.
 
-use dampen_core::handler::CanvasEvent;
-use iced::Point;
+use dampen_core::handler::{CanvasEvent, PointerButton, PointerModifiers};
+use iced::{Point, keyboard, mouse};
 
 /// Handlers for canvas interaction events.
 #[derive(Debug, Clone)]
@@ -30,6 +30,8 @@ pub fn create_canvas_event(
     kind: dampen_core::handler::CanvasEventKind,
     position: Point,
     delta: Option<(f32, f32)>,
+    button: Option<mouse::Button>,
+    modifiers: keyboard::Modifiers,
 ) -> CanvasEvent {
     let (delta_x, delta_y) = match delta {
         Some((dx, dy)) => (Some(dx), Some(dy)),
@@ -42,5 +44,29 @@ pub fn create_canvas_event(
         y: position.y,
         delta_x,
         delta_y,
+        button: button.map(pointer_button),
+        modifiers: pointer_modifiers(modifiers),
+    }
+}
+
+/// Converts an Iced mouse button to the button of a [`CanvasEvent`].
+pub fn pointer_button(button: mouse::Button) -> PointerButton {
+    match button {
+        mouse::Button::Left => PointerButton::Left,
+        mouse::Button::Right => PointerButton::Right,
+        mouse::Button::Middle => PointerButton::Middle,
+        mouse::Button::Back => PointerButton::Back,
+        mouse::Button::Forward => PointerButton::Forward,
+        mouse::Button::Other(id) => PointerButton::Other(id),
+    }
+}
+
+/// Converts Iced keyboard modifiers to the modifiers of a [`CanvasEvent`].
+pub fn pointer_modifiers(modifiers: keyboard::Modifiers) -> PointerModifiers {
+    PointerModifiers {
+        shift: modifiers.shift(),
+        control: modifiers.control(),
+        alt: modifiers.alt(),
+        logo: modifiers.logo(),
     }
 }
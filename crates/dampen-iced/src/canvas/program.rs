@@ -1,10 +1,13 @@
 use crate::canvas::custom::AnyState;
 use crate::canvas::events::{CanvasEventHandlers, create_canvas_event};
 use crate::canvas::shapes::{
-    CanvasShape, CircleShape, GroupShape, LineShape, RectShape, TextShape, Transform,
+    ArcShape, BezierShape, CanvasShape, CircleShape, GroupShape, LineShape, PathShape, RectShape,
+    TextShape, Transform,
 };
+use dampen_core::ir::PathCommand;
+use iced::widget::canvas::path::Arc;
 use iced::widget::canvas::{self, Cache, Event, Frame, Geometry, Path, Stroke};
-use iced::{Color, Point, Rectangle, Renderer, Size, Theme, Vector, mouse};
+use iced::{Color, Point, Rectangle, Renderer, Size, Theme, Vector, keyboard, mouse};
 use std::cell::RefCell;
 
 /// The content of a canvas, which can be either a list of declarative shapes
@@ -45,8 +48,9 @@ pub struct DeclarativeState {
     cache: RefCell<Cache>,
     last_shapes: RefCell<Vec<CanvasShape>>,
     // Interaction state
-    is_dragging: bool,
+    pressed_button: Option<mouse::Button>,
     last_position: Option<Point>,
+    modifiers: keyboard::Modifiers,
 }
 
 impl<M> DeclarativeProgram<M> {
@@ -210,11 +214,18 @@ impl<M> DeclarativeProgram<M> {
         cursor: iced::mouse::Cursor,
     ) -> Option<canvas::Action<M>> {
         let handlers = self.event_handlers.as_ref()?;
+
+        // Modifiers change wherever the cursor is
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
+            state.modifiers = *modifiers;
+            return None;
+        }
+
         let position = cursor.position_in(bounds)?;
 
         match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
-                state.is_dragging = true;
+            Event::Mouse(mouse::Event::ButtonPressed(button)) => {
+                state.pressed_button = Some(*button);
                 state.last_position = Some(position);
 
                 if let Some(ref name) = handlers.handler_names.on_click {
@@ -222,12 +233,14 @@ impl<M> DeclarativeProgram<M> {
                         dampen_core::handler::CanvasEventKind::Click,
                         position,
                         None,
+                        Some(*button),
+                        state.modifiers,
                     );
                     return Some(canvas::Action::publish((handlers.msg_factory)(name, event)));
                 }
             }
             Event::Mouse(mouse::Event::CursorMoved { .. }) => {
-                if state.is_dragging {
+                if let Some(button) = state.pressed_button {
                     let delta = state
                         .last_position
                         .map(|last| (position.x - last.x, position.y - last.y));
@@ -238,6 +251,8 @@ impl<M> DeclarativeProgram<M> {
                             dampen_core::handler::CanvasEventKind::Drag,
                             position,
                             delta,
+                            Some(button),
+                            state.modifiers,
                         );
                         return Some(canvas::Action::publish((handlers.msg_factory)(name, event)));
                     }
@@ -246,12 +261,16 @@ impl<M> DeclarativeProgram<M> {
                         dampen_core::handler::CanvasEventKind::Move,
                         position,
                         None,
+                        None,
+                        state.modifiers,
                     );
                     return Some(canvas::Action::publish((handlers.msg_factory)(name, event)));
                 }
             }
-            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
-                state.is_dragging = false;
+            Event::Mouse(mouse::Event::ButtonReleased(button))
+                if state.pressed_button == Some(*button) =>
+            {
+                state.pressed_button = None;
                 state.last_position = None;
 
                 if let Some(ref name) = handlers.handler_names.on_release {
@@ -259,6 +278,8 @@ impl<M> DeclarativeProgram<M> {
                         dampen_core::handler::CanvasEventKind::Release,
                         position,
                         None,
+                        Some(*button),
+                        state.modifiers,
                     );
                     return Some(canvas::Action::publish((handlers.msg_factory)(name, event)));
                 }
@@ -290,9 +311,79 @@ fn draw_shape(frame: &mut Frame, shape: &CanvasShape) {
         CanvasShape::Line(line) => draw_line(frame, line),
         CanvasShape::Text(text) => draw_text(frame, text),
         CanvasShape::Group(group) => draw_group(frame, group),
+        CanvasShape::Path(path) => draw_path(frame, path),
+        CanvasShape::Arc(arc) => draw_arc(frame, arc),
+        CanvasShape::Bezier(bezier) => draw_bezier(frame, bezier),
     }
 }
 
+/// Fill and stroke a path with the given colors
+fn paint(frame: &mut Frame, path: &Path, fill: Option<Color>, stroke: Option<Color>, width: f32) {
+    if let Some(fill) = fill {
+        frame.fill(path, fill);
+    }
+
+    if let Some(stroke_color) = stroke {
+        let stroke = Stroke {
+            style: canvas::Style::Solid(stroke_color),
+            width,
+            line_cap: canvas::LineCap::Round,
+            ..Default::default()
+        };
+        frame.stroke(path, stroke);
+    }
+}
+
+fn draw_path(frame: &mut Frame, shape: &PathShape) {
+    let path = Path::new(|builder| {
+        for command in &shape.commands {
+            match *command {
+                PathCommand::MoveTo(x, y) => builder.move_to(Point::new(x, y)),
+                PathCommand::LineTo(x, y) => builder.line_to(Point::new(x, y)),
+                PathCommand::QuadraticTo(cx, cy, x, y) => {
+                    builder.quadratic_curve_to(Point::new(cx, cy), Point::new(x, y));
+                }
+                PathCommand::CubicTo(cx1, cy1, cx2, cy2, x, y) => builder.bezier_curve_to(
+                    Point::new(cx1, cy1),
+                    Point::new(cx2, cy2),
+                    Point::new(x, y),
+                ),
+                PathCommand::Close => builder.close(),
+            }
+        }
+    });
+
+    paint(frame, &path, shape.fill, shape.stroke, shape.stroke_width);
+}
+
+fn draw_arc(frame: &mut Frame, shape: &ArcShape) {
+    let path = Path::new(|builder| {
+        builder.arc(Arc {
+            center: Point::new(shape.cx, shape.cy),
+            radius: shape.radius,
+            start_angle: iced::Radians(shape.start_angle),
+            end_angle: iced::Radians(shape.end_angle),
+        });
+    });
+
+    paint(frame, &path, shape.fill, shape.stroke, shape.stroke_width);
+}
+
+fn draw_bezier(frame: &mut Frame, shape: &BezierShape) {
+    let path = Path::new(|builder| {
+        let control1 = Point::new(shape.cx1, shape.cy1);
+        let end = Point::new(shape.x2, shape.y2);
+
+        builder.move_to(Point::new(shape.x1, shape.y1));
+        match shape.control2 {
+            Some((cx2, cy2)) => builder.bezier_curve_to(control1, Point::new(cx2, cy2), end),
+            None => builder.quadratic_curve_to(control1, end),
+        }
+    });
+
+    paint(frame, &path, shape.fill, shape.stroke, shape.stroke_width);
+}
+
 fn draw_rect(frame: &mut Frame, rect: &RectShape) {
     let top_left = Point::new(rect.x, rect.y);
     let size = Size::new(rect.width, rect.height);
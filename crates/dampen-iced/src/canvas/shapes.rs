@@ -1,5 +1,6 @@
 //! Runtime shape definitions for the Canvas widget.
 
+use dampen_core::ir::PathCommand;
 use iced::Color;
 
 /// A runtime representation of a shape that can be drawn on a canvas.
@@ -15,6 +16,12 @@ pub enum CanvasShape {
     Text(TextShape),
     /// A group of shapes with an optional transformation.
     Group(GroupShape),
+    /// A free-form path.
+    Path(PathShape),
+    /// A circular arc.
+    Arc(ArcShape),
+    /// A quadratic or cubic Bézier curve.
+    Bezier(BezierShape),
 }
 
 /// A rectangle shape with optional fill, stroke, and rounded corners.
@@ -87,6 +94,67 @@ pub struct TextShape {
     pub color: Option<Color>,
 }
 
+/// A free-form path with optional fill and stroke.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathShape {
+    /// The drawing commands, in absolute coordinates.
+    pub commands: Vec<PathCommand>,
+    /// The fill color of the path.
+    pub fill: Option<Color>,
+    /// The stroke color of the path.
+    pub stroke: Option<Color>,
+    /// The width of the stroke.
+    pub stroke_width: f32,
+}
+
+/// A circular arc with optional fill and stroke.
+///
+/// Filling an arc fills the area between the arc and its chord.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArcShape {
+    /// The X coordinate of the center.
+    pub cx: f32,
+    /// The Y coordinate of the center.
+    pub cy: f32,
+    /// The radius of the arc.
+    pub radius: f32,
+    /// The start angle in radians.
+    pub start_angle: f32,
+    /// The end angle in radians.
+    pub end_angle: f32,
+    /// The fill color of the arc.
+    pub fill: Option<Color>,
+    /// The stroke color of the arc.
+    pub stroke: Option<Color>,
+    /// The width of the stroke.
+    pub stroke_width: f32,
+}
+
+/// A Bézier curve between two points.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BezierShape {
+    /// The X coordinate of the start point.
+    pub x1: f32,
+    /// The Y coordinate of the start point.
+    pub y1: f32,
+    /// The X coordinate of the first control point.
+    pub cx1: f32,
+    /// The Y coordinate of the first control point.
+    pub cy1: f32,
+    /// The second control point; the curve is quadratic when absent.
+    pub control2: Option<(f32, f32)>,
+    /// The X coordinate of the end point.
+    pub x2: f32,
+    /// The Y coordinate of the end point.
+    pub y2: f32,
+    /// The fill color of the curve.
+    pub fill: Option<Color>,
+    /// The stroke color of the curve.
+    pub stroke: Option<Color>,
+    /// The width of the stroke.
+    pub stroke_width: f32,
+}
+
 /// A container for multiple shapes that can be transformed as a single unit.
 #[derive(Debug, Clone, PartialEq)]
 pub struct GroupShape {
@@ -0,0 +1,303 @@
+//! Code editor
+//!
+//! [`CodeEditor`] is a syntax-highlighted, multi-line text area with line
+//! numbers, bound to a string of the model. It backs the `<code_editor>` widget:
+//!
+//! ```xml
+//! <code_editor language="rust" value="{source}" on_change="update_source" height="300" />
+//! ```
+//!
+//! The editor keeps its own text, cursor and selection: each edit is sent to
+//! `on_change` with the whole text, and the text is only replaced when the
+//! bound value changes to something else. Without `on_change`, the code can be
+//! selected and copied but not edited.
+//!
+//! Highlighting is done by [`Syntax`] with the syntect grammars, in a dark or
+//! light syntect theme matching the theme of the application.
+
+use iced::advanced::widget::{Operation, Tree, tree};
+use iced::advanced::{Clipboard, Layout, Shell, Widget, layout, mouse, renderer};
+use iced::widget::text::Wrapping;
+use iced::widget::text_editor::{Action, Content};
+use iced::widget::{column, container, row, scrollable, text, text_editor};
+use iced::{Element, Event, Font, Length, Padding, Rectangle, Size, Theme};
+
+use crate::syntax::{self, Syntax};
+
+/// Text size of the editor without a `size`
+pub const DEFAULT_SIZE: f32 = 14.0;
+
+/// Padding of the editor, also applied to the line numbers so they line up
+const PADDING: f32 = 6.0;
+
+/// Syntax-highlighted text area with line numbers
+pub struct CodeEditor<'a, Message> {
+    value: String,
+    language: String,
+    size: f32,
+    height: Length,
+    line_numbers: bool,
+    on_change: Option<Box<dyn Fn(String) -> Message + 'a>>,
+}
+
+/// Text being edited, and the value it was last synchronized with
+struct State {
+    content: Content,
+    value: String,
+}
+
+impl<'a, Message> CodeEditor<'a, Message> {
+    /// Create an editor showing `value`
+    pub fn new(value: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            language: String::from("plain"),
+            size: DEFAULT_SIZE,
+            height: Length::Shrink,
+            line_numbers: true,
+            on_change: None,
+        }
+    }
+
+    /// Highlight the code as `language`, e.g. `rust` (see [`syntax::find_syntax`])
+    #[must_use]
+    pub fn language(mut self, language: &str) -> Self {
+        self.language = language.to_string();
+        self
+    }
+
+    /// Set the text size
+    #[must_use]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the height, scrolling the code when it is longer
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Show or hide the line numbers, shown by default
+    #[must_use]
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Emit a message with the whole text after each edit
+    #[must_use]
+    pub fn on_change(mut self, on_change: impl Fn(String) -> Message + 'a) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Line numbers next to the iced text editor of `content`
+    fn editor<'b>(&self, content: &'b Content) -> Element<'b, Action> {
+        let editor = text_editor(content)
+            .on_action(|action| action)
+            .highlight_with::<Syntax>(self.language.clone(), syntax::format)
+            .font(Font::MONOSPACE)
+            .size(self.size)
+            .padding(PADDING)
+            .wrapping(Wrapping::None)
+            .height(Length::Shrink);
+
+        let body: Element<'b, Action> = if self.line_numbers {
+            let numbers = (1..=content.line_count()).map(|number| {
+                text(number.to_string())
+                    .font(Font::MONOSPACE)
+                    .size(self.size)
+                    .style(|theme: &Theme| text::Style {
+                        color: Some(theme.palette().text.scale_alpha(0.5)),
+                    })
+                    .into()
+            });
+            let gutter = container(column(numbers).align_x(iced::Alignment::End)).padding(
+                Padding::new(PADDING)
+                    .left(PADDING * 1.5)
+                    .right(PADDING * 0.5),
+            );
+            row![gutter, editor].into()
+        } else {
+            editor.into()
+        };
+
+        match self.height {
+            Length::Shrink => body,
+            height => scrollable(body).height(height).into(),
+        }
+    }
+}
+
+impl<Message> Widget<Message, Theme, iced::Renderer> for CodeEditor<'_, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            content: Content::with_text(&self.value),
+            value: self.value.clone(),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        let content = Content::with_text(&self.value);
+        vec![Tree::new(self.editor(&content))]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        // Only a value changed by the application replaces the text being edited
+        if state.value != self.value {
+            state.content = Content::with_text(&self.value);
+            state.value = self.value.clone();
+        }
+        let editor = self.editor(&state.content);
+        tree.children[0].diff(&editor);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, self.height)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &iced::Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_ref::<State>();
+        self.editor(&state.content)
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut iced::Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        self.editor(&state.content).as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &iced::Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        self.editor(&state.content).as_widget_mut().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &iced::Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        // The iced editor emits actions, applied here to the text it borrows
+        let mut actions = Vec::new();
+        {
+            let state = tree.state.downcast_ref::<State>();
+            let mut editor = self.editor(&state.content);
+            let mut editor_shell = Shell::new(&mut actions);
+            editor.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                &mut editor_shell,
+                viewport,
+            );
+
+            if editor_shell.is_event_captured() {
+                shell.capture_event();
+            }
+            shell.request_redraw_at(editor_shell.redraw_request());
+            shell.request_input_method(editor_shell.input_method());
+            if editor_shell.is_layout_invalid() {
+                shell.invalidate_layout();
+            }
+            if editor_shell.are_widgets_invalid() {
+                shell.invalidate_widgets();
+            }
+        }
+        if actions.is_empty() {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let mut edited = false;
+        for action in actions {
+            // Read-only editors still move the cursor and select text
+            if action.is_edit() && self.on_change.is_none() {
+                continue;
+            }
+            edited |= action.is_edit();
+            state.content.perform(action);
+        }
+        shell.invalidate_layout();
+        shell.request_redraw();
+
+        if edited && let Some(on_change) = &self.on_change {
+            state.value = state.content.text();
+            shell.publish(on_change(state.value.clone()));
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &iced::Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        self.editor(&state.content).as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message: 'a> From<CodeEditor<'a, Message>> for Element<'a, Message> {
+    fn from(editor: CodeEditor<'a, Message>) -> Self {
+        Element::new(editor)
+    }
+}
@@ -4,9 +4,10 @@
 //! for use in the DampenWidgetBuilder.
 
 pub use crate::style_mapping::{
-    IcedLayout, get_z_index, has_positioning, map_alignment, map_background, map_border_radius,
-    map_color, map_gradient, map_justification, map_layout_constraints, map_length, map_padding,
-    map_style_properties,
+    IcedLayout, get_z_index, has_positioning, map_alignment, map_background,
+    map_background_with_theme, map_border_radius, map_color, map_gradient, map_gradient_with_theme,
+    map_justification, map_layout_constraints, map_length, map_padding, map_style_properties,
+    map_style_properties_with_theme,
 };
 
 // Re-export IR types for convenience
@@ -0,0 +1,137 @@
+//! Debounced input events
+//!
+//! A text input with a `debounce_ms` attribute dispatches its `on_input` handler
+//! once typing paused for that many milliseconds, with the last value typed:
+//!
+//! ```xml
+//! <text_input value="{query}" on_input="search" debounce_ms="300" />
+//! ```
+//!
+//! Each keystroke [`submit`]s the message of the handler, replacing the one still
+//! waiting for the same key; the text typed meanwhile is displayed from
+//! [`pending_text`] since the model is not updated yet. [`ready`] emits the
+//! messages whose delay elapsed.
+//!
+//! `#[dampen_app]` and the generated production code wire this up automatically
+//! for views using `debounce_ms`.
+
+use iced::Subscription;
+use iced::futures::channel::oneshot;
+use iced::futures::stream::{self, Stream};
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+/// A message waiting for its delay to elapse
+struct Pending {
+    generation: u64,
+    text: String,
+}
+
+/// Global debouncing state
+struct Debouncer {
+    pending: HashMap<String, Pending>,
+    next_generation: u64,
+    /// Messages whose delay elapsed, not emitted yet
+    ready: Vec<Box<dyn Any + Send>>,
+    /// Subscriptions waiting for a message
+    waiters: Vec<oneshot::Sender<()>>,
+}
+
+static DEBOUNCER: LazyLock<Mutex<Debouncer>> = LazyLock::new(|| {
+    Mutex::new(Debouncer {
+        pending: HashMap::new(),
+        next_generation: 0,
+        ready: Vec::new(),
+        waiters: Vec::new(),
+    })
+});
+
+fn debouncer() -> MutexGuard<'static, Debouncer> {
+    DEBOUNCER.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Emit `message` after `delay`, unless another one is submitted for `key` first
+///
+/// `text` is the value the input displays until the message is emitted. Keys
+/// are the names of the handlers, so inputs sharing a handler share a delay.
+pub fn submit<M: Send + 'static>(key: &str, text: String, message: M, delay: Duration) {
+    let generation = {
+        let mut state = debouncer();
+        let generation = state.next_generation;
+        state.next_generation += 1;
+        state
+            .pending
+            .insert(key.to_string(), Pending { generation, text });
+        generation
+    };
+
+    let key = key.to_string();
+    std::thread::spawn(move || {
+        std::thread::sleep(delay);
+        let mut state = debouncer();
+        if state
+            .pending
+            .get(&key)
+            .is_some_and(|pending| pending.generation == generation)
+        {
+            state.pending.remove(&key);
+            state.ready.push(Box::new(message));
+            for waiter in state.waiters.drain(..) {
+                let _ = waiter.send(());
+            }
+        }
+    });
+}
+
+/// Text typed in the input of `key` whose message was not emitted yet
+pub fn pending_text(key: &str) -> Option<String> {
+    debouncer()
+        .pending
+        .get(key)
+        .map(|pending| pending.text.clone())
+}
+
+/// Subscription emitting the submitted messages once their delay elapsed
+///
+/// `M` must be the type of the submitted messages; messages of other types are
+/// dropped.
+pub fn ready<M: Send + 'static>() -> Subscription<M> {
+    Subscription::run(watch_ready::<M>)
+}
+
+fn watch_ready<M: Send + 'static>() -> impl Stream<Item = M> {
+    stream::unfold((), |()| async {
+        loop {
+            let (sender, receiver) = oneshot::channel();
+            {
+                let mut state = debouncer();
+                if !state.ready.is_empty() {
+                    let message = state.ready.remove(0);
+                    match message.downcast::<M>() {
+                        Ok(message) => return Some((*message, ())),
+                        Err(_) => continue,
+                    }
+                }
+                state.waiters.push(sender);
+            }
+            receiver.await.ok()?;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_submit_keeps_last_text() {
+        let delay = Duration::from_secs(60);
+        submit("debounce_test_search", "a".to_string(), 1u8, delay);
+        submit("debounce_test_search", "ab".to_string(), 2u8, delay);
+
+        assert_eq!(pending_text("debounce_test_search"), Some("ab".to_string()));
+        assert_eq!(pending_text("debounce_test_other"), None);
+    }
+}
@@ -0,0 +1,328 @@
+//! Per-side borders and outlines
+//!
+//! Iced draws a single border around a widget. [`Decorated`] draws what it
+//! cannot over its content: the borders of individual sides and an outline
+//! outside of the widget bounds, which does not take part in layout.
+//!
+//! ```xml
+//! <container border_bottom="1 #cccccc" padding="8">
+//!     <text value="Section" />
+//! </container>
+//!
+//! <button label="Save" outline="2 #3498db 2" />
+//! ```
+
+use crate::style_mapping::{map_border_radius, map_color};
+use dampen_core::ir::style::StyleProperties;
+use iced::advanced::widget::{Operation, Tree};
+use iced::advanced::{Clipboard, Layout, Shell, Widget, layout, mouse, overlay, renderer};
+use iced::border::Radius;
+use iced::{Border, Color, Element, Event, Length, Rectangle, Size, Vector};
+
+/// Borders of individual sides and outline drawn around an element
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Decoration {
+    top: Option<(f32, Color)>,
+    right: Option<(f32, Color)>,
+    bottom: Option<(f32, Color)>,
+    left: Option<(f32, Color)>,
+    /// Width, color and offset of the outline
+    outline: Option<(f32, Color, f32)>,
+    radius: Radius,
+}
+
+impl Decoration {
+    /// The decoration of `style`, if it has per-side borders or an outline
+    pub fn from_style(style: &StyleProperties) -> Option<Self> {
+        let sides = style.border_sides.unwrap_or_default();
+        if sides.is_empty() && style.outline.is_none() {
+            return None;
+        }
+
+        let side = |side: Option<dampen_core::ir::style::BorderSide>| {
+            side.map(|side| (side.width, map_color(&side.color)))
+        };
+
+        Some(Self {
+            top: side(sides.top),
+            right: side(sides.right),
+            bottom: side(sides.bottom),
+            left: side(sides.left),
+            outline: style
+                .outline
+                .map(|outline| (outline.width, map_color(&outline.color), outline.offset)),
+            radius: style
+                .border
+                .as_ref()
+                .map(|border| map_border_radius(&border.radius))
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Draw a border of `width` on the top side
+    #[must_use]
+    pub fn top(mut self, width: f32, color: Color) -> Self {
+        self.top = Some((width, color));
+        self
+    }
+
+    /// Draw a border of `width` on the right side
+    #[must_use]
+    pub fn right(mut self, width: f32, color: Color) -> Self {
+        self.right = Some((width, color));
+        self
+    }
+
+    /// Draw a border of `width` on the bottom side
+    #[must_use]
+    pub fn bottom(mut self, width: f32, color: Color) -> Self {
+        self.bottom = Some((width, color));
+        self
+    }
+
+    /// Draw a border of `width` on the left side
+    #[must_use]
+    pub fn left(mut self, width: f32, color: Color) -> Self {
+        self.left = Some((width, color));
+        self
+    }
+
+    /// Draw an outline of `width`, `offset` pixels away from the bounds
+    #[must_use]
+    pub fn outline(mut self, width: f32, color: Color, offset: f32) -> Self {
+        self.outline = Some((width, color, offset));
+        self
+    }
+
+    /// Round the outline along the corners of the element, rounded by `radius`
+    #[must_use]
+    pub fn radius(mut self, radius: impl Into<Radius>) -> Self {
+        self.radius = radius.into();
+        self
+    }
+
+    fn draw<Renderer: renderer::Renderer>(&self, renderer: &mut Renderer, bounds: Rectangle) {
+        let sides = [
+            self.top.map(|(width, color)| {
+                let bounds = Rectangle {
+                    height: width,
+                    ..bounds
+                };
+                (bounds, color)
+            }),
+            self.right.map(|(width, color)| {
+                let bounds = Rectangle {
+                    x: bounds.x + bounds.width - width,
+                    width,
+                    ..bounds
+                };
+                (bounds, color)
+            }),
+            self.bottom.map(|(width, color)| {
+                let bounds = Rectangle {
+                    y: bounds.y + bounds.height - width,
+                    height: width,
+                    ..bounds
+                };
+                (bounds, color)
+            }),
+            self.left.map(|(width, color)| {
+                let bounds = Rectangle { width, ..bounds };
+                (bounds, color)
+            }),
+        ];
+
+        for (bounds, color) in sides.into_iter().flatten() {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        }
+
+        if let Some((width, color, offset)) = self.outline {
+            // Iced draws borders inside of the quad, so the quad is grown by the
+            // gap and the width of the outline
+            let gap = offset + width;
+            let radius = self.radius;
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: bounds.expand(gap),
+                    border: Border {
+                        color,
+                        width,
+                        radius: Radius {
+                            top_left: radius.top_left + gap,
+                            top_right: radius.top_right + gap,
+                            bottom_right: radius.bottom_right + gap,
+                            bottom_left: radius.bottom_left + gap,
+                        },
+                    },
+                    ..renderer::Quad::default()
+                },
+                Color::TRANSPARENT,
+            );
+        }
+    }
+}
+
+/// Wrapper drawing a [`Decoration`] over its content
+pub struct Decorated<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    decoration: Decoration,
+}
+
+impl<'a, Message, Theme, Renderer> Decorated<'a, Message, Theme, Renderer> {
+    /// Draw `decoration` over `content`
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        decoration: Decoration,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            decoration,
+        }
+    }
+}
+
+/// Draw `decoration` over `content`
+pub fn decorated<'a, Message: 'a>(
+    content: impl Into<Element<'a, Message>>,
+    decoration: Decoration,
+) -> Element<'a, Message> {
+    Decorated::new(content, decoration).into()
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Decorated<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+        self.decoration.draw(renderer, layout.bounds());
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Decorated<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(decorated: Decorated<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(decorated)
+    }
+}
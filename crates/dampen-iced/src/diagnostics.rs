@@ -0,0 +1,251 @@
+//! Diagnostics collected while building widgets
+//!
+//! When part of a document cannot be rendered as written, [`DampenWidgetBuilder`](crate::DampenWidgetBuilder)
+//! falls back to an empty or placeholder widget so the rest of the view still
+//! renders: a binding that fails to evaluate shows as an empty string, a `<for>`
+//! over something that is not a list renders nothing, an event naming an
+//! unregistered handler does nothing when triggered.
+//!
+//! Attaching a [`BuildDiagnostics`] with
+//! [`with_diagnostics()`](crate::DampenWidgetBuilder::with_diagnostics) collects
+//! the reason of each fallback, so a blank widget can be traced back to its source.
+//! Debug builds already log every fallback as it happens; in release builds each
+//! distinct diagnostic is logged through [`dampen_core::log`] at the `warn` level
+//! the first time it is collected.
+
+use dampen_core::ir::span::Span;
+use dampen_core::parser::error::{ParseError, ParseErrorKind};
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+/// Why a widget fell back to a placeholder
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BuildDiagnosticKind {
+    /// An event names a handler that is not in the registry
+    MissingHandler,
+    /// A binding expression failed to evaluate
+    BindingError,
+    /// An attribute is missing or has a value the widget cannot use
+    UnsupportedAttribute,
+    /// A remote image failed to download
+    ImageLoadFailed,
+}
+
+impl std::fmt::Display for BuildDiagnosticKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            BuildDiagnosticKind::MissingHandler => "missing handler",
+            BuildDiagnosticKind::BindingError => "binding error",
+            BuildDiagnosticKind::UnsupportedAttribute => "unsupported attribute",
+            BuildDiagnosticKind::ImageLoadFailed => "image load failed",
+        })
+    }
+}
+
+/// A problem found while building a widget
+#[derive(Debug, Clone, PartialEq)]
+pub struct BuildDiagnostic {
+    /// Kind of problem
+    pub kind: BuildDiagnosticKind,
+    /// Human-readable description
+    pub message: String,
+    /// Location in the source file (of the binding, event or attribute at fault)
+    pub span: Span,
+}
+
+impl BuildDiagnostic {
+    /// Convert to a [`ParseError`], e.g. to display it in the dev error overlay
+    pub fn to_parse_error(&self) -> ParseError {
+        let kind = match self.kind {
+            BuildDiagnosticKind::BindingError => ParseErrorKind::InvalidExpression,
+            BuildDiagnosticKind::MissingHandler
+            | BuildDiagnosticKind::UnsupportedAttribute
+            | BuildDiagnosticKind::ImageLoadFailed => ParseErrorKind::InvalidValue,
+        };
+        ParseError {
+            kind,
+            message: format!("{}: {}", self.kind, self.message),
+            span: self.span,
+            suggestion: None,
+        }
+    }
+
+    fn key(&self) -> (BuildDiagnosticKind, String, usize) {
+        (self.kind, self.message.clone(), self.span.start)
+    }
+}
+
+impl std::fmt::Display for BuildDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} at line {}, column {}: {}",
+            self.kind, self.span.line, self.span.column, self.message
+        )
+    }
+}
+
+/// Maximum number of distinct diagnostics remembered, and of new ones kept for
+/// [`BuildDiagnostics::take_new`]
+///
+/// Past this, the remembered diagnostics are forgotten (and may be reported as
+/// new again), so a view producing ever-changing messages does not grow memory.
+const MAX_TRACKED: usize = 1024;
+
+#[derive(Debug, Default)]
+struct DiagnosticsState {
+    current: Vec<BuildDiagnostic>,
+    seen: HashSet<(BuildDiagnosticKind, String, usize)>,
+    unseen: Vec<BuildDiagnostic>,
+}
+
+/// Collector of the diagnostics of each `build()`
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use dampen_iced::diagnostics::BuildDiagnostics;
+///
+/// // Stored in the application, e.g. next to the AppState
+/// let diagnostics = BuildDiagnostics::new();
+///
+/// // In view():
+/// DampenWidgetBuilder::from_app_state(&self.state)
+///     .with_diagnostics(&diagnostics)
+///     .build()
+///
+/// // Later, e.g. in update():
+/// for diagnostic in diagnostics.diagnostics() {
+///     eprintln!("{}", diagnostic);
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct BuildDiagnostics {
+    state: Mutex<DiagnosticsState>,
+}
+
+impl BuildDiagnostics {
+    /// Create an empty collector
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Diagnostics of the most recent build, in the order they were found
+    ///
+    /// Widgets built several times (e.g., inside a `<for>` loop) report each
+    /// problem once.
+    pub fn diagnostics(&self) -> Vec<BuildDiagnostic> {
+        self.state
+            .lock()
+            .map(|state| state.current.clone())
+            .unwrap_or_default()
+    }
+
+    /// Diagnostics collected for the first time since the last call
+    ///
+    /// Since views are rebuilt on every update, this lets a caller react once
+    /// to each new problem (e.g., by showing an overlay) rather than on every frame.
+    pub fn take_new(&self) -> Vec<BuildDiagnostic> {
+        self.state
+            .lock()
+            .map(|mut state| std::mem::take(&mut state.unseen))
+            .unwrap_or_default()
+    }
+
+    /// Start collecting a build, discarding the diagnostics of the previous one
+    pub(crate) fn begin_build(&self) {
+        if let Ok(mut state) = self.state.lock() {
+            state.current.clear();
+        }
+    }
+
+    /// Record a diagnostic of the current build
+    pub(crate) fn push(&self, diagnostic: BuildDiagnostic) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        if state.current.contains(&diagnostic) {
+            return;
+        }
+        if state.seen.len() >= MAX_TRACKED {
+            state.seen.clear();
+        }
+        if state.seen.insert(diagnostic.key()) {
+            #[cfg(not(debug_assertions))]
+            dampen_core::log::warn!("{}", diagnostic);
+            if state.unseen.len() < MAX_TRACKED {
+                state.unseen.push(diagnostic.clone());
+            }
+        }
+        state.current.push(diagnostic);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagnostic(message: &str, start: usize) -> BuildDiagnostic {
+        BuildDiagnostic {
+            kind: BuildDiagnosticKind::BindingError,
+            message: message.to_string(),
+            span: Span::new(start, start + 5, 2, 10),
+        }
+    }
+
+    #[test]
+    fn test_diagnostics_are_kept_per_build() {
+        let diagnostics = BuildDiagnostics::new();
+
+        diagnostics.begin_build();
+        diagnostics.push(diagnostic("Field 'count' not found", 12));
+        diagnostics.push(diagnostic("Field 'count' not found", 12));
+        diagnostics.push(diagnostic("Field 'name' not found", 40));
+        assert_eq!(diagnostics.diagnostics().len(), 2);
+        assert_eq!(diagnostics.take_new().len(), 2);
+
+        // The next build reports the remaining problem, but it is not new
+        diagnostics.begin_build();
+        diagnostics.push(diagnostic("Field 'name' not found", 40));
+        assert_eq!(
+            diagnostics.diagnostics(),
+            vec![diagnostic("Field 'name' not found", 40)]
+        );
+        assert!(diagnostics.take_new().is_empty());
+    }
+
+    #[test]
+    fn test_remembered_diagnostics_are_capped() {
+        let diagnostics = BuildDiagnostics::new();
+
+        diagnostics.begin_build();
+        for start in 0..MAX_TRACKED + 10 {
+            diagnostics.push(diagnostic("Field 'count' not found", start));
+        }
+        assert_eq!(diagnostics.take_new().len(), MAX_TRACKED);
+
+        let state = diagnostics.state.lock();
+        assert!(state.is_ok_and(|state| state.seen.len() <= MAX_TRACKED));
+    }
+
+    #[test]
+    fn test_diagnostic_display_and_overlay_conversion() {
+        let diagnostic = BuildDiagnostic {
+            kind: BuildDiagnosticKind::MissingHandler,
+            message: "Handler 'save' is not registered".to_string(),
+            span: Span::new(30, 34, 3, 21),
+        };
+
+        assert_eq!(
+            diagnostic.to_string(),
+            "missing handler at line 3, column 21: Handler 'save' is not registered"
+        );
+        let error = diagnostic.to_parse_error();
+        assert_eq!(error.kind, ParseErrorKind::InvalidValue);
+        assert_eq!(error.span, diagnostic.span);
+        assert_eq!(
+            error.message,
+            "missing handler: Handler 'save' is not registered"
+        );
+    }
+}
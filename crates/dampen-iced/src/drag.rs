@@ -0,0 +1,263 @@
+//! Drag-and-drop for reorderable lists
+//!
+//! This module provides [`DragArea`], the widget wrapped around every element
+//! marked `draggable="true"`. Each area hit-tests mouse (and touch) events against
+//! its own bounds and records the gesture in the [`DragTracker`] shared by the items
+//! of the list, so the item under the cursor when the button is released knows
+//! which item was picked up.
+//!
+//! ```xml
+//! <for each="task" in="{tasks}">
+//!     <row draggable="true" on_drop="reorder_tasks">
+//!         <text value="{task.title}" />
+//!     </row>
+//! </for>
+//! ```
+//!
+//! `on_drag_start` and `on_drag_over` receive the index of the dragged and hovered
+//! item; `on_drop` receives `(source, target)` encoded with
+//! [`encode_event_args`](dampen_core::handler::encode_event_args), so it can be
+//! registered with [`HandlerRegistry::register_with_args`](dampen_core::HandlerRegistry::register_with_args).
+
+use dampen_core::state::DragTracker;
+use iced::advanced::widget::{Operation, Tree};
+use iced::advanced::{Clipboard, Layout, Shell, Widget, layout, mouse, overlay, renderer};
+use iced::{Element, Event, Length, Rectangle, Size, Vector, touch};
+
+/// Wrapper making its content a draggable item of a list
+pub struct DragArea<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    tracker: DragTracker,
+    list: (u32, u32),
+    index: usize,
+    on_drag_start: Option<Message>,
+    on_drag_over: Option<Message>,
+    on_drop: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+}
+
+impl<'a, Message, Theme, Renderer> DragArea<'a, Message, Theme, Renderer> {
+    /// Create a draggable item
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - Element to wrap
+    /// * `tracker` - Tracker shared by the items of the list, usually `AppState::drag`
+    /// * `list` - Identifier of the list (the source location of the item template)
+    /// * `index` - Index of the item in the list
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        tracker: DragTracker,
+        list: (u32, u32),
+        index: usize,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            tracker,
+            list,
+            index,
+            on_drag_start: None,
+            on_drag_over: None,
+            on_drop: None,
+        }
+    }
+
+    /// The message to emit when the item is picked up
+    #[must_use]
+    pub fn on_drag_start(mut self, message: Message) -> Self {
+        self.on_drag_start = Some(message);
+        self
+    }
+
+    /// The message to emit when an item of the list is dragged over this one
+    #[must_use]
+    pub fn on_drag_over(mut self, message: Message) -> Self {
+        self.on_drag_over = Some(message);
+        self
+    }
+
+    /// The message to emit when an item is dropped on this one, from `(source, target)`
+    #[must_use]
+    pub fn on_drop(mut self, on_drop: impl Fn(usize, usize) -> Message + 'a) -> Self {
+        self.on_drop = Some(Box::new(on_drop));
+        self
+    }
+
+    fn is_dragging(&self) -> bool {
+        self.tracker
+            .session()
+            .is_some_and(|session| session.list == self.list && !session.released)
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DragArea<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Message: Clone,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let is_over = cursor.is_over(layout.bounds());
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if is_over =>
+            {
+                self.tracker.begin(self.list, self.index);
+                if let Some(message) = &self.on_drag_start {
+                    shell.publish(message.clone());
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. })
+                if is_over =>
+            {
+                if self.tracker.hover(self.list, self.index)
+                    && let Some(message) = &self.on_drag_over
+                {
+                    shell.publish(message.clone());
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if !is_over {
+                    self.tracker.release(self.list);
+                } else if let Some((source, target)) = self.tracker.drop_on(self.list, self.index)
+                    && let Some(on_drop) = &self.on_drop
+                {
+                    shell.publish(on_drop(source, target));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let content_interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        match content_interaction {
+            mouse::Interaction::None if self.is_dragging() => mouse::Interaction::Grabbing,
+            mouse::Interaction::None if cursor.is_over(layout.bounds()) => mouse::Interaction::Grab,
+            _ => content_interaction,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<DragArea<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(area: DragArea<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(area)
+    }
+}
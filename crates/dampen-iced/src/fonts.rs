@@ -0,0 +1,96 @@
+//! Fonts of the theme typography
+//!
+//! Themes declare the font files they bundle and the family of their text:
+//!
+//! ```xml
+//! <typography font_family="Inter, sans-serif" fonts="fonts/Inter-Regular.ttf, fonts/Inter-Bold.ttf" />
+//! ```
+//!
+//! [`load`] registers the files with iced, after which the family can be used
+//! by name. `#[dampen_app]` loads the fonts of the theme file on startup; the
+//! generated production code embeds them.
+
+use iced::Task;
+use iced::font::{Family, Weight};
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::{LazyLock, Mutex, PoisonError};
+
+/// Register font files with iced
+///
+/// `fonts` are relative to `theme_dir`, usually `src/ui/theme`. Files that
+/// cannot be read are skipped, with a warning in debug builds.
+pub fn load<M: Send + 'static>(theme_dir: &Path, fonts: &[&str]) -> Task<M> {
+    let tasks: Vec<Task<M>> = fonts
+        .iter()
+        .filter_map(|font| match std::fs::read(theme_dir.join(font)) {
+            Ok(bytes) => Some(iced::font::load(bytes).discard()),
+            Err(_error) => {
+                #[cfg(debug_assertions)]
+                #[allow(clippy::print_stderr)]
+                {
+                    eprintln!("[WARN] Failed to load font '{}': {}", font, _error);
+                }
+                None
+            }
+        })
+        .collect();
+    Task::batch(tasks)
+}
+
+/// Family of a `font` attribute or a theme `font_family`
+///
+/// Generic families map to their iced variant, other names to a loaded font.
+pub fn family(name: &str) -> Family {
+    match name.trim() {
+        "sans-serif" => Family::SansSerif,
+        "serif" => Family::Serif,
+        "monospace" => Family::Monospace,
+        name => Family::Name(intern(name)),
+    }
+}
+
+/// Weight of a `weight` attribute, normal when unknown
+pub fn weight(name: &str) -> Weight {
+    match name.trim().to_lowercase().as_str() {
+        "thin" => Weight::Thin,
+        "light" => Weight::Light,
+        "medium" => Weight::Medium,
+        "semibold" => Weight::Semibold,
+        "bold" => Weight::Bold,
+        "black" => Weight::Black,
+        _ => Weight::Normal,
+    }
+}
+
+/// Family names leaked once, as iced requires `'static` names
+static FAMILIES: LazyLock<Mutex<HashSet<&'static str>>> = LazyLock::new(Mutex::default);
+
+fn intern(name: &str) -> &'static str {
+    let mut families = FAMILIES.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(family) = families.get(name) {
+        return family;
+    }
+    let family: &'static str = Box::leak(name.to_string().into_boxed_str());
+    families.insert(family);
+    family
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_family_and_weight() {
+        assert_eq!(family("monospace"), Family::Monospace);
+        assert_eq!(family(" Inter "), Family::Name("Inter"));
+        // Names are leaked once
+        let (Family::Name(first), Family::Name(second)) = (family("Inter"), family("Inter")) else {
+            panic!("expected named families");
+        };
+        assert!(std::ptr::eq(first, second));
+
+        assert_eq!(weight("Bold"), Weight::Bold);
+        assert_eq!(weight("heavy"), Weight::Normal);
+    }
+}
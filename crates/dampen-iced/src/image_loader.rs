@@ -0,0 +1,429 @@
+//! Asynchronous image loading
+//!
+//! `<image>` widgets whose `src` is a remote URL (`http://` or `https://`) are not
+//! loaded while the view is built. Instead, [`handle`] returns the `placeholder`
+//! image and queues the URL; the download then runs in the background and the
+//! result is kept in an in-memory LRU cache shared by all views.
+//!
+//! ```xml
+//! <image src="{user.avatar_url}" placeholder="assets/avatar.png" error_src="assets/broken.png" />
+//! ```
+//!
+//! The loading cycle is driven by the application:
+//!
+//! 1. [`requests`] emits a message when a render queued new URLs
+//! 2. The update function returns [`load_pending`], which downloads them
+//! 3. Each finished download emits a message again, so the view is rebuilt with
+//!    the loaded image (or `error_src` on failure)
+//!
+//! `#[dampen_app]` and the generated production code wire this up automatically.
+//!
+//! Downloads go through a [`Fetcher`], run by a pool of
+//! [`MAX_CONCURRENT_DOWNLOADS`] background threads; further downloads wait in
+//! a queue. With the `http` feature, the default fetcher is `http_fetcher`, a
+//! blocking HTTP client with timeouts. Without it, Dampen ships
+//! no HTTP client and the host application **must** register a fetcher with
+//! [`set_fetcher`]: until then, every remote image fails to load and shows its
+//! `error_src`.
+//!
+//! ```ignore
+//! dampen_iced::image_loader::set_fetcher(|url| {
+//!     let mut response = ureq::get(url).call().map_err(|e| e.to_string())?;
+//!     response.body_mut().read_to_vec().map_err(|e| e.to_string())
+//! });
+//! ```
+//!
+//! Failed downloads are logged through [`dampen_core::log`] and reported as
+//! [`ImageLoadFailed`](crate::diagnostics::BuildDiagnosticKind::ImageLoadFailed)
+//! build diagnostics by the widgets showing them.
+
+use iced::futures::channel::oneshot;
+use iced::futures::stream::{self, Stream};
+use iced::widget::image::Handle;
+use iced::{Subscription, Task};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, LazyLock, Mutex, MutexGuard, PoisonError, mpsc};
+
+/// Default number of images kept in the cache
+pub const DEFAULT_CACHE_CAPACITY: usize = 64;
+
+/// Number of images downloaded at the same time
+pub const MAX_CONCURRENT_DOWNLOADS: usize = 4;
+
+/// Function downloading the bytes of an image from its URL
+///
+/// Fetchers run on a background thread and may block.
+pub type Fetcher = fn(&str) -> Result<Vec<u8>, String>;
+
+/// Loading state of a remote image
+#[derive(Debug, Clone)]
+pub enum ImageState {
+    /// The download is queued or in progress
+    Loading,
+    /// The image was downloaded
+    Ready(Handle),
+    /// The download failed, with the error message
+    Failed(String),
+}
+
+/// In-memory cache of remote images, evicting the least recently used entry
+///
+/// Images still [`Loading`](ImageState::Loading) are never evicted, so their
+/// download is not started again: the cache can exceed its capacity while
+/// more images than that are loading.
+///
+/// # Example
+///
+/// ```rust
+/// use dampen_iced::image_loader::{ImageCache, ImageState};
+///
+/// let failed = || ImageState::Failed("offline".to_string());
+/// let mut cache = ImageCache::new(2);
+/// cache.insert("a", failed());
+/// cache.insert("b", failed());
+/// cache.get("a");
+/// cache.insert("c", failed());
+/// assert!(cache.get("a").is_some());
+/// assert!(cache.get("b").is_none());
+///
+/// // Loading images are kept over the capacity
+/// cache.insert("d", ImageState::Loading);
+/// cache.insert("e", ImageState::Loading);
+/// cache.insert("f", ImageState::Loading);
+/// assert_eq!(cache.len(), 3);
+/// ```
+#[derive(Debug)]
+pub struct ImageCache {
+    capacity: usize,
+    entries: HashMap<String, ImageState>,
+    /// URLs from least to most recently used
+    order: VecDeque<String>,
+}
+
+impl ImageCache {
+    /// Create a cache holding at most `capacity` images (at least one)
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// Get the state of `url`, marking it as recently used
+    pub fn get(&mut self, url: &str) -> Option<&ImageState> {
+        if self.entries.contains_key(url) {
+            self.touch(url);
+        }
+        self.entries.get(url)
+    }
+
+    /// Set the state of `url`, evicting the least recently used entries if full
+    pub fn insert(&mut self, url: impl Into<String>, state: ImageState) {
+        let url = url.into();
+        if self.entries.insert(url.clone(), state).is_some() {
+            self.touch(&url);
+        } else {
+            self.order.push_back(url);
+        }
+        self.evict();
+    }
+
+    /// Change the maximum number of images, evicting entries if needed
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity.max(1);
+        self.evict();
+    }
+
+    /// Number of cached images
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Remove every cached image
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+
+    fn evict(&mut self) {
+        while self.entries.len() > self.capacity {
+            let entries = &self.entries;
+            let Some(oldest) = self
+                .order
+                .iter()
+                .position(|url| !matches!(entries.get(url), Some(ImageState::Loading)))
+                .and_then(|position| self.order.remove(position))
+            else {
+                break;
+            };
+            self.entries.remove(&oldest);
+        }
+    }
+
+    fn touch(&mut self, url: &str) {
+        if let Some(position) = self.order.iter().position(|entry| entry == url)
+            && let Some(entry) = self.order.remove(position)
+        {
+            self.order.push_back(entry);
+        }
+    }
+}
+
+/// Global loader state
+struct Loader {
+    cache: ImageCache,
+    /// URLs requested by a render and not yet downloading
+    pending: Vec<String>,
+    fetcher: Fetcher,
+    /// Subscriptions waiting for new requests
+    waiters: Vec<oneshot::Sender<()>>,
+}
+
+static LOADER: LazyLock<Mutex<Loader>> = LazyLock::new(|| {
+    Mutex::new(Loader {
+        cache: ImageCache::new(DEFAULT_CACHE_CAPACITY),
+        pending: Vec::new(),
+        fetcher: default_fetcher,
+        waiters: Vec::new(),
+    })
+});
+
+/// Transparent image shown while loading when no placeholder is set
+static EMPTY: LazyLock<Handle> = LazyLock::new(|| Handle::from_rgba(1, 1, vec![0; 4]));
+
+fn loader() -> MutexGuard<'static, Loader> {
+    LOADER.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Returns `true` if `src` is loaded asynchronously
+pub fn is_remote(src: &str) -> bool {
+    src.starts_with("http://") || src.starts_with("https://")
+}
+
+/// Error message of the failed download of `src`, if it failed
+pub fn load_error(src: &str) -> Option<String> {
+    match loader().cache.entries.get(src) {
+        Some(ImageState::Failed(error)) => Some(error.clone()),
+        _ => None,
+    }
+}
+
+/// Get the image to display for `src`
+///
+/// Local paths are returned directly, by [`crate::assets::image_handle`]. Remote URLs return the cached image once
+/// loaded; until then `placeholder` is shown and the URL is queued for
+/// [`load_pending`]. If the download failed, `error_src` is shown, falling back
+/// to `placeholder`.
+pub fn handle(src: &str, placeholder: Option<&str>, error_src: Option<&str>) -> Handle {
+    if !is_remote(src) {
+        return crate::assets::image_handle(src);
+    }
+
+    let fallback = {
+        let mut loader = loader();
+        match loader.cache.get(src) {
+            Some(ImageState::Ready(handle)) => return handle.clone(),
+            Some(ImageState::Failed(_)) => error_src.or(placeholder),
+            Some(ImageState::Loading) => placeholder,
+            None => {
+                loader.cache.insert(src, ImageState::Loading);
+                loader.pending.push(src.to_string());
+                for waiter in loader.waiters.drain(..) {
+                    let _ = waiter.send(());
+                }
+                placeholder
+            }
+        }
+    };
+
+    match fallback {
+        // A remote fallback is loaded like any other image, without its own fallback
+        Some(fallback) if fallback != src => handle(fallback, None, None),
+        _ => EMPTY.clone(),
+    }
+}
+
+/// Download the images queued since the last call
+///
+/// The returned task emits `()` each time an image finished loading, which
+/// should trigger a new render.
+pub fn load_pending() -> Task<()> {
+    let (urls, fetcher) = {
+        let mut loader = loader();
+        (std::mem::take(&mut loader.pending), loader.fetcher)
+    };
+
+    Task::batch(
+        urls.into_iter()
+            .map(|url| Task::future(fetch(url, fetcher))),
+    )
+}
+
+/// Subscription emitting `()` whenever a render queues new images
+///
+/// Its messages should be handled by returning [`load_pending`].
+pub fn requests() -> Subscription<()> {
+    Subscription::run(watch_requests)
+}
+
+fn watch_requests() -> impl Stream<Item = ()> {
+    stream::unfold((), |()| async {
+        let (sender, receiver) = oneshot::channel();
+        {
+            let mut loader = loader();
+            if loader.pending.is_empty() {
+                loader.waiters.push(sender);
+            } else {
+                // Images were requested before the subscription started
+                let _ = sender.send(());
+            }
+        }
+        receiver.await.ok().map(|()| ((), ()))
+    })
+}
+
+/// Download queued for the worker threads, with the channel of its result
+type Download = (String, Fetcher, oneshot::Sender<Result<Vec<u8>, String>>);
+
+/// Queue of the downloads, run by [`MAX_CONCURRENT_DOWNLOADS`] worker threads
+static DOWNLOADS: LazyLock<mpsc::Sender<Download>> = LazyLock::new(|| {
+    let (sender, receiver) = mpsc::channel::<Download>();
+    let receiver = Arc::new(Mutex::new(receiver));
+    for worker in 0..MAX_CONCURRENT_DOWNLOADS {
+        let receiver = Arc::clone(&receiver);
+        let spawned = std::thread::Builder::new()
+            .name(format!("dampen-image-loader-{}", worker))
+            .spawn(move || {
+                loop {
+                    let download = receiver
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .recv();
+                    let Ok((url, fetcher, result)) = download else {
+                        break;
+                    };
+                    // A panicking fetcher fails its download, not the worker
+                    let bytes = std::panic::catch_unwind(|| fetcher(&url))
+                        .unwrap_or_else(|_| Err("image fetcher panicked".to_string()));
+                    let _ = result.send(bytes);
+                }
+            });
+        if let Err(error) = spawned {
+            dampen_core::log::warn!(error = %error, "failed to start an image loader thread");
+        }
+    }
+    sender
+});
+
+async fn fetch(url: String, fetcher: Fetcher) {
+    let (sender, receiver) = oneshot::channel();
+    let _ = DOWNLOADS.send((url.clone(), fetcher, sender));
+
+    let state = match receiver.await {
+        Ok(Ok(bytes)) => ImageState::Ready(Handle::from_bytes(bytes)),
+        Ok(Err(error)) => ImageState::Failed(error),
+        Err(_) => ImageState::Failed("no image loader thread is running".to_string()),
+    };
+
+    if let ImageState::Failed(error) = &state {
+        dampen_core::log::warn!(url = %url, error = %error, "failed to load image");
+    }
+
+    loader().cache.insert(url, state);
+}
+
+#[cfg(feature = "http")]
+fn default_fetcher(url: &str) -> Result<Vec<u8>, String> {
+    http_fetcher(url)
+}
+
+#[cfg(not(feature = "http"))]
+fn default_fetcher(url: &str) -> Result<Vec<u8>, String> {
+    Err(format!(
+        "no fetcher registered for '{}' (enable the `http` feature of dampen-iced or call dampen_iced::image_loader::set_fetcher)",
+        url
+    ))
+}
+
+/// Maximum time to connect to the host of an image
+#[cfg(feature = "http")]
+pub const CONNECT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Maximum time to receive the response headers, then the body, of an image
+#[cfg(feature = "http")]
+pub const READ_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+#[cfg(feature = "http")]
+static AGENT: LazyLock<ureq::Agent> = LazyLock::new(|| {
+    ureq::Agent::config_builder()
+        .timeout_connect(Some(CONNECT_TIMEOUT))
+        .timeout_recv_response(Some(READ_TIMEOUT))
+        .timeout_recv_body(Some(READ_TIMEOUT))
+        .build()
+        .new_agent()
+});
+
+/// Download `url` with a blocking HTTP GET request
+///
+/// This is the default [`Fetcher`] when the `http` feature is enabled. The
+/// download fails after [`CONNECT_TIMEOUT`] or [`READ_TIMEOUT`].
+#[cfg(feature = "http")]
+pub fn http_fetcher(url: &str) -> Result<Vec<u8>, String> {
+    let mut response = AGENT.get(url).call().map_err(|e| e.to_string())?;
+    response.body_mut().read_to_vec().map_err(|e| e.to_string())
+}
+
+/// Register the function used to download remote images
+///
+/// Replaces the default fetcher, which fails every download unless the `http`
+/// feature is enabled.
+pub fn set_fetcher(fetcher: Fetcher) {
+    loader().fetcher = fetcher;
+}
+
+/// Change the number of images kept in the cache (default: [`DEFAULT_CACHE_CAPACITY`])
+pub fn set_cache_capacity(capacity: usize) {
+    loader().cache.set_capacity(capacity);
+}
+
+/// Forget every cached image, so they are downloaded again on the next render
+pub fn clear_cache() {
+    loader().cache.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::futures::executor;
+    use iced::futures::future::join_all;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    static RUNNING: AtomicUsize = AtomicUsize::new(0);
+    static MOST_RUNNING: AtomicUsize = AtomicUsize::new(0);
+
+    fn slow_fetcher(_url: &str) -> Result<Vec<u8>, String> {
+        let running = RUNNING.fetch_add(1, Ordering::SeqCst) + 1;
+        MOST_RUNNING.fetch_max(running, Ordering::SeqCst);
+        std::thread::sleep(Duration::from_millis(20));
+        RUNNING.fetch_sub(1, Ordering::SeqCst);
+        Err("offline".to_string())
+    }
+
+    #[test]
+    fn test_downloads_are_bounded() {
+        let downloads = (0..MAX_CONCURRENT_DOWNLOADS * 3)
+            .map(|index| fetch(format!("https://example.com/{}.png", index), slow_fetcher));
+        executor::block_on(join_all(downloads));
+
+        assert_eq!(RUNNING.load(Ordering::SeqCst), 0);
+        assert!(MOST_RUNNING.load(Ordering::SeqCst) <= MAX_CONCURRENT_DOWNLOADS);
+        assert!(load_error("https://example.com/0.png").is_some());
+    }
+}
@@ -0,0 +1,537 @@
+//! Hit-testing of the widgets of a document
+//!
+//! Attaching a [`HitTest`] with
+//! [`with_hit_test()`](crate::DampenWidgetBuilder::with_hit_test) wraps every
+//! widget built from the document in an [`Inspectable`] area, and [`Inspect`]
+//! wraps the whole view:
+//!
+//! ```rust,ignore
+//! let view = DampenWidgetBuilder::from_app_state(&state)
+//!     .with_hit_test(&hit_test)
+//!     .build()
+//!     .map(Message::Handler);
+//!
+//! Inspect::new(view, &hit_test, Message::Hover, Message::Select)
+//! ```
+//!
+//! When the cursor moves, each area under it offers its [`InspectedNode`] to
+//! the hit test, the innermost one winning, and the hovered node is drawn
+//! highlighted. A click selects the hovered node instead of reaching the
+//! widgets, so buttons can be inspected without being pressed.
+//!
+//! The development inspector of `dampen-dev` is built on this module.
+
+use dampen_core::ir::span::Span;
+use dampen_core::ir::style::StyleProperties;
+use iced::advanced::widget::{Operation, Tree};
+use iced::advanced::{Clipboard, Layout, Shell, Widget, layout, mouse, overlay, renderer};
+use iced::{Background, Border, Color, Element, Event, Length, Rectangle, Size, Vector};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// A widget of the document, as found by the hit test
+#[derive(Debug, Clone, PartialEq)]
+pub struct InspectedNode {
+    /// XML tag of the widget (`button`, `column`, ...)
+    pub tag: String,
+    /// Value of the `id` attribute
+    pub id: Option<String>,
+    /// Style classes applied to the widget, bound classes resolved
+    pub classes: Vec<String>,
+    /// Style of the widget, merged from the theme, its classes and its
+    /// inline attributes
+    pub style: Option<StyleProperties>,
+    /// Location of the widget in its `.dampen` file
+    pub span: Span,
+    /// Bounds of the widget in the window, as last laid out
+    pub bounds: Rectangle,
+}
+
+/// Hovered widget of a view, shared by its [`Inspectable`] areas and its
+/// [`Inspect`] root
+///
+/// Kept across `view()` calls, like the other trackers attached to the
+/// builder.
+#[derive(Debug, Default)]
+pub struct HitTest {
+    state: Mutex<HitState>,
+}
+
+#[derive(Debug, Default)]
+struct HitState {
+    /// Innermost node under the cursor so far, during an event
+    candidate: Option<InspectedNode>,
+    /// Node under the cursor after the last event
+    hovered: Option<InspectedNode>,
+}
+
+impl HitTest {
+    /// Create a hit test with no hovered node
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn state(&self) -> MutexGuard<'_, HitState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// The node under the cursor, if any
+    pub fn hovered(&self) -> Option<InspectedNode> {
+        self.state().hovered.clone()
+    }
+
+    /// Forget the hovered node, e.g. when inspection stops
+    pub fn clear(&self) {
+        *self.state() = HitState::default();
+    }
+
+    /// Start hit-testing a cursor event
+    fn begin(&self) {
+        self.state().candidate = None;
+    }
+
+    /// Offer the node `node` at `bounds` as being under the cursor
+    ///
+    /// Areas offer their node before their content does, so the last offer,
+    /// from the innermost area, wins.
+    fn offer(&self, node: &InspectedNode, bounds: Rectangle) {
+        self.state().candidate = Some(InspectedNode {
+            bounds,
+            ..node.clone()
+        });
+    }
+
+    /// Finish hit-testing a cursor event
+    ///
+    /// Returns the new hovered node if it changed.
+    fn finish(&self) -> Option<Option<InspectedNode>> {
+        let mut state = self.state();
+        let candidate = state.candidate.take();
+        if candidate == state.hovered {
+            return None;
+        }
+        state.hovered.clone_from(&candidate);
+        Some(candidate)
+    }
+
+    fn is_hovered(&self, span: &Span, bounds: Rectangle) -> bool {
+        self.state()
+            .hovered
+            .as_ref()
+            .is_some_and(|hovered| hovered.span == *span && hovered.bounds == bounds)
+    }
+}
+
+/// Wrapper offering its widget to a [`HitTest`], and highlighting it while
+/// hovered
+pub struct Inspectable<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    node: InspectedNode,
+    hit_test: &'a HitTest,
+}
+
+impl<'a, Message, Theme, Renderer> Inspectable<'a, Message, Theme, Renderer> {
+    /// Wrap `content`, the widget built from `node`
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        node: InspectedNode,
+        hit_test: &'a HitTest,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            node,
+            hit_test,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Inspectable<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        if let Event::Mouse(mouse::Event::CursorMoved { .. }) = event
+            && cursor.is_over(layout.bounds())
+        {
+            self.hit_test.offer(&self.node, layout.bounds());
+        }
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        let bounds = layout.bounds();
+        if self.hit_test.is_hovered(&self.node.span, bounds) {
+            // In a layer of its own, so that it's drawn over the text
+            renderer.with_layer(bounds, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: Border {
+                            color: HIGHLIGHT,
+                            width: 2.0,
+                            radius: 2.0.into(),
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    Background::Color(HIGHLIGHT.scale_alpha(0.15)),
+                );
+            });
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Inspectable<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(area: Inspectable<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(area)
+    }
+}
+
+/// Color of the highlight drawn over the hovered widget
+const HIGHLIGHT: Color = Color::from_rgb(0.2, 0.55, 1.0);
+
+/// Root of an inspected view, reporting the hovered widget and selecting it
+/// on click
+pub struct Inspect<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    hit_test: &'a HitTest,
+    on_hover: Box<dyn Fn(Option<InspectedNode>) -> Message + 'a>,
+    on_select: Box<dyn Fn(InspectedNode) -> Message + 'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Inspect<'a, Message, Theme, Renderer> {
+    /// Inspect `content`, built with [`with_hit_test(hit_test)`](crate::DampenWidgetBuilder::with_hit_test)
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The view to inspect
+    /// * `hit_test` - The hit test attached to the builder of the view
+    /// * `on_hover` - Message sent when the hovered widget changes
+    /// * `on_select` - Message sent when the hovered widget is clicked
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        hit_test: &'a HitTest,
+        on_hover: impl Fn(Option<InspectedNode>) -> Message + 'a,
+        on_select: impl Fn(InspectedNode) -> Message + 'a,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            hit_test,
+            on_hover: Box::new(on_hover),
+            on_select: Box::new(on_select),
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Inspect<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        // Clicks select the hovered widget instead of reaching it
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) = event
+            && cursor.is_over(layout.bounds())
+            && let Some(node) = self.hit_test.hovered()
+        {
+            shell.publish((self.on_select)(node));
+            shell.capture_event();
+            return;
+        }
+
+        let is_cursor_event = matches!(
+            event,
+            Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft)
+        );
+        if is_cursor_event {
+            self.hit_test.begin();
+        }
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if is_cursor_event && let Some(hovered) = self.hit_test.finish() {
+            shell.publish((self.on_hover)(hovered));
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Crosshair
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Inspect<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(root: Inspect<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(root)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(tag: &str, line: u32) -> InspectedNode {
+        InspectedNode {
+            tag: tag.to_string(),
+            id: None,
+            classes: Vec::new(),
+            style: None,
+            span: Span {
+                start: 0,
+                end: 0,
+                line,
+                column: 5,
+            },
+            bounds: Rectangle::default(),
+        }
+    }
+
+    #[test]
+    fn test_innermost_offer_is_hovered() {
+        let hit_test = HitTest::new();
+        let outer = Rectangle::new(iced::Point::ORIGIN, Size::new(200.0, 100.0));
+        let inner = Rectangle::new(iced::Point::new(10.0, 10.0), Size::new(50.0, 20.0));
+
+        hit_test.begin();
+        hit_test.offer(&node("column", 2), outer);
+        hit_test.offer(&node("button", 3), inner);
+        let hovered = hit_test.finish().flatten();
+        assert_eq!(hovered.as_ref().map(|n| n.tag.as_str()), Some("button"));
+        assert_eq!(hovered.map(|n| n.bounds), Some(inner));
+        assert!(hit_test.is_hovered(&node("button", 3).span, inner));
+        assert!(!hit_test.is_hovered(&node("column", 2).span, outer));
+
+        // Nothing changes while the cursor stays over the same widget
+        hit_test.begin();
+        hit_test.offer(&node("column", 2), outer);
+        hit_test.offer(&node("button", 3), inner);
+        assert_eq!(hit_test.finish(), None);
+
+        // Leaving every widget reports no hovered node
+        hit_test.begin();
+        assert_eq!(hit_test.finish(), Some(None));
+        assert_eq!(hit_test.hovered(), None);
+    }
+}
@@ -0,0 +1,105 @@
+//! Overlay manager
+//!
+//! [`Layers`] stacks the content drawn over a view in the fixed order of
+//! [`Layer`]: the view, then dropdowns, modals, toasts and the development
+//! overlays. Every overlay of the application is added to the same `Layers`,
+//! whatever the order in which they are added:
+//!
+//! ```rust,ignore
+//! use dampen_iced::layers::{Layer, Layers};
+//!
+//! Layers::new(view)
+//!     .push_maybe(Layer::Toast, dampen_iced::notify::layer())
+//!     .push_maybe(Layer::Modal, dampen_iced::palette::layer(&commands, Message::Palette))
+//!     .into()
+//! ```
+//!
+//! `DampenWidgetBuilder` adds the `<float layer="...">` portals of the document,
+//! and `#[dampen_app]` adds toasts, the command palette and the error overlay.
+
+pub use dampen_core::ir::layer::Layer;
+use iced::alignment::{Horizontal, Vertical};
+use iced::widget::{container, stack};
+use iced::{Element, Length};
+
+/// A view and the overlays drawn over it
+pub struct Layers<'a, Message> {
+    content: Element<'a, Message>,
+    overlays: Vec<(Layer, Element<'a, Message>)>,
+}
+
+impl<'a, Message: 'a> Layers<'a, Message> {
+    /// Layers over `content`, with no overlay yet
+    pub fn new(content: impl Into<Element<'a, Message>>) -> Self {
+        Self {
+            content: content.into(),
+            overlays: Vec::new(),
+        }
+    }
+
+    /// Add an overlay in `layer`, above the overlays already added to it
+    pub fn push(mut self, layer: Layer, overlay: impl Into<Element<'a, Message>>) -> Self {
+        self.overlays.push((layer, overlay.into()));
+        self
+    }
+
+    /// Add an overlay in `layer` if there is one
+    pub fn push_maybe(
+        self,
+        layer: Layer,
+        overlay: Option<impl Into<Element<'a, Message>>>,
+    ) -> Self {
+        match overlay {
+            Some(overlay) => self.push(layer, overlay),
+            None => self,
+        }
+    }
+
+    /// Layers of the overlays, from bottom to top
+    pub fn order(&self) -> Vec<Layer> {
+        let mut layers: Vec<Layer> = self.overlays.iter().map(|(layer, _)| *layer).collect();
+        layers.sort();
+        layers
+    }
+}
+
+impl<'a, Message: 'a> From<Layers<'a, Message>> for Element<'a, Message> {
+    fn from(layers: Layers<'a, Message>) -> Self {
+        let Layers {
+            content,
+            mut overlays,
+        } = layers;
+        if overlays.is_empty() {
+            return content;
+        }
+
+        // Stable: overlays of a layer keep the order in which they were added
+        overlays.sort_by_key(|(layer, _)| *layer);
+        stack(std::iter::once(content).chain(overlays.into_iter().map(|(_, overlay)| overlay)))
+            .into()
+    }
+}
+
+/// Place the content of a portal over the whole window
+///
+/// `position` is the `position` of the `<float>`: `TopLeft`, `TopRight`,
+/// `BottomLeft` or `BottomRight`; anything else centers the content.
+pub fn portal<'a, Message: 'a>(
+    content: impl Into<Element<'a, Message>>,
+    position: &str,
+) -> Element<'a, Message> {
+    let (x, y) = match position.trim() {
+        "TopLeft" => (Horizontal::Left, Vertical::Top),
+        "TopRight" => (Horizontal::Right, Vertical::Top),
+        "BottomLeft" => (Horizontal::Left, Vertical::Bottom),
+        "BottomRight" => (Horizontal::Right, Vertical::Bottom),
+        _ => (Horizontal::Center, Vertical::Center),
+    };
+
+    container(content)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(x)
+        .align_y(y)
+        .into()
+}
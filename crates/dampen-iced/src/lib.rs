@@ -1,14 +1,46 @@
 //! Dampen Iced - Iced Backend Implementation
 
+pub mod aspect_ratio;
+pub mod assets;
 pub mod builder;
 pub mod canvas;
+pub mod code_editor;
 pub mod convert;
+pub mod debounce;
+pub mod decoration;
+pub mod diagnostics;
+pub mod drag;
+pub mod fonts;
+pub mod image_loader;
+pub mod inspect;
+pub mod layers;
+pub mod markdown;
+pub mod memo;
+pub mod notify;
+pub mod palette;
+pub mod popover;
+pub mod profiler;
+pub mod reach_end;
+pub mod rich_text;
+pub mod scroll_position;
+pub mod selection;
+pub mod skeleton;
 pub mod style_mapping;
+pub mod svg;
+pub mod syntax;
+pub mod system_preferences;
 pub mod system_theme;
+pub mod task_scope;
 pub mod theme_adapter;
+pub mod tree;
+pub mod viewport;
+pub mod widget_state;
 
 // Re-export system theme subscription for production use
-pub use system_theme::watch_system_theme;
+pub use system_theme::{watch_system_theme, watch_system_theme_with};
+
+// Re-export per-view task scopes used by the dampen_app runtime
+pub use task_scope::TaskScope;
 
 use dampen_core::{AttributeValue, Backend, EventKind, InterpolatedPart, WidgetKind, WidgetNode};
 use iced::widget::{button, column, row, text};
@@ -488,6 +520,10 @@ pub fn render<'a>(
             };
             backend.svg(&path)
         }
+        WidgetKind::Icon => backend.column(Vec::new()),
+        WidgetKind::RadioGroup => backend.column(Vec::new()),
+        WidgetKind::NumberInput => backend.column(Vec::new()),
+        WidgetKind::Skeleton => backend.column(Vec::new()),
         WidgetKind::Space => backend.space(),
         WidgetKind::Rule => backend.rule(),
         WidgetKind::Radio => {
@@ -505,11 +541,17 @@ pub fn render<'a>(
             // For custom widgets, return empty
             backend.column(Vec::new())
         }
+        WidgetKind::Span => backend.column(Vec::new()),
+        WidgetKind::Markdown => backend.column(Vec::new()),
+        WidgetKind::CodeEditor => backend.column(Vec::new()),
+        WidgetKind::Tree => backend.column(Vec::new()),
         WidgetKind::ComboBox => backend.column(Vec::new()),
         WidgetKind::ProgressBar => backend.column(Vec::new()),
         WidgetKind::Tooltip => backend.column(Vec::new()),
+        WidgetKind::TooltipContent => backend.column(Vec::new()),
         WidgetKind::Grid => backend.column(Vec::new()),
         WidgetKind::Canvas => backend.column(Vec::new()),
+        WidgetKind::Chart => backend.column(Vec::new()),
         WidgetKind::Float => backend.column(Vec::new()),
         WidgetKind::For => backend.column(Vec::new()), // For loop requires model context, not supported in this legacy function
         WidgetKind::If => backend.column(Vec::new()),
@@ -517,7 +559,10 @@ pub fn render<'a>(
         | WidgetKind::CanvasCircle
         | WidgetKind::CanvasLine
         | WidgetKind::CanvasText
-        | WidgetKind::CanvasGroup => backend.column(Vec::new()),
+        | WidgetKind::CanvasGroup
+        | WidgetKind::CanvasPath
+        | WidgetKind::CanvasArc
+        | WidgetKind::CanvasBezier => backend.column(Vec::new()),
         WidgetKind::DatePicker | WidgetKind::TimePicker | WidgetKind::ColorPicker => {
             backend.column(Vec::new())
         }
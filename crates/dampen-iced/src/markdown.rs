@@ -0,0 +1,222 @@
+//! Markdown documents
+//!
+//! [`Markdown`] renders a document parsed by
+//! [`parse_blocks`](dampen_core::ir::markdown::parse_blocks) as a column of
+//! rich texts. It backs the `<markdown>` widget:
+//!
+//! ```xml
+//! <scrollable>
+//!     <markdown src="{readme}" on_link="open_url" />
+//! </scrollable>
+//! ```
+//!
+//! Sizes follow the theme typography: paragraphs use the base size and headings
+//! the sizes of the `heading1` to `heading4` text styles (see
+//! [`Typography::size_of`]).
+
+use std::rc::Rc;
+
+use dampen_core::ir::markdown::{MarkdownBlock, parse_blocks};
+use dampen_core::ir::{TextSpan, TextStyle, Typography};
+use iced::font::{Style, Weight};
+use iced::widget::text::{Rich, Span};
+use iced::widget::{column, container, rich_text, row, text};
+use iced::{Border, Color, Element, Font, Length, Theme};
+
+/// Markdown document rendered from its source
+pub struct Markdown<'a, Message> {
+    blocks: Vec<MarkdownBlock>,
+    typography: Typography,
+    color: Option<Color>,
+    link_color: Option<Color>,
+    on_link: Option<Rc<dyn Fn(String) -> Message + 'a>>,
+}
+
+impl<'a, Message: 'a> Markdown<'a, Message> {
+    /// Parse a document written in the supported subset of CommonMark
+    pub fn new(source: &str) -> Self {
+        Self {
+            blocks: parse_blocks(source),
+            typography: Typography::default(),
+            color: None,
+            link_color: None,
+            on_link: None,
+        }
+    }
+
+    /// Take the base size, family and heading scale of a theme typography
+    #[must_use]
+    pub fn typography(mut self, typography: &Typography) -> Self {
+        self.typography = typography.clone();
+        self
+    }
+
+    /// Set the size of paragraphs, headings being scaled from it
+    #[must_use]
+    pub fn size(mut self, size: f32) -> Self {
+        self.typography.font_size_base = Some(size);
+        self
+    }
+
+    /// Set the ratio between two heading levels (1.25 by default)
+    #[must_use]
+    pub fn scale_ratio(mut self, ratio: f32) -> Self {
+        self.typography.scale_ratio = Some(ratio);
+        self
+    }
+
+    /// Set the font family, e.g. `Inter` or `serif`
+    #[must_use]
+    pub fn font(mut self, family: &str) -> Self {
+        self.typography.font_family = Some(family.to_string());
+        self
+    }
+
+    /// Set the color of the text
+    #[must_use]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Set the color of links, underlined in the text color by default
+    #[must_use]
+    pub fn link_color(mut self, color: Color) -> Self {
+        self.link_color = Some(color);
+        self
+    }
+
+    /// Emit a message with the URL of a clicked link
+    #[must_use]
+    pub fn on_link(mut self, on_link: impl Fn(String) -> Message + 'a) -> Self {
+        self.on_link = Some(Rc::new(on_link));
+        self
+    }
+
+    fn family(&self) -> iced::font::Family {
+        self.typography
+            .family()
+            .map(crate::fonts::family)
+            .unwrap_or_default()
+    }
+
+    /// Rich text of inline spans, at the given size and weight
+    fn paragraph(
+        &self,
+        spans: Vec<TextSpan>,
+        size: f32,
+        weight: Weight,
+    ) -> Rich<'a, String, Message> {
+        let family = self.family();
+        let spans: Vec<Span<'a, String>> = spans
+            .into_iter()
+            .map(|span| {
+                let font = Font {
+                    family,
+                    weight: if span.bold { Weight::Bold } else { weight },
+                    style: if span.italic {
+                        Style::Italic
+                    } else {
+                        Style::Normal
+                    },
+                    ..Font::default()
+                };
+                let mut result = iced::widget::span(span.text).font(font);
+                if let Some(link) = span.link {
+                    result = result.link(link).underline(true);
+                    if let Some(color) = self.link_color {
+                        result = result.color(color);
+                    }
+                }
+                result
+            })
+            .collect();
+
+        let mut paragraph = rich_text(spans).size(size).width(Length::Fill);
+        if let Some(color) = self.color {
+            paragraph = paragraph.color(color);
+        }
+        if let Some(on_link) = &self.on_link {
+            let on_link = on_link.clone();
+            paragraph = paragraph.on_link_click(move |link: String| on_link(link));
+        }
+        paragraph
+    }
+
+    fn block(&self, block: MarkdownBlock) -> Element<'a, Message> {
+        let size = self.typography.base_size();
+
+        match block {
+            MarkdownBlock::Heading { level, spans } => {
+                let style = match level {
+                    1 => TextStyle::Heading1,
+                    2 => TextStyle::Heading2,
+                    3 => TextStyle::Heading3,
+                    _ => TextStyle::Heading4,
+                };
+                self.paragraph(spans, self.typography.size_of(style), Weight::Bold)
+                    .into()
+            }
+            MarkdownBlock::Paragraph(spans) => self.paragraph(spans, size, Weight::Normal).into(),
+            MarkdownBlock::List { start, items } => {
+                let items = items.into_iter().enumerate().map(|(i, spans)| {
+                    let marker = match start {
+                        Some(start) => format!("{}.", start + i as u64),
+                        None => "•".to_string(),
+                    };
+                    let mut marker = text(marker).size(size).font(Font {
+                        family: self.family(),
+                        ..Font::default()
+                    });
+                    if let Some(color) = self.color {
+                        marker = marker.color(color);
+                    }
+                    row![marker, self.paragraph(spans, size, Weight::Normal)]
+                        .spacing(size * 0.5)
+                        .into()
+                });
+                column(items)
+                    .spacing(size * 0.25)
+                    .padding(iced::Padding::ZERO.left(size * 0.5))
+                    .into()
+            }
+            MarkdownBlock::CodeBlock { code, .. } => {
+                container(text(code).font(Font::MONOSPACE).size(size * 0.875))
+                    .padding(size * 0.75)
+                    .width(Length::Fill)
+                    .style(code_style)
+                    .into()
+            }
+            MarkdownBlock::Rule => container(text(""))
+                .width(Length::Fill)
+                .height(1)
+                .style(|theme: &Theme| container::Style {
+                    background: Some(theme.extended_palette().background.strong.color.into()),
+                    ..container::Style::default()
+                })
+                .into(),
+        }
+    }
+}
+
+/// Background of code blocks, taken from the theme palette
+fn code_style(theme: &Theme) -> container::Style {
+    let palette = theme.extended_palette();
+    container::Style {
+        text_color: Some(palette.background.weak.text),
+        background: Some(palette.background.weak.color.into()),
+        border: Border::default().rounded(4),
+        ..container::Style::default()
+    }
+}
+
+impl<'a, Message: 'a> From<Markdown<'a, Message>> for Element<'a, Message> {
+    fn from(mut markdown: Markdown<'a, Message>) -> Self {
+        let spacing = markdown.typography.base_size() * 0.75;
+        let blocks = std::mem::take(&mut markdown.blocks);
+        column(blocks.into_iter().map(|block| markdown.block(block)))
+            .spacing(spacing)
+            .width(Length::Fill)
+            .into()
+    }
+}
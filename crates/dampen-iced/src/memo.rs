@@ -0,0 +1,345 @@
+//! Memoization of interpreted subtrees
+//!
+//! Interpreted views evaluate every binding of the document on each `view()`.
+//! With a [`MemoCache`] attached to the builder with
+//! [`with_memo_cache()`](crate::DampenWidgetBuilder::with_memo_cache), the
+//! subtrees marked `memo="true"` are evaluated once and reused until their
+//! inputs change:
+//!
+//! ```xml
+//! <column memo="true">
+//!     <text value="{user.name}" size="24" />
+//!     <text value="Member since {user.joined | date(\"%B %Y\")}" />
+//! </column>
+//! ```
+//!
+//! A memoized subtree is keyed by its [structural hash](dampen_core::ir::structural_hash)
+//! and the values of the fields its bindings read, `user.name` and
+//! `user.joined` above. The cached subtree has its bindings replaced by their
+//! values, so building it again skips binding evaluation entirely.
+//!
+//! Subtrees containing `<for>`, `<if>`, `model="{...}"` scopes, widgets bound
+//! to collections (pick lists, tables, charts, trees, canvases), handler
+//! parameters or `shared.` bindings are always evaluated: `memo` is ignored on
+//! them. Memoized subtrees may be nested in loops, the loop variables they read
+//! being part of their key.
+
+use dampen_core::binding::BindingValue;
+use dampen_core::expr::Expr;
+use dampen_core::ir::node::{AttributeValue, InterpolatedPart, WidgetKind, WidgetNode};
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Attribute marking a subtree to memoize
+pub const MEMO_ATTRIBUTE: &str = "memo";
+
+/// Field paths read by the bindings of a subtree, `None` when it can't be memoized
+type Plan = Option<Arc<Vec<Vec<String>>>>;
+
+/// Resolved subtree, with the build that last used it
+#[derive(Debug)]
+struct Entry {
+    node: Arc<WidgetNode>,
+    build: u64,
+}
+
+#[derive(Debug, Default)]
+struct MemoState {
+    plans: HashMap<u64, Plan>,
+    entries: HashMap<(u64, u64), Entry>,
+    build: u64,
+    hits: usize,
+    misses: usize,
+}
+
+/// Counters of a [`MemoCache`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoStats {
+    /// Subtrees reused from the cache
+    pub hits: usize,
+    /// Subtrees evaluated because their inputs changed or were never seen
+    pub misses: usize,
+    /// Subtrees currently cached
+    pub entries: usize,
+}
+
+/// Cache of the memoized subtrees, kept across `view()` calls
+///
+/// Subtrees not used by a build are dropped at the start of the next one, so
+/// the cache only holds what the current view shows.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use dampen_iced::memo::MemoCache;
+///
+/// // Stored in the application, e.g. next to the AppState
+/// let memo = MemoCache::new();
+///
+/// // In view():
+/// DampenWidgetBuilder::from_app_state(&self.state)
+///     .with_memo_cache(&memo)
+///     .build()
+/// ```
+#[derive(Debug, Default)]
+pub struct MemoCache {
+    state: Mutex<MemoState>,
+}
+
+impl MemoCache {
+    /// Create an empty cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hits, misses and size of the cache
+    pub fn stats(&self) -> MemoStats {
+        let state = self.state();
+        MemoStats {
+            hits: state.hits,
+            misses: state.misses,
+            entries: state.entries.len(),
+        }
+    }
+
+    /// Drop every cached subtree
+    pub fn clear(&self) {
+        let mut state = self.state();
+        state.plans.clear();
+        state.entries.clear();
+    }
+
+    /// Start a build, dropping the subtrees the previous one did not use
+    pub(crate) fn begin_build(&self) {
+        let mut state = self.state();
+        let previous = state.build;
+        state.entries.retain(|_, entry| entry.build >= previous);
+        state.build += 1;
+    }
+
+    /// Field paths read by the subtree of structural hash `hash`
+    pub(crate) fn plan(&self, hash: u64, node: &WidgetNode) -> Plan {
+        self.state()
+            .plans
+            .entry(hash)
+            .or_insert_with(|| {
+                let mut fields = Vec::new();
+                collect_fields(node, &mut fields).then(|| {
+                    fields.sort();
+                    fields.dedup();
+                    Arc::new(fields)
+                })
+            })
+            .clone()
+    }
+
+    /// Resolved subtree cached for `hash` and `inputs`
+    pub(crate) fn get(&self, hash: u64, inputs: u64) -> Option<Arc<WidgetNode>> {
+        let mut state = self.state();
+        let build = state.build;
+        let node = state.entries.get_mut(&(hash, inputs)).map(|entry| {
+            entry.build = build;
+            entry.node.clone()
+        })?;
+        state.hits += 1;
+        Some(node)
+    }
+
+    /// Cache the resolved subtree for `hash` and `inputs`
+    pub(crate) fn insert(&self, hash: u64, inputs: u64, node: Arc<WidgetNode>) {
+        let mut state = self.state();
+        let build = state.build;
+        state.entries.insert((hash, inputs), Entry { node, build });
+        state.misses += 1;
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, MemoState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Whether `node` asks to be memoized
+pub(crate) fn is_memoized(node: &WidgetNode) -> bool {
+    matches!(
+        node.attributes.get(MEMO_ATTRIBUTE),
+        Some(AttributeValue::Static(value)) if value.trim() == "true"
+    )
+}
+
+/// Hash the values read by a subtree, `false` if one can't be compared
+pub(crate) fn hash_value(value: &BindingValue, hasher: &mut impl Hasher) -> bool {
+    std::mem::discriminant(value).hash(hasher);
+    match value {
+        BindingValue::String(s) => s.hash(hasher),
+        BindingValue::Integer(i) => i.hash(hasher),
+        BindingValue::Float(f) => f.to_bits().hash(hasher),
+        BindingValue::Bool(b) => b.hash(hasher),
+        BindingValue::List(items) => {
+            items.len().hash(hasher);
+            return items.iter().all(|item| hash_value(item, hasher));
+        }
+        BindingValue::Object(fields) => {
+            let mut fields: Vec<_> = fields.iter().collect();
+            fields.sort_by(|a, b| a.0.cmp(b.0));
+            return fields.into_iter().all(|(name, value)| {
+                name.hash(hasher);
+                hash_value(value, hasher)
+            });
+        }
+        BindingValue::DateTime(date) => date.hash(hasher),
+        BindingValue::Duration(duration) => duration.hash(hasher),
+        BindingValue::Decimal(decimal) => decimal.hash(hasher),
+        BindingValue::Custom(_) => return false,
+        BindingValue::None => {}
+    }
+    true
+}
+
+/// Collect the field paths read by a subtree, `false` if it can't be memoized
+fn collect_fields(node: &WidgetNode, fields: &mut Vec<Vec<String>>) -> bool {
+    let evaluated_live = matches!(
+        node.kind,
+        WidgetKind::For
+            | WidgetKind::If
+            | WidgetKind::Canvas
+            | WidgetKind::Chart
+            | WidgetKind::DataTable
+            | WidgetKind::TreeView
+            | WidgetKind::Tree
+            | WidgetKind::PickList
+            | WidgetKind::ComboBox
+            | WidgetKind::RadioGroup
+            | WidgetKind::Custom(_)
+    ) || node.attributes.contains_key("model")
+        || node
+            .events
+            .iter()
+            .any(|event| event.param.is_some() || !event.params.is_empty())
+        || node
+            .theme_ref
+            .as_ref()
+            .is_some_and(|theme| !matches!(theme, AttributeValue::Static(_)))
+        || node
+            .breakpoint_attributes
+            .values()
+            .flat_map(|attributes| attributes.values())
+            .any(|attr| !matches!(attr, AttributeValue::Static(_)));
+    if evaluated_live {
+        return false;
+    }
+
+    for attr in node.attributes.values() {
+        let exprs: Vec<_> = match attr {
+            AttributeValue::Static(_) => Vec::new(),
+            AttributeValue::Binding(expr) => vec![expr],
+            AttributeValue::Interpolated(parts) => parts
+                .iter()
+                .filter_map(|part| match part {
+                    InterpolatedPart::Binding(expr) => Some(expr),
+                    InterpolatedPart::Literal(_) => None,
+                })
+                .collect(),
+        };
+        for expr in exprs {
+            if expr.uses_shared() {
+                return false;
+            }
+            collect_expr_fields(&expr.expr, fields);
+        }
+    }
+
+    node.children
+        .iter()
+        .all(|child| collect_fields(child, fields))
+}
+
+/// Field paths read by an expression
+fn collect_expr_fields(expr: &Expr, fields: &mut Vec<Vec<String>>) {
+    match expr {
+        Expr::FieldAccess(field) => fields.push(field.path.clone()),
+        Expr::SharedFieldAccess(_) | Expr::Literal(_) => {}
+        Expr::MethodCall(call) => {
+            collect_expr_fields(&call.receiver, fields);
+            for arg in &call.args {
+                collect_expr_fields(arg, fields);
+            }
+        }
+        Expr::BinaryOp(binary) => {
+            collect_expr_fields(&binary.left, fields);
+            collect_expr_fields(&binary.right, fields);
+        }
+        Expr::UnaryOp(unary) => collect_expr_fields(&unary.operand, fields),
+        Expr::Conditional(conditional) => {
+            collect_expr_fields(&conditional.condition, fields);
+            collect_expr_fields(&conditional.then_branch, fields);
+            collect_expr_fields(&conditional.else_branch, fields);
+        }
+        Expr::Filter(filter) => {
+            collect_expr_fields(&filter.input, fields);
+            for arg in &filter.args {
+                collect_expr_fields(arg, fields);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dampen_core::ir::structural_hash;
+    use dampen_core::parse;
+
+    fn plan_of(xml: &str) -> Option<Vec<Vec<String>>> {
+        let document = parse(xml).ok()?;
+        let cache = MemoCache::new();
+        let plan = cache.plan(structural_hash(&document.root), &document.root)?;
+        Some(plan.as_ref().clone())
+    }
+
+    #[test]
+    fn test_plan_collects_fields_of_memoizable_subtrees() {
+        assert_eq!(
+            plan_of(
+                r#"<column memo="true"><text value="{user.name}" /><text value="{count} items, {user.name}" /></column>"#
+            ),
+            Some(vec![
+                vec!["count".to_string()],
+                vec!["user".to_string(), "name".to_string()],
+            ])
+        );
+        // Loops and handler parameters are always evaluated
+        assert_eq!(
+            plan_of(r#"<column><for each="item" in="{items}"><text value="{item}" /></for></column>"#),
+            None
+        );
+        assert_eq!(
+            plan_of(r#"<button label="Delete" on_click="delete:{id}" />"#),
+            None
+        );
+    }
+
+    #[test]
+    fn test_unused_subtrees_are_dropped() {
+        let cache = MemoCache::new();
+        let node = Arc::new(WidgetNode::default());
+
+        cache.begin_build();
+        cache.insert(1, 10, node.clone());
+        cache.insert(2, 20, node);
+        cache.begin_build();
+        assert!(cache.get(1, 10).is_some());
+        assert!(cache.get(1, 11).is_none());
+
+        // The second subtree was not used by the last build
+        cache.begin_build();
+        assert_eq!(
+            cache.stats(),
+            MemoStats {
+                hits: 1,
+                misses: 2,
+                entries: 1,
+            }
+        );
+    }
+}
@@ -0,0 +1,245 @@
+//! Toast notifications
+//!
+//! Handlers show a toast by returning the task of [`info`], [`success`],
+//! [`warning`] or [`error`]:
+//!
+//! ```ignore
+//! use dampen_iced::notify;
+//!
+//! #[ui_handler]
+//! pub fn save(model: &mut Model) -> Task<Message> {
+//!     model.save();
+//!     notify::success("Saved")
+//! }
+//! ```
+//!
+//! Toasts are stacked in the bottom-right corner of the window, styled from the
+//! theme palette, and dismissed after [`DEFAULT_DURATION`] (see [`show`] for a
+//! custom duration). The display cycle is driven by the application:
+//!
+//! 1. [`overlay`] renders the current toasts over the view, in the
+//!    [`Layer::Toast`] layer (see [`layer`] to add them to other [`Layers`])
+//! 2. [`changes`] emits a message when a toast is shown or expires, so the view
+//!    is rebuilt
+//!
+//! `#[dampen_app]` and the generated production code wire this up automatically
+//! for views whose handlers return tasks.
+
+use crate::layers::{Layer, Layers};
+use iced::futures::channel::oneshot;
+use iced::futures::stream::{self, Stream};
+use iced::widget::{column, container, text};
+use iced::{Alignment, Border, Element, Length, Shadow, Subscription, Task, Theme, Vector};
+use std::sync::{LazyLock, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+/// Time a toast stays visible
+pub const DEFAULT_DURATION: Duration = Duration::from_secs(4);
+
+/// Maximum number of toasts displayed at once, older ones are dismissed first
+pub const MAX_TOASTS: usize = 5;
+
+/// Kind of a toast, selecting its colors
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastLevel {
+    /// Neutral information, on the strong background color
+    Info,
+    /// Completed action, on the success color
+    Success,
+    /// Recoverable problem, on the warning color
+    Warning,
+    /// Failed action, on the danger color
+    Error,
+}
+
+/// A displayed notification
+#[derive(Debug, Clone, PartialEq)]
+pub struct Toast {
+    /// Identifier used by [`dismiss`]
+    pub id: u64,
+    /// Kind of the toast
+    pub level: ToastLevel,
+    /// Text of the toast
+    pub message: String,
+}
+
+/// Global notification state
+struct Notifications {
+    toasts: Vec<Toast>,
+    next_id: u64,
+    /// Set when toasts changed while no subscription was waiting
+    changed: bool,
+    /// Subscriptions waiting for a change
+    waiters: Vec<oneshot::Sender<()>>,
+}
+
+static NOTIFICATIONS: LazyLock<Mutex<Notifications>> = LazyLock::new(|| {
+    Mutex::new(Notifications {
+        toasts: Vec::new(),
+        next_id: 0,
+        changed: false,
+        waiters: Vec::new(),
+    })
+});
+
+fn notifications() -> MutexGuard<'static, Notifications> {
+    NOTIFICATIONS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl Notifications {
+    fn notify_change(&mut self) {
+        if self.waiters.is_empty() {
+            self.changed = true;
+        }
+        for waiter in self.waiters.drain(..) {
+            let _ = waiter.send(());
+        }
+    }
+}
+
+/// Show an information toast
+pub fn info<T>(message: impl Into<String>) -> Task<T> {
+    show(ToastLevel::Info, message, DEFAULT_DURATION)
+}
+
+/// Show a success toast
+pub fn success<T>(message: impl Into<String>) -> Task<T> {
+    show(ToastLevel::Success, message, DEFAULT_DURATION)
+}
+
+/// Show a warning toast
+pub fn warning<T>(message: impl Into<String>) -> Task<T> {
+    show(ToastLevel::Warning, message, DEFAULT_DURATION)
+}
+
+/// Show an error toast
+pub fn error<T>(message: impl Into<String>) -> Task<T> {
+    show(ToastLevel::Error, message, DEFAULT_DURATION)
+}
+
+/// Show a toast for `duration`
+///
+/// The toast is displayed by the next render; the returned task does no work
+/// and only lets handlers return the notification, or chain it with other tasks.
+pub fn show<T>(level: ToastLevel, message: impl Into<String>, duration: Duration) -> Task<T> {
+    let id = {
+        let mut state = notifications();
+        let id = state.next_id;
+        state.next_id += 1;
+        state.toasts.push(Toast {
+            id,
+            level,
+            message: message.into(),
+        });
+        let overflow = state.toasts.len().saturating_sub(MAX_TOASTS);
+        state.toasts.drain(..overflow);
+        state.notify_change();
+        id
+    };
+
+    std::thread::spawn(move || {
+        std::thread::sleep(duration);
+        dismiss(id);
+    });
+
+    Task::none()
+}
+
+/// Remove a toast before it expires
+pub fn dismiss(id: u64) {
+    let mut state = notifications();
+    let count = state.toasts.len();
+    state.toasts.retain(|toast| toast.id != id);
+    if state.toasts.len() != count {
+        state.notify_change();
+    }
+}
+
+/// The toasts currently displayed, oldest first
+pub fn toasts() -> Vec<Toast> {
+    notifications().toasts.clone()
+}
+
+/// Subscription emitting `()` whenever a toast is shown or dismissed
+///
+/// Its messages only need to trigger a new render.
+pub fn changes() -> Subscription<()> {
+    Subscription::run(watch_changes)
+}
+
+fn watch_changes() -> impl Stream<Item = ()> {
+    stream::unfold((), |()| async {
+        let (sender, receiver) = oneshot::channel();
+        {
+            let mut state = notifications();
+            if state.changed {
+                // Toasts changed before the subscription (re)started waiting
+                state.changed = false;
+                let _ = sender.send(());
+            } else {
+                state.waiters.push(sender);
+            }
+        }
+        receiver.await.ok().map(|()| ((), ()))
+    })
+}
+
+/// Render the current toasts over `content`
+///
+/// `content` is returned unchanged when no toast is displayed. Toasts do not
+/// capture the mouse, so the view below stays interactive.
+pub fn overlay<'a, Message: 'a>(content: impl Into<Element<'a, Message>>) -> Element<'a, Message> {
+    Layers::new(content)
+        .push_maybe(Layer::Toast, layer())
+        .into()
+}
+
+/// The current toasts, for the [`Layer::Toast`] layer; `None` when no toast is
+/// displayed
+pub fn layer<'a, Message: 'a>() -> Option<Element<'a, Message>> {
+    let toasts = toasts();
+    if toasts.is_empty() {
+        return None;
+    }
+
+    let cards: Vec<Element<'a, Message>> = toasts
+        .into_iter()
+        .map(|toast| {
+            let level = toast.level;
+            container(text(toast.message))
+                .padding([8, 12])
+                .max_width(360)
+                .style(move |theme: &Theme| toast_style(theme, level))
+                .into()
+        })
+        .collect();
+    let region = container(column(cards).spacing(8).align_x(Alignment::End))
+        .padding(16)
+        .align_right(Length::Fill)
+        .align_bottom(Length::Fill);
+
+    Some(region.into())
+}
+
+/// Colors of a toast, taken from the theme palette
+fn toast_style(theme: &Theme, level: ToastLevel) -> container::Style {
+    let palette = theme.extended_palette();
+    let pair = match level {
+        ToastLevel::Info => palette.background.strong,
+        ToastLevel::Success => palette.success.base,
+        ToastLevel::Warning => palette.warning.base,
+        ToastLevel::Error => palette.danger.base,
+    };
+
+    container::Style {
+        text_color: Some(pair.text),
+        background: Some(pair.color.into()),
+        border: Border::default().rounded(6),
+        shadow: Shadow {
+            color: iced::Color::from_rgba(0.0, 0.0, 0.0, 0.25),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 8.0,
+        },
+        ..container::Style::default()
+    }
+}
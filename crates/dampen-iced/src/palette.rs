@@ -0,0 +1,259 @@
+//! Command palette
+//!
+//! Ctrl+K (Cmd+K on macOS) opens a search box listing the handlers of the view.
+//! Typing fuzzy-searches them, the arrow keys move the selection, Enter runs the
+//! selected handler and Escape closes the palette.
+//!
+//! Handlers are listed by annotating them:
+//!
+//! ```ignore
+//! #[ui_handler(palette = "Save File")]
+//! pub fn save_file(model: &mut Model) { /* ... */ }
+//! ```
+//!
+//! A view without annotated handlers lists all its handlers taking no value.
+//!
+//! The palette is driven by the application, which owns a message variant
+//! wrapping [`Event`]:
+//!
+//! 1. [`subscription`] emits the keyboard events of the palette
+//! 2. [`update`] applies an event, returning the handler to run as an [`Action`]
+//! 3. [`overlay`] renders the open palette over the view, in the [`Layer::Modal`]
+//!    layer (see [`layer`] to add it to other [`Layers`])
+//!
+//! `#[dampen_app(command_palette_variant = "...")]` and the generated production
+//! code wire this up automatically.
+
+use crate::layers::{Layer, Layers};
+use dampen_core::palette::search;
+use dampen_core::{CommandPalette, PaletteCommand};
+use iced::keyboard::{self, Key, key::Named};
+use iced::widget::{button, column, container, mouse_area, opaque, operation, text, text_input};
+use iced::{Border, Color, Element, Length, Shadow, Subscription, Task, Theme, Vector, window};
+use std::sync::{LazyLock, Mutex, MutexGuard, PoisonError};
+
+/// Maximum number of matches listed
+pub const MAX_RESULTS: usize = 8;
+
+/// Identifier of the search box, focused when the palette opens
+const INPUT_ID: &str = "dampen-command-palette";
+
+static PALETTE: LazyLock<Mutex<CommandPalette>> =
+    LazyLock::new(|| Mutex::new(CommandPalette::default()));
+
+fn palette() -> MutexGuard<'static, CommandPalette> {
+    PALETTE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Interaction with the command palette
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// Open or close the palette (Ctrl+K)
+    Toggle,
+    /// Close the palette (Escape or a click outside of it)
+    Close,
+    /// The search query was edited
+    Query(String),
+    /// Select the next match (Down)
+    Next,
+    /// Select the previous match (Up)
+    Previous,
+    /// Run the selected match (Enter)
+    Submit,
+    /// Run the match at this index (click)
+    Select(usize),
+}
+
+/// What the application does after [`update`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    /// Nothing
+    None,
+    /// Focus the search box with [`focus`], the palette was opened
+    Focus,
+    /// Run the handler with this name, taking no value
+    Dispatch(String),
+}
+
+/// Whether the palette is shown
+pub fn is_open() -> bool {
+    palette().is_open()
+}
+
+/// Apply `event` to the palette listing `commands`
+pub fn update(event: Event, commands: &[PaletteCommand]) -> Action {
+    let mut palette = palette();
+    let count = search(palette.query(), commands).len().min(MAX_RESULTS);
+
+    match event {
+        Event::Toggle => {
+            palette.toggle();
+            if palette.is_open() {
+                return Action::Focus;
+            }
+        }
+        Event::Close => palette.close(),
+        Event::Query(query) => palette.set_query(query),
+        Event::Next => palette.select_next(count),
+        Event::Previous => palette.select_previous(count),
+        Event::Submit => {
+            return palette
+                .submit(commands)
+                .map_or(Action::None, Action::Dispatch);
+        }
+        Event::Select(index) => {
+            return palette
+                .choose(index, commands)
+                .map_or(Action::None, Action::Dispatch);
+        }
+    }
+
+    Action::None
+}
+
+/// Task focusing the search box of the palette
+pub fn focus<T>() -> Task<T> {
+    operation::focus(INPUT_ID)
+}
+
+/// Subscription emitting the keyboard events of the palette
+///
+/// Ctrl+K (Cmd+K on macOS) is always listened to; Escape, Up, Down and Enter
+/// only while the palette is open.
+pub fn subscription() -> Subscription<Event> {
+    iced::event::listen_with(key_event)
+}
+
+fn key_event(
+    event: iced::Event,
+    _status: iced::event::Status,
+    _window: window::Id,
+) -> Option<Event> {
+    // The search box captures the keys, so the status is ignored
+    let iced::Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) = event else {
+        return None;
+    };
+
+    if modifiers.command() && matches!(key.as_ref(), Key::Character("k" | "K")) {
+        return Some(Event::Toggle);
+    }
+    if !is_open() {
+        return None;
+    }
+
+    match key.as_ref() {
+        Key::Named(Named::Escape) => Some(Event::Close),
+        Key::Named(Named::ArrowDown) => Some(Event::Next),
+        Key::Named(Named::ArrowUp) => Some(Event::Previous),
+        Key::Named(Named::Enter) => Some(Event::Submit),
+        _ => None,
+    }
+}
+
+/// Render the palette listing `commands` over `content`
+///
+/// `content` is returned unchanged while the palette is closed. The open
+/// palette blocks the view below; clicking outside of it closes it.
+pub fn overlay<'a, Message: Clone + 'a>(
+    content: impl Into<Element<'a, Message>>,
+    commands: &[PaletteCommand],
+    on_event: fn(Event) -> Message,
+) -> Element<'a, Message> {
+    Layers::new(content)
+        .push_maybe(Layer::Modal, layer(commands, on_event))
+        .into()
+}
+
+/// The open palette listing `commands`, for the [`Layer::Modal`] layer; `None`
+/// while the palette is closed
+pub fn layer<'a, Message: Clone + 'a>(
+    commands: &[PaletteCommand],
+    on_event: fn(Event) -> Message,
+) -> Option<Element<'a, Message>> {
+    let palette = palette().clone();
+    if !palette.is_open() {
+        return None;
+    }
+
+    let items: Vec<Element<'a, Message>> = search(palette.query(), commands)
+        .into_iter()
+        .take(MAX_RESULTS)
+        .enumerate()
+        .map(|(index, command)| {
+            let selected = index == palette.selected();
+            button(text(command.label.clone()))
+                .width(Length::Fill)
+                .padding([6, 10])
+                .style(move |theme: &Theme, status| item_style(theme, status, selected))
+                .on_press(on_event(Event::Select(index)))
+                .into()
+        })
+        .collect();
+    let results: Element<'a, Message> = if items.is_empty() {
+        container(text("No matching command"))
+            .padding([6, 10])
+            .into()
+    } else {
+        column(items).spacing(2).into()
+    };
+
+    let search_box = text_input("Type a command...", palette.query())
+        .id(INPUT_ID)
+        .on_input(move |query| on_event(Event::Query(query)))
+        .padding(8);
+    let card = container(column![search_box, results].spacing(8))
+        .padding(12)
+        .width(480)
+        .style(card_style);
+    let backdrop = container(opaque(card))
+        .center_x(Length::Fill)
+        .height(Length::Fill)
+        .padding([80, 16])
+        .style(|_theme: &Theme| container::Style {
+            background: Some(Color::from_rgba(0.0, 0.0, 0.0, 0.4).into()),
+            ..container::Style::default()
+        });
+
+    Some(opaque(
+        mouse_area(backdrop).on_press(on_event(Event::Close)),
+    ))
+}
+
+/// Colors of the palette box, taken from the theme palette
+fn card_style(theme: &Theme) -> container::Style {
+    let palette = theme.extended_palette();
+
+    container::Style {
+        text_color: Some(palette.background.base.text),
+        background: Some(palette.background.base.color.into()),
+        border: Border::default()
+            .rounded(8)
+            .width(1)
+            .color(palette.background.strong.color),
+        shadow: Shadow {
+            color: Color::from_rgba(0.0, 0.0, 0.0, 0.3),
+            offset: Vector::new(0.0, 4.0),
+            blur_radius: 16.0,
+        },
+        ..container::Style::default()
+    }
+}
+
+/// Colors of a match, highlighting the selected one
+fn item_style(theme: &Theme, status: button::Status, selected: bool) -> button::Style {
+    let palette = theme.extended_palette();
+    let pair = if selected {
+        Some(palette.primary.weak)
+    } else if matches!(status, button::Status::Hovered | button::Status::Pressed) {
+        Some(palette.background.weak)
+    } else {
+        None
+    };
+
+    button::Style {
+        background: pair.map(|pair| pair.color.into()),
+        text_color: pair.map_or(palette.background.base.text, |pair| pair.text),
+        border: Border::default().rounded(4),
+        ..button::Style::default()
+    }
+}
@@ -0,0 +1,336 @@
+//! Popovers anchored to a widget
+//!
+//! [`Anchored`] shows popovers over the rest of the interface, next to its
+//! content. Each popover is placed on a side of the content given by its
+//! [`Placement`], and flipped to the opposite side when it would leave the
+//! window.
+//!
+//! ```xml
+//! <button id="menu_button" label="Options" on_click="toggle_menu" />
+//! <float anchor="menu_button" placement="bottom-start" show="{menu_open}">
+//!     <column>
+//!         <button label="Rename" on_click="rename" />
+//!         <button label="Delete" on_click="delete" />
+//!     </column>
+//! </float>
+//! ```
+
+use dampen_core::ir::placement::{Bounds, Placement};
+use iced::advanced::widget::{Operation, Tree};
+use iced::advanced::{Clipboard, Layout, Shell, Widget, layout, mouse, overlay, renderer};
+use iced::{Element, Event, Length, Point, Rectangle, Size, Vector};
+
+/// Content showing popovers next to it
+pub struct Anchored<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    /// The content, followed by the popovers
+    elements: Vec<Element<'a, Message, Theme, Renderer>>,
+    /// Placement and gap of each popover
+    placements: Vec<(Placement, f32)>,
+}
+
+impl<'a, Message, Theme, Renderer> Anchored<'a, Message, Theme, Renderer> {
+    /// Anchor popovers to `content`
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            elements: vec![content.into()],
+            placements: Vec::new(),
+        }
+    }
+
+    /// Show `popover` on the side of the content given by `placement`, `gap`
+    /// pixels away from it
+    #[must_use]
+    pub fn popover(
+        mut self,
+        popover: impl Into<Element<'a, Message, Theme, Renderer>>,
+        placement: Placement,
+        gap: f32,
+    ) -> Self {
+        self.elements.push(popover.into());
+        self.placements.push((placement, gap));
+        self
+    }
+}
+
+/// Show `popover` next to `content`, on the side given by `placement`
+pub fn anchored<'a, Message: 'a>(
+    content: impl Into<Element<'a, Message>>,
+    popover: impl Into<Element<'a, Message>>,
+    placement: Placement,
+    gap: f32,
+) -> Element<'a, Message> {
+    Anchored::new(content).popover(popover, placement, gap).into()
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Anchored<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.elements.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.elements);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.elements[0].as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.elements[0]
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.elements[0]
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.elements[0].as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.elements[0].as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.elements[0].as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let (content, popovers) = self.elements.split_first_mut()?;
+        let (content_tree, popover_trees) = tree.children.split_first_mut()?;
+
+        let mut overlays: Vec<_> = content
+            .as_widget_mut()
+            .overlay(content_tree, layout, renderer, viewport, translation)
+            .into_iter()
+            .collect();
+
+        let anchor = layout.bounds() + translation;
+        overlays.extend(
+            popovers
+                .iter_mut()
+                .zip(popover_trees)
+                .zip(&self.placements)
+                .map(|((element, tree), &(placement, gap))| {
+                    overlay::Element::new(Box::new(Popover {
+                        anchor,
+                        element,
+                        tree,
+                        placement,
+                        gap,
+                    }))
+                }),
+        );
+
+        (!overlays.is_empty()).then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Anchored<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(anchored: Anchored<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(anchored)
+    }
+}
+
+/// Overlay of a single popover
+struct Popover<'a, 'b, Message, Theme, Renderer> {
+    /// Bounds of the anchor in window coordinates
+    anchor: Rectangle,
+    element: &'b mut Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    placement: Placement,
+    gap: f32,
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Popover<'_, '_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let node = self.element.as_widget_mut().layout(
+            self.tree,
+            renderer,
+            &layout::Limits::new(Size::ZERO, bounds),
+        );
+
+        let size = node.size();
+        let anchor = Bounds {
+            x: self.anchor.x,
+            y: self.anchor.y,
+            width: self.anchor.width,
+            height: self.anchor.height,
+        };
+        let window = Bounds {
+            width: bounds.width,
+            height: bounds.height,
+            ..Bounds::default()
+        };
+        let placed = self
+            .placement
+            .resolve(anchor, size.width, size.height, self.gap, window);
+
+        node.move_to(Point::new(placed.x, placed.y))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        self.element.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            &Rectangle::with_size(Size::INFINITE),
+        );
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.element
+            .as_widget_mut()
+            .operate(self.tree, layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        self.element.as_widget_mut().update(
+            self.tree,
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &layout.bounds(),
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.element.as_widget().mouse_interaction(
+            self.tree,
+            layout,
+            cursor,
+            &layout.bounds(),
+            renderer,
+        )
+    }
+
+    fn overlay<'c>(
+        &'c mut self,
+        layout: Layout<'c>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Theme, Renderer>> {
+        self.element.as_widget_mut().overlay(
+            self.tree,
+            layout,
+            renderer,
+            &layout.bounds(),
+            Vector::ZERO,
+        )
+    }
+}
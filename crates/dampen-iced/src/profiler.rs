@@ -0,0 +1,354 @@
+//! Budgeted render profiling for development
+//!
+//! This module provides [`RenderProfiler`], which measures where the time of a
+//! `view()` call goes when the UI is built by [`DampenWidgetBuilder`](crate::DampenWidgetBuilder):
+//! binding evaluation, style cascade resolution and widget construction. Timings are
+//! aggregated per widget (identified by its kind and source location), and when a frame
+//! exceeds the configured budget the slowest widgets are reported.
+//!
+//! The profiler is inactive unless attached to a builder with
+//! [`with_profiler()`](crate::DampenWidgetBuilder::with_profiler), so it costs nothing
+//! in builds that don't use it.
+
+#![allow(clippy::print_stderr)]
+
+use dampen_core::ir::node::WidgetNode;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Phase of widget building measured by the profiler
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfilePhase {
+    /// Evaluation of binding expressions
+    Bindings,
+    /// Theme, class and inline style resolution
+    Styles,
+}
+
+/// Configuration for [`RenderProfiler`]
+#[derive(Debug, Clone)]
+pub struct ProfilerConfig {
+    /// Frame time above which a report is produced
+    pub budget: Duration,
+
+    /// Number of hotspots included in a report
+    pub top_n: usize,
+
+    /// Whether over-budget reports are printed to stderr
+    pub log_to_console: bool,
+}
+
+impl Default for ProfilerConfig {
+    fn default() -> Self {
+        Self {
+            budget: Duration::from_millis(8),
+            top_n: 5,
+            log_to_console: true,
+        }
+    }
+}
+
+/// Accumulated timings for one widget during a frame
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WidgetTiming {
+    /// Widget kind (e.g., `"button"`)
+    pub kind: String,
+    /// Line of the widget in the source file
+    pub line: u32,
+    /// Column of the widget in the source file
+    pub column: u32,
+    /// Number of times the widget was built (more than one inside `<for>` loops)
+    pub count: u32,
+    /// Time spent evaluating bindings
+    pub bindings: Duration,
+    /// Time spent resolving styles
+    pub styles: Duration,
+    /// Remaining time spent constructing the widget itself (children excluded)
+    pub construction: Duration,
+}
+
+impl WidgetTiming {
+    /// Total time attributed to this widget
+    pub fn total(&self) -> Duration {
+        self.bindings + self.styles + self.construction
+    }
+}
+
+/// Report produced for a frame that exceeded the budget
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameReport {
+    /// Total time of the frame
+    pub total: Duration,
+    /// Configured budget
+    pub budget: Duration,
+    /// Slowest widgets, sorted by total time (descending)
+    pub hotspots: Vec<WidgetTiming>,
+}
+
+impl std::fmt::Display for FrameReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "[dampen-iced] view() took {:.2}ms (budget {:.2}ms), top {} hotspots:",
+            self.total.as_secs_f64() * 1000.0,
+            self.budget.as_secs_f64() * 1000.0,
+            self.hotspots.len()
+        )?;
+        for timing in &self.hotspots {
+            writeln!(
+                f,
+                "  <{}> at {}:{} x{}: {:.3}ms (bindings {:.3}ms, styles {:.3}ms, construction {:.3}ms)",
+                timing.kind,
+                timing.line,
+                timing.column,
+                timing.count,
+                timing.total().as_secs_f64() * 1000.0,
+                timing.bindings.as_secs_f64() * 1000.0,
+                timing.styles.as_secs_f64() * 1000.0,
+                timing.construction.as_secs_f64() * 1000.0
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Widget currently being built
+#[derive(Debug)]
+struct OpenWidget {
+    key: (String, u32, u32),
+    start: Instant,
+    children: Duration,
+    bindings: Duration,
+    styles: Duration,
+}
+
+#[derive(Debug, Default)]
+struct ProfilerState {
+    frame_start: Option<Instant>,
+    stack: Vec<OpenWidget>,
+    timings: HashMap<(String, u32, u32), WidgetTiming>,
+    in_phase: bool,
+    last_report: Option<FrameReport>,
+}
+
+/// Profiler measuring widget building time per `view()` call
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use dampen_iced::profiler::{ProfilerConfig, RenderProfiler};
+/// use std::time::Duration;
+///
+/// // Stored in the application, e.g. next to the AppState
+/// let profiler = RenderProfiler::new(ProfilerConfig {
+///     budget: Duration::from_millis(8),
+///     ..Default::default()
+/// });
+///
+/// // In view():
+/// DampenWidgetBuilder::from_app_state(&self.state)
+///     .with_profiler(&profiler)
+///     .build()
+/// ```
+#[derive(Debug, Default)]
+pub struct RenderProfiler {
+    config: ProfilerConfig,
+    state: Mutex<ProfilerState>,
+}
+
+impl RenderProfiler {
+    /// Create a profiler with the given configuration
+    pub fn new(config: ProfilerConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(ProfilerState::default()),
+        }
+    }
+
+    /// Get the profiler configuration
+    pub fn config(&self) -> &ProfilerConfig {
+        &self.config
+    }
+
+    /// Start measuring a frame, discarding timings of the previous one
+    pub fn begin_frame(&self) {
+        if let Ok(mut state) = self.state.lock() {
+            state.frame_start = Some(Instant::now());
+            state.stack.clear();
+            state.timings.clear();
+            state.in_phase = false;
+        }
+    }
+
+    /// Finish the current frame
+    ///
+    /// Returns a report when the frame exceeded the budget. The report is also
+    /// kept for [`last_report()`](Self::last_report) (e.g., for an inspector overlay)
+    /// and printed to stderr if `log_to_console` is enabled.
+    pub fn end_frame(&self) -> Option<FrameReport> {
+        let mut state = self.state.lock().ok()?;
+        let total = state.frame_start.take()?.elapsed();
+
+        if total <= self.config.budget {
+            return None;
+        }
+
+        let mut hotspots: Vec<WidgetTiming> = state.timings.drain().map(|(_, t)| t).collect();
+        hotspots.sort_by_key(|timing| std::cmp::Reverse(timing.total()));
+        hotspots.truncate(self.config.top_n);
+
+        let report = FrameReport {
+            total,
+            budget: self.config.budget,
+            hotspots,
+        };
+
+        if self.config.log_to_console {
+            eprint!("{}", report);
+        }
+
+        state.last_report = Some(report.clone());
+        Some(report)
+    }
+
+    /// Get the report of the most recent over-budget frame
+    pub fn last_report(&self) -> Option<FrameReport> {
+        self.state
+            .lock()
+            .ok()
+            .and_then(|state| state.last_report.clone())
+    }
+
+    /// Mark the start of building a widget
+    pub(crate) fn enter_widget(&self, node: &WidgetNode) {
+        if let Ok(mut state) = self.state.lock() {
+            state.stack.push(OpenWidget {
+                key: (node.kind.to_string(), node.span.line, node.span.column),
+                start: Instant::now(),
+                children: Duration::ZERO,
+                bindings: Duration::ZERO,
+                styles: Duration::ZERO,
+            });
+        }
+    }
+
+    /// Mark the end of building the innermost widget
+    pub(crate) fn exit_widget(&self) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        let Some(widget) = state.stack.pop() else {
+            return;
+        };
+
+        let elapsed = widget.start.elapsed();
+        if let Some(parent) = state.stack.last_mut() {
+            parent.children += elapsed;
+        }
+
+        let own = elapsed.saturating_sub(widget.children);
+        let construction = own
+            .saturating_sub(widget.bindings)
+            .saturating_sub(widget.styles);
+
+        let (kind, line, column) = widget.key.clone();
+        let timing = state.timings.entry(widget.key).or_insert(WidgetTiming {
+            kind,
+            line,
+            column,
+            count: 0,
+            bindings: Duration::ZERO,
+            styles: Duration::ZERO,
+            construction: Duration::ZERO,
+        });
+        timing.count += 1;
+        timing.bindings += widget.bindings;
+        timing.styles += widget.styles;
+        timing.construction += construction;
+    }
+
+    /// Run `f` and attribute its duration to `phase` of the innermost widget
+    ///
+    /// Nested measurements (e.g., bindings evaluated while resolving styles) are
+    /// attributed to the outermost phase only.
+    pub(crate) fn measure<R>(&self, phase: ProfilePhase, f: impl FnOnce() -> R) -> R {
+        let nested = match self.state.lock() {
+            Ok(mut state) => std::mem::replace(&mut state.in_phase, true),
+            Err(_) => return f(),
+        };
+        if nested {
+            return f();
+        }
+
+        let start = Instant::now();
+        let result = f();
+        let elapsed = start.elapsed();
+
+        if let Ok(mut state) = self.state.lock() {
+            state.in_phase = false;
+            if let Some(widget) = state.stack.last_mut() {
+                match phase {
+                    ProfilePhase::Bindings => widget.bindings += elapsed,
+                    ProfilePhase::Styles => widget.styles += elapsed,
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dampen_core::parser::parse;
+
+    fn spin(duration: Duration) {
+        let start = Instant::now();
+        while start.elapsed() < duration {}
+    }
+
+    #[test]
+    fn test_frame_within_budget_has_no_report() {
+        let profiler = RenderProfiler::new(ProfilerConfig {
+            budget: Duration::from_secs(10),
+            ..Default::default()
+        });
+
+        profiler.begin_frame();
+        assert!(profiler.end_frame().is_none());
+        assert!(profiler.last_report().is_none());
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_over_budget_frame_reports_hotspots() {
+        let profiler = RenderProfiler::new(ProfilerConfig {
+            budget: Duration::ZERO,
+            top_n: 1,
+            log_to_console: false,
+        });
+        let doc = parse(r#"<column><text value="{slow}" /></column>"#).expect("valid document");
+
+        profiler.begin_frame();
+        profiler.enter_widget(&doc.root);
+        profiler.enter_widget(&doc.root.children[0]);
+        profiler.measure(ProfilePhase::Bindings, || {
+            // Nested phases are attributed to the outer one
+            profiler.measure(ProfilePhase::Styles, || spin(Duration::from_millis(5)))
+        });
+        profiler.exit_widget();
+        profiler.exit_widget();
+
+        let report = profiler.end_frame().expect("frame exceeds zero budget");
+        assert_eq!(report.hotspots.len(), 1);
+
+        let hotspot = &report.hotspots[0];
+        assert_eq!(hotspot.kind, "text");
+        assert_eq!(hotspot.count, 1);
+        assert!(hotspot.bindings >= Duration::from_millis(5));
+        assert_eq!(hotspot.styles, Duration::ZERO);
+        assert_eq!(profiler.last_report(), Some(report));
+    }
+}
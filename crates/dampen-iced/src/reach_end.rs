@@ -0,0 +1,225 @@
+//! Infinite scrolling
+//!
+//! [`ReachEnd`] wraps the content of a scrollable and emits a message when the
+//! content is scrolled to less than a distance from its bottom, so an
+//! `on_reach_end` handler can load the next items of an infinite list:
+//!
+//! ```xml
+//! <scrollable height="fill" on_reach_end="load_more" reach_end_distance="200">
+//!     <for each="item" in="{items}">
+//!         <text value="{item.name}" />
+//!     </for>
+//! </scrollable>
+//! ```
+//!
+//! The message is emitted once each time the end is reached, and again when the
+//! content grows while the end is still visible, e.g. when a page of items was
+//! not enough to fill the scrollable.
+
+use dampen_core::pagination::reached_end;
+use iced::advanced::widget::{Operation, Tree, tree};
+use iced::advanced::{Clipboard, Layout, Shell, Widget, layout, mouse, overlay, renderer};
+use iced::{Element, Event, Length, Rectangle, Size, Vector};
+
+/// Wrapper emitting a message when its content is scrolled near its bottom
+pub struct ReachEnd<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    on_reach_end: Message,
+    distance: f32,
+}
+
+/// Height of the content when the message was last emitted, while at the end
+#[derive(Debug, Default)]
+struct State {
+    notified_height: Option<f32>,
+}
+
+impl<'a, Message, Theme, Renderer> ReachEnd<'a, Message, Theme, Renderer> {
+    /// Emit `on_reach_end` when `content` is scrolled to less than `distance`
+    /// pixels from its bottom
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        on_reach_end: Message,
+        distance: f32,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            on_reach_end,
+            distance,
+        }
+    }
+}
+
+/// Emit `on_reach_end` when `content` is scrolled to less than `distance`
+/// pixels from its bottom
+pub fn reach_end<'a, Message: Clone + 'a>(
+    content: impl Into<Element<'a, Message>>,
+    on_reach_end: Message,
+    distance: f32,
+) -> Element<'a, Message> {
+    ReachEnd::new(content, on_reach_end, distance).into()
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ReachEnd<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if !matches!(
+            event,
+            Event::Window(iced::window::Event::RedrawRequested(_))
+        ) {
+            return;
+        }
+
+        // Inside a scrollable, the viewport is the visible part of the content
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State>();
+        if reached_end(
+            viewport.y - bounds.y,
+            viewport.height,
+            bounds.height,
+            self.distance,
+        ) {
+            if state.notified_height != Some(bounds.height) {
+                state.notified_height = Some(bounds.height);
+                shell.publish(self.on_reach_end.clone());
+            }
+        } else {
+            state.notified_height = None;
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ReachEnd<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(reach_end: ReachEnd<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(reach_end)
+    }
+}
@@ -0,0 +1,40 @@
+//! Rich text rendering
+//!
+//! This module converts the inline content of a `<text>` widget into Iced
+//! [`Span`]s. It is used both by [`DampenWidgetBuilder`](crate::DampenWidgetBuilder)
+//! and by generated code for `markdown="true"` texts, whose value is only known at
+//! runtime.
+
+use dampen_core::ir::rich_text::{TextSpan, parse_markdown};
+use iced::font::{Style, Weight};
+use iced::widget::text::Span;
+
+/// Render a value written in the supported Markdown subset as rich text spans
+///
+/// Links carry their URL, which is passed to the `on_link` handler when clicked.
+pub fn markdown_spans(source: &str) -> Vec<Span<'static, String>> {
+    parse_markdown(source).into_iter().map(text_span).collect()
+}
+
+/// Convert a parsed [`TextSpan`] into an Iced span
+pub fn text_span(span: TextSpan) -> Span<'static, String> {
+    let font = iced::Font {
+        weight: if span.bold {
+            Weight::Bold
+        } else {
+            Weight::Normal
+        },
+        style: if span.italic {
+            Style::Italic
+        } else {
+            Style::Normal
+        },
+        ..Default::default()
+    };
+
+    let mut result = iced::widget::span(span.text).font(font);
+    if let Some(link) = span.link {
+        result = result.link(link).underline(true);
+    }
+    result
+}
@@ -0,0 +1,295 @@
+//! Bound scroll position
+//!
+//! [`ScrollPosition`] wraps a scrollable and drives its vertical offset:
+//!
+//! ```xml
+//! <scrollable offset="{log_offset}" on_scroll="log_scrolled">
+//!     ...
+//! </scrollable>
+//! <scrollable stick_to_bottom="true" height="fill">
+//!     <for each="line" in="{lines}">
+//!         <text value="{line}" />
+//!     </for>
+//! </scrollable>
+//! ```
+//!
+//! - `offset`: the content is scrolled to the bound offset, in pixels, each
+//!   time the value changes. Handlers of `on_scroll` receive a [`ScrollEvent`]
+//!   to write the offset back to the model.
+//! - `stick_to_bottom`: the content starts at its bottom and keeps following
+//!   it as it grows, until the user scrolls up; scrolling back down to the
+//!   bottom follows it again.
+
+use dampen_core::handler::ScrollEvent;
+use iced::advanced::widget::operation::scrollable::{AbsoluteOffset, RelativeOffset};
+use iced::advanced::widget::operation::Scrollable;
+use iced::advanced::widget::{Id, Operation, Tree, tree};
+use iced::advanced::{Clipboard, Layout, Shell, Widget, layout, mouse, overlay, renderer};
+use iced::widget::scrollable::Viewport;
+use iced::{Element, Event, Length, Rectangle, Size, Vector};
+
+/// Wrapper applying a bound offset and `stick_to_bottom` to a scrollable
+pub struct ScrollPosition<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    offset: Option<f32>,
+    stick_to_bottom: bool,
+}
+
+/// Offset last applied, and whether the content was scrolled yet
+#[derive(Debug, Default)]
+struct State {
+    applied: Option<f32>,
+    started: bool,
+}
+
+impl<'a, Message, Theme, Renderer> ScrollPosition<'a, Message, Theme, Renderer> {
+    /// Scroll `scrollable` to `offset` when it changes, and keep it at its
+    /// bottom with `stick_to_bottom`
+    pub fn new(
+        scrollable: impl Into<Element<'a, Message, Theme, Renderer>>,
+        offset: Option<f32>,
+        stick_to_bottom: bool,
+    ) -> Self {
+        Self {
+            content: scrollable.into(),
+            offset,
+            stick_to_bottom,
+        }
+    }
+}
+
+/// Scroll `scrollable` to `offset` when it changes, and keep it at its bottom
+/// with `stick_to_bottom`
+pub fn scroll_position<'a, Message: 'a>(
+    scrollable: impl Into<Element<'a, Message>>,
+    offset: Option<f32>,
+    stick_to_bottom: bool,
+) -> Element<'a, Message> {
+    ScrollPosition::new(scrollable, offset, stick_to_bottom).into()
+}
+
+/// Event of an `on_scroll` handler, from the viewport of a scrollable
+pub fn scroll_event(viewport: &Viewport) -> ScrollEvent {
+    let offset = viewport.absolute_offset();
+    let relative = viewport.relative_offset();
+    let bounds = viewport.bounds();
+    let content = viewport.content_bounds();
+    ScrollEvent {
+        x: offset.x,
+        y: offset.y,
+        relative_x: relative.x,
+        relative_y: relative.y,
+        viewport_width: bounds.width,
+        viewport_height: bounds.height,
+        content_width: content.width,
+        content_height: content.height,
+    }
+}
+
+/// Applies the offset to the first scrollable met, without traversing it
+struct Apply<'s> {
+    state: &'s mut State,
+    offset: Option<f32>,
+    stick_to_bottom: bool,
+    changed: bool,
+}
+
+impl Operation for Apply<'_> {
+    fn traverse(&mut self, _operate: &mut dyn FnMut(&mut dyn Operation)) {}
+
+    fn scrollable(
+        &mut self,
+        _id: Option<&Id>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        translation: Vector,
+        scrollable: &mut dyn Scrollable,
+    ) {
+        let started = std::mem::replace(&mut self.state.started, true);
+
+        if let Some(offset) = self.offset
+            && self.state.applied != Some(offset)
+        {
+            self.state.applied = Some(offset);
+            // Offsets written back by `on_scroll` are already applied
+            if (offset - translation.y).abs() > 0.5 {
+                scrollable.scroll_to(AbsoluteOffset {
+                    x: None,
+                    y: Some(offset.max(0.0)),
+                });
+                self.changed = true;
+                return;
+            }
+        }
+
+        // A relative offset of 1 follows the bottom as the content grows,
+        // until the user scrolls to an absolute offset
+        let max = (content_bounds.height - bounds.height).max(0.0);
+        if self.stick_to_bottom && (!started || translation.y >= max - 1.0) {
+            scrollable.snap_to(RelativeOffset {
+                x: None,
+                y: Some(1.0),
+            });
+            self.changed |= translation.y < max;
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ScrollPosition<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if self.offset.is_none() && !self.stick_to_bottom {
+            return;
+        }
+
+        // Run after the scrollable handled the event, so a scroll of the user
+        // is seen before the bottom is followed
+        let mut apply = Apply {
+            state: tree.state.downcast_mut::<State>(),
+            offset: self.offset,
+            stick_to_bottom: self.stick_to_bottom,
+            changed: false,
+        };
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, &mut apply);
+
+        if apply.changed {
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ScrollPosition<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(position: ScrollPosition<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(position)
+    }
+}
@@ -0,0 +1,94 @@
+//! Keyboard navigation for selectable lists
+//!
+//! Maps the keys of desktop lists to the [`SelectionAction`]s of
+//! [`SelectionState`](dampen_core::SelectionState):
+//!
+//! | Key                   | Action                                  |
+//! |-----------------------|-----------------------------------------|
+//! | Down, Up              | Select the next or previous item        |
+//! | Home, End             | Select the first or last item           |
+//! | Shift + any of these  | Extend the selection from the anchor    |
+//! | Space                 | Toggle the focused item                 |
+//! | Ctrl+A (Cmd+A)        | Select every item                       |
+//! | Escape                | Clear the selection                     |
+//!
+//! [`subscription`] emits these actions for the keys no widget captured, so
+//! typing in a text input does not move the selection. The application applies
+//! them to the selection of its list:
+//!
+//! ```rust,ignore
+//! Message::Selection(action) => {
+//!     model.selection.apply(action, None);
+//! }
+//! ```
+//!
+//! In interpreted mode, [`handler_message`] turns an action into the message of
+//! the `<field>.<action>` handler registered with
+//! `HandlerRegistry::register_selection`.
+
+use dampen_core::selection::{SelectionAction, SelectionMove};
+use iced::keyboard::{self, Key, Modifiers, key::Named};
+use iced::{Subscription, window};
+
+use crate::HandlerMessage;
+
+/// Subscription emitting the selection actions of the keys no widget captured
+pub fn subscription() -> Subscription<SelectionAction> {
+    iced::event::listen_with(key_event)
+}
+
+fn key_event(
+    event: iced::Event,
+    status: iced::event::Status,
+    _window: window::Id,
+) -> Option<SelectionAction> {
+    if status == iced::event::Status::Captured {
+        return None;
+    }
+    let iced::Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) = event else {
+        return None;
+    };
+    key_action(&key, modifiers)
+}
+
+/// Selection action of a key press, if the key navigates lists
+pub fn key_action(key: &Key, modifiers: Modifiers) -> Option<SelectionAction> {
+    let to = match key.as_ref() {
+        Key::Named(Named::ArrowDown) => SelectionMove::Next,
+        Key::Named(Named::ArrowUp) => SelectionMove::Previous,
+        Key::Named(Named::Home) => SelectionMove::First,
+        Key::Named(Named::End) => SelectionMove::Last,
+        Key::Named(Named::Space) => return Some(SelectionAction::ToggleFocused),
+        Key::Named(Named::Escape) => return Some(SelectionAction::Clear),
+        Key::Character("a" | "A") if modifiers.command() => {
+            return Some(SelectionAction::SelectAll);
+        }
+        _ => return None,
+    };
+
+    Some(SelectionAction::Move {
+        to,
+        extend: modifiers.shift(),
+    })
+}
+
+/// Selection action of a click with these modifiers
+///
+/// A click selects the item, Ctrl+click (Cmd+click) toggles it and
+/// Shift+click extends the selection to it.
+pub fn click_action(modifiers: Modifiers) -> SelectionAction {
+    if modifiers.shift() {
+        SelectionAction::Extend
+    } else if modifiers.command() {
+        SelectionAction::Toggle
+    } else {
+        SelectionAction::Select
+    }
+}
+
+/// Message of the `<field>.<action>` handler running `action`
+///
+/// Only valid for actions not taking an id, such as those of [`subscription`].
+pub fn handler_message(field: &str, action: SelectionAction) -> HandlerMessage {
+    HandlerMessage::Handler(format!("{}.{}", field, action.name()), None)
+}
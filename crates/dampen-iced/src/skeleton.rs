@@ -0,0 +1,189 @@
+//! Loading placeholders
+//!
+//! [`Skeleton`] draws rounded bars swept by a shimmer, standing in for content
+//! that is not available yet. It backs the `<skeleton>` widget and the `loading`
+//! attribute of containers, which swaps their children for a skeleton while true:
+//!
+//! ```xml
+//! <skeleton lines="3" />
+//!
+//! <column loading="{is_loading}">
+//!     <text value="{user.name}" />
+//!     <text value="{user.email}" />
+//! </column>
+//! ```
+//!
+//! The shimmer redraws the window on every frame while a skeleton is displayed,
+//! and stops as soon as the content replaces it. With reduced motion (see
+//! [`dampen_core::system`]), the lines stand still instead.
+
+use iced::advanced::widget::{Tree, tree};
+use iced::advanced::{Clipboard, Layout, Shell, Widget, layout, mouse, renderer};
+use iced::gradient::Linear;
+use iced::time::{Duration, Instant};
+use iced::{
+    Background, Border, Color, Element, Event, Gradient, Length, Radians, Rectangle, Size, Theme,
+};
+
+/// Number of lines of the placeholder shown by `loading` containers
+pub const DEFAULT_LINES: usize = 3;
+
+const LINE_HEIGHT: f32 = 14.0;
+const LINE_SPACING: f32 = 10.0;
+const SHIMMER_PERIOD: Duration = Duration::from_millis(1500);
+
+/// Placeholder made of shimmering text lines
+pub struct Skeleton {
+    lines: usize,
+    width: Length,
+}
+
+impl Skeleton {
+    /// Create a placeholder of `lines` lines (at least one)
+    pub fn new(lines: usize) -> Self {
+        Self {
+            lines: lines.max(1),
+            width: Length::Fill,
+        }
+    }
+
+    /// Set the width of the lines, filling the available space by default
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    fn height(&self) -> f32 {
+        self.lines as f32 * (LINE_HEIGHT + LINE_SPACING) - LINE_SPACING
+    }
+}
+
+/// Progress of the shimmer sweep
+#[derive(Default)]
+struct State {
+    started: Option<Instant>,
+    /// Position of the highlight, from `0.0` to `1.0`
+    phase: f32,
+}
+
+impl<Message, Renderer> Widget<Message, Theme, Renderer> for Skeleton
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height())
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if let Event::Window(iced::window::Event::RedrawRequested(now)) = event
+            && !dampen_core::system::preferences().reduced_motion
+        {
+            let state = tree.state.downcast_mut::<State>();
+            let started = *state.started.get_or_insert(*now);
+            let elapsed = now.duration_since(started).as_secs_f32();
+            state.phase = (elapsed / SHIMMER_PERIOD.as_secs_f32()).fract();
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let palette = theme.extended_palette();
+        let bounds = layout.bounds();
+        let background: Background = if dampen_core::system::preferences().reduced_motion {
+            palette.background.weak.color.into()
+        } else {
+            shimmer(
+                palette.background.weak.color,
+                palette.background.base.color,
+                state.phase,
+            )
+            .into()
+        };
+
+        for line in 0..self.lines {
+            // The last line of a paragraph is shorter
+            let width = if line + 1 == self.lines && self.lines > 1 {
+                bounds.width * 0.6
+            } else {
+                bounds.width
+            };
+            let line_bounds = Rectangle {
+                x: bounds.x,
+                y: bounds.y + line as f32 * (LINE_HEIGHT + LINE_SPACING),
+                width,
+                height: LINE_HEIGHT,
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: line_bounds,
+                    border: Border::default().rounded(4),
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+    }
+}
+
+/// Gradient of a line, with a highlight band crossing it as `phase` grows
+fn shimmer(base: Color, highlight: Color, phase: f32) -> Gradient {
+    // The band starts and ends outside of the line so it enters and leaves smoothly
+    let center = phase * 1.6 - 0.3;
+
+    Linear::new(Radians(std::f32::consts::FRAC_PI_2))
+        .add_stop(0.0, base)
+        .add_stop(center - 0.3, base)
+        .add_stop(center, highlight)
+        .add_stop(center + 0.3, base)
+        .add_stop(1.0, base)
+        .into()
+}
+
+impl<'a, Message, Renderer> From<Skeleton> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(skeleton: Skeleton) -> Self {
+        Element::new(skeleton)
+    }
+}
@@ -4,7 +4,7 @@
 //! style types, including widget state-specific styling.
 
 use dampen_core::ir::layout::{Alignment, Justification, LayoutConstraints, Length, Position};
-use dampen_core::ir::style::{Background, Color, StyleProperties};
+use dampen_core::ir::style::{Background, BorderSides, Color, Gradient, StyleProperties};
 use dampen_core::ir::theme::{StyleClass, WidgetState};
 use iced::border::Radius;
 
@@ -71,6 +71,9 @@ pub fn merge_style_properties(
             .transform
             .clone()
             .or_else(|| base.transform.clone()),
+        border_sides: BorderSides::cascade(base.border_sides, state_override.border_sides),
+        outline: state_override.outline.or(base.outline),
+        elevation: state_override.elevation.or(base.elevation),
     }
 }
 
@@ -617,7 +620,22 @@ pub fn map_style_properties(style: &StyleProperties) -> iced::widget::container:
     container_style
 }
 
+/// Map StyleProperties to Iced container Style, resolving theme colors against `theme`
+pub fn map_style_properties_with_theme(
+    style: &StyleProperties,
+    theme: &iced::Theme,
+) -> iced::widget::container::Style {
+    let mut container_style = map_style_properties(style);
+    if let Some(bg) = &style.background {
+        container_style.background = Some(map_background_with_theme(bg, theme));
+    }
+    container_style
+}
+
 /// Map Background to Iced Background
+///
+/// Gradient stops referencing a theme color use their fallback color, see
+/// [`map_background_with_theme`].
 pub fn map_background(background: &Background) -> iced::Background {
     match background {
         Background::Color(color) => iced::Background::Color(map_color(color)),
@@ -631,35 +649,64 @@ pub fn map_background(background: &Background) -> iced::Background {
     }
 }
 
-/// Map Dampen Gradient to Iced Gradient
-pub fn map_gradient(gradient: &dampen_core::ir::style::Gradient) -> iced::Gradient {
-    match gradient {
-        dampen_core::ir::style::Gradient::Linear { angle, stops } => {
-            // Convert angle from degrees to radians
-            let radians = angle * (std::f32::consts::PI / 180.0);
-
-            // Create Iced Linear gradient
-            let mut iced_linear = iced::gradient::Linear::new(iced::Radians(radians));
+/// Map Background to Iced Background, resolving the theme colors of gradient stops
+pub fn map_background_with_theme(background: &Background, theme: &iced::Theme) -> iced::Background {
+    match background {
+        Background::Gradient(gradient) => {
+            iced::Background::Gradient(map_gradient_with_theme(gradient, theme))
+        }
+        _ => map_background(background),
+    }
+}
 
-            // Add color stops (Iced supports up to 8)
-            for stop in stops.iter().take(8) {
-                iced_linear = iced_linear.add_stop(stop.offset, map_color(&stop.color));
-            }
+/// Map Dampen Gradient to Iced Gradient
+///
+/// Iced only draws linear gradients, radial ones are approximated as described in
+/// [`Gradient::to_linear`]. Stops referencing a theme color use their fallback color.
+pub fn map_gradient(gradient: &Gradient) -> iced::Gradient {
+    build_gradient(gradient, None)
+}
 
-            iced::Gradient::Linear(iced_linear)
-        }
-        dampen_core::ir::style::Gradient::Radial { shape: _, stops } => {
-            // Iced 0.14 only supports linear gradients
-            // Convert to linear as fallback
-            let radians = 0.0;
-            let mut iced_linear = iced::gradient::Linear::new(iced::Radians(radians));
+/// Map Dampen Gradient to Iced Gradient, resolving the theme colors of its stops
+pub fn map_gradient_with_theme(gradient: &Gradient, theme: &iced::Theme) -> iced::Gradient {
+    build_gradient(gradient, Some(theme))
+}
 
-            for stop in stops.iter().take(8) {
-                iced_linear = iced_linear.add_stop(stop.offset, map_color(&stop.color));
-            }
+fn build_gradient(gradient: &Gradient, theme: Option<&iced::Theme>) -> iced::Gradient {
+    let (angle, stops) = gradient.to_linear();
+
+    // Iced keeps the first 8 stops, added in ascending order
+    let linear = stops.iter().fold(
+        iced::gradient::Linear::new(iced::Radians(angle.to_radians())),
+        |linear, stop| {
+            let color = stop
+                .theme_color
+                .as_deref()
+                .zip(theme)
+                .and_then(|(name, theme)| theme_color(name, theme))
+                .unwrap_or_else(|| map_color(&stop.color));
+            linear.add_stop(stop.offset, color)
+        },
+    );
+
+    iced::Gradient::Linear(linear)
+}
 
-            iced::Gradient::Linear(iced_linear)
-        }
+/// Color of the theme palette entry `name` (`primary`, `background`, `text`...)
+///
+/// Returns `None` for names outside of
+/// [`THEME_COLORS`](dampen_core::parser::gradient::THEME_COLORS).
+pub fn theme_color(name: &str, theme: &iced::Theme) -> Option<iced::Color> {
+    let palette = theme.extended_palette();
+    match name {
+        "primary" => Some(palette.primary.base.color),
+        "secondary" => Some(palette.secondary.base.color),
+        "success" => Some(palette.success.base.color),
+        "warning" => Some(palette.warning.base.color),
+        "danger" => Some(palette.danger.base.color),
+        "background" => Some(palette.background.base.color),
+        "text" => Some(palette.background.base.text),
+        _ => None,
     }
 }
 
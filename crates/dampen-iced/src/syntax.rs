@@ -0,0 +1,276 @@
+//! Syntax highlighting
+//!
+//! [`Syntax`] is an iced [`Highlighter`] used by the `<code_editor>` widget
+//! (see [`crate::code_editor`]), built on [syntect]: the language is looked up
+//! in the syntect grammars, extended by [two-face], by name or file extension (see
+//! [`find_syntax`]), and each line is parsed into scopes starting from the
+//! parser state of the previous line, so block comments and multi-line strings
+//! carry over.
+//!
+//! Every highlighted range is styled by two syntect themes, [`DARK_THEME`] and
+//! [`LIGHT_THEME`]. [`format`] picks one of them from the iced theme, so the
+//! highlighting follows the theme of the application.
+//!
+//! [syntect]: https://docs.rs/syntect
+//! [two-face]: https://docs.rs/two-face
+
+use std::ops::Range;
+use std::sync::LazyLock;
+
+use iced::advanced::text::Highlighter;
+use iced::advanced::text::highlighter::Format;
+use iced::font::{Style as FontStyle, Weight};
+use iced::{Color, Font, Theme};
+use syntect::highlighting::{self, FontStyle as SyntectFontStyle, Style, ThemeSet};
+use syntect::parsing::{ParseState, ScopeStack, SyntaxReference, SyntaxSet};
+
+/// Syntect theme used with dark iced themes
+pub const DARK_THEME: &str = "base16-ocean.dark";
+
+/// Syntect theme used with light iced themes
+pub const LIGHT_THEME: &str = "InspiredGitHub";
+
+/// Syntect grammars of the default set and of `two-face` (TOML, TypeScript, ...),
+/// for lines without their newline
+static SYNTAXES: LazyLock<SyntaxSet> = LazyLock::new(two_face::syntax::extra_no_newlines);
+
+static THEMES: LazyLock<ThemeSet> = LazyLock::new(ThemeSet::load_defaults);
+
+/// Highlighters of [`DARK_THEME`] and [`LIGHT_THEME`]
+static HIGHLIGHTERS: LazyLock<[highlighting::Highlighter<'static>; 2]> = LazyLock::new(|| {
+    [DARK_THEME, LIGHT_THEME].map(|name| highlighting::Highlighter::new(&THEMES.themes[name]))
+});
+
+/// Grammar of a language name or file extension, plain text when unknown
+///
+/// Names are matched like syntect does (`rust`, `rs`, `Python`, `toml`, ...),
+/// plus `dampen` for XML views.
+pub fn find_syntax(language: &str) -> &'static SyntaxReference {
+    let token = match language.trim() {
+        "dampen" => "xml",
+        token => token,
+    };
+    SYNTAXES
+        .find_syntax_by_token(token)
+        .unwrap_or_else(|| SYNTAXES.find_syntax_plain_text())
+}
+
+/// Style of a highlighted range in the dark and the light theme
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Highlight {
+    dark: Style,
+    light: Style,
+}
+
+impl Highlight {
+    fn of(stack: &ScopeStack) -> Self {
+        let [dark, light] = &*HIGHLIGHTERS;
+        Self {
+            dark: dark.style_for_stack(stack.as_slice()),
+            light: light.style_for_stack(stack.as_slice()),
+        }
+    }
+}
+
+/// Highlighter of a language, fed line by line by the editor
+pub struct Syntax {
+    syntax: &'static SyntaxReference,
+    /// Parser and scopes at the start of each line, up to the next line to highlight
+    states: Vec<(ParseState, ScopeStack)>,
+}
+
+impl Syntax {
+    fn is_plain(&self) -> bool {
+        self.syntax.name == SYNTAXES.find_syntax_plain_text().name
+    }
+}
+
+impl std::fmt::Debug for Syntax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Syntax")
+            .field("syntax", &self.syntax.name)
+            .field("current_line", &self.current_line())
+            .finish()
+    }
+}
+
+impl Highlighter for Syntax {
+    /// Language name or file extension (see [`find_syntax`])
+    type Settings = String;
+    type Highlight = Highlight;
+    type Iterator<'a> = std::vec::IntoIter<(Range<usize>, Highlight)>;
+
+    fn new(settings: &Self::Settings) -> Self {
+        let syntax = find_syntax(settings);
+        Self {
+            syntax,
+            states: vec![(ParseState::new(syntax), ScopeStack::new())],
+        }
+    }
+
+    fn update(&mut self, new_settings: &Self::Settings) {
+        *self = Self::new(new_settings);
+    }
+
+    fn change_line(&mut self, line: usize) {
+        self.states.truncate(line + 1);
+    }
+
+    fn highlight_line(&mut self, line: &str) -> Self::Iterator<'_> {
+        let Some((mut parser, mut stack)) = self.states.last().cloned() else {
+            return Vec::new().into_iter();
+        };
+        if self.is_plain() {
+            self.states.push((parser, stack));
+            return Vec::new().into_iter();
+        }
+
+        // A line the grammar fails on is left unhighlighted
+        let ops = parser.parse_line(line, &SYNTAXES).unwrap_or_default();
+        let mut highlights = Vec::new();
+        let mut start = 0;
+        for (at, op) in ops {
+            if at > start {
+                highlights.push((start..at, Highlight::of(&stack)));
+                start = at;
+            }
+            if stack.apply(&op).is_err() {
+                stack = ScopeStack::new();
+            }
+        }
+        if start < line.len() {
+            highlights.push((start..line.len(), Highlight::of(&stack)));
+        }
+
+        self.states.push((parser, stack));
+        highlights.into_iter()
+    }
+
+    fn current_line(&self) -> usize {
+        self.states.len() - 1
+    }
+}
+
+/// Color and font of a highlighted range, in the syntect theme matching the iced theme
+pub fn format(highlight: &Highlight, theme: &Theme) -> Format<Font> {
+    let style = if theme.extended_palette().is_dark {
+        highlight.dark
+    } else {
+        highlight.light
+    };
+    let foreground = style.foreground;
+    let bold = style.font_style.contains(SyntectFontStyle::BOLD);
+    let italic = style.font_style.contains(SyntectFontStyle::ITALIC);
+    Format {
+        color: Some(Color::from_rgba8(
+            foreground.r,
+            foreground.g,
+            foreground.b,
+            f32::from(foreground.a) / 255.0,
+        )),
+        font: (bold || italic).then_some(Font {
+            weight: if bold { Weight::Bold } else { Weight::Normal },
+            style: if italic {
+                FontStyle::Italic
+            } else {
+                FontStyle::Normal
+            },
+            ..Font::MONOSPACE
+        }),
+    }
+}
+
+/// Scopes of each op-free range, for tests
+#[cfg(test)]
+fn scopes(syntax: &mut Syntax, line: &str) -> Vec<(String, String)> {
+    let (mut parser, mut stack) = syntax
+        .states
+        .last()
+        .cloned()
+        .unwrap_or_else(|| (ParseState::new(syntax.syntax), ScopeStack::new()));
+    let ops = parser.parse_line(line, &SYNTAXES).unwrap_or_default();
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    for (at, op) in ops {
+        if at > start {
+            ranges.push((line[start..at].to_string(), stack.to_string()));
+            start = at;
+        }
+        let _ = stack.apply(&op);
+    }
+    if start < line.len() {
+        ranges.push((line[start..].to_string(), stack.to_string()));
+    }
+    let _ = syntax.highlight_line(line);
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scope_of<'a>(ranges: &'a [(String, String)], text: &str) -> &'a str {
+        ranges
+            .iter()
+            .find(|(range, _)| range == text)
+            .map(|(_, scope)| scope.as_str())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_rust_scopes() {
+        let mut syntax = Syntax::new(&"rust".to_string());
+        let ranges = scopes(
+            &mut syntax,
+            r##"fn main() { let s = r#"a "quoted" b"#; } // done"##,
+        );
+
+        assert!(scope_of(&ranges, "fn").contains("storage.type"));
+        assert!(scope_of(&ranges, "let").contains("storage.type"));
+        // Raw strings keep their inner quotes
+        assert!(
+            ranges
+                .iter()
+                .any(|(text, scope)| text.contains("\"quoted\"") && scope.contains("string"))
+        );
+        assert!(scope_of(&ranges, " done").contains("comment"));
+    }
+
+    #[test]
+    fn test_open_constructs_carry_over() {
+        let mut syntax = Syntax::new(&"py".to_string());
+        let _ = syntax.highlight_line("x = \"\"\"doc");
+        let ranges = scopes(&mut syntax, "still doc\"\"\" + 1");
+        assert!(scope_of(&ranges, "still doc").contains("string"));
+        assert!(scope_of(&ranges, "1").contains("constant.numeric"));
+        assert_eq!(syntax.current_line(), 2);
+
+        syntax.change_line(1);
+        assert_eq!(syntax.current_line(), 1);
+    }
+
+    #[test]
+    fn test_find_syntax() {
+        assert_eq!(find_syntax("RS").name, "Rust");
+        assert_eq!(find_syntax("dampen").name, "XML");
+        assert_eq!(find_syntax("ts").name, "TypeScript");
+        assert_eq!(find_syntax("cobol").name, "Plain Text");
+        assert_eq!(find_syntax("toml").name, "TOML");
+
+        let mut plain = Syntax::new(&"cobol".to_string());
+        assert_eq!(plain.highlight_line("MOVE A TO B").count(), 0);
+    }
+
+    #[test]
+    fn test_format_follows_theme() {
+        let mut syntax = Syntax::new(&"rust".to_string());
+        let (_, keyword) = syntax
+            .highlight_line("fn")
+            .next()
+            .expect("keyword is highlighted");
+        let dark = format(&keyword, &Theme::Dark).color;
+        let light = format(&keyword, &Theme::Light).color;
+        assert!(dark.is_some());
+        assert_ne!(dark, light);
+    }
+}
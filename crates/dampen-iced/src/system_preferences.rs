@@ -0,0 +1,235 @@
+//! Accessibility preferences detection
+//!
+//! This module reads the reduced-motion and high-contrast settings of the
+//! desktop and stores them in [`dampen_core::system`], where they are bound as
+//! `{system.reduced_motion}` and `{system.high_contrast}`. A
+//! [`PreferencesBackend`] is polled in a background thread, like the theme of
+//! [`crate::system_theme`].
+//!
+//! [`PlatformPreferencesBackend`] reads the settings of the current platform:
+//!
+//! - Linux: the `reduced-motion` and `contrast` settings of the XDG desktop
+//!   portal, then the GNOME `enable-animations` and `high-contrast` settings
+//! - macOS: the `reduceMotion` and `increaseContrast` accessibility defaults
+//! - Windows: the `MinAnimate` and `HighContrast` registry values
+//!
+//! `#[dampen_app]` and the generated production code subscribe to
+//! [`changes`] when a view binds `system.*` or a theme has a high-contrast
+//! variant.
+
+use crate::system_theme::{POLL_INTERVAL, command_output};
+use dampen_core::system::{self, SystemPreferences};
+use iced::Subscription;
+use iced::futures::channel::mpsc;
+use std::hash::Hash;
+use std::time::Duration;
+
+/// Source of the accessibility preferences
+///
+/// Implement this trait to read the preferences from another source, or to
+/// fake them in tests.
+pub trait PreferencesBackend: Send + 'static {
+    /// Read the current preferences, `None` when they are unknown
+    ///
+    /// Called from a background thread, so it may block.
+    fn detect(&self) -> Option<SystemPreferences>;
+}
+
+/// Reads the accessibility preferences of the current platform
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PlatformPreferencesBackend;
+
+impl PreferencesBackend for PlatformPreferencesBackend {
+    #[cfg(target_os = "linux")]
+    fn detect(&self) -> Option<SystemPreferences> {
+        let portal = |key: &str| {
+            command_output(
+                "gdbus",
+                &[
+                    "call",
+                    "--session",
+                    "--dest",
+                    "org.freedesktop.portal.Desktop",
+                    "--object-path",
+                    "/org/freedesktop/portal/desktop",
+                    "--method",
+                    "org.freedesktop.portal.Settings.Read",
+                    "org.freedesktop.appearance",
+                    key,
+                ],
+            )
+            .and_then(|output| parse_portal_flag(&output))
+        };
+        let gsettings = |schema: &str, key: &str| {
+            command_output("gsettings", &["get", schema, key])
+                .and_then(|output| parse_gsettings_bool(&output))
+        };
+
+        let reduced_motion = portal("reduced-motion").or_else(|| {
+            gsettings("org.gnome.desktop.interface", "enable-animations").map(|enabled| !enabled)
+        });
+        let high_contrast = portal("contrast")
+            .or_else(|| gsettings("org.gnome.desktop.a11y.interface", "high-contrast"));
+        preferences(reduced_motion, high_contrast)
+    }
+
+    #[cfg(target_os = "macos")]
+    fn detect(&self) -> Option<SystemPreferences> {
+        let default = |key: &str| {
+            command_output("defaults", &["read", "com.apple.universalaccess", key])
+                .map(|value| value.trim() == "1")
+        };
+        preferences(default("reduceMotion"), default("increaseContrast"))
+    }
+
+    #[cfg(target_os = "windows")]
+    fn detect(&self) -> Option<SystemPreferences> {
+        let value = |key: &str, name: &str| {
+            command_output("reg", &["query", key, "/v", name])
+                .and_then(|output| parse_registry_value(&output, name))
+        };
+
+        let reduced_motion = value(r"HKCU\Control Panel\Desktop\WindowMetrics", "MinAnimate")
+            .map(|value| value == "0");
+        // HCF_HIGHCONTRASTON is the first bit of the flags
+        let high_contrast = value(r"HKCU\Control Panel\Accessibility\HighContrast", "Flags")
+            .and_then(|flags| flags.parse::<u32>().ok())
+            .map(|flags| flags & 1 == 1);
+        preferences(reduced_motion, high_contrast)
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+    fn detect(&self) -> Option<SystemPreferences> {
+        None
+    }
+}
+
+/// Preferences from the settings read, `None` if none could be read
+#[cfg_attr(
+    not(any(target_os = "linux", target_os = "macos", target_os = "windows")),
+    allow(dead_code)
+)]
+fn preferences(
+    reduced_motion: Option<bool>,
+    high_contrast: Option<bool>,
+) -> Option<SystemPreferences> {
+    (reduced_motion.is_some() || high_contrast.is_some()).then(|| SystemPreferences {
+        reduced_motion: reduced_motion.unwrap_or_default(),
+        high_contrast: high_contrast.unwrap_or_default(),
+    })
+}
+
+/// Notify the application when the accessibility preferences change
+///
+/// The preferences are read with [`PlatformPreferencesBackend`] and stored in
+/// [`dampen_core::system`] before the subscription emits them, so the view
+/// rebuilt on the message binds the new values. The first preferences read are
+/// emitted too.
+///
+/// # Example
+///
+/// ```ignore
+/// fn subscription(&self) -> iced::Subscription<Message> {
+///     dampen_iced::system_preferences::changes().map(|_| Message::PreferencesChanged)
+/// }
+/// ```
+pub fn changes() -> Subscription<SystemPreferences> {
+    changes_with(PlatformPreferencesBackend)
+}
+
+/// Notify the application when the preferences read from `backend` change
+///
+/// Same as [`changes`], with another [`PreferencesBackend`]. The backend
+/// identifies the subscription, so it must hash the same across calls.
+pub fn changes_with<B>(backend: B) -> Subscription<SystemPreferences>
+where
+    B: PreferencesBackend + Clone + Hash,
+{
+    Subscription::run_with((backend, POLL_INTERVAL), poll_backend::<B>)
+}
+
+/// Poll `backend` in a background thread, until the subscription stops
+fn poll_backend<B>(
+    (backend, interval): &(B, Duration),
+) -> mpsc::UnboundedReceiver<SystemPreferences>
+where
+    B: PreferencesBackend + Clone,
+{
+    let (sender, receiver) = mpsc::unbounded();
+    let backend = backend.clone();
+    let interval = *interval;
+
+    std::thread::spawn(move || {
+        let mut first = true;
+        while !sender.is_closed() {
+            if let Some(detected) = backend.detect()
+                && (system::set_detected(detected) || std::mem::take(&mut first))
+                && sender.unbounded_send(system::preferences()).is_err()
+            {
+                break;
+            }
+            std::thread::sleep(interval);
+        }
+    });
+
+    receiver
+}
+
+/// Parse a flag of the portal: `(<<uint32 1>>,)`, 1 enabling the setting
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_portal_flag(output: &str) -> Option<bool> {
+    let value = output.split("uint32").nth(1)?.trim_start();
+    let value = value.split(|c: char| !c.is_ascii_digit()).next()?;
+    value.parse::<u32>().ok().map(|value| value == 1)
+}
+
+/// Parse a boolean GNOME setting: `true` or `false`
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_gsettings_bool(output: &str) -> Option<bool> {
+    match output.trim() {
+        "true" => Some(true),
+        "false" => Some(false),
+        _ => None,
+    }
+}
+
+/// Parse the value named `name` in the output of `reg query`: `MinAnimate    REG_SZ    0`
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn parse_registry_value(output: &str, name: &str) -> Option<String> {
+    output
+        .lines()
+        .find(|line| line.trim_start().starts_with(name))
+        .and_then(|line| line.split_whitespace().nth(2))
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_platform_settings() {
+        assert_eq!(parse_portal_flag("(<<uint32 1>>,)\n"), Some(true));
+        assert_eq!(parse_portal_flag("(<uint32 0>,)\n"), Some(false));
+        assert_eq!(parse_portal_flag("Error: No such key\n"), None);
+
+        assert_eq!(parse_gsettings_bool("false\n"), Some(false));
+        assert_eq!(parse_gsettings_bool("'default'"), None);
+
+        let registry = "\r\nHKEY_CURRENT_USER\\Control Panel\\Desktop\\WindowMetrics\r\n    MinAnimate    REG_SZ    0\r\n";
+        assert_eq!(
+            parse_registry_value(registry, "MinAnimate"),
+            Some("0".to_string())
+        );
+        assert_eq!(parse_registry_value(registry, "Flags"), None);
+
+        assert_eq!(preferences(None, None), None);
+        assert_eq!(
+            preferences(Some(true), None),
+            Some(SystemPreferences {
+                reduced_motion: true,
+                high_contrast: false,
+            })
+        );
+    }
+}
@@ -1,17 +1,400 @@
 //! System theme detection for production builds
 //!
-//! This module provides system theme change detection using Iced's native
-//! theme subscription, which works in both debug and release builds.
+//! This module provides system theme change detection, which works in both
+//! debug and release builds. It combines two sources:
+//!
+//! - Iced's native theme subscription, notified by the windowing system when
+//!   the appearance changes (macOS appearance and Windows settings changes)
+//! - A [`ThemeBackend`] read in a background thread, which reports the initial
+//!   theme on startup and covers desktops the windowing system does not notify,
+//!   such as most Linux desktops. The backend is read again when its
+//!   [`ChangeListener`] reports a change, and only polled every
+//!   [`POLL_INTERVAL`] when it has no change notifications.
+//!
+//! [`PlatformThemeBackend`] reads the theme of the current platform:
+//!
+//! - Linux: the `color-scheme` setting of the XDG desktop portal, then the
+//!   GTK settings (`gsettings`, `GTK_THEME` and `~/.config/gtk-3.0/settings.ini`).
+//!   Changes are notified by the `SettingChanged` signal of the portal; the GTK
+//!   settings are polled.
+//! - macOS: the `AppleInterfaceStyle` user default, notified by the
+//!   `AppleInterfaceThemeChangedNotification` distributed notification
+//! - Windows: the `AppsUseLightTheme` registry value, notified by
+//!   `RegNotifyChangeKeyValue`
 
 use iced::Subscription;
+use iced::futures::channel::mpsc;
+use iced::theme::Mode;
+use std::hash::Hash;
+use std::process::Command;
+use std::time::Duration;
+
+/// Interval between two reads of a [`ThemeBackend`] without change notifications
+pub const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Source of the system theme
+///
+/// Implement this trait to read the theme from another source, or to fake it
+/// in tests.
+pub trait ThemeBackend: Send + 'static {
+    /// Read the current system theme, [`Mode::None`] when it is unknown
+    ///
+    /// Called from a background thread, so it may block.
+    fn detect(&self) -> Mode;
+
+    /// Start listening to the changes of the system theme
+    ///
+    /// Returns `None` when the system cannot notify the changes, in which case
+    /// the backend is polled every [`POLL_INTERVAL`]. The default
+    /// implementation returns `None`.
+    fn listen(&self) -> Option<Box<dyn ChangeListener>> {
+        None
+    }
+}
+
+/// Notifications of the changes of the system theme, see [`ThemeBackend::listen`]
+pub trait ChangeListener: Send {
+    /// Block until the system theme may have changed
+    ///
+    /// Returns `false` when the notifications stopped, in which case the
+    /// backend is polled from then on.
+    fn wait(&mut self) -> bool;
+}
+
+/// Reads the system theme of the current platform
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PlatformThemeBackend;
+
+impl ThemeBackend for PlatformThemeBackend {
+    #[cfg(target_os = "linux")]
+    fn detect(&self) -> Mode {
+        let color_scheme = || {
+            command_output(
+                "gsettings",
+                &["get", "org.gnome.desktop.interface", "color-scheme"],
+            )
+            .map_or(Mode::None, |output| parse_gsettings_color_scheme(&output))
+        };
+        let gtk_theme = || {
+            std::env::var("GTK_THEME")
+                .ok()
+                .or_else(|| {
+                    command_output(
+                        "gsettings",
+                        &["get", "org.gnome.desktop.interface", "gtk-theme"],
+                    )
+                })
+                .map_or(Mode::None, |name| parse_gtk_theme_name(&name))
+        };
+        let settings_ini = || {
+            std::env::var_os("XDG_CONFIG_HOME")
+                .map(std::path::PathBuf::from)
+                .or_else(|| {
+                    std::env::var_os("HOME").map(|home| std::path::Path::new(&home).join(".config"))
+                })
+                .and_then(|config| {
+                    std::fs::read_to_string(config.join("gtk-3.0/settings.ini")).ok()
+                })
+                .map_or(Mode::None, |settings| parse_gtk_settings_ini(&settings))
+        };
+
+        let sources: [&dyn Fn() -> Mode; 4] = [
+            &linux::read_portal_color_scheme,
+            &color_scheme,
+            &gtk_theme,
+            &settings_ini,
+        ];
+        sources
+            .into_iter()
+            .map(|detect| detect())
+            .find(|mode| *mode != Mode::None)
+            .unwrap_or(Mode::None)
+    }
+
+    #[cfg(target_os = "macos")]
+    fn detect(&self) -> Mode {
+        // The key only exists in dark mode, so reading it fails in light mode
+        match command_output("defaults", &["read", "-g", "AppleInterfaceStyle"]) {
+            Some(style) if style.trim().eq_ignore_ascii_case("dark") => Mode::Dark,
+            _ => Mode::Light,
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    fn detect(&self) -> Mode {
+        windows::read_apps_use_light_theme()
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+    fn detect(&self) -> Mode {
+        Mode::None
+    }
+
+    #[cfg(target_os = "linux")]
+    fn listen(&self) -> Option<Box<dyn ChangeListener>> {
+        // Without the portal, the theme comes from the GTK settings, which are polled
+        if linux::read_portal_color_scheme() == Mode::None {
+            return None;
+        }
+        linux::PortalListener::spawn().map(|listener| Box::new(listener) as Box<dyn ChangeListener>)
+    }
+
+    #[cfg(target_os = "macos")]
+    fn listen(&self) -> Option<Box<dyn ChangeListener>> {
+        Some(Box::new(macos::AppearanceListener::register()))
+    }
+
+    #[cfg(target_os = "windows")]
+    fn listen(&self) -> Option<Box<dyn ChangeListener>> {
+        windows::PersonalizeListener::open()
+            .map(|listener| Box::new(listener) as Box<dyn ChangeListener>)
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use super::{ChangeListener, Mode, command_output, is_color_scheme_change};
+    use std::io::{BufRead, BufReader, Lines};
+    use std::process::{Child, ChildStdout, Command, Stdio};
+
+    /// Arguments of `gdbus` addressing the desktop portal
+    const PORTAL_ARGS: [&str; 5] = [
+        "--session",
+        "--dest",
+        "org.freedesktop.portal.Desktop",
+        "--object-path",
+        "/org/freedesktop/portal/desktop",
+    ];
+
+    /// Read the `color-scheme` setting of the XDG desktop portal
+    pub(super) fn read_portal_color_scheme() -> Mode {
+        let mut args = vec!["call"];
+        args.extend(PORTAL_ARGS);
+        args.extend([
+            "--method",
+            "org.freedesktop.portal.Settings.Read",
+            "org.freedesktop.appearance",
+            "color-scheme",
+        ]);
+        command_output("gdbus", &args).map_or(Mode::None, |output| {
+            super::parse_portal_color_scheme(&output)
+        })
+    }
+
+    /// Signals of the portal, printed by a `gdbus monitor` running as long as the listener
+    pub(super) struct PortalListener {
+        monitor: Child,
+        lines: Lines<BufReader<ChildStdout>>,
+    }
+
+    impl PortalListener {
+        pub(super) fn spawn() -> Option<Self> {
+            let mut monitor = Command::new("gdbus")
+                .arg("monitor")
+                .args(PORTAL_ARGS)
+                .stdout(Stdio::piped())
+                .stderr(Stdio::null())
+                .spawn()
+                .ok()?;
+            let stdout = monitor.stdout.take()?;
+            Some(Self {
+                monitor,
+                lines: BufReader::new(stdout).lines(),
+            })
+        }
+    }
+
+    impl ChangeListener for PortalListener {
+        fn wait(&mut self) -> bool {
+            // The monitor exiting ends the lines
+            self.lines
+                .by_ref()
+                .map_while(Result::ok)
+                .any(|line| is_color_scheme_change(&line))
+        }
+    }
+
+    impl Drop for PortalListener {
+        fn drop(&mut self) {
+            let _ = self.monitor.kill();
+            let _ = self.monitor.wait();
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod macos {
+    use super::ChangeListener;
+    use block2::RcBlock;
+    use objc2_foundation::{
+        NSDistributedNotificationCenter, NSNotification, NSOperationQueue, NSString,
+    };
+    use std::ptr::NonNull;
+    use std::sync::mpsc::{self, Receiver};
+
+    /// `AppleInterfaceThemeChangedNotification`s, posted when the appearance changes
+    pub(super) struct AppearanceListener(Receiver<()>);
+
+    impl AppearanceListener {
+        pub(super) fn register() -> Self {
+            let (sender, receiver) = mpsc::channel();
+            let block = RcBlock::new(move |_: NonNull<NSNotification>| {
+                let _ = sender.send(());
+            });
+            let name = NSString::from_str("AppleInterfaceThemeChangedNotification");
+            let center = NSDistributedNotificationCenter::defaultCenter();
+            // SAFETY: the block only sends on a channel, from the main queue.
+            // The center keeps the observer registered for the life of the app.
+            let _observer = unsafe {
+                center.addObserverForName_object_queue_usingBlock(
+                    Some(&name),
+                    None,
+                    Some(&NSOperationQueue::mainQueue()),
+                    &block,
+                )
+            };
+            Self(receiver)
+        }
+    }
+
+    impl ChangeListener for AppearanceListener {
+        fn wait(&mut self) -> bool {
+            self.0.recv().is_ok()
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod windows {
+    use super::{ChangeListener, Mode};
+    use windows_sys::Win32::Foundation::ERROR_SUCCESS;
+    use windows_sys::Win32::System::Registry::{
+        HKEY, HKEY_CURRENT_USER, KEY_NOTIFY, KEY_QUERY_VALUE, REG_NOTIFY_CHANGE_LAST_SET,
+        RRF_RT_REG_DWORD, RegCloseKey, RegGetValueW, RegNotifyChangeKeyValue, RegOpenKeyExW,
+    };
+
+    const PERSONALIZE: &str = r"Software\Microsoft\Windows\CurrentVersion\Themes\Personalize";
+
+    /// Null-terminated UTF-16 string
+    fn wide(text: &str) -> Vec<u16> {
+        text.encode_utf16().chain(std::iter::once(0)).collect()
+    }
+
+    /// Read the `AppsUseLightTheme` value, 0 in dark mode
+    pub(super) fn read_apps_use_light_theme() -> Mode {
+        let key = wide(PERSONALIZE);
+        let name = wide("AppsUseLightTheme");
+        let mut value: u32 = 0;
+        let mut size = std::mem::size_of::<u32>() as u32;
+        // SAFETY: the strings are null-terminated and `value` holds the `size` bytes of a DWORD
+        let status = unsafe {
+            RegGetValueW(
+                HKEY_CURRENT_USER,
+                key.as_ptr(),
+                name.as_ptr(),
+                RRF_RT_REG_DWORD,
+                std::ptr::null_mut(),
+                (&mut value as *mut u32).cast(),
+                &mut size,
+            )
+        };
+        match (status, value) {
+            (ERROR_SUCCESS, 0) => Mode::Dark,
+            (ERROR_SUCCESS, _) => Mode::Light,
+            _ => Mode::None,
+        }
+    }
+
+    /// Personalization key of the registry, waited on for changes of its values
+    pub(super) struct PersonalizeListener(HKEY);
+
+    // SAFETY: registry handles are not bound to the thread that opened them
+    unsafe impl Send for PersonalizeListener {}
+
+    impl PersonalizeListener {
+        pub(super) fn open() -> Option<Self> {
+            let key = wide(PERSONALIZE);
+            let mut handle: HKEY = std::ptr::null_mut();
+            // SAFETY: the key name is null-terminated and `handle` receives the opened key
+            let status = unsafe {
+                RegOpenKeyExW(
+                    HKEY_CURRENT_USER,
+                    key.as_ptr(),
+                    0,
+                    KEY_NOTIFY | KEY_QUERY_VALUE,
+                    &mut handle,
+                )
+            };
+            (status == ERROR_SUCCESS).then_some(Self(handle))
+        }
+    }
+
+    impl ChangeListener for PersonalizeListener {
+        fn wait(&mut self) -> bool {
+            // SAFETY: the key is open until the listener is dropped; without an
+            // event, the call blocks until a value of the key is set
+            let status = unsafe {
+                RegNotifyChangeKeyValue(
+                    self.0,
+                    0,
+                    REG_NOTIFY_CHANGE_LAST_SET,
+                    std::ptr::null_mut(),
+                    0,
+                )
+            };
+            status == ERROR_SUCCESS
+        }
+    }
+
+    impl Drop for PersonalizeListener {
+        fn drop(&mut self) {
+            // SAFETY: the key was opened by `open` and is closed once
+            unsafe {
+                RegCloseKey(self.0);
+            }
+        }
+    }
+}
+
+/// Reports the changes of the theme read from a [`ThemeBackend`]
+///
+/// The first known theme is reported as a change, so that watchers start with
+/// the current theme.
+#[derive(Debug)]
+pub struct ThemeWatcher<B> {
+    backend: B,
+    last: Mode,
+}
+
+impl<B: ThemeBackend> ThemeWatcher<B> {
+    /// Watch the theme read from `backend`
+    pub fn new(backend: B) -> Self {
+        Self {
+            backend,
+            last: Mode::None,
+        }
+    }
+
+    /// Read the theme, returning it when it changed since the last read
+    pub fn poll(&mut self) -> Option<Mode> {
+        let mode = self.backend.detect();
+        if mode == Mode::None || mode == self.last {
+            return None;
+        }
+        self.last = mode;
+        Some(mode)
+    }
+}
 
 /// Watch for system theme changes.
 ///
 /// Returns a subscription that emits "light" or "dark" strings when the
-/// operating system theme preference changes.
+/// operating system theme preference changes, and once with the current theme
+/// when it starts.
 ///
 /// This function uses Iced's native `system::theme_changes()` subscription
-/// and is safe to use in production (release) builds.
+/// along with [`PlatformThemeBackend`], and is safe to use in production
+/// (release) builds.
 ///
 /// # Returns
 ///
@@ -29,9 +412,260 @@ use iced::Subscription;
 /// }
 /// ```
 pub fn watch_system_theme() -> Subscription<String> {
-    iced::system::theme_changes().map(|mode| match mode {
-        iced::theme::Mode::Light => "light".to_string(),
-        iced::theme::Mode::Dark => "dark".to_string(),
-        iced::theme::Mode::None => "light".to_string(),
-    })
+    watch_system_theme_with(PlatformThemeBackend)
+}
+
+/// Watch for system theme changes read from `backend`
+///
+/// Same as [`watch_system_theme`], with another [`ThemeBackend`]. The backend
+/// identifies the subscription, so it must hash the same across calls.
+pub fn watch_system_theme_with<B>(backend: B) -> Subscription<String>
+where
+    B: ThemeBackend + Clone + Hash,
+{
+    Subscription::batch([
+        iced::system::theme_changes().map(mode_name),
+        Subscription::run_with((backend, POLL_INTERVAL), poll_backend::<B>).map(mode_name),
+    ])
+}
+
+fn mode_name(mode: Mode) -> String {
+    match mode {
+        Mode::Light => "light".to_string(),
+        Mode::Dark => "dark".to_string(),
+        Mode::None => "light".to_string(),
+    }
+}
+
+/// Read `backend` in a background thread on each change, until the subscription stops
+///
+/// The backend is polled every `interval` when it has no [`ChangeListener`],
+/// or once its notifications stopped.
+fn poll_backend<B>((backend, interval): &(B, Duration)) -> mpsc::UnboundedReceiver<Mode>
+where
+    B: ThemeBackend + Clone,
+{
+    let (sender, receiver) = mpsc::unbounded();
+    let mut watcher = ThemeWatcher::new(backend.clone());
+    let interval = *interval;
+
+    std::thread::spawn(move || {
+        // Listen before the first read, so no change is missed in between
+        let mut listener = watcher.backend.listen();
+        while !sender.is_closed() {
+            if let Some(mode) = watcher.poll()
+                && sender.unbounded_send(mode).is_err()
+            {
+                break;
+            }
+            if !listener.as_mut().is_some_and(|listener| listener.wait()) {
+                listener = None;
+                std::thread::sleep(interval);
+            }
+        }
+    });
+
+    receiver
+}
+
+/// Standard output of a command, if it succeeded
+#[cfg_attr(
+    not(any(target_os = "linux", target_os = "macos", target_os = "windows")),
+    allow(dead_code)
+)]
+pub(crate) fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let mut command = Command::new(program);
+    command.args(args);
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+
+        // Console programs would flash a console window over GUI applications
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+    let output = command.output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Parse the reply of the portal: `(<<uint32 1>>,)`, 1 preferring dark and 2 light
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_portal_color_scheme(output: &str) -> Mode {
+    let value = output.split("uint32").nth(1).and_then(|rest| {
+        rest.trim_start()
+            .split(|c: char| !c.is_ascii_digit())
+            .next()
+    });
+    match value {
+        Some("1") => Mode::Dark,
+        Some("2") => Mode::Light,
+        _ => Mode::None,
+    }
+}
+
+/// Whether a line of `gdbus monitor` is a `SettingChanged` signal of the portal's `color-scheme`
+///
+/// E.g. `/org/freedesktop/portal/desktop: org.freedesktop.portal.Settings.SettingChanged
+/// ('org.freedesktop.appearance', 'color-scheme', <uint32 1>)`
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn is_color_scheme_change(line: &str) -> bool {
+    line.contains("org.freedesktop.portal.Settings.SettingChanged")
+        && line.contains("'org.freedesktop.appearance', 'color-scheme'")
+}
+
+/// Parse the GNOME `color-scheme` setting: `'prefer-dark'`, `'prefer-light'` or `'default'`
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_gsettings_color_scheme(output: &str) -> Mode {
+    match output.trim().trim_matches('\'') {
+        "prefer-dark" => Mode::Dark,
+        "prefer-light" => Mode::Light,
+        _ => Mode::None,
+    }
+}
+
+/// Parse a GTK theme name, dark themes ending in `-dark` or `:dark` (`Adwaita-dark`)
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_gtk_theme_name(name: &str) -> Mode {
+    let name = name.trim().trim_matches('\'').to_lowercase();
+    if name.is_empty() {
+        Mode::None
+    } else if name.ends_with("-dark") || name.ends_with(":dark") {
+        Mode::Dark
+    } else {
+        Mode::Light
+    }
+}
+
+/// Parse `gtk-application-prefer-dark-theme` in a GTK `settings.ini`
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_gtk_settings_ini(settings: &str) -> Mode {
+    settings
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .find(|(key, _)| key.trim() == "gtk-application-prefer-dark-theme")
+        .map_or(Mode::None, |(_, value)| match value.trim() {
+            "1" | "true" => Mode::Dark,
+            _ => Mode::Light,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::futures::{StreamExt, executor};
+    use std::sync::mpsc as std_mpsc;
+    use std::sync::{Arc, Mutex};
+
+    /// Backend returning the themes set by the test
+    #[derive(Clone, Default)]
+    struct MockBackend(Arc<Mutex<Mode>>);
+
+    impl MockBackend {
+        fn set(&self, mode: Mode) {
+            if let Ok(mut current) = self.0.lock() {
+                *current = mode;
+            }
+        }
+    }
+
+    impl ThemeBackend for MockBackend {
+        fn detect(&self) -> Mode {
+            self.0.lock().map_or(Mode::None, |mode| *mode)
+        }
+    }
+
+    /// Backend notifying the changes sent by the test
+    #[derive(Clone)]
+    struct NotifyingBackend {
+        theme: MockBackend,
+        notifications: Arc<Mutex<Option<std_mpsc::Receiver<()>>>>,
+    }
+
+    struct MockListener(std_mpsc::Receiver<()>);
+
+    impl ChangeListener for MockListener {
+        fn wait(&mut self) -> bool {
+            self.0.recv().is_ok()
+        }
+    }
+
+    impl ThemeBackend for NotifyingBackend {
+        fn detect(&self) -> Mode {
+            self.theme.detect()
+        }
+
+        fn listen(&self) -> Option<Box<dyn ChangeListener>> {
+            let receiver = self.notifications.lock().ok()?.take()?;
+            Some(Box::new(MockListener(receiver)))
+        }
+    }
+
+    #[test]
+    fn test_watcher_reports_initial_theme_then_changes() {
+        let backend = MockBackend::default();
+        let mut watcher = ThemeWatcher::new(backend.clone());
+
+        // Unknown theme: nothing to report yet
+        assert_eq!(watcher.poll(), None);
+
+        backend.set(Mode::Dark);
+        assert_eq!(watcher.poll(), Some(Mode::Dark));
+        assert_eq!(watcher.poll(), None);
+
+        backend.set(Mode::Light);
+        assert_eq!(watcher.poll(), Some(Mode::Light));
+
+        // A failed read keeps the last theme
+        backend.set(Mode::None);
+        assert_eq!(watcher.poll(), None);
+        backend.set(Mode::Light);
+        assert_eq!(watcher.poll(), None);
+    }
+
+    #[test]
+    fn test_backend_is_read_on_notifications() {
+        let (notify, notifications) = std_mpsc::channel();
+        let backend = NotifyingBackend {
+            theme: MockBackend::default(),
+            notifications: Arc::new(Mutex::new(Some(notifications))),
+        };
+        backend.theme.set(Mode::Dark);
+
+        // Polling every hour would not see the change before the test ends
+        let mut changes = poll_backend(&(backend.clone(), Duration::from_secs(3600)));
+        assert_eq!(executor::block_on(changes.next()), Some(Mode::Dark));
+
+        backend.theme.set(Mode::Light);
+        assert!(notify.send(()).is_ok());
+        assert_eq!(executor::block_on(changes.next()), Some(Mode::Light));
+    }
+
+    #[test]
+    fn test_parse_platform_settings() {
+        assert_eq!(parse_portal_color_scheme("(<<uint32 1>>,)\n"), Mode::Dark);
+        assert_eq!(parse_portal_color_scheme("(<uint32 2>,)\n"), Mode::Light);
+        assert_eq!(parse_portal_color_scheme("(<<uint32 0>>,)\n"), Mode::None);
+
+        assert_eq!(parse_gsettings_color_scheme("'prefer-dark'\n"), Mode::Dark);
+        assert_eq!(parse_gsettings_color_scheme("'default'\n"), Mode::None);
+
+        assert_eq!(parse_gtk_theme_name("'Adwaita-dark'\n"), Mode::Dark);
+        assert_eq!(parse_gtk_theme_name("Adwaita:dark"), Mode::Dark);
+        assert_eq!(parse_gtk_theme_name("'Adwaita'"), Mode::Light);
+
+        let settings =
+            "[Settings]\ngtk-theme-name=Adwaita\ngtk-application-prefer-dark-theme = 1\n";
+        assert_eq!(parse_gtk_settings_ini(settings), Mode::Dark);
+        assert_eq!(parse_gtk_settings_ini("[Settings]\n"), Mode::None);
+
+        assert!(is_color_scheme_change(
+            "/org/freedesktop/portal/desktop: org.freedesktop.portal.Settings.SettingChanged ('org.freedesktop.appearance', 'color-scheme', <uint32 1>)"
+        ));
+        assert!(!is_color_scheme_change(
+            "/org/freedesktop/portal/desktop: org.freedesktop.portal.Settings.SettingChanged ('org.gnome.desktop.interface', 'font-name', <'Cantarell 11'>)"
+        ));
+    }
 }
@@ -0,0 +1,116 @@
+//! Per-view task scopes
+//!
+//! This module provides [`TaskScope`], a small registry of abort handles used by
+//! the `#[dampen_app]` runtime to tie background tasks to the view that started them.
+//! When the user navigates away from a view, every task tracked by its scope is
+//! aborted so timers, streams and jobs stop producing messages for a hidden view.
+
+use iced::Task;
+use iced::task::Handle;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Tracks the tasks started by a single view.
+///
+/// Tasks are registered with [`TaskScope::track`], which makes them abortable and
+/// keeps the corresponding [`Handle`] until the task finishes. Calling
+/// [`TaskScope::cancel_all`] aborts every running task and releases the handles.
+///
+/// Iced tasks cannot be suspended, so "pausing" a view means cancelling its scope
+/// on exit and starting the work again when the view is entered (the generated
+/// app dispatches the view's `on_view_enter` handler for that purpose).
+///
+/// # Example
+///
+/// ```ignore
+/// use dampen_iced::TaskScope;
+///
+/// let mut scope = TaskScope::new();
+/// let task = scope.track(iced::Task::perform(fetch_data(), Message::Loaded));
+///
+/// // Later, when leaving the view:
+/// scope.cancel_all();
+/// ```
+#[derive(Debug, Default)]
+pub struct TaskScope {
+    handles: Vec<TrackedTask>,
+}
+
+/// Abort handle of a tracked task
+#[derive(Debug)]
+struct TrackedTask {
+    handle: Handle,
+    /// Set once the task has produced all of its messages
+    finished: Arc<AtomicBool>,
+}
+
+impl TrackedTask {
+    fn is_running(&self) -> bool {
+        !self.finished.load(Ordering::Relaxed)
+    }
+}
+
+impl TaskScope {
+    /// Create an empty task scope
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a task with this scope
+    ///
+    /// Returns the abortable version of the task, which must be handed back to
+    /// the Iced runtime. Tasks that perform no work are returned unchanged.
+    ///
+    /// Handles of the tasks that finished since the last call are released, so
+    /// the scope of a view that stays open does not grow.
+    pub fn track<T: Send + 'static>(&mut self, task: Task<T>) -> Task<T> {
+        if task.units() == 0 {
+            return task;
+        }
+        self.handles.retain(TrackedTask::is_running);
+
+        let (task, handle) = task.abortable();
+        let finished = Arc::new(AtomicBool::new(false));
+        self.handles.push(TrackedTask {
+            handle,
+            finished: finished.clone(),
+        });
+
+        // Runs once the task has produced all of its messages, or was aborted
+        let mark_finished = Task::future(async move { finished.store(true, Ordering::Relaxed) });
+        task.chain(mark_finished.discard())
+    }
+
+    /// Abort every running task tracked by this scope
+    ///
+    /// Returns the number of tasks that were aborted.
+    pub fn cancel_all(&mut self) -> usize {
+        let mut count = 0;
+        for tracked in self.handles.drain(..) {
+            if tracked.is_running() {
+                tracked.handle.abort();
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Number of running tasks tracked by this scope
+    pub fn len(&self) -> usize {
+        self.handles
+            .iter()
+            .filter(|tracked| tracked.is_running())
+            .count()
+    }
+
+    /// Returns `true` if no running tasks are tracked
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Drop for TaskScope {
+    fn drop(&mut self) {
+        self.cancel_all();
+    }
+}
@@ -1,51 +1,163 @@
 //! Theme adapter for converting Dampen themes to Iced themes
 //!
-//! This module adapts Dampen's theme system to Iced's theme system.
+//! This module adapts Dampen's theme system to Iced's theme system. Themes are
+//! converted to custom Iced themes whose extended palette is derived from every
+//! color of the Dampen palette, and the style functions of the
+//! button, container and text input widgets follow the theme tokens.
 
+use crate::style_mapping::{map_border_radius, map_color};
 use dampen_core::ir::theme::{FontWeight, Theme, ThemePalette, Typography};
 use iced::Theme as IcedTheme;
+use iced::theme::palette::{Extended, Pair, Primary, Secondary};
+use iced::widget::{button, container, text_input};
+use iced::{Background, Border};
 
 /// Adapter for converting Dampen themes to Iced themes
 pub struct ThemeAdapter;
 
 impl ThemeAdapter {
     /// Convert a Dampen theme to an Iced custom theme
+    ///
+    /// Built-in Iced widget styles read the extended palette of the theme, see
+    /// [`ThemeAdapter::extended_palette`].
     pub fn to_iced(theme: &Theme) -> IcedTheme {
-        let palette_colors = theme.palette.iced_colors();
-        let palette = iced::theme::Palette {
-            primary: iced::Color::from_rgb(
-                palette_colors.primary.0,
-                palette_colors.primary.1,
-                palette_colors.primary.2,
-            ),
-            background: iced::Color::from_rgb(
-                palette_colors.background.0,
-                palette_colors.background.1,
-                palette_colors.background.2,
-            ),
-            text: iced::Color::from_rgb(
-                palette_colors.text.0,
-                palette_colors.text.1,
-                palette_colors.text.2,
-            ),
-            success: iced::Color::from_rgb(
-                palette_colors.success.0,
-                palette_colors.success.1,
-                palette_colors.success.2,
-            ),
-            warning: iced::Color::from_rgb(
-                palette_colors.warning.0,
-                palette_colors.warning.1,
-                palette_colors.warning.2,
-            ),
-            danger: iced::Color::from_rgb(
-                palette_colors.danger.0,
-                palette_colors.danger.1,
-                palette_colors.danger.2,
-            ),
+        let extended = Self::extended_palette(theme);
+        IcedTheme::custom_with_fn(
+            theme.name.clone(),
+            Self::palette_to_iced(&theme.palette),
+            move |_| extended,
+        )
+    }
+
+    /// Derive the Iced extended palette of a Dampen theme
+    ///
+    /// Iced derives a neutral secondary color from the background and has no
+    /// surface color: the secondary colors follow the Dampen `secondary`
+    /// color instead, and the weak background (used by boxed containers and
+    /// disabled inputs) is the Dampen `surface` color.
+    pub fn extended_palette(theme: &Theme) -> Extended {
+        let palette = Self::palette_to_iced(&theme.palette);
+        let mut extended = Extended::generate(palette);
+
+        if let Some(secondary) = &theme.palette.secondary {
+            let Primary { base, weak, strong } =
+                Primary::generate(map_color(secondary), palette.background, palette.text);
+            extended.secondary = Secondary { base, weak, strong };
+        }
+
+        if let Some(surface) = &theme.palette.surface {
+            extended.background.weak = Pair::new(map_color(surface), palette.text);
+        }
+
+        extended
+    }
+
+    /// Style function of buttons in the primary color of `theme`
+    pub fn button_style(
+        theme: &Theme,
+    ) -> impl Fn(&IcedTheme, button::Status) -> button::Style + 'static {
+        let palette = Self::extended_palette(theme);
+        let border = Self::base_border(theme, "button");
+
+        move |_, status| {
+            let active = button::Style {
+                background: Some(Background::Color(palette.primary.base.color)),
+                text_color: palette.primary.base.text,
+                border,
+                ..button::Style::default()
+            };
+
+            match status {
+                button::Status::Active | button::Status::Pressed => active,
+                button::Status::Hovered => button::Style {
+                    background: Some(Background::Color(palette.primary.strong.color)),
+                    ..active
+                },
+                button::Status::Disabled => button::Style {
+                    background: active.background.map(|bg| bg.scale_alpha(0.5)),
+                    text_color: active.text_color.scale_alpha(0.5),
+                    ..active
+                },
+            }
+        }
+    }
+
+    /// Style function of containers on the surface color of `theme`
+    pub fn container_style(theme: &Theme) -> impl Fn(&IcedTheme) -> container::Style + 'static {
+        let palette = Self::extended_palette(theme);
+        let border = Self::base_border(theme, "container");
+
+        move |_| container::Style {
+            background: Some(Background::Color(palette.background.weak.color)),
+            text_color: Some(palette.background.weak.text),
+            border,
+            ..container::Style::default()
+        }
+    }
+
+    /// Style function of text inputs, with placeholders in the secondary text
+    /// color of `theme`
+    pub fn text_input_style(
+        theme: &Theme,
+    ) -> impl Fn(&IcedTheme, text_input::Status) -> text_input::Style + 'static {
+        let palette = Self::extended_palette(theme);
+        let border = Border {
+            width: 1.0,
+            color: palette.background.strong.color,
+            ..Self::base_border(theme, "text_input")
         };
+        let muted = theme
+            .palette
+            .text_secondary
+            .as_ref()
+            .map_or(palette.background.strongest.color, map_color);
+
+        move |_, status| {
+            let active = text_input::Style {
+                background: Background::Color(palette.background.base.color),
+                border,
+                icon: muted,
+                placeholder: muted,
+                value: palette.background.base.text,
+                selection: palette.primary.weak.color,
+            };
+
+            match status {
+                text_input::Status::Active => active,
+                text_input::Status::Hovered => text_input::Style {
+                    border: Border {
+                        color: palette.background.base.text,
+                        ..border
+                    },
+                    ..active
+                },
+                text_input::Status::Focused { .. } => text_input::Style {
+                    border: Border {
+                        color: palette.primary.strong.color,
+                        ..border
+                    },
+                    ..active
+                },
+                text_input::Status::Disabled => text_input::Style {
+                    background: Background::Color(palette.background.weak.color),
+                    value: muted,
+                    ..active
+                },
+            }
+        }
+    }
 
-        IcedTheme::custom(theme.name.clone(), palette)
+    /// Border of the base style of `widget` in `theme`, slightly rounded by default
+    fn base_border(theme: &Theme, widget: &str) -> Border {
+        theme
+            .base_styles
+            .get(widget)
+            .and_then(|style| style.border.as_ref())
+            .map_or(iced::border::rounded(2), |border| Border {
+                width: border.width,
+                color: map_color(&border.color),
+                radius: map_border_radius(&border.radius),
+            })
     }
 
     /// Convert a Dampen ThemePalette to an Iced Palette
@@ -0,0 +1,643 @@
+//! Trees
+//!
+//! [`Tree`] shows nested model items as rows that expand and collapse. It backs
+//! the `<tree>` widget:
+//!
+//! ```xml
+//! <tree id="files" items="{nodes}" children="children" label="{node.name}" on_expand="load_children" />
+//! ```
+//!
+//! Items are flattened into [`TreeRow`]s, by [`rows`] in generated code. A
+//! node whose children are `None` is not loaded yet: it can be expanded, and
+//! `on_expand` receives its key so the handler can load them. Nodes are keyed
+//! by their position, e.g. `0.2` for the third child of the first item, unless
+//! a `key` is given.
+//!
+//! The tree keeps the expanded nodes and its cursor itself. Once focused, by a
+//! click or with Tab, it is navigated with the keyboard:
+//! - Up and Down move the cursor, Home and End to the first and last row
+//! - Right expands the node under the cursor, or moves to its first child
+//! - Left collapses it, or moves to its parent
+//! - Enter and Space select it
+
+use std::collections::HashSet;
+
+use iced::advanced::widget::operation::Focusable;
+use iced::advanced::widget::{Id, Operation, Tree as WidgetTree, tree};
+use iced::advanced::{Clipboard, Layout, Shell, Widget, layout, mouse, renderer};
+use iced::keyboard::{self, key::Named};
+use iced::widget::{Space, button, column, row, text};
+use iced::{Alignment, Element, Event, Length, Rectangle, Size, Theme};
+
+/// Text size of a tree without a `size`
+pub const DEFAULT_SIZE: f32 = 14.0;
+
+/// Indentation of a nesting level of a tree without an `indent`
+pub const DEFAULT_INDENT: f32 = 16.0;
+
+/// Node of a tree, flattened after its parent
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeRow {
+    /// Key of the node, passed to handlers
+    pub key: String,
+    /// Text shown
+    pub label: String,
+    /// Nesting level, 0 for the items of the tree
+    pub depth: usize,
+    /// Number of children, `None` until they are loaded
+    pub children: Option<usize>,
+}
+
+/// Children of a tree item: a list, or an optional list loaded on expansion
+pub trait TreeChildren<T> {
+    /// Children of the item, `None` when not loaded yet
+    fn tree_children(&self) -> Option<&[T]>;
+}
+
+impl<T> TreeChildren<T> for Vec<T> {
+    fn tree_children(&self) -> Option<&[T]> {
+        Some(self)
+    }
+}
+
+impl<T> TreeChildren<T> for Option<Vec<T>> {
+    fn tree_children(&self) -> Option<&[T]> {
+        self.as_deref()
+    }
+}
+
+/// Flatten `items` and their children into rows
+///
+/// `key` receives an item and its position, e.g. `0.2`, and returns its key.
+pub fn rows<T, C: TreeChildren<T>>(
+    items: &[T],
+    children: impl Fn(&T) -> &C,
+    label: impl Fn(&T) -> String,
+    key: impl Fn(&T, &str) -> String,
+) -> Vec<TreeRow> {
+    fn push<T, C: TreeChildren<T>>(
+        items: &[T],
+        parent: Option<&str>,
+        depth: usize,
+        children: &impl Fn(&T) -> &C,
+        label: &impl Fn(&T) -> String,
+        key: &impl Fn(&T, &str) -> String,
+        rows: &mut Vec<TreeRow>,
+    ) {
+        for (index, item) in items.iter().enumerate() {
+            let path = match parent {
+                Some(parent) => format!("{parent}.{index}"),
+                None => index.to_string(),
+            };
+            let item_children = children(item).tree_children();
+            rows.push(TreeRow {
+                key: key(item, &path),
+                label: label(item),
+                depth,
+                children: item_children.map(<[T]>::len),
+            });
+            if let Some(item_children) = item_children {
+                push(
+                    item_children,
+                    Some(&path),
+                    depth + 1,
+                    children,
+                    label,
+                    key,
+                    rows,
+                );
+            }
+        }
+    }
+
+    let mut rows = Vec::new();
+    push(items, None, 0, &children, &label, &key, &mut rows);
+    rows
+}
+
+/// Callback receiving the key of a node and whether it is now expanded
+type OnToggle<'a> = Box<dyn Fn(&str, bool) + 'a>;
+
+/// Expandable rows of nested items, navigable with the keyboard
+pub struct Tree<'a, Message> {
+    rows: Vec<TreeRow>,
+    expanded: HashSet<String>,
+    id: Option<Id>,
+    indent: f32,
+    size: f32,
+    on_expand: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    on_select: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    on_toggle: Option<OnToggle<'a>>,
+}
+
+/// Expanded nodes, and the node under the cursor
+#[derive(Debug, Default)]
+struct State {
+    expanded: HashSet<String>,
+    cursor: Option<String>,
+    focused: bool,
+}
+
+impl Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn focus(&mut self) {
+        self.focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.focused = false;
+    }
+}
+
+/// Click on a row, or on the expander of a row
+#[derive(Debug, Clone)]
+enum Action {
+    Toggle(String),
+    Select(String),
+}
+
+impl<'a, Message> Tree<'a, Message> {
+    /// Create a tree showing `rows`, all collapsed
+    pub fn new(rows: Vec<TreeRow>) -> Self {
+        Self {
+            rows,
+            expanded: HashSet::new(),
+            id: None,
+            indent: DEFAULT_INDENT,
+            size: DEFAULT_SIZE,
+            on_expand: None,
+            on_select: None,
+            on_toggle: None,
+        }
+    }
+
+    /// Set the id, used to focus the tree
+    #[must_use]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Expand the nodes with these keys when the tree is first shown
+    #[must_use]
+    pub fn expanded(mut self, keys: impl IntoIterator<Item = String>) -> Self {
+        self.expanded = keys.into_iter().collect();
+        self
+    }
+
+    /// Set the indentation of each nesting level
+    #[must_use]
+    pub fn indent(mut self, indent: f32) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Set the text size
+    #[must_use]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Emit a message with the key of each expanded node
+    #[must_use]
+    pub fn on_expand(mut self, on_expand: impl Fn(String) -> Message + 'a) -> Self {
+        self.on_expand = Some(Box::new(on_expand));
+        self
+    }
+
+    /// Emit a message with the key of a node clicked, or selected with the keyboard
+    #[must_use]
+    pub fn on_select(mut self, on_select: impl Fn(String) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Call `on_toggle` with the key of each node expanded or collapsed
+    ///
+    /// The builder records the expanded nodes with it, to restore them after a
+    /// hot-reload.
+    #[must_use]
+    pub fn on_toggle(mut self, on_toggle: impl Fn(&str, bool) + 'a) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Indices of the rows whose ancestors are all expanded
+    fn visible(&self, state: &State) -> Vec<usize> {
+        let mut visible = Vec::new();
+        let mut collapsed: Option<usize> = None;
+        for (index, row) in self.rows.iter().enumerate() {
+            if collapsed.is_some_and(|depth| row.depth > depth) {
+                continue;
+            }
+            collapsed = (!state.expanded.contains(&row.key)).then_some(row.depth);
+            visible.push(index);
+        }
+        visible
+    }
+
+    /// Rows shown for `state`
+    fn content(&self, state: &State) -> Element<'static, Action> {
+        let size = self.size;
+        let mut lines: Vec<Element<'static, Action>> = Vec::new();
+
+        for index in self.visible(state) {
+            let node = &self.rows[index];
+            let expanded = state.expanded.contains(&node.key);
+            let indent = Space::new().width(node.depth as f32 * self.indent);
+
+            let expander: Element<'static, Action> = if node.children == Some(0) {
+                Space::new().width(size).into()
+            } else {
+                button(text(if expanded { "▼" } else { "▶" }).size(size * 0.75))
+                    .padding(0)
+                    .width(size)
+                    .style(button::text)
+                    .on_press(Action::Toggle(node.key.clone()))
+                    .into()
+            };
+
+            let is_cursor = state.cursor.as_ref() == Some(&node.key);
+            let focused = state.focused;
+            lines.push(
+                button(
+                    row![indent, expander, text(node.label.clone()).size(size)]
+                        .spacing(size * 0.25)
+                        .align_y(Alignment::Center),
+                )
+                .padding([2, 4])
+                .width(Length::Fill)
+                .style(move |theme: &Theme, status| row_style(theme, status, is_cursor, focused))
+                .on_press(Action::Select(node.key.clone()))
+                .into(),
+            );
+
+            // Children being loaded
+            if expanded && node.children.is_none() {
+                let indent = Space::new().width((node.depth + 1) as f32 * self.indent + size);
+                lines.push(
+                    row![
+                        indent,
+                        text("…").size(size).style(|theme: &Theme| text::Style {
+                            color: Some(theme.palette().text.scale_alpha(0.5)),
+                        })
+                    ]
+                    .padding([2, 4])
+                    .spacing(size * 0.25)
+                    .into(),
+                );
+            }
+        }
+
+        column(lines).width(Length::Fill).into()
+    }
+
+    /// Expand, collapse or select a node
+    fn perform(&self, state: &mut State, action: Action, shell: &mut Shell<'_, Message>) {
+        match action {
+            Action::Toggle(key) => {
+                let expanded = !state.expanded.remove(&key);
+                if expanded {
+                    state.expanded.insert(key.clone());
+                }
+                if let Some(on_toggle) = &self.on_toggle {
+                    on_toggle(&key, expanded);
+                }
+                if expanded && let Some(on_expand) = &self.on_expand {
+                    shell.publish(on_expand(key.clone()));
+                }
+                state.cursor = Some(key);
+            }
+            Action::Select(key) => {
+                if let Some(on_select) = &self.on_select {
+                    shell.publish(on_select(key.clone()));
+                }
+                state.cursor = Some(key);
+            }
+        }
+        shell.invalidate_layout();
+        shell.request_redraw();
+    }
+
+    /// Move the cursor, or act on its node, for a key press
+    ///
+    /// Returns `false` for keys the tree doesn't handle.
+    fn navigate(&self, state: &mut State, key: Named, shell: &mut Shell<'_, Message>) -> bool {
+        let visible = self.visible(state);
+        let Some(last) = visible.len().checked_sub(1) else {
+            return false;
+        };
+        let position = state
+            .cursor
+            .as_ref()
+            .and_then(|cursor| visible.iter().position(|&i| &self.rows[i].key == cursor));
+        let node = |position: usize| &self.rows[visible[position]];
+
+        let target = match (key, position) {
+            (Named::ArrowDown, None) | (Named::ArrowUp, None) | (Named::Home, _) => 0,
+            (Named::End, _) => last,
+            (Named::ArrowDown, Some(position)) => (position + 1).min(last),
+            (Named::ArrowUp, Some(position)) => position.saturating_sub(1),
+            (Named::ArrowRight, Some(position)) => {
+                let current = node(position);
+                if current.children != Some(0) && !state.expanded.contains(&current.key) {
+                    self.perform(state, Action::Toggle(current.key.clone()), shell);
+                    return true;
+                }
+                match visible.get(position + 1) {
+                    Some(&next) if self.rows[next].depth > current.depth => position + 1,
+                    _ => position,
+                }
+            }
+            (Named::ArrowLeft, Some(position)) => {
+                let current = node(position);
+                if state.expanded.contains(&current.key) {
+                    self.perform(state, Action::Toggle(current.key.clone()), shell);
+                    return true;
+                }
+                (0..position)
+                    .rev()
+                    .find(|&parent| node(parent).depth < current.depth)
+                    .unwrap_or(position)
+            }
+            (Named::Enter | Named::Space, Some(position)) => {
+                self.perform(state, Action::Select(node(position).key.clone()), shell);
+                return true;
+            }
+            _ => return false,
+        };
+
+        state.cursor = Some(node(target).key.clone());
+        shell.invalidate_layout();
+        shell.request_redraw();
+        true
+    }
+}
+
+/// Rows are transparent, except the one under the cursor and hovered ones
+fn row_style(
+    theme: &Theme,
+    status: button::Status,
+    is_cursor: bool,
+    focused: bool,
+) -> button::Style {
+    let palette = theme.extended_palette();
+    let background = if is_cursor && focused {
+        Some(palette.primary.weak)
+    } else if is_cursor || matches!(status, button::Status::Hovered | button::Status::Pressed) {
+        Some(palette.background.weak)
+    } else {
+        None
+    };
+
+    button::Style {
+        background: background.map(|pair| pair.color.into()),
+        text_color: background.map_or(palette.background.base.text, |pair| pair.text),
+        ..button::Style::default()
+    }
+}
+
+impl<Message> Widget<Message, Theme, iced::Renderer> for Tree<'_, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            expanded: self.expanded.clone(),
+            ..State::default()
+        })
+    }
+
+    fn children(&self) -> Vec<WidgetTree> {
+        let state = State {
+            expanded: self.expanded.clone(),
+            ..State::default()
+        };
+        vec![WidgetTree::new(self.content(&state))]
+    }
+
+    fn diff(&self, tree: &mut WidgetTree) {
+        let content = self.content(tree.state.downcast_ref::<State>());
+        tree.children[0].diff(&content);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Shrink)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut WidgetTree,
+        renderer: &iced::Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        // Rows appear and disappear as nodes are expanded and collapsed
+        let mut content = self.content(tree.state.downcast_ref::<State>());
+        tree.children[0].diff(&content);
+        content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &WidgetTree,
+        renderer: &mut iced::Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content(tree.state.downcast_ref::<State>())
+            .as_widget()
+            .draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor,
+                viewport,
+            );
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut WidgetTree,
+        layout: Layout<'_>,
+        _renderer: &iced::Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        operation.focusable(self.id.as_ref(), layout.bounds(), state);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut WidgetTree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &iced::Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        {
+            let state = tree.state.downcast_mut::<State>();
+            if let Event::Mouse(mouse::Event::ButtonPressed(_)) = event {
+                let focused = cursor.is_over(layout.bounds());
+                if focused != state.focused {
+                    state.focused = focused;
+                    shell.request_redraw();
+                }
+            }
+            if state.focused
+                && let Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Named(key),
+                    ..
+                }) = event
+                && self.navigate(state, *key, shell)
+            {
+                shell.capture_event();
+                return;
+            }
+        }
+
+        // Rows emit actions, performed here on the state of the tree
+        let mut actions = Vec::new();
+        {
+            let mut content = self.content(tree.state.downcast_ref::<State>());
+            let mut content_shell = Shell::new(&mut actions);
+            content.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                &mut content_shell,
+                viewport,
+            );
+
+            if content_shell.is_event_captured() {
+                shell.capture_event();
+            }
+            shell.request_redraw_at(content_shell.redraw_request());
+            if content_shell.is_layout_invalid() {
+                shell.invalidate_layout();
+            }
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        for action in actions {
+            self.perform(state, action, shell);
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &WidgetTree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &iced::Renderer,
+    ) -> mouse::Interaction {
+        self.content(tree.state.downcast_ref::<State>())
+            .as_widget()
+            .mouse_interaction(&tree.children[0], layout, cursor, viewport, renderer)
+    }
+}
+
+impl<'a, Message: 'a> From<Tree<'a, Message>> for Element<'a, Message> {
+    fn from(tree: Tree<'a, Message>) -> Self {
+        Element::new(tree)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Node {
+        name: &'static str,
+        children: Option<Vec<Node>>,
+    }
+
+    fn node(name: &'static str, children: Option<Vec<Node>>) -> Node {
+        Node { name, children }
+    }
+
+    fn files() -> Vec<TreeRow> {
+        let nodes = vec![
+            node(
+                "src",
+                Some(vec![node("main.rs", Some(vec![])), node("ui", None)]),
+            ),
+            node("README.md", Some(vec![])),
+        ];
+        rows(
+            &nodes,
+            |node| &node.children,
+            |node| node.name.to_string(),
+            |_, path| path.to_string(),
+        )
+    }
+
+    #[test]
+    fn test_rows_flatten_loaded_children() {
+        let rows = files();
+        let keys: Vec<_> = rows.iter().map(|row| row.key.as_str()).collect();
+        assert_eq!(keys, ["0", "0.0", "0.1", "1"]);
+        assert_eq!(rows[1].depth, 1);
+        assert_eq!(rows[0].children, Some(2));
+        assert_eq!(rows[2].children, None);
+        assert_eq!(rows[3].children, Some(0));
+    }
+
+    #[test]
+    fn test_only_children_of_expanded_nodes_are_visible() {
+        let tree = Tree::<()>::new(files());
+        let mut state = State::default();
+        assert_eq!(tree.visible(&state), [0, 3]);
+
+        state.expanded.insert("0".to_string());
+        assert_eq!(tree.visible(&state), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_keyboard_navigation() {
+        let tree = Tree::new(files()).on_expand(|key| key);
+        let mut state = State::default();
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        assert!(tree.navigate(&mut state, Named::ArrowDown, &mut shell));
+        assert_eq!(state.cursor.as_deref(), Some("0"));
+
+        // Right expands the node, then moves to its first child
+        tree.navigate(&mut state, Named::ArrowRight, &mut shell);
+        assert!(state.expanded.contains("0"));
+        tree.navigate(&mut state, Named::ArrowRight, &mut shell);
+        assert_eq!(state.cursor.as_deref(), Some("0.0"));
+
+        // Left moves to the parent, then collapses it
+        tree.navigate(&mut state, Named::ArrowLeft, &mut shell);
+        assert_eq!(state.cursor.as_deref(), Some("0"));
+        tree.navigate(&mut state, Named::ArrowLeft, &mut shell);
+        assert!(!state.expanded.contains("0"));
+
+        tree.navigate(&mut state, Named::End, &mut shell);
+        assert_eq!(state.cursor.as_deref(), Some("1"));
+        assert!(!tree.navigate(&mut state, Named::Tab, &mut shell));
+
+        drop(shell);
+        assert_eq!(messages, ["0"]);
+    }
+}
@@ -0,0 +1,55 @@
+//! Window size tracking
+//!
+//! [`changes`] follows the size of the window and stores it in
+//! [`dampen_core::viewport`], where the widget builder reads it to resolve the
+//! breakpoint-prefixed attributes of widgets (`mobile-spacing="10"`).
+//!
+//! `#[dampen_app]` subscribes to [`changes`] when a view uses breakpoint
+//! attributes, or to [`resizes`] when it binds the window size with
+//! `{env.viewport_width}`, and renders the view again when it emits.
+
+use dampen_core::viewport::{self, ViewportSize};
+use iced::{Event, Subscription, window};
+
+/// Store the size of the window as it changes, emitting the new size when it
+/// crosses the threshold of a breakpoint
+///
+/// Resizing within a breakpoint only stores the size: the view does not have
+/// to be rebuilt. The thresholds are those of the fixed breakpoints and of the
+/// `<breakpoints>` of the documents built so far.
+///
+/// # Example
+///
+/// ```ignore
+/// fn subscription(&self) -> iced::Subscription<Message> {
+///     dampen_iced::viewport::changes().map(|_| Message::BreakpointChanged)
+/// }
+/// ```
+pub fn changes() -> Subscription<ViewportSize> {
+    iced::event::listen_with(|event, _status, _id| match event {
+        Event::Window(window::Event::Opened { size, .. } | window::Event::Resized(size)) => {
+            viewport::set_size(size.width, size.height).then_some(ViewportSize {
+                width: size.width,
+                height: size.height,
+            })
+        }
+        _ => None,
+    })
+}
+
+/// Store the size of the window as it changes, emitting every new size
+///
+/// Used when the view binds the size itself (`{env.viewport_width}`, see
+/// [`dampen_core::env`]).
+pub fn resizes() -> Subscription<ViewportSize> {
+    iced::event::listen_with(|event, _status, _id| match event {
+        Event::Window(window::Event::Opened { size, .. } | window::Event::Resized(size)) => {
+            viewport::set_size(size.width, size.height);
+            Some(ViewportSize {
+                width: size.width,
+                height: size.height,
+            })
+        }
+        _ => None,
+    })
+}
@@ -0,0 +1,61 @@
+//! Hot-reload restoration of ephemeral widget state
+//!
+//! The builder records scroll offsets, focus and text input cursors into the view's
+//! [`WidgetStateTracker`](dampen_core::state::WidgetStateTracker) while the user
+//! interacts with the UI. After a hot-reload rebuilds the widget tree, the tasks
+//! produced here re-apply that state to the widgets with matching ids.
+
+use dampen_core::state::WidgetStateSnapshot;
+use iced::Task;
+use iced::widget::operation::{self, AbsoluteOffset};
+
+/// Create a task that restores the given widget state.
+///
+/// Scrollables are scrolled back to their recorded offset, text inputs get their
+/// recorded cursor or selection back, and the previously focused widget is
+/// focused again. Ids missing from the current widget tree are ignored by Iced.
+/// Expanded tree nodes need no task: the builder passes them to the trees it
+/// rebuilds.
+///
+/// # Example
+///
+/// ```ignore
+/// // In update(), after applying a reloaded document:
+/// self.state.hot_reload(new_document);
+/// return dampen_iced::widget_state::restore_widget_state(&self.state.widget_state.snapshot());
+/// ```
+pub fn restore_widget_state<T>(snapshot: &WidgetStateSnapshot) -> Task<T>
+where
+    T: 'static,
+{
+    if snapshot.is_empty() {
+        return Task::none();
+    }
+
+    let mut tasks = Vec::new();
+
+    for (id, offset) in &snapshot.scroll_offsets {
+        tasks.push(operation::scroll_to(
+            id.clone(),
+            AbsoluteOffset {
+                x: offset.x,
+                y: offset.y,
+            },
+        ));
+    }
+
+    // Focus before moving the cursor, since focusing moves it to the end
+    if let Some(id) = &snapshot.focused {
+        tasks.push(operation::focus(id.clone()));
+    }
+
+    for (id, cursor) in &snapshot.text_cursors {
+        let task = match cursor.selection {
+            Some((start, end)) => operation::select_range(id.clone(), start, end),
+            None => operation::move_cursor_to(id.clone(), cursor.position),
+        };
+        tasks.push(task);
+    }
+
+    Task::batch(tasks)
+}
@@ -205,3 +205,52 @@ fn test_button_enabled_with_verbose() {
     let builder = DampenWidgetBuilder::new(&doc, &model, Some(&registry));
     let _element: Element<'_, HandlerMessage, Theme, Renderer> = builder.build();
 }
+
+#[test]
+fn test_memoized_subtree_is_reused_until_its_fields_change() {
+    use dampen_iced::memo::{MemoCache, MemoStats};
+
+    let xml = r#"<column>
+        <column memo="true">
+            <text value="{message}" />
+            <text value="Static" />
+        </column>
+        <text value="{count}" />
+    </column>"#;
+    let doc = parse(xml).unwrap();
+    let registry = create_registry();
+    let memo = MemoCache::new();
+
+    let build = |model: &TestModel| {
+        let _element: Element<'_, HandlerMessage, Theme, Renderer> =
+            DampenWidgetBuilder::new(&doc, model, Some(&registry))
+                .with_memo_cache(&memo)
+                .build();
+    };
+
+    let mut model = create_model();
+    build(&model);
+    // `count` is read outside the memoized subtree
+    model.count += 1;
+    build(&model);
+    assert_eq!(
+        memo.stats(),
+        MemoStats {
+            hits: 1,
+            misses: 1,
+            entries: 1,
+        }
+    );
+
+    model.message = "Changed".to_string();
+    build(&model);
+    build(&model);
+    assert_eq!(
+        memo.stats(),
+        MemoStats {
+            hits: 2,
+            misses: 2,
+            entries: 1,
+        }
+    );
+}
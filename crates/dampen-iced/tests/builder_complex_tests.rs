@@ -222,3 +222,42 @@ fn test_mixed_widget_types() {
     let builder = DampenWidgetBuilder::new(&doc, &model, Some(&registry));
     let _element: Element<'_, HandlerMessage, Theme, Renderer> = builder.build();
 }
+
+// Theme overrides apply to their subtree; unknown themes are reported
+#[test]
+fn test_theme_overrides() {
+    let xml = r#"
+        <column>
+            <container theme="dark">
+                <button label="Dark" />
+            </container>
+            <container theme="{user.name}">
+                <text value="Unknown theme" />
+            </container>
+        </column>
+    "#;
+    let doc = parse(xml).unwrap();
+    let theme_document = dampen_core::parser::theme_parser::parse_theme_document(
+        r##"<dampen><themes>
+            <theme name="light"><palette primary="#3498db" secondary="#2ecc71" success="#27ae60" warning="#f39c12" danger="#e74c3c" background="#ecf0f1" surface="#ffffff" text="#2c3e50" text_secondary="#7f8c8d" /></theme>
+            <theme name="dark" extends="light"><palette background="#1a1a2e" text="#eaeaea" /></theme>
+        </themes></dampen>"##,
+    )
+    .unwrap();
+    let theme_context = dampen_core::ThemeContext::from_document(theme_document, None).unwrap();
+    let model = create_complex_model();
+    let registry = create_registry();
+    let diagnostics = dampen_iced::diagnostics::BuildDiagnostics::new();
+
+    let builder = DampenWidgetBuilder::new(&doc, &model, Some(&registry))
+        .with_theme_context(&theme_context)
+        .with_diagnostics(&diagnostics);
+    let _element: Element<'_, HandlerMessage, Theme, Renderer> = builder.build();
+
+    let messages: Vec<String> = diagnostics
+        .diagnostics()
+        .into_iter()
+        .map(|diagnostic| diagnostic.message)
+        .collect();
+    assert_eq!(messages, vec!["Unknown theme 'Alice'".to_string()]);
+}
@@ -0,0 +1,17 @@
+use dampen_iced::layers::{Layer, Layers};
+use iced::Element;
+use iced::widget::text;
+
+#[test]
+fn test_overlays_are_ordered_by_layer() {
+    let layers: Layers<'_, ()> = Layers::new(text("view"))
+        .push(Layer::DevOverlay, text("error"))
+        .push(Layer::Toast, text("saved"))
+        .push_maybe(Layer::Dropdown, None::<Element<'_, ()>>)
+        .push(Layer::Modal, text("palette"));
+
+    assert_eq!(
+        layers.order(),
+        vec![Layer::Modal, Layer::Toast, Layer::DevOverlay]
+    );
+}
@@ -0,0 +1,41 @@
+use dampen_core::selection::{SelectionAction, SelectionMove};
+use dampen_iced::HandlerMessage;
+use dampen_iced::selection::{click_action, handler_message, key_action};
+use iced::keyboard::{Key, Modifiers, key::Named};
+
+#[test]
+fn test_arrow_keys_move_and_extend() {
+    assert_eq!(
+        key_action(&Key::Named(Named::ArrowDown), Modifiers::empty()),
+        Some(SelectionAction::Move {
+            to: SelectionMove::Next,
+            extend: false
+        })
+    );
+    assert_eq!(
+        key_action(&Key::Named(Named::Home), Modifiers::SHIFT),
+        Some(SelectionAction::Move {
+            to: SelectionMove::First,
+            extend: true
+        })
+    );
+    assert_eq!(
+        key_action(&Key::Named(Named::Space), Modifiers::empty()),
+        Some(SelectionAction::ToggleFocused)
+    );
+    assert_eq!(
+        key_action(&Key::Character("a".into()), Modifiers::empty()),
+        None
+    );
+}
+
+#[test]
+fn test_click_modifiers_and_handler_messages() {
+    assert_eq!(click_action(Modifiers::empty()), SelectionAction::Select);
+    assert_eq!(click_action(Modifiers::SHIFT), SelectionAction::Extend);
+
+    assert_eq!(
+        handler_message("selection", SelectionAction::Clear),
+        HandlerMessage::Handler("selection.clear".to_string(), None)
+    );
+}
@@ -0,0 +1,86 @@
+//! Tests for converting Dampen themes to custom Iced themes
+
+use dampen_core::ir::theme::{
+    FontWeight, ShadowScale, SpacingScale, Theme, ThemePalette, Typography,
+};
+use dampen_iced::style_mapping::map_color;
+use dampen_iced::theme_adapter::ThemeAdapter;
+use iced::widget::{button, text_input};
+use iced::{Background, Color};
+use std::collections::HashMap;
+
+fn test_theme() -> Theme {
+    Theme {
+        name: "brand".to_string(),
+        palette: ThemePalette::light(),
+        typography: Typography {
+            font_family: None,
+            font_size_base: Some(16.0),
+            font_size_small: None,
+            font_size_large: None,
+            font_weight: FontWeight::Normal,
+            line_height: None,
+            scale_ratio: None,
+            fonts: Vec::new(),
+        },
+        spacing: SpacingScale { unit: Some(4.0) },
+        shadows: ShadowScale::default(),
+        base_styles: HashMap::new(),
+        extends: None,
+    }
+}
+
+fn palette_color(color: Option<dampen_core::ir::style::Color>) -> Color {
+    map_color(&color.expect("light palette sets every color"))
+}
+
+#[test]
+fn test_custom_theme_uses_the_extended_palette_of_the_dampen_theme() {
+    let theme = test_theme();
+    let iced_theme = ThemeAdapter::to_iced(&theme);
+    let extended = iced_theme.extended_palette();
+
+    assert_eq!(iced_theme.to_string(), "brand");
+    assert_eq!(
+        extended.primary.base.color,
+        palette_color(theme.palette.primary)
+    );
+    assert_eq!(
+        extended.secondary.base.color,
+        palette_color(theme.palette.secondary)
+    );
+    assert_eq!(
+        extended.background.weak.color,
+        palette_color(theme.palette.surface)
+    );
+    assert!(!extended.is_dark);
+}
+
+#[test]
+fn test_widget_styles_follow_theme_tokens() {
+    let theme = test_theme();
+    let iced_theme = ThemeAdapter::to_iced(&theme);
+    let primary = palette_color(theme.palette.primary);
+
+    let button = ThemeAdapter::button_style(&theme)(&iced_theme, button::Status::Active);
+    assert_eq!(button.background, Some(Background::Color(primary)));
+
+    let container = ThemeAdapter::container_style(&theme)(&iced_theme);
+    assert_eq!(
+        container.background,
+        Some(Background::Color(palette_color(theme.palette.surface)))
+    );
+
+    let input = ThemeAdapter::text_input_style(&theme)(
+        &iced_theme,
+        text_input::Status::Focused { is_hovered: false },
+    );
+    assert_eq!(
+        input.placeholder,
+        palette_color(theme.palette.text_secondary)
+    );
+    assert_eq!(
+        input.border.color,
+        iced_theme.extended_palette().primary.strong.color
+    );
+}
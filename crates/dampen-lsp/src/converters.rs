@@ -62,25 +62,14 @@ pub fn offset_to_position(content: &str, offset: usize) -> Option<Position> {
         return None;
     }
 
-    let mut line = 0u32;
-    let mut character = 0u32;
-    let mut current_offset = 0usize;
+    // An offset inside a multi-byte character points after it
+    let end = (offset..=content.len()).find(|&i| content.is_char_boundary(i))?;
+    let before = &content[..end];
 
-    for ch in content.chars() {
-        if current_offset >= offset {
-            break;
-        }
-
-        if ch == '\n' {
-            line += 1;
-            character = 0;
-        } else {
-            // LSP uses UTF-16 code units
-            character += ch.encode_utf16(&mut [0; 2]).len() as u32;
-        }
-
-        current_offset += ch.len_utf8();
-    }
+    let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+    let line = before.matches('\n').count() as u32;
+    // LSP uses UTF-16 code units
+    let character = before[line_start..].encode_utf16().count() as u32;
 
     Some(Position::new(line, character))
 }
@@ -154,6 +143,7 @@ pub fn parse_error_to_diagnostic(content: &str, error: ParseError) -> Diagnostic
         ParseErrorKind::InvalidDateFormat => DiagnosticSeverity::ERROR,
         ParseErrorKind::InvalidTimeFormat => DiagnosticSeverity::ERROR,
         ParseErrorKind::InvalidDateRange => DiagnosticSeverity::ERROR,
+        ParseErrorKind::LimitExceeded => DiagnosticSeverity::ERROR,
     });
 
     let code = Some(NumberOrString::String(format!("E{:03}", error.kind as u8)));
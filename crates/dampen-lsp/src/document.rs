@@ -8,7 +8,7 @@ use std::num::NonZeroUsize;
 
 use dampen_core::ir::DampenDocument;
 use dampen_core::parser::error::ParseError;
-use dampen_core::parser::parse;
+use dampen_core::parser::{parse_with_recovery, reparse_with_recovery};
 use lru::LruCache;
 use tower_lsp::lsp_types::Url;
 use tracing::{debug, trace};
@@ -24,16 +24,16 @@ pub struct DocumentState {
     pub content: String,
     /// Document version (incremented on each change)
     pub version: i32,
-    /// Parsed AST (None if parse failed)
+    /// Parsed AST (partial if the document has errors, None if the XML is malformed)
     pub ast: Option<DampenDocument>,
-    /// Parse errors (empty if parse succeeded)
+    /// All parse errors (empty if parse succeeded)
     pub parse_errors: Vec<ParseError>,
 }
 
 impl DocumentState {
     /// Creates a new document state.
     ///
-    /// Parses the content immediately and stores the result.
+    /// Parses the content immediately with error recovery and stores the result.
     ///
     /// # Arguments
     ///
@@ -43,11 +43,8 @@ impl DocumentState {
     pub fn new(uri: Url, content: String, version: i32) -> Self {
         trace!("Creating DocumentState for {} (version {})", uri, version);
 
-        // Parse the document
-        let (ast, parse_errors) = match parse(&content) {
-            Ok(doc) => (Some(doc), vec![]),
-            Err(error) => (None, vec![error]),
-        };
+        // Parse the document, collecting every error
+        let (ast, parse_errors) = parse_with_recovery(&content);
 
         Self {
             uri,
@@ -57,6 +54,39 @@ impl DocumentState {
             parse_errors,
         }
     }
+
+    /// Creates the state of the document after an edit.
+    ///
+    /// If this document parsed without errors, only the widget enclosing the
+    /// changed text is parsed again and patched into the current AST, keeping
+    /// diagnostics fast in large files. Otherwise the content is parsed from
+    /// scratch, as in [`DocumentState::new`].
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - Document content after the edit
+    /// * `version` - Document version after the edit
+    pub fn edited(&self, content: String, version: i32) -> Self {
+        let incremental = match &self.ast {
+            Some(ast) if self.parse_errors.is_empty() => {
+                reparse_with_recovery(ast, &self.content, &content)
+            }
+            _ => None,
+        };
+
+        let Some((ast, parse_errors)) = incremental else {
+            return Self::new(self.uri.clone(), content, version);
+        };
+        trace!("Reparsed {} incrementally (version {})", self.uri, version);
+
+        Self {
+            uri: self.uri.clone(),
+            content,
+            version,
+            ast: Some(ast),
+            parse_errors,
+        }
+    }
 }
 
 /// LRU cache for open documents.
@@ -219,4 +249,26 @@ mod tests {
         assert!(cache.get(&uri3).is_some());
         assert_eq!(cache.len(), 2);
     }
+
+    #[test]
+    fn test_edited_matches_full_parse() {
+        let content =
+            "<column>\n    <text value=\"Hello\" />\n    <button label=\"Go\" />\n</column>";
+        let state = DocumentState::new(test_uri(), content.to_string(), 1);
+
+        // Valid edit, then an edit introducing an error
+        let edited = state.edited(content.replace("Hello", "Hello, world"), 2);
+        let expected = DocumentState::new(test_uri(), edited.content.clone(), 2);
+        assert_eq!(edited.version, 2);
+        assert_eq!(edited.ast, expected.ast);
+        assert!(edited.parse_errors.is_empty());
+
+        let broken = edited.edited(edited.content.replace("<button", "<buton"), 3);
+        assert_eq!(broken.parse_errors.len(), 1);
+
+        // Fixing it parses from scratch
+        let fixed = broken.edited(edited.content.clone(), 4);
+        assert_eq!(fixed.ast, expected.ast);
+        assert!(fixed.parse_errors.is_empty());
+    }
 }
@@ -0,0 +1,204 @@
+//! Document color handlers.
+//!
+//! Reports the color values of a document so the editor shows a swatch next to
+//! them, and formats the colors picked in the editor's color picker.
+//!
+//! Colors are the values of `background`, `color` and `border_color` (also
+//! prefixed by a state, as in `hover:background`) and of the attributes of a
+//! theme `<palette>`. Values are read by the style parser, so bindings, theme
+//! tokens and gradients get no swatch.
+
+use dampen_core::ir::style::Color as DampenColor;
+use tower_lsp::lsp_types::{Color, ColorInformation, ColorPresentation, Range, TextEdit};
+
+use crate::converters::position_to_offset;
+use crate::document::DocumentState;
+use crate::markup::{LineIndex, Tag, tags};
+
+/// Attributes holding a color
+const COLOR_ATTRIBUTES: &[&str] = &["background", "color", "border_color"];
+
+/// Handles document color requests.
+///
+/// Returns the color values of the document, in source order.
+pub fn document_colors(doc: &DocumentState) -> Vec<ColorInformation> {
+    let content = &doc.content;
+    let lines = LineIndex::new(content);
+    let mut colors = Vec::new();
+
+    for tag in tags(content) {
+        let Tag::Start {
+            name, attributes, ..
+        } = tag
+        else {
+            continue;
+        };
+
+        for attribute in attributes {
+            let property = attribute
+                .name
+                .rsplit_once(':')
+                .map_or(attribute.name, |(_, property)| property);
+            if name != "palette" && !COLOR_ATTRIBUTES.contains(&property) {
+                continue;
+            }
+
+            let value = attribute.value.trim();
+            if value.is_empty() || value.contains('{') {
+                continue;
+            }
+            let Ok(color) = DampenColor::parse(value) else {
+                continue;
+            };
+
+            let leading = attribute.value.len() - attribute.value.trim_start().len();
+            let start = attribute.value_start + leading;
+            colors.push(ColorInformation {
+                range: Range::new(
+                    lines.position(content, start),
+                    lines.position(content, start + value.len()),
+                ),
+                color: Color {
+                    red: color.r,
+                    green: color.g,
+                    blue: color.b,
+                    alpha: color.a,
+                },
+            });
+        }
+    }
+
+    colors
+}
+
+/// Handles color presentation requests.
+///
+/// Returns the notations of `color` accepted by the style parser: hex, with an
+/// alpha channel if translucent, and `rgb()`/`rgba()`. The notation of the
+/// value being replaced comes first, so picking a color keeps it.
+pub fn color_presentations(
+    doc: &DocumentState,
+    color: Color,
+    range: Range,
+) -> Vec<ColorPresentation> {
+    let color = DampenColor {
+        r: color.red,
+        g: color.green,
+        b: color.blue,
+        a: color.alpha,
+    };
+    let [r, g, b, a] = color.to_rgba8();
+
+    let (hex, rgb) = if a == u8::MAX {
+        (color.to_hex(), format!("rgb({}, {}, {})", r, g, b))
+    } else {
+        (
+            color.to_rgba_hex(),
+            format!("rgba({}, {}, {}, {})", r, g, b, format_alpha(color.a)),
+        )
+    };
+
+    let current = position_to_offset(&doc.content, range.start)
+        .zip(position_to_offset(&doc.content, range.end))
+        .and_then(|(start, end)| doc.content.get(start..end))
+        .unwrap_or_default();
+    let labels = if current.trim_start().starts_with("rgb") {
+        [rgb, hex]
+    } else {
+        [hex, rgb]
+    };
+
+    labels
+        .into_iter()
+        .map(|label| ColorPresentation {
+            text_edit: Some(TextEdit::new(range, label.clone())),
+            label,
+            additional_text_edits: None,
+        })
+        .collect()
+}
+
+/// Alpha channel with at most two decimals, as written in `rgba()`
+fn format_alpha(alpha: f32) -> String {
+    let alpha = (alpha.clamp(0.0, 1.0) * 100.0).round() / 100.0;
+    format!("{}", alpha)
+}
+
+#[cfg(test)]
+mod tests {
+    use tower_lsp::lsp_types::{Position, Url};
+
+    use super::*;
+
+    fn document(content: &str) -> DocumentState {
+        let uri = Url::parse("file:///test.dampen").unwrap();
+        DocumentState::new(uri, content.to_string(), 1)
+    }
+
+    #[test]
+    fn test_document_colors() {
+        let doc = document(
+            r##"<dampen>
+    <themes>
+        <theme name="brand"><palette primary="#3498db" text="rgba(0, 0, 0, 0.5)" /></theme>
+    </themes>
+    <column background="linear-gradient(90deg, #fff, #000)" color="{accent}">
+        <button label="Go" background=" red " hover:border_color="#ff000080" padding="8" />
+    </column>
+</dampen>"##,
+        );
+
+        let colors = document_colors(&doc);
+        let found: Vec<(Position, [u8; 4])> = colors
+            .iter()
+            .map(|info| {
+                let color = DampenColor {
+                    r: info.color.red,
+                    g: info.color.green,
+                    b: info.color.blue,
+                    a: info.color.alpha,
+                };
+                (info.range.start, color.to_rgba8())
+            })
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (Position::new(2, 46), [52, 152, 219, 255]),
+                (Position::new(2, 61), [0, 0, 0, 128]),
+                (Position::new(5, 40), [255, 0, 0, 255]),
+                (Position::new(5, 66), [255, 0, 0, 128]),
+            ]
+        );
+        assert_eq!(colors[2].range.end, Position::new(5, 43));
+    }
+
+    #[test]
+    fn test_color_presentations_keep_notation() {
+        let doc = document(r##"<text color="rgb(0, 0, 255)" background="#00f" />"##);
+        let picked = Color {
+            red: 52.0 / 255.0,
+            green: 152.0 / 255.0,
+            blue: 219.0 / 255.0,
+            alpha: 1.0,
+        };
+
+        let rgb = Range::new(Position::new(0, 13), Position::new(0, 27));
+        let labels: Vec<String> = color_presentations(&doc, picked, rgb)
+            .into_iter()
+            .map(|p| p.label)
+            .collect();
+        assert_eq!(labels, vec!["rgb(52, 152, 219)", "#3498db"]);
+
+        let hex = Range::new(Position::new(0, 41), Position::new(0, 45));
+        let translucent = Color {
+            alpha: 0.8,
+            ..picked
+        };
+        let labels: Vec<String> = color_presentations(&doc, translucent, hex)
+            .into_iter()
+            .map(|p| p.label)
+            .collect();
+        assert_eq!(labels, vec!["#3498dbcc", "rgba(52, 152, 219, 0.8)"]);
+    }
+}
@@ -3,6 +3,7 @@
 //! Provides context-aware autocompletion for widgets, attributes, and values.
 
 use dampen_core::ir::WidgetKind;
+use dampen_core::ir::layout::Breakpoints;
 use dampen_core::schema::get_widget_schema;
 use tower_lsp::lsp_types::{
     CompletionItem, CompletionItemKind, CompletionParams, CompletionResponse, InsertTextFormat,
@@ -29,7 +30,14 @@ pub fn completion(doc: &DocumentState, params: CompletionParams) -> Option<Compl
 
     let items = match context {
         CompletionContext::WidgetName => complete_widget_names(),
-        CompletionContext::AttributeName { widget } => complete_attributes(&widget),
+        CompletionContext::AttributeName { widget } => {
+            let breakpoints = doc
+                .ast
+                .as_ref()
+                .map(|ast| ast.breakpoints.clone())
+                .unwrap_or_default();
+            complete_attributes(&widget, &breakpoints)
+        }
         CompletionContext::AttributeValue { widget, attribute } => {
             complete_values(&widget, &attribute)
         }
@@ -51,7 +59,7 @@ fn complete_widget_names() -> Vec<CompletionItem> {
         .collect()
 }
 
-fn complete_attributes(widget_name: &str) -> Vec<CompletionItem> {
+fn complete_attributes(widget_name: &str, breakpoints: &Breakpoints) -> Vec<CompletionItem> {
     let kind = match widget_kind_from_str(widget_name) {
         Some(k) => k,
         None => return vec![],
@@ -96,10 +104,43 @@ fn complete_attributes(widget_name: &str) -> Vec<CompletionItem> {
         CompletionItemKind::PROPERTY,
     );
 
+    // Prefixes of the breakpoint attributes, from the document's <breakpoints>
+    for (breakpoint, min_width) in breakpoints.all() {
+        items.push(CompletionItem {
+            label: format!("{}-", breakpoint),
+            kind: Some(CompletionItemKind::MODULE),
+            detail: Some(format!("Breakpoint (>= {}px)", min_width)),
+            ..Default::default()
+        });
+    }
+
     items
 }
 
-fn complete_values(_widget: &str, attr: &str) -> Vec<CompletionItem> {
+fn complete_values(widget: &str, attr: &str) -> Vec<CompletionItem> {
+    if widget == "icon" && attr == "name" {
+        return dampen_core::icons::names()
+            .map(|name| CompletionItem {
+                label: name.to_string(),
+                kind: Some(CompletionItemKind::ENUM_MEMBER),
+                detail: Some("Built-in icon".to_string()),
+                ..Default::default()
+            })
+            .collect();
+    }
+
+    if attr == "role" {
+        return dampen_core::AccessibilityRole::ALL
+            .iter()
+            .map(|role| CompletionItem {
+                label: role.as_str().to_string(),
+                kind: Some(CompletionItemKind::ENUM_MEMBER),
+                detail: Some("Accessibility role".to_string()),
+                ..Default::default()
+            })
+            .collect();
+    }
+
     // Basic heuristics for common attribute types
     match attr {
         "enabled" | "checked" | "visible" | "show" | "toggled" | "selected" | "active"
@@ -191,6 +232,19 @@ fn complete_values(_widget: &str, attr: &str) -> Vec<CompletionItem> {
                 ..Default::default()
             },
         ],
+        "mask" => vec![CompletionItem {
+            label: "password".to_string(),
+            kind: Some(CompletionItemKind::ENUM_MEMBER),
+            ..Default::default()
+        }],
+        "filter" => ["numeric", "alpha", "alphanumeric", "regex:''"]
+            .iter()
+            .map(|filter| CompletionItem {
+                label: filter.to_string(),
+                kind: Some(CompletionItemKind::ENUM_MEMBER),
+                ..Default::default()
+            })
+            .collect(),
         _ => {
             // Check for color attributes
             if attr.contains("color") || attr == "background" || attr == "fill" || attr == "stroke"
@@ -229,8 +283,10 @@ fn widget_kind_from_str(name: &str) -> Option<WidgetKind> {
         "text" => Some(WidgetKind::Text),
         "image" => Some(WidgetKind::Image),
         "svg" => Some(WidgetKind::Svg),
+        "icon" => Some(WidgetKind::Icon),
         "button" => Some(WidgetKind::Button),
         "text_input" => Some(WidgetKind::TextInput),
+        "number_input" => Some(WidgetKind::NumberInput),
         "checkbox" => Some(WidgetKind::Checkbox),
         "slider" => Some(WidgetKind::Slider),
         "pick_list" => Some(WidgetKind::PickList),
@@ -238,9 +294,15 @@ fn widget_kind_from_str(name: &str) -> Option<WidgetKind> {
         "space" => Some(WidgetKind::Space),
         "rule" => Some(WidgetKind::Rule),
         "radio" => Some(WidgetKind::Radio),
+        "radio_group" => Some(WidgetKind::RadioGroup),
+        "span" => Some(WidgetKind::Span),
+        "markdown" => Some(WidgetKind::Markdown),
+        "code_editor" => Some(WidgetKind::CodeEditor),
         "combobox" => Some(WidgetKind::ComboBox),
         "progress_bar" => Some(WidgetKind::ProgressBar),
+        "skeleton" => Some(WidgetKind::Skeleton),
         "tooltip" => Some(WidgetKind::Tooltip),
+        "tooltip_content" => Some(WidgetKind::TooltipContent),
         "grid" => Some(WidgetKind::Grid),
         "canvas" => Some(WidgetKind::Canvas),
         "rect" => Some(WidgetKind::CanvasRect),
@@ -248,6 +310,10 @@ fn widget_kind_from_str(name: &str) -> Option<WidgetKind> {
         "line" => Some(WidgetKind::CanvasLine),
         "canvas_text" => Some(WidgetKind::CanvasText),
         "group" => Some(WidgetKind::CanvasGroup),
+        "path" => Some(WidgetKind::CanvasPath),
+        "arc" => Some(WidgetKind::CanvasArc),
+        "bezier" => Some(WidgetKind::CanvasBezier),
+        "chart" => Some(WidgetKind::Chart),
         "date_picker" => Some(WidgetKind::DatePicker),
         "time_picker" => Some(WidgetKind::TimePicker),
         "color_picker" => Some(WidgetKind::ColorPicker),
@@ -259,6 +325,7 @@ fn widget_kind_from_str(name: &str) -> Option<WidgetKind> {
         "data_table" => Some(WidgetKind::DataTable),
         "data_column" => Some(WidgetKind::DataColumn),
         "tree_view" => Some(WidgetKind::TreeView),
+        "tree" => Some(WidgetKind::Tree),
         "tree_node" => Some(WidgetKind::TreeNode),
         "for" => Some(WidgetKind::For),
         "if" => Some(WidgetKind::If),
@@ -1,16 +1,24 @@
 //! Diagnostic computation and publishing.
 //!
-//! Converts Dampen parse errors to LSP diagnostics.
+//! Converts Dampen parse errors to LSP diagnostics, and checks the handlers and
+//! bindings of a view against the Rust module that loads it.
 
-use dampen_core::parser::parse;
+use std::path::Path;
+
+use dampen_core::expr::{BindingExpr, Expr};
+use dampen_core::ir::scope::MODEL_ATTRIBUTE;
+use dampen_core::ir::span::Span;
+use dampen_core::ir::{AttributeValue, InterpolatedPart, WidgetKind, WidgetNode};
+use dampen_core::parser::parse_with_recovery;
 use tower_lsp::lsp_types::*;
 
 use crate::converters;
 use crate::document::DocumentState;
+use crate::workspace::UiModule;
 
 /// Computes diagnostics for a document.
 ///
-/// Parses the document content and converts any errors to LSP diagnostics.
+/// Parses the document content and converts every error to an LSP diagnostic.
 ///
 /// # Arguments
 ///
@@ -22,10 +30,7 @@ use crate::document::DocumentState;
 pub fn compute_diagnostics(doc: &DocumentState) -> Vec<Diagnostic> {
     // Use existing parse errors if available, otherwise re-parse
     let errors = if doc.parse_errors.is_empty() {
-        match parse(&doc.content) {
-            Ok(_) => return vec![],
-            Err(error) => vec![error],
-        }
+        parse_with_recovery(&doc.content).1
     } else {
         doc.parse_errors.clone()
     };
@@ -36,3 +41,172 @@ pub fn compute_diagnostics(doc: &DocumentState) -> Vec<Diagnostic> {
         .map(|err| converters::parse_error_to_diagnostic(&doc.content, err))
         .collect()
 }
+
+/// Computes the diagnostics comparing a view with the Rust module that loads it.
+///
+/// Events naming a handler the module does not declare, and bindings reading a
+/// field its model does not have, are reported as warnings linking to the
+/// module. Loop variables and `model="..."` scopes are not checked, nor dotted
+/// paths whose first segment may be a named shared context.
+///
+/// # Arguments
+///
+/// * `doc` - The document state to validate
+/// * `module` - The indexed Rust module of the view
+///
+/// # Returns
+///
+/// Vector of LSP diagnostics (empty if the document could not be parsed)
+pub fn workspace_diagnostics(doc: &DocumentState, module: &UiModule) -> Vec<Diagnostic> {
+    let (Some(document), Ok(module_uri)) = (&doc.ast, Url::from_file_path(&module.path)) else {
+        return Vec::new();
+    };
+
+    let mut checker = WorkspaceChecker {
+        content: &doc.content,
+        module,
+        module_uri,
+        scope: vec!["item".to_string(), "index".to_string()],
+        diagnostics: Vec::new(),
+    };
+    checker.check_node(&document.root);
+    checker.diagnostics
+}
+
+/// Walks a widget tree, tracking the loop variables in scope
+struct WorkspaceChecker<'a> {
+    content: &'a str,
+    module: &'a UiModule,
+    module_uri: Url,
+    scope: Vec<String>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl WorkspaceChecker<'_> {
+    fn check_node(&mut self, node: &WidgetNode) {
+        // Bindings of custom widgets and `model` scopes are not resolved against the model
+        if matches!(node.kind, WidgetKind::Custom(_))
+            || node.attributes.contains_key(MODEL_ATTRIBUTE)
+        {
+            return;
+        }
+
+        for event in &node.events {
+            if !self.module.has_handler(&event.handler) {
+                let message = format!("Handler '{}' is not declared", event.handler);
+                let related = format!(
+                    "Handlers of this view are declared in {}",
+                    file_name(&self.module.path)
+                );
+                self.push(event.span, message, self.module.line, related);
+            }
+            for param in event.param.iter().chain(&event.params) {
+                self.check_binding(param);
+            }
+        }
+
+        let values = node
+            .attributes
+            .values()
+            .chain(node.breakpoint_attributes.values().flat_map(|a| a.values()));
+        for value in values {
+            match value {
+                AttributeValue::Static(_) => {}
+                AttributeValue::Binding(binding) => self.check_binding(binding),
+                AttributeValue::Interpolated(parts) => {
+                    for part in parts {
+                        if let InterpolatedPart::Binding(binding) = part {
+                            self.check_binding(binding);
+                        }
+                    }
+                }
+            }
+        }
+
+        let loop_variable = match (&node.kind, node.attributes.get("each")) {
+            (WidgetKind::For, Some(AttributeValue::Static(name))) => Some(name.clone()),
+            _ => None,
+        };
+        let depth = self.scope.len();
+        self.scope.extend(loop_variable);
+        for child in &node.children {
+            self.check_node(child);
+        }
+        self.scope.truncate(depth);
+    }
+
+    fn check_binding(&mut self, binding: &BindingExpr) {
+        let Some(model) = &self.module.model else {
+            return;
+        };
+        let mut fields = Vec::new();
+        collect_fields(&binding.expr, &mut fields);
+
+        for path in fields {
+            let [name] = path else {
+                continue;
+            };
+            if self.scope.contains(name) || model.has_field(name) {
+                continue;
+            }
+            let message = format!("Field '{}' is not defined on {}", name, model.symbol.name);
+            let related = format!("{} is defined here", model.symbol.name);
+            self.push(binding.span, message, model.symbol.line, related);
+        }
+    }
+
+    fn push(&mut self, span: Span, message: String, line: u32, related: String) {
+        let position = Position::new(line, 0);
+        self.diagnostics.push(Diagnostic {
+            range: converters::span_to_range(self.content, span),
+            severity: Some(DiagnosticSeverity::WARNING),
+            source: Some("dampen".to_string()),
+            message,
+            related_information: Some(vec![DiagnosticRelatedInformation {
+                location: Location {
+                    uri: self.module_uri.clone(),
+                    range: Range::new(position, position),
+                },
+                message: related,
+            }]),
+            ..Diagnostic::default()
+        });
+    }
+}
+
+/// Field paths read by an expression, excluding `shared.` paths
+fn collect_fields<'a>(expr: &'a Expr, fields: &mut Vec<&'a [String]>) {
+    match expr {
+        Expr::FieldAccess(field) => fields.push(&field.path),
+        Expr::SharedFieldAccess(_) | Expr::Literal(_) => {}
+        Expr::MethodCall(call) => {
+            collect_fields(&call.receiver, fields);
+            for arg in &call.args {
+                collect_fields(arg, fields);
+            }
+        }
+        Expr::BinaryOp(binary) => {
+            collect_fields(&binary.left, fields);
+            collect_fields(&binary.right, fields);
+        }
+        Expr::UnaryOp(unary) => collect_fields(&unary.operand, fields),
+        Expr::Conditional(conditional) => {
+            collect_fields(&conditional.condition, fields);
+            collect_fields(&conditional.then_branch, fields);
+            collect_fields(&conditional.else_branch, fields);
+        }
+        Expr::Filter(filter) => {
+            collect_fields(&filter.input, fields);
+            for arg in &filter.args {
+                collect_fields(arg, fields);
+            }
+        }
+    }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().map_or_else(
+        || path.display().to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    )
+}
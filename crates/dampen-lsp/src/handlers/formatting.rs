@@ -0,0 +1,133 @@
+//! Document formatting handlers.
+//!
+//! Formats documents with `dampen_core::format`, for `textDocument/formatting`
+//! and `textDocument/rangeFormatting`. The formatter only changes the
+//! whitespace around the lines, so each changed line becomes its own edit and
+//! the cursor stays in place elsewhere.
+
+use dampen_core::format::{FormatOptions, format_lines};
+use tower_lsp::lsp_types::{FormattingOptions, Position, Range, TextEdit};
+
+use crate::document::DocumentState;
+
+/// Handles document formatting requests.
+///
+/// Returns an edit per line changed by the formatter.
+pub fn format_document(doc: &DocumentState, options: &FormattingOptions) -> Vec<TextEdit> {
+    line_edits(doc, options, |_| true)
+}
+
+/// Handles range formatting requests.
+///
+/// Formats the whole document, so the lines of `range` are indented by their
+/// nesting, and returns the edits of the lines `range` touches.
+pub fn format_range(
+    doc: &DocumentState,
+    range: Range,
+    options: &FormattingOptions,
+) -> Vec<TextEdit> {
+    line_edits(doc, options, |line| {
+        (range.start.line..=range.end.line).contains(&line)
+    })
+}
+
+/// Edits replacing the lines accepted by `in_range` with their formatted text
+fn line_edits(
+    doc: &DocumentState,
+    options: &FormattingOptions,
+    in_range: impl Fn(u32) -> bool,
+) -> Vec<TextEdit> {
+    let formatted = format_lines(&doc.content, &format_options(options));
+
+    doc.content
+        .split('\n')
+        .zip(formatted)
+        .enumerate()
+        .filter_map(|(line, (original, formatted))| {
+            let line = line as u32;
+            let original = original.strip_suffix('\r').unwrap_or(original);
+            (in_range(line) && original != formatted).then(|| TextEdit {
+                range: Range::new(
+                    Position::new(line, 0),
+                    Position::new(line, original.encode_utf16().count() as u32),
+                ),
+                new_text: formatted,
+            })
+        })
+        .collect()
+}
+
+/// Indentation requested by the editor
+fn format_options(options: &FormattingOptions) -> FormatOptions {
+    let indent = if options.insert_spaces {
+        " ".repeat(options.tab_size as usize)
+    } else {
+        "\t".to_string()
+    };
+    FormatOptions { indent }
+}
+
+#[cfg(test)]
+mod tests {
+    use tower_lsp::lsp_types::Url;
+
+    use super::*;
+
+    fn document(content: &str) -> Result<DocumentState, url::ParseError> {
+        let uri = Url::parse("file:///test.dampen")?;
+        Ok(DocumentState::new(uri, content.to_string(), 1))
+    }
+
+    fn options(tab_size: u32, insert_spaces: bool) -> FormattingOptions {
+        FormattingOptions {
+            tab_size,
+            insert_spaces,
+            ..FormattingOptions::default()
+        }
+    }
+
+    #[test]
+    fn test_format_document() -> Result<(), url::ParseError> {
+        let doc = document(
+            "<column>\r\n<text value=\"Café\" />  \r\n    <button label=\"Go\" />\r\n</column>",
+        )?;
+
+        let edits = format_document(&doc, &options(2, true));
+        assert_eq!(
+            edits,
+            [
+                TextEdit {
+                    range: Range::new(Position::new(1, 0), Position::new(1, 23)),
+                    new_text: "  <text value=\"Café\" />".to_string(),
+                },
+                TextEdit {
+                    range: Range::new(Position::new(2, 0), Position::new(2, 25)),
+                    new_text: "  <button label=\"Go\" />".to_string(),
+                },
+            ]
+        );
+
+        let edits = format_document(&doc, &options(4, false));
+        assert_eq!(
+            edits.first().map(|edit| edit.new_text.as_str()),
+            Some("\t<text value=\"Café\" />")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_range() -> Result<(), url::ParseError> {
+        let doc = document("<column>\n<row>\n<text value=\"a\" />\n</row>\n</column>\n")?;
+        let range = Range::new(Position::new(2, 0), Position::new(2, 5));
+
+        let edits = format_range(&doc, range, &options(4, true));
+        assert_eq!(
+            edits,
+            [TextEdit {
+                range: Range::new(Position::new(2, 0), Position::new(2, 18)),
+                new_text: "        <text value=\"a\" />".to_string(),
+            }]
+        );
+        Ok(())
+    }
+}
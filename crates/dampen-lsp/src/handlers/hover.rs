@@ -1,9 +1,17 @@
 //! Hover request handler.
 //!
-//! Provides contextual documentation on hover.
+//! Provides contextual documentation on hover. Hovering a widget also shows
+//! its `<!-- @doc: ... -->` annotations and its resolved style cascade, against
+//! the theme of the project.
 
 #![allow(dead_code)]
 
+use std::path::Path;
+
+use dampen_core::ir::cascade::{StyleCascade, StyleSource, describe_color, describe_style};
+use dampen_core::ir::{DampenDocument, Theme, WidgetNode};
+use dampen_core::mock;
+use dampen_core::parser::theme_parser::parse_theme_document;
 use tower_lsp::lsp_types::*;
 
 use crate::analyzer::{Analyzer, CompletionContext};
@@ -39,7 +47,7 @@ pub fn hover(doc: &DocumentState, position: Position) -> Option<Hover> {
         CompletionContext::WidgetName => {
             // Try to find the widget name at this position
             if let Some(widget_name) = analyzer.find_widget_at_position(doc, position) {
-                return hover_widget(&widget_name, position);
+                return hover_widget_in(doc, &widget_name, position);
             }
         }
         CompletionContext::AttributeName { widget } => {
@@ -48,7 +56,7 @@ pub fn hover(doc: &DocumentState, position: Position) -> Option<Hover> {
                 return hover_attribute(&widget, &attr_name, position);
             }
             // If not on a specific attribute, show widget documentation
-            return hover_widget(&widget, position);
+            return hover_widget_in(doc, &widget, position);
         }
         CompletionContext::AttributeValue { widget, attribute } => {
             // We're inside an attribute value - show value documentation
@@ -56,13 +64,17 @@ pub fn hover(doc: &DocumentState, position: Position) -> Option<Hover> {
         }
         CompletionContext::BindingExpression => {
             // Hovering over a binding expression
+            let mut value = "**Binding Expression**\n\n\
+Dynamic value bound to application state.\n\n\
+Syntax: `{|expression|}`"
+                .to_string();
+            if let Some(example) = binding_example(doc, position) {
+                value.push_str(&format!("\n\nExample: `{}`", example));
+            }
             return Some(Hover {
                 contents: HoverContents::Markup(MarkupContent {
                     kind: MarkupKind::Markdown,
-                    value: "**Binding Expression**\n\n\
-Dynamic value bound to application state.\n\n\
-Syntax: `{|expression|}`"
-                        .to_string(),
+                    value,
                 }),
                 range: Some(Range {
                     start: position,
@@ -80,7 +92,7 @@ Syntax: `{|expression|}`"
                 if let Some((_, attr_name)) = analyzer.find_attribute_at_position(doc, position) {
                     return hover_attribute(&widget_name, &attr_name, position);
                 }
-                return hover_widget(&widget_name, position);
+                return hover_widget_in(doc, &widget_name, position);
             }
         }
     }
@@ -126,6 +138,155 @@ fn hover_widget(widget_name: &str, position: Position) -> Option<Hover> {
     })
 }
 
+/// Generates hover information for a widget of a document.
+///
+/// The widget documentation is preceded by the `@doc` annotations of the
+/// widget element under the cursor, and followed by its style cascade, if it
+/// has any style.
+fn hover_widget_in(doc: &DocumentState, widget_name: &str, position: Position) -> Option<Hover> {
+    let mut hover = hover_widget(widget_name, position)?;
+    if let HoverContents::Markup(content) = &mut hover.contents {
+        if let Some(annotation) = widget_doc(doc, position) {
+            content.value = format!("{}\n\n---\n\n{}", annotation, content.value);
+        }
+        if let Some(cascade) = style_cascade_section(doc, position) {
+            content.value.push_str("\n\n---\n\n");
+            content.value.push_str(&cascade);
+        }
+    }
+    Some(hover)
+}
+
+/// The `@doc` annotations of the widget at a given position.
+fn widget_doc(doc: &DocumentState, position: Position) -> Option<String> {
+    let ast = doc.ast.as_ref()?;
+    let offset = position_to_offset(&doc.content, position)?;
+    widget_at_offset(&ast.root, offset)?.doc()
+}
+
+/// Formats the style cascade of the widget at a given position.
+///
+/// Lists the layers in order of precedence (theme defaults, classes, inline
+/// attributes), then the computed value of each property with the layer
+/// setting it.
+///
+/// # Arguments
+///
+/// * `doc` - The document state
+/// * `position` - Cursor position inside the widget's element
+///
+/// # Returns
+///
+/// The Markdown section, or `None` if the widget has no style
+fn style_cascade_section(doc: &DocumentState, position: Position) -> Option<String> {
+    let ast = doc.ast.as_ref()?;
+    let offset = position_to_offset(&doc.content, position)?;
+    let node = widget_at_offset(&ast.root, offset)?;
+
+    let theme = doc
+        .uri
+        .to_file_path()
+        .ok()
+        .and_then(|path| project_theme(&path))
+        .or_else(|| document_theme(ast));
+    let cascade = StyleCascade::resolve(node, &ast.style_classes, theme.as_ref());
+    if cascade.layers.is_empty() && cascade.missing_classes.is_empty() {
+        return None;
+    }
+
+    let mut section = String::from("**Style cascade**\n");
+    for (index, layer) in cascade.layers.iter().enumerate() {
+        let properties: Vec<String> = describe_style(&layer.style)
+            .into_iter()
+            .map(|(name, value)| {
+                let token = match (&layer.source, &theme) {
+                    (StyleSource::Theme(_), Some(theme)) => palette_token(theme, &value),
+                    _ => None,
+                };
+                match token {
+                    Some(token) => format!("`{}: {}` (`@{}`)", name, value, token),
+                    None => format!("`{}: {}`", name, value),
+                }
+            })
+            .collect();
+        section.push_str(&format!(
+            "\n{}. {}: {}",
+            index + 1,
+            layer.source,
+            properties.join(", ")
+        ));
+    }
+    for class in &cascade.missing_classes {
+        section.push_str(&format!("\n- class `{}` is not defined", class));
+    }
+
+    let computed = describe_style(&cascade.computed());
+    if !computed.is_empty() {
+        section.push_str("\n\n**Computed style**\n");
+        for (name, value) in computed {
+            let origin = cascade
+                .origin(name)
+                .map(ToString::to_string)
+                .unwrap_or_default();
+            section.push_str(&format!("\n- `{}: {}` from {}", name, value, origin));
+        }
+    }
+    Some(section)
+}
+
+/// Finds the innermost widget whose element contains a byte offset.
+fn widget_at_offset(node: &WidgetNode, offset: usize) -> Option<&WidgetNode> {
+    if !(node.span.start..node.span.end).contains(&offset) {
+        return None;
+    }
+    node.children
+        .iter()
+        .find_map(|child| widget_at_offset(child, offset))
+        .or(Some(node))
+}
+
+/// Loads the default theme of the project containing a view.
+///
+/// The theme file is `src/ui/theme/theme.dampen` in the nearest ancestor
+/// directory that has one, as loaded by the runtime.
+fn project_theme(view: &Path) -> Option<Theme> {
+    let theme_file = view
+        .ancestors()
+        .skip(1)
+        .map(|dir| dir.join("src/ui/theme/theme.dampen"))
+        .find(|file| file.is_file())?;
+    let content = std::fs::read_to_string(theme_file).ok()?;
+    let document = parse_theme_document(&content).ok()?;
+
+    let name = document.effective_default(None).to_string();
+    document.resolve_inheritance().remove(&name)
+}
+
+/// The global theme declared by the view itself, if any.
+fn document_theme(ast: &DampenDocument) -> Option<Theme> {
+    let name = ast.global_theme.as_ref()?;
+    ast.themes.get(name).cloned()
+}
+
+/// Name of the palette color of a theme with the given hex value.
+fn palette_token(theme: &Theme, value: &str) -> Option<&'static str> {
+    let palette = &theme.palette;
+    [
+        ("primary", palette.primary),
+        ("secondary", palette.secondary),
+        ("success", palette.success),
+        ("warning", palette.warning),
+        ("danger", palette.danger),
+        ("background", palette.background),
+        ("surface", palette.surface),
+        ("text", palette.text),
+        ("text_secondary", palette.text_secondary),
+    ]
+    .into_iter()
+    .find(|(_, color)| color.is_some_and(|color| describe_color(&color) == value))
+    .map(|(name, _)| name)
+}
+
 /// Generates hover information for an attribute.
 ///
 /// Retrieves attribute documentation from the schema data and formats it
@@ -276,6 +437,35 @@ See attribute documentation for valid values.",
     })
 }
 
+/// Evaluates the binding expression at a given position against mock data.
+///
+/// The model and shared state are mocked from the fields the document binds
+/// (see `dampen_core::mock`), so the example shows the kind of value the
+/// expression renders.
+///
+/// # Arguments
+///
+/// * `doc` - The document state
+/// * `position` - Cursor position inside the expression
+///
+/// # Returns
+///
+/// The example value, or `None` if the expression does not evaluate (e.g. it
+/// uses a loop variable)
+fn binding_example(doc: &DocumentState, position: Position) -> Option<String> {
+    let ast = doc.ast.clone()?;
+    let offset = position_to_offset(&doc.content, position)?;
+
+    let before = doc.content.get(..offset)?;
+    let after = doc.content.get(offset..)?;
+    let start = before.rfind('{')? + 1;
+    let end = offset + after.find('}')?;
+    let expr = doc.content.get(start..end)?;
+
+    let value = mock::app_state(ast).evaluate(expr).ok()?;
+    Some(value.to_display_string())
+}
+
 /// Extracts the value at a given position in the document.
 ///
 /// Looks for quoted values around the cursor position.
@@ -449,6 +639,116 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_hover_binding_shows_mock_example() {
+        let doc = create_test_doc(
+            r#"<dampen version="1.1"><column><text value="{count * 2}"/></column></dampen>"#,
+        );
+        // Position inside "count"
+        let position = Position::new(0, 47);
+
+        let result = hover(&doc, position);
+
+        assert!(result.is_some());
+        if let Some(Hover {
+            contents: HoverContents::Markup(content),
+            ..
+        }) = result
+        {
+            assert!(content.value.contains("Example: `6`"));
+        }
+    }
+
+    #[test]
+    fn test_hover_widget_shows_style_cascade() {
+        let doc = create_test_doc(
+            r##"<dampen>
+<styles>
+<style name="primary"><base background="#3498db" color="#ffffff" /></style>
+</styles>
+<button label="Save" class="primary missing" background="#e74c3c" />
+</dampen>"##,
+        );
+        // Position after "button"
+        let result = hover(&doc, Position::new(4, 7));
+
+        let Some(Hover {
+            contents: HoverContents::Markup(content),
+            ..
+        }) = result
+        else {
+            panic!("Expected Markup content");
+        };
+        assert!(content.value.contains("**Style cascade**"));
+        assert!(
+            content
+                .value
+                .contains("1. class `primary`: `background: #3498db`, `color: #ffffff`")
+        );
+        assert!(content.value.contains("2. inline: `background: #e74c3c`"));
+        assert!(content.value.contains("class `missing` is not defined"));
+        assert!(content.value.contains("`background: #e74c3c` from inline"));
+        assert!(
+            content
+                .value
+                .contains("`color: #ffffff` from class `primary`")
+        );
+    }
+
+    #[test]
+    fn test_hover_widget_shows_doc_annotation() {
+        let doc = create_test_doc(
+            r#"<dampen>
+<column>
+<!-- @doc: Saves the form -->
+<button label="Save" on_click="save" />
+</column>
+</dampen>"#,
+        );
+        // Position after "button"
+        let result = hover(&doc, Position::new(3, 7));
+
+        let Some(Hover {
+            contents: HoverContents::Markup(content),
+            ..
+        }) = result
+        else {
+            panic!("Expected Markup content");
+        };
+        assert!(content.value.starts_with("Saves the form\n\n---"));
+    }
+
+    #[test]
+    fn test_style_cascade_uses_project_theme() {
+        let dir = tempfile::tempdir().unwrap();
+        let theme_dir = dir.path().join("src/ui/theme");
+        std::fs::create_dir_all(&theme_dir).unwrap();
+        std::fs::write(
+            theme_dir.join("theme.dampen"),
+            r##"<dampen version="1.1">
+    <themes>
+        <theme name="light">
+            <palette primary="#3498db" secondary="#2ecc71" success="#27ae60" warning="#f39c12"
+                danger="#e74c3c" background="#ecf0f1" surface="#ffffff" text="#2c3e50"
+                text_secondary="#7f8c8d" />
+            <typography font_size_base="16" />
+            <spacing unit="8" />
+        </theme>
+    </themes>
+    <default_theme name="light" />
+</dampen>"##,
+        )
+        .unwrap();
+
+        let view = dir.path().join("src/ui/window.dampen");
+        let uri = Url::from_file_path(&view).unwrap();
+        let doc = DocumentState::new(uri, r#"<button label="Save" />"#.to_string(), 1);
+
+        let section = style_cascade_section(&doc, Position::new(0, 3)).unwrap();
+        assert!(section.contains("1. theme `light`: `background: #3498db` (`@primary`)"));
+        assert!(section.contains("`color: #2c3e50` from theme `light`"));
+    }
+
     #[test]
     fn test_extract_value_at_position() {
         let content = r#"enabled="true""#;
@@ -1,9 +1,13 @@
 //! LSP method handlers.
 //!
 //! This module contains implementations for LSP protocol methods,
-//! organized by category (text document, diagnostics, completion, hover).
+//! organized by category (text document, diagnostics, completion, hover,
+//! references, colors, formatting).
 
+pub mod color;
 pub mod completion;
 pub mod diagnostics;
+pub mod formatting;
 pub mod hover;
+pub mod references;
 pub mod text_document;
@@ -0,0 +1,186 @@
+//! References request handler.
+//!
+//! Lists every place a style class, theme, handler or widget id is named.
+//! Classes, themes and handlers are shared by all the views of a project and
+//! are searched across the workspace index; widget ids are local to their view.
+
+use tower_lsp::lsp_types::{Location, Position, Range};
+
+use crate::document::DocumentState;
+use crate::symbols::{self, SymbolKind};
+use crate::workspace::WorkspaceIndex;
+
+/// Handles references requests.
+///
+/// Returns the locations naming the symbol at `position`: first in the current
+/// document, from its unsaved content, then in the other views of the workspace.
+/// With `include_declaration`, definitions are listed too, including the Rust
+/// declaration of a handler.
+///
+/// # Returns
+///
+/// `None` if there is no symbol at `position`
+pub fn references(
+    index: &WorkspaceIndex,
+    doc: &DocumentState,
+    position: Position,
+    include_declaration: bool,
+) -> Option<Vec<Location>> {
+    let occurrences = symbols::scan(&doc.content);
+    let symbol = symbols::symbol_at(&occurrences, position)?;
+    let wanted = |kind: SymbolKind, name: &str, is_definition: bool| {
+        kind == symbol.kind && name == symbol.name && (include_declaration || !is_definition)
+    };
+
+    let mut locations: Vec<Location> = occurrences
+        .iter()
+        .filter(|o| wanted(o.kind, &o.name, o.is_definition))
+        .map(|o| Location::new(doc.uri.clone(), o.range))
+        .collect();
+
+    let current = doc.uri.to_file_path().ok();
+    if symbol.kind != SymbolKind::Id {
+        for (path, o) in index.occurrences(symbol.kind, &symbol.name) {
+            if current.as_deref() == Some(path) || !wanted(o.kind, &o.name, o.is_definition) {
+                continue;
+            }
+            if let Ok(uri) = url::Url::from_file_path(path) {
+                locations.push(Location::new(uri, o.range));
+            }
+        }
+    }
+
+    if symbol.kind == SymbolKind::Handler
+        && include_declaration
+        && let Some(module) = current.and_then(|path| index.module_for(&path))
+        && let Ok(uri) = url::Url::from_file_path(&module.path)
+    {
+        for handler in module.handlers.iter().filter(|h| h.name == symbol.name) {
+            let start = Position::new(handler.line, 0);
+            locations.push(Location::new(uri.clone(), Range::new(start, start)));
+        }
+    }
+
+    Some(locations)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+
+    const THEME: &str = r##"<dampen>
+    <themes>
+        <theme name="brand"><palette primary="#3498db" /></theme>
+    </themes>
+</dampen>"##;
+
+    const MAIN: &str = r#"<dampen>
+    <styles>
+        <style name="card"><base padding="8" /></style>
+    </styles>
+    <column id="main" class="card" theme="brand">
+        <button label="Save" on_click="save" />
+        <float anchor="main" />
+    </column>
+</dampen>"#;
+
+    const SETTINGS: &str = r#"<dampen>
+    <container id="main" class="card" theme="brand">
+        <button label="Save" on_click="save" />
+    </container>
+</dampen>"#;
+
+    const MODULE: &str = r#"#[dampen_ui("main.dampen")]
+mod _app {}
+
+#[ui_handler]
+pub fn save(model: &mut Model) {}
+"#;
+
+    fn workspace() -> (WorkspaceIndex, DocumentState) {
+        let mut index = WorkspaceIndex::default();
+        index.update_view(Path::new("/app/src/ui/theme/theme.dampen"), THEME);
+        index.update_view(Path::new("/app/src/ui/settings.dampen"), SETTINGS);
+        // The indexed copy of the open document is stale
+        index.update_view(Path::new("/app/src/ui/main.dampen"), "<column />");
+        index.update(Path::new("/app/src/ui/main.rs"), MODULE);
+
+        let uri = url::Url::parse("file:///app/src/ui/main.dampen").unwrap();
+        (index, DocumentState::new(uri, MAIN.to_string(), 1))
+    }
+
+    fn summary(locations: &[Location]) -> Vec<(String, u32)> {
+        locations
+            .iter()
+            .map(|l| {
+                let file = l.uri.path().rsplit('/').next().unwrap_or_default();
+                (file.to_string(), l.range.start.line)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_class_references_across_views() {
+        let (index, doc) = workspace();
+
+        let locations = references(&index, &doc, Position::new(4, 30), false).unwrap();
+        assert_eq!(
+            summary(&locations),
+            vec![
+                ("main.dampen".to_string(), 4),
+                ("settings.dampen".to_string(), 1)
+            ]
+        );
+
+        let locations = references(&index, &doc, Position::new(4, 30), true).unwrap();
+        assert_eq!(summary(&locations)[0], ("main.dampen".to_string(), 2));
+        assert_eq!(locations.len(), 3);
+    }
+
+    #[test]
+    fn test_theme_references_include_definition() {
+        let (index, doc) = workspace();
+
+        let locations = references(&index, &doc, Position::new(4, 47), true).unwrap();
+        assert_eq!(
+            summary(&locations),
+            vec![
+                ("main.dampen".to_string(), 4),
+                ("settings.dampen".to_string(), 1),
+                ("theme.dampen".to_string(), 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_handler_references_include_rust_declaration() {
+        let (index, doc) = workspace();
+
+        let locations = references(&index, &doc, Position::new(5, 40), true).unwrap();
+        assert_eq!(
+            summary(&locations),
+            vec![
+                ("main.dampen".to_string(), 5),
+                ("settings.dampen".to_string(), 2),
+                ("main.rs".to_string(), 4)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_id_references_stay_in_document() {
+        let (index, doc) = workspace();
+
+        let locations = references(&index, &doc, Position::new(6, 24), true).unwrap();
+        assert_eq!(
+            summary(&locations),
+            vec![
+                ("main.dampen".to_string(), 4),
+                ("main.dampen".to_string(), 6)
+            ]
+        );
+        assert!(references(&index, &doc, Position::new(5, 10), true).is_none());
+    }
+}
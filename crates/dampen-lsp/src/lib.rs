@@ -13,6 +13,8 @@
 //! - **DocumentCache**: LRU cache of open documents (50 max)
 //! - **Analyzer**: Semantic analysis and position-based queries
 //! - **Handlers**: LSP method implementations (textDocument/*)
+//! - **WorkspaceIndex**: Handlers and models declared by the `ui/*.rs` modules,
+//!   and the symbols named by every `.dampen` file
 //!
 //! # Usage
 //!
@@ -25,7 +27,10 @@ pub mod capabilities;
 pub mod converters;
 pub mod document;
 pub mod handlers;
+pub mod markup;
 pub mod schema_data;
+pub mod symbols;
+pub mod workspace;
 
 // Re-export main types for convenience
 pub use document::{DocumentCache, DocumentState};
@@ -13,6 +13,8 @@
 //! - **DocumentCache**: LRU cache of open documents (50 max)
 //! - **Analyzer**: Semantic analysis and position-based queries
 //! - **Handlers**: LSP method implementations (textDocument/*)
+//! - **WorkspaceIndex**: Handlers and models declared by the `ui/*.rs` modules,
+//!   and the symbols named by every `.dampen` file
 //!
 //! # Usage
 //!
@@ -34,9 +36,13 @@ mod capabilities;
 mod converters;
 mod document;
 mod handlers;
+mod markup;
 mod schema_data;
+mod symbols;
+mod workspace;
 
 use document::{DocumentCache, DocumentState};
+use workspace::WorkspaceIndex;
 
 /// Main LSP server implementation.
 ///
@@ -47,6 +53,8 @@ pub struct LspServer {
     client: Client,
     /// LRU cache of open documents
     document_cache: Arc<RwLock<DocumentCache>>,
+    /// Handlers and models of the workspace's UI modules
+    workspace: Arc<RwLock<WorkspaceIndex>>,
 }
 
 impl LspServer {
@@ -59,6 +67,7 @@ impl LspServer {
         Self {
             client,
             document_cache: Arc::new(RwLock::new(DocumentCache::new(50))),
+            workspace: Arc::new(RwLock::new(WorkspaceIndex::default())),
         }
     }
 }
@@ -67,10 +76,39 @@ impl LspServer {
 impl LanguageServer for LspServer {
     /// Handles LSP initialization.
     ///
-    /// Advertises server capabilities to the client.
-    async fn initialize(&self, _: InitializeParams) -> Result<InitializeResult> {
+    /// Advertises server capabilities to the client and indexes the UI modules
+    /// of the workspace.
+    async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
         info!("Dampen LSP server initializing");
 
+        #[allow(deprecated)]
+        let root_uris: Vec<Url> = match (params.workspace_folders, params.root_uri) {
+            (Some(folders), _) => folders.into_iter().map(|folder| folder.uri).collect(),
+            (None, Some(root)) => vec![root],
+            (None, None) => Vec::new(),
+        };
+        let roots: Vec<_> = root_uris
+            .iter()
+            .filter_map(|uri| uri.to_file_path().ok())
+            .collect();
+        if !roots.is_empty() {
+            let scanned = tokio::task::spawn_blocking(move || {
+                let mut index = WorkspaceIndex::default();
+                for root in &roots {
+                    index.merge(WorkspaceIndex::scan(root));
+                }
+                index
+            })
+            .await;
+            match scanned {
+                Ok(index) => {
+                    info!("Indexed {} UI module(s)", index.module_count());
+                    *self.workspace.write().await = index;
+                }
+                Err(e) => warn!("Workspace scan failed: {}", e),
+            }
+        }
+
         Ok(InitializeResult {
             capabilities: capabilities::server_capabilities(),
             ..InitializeResult::default()
@@ -99,6 +137,10 @@ impl LanguageServer for LspServer {
 
         info!("Document opened: {}", uri);
 
+        if let Ok(path) = uri.to_file_path() {
+            self.workspace.write().await.update_view(&path, &content);
+        }
+
         // Create document state and parse
         let doc_state = DocumentState::new(uri.clone(), content, version);
 
@@ -125,7 +167,7 @@ impl LanguageServer for LspServer {
         let mut cache = self.document_cache.write().await;
 
         if let Some(doc) = cache.get(&uri) {
-            // Apply changes (full document sync for V1)
+            // Apply changes, in order
             let mut new_content = doc.content.clone();
 
             for change in params.content_changes {
@@ -143,8 +185,15 @@ impl LanguageServer for LspServer {
                 }
             }
 
-            // Create updated document state
-            let updated_doc = DocumentState::new(uri.clone(), new_content, version);
+            if let Ok(path) = uri.to_file_path() {
+                self.workspace
+                    .write()
+                    .await
+                    .update_view(&path, &new_content);
+            }
+
+            // Reparse the edited part of the document
+            let updated_doc = doc.edited(new_content, version);
             cache.insert(uri.clone(), updated_doc);
 
             // Publish diagnostics
@@ -157,12 +206,19 @@ impl LanguageServer for LspServer {
 
     /// Handles document close notification.
     ///
-    /// Removes document from cache and clears diagnostics.
+    /// Removes document from cache, re-indexes the file as saved on disk and
+    /// clears diagnostics.
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
         let uri = params.text_document.uri;
 
         info!("Document closed: {}", uri);
 
+        if let Ok(path) = uri.to_file_path()
+            && let Ok(content) = std::fs::read_to_string(&path)
+        {
+            self.workspace.write().await.update_view(&path, &content);
+        }
+
         // Remove from cache
         {
             let mut cache = self.document_cache.write().await;
@@ -228,6 +284,112 @@ impl LanguageServer for LspServer {
 
         result
     }
+
+    /// Handles references request.
+    ///
+    /// Lists the uses of the class, theme, handler or id under the cursor.
+    async fn references(&self, params: ReferenceParams) -> Result<Option<Vec<Location>>> {
+        let uri = params.text_document_position.text_document.uri;
+        let position = params.text_document_position.position;
+
+        info!("References request for: {} at {:?}", uri, position);
+
+        // Use write lock because cache.get() updates LRU recency
+        let mut cache = self.document_cache.write().await;
+        if let Some(doc) = cache.get(&uri) {
+            let workspace = self.workspace.read().await;
+            Ok(handlers::references::references(
+                &workspace,
+                doc,
+                position,
+                params.context.include_declaration,
+            ))
+        } else {
+            warn!("References requested for unknown document: {}", uri);
+            Ok(None)
+        }
+    }
+
+    /// Handles document color request.
+    ///
+    /// Lists the color values of the document, for the editor's swatches.
+    async fn document_color(&self, params: DocumentColorParams) -> Result<Vec<ColorInformation>> {
+        let uri = params.text_document.uri;
+
+        // Use write lock because cache.get() updates LRU recency
+        let mut cache = self.document_cache.write().await;
+        if let Some(doc) = cache.get(&uri) {
+            Ok(handlers::color::document_colors(doc))
+        } else {
+            warn!("Colors requested for unknown document: {}", uri);
+            Ok(Vec::new())
+        }
+    }
+
+    /// Handles color presentation request.
+    ///
+    /// Formats a color picked in the editor as attribute values.
+    async fn color_presentation(
+        &self,
+        params: ColorPresentationParams,
+    ) -> Result<Vec<ColorPresentation>> {
+        let uri = params.text_document.uri;
+
+        // Use write lock because cache.get() updates LRU recency
+        let mut cache = self.document_cache.write().await;
+        if let Some(doc) = cache.get(&uri) {
+            Ok(handlers::color::color_presentations(
+                doc,
+                params.color,
+                params.range,
+            ))
+        } else {
+            warn!("Color presentation requested for unknown document: {}", uri);
+            Ok(Vec::new())
+        }
+    }
+
+    /// Handles formatting request.
+    ///
+    /// Re-indents the document by the nesting of its elements.
+    async fn formatting(&self, params: DocumentFormattingParams) -> Result<Option<Vec<TextEdit>>> {
+        let uri = params.text_document.uri;
+
+        // Use write lock because cache.get() updates LRU recency
+        let mut cache = self.document_cache.write().await;
+        if let Some(doc) = cache.get(&uri) {
+            Ok(Some(handlers::formatting::format_document(
+                doc,
+                &params.options,
+            )))
+        } else {
+            warn!("Formatting requested for unknown document: {}", uri);
+            Ok(None)
+        }
+    }
+
+    /// Handles range formatting request.
+    ///
+    /// Re-indents the lines of the range by the nesting of their elements.
+    async fn range_formatting(
+        &self,
+        params: DocumentRangeFormattingParams,
+    ) -> Result<Option<Vec<TextEdit>>> {
+        let uri = params.text_document.uri;
+
+        // Use write lock because cache.get() updates LRU recency
+        let mut cache = self.document_cache.write().await;
+        if let Some(doc) = cache.get(&uri) {
+            Ok(Some(handlers::formatting::format_range(
+                doc,
+                params.range,
+                &params.options,
+            )))
+        } else {
+            warn!("Range formatting requested for unknown document: {}", uri);
+            Ok(None)
+        }
+    }
 }
 
 impl LspServer {
@@ -238,7 +400,12 @@ impl LspServer {
         let mut cache = self.document_cache.write().await;
 
         if let Some(doc) = cache.get(&uri.clone()) {
-            let diagnostics = handlers::diagnostics::compute_diagnostics(doc);
+            let mut diagnostics = handlers::diagnostics::compute_diagnostics(doc);
+            if let Ok(path) = uri.to_file_path()
+                && let Some(module) = self.workspace.read().await.module_for(&path)
+            {
+                diagnostics.extend(handlers::diagnostics::workspace_diagnostics(doc, module));
+            }
             let version = Some(doc.version);
 
             drop(cache);
@@ -0,0 +1,157 @@
+//! Tolerant scanning of `.dampen` markup.
+//!
+//! Editor features that only need the tags and attribute values of a file scan
+//! its text instead of parsing it, so they keep working while the document is
+//! being edited and does not parse. Attribute values keep their byte offsets to
+//! map them back to editor ranges.
+
+use tower_lsp::lsp_types::Position;
+
+/// An attribute of a start tag
+pub struct Attribute<'a> {
+    /// Attribute name, with its prefix if any
+    pub name: &'a str,
+    /// Raw value, entities not decoded
+    pub value: &'a str,
+    /// Byte offset of the value, after the opening quote
+    pub value_start: usize,
+}
+
+/// A tag of a document
+pub enum Tag<'a> {
+    /// Start or empty-element tag
+    Start {
+        name: &'a str,
+        attributes: Vec<Attribute<'a>>,
+        self_closing: bool,
+    },
+    /// End tag
+    End,
+}
+
+/// Start and end tags of a document, skipping comments, CDATA sections,
+/// processing instructions and declarations
+///
+/// Malformed tags end the scan at the point they become unreadable.
+pub fn tags(content: &str) -> Vec<Tag<'_>> {
+    let mut tags = Vec::new();
+    let mut pos = 0;
+
+    while let Some(next) = content[pos..].find('<') {
+        let start = pos + next;
+        let rest = &content[start..];
+        let skip_to = |end: &str| rest.find(end).map(|i| start + i + end.len());
+
+        let end = if rest.starts_with("<!--") {
+            skip_to("-->")
+        } else if rest.starts_with("<![CDATA[") {
+            skip_to("]]>")
+        } else if rest.starts_with("<?") {
+            skip_to("?>")
+        } else if rest.starts_with("<!") {
+            skip_to(">")
+        } else if rest.starts_with("</") {
+            tags.push(Tag::End);
+            skip_to(">")
+        } else {
+            start_tag(content, start + 1).map(|(tag, end)| {
+                tags.push(tag);
+                end
+            })
+        };
+        let Some(end) = end else {
+            break;
+        };
+        pos = end;
+    }
+
+    tags
+}
+
+/// Parse the start tag whose name begins at `pos`, returning the offset after it
+fn start_tag(content: &str, mut pos: usize) -> Option<(Tag<'_>, usize)> {
+    let is_name_char = |c: char| !c.is_whitespace() && !matches!(c, '/' | '>' | '=' | '<');
+    let name_end = |from: usize| {
+        content[from..]
+            .find(|c: char| !is_name_char(c))
+            .map_or(content.len(), |i| from + i)
+    };
+
+    let end = name_end(pos);
+    let name = &content[pos..end];
+    if name.is_empty() {
+        return None;
+    }
+    pos = end;
+
+    let mut attributes = Vec::new();
+    loop {
+        let rest = &content[pos..];
+        let trimmed = rest.trim_start();
+        pos += rest.len() - trimmed.len();
+
+        if trimmed.starts_with("/>") {
+            let tag = Tag::Start {
+                name,
+                attributes,
+                self_closing: true,
+            };
+            return Some((tag, pos + 2));
+        }
+        if trimmed.starts_with('>') {
+            let tag = Tag::Start {
+                name,
+                attributes,
+                self_closing: false,
+            };
+            return Some((tag, pos + 1));
+        }
+
+        let end = name_end(pos);
+        let attribute = &content[pos..end];
+        if attribute.is_empty() {
+            return None;
+        }
+        pos = end;
+
+        let rest = content[pos..].trim_start().strip_prefix('=')?.trim_start();
+        let quote = rest.chars().next().filter(|c| matches!(c, '"' | '\''))?;
+        let value_start = content.len() - rest.len() + 1;
+        let value_len = content[value_start..].find(quote)?;
+        attributes.push(Attribute {
+            name: attribute,
+            value: &content[value_start..value_start + value_len],
+            value_start,
+        });
+        pos = value_start + value_len + 1;
+    }
+}
+
+/// Converts byte offsets to LSP positions (UTF-16 columns)
+pub struct LineIndex {
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+    /// Index the line starts of `content`
+    pub fn new(content: &str) -> Self {
+        Self {
+            line_starts: std::iter::once(0)
+                .chain(content.match_indices('\n').map(|(i, _)| i + 1))
+                .collect(),
+        }
+    }
+
+    /// Position of a byte offset of `content`
+    pub fn position(&self, content: &str, offset: usize) -> Position {
+        let line = self
+            .line_starts
+            .partition_point(|&start| start <= offset)
+            .saturating_sub(1);
+        let line_start = self.line_starts.get(line).copied().unwrap_or(0);
+        let character = content
+            .get(line_start..offset)
+            .map_or(0, |before| before.encode_utf16().count());
+        Position::new(line as u32, character as u32)
+    }
+}
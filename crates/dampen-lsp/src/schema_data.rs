@@ -28,6 +28,8 @@ It's one of the fundamental layout containers in Dampen.\n\n\
 **Style Attributes:**\n\
 - `background` - Background color (hex or CSS color name)\n\
 - `border_radius` - Corner rounding\n\n\
+**Loading:**\n\
+- `loading` - While true, the children are replaced by a shimmering `skeleton` (supports bindings)\n\n\
 ## Example\n\n\
 ```xml\n\
 <column spacing=\"10px\" padding=\"20px\" align_items=\"center\">\n\
@@ -58,6 +60,8 @@ Use it for toolbars, button groups, or any horizontal layout needs.\n\n\
 **Style Attributes:**\n\
 - `background` - Background color\n\
 - `border_radius` - Corner rounding\n\n\
+**Loading:**\n\
+- `loading` - While true, the children are replaced by a shimmering `skeleton` (supports bindings)\n\n\
 ## Example\n\n\
 ```xml\n\
 <row spacing=\"10px\" align_items=\"center\">\n\
@@ -89,6 +93,8 @@ It's useful for adding visual styling or spacing around content.\n\n\
 - `background` - Background color\n\
 - `border_color`, `border_width`, `border_radius` - Border styling\n\
 - `shadow` - Box shadow effect\n\n\
+**Loading:**\n\
+- `loading` - While true, the children are replaced by a shimmering `skeleton` (supports bindings)\n\n\
 ## Example\n\n\
 ```xml\n\
 <container \n\
@@ -135,6 +141,56 @@ The `text` widget renders a string of text with support for styling, sizing, and
 - `text_input` - Editable text field",
     );
 
+    docs.insert(
+        "markdown",
+        "# Markdown Widget\n\n\
+Renders a document written in a safe subset of CommonMark.\n\n\
+## Description\n\n\
+The `markdown` widget displays headings, paragraphs, lists, code blocks and links. \
+HTML, images and tables are shown as-is, so untrusted content can be displayed safely. \
+Sizes and fonts follow the theme typography.\n\n\
+## Required Attributes\n\n\
+- `src` - Markdown source, usually bound to a model field\n\n\
+## Optional Attributes\n\n\
+- `size` - Size of paragraphs (default: theme base size)\n\
+- `font` - Font family (default: theme family)\n\
+- `scale_ratio` - Ratio between heading levels (default: theme ratio or 1.25)\n\
+- `color` - Text color\n\n\
+## Event Attributes\n\n\
+- `on_link` - Triggered when a link is clicked, with its URL\n\n\
+## Example\n\n\
+```xml\n\
+<scrollable>\n\
+    <markdown src=\"{readme}\" on_link=\"open_url\"/>\n\
+</scrollable>\n\
+```\n\n\
+## See Also\n\n\
+- `text` - Inline Markdown with `markdown=\"true\"`",
+    );
+
+    docs.insert(
+        "code_editor",
+        "# Code Editor Widget\n\n\
+A multi-line text area for source code.\n\n\
+## Description\n\n\
+The `code_editor` widget shows code in a monospace font with line numbers and syntax highlighting. \
+Each edit sends the whole text to `on_change`; without it, the editor is read-only.\n\n\
+## Optional Attributes\n\n\
+- `value` - Code shown, usually bound to a model field\n\
+- `language` - Syntect language name or file extension, e.g. `rust`, `py`, `toml` or `dampen` (default: plain text)\n\
+- `size` - Text size (default: 14)\n\
+- `line_numbers` - Whether line numbers are shown (default: true)\n\
+- `height` - Height, longer code scrolls (default: fits the code)\n\n\
+## Event Attributes\n\n\
+- `on_change` - Triggered after each edit, with the whole text\n\n\
+## Example\n\n\
+```xml\n\
+<code_editor language=\"rust\" value=\"{source}\" on_change=\"update_source\" height=\"300\"/>\n\
+```\n\n\
+## See Also\n\n\
+- `text_input` - Single-line text field",
+    );
+
     docs.insert(
         "button",
         "# Button Widget\n\n\
@@ -206,6 +262,10 @@ The `text_input` widget provides an editable text field where users can type and
 - `placeholder` - Hint text shown when empty\n\
 - `value` - Initial text value\n\
 - `password` - Mask input as password (true/false)\n\
+- `mask` - `password` hides the typed characters\n\
+- `max_length` - Maximum number of characters\n\
+- `filter` - Allowed input: `numeric`, `alpha`, `alphanumeric` or `regex:'pattern'`\n\
+- `debounce_ms` - Dispatch `on_input` only after typing paused for this many milliseconds\n\
 - `icon` - Icon to display in the input\n\
 - `size` - Font size\n\n\
 ## Event Attributes\n\n\
@@ -230,6 +290,32 @@ The `text_input` widget provides an editable text field where users can type and
 - `checkbox` - Boolean input",
     );
 
+    docs.insert(
+        "number_input",
+        "# NumberInput Widget\n\n\
+A numeric field with step buttons.\n\n\
+## Description\n\n\
+The `number_input` widget shows a text field followed by `-` and `+` buttons. \
+Typed values are clamped to `min` and `max`; edits that are not a number are ignored.\n\n\
+## Optional Attributes\n\n\
+- `value` - Current number\n\
+- `min` - Smallest accepted value\n\
+- `max` - Largest accepted value\n\
+- `step` - Amount added or removed by the buttons (default 1)\n\
+- `decimals` - Fractional digits, defaulting to those of `step`\n\
+- `placeholder` - Hint text shown when empty\n\
+- `disabled` - Disable editing (true/false)\n\n\
+## Event Attributes\n\n\
+- `on_change` - Called with the new number (`i64`, `f64`, ...)\n\n\
+## Example\n\n\
+```xml\n\
+<number_input value=\"{qty}\" min=\"0\" max=\"99\" on_change=\"set_qty\"/>\n\
+```\n\n\
+## See Also\n\n\
+- `slider` - Numeric range selection\n\
+- `text_input` - Free text input",
+    );
+
     docs.insert(
         "checkbox",
         "# Checkbox Widget\n\n\
@@ -266,10 +352,12 @@ A slider for numeric input within a range.\n\n\
 ## Description\n\n\
 The `slider` widget provides a draggable slider for selecting numeric values within a defined range.\n\n\
 ## Optional Attributes\n\n\
-- `min` - Minimum value (default: 0)\n\
-- `max` - Maximum value (default: 100)\n\
+- `min` - Minimum value (default: 0, supports bindings)\n\
+- `max` - Maximum value (default: 100, supports bindings)\n\
 - `value` - Current value\n\
-- `step` - Step increment for discrete values\n\n\
+- `step` - Step increment for discrete values (supports bindings)\n\
+- `show_value` - Show the value next to the slider (true/false)\n\
+- `value_format` - Filter formatting the shown value, e.g. `decimal(1)`\n\n\
 ## Event Attributes\n\n\
 - `on_change` - Triggered when value changes\n\
 - `on_release` - Triggered when user releases the slider\n\n\
@@ -296,9 +384,12 @@ A container that allows scrolling when content overflows.\n\n\
 ## Description\n\n\
 The `scrollable` widget wraps content and enables scrolling when the content exceeds the available space.\n\n\
 ## Optional Attributes\n\n\
-- `direction` - Scroll direction: \"vertical\", \"horizontal\", \"both\"\n\n\
+- `direction` - Scroll direction: \"vertical\", \"horizontal\", \"both\"\n\
+- `offset` - Vertical offset in pixels, applied when the bound value changes\n\
+- `stick_to_bottom` - Follow new content at the bottom until the user scrolls up\n\n\
 ## Event Attributes\n\n\
-- `on_scroll` - Triggered when scroll position changes\n\n\
+- `on_scroll` - Triggered when scroll position changes, with a `ScrollEvent`\n\
+- `on_reach_end` - Triggered when scrolled near the bottom, within `reach_end_distance` pixels\n\n\
 ## Style Attributes\n\n\
 - All standard layout and style attributes\n\n\
 ## Example\n\n\
@@ -347,8 +438,10 @@ A dropdown list for selecting from options.\n\n\
 The `pick_list` widget provides a dropdown menu where users can select one option from a list.\n\n\
 ## Optional Attributes\n\n\
 - `placeholder` - Text shown when no selection\n\
-- `selected` - Currently selected value\n\
-- `options` - List of available options\n\n\
+- `selected` - Value of the selected option\n\
+- `options` - Comma-separated options, or a binding to a collection\n\
+- `display` - Text of each bound option, e.g. `{item.name}`\n\
+- `value` - Value of each bound option, sent to `on_select`, e.g. `{item.code}`\n\n\
 ## Event Attributes\n\n\
 - `on_select` - Triggered when selection changes\n\n\
 ## Style Attributes\n\n\
@@ -467,10 +560,42 @@ The `radio` widget provides a radio button for selecting one option from a mutua
 </column>\n\
 ```\n\n\
 ## See Also\n\n\
+- `radio_group` - Radios sharing one selection\n\
 - `checkbox` - Multi-select toggle\n\
 - `pick_list` - Dropdown selection",
     );
 
+    docs.insert(
+        "radio_group",
+        "# RadioGroup Widget\n\n\
+A group of radio buttons selecting one value.\n\n\
+## Description\n\n\
+The `radio_group` widget renders one radio per option and sends the value of the chosen one. \
+Options come from `options` or from `<radio>` children, which take the group's selection and handler.\n\n\
+## Optional Attributes\n\n\
+- `value` - Value of the selected option\n\
+- `options` - Comma-separated options, or a binding to a collection\n\
+- `display` - Text of each bound option, e.g. `{item.label}`\n\
+- `direction` - `vertical` (default) or `horizontal`\n\
+- `disabled` - Whether the radios are disabled\n\n\
+## Event Attributes\n\n\
+- `on_select` - Triggered with the value of the selected option\n\n\
+## Style Attributes\n\n\
+- All standard layout and style attributes\n\n\
+## Example\n\n\
+```xml\n\
+<radio_group value=\"{plan}\" options=\"{plans}\" on_select=\"set_plan\"/>\n\
+\n\
+<radio_group value=\"{size}\" direction=\"horizontal\" on_select=\"set_size\">\n\
+    <radio label=\"Small\" value=\"s\"/>\n\
+    <radio label=\"Large\" value=\"l\"/>\n\
+</radio_group>\n\
+```\n\n\
+## See Also\n\n\
+- `radio` - Single radio button\n\
+- `pick_list` - Dropdown selection",
+    );
+
     docs.insert(
         "combobox",
         "# ComboBox Widget\n\n\
@@ -479,9 +604,10 @@ A combination of text input and dropdown list.\n\n\
 The `combobox` widget combines a text input with a dropdown, allowing users to either type a value or select from predefined options.\n\n\
 ## Optional Attributes\n\n\
 - `placeholder` - Hint text\n\
-- `value` - Current text value\n\
-- `selected` - Currently selected option\n\
-- `options` - Available options list\n\n\
+- `selected` - Value of the selected option\n\
+- `options` - Comma-separated options, or a binding to a collection\n\
+- `display` - Text of each bound option, e.g. `{item.name}`\n\
+- `value` - Value of each bound option, sent to `on_select`, e.g. `{item.code}`\n\n\
 ## Event Attributes\n\n\
 - `on_input` - Triggered when text changes\n\
 - `on_select` - Triggered when option selected\n\n\
@@ -491,7 +617,6 @@ The `combobox` widget combines a text input with a dropdown, allowing users to e
 ```xml\n\
 <combobox \n\
     placeholder=\"Type or select...\"\n\
-    value=\"{|search_text|}\"\n\
     options=\"{|suggestions|}\"\n\
     on_input=\"update_search\"\n\
     on_select=\"select_option\"/>\n\
@@ -509,8 +634,10 @@ Displays progress as a horizontal bar.\n\n\
 The `progress_bar` widget shows a visual representation of progress, typically from 0% to 100%.\n\n\
 ## Optional Attributes\n\n\
 - `value` - Current progress value (supports bindings)\n\
-- `min` - Minimum value (default: 0)\n\
-- `max` - Maximum value (default: 100)\n\
+- `min` - Minimum value (default: 0, supports bindings)\n\
+- `max` - Maximum value (default: 100, supports bindings)\n\
+- `show_value` - Show the value next to the bar (true/false)\n\
+- `value_format` - Filter formatting the shown value, e.g. `decimal(0)`\n\
 - `style` - Predefined style: `primary`, `success`, `warning`, `danger`, `secondary`\n\
 - `bar_color` - Custom bar color (supports bindings). Formats: `#RRGGBB`, `#RRGGBBAA`, `rgb(r,g,b)`, `rgba(r,g,b,a)`\n\
 - `background_color` - Custom background color. Formats: `#RRGGBB`, `#RRGGBBAA`, `rgb(r,g,b)`, `rgba(r,g,b,a)`\n\
@@ -537,6 +664,29 @@ The `progress_bar` widget shows a visual representation of progress, typically f
 - `slider` - Interactive value selector",
     );
 
+    docs.insert(
+        "skeleton",
+        "# Skeleton Widget\n\n\
+Shimmering placeholder lines shown while content loads.\n\n\
+## Description\n\n\
+The `skeleton` widget draws rounded bars, swept by a shimmer, where text will appear. \
+Containers with a `loading` attribute show one in place of their children.\n\n\
+## Optional Attributes\n\n\
+- `lines` - Number of lines (default: 3)\n\
+- `width` - Width of the lines: fixed pixels, `fill` (default), `shrink`, or percentage\n\n\
+## Example\n\n\
+```xml\n\
+<if condition=\"{is_loading}\">\n\
+    <skeleton lines=\"2\" width=\"240\"/>\n\
+</if>\n\
+<column loading=\"{is_loading}\">\n\
+    <text value=\"{user.name}\"/>\n\
+</column>\n\
+```\n\n\
+## See Also\n\n\
+- `progress_bar` - Determinate progress",
+    );
+
     docs.insert(
         "tooltip",
         "# Tooltip Widget\n\n\
@@ -544,17 +694,27 @@ Shows a tooltip message when hovering over its child.\n\n\
 ## Description\n\n\
 The `tooltip` widget wraps another widget and displays a popup message when the user hovers over it.\n\n\
 ## Optional Attributes\n\n\
-- `message` - Tooltip text content\n\
-- `position` - Tooltip position: \"top\", \"bottom\", \"left\", \"right\"\n\
-- `delay` - Delay before showing (in milliseconds)\n\n\
+- `message` - Tooltip text content (or a `<tooltip_content>` child)\n\
+- `position` - Tooltip position: \"top\", \"bottom\", \"left\", \"right\", \"follow_cursor\" (default)\n\
+- `delay_ms` - Delay before showing (in milliseconds)\n\n\
 ## Event Attributes\n\n\
 - All standard events from child\n\n\
 ## Example\n\n\
 ```xml\n\
-<tooltip message=\"Click to save\" position=\"top\">\n\
+<tooltip message=\"Click to save\" position=\"top\" delay_ms=\"500\">\n\
     <button label=\"Save\" on_click=\"save\"/>\n\
 </tooltip>\n\
 ```\n\n\
+Rich content replaces the message:\n\n\
+```xml\n\
+<tooltip>\n\
+    <button label=\"Delete\" on_click=\"delete\"/>\n\
+    <tooltip_content padding=\"8\" background=\"#333333\">\n\
+        <icon name=\"warning\" tint=\"@warning\"/>\n\
+        <text value=\"Deletes all items\"/>\n\
+    </tooltip_content>\n\
+</tooltip>\n\
+```\n\n\
 ## See Also\n\n\
 - `container` - Visual wrapper\n\
 - `float` - Positioned overlay",
@@ -635,7 +795,9 @@ The `svg` widget renders Scalable Vector Graphics files.\n\n\
 ## Optional Attributes\n\n\
 - `width` - Display width\n\
 - `height` - Display height\n\
-- `path` - Alternative to src for inline SVG\n\n\
+- `path` - Alternative to src for inline SVG\n\
+- `content` - SVG markup (an inline `<svg>` child is used as content)\n\
+- `tint` - Recolor color, or theme color such as `@primary`\n\n\
 ## Style Attributes\n\n\
 - All standard layout attributes\n\n\
 ## Example\n\n\
@@ -647,6 +809,25 @@ The `svg` widget renders Scalable Vector Graphics files.\n\n\
 - `canvas` - Custom drawing",
     );
 
+    docs.insert(
+        "icon",
+        "# Icon Widget\n\n\
+Displays an icon from the built-in icon set.\n\n\
+## Description\n\n\
+The `icon` widget renders a monochrome icon by name, drawn with the theme's text color unless tinted.\n\n\
+## Required Attributes\n\n\
+- `name` - Icon name (e.g. `trash`, `edit`, `search`, `settings`)\n\n\
+## Optional Attributes\n\n\
+- `size` - Icon size in pixels (default: 16)\n\
+- `tint` - Color, or theme color such as `@primary`\n\n\
+## Example\n\n\
+```xml\n\
+<icon name=\"trash\" size=\"16\" tint=\"@danger\"/>\n\
+```\n\n\
+## See Also\n\n\
+- `svg` - Custom vector graphics",
+    );
+
     docs.insert(
         "date_picker",
         "# DatePicker Widget\n\n\
@@ -659,9 +840,12 @@ The `date_picker` widget provides a calendar interface for selecting dates.\n\n\
 - `show` - Whether picker is visible\n\
 - `min_date` - Minimum selectable date\n\
 - `max_date` - Maximum selectable date\n\n\
+Without a child, the picker shows a button with its date.\n\n\
 ## Event Attributes\n\n\
-- `on_submit` - Date confirmed\n\
-- `on_cancel` - Selection cancelled\n\n\
+- `on_change` - Called with the picked date (`chrono::NaiveDate`)\n\
+- `on_submit` - Date confirmed, as a `YYYY-MM-DD` string\n\
+- `on_cancel` - Selection cancelled\n\
+- `on_open` - The date button was pressed, e.g. to set `show`\n\n\
 ## Style Attributes\n\n\
 - All standard layout and style attributes\n\n\
 ## Example\n\n\
@@ -856,18 +1040,29 @@ The `context_menu` widget provides a popup menu that appears when the user right
     docs.insert(
         "float",
         "# Float Widget\n\n\
-A floating container that can be positioned freely.\n\n\
+A floating container shown over other content.\n\n\
 ## Description\n\n\
-The `float` widget creates a positioned overlay that floats above other content. \
-Useful for modals, popups, and positioned elements.\n\n\
+With an `anchor`, the `float` widget is a popover shown next to the widget with \
+this id, such as a dropdown menu. It is flipped to the opposite side of the anchor \
+when it would leave the window, and kept within the window.\n\n\
+With a `layer`, it is a portal rendered over the whole window in that layer, \
+above the layers below it: `content` < `dropdown` < `modal` < `toast` < `dev-overlay`.\n\n\
 ## Attributes\n\n\
+- `anchor` - Id of the widget the float is shown next to\n\
+- `layer` - Window layer of a portal float: `dropdown`, `modal` or `toast`\n\
+- `placement` - Side of the anchor and alignment: `bottom` (default), `top`, `left`, \
+`right`, optionally followed by `-start` or `-end` (e.g. `bottom-start`)\n\
+- `gap` - Distance from the anchor in pixels (default: 4)\n\
+- `show` - Whether the float is visible (default: true)\n\
 - Standard layout attributes\n\n\
 ## Example\n\n\
 ```xml\n\
-<float top=\"100px\" left=\"100px\">\n\
-    <container padding=\"20px\" background=\"white\">\n\
-        <text value=\"Floating Content\"/>\n\
-    </container>\n\
+<button id=\"menu_button\" label=\"Options\" on_click=\"toggle_menu\"/>\n\
+<float anchor=\"menu_button\" placement=\"bottom-start\" show=\"{menu_open}\">\n\
+    <column>\n\
+        <button label=\"Rename\" on_click=\"rename\"/>\n\
+        <button label=\"Delete\" on_click=\"delete\"/>\n\
+    </column>\n\
 </float>\n\
 ```\n\n\
 ## See Also\n\n\
@@ -1002,6 +1197,35 @@ The `tree_node` widget represents a single node within a tree view hierarchy.\n\
 - `text` - Text display",
     );
 
+    docs.insert(
+        "tree",
+        "# Tree Widget\n\n\
+Nested model items shown as rows that expand and collapse.\n\n\
+## Description\n\n\
+The `tree` widget shows a list of items and their children, e.g. files or settings. \
+Children are loaded lazily: an item whose children field is `None` can be expanded, \
+and `on_expand` receives its key so the handler can load them. \
+Once focused, the tree is navigated with the arrow keys, Home, End, Enter and Space.\n\n\
+## Required Attributes\n\n\
+- `items` - Binding to the top-level items\n\
+- `label` - Text of a node, e.g. `{node.name}`\n\n\
+## Optional Attributes\n\n\
+- `children` - Field holding the children of an item (default: `children`)\n\
+- `each` - Name of the item in `label` and `key` (default: `node`)\n\
+- `key` - Key of a node (default: its position, e.g. `0.2`)\n\
+- `indent` - Indentation of each level (default: 16)\n\
+- `size` - Text size (default: 14)\n\n\
+## Event Attributes\n\n\
+- `on_expand` - Triggered when a node is expanded, with its key\n\
+- `on_select` - Triggered when a node is clicked or selected with Enter, with its key\n\n\
+## Example\n\n\
+```xml\n\
+<tree id=\"files\" items=\"{nodes}\" children=\"children\" label=\"{node.name}\" on_expand=\"load_children\"/>\n\
+```\n\n\
+## See Also\n\n\
+- `for` - Flat lists of items",
+    );
+
     docs.insert(
         "rect",
         "# Rect Widget (Canvas)\n\n\
@@ -1118,7 +1342,8 @@ The `group` widget groups multiple canvas shapes together, applying a shared tra
 - `rect` - Rectangle shapes\n\
 - `circle` - Circle shapes\n\
 - `line` - Line shapes\n\
-- `canvas_text` - Text elements\n\n\
+- `canvas_text` - Text elements\n\
+- `path`, `arc`, `bezier` - Curves and free-form paths\n\n\
 ## Example\n\n\
 ```xml\n\
 <canvas width=\"400\" height=\"300\">\n\
@@ -1132,6 +1357,108 @@ The `group` widget groups multiple canvas shapes together, applying a shared tra
 - `canvas` - Drawing surface",
     );
 
+    docs.insert(
+        "path",
+        "# Path Widget (Canvas)\n\n\
+A free-form path for canvas drawing.\n\n\
+## Description\n\n\
+The `path` widget draws a shape described by SVG-like path data within a `canvas` element.\n\n\
+## Required Attributes\n\n\
+- `d` - Path data: `M`, `L`, `H`, `V`, `Q`, `C` and `Z` commands (lowercase for relative coordinates)\n\n\
+## Optional Attributes\n\n\
+- `fill` - Fill color\n\
+- `stroke` - Stroke color\n\
+- `stroke_width` - Stroke thickness\n\n\
+## Example\n\n\
+```xml\n\
+<canvas width=\"400\" height=\"300\">\n\
+    <path d=\"M10 10 L50 80 C60 90 80 90 90 80 Z\" fill=\"#3498db\" stroke=\"black\"/>\n\
+</canvas>\n\
+```\n\n\
+## See Also\n\n\
+- `canvas` - Drawing surface\n\
+- `bezier` - Single curve",
+    );
+
+    docs.insert(
+        "arc",
+        "# Arc Widget (Canvas)\n\n\
+A circular arc for canvas drawing.\n\n\
+## Description\n\n\
+The `arc` widget draws part of a circle within a `canvas` element. Angles are in radians, clockwise from the positive x-axis.\n\n\
+## Required Attributes\n\n\
+- `cx` - Center X coordinate\n\
+- `cy` - Center Y coordinate\n\
+- `radius` - Arc radius\n\
+- `start_angle` - Start angle in radians\n\
+- `end_angle` - End angle in radians\n\n\
+## Optional Attributes\n\n\
+- `fill` - Fill color (fills the area between the arc and its chord)\n\
+- `stroke` - Stroke color\n\
+- `stroke_width` - Stroke thickness\n\n\
+## Example\n\n\
+```xml\n\
+<canvas width=\"400\" height=\"300\">\n\
+    <arc cx=\"100\" cy=\"100\" radius=\"50\" start_angle=\"0\" end_angle=\"3.14\" stroke=\"red\" stroke_width=\"4\"/>\n\
+</canvas>\n\
+```\n\n\
+## See Also\n\n\
+- `canvas` - Drawing surface\n\
+- `circle` - Full circle",
+    );
+
+    docs.insert(
+        "bezier",
+        "# Bezier Widget (Canvas)\n\n\
+A Bézier curve for canvas drawing.\n\n\
+## Description\n\n\
+The `bezier` widget draws a curve within a `canvas` element. It is cubic when `cx2`/`cy2` are set, quadratic otherwise.\n\n\
+## Required Attributes\n\n\
+- `x1`, `y1` - Start point\n\
+- `cx1`, `cy1` - First control point\n\
+- `x2`, `y2` - End point\n\n\
+## Optional Attributes\n\n\
+- `cx2`, `cy2` - Second control point\n\
+- `fill` - Fill color\n\
+- `stroke` - Stroke color\n\
+- `stroke_width` - Stroke thickness\n\n\
+## Example\n\n\
+```xml\n\
+<canvas width=\"400\" height=\"300\">\n\
+    <bezier x1=\"0\" y1=\"100\" cx1=\"100\" cy1=\"0\" cx2=\"200\" cy2=\"200\" x2=\"300\" y2=\"100\" stroke=\"black\"/>\n\
+</canvas>\n\
+```\n\n\
+## See Also\n\n\
+- `canvas` - Drawing surface\n\
+- `path` - Free-form path",
+    );
+
+    docs.insert(
+        "chart",
+        "# Chart Widget\n\n\
+A line, bar or pie chart bound to a collection.\n\n\
+## Description\n\n\
+The `chart` widget draws one point, bar or slice per item of `data`. Colors follow the current theme unless `color` is set.\n\n\
+## Required Attributes\n\n\
+- `data` - Binding to the collection to plot\n\n\
+## Optional Attributes\n\n\
+- `type` - `line` (default), `bar` or `pie`\n\
+- `x` - Field of each item used as its label (defaults to the index)\n\
+- `y` - Numeric field of each item (defaults to the item itself)\n\
+- `label` - Series name shown in the legend (defaults to `y`)\n\
+- `color` - Series color\n\
+- `show_axes` - Show axes and their labels (default: true)\n\
+- `show_legend` - Show the legend (default: false)\n\
+- `width`, `height` - Size of the chart\n\n\
+## Example\n\n\
+```xml\n\
+<chart type=\"line\" data=\"{points}\" x=\"time\" y=\"value\" show_legend=\"true\"/>\n\
+```\n\n\
+## See Also\n\n\
+- `canvas` - Drawing surface for custom graphics\n\
+- `data_table` - Tabular view of a collection",
+    );
+
     docs.insert(
         "for",
         "# For Widget\n\n\
@@ -1327,6 +1654,43 @@ Sets the direction of flex layout.\n\n\
 - `column_reverse` - Bottom to top",
     );
 
+    docs.insert(
+        "wrap",
+        "**wrap** - Wrap children onto multiple lines\n\n\
+Type: `boolean`\n\n\
+On `row` and `column`. Children that do not fit start a new line (row) or a new \
+column (column).\n\n\
+Example: `<row wrap=\"true\" spacing=\"8\">`",
+    );
+
+    docs.insert(
+        "grow",
+        "**grow** - Share of the free space in the parent row or column\n\n\
+Type: `integer` (1-255)\n\n\
+Along the main axis of the parent, children share the free space in proportion \
+to their `grow`.\n\n\
+Example: `<text_input grow=\"2\" />`",
+    );
+
+    docs.insert(
+        "aspect_ratio",
+        "**aspect_ratio** - Ratio of the width to the height\n\n\
+Type: `string` (\"16:9\", \"4/3\" or a number)\n\n\
+On `container`. The height follows the width, so media areas and thumbnails \
+keep their proportions when the window is resized.\n\n\
+Example: `<container width=\"fill\" aspect_ratio=\"16:9\">`",
+    );
+
+    docs.insert(
+        "overflow",
+        "**overflow** - Handling of content larger than the widget\n\n\
+Type: `enum` (\"visible\", \"clip\", \"scroll\")\n\n\
+On `container`.\n\n\
+- `visible` - Content is drawn past the bounds (default)\n\
+- `clip` - Content is cut at the bounds\n\
+- `scroll` - Content scrolls vertically",
+    );
+
     docs.insert(
         "position",
         "**position** - Positioning mode\n\n\
@@ -1459,6 +1823,47 @@ Sets the style of the border line.\n\n\
 Example: `border_style=\"dashed\"`",
     );
 
+    docs.insert(
+        "border_top",
+        "**border_top** - Top border\n\n\
+Type: `width color`\n\n\
+Draws a border on the top side only, over the border of the widget.\n\n\
+Example: `border_top=\"1 #CCCCCC\"`",
+    );
+
+    docs.insert(
+        "border_right",
+        "**border_right** - Right border\n\n\
+Type: `width color`\n\n\
+Draws a border on the right side only, over the border of the widget.\n\n\
+Example: `border_right=\"1 #CCCCCC\"`",
+    );
+
+    docs.insert(
+        "border_bottom",
+        "**border_bottom** - Bottom border\n\n\
+Type: `width color`\n\n\
+Draws a border on the bottom side only, over the border of the widget.\n\n\
+Example: `border_bottom=\"1 #CCCCCC\"`",
+    );
+
+    docs.insert(
+        "border_left",
+        "**border_left** - Left border\n\n\
+Type: `width color`\n\n\
+Draws a border on the left side only, over the border of the widget.\n\n\
+Example: `border_left=\"1 #CCCCCC\"`",
+    );
+
+    docs.insert(
+        "outline",
+        "**outline** - Outline\n\n\
+Type: `width color [offset]`\n\n\
+Draws a line around the widget, outside of its bounds and without affecting \
+layout. Use it in a `focus:` state for focus rings.\n\n\
+Example: `focus:outline=\"2 #3498DB 1\"`",
+    );
+
     docs.insert(
         "shadow",
         "**shadow** - Box shadow\n\n\
@@ -1468,6 +1873,15 @@ Format: `offset-x offset-y blur-radius color`\n\n\
 Example: `shadow=\"0 4px 8px rgba(0,0,0,0.2)\"`",
     );
 
+    docs.insert(
+        "elevation",
+        "**elevation** - Depth level\n\n\
+Type: `integer` (0 to 5)\n\n\
+Draws the shadow of this level, as defined by the `<shadows>` of the theme. \
+An explicit `shadow` takes precedence.\n\n\
+Example: `elevation=\"2\"`",
+    );
+
     docs.insert(
         "shadow_color",
         "**shadow_color** - Shadow color\n\n\
@@ -1652,6 +2066,30 @@ Triggered when the widget is scrolled.\n\n\
 Example: `on_scroll=\"handle_scroll\"`",
     );
 
+    docs.insert(
+        "on_reach_end",
+        "**on_reach_end** - Reach end event handler\n\n\
+Type: `event_binding`\n\n\
+Triggered when a scrollable is scrolled near its bottom, within `reach_end_distance` pixels (default 100).\n\n\
+Example: `on_reach_end=\"load_more\"`",
+    );
+
+    docs.insert(
+        "on_expand",
+        "**on_expand** - Expand event handler\n\n\
+Type: `event_binding`\n\n\
+Triggered when a node of a `<tree>` is expanded, with the key of the node, so its children can be loaded.\n\n\
+Example: `on_expand=\"load_children\"`",
+    );
+
+    docs.insert(
+        "page_size",
+        "**page_size** - Items per page\n\n\
+Type: `integer`\n\n\
+Renders only one page of a `<for>` collection, selected by `page` (starting at 0).\n\n\
+Example: `page_size=\"20\" page=\"{current_page}\"`",
+    );
+
     docs.insert(
         "on_paste",
         "**on_paste** - Paste event handler\n\n\
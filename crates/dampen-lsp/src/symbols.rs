@@ -0,0 +1,226 @@
+//! Named symbols of `.dampen` files.
+//!
+//! Style classes, themes, event handlers and widget ids are referred to by name
+//! across views. This module finds every occurrence of these names in a file,
+//! with the exact range of the name so that it can be listed as a reference or
+//! replaced by a rename.
+//!
+//! Files are scanned as text rather than parsed: a view with errors, or one
+//! using a widget the parser does not know, still yields its symbols.
+
+use tower_lsp::lsp_types::{Position, Range};
+
+use crate::markup::{LineIndex, Tag, tags};
+
+/// Kinds of named symbols.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SymbolKind {
+    /// Style class, defined by `<style name="...">` and used in `class` and `extends`
+    Class,
+    /// Theme, defined by `<theme name="...">` and used by `theme`, `extends` and
+    /// `<global_theme>`
+    Theme,
+    /// Event handler, used by `on_*` attributes and declared in Rust
+    Handler,
+    /// Widget id, defined by `id` and used by `anchor` and `described_by`
+    Id,
+}
+
+/// An occurrence of a symbol name in a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Occurrence {
+    /// Kind of the symbol
+    pub kind: SymbolKind,
+    /// Name of the symbol
+    pub name: String,
+    /// Range of the name
+    pub range: Range,
+    /// Whether this occurrence defines the symbol
+    pub is_definition: bool,
+}
+
+/// Finds every symbol occurrence of a `.dampen` file, in source order.
+pub fn scan(content: &str) -> Vec<Occurrence> {
+    let lines = LineIndex::new(content);
+    let mut occurrences = Vec::new();
+    let mut open_tags: Vec<&str> = Vec::new();
+
+    for tag in tags(content) {
+        match tag {
+            Tag::End => {
+                open_tags.pop();
+            }
+            Tag::Start {
+                name,
+                attributes,
+                self_closing,
+            } => {
+                let parent = open_tags.last().copied();
+                for attribute in &attributes {
+                    if let Some((kind, is_definition)) = classify(name, parent, attribute.name) {
+                        for (offset, value) in names(kind, attribute.name, attribute.value) {
+                            let start = attribute.value_start + offset;
+                            occurrences.push(Occurrence {
+                                kind,
+                                name: value.to_string(),
+                                range: Range {
+                                    start: lines.position(content, start),
+                                    end: lines.position(content, start + value.len()),
+                                },
+                                is_definition,
+                            });
+                        }
+                    }
+                }
+                if !self_closing {
+                    open_tags.push(name);
+                }
+            }
+        }
+    }
+
+    occurrences
+}
+
+/// The occurrence whose name contains `position`, if any.
+pub fn symbol_at(occurrences: &[Occurrence], position: Position) -> Option<&Occurrence> {
+    occurrences
+        .iter()
+        .find(|occurrence| occurrence.range.start <= position && position <= occurrence.range.end)
+}
+
+/// Kind of symbol named by an attribute of an element, and whether it defines it
+fn classify(tag: &str, parent: Option<&str>, attribute: &str) -> Option<(SymbolKind, bool)> {
+    let in_styles = matches!(parent, Some("styles" | "style_classes" | "classes"));
+    match (tag, attribute) {
+        ("style" | "class", "name") if in_styles => Some((SymbolKind::Class, true)),
+        ("style" | "class", "extends") if in_styles => Some((SymbolKind::Class, false)),
+        ("theme", "name") if parent == Some("themes") => Some((SymbolKind::Theme, true)),
+        ("theme", "extends") if parent == Some("themes") => Some((SymbolKind::Theme, false)),
+        ("global_theme" | "default_theme", "name") => Some((SymbolKind::Theme, false)),
+        (_, "class") => Some((SymbolKind::Class, false)),
+        (_, "theme") => Some((SymbolKind::Theme, false)),
+        (_, "id") => Some((SymbolKind::Id, true)),
+        (_, "anchor" | "described_by") => Some((SymbolKind::Id, false)),
+        (_, name) if name.starts_with("on_") => Some((SymbolKind::Handler, false)),
+        _ => None,
+    }
+}
+
+/// Names in an attribute value, with their byte offset in the value
+///
+/// Bound values (`{...}`) name no symbol; a handler is the part of the value
+/// before its parameters (`delete:{item.id}`).
+fn names<'a>(kind: SymbolKind, attribute: &str, value: &'a str) -> Vec<(usize, &'a str)> {
+    let multiple = matches!(attribute, "class" | "extends");
+    let value = match kind {
+        SymbolKind::Handler => value.split(':').next().unwrap_or(value),
+        _ => value,
+    };
+
+    let mut names = Vec::new();
+    let mut offset = 0;
+    for word in value.split_inclusive(|c: char| c.is_whitespace() || c == ',') {
+        let name = word.trim_end_matches(|c: char| c.is_whitespace() || c == ',');
+        let leading = name.len() - name.trim_start().len();
+        let name = name.trim();
+        if !name.is_empty() && !name.contains(['{', '}']) {
+            names.push((offset + leading, name));
+        }
+        offset += word.len();
+    }
+
+    if !multiple && names.len() > 1 {
+        names.clear();
+    }
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEW: &str = r##"<dampen>
+    <themes>
+        <theme name="brand" extends="light">
+            <palette primary="#3498db" />
+        </theme>
+    </themes>
+    <global_theme name="brand" />
+    <styles>
+        <style name="card"><base padding="8" /></style>
+        <style name="card_active" extends="card"><hover background="#eee" /></style>
+    </styles>
+    <!-- <button class="commented" /> -->
+    <column id="main" class="card  card_active">
+        <button label="Delete" on_click="delete:{item.id}" class="{dynamic}" />
+        <float anchor='main' hover:opacity="0.5" />
+    </column>
+</dampen>"##;
+
+    fn found(occurrences: &[Occurrence]) -> Vec<(SymbolKind, &str, bool, u32, u32)> {
+        occurrences
+            .iter()
+            .map(|o| {
+                (
+                    o.kind,
+                    o.name.as_str(),
+                    o.is_definition,
+                    o.range.start.line,
+                    o.range.start.character,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_scan_finds_definitions_and_references() {
+        use SymbolKind::*;
+
+        assert_eq!(
+            found(&scan(VIEW)),
+            vec![
+                (Theme, "brand", true, 2, 21),
+                (Theme, "light", false, 2, 37),
+                (Theme, "brand", false, 6, 24),
+                (Class, "card", true, 8, 21),
+                (Class, "card_active", true, 9, 21),
+                (Class, "card", false, 9, 43),
+                (Id, "main", true, 12, 16),
+                (Class, "card", false, 12, 29),
+                (Class, "card_active", false, 12, 35),
+                (Handler, "delete", false, 13, 41),
+                (Id, "main", false, 14, 23),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_symbol_at_position() {
+        let occurrences = scan(VIEW);
+        let symbol = symbol_at(&occurrences, Position::new(12, 38));
+        assert_eq!(
+            symbol.map(|s| (s.kind, s.name.as_str())),
+            Some((SymbolKind::Class, "card_active"))
+        );
+        assert!(symbol_at(&occurrences, Position::new(12, 5)).is_none());
+    }
+
+    #[test]
+    fn test_scan_stops_at_malformed_markup() {
+        let occurrences = scan(r#"<column id="a"><button on_click="save" <text id="b" />"#);
+        assert_eq!(
+            found(&occurrences),
+            vec![(SymbolKind::Id, "a", true, 0, 12)]
+        );
+    }
+
+    #[test]
+    fn test_positions_count_utf16_units() {
+        let occurrences = scan("<text value=\"😀\" id=\"emoji\" />");
+        assert_eq!(
+            occurrences.first().map(|o| o.range.start.character),
+            Some(21)
+        );
+    }
+}
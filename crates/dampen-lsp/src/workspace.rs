@@ -0,0 +1,442 @@
+//! Workspace index of the Rust side of each view.
+//!
+//! On initialization the server scans the workspace for the `ui/*.rs` modules
+//! that load a view with `#[dampen_ui("window.dampen")]`, and records the
+//! handlers and model fields they declare. Diagnostics then flag events and
+//! bindings of the view that name neither.
+//!
+//! Modules are scanned line by line, as written by `dampen new` and `dampen add`:
+//! a module whose declarations cannot be recognized yields no diagnostics rather
+//! than false ones.
+//!
+//! The index also records the style classes, themes, handlers and widget ids
+//! named in every `.dampen` file, for find-all-references.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use tracing::debug;
+use walkdir::WalkDir;
+
+use crate::symbols::{self, Occurrence, SymbolKind};
+
+/// A name declared in a Rust file, with the line it is declared on (0-based).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Symbol {
+    /// Declared name
+    pub name: String,
+    /// Line of the declaration
+    pub line: u32,
+}
+
+/// The `#[derive(UiModel)]` struct of a UI module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModelDefinition {
+    /// Struct name and line
+    pub symbol: Symbol,
+    /// Fields bindable from the view, under their XML-visible names
+    pub fields: Vec<Symbol>,
+    /// Whether a `#[ui_flatten]` field exposes fields the index does not know
+    pub has_flattened: bool,
+}
+
+impl ModelDefinition {
+    /// Whether `name` is a field of the model, or may be one
+    pub fn has_field(&self, name: &str) -> bool {
+        self.has_flattened || self.fields.iter().any(|field| field.name == name)
+    }
+}
+
+/// Handlers and model declared by the Rust module of a view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UiModule {
+    /// Path of the Rust file
+    pub path: PathBuf,
+    /// Line of the `#[dampen_ui]` attribute
+    pub line: u32,
+    /// `#[ui_handler]` functions, `inventory_handlers!` entries and names registered
+    /// on a `HandlerRegistry`
+    pub handlers: Vec<Symbol>,
+    /// Model of the view, if the module defines exactly one `UiModel` or one named `Model`
+    pub model: Option<ModelDefinition>,
+}
+
+impl UiModule {
+    /// Whether the module declares a handler named `name`
+    pub fn has_handler(&self, name: &str) -> bool {
+        self.handlers.iter().any(|handler| handler.name == name)
+    }
+}
+
+/// UI modules and view symbols of the workspace.
+#[derive(Debug, Default)]
+pub struct WorkspaceIndex {
+    /// UI modules, by the path of the view they load
+    modules: HashMap<PathBuf, UiModule>,
+    /// Symbol occurrences of each `.dampen` file
+    views: HashMap<PathBuf, Vec<Occurrence>>,
+}
+
+impl WorkspaceIndex {
+    /// Scans the Rust files under the `ui` directories of `root`, and every
+    /// `.dampen` file.
+    ///
+    /// `target` and hidden directories are skipped.
+    pub fn scan(root: &Path) -> Self {
+        let mut index = Self::default();
+
+        let files = WalkDir::new(root)
+            .into_iter()
+            .filter_entry(|entry| {
+                let name = entry.file_name().to_string_lossy();
+                entry.depth() == 0 || !(name.starts_with('.') || name == "target")
+            })
+            .filter_map(Result::ok);
+
+        for entry in files {
+            let path = entry.path();
+            let is_view = path.extension().is_some_and(|ext| ext == "dampen");
+            let is_module = path.extension().is_some_and(|ext| ext == "rs")
+                && path
+                    .ancestors()
+                    .any(|dir| dir.file_name().is_some_and(|name| name == "ui"));
+            if !is_view && !is_module {
+                continue;
+            }
+            if let Ok(content) = std::fs::read_to_string(path) {
+                if is_view {
+                    index.update_view(path, &content);
+                } else {
+                    index.update(path, &content);
+                }
+            }
+        }
+
+        debug!(
+            "Indexed {} UI module(s) and {} view(s) under {}",
+            index.modules.len(),
+            index.views.len(),
+            root.display()
+        );
+        index
+    }
+
+    /// Indexes (or re-indexes) the Rust file at `path`.
+    pub fn update(&mut self, path: &Path, content: &str) {
+        self.modules.retain(|_, module| module.path != path);
+        if let Some((view, module)) = parse_ui_module(path, content) {
+            self.modules.insert(view, module);
+        }
+    }
+
+    /// Indexes (or re-indexes) the symbols of the `.dampen` file at `path`.
+    pub fn update_view(&mut self, path: &Path, content: &str) {
+        self.views
+            .insert(path.to_path_buf(), symbols::scan(content));
+    }
+
+    /// Adds the modules and views of another index, e.g. of another workspace folder.
+    pub fn merge(&mut self, other: WorkspaceIndex) {
+        self.modules.extend(other.modules);
+        self.views.extend(other.views);
+    }
+
+    /// The module loading the view at `view_path`, if indexed
+    pub fn module_for(&self, view_path: &Path) -> Option<&UiModule> {
+        self.modules.get(view_path)
+    }
+
+    /// Number of indexed modules
+    pub fn module_count(&self) -> usize {
+        self.modules.len()
+    }
+
+    /// Occurrences of a symbol in the indexed views, ordered by path
+    pub fn occurrences(&self, kind: SymbolKind, name: &str) -> Vec<(&Path, &Occurrence)> {
+        let mut occurrences: Vec<_> = self
+            .views
+            .iter()
+            .flat_map(|(path, view)| {
+                view.iter()
+                    .filter(|o| o.kind == kind && o.name == name)
+                    .map(|o| (path.as_path(), o))
+            })
+            .collect();
+        occurrences.sort_by_key(|(path, o)| (*path, o.range.start));
+        occurrences
+    }
+}
+
+/// Parses a Rust UI module, returning the path of the view it loads and its declarations.
+///
+/// Returns `None` if the file has no `#[dampen_ui("...")]` attribute.
+pub fn parse_ui_module(path: &Path, content: &str) -> Option<(PathBuf, UiModule)> {
+    let mut view = None;
+    let mut handlers = Vec::new();
+    let mut models = Vec::new();
+
+    let lines: Vec<(u32, &str)> = content
+        .lines()
+        .enumerate()
+        .map(|(line, text)| (line as u32, text.trim()))
+        .collect();
+    let mut pending_handler = false;
+    let mut pending_model = false;
+    let mut in_inventory = false;
+    let mut i = 0;
+
+    while i < lines.len() {
+        let (line, text) = lines[i];
+        i += 1;
+
+        if let Some(rest) = text.strip_prefix("#[dampen_ui(") {
+            if let Some(file) = string_literal(rest) {
+                view = Some((file, line));
+            }
+            continue;
+        }
+        if text.starts_with("#[ui_handler") {
+            pending_handler = true;
+            continue;
+        }
+        if text.starts_with("#[derive(") && text.contains("UiModel") {
+            pending_model = true;
+            continue;
+        }
+        if text.starts_with("inventory_handlers!") {
+            in_inventory = true;
+        }
+        if in_inventory {
+            let names = text
+                .trim_start_matches("inventory_handlers!")
+                .split([',', '{', '}', '(', ')', ';'])
+                .map(str::trim)
+                .filter(|name| is_identifier(name));
+            handlers.extend(names.map(|name| Symbol {
+                name: name.to_string(),
+                line,
+            }));
+            in_inventory = !text.contains('}') && !text.contains(')');
+            continue;
+        }
+        if let Some((_, rest)) = text.split_once(".register")
+            && let Some((_, args)) = rest.split_once('(')
+            && let Some(name) = string_literal(args)
+        {
+            handlers.push(Symbol { name, line });
+            continue;
+        }
+        if text.starts_with("#[") || text.starts_with("//") || text.is_empty() {
+            continue;
+        }
+
+        if pending_handler {
+            pending_handler = false;
+            if let Some(name) = item_name(text, "fn ") {
+                handlers.push(Symbol { name, line });
+            }
+        } else if pending_model {
+            pending_model = false;
+            // Unit and single-line structs are not parsed
+            if let Some(name) = item_name(text, "struct ")
+                && text.ends_with('{')
+            {
+                let (fields, has_flattened, end) = parse_fields(&lines, i);
+                i = end;
+                models.push(ModelDefinition {
+                    symbol: Symbol { name, line },
+                    fields,
+                    has_flattened,
+                });
+            }
+        }
+    }
+
+    let (file, line) = view?;
+    let view_path = path.parent().unwrap_or(Path::new("")).join(file);
+    let model = if models.len() == 1 {
+        models.pop()
+    } else {
+        models
+            .into_iter()
+            .find(|model| model.symbol.name == "Model")
+    };
+
+    Some((
+        view_path,
+        UiModule {
+            path: path.to_path_buf(),
+            line,
+            handlers,
+            model,
+        },
+    ))
+}
+
+/// Parses the fields of a struct body starting at `lines[start]`.
+///
+/// Returns the bindable fields, whether one is flattened, and the index of the
+/// line after the body.
+fn parse_fields(lines: &[(u32, &str)], start: usize) -> (Vec<Symbol>, bool, usize) {
+    let mut fields = Vec::new();
+    let mut has_flattened = false;
+    let mut skip = false;
+    let mut bind = false;
+    let mut rename = None;
+
+    for (offset, &(line, text)) in lines[start..].iter().enumerate() {
+        if text.starts_with('}') {
+            return (fields, has_flattened, start + offset + 1);
+        }
+        if text.starts_with("#[ui_skip") {
+            skip = true;
+        } else if text.starts_with("#[ui_bind") {
+            bind = true;
+        } else if text.starts_with("#[ui_flatten") {
+            has_flattened = true;
+            skip = true;
+        } else if let Some(rest) = text.strip_prefix("#[ui_rename") {
+            rename = string_literal(rest);
+        } else if !text.starts_with("#[") && !text.starts_with("//") {
+            let declaration = text
+                .strip_prefix("pub(crate) ")
+                .or_else(|| text.strip_prefix("pub "))
+                .unwrap_or(text);
+            if let Some((name, _)) = declaration.split_once(':')
+                && is_identifier(name.trim())
+            {
+                if !skip || bind {
+                    fields.push(Symbol {
+                        name: rename.take().unwrap_or_else(|| name.trim().to_string()),
+                        line,
+                    });
+                }
+                skip = false;
+                bind = false;
+                rename = None;
+            }
+        }
+    }
+
+    (fields, has_flattened, lines.len())
+}
+
+/// Name of the item declared by `keyword` on `text` (e.g. `pub fn name(`)
+fn item_name(text: &str, keyword: &str) -> Option<String> {
+    let (_, rest) = text.split_once(keyword)?;
+    let name: String = rest
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect();
+    (!name.is_empty()).then_some(name)
+}
+
+/// Content of the first string literal in `text`
+fn string_literal(text: &str) -> Option<String> {
+    let (_, rest) = text.split_once('"')?;
+    let (literal, _) = rest.split_once('"')?;
+    Some(literal.to_string())
+}
+
+fn is_identifier(name: &str) -> bool {
+    name.chars()
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODULE: &str = r#"
+use dampen_macros::{UiModel, dampen_ui, ui_handler};
+
+#[dampen_ui("window.dampen")]
+mod _app {}
+
+#[derive(Default, UiModel, Clone)]
+pub struct Model {
+    pub count: i32,
+    #[ui_skip]
+    pub cache: Vec<u8>,
+    #[ui_rename = "title"]
+    pub window_title: String,
+}
+
+#[ui_handler]
+pub fn increment(model: &mut Model) {
+    model.count += 1;
+}
+
+inventory_handlers! {
+    increment,
+    reset
+}
+
+pub fn create_handler_registry() -> HandlerRegistry {
+    let registry = HandlerRegistry::new();
+    registry.register_simple("clear", |_model| {});
+    registry
+}
+"#;
+
+    #[test]
+    fn test_parse_ui_module_collects_handlers_and_fields() -> Result<(), String> {
+        let (view, module) = parse_ui_module(Path::new("/app/src/ui/window.rs"), MODULE)
+            .ok_or("module not recognized")?;
+
+        assert_eq!(view, PathBuf::from("/app/src/ui/window.dampen"));
+        assert_eq!(module.line, 3);
+        for handler in ["increment", "reset", "clear"] {
+            assert!(module.has_handler(handler), "missing {}", handler);
+        }
+        assert!(!module.has_handler("decrement"));
+
+        let model = module.model.ok_or("model not found")?;
+        assert_eq!(
+            model.symbol,
+            Symbol {
+                name: "Model".to_string(),
+                line: 7,
+            }
+        );
+        let fields: Vec<&str> = model.fields.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(fields, vec!["count", "title"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_occurrences_across_views() {
+        let mut index = WorkspaceIndex::default();
+        index.update_view(
+            Path::new("/app/src/ui/b.dampen"),
+            r#"<button class="primary" on_click="save" />"#,
+        );
+        index.update_view(
+            Path::new("/app/src/ui/a.dampen"),
+            r#"<column class="primary"><text class="muted" /></column>"#,
+        );
+
+        let paths: Vec<&Path> = index
+            .occurrences(SymbolKind::Class, "primary")
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                Path::new("/app/src/ui/a.dampen"),
+                Path::new("/app/src/ui/b.dampen")
+            ]
+        );
+
+        index.update_view(Path::new("/app/src/ui/b.dampen"), "<button />");
+        assert_eq!(index.occurrences(SymbolKind::Class, "primary").len(), 1);
+    }
+
+    #[test]
+    fn test_files_without_dampen_ui_are_not_modules() {
+        assert!(parse_ui_module(Path::new("ui/mod.rs"), "pub mod window;").is_none());
+    }
+}
@@ -54,6 +54,25 @@ fn test_complete_attributes() {
     }
 }
 
+#[test]
+fn test_complete_custom_breakpoint_prefixes() {
+    let (doc, uri) = create_doc(
+        "<dampen version=\"1.1\">\n<breakpoints sm=\"480\" md=\"768\" />\n<column ></column>\n</dampen>",
+    );
+    let params = create_params(uri, 2, 8);
+
+    let response = completion(&doc, params).unwrap();
+
+    if let tower_lsp::lsp_types::CompletionResponse::Array(items) = response {
+        let md = items.iter().find(|i| i.label == "md-").unwrap();
+        assert_eq!(md.detail.as_deref(), Some("Breakpoint (>= 768px)"));
+        assert!(items.iter().any(|i| i.label == "sm-"));
+        assert!(!items.iter().any(|i| i.label == "mobile-"));
+    } else {
+        panic!("Expected Array response");
+    }
+}
+
 #[test]
 fn test_complete_values_boolean() {
     let (doc, uri) = create_doc("<button enabled=\"");
@@ -85,6 +104,36 @@ fn test_complete_values_color() {
     }
 }
 
+#[test]
+fn test_complete_values_icon_name() {
+    let (doc, uri) = create_doc("<icon name=\"");
+    let params = create_params(uri, 0, 12);
+
+    let response = completion(&doc, params).unwrap();
+
+    if let tower_lsp::lsp_types::CompletionResponse::Array(items) = response {
+        assert!(items.iter().any(|i| i.label == "trash"));
+        assert!(items.iter().any(|i| i.label == "settings"));
+    } else {
+        panic!("Expected Array response");
+    }
+}
+
+#[test]
+fn test_complete_values_role() {
+    let (doc, uri) = create_doc("<button role=\"");
+    let params = create_params(uri, 0, 14);
+
+    let response = completion(&doc, params).unwrap();
+
+    if let tower_lsp::lsp_types::CompletionResponse::Array(items) = response {
+        assert!(items.iter().any(|i| i.label == "heading"));
+        assert!(items.iter().any(|i| i.label == "navigation"));
+    } else {
+        panic!("Expected Array response");
+    }
+}
+
 #[test]
 fn test_complete_unknown_context() {
     let (doc, uri) = create_doc("just text");
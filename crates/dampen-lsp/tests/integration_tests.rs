@@ -108,6 +108,20 @@ fn test_diagnostics_computation_invalid_widget() {
     }
 }
 
+#[test]
+fn test_diagnostics_report_every_error() {
+    let uri = test_uri("multiple_errors.dampen");
+    let content = load_fixture("multiple_errors.dampen");
+
+    let doc_state = DocumentState::new(uri, content, 1);
+    let diagnostics = diagnostics::compute_diagnostics(&doc_state);
+
+    // One diagnostic per invalid widget, and the valid part is still parsed
+    assert!(doc_state.ast.is_some());
+    let lines: Vec<u32> = diagnostics.iter().map(|d| d.range.start.line).collect();
+    assert_eq!(lines, vec![2, 4, 5]);
+}
+
 #[test]
 fn test_complex_document_parsing() {
     let uri = test_uri("complex.dampen");
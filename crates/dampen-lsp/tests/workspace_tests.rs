@@ -0,0 +1,90 @@
+//! Tests for the workspace diagnostics of handler and model mismatches.
+
+use std::error::Error;
+use std::fs;
+
+use tower_lsp::lsp_types::*;
+use url::Url;
+
+use dampen_lsp::document::DocumentState;
+use dampen_lsp::handlers::diagnostics;
+use dampen_lsp::workspace::WorkspaceIndex;
+
+const WINDOW_RS: &str = r#"
+use dampen_macros::{UiModel, dampen_ui, ui_handler};
+
+#[dampen_ui("window.dampen")]
+mod _app {}
+
+#[derive(Default, UiModel)]
+pub struct Model {
+    pub count: i32,
+    pub items: Vec<String>,
+}
+
+#[ui_handler]
+pub fn increment(model: &mut Model) {
+    model.count += 1;
+}
+"#;
+
+const WINDOW_DAMPEN: &str = r#"<column>
+    <text value="Count: {count}" />
+    <button label="+" on_click="increment" />
+    <button label="?" on_click="nope" />
+    <text value="{missing_field}" />
+    <for each="entry" in="{items}">
+        <text value="{index}: {entry}" />
+    </for>
+    <text value="{shared.theme}" />
+</column>"#;
+
+#[test]
+fn test_workspace_diagnostics_flag_missing_handlers_and_fields() -> Result<(), Box<dyn Error>> {
+    let root = tempfile::tempdir()?;
+    let ui_dir = root.path().join("src").join("ui");
+    fs::create_dir_all(&ui_dir)?;
+    fs::write(ui_dir.join("window.rs"), WINDOW_RS)?;
+    fs::write(ui_dir.join("target.rs"), "not a ui module")?;
+
+    let index = WorkspaceIndex::scan(root.path());
+    assert_eq!(index.module_count(), 1);
+
+    let view_path = ui_dir.join("window.dampen");
+    let module = index
+        .module_for(&view_path)
+        .ok_or("window.rs not indexed")?;
+    let uri = Url::from_file_path(&view_path).map_err(|_| "invalid path")?;
+    let doc = DocumentState::new(uri, WINDOW_DAMPEN.to_string(), 1);
+
+    let found = diagnostics::workspace_diagnostics(&doc, module);
+    let messages: Vec<&str> = found.iter().map(|d| d.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "Handler 'nope' is not declared",
+            "Field 'missing_field' is not defined on Model",
+        ]
+    );
+
+    let handler = &found[0];
+    assert_eq!(handler.severity, Some(DiagnosticSeverity::WARNING));
+    assert_eq!(handler.range.start, Position::new(3, 32));
+    let related = handler
+        .related_information
+        .as_deref()
+        .ok_or("missing related information")?;
+    assert_eq!(
+        related[0].location.uri,
+        Url::from_file_path(ui_dir.join("window.rs")).map_err(|_| "invalid path")?
+    );
+    assert_eq!(related[0].location.range.start, Position::new(3, 0));
+
+    // The field warning links to the model definition
+    let related = found[1]
+        .related_information
+        .as_deref()
+        .ok_or("missing related information")?;
+    assert_eq!(related[0].location.range.start, Position::new(7, 0));
+    Ok(())
+}
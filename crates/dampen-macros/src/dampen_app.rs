@@ -10,7 +10,7 @@ use quote::quote;
 use std::path::PathBuf;
 use syn::{Ident, LitStr, Token, parse::Parse};
 
-use crate::discovery::{ViewInfo, discover_dampen_files};
+use crate::discovery::{ViewInfo, discover_dampen_files, to_pascal_case};
 
 /// Configuration attributes parsed from the `#[dampen_app(...)]` macro.
 ///
@@ -28,9 +28,32 @@ use crate::discovery::{ViewInfo, discover_dampen_files};
 /// - `hot_reload_variant`: Message variant for hot-reload events (enables file watching in debug builds)
 /// - `dismiss_error_variant`: Message variant for error overlay dismissal (enables error overlay in debug builds)
 /// - `switch_view_variant`: Message variant for programmatic view switching (e.g., `SwitchToView`)
+/// - `command_palette_variant`: Message variant wrapping `dampen_iced::palette::Event` (enables the Ctrl+K command palette)
 /// - `exclude`: Glob patterns to exclude from discovery (e.g., `["debug", "experimental/*"]`)
-/// - `default_view`: View to display on startup (without `.dampen` extension, defaults to first alphabetically)
+/// - `styles`: Style library files merged into every view, relative to `ui_dir` (e.g., `["styles/buttons.dampen"]`)
+/// - `default_view`: View to display on startup (without `.dampen` extension, dotted path for nested views, defaults to first alphabetically)
 /// - `shared_model`: Optional shared state model type for inter-view communication (e.g., `"SharedState"`)
+/// - `shared`: Several named shared state models instead of `shared_model` (e.g., `["Session", "Settings"]`)
+/// - `nested_views`: Turn subdirectories of `ui_dir` into navigation groups (defaults to `false`)
+/// - `group_shared_models`: Shared state model per group, as `"group:Type"` entries (requires `nested_views`)
+/// - `lazy`: Create view states on first navigation instead of at startup (defaults to `false`)
+/// - `prefetch`: Views created at startup anyway when `lazy` is enabled (e.g., `["settings"]`)
+/// - `catch_panics`: Show handler panics in the error overlay instead of aborting, restoring the model
+///   (defaults to `false`; debug builds in interpreted mode; requires `dismiss_error_variant` and models
+///   implementing `Clone`, the model being cloned before every handler)
+/// - `reset_state_variant`: Message variant resetting the current view's model after a panic (requires `catch_panics`)
+/// - `log_viewer_variant`: Message variant toggling the log viewer pane with F12 (debug builds in interpreted mode)
+/// - `debug_server_variant`: Message variant wrapping `dampen_dev::debug_server::DebugRequest` (serves debuggers
+///   such as `dampen debug attach` in debug builds of interpreted mode)
+/// - `time_travel_variant`: Message variant wrapping `dampen_dev::time_travel::Event` (records handler messages
+///   in a timeline opened with F9; debug builds in interpreted mode, requires models implementing `Clone`)
+/// - `state_file_variant`: Message variant wrapping `dampen_dev::state_file::Event` (exports and imports the models
+///   as JSON from a toolbar opened with F8, and imports `dampen run --state` at startup; debug builds in interpreted
+///   mode, requires models and `shared_model` implementing `Serialize` and `Deserialize`)
+/// - `inspector_variant`: Message variant wrapping `dampen_dev::inspector::Event` (highlights and describes the
+///   widget under the cursor, opened with Ctrl+Shift+I, and opens its file on click; debug builds in interpreted mode)
+/// - `error_overlay_style`: Function returning the `dampen_dev::OverlayStyle` of the error overlay
+///   (e.g., `"dev_overlay_style"`; requires `dismiss_error_variant`)
 ///
 /// # Examples
 ///
@@ -85,6 +108,42 @@ use crate::discovery::{ViewInfo, discover_dampen_files};
 /// struct MyApp;
 /// ```
 ///
+/// With `nested_views`, `src/ui/settings/appearance.dampen` is the `Appearance`
+/// view of the `settings` group. `CurrentView` becomes hierarchical and views are
+/// addressed by dotted paths:
+///
+/// ```ignore
+/// #[dampen_app(
+///     ui_dir = "src/ui",
+///     message_type = "Message",
+///     handler_variant = "Handler",
+///     switch_view_variant = "SwitchToView",
+///     nested_views = true,
+///     default_view = "settings.appearance",
+///     group_shared_models = ["settings:SettingsState"]
+/// )]
+/// struct MyApp;
+///
+/// // Generated: CurrentView::Settings(SettingsView::Appearance)
+/// // The SwitchToView payload may be a CurrentView or a dotted path:
+/// enum Message {
+///     Handler(HandlerMessage),
+///     SwitchToView(&'static str), // e.g. Message::SwitchToView("settings.appearance")
+/// }
+/// ```
+///
+/// With `lazy = true`, only the default view (and the `prefetch` views) are created by
+/// `init()`; the others are created when first switched to, or warmed with the generated
+/// `prefetch(CurrentView)` method.
+///
+/// Views of the `settings` group (and its subgroups) then share a
+/// `SharedContext<shared::SettingsState>` instead of the app-wide `shared_model`.
+///
+/// With `shared = ["Session", "Settings"]`, each model of `src/shared.rs` is held in
+/// a `dampen_core::SharedContexts` under its snake_case name, bound as `{session.user}`.
+/// Views then take a `SharedContext<SharedContexts>`, and handlers registered with
+/// `HandlerRegistry::register_with_context` receive the context of the type they name.
+///
 /// With `shared_model`, all views can access and modify the shared state:
 ///
 /// ```ignore
@@ -96,7 +155,7 @@ use crate::discovery::{ViewInfo, discover_dampen_files};
 ///
 /// // src/ui/settings.rs
 /// #[ui_handler]
-/// pub fn update_theme(shared: &SharedContext<SharedState>) {
+/// pub fn update_theme(model: &mut Model, shared: &SharedContext<SharedState>) {
 ///     shared.update(|s| s.theme = "dark".to_string());
 /// }
 /// ```
@@ -109,6 +168,7 @@ use crate::discovery::{ViewInfo, discover_dampen_files};
 /// - Exclusion patterns compile as valid globs
 /// - `default_view` (if specified) exists in discovered views
 /// - `shared_model` (if specified) corresponds to an existing `src/shared.rs` file
+/// - `group_shared_models` (if specified) name existing groups of nested views
 #[derive(Debug, Clone)]
 pub struct MacroAttributes {
     /// Required: Directory to scan for .dampen files (relative to crate root)
@@ -133,7 +193,13 @@ pub struct MacroAttributes {
     /// Optional: Glob patterns to exclude from discovery
     pub exclude: Vec<String>,
 
+    /// Optional: Style library files, relative to ui_dir, whose themes and style classes
+    /// are merged into every view (e.g., `["styles/buttons.dampen"]`)
+    /// Libraries are not views; the classes of a view win over those of the libraries
+    pub styles: Vec<String>,
+
     /// Optional: Default view to display on startup (without .dampen extension)
+    /// Nested views are given as dotted paths (e.g., `"settings.appearance"`)
     /// If not specified, defaults to first view alphabetically
     pub default_view: Option<String>,
 
@@ -141,14 +207,72 @@ pub struct MacroAttributes {
     /// If specified, expects a type in `shared` module (e.g., `"SharedState"` → `shared::SharedState`)
     pub shared_model: Option<Ident>,
 
+    /// Optional: Several shared state models, each bound under its snake_case name
+    /// (e.g., `["Session", "Settings"]` → `{session.user}`, `{settings.theme}`)
+    pub shared: Vec<Ident>,
+
     /// Optional: Message variant for system theme change events
     pub system_theme_variant: Option<Ident>,
 
+    /// Optional: Message variant for command palette events
+    /// If specified, the macro renders the Ctrl+K palette and handles `Message::Palette(Event)`
+    pub command_palette_variant: Option<Ident>,
+
     /// Optional: Enable window state persistence (requires app_name)
     pub persistence: bool,
 
     /// Optional: Application identifier for persistence (required if persistence = true)
     pub app_name: Option<String>,
+
+    /// Optional: Handlers whose tasks outlive view switches
+    /// Tasks returned by any other handler are scoped to the view that started them
+    /// and aborted when that view is left
+    pub global_tasks: Vec<String>,
+
+    /// Optional: Turn subdirectories of ui_dir into navigation groups
+    /// `ui/settings/appearance.dampen` becomes `CurrentView::Settings(SettingsView::Appearance)`
+    pub nested_views: bool,
+
+    /// Optional: Shared state model per group of nested views, as (dotted group path, type)
+    /// (e.g., `"settings:SettingsState"` → `("settings", shared::SettingsState)`)
+    pub group_shared_models: Vec<(String, Ident)>,
+
+    /// Optional: Create view states on first navigation (`Option<AppState>` fields)
+    pub lazy: bool,
+
+    /// Optional: Views whose state `init()` creates even when `lazy` is enabled
+    pub prefetch: Vec<String>,
+
+    /// Optional: Catch handler panics and show them in the error overlay (debug builds only)
+    /// The model of the view is restored to its state before the handler ran
+    pub catch_panics: bool,
+
+    /// Optional: Message variant resetting the model of the current view after a panic
+    pub reset_state_variant: Option<Ident>,
+
+    /// Optional: Message variant toggling the log viewer pane (debug builds only)
+    /// If specified, F12 shows the recent records of `dampen_dev::logging` under the view
+    pub log_viewer_variant: Option<Ident>,
+
+    /// Optional: Message variant for debug server requests (debug builds only)
+    /// If specified, the app answers the commands of `dampen_dev::debug_server`
+    pub debug_server_variant: Option<Ident>,
+
+    /// Optional: Message variant for time-travel debugging events (debug builds only)
+    /// If specified, handler messages are recorded with model snapshots in a timeline
+    pub time_travel_variant: Option<Ident>,
+
+    /// Optional: Message variant for state export/import events (debug builds only)
+    /// If specified, the models can be saved to and loaded from a JSON state file
+    pub state_file_variant: Option<Ident>,
+
+    /// Optional: Message variant for widget inspector events (debug builds only)
+    /// If specified, Ctrl+Shift+I highlights the widget under the cursor and describes it
+    pub inspector_variant: Option<Ident>,
+
+    /// Optional: Function returning the `dampen_dev::OverlayStyle` of the error overlay
+    /// (e.g., `"dev_overlay_style"` → `error_overlay: ErrorOverlay::new().with_style(dev_overlay_style())`)
+    pub error_overlay_style: Option<syn::Path>,
 }
 
 impl Parse for MacroAttributes {
@@ -160,11 +284,27 @@ impl Parse for MacroAttributes {
         let mut dismiss_error_variant = None;
         let mut switch_view_variant = None;
         let mut exclude = Vec::new();
+        let mut styles = Vec::new();
         let mut default_view = None;
         let mut shared_model = None;
+        let mut shared = Vec::new();
         let mut system_theme_variant = None;
+        let mut command_palette_variant = None;
         let mut persistence = false;
         let mut app_name = None;
+        let mut global_tasks = Vec::new();
+        let mut nested_views = false;
+        let mut group_shared_models = Vec::new();
+        let mut lazy = false;
+        let mut prefetch = Vec::new();
+        let mut catch_panics = false;
+        let mut reset_state_variant = None;
+        let mut log_viewer_variant = None;
+        let mut debug_server_variant = None;
+        let mut time_travel_variant = None;
+        let mut state_file_variant = None;
+        let mut inspector_variant = None;
+        let mut error_overlay_style = None;
 
         // Parse key-value pairs
         while !input.is_empty() {
@@ -192,14 +332,17 @@ impl Parse for MacroAttributes {
             } else if key == "system_theme_variant" {
                 let value: LitStr = input.parse()?;
                 system_theme_variant = Some(Ident::new(&value.value(), value.span()));
+            } else if key == "command_palette_variant" {
+                let value: LitStr = input.parse()?;
+                command_palette_variant = Some(Ident::new(&value.value(), value.span()));
             } else if key == "default_view" {
                 let value: LitStr = input.parse()?;
                 let view_name = value.value();
-                // Strip .dampen extension if provided
+                // Strip .dampen extension if provided, nested views use dotted paths
                 let view_name = view_name
                     .strip_suffix(".dampen")
                     .unwrap_or(&view_name)
-                    .to_string();
+                    .replace('/', ".");
                 default_view = Some(view_name);
             } else if key == "exclude" {
                 // Parse array of string literals: ["debug", "experimental/*"]
@@ -210,6 +353,112 @@ impl Parse for MacroAttributes {
                     let pattern: LitStr = content.parse()?;
                     exclude.push(pattern.value());
 
+                    // Parse optional comma
+                    if content.peek(Token![,]) {
+                        content.parse::<Token![,]>()?;
+                    }
+                }
+            } else if key == "styles" {
+                // Parse array of library files: ["styles/buttons.dampen"]
+                let content;
+                syn::bracketed!(content in input);
+
+                while !content.is_empty() {
+                    let library: LitStr = content.parse()?;
+                    styles.push(library.value());
+
+                    // Parse optional comma
+                    if content.peek(Token![,]) {
+                        content.parse::<Token![,]>()?;
+                    }
+                }
+            } else if key == "global_tasks" {
+                // Parse array of handler names: ["sync_loop", "poll_updates"]
+                let content;
+                syn::bracketed!(content in input);
+
+                while !content.is_empty() {
+                    let handler: LitStr = content.parse()?;
+                    global_tasks.push(handler.value());
+
+                    // Parse optional comma
+                    if content.peek(Token![,]) {
+                        content.parse::<Token![,]>()?;
+                    }
+                }
+            } else if key == "group_shared_models" {
+                // Parse array of "group:Type" entries: ["settings:SettingsState"]
+                let content;
+                syn::bracketed!(content in input);
+
+                while !content.is_empty() {
+                    let entry: LitStr = content.parse()?;
+                    let value = entry.value();
+                    let Some((group, model)) = value.split_once(':') else {
+                        return Err(syn::Error::new(
+                            entry.span(),
+                            format!(
+                                "Invalid group_shared_models entry '{}'\nhelp: Use \"group:Type\", e.g. \"settings:SettingsState\"",
+                                value
+                            ),
+                        ));
+                    };
+                    group_shared_models.push((
+                        group.trim().replace('/', "."),
+                        Ident::new(model.trim(), entry.span()),
+                    ));
+
+                    // Parse optional comma
+                    if content.peek(Token![,]) {
+                        content.parse::<Token![,]>()?;
+                    }
+                }
+            } else if key == "prefetch" {
+                // Parse array of view names: ["settings", "settings.appearance"]
+                let content;
+                syn::bracketed!(content in input);
+
+                while !content.is_empty() {
+                    let view: LitStr = content.parse()?;
+                    let view = view.value();
+                    prefetch.push(
+                        view.strip_suffix(".dampen")
+                            .unwrap_or(&view)
+                            .replace('/', "."),
+                    );
+
+                    // Parse optional comma
+                    if content.peek(Token![,]) {
+                        content.parse::<Token![,]>()?;
+                    }
+                }
+            } else if key == "lazy" {
+                let value: syn::LitBool = input.parse()?;
+                lazy = value.value;
+            } else if key == "nested_views" {
+                let value: syn::LitBool = input.parse()?;
+                nested_views = value.value;
+            } else if key == "shared" {
+                // Parse array of shared model types: ["Session", "Settings"]
+                let content;
+                syn::bracketed!(content in input);
+
+                while !content.is_empty() {
+                    let model: LitStr = content.parse()?;
+                    let model_ident: Ident = syn::parse_str(&model.value()).map_err(|_| {
+                        syn::Error::new(
+                            model.span(),
+                            format!("Invalid shared model type '{}'", model.value()),
+                        )
+                    })?;
+                    if shared.contains(&model_ident) {
+                        return Err(syn::Error::new(
+                            model.span(),
+                            format!("Shared model '{}' is listed twice", model.value()),
+                        ));
+                    }
+                    shared.push(Ident::new(&model.value(), model.span()));
+
                     // Parse optional comma
                     if content.peek(Token![,]) {
                         content.parse::<Token![,]>()?;
@@ -221,6 +470,30 @@ impl Parse for MacroAttributes {
             } else if key == "persistence" {
                 let value: syn::LitBool = input.parse()?;
                 persistence = value.value;
+            } else if key == "catch_panics" {
+                let value: syn::LitBool = input.parse()?;
+                catch_panics = value.value;
+            } else if key == "reset_state_variant" {
+                let value: LitStr = input.parse()?;
+                reset_state_variant = Some(Ident::new(&value.value(), value.span()));
+            } else if key == "log_viewer_variant" {
+                let value: LitStr = input.parse()?;
+                log_viewer_variant = Some(Ident::new(&value.value(), value.span()));
+            } else if key == "debug_server_variant" {
+                let value: LitStr = input.parse()?;
+                debug_server_variant = Some(Ident::new(&value.value(), value.span()));
+            } else if key == "time_travel_variant" {
+                let value: LitStr = input.parse()?;
+                time_travel_variant = Some(Ident::new(&value.value(), value.span()));
+            } else if key == "state_file_variant" {
+                let value: LitStr = input.parse()?;
+                state_file_variant = Some(Ident::new(&value.value(), value.span()));
+            } else if key == "inspector_variant" {
+                let value: LitStr = input.parse()?;
+                inspector_variant = Some(Ident::new(&value.value(), value.span()));
+            } else if key == "error_overlay_style" {
+                let value: LitStr = input.parse()?;
+                error_overlay_style = Some(value.parse::<syn::Path>()?);
             } else if key == "app_name" {
                 let value: LitStr = input.parse()?;
                 app_name = Some(value.value());
@@ -280,8 +553,73 @@ impl Parse for MacroAttributes {
             }
         }
 
+        // Validate style library files exist
+        for library in &styles {
+            let manifest_dir =
+                std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".to_string());
+            if !PathBuf::from(manifest_dir)
+                .join(&ui_dir)
+                .join(library)
+                .is_file()
+            {
+                return Err(syn::Error::new(
+                    input.span(),
+                    format!(
+                        "Style library '{}' not found in '{}'\nhelp: Library paths are relative to ui_dir, e.g. \"styles/buttons.dampen\"",
+                        library, ui_dir
+                    ),
+                ));
+            }
+        }
+
+        if catch_panics && dismiss_error_variant.is_none() {
+            return Err(syn::Error::new(
+                input.span(),
+                "catch_panics = true requires dismiss_error_variant attribute\nhelp: Add dismiss_error_variant = \"DismissError\" to show panics in the error overlay",
+            ));
+        }
+
+        if error_overlay_style.is_some() && dismiss_error_variant.is_none() {
+            return Err(syn::Error::new(
+                input.span(),
+                "error_overlay_style requires dismiss_error_variant attribute\nhelp: Add dismiss_error_variant = \"DismissError\" to enable the error overlay",
+            ));
+        }
+
+        if reset_state_variant.is_some() && !catch_panics {
+            return Err(syn::Error::new(
+                input.span(),
+                "reset_state_variant requires catch_panics = true\nhelp: Add catch_panics = true",
+            ));
+        }
+
+        if !prefetch.is_empty() && !lazy {
+            return Err(syn::Error::new(
+                input.span(),
+                "prefetch requires lazy = true\nhelp: Add lazy = true, or remove prefetch as all views are created at startup",
+            ));
+        }
+
+        if !group_shared_models.is_empty() && !nested_views {
+            return Err(syn::Error::new(
+                input.span(),
+                "group_shared_models requires nested_views = true\nhelp: Add nested_views = true",
+            ));
+        }
+
+        if shared_model.is_some() && !shared.is_empty() {
+            return Err(syn::Error::new(
+                input.span(),
+                "shared_model and shared cannot be used together\nhelp: List every shared model in shared = [...]",
+            ));
+        }
+
         // Validate shared_model file exists if specified
-        if let Some(ref shared_model_name) = shared_model {
+        let shared_models = shared_model
+            .iter()
+            .chain(shared.iter())
+            .chain(group_shared_models.iter().map(|(_, model)| model));
+        for shared_model_name in shared_models {
             // Use CARGO_MANIFEST_DIR to get the crate directory (not workspace root)
             let manifest_dir =
                 std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".to_string());
@@ -309,11 +647,27 @@ impl Parse for MacroAttributes {
             dismiss_error_variant,
             switch_view_variant,
             exclude,
+            styles,
             default_view,
             shared_model,
+            shared,
             system_theme_variant,
+            command_palette_variant,
             persistence,
             app_name,
+            global_tasks,
+            nested_views,
+            group_shared_models,
+            lazy,
+            prefetch,
+            catch_panics,
+            reset_state_variant,
+            log_viewer_variant,
+            debug_server_variant,
+            time_travel_variant,
+            state_file_variant,
+            inspector_variant,
+            error_overlay_style,
         })
     }
 }
@@ -323,6 +677,10 @@ impl Parse for MacroAttributes {
 /// Creates an enum with one variant per view file, used to track which view is currently active.
 /// The enum derives `Debug`, `Clone`, `PartialEq`, and `Eq` for convenient usage.
 ///
+/// With `nested_views`, each group (subdirectory) gets its own enum, wrapped by a variant of
+/// its parent group: `ui/settings/appearance.dampen` generates `SettingsView::Appearance`
+/// and `CurrentView::Settings(SettingsView)`.
+///
 /// # Arguments
 ///
 /// * `views` - Slice of discovered view information (file paths, variant names)
@@ -352,15 +710,135 @@ impl Parse for MacroAttributes {
 /// }
 /// ```
 pub fn generate_current_view_enum(views: &[ViewInfo]) -> TokenStream {
-    let variants: Vec<_> = views
+    // Every group prefix has its own enum, the root group being `CurrentView`
+    let mut groups: Vec<&[String]> = views
         .iter()
-        .map(|v| Ident::new(&v.variant_name, proc_macro2::Span::call_site()))
+        .flat_map(|v| (0..=v.group.len()).map(|depth| &v.group[..depth]))
         .collect();
+    groups.sort();
+    groups.dedup();
+
+    let enums = groups.into_iter().map(|group| {
+        let enum_name = group_enum_ident(group);
+
+        let view_variants = views
+            .iter()
+            .filter(|v| v.group.as_slice() == group)
+            .map(|v| {
+                let variant = Ident::new(&v.variant_name, proc_macro2::Span::call_site());
+                quote! { #variant }
+            });
+
+        // Subdirectories become variants wrapping their own enum
+        let mut directories: Vec<&String> = views
+            .iter()
+            .filter(|v| v.group.len() > group.len() && v.group.starts_with(group))
+            .map(|v| &v.group[group.len()])
+            .collect();
+        directories.dedup();
+        let group_variants = directories.into_iter().map(|directory| {
+            let variant = Ident::new(&to_pascal_case(directory), proc_macro2::Span::call_site());
+            let mut subgroup = group.to_vec();
+            subgroup.push(directory.clone());
+            let inner = group_enum_ident(&subgroup);
+            quote! { #variant(#inner) }
+        });
+
+        let variants: Vec<_> = view_variants.chain(group_variants).collect();
+
+        quote! {
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            pub enum #enum_name {
+                #(#variants),*
+            }
+        }
+    });
+
+    quote! {
+        #(#enums)*
+    }
+}
+
+/// Generates the dotted path conversions of a hierarchical `CurrentView`.
+///
+/// Only generated with `nested_views`. Creates:
+/// - `CurrentView::path()` and `CurrentView::from_path()`, mapping views to paths like
+///   `"settings.appearance"`
+/// - `FromStr` for `CurrentView`
+/// - The `IntoCurrentView` trait, implemented for `CurrentView`, `&str` and `String`, so the
+///   `switch_view_variant` payload may be either a view or a dotted path
+///
+/// # Arguments
+///
+/// * `views` - Slice of discovered view information
+///
+/// # Returns
+///
+/// Token stream containing the impl blocks and trait:
+///
+/// ```ignore
+/// impl CurrentView {
+///     pub fn path(&self) -> &'static str {
+///         match self {
+///             CurrentView::Settings(SettingsView::Appearance) => "settings.appearance",
+///             // ...
+///         }
+///     }
+///
+///     pub fn from_path(path: &str) -> Option<Self> { /* ... */ }
+/// }
+/// ```
+pub fn generate_view_paths(views: &[ViewInfo]) -> TokenStream {
+    let variants: Vec<_> = views.iter().map(view_variant).collect();
+    let paths: Vec<_> = views.iter().map(ViewInfo::path).collect();
 
     quote! {
-        #[derive(Debug, Clone, PartialEq, Eq)]
-        pub enum CurrentView {
-            #(#variants),*
+        impl CurrentView {
+            /// Dotted path of the view from the UI directory (e.g. `"settings.appearance"`)
+            pub fn path(&self) -> &'static str {
+                match self {
+                    #(#variants => #paths,)*
+                }
+            }
+
+            /// Find a view by its dotted path
+            pub fn from_path(path: &str) -> Option<Self> {
+                match path.trim().trim_end_matches(".dampen") {
+                    #(#paths => Some(#variants),)*
+                    _ => None,
+                }
+            }
+        }
+
+        impl std::str::FromStr for CurrentView {
+            type Err = String;
+
+            fn from_str(path: &str) -> Result<Self, Self::Err> {
+                Self::from_path(path).ok_or_else(|| format!("Unknown view '{}'", path))
+            }
+        }
+
+        /// Payload accepted by the view switching message: a view or its dotted path
+        pub trait IntoCurrentView {
+            fn into_current_view(self) -> Option<CurrentView>;
+        }
+
+        impl IntoCurrentView for CurrentView {
+            fn into_current_view(self) -> Option<CurrentView> {
+                Some(self)
+            }
+        }
+
+        impl IntoCurrentView for &str {
+            fn into_current_view(self) -> Option<CurrentView> {
+                CurrentView::from_path(self)
+            }
+        }
+
+        impl IntoCurrentView for String {
+            fn into_current_view(self) -> Option<CurrentView> {
+                CurrentView::from_path(&self)
+            }
         }
     }
 }
@@ -370,7 +848,14 @@ pub fn generate_current_view_enum(views: &[ViewInfo]) -> TokenStream {
 /// Creates a struct with:
 /// - One `AppState` field per discovered view
 /// - A `current_view: CurrentView` field to track the active view
+/// - One `TaskScope` field per view, holding the tasks started by that view
+/// - A `build_diagnostics` field collecting the problems found while building views
+/// - A `memo_cache` field keeping the `memo="true"` subtrees across builds
 /// - An `error_overlay` field if `dismiss_error_variant` is specified (debug builds only)
+/// - A `show_logs` field if `log_viewer_variant` is specified (debug builds only)
+/// - A `timeline` field if `time_travel_variant` is specified (debug builds only)
+/// - A `state_tools` field if `state_file_variant` is specified (debug builds only)
+/// - An `inspector` field if `inspector_variant` is specified (debug builds only)
 ///
 /// # Arguments
 ///
@@ -392,6 +877,11 @@ pub fn generate_current_view_enum(views: &[ViewInfo]) -> TokenStream {
 ///     window_state: dampen_core::AppState<window::Model>,
 ///     settings_state: dampen_core::AppState<settings::Model>,
 ///     current_view: CurrentView,
+///     window_tasks: dampen_iced::TaskScope,
+///     settings_tasks: dampen_iced::TaskScope,
+///     build_diagnostics: dampen_iced::diagnostics::BuildDiagnostics,
+///     memo_cache: dampen_iced::memo::MemoCache,
+///     theme_switch: dampen_core::theme_switch::ThemeSwitch,
 ///     #[cfg(debug_assertions)]
 ///     error_overlay: dampen_dev::ErrorOverlay,
 /// }
@@ -402,13 +892,25 @@ pub fn generate_app_struct(
     attrs: &MacroAttributes,
     struct_name: &Ident,
 ) -> TokenStream {
-    // Add shared field if shared_model is specified
-    let shared_field = attrs.shared_model.as_ref().map(|shared_model| {
+    // Add shared field if shared_model (or shared) is specified
+    let shared_field = app_shared_type(attrs).map(|shared_type| {
         quote! {
-            shared: dampen_core::SharedContext<shared::#shared_model>,
+            shared: dampen_core::SharedContext<#shared_type>,
         }
     });
 
+    // One shared context per group listed in group_shared_models
+    let group_shared_fields: Vec<_> = attrs
+        .group_shared_models
+        .iter()
+        .map(|(group_path, shared_model)| {
+            let field_name = group_shared_field(group_path);
+            quote! {
+                #field_name: dampen_core::SharedContext<shared::#shared_model>,
+            }
+        })
+        .collect();
+
     // Generate AppState fields with or without shared state type parameter
     let fields: Vec<_> = views
         .iter()
@@ -422,20 +924,38 @@ pub fn generate_app_struct(
                 .map(|part| Ident::new(part, proc_macro2::Span::call_site()))
                 .collect();
 
-            // If shared_model is specified, use AppState<Model, SharedState>
+            // If the view has a shared model, use AppState<Model, SharedState>
             // Otherwise use AppState<Model>
-            if let Some(ref shared_model) = attrs.shared_model {
+            let state_type = if let Some((_, shared_type)) = view_shared(v, attrs) {
+                quote! { dampen_core::AppState<#(#module_parts)::*::Model, #shared_type> }
+            } else {
+                quote! { dampen_core::AppState<#(#module_parts)::*::Model> }
+            };
+
+            // Lazy views are created on first navigation
+            if attrs.lazy {
                 quote! {
-                    #field_name: dampen_core::AppState<#(#module_parts)::*::Model, shared::#shared_model>
+                    #field_name: Option<#state_type>
                 }
             } else {
                 quote! {
-                    #field_name: dampen_core::AppState<#(#module_parts)::*::Model>
+                    #field_name: #state_type
                 }
             }
         })
         .collect();
 
+    // One task scope per view, so tasks can be cancelled when the view is left
+    let task_scope_fields: Vec<_> = views
+        .iter()
+        .map(|v| {
+            let scope_field = task_scope_field(v);
+            quote! {
+                #scope_field: dampen_iced::TaskScope
+            }
+        })
+        .collect();
+
     // Add error_overlay field if dismiss_error_variant is specified
     let error_overlay_field = if attrs.dismiss_error_variant.is_some() {
         Some(quote! {
@@ -446,6 +966,38 @@ pub fn generate_app_struct(
         None
     };
 
+    // Add show_logs field if log_viewer_variant is specified
+    let log_viewer_field = attrs.log_viewer_variant.is_some().then(|| {
+        quote! {
+            #[cfg(all(debug_assertions, feature = "interpreted"))]
+            show_logs: bool,
+        }
+    });
+
+    // Add timeline field if time_travel_variant is specified
+    let timeline_field = attrs.time_travel_variant.is_some().then(|| {
+        quote! {
+            #[cfg(all(debug_assertions, feature = "interpreted"))]
+            timeline: dampen_dev::time_travel::Timeline,
+        }
+    });
+
+    // Add state_tools field if state_file_variant is specified
+    let state_tools_field = attrs.state_file_variant.is_some().then(|| {
+        quote! {
+            #[cfg(all(debug_assertions, feature = "interpreted"))]
+            state_tools: dampen_dev::state_file::StateTools,
+        }
+    });
+
+    // Add inspector field if inspector_variant is specified
+    let inspector_field = attrs.inspector_variant.is_some().then(|| {
+        quote! {
+            #[cfg(all(debug_assertions, feature = "interpreted"))]
+            inspector: dampen_dev::inspector::Inspector,
+        }
+    });
+
     // Add window_state field if persistence is enabled
     let window_state_field = if attrs.persistence {
         Some(quote! {
@@ -458,12 +1010,29 @@ pub fn generate_app_struct(
         None
     };
 
+    // Lazy views receive the theme context when they are created
+    let theme_context_field = attrs.lazy.then(|| {
+        quote! {
+            theme_context: Option<dampen_core::ThemeContext>,
+        }
+    });
+
     quote! {
         pub struct #struct_name {
             #shared_field
+            #(#group_shared_fields)*
             #(#fields,)*
+            #theme_context_field
             current_view: CurrentView,
+            #(#task_scope_fields,)*
+            build_diagnostics: dampen_iced::diagnostics::BuildDiagnostics,
+            memo_cache: dampen_iced::memo::MemoCache,
+            theme_switch: dampen_core::theme_switch::ThemeSwitch,
             #error_overlay_field
+            #log_viewer_field
+            #timeline_field
+            #state_tools_field
+            #inspector_field
             #window_state_field
         }
     }
@@ -472,9 +1041,14 @@ pub fn generate_app_struct(
 /// Generates the `init()` method to initialize all AppState fields.
 ///
 /// Creates initialization logic that:
-/// - Creates an `AppState` for each view by calling `create_{view}_state()`
+/// - Creates an `AppState` for each view by calling `create_{view}_state()`; with `lazy`,
+///   only for the initial view and the `prefetch` views
 /// - Sets `current_view` to either the user-specified `default_view` or the first view alphabetically
+/// - Creates an empty `TaskScope` for each view
 /// - Initializes the error overlay if `dismiss_error_variant` is specified
+/// - Imports the state file of `dampen run --state` if `state_file_variant` is specified
+/// - Creates the inspector if `inspector_variant` is specified (debug builds only)
+/// - Returns the task produced by entering the initial view (see `enter_current_view()`)
 ///
 /// # Arguments
 ///
@@ -495,6 +1069,8 @@ pub fn generate_app_struct(
 ///         window_state: create_window_state(),
 ///         settings_state: create_settings_state(),
 ///         current_view: CurrentView::Window,  // User-specified
+///         build_diagnostics: dampen_iced::diagnostics::BuildDiagnostics::new(),
+///         memo_cache: dampen_iced::memo::MemoCache::new(),
 ///         #[cfg(debug_assertions)]
 ///         error_overlay: dampen_dev::ErrorOverlay::new(),
 ///     }
@@ -506,8 +1082,8 @@ pub fn generate_init_method(views: &[ViewInfo], attrs: &MacroAttributes) -> Toke
     // Determine the default view variant
     let first_variant = if let Some(ref default_view_name) = attrs.default_view {
         // User specified a default view - find it (validated in dampen_app_impl)
-        if let Some(default_view) = views.iter().find(|v| v.view_name == *default_view_name) {
-            Ident::new(&default_view.variant_name, proc_macro2::Span::call_site())
+        if let Some(default_view) = views.iter().find(|v| v.path() == *default_view_name) {
+            view_variant(default_view)
         } else {
             // This should never happen due to validation, but handle gracefully
             return quote! {
@@ -516,7 +1092,7 @@ pub fn generate_init_method(views: &[ViewInfo], attrs: &MacroAttributes) -> Toke
         }
     } else if let Some(first) = views.first() {
         // No default specified - use first alphabetically
-        Ident::new(&first.variant_name, proc_macro2::Span::call_site())
+        view_variant(first)
     } else {
         return quote! {
             pub fn init() -> Self {
@@ -534,67 +1110,193 @@ pub fn generate_init_method(views: &[ViewInfo], attrs: &MacroAttributes) -> Toke
             shared: shared.clone(),
         });
         (Some(init_code), field_init)
+    } else if !attrs.shared.is_empty() {
+        // One named context per shared model, bound as {name.field}
+        let names = attrs.shared.iter().map(context_name);
+        let models = &attrs.shared;
+        let init_code = quote! {
+            let shared = dampen_core::SharedContext::new(
+                dampen_core::SharedContexts::new()
+                    #(.with(#names, dampen_core::SharedContext::new(shared::#models::default())))*
+            );
+        };
+        let field_init = Some(quote! {
+            shared: shared.clone(),
+        });
+        (Some(init_code), field_init)
     } else {
         (None, None)
     };
 
+    // Initialize the shared context of each group listed in group_shared_models
+    let (group_shared_inits, group_shared_field_inits): (Vec<_>, Vec<_>) = attrs
+        .group_shared_models
+        .iter()
+        .map(|(group_path, shared_model)| {
+            let field_name = group_shared_field(group_path);
+            (
+                quote! {
+                    let #field_name = dampen_core::SharedContext::new(shared::#shared_model::default());
+                },
+                quote! {
+                    #field_name: #field_name.clone(),
+                },
+            )
+        })
+        .unzip();
+
     // Generate field initializations with or without shared context
     let field_inits: Vec<_> = views
         .iter()
         .map(|v| {
             let field_name = Ident::new(&v.field_name, proc_macro2::Span::call_site());
 
-            // Convert module_path to Rust path tokens (e.g., "ui::window" -> ui::window)
-            let module_parts: Vec<_> = v
-                .module_path
-                .split("::")
-                .map(|part| Ident::new(part, proc_macro2::Span::call_site()))
-                .collect();
-
-            // If shared_model is specified, call create_app_state_with_shared
-            // Otherwise call create_app_state
-            if attrs.shared_model.is_some() {
+            // Lazy views are created by enter_current_view() or prefetch()
+            if attrs.lazy {
                 quote! {
-                    #field_name: #(#module_parts)::*::create_app_state_with_shared(shared.clone())
+                    #field_name: None
                 }
             } else {
+                let create_state = create_app_state_call(v, attrs, false);
                 quote! {
-                    #field_name: #(#module_parts)::*::create_app_state()
+                    #field_name: #create_state
                 }
             }
         })
         .collect();
 
-    // Generate theme context setting for each view
-    let theme_context_setters: Vec<_> = views
+    let task_scope_inits: Vec<_> = views
         .iter()
         .map(|v| {
-            let field_name = Ident::new(&v.field_name, proc_macro2::Span::call_site());
+            let scope_field = task_scope_field(v);
             quote! {
-                if let Some(ref ctx) = theme_context {
-                    app.#field_name.set_theme_context(ctx.clone());
-                }
+                #scope_field: dampen_iced::TaskScope::new()
             }
         })
         .collect();
 
+    // Generate theme context setting for each view (lazy views get it on creation)
+    let theme_context_setters: Vec<_> = views
+        .iter()
+        .filter(|_| !attrs.lazy)
+        .map(|v| {
+            let field_name = Ident::new(&v.field_name, proc_macro2::Span::call_site());
+            quote! {
+                if let Some(ref ctx) = theme_context {
+                    app.#field_name.set_theme_context(ctx.clone());
+                }
+            }
+        })
+        .collect();
+
+    // Keep the theme context for the views created later, and warm the prefetched views
+    let theme_context_init = attrs.lazy.then(|| {
+        quote! {
+            theme_context: theme_context.clone(),
+        }
+    });
+    let prefetch_views: Vec<_> = attrs
+        .lazy
+        .then(|| {
+            let prefetched = views
+                .iter()
+                .filter(|v| attrs.prefetch.contains(&v.path()))
+                .map(view_variant);
+            std::iter::once(first_variant.clone())
+                .chain(prefetched)
+                .map(|variant| {
+                    quote! {
+                        app.prefetch(#variant);
+                    }
+                })
+        })
+        .into_iter()
+        .flatten()
+        .collect();
+
     // Add error_overlay initialization if dismiss_error_variant is specified
     let error_overlay_init = if attrs.dismiss_error_variant.is_some() {
+        let style = attrs
+            .error_overlay_style
+            .as_ref()
+            .map(|style| quote! { .with_style(#style()) });
         Some(quote! {
             #[cfg(all(debug_assertions, feature = "interpreted"))]
-            error_overlay: dampen_dev::ErrorOverlay::new(),
+            error_overlay: dampen_dev::ErrorOverlay::new() #style,
         })
     } else {
         None
     };
 
+    // The log viewer starts hidden
+    let log_viewer_init = attrs.log_viewer_variant.is_some().then(|| {
+        quote! {
+            #[cfg(all(debug_assertions, feature = "interpreted"))]
+            show_logs: false,
+        }
+    });
+
+    // The timeline starts empty
+    let timeline_init = attrs.time_travel_variant.is_some().then(|| {
+        quote! {
+            #[cfg(all(debug_assertions, feature = "interpreted"))]
+            timeline: dampen_dev::time_travel::Timeline::new(),
+        }
+    });
+
+    // The state toolbar starts hidden; the state file of `dampen run --state` is imported after
+    let (state_tools_init, startup_state_import) = match attrs.state_file_variant {
+        Some(_) => (
+            Some(quote! {
+                #[cfg(all(debug_assertions, feature = "interpreted"))]
+                state_tools: dampen_dev::state_file::StateTools::new(),
+            }),
+            Some(quote! {
+                // Start from the state file given to `dampen run --state`, if any
+                #[cfg(all(debug_assertions, feature = "interpreted"))]
+                if let Some(path) = dampen_dev::state_file::startup_path() {
+                    let result = dampen_dev::state_file::StateFile::read(&path)
+                        .and_then(|file| app.import_state(&file))
+                        .map(|()| format!("Imported state from {}", path.display()));
+                    app.state_tools.report(result);
+                }
+            }),
+        ),
+        None => (None, None),
+    };
+
+    // The inspector starts off
+    let inspector_init = attrs.inspector_variant.is_some().then(|| {
+        quote! {
+            #[cfg(all(debug_assertions, feature = "interpreted"))]
+            inspector: dampen_dev::inspector::Inspector::new(),
+        }
+    });
+
+    // Breakpoints apply from the first render, with the declared window size
+    // until the window reports its own (see dampen_iced::viewport)
+    let viewport_init =
+        (views_use_breakpoints(views) || views_bind_env(views, "viewport_")).then(|| {
+            let (width, height) = default_window_declaration(views, attrs)
+                .and_then(|window| window.size)
+                .unwrap_or((1024, 768));
+            quote! {
+                if dampen_core::viewport::size().is_none() {
+                    dampen_core::viewport::set_size(#width as f32, #height as f32);
+                }
+            }
+        });
+
     // Initialize window_state if persistence is enabled
     let window_state_init = if attrs.persistence {
         #[allow(clippy::unwrap_used)]
         let app_name = attrs.app_name.as_ref().unwrap();
+        let (width, height) = default_window_declaration(views, attrs)
+            .and_then(|window| window.size)
+            .unwrap_or((800, 600));
         Some(quote! {
             #[cfg(feature = "interpreted")]
-            persisted_window_state: dampen_dev::persistence::load_or_default(#app_name, 800, 600),
+            persisted_window_state: dampen_dev::persistence::load_or_default(#app_name, #width, #height),
             #[cfg(not(feature = "interpreted"))]
             persisted_window_state: (),
         })
@@ -607,94 +1309,1421 @@ pub fn generate_init_method(views: &[ViewInfo], attrs: &MacroAttributes) -> Toke
             #[cfg(debug_assertions)]
             println!("DEBUG: DampenApp::init called");
 
+            // Print the logs filtered by DAMPEN_LOG and keep them for the log viewer
+            #[cfg(all(debug_assertions, feature = "interpreted"))]
+            dampen_dev::logging::init();
+
             #shared_init
+            #(#group_shared_inits)*
 
             // Load theme context from theme.dampen if present (interpreted mode only)
             #[cfg(feature = "interpreted")]
-            let theme_context = {
-                dampen_dev::theme_loader::find_project_root()
-                    .and_then(|root| dampen_dev::theme_loader::load_theme_context(&root).ok().flatten())
+            let project_root = dampen_dev::theme_loader::find_project_root();
+            #[cfg(feature = "interpreted")]
+            let theme_context = project_root
+                .as_ref()
+                .and_then(|root| dampen_dev::theme_loader::load_theme_context(root).ok().flatten());
+
+            // Register the font files of the themes (interpreted mode only)
+            #[cfg(feature = "interpreted")]
+            let font_task = match (&project_root, &theme_context) {
+                (Some(root), Some(ctx)) => {
+                    dampen_iced::fonts::load(&root.join("src/ui/theme"), &ctx.fonts())
+                }
+                _ => iced::Task::none(),
             };
+            #[cfg(not(feature = "interpreted"))]
+            let font_task = iced::Task::none();
 
             #[cfg(not(feature = "interpreted"))]
             let theme_context: Option<dampen_core::ThemeContext> = None;
 
             let mut app = Self {
                 #shared_field_init
+                #(#group_shared_field_inits)*
                 #(#field_inits,)*
-                current_view: CurrentView::#first_variant,
+                #theme_context_init
+                current_view: #first_variant,
+                #(#task_scope_inits,)*
+                build_diagnostics: dampen_iced::diagnostics::BuildDiagnostics::new(),
+                memo_cache: dampen_iced::memo::MemoCache::new(),
+                theme_switch: dampen_core::theme_switch::ThemeSwitch::new(),
                 #error_overlay_init
+                #log_viewer_init
+                #timeline_init
+                #state_tools_init
+                #inspector_init
                 #window_state_init
             };
 
             // Set theme context on all view states
             #(#theme_context_setters)*
+            #(#prefetch_views)*
+            #startup_state_import
+            #viewport_init
+
+            // Start the initial view's scoped tasks
+            let task = iced::Task::batch([font_task, app.enter_current_view()]);
 
-            (app, iced::Task::none())
+            (app, task)
         }
 
         pub fn new() -> (Self, iced::Task<#message_type>) {
             Self::init()
         }
+
+        /// Animate theme switches over `duration`, or switch at once with `None` (the default)
+        pub fn set_theme_transition(&mut self, duration: Option<std::time::Duration>) {
+            self.theme_switch.set_transition(duration);
+        }
     }
 }
 
 /// Generates convenience `switch_to_*()` methods for each view.
 ///
-/// Creates helper methods that update the `current_view` field, providing an ergonomic API
-/// for view switching in user code.
+/// Creates helper methods that update the `current_view` field, providing an ergonomic API
+/// for view switching in user code. Switching to a different view aborts the tasks scoped
+/// to the view being left; switching to the active view is a no-op. With `lazy`, the
+/// state of the target view is created on first switch.
+///
+/// # Arguments
+///
+/// * `views` - Slice of discovered view information
+/// * `attrs` - Parsed macro attributes (for `lazy`)
+///
+/// # Returns
+///
+/// Token stream containing one method per view:
+///
+/// ```ignore
+/// pub fn switch_to_window(&mut self) {
+///     if self.current_view == CurrentView::Window {
+///         return;
+///     }
+///     self.cancel_current_view_tasks();
+///     self.current_view = CurrentView::Window;
+/// }
+///
+/// pub fn switch_to_settings(&mut self) {
+///     if self.current_view == CurrentView::Settings {
+///         return;
+///     }
+///     self.cancel_current_view_tasks();
+///     self.current_view = CurrentView::Settings;
+/// }
+/// // ... one method per view
+/// ```
+///
+/// # Examples
+///
+/// Users can call these methods in their update logic:
+///
+/// ```ignore
+/// fn custom_update(&mut self, msg: Message) {
+///     match msg {
+///         Message::GoToSettings => self.switch_to_settings(),
+///         // ...
+///     }
+/// }
+/// ```
+pub fn generate_switch_to_methods(views: &[ViewInfo], attrs: &MacroAttributes) -> TokenStream {
+    let methods: Vec<_> = views
+        .iter()
+        .map(|v| {
+            let method_name = switch_method(v);
+            let variant = view_variant(v);
+
+            // Lazy views are created on first navigation
+            let load_state = lazy_load(v, attrs);
+
+            quote! {
+                pub fn #method_name(&mut self) {
+                    if self.current_view == #variant {
+                        return;
+                    }
+                    self.cancel_current_view_tasks();
+                    #load_state
+                    self.current_view = #variant;
+                }
+            }
+        })
+        .collect();
+
+    quote! {
+        #(#methods)*
+    }
+}
+
+/// Generates the view state loading methods of a lazy application.
+///
+/// Only generated with `lazy = true`. Creates:
+/// - `prefetch(view)`: creates the `AppState` of a view ahead of navigation, so switching to
+///   it is instant; does nothing if the view is already loaded
+/// - `is_view_loaded(view)`: whether the `AppState` of a view exists
+///
+/// # Arguments
+///
+/// * `views` - Slice of discovered view information
+/// * `attrs` - Parsed macro attributes (for shared models)
+///
+/// # Returns
+///
+/// Token stream containing both methods:
+///
+/// ```ignore
+/// pub fn prefetch(&mut self, view: CurrentView) {
+///     match view {
+///         CurrentView::Settings => {
+///             if self.settings_state.is_none() {
+///                 let mut state = ui::settings::create_app_state();
+///                 if let Some(ref ctx) = self.theme_context {
+///                     state.set_theme_context(ctx.clone());
+///                 }
+///                 self.settings_state = Some(state);
+///             }
+///         }
+///         // ...
+///     }
+/// }
+/// ```
+pub fn generate_lazy_methods(views: &[ViewInfo], attrs: &MacroAttributes) -> Option<TokenStream> {
+    if !attrs.lazy {
+        return None;
+    }
+
+    let prefetch_arms: Vec<_> = views
+        .iter()
+        .map(|v| {
+            let variant = view_variant(v);
+            let field_name = Ident::new(&v.field_name, proc_macro2::Span::call_site());
+            let create_state = create_app_state_call(v, attrs, true);
+            quote! {
+                #variant => {
+                    if self.#field_name.is_none() {
+                        let mut state = #create_state;
+                        if let Some(ref ctx) = self.theme_context {
+                            state.set_theme_context(ctx.clone());
+                        }
+                        self.#field_name = Some(state);
+                    }
+                }
+            }
+        })
+        .collect();
+
+    let loaded_arms: Vec<_> = views
+        .iter()
+        .map(|v| {
+            let variant = view_variant(v);
+            let field_name = Ident::new(&v.field_name, proc_macro2::Span::call_site());
+            quote! {
+                #variant => self.#field_name.is_some(),
+            }
+        })
+        .collect();
+
+    Some(quote! {
+        /// Create the state of a view ahead of navigation
+        pub fn prefetch(&mut self, view: CurrentView) {
+            match view {
+                #(#prefetch_arms)*
+            }
+        }
+
+        /// Whether the state of a view has been created
+        pub fn is_view_loaded(&self, view: &CurrentView) -> bool {
+            match view {
+                #(#loaded_arms)*
+            }
+        }
+    })
+}
+
+/// Generates `export_state()` and `import_state()` if `state_file_variant` is specified.
+///
+/// The state file holds the models of the created views, the `shared_model` state and the
+/// current view. Importing checks every model before replacing any of them; with `lazy`,
+/// views present in the file are created first.
+pub fn generate_state_file_methods(
+    views: &[ViewInfo],
+    attrs: &MacroAttributes,
+) -> Option<TokenStream> {
+    attrs.state_file_variant.as_ref()?;
+
+    let paths: Vec<String> = views.iter().map(ViewInfo::path).collect();
+    let locals: Vec<Ident> = views
+        .iter()
+        .map(|v| {
+            Ident::new(
+                &format!("{}_model", v.qualified_name()),
+                proc_macro2::Span::call_site(),
+            )
+        })
+        .collect();
+    let exports: Vec<TokenStream> = views
+        .iter()
+        .zip(&paths)
+        .map(|(v, path)| {
+            let field_name = Ident::new(&v.field_name, proc_macro2::Span::call_site());
+            if attrs.lazy {
+                quote! {
+                    if let Some(state) = &self.#field_name {
+                        file.insert_model(#path, &state.model)?;
+                    }
+                }
+            } else {
+                quote! {
+                    file.insert_model(#path, &self.#field_name.model)?;
+                }
+            }
+        })
+        .collect();
+    let imports: Vec<TokenStream> = views
+        .iter()
+        .zip(&locals)
+        .map(|(v, local)| {
+            let field_name = Ident::new(&v.field_name, proc_macro2::Span::call_site());
+            let lazy_load = lazy_load(v, attrs);
+            let state = if attrs.lazy {
+                quote! { self.#field_name.as_mut() }
+            } else {
+                quote! { Some(&mut self.#field_name) }
+            };
+            quote! {
+                if let Some(model) = #local {
+                    #lazy_load
+                    if let Some(state) = #state {
+                        state.model = model;
+                    }
+                }
+            }
+        })
+        .collect();
+    let (shared_export, shared_read, shared_import) = match attrs.shared_model {
+        Some(_) => (
+            Some(quote! { file.set_shared(&*self.shared.read())?; }),
+            Some(quote! { let shared = file.shared()?; }),
+            Some(quote! {
+                if let Some(shared) = shared {
+                    *self.shared.write() = shared;
+                }
+            }),
+        ),
+        None => (None, None, None),
+    };
+
+    Some(quote! {
+        /// Export the models of the created views, the shared state and the current view
+        #[cfg(all(debug_assertions, feature = "interpreted"))]
+        pub fn export_state(
+            &self,
+        ) -> Result<dampen_dev::state_file::StateFile, dampen_dev::state_file::StateFileError> {
+            let mut file = dampen_dev::state_file::StateFile::new(self.current_view.path());
+            #(#exports)*
+            #shared_export
+            Ok(file)
+        }
+
+        /// Import a state file; views missing from the file keep their model
+        #[cfg(all(debug_assertions, feature = "interpreted"))]
+        pub fn import_state(
+            &mut self,
+            file: &dampen_dev::state_file::StateFile,
+        ) -> Result<(), dampen_dev::state_file::StateFileError> {
+            // Check every model before replacing any
+            #(let #locals = file.model(#paths)?;)*
+            #shared_read
+
+            #(#imports)*
+            #shared_import
+            if let Some(view) = file.view.as_deref().and_then(CurrentView::from_path) {
+                self.current_view = view;
+            }
+            Ok(())
+        }
+    })
+}
+
+/// Name of the handler dispatched when a view becomes active.
+///
+/// Views use it to (re)start the background work that was cancelled when they were left.
+const VIEW_ENTER_HANDLER: &str = "on_view_enter";
+
+/// Returns the `TaskScope` field name for a view (e.g., `settings` → `settings_tasks`).
+fn task_scope_field(view: &ViewInfo) -> Ident {
+    Ident::new(
+        &format!("{}_tasks", view.qualified_name()),
+        proc_macro2::Span::call_site(),
+    )
+}
+
+/// Returns the `switch_to_*` method name for a view (e.g., `settings.appearance` →
+/// `switch_to_settings_appearance`).
+fn switch_method(view: &ViewInfo) -> Ident {
+    Ident::new(
+        &format!("switch_to_{}", view.qualified_name()),
+        proc_macro2::Span::call_site(),
+    )
+}
+
+/// Returns the enum listing the views of a group (`CurrentView` for the root group,
+/// e.g. `SettingsView` for `settings`).
+fn group_enum_ident(group: &[String]) -> Ident {
+    if group.is_empty() {
+        return Ident::new("CurrentView", proc_macro2::Span::call_site());
+    }
+    let name: String = group
+        .iter()
+        .map(|directory| to_pascal_case(directory))
+        .collect();
+    Ident::new(&format!("{}View", name), proc_macro2::Span::call_site())
+}
+
+/// Returns the full `CurrentView` value of a view
+/// (e.g., `CurrentView::Settings(SettingsView::Appearance)`).
+fn view_variant(view: &ViewInfo) -> TokenStream {
+    let enum_name = group_enum_ident(&view.group);
+    let variant = Ident::new(&view.variant_name, proc_macro2::Span::call_site());
+    let mut tokens = quote! { #enum_name::#variant };
+
+    // Wrap in the variants of the enclosing groups, innermost first
+    for depth in (0..view.group.len()).rev() {
+        let parent = group_enum_ident(&view.group[..depth]);
+        let directory = Ident::new(
+            &to_pascal_case(&view.group[depth]),
+            proc_macro2::Span::call_site(),
+        );
+        tokens = quote! { #parent::#directory(#tokens) };
+    }
+
+    tokens
+}
+
+/// Returns the statement binding a view's `AppState` for mutation, and the expression
+/// reaching it.
+///
+/// Lazy views bind `state` from their `Option` field, returning early if the view was never
+/// created; other views use the field directly.
+fn state_binding(view: &ViewInfo, attrs: &MacroAttributes) -> (Option<TokenStream>, TokenStream) {
+    let field_name = Ident::new(&view.field_name, proc_macro2::Span::call_site());
+    if attrs.lazy {
+        (
+            Some(quote! {
+                let Some(state) = self.#field_name.as_mut() else {
+                    return iced::Task::none();
+                };
+            }),
+            quote! { state },
+        )
+    } else {
+        (None, quote! { self.#field_name })
+    }
+}
+
+/// Wraps a handler dispatch so that a panic of the handler is shown in the error overlay.
+///
+/// Only generated with `catch_panics = true`. The model of the view is cloned before each
+/// registered handler runs and restored if it panics, so every dispatch pays for a clone of
+/// the model; messages naming no handler of the view skip it. Handlers are only caught in
+/// debug builds of interpreted mode, where the overlay exists.
+fn catch_handler_panic(state: &TokenStream, dispatch: TokenStream) -> TokenStream {
+    let handler_name = handler_name();
+    quote! {
+        {
+            #[cfg(all(debug_assertions, feature = "interpreted"))]
+            {
+                let handler_name = #handler_name;
+                let snapshot = #state
+                    .handler_registry
+                    .contains(&handler_name)
+                    .then(|| #state.model.clone());
+                match dampen_dev::catch_handler_panic(&handler_name, || #dispatch) {
+                    Ok(task) => task,
+                    Err(report) => {
+                        if let Some(snapshot) = snapshot {
+                            #state.model = snapshot;
+                        }
+                        self.error_overlay.show_panic(report);
+                        iced::Task::none()
+                    }
+                }
+            }
+            #[cfg(not(all(debug_assertions, feature = "interpreted")))]
+            {
+                #dispatch
+            }
+        }
+    }
+}
+
+/// Wraps a handler dispatch in the `dampen_core::log` span of the handler.
+///
+/// Events logged by the handler are annotated with the view, the handler name and the
+/// reload generation of the view.
+fn log_handler_span(view: &ViewInfo, state: &TokenStream, dispatch: TokenStream) -> TokenStream {
+    let view_path = view.path();
+    let handler_name = handler_name();
+    quote! {
+        {
+            let handler_name = #handler_name;
+            let _span = dampen_core::log::handler_span(
+                #view_path,
+                &handler_name,
+                #state.reload_generation,
+            )
+            .entered();
+            #dispatch
+        }
+    }
+}
+
+/// Wraps a handler dispatch so that the message and the models before and after the
+/// handler are recorded in the time-travel timeline (debug builds of interpreted mode).
+fn record_in_timeline(view: &ViewInfo, state: &TokenStream, dispatch: TokenStream) -> TokenStream {
+    let view_path = view.path();
+    quote! {
+        {
+            #[cfg(all(debug_assertions, feature = "interpreted"))]
+            let recorded = dampen_dev::time_travel::describe(&handler_msg)
+                .map(|message| (message, #state.model.clone()));
+            let task = #dispatch;
+            #[cfg(all(debug_assertions, feature = "interpreted"))]
+            if let Some((message, before)) = recorded {
+                self.timeline.record(#view_path, message, before, &#state.model);
+            }
+            task
+        }
+    }
+}
+
+/// Returns the expression naming the handler of `handler_msg` (empty for `HandlerMessage::None`).
+fn handler_name() -> TokenStream {
+    quote! {
+        match &handler_msg {
+            dampen_iced::HandlerMessage::Handler(name, _) => name.clone(),
+            dampen_iced::HandlerMessage::None => String::new(),
+        }
+    }
+}
+
+/// Returns the statement logging the hot-reload of a view, in its `dampen_core::log` span.
+fn log_reload(view: &ViewInfo, state: &TokenStream) -> TokenStream {
+    let view_path = view.path();
+    quote! {
+        dampen_core::log::reload_span(#view_path, #state.reload_generation)
+            .in_scope(|| dampen_core::log::info!("view reloaded"));
+    }
+}
+
+/// Returns the `update()` arm answering the commands of `dampen_dev::debug_server`.
+///
+/// `views` lists the views, `model` and `evaluate` inspect the state of a view, and
+/// `dispatch` runs a handler of the current view through `update()` itself.
+fn debug_server_arm(
+    views: &[ViewInfo],
+    attrs: &MacroAttributes,
+    debug_server_variant: &Ident,
+) -> TokenStream {
+    let message_type = &attrs.message_type;
+    let handler_variant = &attrs.handler_variant;
+    let paths: Vec<String> = views.iter().map(ViewInfo::path).collect();
+    let variants: Vec<TokenStream> = views.iter().map(view_variant).collect();
+    let states: Vec<TokenStream> = views
+        .iter()
+        .map(|v| {
+            let field_name = Ident::new(&v.field_name, proc_macro2::Span::call_site());
+            if attrs.lazy {
+                quote! { self.#field_name.as_ref() }
+            } else {
+                quote! { Some(&self.#field_name) }
+            }
+        })
+        .collect();
+
+    quote! {
+        #[cfg(all(debug_assertions, feature = "interpreted"))]
+        #message_type::#debug_server_variant(request) => {
+            use dampen_dev::debug_server::DebugCommand;
+
+            let current = self.current_view.path();
+            match &request.command {
+                DebugCommand::Views => {
+                    request.respond(Ok(dampen_dev::debug_server::views(current, &[#(#paths),*])));
+                }
+                DebugCommand::Dispatch { handler, value } => {
+                    let known = match self.current_view {
+                        #(#variants => #states
+                            .is_some_and(|state| state.handler_registry.contains(handler)),)*
+                    };
+                    if !known {
+                        request.respond(Err(format!("Unknown handler '{}' in view '{}'", handler, current)));
+                        return iced::Task::none();
+                    }
+                    request.respond(Ok(Default::default()));
+                    return self.update(#message_type::#handler_variant(
+                        dampen_iced::HandlerMessage::Handler(handler.clone(), value.clone()),
+                    ));
+                }
+                command => {
+                    let view = command.view().unwrap_or(current);
+                    let result = match view {
+                        #(#paths => match #states {
+                            Some(state) => dampen_dev::debug_server::inspect(state, command),
+                            None => Err(format!("View '{}' is not created yet", view)),
+                        },)*
+                        _ => Err(format!("Unknown view '{}'", view)),
+                    };
+                    request.respond(result);
+                }
+            }
+            iced::Task::none()
+        }
+    }
+}
+
+/// Returns the `update()` arm handling the events of the time-travel timeline.
+///
+/// Each step restores the recorded model into the state of its view and shows that view,
+/// which re-renders at that point of the timeline.
+fn time_travel_arm(
+    views: &[ViewInfo],
+    attrs: &MacroAttributes,
+    time_travel_variant: &Ident,
+) -> TokenStream {
+    let message_type = &attrs.message_type;
+    let restore_arms: Vec<_> = views
+        .iter()
+        .map(|v| {
+            let path = v.path();
+            let field_name = Ident::new(&v.field_name, proc_macro2::Span::call_site());
+            let state = if attrs.lazy {
+                quote! { self.#field_name.as_mut() }
+            } else {
+                quote! { Some(&mut self.#field_name) }
+            };
+            quote! {
+                #path => {
+                    if let Some(state) = #state {
+                        dampen_dev::time_travel::restore(&mut state.model, snapshot);
+                    }
+                }
+            }
+        })
+        .collect();
+    let restore = quote! {
+        match view {
+            #(#restore_arms)*
+            _ => {}
+        }
+        if let Some(current_view) = CurrentView::from_path(view) {
+            self.current_view = current_view;
+        }
+    };
+
+    quote! {
+        #[cfg(all(debug_assertions, feature = "interpreted"))]
+        #message_type::#time_travel_variant(event) => {
+            use dampen_dev::time_travel::Event;
+
+            match event {
+                Event::Toggle => self.timeline.toggle(),
+                Event::Back => {
+                    if let Some((view, snapshot)) = self.timeline.step_back() {
+                        #restore
+                    }
+                }
+                Event::Forward => {
+                    if let Some((view, snapshot)) = self.timeline.step_forward() {
+                        #restore
+                    }
+                }
+                Event::Latest => {
+                    while let Some((view, snapshot)) = self.timeline.step_forward() {
+                        #restore
+                    }
+                }
+            }
+            iced::Task::none()
+        }
+    }
+}
+
+/// Returns the `update()` arm handling the events of the state toolbar.
+///
+/// Exports and imports go through `export_state()` and `import_state()`; their outcome is
+/// shown in the toolbar.
+fn state_file_arm(attrs: &MacroAttributes, state_file_variant: &Ident) -> TokenStream {
+    let message_type = &attrs.message_type;
+    quote! {
+        #[cfg(all(debug_assertions, feature = "interpreted"))]
+        #message_type::#state_file_variant(event) => {
+            use dampen_dev::state_file::{Event, StateFile};
+
+            match event {
+                Event::Toggle => self.state_tools.toggle(),
+                Event::Export => {
+                    let path = self.state_tools.export_path.clone();
+                    let result = self
+                        .export_state()
+                        .and_then(|file| file.write(&path))
+                        .map(|()| format!("Exported state to {}", path.display()));
+                    self.state_tools.report(result);
+                }
+                Event::Import => {
+                    let path = self.state_tools.import_path.clone();
+                    let result = StateFile::read(&path)
+                        .and_then(|file| self.import_state(&file))
+                        .map(|()| format!("Imported state from {}", path.display()));
+                    self.state_tools.report(result);
+                }
+            }
+            iced::Task::none()
+        }
+    }
+}
+
+/// Returns the `update()` arm handling the events of the inspector.
+///
+/// Selected widgets are opened in the `.dampen` file of the current view.
+fn inspector_arm(
+    views: &[ViewInfo],
+    attrs: &MacroAttributes,
+    inspector_variant: &Ident,
+) -> TokenStream {
+    let message_type = &attrs.message_type;
+    let file_arms = views.iter().map(|v| {
+        let variant = view_variant(v);
+        let dampen_file = v.dampen_file.to_string_lossy().to_string();
+        quote! { #variant => #dampen_file, }
+    });
+    quote! {
+        #[cfg(all(debug_assertions, feature = "interpreted"))]
+        #message_type::#inspector_variant(event) => {
+            use dampen_dev::inspector::Event;
+
+            match event {
+                Event::Toggle => self.inspector.toggle(),
+                Event::Hover(node) => self.inspector.hover(node),
+                Event::Select(node) => {
+                    let file = match self.current_view {
+                        #(#file_arms)*
+                    };
+                    self.inspector.open(std::path::Path::new(file), &node);
+                }
+            }
+            iced::Task::none()
+        }
+    }
+}
+
+/// Returns the statement creating a lazy view's `AppState` if it does not exist yet.
+fn lazy_load(view: &ViewInfo, attrs: &MacroAttributes) -> Option<TokenStream> {
+    attrs.lazy.then(|| {
+        let variant = view_variant(view);
+        quote! {
+            self.prefetch(#variant);
+        }
+    })
+}
+
+/// Returns the call creating a view's `AppState`, with its shared context if it has one.
+///
+/// `from_self` reads the shared context from the app fields instead of `init()` locals.
+/// The style libraries listed in `styles` are merged into the document of the new state.
+fn create_app_state_call(view: &ViewInfo, attrs: &MacroAttributes, from_self: bool) -> TokenStream {
+    let create_state = create_view_state_call(view, attrs, from_self);
+    if attrs.styles.is_empty() {
+        return create_state;
+    }
+    quote! {
+        {
+            let mut state = #create_state;
+            Self::apply_style_libraries(&mut state.document);
+            state
+        }
+    }
+}
+
+/// Returns the call of the view module's `create_app_state` function.
+fn create_view_state_call(
+    view: &ViewInfo,
+    attrs: &MacroAttributes,
+    from_self: bool,
+) -> TokenStream {
+    // Convert module_path to Rust path tokens (e.g., "ui::window" -> ui::window)
+    let module_parts: Vec<_> = view
+        .module_path
+        .split("::")
+        .map(|part| Ident::new(part, proc_macro2::Span::call_site()))
+        .collect();
+
+    // If the view has a shared model, call create_app_state_with_shared
+    // Otherwise call create_app_state
+    match view_shared(view, attrs) {
+        Some((shared_field, _)) if from_self => quote! {
+            #(#module_parts)::*::create_app_state_with_shared(self.#shared_field.clone())
+        },
+        Some((shared_field, _)) => quote! {
+            #(#module_parts)::*::create_app_state_with_shared(#shared_field.clone())
+        },
+        None => quote! {
+            #(#module_parts)::*::create_app_state()
+        },
+    }
+}
+
+/// Returns the condition matching a reloaded file to a view.
+///
+/// Flat views match on their file name; views of a group match on their path relative to
+/// `ui_dir` (e.g., `settings/appearance.dampen`), as file names repeat across groups.
+fn hot_reload_path_match(view: &ViewInfo) -> TokenStream {
+    let dampen_file_name = view
+        .dampen_file
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or(&view.view_name);
+
+    if view.group.is_empty() {
+        quote! { path_str.ends_with(#dampen_file_name) }
+    } else {
+        let relative_path = format!("{}/{}", view.group.join("/"), dampen_file_name);
+        quote! { std::path::Path::new(path_str).ends_with(#relative_path) }
+    }
+}
+
+/// Returns the statements replacing the document of `state` with the reloaded `document`,
+/// merging the style libraries into it again.
+///
+/// The statements bind `scope`, the `dampen_dev::reload::ReloadScope` of the widgets
+/// changed by the reload.
+fn reload_document(state: &TokenStream, attrs: &MacroAttributes) -> TokenStream {
+    // Lazy views bind `state` to `&mut AppState` already
+    let state_mut = if attrs.lazy {
+        state.clone()
+    } else {
+        quote! { &mut #state }
+    };
+    if attrs.styles.is_empty() {
+        return quote! {
+            let scope = dampen_dev::reload::apply_incremental_hot_reload(#state_mut, *document.clone());
+        };
+    }
+    quote! {
+        let mut document = *document.clone();
+        Self::apply_style_libraries(&mut document);
+        let scope = dampen_dev::reload::apply_incremental_hot_reload(#state_mut, document);
+    }
+}
+
+/// Returns the `SharedContext` field name of a group (e.g., `settings` → `settings_shared`).
+fn group_shared_field(group_path: &str) -> Ident {
+    Ident::new(
+        &format!("{}_shared", group_path.replace('.', "_")),
+        proc_macro2::Span::call_site(),
+    )
+}
+
+/// Returns the app-wide shared state type: `shared_model`, or the `SharedContexts` of `shared`.
+fn app_shared_type(attrs: &MacroAttributes) -> Option<TokenStream> {
+    if let Some(shared_model) = &attrs.shared_model {
+        return Some(quote! { shared::#shared_model });
+    }
+    (!attrs.shared.is_empty()).then(|| quote! { dampen_core::SharedContexts })
+}
+
+/// Returns the binding name of a model listed in `shared` (e.g., `UserSession` → `user_session`).
+fn context_name(model: &Ident) -> String {
+    let mut name = String::new();
+    for (i, c) in model.to_string().chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                name.push('_');
+            }
+            name.extend(c.to_lowercase());
+        } else {
+            name.push(c);
+        }
+    }
+    name
+}
+
+/// Returns the `SharedContext` field and state type used by a view, if any.
+///
+/// The closest group listed in `group_shared_models` wins, falling back to the app-wide
+/// shared state.
+fn view_shared(view: &ViewInfo, attrs: &MacroAttributes) -> Option<(Ident, TokenStream)> {
+    let group_shared = attrs
+        .group_shared_models
+        .iter()
+        .filter(|(group_path, _)| {
+            let segments: Vec<&str> = group_path.split('.').collect();
+            view.group.len() >= segments.len()
+                && view.group.iter().zip(&segments).all(|(a, b)| a == b)
+        })
+        .max_by_key(|(group_path, _)| group_path.split('.').count());
+
+    if let Some((group_path, model)) = group_shared {
+        return Some((group_shared_field(group_path), quote! { shared::#model }));
+    }
+
+    app_shared_type(attrs).map(|shared_type| {
+        (
+            Ident::new("shared", proc_macro2::Span::call_site()),
+            shared_type,
+        )
+    })
+}
+
+/// Returns `true` if any view may display remote images.
+///
+/// This is the case when an `<image>` has a bound or `http(s)` source, or sets
+/// `placeholder`/`error_src`; the app then drives `dampen_iced::image_loader`.
+fn views_load_remote_images(views: &[ViewInfo]) -> bool {
+    views.iter().any(|view| {
+        let Ok(content) = std::fs::read_to_string(&view.dampen_file) else {
+            return false;
+        };
+        let Ok(document) = roxmltree::Document::parse(&content) else {
+            return false;
+        };
+        document
+            .descendants()
+            .filter(|node| node.has_tag_name("image"))
+            .any(|image| {
+                let src = image.attribute("src").unwrap_or_default();
+                src.contains('{')
+                    || src.starts_with("http://")
+                    || src.starts_with("https://")
+                    || image.has_attribute("placeholder")
+                    || image.has_attribute("error_src")
+            })
+    })
+}
+
+/// Directories of the assets referenced by the views, watched by hot-reload
+///
+/// These are the directories of the local files of `<image>` and `<svg>`
+/// widgets (their static `src`, `path`, `placeholder` and `error_src`,
+/// breakpoint variants included), the theme directory when it holds fonts,
+/// and the `i18n`, `locales` or `translations` directory of the package.
+/// Relative paths are looked up from the package directory and its parents,
+/// as `dampen_core::assets::locate` does.
+fn view_asset_dirs(views: &[ViewInfo], attrs: &MacroAttributes) -> Vec<PathBuf> {
+    const PATH_ATTRIBUTES: [&str; 4] = ["src", "path", "placeholder", "error_src"];
+
+    let manifest_dir =
+        PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".to_string()));
+    let mut dirs: Vec<PathBuf> = Vec::new();
+    let mut add = |dir: PathBuf| {
+        if !dirs.contains(&dir) {
+            dirs.push(dir);
+        }
+    };
+
+    for view in views {
+        let Ok(content) = std::fs::read_to_string(&view.dampen_file) else {
+            continue;
+        };
+        let Ok(document) = roxmltree::Document::parse(&content) else {
+            continue;
+        };
+        let files = document
+            .descendants()
+            .filter(|node| node.has_tag_name("image") || node.has_tag_name("svg"))
+            .flat_map(|node| node.attributes())
+            .filter(|attribute| {
+                let name = attribute.name();
+                PATH_ATTRIBUTES.iter().any(|path_attribute| {
+                    name == *path_attribute || name.ends_with(&format!("-{}", path_attribute))
+                })
+            })
+            .map(|attribute| attribute.value())
+            .filter(|value| {
+                !value.is_empty()
+                    && !value.contains('{')
+                    && !["http://", "https://", "data:"]
+                        .iter()
+                        .any(|scheme| value.starts_with(scheme))
+            });
+        for file in files {
+            let found = manifest_dir
+                .ancestors()
+                .map(|dir| dir.join(file))
+                .find(|candidate| candidate.is_file());
+            if let Some(dir) = found.as_ref().and_then(|file| file.parent()) {
+                add(dir.to_path_buf());
+            }
+        }
+    }
+
+    let theme_dir = manifest_dir.join(&attrs.ui_dir).join("theme");
+    let has_fonts = std::fs::read_dir(&theme_dir).is_ok_and(|entries| {
+        entries.flatten().any(|entry| {
+            entry
+                .path()
+                .extension()
+                .is_some_and(|ext| ext == "ttf" || ext == "otf")
+        })
+    });
+    if has_fonts {
+        add(theme_dir);
+    }
+    for translations in ["i18n", "locales", "translations"] {
+        let dir = manifest_dir.join(translations);
+        if dir.is_dir() {
+            add(dir);
+        }
+    }
+
+    dirs
+}
+
+/// Returns `true` if the app has a theme file, whose themes can be switched at runtime.
+fn has_theme_file(attrs: &MacroAttributes) -> bool {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".to_string());
+    PathBuf::from(manifest_dir)
+        .join(&attrs.ui_dir)
+        .join("theme/theme.dampen")
+        .exists()
+}
+
+/// Returns `true` if the app must follow the accessibility preferences of the system.
+///
+/// This is the case when a view binds `{system.reduced_motion}` or
+/// `{system.high_contrast}`, or when the theme file defines a `_high_contrast`
+/// variant; the app then renders again when the preferences change (see
+/// `dampen_iced::system_preferences`).
+fn views_use_system_preferences(views: &[ViewInfo], attrs: &MacroAttributes) -> bool {
+    // `system.` starting a path, not ending another one such as `os.system.name`
+    fn binds_system(value: &str) -> bool {
+        value.contains('{')
+            && value.match_indices("system.").any(|(index, _)| {
+                value[..index]
+                    .chars()
+                    .next_back()
+                    .is_none_or(|c| !(c.is_alphanumeric() || c == '_' || c == '.'))
+            })
+    }
+
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".to_string());
+    let theme_file = PathBuf::from(manifest_dir)
+        .join(&attrs.ui_dir)
+        .join("theme/theme.dampen");
+    let high_contrast_themes =
+        std::fs::read_to_string(theme_file).is_ok_and(|theme| theme.contains("_high_contrast\""));
+
+    high_contrast_themes
+        || views.iter().any(|view| {
+            let Ok(content) = std::fs::read_to_string(&view.dampen_file) else {
+                return false;
+            };
+            let Ok(document) = roxmltree::Document::parse(&content) else {
+                return false;
+            };
+            document
+                .descendants()
+                .flat_map(|node| node.attributes())
+                .any(|attribute| binds_system(attribute.value()))
+        })
+}
+
+/// Returns `true` if any view has breakpoint-prefixed attributes (`mobile-spacing`),
+/// declares its own `<breakpoints>` or binds `{env.breakpoint}`.
+///
+/// The app then follows the size of the window, and renders again when its
+/// breakpoint changes (see `dampen_iced::viewport`).
+fn views_use_breakpoints(views: &[ViewInfo]) -> bool {
+    views_bind_env(views, "breakpoint")
+        || views.iter().any(|view| {
+            let Ok(content) = std::fs::read_to_string(&view.dampen_file) else {
+                return false;
+            };
+            let Ok(document) = roxmltree::Document::parse(&content) else {
+                return false;
+            };
+            document
+                .descendants()
+                .any(|node| node.has_tag_name("breakpoints"))
+                || document
+                    .descendants()
+                    .flat_map(|node| node.attributes())
+                    .any(|attribute| {
+                        attribute.name().split_once('-').is_some_and(|(prefix, _)| {
+                            matches!(prefix, "mobile" | "tablet" | "desktop")
+                        })
+                    })
+        })
+}
+
+/// Returns `true` if any view binds an `{env.<field>}` value whose field starts
+/// with `field`, such as `{env.viewport_width}` for `viewport_`.
+fn views_bind_env(views: &[ViewInfo], field: &str) -> bool {
+    let path = format!("env.{}", field);
+    // `env.` starting a path, not ending another one such as `config.env.name`
+    let binds_env = |value: &str| {
+        value.contains('{')
+            && value.match_indices(&path).any(|(index, _)| {
+                value[..index]
+                    .chars()
+                    .next_back()
+                    .is_none_or(|c| !(c.is_alphanumeric() || c == '_' || c == '.'))
+            })
+    };
+
+    views.iter().any(|view| {
+        let Ok(content) = std::fs::read_to_string(&view.dampen_file) else {
+            return false;
+        };
+        let Ok(document) = roxmltree::Document::parse(&content) else {
+            return false;
+        };
+        document
+            .descendants()
+            .flat_map(|node| node.attributes())
+            .any(|attribute| binds_env(attribute.value()))
+    })
+}
+
+/// Returns `true` if any view debounces an input with `debounce_ms`.
+///
+/// The app then emits the debounced handler messages (see `dampen_iced::debounce`).
+fn views_debounce_inputs(views: &[ViewInfo]) -> bool {
+    views.iter().any(|view| {
+        let Ok(content) = std::fs::read_to_string(&view.dampen_file) else {
+            return false;
+        };
+        let Ok(document) = roxmltree::Document::parse(&content) else {
+            return false;
+        };
+        document
+            .descendants()
+            .any(|node| node.has_attribute("debounce_ms"))
+    })
+}
+
+/// Returns `true` if the handlers of any view may show toasts.
+///
+/// This is the case when a view's `.rs` file calls `dampen_iced::notify`; the app
+/// then renders the toast overlay and re-renders when a toast expires.
+fn views_show_notifications(views: &[ViewInfo]) -> bool {
+    views.iter().any(|view| {
+        std::fs::read_to_string(&view.rs_file).is_ok_and(|source| source.contains("notify::"))
+    })
+}
+
+/// Returns the functions of a view's module annotated with `#[ui_handler]`.
+fn ui_handler_functions(view: &ViewInfo) -> Vec<syn::ItemFn> {
+    let Ok(source) = std::fs::read_to_string(&view.rs_file) else {
+        return Vec::new();
+    };
+    let Ok(file) = syn::parse_file(&source) else {
+        return Vec::new();
+    };
+
+    file.items
+        .into_iter()
+        .filter_map(|item| match item {
+            syn::Item::Fn(function) => Some(function),
+            _ => None,
+        })
+        .filter(|function| function.attrs.iter().any(is_ui_handler_attr))
+        .collect()
+}
+
+fn is_ui_handler_attr(attr: &syn::Attribute) -> bool {
+    attr.path()
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "ui_handler")
+}
+
+/// Returns the `(handler, label)` pairs of a view's `#[ui_handler(palette = "...")]` handlers.
+fn palette_labels(view: &ViewInfo) -> Vec<(String, String)> {
+    let mut labels = Vec::new();
+    for function in ui_handler_functions(view) {
+        for attr in &function.attrs {
+            if !is_ui_handler_attr(attr) || !matches!(attr.meta, syn::Meta::List(_)) {
+                continue;
+            }
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("palette") {
+                    let label: LitStr = meta.value()?.parse()?;
+                    labels.push((function.sig.ident.to_string(), label.value()));
+                }
+                Ok(())
+            });
+        }
+    }
+    labels
+}
+
+/// Returns the names of the handlers a view defines: its `#[ui_handler]` functions and the
+/// names registered with `registry.register_*("name", ...)`.
+fn handler_names(view: &ViewInfo) -> Vec<String> {
+    let mut names: Vec<String> = ui_handler_functions(view)
+        .iter()
+        .map(|function| function.sig.ident.to_string())
+        .collect();
+    if let Some(tokens) = std::fs::read_to_string(&view.rs_file)
+        .ok()
+        .and_then(|source| source.parse::<proc_macro2::TokenStream>().ok())
+    {
+        collect_registered_names(tokens, &mut names);
+    }
+    names
+}
+
+/// Collects the string literals passed first to `register*(...)` calls.
+fn collect_registered_names(tokens: proc_macro2::TokenStream, names: &mut Vec<String>) {
+    use proc_macro2::{Delimiter, TokenTree};
+
+    let mut previous_is_register = false;
+    for token in tokens {
+        if let TokenTree::Group(group) = &token {
+            if previous_is_register
+                && group.delimiter() == Delimiter::Parenthesis
+                && let Some(TokenTree::Literal(literal)) = group.stream().into_iter().next()
+                && let Ok(name) = syn::parse_str::<LitStr>(&literal.to_string())
+            {
+                names.push(name.value());
+            }
+            collect_registered_names(group.stream(), names);
+        }
+        previous_is_register =
+            matches!(&token, TokenTree::Ident(ident) if ident.to_string().starts_with("register"));
+    }
+}
+
+/// Returns the absolute paths of the style libraries listed in `styles`.
+fn style_library_paths(attrs: &MacroAttributes) -> Vec<PathBuf> {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".to_string());
+    let ui_dir = PathBuf::from(manifest_dir).join(&attrs.ui_dir);
+    attrs
+        .styles
+        .iter()
+        .map(|library| ui_dir.join(library))
+        .collect()
+}
+
+/// Generates the `apply_style_libraries()` function merging the libraries listed in
+/// `styles` into a view document. Only generated when `styles` is specified.
+///
+/// Libraries are embedded in the binary; debug builds read them from disk first so that
+/// hot-reload picks up their changes. A library that fails to parse is skipped.
+///
+/// # Examples
+///
+/// ```ignore
+/// fn apply_style_libraries(document: &mut dampen_core::DampenDocument) {
+///     const LIBRARIES: &[(&str, &str)] = &[(
+///         "/path/to/src/ui/styles/buttons.dampen",
+///         include_str!("/path/to/src/ui/styles/buttons.dampen"),
+///     )];
+///     for (path, embedded) in LIBRARIES {
+///         // ...
+///         match dampen_core::parse(&xml) {
+///             Ok(library) => document.merge(&library),
+///             Err(error) => eprintln!("[dampen] Ignoring style library {}: {}", path, error),
+///         }
+///     }
+/// }
+/// ```
+pub fn generate_style_libraries_method(attrs: &MacroAttributes) -> Option<TokenStream> {
+    if attrs.styles.is_empty() {
+        return None;
+    }
+
+    let libraries = style_library_paths(attrs).into_iter().map(|path| {
+        let path = path.to_string_lossy().to_string();
+        quote! { (#path, include_str!(#path)) }
+    });
+
+    Some(quote! {
+        /// Merge the style libraries into a view document, the view's own definitions winning
+        fn apply_style_libraries(document: &mut dampen_core::DampenDocument) {
+            const LIBRARIES: &[(&str, &str)] = &[#(#libraries),*];
+            for (path, embedded) in LIBRARIES {
+                // Read the library again in debug builds, so hot-reload sees its changes
+                #[cfg(debug_assertions)]
+                let xml = std::fs::read_to_string(path).unwrap_or_else(|_| embedded.to_string());
+                #[cfg(not(debug_assertions))]
+                let xml = embedded.to_string();
+
+                match dampen_core::parse(&xml) {
+                    Ok(library) => document.merge(&library),
+                    Err(error) => eprintln!("[dampen] Ignoring style library {}: {}", path, error),
+                }
+            }
+        }
+    })
+}
+
+/// Generates the `palette_commands()` method listing the commands of the active view.
+///
+/// A view lists its handlers annotated with `#[ui_handler(palette = "...")]`, or every
+/// registered handler taking no value when none is annotated. Only generated when
+/// `command_palette_variant` is specified.
+///
+/// # Examples
+///
+/// ```ignore
+/// pub fn palette_commands(&self) -> Vec<dampen_core::PaletteCommand> {
+///     match self.current_view {
+///         CurrentView::Editor => vec![
+///             dampen_core::PaletteCommand::new("save_file", "Save File"),
+///         ],
+///         CurrentView::Settings => self.settings_state.handler_registry
+///             .names_without_value()
+///             .iter()
+///             .map(|name| dampen_core::PaletteCommand::from_handler(name))
+///             .collect(),
+///     }
+/// }
+/// ```
+pub fn generate_palette_method(views: &[ViewInfo], attrs: &MacroAttributes) -> Option<TokenStream> {
+    attrs.command_palette_variant.as_ref()?;
+
+    let match_arms: Vec<_> = views
+        .iter()
+        .map(|v| {
+            let variant = view_variant(v);
+            let field_name = Ident::new(&v.field_name, proc_macro2::Span::call_site());
+            let labels = palette_labels(v);
+
+            if !labels.is_empty() {
+                let commands = labels.iter().map(|(handler, label)| {
+                    quote! { dampen_core::PaletteCommand::new(#handler, #label) }
+                });
+                return quote! {
+                    #variant => vec![#(#commands),*],
+                };
+            }
+
+            let registry = if attrs.lazy {
+                quote! {
+                    let Some(state) = self.#field_name.as_ref() else {
+                        return Vec::new();
+                    };
+                    let registry = &state.handler_registry;
+                }
+            } else {
+                quote! {
+                    let registry = &self.#field_name.handler_registry;
+                }
+            };
+            quote! {
+                #variant => {
+                    #registry
+                    registry
+                        .names_without_value()
+                        .iter()
+                        .map(|name| dampen_core::PaletteCommand::from_handler(name))
+                        .collect()
+                }
+            }
+        })
+        .collect();
+
+    Some(quote! {
+        pub fn palette_commands(&self) -> Vec<dampen_core::PaletteCommand> {
+            match self.current_view {
+                #(#match_arms)*
+            }
+        }
+    })
+}
+
+/// Generates the per-view task scope methods.
+///
+/// Creates:
+/// - `cancel_current_view_tasks()`: aborts every task scoped to the active view
+/// - `enter_current_view()`: dispatches the active view's `on_view_enter` handler, if it is
+///   registered, so the view can restart the tasks that were cancelled when it was left
+///
+/// Tasks returned by handlers listed in the `global_tasks` attribute are never scoped and
+/// keep running across view switches.
 ///
 /// # Arguments
 ///
 /// * `views` - Slice of discovered view information
+/// * `attrs` - Parsed macro attributes (for message_type and handler_variant)
 ///
 /// # Returns
 ///
-/// Token stream containing one method per view:
+/// Token stream containing both methods:
 ///
 /// ```ignore
-/// pub fn switch_to_window(&mut self) {
-///     self.current_view = CurrentView::Window;
-/// }
-///
-/// pub fn switch_to_settings(&mut self) {
-///     self.current_view = CurrentView::Settings;
+/// pub fn cancel_current_view_tasks(&mut self) {
+///     match self.current_view {
+///         CurrentView::Window => { self.window_tasks.cancel_all(); }
+///         CurrentView::Settings => { self.settings_tasks.cancel_all(); }
+///     }
 /// }
-/// // ... one method per view
-/// ```
-///
-/// # Examples
-///
-/// Users can call these methods in their update logic:
 ///
-/// ```ignore
-/// fn custom_update(&mut self, msg: Message) {
-///     match msg {
-///         Message::GoToSettings => self.switch_to_settings(),
-///         // ...
-///     }
+/// pub fn enter_current_view(&mut self) -> iced::Task<Message> {
+///     let has_enter_handler = match self.current_view {
+///         CurrentView::Window => self.window_state.handler_registry.contains("on_view_enter"),
+///         CurrentView::Settings => self.settings_state.handler_registry.contains("on_view_enter"),
+///     };
+///     // ...dispatches Message::Handler(HandlerMessage::Handler("on_view_enter", None))
 /// }
 /// ```
-pub fn generate_switch_to_methods(views: &[ViewInfo]) -> TokenStream {
-    let methods: Vec<_> = views
+pub fn generate_task_scope_methods(views: &[ViewInfo], attrs: &MacroAttributes) -> TokenStream {
+    let message_type = &attrs.message_type;
+    let handler_variant = &attrs.handler_variant;
+
+    let cancel_arms: Vec<_> = views
         .iter()
         .map(|v| {
-            let method_name = Ident::new(
-                &format!("switch_to_{}", v.view_name),
-                proc_macro2::Span::call_site(),
-            );
-            let variant = Ident::new(&v.variant_name, proc_macro2::Span::call_site());
-
+            let variant = view_variant(v);
+            let scope_field = task_scope_field(v);
             quote! {
-                pub fn #method_name(&mut self) {
-                    self.current_view = CurrentView::#variant;
+                #variant => {
+                    self.#scope_field.cancel_all();
+                }
+            }
+        })
+        .collect();
+
+    let enter_arms: Vec<_> = views
+        .iter()
+        .map(|v| {
+            let variant = view_variant(v);
+            let field_name = Ident::new(&v.field_name, proc_macro2::Span::call_site());
+            if attrs.lazy {
+                quote! {
+                    #variant => self.#field_name.as_ref().is_some_and(|state| {
+                        state.handler_registry.contains(#VIEW_ENTER_HANDLER)
+                    }),
+                }
+            } else {
+                quote! {
+                    #variant => self.#field_name.handler_registry.contains(#VIEW_ENTER_HANDLER),
                 }
             }
         })
         .collect();
 
     quote! {
-        #(#methods)*
+        pub fn cancel_current_view_tasks(&mut self) {
+            match self.current_view {
+                #(#cancel_arms)*
+            }
+        }
+
+        pub fn enter_current_view(&mut self) -> iced::Task<#message_type> {
+            let has_enter_handler = match self.current_view {
+                #(#enter_arms)*
+            };
+
+            if has_enter_handler {
+                self.update(#message_type::#handler_variant(
+                    dampen_iced::HandlerMessage::Handler(#VIEW_ENTER_HANDLER.to_string(), None),
+                ))
+            } else {
+                iced::Task::none()
+            }
+        }
     }
 }
 
@@ -702,6 +2731,7 @@ pub fn generate_switch_to_methods(views: &[ViewInfo]) -> TokenStream {
 ///
 /// Creates update logic that:
 /// - Routes `Handler` messages to the appropriate view's handler registry
+/// - Scopes handler tasks to the active view, except for handlers listed in `global_tasks`
 /// - Supports hot-reload file change events (debug builds only)
 /// - Supports error overlay dismissal (debug builds only)
 /// - Returns `iced::Task` for asynchronous operations
@@ -739,108 +2769,135 @@ pub fn generate_update_method(views: &[ViewInfo], attrs: &MacroAttributes) -> To
     let handler_variant = &attrs.handler_variant;
     let message_type = &attrs.message_type;
 
-    // Determine if we're using shared state
-    let use_shared = attrs.shared_model.is_some();
+    // Determine which dispatch helpers the views need
+    let use_shared = views.iter().any(|v| view_shared(v, attrs).is_some());
+    let use_unshared = views.iter().any(|v| view_shared(v, attrs).is_none());
 
     // Generate match arms for each view's handler dispatch
     let view_match_arms: Vec<_> = views
         .iter()
         .map(|v| {
-            let variant = Ident::new(&v.variant_name, proc_macro2::Span::call_site());
-            let field_name = Ident::new(&v.field_name, proc_macro2::Span::call_site());
+            let variant = view_variant(v);
+            let scope_field = task_scope_field(v);
+            let (bind_state, state) = state_binding(v, attrs);
 
-            if use_shared {
-                quote! {
-                    CurrentView::#variant => {
-                        // Handle built-in set_theme action
-                        if let dampen_iced::HandlerMessage::Handler(name, value) = &handler_msg {
-                            if name == "set_theme" {
-                                if let Some(ref mut ctx) = self.#field_name.theme_context {
-                                    if let Some(theme_name) = value {
-                                        let _ = ctx.set_theme(&theme_name);
-                                    }
-                                }
-                                return iced::Task::none();
-                            }
-                        }
-                        dispatch_handler_with_task_and_shared(
-                            &mut self.#field_name.model,
-                            &self.#field_name.handler_registry,
-                            &self.shared,
-                            handler_msg
-                        )
-                    }
-                }
+            let dispatch = match view_shared(v, attrs) {
+                Some((shared_field, _)) => quote! {
+                    dispatch_handler_with_task_and_shared(
+                        &mut #state.model,
+                        &#state.handler_registry,
+                        &self.#shared_field,
+                        &mut self.#scope_field,
+                        handler_msg
+                    )
+                },
+                None => quote! {
+                    dispatch_handler_with_task(
+                        &mut #state.model,
+                        &#state.handler_registry,
+                        &mut self.#scope_field,
+                        handler_msg
+                    )
+                },
+            };
+            let dispatch = if attrs.catch_panics {
+                catch_handler_panic(&state, dispatch)
             } else {
-                quote! {
-                    CurrentView::#variant => {
-                        // Handle built-in set_theme action
-                        if let dampen_iced::HandlerMessage::Handler(name, value) = &handler_msg {
-                            if name == "set_theme" {
-                                if let Some(ref mut ctx) = self.#field_name.theme_context {
-                                    if let Some(theme_name) = value {
-                                        let _ = ctx.set_theme(&theme_name);
-                                    }
-                                }
-                                return iced::Task::none();
-                            }
+                dispatch
+            };
+            let dispatch = if attrs.time_travel_variant.is_some() {
+                record_in_timeline(v, &state, dispatch)
+            } else {
+                dispatch
+            };
+            let dispatch = log_handler_span(v, &state, dispatch);
+
+            quote! {
+                #variant => {
+                    #bind_state
+                    // Handle built-in set_theme action, applied to every view below,
+                    // unless the view has its own set_theme handler
+                    if let dampen_iced::HandlerMessage::Handler(name, value) = &handler_msg
+                        && name == "set_theme"
+                        && !#state.handler_registry.contains("set_theme")
+                    {
+                        if let Some(theme_name) = value {
+                            self.theme_switch.request(theme_name);
                         }
-                        dispatch_handler_with_task(
-                            &mut self.#field_name.model,
-                            &self.#field_name.handler_registry,
-                            handler_msg
-                        )
+                        iced::Task::none()
+                    } else {
+                        // Collect the theme switches requested by the handler
+                        let theme_capture = dampen_core::theme_switch::capture();
+                        let task = #dispatch;
+                        theme_capture.finish(&mut self.theme_switch);
+                        task
                     }
                 }
             }
         })
         .collect();
 
+    // Views of nested groups are matched first, so a root view cannot claim their files
+    let mut hot_reload_views: Vec<&ViewInfo> = views.iter().collect();
+    hot_reload_views.sort_by_key(|v| std::cmp::Reverse(v.group.len()));
+
     // Generate hot-reload file matching arms if hot_reload_variant is specified
     let hot_reload_match_arms: Vec<_> =
         if attrs.hot_reload_variant.is_some() && attrs.dismiss_error_variant.is_some() {
-            views
+            hot_reload_views
                 .iter()
                 .map(|v| {
-                    let field_name = Ident::new(&v.field_name, proc_macro2::Span::call_site());
-                    let dampen_file_name = v
-                        .dampen_file
-                        .file_name()
-                        .and_then(|n| n.to_str())
-                        .unwrap_or(&v.view_name);
+                    let matches_path = hot_reload_path_match(v);
+                    let load_state = lazy_load(v, attrs);
+                    let (bind_state, state) = state_binding(v, attrs);
+                    let reload_document = reload_document(&state, attrs);
+                    let log_reload = log_reload(v, &state);
 
                     quote! {
-                        if path_str.ends_with(#dampen_file_name) {
+                        if #matches_path {
+                            #load_state
+                            #bind_state
                             // Update the AppState with the new document
-                            self.#field_name.hot_reload(*document.clone());
+                            #reload_document
+                            #log_reload
 
                             // Reload succeeded, clear any error overlay
                             #[cfg(debug_assertions)]
                             {
                                 self.error_overlay.hide();
                             }
-                            return iced::Task::none();
+
+                            // Restore scroll offsets and focus in the rebuilt subtrees
+                            return dampen_iced::widget_state::restore_widget_state(
+                                &scope.widget_state(&#state),
+                            );
                         }
                     }
                 })
                 .collect()
         } else if attrs.hot_reload_variant.is_some() {
             // No error overlay, just match files without clearing overlay
-            views
+            hot_reload_views
                 .iter()
                 .map(|v| {
-                    let field_name = Ident::new(&v.field_name, proc_macro2::Span::call_site());
-                    let dampen_file_name = v
-                        .dampen_file
-                        .file_name()
-                        .and_then(|n| n.to_str())
-                        .unwrap_or(&v.view_name);
+                    let matches_path = hot_reload_path_match(v);
+                    let load_state = lazy_load(v, attrs);
+                    let (bind_state, state) = state_binding(v, attrs);
+                    let reload_document = reload_document(&state, attrs);
+                    let log_reload = log_reload(v, &state);
 
                     quote! {
-                        if path_str.ends_with(#dampen_file_name) {
+                        if #matches_path {
+                            #load_state
+                            #bind_state
                             // Update the AppState with the new document
-                            self.#field_name.hot_reload(*document.clone());
-                            return iced::Task::none();
+                            #reload_document
+                            #log_reload
+
+                            // Restore scroll offsets and focus in the rebuilt subtrees
+                            return dampen_iced::widget_state::restore_widget_state(
+                                &scope.widget_state(&#state),
+                            );
                         }
                     }
                 })
@@ -849,14 +2906,102 @@ pub fn generate_update_method(views: &[ViewInfo], attrs: &MacroAttributes) -> To
             vec![]
         };
 
+    // A changed style library is merged again into every view, reloaded from its file
+    let style_library_reload = (!attrs.styles.is_empty()).then(|| {
+        let libraries = &attrs.styles;
+        let view_reloads = views.iter().map(|v| {
+            let field_name = Ident::new(&v.field_name, proc_macro2::Span::call_site());
+            let dampen_file = v.dampen_file.to_string_lossy().to_string();
+            let reload = quote! {
+                if let Some(mut document) = std::fs::read_to_string(#dampen_file)
+                    .ok()
+                    .and_then(|xml| dampen_core::parse(&xml).ok())
+                {
+                    Self::apply_style_libraries(&mut document);
+                    state.hot_reload(document);
+                }
+            };
+            if attrs.lazy {
+                quote! {
+                    if let Some(state) = self.#field_name.as_mut() {
+                        #reload
+                    }
+                }
+            } else {
+                quote! {
+                    {
+                        let state = &mut self.#field_name;
+                        #reload
+                    }
+                }
+            }
+        });
+        let hide_error_overlay = attrs.dismiss_error_variant.is_some().then(|| {
+            quote! {
+                #[cfg(debug_assertions)]
+                {
+                    self.error_overlay.hide();
+                }
+            }
+        });
+        quote! {
+            if [#(#libraries),*]
+                .iter()
+                .any(|library| std::path::Path::new(path_str).ends_with(library))
+            {
+                #(#view_reloads)*
+                #hide_error_overlay
+                return iced::Task::none();
+            }
+        }
+    });
+
+    // Generate theme update statements (one per view, each view holds its own ThemeContext)
+    let mut theme_update_arms: Vec<_> = views
+        .iter()
+        .map(|v| {
+            let field_name = Ident::new(&v.field_name, proc_macro2::Span::call_site());
+            if attrs.lazy {
+                quote! {
+                    if let Some(state) = self.#field_name.as_mut()
+                        && let Some(ref mut ctx) = state.theme_context
+                    {
+                        let diff = ctx.diff(&document);
+                        ctx.apply_update(diff);
+                    }
+                }
+            } else {
+                quote! {
+                    if let Some(ref mut ctx) = self.#field_name.theme_context {
+                        let diff = ctx.diff(&document);
+                        ctx.apply_update(diff);
+                    }
+                }
+            }
+        })
+        .collect();
+
+    // Lazy views not created yet will start from the updated themes
+    if attrs.lazy {
+        theme_update_arms.push(quote! {
+            if let Some(ref mut ctx) = self.theme_context {
+                let diff = ctx.diff(&document);
+                ctx.apply_update(diff);
+            }
+        });
+    }
+
     // Generate HotReload match arm if hot_reload_variant is specified
     let hot_reload_arm = if let Some(hot_reload_variant) = &attrs.hot_reload_variant {
         let parse_error_handling = if attrs.dismiss_error_variant.is_some() {
             quote! {
-                // Show error overlay
+                // Show every error of the file in the overlay
                 #[cfg(debug_assertions)]
                 {
-                    self.error_overlay.show(error);
+                    self.error_overlay.show_in_source(
+                        std::iter::once(error).chain(more_errors).collect(),
+                        content,
+                    );
                 }
                 iced::Task::none()
             }
@@ -874,17 +3019,38 @@ pub fn generate_update_method(views: &[ViewInfo], attrs: &MacroAttributes) -> To
                     dampen_dev::subscription::FileEvent::Success { path, document } => {
                         // Match path to corresponding view and update its AppState
                         if let Some(path_str) = path.to_str() {
+                            #style_library_reload
                             #(#hot_reload_match_arms)*
                         }
                         iced::Task::none()
                     }
-                    dampen_dev::subscription::FileEvent::ParseError { path, error, content: _ } => {
+                    dampen_dev::subscription::FileEvent::ThemeChanged { path: _, document } => {
+                        // Update only the changed themes in place, without rebuilding views
+                        #(#theme_update_arms)*
+                        iced::Task::none()
+                    }
+                    dampen_dev::subscription::FileEvent::ParseError { path, error, more_errors, content } => {
                         #parse_error_handling
                     }
+                    dampen_dev::subscription::FileEvent::AssetChanged { path } => {
+                        // Images and SVGs of the file are read again by the next view()
+                        dampen_iced::assets::invalidate(&path);
+                        iced::Task::none()
+                    }
                     dampen_dev::subscription::FileEvent::WatcherError { path: _, error: _ } => {
                         // Ignore watcher errors for now (permissions, etc.)
                         iced::Task::none()
                     }
+                    dampen_dev::subscription::FileEvent::Batch(batch) => {
+                        // Apply each file of the batch in reload order
+                        iced::Task::batch(
+                            batch
+                                .events
+                                .into_iter()
+                                .map(|event| self.update(#message_type::#hot_reload_variant(event)))
+                                .collect::<Vec<_>>(),
+                        )
+                    }
                 }
             }
         })
@@ -892,6 +3058,21 @@ pub fn generate_update_method(views: &[ViewInfo], attrs: &MacroAttributes) -> To
         None
     };
 
+    // Show the new problems of the last view() as warnings (a rebuild follows every update)
+    let build_diagnostics_check = attrs.dismiss_error_variant.is_some().then(|| {
+        quote! {
+            #[cfg(all(debug_assertions, feature = "interpreted"))]
+            {
+                let diagnostics = self.build_diagnostics.take_new();
+                if !diagnostics.is_empty() {
+                    self.error_overlay.show_warnings(
+                        diagnostics.iter().map(|d| d.to_parse_error()).collect(),
+                    );
+                }
+            }
+        }
+    });
+
     // Generate DismissError match arm if dismiss_error_variant is specified
     let dismiss_error_arm = attrs
         .dismiss_error_variant
@@ -906,6 +3087,68 @@ pub fn generate_update_method(views: &[ViewInfo], attrs: &MacroAttributes) -> To
             }
         });
 
+    // Generate ResetState match arm if reset_state_variant is specified
+    let reset_state_arm = attrs
+        .reset_state_variant
+        .as_ref()
+        .map(|reset_state_variant| {
+            let reset_arms = views.iter().map(|v| {
+                let variant = view_variant(v);
+                let (bind_state, state) = state_binding(v, attrs);
+                quote! {
+                    #variant => {
+                        #bind_state
+                        #state.model = Default::default();
+                    }
+                }
+            });
+            quote! {
+                #[cfg(all(debug_assertions, feature = "interpreted"))]
+                #message_type::#reset_state_variant => {
+                    match self.current_view {
+                        #(#reset_arms)*
+                    }
+                    self.error_overlay.hide();
+                    iced::Task::none()
+                }
+            }
+        });
+
+    // Generate the log viewer toggle arm if log_viewer_variant is specified
+    let log_viewer_arm = attrs.log_viewer_variant.as_ref().map(|log_viewer_variant| {
+        quote! {
+            #[cfg(all(debug_assertions, feature = "interpreted"))]
+            #message_type::#log_viewer_variant => {
+                self.show_logs = !self.show_logs;
+                iced::Task::none()
+            }
+        }
+    });
+
+    // Answer the debug server commands if debug_server_variant is specified
+    let debug_server_arm = attrs
+        .debug_server_variant
+        .as_ref()
+        .map(|debug_server_variant| debug_server_arm(views, attrs, debug_server_variant));
+
+    // Step through the timeline if time_travel_variant is specified
+    let time_travel_arm = attrs
+        .time_travel_variant
+        .as_ref()
+        .map(|time_travel_variant| time_travel_arm(views, attrs, time_travel_variant));
+
+    // Export and import the state if state_file_variant is specified
+    let state_file_arm = attrs
+        .state_file_variant
+        .as_ref()
+        .map(|state_file_variant| state_file_arm(attrs, state_file_variant));
+
+    // Highlight and open widgets if inspector_variant is specified
+    let inspector_arm = attrs
+        .inspector_variant
+        .as_ref()
+        .map(|inspector_variant| inspector_arm(views, attrs, inspector_variant));
+
     // Generate SwitchToView match arm if switch_view_variant is specified
     let switch_view_arm = attrs
         .switch_view_variant
@@ -915,66 +3158,84 @@ pub fn generate_update_method(views: &[ViewInfo], attrs: &MacroAttributes) -> To
             let switch_match_arms: Vec<_> = views
                 .iter()
                 .map(|v| {
-                    let variant = Ident::new(&v.variant_name, proc_macro2::Span::call_site());
-                    let switch_method = Ident::new(
-                        &format!("switch_to_{}", v.view_name),
-                        proc_macro2::Span::call_site(),
-                    );
+                    let variant = view_variant(v);
+                    let switch_method = switch_method(v);
 
                     quote! {
-                        CurrentView::#variant => self.#switch_method(),
+                        #variant => self.#switch_method(),
                     }
                 })
                 .collect();
 
+            // With nested views, the payload may also be a dotted path (see IntoCurrentView)
+            let resolve_view = attrs.nested_views.then(|| {
+                quote! {
+                    let Some(view) = IntoCurrentView::into_current_view(view) else {
+                        return iced::Task::none();
+                    };
+                }
+            });
+
             quote! {
                 #message_type::#switch_view_variant(view) => {
+                    #resolve_view
+                    let previous_view = self.current_view.clone();
                     match view {
                         #(#switch_match_arms)*
                     }
-                    iced::Task::none()
+                    if self.current_view != previous_view {
+                        self.enter_current_view()
+                    } else {
+                        iced::Task::none()
+                    }
                 }
             }
         });
 
     // Generate helper function(s) for handler dispatch
-    let helper_functions = if use_shared {
-        // Generate version with shared context
-        if let Some(ref shared_model) = attrs.shared_model {
-            quote! {
-                // Helper function to dispatch handlers with shared state and return tasks
-                fn dispatch_handler_with_task_and_shared<M: dampen_core::UiBindable + 'static>(
-                    model: &mut M,
-                    registry: &dampen_core::HandlerRegistry,
-                    shared: &dampen_core::SharedContext<shared::#shared_model>,
-                    handler_msg: dampen_iced::HandlerMessage,
-                ) -> iced::Task<#message_type> {
-                    match handler_msg {
-                        dampen_iced::HandlerMessage::Handler(handler_name, value) => {
-                            let model_any: &mut dyn std::any::Any = model;
-                            let shared_any: &dyn std::any::Any = shared;
-                            if let Some(boxed_task) = registry.dispatch_with_shared(&handler_name, model_any, shared_any, value) {
-                                // Try to downcast to Task<Message>
-                                if let Ok(task) = boxed_task.downcast::<iced::Task<#message_type>>() {
+    let shared_helper = use_shared.then(|| {
+        // Generate version with shared context, generic over the shared model of each group
+        quote! {
+            // Helper function to dispatch handlers with shared state and return tasks
+            fn dispatch_handler_with_task_and_shared<M, S>(
+                model: &mut M,
+                registry: &dampen_core::HandlerRegistry,
+                shared: &dampen_core::SharedContext<S>,
+                scope: &mut dampen_iced::TaskScope,
+                handler_msg: dampen_iced::HandlerMessage,
+            ) -> iced::Task<#message_type>
+            where
+                M: dampen_core::UiBindable + 'static,
+                S: dampen_core::UiBindable + Send + Sync + 'static,
+            {
+                match handler_msg {
+                    dampen_iced::HandlerMessage::Handler(handler_name, value) => {
+                        let model_any: &mut dyn std::any::Any = model;
+                        let shared_any: &dyn std::any::Any = shared;
+                        if let Some(boxed_task) = registry.dispatch_with_shared(&handler_name, model_any, shared_any, value) {
+                            // Try to downcast to Task<Message>
+                            if let Ok(task) = boxed_task.downcast::<iced::Task<#message_type>>() {
+                                if GLOBAL_TASK_HANDLERS.contains(&handler_name.as_str()) {
                                     return *task;
                                 }
+                                return scope.track(*task);
                             }
-                            iced::Task::none()
                         }
-                        dampen_iced::HandlerMessage::None => iced::Task::none(),
+                        iced::Task::none()
                     }
+                    dampen_iced::HandlerMessage::None => iced::Task::none(),
                 }
             }
-        } else {
-            quote! {}
         }
-    } else {
+    });
+    let unshared_helper = use_unshared.then(|| {
         // Generate version without shared context
         quote! {
             // Helper function to dispatch handlers and return tasks
             fn dispatch_handler_with_task<M: dampen_core::UiBindable + 'static>(
                 model: &mut M,
                 registry: &dampen_core::HandlerRegistry,
+                scope: &mut dampen_iced::TaskScope,
                 handler_msg: dampen_iced::HandlerMessage,
             ) -> iced::Task<#message_type> {
                 match handler_msg {
@@ -983,7 +3244,10 @@ pub fn generate_update_method(views: &[ViewInfo], attrs: &MacroAttributes) -> To
                         if let Some(boxed_task) = registry.dispatch_with_command(&handler_name, model_any, value) {
                             // Try to downcast to Task<Message>
                             if let Ok(task) = boxed_task.downcast::<iced::Task<#message_type>>() {
-                                return *task;
+                                if GLOBAL_TASK_HANDLERS.contains(&handler_name.as_str()) {
+                                    return *task;
+                                }
+                                return scope.track(*task);
                             }
                         }
                         iced::Task::none()
@@ -992,22 +3256,59 @@ pub fn generate_update_method(views: &[ViewInfo], attrs: &MacroAttributes) -> To
                 }
             }
         }
+    });
+    let helper_functions = quote! {
+        #shared_helper
+        #unshared_helper
     };
 
+    // Handlers whose tasks are not scoped to the view that started them
+    let global_tasks = &attrs.global_tasks;
+
+    // No-op messages come from the image loader: start the downloads requested by
+    // the last render, and render again as each one finishes
+    let image_loader_arm = views_load_remote_images(views).then(|| {
+        quote! {
+            #message_type::#handler_variant(dampen_iced::HandlerMessage::None) => {
+                dampen_iced::image_loader::load_pending()
+                    .map(|()| #message_type::#handler_variant(dampen_iced::HandlerMessage::None))
+            }
+        }
+    });
+
     // Generate update_system_preference match arm if system_theme_variant is specified
     let system_theme_arm = if let Some(system_theme_variant) = &attrs.system_theme_variant {
-        let update_all_views: Vec<_> = views
+        let mut update_all_views: Vec<_> = views
             .iter()
             .map(|v| {
                 let field_name = Ident::new(&v.field_name, proc_macro2::Span::call_site());
-                quote! {
-                    if let Some(ref mut ctx) = self.#field_name.theme_context {
-                        ctx.update_system_preference(&theme_name);
+                if attrs.lazy {
+                    quote! {
+                        if let Some(state) = self.#field_name.as_mut()
+                            && let Some(ref mut ctx) = state.theme_context
+                        {
+                            ctx.update_system_preference(&theme_name);
+                        }
+                    }
+                } else {
+                    quote! {
+                        if let Some(ref mut ctx) = self.#field_name.theme_context {
+                            ctx.update_system_preference(&theme_name);
+                        }
                     }
                 }
             })
             .collect();
 
+        // Lazy views not created yet will start from the current preference
+        if attrs.lazy {
+            update_all_views.push(quote! {
+                if let Some(ref mut ctx) = self.theme_context {
+                    ctx.update_system_preference(&theme_name);
+                }
+            });
+        }
+
         Some(quote! {
             #message_type::#system_theme_variant(theme_name) => {
                 #(#update_all_views)*
@@ -1018,6 +3319,27 @@ pub fn generate_update_method(views: &[ViewInfo], attrs: &MacroAttributes) -> To
         None
     };
 
+    // Run the handler chosen in the command palette as if its widget sent it
+    let palette_arm = attrs
+        .command_palette_variant
+        .as_ref()
+        .map(|command_palette_variant| {
+            quote! {
+                #message_type::#command_palette_variant(event) => {
+                    let commands = self.palette_commands();
+                    match dampen_iced::palette::update(event, &commands) {
+                        dampen_iced::palette::Action::Focus => dampen_iced::palette::focus(),
+                        dampen_iced::palette::Action::Dispatch(handler) => self.update(
+                            #message_type::#handler_variant(
+                                dampen_iced::HandlerMessage::Handler(handler, None),
+                            ),
+                        ),
+                        dampen_iced::palette::Action::None => iced::Task::none(),
+                    }
+                }
+            }
+        });
+
     // Generate window event handling match arm if persistence is enabled
     let window_event_arm = if attrs.persistence {
         #[allow(clippy::unwrap_used)]
@@ -1048,6 +3370,10 @@ pub fn generate_update_method(views: &[ViewInfo], attrs: &MacroAttributes) -> To
                         self.persisted_window_state.y = Some(position.y as i32);
                         iced::Task::none()
                     }
+                    iced::window::Event::Rescaled(scale_factor) => {
+                        self.persisted_window_state.scale_factor = Some(scale_factor);
+                        iced::Task::none()
+                    }
                     iced::window::Event::CloseRequested => {
                          #[cfg(debug_assertions)]
                          println!("DEBUG: Saving window state on close");
@@ -1063,14 +3389,55 @@ pub fn generate_update_method(views: &[ViewInfo], attrs: &MacroAttributes) -> To
         None
     };
 
+    // Theme switches requested with dampen_core::theme_switch apply to every view,
+    // and theme transitions advance on each message
+    let mut theme_switch_updates: Vec<_> = views
+        .iter()
+        .map(|v| {
+            let field_name = Ident::new(&v.field_name, proc_macro2::Span::call_site());
+            let context = if attrs.lazy {
+                quote! {
+                    self.#field_name.as_mut().and_then(|state| state.theme_context.as_mut())
+                }
+            } else {
+                quote! { self.#field_name.theme_context.as_mut() }
+            };
+            quote! {
+                if let Some(ctx) = #context {
+                    if let Some(theme_name) = &theme_request {
+                        let _ = ctx.switch_theme(theme_name, theme_transition);
+                    }
+                    ctx.tick(now);
+                }
+            }
+        })
+        .collect();
+
+    // Lazy views not created yet will start from the requested theme
+    if attrs.lazy {
+        theme_switch_updates.push(quote! {
+            if let Some(ctx) = self.theme_context.as_mut()
+                && let Some(theme_name) = &theme_request
+            {
+                let _ = ctx.set_theme(theme_name);
+            }
+        });
+    }
+
     quote! {
         pub fn update(&mut self, message: #message_type) -> iced::Task<#message_type> {
             #[cfg(debug_assertions)]
             println!("DEBUG: Update received message"); // Generic debug to avoid needing Debug trait on Message
 
+            // Handlers whose tasks keep running across view switches
+            const GLOBAL_TASK_HANDLERS: &[&str] = &[#(#global_tasks),*];
+
             #helper_functions
 
-            match message {
+            #build_diagnostics_check
+
+            let task = match message {
+                #image_loader_arm
                 #message_type::#handler_variant(handler_msg) => {
                     match self.current_view {
                         #(#view_match_arms)*
@@ -1078,11 +3445,25 @@ pub fn generate_update_method(views: &[ViewInfo], attrs: &MacroAttributes) -> To
                 }
                 #hot_reload_arm
                 #dismiss_error_arm
+                #reset_state_arm
+                #log_viewer_arm
+                #debug_server_arm
+                #time_travel_arm
+                #state_file_arm
+                #inspector_arm
                 #switch_view_arm
                 #system_theme_arm
+                #palette_arm
                 #window_event_arm
                 _ => iced::Task::none(),
-            }
+            };
+
+            let theme_request = self.theme_switch.take_request();
+            let theme_transition = self.theme_switch.transition();
+            let now = std::time::Instant::now();
+            #(#theme_switch_updates)*
+
+            task
         }
     }
 }
@@ -1092,7 +3473,15 @@ pub fn generate_update_method(views: &[ViewInfo], attrs: &MacroAttributes) -> To
 /// Creates view rendering logic that:
 /// - Matches on `current_view` to render the appropriate AppState's UI
 /// - Wraps the Message in the user's `Handler` variant
-/// - Shows error overlay on top if visible (debug builds only)
+/// - Stacks the overlays in the layers of `dampen_iced::layers`:
+///   - the command palette as a modal, if `command_palette_variant` is specified
+///   - the toasts of `dampen_iced::notify`, if handlers show toasts
+///   - the error overlay on top if visible (debug builds only)
+/// - Shows the log viewer pane under the view if toggled, if `log_viewer_variant` is specified
+/// - Shows the time-travel timeline under the view if opened, if `time_travel_variant` is specified
+/// - Shows the state toolbar under the view if opened, if `state_file_variant` is specified
+/// - Highlights the hovered widget and shows the inspector panel under the view if turned on,
+///   if `inspector_variant` is specified
 ///
 /// # Arguments
 ///
@@ -1109,59 +3498,202 @@ pub fn generate_update_method(views: &[ViewInfo], attrs: &MacroAttributes) -> To
 ///
 /// ```ignore
 /// pub fn view(&self) -> iced::Element<Message> {
-///     #[cfg(debug_assertions)]
-///     if self.error_overlay.is_visible() {
-///         return self.error_overlay.render(Message::DismissError);
-///     }
-///
-///     match self.current_view {
+///     let content: iced::Element<Message> = Layers::new(match self.current_view {
 ///         CurrentView::Window => self.window_state.view().map(Message::Handler),
 ///         CurrentView::Settings => self.settings_state.view().map(Message::Handler),
-///     }
+///     })
+///     .into();
+///
+///     #[cfg(debug_assertions)]
+///     let content: iced::Element<Message> = Layers::new(content)
+///         .push_maybe(Layer::DevOverlay, self.error_overlay.layer(Message::DismissError, None))
+///         .into();
+///
+///     content
 /// }
 /// ```
 pub fn generate_view_method(views: &[ViewInfo], attrs: &MacroAttributes) -> TokenStream {
     let _handler_variant = &attrs.handler_variant;
     let message_type = &attrs.message_type;
 
+    // Attach the hit test of the inspector to the builder while inspecting
+    let hit_test = attrs.inspector_variant.is_some().then(|| {
+        quote! {
+            #[cfg(all(debug_assertions, feature = "interpreted"))]
+            let builder = if self.inspector.is_active() {
+                builder.with_hit_test(&self.inspector.hit_test)
+            } else {
+                builder
+            };
+        }
+    });
+
     // Generate match arms for each view's rendering
     let view_match_arms: Vec<_> = views
         .iter()
         .map(|v| {
-            let variant = Ident::new(&v.variant_name, proc_macro2::Span::call_site());
+            let variant = view_variant(v);
             let _field_name = Ident::new(&v.field_name, proc_macro2::Span::call_site());
 
-            quote! {
-                CurrentView::#variant => {
-                    dampen_iced::DampenWidgetBuilder::from_app_state(&self.#_field_name)
-                        .build()
-                        .map(#message_type::#_handler_variant)
+            // Lazy views are created before they become current, render nothing otherwise
+            if let Some(hit_test) = &hit_test {
+                let state = if attrs.lazy {
+                    quote! { state }
+                } else {
+                    quote! { &self.#_field_name }
+                };
+                let build = quote! {
+                    let builder = dampen_iced::DampenWidgetBuilder::from_app_state(#state)
+                        .with_diagnostics(&self.build_diagnostics)
+                        .with_memo_cache(&self.memo_cache);
+                    #hit_test
+                    builder.build().map(#message_type::#_handler_variant)
+                };
+                if attrs.lazy {
+                    quote! {
+                        #variant => match self.#_field_name.as_ref() {
+                            Some(state) => { #build }
+                            None => iced::widget::Space::new().into(),
+                        }
+                    }
+                } else {
+                    quote! {
+                        #variant => { #build }
+                    }
+                }
+            } else if attrs.lazy {
+                quote! {
+                    #variant => match self.#_field_name.as_ref() {
+                        Some(state) => dampen_iced::DampenWidgetBuilder::from_app_state(state)
+                            .with_diagnostics(&self.build_diagnostics)
+                            .with_memo_cache(&self.memo_cache)
+                            .build()
+                            .map(#message_type::#_handler_variant),
+                        None => iced::widget::Space::new().into(),
+                    }
+                }
+            } else {
+                quote! {
+                    #variant => {
+                        dampen_iced::DampenWidgetBuilder::from_app_state(&self.#_field_name)
+                            .with_diagnostics(&self.build_diagnostics)
+                            .with_memo_cache(&self.memo_cache)
+                            .build()
+                            .map(#message_type::#_handler_variant)
+                    }
                 }
             }
         })
         .collect();
 
-    // Generate error overlay rendering if dismiss_error_variant is specified
-    let error_overlay_check = attrs
+    // Show the error overlay over everything else (debug builds only)
+    let error_overlay = attrs
         .dismiss_error_variant
         .as_ref()
         .map(|dismiss_error_variant| {
+            let on_reset = match &attrs.reset_state_variant {
+                Some(reset_state_variant) => quote! { Some(#message_type::#reset_state_variant) },
+                None => quote! { None },
+            };
             quote! {
-                // Show error overlay if visible (debug builds only)
                 #[cfg(debug_assertions)]
-                if self.error_overlay.is_visible() {
-                    return self.error_overlay.render(#message_type::#dismiss_error_variant);
-                }
+                let content: iced::Element<'_, #message_type> =
+                    dampen_iced::layers::Layers::new(content)
+                        .push_maybe(
+                            dampen_iced::layers::Layer::DevOverlay,
+                            self.error_overlay
+                                .layer(#message_type::#dismiss_error_variant, #on_reset),
+                        )
+                        .into();
+            }
+        });
+
+    let current_view = quote! {
+        match self.current_view {
+            #(#view_match_arms)*
+        }
+    };
+
+    // Render the toasts shown by handlers over the current view
+    let toasts = views_show_notifications(views).then(|| {
+        quote! {
+            .push_maybe(dampen_iced::layers::Layer::Toast, dampen_iced::notify::layer())
+        }
+    });
+
+    // Render the command palette as a modal over the view
+    let palette = attrs
+        .command_palette_variant
+        .as_ref()
+        .map(|command_palette_variant| {
+            quote! {
+                .push_maybe(
+                    dampen_iced::layers::Layer::Modal,
+                    dampen_iced::palette::layer(
+                        &self.palette_commands(),
+                        #message_type::#command_palette_variant,
+                    ),
+                )
+            }
+        });
+
+    // Show the log viewer pane under everything else
+    let log_viewer = attrs.log_viewer_variant.is_some().then(|| {
+        quote! {
+            #[cfg(all(debug_assertions, feature = "interpreted"))]
+            let content: iced::Element<'_, #message_type> = if self.show_logs {
+                iced::widget::column![
+                    iced::widget::container(content).height(iced::Length::Fill),
+                    dampen_dev::logging::viewer(),
+                ]
+                .into()
+            } else {
+                content
+            };
+        }
+    });
+
+    // Show the time-travel timeline under the view, if opened
+    let timeline = attrs
+        .time_travel_variant
+        .as_ref()
+        .map(|time_travel_variant| {
+            quote! {
+                #[cfg(all(debug_assertions, feature = "interpreted"))]
+                let content = self.timeline.view(content, #message_type::#time_travel_variant);
             }
         });
 
+    // Show the state toolbar under the view, if opened
+    let state_tools = attrs.state_file_variant.as_ref().map(|state_file_variant| {
+        quote! {
+            #[cfg(all(debug_assertions, feature = "interpreted"))]
+            let content = self.state_tools.view(content, #message_type::#state_file_variant);
+        }
+    });
+
+    // Highlight the hovered widget and show the inspector panel, if turned on
+    let inspector = attrs.inspector_variant.as_ref().map(|inspector_variant| {
+        quote! {
+            #[cfg(all(debug_assertions, feature = "interpreted"))]
+            let content = self.inspector.view(content, #message_type::#inspector_variant);
+        }
+    });
+
     quote! {
         pub fn view(&self) -> iced::Element<'_, #message_type> {
-            #error_overlay_check
+            let content: iced::Element<'_, #message_type> =
+                dampen_iced::layers::Layers::new(#current_view)
+                    #toasts
+                    #palette
+                    .into();
+            #inspector
+            #timeline
+            #state_tools
+            #log_viewer
+            #error_overlay
 
-            match self.current_view {
-                #(#view_match_arms)*
-            }
+            content
         }
     }
 }
@@ -1176,20 +3708,30 @@ pub fn generate_view_method(views: &[ViewInfo], attrs: &MacroAttributes) -> Toke
 /// # Arguments
 ///
 /// * `views` - Slice of discovered view information
+/// * `attrs` - Parsed macro attributes (for `lazy`)
 ///
 /// # Returns
 ///
 /// Token stream containing the `theme()` method implementation.
-pub fn generate_theme_method(views: &[ViewInfo]) -> TokenStream {
+pub fn generate_theme_method(views: &[ViewInfo], attrs: &MacroAttributes) -> TokenStream {
     let view_match_arms: Vec<_> = views
         .iter()
         .map(|v| {
-            let variant = Ident::new(&v.variant_name, proc_macro2::Span::call_site());
+            let variant = view_variant(v);
             let field_name = Ident::new(&v.field_name, proc_macro2::Span::call_site());
+            let theme_context = if attrs.lazy {
+                quote! {
+                    self.#field_name.as_ref().and_then(|state| state.theme_context())
+                }
+            } else {
+                quote! {
+                    self.#field_name.theme_context()
+                }
+            };
 
             quote! {
-                CurrentView::#variant => {
-                    self.#field_name.theme_context()
+                #variant => {
+                    #theme_context
                         .map(|ctx| dampen_iced::theme_adapter::ThemeAdapter::to_iced(ctx.active()))
                         .unwrap_or(iced::Theme::Light)
                 }
@@ -1206,25 +3748,160 @@ pub fn generate_theme_method(views: &[ViewInfo]) -> TokenStream {
     }
 }
 
-/// Generates a static `window_settings()` method for persistence.
+/// `<window>` declaration of a view, read at compile time.
+///
+/// Values are validated by the parser when the view is loaded; invalid ones are ignored here.
+#[derive(Default)]
+struct WindowDeclaration {
+    title: Option<String>,
+    size: Option<(u32, u32)>,
+    min_size: Option<(u32, u32)>,
+    max_size: Option<(u32, u32)>,
+    resizable: Option<bool>,
+    decorations: Option<bool>,
+}
+
+impl WindowDeclaration {
+    /// Reads the `<window>` element of a view, if any.
+    fn read(view: &ViewInfo) -> Option<Self> {
+        let content = std::fs::read_to_string(&view.dampen_file).ok()?;
+        let document = roxmltree::Document::parse(&content).ok()?;
+        let window = document
+            .root_element()
+            .children()
+            .find(|node| node.has_tag_name("window"))?;
+
+        let number = |name: &str| {
+            window
+                .attribute(name)
+                .and_then(|value| value.trim().parse::<u32>().ok())
+        };
+        let boolean = |name: &str| {
+            window
+                .attribute(name)
+                .and_then(|value| value.trim().parse::<bool>().ok())
+        };
+        let pair = |width: Option<u32>, height: Option<u32>, default: (u32, u32)| {
+            (width.is_some() || height.is_some())
+                .then(|| (width.unwrap_or(default.0), height.unwrap_or(default.1)))
+        };
+
+        Some(Self {
+            title: window.attribute("title").map(str::to_string),
+            size: pair(number("width"), number("height"), (1024, 768)),
+            min_size: pair(number("min_width"), number("min_height"), (0, 0)),
+            max_size: pair(
+                number("max_width"),
+                number("max_height"),
+                (u32::MAX, u32::MAX),
+            ),
+            resizable: boolean("resizable"),
+            decorations: boolean("decorations"),
+        })
+    }
+
+    /// `iced::window::Settings` expression of the declaration.
+    fn settings(&self) -> TokenStream {
+        let size = |(width, height): (u32, u32)| {
+            quote! { iced::Size::new(#width as f32, #height as f32) }
+        };
+        let mut fields = Vec::new();
+        if let Some(value) = self.size.map(size) {
+            fields.push(quote! { size: #value, });
+        }
+        if let Some(value) = self.min_size.map(size) {
+            fields.push(quote! { min_size: Some(#value), });
+        }
+        if let Some(value) = self.max_size.map(size) {
+            fields.push(quote! { max_size: Some(#value), });
+        }
+        if let Some(resizable) = self.resizable {
+            fields.push(quote! { resizable: #resizable, });
+        }
+        if let Some(decorations) = self.decorations {
+            fields.push(quote! { decorations: #decorations, });
+        }
+
+        quote! {
+            iced::window::Settings {
+                #(#fields)*
+                ..Default::default()
+            }
+        }
+    }
+
+    /// `WindowSettingsBuilder` calls applying the declaration.
+    ///
+    /// The declared size becomes the default size, used until a window state is persisted.
+    fn builder_calls(&self) -> TokenStream {
+        let mut calls = Vec::new();
+        if let Some((width, height)) = self.size {
+            calls.push(quote! { .default_size(#width, #height) });
+        }
+        if let Some((width, height)) = self.min_size {
+            calls.push(quote! { .min_size(#width, #height) });
+        }
+        if let Some((width, height)) = self.max_size {
+            calls.push(quote! { .max_size(#width, #height) });
+        }
+        if let Some(resizable) = self.resizable {
+            calls.push(quote! { .resizable(#resizable) });
+        }
+        if let Some(decorations) = self.decorations {
+            calls.push(quote! { .decorations(#decorations) });
+        }
+        quote! { #(#calls)* }
+    }
+}
+
+/// Returns the `<window>` declaration of the default view, if any.
+///
+/// The default view is the user-specified `default_view`, or the first view alphabetically.
+fn default_window_declaration(
+    views: &[ViewInfo],
+    attrs: &MacroAttributes,
+) -> Option<WindowDeclaration> {
+    let view = match attrs.default_view {
+        Some(ref name) => views.iter().find(|v| v.path() == *name),
+        None => views.first(),
+    }?;
+    WindowDeclaration::read(view)
+}
+
+/// Generates a static `window_settings()` method.
 ///
-/// Creates a method that returns `iced::window::Settings` with the persisted window size
-/// so the window can be created with the correct initial size.
+/// With persistence, creates a method that returns a `WindowSettingsBuilder` with the persisted
+/// window size so the window can be created with the correct initial size. Otherwise, creates a
+/// method that returns the `iced::window::Settings` declared by the default view's `<window>`.
 ///
 /// # Arguments
 ///
+/// * `views` - Discovered views (for the `<window>` declaration of the default view)
 /// * `attrs` - Parsed macro attributes (for app_name)
 ///
 /// # Returns
 ///
-/// Option with token stream containing the `window_settings()` method if persistence is enabled.
-pub fn generate_window_settings_method(attrs: &MacroAttributes) -> Option<TokenStream> {
+/// Option with token stream containing the `window_settings()` method if persistence is enabled
+/// or the default view declares a `<window>`.
+pub fn generate_window_settings_method(
+    views: &[ViewInfo],
+    attrs: &MacroAttributes,
+) -> Option<TokenStream> {
+    let declared = default_window_declaration(views, attrs);
+
     if !attrs.persistence {
-        return None;
+        let settings = declared?.settings();
+        return Some(quote! {
+            /// Window settings declared by the `<window>` of the default view.
+            pub fn window_settings() -> iced::window::Settings {
+                #settings
+            }
+        });
     }
 
     #[allow(clippy::unwrap_used)]
     let app_name = attrs.app_name.as_ref().unwrap();
+    let declared_calls = declared.as_ref().map(WindowDeclaration::builder_calls);
 
     Some(quote! {
         /// Default window settings for first launch.
@@ -1244,6 +3921,60 @@ pub fn generate_window_settings_method(attrs: &MacroAttributes) -> Option<TokenS
         /// ```
         pub fn window_settings() -> dampen_dev::persistence::WindowSettingsBuilder {
             dampen_dev::persistence::WindowSettingsBuilder::new(#app_name)
+                #declared_calls
+        }
+    })
+}
+
+/// Generates the `title()` method returning the window title declared by the current view.
+///
+/// The title is read from the view's document, so hot-reload updates it. Views without a
+/// `<window title="...">` fall back to the title of the default view, then to `app_name`.
+///
+/// # Returns
+///
+/// Option with token stream containing the `title()` method if any view declares a title.
+pub fn generate_title_method(views: &[ViewInfo], attrs: &MacroAttributes) -> Option<TokenStream> {
+    let declares_title = |view: &ViewInfo| {
+        WindowDeclaration::read(view).is_some_and(|window| window.title.is_some())
+    };
+    if !views.iter().any(declares_title) {
+        return None;
+    }
+
+    let fallback = default_window_declaration(views, attrs)
+        .and_then(|window| window.title)
+        .or_else(|| attrs.app_name.clone())
+        .unwrap_or_default();
+
+    let view_match_arms: Vec<_> = views
+        .iter()
+        .map(|v| {
+            let variant = view_variant(v);
+            let field_name = Ident::new(&v.field_name, proc_macro2::Span::call_site());
+            let window = if attrs.lazy {
+                quote! {
+                    self.#field_name.as_ref().and_then(|state| state.document.window.as_ref())
+                }
+            } else {
+                quote! {
+                    self.#field_name.document.window.as_ref()
+                }
+            };
+
+            quote! {
+                #variant => #window.and_then(|window| window.title.clone()),
+            }
+        })
+        .collect();
+
+    Some(quote! {
+        /// Window title declared by the `<window>` of the current view.
+        pub fn title(&self) -> String {
+            let title = match self.current_view {
+                #(#view_match_arms)*
+            };
+            title.unwrap_or_else(|| #fallback.to_string())
         }
     })
 }
@@ -1254,7 +3985,15 @@ pub fn generate_window_settings_method(attrs: &MacroAttributes) -> Option<TokenS
 /// - Watches all `.dampen` files for changes (debug builds only)
 /// - Sends `HotReload` messages when files change
 /// - Returns `iced::Subscription::none()` in release builds
-/// - Only generated if `hot_reload_variant` is specified in attributes
+/// - Wakes the remote image loader when a view displays remote images
+/// - Re-renders when a toast is shown or expires, if handlers show toasts
+/// - Listens to the command palette keys, if `command_palette_variant` is specified
+/// - Listens to the log viewer key (F12), if `log_viewer_variant` is specified (debug builds only)
+/// - Serves debuggers, if `debug_server_variant` is specified (debug builds only)
+/// - Listens to the timeline key (F9), if `time_travel_variant` is specified (debug builds only)
+/// - Listens to the state toolbar key (F8), if `state_file_variant` is specified (debug builds only)
+/// - Listens to the inspector keys (Ctrl+Shift+I), if `inspector_variant` is specified (debug builds only)
+/// - Only generated if at least one of these subscriptions is needed
 ///
 /// # Arguments
 ///
@@ -1293,7 +4032,7 @@ pub fn generate_subscription_method(
     // Hot reload subscription
     let hot_reload_sub = if let Some(hot_reload_variant) = &attrs.hot_reload_variant {
         // Collect all .dampen file paths from views
-        let watch_paths: Vec<_> = views
+        let mut watch_paths: Vec<_> = views
             .iter()
             .map(|v| {
                 let path = v.dampen_file.to_string_lossy().to_string();
@@ -1301,12 +4040,48 @@ pub fn generate_subscription_method(
             })
             .collect();
 
+        // Also watch the theme file, which is usually excluded from views
+        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".to_string());
+        let theme_file = PathBuf::from(manifest_dir)
+            .join(&attrs.ui_dir)
+            .join("theme/theme.dampen");
+        if theme_file.exists() && !views.iter().any(|v| v.dampen_file == theme_file) {
+            let path = theme_file.to_string_lossy().to_string();
+            watch_paths.push(quote! { std::path::PathBuf::from(#path) });
+        }
+
+        // And the style libraries, which are not views either
+        for path in style_library_paths(attrs) {
+            let path = path.to_string_lossy().to_string();
+            watch_paths.push(quote! { std::path::PathBuf::from(#path) });
+        }
+
+        // And the directories of the images, fonts and translations they use
+        let asset_dirs = view_asset_dirs(views, attrs);
+        let watch = if asset_dirs.is_empty() {
+            quote! {
+                dampen_dev::subscription::watch_files(
+                    vec![#(#watch_paths),*],
+                    100  // 100ms debounce
+                )
+            }
+        } else {
+            let asset_dirs = asset_dirs.iter().map(|dir| {
+                let dir = dir.to_string_lossy().to_string();
+                quote! { std::path::PathBuf::from(#dir) }
+            });
+            quote! {
+                dampen_dev::subscription::watch_files_and_assets(
+                    vec![#(#watch_paths),*],
+                    vec![#(#asset_dirs),*],
+                    100  // 100ms debounce
+                )
+            }
+        };
+
         Some(quote! {
             #[cfg(debug_assertions)]
-            let hot_reload = dampen_dev::subscription::watch_files(
-                vec![#(#watch_paths),*],
-                100  // 100ms debounce
-            ).map(#message_type::#hot_reload_variant);
+            let hot_reload = #watch.map(#message_type::#hot_reload_variant);
         })
     } else {
         None
@@ -1337,6 +4112,152 @@ pub fn generate_subscription_method(
         None
     };
 
+    // Remote image loading (see dampen_iced::image_loader), handled in update()
+    let images_sub = views_load_remote_images(views).then(|| {
+        let handler_variant = &attrs.handler_variant;
+        quote! {
+            let images = dampen_iced::image_loader::requests()
+                .map(|()| #message_type::#handler_variant(dampen_iced::HandlerMessage::None));
+        }
+    });
+
+    // Toasts (see dampen_iced::notify) only need a new render when they change
+    let toasts_sub = views_show_notifications(views).then(|| {
+        let handler_variant = &attrs.handler_variant;
+        quote! {
+            let toasts = dampen_iced::notify::changes()
+                .map(|()| #message_type::#handler_variant(dampen_iced::HandlerMessage::None));
+        }
+    });
+
+    // Accessibility preferences (see dampen_iced::system_preferences) only need a new render
+    let preferences_sub = views_use_system_preferences(views, attrs).then(|| {
+        let handler_variant = &attrs.handler_variant;
+        quote! {
+            let preferences = dampen_iced::system_preferences::changes()
+                .map(|_| #message_type::#handler_variant(dampen_iced::HandlerMessage::None));
+        }
+    });
+
+    // Breakpoint attributes (see dampen_iced::viewport) only need a new render,
+    // on each resize when the window size is bound with `{env.viewport_width}`
+    let viewport_sub = if views_bind_env(views, "viewport_") {
+        Some(quote! { dampen_iced::viewport::resizes() })
+    } else {
+        views_use_breakpoints(views).then(|| quote! { dampen_iced::viewport::changes() })
+    }
+    .map(|sizes| {
+        let handler_variant = &attrs.handler_variant;
+        quote! {
+            let viewport = #sizes
+                .map(|_| #message_type::#handler_variant(dampen_iced::HandlerMessage::None));
+        }
+    });
+
+    // Theme transitions (see dampen_core::theme_switch) render again on each frame
+    let theme_transition_sub = has_theme_file(attrs).then(|| {
+        let handler_variant = &attrs.handler_variant;
+        let transitioning = views.iter().map(|v| {
+            let field_name = Ident::new(&v.field_name, proc_macro2::Span::call_site());
+            let context = if attrs.lazy {
+                quote! { self.#field_name.as_ref().and_then(|state| state.theme_context.as_ref()) }
+            } else {
+                quote! { self.#field_name.theme_context.as_ref() }
+            };
+            quote! { #context.is_some_and(|ctx| ctx.is_transitioning()) }
+        });
+        quote! {
+            let theme_transition = if [#(#transitioning),*].contains(&true) {
+                iced::window::frames()
+                    .map(|_| #message_type::#handler_variant(dampen_iced::HandlerMessage::None))
+            } else {
+                iced::Subscription::none()
+            };
+        }
+    });
+
+    // Debounced input handlers (see dampen_iced::debounce)
+    let debounce_sub = views_debounce_inputs(views).then(|| {
+        let handler_variant = &attrs.handler_variant;
+        quote! {
+            let debounced = dampen_iced::debounce::ready::<dampen_iced::HandlerMessage>()
+                .map(#message_type::#handler_variant);
+        }
+    });
+
+    // Command palette keys (see dampen_iced::palette)
+    let palette_sub = attrs
+        .command_palette_variant
+        .as_ref()
+        .map(|command_palette_variant| {
+            quote! {
+                let palette = dampen_iced::palette::subscription()
+                    .map(#message_type::#command_palette_variant);
+            }
+        });
+
+    // Log viewer key, in development mode only (see dampen_dev::logging)
+    let log_viewer_sub = attrs.log_viewer_variant.as_ref().map(|log_viewer_variant| {
+        quote! {
+            #[cfg(feature = "interpreted")]
+            let logs = dampen_dev::logging::toggle_key()
+                .map(|()| #message_type::#log_viewer_variant);
+            #[cfg(not(feature = "interpreted"))]
+            let logs = iced::Subscription::none();
+        }
+    });
+
+    // Debug server, in development mode only (see dampen_dev::debug_server)
+    let debug_server_sub = attrs
+        .debug_server_variant
+        .as_ref()
+        .map(|debug_server_variant| {
+            quote! {
+                #[cfg(feature = "interpreted")]
+                let debug_server =
+                    dampen_dev::debug_server::serve(dampen_dev::debug_server::port())
+                        .map(#message_type::#debug_server_variant);
+                #[cfg(not(feature = "interpreted"))]
+                let debug_server = iced::Subscription::none();
+            }
+        });
+
+    // Timeline key, in development mode only (see dampen_dev::time_travel)
+    let time_travel_sub = attrs
+        .time_travel_variant
+        .as_ref()
+        .map(|time_travel_variant| {
+            quote! {
+                #[cfg(feature = "interpreted")]
+                let timeline = dampen_dev::time_travel::toggle_key()
+                    .map(#message_type::#time_travel_variant);
+                #[cfg(not(feature = "interpreted"))]
+                let timeline = iced::Subscription::none();
+            }
+        });
+
+    // State toolbar key, in development mode only (see dampen_dev::state_file)
+    let state_file_sub = attrs.state_file_variant.as_ref().map(|state_file_variant| {
+        quote! {
+            #[cfg(feature = "interpreted")]
+            let state_tools = dampen_dev::state_file::toggle_key()
+                .map(#message_type::#state_file_variant);
+            #[cfg(not(feature = "interpreted"))]
+            let state_tools = iced::Subscription::none();
+        }
+    });
+
+    // Inspector keys, in development mode only (see dampen_dev::inspector)
+    let inspector_sub = attrs.inspector_variant.as_ref().map(|inspector_variant| {
+        quote! {
+            #[cfg(feature = "interpreted")]
+            let inspector = dampen_dev::inspector::toggle_key()
+                .map(#message_type::#inspector_variant);
+            #[cfg(not(feature = "interpreted"))]
+            let inspector = iced::Subscription::none();
+        }
+    });
+
     // Build subscription expressions for debug mode (hot reload + system theme + persistence)
     let mut debug_subs = Vec::new();
     if hot_reload_sub.is_some() {
@@ -1348,6 +4269,42 @@ pub fn generate_subscription_method(
     if persistence_sub.is_some() {
         debug_subs.push(quote! { window_events });
     }
+    if images_sub.is_some() {
+        debug_subs.push(quote! { images });
+    }
+    if toasts_sub.is_some() {
+        debug_subs.push(quote! { toasts });
+    }
+    if preferences_sub.is_some() {
+        debug_subs.push(quote! { preferences });
+    }
+    if viewport_sub.is_some() {
+        debug_subs.push(quote! { viewport });
+    }
+    if theme_transition_sub.is_some() {
+        debug_subs.push(quote! { theme_transition });
+    }
+    if debounce_sub.is_some() {
+        debug_subs.push(quote! { debounced });
+    }
+    if palette_sub.is_some() {
+        debug_subs.push(quote! { palette });
+    }
+    if log_viewer_sub.is_some() {
+        debug_subs.push(quote! { logs });
+    }
+    if debug_server_sub.is_some() {
+        debug_subs.push(quote! { debug_server });
+    }
+    if time_travel_sub.is_some() {
+        debug_subs.push(quote! { timeline });
+    }
+    if state_file_sub.is_some() {
+        debug_subs.push(quote! { state_tools });
+    }
+    if inspector_sub.is_some() {
+        debug_subs.push(quote! { inspector });
+    }
 
     // Build subscription expressions for release mode (system theme + persistence)
     let mut release_subs = Vec::new();
@@ -1357,6 +4314,27 @@ pub fn generate_subscription_method(
     if persistence_sub.is_some() {
         release_subs.push(quote! { window_events });
     }
+    if images_sub.is_some() {
+        release_subs.push(quote! { images });
+    }
+    if toasts_sub.is_some() {
+        release_subs.push(quote! { toasts });
+    }
+    if preferences_sub.is_some() {
+        release_subs.push(quote! { preferences });
+    }
+    if viewport_sub.is_some() {
+        release_subs.push(quote! { viewport });
+    }
+    if theme_transition_sub.is_some() {
+        release_subs.push(quote! { theme_transition });
+    }
+    if debounce_sub.is_some() {
+        release_subs.push(quote! { debounced });
+    }
+    if palette_sub.is_some() {
+        release_subs.push(quote! { palette });
+    }
 
     // If no subscriptions at all, don't generate the method
     if debug_subs.is_empty() && release_subs.is_empty() {
@@ -1385,6 +4363,18 @@ pub fn generate_subscription_method(
             #hot_reload_sub
             #system_theme_sub
             #persistence_sub
+            #images_sub
+            #toasts_sub
+            #preferences_sub
+            #viewport_sub
+            #theme_transition_sub
+            #debounce_sub
+            #palette_sub
+            #log_viewer_sub
+            #debug_server_sub
+            #time_travel_sub
+            #state_file_sub
+            #inspector_sub
 
             #debug_sub_expr
         }
@@ -1393,6 +4383,13 @@ pub fn generate_subscription_method(
         pub fn subscription(&self) -> iced::Subscription<#message_type> {
             #system_theme_sub
             #persistence_sub
+            #images_sub
+            #toasts_sub
+            #preferences_sub
+            #viewport_sub
+            #theme_transition_sub
+            #debounce_sub
+            #palette_sub
 
             #release_sub_expr
         }
@@ -1467,8 +4464,9 @@ pub fn dampen_app_impl(attr: TokenStream, item: TokenStream) -> Result<TokenStre
         ));
     }
 
-    // Discover views
-    let views = discover_dampen_files(&ui_dir, &attrs.exclude)
+    // Discover views, style libraries are not views
+    let exclude: Vec<String> = attrs.exclude.iter().chain(&attrs.styles).cloned().collect();
+    let views = discover_dampen_files(&ui_dir, &exclude, attrs.nested_views)
         .map_err(|e| syn::Error::new(proc_macro2::Span::call_site(), e))?;
 
     if views.is_empty() {
@@ -1483,31 +4481,88 @@ pub fn dampen_app_impl(attr: TokenStream, item: TokenStream) -> Result<TokenStre
 
     // Validate default_view if specified
     if let Some(ref default_view_name) = attrs.default_view {
-        let view_exists = views.iter().any(|v| v.view_name == *default_view_name);
+        let view_exists = views.iter().any(|v| v.path() == *default_view_name);
         if !view_exists {
-            let available_views: Vec<_> = views.iter().map(|v| v.view_name.as_str()).collect();
+            let available_views: Vec<_> = views.iter().map(ViewInfo::path).collect();
             return Err(syn::Error::new(
                 proc_macro2::Span::call_site(),
                 format!(
                     "default_view '{}' not found in discovered views\nhelp: Available views: {}\nhelp: Use default_view = \"{}\" (without .dampen extension)",
                     default_view_name,
                     available_views.join(", "),
-                    available_views.first().unwrap_or(&"window")
+                    available_views
+                        .first()
+                        .map(String::as_str)
+                        .unwrap_or("window")
+                ),
+            ));
+        }
+    }
+
+    // Validate prefetch names discovered views
+    for prefetch_view in &attrs.prefetch {
+        if !views.iter().any(|v| v.path() == *prefetch_view) {
+            let available_views: Vec<_> = views.iter().map(ViewInfo::path).collect();
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                format!(
+                    "prefetch view '{}' not found in discovered views\nhelp: Available views: {}",
+                    prefetch_view,
+                    available_views.join(", ")
+                ),
+            ));
+        }
+    }
+
+    // Validate group_shared_models name groups of nested views
+    for (group_path, _) in &attrs.group_shared_models {
+        let group_exists = views
+            .iter()
+            .any(|v| v.path().starts_with(&format!("{}.", group_path)));
+        if !group_exists {
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                format!(
+                    "group_shared_models: no views found in group '{}'\nhelp: Groups are subdirectories of '{}', written as dotted paths (e.g. \"settings\")",
+                    group_path, attrs.ui_dir
                 ),
             ));
         }
     }
 
+    // Validate global_tasks name handlers of the views
+    if !attrs.global_tasks.is_empty() {
+        let handlers: Vec<String> = views.iter().flat_map(handler_names).collect();
+        for handler in &attrs.global_tasks {
+            if !handlers.contains(handler) {
+                return Err(syn::Error::new(
+                    proc_macro2::Span::call_site(),
+                    format!(
+                        "global_tasks handler '{}' not found in discovered views\nhelp: Handlers are #[ui_handler] functions or names registered in a view module",
+                        handler
+                    ),
+                ));
+            }
+        }
+    }
+
     // Generate code
     let current_view_enum = generate_current_view_enum(&views);
+    let view_paths = attrs.nested_views.then(|| generate_view_paths(&views));
     let app_struct = generate_app_struct(&views, &attrs.message_type, &attrs, struct_name);
     let init_method = generate_init_method(&views, &attrs);
-    let switch_to_methods = generate_switch_to_methods(&views);
+    let switch_to_methods = generate_switch_to_methods(&views, &attrs);
+    let lazy_methods = generate_lazy_methods(&views, &attrs);
+    let state_file_methods = generate_state_file_methods(&views, &attrs);
+    let task_scope_methods = generate_task_scope_methods(&views, &attrs);
     let update_method = generate_update_method(&views, &attrs);
     let view_method = generate_view_method(&views, &attrs);
-    let theme_method = generate_theme_method(&views);
+    let theme_method = generate_theme_method(&views, &attrs);
     let subscription_method = generate_subscription_method(&views, &attrs);
-    let window_settings_method = generate_window_settings_method(&attrs);
+    let palette_method = generate_palette_method(&views, &attrs);
+    let style_libraries_method = generate_style_libraries_method(&attrs);
+    let window_settings_method = generate_window_settings_method(&views, &attrs);
+    let title_method = generate_title_method(&views, &attrs);
 
     // Build impl block with optional methods
     let impl_methods = match (subscription_method, window_settings_method) {
@@ -1516,9 +4571,15 @@ pub fn dampen_app_impl(attr: TokenStream, item: TokenStream) -> Result<TokenStre
                 impl #struct_name {
                     #init_method
                     #switch_to_methods
+                    #lazy_methods
+                    #state_file_methods
+                    #task_scope_methods
                     #update_method
                     #view_method
                     #theme_method
+                    #palette_method
+                    #style_libraries_method
+                    #title_method
                     #subscription
                     #window_settings
                 }
@@ -1529,9 +4590,15 @@ pub fn dampen_app_impl(attr: TokenStream, item: TokenStream) -> Result<TokenStre
                 impl #struct_name {
                     #init_method
                     #switch_to_methods
+                    #lazy_methods
+                    #state_file_methods
+                    #task_scope_methods
                     #update_method
                     #view_method
                     #theme_method
+                    #palette_method
+                    #style_libraries_method
+                    #title_method
                     #subscription
                 }
             }
@@ -1541,9 +4608,15 @@ pub fn dampen_app_impl(attr: TokenStream, item: TokenStream) -> Result<TokenStre
                 impl #struct_name {
                     #init_method
                     #switch_to_methods
+                    #lazy_methods
+                    #state_file_methods
+                    #task_scope_methods
                     #update_method
                     #view_method
                     #theme_method
+                    #palette_method
+                    #style_libraries_method
+                    #title_method
                     #window_settings
                 }
             }
@@ -1553,9 +4626,15 @@ pub fn dampen_app_impl(attr: TokenStream, item: TokenStream) -> Result<TokenStre
                 impl #struct_name {
                     #init_method
                     #switch_to_methods
+                    #lazy_methods
+                    #state_file_methods
+                    #task_scope_methods
                     #update_method
                     #view_method
                     #theme_method
+                    #palette_method
+                    #style_libraries_method
+                    #title_method
                 }
             }
         }
@@ -1564,6 +4643,8 @@ pub fn dampen_app_impl(attr: TokenStream, item: TokenStream) -> Result<TokenStre
     Ok(quote! {
         #current_view_enum
 
+        #view_paths
+
         #app_struct
 
         #impl_methods
@@ -5,6 +5,7 @@
 //! - Building `ViewInfo` metadata structures
 //! - Validating view names and file structure
 //! - Applying exclusion patterns
+//! - Grouping views of nested directories (`nested_views = true`)
 
 use globset::{Glob, GlobSetBuilder};
 use std::collections::HashMap;
@@ -62,6 +63,7 @@ fn is_excluded(relative_path: &str, exclude_patterns: &[String]) -> Result<bool,
 ///
 /// # Fields
 ///
+/// * `group` - Directories between ui_dir and the file when views are nested (e.g., `["settings"]`), empty otherwise
 /// * `view_name` - Snake_case identifier derived from filename (e.g., "text_input", "main_window")
 /// * `variant_name` - PascalCase enum variant name for `CurrentView` (e.g., "TextInput", "MainWindow")
 /// * `field_name` - Struct field name for the AppState instance (e.g., "text_input_state")
@@ -75,6 +77,7 @@ fn is_excluded(relative_path: &str, exclude_patterns: &[String]) -> Result<bool,
 ///
 /// ```ignore
 /// ViewInfo {
+///     group: vec![],
 ///     view_name: "text_input".to_string(),
 ///     variant_name: "TextInput".to_string(),
 ///     field_name: "text_input_state".to_string(),
@@ -84,14 +87,21 @@ fn is_excluded(relative_path: &str, exclude_patterns: &[String]) -> Result<bool,
 /// }
 /// ```
 ///
+/// With nested views, the same file belongs to the `widgets` group and its
+/// field name is qualified with it (`group: vec!["widgets"]`,
+/// `field_name: "widgets_text_input_state"`).
+///
 /// # Validation
 ///
 /// ViewInfo instances are validated to ensure:
-/// - `view_name` is a valid Rust identifier (VR-001)
-/// - All variant names are unique within a set of views (VR-002)
+/// - `view_name` and group directories are valid Rust identifiers (VR-001)
+/// - All variant names are unique within a group of views (VR-002)
 /// - The corresponding `.rs` file exists (VR-003)
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ViewInfo {
+    /// Directories from ui_dir root when views are nested (e.g., `["settings"]`)
+    pub group: Vec<String>,
+
     /// Snake_case identifier derived from filename (e.g., "text_input")
     pub view_name: String,
 
@@ -136,10 +146,11 @@ impl ViewInfo {
     /// # Arguments
     /// * `dampen_file` - Absolute path to .dampen file
     /// * `ui_dir` - Root UI directory (absolute path)
+    /// * `nested` - Whether directories group views instead of being flattened
     ///
     /// # Returns
     /// ViewInfo with all fields derived from the file path
-    pub fn from_path(dampen_file: &Path, ui_dir: &Path) -> Result<Self, String> {
+    pub fn from_path(dampen_file: &Path, ui_dir: &Path, nested: bool) -> Result<Self, String> {
         // Extract filename without extension
         let file_stem = dampen_file
             .file_stem()
@@ -152,7 +163,6 @@ impl ViewInfo {
         validate_rust_identifier(&view_name)?;
 
         let variant_name = to_pascal_case(&view_name);
-        let field_name = format!("{}_state", view_name);
 
         // Build module path from relative path
         let relative_path = dampen_file.strip_prefix(ui_dir).map_err(|_| {
@@ -162,31 +172,80 @@ impl ViewInfo {
             )
         })?;
 
-        let module_path = if let Some(parent) = relative_path.parent() {
-            if parent.as_os_str().is_empty() {
-                format!("ui::{}", view_name)
-            } else {
-                let parent_parts: Vec<_> = parent
-                    .components()
-                    .filter_map(|c| c.as_os_str().to_str())
-                    .collect();
-                format!("ui::{}::{}", parent_parts.join("::"), view_name)
+        // Directories become navigation groups of nested views
+        let group = if nested {
+            let group: Vec<String> = relative_path
+                .parent()
+                .into_iter()
+                .flat_map(|parent| parent.components())
+                .filter_map(|c| c.as_os_str().to_str())
+                .map(str::to_string)
+                .collect();
+            for directory in &group {
+                validate_rust_identifier(directory).map_err(|e| {
+                    format!(
+                        "{}\nnote: nested view directories become CurrentView variants",
+                        e
+                    )
+                })?;
             }
+            group
         } else {
-            format!("ui::{}", view_name)
+            Vec::new()
         };
 
-        // Derive .rs file path
-        let rs_file = dampen_file.with_extension("rs");
-
-        Ok(ViewInfo {
+        let mut view = ViewInfo {
+            group,
             view_name,
             variant_name,
-            field_name,
-            module_path,
+            field_name: String::new(),
+            module_path: String::new(),
             dampen_file: dampen_file.to_path_buf(),
-            rs_file,
-        })
+            rs_file: dampen_file.with_extension("rs"),
+        };
+        view.field_name = format!("{}_state", view.qualified_name());
+        view.module_path = module_path(relative_path, &view.view_name);
+
+        Ok(view)
+    }
+
+    /// Dotted path of the view from ui_dir root (e.g., "settings.appearance")
+    ///
+    /// Flat views are addressed by their view name alone.
+    pub fn path(&self) -> String {
+        self.segments().join(".")
+    }
+
+    /// Snake_case name of the view qualified by its group (e.g., "settings_appearance")
+    ///
+    /// Used for the generated field and method names, which must be unique across groups.
+    pub fn qualified_name(&self) -> String {
+        self.segments().join("_")
+    }
+
+    fn segments(&self) -> Vec<&str> {
+        self.group
+            .iter()
+            .map(String::as_str)
+            .chain(std::iter::once(self.view_name.as_str()))
+            .collect()
+    }
+}
+
+/// Build the Rust module path of a view from its path relative to ui_dir
+fn module_path(relative_path: &Path, view_name: &str) -> String {
+    if let Some(parent) = relative_path.parent() {
+        if parent.as_os_str().is_empty() {
+            format!("ui::{}", view_name)
+        } else {
+            let parent_parts: Vec<_> = parent
+                .components()
+                .filter_map(|c| c.as_os_str().to_str())
+                .collect();
+            format!("ui::{}::{}", parent_parts.join("::"), view_name)
+        }
+    } else {
+        format!("ui::{}", view_name)
     }
 }
 
@@ -199,10 +258,11 @@ impl ViewInfo {
 ///
 /// * `ui_dir` - Root directory to scan for `.dampen` files (absolute path)
 /// * `exclude_patterns` - Glob patterns to exclude (e.g., `["debug", "experimental/*"]`)
+/// * `nested` - Whether subdirectories are navigation groups (`nested_views = true`)
 ///
 /// # Returns
 ///
-/// * `Ok(Vec<ViewInfo>)` - Sorted vector of discovered views (alphabetically by view_name)
+/// * `Ok(Vec<ViewInfo>)` - Sorted vector of discovered views (alphabetically by group, then view_name)
 /// * `Err(String)` - Error message if:
 ///   - Directory traversal fails
 ///   - ViewInfo creation fails (invalid filename, missing .rs file, etc.)
@@ -214,7 +274,8 @@ impl ViewInfo {
 /// ```ignore
 /// let views = discover_dampen_files(
 ///     Path::new("/path/to/src/ui"),
-///     &["debug".to_string(), "experimental/*".to_string()]
+///     &["debug".to_string(), "experimental/*".to_string()],
+///     false,
 /// )?;
 /// // Returns ViewInfo for all .dampen files except those matching patterns
 /// ```
@@ -222,12 +283,14 @@ impl ViewInfo {
 /// # Notes
 ///
 /// - Files are sorted alphabetically by `view_name` for deterministic code generation
+/// - Without `nested`, files of subdirectories are flattened into the root group
 /// - Exclusion patterns match both with and without `.dampen` extension
 /// - Empty directories are silently skipped
 /// - Validation is performed on all discovered ViewInfo instances
 pub fn discover_dampen_files(
     ui_dir: &Path,
     exclude_patterns: &[String],
+    nested: bool,
 ) -> Result<Vec<ViewInfo>, String> {
     if !ui_dir.exists() {
         return Err(format!("UI directory not found: {:?}", ui_dir));
@@ -263,7 +326,7 @@ pub fn discover_dampen_files(
                 }
             }
 
-            let view_info = ViewInfo::from_path(path, ui_dir)?;
+            let view_info = ViewInfo::from_path(path, ui_dir, nested)?;
 
             // Validate .rs file exists (VR-003)
             if !view_info.rs_file.exists() {
@@ -315,11 +378,15 @@ fn validate_rust_identifier(name: &str) -> Result<(), String> {
 }
 
 /// Validate that all variant names are unique (VR-002)
+///
+/// Variants only need to be unique within their group, but a group directory is
+/// itself a variant of its parent group and must not clash with a view.
 fn validate_unique_variants(views: &[ViewInfo]) -> Result<(), String> {
     let mut seen = HashMap::new();
 
     for view in views {
-        if let Some(existing) = seen.insert(&view.variant_name, &view.dampen_file) {
+        let key = (view.group.as_slice(), view.variant_name.clone());
+        if let Some(existing) = seen.insert(key, &view.dampen_file) {
             return Err(format!(
                 "View naming conflict: '{}' found in multiple locations:\n  - {}\n  - {}\nhelp: Rename one of the files or exclude one via the 'exclude' attribute",
                 view.variant_name,
@@ -329,6 +396,33 @@ fn validate_unique_variants(views: &[ViewInfo]) -> Result<(), String> {
         }
     }
 
+    for view in views {
+        for depth in 0..view.group.len() {
+            let key = (&view.group[..depth], to_pascal_case(&view.group[depth]));
+            if let Some(existing) = seen.get(&key) {
+                return Err(format!(
+                    "View naming conflict: '{}' is both a view and a view directory:\n  - {}\n  - {}\nhelp: Rename the file or the directory",
+                    key.1,
+                    existing.display(),
+                    view.dampen_file.display()
+                ));
+            }
+        }
+    }
+
+    // Field and method names are qualified by the group
+    let mut qualified = HashMap::new();
+    for view in views {
+        if let Some(existing) = qualified.insert(view.qualified_name(), &view.dampen_file) {
+            return Err(format!(
+                "View naming conflict: '{}' is the qualified name of multiple views:\n  - {}\n  - {}\nhelp: Rename one of the files or directories",
+                view.qualified_name(),
+                existing.display(),
+                view.dampen_file.display()
+            ));
+        }
+    }
+
     Ok(())
 }
 
@@ -351,4 +445,51 @@ mod tests {
     fn test_to_pascal_case_already_capitalized() {
         assert_eq!(to_pascal_case("Button"), "Button");
     }
+
+    fn fixture(name: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures")
+            .join(name)
+            .join("src/ui")
+    }
+
+    #[test]
+    fn test_discover_flattens_directories_by_default() -> Result<(), String> {
+        let views = discover_dampen_files(&fixture("nested_views"), &[], false)?;
+        let button = views
+            .iter()
+            .find(|v| v.view_name == "button")
+            .ok_or("button view not found")?;
+
+        assert!(button.group.is_empty());
+        assert_eq!(button.field_name, "button_state");
+        assert_eq!(button.module_path, "ui::widgets::button");
+        assert_eq!(button.path(), "button");
+        Ok(())
+    }
+
+    #[test]
+    fn test_discover_nested_groups() -> Result<(), String> {
+        let views = discover_dampen_files(&fixture("nested_views"), &[], true)?;
+        let paths: Vec<_> = views.iter().map(ViewInfo::path).collect();
+        assert_eq!(paths, vec!["main", "widgets.button"]);
+
+        let button = &views[1];
+        assert_eq!(button.group, vec!["widgets".to_string()]);
+        assert_eq!(button.variant_name, "Button");
+        assert_eq!(button.field_name, "widgets_button_state");
+        assert_eq!(button.module_path, "ui::widgets::button");
+        Ok(())
+    }
+
+    #[test]
+    fn test_nested_groups_allow_same_file_name() -> Result<(), String> {
+        // Conflicts when flattened, but each main lives in its own group
+        assert!(discover_dampen_files(&fixture("naming_conflict"), &[], false).is_err());
+
+        let views = discover_dampen_files(&fixture("naming_conflict"), &[], true)?;
+        let paths: Vec<_> = views.iter().map(ViewInfo::path).collect();
+        assert_eq!(paths, vec!["main", "subdir.main"]);
+        Ok(())
+    }
 }
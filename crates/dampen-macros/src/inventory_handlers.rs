@@ -6,8 +6,9 @@
 //! # Purpose
 //!
 //! When using codegen mode, the build script needs to know which handlers are available
-//! in each UI module. This macro creates a public constant that lists all handlers,
-//! allowing build.rs to discover them automatically.
+//! in each UI module. It discovers the top-level `#[ui_handler]` functions on its own;
+//! this macro creates a public constant that lists the handlers explicitly, and is
+//! optional.
 //!
 //! # Example
 //!
@@ -51,7 +52,8 @@ use syn::{parse_macro_input, punctuated::Punctuated, token::Comma};
 ///
 /// # Arguments
 ///
-/// * `input` - Comma-separated list of handler names (e.g., `increment, decrement`)
+/// * `input` - Comma-separated list of handler names (e.g., `increment, decrement`),
+///   possibly empty
 ///
 /// # Returns
 ///
@@ -60,15 +62,6 @@ pub fn process_inventory_handlers(input: TokenStream) -> TokenStream {
     let handler_names =
         parse_macro_input!(input with Punctuated::<syn::Ident, Comma>::parse_terminated);
 
-    if handler_names.is_empty() {
-        return syn::Error::new(
-            proc_macro2::Span::call_site(),
-            "inventory_handlers! requires at least one handler name",
-        )
-        .to_compile_error()
-        .into();
-    }
-
     // Generate references to metadata constants
     let metadata_refs: Vec<_> = handler_names
         .iter()
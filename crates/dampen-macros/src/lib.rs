@@ -17,7 +17,18 @@ mod ui_model;
 ///
 /// - `#[ui_skip]`: Exclude field from binding
 /// - `#[ui_bind]`: Explicitly include field (overrides ui_skip)
-#[proc_macro_derive(UiModel, attributes(ui_skip, ui_bind))]
+/// - `#[ui_rename = "display_name"]`: Expose the field under another name
+/// - `#[ui_flatten]`: Expose the fields of a nested `UiBindable` struct at the
+///   top level (own fields take precedence on name clashes)
+/// - `#[ui_nested]`: Bind the fields of a nested `UiBindable` struct with
+///   dotted paths (`{cart.total}`), as `model="{cart}"` scopes do
+///
+/// Renamed and flattened names are resolved through `UiBindable`, so they
+/// apply to interpreted mode; codegen mode accesses struct fields directly.
+#[proc_macro_derive(
+    UiModel,
+    attributes(ui_skip, ui_bind, ui_rename, ui_flatten, ui_nested)
+)]
 pub fn ui_model_derive(input: TokenStream) -> TokenStream {
     ui_model::ui_model_derive(input)
 }
@@ -57,6 +68,22 @@ pub fn dampen_ui(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///     model.count += 1;
 /// }
 /// ```
+///
+/// # Supported Signatures
+///
+/// - `fn(model: &mut Model)`
+/// - `fn(model: &mut Model, value: T)`
+/// - `fn(model: &mut Model, shared: &SharedContext<S>)`
+/// - `fn(model: &mut Model, value: T, shared: &SharedContext<S>)`
+///
+/// Each may return `()` or a `Task`. Any other shape (a `self` receiver, an
+/// `async fn`, a non-`&mut` model, a borrowed value...) is a compile error
+/// pointing at the offending parameter.
+///
+/// # Command Palette
+///
+/// `#[ui_handler(palette = "Save File")]` lists a handler taking no value in the
+/// command palette (Ctrl+K) under the given label.
 #[proc_macro_attribute]
 pub fn ui_handler(attr: TokenStream, item: TokenStream) -> TokenStream {
     ui_handler::process_ui_handler(attr, item)
@@ -84,6 +111,12 @@ pub fn ui_handler(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// - `hot_reload_variant`: Message variant for file change events (e.g., `"HotReload"`)
 /// - `dismiss_error_variant`: Message variant for error overlay dismissal (e.g., `"DismissError"`)
 /// - `exclude`: Array of glob patterns to exclude views (e.g., `["debug_*", "test/*"]`)
+/// - `global_tasks`: Array of handler names whose tasks keep running across view switches
+///   (e.g., `["sync_loop"]`). Tasks returned by all other handlers are scoped to the view
+///   that started them and aborted when the user navigates away; a view can restart its
+///   work from an `on_view_enter` handler, which is dispatched whenever the view is entered.
+///   Names that no view defines, with `#[ui_handler]` or a registry `register_*` call, are
+///   a compile error.
 ///
 /// # Example
 ///
@@ -110,9 +143,10 @@ pub fn dampen_app(attr: TokenStream, item: TokenStream) -> TokenStream {
 
 /// Declare an inventory of UI handlers for build-time code generation.
 ///
-/// This macro must be used in any module that contains `#[ui_handler]` functions
-/// when building with the `codegen` feature. It creates a constant that the build
-/// script can use to discover available handlers.
+/// This macro is optional: the build script generated by `dampen new` discovers
+/// the `#[ui_handler]` functions of each module on its own. It creates a
+/// constant listing the handlers, and its names are still read by the build
+/// script, for handlers defined without `#[ui_handler]` at the top level.
 ///
 /// # Convention
 ///
@@ -145,9 +179,9 @@ pub fn dampen_app(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// # Errors
 ///
-/// This macro will fail at compile time if:
-/// - No handler names are provided
-/// - A referenced handler doesn't have the `#[ui_handler]` attribute
+/// This macro will fail at compile time if a referenced handler doesn't have
+/// the `#[ui_handler]` attribute. An empty list declares a module without
+/// handlers, as scaffolded by `dampen add view`.
 #[proc_macro]
 pub fn inventory_handlers(input: TokenStream) -> TokenStream {
     inventory_handlers::process_inventory_handlers(input)
@@ -25,10 +25,31 @@
 //!     model.count += 1;
 //! }
 //! ```
+//!
+//! # Command Palette
+//!
+//! `#[ui_handler(palette = "Save File")]` lists the handler in the command palette
+//! under the given label. Only handlers taking no value can be listed, since the
+//! palette runs them without one.
+//!
+//! # Signature Validation
+//!
+//! Handler signatures are checked when the macro expands, so an unsupported shape is
+//! reported at the offending parameter instead of failing later in generated code or
+//! being silently ignored at runtime. See [`SUPPORTED_SIGNATURES`].
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{FnArg, ItemFn, ReturnType, parse_macro_input};
+use syn::spanned::Spanned;
+use syn::{FnArg, ItemFn, LitStr, ReturnType, Type, parse_macro_input};
+
+/// Handler shapes accepted by `#[ui_handler]`, listed in signature errors
+pub const SUPPORTED_SIGNATURES: &str = "supported handler signatures:
+  fn(model: &mut Model)
+  fn(model: &mut Model, value: T)
+  fn(model: &mut Model, shared: &SharedContext<S>)
+  fn(model: &mut Model, value: T, shared: &SharedContext<S>)
+each optionally returning a Task (or Command)";
 
 /// Process the `#[ui_handler]` attribute macro.
 ///
@@ -58,9 +79,40 @@ use syn::{FnArg, ItemFn, ReturnType, parse_macro_input};
 ///
 /// The metadata constant is marked `#[doc(hidden)]` and does not affect
 /// the public API surface.
-pub fn process_ui_handler(_attr: TokenStream, item: TokenStream) -> TokenStream {
+///
+/// # Errors
+///
+/// Unsupported signatures (see [`SUPPORTED_SIGNATURES`]) produce compile errors
+/// pointing at the offending receiver, parameter or return type. The function
+/// itself is still emitted so its callers do not report unrelated errors.
+pub fn process_ui_handler(attr: TokenStream, item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as ItemFn);
 
+    let palette = match parse_palette_label(attr.into(), &input) {
+        Ok(palette) => palette,
+        Err(error) => {
+            let error = error.to_compile_error();
+            return quote! {
+                #input
+                #error
+            }
+            .into();
+        }
+    };
+    let palette = match palette {
+        Some(label) => quote! { Some(#label) },
+        None => quote! { None },
+    };
+
+    if let Err(error) = validate_signature(&input) {
+        let error = error.to_compile_error();
+        return quote! {
+            #input
+            #error
+        }
+        .into();
+    }
+
     // Extract handler metadata
     let handler_name = input.sig.ident.clone();
     let handler_name_str = handler_name.to_string();
@@ -102,12 +154,220 @@ pub fn process_ui_handler(_attr: TokenStream, item: TokenStream) -> TokenStream
             return_type: #return_type_str,
             source_file: #source_file,
             source_line: #source_line,
+            palette: #palette,
         };
     };
 
     output.into()
 }
 
+/// Parse the `palette = "Label"` argument of `#[ui_handler(...)]`
+///
+/// A handler listed in the palette must not take a value, as the palette has none
+/// to give it.
+fn parse_palette_label(
+    attr: proc_macro2::TokenStream,
+    func: &ItemFn,
+) -> syn::Result<Option<LitStr>> {
+    let mut label: Option<LitStr> = None;
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("palette") {
+            label = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error(
+                "unsupported #[ui_handler] argument\nhelp: The only argument is `palette = \"Label\"`",
+            ))
+        }
+    });
+    syn::parse::Parser::parse2(parser, attr)?;
+
+    if let Some(label) = &label {
+        if label.value().trim().is_empty() {
+            return Err(syn::Error::new(
+                label.span(),
+                "the palette label of a #[ui_handler] function cannot be empty",
+            ));
+        }
+        let value_param = func
+            .sig
+            .inputs
+            .iter()
+            .filter_map(|input| match input {
+                FnArg::Typed(pat_type) => Some(&*pat_type.ty),
+                FnArg::Receiver(_) => None,
+            })
+            .skip(1)
+            .find(|ty| !is_shared_context(ty));
+        if let Some(ty) = value_param {
+            return Err(syn::Error::new(
+                ty.span(),
+                "#[ui_handler] functions listed in the command palette cannot take a value\nhelp: The palette runs the handler without one; remove `palette` or the value parameter",
+            ));
+        }
+    }
+
+    Ok(label)
+}
+
+/// Validate that a handler has one of the [`SUPPORTED_SIGNATURES`]
+///
+/// All problems are reported together, each spanned on the offending item.
+fn validate_signature(func: &ItemFn) -> syn::Result<()> {
+    let sig = &func.sig;
+    let mut errors: Vec<syn::Error> = Vec::new();
+    let mut error = |span: proc_macro2::Span, message: &str| {
+        errors.push(syn::Error::new(
+            span,
+            format!("{}\n\n{}", message, SUPPORTED_SIGNATURES),
+        ));
+    };
+
+    if let Some(asyncness) = &sig.asyncness {
+        error(
+            asyncness.span(),
+            "#[ui_handler] functions cannot be async\nhelp: Return a Task running the async work instead",
+        );
+    }
+    if !sig.generics.params.is_empty() {
+        error(
+            sig.generics.params.span(),
+            "#[ui_handler] functions cannot be generic\nhelp: Use the concrete model and value types",
+        );
+    }
+    if let Some(variadic) = &sig.variadic {
+        error(
+            variadic.span(),
+            "#[ui_handler] functions cannot be variadic",
+        );
+    }
+
+    let mut typed = Vec::new();
+    for input in &sig.inputs {
+        match input {
+            FnArg::Receiver(receiver) => error(
+                receiver.span(),
+                "#[ui_handler] functions cannot take `self`\nhelp: Handlers are free functions taking the model as first parameter",
+            ),
+            FnArg::Typed(pat_type) => typed.push(&*pat_type.ty),
+        }
+    }
+
+    // The model comes first, as a mutable reference
+    match typed.first() {
+        None if !sig.inputs.iter().any(|i| matches!(i, FnArg::Receiver(_))) => error(
+            sig.paren_token.span.join(),
+            "#[ui_handler] functions must take the model as first parameter\nhelp: Add `model: &mut Model`",
+        ),
+        Some(ty) if is_shared_context(ty) => error(
+            ty.span(),
+            "the first parameter of a #[ui_handler] function must be the model\nhelp: Add `model: &mut Model` before the shared context",
+        ),
+        Some(ty) if !is_mut_reference(ty) => error(
+            ty.span(),
+            "the model parameter of a #[ui_handler] function must be a mutable reference\nhelp: Use `model: &mut Model`",
+        ),
+        _ => {}
+    }
+
+    // Then an optional value and an optional shared context, in that order
+    let rest = typed.iter().skip(1).collect::<Vec<_>>();
+    for (index, ty) in rest.iter().enumerate() {
+        let is_last = index + 1 == rest.len();
+        if is_shared_context(ty) {
+            if !is_last {
+                error(
+                    ty.span(),
+                    "the shared context must be the last parameter of a #[ui_handler] function",
+                );
+            } else if !matches!(ty, Type::Reference(r) if r.mutability.is_none()) {
+                error(
+                    ty.span(),
+                    "the shared context parameter of a #[ui_handler] function must be a shared reference\nhelp: Use `shared: &SharedContext<S>`",
+                );
+            }
+        } else if index > 0 {
+            error(
+                ty.span(),
+                "#[ui_handler] functions take at most one value parameter",
+            );
+        } else if let Err(message) = check_value_type(ty) {
+            error(ty.span(), message);
+        }
+    }
+
+    // Handlers return nothing, or a task for side effects
+    if let ReturnType::Type(_, ty) = &sig.output
+        && !is_task_type(ty)
+    {
+        error(
+            ty.span(),
+            "#[ui_handler] functions must return `()` or a Task\nhelp: Store results in the model instead of returning them",
+        );
+    }
+
+    match errors.into_iter().reduce(|mut all, next| {
+        all.combine(next);
+        all
+    }) {
+        Some(all) => Err(all),
+        None => Ok(()),
+    }
+}
+
+/// Returns `true` for `&mut T`
+fn is_mut_reference(ty: &Type) -> bool {
+    matches!(ty, Type::Reference(reference) if reference.mutability.is_some())
+}
+
+/// Returns `true` for `SharedContext<S>`, behind any reference
+fn is_shared_context(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(reference) => is_shared_context(&reference.elem),
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "SharedContext"),
+        _ => false,
+    }
+}
+
+/// Returns `true` for `Task<...>` and `Command<...>`, with or without a path
+fn is_task_type(ty: &Type) -> bool {
+    match ty {
+        Type::Tuple(tuple) => tuple.elems.is_empty(),
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Task" || segment.ident == "Command"),
+        _ => false,
+    }
+}
+
+/// Check the type of the value parameter, which is converted from the UI event
+fn check_value_type(ty: &Type) -> Result<(), &'static str> {
+    match ty {
+        Type::Reference(reference) if reference.mutability.is_some() => Err(
+            "the value parameter of a #[ui_handler] function cannot be a mutable reference\nhelp: Take the value by value, e.g. `value: String`",
+        ),
+        Type::Reference(reference) => match &*reference.elem {
+            Type::Path(path) if path.path.is_ident("str") => Ok(()),
+            _ => Err(
+                "the value parameter of a #[ui_handler] function must be owned (or `&str`)\nhelp: Take the value by value, e.g. `value: String`",
+            ),
+        },
+        Type::ImplTrait(_) => Err(
+            "the value parameter of a #[ui_handler] function cannot be `impl Trait`\nhelp: Use a concrete type, e.g. `value: String`",
+        ),
+        Type::TraitObject(_) | Type::Infer(_) | Type::Never(_) => Err(
+            "unsupported value parameter type for a #[ui_handler] function\nhelp: Use a concrete type, e.g. `value: String`",
+        ),
+        _ => Ok(()),
+    }
+}
+
 /// Analyze function signature to determine handler type and parameters
 fn analyze_signature(func: &ItemFn) -> (String, Vec<String>) {
     let mut param_types = Vec::new();
@@ -148,13 +408,122 @@ fn analyze_signature(func: &ItemFn) -> (String, Vec<String>) {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    fn error_message(func: ItemFn) -> Result<String, String> {
+        match validate_signature(&func) {
+            Ok(()) => Err("expected a signature error".to_string()),
+            Err(error) => Ok(error.to_string()),
+        }
+    }
+
     #[test]
-    fn test_signature_analysis() {
-        // This is a unit test for the signature analysis logic
-        // The actual macro tests are in tests/handler_metadata_tests.rs
+    fn test_supported_signatures_are_accepted() -> Result<(), String> {
+        let handlers: Vec<ItemFn> = vec![
+            parse_quote! { fn a(model: &mut Model) {} },
+            parse_quote! { fn b(model: &mut Model, value: String) {} },
+            parse_quote! { fn c(model: &mut Model, value: &str) {} },
+            parse_quote! { fn d(model: &mut Model, shared: &SharedContext<S>) {} },
+            parse_quote! { fn e(model: &mut Model, v: f32, shared: &dampen_core::SharedContext<S>) {} },
+            parse_quote! { fn f(model: &mut Model) -> Task<Message> { Task::none() } },
+            parse_quote! { fn g(model: &mut Model) -> iced::Task<Message> { Task::none() } },
+            parse_quote! { fn h(model: &mut Model) -> () {} },
+        ];
+        for handler in handlers {
+            validate_signature(&handler)
+                .map_err(|e| format!("{} rejected: {}", handler.sig.ident, e))?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_self_receiver_is_rejected() -> Result<(), String> {
+        let message = error_message(parse_quote! { fn a(&mut self) {} })?;
+        assert!(message.contains("cannot take `self`"), "{message}");
         assert!(
-            true,
-            "Signature analysis logic tested via integration tests"
+            message.contains("supported handler signatures"),
+            "{message}"
         );
+        Ok(())
+    }
+
+    #[test]
+    fn test_model_parameter_is_required() -> Result<(), String> {
+        let missing = error_message(parse_quote! { fn a() {} })?;
+        assert!(missing.contains("first parameter"), "{missing}");
+
+        let by_value = error_message(parse_quote! { fn a(model: Model) {} })?;
+        assert!(by_value.contains("mutable reference"), "{by_value}");
+
+        let shared_first = error_message(parse_quote! { fn a(shared: &SharedContext<S>) {} })?;
+        assert!(shared_first.contains("must be the model"), "{shared_first}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsupported_value_parameters_are_rejected() -> Result<(), String> {
+        let mutable = error_message(parse_quote! { fn a(model: &mut Model, v: &mut String) {} })?;
+        assert!(mutable.contains("mutable reference"), "{mutable}");
+
+        let impl_trait =
+            error_message(parse_quote! { fn a(model: &mut Model, v: impl Into<String>) {} })?;
+        assert!(impl_trait.contains("`impl Trait`"), "{impl_trait}");
+
+        let two_values =
+            error_message(parse_quote! { fn a(model: &mut Model, a: String, b: String) {} })?;
+        assert!(two_values.contains("at most one value"), "{two_values}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_shared_context_must_be_last() -> Result<(), String> {
+        let message = error_message(parse_quote! {
+            fn a(model: &mut Model, shared: &SharedContext<S>, value: String) {}
+        })?;
+        assert!(message.contains("must be the last parameter"), "{message}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsupported_return_and_async_are_rejected() -> Result<(), String> {
+        let returns = error_message(parse_quote! { fn a(model: &mut Model) -> bool { true } })?;
+        assert!(returns.contains("must return `()` or a Task"), "{returns}");
+
+        let asynchronous = error_message(parse_quote! { async fn a(model: &mut Model) {} })?;
+        assert!(asynchronous.contains("cannot be async"), "{asynchronous}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_palette_label() -> Result<(), String> {
+        let save: ItemFn = parse_quote! { fn save(model: &mut Model) {} };
+        let label = parse_palette_label(quote! { palette = "Save File" }, &save)
+            .map_err(|e| e.to_string())?;
+        assert_eq!(label.map(|l| l.value()), Some("Save File".to_string()));
+        assert!(matches!(parse_palette_label(quote! {}, &save), Ok(None)));
+
+        let unknown = parse_palette_label(quote! { label = "Save" }, &save)
+            .err()
+            .map(|e| e.to_string())
+            .unwrap_or_default();
+        assert!(unknown.contains("unsupported"), "{unknown}");
+
+        let rename: ItemFn = parse_quote! { fn rename(model: &mut Model, name: String) {} };
+        let with_value = parse_palette_label(quote! { palette = "Rename" }, &rename)
+            .err()
+            .map(|e| e.to_string())
+            .unwrap_or_default();
+        assert!(with_value.contains("cannot take a value"), "{with_value}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_all_errors_are_reported() {
+        let func: ItemFn = parse_quote! { fn a(model: Model, v: impl Display) -> bool { true } };
+        let count = validate_signature(&func)
+            .err()
+            .map_or(0, |error| error.into_iter().count());
+        assert_eq!(count, 3);
     }
 }
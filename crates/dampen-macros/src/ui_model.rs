@@ -17,11 +17,16 @@ pub fn ui_model_derive(input: TokenStream) -> TokenStream {
         }
     };
 
+    let bindings = match collect_bindings(fields) {
+        Ok(bindings) => bindings,
+        Err(error) => return error.to_compile_error().into(),
+    };
+
     // Generate get_field implementation
-    let get_field_impl = generate_get_field(name, fields);
+    let get_field_impl = generate_get_field(name, &bindings);
 
     // Generate available_fields implementation
-    let available_fields_impl = generate_available_fields(name, fields);
+    let available_fields_impl = generate_available_fields(name, &bindings);
 
     let expanded = quote! {
         impl dampen_core::binding::UiBindable for #name {
@@ -33,43 +38,184 @@ pub fn ui_model_derive(input: TokenStream) -> TokenStream {
     expanded.into()
 }
 
-fn generate_get_field(_name: &syn::Ident, fields: &Fields) -> proc_macro2::TokenStream {
-    let mut arms = Vec::new();
+/// How a struct field is exposed to bindings
+enum FieldBinding<'a> {
+    /// Field bound under an XML-visible name (its own, or `#[ui_rename]`)
+    Named { field: &'a syn::Ident, name: String },
+    /// `#[ui_nested]` field whose own fields are bound with dotted paths (`{cart.total}`)
+    Nested {
+        field: &'a syn::Ident,
+        name: String,
+        ty: &'a syn::Type,
+    },
+    /// `#[ui_flatten]` field whose own fields are exposed at the top level
+    Flattened {
+        field: &'a syn::Ident,
+        ty: &'a syn::Type,
+    },
+}
+
+/// Collect the bound fields, honouring `#[ui_skip]`, `#[ui_bind]`, `#[ui_rename]`,
+/// `#[ui_flatten]` and `#[ui_nested]`
+fn collect_bindings(fields: &Fields) -> syn::Result<Vec<FieldBinding<'_>>> {
+    let mut bindings = Vec::new();
+    let mut names: Vec<(String, &syn::Ident)> = Vec::new();
+
+    let Fields::Named(fields_named) = fields else {
+        return Ok(bindings);
+    };
+
+    for field in &fields_named.named {
+        let Some(field_name) = field.ident.as_ref() else {
+            continue;
+        };
+
+        // Check for #[ui_skip] and #[ui_bind] attributes
+        let skip = field
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident("ui_skip"));
+        let bind = field
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident("ui_bind"));
+        let flatten = field
+            .attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("ui_flatten"));
+        let rename = field
+            .attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("ui_rename"));
+        let nested = field
+            .attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("ui_nested"));
+
+        if skip && !bind {
+            continue;
+        }
 
-    if let Fields::Named(fields_named) = fields {
-        for field in &fields_named.named {
-            let field_name = match field.ident.as_ref() {
-                Some(name) => name,
-                None => continue,
-            };
-            let field_name_str = field_name.to_string();
-
-            // Check for #[ui_skip] and #[ui_bind] attributes
-            let skip = field
-                .attrs
-                .iter()
-                .any(|attr| attr.path().is_ident("ui_skip"));
-            let bind = field
-                .attrs
-                .iter()
-                .any(|attr| attr.path().is_ident("ui_bind"));
-
-            if skip && !bind {
-                continue;
+        if let Some(flatten) = flatten {
+            flatten.meta.require_path_only()?;
+            if let Some(nested) = nested {
+                return Err(syn::Error::new_spanned(
+                    nested,
+                    "#[ui_nested] cannot be combined with #[ui_flatten]",
+                ));
+            }
+            if let Some(rename) = rename {
+                return Err(syn::Error::new_spanned(
+                    rename,
+                    "#[ui_rename] cannot be combined with #[ui_flatten]; rename the fields of the nested struct instead",
+                ));
             }
+            bindings.push(FieldBinding::Flattened {
+                field: field_name,
+                ty: &field.ty,
+            });
+            continue;
+        }
 
-            // Generate single-field match arm
-            arms.push(quote! {
-                [#field_name_str] => {
-                    use dampen_core::binding::ToBindingValue;
-                    Some(dampen_core::binding::BindingValue::from_value(&self.#field_name))
-                }
+        let name = match rename {
+            Some(rename) => parse_rename(rename)?,
+            None => field_name.to_string(),
+        };
+
+        if let Some((_, other)) = names.iter().find(|(existing, _)| *existing == name) {
+            return Err(syn::Error::new_spanned(
+                field_name,
+                format!(
+                    "UI field name '{}' is already used by field '{}'\nhelp: Use #[ui_rename = \"...\"] to give one of them a different name",
+                    name, other
+                ),
+            ));
+        }
+        names.push((name.clone(), field_name));
+
+        if let Some(nested) = nested {
+            nested.meta.require_path_only()?;
+            bindings.push(FieldBinding::Nested {
+                field: field_name,
+                name,
+                ty: &field.ty,
             });
+            continue;
+        }
+
+        bindings.push(FieldBinding::Named {
+            field: field_name,
+            name,
+        });
+    }
 
-            // Generate nested-field match arms (for fields that are UiBindable)
-            // This is a simplified approach - in reality, we'd need to detect if the type
-            // implements UiBindable, which requires more complex macro logic
-            // For now, we'll just handle single-level paths
+    Ok(bindings)
+}
+
+/// Parse `#[ui_rename = "display_name"]`, requiring a valid binding identifier
+fn parse_rename(attr: &syn::Attribute) -> syn::Result<String> {
+    let name_value = attr.meta.require_name_value()?;
+    let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Str(lit),
+        ..
+    }) = &name_value.value
+    else {
+        return Err(syn::Error::new_spanned(
+            &name_value.value,
+            "expected a string literal, e.g. #[ui_rename = \"display_name\"]",
+        ));
+    };
+
+    let name = lit.value();
+    let mut chars = name.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid {
+        return Err(syn::Error::new_spanned(
+            lit,
+            format!(
+                "invalid UI field name '{}': must start with a letter or underscore and contain only letters, digits and underscores",
+                name
+            ),
+        ));
+    }
+
+    Ok(name)
+}
+
+fn generate_get_field(_name: &syn::Ident, bindings: &[FieldBinding]) -> proc_macro2::TokenStream {
+    let mut arms = Vec::new();
+    let mut flattened = Vec::new();
+
+    for binding in bindings {
+        match binding {
+            FieldBinding::Named { field, name } => {
+                // Generate single-field match arm
+                arms.push(quote! {
+                    [#name] => {
+                        use dampen_core::binding::ToBindingValue;
+                        Some(dampen_core::binding::BindingValue::from_value(&self.#field))
+                    }
+                });
+            }
+            FieldBinding::Nested { field, name, .. } => {
+                // Dotted paths are resolved by the nested model
+                arms.push(quote! {
+                    [#name, rest @ ..] if !rest.is_empty() => {
+                        dampen_core::binding::UiBindable::get_field(&self.#field, rest)
+                    }
+                });
+            }
+            FieldBinding::Flattened { field, .. } => {
+                // Flattened fields are searched in declaration order after own fields
+                flattened.push(quote! {
+                    if let Some(value) = dampen_core::binding::UiBindable::get_field(&self.#field, path) {
+                        return Some(value);
+                    }
+                });
+            }
         }
     }
 
@@ -81,42 +227,56 @@ fn generate_get_field(_name: &syn::Ident, fields: &Fields) -> proc_macro2::Token
 
             match path {
                 #(#arms)*
-                _ => None,
+                _ => {
+                    #(#flattened)*
+                    None
+                }
             }
         }
     }
 }
 
-fn generate_available_fields(_name: &syn::Ident, fields: &Fields) -> proc_macro2::TokenStream {
-    let field_names: Vec<String> = match fields {
-        Fields::Named(fields_named) => fields_named
-            .named
-            .iter()
-            .filter_map(|field| {
-                let skip = field
-                    .attrs
-                    .iter()
-                    .any(|attr| attr.path().is_ident("ui_skip"));
-
-                let bind = field
-                    .attrs
-                    .iter()
-                    .any(|attr| attr.path().is_ident("ui_bind"));
-
-                if skip && !bind {
-                    None
-                } else {
-                    let field_name = field.ident.as_ref()?;
-                    Some(field_name.to_string())
-                }
-            })
-            .collect(),
-        _ => Vec::new(),
-    };
+fn generate_available_fields(
+    _name: &syn::Ident,
+    bindings: &[FieldBinding],
+) -> proc_macro2::TokenStream {
+    let mut names = Vec::new();
+    let mut nested = Vec::new();
+    let mut flattened = Vec::new();
+    for binding in bindings {
+        match binding {
+            FieldBinding::Named { name, .. } => names.push(name),
+            FieldBinding::Nested { name, ty, .. } => nested.push((name, ty)),
+            FieldBinding::Flattened { ty, .. } => flattened.push(ty),
+        }
+    }
+
+    // Own fields come first, matching the lookup order of get_field
+    if nested.is_empty() && flattened.is_empty() {
+        return quote! {
+            fn available_fields() -> Vec<String> {
+                vec![#(#names.to_string()),*]
+            }
+        };
+    }
+
+    // Nested fields are listed with their dotted path
+    let nested_fields = nested.iter().map(|(name, ty)| {
+        quote! {
+            fields.extend(
+                <#ty as dampen_core::binding::UiBindable>::available_fields()
+                    .into_iter()
+                    .map(|field| format!("{}.{}", #name, field)),
+            );
+        }
+    });
 
     quote! {
         fn available_fields() -> Vec<String> {
-            vec![#(#field_names.to_string()),*]
+            let mut fields = vec![#(#names.to_string()),*];
+            #(#nested_fields)*
+            #(fields.extend(<#flattened as dampen_core::binding::UiBindable>::available_fields());)*
+            fields
         }
     }
 }
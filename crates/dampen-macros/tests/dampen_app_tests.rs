@@ -540,6 +540,66 @@ mod us3_hot_reload_tests {
         );
     }
 
+    // Integration test: Verify batched file changes are dispatched one by one
+    #[test]
+    fn test_hot_reload_batch_handling() {
+        // Given: MacroAttributes with hot_reload_variant
+        let attr = quote::quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            hot_reload_variant = "HotReload"
+        };
+        let item = quote::quote! { struct App; };
+
+        // When: We expand the macro
+        let output =
+            dampen_app::dampen_app_impl(attr, item).expect("Macro expansion should succeed");
+        let output_str = output.to_string();
+
+        // Then: Batches should be re-dispatched through update() as individual events
+        assert!(
+            output_str.contains("FileEvent :: Batch (batch)"),
+            "Should handle FileEvent::Batch"
+        );
+        assert!(
+            output_str.contains("self . update (Message :: HotReload (event))"),
+            "Should apply each event of the batch through update()"
+        );
+    }
+
+    // Integration test: Verify theme file changes update theme contexts in place
+    #[test]
+    fn test_hot_reload_theme_changed_handling() {
+        // Given: MacroAttributes with hot_reload_variant
+        let attr = quote::quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            hot_reload_variant = "HotReload"
+        };
+        let item = quote::quote! { struct App; };
+
+        // When: We expand the macro
+        let output =
+            dampen_app::dampen_app_impl(attr, item).expect("Macro expansion should succeed");
+        let output_str = output.to_string();
+
+        // Then: Each view's ThemeContext should be updated incrementally
+        assert!(
+            output_str.contains("FileEvent :: ThemeChanged"),
+            "Should handle FileEvent::ThemeChanged"
+        );
+        assert!(
+            output_str.contains("self . home_state . theme_context"),
+            "Should update the theme context of each view"
+        );
+        assert!(
+            output_str.contains("ctx . apply_update (diff)"),
+            "Should apply the theme diff instead of reloading the whole context"
+        );
+    }
+
     // Integration test: Verify error overlay only generated when variant specified
     #[test]
     fn test_error_overlay_conditional_generation() {
@@ -1004,5 +1064,822 @@ mod shared_model_tests {
                 "shared_model should be 'SharedState'"
             );
         }
+
+        // Part 3: Several named shared models
+        // -----------------------------------------------------------
+
+        let attr_named = quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            shared = ["Session", "UserSettings"]
+        };
+        let item = quote! { struct App; };
+
+        // src/shared.rs was created above, so the named models must be accepted
+        let output = dampen_app::dampen_app_impl(attr_named, item)
+            .unwrap_or_else(|e| panic!("Unexpected error with named shared models: {}", e));
+        let output_str = output.to_string();
+        assert!(
+            output_str.contains(
+                "shared : dampen_core :: SharedContext < dampen_core :: SharedContexts >"
+            )
+        );
+        assert!(output_str.contains(
+            ". with (\"session\" , dampen_core :: SharedContext :: new (shared :: Session :: default ()))"
+        ));
+        assert!(output_str.contains(
+            ". with (\"user_settings\" , dampen_core :: SharedContext :: new (shared :: UserSettings :: default ()))"
+        ));
+        assert!(output_str.contains(
+            "dampen_core :: AppState < ui :: home :: Model , dampen_core :: SharedContexts >"
+        ));
+    }
+
+    // shared lists named models and excludes shared_model
+    #[test]
+    fn test_named_shared_models_validation() {
+        let both = quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            shared_model = "SharedState",
+            shared = ["Session"]
+        };
+        let result = parse2::<dampen_app::MacroAttributes>(both);
+        assert!(result.is_err(), "shared_model and shared should conflict");
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("shared_model and shared cannot be used together")
+        );
+
+        let duplicate = quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            shared = ["Session", "Session"]
+        };
+        let result = parse2::<dampen_app::MacroAttributes>(duplicate);
+        assert!(
+            result.is_err(),
+            "Duplicate shared models should be rejected"
+        );
+        assert!(result.unwrap_err().to_string().contains("listed twice"));
+    }
+}
+
+// ==============================================================================
+// Per-view task scopes
+// ==============================================================================
+
+#[cfg(test)]
+mod task_scope_tests {
+    use super::*;
+
+    // Handler tasks are scoped to the active view by default
+    #[test]
+    fn test_handler_tasks_scoped_by_default() {
+        let attr = quote::quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler"
+        };
+        let item = quote::quote! { struct App; };
+
+        let output =
+            dampen_app::dampen_app_impl(attr, item).expect("Macro expansion should succeed");
+        let output_str = output.to_string();
+
+        assert!(
+            output_str.contains("home_tasks : dampen_iced :: TaskScope"),
+            "Should generate one task scope field per view"
+        );
+        assert!(
+            output_str.contains("scope . track (* task)"),
+            "Handler tasks should be tracked by the view's scope"
+        );
+        assert!(
+            output_str.contains("const GLOBAL_TASK_HANDLERS : & [& str] = & [] ;"),
+            "No handler should be global without the global_tasks attribute"
+        );
+    }
+
+    // Switching views cancels the scope of the view being left
+    #[test]
+    fn test_switch_to_cancels_current_view_tasks() {
+        let attr = quote::quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            switch_view_variant = "SwitchToView"
+        };
+        let item = quote::quote! { struct App; };
+
+        let output =
+            dampen_app::dampen_app_impl(attr, item).expect("Macro expansion should succeed");
+        let output_str = output.to_string();
+
+        assert!(
+            output_str.contains("pub fn cancel_current_view_tasks (& mut self)"),
+            "Should generate cancel_current_view_tasks()"
+        );
+        assert!(
+            output_str.contains("self . settings_tasks . cancel_all ()"),
+            "Should cancel the settings scope when leaving the settings view"
+        );
+        assert!(
+            output_str.contains("pub fn enter_current_view (& mut self)"),
+            "Should generate enter_current_view()"
+        );
+        assert!(
+            output_str.contains("\"on_view_enter\""),
+            "Entering a view should dispatch its on_view_enter handler"
+        );
+    }
+
+    // global_tasks opts handlers out of view scoping
+    #[test]
+    fn test_global_tasks_attribute() {
+        let attr = quote::quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            global_tasks = ["sync_loop", "poll_updates"]
+        };
+        let item = quote::quote! { struct App; };
+
+        let output =
+            dampen_app::dampen_app_impl(attr, item).expect("Macro expansion should succeed");
+        let output_str = output.to_string();
+
+        assert!(
+            output_str.contains(
+                "const GLOBAL_TASK_HANDLERS : & [& str] = & [\"sync_loop\" , \"poll_updates\"] ;"
+            ),
+            "Global handlers should be listed (found in: {})",
+            output_str
+        );
+    }
+
+    // global_tasks must be an array of string literals
+    #[test]
+    fn test_global_tasks_parsing() {
+        let attr = quote::quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            global_tasks = ["sync_loop"]
+        };
+        let attrs =
+            syn::parse2::<dampen_app::MacroAttributes>(attr).expect("global_tasks should parse");
+        assert_eq!(attrs.global_tasks, vec!["sync_loop".to_string()]);
+
+        let invalid = quote::quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            global_tasks = "sync_loop"
+        };
+        assert!(syn::parse2::<dampen_app::MacroAttributes>(invalid).is_err());
+    }
+
+    // global_tasks entries must name handlers of the views
+    #[test]
+    fn test_global_tasks_unknown_handler() {
+        let attr = quote::quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            global_tasks = ["sync_loop", "sync_lop"]
+        };
+        let item = quote::quote! { struct App; };
+
+        let result = dampen_app::dampen_app_impl(attr, item);
+        assert!(result.is_err(), "Unknown global task handler should fail");
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("global_tasks handler 'sync_lop' not found")
+        );
+    }
+}
+
+// ==============================================================================
+// Widget state preservation across hot-reload
+// ==============================================================================
+
+#[cfg(test)]
+mod widget_state_tests {
+    use super::*;
+
+    // A successful reload restores scroll offsets and focus in the changed subtrees of the view
+    #[test]
+    fn test_hot_reload_restores_widget_state() {
+        let attr = quote::quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            hot_reload_variant = "HotReload"
+        };
+        let item = quote::quote! { struct App; };
+
+        let output =
+            dampen_app::dampen_app_impl(attr, item).expect("Macro expansion should succeed");
+        let output_str = output.to_string();
+
+        assert!(
+            output_str.contains("dampen_iced :: widget_state :: restore_widget_state"),
+            "Hot-reload should restore widget state"
+        );
+        assert!(
+            output_str.contains(
+                "let scope = dampen_dev :: reload :: apply_incremental_hot_reload (& mut self . home_state , * document . clone ()) ;"
+            ),
+            "Should reload the view incrementally"
+        );
+        assert!(
+            output_str.contains("& scope . widget_state (& self . home_state)"),
+            "Should restore the state recorded by the reloaded view"
+        );
+    }
+}
+
+// ==============================================================================
+// Nested view directories as navigation groups
+// ==============================================================================
+
+#[cfg(test)]
+mod nested_views_tests {
+    use super::*;
+
+    // Subdirectories stay flat unless nested_views is enabled
+    #[test]
+    fn test_nested_views_disabled_by_default() {
+        let attr = quote::quote! {
+            ui_dir = "tests/fixtures/nested_views/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler"
+        };
+        let item = quote::quote! { struct App; };
+
+        let output =
+            dampen_app::dampen_app_impl(attr, item).expect("Macro expansion should succeed");
+        let output_str = output.to_string();
+
+        assert!(output_str.contains("pub enum CurrentView { Button , Main }"));
+        assert!(!output_str.contains("WidgetsView"));
+        assert!(!output_str.contains("IntoCurrentView"));
+    }
+
+    // Directories generate one enum per group, wrapped by the parent group
+    #[test]
+    fn test_nested_views_generate_hierarchical_enum() {
+        let attr = quote::quote! {
+            ui_dir = "tests/fixtures/nested_views/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            hot_reload_variant = "HotReload",
+            switch_view_variant = "SwitchToView",
+            nested_views = true
+        };
+        let item = quote::quote! { struct App; };
+
+        let output =
+            dampen_app::dampen_app_impl(attr, item).expect("Macro expansion should succeed");
+        let output_str = output.to_string();
+
+        assert!(
+            output_str.contains("pub enum CurrentView { Main , Widgets (WidgetsView) }"),
+            "Root enum should wrap the widgets group (found in: {})",
+            output_str
+        );
+        assert!(output_str.contains("pub enum WidgetsView { Button }"));
+        assert!(output_str.contains("widgets_button_state : dampen_core :: AppState"));
+        assert!(output_str.contains(
+            "pub fn switch_to_widgets_button (& mut self) { if self . current_view == CurrentView :: Widgets (WidgetsView :: Button)"
+        ));
+        assert!(
+            output_str.contains(
+                "std :: path :: Path :: new (path_str) . ends_with (\"widgets/button.dampen\")"
+            ),
+            "Grouped views should hot-reload by relative path"
+        );
+    }
+
+    // Views are addressed by dotted paths
+    #[test]
+    fn test_nested_views_dotted_paths() {
+        let attr = quote::quote! {
+            ui_dir = "tests/fixtures/nested_views/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            switch_view_variant = "SwitchToView",
+            nested_views = true,
+            default_view = "widgets.button"
+        };
+        let item = quote::quote! { struct App; };
+
+        let output =
+            dampen_app::dampen_app_impl(attr, item).expect("Macro expansion should succeed");
+        let output_str = output.to_string();
+
+        assert!(
+            output_str.contains("current_view : CurrentView :: Widgets (WidgetsView :: Button)")
+        );
+        assert!(output_str.contains(
+            "\"widgets.button\" => Some (CurrentView :: Widgets (WidgetsView :: Button))"
+        ));
+        assert!(
+            output_str.contains("IntoCurrentView :: into_current_view (view)"),
+            "SwitchToView should accept dotted paths"
+        );
+
+        // Flat names of nested views are not valid default views
+        let attr = quote::quote! {
+            ui_dir = "tests/fixtures/nested_views/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            nested_views = true,
+            default_view = "button"
+        };
+        let item = quote::quote! { struct App; };
+        let result = dampen_app::dampen_app_impl(attr, item);
+        assert!(result.is_err(), "button is widgets.button");
+        let err = result.unwrap_err();
+        assert!(err.to_string().contains("widgets.button"));
+    }
+
+    // Same file names in different groups do not conflict
+    #[test]
+    fn test_nested_views_resolve_naming_conflicts() {
+        let attr = quote::quote! {
+            ui_dir = "tests/fixtures/naming_conflict/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            nested_views = true
+        };
+        let item = quote::quote! { struct App; };
+
+        let output =
+            dampen_app::dampen_app_impl(attr, item).expect("Macro expansion should succeed");
+        let output_str = output.to_string();
+
+        assert!(output_str.contains("main_state :"));
+        assert!(output_str.contains("subdir_main_state :"));
+    }
+
+    // group_shared_models is only meaningful for nested views
+    #[test]
+    fn test_group_shared_models_requires_nested_views() {
+        let attr = quote::quote! {
+            ui_dir = "tests/fixtures/nested_views/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            group_shared_models = ["widgets:WidgetsState"]
+        };
+        let result = syn::parse2::<dampen_app::MacroAttributes>(attr);
+        assert!(
+            result.is_err(),
+            "group_shared_models without nested_views should fail"
+        );
+        let err = result.unwrap_err();
+        assert!(err.to_string().contains("nested_views"));
+
+        let invalid = quote::quote! {
+            ui_dir = "tests/fixtures/nested_views/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            nested_views = true,
+            group_shared_models = ["WidgetsState"]
+        };
+        let result = syn::parse2::<dampen_app::MacroAttributes>(invalid);
+        assert!(result.is_err(), "entries without a group should fail");
+        let err = result.unwrap_err();
+        assert!(err.to_string().contains("group:Type"));
+    }
+}
+
+// ==============================================================================
+// Lazy view initialization
+// ==============================================================================
+
+#[cfg(test)]
+mod lazy_views_tests {
+    use super::*;
+
+    // Lazy views are optional fields, created on first navigation
+    #[test]
+    fn test_lazy_views_created_on_navigation() {
+        let attr = quote::quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            default_view = "home",
+            lazy = true
+        };
+        let item = quote::quote! { struct App; };
+
+        let output =
+            dampen_app::dampen_app_impl(attr, item).expect("Macro expansion should succeed");
+        let output_str = output.to_string();
+
+        assert!(output_str.contains(
+            "settings_state : Option < dampen_core :: AppState < ui :: settings :: Model > >"
+        ));
+        assert!(output_str.contains("settings_state : None"));
+        assert!(
+            output_str.contains("app . prefetch (CurrentView :: Home) ;"),
+            "init() should only create the default view"
+        );
+        assert!(!output_str.contains("app . prefetch (CurrentView :: Settings)"));
+        assert!(
+            output_str.contains(
+                "self . cancel_current_view_tasks () ; self . prefetch (CurrentView :: Settings) ;"
+            ),
+            "Switching should create the target view"
+        );
+        assert!(output_str.contains("pub fn prefetch (& mut self , view : CurrentView)"));
+        assert!(
+            output_str.contains("pub fn is_view_loaded (& self , view : & CurrentView) -> bool")
+        );
+    }
+
+    // prefetch warms views at startup
+    #[test]
+    fn test_lazy_views_prefetch_attribute() {
+        let attr = quote::quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            lazy = true,
+            prefetch = ["settings"]
+        };
+        let item = quote::quote! { struct App; };
+
+        let output =
+            dampen_app::dampen_app_impl(attr, item).expect("Macro expansion should succeed");
+        let output_str = output.to_string();
+
+        assert!(output_str.contains("app . prefetch (CurrentView :: About) ;"));
+        assert!(output_str.contains("app . prefetch (CurrentView :: Settings) ;"));
+
+        let unknown = quote::quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            lazy = true,
+            prefetch = ["missing"]
+        };
+        let item = quote::quote! { struct App; };
+        let result = dampen_app::dampen_app_impl(unknown, item);
+        assert!(result.is_err(), "Unknown prefetch views should fail");
+
+        let not_lazy = quote::quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            prefetch = ["settings"]
+        };
+        let result = syn::parse2::<dampen_app::MacroAttributes>(not_lazy);
+        assert!(result.is_err(), "prefetch without lazy should fail");
+    }
+
+    // Views are created upfront unless lazy is enabled
+    #[test]
+    fn test_views_created_upfront_by_default() {
+        let attr = quote::quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler"
+        };
+        let item = quote::quote! { struct App; };
+
+        let output =
+            dampen_app::dampen_app_impl(attr, item).expect("Macro expansion should succeed");
+        let output_str = output.to_string();
+
+        assert!(output_str.contains("settings_state : ui :: settings :: create_app_state ()"));
+        assert!(!output_str.contains("fn prefetch"));
+    }
+}
+
+// ==============================================================================
+// Style libraries
+// ==============================================================================
+
+#[cfg(test)]
+mod style_library_tests {
+    use super::*;
+
+    // Style libraries are merged into every view instead of being views
+    #[test]
+    fn test_style_libraries_merged_into_views() {
+        let attr = quote::quote! {
+            ui_dir = "tests/fixtures/style_library/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            hot_reload_variant = "HotReload",
+            styles = ["styles/buttons.dampen"]
+        };
+        let item = quote::quote! { struct App; };
+
+        let output =
+            dampen_app::dampen_app_impl(attr, item).expect("Macro expansion should succeed");
+        let output_str = output.to_string();
+
+        assert!(
+            !output_str.contains("Buttons"),
+            "Style libraries should not be discovered as views"
+        );
+        assert!(output_str.contains(
+            "let mut state = ui :: main :: create_app_state () ; Self :: apply_style_libraries (& mut state . document) ;"
+        ));
+        assert!(
+            output_str.contains(
+                "fn apply_style_libraries (document : & mut dampen_core :: DampenDocument)"
+            )
+        );
+        assert!(
+            output_str.contains("Self :: apply_style_libraries (& mut document) ; let scope = dampen_dev :: reload :: apply_incremental_hot_reload (& mut self . main_state , document) ;"),
+            "Reloaded views should get the libraries merged again"
+        );
+
+        let missing = quote::quote! {
+            ui_dir = "tests/fixtures/style_library/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            styles = ["styles/missing.dampen"]
+        };
+        let result = syn::parse2::<dampen_app::MacroAttributes>(missing);
+        assert!(result.is_err(), "Missing style libraries should fail");
+    }
+}
+
+// ==============================================================================
+// Handler panics
+// ==============================================================================
+
+#[cfg(test)]
+mod catch_panics_tests {
+    use super::*;
+
+    // Panicking handlers are shown in the error overlay, with a reset button
+    #[test]
+    fn test_catch_panics_shows_panics_in_overlay() {
+        let attr = quote::quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            dismiss_error_variant = "DismissError",
+            catch_panics = true,
+            reset_state_variant = "ResetState"
+        };
+        let item = quote::quote! { struct App; };
+
+        let output =
+            dampen_app::dampen_app_impl(attr, item).expect("Macro expansion should succeed");
+        let output_str = output.to_string();
+
+        assert!(output_str.contains(
+            "let snapshot = self . home_state . handler_registry . contains (& handler_name) . then (|| self . home_state . model . clone ()) ;"
+        ));
+        assert!(output_str.contains("dampen_dev :: catch_handler_panic (& handler_name"));
+        assert!(
+            output_str.contains(
+                "if let Some (snapshot) = snapshot { self . home_state . model = snapshot ; } self . error_overlay . show_panic (report) ;"
+            ),
+            "The model should be restored when the handler panics"
+        );
+        assert!(output_str.contains("Message :: ResetState =>"));
+        assert!(output_str.contains("self . home_state . model = Default :: default () ;"));
+        assert!(output_str.contains(
+            "error_overlay . layer (Message :: DismissError , Some (Message :: ResetState))"
+        ));
+
+        // Panics are not caught by default, and the model is never cloned
+        let default = quote::quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            dismiss_error_variant = "DismissError"
+        };
+        let output = dampen_app::dampen_app_impl(default, quote::quote! { struct App; })
+            .expect("Macro expansion should succeed");
+        let output_str = output.to_string();
+        assert!(!output_str.contains("catch_handler_panic"));
+        assert!(!output_str.contains("model . clone ()"));
+
+        let without_overlay = quote::quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            catch_panics = true
+        };
+        let result = syn::parse2::<dampen_app::MacroAttributes>(without_overlay);
+        assert!(result.is_err(), "catch_panics requires the error overlay");
+    }
+
+    // The overlay style comes from a function, build diagnostics are shown as warnings
+    #[test]
+    fn test_error_overlay_style() {
+        let attr = quote::quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            dismiss_error_variant = "DismissError",
+            error_overlay_style = "dev::overlay_style"
+        };
+        let item = quote::quote! { struct App; };
+
+        let output =
+            dampen_app::dampen_app_impl(attr, item).expect("Macro expansion should succeed");
+        let output_str = output.to_string();
+
+        assert!(output_str.contains(
+            "error_overlay : dampen_dev :: ErrorOverlay :: new () . with_style (dev :: overlay_style ())"
+        ));
+        assert!(output_str.contains("self . error_overlay . show_warnings ("));
+
+        let without_overlay = quote::quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            error_overlay_style = "dev::overlay_style"
+        };
+        let result = syn::parse2::<dampen_app::MacroAttributes>(without_overlay);
+        assert!(
+            result.is_err(),
+            "error_overlay_style requires the error overlay"
+        );
+    }
+}
+
+mod logging_tests {
+    use super::*;
+
+    // Handlers run in a span naming the view and handler, F12 toggles the log viewer
+    #[test]
+    fn test_handlers_are_logged_with_spans_and_viewer() {
+        let attr = quote::quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            hot_reload_variant = "HotReload",
+            log_viewer_variant = "ToggleLogs"
+        };
+        let item = quote::quote! { struct App; };
+
+        let output =
+            dampen_app::dampen_app_impl(attr, item).expect("Macro expansion should succeed");
+        let output_str = output.to_string();
+
+        assert!(output_str.contains(
+            "dampen_core :: log :: handler_span (\"home\" , & handler_name , self . home_state . reload_generation ,)"
+        ));
+        assert!(output_str.contains(
+            "dampen_core :: log :: reload_span (\"home\" , self . home_state . reload_generation)"
+        ));
+        assert!(output_str.contains("dampen_dev :: logging :: init () ;"));
+        assert!(output_str.contains("show_logs : bool"));
+        assert!(
+            output_str
+                .contains("Message :: ToggleLogs => { self . show_logs = ! self . show_logs ;")
+        );
+        assert!(output_str.contains("dampen_dev :: logging :: viewer ()"));
+        assert!(output_str.contains("dampen_dev :: logging :: toggle_key ()"));
+    }
+}
+
+mod debug_server_tests {
+    use super::*;
+
+    // Debugger commands are answered in update() from the live view states
+    #[test]
+    fn test_debug_server_answers_commands() {
+        let attr = quote::quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            debug_server_variant = "Debug"
+        };
+        let item = quote::quote! { struct App; };
+
+        let output =
+            dampen_app::dampen_app_impl(attr, item).expect("Macro expansion should succeed");
+        let output_str = output.to_string();
+
+        assert!(output_str.contains("Message :: Debug (request) =>"));
+        assert!(output_str.contains(
+            "dampen_dev :: debug_server :: serve (dampen_dev :: debug_server :: port ())"
+        ));
+        assert!(output_str.contains(
+            "\"home\" => match Some (& self . home_state) { Some (state) => dampen_dev :: debug_server :: inspect (state , command)"
+        ));
+        assert!(output_str.contains(
+            "return self . update (Message :: Handler (dampen_iced :: HandlerMessage :: Handler (handler . clone () , value . clone ())"
+        ));
+    }
+}
+
+mod time_travel_tests {
+    use super::*;
+
+    // Handler messages are recorded with snapshots, and steps restore them into their view
+    #[test]
+    fn test_time_travel_records_and_restores_models() {
+        let attr = quote::quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            time_travel_variant = "TimeTravel"
+        };
+        let item = quote::quote! { struct App; };
+
+        let output =
+            dampen_app::dampen_app_impl(attr, item).expect("Macro expansion should succeed");
+        let output_str = output.to_string();
+
+        assert!(output_str.contains("timeline : dampen_dev :: time_travel :: Timeline ,"));
+        assert!(output_str.contains(
+            "dampen_dev :: time_travel :: describe (& handler_msg) . map (| message | (message , self . home_state . model . clone ()))"
+        ));
+        assert!(output_str.contains(
+            "self . timeline . record (\"home\" , message , before , & self . home_state . model)"
+        ));
+        assert!(output_str.contains("Message :: TimeTravel (event) =>"));
+        assert!(
+            output_str
+                .contains("dampen_dev :: time_travel :: restore (& mut state . model , snapshot)")
+        );
+        assert!(output_str.contains("self . timeline . view (content , Message :: TimeTravel)"));
+    }
+}
+
+// ============================================================================
+// State export/import (state_file_variant attribute)
+// ============================================================================
+
+mod state_file_tests {
+    use super::*;
+
+    // Every model is exported, and imported only once all of them deserialize
+    #[test]
+    fn test_state_file_exports_and_imports_models() {
+        let attr = quote::quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            state_file_variant = "State"
+        };
+        let item = quote::quote! { struct App; };
+
+        let output =
+            dampen_app::dampen_app_impl(attr, item).expect("Macro expansion should succeed");
+        let output_str = output.to_string();
+
+        assert!(output_str.contains("state_tools : dampen_dev :: state_file :: StateTools ,"));
+        assert!(output_str.contains("pub fn export_state (& self"));
+        assert!(
+            output_str.contains("file . insert_model (\"home\" , & self . home_state . model) ?")
+        );
+        assert!(output_str.contains("let home_model = file . model (\"home\") ? ;"));
+        assert!(output_str.contains("state . model = model ;"));
+        assert!(output_str.contains("dampen_dev :: state_file :: startup_path ()"));
+        assert!(output_str.contains("Message :: State (event) =>"));
+        assert!(output_str.contains("self . state_tools . view (content , Message :: State)"));
+    }
+}
+
+// ============================================================================
+// Widget inspector (inspector_variant attribute)
+// ============================================================================
+
+mod inspector_tests {
+    use super::*;
+
+    // The hit test is attached while inspecting, selections open the current view's file
+    #[test]
+    fn test_inspector_wraps_view_and_opens_files() {
+        let attr = quote::quote! {
+            ui_dir = "tests/fixtures/multi_view/src/ui",
+            message_type = "Message",
+            handler_variant = "Handler",
+            inspector_variant = "Inspect"
+        };
+        let item = quote::quote! { struct App; };
+
+        let output =
+            dampen_app::dampen_app_impl(attr, item).expect("Macro expansion should succeed");
+        let output_str = output.to_string();
+
+        assert!(output_str.contains("inspector : dampen_dev :: inspector :: Inspector ,"));
+        assert!(output_str.contains("builder . with_hit_test (& self . inspector . hit_test)"));
+        assert!(output_str.contains("self . inspector . view (content , Message :: Inspect)"));
+        assert!(output_str.contains("Message :: Inspect (event) =>"));
+        assert!(output_str.contains("home.dampen\""));
+        assert!(output_str.contains("dampen_dev :: inspector :: toggle_key ()"));
     }
 }
@@ -2,3 +2,14 @@
 pub struct Model {
     pub message: String,
 }
+
+#[ui_handler]
+pub fn sync_loop(model: &mut Model) -> iced::Task<crate::Message> {
+    iced::Task::none()
+}
+
+pub fn create_handler_registry() -> dampen_core::HandlerRegistry {
+    let registry = dampen_core::HandlerRegistry::new();
+    registry.register_simple("poll_updates", |_model: &mut dyn std::any::Any| {});
+    registry
+}
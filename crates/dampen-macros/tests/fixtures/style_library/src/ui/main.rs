@@ -0,0 +1,6 @@
+use dampen_macros::UiModel;
+
+#[derive(UiModel, Default)]
+pub struct MainModel {
+    pub title: String,
+}
@@ -93,6 +93,7 @@ fn test_handler_signature_detection() {
         return_type: "()",
         source_file: "test.rs",
         source_line: 42,
+        palette: None,
     };
     assert_eq!(info.signature_type, HandlerSignatureType::Simple);
 
@@ -104,6 +105,7 @@ fn test_handler_signature_detection() {
         return_type: "()",
         source_file: "test.rs",
         source_line: 43,
+        palette: None,
     };
     assert_eq!(info.signature_type, HandlerSignatureType::WithValue);
 
@@ -115,6 +117,7 @@ fn test_handler_signature_detection() {
         return_type: "Command<Message>",
         source_file: "test.rs",
         source_line: 44,
+        palette: None,
     };
     assert_eq!(info.signature_type, HandlerSignatureType::WithCommand);
 }
@@ -0,0 +1,15 @@
+// Test: #[ui_handler] with an unsupported parameter type
+// Expected error: "the model parameter of a #[ui_handler] function must be a mutable reference"
+
+use dampen_macros::ui_handler;
+
+struct Model {
+    count: i32,
+}
+
+#[ui_handler]
+fn increment(model: Model) {
+    let _ = model.count;
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+// Test: #[ui_rename] producing a name already used by another field
+// Expected error: "UI field name 'title' is already used by field 'title'"
+
+use dampen_macros::UiModel;
+
+#[derive(UiModel)]
+struct Model {
+    title: String,
+    #[ui_rename = "title"]
+    heading: String,
+}
+
+fn main() {}
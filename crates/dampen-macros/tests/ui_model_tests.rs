@@ -219,3 +219,103 @@ fn test_to_bool() {
     assert_eq!(BindingValue::List(vec![]).to_bool(), false);
     assert_eq!(BindingValue::None.to_bool(), false);
 }
+
+/// Nested model exposed through #[ui_flatten]
+#[derive(UiModel, Debug, Clone, Default)]
+struct Address {
+    city: String,
+    #[ui_rename = "zip"]
+    postal_code: String,
+}
+
+/// Test model with #[ui_rename] and #[ui_flatten]
+#[derive(UiModel, Debug, Clone, Default)]
+struct ProfileModel {
+    #[ui_rename = "display_name"]
+    name: String,
+    #[ui_flatten]
+    address: Address,
+}
+
+#[test]
+fn test_ui_rename_attribute() {
+    let model = ProfileModel {
+        name: "Ada".to_string(),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        model.get_field(&["display_name"]),
+        Some(BindingValue::String("Ada".to_string()))
+    );
+    assert_eq!(model.get_field(&["name"]), None);
+}
+
+#[test]
+fn test_ui_flatten_attribute() {
+    let model = ProfileModel {
+        name: "Ada".to_string(),
+        address: Address {
+            city: "London".to_string(),
+            postal_code: "NW1".to_string(),
+        },
+    };
+
+    assert_eq!(
+        model.get_field(&["city"]),
+        Some(BindingValue::String("London".to_string()))
+    );
+    assert_eq!(
+        model.get_field(&["zip"]),
+        Some(BindingValue::String("NW1".to_string()))
+    );
+    assert_eq!(model.get_field(&["address"]), None);
+
+    // available_fields reflects renamed and flattened names
+    assert_eq!(
+        ProfileModel::available_fields(),
+        vec![
+            "display_name".to_string(),
+            "city".to_string(),
+            "zip".to_string()
+        ]
+    );
+}
+
+/// Test model with a #[ui_nested] sub-model
+#[derive(UiModel, Debug, Clone, Default)]
+struct ShopModel {
+    title: String,
+    #[ui_nested]
+    cart: Address,
+}
+
+#[test]
+fn test_ui_nested_attribute() {
+    let model = ShopModel {
+        title: "Shop".to_string(),
+        cart: Address {
+            city: "Paris".to_string(),
+            postal_code: "75001".to_string(),
+        },
+    };
+
+    assert_eq!(
+        model.get_field(&["cart", "city"]),
+        Some(BindingValue::String("Paris".to_string()))
+    );
+    assert_eq!(
+        model.get_field(&["cart", "zip"]),
+        Some(BindingValue::String("75001".to_string()))
+    );
+    assert_eq!(model.get_field(&["cart"]), None);
+
+    assert_eq!(
+        ShopModel::available_fields(),
+        vec![
+            "title".to_string(),
+            "cart.city".to_string(),
+            "cart.zip".to_string()
+        ]
+    );
+}
@@ -1,9 +1,12 @@
 //! Visual regression testing infrastructure for Dampen UI framework.
 //!
 //! This crate provides tools to verify pixel-perfect parity between Interpreted
-//! and Codegen modes by rendering widgets offscreen and comparing the output.
+//! and Codegen modes by rendering widgets offscreen and comparing the output,
+//! and behavioral parity by driving both modes with the same events (see
+//! [`parity`]).
 
 pub mod compare;
+pub mod parity;
 pub mod renderer;
 
 use std::path::Path;
@@ -0,0 +1,527 @@
+//! Behavioral parity between interpreted and codegen modes.
+//!
+//! A parity case drives the same scripted handler events through a view of an
+//! example in both modes and compares the results: the serialized model and the
+//! tree rendered from it by the headless renderer.
+//!
+//! For each case, the runner writes a small crate that includes the `ui`
+//! module of the example and the code generated for the view, then builds and
+//! tests it twice: with the `interpreted` feature, dispatching the events
+//! through [`AppState::dispatch`](dampen_core::AppState::dispatch), and with
+//! the `codegen` feature, sending the generated messages to `update_model`.
+//!
+//! Cases are JSON scripts, e.g. `tests/visual/parity/counter.json`:
+//!
+//! ```json
+//! {
+//!   "example": "counter",
+//!   "events": [
+//!     { "handler": "increment" },
+//!     { "handler": "set_name", "value": "Alice" }
+//!   ],
+//!   "ignore": ["model.tasks.*.id"]
+//! }
+//! ```
+
+use dampen_core::codegen::{generate_application_with_theme_and_subscriptions, inventory, update};
+use dampen_core::parser::theme_parser::parse_theme_document;
+use serde::Deserialize;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// A handler event of a parity script.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ScriptedEvent {
+    /// Handler name, as written in the `.dampen` file
+    pub handler: String,
+    /// Value passed to the handler, as a widget would send it
+    #[serde(default)]
+    pub value: Option<String>,
+}
+
+/// A parity test case: an example view and the events to drive through it.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ParityCase {
+    /// Name of the case (the script file name)
+    #[serde(skip)]
+    pub name: String,
+    /// Example directory name, under `examples/`
+    pub example: String,
+    /// View to drive, a `.dampen` file of `src/ui/`
+    #[serde(default = "default_view")]
+    pub view: String,
+    /// Events, in order
+    pub events: Vec<ScriptedEvent>,
+    /// Dotted paths left out of the comparison, such as generated ids;
+    /// `*` matches one segment and `**` any number of segments
+    #[serde(default)]
+    pub ignore: Vec<String>,
+}
+
+fn default_view() -> String {
+    "window".to_string()
+}
+
+impl ParityCase {
+    /// Loads a case from a JSON script, named after the file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ParityError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)?;
+        let mut case: Self = serde_json::from_str(&content)
+            .map_err(|e| ParityError::Script(format!("{}: {}", path.display(), e)))?;
+        case.name = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or(&case.example)
+            .to_string();
+        Ok(case)
+    }
+}
+
+/// Mode a parity crate is built in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Runtime interpretation of the `.dampen` file
+    Interpreted,
+    /// Code generated from the `.dampen` file
+    Codegen,
+}
+
+impl Mode {
+    /// Cargo feature selecting the mode.
+    pub fn feature(&self) -> &'static str {
+        match self {
+            Mode::Interpreted => "interpreted",
+            Mode::Codegen => "codegen",
+        }
+    }
+}
+
+/// Result of a parity case.
+#[derive(Debug)]
+pub struct ParityReport {
+    /// Name of the case
+    pub name: String,
+    /// Model and tree after the events in interpreted mode
+    pub interpreted: Value,
+    /// Model and tree after the events in codegen mode
+    pub codegen: Value,
+    /// Paths whose values differ, e.g. `model.count: 3 != 2`
+    pub differences: Vec<String>,
+}
+
+impl ParityReport {
+    /// Whether both modes ended in the same state.
+    pub fn passed(&self) -> bool {
+        self.differences.is_empty()
+    }
+}
+
+/// Builds and runs parity crates.
+#[derive(Debug, Clone)]
+pub struct ParityRunner {
+    /// Root of the Dampen workspace, holding `examples/`
+    workspace_root: PathBuf,
+    /// Directory of the generated crates and their build
+    out_dir: PathBuf,
+}
+
+impl ParityRunner {
+    /// Creates a runner writing its crates to `target/parity` of the workspace.
+    pub fn new(workspace_root: impl Into<PathBuf>) -> Self {
+        let workspace_root = workspace_root.into();
+        let out_dir = workspace_root.join("target").join("parity");
+        Self {
+            workspace_root,
+            out_dir,
+        }
+    }
+
+    /// Sets the directory of the generated crates.
+    pub fn with_out_dir(mut self, out_dir: impl Into<PathBuf>) -> Self {
+        self.out_dir = out_dir.into();
+        self
+    }
+
+    /// Runs a case in both modes and compares the results.
+    ///
+    /// Builds the parity crate twice; the first run of a session compiles iced
+    /// for each mode.
+    pub fn run(&self, case: &ParityCase) -> Result<ParityReport, ParityError> {
+        let crate_dir = self.write_crate(case)?;
+        let interpreted = self.snapshot(&crate_dir, case, Mode::Interpreted)?;
+        let codegen = self.snapshot(&crate_dir, case, Mode::Codegen)?;
+
+        let differences = differences(&interpreted, &codegen, &case.ignore);
+
+        Ok(ParityReport {
+            name: case.name.clone(),
+            interpreted,
+            codegen,
+            differences,
+        })
+    }
+
+    /// Writes the parity crate of a case and returns its directory.
+    pub fn write_crate(&self, case: &ParityCase) -> Result<PathBuf, ParityError> {
+        let example_dir = self.workspace_root.join("examples").join(&case.example);
+        let ui_dir = example_dir.join("src").join("ui");
+        let dampen_file = ui_dir.join(format!("{}.dampen", case.view));
+        let rs_file = ui_dir.join(format!("{}.rs", case.view));
+
+        let document = dampen_core::parse(&std::fs::read_to_string(&dampen_file)?)
+            .map_err(|e| ParityError::Parse(format!("{}: {}", dampen_file.display(), e)))?;
+        let theme_file = ui_dir.join("theme").join("theme.dampen");
+        let theme = if theme_file.exists() {
+            Some(
+                parse_theme_document(&std::fs::read_to_string(&theme_file)?)
+                    .map_err(|e| ParityError::Parse(format!("{}: {}", theme_file.display(), e)))?,
+            )
+        } else {
+            None
+        };
+
+        // Generated as build.rs does, plus the messages of the handler names
+        let handlers = inventory::extract_handler_signatures_from_file(&rs_file);
+        let output = generate_application_with_theme_and_subscriptions(
+            &document,
+            "Model",
+            "Message",
+            &handlers,
+            theme.as_ref(),
+        )
+        .map_err(|e| ParityError::Codegen(e.to_string()))?;
+        let dispatch = update::generate_dispatch_function(&handlers, "Message");
+
+        let crate_name = format!("parity_{}", case.name.replace('-', "_"));
+        let crate_dir = self.out_dir.join(&case.name);
+        std::fs::create_dir_all(crate_dir.join("src"))?;
+        std::fs::create_dir_all(crate_dir.join("tests"))?;
+
+        std::fs::write(
+            crate_dir.join("Cargo.toml"),
+            self.manifest(&crate_name, &example_dir)?,
+        )?;
+        std::fs::write(
+            crate_dir.join("src").join("generated.rs"),
+            format!(
+                "pub mod {} {{\n{}\n{}\n}}\n",
+                case.view, output.code, dispatch
+            ),
+        )?;
+        std::fs::write(
+            crate_dir.join("src").join("lib.rs"),
+            format!(
+                "//! Parity crate of the `{}` example, generated by dampen-visual-tests\n\
+                 #![allow(dead_code, unused_imports)]\n\n\
+                 #[path = {:?}]\n\
+                 pub mod ui;\n\n\
+                 #[cfg(feature = \"codegen\")]\n\
+                 include!(\"generated.rs\");\n",
+                case.example,
+                ui_dir.join("mod.rs"),
+            ),
+        )?;
+        std::fs::write(
+            crate_dir.join("tests").join("parity.rs"),
+            test_source(&crate_name, case, &dampen_file),
+        )?;
+
+        Ok(crate_dir)
+    }
+
+    /// Builds the parity crate in a mode and returns the model and tree after the events.
+    pub fn snapshot(
+        &self,
+        crate_dir: &Path,
+        case: &ParityCase,
+        mode: Mode,
+    ) -> Result<Value, ParityError> {
+        let snapshot_file = self
+            .out_dir
+            .join(format!("{}-{}.json", case.name, mode.feature()));
+        let output = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+            .arg("test")
+            .arg("--quiet")
+            .arg("--manifest-path")
+            .arg(crate_dir.join("Cargo.toml"))
+            .args(["--test", "parity", "--features", mode.feature()])
+            .env("CARGO_TARGET_DIR", self.out_dir.join("target"))
+            .env("PARITY_SNAPSHOT", &snapshot_file)
+            .output()?;
+        if !output.status.success() {
+            return Err(ParityError::Build {
+                mode,
+                output: String::from_utf8_lossy(&output.stderr).into_owned()
+                    + &String::from_utf8_lossy(&output.stdout),
+            });
+        }
+
+        let content = std::fs::read_to_string(&snapshot_file)?;
+        serde_json::from_str(&content)
+            .map_err(|e| ParityError::Script(format!("{}: {}", snapshot_file.display(), e)))
+    }
+
+    /// Manifest of a parity crate: the dependencies of the example, resolved
+    /// against the workspace, with its own `interpreted` and `codegen` features.
+    fn manifest(&self, crate_name: &str, example_dir: &Path) -> Result<String, ParityError> {
+        let read = |path: &Path| -> Result<toml::Table, ParityError> {
+            std::fs::read_to_string(path)?
+                .parse::<toml::Table>()
+                .map_err(|e| ParityError::Manifest(format!("{}: {}", path.display(), e)))
+        };
+        let example = read(&example_dir.join("Cargo.toml"))?;
+        let workspace = read(&self.workspace_root.join("Cargo.toml"))?;
+        let workspace_deps = workspace
+            .get("workspace")
+            .and_then(|workspace| workspace.get("dependencies"))
+            .and_then(toml::Value::as_table);
+
+        let mut dependencies = toml::Table::new();
+        let example_deps = example
+            .get("dependencies")
+            .and_then(toml::Value::as_table)
+            .cloned()
+            .unwrap_or_default();
+        for (name, spec) in example_deps {
+            let inherited = spec
+                .get("workspace")
+                .and_then(toml::Value::as_bool)
+                .unwrap_or(false);
+            let (base, base_dir) =
+                match (inherited, workspace_deps.and_then(|deps| deps.get(&name))) {
+                    (true, Some(base)) => (base.clone(), self.workspace_root.as_path()),
+                    (true, None) => {
+                        return Err(ParityError::Manifest(format!(
+                            "{} is not a workspace dependency",
+                            name
+                        )));
+                    }
+                    (false, _) => (spec.clone(), example_dir),
+                };
+
+            let mut table = match base {
+                toml::Value::String(version) => {
+                    toml::Table::from_iter([("version".to_string(), toml::Value::String(version))])
+                }
+                toml::Value::Table(table) => table,
+                _ => continue,
+            };
+            if inherited && let Some(extra) = spec.as_table() {
+                for (key, value) in extra {
+                    match (key.as_str(), table.get_mut(key)) {
+                        ("features", Some(toml::Value::Array(features))) => {
+                            if let Some(extra) = value.as_array() {
+                                features.extend(extra.iter().cloned());
+                            }
+                        }
+                        ("workspace", _) => {}
+                        _ => {
+                            table.insert(key.clone(), value.clone());
+                        }
+                    }
+                }
+            }
+            // Both modes are built from one crate, so every dependency is needed
+            table.remove("optional");
+            if let Some(path) = table.get("path").and_then(toml::Value::as_str) {
+                let path = base_dir.join(path);
+                table.insert(
+                    "path".to_string(),
+                    toml::Value::String(path.display().to_string()),
+                );
+            }
+            dependencies.insert(name, toml::Value::Table(table));
+        }
+        dependencies
+            .entry("serde_json")
+            .or_insert_with(|| toml::Value::String("1.0".to_string()));
+
+        let mut manifest = format!(
+            "[package]\n\
+             name = {:?}\n\
+             version = \"0.0.0\"\n\
+             edition = \"2024\"\n\
+             publish = false\n\n\
+             # Not a member of the Dampen workspace\n\
+             [workspace]\n\n\
+             [features]\n\
+             interpreted = [\"dampen-core/interpreted\"]\n\
+             codegen = [\"dampen-core/codegen\"]\n\n",
+            crate_name.replace('_', "-")
+        );
+        let dependencies = toml::Table::from_iter([(
+            "dependencies".to_string(),
+            toml::Value::Table(dependencies),
+        )]);
+        manifest.push_str(
+            &toml::to_string(&dependencies).map_err(|e| ParityError::Manifest(e.to_string()))?,
+        );
+        Ok(manifest)
+    }
+}
+
+/// Test of a parity crate, writing the snapshot of its mode to `PARITY_SNAPSHOT`.
+fn test_source(crate_name: &str, case: &ParityCase, dampen_file: &Path) -> String {
+    let events: String = case
+        .events
+        .iter()
+        .map(|event| format!("    ({:?}, {:?}),\n", event.handler, event.value.as_deref()))
+        .collect();
+    let view = &case.view;
+
+    format!(
+        r#"//! Generated by dampen-visual-tests
+
+use dampen_core::{{AppState, UiBindable}};
+use serde::Serialize;
+
+const EVENTS: &[(&str, Option<&str>)] = &[
+{events}];
+
+fn snapshot<M: UiBindable + Serialize>(state: &AppState<M>) -> Result<serde_json::Value, String> {{
+    let model = state.model_snapshot().map_err(|e| e.to_string())?;
+    let tree = state.render().map_err(|e| e.to_string())?;
+    let tree = serde_json::to_value(tree).map_err(|e| e.to_string())?;
+    Ok(serde_json::json!({{ "model": model, "tree": tree }}))
+}}
+
+#[cfg(feature = "interpreted")]
+fn run() -> Result<serde_json::Value, String> {{
+    let mut state = {crate_name}::ui::{view}::create_app_state();
+    for (handler, value) in EVENTS {{
+        state
+            .dispatch(handler, value.map(str::to_string))
+            .map_err(|e| e.to_string())?;
+    }}
+    snapshot(&state)
+}}
+
+#[cfg(feature = "codegen")]
+fn run() -> Result<serde_json::Value, String> {{
+    let (mut model, _) = {crate_name}::{view}::new_model();
+    for (handler, value) in EVENTS {{
+        let message = {crate_name}::{view}::message_for(handler, value.map(str::to_string))
+            .ok_or_else(|| format!("No message for handler '{{}}'", handler))?;
+        let _ = {crate_name}::{view}::update_model(&mut model, message);
+    }}
+    let document = dampen_core::parse(include_str!({dampen_file:?})).map_err(|e| e.to_string())?;
+    snapshot(&AppState::with_model(document, model))
+}}
+
+#[test]
+fn parity() -> Result<(), String> {{
+    let snapshot = run()?;
+    let path = std::env::var("PARITY_SNAPSHOT").map_err(|e| e.to_string())?;
+    let json = serde_json::to_string_pretty(&snapshot).map_err(|e| e.to_string())?;
+    std::fs::write(path, json).map_err(|e| e.to_string())
+}}
+"#
+    )
+}
+
+/// Lists the paths where two snapshots differ, skipping the `ignore` patterns.
+pub fn differences(interpreted: &Value, codegen: &Value, ignore: &[String]) -> Vec<String> {
+    let mut differences = Vec::new();
+    diff("", interpreted, codegen, ignore, &mut differences);
+    differences
+}
+
+fn diff(path: &str, left: &Value, right: &Value, ignore: &[String], out: &mut Vec<String>) {
+    if ignore.iter().any(|pattern| matches_path(pattern, path)) {
+        return;
+    }
+    let child = |key: &str| {
+        if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", path, key)
+        }
+    };
+
+    match (left, right) {
+        (Value::Object(left), Value::Object(right)) => {
+            for (key, value) in left {
+                diff(
+                    &child(key),
+                    value,
+                    right.get(key).unwrap_or(&Value::Null),
+                    ignore,
+                    out,
+                );
+            }
+            for (key, value) in right {
+                if !left.contains_key(key) {
+                    diff(&child(key), &Value::Null, value, ignore, out);
+                }
+            }
+        }
+        (Value::Array(left), Value::Array(right)) if left.len() == right.len() => {
+            for (index, (left, right)) in left.iter().zip(right).enumerate() {
+                diff(&child(&index.to_string()), left, right, ignore, out);
+            }
+        }
+        (Value::Array(left), Value::Array(right)) => out.push(format!(
+            "{}: {} items != {} items",
+            path,
+            left.len(),
+            right.len()
+        )),
+        _ if left != right => out.push(format!("{}: {} != {}", path, left, right)),
+        _ => {}
+    }
+}
+
+/// Whether a dotted path matches a pattern of the `ignore` list.
+fn matches_path(pattern: &str, path: &str) -> bool {
+    fn matches(pattern: &[&str], path: &[&str]) -> bool {
+        match (pattern.split_first(), path.split_first()) {
+            (None, None) => true,
+            (Some((&"**", rest)), _) => {
+                matches(rest, path) || (!path.is_empty() && matches(pattern, &path[1..]))
+            }
+            (Some((segment, rest)), Some((first, path))) => {
+                (*segment == "*" || segment == first) && matches(rest, path)
+            }
+            _ => false,
+        }
+    }
+
+    let pattern: Vec<&str> = pattern.split('.').collect();
+    let path: Vec<&str> = path.split('.').collect();
+    matches(&pattern, &path)
+}
+
+/// Errors that can occur while running a parity case.
+#[derive(Debug, thiserror::Error)]
+pub enum ParityError {
+    /// Failed to read or write a file
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Invalid parity script or snapshot
+    #[error("Invalid script: {0}")]
+    Script(String),
+
+    /// Failed to parse the view or theme of the example
+    #[error("Failed to parse: {0}")]
+    Parse(String),
+
+    /// Code generation failed for the view
+    #[error("Code generation failed: {0}")]
+    Codegen(String),
+
+    /// Invalid manifest of the example or workspace
+    #[error("Invalid manifest: {0}")]
+    Manifest(String),
+
+    /// The parity crate failed to build or run
+    #[error("Parity crate failed in {mode:?} mode:\n{output}")]
+    Build {
+        /// Mode being built
+        mode: Mode,
+        /// Output of cargo
+        output: String,
+    },
+}
@@ -72,7 +72,7 @@ pub fn render_to_png(
 ) -> Result<(), RenderError> {
     // TODO: Implement actual rendering
     // This requires:
-    // 1. Parse XML with dampen-core
+    // 1. Parse XML with dampen-core, with a mock model from `dampen_core::mock::app_state`
     // 2. Build widget tree with dampen-iced
     // 3. Create wgpu surface and renderer
     // 4. Manually trigger render pass
@@ -0,0 +1,128 @@
+//! Behavioral parity tests between interpreted and codegen modes.
+
+use dampen_visual_tests::parity::{ParityCase, ParityRunner, differences};
+use serde_json::json;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+fn workspace_root() -> std::io::Result<PathBuf> {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+}
+
+fn parity_scripts() -> std::io::Result<Vec<PathBuf>> {
+    let mut scripts = Vec::new();
+    for entry in std::fs::read_dir(workspace_root()?.join("tests/visual/parity"))? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "json") {
+            scripts.push(path);
+        }
+    }
+    scripts.sort();
+    Ok(scripts)
+}
+
+#[test]
+fn test_parity_scripts_load() -> Result<(), Box<dyn Error>> {
+    let scripts = parity_scripts()?;
+    assert!(!scripts.is_empty());
+
+    for script in scripts {
+        let case = ParityCase::load(&script)?;
+        assert!(!case.events.is_empty(), "{} has no events", case.name);
+        assert!(
+            workspace_root()?
+                .join("examples")
+                .join(&case.example)
+                .join("src/ui")
+                .join(format!("{}.dampen", case.view))
+                .exists(),
+            "{} drives a missing view",
+            case.name
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_differences_list_diverging_paths() {
+    let interpreted = json!({
+        "model": { "count": 3, "tasks": [{ "id": "a", "done": true }] },
+        "tree": { "children": [{ "label": "3" }] }
+    });
+    let codegen = json!({
+        "model": { "count": 2, "tasks": [{ "id": "b", "done": true }] },
+        "tree": { "children": [{ "label": "2" }, { "label": "" }] }
+    });
+
+    let found = differences(&interpreted, &codegen, &["model.tasks.*.id".to_string()]);
+    assert_eq!(
+        found,
+        ["model.count: 3 != 2", "tree.children: 1 items != 2 items"]
+    );
+
+    let found = differences(
+        &interpreted,
+        &codegen,
+        &[
+            "**.count".to_string(),
+            "tree".to_string(),
+            "model.tasks.**".to_string(),
+        ],
+    );
+    assert!(found.is_empty(), "{:?}", found);
+}
+
+#[test]
+fn test_parity_crate_is_generated() -> Result<(), Box<dyn Error>> {
+    let out_dir = tempfile::tempdir()?;
+    let case = ParityCase::load(workspace_root()?.join("tests/visual/parity/counter.json"))?;
+
+    let crate_dir = ParityRunner::new(workspace_root()?)
+        .with_out_dir(out_dir.path())
+        .write_crate(&case)?;
+
+    let manifest = std::fs::read_to_string(crate_dir.join("Cargo.toml"))?;
+    assert!(manifest.contains("[workspace]"));
+    assert!(manifest.contains("codegen = [\"dampen-core/codegen\"]"));
+    assert!(manifest.contains("crates/dampen-core"));
+    assert!(!manifest.contains("optional"));
+
+    let generated = std::fs::read_to_string(crate_dir.join("src/generated.rs"))?;
+    assert!(generated.starts_with("pub mod window {"));
+    assert!(generated.contains("pub fn message_for"));
+
+    let test = std::fs::read_to_string(crate_dir.join("tests/parity.rs"))?;
+    assert!(test.contains("(\"increment\", None)"));
+    assert!(test.contains("parity_counter::ui::window::create_app_state()"));
+    Ok(())
+}
+
+/// Builds every example twice, so it only runs on request:
+/// `cargo test -p dampen-visual-tests --test parity_test -- --ignored`
+#[test]
+#[ignore = "builds each example in both modes"]
+fn test_examples_behave_the_same_in_both_modes() -> Result<(), Box<dyn Error>> {
+    let runner = ParityRunner::new(workspace_root()?);
+
+    let mut failures = Vec::new();
+    for script in parity_scripts()? {
+        let case = ParityCase::load(&script)?;
+        let report = runner.run(&case)?;
+        if !report.passed() {
+            failures.push(format!(
+                "{}:\n  {}",
+                report.name,
+                report.differences.join("\n  ")
+            ));
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "Modes diverge:\n{}",
+        failures.join("\n")
+    );
+    Ok(())
+}
@@ -174,7 +174,7 @@ fn generate_ui_code() {
     );
 }
 
-/// Find all .dampen files in a directory recursively
+/// Find all .dampen files in a directory recursively, sorted by path
 #[cfg(feature = "codegen")]
 fn find_dampen_files(dir: &Path) -> Vec<PathBuf> {
     let mut files = Vec::new();
@@ -190,5 +190,6 @@ fn find_dampen_files(dir: &Path) -> Vec<PathBuf> {
         }
     }
 
+    files.sort();
     files
 }
@@ -66,7 +66,14 @@ pub fn main() -> iced::Result {
     #[cfg(debug_assertions)]
     println!("Theming example - Edit src/ui/theme/theme.dampen to see hot-reload!");
 
-    iced::application(ThemingApp::init, ThemingApp::update, ThemingApp::view)
+    let init = || {
+        let (mut app, task) = ThemingApp::init();
+        // Fade between the palettes when the theme is switched
+        app.set_theme_transition(Some(std::time::Duration::from_millis(300)));
+        (app, task)
+    };
+
+    iced::application(init, ThemingApp::update, ThemingApp::view)
         .window(ThemingApp::window_settings().default_size(600, 400).build())
         .theme(ThemingApp::theme)
         .subscription(ThemingApp::subscription)
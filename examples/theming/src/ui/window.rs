@@ -15,12 +15,8 @@ pub struct Model {
 pub fn set_theme(model: &mut Model, theme: String) {
     model.message = format!("Switched to theme: {}", theme);
 
-    // In codegen mode, call the generated app_set_current_theme function
-    // to actually change the active theme
-    #[cfg(feature = "codegen")]
-    {
-        crate::window::app_set_current_theme(&theme);
-    }
+    // Applied to every view once the handler returns
+    dampen_core::theme_switch::set_theme(&theme);
 }
 
 inventory_handlers! {
@@ -34,5 +30,16 @@ pub fn create_app_state() -> AppState<Model> {
 }
 
 pub fn create_handler_registry() -> HandlerRegistry {
-    HandlerRegistry::new()
+    let registry = HandlerRegistry::new();
+
+    // Replaces the built-in set_theme handler, so the message is updated too
+    registry.register_with_value("set_theme", |model: &mut dyn std::any::Any, value| {
+        if let Some(m) = model.downcast_mut::<Model>()
+            && let Ok(theme) = value.downcast::<String>()
+        {
+            set_theme(m, *theme);
+        }
+    });
+
+    registry
 }
@@ -100,35 +100,26 @@ pub fn create_handler_registry() -> HandlerRegistry {
         }
     });
 
-    registry.register_with_value(
+    registry.register_canvas_event(
         "on_canvas_click",
-        |model: &mut dyn std::any::Any, val: Box<dyn std::any::Any>| {
-            if let Some(m) = model.downcast_mut::<Model>()
-                && let Some(json) = val.downcast_ref::<String>()
-                && let Ok(event) = serde_json::from_str::<CanvasEvent>(json)
-            {
+        |model: &mut dyn std::any::Any, event: CanvasEvent| {
+            if let Some(m) = model.downcast_mut::<Model>() {
                 on_canvas_click(m, event);
             }
         },
     );
-    registry.register_with_value(
+    registry.register_canvas_event(
         "on_canvas_drag",
-        |model: &mut dyn std::any::Any, val: Box<dyn std::any::Any>| {
-            if let Some(m) = model.downcast_mut::<Model>()
-                && let Some(json) = val.downcast_ref::<String>()
-                && let Ok(event) = serde_json::from_str::<CanvasEvent>(json)
-            {
+        |model: &mut dyn std::any::Any, event: CanvasEvent| {
+            if let Some(m) = model.downcast_mut::<Model>() {
                 on_canvas_drag(m, event);
             }
         },
     );
-    registry.register_with_value(
+    registry.register_canvas_event(
         "on_canvas_release",
-        |model: &mut dyn std::any::Any, val: Box<dyn std::any::Any>| {
-            if let Some(m) = model.downcast_mut::<Model>()
-                && let Some(json) = val.downcast_ref::<String>()
-                && let Ok(event) = serde_json::from_str::<CanvasEvent>(json)
-            {
+        |model: &mut dyn std::any::Any, event: CanvasEvent| {
+            if let Some(m) = model.downcast_mut::<Model>() {
                 on_canvas_release(m, event);
             }
         },
@@ -34,8 +34,11 @@ fn create_test_theme() -> Theme {
             font_size_large: Some(24.0),
             font_weight: dampen_core::ir::theme::FontWeight::Normal,
             line_height: Some(1.5),
+            scale_ratio: None,
+            fonts: Vec::new(),
         },
         spacing: dampen_core::ir::theme::SpacingScale { unit: Some(8.0) },
+        shadows: Default::default(),
         base_styles: std::collections::HashMap::new(),
         extends: None,
     }
@@ -313,8 +316,11 @@ mod contract_theme_context_creation {
                 font_size_large: Some(24.0),
                 font_weight: dampen_core::ir::theme::FontWeight::Normal,
                 line_height: Some(1.5),
+                scale_ratio: None,
+                fonts: Vec::new(),
             },
             spacing: SpacingScale { unit: Some(8.0) },
+            shadows: Default::default(),
             base_styles: std::collections::HashMap::new(),
             extends: None,
         }
@@ -450,8 +456,11 @@ mod contract_runtime_theme_switching {
                 font_size_large: Some(24.0),
                 font_weight: dampen_core::ir::theme::FontWeight::Normal,
                 line_height: Some(1.5),
+                scale_ratio: None,
+                fonts: Vec::new(),
             },
             spacing: dampen_core::ir::theme::SpacingScale { unit: Some(8.0) },
+            shadows: Default::default(),
             base_styles: std::collections::HashMap::new(),
             extends: None,
         }
@@ -583,8 +592,11 @@ mod contract_hot_reload_theme_update {
                 font_size_large: Some(24.0),
                 font_weight: dampen_core::ir::theme::FontWeight::Normal,
                 line_height: Some(1.5),
+                scale_ratio: None,
+                fonts: Vec::new(),
             },
             spacing: SpacingScale { unit: Some(8.0) },
+            shadows: Default::default(),
             base_styles: std::collections::HashMap::new(),
             extends: None,
         }
@@ -709,8 +721,11 @@ mod contract_theme_codegen {
                 font_size_large: Some(24.0),
                 font_weight: dampen_core::ir::theme::FontWeight::Normal,
                 line_height: Some(1.5),
+                scale_ratio: None,
+                fonts: Vec::new(),
             },
             spacing: SpacingScale { unit: Some(8.0) },
+            shadows: Default::default(),
             base_styles: std::collections::HashMap::new(),
             extends: None,
         }
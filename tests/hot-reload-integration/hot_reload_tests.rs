@@ -11,7 +11,7 @@ use dampen_core::binding::UiBindable;
 use dampen_core::handler::HandlerRegistry;
 use dampen_core::parser;
 use dampen_core::state::AppState;
-use dampen_dev::reload::{attempt_hot_reload, HotReloadContext, ReloadResult};
+use dampen_dev::reload::{HotReloadContext, ReloadResult, attempt_hot_reload};
 use serde::{Deserialize, Serialize};
 use std::thread;
 use std::time::{Duration, Instant};
@@ -123,6 +123,12 @@ fn test_state_preservation_across_reload() {
         ReloadResult::StateRestoreWarning(_, e) => {
             panic!("State restoration should not fail: {}", e);
         }
+        ReloadResult::Partial(_, paths) => {
+            panic!(
+                "attempt_hot_reload should not report partial reloads: {:?}",
+                paths
+            );
+        }
     }
 }
 
@@ -180,6 +186,12 @@ fn test_state_preservation_with_schema_change() {
         ReloadResult::ValidationError(e) => {
             panic!("Should not fail with validation error: {:?}", e);
         }
+        ReloadResult::Partial(_, paths) => {
+            panic!(
+                "attempt_hot_reload should not report partial reloads: {:?}",
+                paths
+            );
+        }
     }
 }
 
@@ -229,6 +241,9 @@ fn test_parse_error_handling() {
         ReloadResult::StateRestoreWarning(_, _) => {
             panic!("Should fail with parse error, not state restore warning");
         }
+        ReloadResult::Partial(_, _) => {
+            panic!("Should fail with parse error, not partial reload");
+        }
     }
 
     // Verify original state is unchanged (reload was rejected)
@@ -364,6 +379,12 @@ fn test_rapid_successive_reloads() {
                 println!("Reload {} had state restore warning: {}", i, warning);
                 app_state = new_state;
             }
+            ReloadResult::Partial(_, paths) => {
+                panic!(
+                    "Reload {} unexpectedly reported partial reload: {:?}",
+                    i, paths
+                );
+            }
         }
 
         // Small delay to simulate realistic file save timing
@@ -12,7 +12,7 @@ use dampen_core::parser;
 use dampen_core::parser::error::{ParseError, ParseErrorKind};
 use dampen_core::state::AppState;
 use dampen_dev::overlay::ErrorOverlay;
-use dampen_dev::reload::{attempt_hot_reload, HotReloadContext, ReloadResult};
+use dampen_dev::reload::{HotReloadContext, ReloadResult, attempt_hot_reload};
 use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
@@ -158,6 +158,12 @@ fn test_large_file_reload_performance() {
                 "Should have loaded all widgets"
             );
         }
+        ReloadResult::Partial(_, paths) => {
+            panic!(
+                "attempt_hot_reload should not report partial reloads: {:?}",
+                paths
+            );
+        }
     }
 
     // Validate performance (current realistic budget, not ideal target)
@@ -319,6 +325,12 @@ fn test_state_preservation_100_reloads() {
             ReloadResult::ValidationError(e) => {
                 panic!("Reload {} failed with validation error: {:?}", i, e);
             }
+            ReloadResult::Partial(_, paths) => {
+                panic!(
+                    "Reload {} unexpectedly reported partial reload: {:?}",
+                    i, paths
+                );
+            }
         }
     }
 
@@ -0,0 +1,38 @@
+//! Build script of the integration tests
+//!
+//! Generates the update and `message_for` functions of the handlers of
+//! `middleware_parity.rs`, so that the test drives the code production builds
+//! run rather than a copy of it.
+
+use dampen_core::HandlerSignature;
+use dampen_core::codegen::update::{generate_dispatch_function, generate_update_match_arms};
+use std::path::PathBuf;
+
+fn handler(name: &str, param_type: Option<&str>) -> HandlerSignature {
+    HandlerSignature {
+        name: name.to_string(),
+        param_type: param_type.map(str::to_string),
+        returns_command: false,
+        palette: None,
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let handlers = vec![
+        handler("increment", None),
+        handler("set_count", Some("i32")),
+        handler("set_name", Some("String")),
+    ];
+    let update = generate_update_match_arms(&handlers, "Message")?;
+    let dispatch = generate_dispatch_function(&handlers, "Message");
+
+    let code = format!(
+        "pub fn update_model(model: &mut Model, message: Message) -> iced::Task<Message> {{\n{}\n}}\n\n{}\n",
+        update, dispatch
+    );
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR")?);
+    std::fs::write(out_dir.join("middleware_parity.rs"), code)?;
+    Ok(())
+}
@@ -0,0 +1,21 @@
+// View of the app reloaded by `hot_reload_app.rs`.
+
+use dampen_core::{AppState, HandlerRegistry};
+use dampen_macros::{UiModel, dampen_ui};
+use serde::{Deserialize, Serialize};
+
+#[dampen_ui("window.dampen")]
+mod _app {}
+
+#[derive(Default, UiModel, Serialize, Deserialize, Clone, Debug)]
+pub struct Model {
+    pub status: String,
+}
+
+pub fn create_app_state() -> AppState<Model> {
+    AppState::with_handlers(_app::document(), create_handler_registry())
+}
+
+pub fn create_handler_registry() -> HandlerRegistry {
+    HandlerRegistry::new()
+}
@@ -0,0 +1,115 @@
+//! Hot-reload of a view through an app generated by `#[dampen_app]`
+//!
+//! A saved view file reaches `update()` as a `FileEvent::Success`; the app
+//! replaces the document of the view and restores the widget state of the
+//! changed subtrees only.
+
+#![cfg(debug_assertions)]
+
+use dampen_core::parse;
+use dampen_core::state::ScrollOffset;
+use dampen_dev::FileEvent;
+use dampen_iced::HandlerMessage;
+use dampen_macros::dampen_app;
+use std::path::PathBuf;
+
+#[path = "fixtures/hot_reload_app/ui/mod.rs"]
+mod ui;
+
+#[derive(Clone, Debug)]
+enum Message {
+    Handler(HandlerMessage),
+    HotReload(FileEvent),
+    DismissError,
+}
+
+#[dampen_app(
+    ui_dir = "fixtures/hot_reload_app/ui",
+    message_type = "Message",
+    handler_variant = "Handler",
+    hot_reload_variant = "HotReload",
+    dismiss_error_variant = "DismissError",
+    default_view = "window"
+)]
+struct HotReloadApp;
+
+/// The `window` view saved with `xml` as its content
+fn saved(xml: &str) -> Result<Message, Box<dyn std::error::Error>> {
+    Ok(Message::HotReload(FileEvent::Success {
+        path: PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("fixtures/hot_reload_app/ui/window.dampen"),
+        document: Box::new(parse(xml).map_err(|error| error.to_string())?),
+    }))
+}
+
+const CHANGED_LOG: &str = r#"<column spacing="10"><scrollable id="list"><text value="Items" /></scrollable><scrollable id="log" height="200"><text value="{status}" /></scrollable></column>"#;
+
+const CHANGED_ROOT: &str = r#"<row spacing="10"><scrollable id="list"><text value="Items" /></scrollable><scrollable id="log" height="200"><text value="{status}" /></scrollable></row>"#;
+
+#[test]
+fn test_reload_restores_changed_subtrees() -> Result<(), Box<dyn std::error::Error>> {
+    let (mut app, _) = HotReloadApp::init();
+    let tracker = app.window_state.widget_state.clone();
+    tracker.record_scroll("list", ScrollOffset { x: 0.0, y: 10.0 });
+    tracker.record_scroll("log", ScrollOffset { x: 0.0, y: 20.0 });
+
+    // Only the second scrollable changed: its offset alone is restored
+    let task = app.update(saved(CHANGED_LOG)?);
+    assert_eq!(task.units(), 1);
+    assert_eq!(app.window_state.reload_generation, 1);
+    assert_eq!(
+        app.window_state.document.stable_hash(),
+        parse(CHANGED_LOG)
+            .map_err(|error| error.to_string())?
+            .stable_hash()
+    );
+
+    // Saving the same content again restores nothing
+    let task = app.update(saved(CHANGED_LOG)?);
+    assert_eq!(task.units(), 0);
+    assert_eq!(app.window_state.reload_generation, 2);
+
+    // A new root rebuilds the whole tree: every offset is restored
+    let task = app.update(saved(CHANGED_ROOT)?);
+    assert_eq!(task.units(), 2);
+    Ok(())
+}
+
+/// The same app with its view state created on first use
+mod lazy {
+    use super::{Message, saved, ui};
+    use dampen_core::state::ScrollOffset;
+    use dampen_macros::dampen_app;
+
+    #[dampen_app(
+        ui_dir = "fixtures/hot_reload_app/ui",
+        message_type = "Message",
+        handler_variant = "Handler",
+        hot_reload_variant = "HotReload",
+        dismiss_error_variant = "DismissError",
+        default_view = "window",
+        lazy = true
+    )]
+    struct LazyHotReloadApp;
+
+    #[test]
+    fn test_lazy_view_reloads() -> Result<(), Box<dyn std::error::Error>> {
+        let (mut app, _) = LazyHotReloadApp::init();
+        let tracker = app
+            .window_state
+            .as_ref()
+            .map(|state| state.widget_state.clone())
+            .ok_or("the default view is loaded")?;
+        tracker.record_scroll("log", ScrollOffset { x: 0.0, y: 20.0 });
+
+        let task = app.update(saved(super::CHANGED_LOG)?);
+        assert_eq!(task.units(), 1);
+        assert_eq!(
+            app.window_state
+                .as_ref()
+                .map(|state| state.reload_generation),
+            Some(1)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+//! Handler middleware parity between interpreted and codegen modes
+//!
+//! The same middleware rewrites and cancels the values of typed and `String`
+//! handlers. Interpreted mode runs it on the registry, generated code (built by
+//! `build.rs`) as a global middleware; both must leave the model in the same state.
+
+use dampen_core::HandlerRegistry;
+use dampen_core::handler::{
+    DispatchResult, Next, clear_global_middleware, register_global_middleware,
+};
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Model {
+    count: i32,
+    name: String,
+}
+
+pub fn increment(model: &mut Model) {
+    model.count += 1;
+}
+
+pub fn set_count(model: &mut Model, value: i32) {
+    model.count = value;
+}
+
+pub fn set_name(model: &mut Model, value: String) {
+    model.name = value;
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    Increment,
+    SetCount(i32),
+    SetName(String),
+}
+
+mod generated {
+    use super::*;
+
+    include!(concat!(env!("OUT_DIR"), "/middleware_parity.rs"));
+}
+
+const EVENTS: &[(&str, Option<&str>)] = &[
+    ("increment", None),
+    // Doubled by the middleware
+    ("set_count", Some("21")),
+    ("set_name", Some("ada")),
+    // Cancelled by the middleware
+    ("set_count", Some("-1")),
+    // Rewritten to a value that does not parse, so the handler is skipped
+    ("set_count", Some("7")),
+    ("increment", None),
+];
+
+/// Doubles counts, upper-cases names and cancels negative counts
+fn rewrite(next: Next<'_>, name: &str, value: Option<String>) -> DispatchResult {
+    match (name, value) {
+        ("set_count", Some(value)) if value.starts_with('-') => None,
+        ("set_count", Some(value)) if value == "7" => next.run(Some("seven".to_string())),
+        ("set_count", Some(value)) => next.run(
+            value
+                .parse::<i32>()
+                .ok()
+                .map(|count| (count * 2).to_string()),
+        ),
+        ("set_name", value) => next.run(value.map(|name| name.to_uppercase())),
+        (_, value) => next.run(value),
+    }
+}
+
+fn run_interpreted() -> Model {
+    let registry = HandlerRegistry::new();
+    registry.register_simple("increment", |model| {
+        if let Some(model) = model.downcast_mut::<Model>() {
+            increment(model);
+        }
+    });
+    registry.register_with_number("set_count", |model, value: i32| {
+        if let Some(model) = model.downcast_mut::<Model>() {
+            set_count(model, value);
+        }
+    });
+    registry.register_with_value("set_name", |model, value| {
+        if let (Some(model), Ok(value)) =
+            (model.downcast_mut::<Model>(), value.downcast::<String>())
+        {
+            set_name(model, *value);
+        }
+    });
+    registry.register_middleware(rewrite);
+
+    let mut model = Model::default();
+    for (handler, value) in EVENTS {
+        registry.dispatch(handler, &mut model, value.map(str::to_string));
+    }
+    model
+}
+
+/// Clears the global middleware when dropped, even if the run fails
+struct GlobalMiddlewareGuard;
+
+impl Drop for GlobalMiddlewareGuard {
+    fn drop(&mut self) {
+        clear_global_middleware();
+    }
+}
+
+fn run_codegen() -> Result<Model, String> {
+    let _guard = GlobalMiddlewareGuard;
+    register_global_middleware(rewrite);
+
+    let mut model = Model::default();
+    for (handler, value) in EVENTS {
+        let message = generated::message_for(handler, value.map(str::to_string))
+            .ok_or_else(|| format!("No message for handler '{}'", handler))?;
+        let _ = generated::update_model(&mut model, message);
+    }
+    Ok(model)
+}
+
+#[test]
+fn test_middleware_rewrites_typed_values_in_both_modes() -> Result<(), String> {
+    let interpreted = run_interpreted();
+    let codegen = run_codegen()?;
+
+    assert_eq!(
+        interpreted,
+        Model {
+            count: 43,
+            name: "ADA".to_string(),
+        }
+    );
+    assert_eq!(codegen, interpreted);
+    Ok(())
+}
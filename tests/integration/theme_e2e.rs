@@ -81,8 +81,11 @@ fn create_test_theme(name: &str) -> Theme {
             font_size_large: Some(24.0),
             font_weight: dampen_core::ir::theme::FontWeight::Normal,
             line_height: Some(1.5),
+            scale_ratio: None,
+            fonts: Vec::new(),
         },
         spacing: SpacingScale { unit: Some(8.0) },
+        shadows: Default::default(),
         base_styles: HashMap::new(),
         extends: None,
     }
@@ -231,8 +234,11 @@ mod contract_theme_hot_reload_integration {
                 font_size_large: Some(24.0),
                 font_weight: dampen_core::ir::theme::FontWeight::Normal,
                 line_height: Some(1.5),
+                scale_ratio: None,
+                fonts: Vec::new(),
             },
             spacing: SpacingScale { unit: Some(8.0) },
+            shadows: Default::default(),
             base_styles: HashMap::new(),
             extends: None,
         }
@@ -664,8 +670,11 @@ mod contract_theme_codegen_integration {
                 font_size_large: Some(24.0),
                 font_weight: dampen_core::ir::theme::FontWeight::Normal,
                 line_height: Some(1.5),
+                scale_ratio: None,
+                fonts: Vec::new(),
             },
             spacing: SpacingScale { unit: Some(8.0) },
+            shadows: Default::default(),
             base_styles: HashMap::new(),
             extends: None,
         }